@@ -3,6 +3,7 @@ use std::path::PathBuf;
 fn main() -> Result<(), Box<dyn std::error::Error>> {
     let proto_root = PathBuf::from("proto/temporal-api");
     let service_proto = proto_root.join("temporal/api/workflowservice/v1/service.proto");
+    let operator_proto = proto_root.join("temporal/api/operatorservice/v1/service.proto");
 
     if service_proto.exists() {
         println!("cargo:rerun-if-changed=proto/temporal-api");
@@ -10,10 +11,23 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
         let out_dir = PathBuf::from("src/proto/generated");
         std::fs::create_dir_all(&out_dir)?;
 
+        let descriptor_path = PathBuf::from(std::env::var("OUT_DIR")?).join("descriptor.bin");
+
+        // Well-known types come from pbjson-types so every message can
+        // serialize to proto JSON.
         tonic_build::configure()
             .build_server(false)
             .out_dir(&out_dir)
-            .compile_protos(&[service_proto], &[proto_root.as_path()])?;
+            .file_descriptor_set_path(&descriptor_path)
+            .compile_well_known_types(true)
+            .extern_path(".google.protobuf", "::pbjson_types")
+            .compile_protos(&[service_proto, operator_proto], &[proto_root.as_path()])?;
+
+        let descriptors = std::fs::read(&descriptor_path)?;
+        pbjson_build::Builder::new()
+            .register_descriptors(&descriptors)?
+            .out_dir(&out_dir)
+            .build(&[".temporal", ".google.api"])?;
     }
 
     Ok(())
@@ -1,5 +1,10 @@
+use crate::app::{PayloadSide, PreviewRow};
+use crate::audit::AuditEntry;
+use crate::client::Capabilities;
+use crate::config::{Cli, ConfigFile};
 use crate::domain::*;
-use crate::kinds::OperationId;
+use crate::kinds::{KindId, OperationId};
+use crate::nav::Location;
 
 #[derive(Debug, Clone)]
 pub enum Action {
@@ -25,12 +30,32 @@ pub enum Action {
     // UI
     OpenCommandInput,
     OpenSearch,
+    OpenFilter,
+    SubmitFilter,
     CloseOverlay,
     SubmitCommandInput(String),
     SubmitSearch(String),
+    /// Accept the first completion for the search being typed.
+    CompleteSearch,
     UpdateInputBuffer(String),
     ToggleHelp,
+    OpenSignalPicker,
+    OpenQueryPicker,
+    OpenQueryBuilder,
+    /// A character typed in the query builder.
+    QueryBuilderInput(char),
+    QueryBuilderBack,
+    ToggleWideMode,
+    /// List on the left, highlighted row's detail on the right.
+    ToggleSplitView,
+    /// The terminal is now this many columns wide.
+    Resize(u16),
+    /// The config file was edited and read back without errors.
+    ConfigReloaded(Box<ConfigFile>),
+    ToggleRelativeTime,
     SwitchNamespace(String),
+    /// `1`–`9`: the favorite namespace at this index, back where it was left.
+    SwitchFavoriteNamespace(usize),
 
     // Tab navigation (for detail views)
     NextTab,
@@ -39,29 +64,125 @@ pub enum Action {
     // Nested navigation
     OpenScheduleWorkflows,
     OpenWorkflowActivities,
+    OpenParentWorkflow,
+    OpenRootWorkflow,
+
+    // Collection layout
+    ToggleScheduleGrouping,
+
+    // JSON trees (Input/Output, history details)
+    ToggleFold,
+    NextMatch,
+    PrevMatch,
 
     // Data responses
     WorkflowsLoaded(Vec<WorkflowSummary>, Vec<u8>),
     MoreWorkflowsLoaded(Vec<WorkflowSummary>, Vec<u8>),
+    /// An `:allns` list, with the namespaces that couldn't be listed and why.
+    FanoutLoaded(Vec<WorkflowSummary>, Vec<(String, String)>),
     WorkflowDetailLoaded(Box<WorkflowDetail>),
+    /// A preview's row has been highlighted for the debounce.
+    PreviewDue(PreviewRow),
+    /// The preview pane's describe of the workflow id and run id it asked for.
+    WorkflowPreviewLoaded(String, String, Result<Box<WorkflowDetail>, String>),
     HistoryLoaded(Vec<HistoryEvent>),
+    StackTraceLoaded(String),
+    StackTraceFailed(String),
+    /// The describe response for the Raw tab.
+    RawDetailLoaded(Box<serde_json::Value>),
+    RawDetailFailed(String),
+    WorkflowMetadataLoaded(Box<WorkflowMetadata>),
+    WorkflowMetadataFailed(String),
+    WorkflowRestarted(String, String),
+    /// How many workflows in a namespace match a `:bulk` query.
+    BatchCounted(String, BatchOperation, String, u64),
+    /// A batch job was accepted; carries its job id.
+    BatchStarted(BatchOperation, String),
     NamespacesLoaded(Vec<Namespace>),
-    SchedulesLoaded(Vec<Schedule>),
+    ClusterInfoLoaded(Box<ClusterInfo>),
+    /// Round trip and server info from a health check, or why it failed.
+    HealthChecked(Result<(std::time::Duration, Box<ClusterInfo>), String>),
+    SchedulesLoaded(Vec<Schedule>, Vec<u8>),
+    MoreSchedulesLoaded(Vec<Schedule>, Vec<u8>),
     ScheduleDetailLoaded(Box<Schedule>),
+    ScheduleDraftLoaded(Box<ScheduleDraft>),
+    /// The text the editor was opened with, and the saved text or why the
+    /// editor failed.
+    ScheduleEdited(Box<ScheduleDraft>, String, Result<String, String>),
+    /// Open the selected pending activity's options in `$EDITOR`.
+    EditActivityOptions,
+    /// The text the editor was opened with, and the saved text or why the
+    /// editor failed.
+    ActivityOptionsEdited(Box<ActivityOptionsDraft>, String, Result<String, String>),
     WorkflowCountLoaded(u64),
+    /// Workflow counts per status; the total is their sum.
+    WorkflowStatusCountsLoaded(Vec<(WorkflowStatus, u64)>),
+    /// The server can't count, e.g. without advanced visibility.
+    WorkflowCountUnavailable,
     TaskQueueDetailLoaded(Box<TaskQueueInfo>),
     ActivityExecutionsLoaded(Vec<ActivityExecutionSummary>, Vec<u8>),
     MoreActivityExecutionsLoaded(Vec<ActivityExecutionSummary>, Vec<u8>),
     ActivityExecutionDetailLoaded(Box<ActivityExecutionDetail>),
     ActivityExecutionCountLoaded(u64),
     ActivitiesSupported(bool),
+    /// The namespace's search attributes, or why they couldn't be listed.
+    SearchAttributesLoaded(Result<Vec<SearchAttribute>, String>),
+    OverviewLoaded(Box<NamespaceOverview>),
+    /// The server rejected the search query for a kind's list.
+    InvalidQuery(KindId, String),
 
     // App control
     Refresh,
     Quit,
     Tick,
+    OpenContextPicker,
+    /// Opens the command line prefilled to add a search attribute.
+    PromptSearchAttribute,
+    /// Open command mode with `String` already typed.
+    PromptCommand(String),
+    /// Open (or reload) the namespace overview.
+    OpenOverview,
+    /// Pick a StartTime preset for the workflow list.
+    OpenTimeRangePicker,
+    /// Jump to a deep link's view, switching namespace if needed.
+    OpenLocation(Location),
+    /// Copy the current view's deep link to the clipboard.
+    CopyLink,
+    /// Mark or unmark the workflow under the cursor for a bulk operation.
+    ToggleMark,
+    /// Open the menu of everything that can be done to the selected row.
+    OpenQuickActions,
+    /// Open the current tab's long text in the pager.
+    OpenPager,
+    /// Run the selected payload through a `[[pipes]]` command.
+    PipePayload,
+    /// `i` / `o` on the Input/Output tab: the workflow's input or output
+    /// to the clipboard.
+    CopyPayload(PayloadSide),
+    /// A pipe command's output (or error), for the pager titled `String`.
+    PipeFinished(String, Result<String, String>),
+    OpenBookmarks,
+    /// Remove the bookmark under the picker cursor.
+    DeleteBookmark,
+    /// A mutation the worker finished, successfully or not.
+    Audited(Box<AuditEntry>),
     Error(String),
+    /// A request failed while the client is reconnecting.
+    ConnectionLost(String),
+    /// A request failed after the client gave up reconnecting.
+    Disconnected(String),
+    /// Start reconnecting again; any key sends it while disconnected.
+    Reconnect,
+    /// A worker result for the named context and, for namespace- or
+    /// query-dependent loads, the generation it was requested in. Ignored once
+    /// another context is active or the generation has moved on.
+    ForContext(String, Option<u64>, Box<Action>),
+    /// The `:ctx` profile is connected, with its settings and client
+    /// capabilities, or failed to connect. The client is already registered
+    /// with the worker.
+    ContextConnected(String, Result<(Box<Cli>, Capabilities), String>),
     ClearError,
+    FocusError,
     TogglePolling,
 }
 
@@ -1,15 +1,47 @@
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet, VecDeque};
 use std::time::{Duration, Instant};
 
 use ratatui::widgets::TableState;
 
 use crate::action::{Action, ViewType};
+use crate::audit::AuditEntry;
+use crate::bookmarks::Bookmark;
+use crate::client::Capabilities;
+use crate::config::{
+    ConfigFile, KindConfig, PipeConfig, QueryConfig, TypedConfirmConfig, MAX_PAGE_SIZE,
+};
 use crate::domain::*;
-use crate::kinds::{detail_tab_count, operation_effect_spec, operation_spec, KindId, OperationId};
+use crate::editor;
+use crate::export::{ExportFormat, ExportTable, EXPORT_ROW_CAP};
+use crate::i18n::{t, tf, Msg};
+use crate::input::fuzzy_matches;
+use crate::input::query_builder::{BuilderStep, QueryBuilder, DEFAULT_ATTRIBUTES};
+use crate::input::query_complete::{complete, Completion, Vocabulary};
+use crate::input::query_syntax;
+use crate::kinds::{
+    default_columns, detail_tab_count, kind_spec, operation_effect_spec, operation_spec,
+    preset_columns, resolve_columns, wide_columns, ColumnSpec, CustomKind, KindId, KindRegistry,
+    OperationId, SPLIT_MIN_WIDTH, WIDE_MIN_WIDTH,
+};
+use crate::mask::{mask, mask_text, MaskRule};
 use crate::nav::{
-    parse_deep_link, ActivitiesRoute, Location, RouteSegment, SchedulesRoute, UriError,
-    WorkflowsRoute,
+    format_deep_link, parse_deep_link, web_ui_url, ActivitiesRoute, Location, RouteSegment,
+    SchedulesRoute, WebUiPage, WorkflowsRoute,
 };
+use crate::pager::PagerState;
+use crate::widgets::json_tree::{self, JsonTreeState, TreeRow};
+
+const ERROR_TOAST_TTL: Duration = Duration::from_secs(5);
+const NOTICE_TTL: Duration = Duration::from_secs(3);
+const ERROR_LOG_LIMIT: usize = 200;
+const RECENT_SIGNAL_LIMIT: usize = 10;
+const HEALTH_CHECK_INTERVAL: Duration = Duration::from_secs(15);
+/// How long the list cursor rests on a row before the preview pane or the
+/// split view describes it.
+pub const PREVIEW_DEBOUNCE: Duration = Duration::from_millis(300);
+/// Context id, shown in the audit log, of a connection made without a
+/// profile.
+pub const DEFAULT_CONTEXT: &str = "default";
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum View {
@@ -17,25 +49,222 @@ pub enum View {
     Detail(KindId),
 }
 
+/// What the current view polls, each with its own interval.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum PollTarget {
+    List,
+    Detail,
+    /// A workflow's History tab.
+    History,
+}
+
+impl PollTarget {
+    pub const ALL: [PollTarget; 3] = [Self::List, Self::Detail, Self::History];
+
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::List => "list",
+            Self::Detail => "detail",
+            Self::History => "history",
+        }
+    }
+
+    pub fn parse(s: &str) -> Option<Self> {
+        Self::ALL
+            .into_iter()
+            .find(|target| target.as_str().eq_ignore_ascii_case(s))
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum InputMode {
     Normal,
     Command,
     Search,
+    /// Typing narrows the loaded rows locally, without a server query.
+    Filter,
     PendingG,
 }
 
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq)]
 pub enum Overlay {
     None,
     Help,
     NamespaceSelector,
     Confirm(ConfirmAction),
+    Logs,
+    SignalPicker,
+    QueryPicker,
+    ContextPicker,
+    SearchAttributes,
+    QueryBuilder,
+    Overview,
+    TimeRange,
+    Bookmarks,
+    Pager,
+    Audit,
+    /// The pending activity under the Pending tab's cursor.
+    PendingActivity,
+    /// Everything that can be done to the selected row.
+    QuickActions,
 }
 
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq)]
 pub enum ConfirmAction {
     Operation(OperationConfirm),
+    /// Second confirmation for a terminate-and-restart, shown after the terminate
+    /// step has been accepted. Nothing runs until both are confirmed.
+    Restart(OperationConfirm),
+    Namespace(NamespaceChange),
+    Batch(BatchConfirm),
+    /// Pending activity options as opened, and as edited.
+    ActivityOptions(Box<ActivityOptionsDraft>, Box<ActivityOptionsEdit>),
+}
+
+impl ConfirmAction {
+    /// Returns the overlay to show next when this confirmation is accepted, or `None`
+    /// if the operation should run now.
+    pub fn next_step(&self) -> Option<ConfirmAction> {
+        match self {
+            ConfirmAction::Operation(confirm) if confirm.op == OperationId::TerminateAndRestart => {
+                Some(ConfirmAction::Restart(confirm.clone()))
+            }
+            _ => None,
+        }
+    }
+}
+
+/// Marked rows up to this many are cancelled or terminated one request at a
+/// time; more go to the server's batch API.
+pub const BATCH_DIRECT_LIMIT: usize = 20;
+
+/// A cancel or terminate of several workflows awaiting confirmation.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BatchConfirm {
+    /// Namespace the workflows were marked or counted in.
+    pub namespace: String,
+    pub operation: BatchOperation,
+    pub target: BatchTarget,
+    /// Workflows affected; from `CountWorkflowExecutions` for a query.
+    pub count: u64,
+}
+
+impl BatchConfirm {
+    pub fn effects(&self) -> Vec<Effect> {
+        match &self.target {
+            BatchTarget::Executions(executions) if executions.len() <= BATCH_DIRECT_LIMIT => {
+                executions
+                    .iter()
+                    .map(|(workflow_id, run_id)| {
+                        let (workflow_id, run_id) = (workflow_id.clone(), Some(run_id.clone()));
+                        match self.operation {
+                            BatchOperation::Cancel => Effect::CancelWorkflow(workflow_id, run_id),
+                            BatchOperation::Terminate => {
+                                Effect::TerminateWorkflow(workflow_id, run_id)
+                            }
+                        }
+                    })
+                    .collect()
+            }
+            target => vec![Effect::StartBatchOperation(
+                self.namespace.clone(),
+                self.operation,
+                target.clone(),
+            )],
+        }
+    }
+}
+
+/// A payload on its way through a `[[pipes]]` command.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PipeJob {
+    /// Pager title for the output, e.g. `Input | jq`.
+    pub title: String,
+    pub command: String,
+    pub input: String,
+}
+
+/// Where the cursor is in a list, shown in the footer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ListPosition {
+    /// 1-based; 0 with nothing selected.
+    pub selected: usize,
+    /// Rows on screen, after the local filter.
+    pub shown: usize,
+    /// The server's count of matching rows, when it has been asked.
+    pub total: Option<u64>,
+    pub more_pages: bool,
+    /// Pages still to load, when `total` allows an estimate.
+    pub pages_left: Option<u64>,
+}
+
+/// A list to write out with `:export`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ExportJob {
+    pub kind: KindId,
+    pub path: std::path::PathBuf,
+    /// Load every remaining page, up to [`EXPORT_ROW_CAP`], before writing.
+    pub all: bool,
+    /// Replace `path` if it exists (`:export!`).
+    pub overwrite: bool,
+}
+
+/// A row a preview waits on for [`PREVIEW_DEBOUNCE`] before loading it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PreviewRow {
+    /// The preview pane's (workflow id, run id).
+    Workflow(String, String),
+    /// The split view's highlighted row.
+    Split(KindId, String),
+}
+
+/// The workflow payload copied with `i` / `o` or written with
+/// `:save-input` / `:save-output`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PayloadSide {
+    Input,
+    Output,
+}
+
+impl PayloadSide {
+    fn label(self) -> &'static str {
+        match self {
+            PayloadSide::Input => "input",
+            PayloadSide::Output => "output",
+        }
+    }
+}
+
+/// An entry in the quick actions menu (`.`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum QuickAction {
+    Operation(OperationId),
+    Signal,
+    CopyId,
+    CopyLink,
+    OpenWebUi,
+}
+
+impl QuickAction {
+    /// The key that does the same outside the menu.
+    pub fn key(self, kind: KindId) -> Option<char> {
+        match self {
+            QuickAction::Operation(op) => operation_spec(kind, op).map(|spec| spec.key),
+            QuickAction::Signal => Some('S'),
+            QuickAction::CopyId | QuickAction::OpenWebUi => None,
+            QuickAction::CopyLink => Some('Y'),
+        }
+    }
+
+    pub fn label(self, kind: KindId) -> &'static str {
+        match self {
+            QuickAction::Operation(op) => operation_spec(kind, op).map_or("", |spec| spec.label),
+            QuickAction::Signal => t(Msg::QuickActionSignal),
+            QuickAction::CopyId => t(Msg::QuickActionCopyId),
+            QuickAction::CopyLink => t(Msg::QuickActionCopyLink),
+            QuickAction::OpenWebUi => t(Msg::QuickActionOpenWebUi),
+        }
+    }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -60,6 +289,44 @@ pub enum OperationTarget {
     },
 }
 
+/// Where a namespace was left: the view, every list's query and the
+/// workflow time range.
+#[derive(Debug, Clone)]
+struct NamespaceMemory {
+    location: Location,
+    queries: HashMap<KindId, String>,
+    time_range: Option<TimeRange>,
+    time_range_anchor: chrono::DateTime<chrono::Utc>,
+}
+
+/// A signal sent from the TUI, kept so it can be resent from the quick-pick.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RecentSignal {
+    pub name: String,
+    pub input: Option<String>,
+}
+
+/// A saved list filter from the config file.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SavedQuery {
+    pub name: String,
+    pub kind: KindId,
+    pub namespace: Option<String>,
+    pub query: String,
+}
+
+/// A visible row in the schedules table: either a group header or an index into
+/// `App::schedules`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ScheduleListRow {
+    Group {
+        key: String,
+        count: usize,
+        collapsed: bool,
+    },
+    Schedule(usize),
+}
+
 #[derive(Debug, Clone)]
 pub enum LoadState<T> {
     NotLoaded,
@@ -89,21 +356,53 @@ pub enum ConnectionStatus {
     Error(String),
 }
 
+/// Which outstanding loads to abandon. `Namespace` covers everything that
+/// depends on the namespace, detail loads included.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CancelScope {
+    Namespace,
+    Detail,
+}
+
 #[derive(Debug, Clone)]
 pub enum Effect {
+    /// Abandon in-flight and queued requests in the scope.
+    CancelRequests(CancelScope),
+    /// Have the client start reconnecting after it gave up.
+    Reconnect,
     LoadWorkflows,
     LoadWorkflowDetail(String, Option<String>),
+    /// Describe a workflow for the preview pane.
+    LoadWorkflowPreview(String, String),
     LoadHistory(String, Option<String>),
+    LoadStackTrace(String, Option<String>),
+    LoadWorkflowMetadata(String, Option<String>),
+    LoadRawWorkflow(String, Option<String>),
+    LoadRawSchedule(String),
     LoadNamespaces,
+    HealthCheck,
     LoadSchedules,
     LoadScheduleDetail(String),
     LoadWorkflowCount,
     CancelWorkflow(String, Option<String>),
     TerminateWorkflow(String, Option<String>),
-    PauseSchedule(String, bool),
+    TerminateAndRestartWorkflow(String, Option<String>),
+    CountBatch(BatchOperation, String),
+    /// Namespace, operation and target of a confirmed batch job.
+    StartBatchOperation(String, BatchOperation, BatchTarget),
+    /// Schedule id, whether to pause, and the note to record.
+    PauseSchedule(String, bool, String),
     TriggerSchedule(String),
     DeleteSchedule(String),
+    LoadScheduleDraft(String),
+    UpdateSchedule {
+        schedule_id: String,
+        edit: Box<ScheduleEdit>,
+        conflict_token: Vec<u8>,
+    },
+    UpdateActivityOptions(Box<ActivityOptionsDraft>, Box<ActivityOptionsEdit>),
     LoadMoreWorkflows,
+    LoadMoreSchedules,
     LoadTaskQueueDetail(String),
     LoadActivityExecutions {
         namespace: String,
@@ -133,9 +432,46 @@ pub enum Effect {
         namespace: String,
     },
     SignalWorkflow(String, Option<String>, String, Option<String>),
+    LoadSearchAttributes,
+    AddSearchAttribute(String, SearchAttributeType),
+    ChangeNamespace(NamespaceChange),
+    LoadOverview,
+    SetMouseCapture(bool),
+    CopyToClipboard(String),
+    /// Open a URL in the browser.
+    OpenUrl(String),
+    /// Switch to a `:ctx` profile, connecting on first use.
+    SwitchContext(String),
+    /// Hand back [`Action::PreviewDue`] after [`PREVIEW_DEBOUNCE`].
+    DelayPreview(PreviewRow),
+    /// Open a fetched schedule's text in `$EDITOR` for `e`.
+    EditSchedule(Box<ScheduleDraft>, String),
+    /// Open pending activity options' text in `$EDITOR` for `e`.
+    EditActivityOptions(Box<ActivityOptionsDraft>, String),
+    /// Run a payload through a `[[pipes]]` command.
+    RunPipe(PipeJob),
+    /// Run a `:!` command on the suspended terminal with its `T9S_*`
+    /// environment.
+    RunShell(String, Vec<(String, String)>),
+    /// Write a file for `:export` or `:save-input` / `:save-output`.
+    WriteFile(FileWrite),
+    SaveBookmarks(Vec<Bookmark>),
     Quit,
 }
 
+/// A file a `:` command writes, and what to say once it is written.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FileWrite {
+    pub path: std::path::PathBuf,
+    pub contents: String,
+    /// Replace an existing file; set by the `!` form of the command.
+    pub overwrite: bool,
+    /// The command, named in the error when the file already exists.
+    pub command: &'static str,
+    /// Notice shown once the file is written.
+    pub done: String,
+}
+
 pub struct App {
     // View state
     pub view: View,
@@ -145,20 +481,77 @@ pub struct App {
     // Connection
     pub namespace: String,
     pub namespaces: Vec<Namespace>,
+    /// The config's `favorite_namespaces`, switched to with `1`–`9`.
+    pub favorite_namespaces: Vec<String>,
+    /// Where each namespace was left, restored when a favorite key returns
+    /// to it.
+    namespace_memory: HashMap<String, NamespaceMemory>,
     pub connection_status: ConnectionStatus,
+    pub capabilities: Capabilities,
+    /// Profile names from the config file, offered by `:ctx`.
+    pub profiles: Vec<String>,
+    /// Profile of the active context; `None` for the startup connection.
+    pub active_profile: Option<String>,
+    /// Profile being switched to; a connection finishing for any other
+    /// profile is not switched to.
+    pub switching_context: Option<String>,
+    /// Export requested with `:export`, written once its pages are loaded.
+    pub pending_export: Option<ExportJob>,
+    /// Mutations sent this session, oldest first, for `:audit`.
+    pub audit_log: Vec<AuditEntry>,
+    /// The config's `audit_file`, which the worker appends every mutation to.
+    pub audit_file: Option<std::path::PathBuf>,
+    /// Commands from the config file's `[[pipes]]`, offered by `|`.
+    pub pipes: Vec<PipeConfig>,
+    /// The config's `[[mask]]` rules, applied to payloads as they are drawn.
+    pub mask_rules: Vec<MaskRule>,
+    pub pager: PagerState,
+    pub context_picker_cursor: usize,
+    /// Bumped whenever the namespace or a list query changes; loads sent
+    /// before that are dropped when they return.
+    pub generation: u64,
+    pub time_display: TimeDisplay,
+    pub cluster_info: Option<ClusterInfo>,
+    /// The config's `web_url`.
+    pub web_url: Option<String>,
 
     // Workflow data
     pub workflows: LoadState<Vec<WorkflowSummary>>,
+    /// Generation `workflows` was loaded in; a load in the same one is a
+    /// poll and merges instead of replacing.
+    workflows_generation: Option<u64>,
     pub workflow_count: Option<u64>,
+    /// Breakdown of `workflow_count` by status, refreshed with it.
+    pub workflow_status_counts: Vec<(WorkflowStatus, u64)>,
     pub selected_workflow: Option<WorkflowDetail>,
     pub workflow_history: LoadState<Vec<HistoryEvent>>,
     pub workflow_table_state: TableState,
     pub workflow_detail_tab: usize,
+    pub history_cursor: usize,
+    pub child_cursor: usize,
+    pub pending_cursor: usize,
+    pub io_tree: JsonTreeState,
+    pub history_tree: JsonTreeState,
+    pub stack_trace: LoadState<String>,
+    /// Raw tab of the open workflow or schedule, as pretty JSON.
+    pub raw_detail: LoadState<serde_json::Value>,
+    /// Handlers and current details for the Metadata tab.
+    pub workflow_metadata: LoadState<WorkflowMetadata>,
+    /// Workflows drilled through to reach the current child, outermost first.
+    pub workflow_parents: Vec<WorkflowRef>,
 
     // Schedule data
     pub schedules: LoadState<Vec<Schedule>>,
+    /// Generation `schedules` was loaded in, as for `workflows_generation`.
+    schedules_generation: Option<u64>,
+    pub schedule_next_page_token: Vec<u8>,
     pub selected_schedule: Option<Schedule>,
     pub schedule_table_state: TableState,
+    pub schedule_grouping: ScheduleGrouping,
+    /// Selected row of the schedule detail's recent actions.
+    pub schedule_action_cursor: usize,
+    pub schedule_detail_tab: usize,
+    pub collapsed_schedule_groups: HashSet<String>,
 
     // Standalone activity data
     pub activity_executions: LoadState<Vec<ActivityExecutionSummary>>,
@@ -175,30 +568,110 @@ pub struct App {
 
     // Namespace selector
     pub namespace_selector_state: TableState,
+    /// Narrows the namespace selector by name (`/` in the selector).
+    pub namespace_filter: String,
+
+    // Search attributes overlay
+    pub search_attributes: LoadState<Vec<SearchAttribute>>,
+    pub search_attribute_cursor: usize,
+    pub query_builder: QueryBuilder,
+
+    // Namespace overview
+    pub overview: LoadState<Box<NamespaceOverview>>,
+    pub overview_cursor: usize,
+
+    // StartTime preset for the workflow list
+    pub time_range: Option<TimeRange>,
+    /// When the time range was picked. The range counts back from here, so
+    /// the query stays the same across polls and pages.
+    pub time_range_anchor: chrono::DateTime<chrono::Utc>,
+    pub time_range_cursor: usize,
+    /// The workflow list shows archived runs (`:archived`).
+    pub archived_workflows: bool,
+    /// Namespaces the workflow list fans out across (`:allns`); empty lists
+    /// just the current one.
+    pub namespace_fanout: Vec<String>,
+    /// Namespaces of the `:allns` list that failed to load last time.
+    pub fanout_failed: Vec<String>,
+
+    // Saved deep links
+    pub bookmarks: Vec<Bookmark>,
+    pub bookmark_cursor: usize,
 
     // Detail scroll
     pub detail_scroll: u16,
 
     // Input
     pub input_buffer: String,
+    /// Why the last search was rejected, shown in the search modal.
+    pub search_error: Option<String>,
     pub search_queries: HashMap<KindId, String>,
+    /// Fuzzy filters over the rows already loaded, per kind.
+    pub local_filters: HashMap<KindId, String>,
+    /// Columns chosen in the config file; kinds without an entry use their defaults.
+    pub columns: HashMap<KindId, Vec<ColumnSpec>>,
+    /// Wide-mode columns from the config file (`workflows-wide = [...]`).
+    pub wide_columns: HashMap<KindId, Vec<ColumnSpec>>,
+    pub wide_mode: bool,
+    /// Preview the highlighted row's detail beside the list.
+    pub split_view: bool,
+    /// Terminal width in columns, which decides whether the split view fits.
+    pub screen_width: u16,
+    /// Show a compact preview of the highlighted workflow under the list.
+    pub preview_pane: bool,
+    /// The preview pane's workflow.
+    pub workflow_preview: LoadState<WorkflowDetail>,
 
     // Polling
     pub polling_enabled: bool,
-    pub polling_interval: Duration,
+    /// Interval for views without their own entry in `poll_intervals`.
     pub base_polling_interval: Duration,
+    pub poll_intervals: HashMap<PollTarget, Duration>,
+    /// When the current view's data last arrived.
     pub last_refresh: Option<Instant>,
+    /// When the last poll was sent, so a failing view doesn't poll every tick.
+    pub last_poll: Option<Instant>,
     pub error_count: u32,
+    pub health: Health,
+    pub last_health_check: Option<Instant>,
 
     // Pagination
     pub loading_more: bool,
 
     // App
     pub should_quit: bool,
-    pub last_error: Option<(String, Instant)>,
+    /// Off in copy mode so the terminal's own text selection works.
+    pub mouse_capture: bool,
+    /// Queued error toasts; the front one is visible and its instant is when
+    /// it was first shown.
+    pub error_toasts: VecDeque<(String, Instant)>,
+    pub toast_focused: bool,
+    /// A short confirmation for the footer, such as a copied link, and when
+    /// it was raised.
+    pub notice: Option<(String, Instant)>,
+    pub error_log: Vec<(chrono::DateTime<chrono::Local>, String)>,
+    pub logs_scroll: u16,
+    /// Recently sent signals per namespace, newest first.
+    pub recent_signals: HashMap<String, Vec<RecentSignal>>,
+    pub signal_picker_cursor: usize,
+    pub quick_action_cursor: usize,
+    pub saved_queries: Vec<SavedQuery>,
+    /// (workflow id, run id) of rows marked with `m` for a bulk operation.
+    pub marked_workflows: Vec<(String, String)>,
+    /// Namespaces from `[[typed_confirm]]`.
+    pub typed_confirm: Vec<TypedConfirmConfig>,
+    /// Text the open confirmation needs typed before it can be accepted.
+    pub confirm_phrase: Option<String>,
+    pub confirm_input: String,
+    /// Lists from the config file's `[[kinds]]`.
+    pub kinds: KindRegistry,
+    /// Index into `kinds.custom()` of the list on screen, if it is one.
+    pub custom_kind: Option<usize>,
+    pub query_picker_cursor: usize,
     pub active_tab: ViewType,
     pub page_size: i32,
     pub activity_page_size: i32,
+    pub schedule_page_size: i32,
     pub next_page_token: Vec<u8>,
 }
 
@@ -211,18 +684,50 @@ impl App {
 
             namespace,
             namespaces: vec![],
+            favorite_namespaces: vec![],
+            namespace_memory: HashMap::new(),
             connection_status: ConnectionStatus::Connecting,
+            capabilities: Capabilities::default(),
+            profiles: Vec::new(),
+            active_profile: None,
+            switching_context: None,
+            pending_export: None,
+            audit_log: vec![],
+            audit_file: None,
+            pipes: Vec::new(),
+            mask_rules: Vec::new(),
+            pager: PagerState::default(),
+            context_picker_cursor: 0,
+            time_display: TimeDisplay::default(),
+            cluster_info: None,
+            web_url: None,
 
             workflows: LoadState::NotLoaded,
             workflow_count: None,
+            workflow_status_counts: vec![],
             selected_workflow: None,
             workflow_history: LoadState::NotLoaded,
             workflow_table_state: TableState::default(),
             workflow_detail_tab: 0,
+            history_cursor: 0,
+            child_cursor: 0,
+            pending_cursor: 0,
+            io_tree: JsonTreeState::default(),
+            history_tree: JsonTreeState::default(),
+            stack_trace: LoadState::NotLoaded,
+            raw_detail: LoadState::NotLoaded,
+            workflow_metadata: LoadState::NotLoaded,
+            workflow_parents: vec![],
 
             schedules: LoadState::NotLoaded,
+            schedules_generation: None,
+            schedule_next_page_token: vec![],
             selected_schedule: None,
             schedule_table_state: TableState::default(),
+            schedule_grouping: ScheduleGrouping::None,
+            schedule_action_cursor: 0,
+            schedule_detail_tab: 0,
+            collapsed_schedule_groups: HashSet::new(),
 
             activity_executions: LoadState::NotLoaded,
             activity_execution_detail: LoadState::NotLoaded,
@@ -236,40 +741,213 @@ impl App {
             task_queue_detail: LoadState::NotLoaded,
 
             namespace_selector_state: TableState::default(),
+            namespace_filter: String::new(),
+            search_attributes: LoadState::NotLoaded,
+            search_attribute_cursor: 0,
+            overview: LoadState::NotLoaded,
+            overview_cursor: 0,
+            time_range: None,
+            time_range_anchor: chrono::Utc::now(),
+            time_range_cursor: 0,
+            archived_workflows: false,
+            namespace_fanout: vec![],
+            fanout_failed: vec![],
+            bookmarks: Vec::new(),
+            bookmark_cursor: 0,
+            query_builder: QueryBuilder::default(),
             detail_scroll: 0,
 
             input_buffer: String::new(),
+            search_error: None,
             search_queries: HashMap::new(),
+            generation: 0,
+            workflows_generation: None,
+            local_filters: HashMap::new(),
+            columns: HashMap::new(),
+            wide_columns: HashMap::new(),
+            wide_mode: false,
+            split_view: false,
+            screen_width: 0,
+            preview_pane: false,
+            workflow_preview: LoadState::NotLoaded,
 
             loading_more: false,
 
             polling_enabled: true,
-            polling_interval: Duration::from_secs(3),
             base_polling_interval: Duration::from_secs(3),
+            poll_intervals: HashMap::new(),
             last_refresh: None,
+            last_poll: None,
+            health: Health::default(),
+            last_health_check: None,
             error_count: 0,
 
             should_quit: false,
-            last_error: None,
+            mouse_capture: true,
+            error_toasts: VecDeque::new(),
+            notice: None,
+            toast_focused: false,
+            error_log: vec![],
+            logs_scroll: 0,
+            recent_signals: HashMap::new(),
+            signal_picker_cursor: 0,
+            quick_action_cursor: 0,
+            saved_queries: Vec::new(),
+            marked_workflows: Vec::new(),
+            typed_confirm: Vec::new(),
+            confirm_phrase: None,
+            confirm_input: String::new(),
+            kinds: KindRegistry::default(),
+            custom_kind: None,
+            query_picker_cursor: 0,
             active_tab: ViewType::Workflows,
             page_size: 50,
             activity_page_size: 20,
+            schedule_page_size: 100,
             next_page_token: vec![],
         }
     }
 
     pub fn update(&mut self, action: Action) -> Vec<Effect> {
+        let namespace = self.namespace.clone();
+        let queries = self.search_queries.clone();
+        let time_range = (self.time_range, self.time_range_anchor);
+        let archived = self.archived_workflows;
+        let fanout = self.namespace_fanout.clone();
+        let custom_kind = self.active_custom_kind().cloned();
+        let view = self.view.clone();
+        let preview = self.preview_key().filter(|_| self.split_visible());
+        let preview_row = self.workflow_preview_key();
+        let location = self.location();
+        let mut effects = self.apply(action);
+        if self.namespace != namespace
+            || self.search_queries != queries
+            || (self.time_range, self.time_range_anchor) != time_range
+            || self.archived_workflows != archived
+            || self.namespace_fanout != fanout
+            || self.active_custom_kind() != custom_kind.as_ref()
+        {
+            self.generation += 1;
+        }
+        // Cancels go first so the loads that follow are not caught by them.
+        if self.namespace != namespace {
+            // A cancelled page load never reports back.
+            self.loading_more = false;
+            self.namespace_memory.insert(
+                namespace,
+                NamespaceMemory {
+                    location,
+                    queries,
+                    time_range: time_range.0,
+                    time_range_anchor: time_range.1,
+                },
+            );
+            effects.insert(0, Effect::CancelRequests(CancelScope::Namespace));
+        } else if self.active_custom_kind() != custom_kind.as_ref() {
+            // The previous list's pages would otherwise land in this one.
+            self.loading_more = false;
+            effects.insert(0, Effect::CancelRequests(CancelScope::Namespace));
+        } else if matches!(view, View::Detail(_)) && self.view != view {
+            effects.insert(0, Effect::CancelRequests(CancelScope::Detail));
+        }
+        let previewed = self.workflow_preview_key();
+        if previewed != preview_row {
+            self.workflow_preview = match previewed {
+                None => LoadState::NotLoaded,
+                // The preview is the row itself.
+                Some(_) if self.summary_only_rows() => self
+                    .selected_workflow_summary()
+                    .cloned()
+                    .map(|wf| LoadState::Loaded(WorkflowDetail::from_summary(wf)))
+                    .unwrap_or(LoadState::NotLoaded),
+                Some((workflow_id, run_id)) => {
+                    effects.push(Effect::DelayPreview(PreviewRow::Workflow(
+                        workflow_id,
+                        run_id,
+                    )));
+                    LoadState::Loading
+                }
+            };
+        }
+        let previewed = self.preview_key().filter(|_| self.split_visible());
+        if previewed.is_some() && previewed != preview {
+            // A new row's preview starts empty, on its first tab.
+            self.selected_workflow = None;
+            self.selected_schedule = None;
+            self.activity_execution_detail = LoadState::Loading;
+            self.workflow_detail_tab = 0;
+            self.schedule_detail_tab = 0;
+            self.activity_detail_tab = 0;
+            self.detail_scroll = 0;
+            if self.summary_only_rows() && self.view == View::Collection(KindId::WorkflowExecution)
+            {
+                self.selected_workflow = self
+                    .selected_workflow_summary()
+                    .cloned()
+                    .map(WorkflowDetail::from_summary);
+            }
+            effects.extend(
+                previewed.map(|(kind, id)| Effect::DelayPreview(PreviewRow::Split(kind, id))),
+            );
+        }
+        effects.extend(self.ready_export());
+        effects
+    }
+
+    fn apply(&mut self, action: Action) -> Vec<Effect> {
         // Clear stale error toasts
-        if let Some((_, at)) = &self.last_error {
-            if at.elapsed() > Duration::from_secs(5) {
-                self.last_error = None;
+        if let Some((_, at)) = self.error_toasts.front() {
+            if !self.toast_focused && at.elapsed() > ERROR_TOAST_TTL {
+                self.dismiss_error();
             }
         }
+        if self
+            .notice
+            .as_ref()
+            .is_some_and(|(_, at)| at.elapsed() > NOTICE_TTL)
+        {
+            self.notice = None;
+        }
 
         match action {
             // Navigation
             Action::NavigateUp => {
-                if self.is_detail_view() {
+                if matches!(
+                    self.overlay,
+                    Overlay::Logs | Overlay::Audit | Overlay::PendingActivity
+                ) {
+                    self.logs_scroll = self.logs_scroll.saturating_sub(1);
+                } else if self.overlay == Overlay::SignalPicker {
+                    self.signal_picker_cursor = self.signal_picker_cursor.saturating_sub(1);
+                } else if self.overlay == Overlay::QuickActions {
+                    self.quick_action_cursor = self.quick_action_cursor.saturating_sub(1);
+                } else if self.overlay == Overlay::QueryPicker {
+                    self.query_picker_cursor = self.query_picker_cursor.saturating_sub(1);
+                } else if self.overlay == Overlay::ContextPicker {
+                    self.context_picker_cursor = self.context_picker_cursor.saturating_sub(1);
+                } else if self.overlay == Overlay::SearchAttributes {
+                    self.search_attribute_cursor = self.search_attribute_cursor.saturating_sub(1);
+                } else if self.overlay == Overlay::QueryBuilder {
+                    self.query_builder.cursor = self.query_builder.cursor.saturating_sub(1);
+                } else if self.overlay == Overlay::Overview {
+                    self.overview_cursor = self.overview_cursor.saturating_sub(1);
+                } else if self.overlay == Overlay::TimeRange {
+                    self.time_range_cursor = self.time_range_cursor.saturating_sub(1);
+                } else if self.overlay == Overlay::Bookmarks {
+                    self.bookmark_cursor = self.bookmark_cursor.saturating_sub(1);
+                } else if self.overlay == Overlay::Pager {
+                    self.pager.scroll_by(-1);
+                } else if self.is_children_tab() {
+                    self.move_child_cursor(-1);
+                } else if self.is_pending_tab() {
+                    self.move_pending_cursor(-1);
+                } else if self.has_schedule_actions() {
+                    self.move_schedule_action_cursor(-1);
+                } else if self.is_io_tab() {
+                    self.move_io_cursor(-1);
+                } else if self.is_history_tab() {
+                    self.move_history_cursor(-1);
+                } else if self.is_detail_view() {
                     self.detail_scroll = self.detail_scroll.saturating_sub(1);
                 } else {
                     self.navigate_up();
@@ -277,15 +955,79 @@ impl App {
                 vec![]
             }
             Action::NavigateDown => {
-                if self.is_detail_view() {
+                if matches!(
+                    self.overlay,
+                    Overlay::Logs | Overlay::Audit | Overlay::PendingActivity
+                ) {
+                    self.logs_scroll = self.logs_scroll.saturating_add(1);
+                } else if self.overlay == Overlay::SignalPicker {
+                    let len = self.namespace_recent_signals().len();
+                    self.signal_picker_cursor =
+                        (self.signal_picker_cursor + 1).min(len.saturating_sub(1));
+                } else if self.overlay == Overlay::QuickActions {
+                    let len = self.quick_actions().len();
+                    self.quick_action_cursor =
+                        (self.quick_action_cursor + 1).min(len.saturating_sub(1));
+                } else if self.overlay == Overlay::QueryPicker {
+                    let len = self.picker_saved_queries().len();
+                    self.query_picker_cursor =
+                        (self.query_picker_cursor + 1).min(len.saturating_sub(1));
+                } else if self.overlay == Overlay::ContextPicker {
+                    self.context_picker_cursor =
+                        (self.context_picker_cursor + 1).min(self.profiles.len().saturating_sub(1));
+                } else if self.overlay == Overlay::SearchAttributes {
+                    let len = self.search_attributes.data().map_or(0, Vec::len);
+                    self.search_attribute_cursor =
+                        (self.search_attribute_cursor + 1).min(len.saturating_sub(1));
+                } else if self.overlay == Overlay::QueryBuilder {
+                    let len = self
+                        .query_builder
+                        .options(&self.query_builder_attributes())
+                        .len();
+                    self.query_builder.cursor =
+                        (self.query_builder.cursor + 1).min(len.saturating_sub(1));
+                } else if self.overlay == Overlay::TimeRange {
+                    self.time_range_cursor = (self.time_range_cursor + 1).min(TimeRange::ALL.len());
+                } else if self.overlay == Overlay::Bookmarks {
+                    self.bookmark_cursor =
+                        (self.bookmark_cursor + 1).min(self.bookmarks.len().saturating_sub(1));
+                } else if self.overlay == Overlay::Pager {
+                    self.pager.scroll_by(1);
+                } else if self.overlay == Overlay::Overview {
+                    let len = self.overview.data().map_or(0, |o| o.rows().len());
+                    self.overview_cursor = (self.overview_cursor + 1).min(len.saturating_sub(1));
+                } else if self.is_children_tab() {
+                    self.move_child_cursor(1);
+                } else if self.is_pending_tab() {
+                    self.move_pending_cursor(1);
+                } else if self.has_schedule_actions() {
+                    self.move_schedule_action_cursor(1);
+                } else if self.is_io_tab() {
+                    self.move_io_cursor(1);
+                } else if self.is_history_tab() {
+                    self.move_history_cursor(1);
+                } else if self.is_detail_view() {
                     self.detail_scroll = self.detail_scroll.saturating_add(1);
                 } else {
                     self.navigate_down();
                 }
                 self.maybe_load_more()
             }
+            Action::NavigateTop if self.overlay == Overlay::Pager => {
+                self.pager.scroll = 0;
+                vec![]
+            }
+            Action::NavigateBottom if self.overlay == Overlay::Pager => {
+                self.pager.scroll_by(isize::MAX);
+                vec![]
+            }
             Action::NavigateTop => {
-                if self.is_detail_view() {
+                if self.is_history_tab() {
+                    self.input_mode = InputMode::Normal;
+                    self.history_cursor = 0;
+                } else if self.is_io_tab() {
+                    self.io_tree.cursor = 0;
+                } else if self.is_detail_view() {
                     self.detail_scroll = 0;
                 } else {
                     self.navigate_top();
@@ -293,7 +1035,11 @@ impl App {
                 vec![]
             }
             Action::NavigateBottom => {
-                if self.is_detail_view() {
+                if self.is_history_tab() {
+                    self.move_history_cursor(isize::MAX);
+                } else if self.is_io_tab() {
+                    self.move_io_cursor(isize::MAX);
+                } else if self.is_detail_view() {
                     self.detail_scroll = u16::MAX;
                 } else {
                     self.navigate_bottom();
@@ -301,7 +1047,13 @@ impl App {
                 self.maybe_load_more()
             }
             Action::PageUp => {
-                if self.is_detail_view() {
+                if self.overlay == Overlay::Pager {
+                    self.pager.scroll_by(-(self.page_height() as isize));
+                } else if self.is_history_tab() {
+                    self.move_history_cursor(-(self.page_height() as isize));
+                } else if self.is_io_tab() {
+                    self.move_io_cursor(-(self.page_height() as isize));
+                } else if self.is_detail_view() {
                     self.detail_scroll =
                         self.detail_scroll.saturating_sub(self.page_height() as u16);
                 } else {
@@ -312,7 +1064,13 @@ impl App {
                 vec![]
             }
             Action::PageDown => {
-                if self.is_detail_view() {
+                if self.overlay == Overlay::Pager {
+                    self.pager.scroll_by(self.page_height() as isize);
+                } else if self.is_history_tab() {
+                    self.move_history_cursor(self.page_height() as isize);
+                } else if self.is_io_tab() {
+                    self.move_io_cursor(self.page_height() as isize);
+                } else if self.is_detail_view() {
                     self.detail_scroll =
                         self.detail_scroll.saturating_add(self.page_height() as u16);
                 } else {
@@ -322,12 +1080,90 @@ impl App {
                 }
                 self.maybe_load_more()
             }
+            Action::Select if self.overlay == Overlay::SignalPicker => {
+                self.pick_recent_signal();
+                vec![]
+            }
+            Action::Select if self.overlay == Overlay::QuickActions => {
+                self.overlay = Overlay::None;
+                match self.quick_actions().get(self.quick_action_cursor) {
+                    Some(&action) => self.run_quick_action(action),
+                    None => vec![],
+                }
+            }
+            Action::Select if self.overlay == Overlay::QueryPicker => {
+                let picked = self
+                    .picker_saved_queries()
+                    .get(self.query_picker_cursor)
+                    .map(|q| (*q).clone());
+                self.overlay = Overlay::None;
+                match picked {
+                    Some(query) => self.apply_saved_query(&query),
+                    None => vec![],
+                }
+            }
+            Action::Select if self.overlay == Overlay::QueryBuilder => {
+                let attributes = self.query_builder_attributes();
+                match self.query_builder.select(&attributes) {
+                    Some(query) => {
+                        self.overlay = Overlay::None;
+                        self.apply(Action::SubmitSearch(query))
+                    }
+                    None => vec![],
+                }
+            }
+            Action::Select if self.overlay == Overlay::Overview => {
+                let query = self
+                    .overview
+                    .data()
+                    .and_then(|o| o.rows().into_iter().nth(self.overview_cursor))
+                    .and_then(|row| row.query);
+                let Some(query) = query else {
+                    return vec![];
+                };
+                self.overlay = Overlay::None;
+                self.active_tab = ViewType::Workflows;
+                self.view = View::Collection(KindId::WorkflowExecution);
+                self.apply(Action::SubmitSearch(query))
+            }
+            Action::Select if self.overlay == Overlay::TimeRange => {
+                self.overlay = Overlay::None;
+                self.set_time_range(time_range_options()[self.time_range_cursor])
+            }
+            Action::Select if self.overlay == Overlay::Bookmarks => {
+                self.overlay = Overlay::None;
+                let Some(bookmark) = self.bookmarks.get(self.bookmark_cursor) else {
+                    return vec![];
+                };
+                match parse_deep_link(&bookmark.uri) {
+                    Ok(location) => self.apply_location(location),
+                    Err(err) => {
+                        let msg = format!("bookmark {}: {}", bookmark.name, err);
+                        self.push_error(msg);
+                        vec![]
+                    }
+                }
+            }
+            Action::Select if self.overlay == Overlay::ContextPicker => {
+                self.overlay = Overlay::None;
+                match self.profiles.get(self.context_picker_cursor).cloned() {
+                    Some(name) => self.request_context(name),
+                    None => vec![],
+                }
+            }
             Action::Select => self.handle_select(),
-            Action::Back => self.handle_back(),
+            Action::Back => {
+                if !self.error_toasts.is_empty() {
+                    self.dismiss_error();
+                    return vec![];
+                }
+                self.handle_back()
+            }
 
             // View switching
             Action::SwitchView(view_type) => {
                 self.active_tab = view_type.clone();
+                self.custom_kind = None;
                 match view_type {
                     ViewType::Workflows => {
                         self.view = View::Collection(KindId::WorkflowExecution);
@@ -377,17 +1213,77 @@ impl App {
                 self.input_buffer.clear();
                 vec![]
             }
+            Action::OpenSearch if self.overlay == Overlay::Pager => {
+                self.input_buffer = self.pager.search.clone().unwrap_or_default();
+                self.input_mode = InputMode::Search;
+                vec![]
+            }
             Action::OpenSearch => {
+                if let Some(tree) = self.active_json_tree() {
+                    self.input_buffer = tree.search.clone().unwrap_or_default();
+                } else if self.is_detail_view() {
+                    return vec![];
+                } else {
+                    self.input_buffer = self.current_search_query().unwrap_or_default();
+                }
                 self.input_mode = InputMode::Search;
-                self.input_buffer = self.current_search_query().unwrap_or_default();
+                self.search_error = None;
+                if self.active_json_tree().is_some()
+                    || !matches!(self.search_attributes, LoadState::NotLoaded)
+                {
+                    return vec![];
+                }
+                // Attribute names are checked against this list on submit.
+                self.search_attributes = LoadState::Loading;
+                vec![Effect::LoadSearchAttributes]
+            }
+            Action::OpenFilter if self.overlay == Overlay::NamespaceSelector => {
+                self.input_buffer = self.namespace_filter.clone();
+                self.input_mode = InputMode::Filter;
+                vec![]
+            }
+            Action::OpenFilter => {
+                let kind = self.current_kind_id();
+                if matches!(kind, KindId::WorkflowExecution | KindId::Schedule)
+                    && !self.is_detail_view()
+                {
+                    self.input_buffer = self.local_filters.get(&kind).cloned().unwrap_or_default();
+                    self.input_mode = InputMode::Filter;
+                }
+                vec![]
+            }
+            Action::SubmitFilter => {
+                self.input_mode = InputMode::Normal;
+                self.input_buffer.clear();
+                vec![]
+            }
+            Action::CloseOverlay
+                if self.overlay == Overlay::NamespaceSelector
+                    && self.input_mode == InputMode::Filter =>
+            {
+                self.input_mode = InputMode::Normal;
+                self.input_buffer.clear();
+                self.set_namespace_filter(String::new());
+                vec![]
+            }
+            Action::CloseOverlay
+                if self.overlay == Overlay::Pager && self.input_mode == InputMode::Search =>
+            {
+                self.input_mode = InputMode::Normal;
+                self.input_buffer.clear();
                 vec![]
             }
             Action::CloseOverlay => {
                 if self.overlay != Overlay::None {
-                    self.overlay = Overlay::None;
+                    self.close_overlay();
+                } else if self.input_mode == InputMode::Filter {
+                    self.input_mode = InputMode::Normal;
+                    self.input_buffer.clear();
+                    self.set_local_filter(String::new());
                 } else if self.input_mode != InputMode::Normal {
                     self.input_mode = InputMode::Normal;
                     self.input_buffer.clear();
+                    self.search_error = None;
                 }
                 vec![]
             }
@@ -398,36 +1294,86 @@ impl App {
                 effects
             }
             Action::UpdateInputBuffer(buf) => {
+                if self.input_mode == InputMode::Filter {
+                    if self.overlay == Overlay::NamespaceSelector {
+                        self.set_namespace_filter(buf.clone());
+                    } else {
+                        self.set_local_filter(buf.clone());
+                    }
+                }
+                if self.input_mode == InputMode::Search {
+                    self.search_error = None;
+                }
                 self.input_buffer = buf;
                 vec![]
             }
+            Action::SubmitSearch(query) if self.overlay == Overlay::Pager => {
+                self.input_mode = InputMode::Normal;
+                self.input_buffer.clear();
+                if !self.pager.set_search(&query) {
+                    self.notice = Some((format!("Pattern not found: {}", query), Instant::now()));
+                }
+                vec![]
+            }
             Action::SubmitSearch(query) => {
                 self.input_mode = InputMode::Normal;
+                if let Some(tree) = self.active_json_tree() {
+                    tree.search = Some(query).filter(|q| !q.is_empty());
+                    self.input_buffer.clear();
+                    self.jump_to_match(true);
+                    return vec![];
+                }
                 let kind = self.current_kind_id();
+                let known = match (&self.search_attributes, kind) {
+                    // Activity visibility has attributes of its own that
+                    // the namespace list doesn't cover.
+                    (_, KindId::ActivityExecution) => None,
+                    (LoadState::Loaded(attributes), _) => {
+                        let mut names: Vec<String> =
+                            attributes.iter().map(|a| a.name.clone()).collect();
+                        if kind == KindId::Schedule {
+                            names.push("ScheduleId".to_string());
+                        }
+                        Some(names)
+                    }
+                    _ => None,
+                };
+                if let Err(e) = query_syntax::check(&query, known.as_deref()) {
+                    // Keep the modal open so the query can be fixed in place.
+                    self.input_mode = InputMode::Search;
+                    self.search_error = Some(e);
+                    return vec![];
+                }
+                self.search_error = None;
                 if query.is_empty() {
                     self.search_queries.remove(&kind);
                 } else {
                     self.search_queries.insert(kind, query);
                 }
                 self.input_buffer.clear();
-                match kind {
-                    KindId::WorkflowExecution => {
-                        vec![Effect::LoadWorkflows, Effect::LoadWorkflowCount]
-                    }
-                    KindId::Schedule => vec![Effect::LoadSchedules],
-                    KindId::ActivityExecution => vec![
-                        Effect::LoadActivityExecutions {
-                            namespace: self.namespace.clone(),
-                            query: self.search_query_for_kind(KindId::ActivityExecution),
-                            page_size: self.activity_page_size,
-                            next_page_token: vec![],
-                        },
-                        Effect::CountActivityExecutions {
-                            namespace: self.namespace.clone(),
-                            query: self.search_query_for_kind(KindId::ActivityExecution),
-                        },
-                    ],
+                self.search_effects(kind)
+            }
+            Action::CompleteSearch => match self.search_completion().accept(&self.input_buffer) {
+                Some(buf) => self.apply(Action::UpdateInputBuffer(buf)),
+                None => vec![],
+            },
+            Action::InvalidQuery(kind, msg) => {
+                // The list and its count both fail; only the first gets here
+                // with the query still set.
+                let Some(query) = self.search_queries.remove(&kind) else {
+                    return vec![];
+                };
+                self.log_error(format!("invalid query: {}", msg));
+                if self.current_kind_id() == kind
+                    && !self.is_detail_view()
+                    && self.overlay == Overlay::None
+                    && self.input_mode == InputMode::Normal
+                {
+                    self.input_buffer = query;
+                    self.input_mode = InputMode::Search;
+                    self.search_error = Some(msg);
                 }
+                self.search_effects(kind)
             }
             Action::ToggleHelp => {
                 self.overlay = if self.overlay == Overlay::Help {
@@ -437,8 +1383,36 @@ impl App {
                 };
                 vec![]
             }
+            Action::SwitchFavoriteNamespace(index) => {
+                let Some(name) = self.favorite_namespaces.get(index).cloned() else {
+                    self.push_error(format!(
+                        "no favorite namespace {}; set favorite_namespaces in the config",
+                        index + 1
+                    ));
+                    return vec![];
+                };
+                if name == self.namespace {
+                    return vec![];
+                }
+                let Some(memory) = self.namespace_memory.get(&name).cloned() else {
+                    return self.apply(Action::SwitchNamespace(name));
+                };
+                self.overlay = Overlay::None;
+                let effects = self.apply_location(memory.location);
+                self.search_queries = memory.queries;
+                self.time_range = memory.time_range;
+                self.time_range_anchor = memory.time_range_anchor;
+                effects
+            }
             Action::SwitchNamespace(ns) => {
                 self.namespace = ns;
+                self.marked_workflows.clear();
+                if self.overlay == Overlay::NamespaceSelector
+                    && self.input_mode == InputMode::Filter
+                {
+                    self.input_mode = InputMode::Normal;
+                    self.input_buffer.clear();
+                }
                 self.overlay = Overlay::None;
                 self.workflows = LoadState::NotLoaded;
                 self.schedules = LoadState::NotLoaded;
@@ -450,10 +1424,15 @@ impl App {
                 self.activity_execution_table_state = TableState::default();
                 self.selected_workflow = None;
                 self.selected_schedule = None;
+                self.schedule_next_page_token = vec![];
                 self.activity_next_page_token = vec![];
                 self.activity_count = None;
                 self.activities_supported = false;
                 self.search_queries.clear();
+                self.time_range = None;
+                self.pending_export = None;
+                self.search_attributes = LoadState::NotLoaded;
+                self.overview = LoadState::NotLoaded;
                 let mut effects = vec![Effect::CheckActivitySupport {
                     namespace: self.namespace.clone(),
                 }];
@@ -497,6 +1476,12 @@ impl App {
                     self.detail_scroll = 0;
                     return self.load_activity_tab_data();
                 }
+                if self.view == View::Detail(KindId::Schedule) {
+                    let tab_count = detail_tab_count(KindId::Schedule).max(1);
+                    self.schedule_detail_tab = (self.schedule_detail_tab + 1) % tab_count;
+                    self.detail_scroll = 0;
+                    return self.load_schedule_tab_data();
+                }
                 vec![]
             }
             Action::PrevTab => {
@@ -520,6 +1505,16 @@ impl App {
                     self.detail_scroll = 0;
                     return self.load_activity_tab_data();
                 }
+                if self.view == View::Detail(KindId::Schedule) {
+                    let tab_count = detail_tab_count(KindId::Schedule).max(1);
+                    self.schedule_detail_tab = if self.schedule_detail_tab == 0 {
+                        tab_count - 1
+                    } else {
+                        self.schedule_detail_tab - 1
+                    };
+                    self.detail_scroll = 0;
+                    return self.load_schedule_tab_data();
+                }
                 vec![]
             }
             Action::OpenScheduleWorkflows => {
@@ -535,10 +1530,38 @@ impl App {
                 }
                 vec![]
             }
+            Action::OpenParentWorkflow | Action::OpenRootWorkflow => {
+                let Some(detail) = self.selected_workflow.as_ref() else {
+                    return vec![];
+                };
+                let (target, label) = if matches!(action, Action::OpenParentWorkflow) {
+                    (detail.parent.clone(), "parent")
+                } else {
+                    (
+                        detail
+                            .root
+                            .clone()
+                            .filter(|root| root.workflow_id != detail.summary.workflow_id),
+                        "root",
+                    )
+                };
+                match target {
+                    Some(target) => {
+                        self.open_workflow_detail(target.workflow_id, Some(target.run_id), None)
+                    }
+                    None => {
+                        self.push_error(format!("workflow has no {} execution", label));
+                        vec![]
+                    }
+                }
+            }
             Action::OpenWorkflowActivities => {
                 if let Some(workflow) = self.selected_workflow_summary() {
                     let location = Location::new(
-                        self.namespace.clone(),
+                        workflow
+                            .namespace
+                            .clone()
+                            .unwrap_or_else(|| self.namespace.clone()),
                         vec![RouteSegment::Workflows(WorkflowsRoute::Activities {
                             workflow_id: workflow.workflow_id.clone(),
                             activity_id: None,
@@ -550,31 +1573,83 @@ impl App {
             }
 
             // Data responses
-            Action::WorkflowsLoaded(workflows, next_page_token) => {
-                self.workflows = LoadState::Loaded(workflows);
-                self.next_page_token = next_page_token;
+            Action::WorkflowsLoaded(mut workflows, next_page_token) => {
+                let selected = self.selected_workflow_row();
+                let refresh = self.workflows_generation == Some(self.generation);
+                match &self.workflows {
+                    // A poll of the same list: merge so rows from later pages
+                    // and the cursor survive.
+                    LoadState::Loaded(existing) if refresh => {
+                        let (merged, token) = merge_workflow_page(
+                            existing,
+                            workflows,
+                            next_page_token,
+                            &self.next_page_token,
+                            self.workflows_ordered_by_query(),
+                        );
+                        self.workflows = LoadState::Loaded(merged);
+                        self.next_page_token = token;
+                    }
+                    _ => {
+                        if !self.workflows_ordered_by_query() {
+                            order_ties(&mut workflows);
+                        }
+                        self.workflows = LoadState::Loaded(workflows);
+                        self.next_page_token = next_page_token;
+                    }
+                }
+                self.workflows_generation = Some(self.generation);
                 self.loading_more = false;
                 self.connection_status = ConnectionStatus::Connected;
                 self.reset_backoff();
                 self.last_refresh = Some(Instant::now());
-                if self.workflow_table_state.selected().is_none() {
+                if let Some(row) = selected.and_then(|key| self.visible_workflow_position(&key)) {
+                    self.workflow_table_state.select(Some(row));
+                } else if self.workflow_table_state.selected().is_none() {
                     self.workflow_table_state.select_first();
                 }
                 vec![]
             }
+            Action::FanoutLoaded(mut workflows, failed) => {
+                let names: Vec<String> = failed.iter().map(|(ns, _)| ns.clone()).collect();
+                // Polls repeat the list; only report namespaces newly failing.
+                for (namespace, error) in &failed {
+                    if !self.fanout_failed.contains(namespace) {
+                        self.push_error(format!("{}: {}", namespace, error));
+                    }
+                }
+                self.fanout_failed = names;
+                // Each namespace's page is in the server's order; interleave
+                // them the same way.
+                if !self.workflows_ordered_by_query() {
+                    workflows.sort_by(server_order);
+                }
+                self.apply(Action::WorkflowsLoaded(workflows, vec![]))
+            }
             Action::MoreWorkflowsLoaded(workflows, next_page_token) => {
+                let ordered = self.workflows_ordered_by_query();
                 if let LoadState::Loaded(ref mut existing) = self.workflows {
                     existing.extend(workflows);
+                    if !ordered {
+                        order_ties(existing);
+                    }
                 }
                 self.next_page_token = next_page_token;
                 self.loading_more = false;
                 self.connection_status = ConnectionStatus::Connected;
                 self.reset_backoff();
-                vec![]
+                self.continue_export()
             }
             Action::WorkflowDetailLoaded(mut detail) => {
+                if self.stale_preview(KindId::WorkflowExecution, &detail.summary.workflow_id) {
+                    return vec![];
+                }
                 // Preserve input/output/failure extracted from history
-                if let Some(ref existing) = self.selected_workflow {
+                if let Some(existing) = self
+                    .selected_workflow
+                    .as_ref()
+                    .filter(|e| e.summary.run_id == detail.summary.run_id)
+                {
                     if detail.input.is_none() {
                         detail.input = existing.input.clone();
                     }
@@ -584,11 +1659,24 @@ impl App {
                     if detail.failure.is_none() {
                         detail.failure = existing.failure.clone();
                     }
+                    if detail.retry_policy.is_none() {
+                        detail.retry_policy = existing.retry_policy.clone();
+                    }
+                    if detail.cron_schedule.is_none() {
+                        detail.cron_schedule = existing.cron_schedule.clone();
+                    }
                     if detail.history_length == 0 && existing.history_length > 0 {
                         detail.history_length = existing.history_length;
                     }
                 }
+                self.child_cursor = self
+                    .child_cursor
+                    .min(detail.pending_children.len().saturating_sub(1));
+                self.pending_cursor = self
+                    .pending_cursor
+                    .min(detail.pending_activities.len().saturating_sub(1));
                 self.selected_workflow = Some(*detail);
+                self.last_refresh = Some(Instant::now());
                 vec![]
             }
             Action::HistoryLoaded(events) => {
@@ -598,46 +1686,91 @@ impl App {
                         if event.event_type.contains("WorkflowExecutionStarted")
                             && !event.event_type.contains("Child")
                         {
-                            if let Some(input) = event.details.get("input") {
-                                detail.input = Some(input.clone());
+                            if let Some(input) = event.payload("input") {
+                                detail.input = Some(input);
                             }
+                            detail.retry_policy = event
+                                .details
+                                .get("retry_policy")
+                                .map(WorkflowRetryPolicy::from_json);
+                            detail.cron_schedule = event
+                                .details
+                                .get("cron_schedule")
+                                .and_then(|c| c.as_str())
+                                .filter(|c| !c.is_empty())
+                                .map(str::to_string);
                         }
                         if event.event_type.contains("WorkflowExecutionCompleted")
                             && !event.event_type.contains("Child")
                         {
-                            if let Some(result) = event.details.get("result") {
-                                detail.output = Some(result.clone());
+                            if let Some(result) = event.payload("result") {
+                                detail.output = Some(result);
                             }
                         }
                         if event.event_type.contains("WorkflowExecutionFailed")
                             && !event.event_type.contains("Child")
                         {
                             if let Some(failure) = event.details.get("failure") {
-                                detail.failure = Some(FailureInfo {
-                                    message: failure
-                                        .get("message")
-                                        .and_then(|v| v.as_str())
-                                        .unwrap_or("")
-                                        .to_string(),
-                                    failure_type: failure
-                                        .get("source")
-                                        .and_then(|v| v.as_str())
-                                        .unwrap_or("")
-                                        .to_string(),
-                                    stack_trace: failure
-                                        .get("stack_trace")
-                                        .and_then(|v| v.as_str())
-                                        .map(|s| s.to_string()),
-                                    cause: None,
-                                });
+                                detail.failure = Some(FailureInfo::from_json(failure));
                             }
                         }
                     }
                     detail.history_length = events.len() as u64;
                 }
+                self.history_cursor = self.history_cursor.min(events.len().saturating_sub(1));
                 self.workflow_history = LoadState::Loaded(events);
+                self.last_refresh = Some(Instant::now());
+                vec![]
+            }
+            Action::ClusterInfoLoaded(info) => {
+                if info.is_outdated() {
+                    self.push_error(format!(
+                        "server {} is older than the minimum supported {}.{}.{}",
+                        info.server_version,
+                        MIN_SERVER_VERSION.0,
+                        MIN_SERVER_VERSION.1,
+                        MIN_SERVER_VERSION.2
+                    ));
+                }
+                self.cluster_info = Some(*info);
+                vec![]
+            }
+            Action::StackTraceLoaded(trace) => {
+                self.stack_trace = LoadState::Loaded(trace);
+                vec![]
+            }
+            Action::StackTraceFailed(msg) => {
+                self.stack_trace = LoadState::Error(msg);
+                vec![]
+            }
+            Action::RawDetailLoaded(value) => {
+                self.raw_detail = LoadState::Loaded(*value);
+                vec![]
+            }
+            Action::RawDetailFailed(msg) => {
+                self.raw_detail = LoadState::Error(msg);
+                vec![]
+            }
+            Action::WorkflowMetadataLoaded(metadata) => {
+                self.workflow_metadata = LoadState::Loaded(*metadata);
                 vec![]
             }
+            Action::WorkflowMetadataFailed(msg) => {
+                self.workflow_metadata = LoadState::Error(msg);
+                vec![]
+            }
+            Action::WorkflowRestarted(workflow_id, run_id) => {
+                let viewing_restarted = self.view == View::Detail(KindId::WorkflowExecution)
+                    && self
+                        .selected_workflow
+                        .as_ref()
+                        .is_some_and(|wf| wf.summary.workflow_id == workflow_id);
+                if viewing_restarted {
+                    self.open_workflow_detail(workflow_id, Some(run_id), None)
+                } else {
+                    self.refresh_current_view()
+                }
+            }
             Action::NamespacesLoaded(namespaces) => {
                 self.namespaces = namespaces;
                 if self.namespace_selector_state.selected().is_none() {
@@ -645,20 +1778,344 @@ impl App {
                 }
                 vec![]
             }
-            Action::SchedulesLoaded(schedules) => {
-                self.schedules = LoadState::Loaded(schedules);
+            Action::SchedulesLoaded(schedules, next_page_token) => {
+                let refresh = self.schedules_generation == Some(self.generation);
+                match &self.schedules {
+                    LoadState::Loaded(existing) if refresh => {
+                        let (merged, token) = merge_schedule_page(
+                            existing,
+                            schedules,
+                            next_page_token,
+                            &self.schedule_next_page_token,
+                        );
+                        self.schedules = LoadState::Loaded(merged);
+                        self.schedule_next_page_token = token;
+                    }
+                    _ => {
+                        self.schedules = LoadState::Loaded(schedules);
+                        self.schedule_next_page_token = next_page_token;
+                    }
+                }
+                self.schedules_generation = Some(self.generation);
+                self.loading_more = false;
                 self.last_refresh = Some(Instant::now());
                 if self.schedule_table_state.selected().is_none() {
                     self.schedule_table_state.select_first();
                 }
                 vec![]
             }
+            Action::MoreSchedulesLoaded(schedules, next_page_token) => {
+                if let LoadState::Loaded(ref mut existing) = self.schedules {
+                    existing.extend(schedules);
+                }
+                self.schedule_next_page_token = next_page_token;
+                self.loading_more = false;
+                self.continue_export()
+            }
+            Action::ScheduleDraftLoaded(draft) => {
+                let text = serde_json::to_string_pretty(&draft.edit).unwrap_or_default();
+                vec![Effect::EditSchedule(draft, text)]
+            }
+            Action::ScheduleEdited(draft, opened, result) => {
+                let parsed = result.map(|text| {
+                    let edit = ScheduleEdit::parse(editor::strip_error(&text));
+                    (text, edit)
+                });
+                let edit = match parsed {
+                    Ok((_, Ok(edit))) => edit,
+                    // A rejected document comes back with the error on top,
+                    // until it is saved as it was reopened.
+                    Ok((text, Err(e))) if text != opened => {
+                        return vec![Effect::EditSchedule(draft, editor::with_error(&text, &e))];
+                    }
+                    Ok((_, Err(e))) | Err(e) => {
+                        self.push_error(format!(
+                            "schedule {} not updated: {}",
+                            draft.schedule_id, e
+                        ));
+                        return vec![];
+                    }
+                };
+                if edit == draft.edit {
+                    return vec![];
+                }
+                vec![Effect::UpdateSchedule {
+                    schedule_id: draft.schedule_id,
+                    edit: Box::new(edit),
+                    conflict_token: draft.conflict_token,
+                }]
+            }
+            Action::EditActivityOptions => {
+                if !self.capabilities.execution_admin {
+                    self.push_error("updating activity options is not supported by this backend");
+                    return vec![];
+                }
+                let Some(detail) = self.selected_workflow.as_ref() else {
+                    return vec![];
+                };
+                let Some(activity) = self.selected_pending_activity() else {
+                    return vec![];
+                };
+                let Some(edit) = activity.options.clone() else {
+                    self.push_error(format!(
+                        "the server did not report options for activity {}",
+                        activity.activity_id
+                    ));
+                    return vec![];
+                };
+                let text = serde_json::to_string_pretty(&edit).unwrap_or_default();
+                let draft = ActivityOptionsDraft {
+                    workflow_id: detail.summary.workflow_id.clone(),
+                    run_id: detail.summary.run_id.clone(),
+                    activity_id: activity.activity_id.clone(),
+                    edit,
+                };
+                vec![Effect::EditActivityOptions(Box::new(draft), text)]
+            }
+            Action::ActivityOptionsEdited(draft, opened, result) => {
+                let parsed = result.map(|text| {
+                    let edit = ActivityOptionsEdit::parse(editor::strip_error(&text));
+                    (text, edit)
+                });
+                let edit = match parsed {
+                    Ok((_, Ok(edit))) => edit,
+                    Ok((text, Err(e))) if text != opened => {
+                        let text = editor::with_error(&text, &e);
+                        return vec![Effect::EditActivityOptions(draft, text)];
+                    }
+                    Ok((_, Err(e))) | Err(e) => {
+                        self.push_error(format!(
+                            "activity {} not updated: {}",
+                            draft.activity_id, e
+                        ));
+                        return vec![];
+                    }
+                };
+                if edit == draft.edit {
+                    return vec![];
+                }
+                self.open_confirm(ConfirmAction::ActivityOptions(draft, Box::new(edit)), None);
+                vec![]
+            }
             Action::ScheduleDetailLoaded(schedule) => {
+                if self.stale_preview(KindId::Schedule, &schedule.schedule_id) {
+                    return vec![];
+                }
+                self.schedule_action_cursor = self
+                    .schedule_action_cursor
+                    .min(schedule.recent_actions.len().saturating_sub(1));
                 self.selected_schedule = Some(*schedule);
+                self.last_refresh = Some(Instant::now());
                 vec![]
             }
             Action::WorkflowCountLoaded(count) => {
                 self.workflow_count = Some(count);
+                self.workflow_status_counts.clear();
+                vec![]
+            }
+            Action::WorkflowStatusCountsLoaded(counts) => {
+                self.workflow_count = Some(counts.iter().map(|(_, n)| n).sum());
+                self.workflow_status_counts = counts;
+                vec![]
+            }
+            Action::WorkflowCountUnavailable => {
+                self.workflow_count = None;
+                self.workflow_status_counts.clear();
+                vec![]
+            }
+            Action::SearchAttributesLoaded(result) => {
+                self.search_attributes = match result {
+                    Ok(attributes) => {
+                        self.search_attribute_cursor = self
+                            .search_attribute_cursor
+                            .min(attributes.len().saturating_sub(1));
+                        LoadState::Loaded(attributes)
+                    }
+                    Err(msg) => LoadState::Error(msg),
+                };
+                vec![]
+            }
+            Action::OpenLocation(location) => self.apply_location(location),
+            Action::PipePayload => match self.pipes.as_slice() {
+                [] => {
+                    self.push_error("no pipes configured; add [[pipes]] to the config file");
+                    vec![]
+                }
+                [pipe] => {
+                    let name = pipe.name.clone();
+                    self.start_pipe(&name)
+                }
+                _ => self.apply(Action::PromptCommand("pipe ".to_string())),
+            },
+            Action::OpenPager => {
+                match self.pager_text() {
+                    Some((title, text)) => {
+                        self.pager = PagerState::new(title, &text);
+                        self.overlay = Overlay::Pager;
+                    }
+                    None => self.push_error("nothing to page on this tab"),
+                }
+                vec![]
+            }
+            Action::ToggleMark => {
+                if self.reject_foreign_row() {
+                    return vec![];
+                }
+                let Some(row) = self.selected_workflow_row() else {
+                    return vec![];
+                };
+                match self.marked_workflows.iter().position(|m| *m == row) {
+                    Some(i) => {
+                        self.marked_workflows.remove(i);
+                    }
+                    None => self.marked_workflows.push(row),
+                }
+                self.apply(Action::NavigateDown)
+            }
+            Action::BatchCounted(namespace, operation, query, count) => {
+                // Only ask about the list the count was for, and not over
+                // something else the user has opened since.
+                let current = namespace == self.namespace
+                    && self.view == View::Collection(KindId::WorkflowExecution)
+                    && self
+                        .search_query_for_kind(KindId::WorkflowExecution)
+                        .as_ref()
+                        == Some(&query)
+                    && self.overlay == Overlay::None;
+                if !current {
+                    return vec![];
+                }
+                if count == 0 {
+                    self.push_error(format!("no workflows match {}", query));
+                } else {
+                    self.confirm_batch(BatchConfirm {
+                        namespace,
+                        operation,
+                        target: BatchTarget::Query(query),
+                        count,
+                    });
+                }
+                vec![]
+            }
+            Action::BatchStarted(operation, job_id) => {
+                self.notice = Some((
+                    format!("started batch {} job {}", operation.verb(), job_id),
+                    Instant::now(),
+                ));
+                self.apply(Action::Refresh)
+            }
+            Action::PipeFinished(title, result) => {
+                match result {
+                    Ok(output) => {
+                        self.pager = PagerState::new(title, &output);
+                        self.overlay = Overlay::Pager;
+                    }
+                    Err(e) => self.push_error(e),
+                }
+                vec![]
+            }
+            Action::CopyPayload(side) => {
+                if !self.is_io_tab() {
+                    self.push_error("copy the input or output from the Input/Output tab");
+                    return vec![];
+                }
+                match self.payload_json(side) {
+                    Some(json) => {
+                        self.notice =
+                            Some((tf(Msg::NoticeCopied, &[side.label()]), Instant::now()));
+                        vec![Effect::CopyToClipboard(json)]
+                    }
+                    None => vec![],
+                }
+            }
+            Action::CopyLink => {
+                let link = format_deep_link(&self.location());
+                self.notice = Some((tf(Msg::NoticeCopied, &[&link]), Instant::now()));
+                vec![Effect::CopyToClipboard(link)]
+            }
+            Action::OpenBookmarks => {
+                if self.bookmarks.is_empty() {
+                    self.push_error("no bookmarks yet; save this view with :bookmark <name>");
+                } else {
+                    self.bookmark_cursor = self.bookmark_cursor.min(self.bookmarks.len() - 1);
+                    self.overlay = Overlay::Bookmarks;
+                }
+                vec![]
+            }
+            Action::DeleteBookmark => {
+                let mut effects = vec![];
+                if self.bookmark_cursor < self.bookmarks.len() {
+                    self.bookmarks.remove(self.bookmark_cursor);
+                    effects.push(Effect::SaveBookmarks(self.bookmarks.clone()));
+                }
+                if self.bookmarks.is_empty() {
+                    self.overlay = Overlay::None;
+                }
+                self.bookmark_cursor = self
+                    .bookmark_cursor
+                    .min(self.bookmarks.len().saturating_sub(1));
+                effects
+            }
+            Action::OpenTimeRangePicker => {
+                self.overlay = Overlay::TimeRange;
+                self.time_range_cursor = time_range_options()
+                    .iter()
+                    .position(|r| *r == self.time_range)
+                    .unwrap_or(0);
+                vec![]
+            }
+            Action::OpenOverview => {
+                self.overlay = Overlay::Overview;
+                self.overview = LoadState::Loading;
+                self.overview_cursor = 0;
+                vec![Effect::LoadOverview]
+            }
+            Action::OverviewLoaded(overview) => {
+                self.overview = LoadState::Loaded(overview);
+                vec![]
+            }
+            Action::OpenQueryBuilder => {
+                if self.is_detail_view() {
+                    return vec![];
+                }
+                self.query_builder = QueryBuilder::default();
+                self.overlay = Overlay::QueryBuilder;
+                if matches!(self.search_attributes, LoadState::Loaded(_)) {
+                    return vec![];
+                }
+                self.search_attributes = LoadState::Loading;
+                vec![Effect::LoadSearchAttributes]
+            }
+            Action::QueryBuilderInput(c) => {
+                let builder = &mut self.query_builder;
+                match (builder.step, c) {
+                    (BuilderStep::Value, c) => builder.value.push(c),
+                    (_, 'j') => return self.apply(Action::NavigateDown),
+                    (_, 'k') => return self.apply(Action::NavigateUp),
+                    (_, 'q') => self.overlay = Overlay::None,
+                    _ => {}
+                }
+                vec![]
+            }
+            Action::QueryBuilderBack => {
+                let builder = &mut self.query_builder;
+                if builder.step == BuilderStep::Value && !builder.value.is_empty() {
+                    builder.value.pop();
+                } else {
+                    builder.back();
+                }
+                vec![]
+            }
+            Action::PromptSearchAttribute => {
+                self.overlay = Overlay::None;
+                self.input_mode = InputMode::Command;
+                self.input_buffer = "attributes add ".to_string();
+                vec![]
+            }
+            Action::PromptCommand(command) => {
+                self.overlay = Overlay::None;
+                self.input_mode = InputMode::Command;
+                self.input_buffer = command;
                 vec![]
             }
             Action::TaskQueueDetailLoaded(tq) => {
@@ -689,10 +2146,14 @@ impl App {
                 self.loading_more = false;
                 self.connection_status = ConnectionStatus::Connected;
                 self.reset_backoff();
-                vec![]
+                self.continue_export()
             }
             Action::ActivityExecutionDetailLoaded(detail) => {
+                if self.stale_preview(KindId::ActivityExecution, &detail.summary.activity_id) {
+                    return vec![];
+                }
                 self.activity_execution_detail = LoadState::Loaded(*detail);
+                self.last_refresh = Some(Instant::now());
                 self.load_activity_tab_data()
             }
             Action::ActivityExecutionCountLoaded(count) => {
@@ -710,34 +2171,261 @@ impl App {
             }
 
             // App control
-            Action::Refresh => self.refresh_current_view(),
+            Action::Refresh => {
+                let mut effects = self.refresh_current_view();
+                // Stack traces cost a worker round trip, so only reload on request.
+                if self.view == View::Detail(KindId::WorkflowExecution)
+                    && matches!(self.workflow_detail_tab, 5 | 7 | 8)
+                {
+                    effects.extend(self.load_workflow_tab_data());
+                }
+                if self.view == View::Detail(KindId::Schedule) {
+                    effects.extend(self.load_schedule_tab_data());
+                }
+                effects
+            }
             Action::Quit => {
                 self.should_quit = true;
                 vec![Effect::Quit]
             }
             Action::Tick => {
-                if self.polling_enabled {
-                    let should_poll = self
-                        .last_refresh
-                        .map(|t| t.elapsed() >= self.polling_interval)
-                        .unwrap_or(true);
-                    if should_poll {
-                        return self.refresh_current_view();
-                    }
+                // Nothing would get through until a key restarts reconnecting.
+                if self.connection_status == ConnectionStatus::Disconnected {
+                    return vec![];
                 }
+                let mut effects = vec![];
+                let health_due = self
+                    .last_health_check
+                    .is_none_or(|t| t.elapsed() >= HEALTH_CHECK_INTERVAL);
+                if self.polling_enabled && health_due {
+                    self.last_health_check = Some(Instant::now());
+                    effects.push(Effect::HealthCheck);
+                }
+                if self.polling_active() && self.next_poll_in().is_none_or(|d| d.is_zero()) {
+                    self.last_poll = Some(Instant::now());
+                    effects.extend(self.refresh_current_view());
+                }
+                effects
+            }
+            Action::HealthChecked(Ok((latency, info))) => {
+                self.health = Health {
+                    latency: Some(latency),
+                    failures: 0,
+                };
+                if matches!(
+                    self.connection_status,
+                    ConnectionStatus::Connecting | ConnectionStatus::Error(_)
+                ) {
+                    self.connection_status = ConnectionStatus::Connected;
+                }
+                self.cluster_info = Some(*info);
+                vec![]
+            }
+            Action::HealthChecked(Err(_)) => {
+                // The tab bar shows failed checks; list loads report the details.
+                self.health.failures += 1;
+                vec![]
+            }
+            Action::Audited(entry) => {
+                self.audit_log.push(*entry);
                 vec![]
             }
             Action::Error(msg) => {
-                self.last_error = Some((msg.clone(), Instant::now()));
+                self.push_error(msg.clone());
                 self.error_count += 1;
-                self.apply_backoff();
                 if self.connection_status == ConnectionStatus::Connected {
                     self.connection_status = ConnectionStatus::Error(msg);
                 }
                 vec![]
             }
+            Action::ConnectionLost(msg) => {
+                // One toast per outage; the tab bar shows the reconnect.
+                if self.connection_status != ConnectionStatus::Connecting {
+                    self.push_error(format!("connection lost, reconnecting: {}", msg));
+                }
+                self.connection_status = ConnectionStatus::Connecting;
+                self.error_count += 1;
+                vec![]
+            }
+            Action::Disconnected(msg) => {
+                if self.connection_status != ConnectionStatus::Disconnected {
+                    self.push_error(format!(
+                        "connection lost, gave up reconnecting: {}; press any key to retry",
+                        msg
+                    ));
+                }
+                self.connection_status = ConnectionStatus::Disconnected;
+                self.error_count += 1;
+                vec![]
+            }
+            Action::Reconnect => {
+                self.connection_status = ConnectionStatus::Connecting;
+                vec![Effect::Reconnect]
+            }
             Action::ClearError => {
-                self.last_error = None;
+                self.error_toasts.clear();
+                self.toast_focused = false;
+                vec![]
+            }
+            Action::FocusError => {
+                self.toast_focused = !self.toast_focused && !self.error_toasts.is_empty();
+                vec![]
+            }
+            Action::OpenQuickActions => {
+                if self.reject_foreign_row() {
+                    return vec![];
+                }
+                if self.selected_row_id().is_none() {
+                    self.push_error("no row selected");
+                } else {
+                    self.quick_action_cursor = 0;
+                    self.overlay = Overlay::QuickActions;
+                }
+                vec![]
+            }
+            Action::OpenSignalPicker => {
+                if self.reject_foreign_row() {
+                    return vec![];
+                }
+                if !self.capabilities.execution_admin {
+                    self.push_error("signals are not supported by this backend");
+                } else if self.selected_workflow_summary().is_none() {
+                    self.push_error("no workflow selected");
+                } else if self.namespace_recent_signals().is_empty() {
+                    self.push_error(format!(
+                        "no signals sent in {} yet; use :signal <name> [json-input]",
+                        self.namespace
+                    ));
+                } else {
+                    self.signal_picker_cursor = 0;
+                    self.overlay = Overlay::SignalPicker;
+                }
+                vec![]
+            }
+            Action::PreviewDue(PreviewRow::Workflow(workflow_id, run_id)) => {
+                let row = (workflow_id, run_id);
+                if self.workflow_preview_key().as_ref() != Some(&row) {
+                    return vec![];
+                }
+                vec![Effect::LoadWorkflowPreview(row.0, row.1)]
+            }
+            Action::PreviewDue(PreviewRow::Split(kind, id)) => {
+                let row = Some((kind, id));
+                if !self.split_visible() || self.preview_key() != row {
+                    return vec![];
+                }
+                self.preview_effects()
+            }
+            Action::WorkflowPreviewLoaded(workflow_id, run_id, result) => {
+                // Results for rows the cursor has since left are dropped.
+                if self.workflow_preview_key() == Some((workflow_id, run_id)) {
+                    self.workflow_preview = match result {
+                        Ok(detail) => LoadState::Loaded(*detail),
+                        Err(e) => LoadState::Error(e),
+                    };
+                }
+                vec![]
+            }
+            Action::ToggleSplitView => {
+                self.split_view = !self.split_view;
+                vec![]
+            }
+            Action::Resize(width) => {
+                self.screen_width = width;
+                vec![]
+            }
+            Action::ConfigReloaded(config) => {
+                let custom_kind = self.active_custom_kind().cloned();
+                self.apply_config(&config);
+                self.notice = Some(("config reloaded".to_string(), Instant::now()));
+                // A `[[kinds]]` list whose query changed, or that is gone,
+                // shows rows from the old query until it is reloaded.
+                if matches!(self.view, View::Collection(_))
+                    && self.active_custom_kind() != custom_kind.as_ref()
+                {
+                    return self.refresh_current_view();
+                }
+                vec![]
+            }
+            Action::ToggleWideMode => {
+                self.wide_mode = !self.wide_mode;
+                vec![]
+            }
+            Action::ToggleRelativeTime => {
+                self.time_display.relative = !self.time_display.relative;
+                vec![]
+            }
+            Action::OpenContextPicker => {
+                if self.profiles.is_empty() {
+                    self.push_error(
+                        "no profiles configured; add [[profiles]] to the config file".to_string(),
+                    );
+                } else {
+                    self.context_picker_cursor = self
+                        .active_profile
+                        .as_ref()
+                        .and_then(|active| self.profiles.iter().position(|p| p == active))
+                        .unwrap_or(0);
+                    self.overlay = Overlay::ContextPicker;
+                }
+                vec![]
+            }
+            Action::ForContext(context, generation, action) => {
+                let current = generation.is_none_or(|g| g == self.generation);
+                if context == self.context_id() && current {
+                    self.apply(*action)
+                } else {
+                    vec![]
+                }
+            }
+            Action::ContextConnected(name, result) => {
+                if self.switching_context.as_ref() != Some(&name) {
+                    return vec![];
+                }
+                self.switching_context = None;
+                self.notice = None;
+                match result {
+                    Ok((next, capabilities)) => {
+                        self.capabilities = capabilities;
+                        self.active_profile = Some(name);
+                        self.connection_status = ConnectionStatus::Connected;
+                        self.health = Default::default();
+                        self.last_health_check = None;
+                        self.apply(Action::SwitchNamespace(next.namespace))
+                    }
+                    Err(e) => {
+                        self.push_error(format!("ctx {}: {}", name, e));
+                        vec![]
+                    }
+                }
+            }
+            Action::OpenQueryPicker => {
+                if self.picker_saved_queries().is_empty() {
+                    self.push_error(format!(
+                        "no saved {} queries for {}; add [[queries]] to the config file",
+                        self.current_kind_id().label().to_lowercase(),
+                        self.namespace
+                    ));
+                } else {
+                    self.query_picker_cursor = 0;
+                    self.overlay = Overlay::QueryPicker;
+                }
+                vec![]
+            }
+            Action::ToggleFold => {
+                self.toggle_fold();
+                vec![]
+            }
+            Action::NextMatch => {
+                self.jump_to_match(true);
+                vec![]
+            }
+            Action::PrevMatch => {
+                self.jump_to_match(false);
+                vec![]
+            }
+            Action::ToggleScheduleGrouping => {
+                self.set_schedule_grouping(self.schedule_grouping.next());
                 vec![]
             }
             Action::TogglePolling => {
@@ -750,14 +2438,43 @@ impl App {
     fn handle_select(&mut self) -> Vec<Effect> {
         match self.view {
             View::Collection(KindId::WorkflowExecution) => {
+                let visible = self.visible_workflow_indices();
                 if let Some(workflows) = self.workflows.data() {
                     if let Some(idx) = self.workflow_table_state.selected() {
-                        if let Some(wf) = workflows.get(idx) {
+                        if let Some(wf) = visible.get(idx).and_then(|i| workflows.get(*i)) {
+                            if let Some(namespace) = wf.namespace.clone() {
+                                let (workflow_id, run_id) =
+                                    (wf.workflow_id.clone(), wf.run_id.clone());
+                                return self.apply_location(Location::new(
+                                    namespace,
+                                    vec![RouteSegment::Workflows(WorkflowsRoute::Detail {
+                                        workflow_id,
+                                        run_id: Some(run_id),
+                                        tab: None,
+                                    })],
+                                ));
+                            }
                             self.view = View::Detail(KindId::WorkflowExecution);
                             self.workflow_detail_tab = 0;
+                            self.history_cursor = 0;
+                            self.child_cursor = 0;
+                            self.pending_cursor = 0;
+                            self.io_tree = JsonTreeState::default();
+                            self.history_tree = JsonTreeState::default();
+                            self.workflow_parents.clear();
                             self.workflow_history = LoadState::Loading;
                             self.task_queue_detail = LoadState::NotLoaded;
+                            self.stack_trace = LoadState::NotLoaded;
+                            self.workflow_metadata = LoadState::NotLoaded;
                             self.detail_scroll = 0;
+                            if self.archived_workflows {
+                                self.selected_workflow =
+                                    Some(WorkflowDetail::from_summary(wf.clone()));
+                                return vec![Effect::LoadHistory(
+                                    wf.workflow_id.clone(),
+                                    Some(wf.run_id.clone()),
+                                )];
+                            }
                             return vec![
                                 Effect::LoadWorkflowDetail(
                                     wf.workflow_id.clone(),
@@ -774,17 +2491,54 @@ impl App {
                 vec![]
             }
             View::Collection(KindId::Schedule) => {
-                if let Some(schedules) = self.schedules.data() {
-                    if let Some(idx) = self.schedule_table_state.selected() {
-                        if let Some(sch) = schedules.get(idx) {
-                            self.view = View::Detail(KindId::Schedule);
-                            self.detail_scroll = 0;
-                            return vec![Effect::LoadScheduleDetail(sch.schedule_id.clone())];
+                let Some(idx) = self.schedule_table_state.selected() else {
+                    return vec![];
+                };
+                match self.schedule_list_rows().get(idx) {
+                    Some(ScheduleListRow::Group { key, collapsed, .. }) => {
+                        if *collapsed {
+                            self.collapsed_schedule_groups.remove(key);
+                        } else {
+                            self.collapsed_schedule_groups.insert(key.clone());
                         }
+                        vec![]
+                    }
+                    Some(ScheduleListRow::Schedule(schedule_idx)) => {
+                        let Some(sch) = self
+                            .schedules
+                            .data()
+                            .and_then(|schedules| schedules.get(*schedule_idx))
+                        else {
+                            return vec![];
+                        };
+                        let schedule_id = sch.schedule_id.clone();
+                        self.view = View::Detail(KindId::Schedule);
+                        self.detail_scroll = 0;
+                        self.schedule_action_cursor = 0;
+                        self.schedule_detail_tab = 0;
+                        vec![Effect::LoadScheduleDetail(schedule_id)]
                     }
+                    None => vec![],
                 }
+            }
+            View::Detail(KindId::WorkflowExecution) if self.is_history_tab() => {
+                self.open_history_child()
+            }
+            View::Detail(KindId::WorkflowExecution) if self.is_children_tab() => {
+                self.open_pending_child()
+            }
+            View::Detail(KindId::WorkflowExecution) if self.is_pending_tab() => {
+                self.logs_scroll = 0;
+                self.overlay = Overlay::PendingActivity;
+                vec![]
+            }
+            View::Detail(KindId::WorkflowExecution) if self.is_io_tab() => {
+                self.toggle_fold();
                 vec![]
             }
+            View::Detail(KindId::Schedule) if self.schedule_detail_tab == 0 => {
+                self.open_schedule_action()
+            }
             View::Collection(KindId::ActivityExecution) => {
                 if let Some(activities) = self.activity_executions.data() {
                     if let Some(idx) = self.activity_execution_table_state.selected() {
@@ -811,9 +2565,23 @@ impl App {
     fn handle_back(&mut self) -> Vec<Effect> {
         match self.view {
             View::Detail(KindId::WorkflowExecution) => {
+                if let Some(parent) = self.workflow_parents.pop() {
+                    let parents = std::mem::take(&mut self.workflow_parents);
+                    let effects = self.open_workflow_detail(
+                        parent.workflow_id,
+                        Some(parent.run_id),
+                        Some("history"),
+                    );
+                    self.workflow_parents = parents;
+                    return effects;
+                }
                 self.view = View::Collection(KindId::WorkflowExecution);
                 self.selected_workflow = None;
                 self.workflow_history = LoadState::NotLoaded;
+                // Opened straight from a link or in another namespace.
+                if matches!(self.workflows, LoadState::NotLoaded) {
+                    return vec![Effect::LoadWorkflows, Effect::LoadWorkflowCount];
+                }
                 vec![]
             }
             View::Detail(KindId::Schedule) => {
@@ -827,34 +2595,52 @@ impl App {
                 self.activity_execution_task_queue = LoadState::NotLoaded;
                 vec![]
             }
+            View::Collection(KindId::WorkflowExecution) => {
+                self.marked_workflows.clear();
+                vec![]
+            }
             _ => vec![],
         }
     }
 
     fn execute_command(&mut self, cmd: &str) -> Vec<Effect> {
+        if let Some(shell) = cmd.trim().strip_prefix('!') {
+            match shell.trim() {
+                "" => self.push_error("usage: :!<shell command>"),
+                shell => return vec![Effect::RunShell(shell.to_string(), self.shell_env())],
+            }
+            return vec![];
+        }
         let parts: Vec<&str> = cmd.trim().splitn(2, ' ').collect();
         let command = parts[0].to_lowercase();
         let args = parts.get(1).map(|s| s.trim());
 
+        if let Some(idx) = self.kinds.custom_by_command(&command) {
+            let base = self.kinds.custom()[idx].base;
+            let effects = self.execute_command(&base.label().to_lowercase());
+            self.custom_kind = Some(idx);
+            return effects;
+        }
+
         match command.as_str() {
             "workflows" | "wf" => {
+                self.custom_kind = None;
                 self.active_tab = ViewType::Workflows;
                 self.view = View::Collection(KindId::WorkflowExecution);
                 vec![Effect::LoadWorkflows]
             }
             "schedules" | "sch" => {
+                self.custom_kind = None;
                 self.active_tab = ViewType::Schedules;
                 self.view = View::Collection(KindId::Schedule);
                 vec![Effect::LoadSchedules]
             }
             "activities" | "act" => {
                 if !self.activities_supported {
-                    self.last_error = Some((
-                        "activities not supported by this server".to_string(),
-                        Instant::now(),
-                    ));
+                    self.push_error("activities not supported by this server".to_string());
                     return vec![];
                 }
+                self.custom_kind = None;
                 self.active_tab = ViewType::Activities;
                 self.view = View::Collection(KindId::ActivityExecution);
                 vec![
@@ -871,26 +2657,31 @@ impl App {
                 ]
             }
             "signal" | "sig" => {
+                if self.reject_foreign_row() {
+                    return vec![];
+                }
+                if !self.capabilities.execution_admin {
+                    self.push_error("signals are not supported by this backend");
+                    return vec![];
+                }
                 if let Some(signal_args) = args {
                     let signal_parts: Vec<&str> = signal_args.splitn(2, ' ').collect();
                     let signal_name = signal_parts[0].to_string();
                     let signal_input = signal_parts.get(1).map(|s| s.to_string());
                     if let Some(wf) = self.selected_workflow_summary() {
-                        return vec![Effect::SignalWorkflow(
+                        let effect = Effect::SignalWorkflow(
                             wf.workflow_id.clone(),
                             Some(wf.run_id.clone()),
-                            signal_name,
-                            signal_input,
-                        )];
+                            signal_name.clone(),
+                            signal_input.clone(),
+                        );
+                        self.remember_signal(signal_name, signal_input);
+                        return vec![effect];
                     } else {
-                        self.last_error =
-                            Some(("no workflow selected".to_string(), Instant::now()));
+                        self.push_error("no workflow selected".to_string());
                     }
                 } else {
-                    self.last_error = Some((
-                        "usage: :signal <name> [json-input]".to_string(),
-                        Instant::now(),
-                    ));
+                    self.push_error("usage: :signal <name> [json-input]".to_string());
                 }
                 vec![]
             }
@@ -899,24 +2690,19 @@ impl App {
                     match parse_deep_link(uri) {
                         Ok(location) => self.apply_location(location),
                         Err(err) => {
-                            self.last_error = Some((
-                                format!("invalid uri: {}", format_uri_error(err)),
-                                Instant::now(),
-                            ));
+                            self.push_error(format!("invalid uri: {}", err));
                             vec![]
                         }
                     }
                 } else {
-                    self.last_error = Some((
-                        "usage: :open temporal://tui/namespaces/<ns>/...".to_string(),
-                        Instant::now(),
-                    ));
+                    self.push_error("usage: :open temporal://tui/namespaces/<ns>/...".to_string());
                     vec![]
                 }
             }
             "namespace" | "ns" => {
                 if let Some(ns_name) = args {
                     self.namespace = ns_name.to_string();
+                    self.marked_workflows.clear();
                     self.workflows = LoadState::NotLoaded;
                     self.schedules = LoadState::NotLoaded;
                     self.activity_executions = LoadState::NotLoaded;
@@ -930,9 +2716,271 @@ impl App {
                     effects
                 } else {
                     self.overlay = Overlay::NamespaceSelector;
+                    self.set_namespace_filter(String::new());
                     vec![Effect::LoadNamespaces]
                 }
             }
+            "pause" | "unpause" => {
+                if !OperationId::PauseSchedule.is_supported(&self.capabilities) {
+                    self.push_error("pausing schedules is not supported by this backend");
+                    return vec![];
+                }
+                let Some(sch) = self.selected_schedule_summary() else {
+                    self.push_error("no schedule selected".to_string());
+                    return vec![];
+                };
+                vec![Effect::PauseSchedule(
+                    sch.schedule_id.clone(),
+                    command == "pause",
+                    args.unwrap_or_default().to_string(),
+                )]
+            }
+            "group" | "grp" => {
+                let grouping = match args {
+                    Some(value) => match ScheduleGrouping::from_param(value) {
+                        Some(grouping) => grouping,
+                        None => {
+                            self.push_error("usage: :group [type|state|none]".to_string());
+                            return vec![];
+                        }
+                    },
+                    None => self.schedule_grouping.next(),
+                };
+                self.set_schedule_grouping(grouping);
+                if self.view != View::Collection(KindId::Schedule) {
+                    self.active_tab = ViewType::Schedules;
+                    self.view = View::Collection(KindId::Schedule);
+                    return vec![Effect::LoadSchedules];
+                }
+                vec![]
+            }
+            "timezone" | "tz" => {
+                match args {
+                    Some(value) => match DisplayZone::parse(value) {
+                        Some(zone) => self.time_display.zone = zone,
+                        None => self.push_error(format!(
+                            "unknown time zone {}; use local, utc or an IANA name",
+                            value
+                        )),
+                    },
+                    None => {
+                        self.time_display.zone = match self.time_display.zone {
+                            DisplayZone::Local => DisplayZone::Utc,
+                            _ => DisplayZone::Local,
+                        }
+                    }
+                }
+                vec![]
+            }
+            "attributes" | "attr" => {
+                let Some(args) = args else {
+                    self.overlay = Overlay::SearchAttributes;
+                    self.search_attributes = LoadState::Loading;
+                    self.search_attribute_cursor = 0;
+                    return vec![Effect::LoadSearchAttributes];
+                };
+                let (name, value_type) = match args.split_whitespace().collect::<Vec<_>>()[..] {
+                    ["add", name, value_type] => (name, value_type),
+                    _ => {
+                        self.push_error("usage: :attributes add <name> <type>");
+                        return vec![];
+                    }
+                };
+                if !self.capabilities.namespace_admin {
+                    self.push_error("adding search attributes is not supported by this backend");
+                    return vec![];
+                }
+                let Some(value_type) = SearchAttributeType::parse(value_type) else {
+                    let types: Vec<&str> =
+                        SearchAttributeType::ALL.iter().map(|t| t.label()).collect();
+                    self.push_error(format!(
+                        "unknown search attribute type {}; use one of {}",
+                        value_type,
+                        types.join(", ")
+                    ));
+                    return vec![];
+                };
+                self.overlay = Overlay::SearchAttributes;
+                self.search_attributes = LoadState::Loading;
+                vec![Effect::AddSearchAttribute(name.to_string(), value_type)]
+            }
+            "overview" | "ov" => self.apply(Action::OpenOverview),
+            "nsadmin" => {
+                if !self.capabilities.namespace_admin {
+                    self.push_error("namespace administration is not supported by this backend");
+                    return vec![];
+                }
+                match parse_namespace_change(args.unwrap_or("")) {
+                    Ok(change) => self.open_confirm(ConfirmAction::Namespace(change), None),
+                    Err(msg) => self.push_error(msg),
+                }
+                vec![]
+            }
+            "poll" => {
+                let (target, secs) = match args.map(|a| a.split_whitespace().collect::<Vec<_>>()) {
+                    Some(words) if words.len() == 1 => (Some(self.poll_target()), words[0]),
+                    Some(words) if words.len() == 2 => (PollTarget::parse(words[0]), words[1]),
+                    _ => (None, ""),
+                };
+                match (target, parse_duration_text(secs)) {
+                    (Some(target), Some(secs)) if secs > 0 => {
+                        self.poll_intervals
+                            .insert(target, Duration::from_secs(secs as u64));
+                    }
+                    _ => self.push_error("usage: :poll [list|detail|history] <secs>"),
+                }
+                vec![]
+            }
+            "link" => self.apply(Action::CopyLink),
+            "preview" => {
+                self.preview_pane = !self.preview_pane;
+                vec![]
+            }
+            "pagesize" | "ps" => {
+                let View::Collection(kind) = self.view else {
+                    self.push_error("pagesize works in list views");
+                    return vec![];
+                };
+                let size = match args.map(str::parse::<i32>) {
+                    Some(Ok(size)) if (1..=MAX_PAGE_SIZE).contains(&size) => size,
+                    _ => {
+                        self.push_error(format!("usage: :pagesize <1-{}>", MAX_PAGE_SIZE));
+                        return vec![];
+                    }
+                };
+                *match kind {
+                    KindId::WorkflowExecution => &mut self.page_size,
+                    KindId::Schedule => &mut self.schedule_page_size,
+                    KindId::ActivityExecution => &mut self.activity_page_size,
+                } = size;
+                self.search_effects(kind)
+            }
+            "save-input" | "save-input!" | "save-output" | "save-output!" => {
+                let (name, side) = match command.trim_end_matches('!') {
+                    "save-input" => ("save-input", PayloadSide::Input),
+                    _ => ("save-output", PayloadSide::Output),
+                };
+                let [path] = &split_quoted(args.unwrap_or_default())[..] else {
+                    self.push_error(format!("usage: :{} <path>", name));
+                    return vec![];
+                };
+                let path = std::path::PathBuf::from(path);
+                self.payload_json(side)
+                    .map(|json| {
+                        Effect::WriteFile(FileWrite {
+                            done: format!("saved {}", path.display()),
+                            path,
+                            contents: json,
+                            overwrite: command.ends_with('!'),
+                            command: name,
+                        })
+                    })
+                    .into_iter()
+                    .collect()
+            }
+            "export" | "export!" => self.start_export(args, command.ends_with('!')),
+            "bulk" => self.start_bulk(args),
+            "pipe" => match args {
+                Some(name) => self.start_pipe(name),
+                None => {
+                    let names: Vec<&str> = self.pipes.iter().map(|p| p.name.as_str()).collect();
+                    self.push_error(format!("usage: :pipe <{}>", names.join("|")));
+                    vec![]
+                }
+            },
+            "bookmark" | "bm" => match args {
+                None => self.apply(Action::OpenBookmarks),
+                Some(name) => self.add_bookmark(name),
+            },
+            "last" => match args {
+                None => self.apply(Action::OpenTimeRangePicker),
+                Some("all") => self.set_time_range(None),
+                Some(arg) => match TimeRange::parse(arg) {
+                    Some(range) => self.set_time_range(Some(range)),
+                    None => {
+                        self.push_error(format!(
+                            "unknown time range '{}' (use 15m, 1h, 24h, 7d or all)",
+                            arg
+                        ));
+                        vec![]
+                    }
+                },
+            },
+            "logs" | "log" => {
+                self.overlay = Overlay::Logs;
+                self.logs_scroll = 0;
+                vec![]
+            }
+            "archived" => {
+                self.archived_workflows = !self.archived_workflows;
+                self.namespace_fanout.clear();
+                self.fanout_failed.clear();
+                self.show_workflow_list()
+            }
+            "allns" => {
+                let names: Vec<String> = args
+                    .unwrap_or_default()
+                    .split([',', ' '])
+                    .filter(|name| !name.is_empty())
+                    .map(str::to_string)
+                    .collect();
+                let known: Vec<&str> = self.namespaces.iter().map(|ns| ns.name.as_str()).collect();
+                if let Some(unknown) = names
+                    .iter()
+                    .find(|name| !known.is_empty() && !known.contains(&name.as_str()))
+                {
+                    self.push_error(format!("unknown namespace {}", unknown));
+                    return vec![];
+                }
+                self.namespace_fanout = match names.is_empty() {
+                    false => names,
+                    true if !self.namespace_fanout.is_empty() => vec![],
+                    true if known.is_empty() => {
+                        self.push_error("no namespaces loaded yet; try :allns <ns> <ns>...");
+                        return vec![];
+                    }
+                    true => known.iter().map(|name| name.to_string()).collect(),
+                };
+                self.fanout_failed.clear();
+                self.archived_workflows = false;
+                self.show_workflow_list()
+            }
+            "audit" => {
+                self.overlay = Overlay::Audit;
+                self.logs_scroll = 0;
+                vec![]
+            }
+            "ctx" | "context" => match args {
+                Some(name) if self.profiles.iter().any(|p| p == name) => {
+                    self.request_context(name.to_string())
+                }
+                Some(name) => {
+                    self.push_error(format!("unknown profile {}", name));
+                    vec![]
+                }
+                None => self.apply(Action::OpenContextPicker),
+            },
+            "copy-mode" | "copy" => {
+                self.mouse_capture = !self.mouse_capture;
+                vec![Effect::SetMouseCapture(self.mouse_capture)]
+            }
+            "q" if args.is_some() => {
+                let name = args.unwrap_or_default();
+                let found = self
+                    .namespace_saved_queries()
+                    .find(|q| q.name == name)
+                    .cloned();
+                match found {
+                    Some(query) => self.apply_saved_query(&query),
+                    None => {
+                        self.push_error(format!(
+                            "no saved query named '{}' in {}",
+                            name, self.namespace
+                        ));
+                        vec![]
+                    }
+                }
+            }
             "quit" | "q" => {
                 self.should_quit = true;
                 vec![Effect::Quit]
@@ -942,23 +2990,124 @@ impl App {
                 vec![]
             }
             _ => {
-                self.last_error = Some((format!("unknown command: {}", command), Instant::now()));
+                self.push_error(format!("unknown command: {}", command));
                 vec![]
             }
         }
     }
 
+    /// Reloads the workflow list from scratch after its source changed.
+    fn show_workflow_list(&mut self) -> Vec<Effect> {
+        self.custom_kind = None;
+        self.active_tab = ViewType::Workflows;
+        self.view = View::Collection(KindId::WorkflowExecution);
+        self.marked_workflows.clear();
+        self.workflows = LoadState::Loading;
+        self.workflow_table_state = TableState::default();
+        self.workflow_count = None;
+        self.workflow_status_counts.clear();
+        vec![Effect::LoadWorkflows, Effect::LoadWorkflowCount]
+    }
+
     fn refresh_current_view(&mut self) -> Vec<Effect> {
+        let mut effects = self.refresh_view_data();
+        if self.split_visible() && matches!(self.view, View::Collection(_)) {
+            effects.extend(self.preview_effects());
+        }
+        effects
+    }
+
+    /// The kind and id of the row the split view previews.
+    fn preview_key(&self) -> Option<(KindId, String)> {
+        match self.view {
+            View::Collection(KindId::WorkflowExecution) => self
+                .selected_workflow_summary()
+                .map(|wf| (KindId::WorkflowExecution, wf.workflow_id.clone())),
+            View::Collection(KindId::Schedule) => self
+                .selected_schedule_summary()
+                .map(|s| (KindId::Schedule, s.schedule_id.clone())),
+            View::Collection(KindId::ActivityExecution) => self
+                .selected_activity_summary()
+                .map(|a| (KindId::ActivityExecution, a.activity_id.clone())),
+            View::Detail(_) => None,
+        }
+    }
+
+    /// Whether the split view is on and the terminal is wide enough to show it.
+    pub fn split_visible(&self) -> bool {
+        self.split_view && self.screen_width >= SPLIT_MIN_WIDTH
+    }
+
+    /// The (workflow id, run id) the preview pane shows: the highlighted
+    /// workflow row while the pane is on and the split view is not showing.
+    fn workflow_preview_key(&self) -> Option<(String, String)> {
+        if !self.preview_pane || self.split_visible() || self.overlay != Overlay::None {
+            return None;
+        }
+        self.selected_workflow_row()
+            .filter(|_| self.view == View::Collection(KindId::WorkflowExecution))
+    }
+
+    /// Loads the detail of the highlighted row for the split view.
+    fn preview_effects(&self) -> Vec<Effect> {
+        match self.view {
+            // The preview is the row itself.
+            View::Collection(KindId::WorkflowExecution) if self.summary_only_rows() => vec![],
+            View::Collection(KindId::WorkflowExecution) => self
+                .selected_workflow_summary()
+                .map(|wf| {
+                    Effect::LoadWorkflowDetail(wf.workflow_id.clone(), Some(wf.run_id.clone()))
+                })
+                .into_iter()
+                .collect(),
+            View::Collection(KindId::Schedule) => self
+                .selected_schedule_summary()
+                .map(|s| Effect::LoadScheduleDetail(s.schedule_id.clone()))
+                .into_iter()
+                .collect(),
+            View::Collection(KindId::ActivityExecution) => self
+                .selected_activity_summary()
+                .map(|a| Effect::LoadActivityExecutionDetail {
+                    namespace: self.namespace.clone(),
+                    activity_id: a.activity_id.clone(),
+                    run_id: a.run_id.clone(),
+                })
+                .into_iter()
+                .collect(),
+            View::Detail(_) => vec![],
+        }
+    }
+
+    /// A detail load for a row the split view no longer highlights.
+    fn stale_preview(&self, kind: KindId, id: &str) -> bool {
+        self.split_visible()
+            && self.view == View::Collection(kind)
+            && self.preview_key().is_none_or(|(_, key)| key != id)
+    }
+
+    fn refresh_view_data(&mut self) -> Vec<Effect> {
         match self.view {
             View::Collection(KindId::WorkflowExecution) => {
                 vec![Effect::LoadWorkflows, Effect::LoadWorkflowCount]
             }
+            // Archived runs don't change.
+            View::Detail(KindId::WorkflowExecution) if self.archived_workflows => vec![],
             View::Detail(KindId::WorkflowExecution) => {
                 if let Some(ref wf) = self.selected_workflow {
-                    vec![Effect::LoadWorkflowDetail(
+                    let mut effects = vec![Effect::LoadWorkflowDetail(
                         wf.summary.workflow_id.clone(),
                         Some(wf.summary.run_id.clone()),
-                    )]
+                    )];
+                    // Closed runs' history doesn't change.
+                    if (self.is_history_tab() || self.workflow_detail_tab == 9)
+                        && wf.summary.status == WorkflowStatus::Running
+                    {
+                        effects.push(Effect::LoadHistory(
+                            wf.summary.workflow_id.clone(),
+                            Some(wf.summary.run_id.clone()),
+                        ));
+                    }
+                    effects
                 } else {
                     vec![]
                 }
@@ -997,12 +3146,49 @@ impl App {
         }
     }
 
+    /// Whether workflow rows can't be described in the current namespace:
+    /// archived runs, or rows of a list spanning namespaces.
+    fn summary_only_rows(&self) -> bool {
+        self.archived_workflows || !self.namespace_fanout.is_empty()
+    }
+
+    /// Pushes an error when the highlighted row of a list spanning
+    /// namespaces is from another one, where acting on it from here would
+    /// go to the wrong namespace.
+    fn reject_foreign_row(&mut self) -> bool {
+        let foreign = self
+            .selected_workflow_summary()
+            .filter(|_| self.view == View::Collection(KindId::WorkflowExecution))
+            .and_then(|wf| wf.namespace.clone())
+            .filter(|ns| *ns != self.namespace);
+        if let Some(ns) = foreign {
+            self.push_error(format!("that run is in {}; open it to act on it", ns));
+            return true;
+        }
+        false
+    }
+
+    /// The listed archived run matching a detail route, when the list shows
+    /// archived runs.
+    fn archived_summary(&self, workflow_id: &str, run_id: Option<&str>) -> Option<WorkflowSummary> {
+        if !self.archived_workflows {
+            return None;
+        }
+        let LoadState::Loaded(ref workflows) = self.workflows else {
+            return None;
+        };
+        workflows
+            .iter()
+            .find(|wf| wf.workflow_id == workflow_id && run_id.is_none_or(|r| r == wf.run_id))
+            .cloned()
+    }
+
     fn selected_workflow_summary(&self) -> Option<&WorkflowSummary> {
         match self.view {
             View::Collection(KindId::WorkflowExecution) => {
                 let workflows = self.workflows.data()?;
                 let idx = self.workflow_table_state.selected()?;
-                workflows.get(idx)
+                workflows.get(*self.visible_workflow_indices().get(idx)?)
             }
             View::Detail(KindId::WorkflowExecution) => {
                 self.selected_workflow.as_ref().map(|d| &d.summary)
@@ -1016,13 +3202,162 @@ impl App {
             View::Collection(KindId::Schedule) => {
                 let schedules = self.schedules.data()?;
                 let idx = self.schedule_table_state.selected()?;
-                schedules.get(idx)
+                match self.schedule_list_rows().get(idx)? {
+                    ScheduleListRow::Schedule(schedule_idx) => schedules.get(*schedule_idx),
+                    ScheduleListRow::Group { .. } => None,
+                }
             }
             View::Detail(KindId::Schedule) => self.selected_schedule.as_ref(),
             _ => None,
         }
     }
 
+    /// (workflow id, run id) of the row under the list cursor.
+    fn selected_workflow_row(&self) -> Option<(String, String)> {
+        let workflows = self.workflows.data()?;
+        let idx = self.workflow_table_state.selected()?;
+        let wf = workflows.get(*self.visible_workflow_indices().get(idx)?)?;
+        Some((wf.workflow_id.clone(), wf.run_id.clone()))
+    }
+
+    fn visible_workflow_position(&self, (workflow_id, run_id): &(String, String)) -> Option<usize> {
+        let workflows = self.workflows.data()?;
+        self.visible_workflow_indices().iter().position(|&i| {
+            workflows[i].workflow_id == *workflow_id && workflows[i].run_id == *run_id
+        })
+    }
+
+    /// Indexes into `workflows` of the rows passing the local filter.
+    pub fn visible_workflow_indices(&self) -> Vec<usize> {
+        let Some(workflows) = self.workflows.data() else {
+            return vec![];
+        };
+        let filter = self.local_filter(KindId::WorkflowExecution);
+        workflows
+            .iter()
+            .enumerate()
+            .filter(|(_, wf)| {
+                filter.is_none_or(|f| {
+                    fuzzy_matches(
+                        f,
+                        &format!(
+                            "{} {} {} {} {}",
+                            wf.workflow_id,
+                            wf.workflow_type,
+                            wf.status.as_str(),
+                            wf.task_queue,
+                            wf.run_id
+                        ),
+                    )
+                })
+            })
+            .map(|(i, _)| i)
+            .collect()
+    }
+
+    /// Namespaces matching the selector's filter, in server order.
+    pub fn visible_namespaces(&self) -> Vec<&Namespace> {
+        let filter = self.namespace_filter.to_lowercase();
+        self.namespaces
+            .iter()
+            .filter(|ns| ns.name.to_lowercase().contains(&filter))
+            .collect()
+    }
+
+    pub fn selected_namespace_name(&self) -> Option<String> {
+        let idx = self.namespace_selector_state.selected()?;
+        self.visible_namespaces().get(idx).map(|ns| ns.name.clone())
+    }
+
+    fn set_namespace_filter(&mut self, filter: String) {
+        self.namespace_filter = filter;
+        self.namespace_selector_state.select_first();
+    }
+
+    pub fn local_filter(&self, kind: KindId) -> Option<&str> {
+        self.local_filters
+            .get(&kind)
+            .map(String::as_str)
+            .filter(|f| !f.trim().is_empty())
+    }
+
+    fn set_local_filter(&mut self, filter: String) {
+        let kind = self.current_kind_id();
+        if filter.trim().is_empty() {
+            self.local_filters.remove(&kind);
+        } else {
+            self.local_filters.insert(kind, filter);
+        }
+        match kind {
+            KindId::WorkflowExecution => self.workflow_table_state.select_first(),
+            KindId::Schedule => self.schedule_table_state.select_first(),
+            KindId::ActivityExecution => {}
+        }
+    }
+
+    /// Visible rows of the schedules table, honoring the local filter, the
+    /// active grouping and collapsed group headers.
+    pub fn schedule_list_rows(&self) -> Vec<ScheduleListRow> {
+        let Some(schedules) = self.schedules.data() else {
+            return vec![];
+        };
+        let filter = self.local_filter(KindId::Schedule);
+        let visible: Vec<usize> = schedules
+            .iter()
+            .enumerate()
+            .filter(|(_, sch)| {
+                filter.is_none_or(|f| {
+                    fuzzy_matches(
+                        f,
+                        &format!(
+                            "{} {} {} {}",
+                            sch.schedule_id,
+                            sch.workflow_type,
+                            sch.state.as_str(),
+                            sch.spec_description
+                        ),
+                    )
+                })
+            })
+            .map(|(i, _)| i)
+            .collect();
+        if self.schedule_grouping == ScheduleGrouping::None {
+            return visible.into_iter().map(ScheduleListRow::Schedule).collect();
+        }
+
+        let mut groups: Vec<(String, Vec<usize>)> = Vec::new();
+        for idx in visible {
+            let schedule = &schedules[idx];
+            let key = self.schedule_grouping.group_key(schedule);
+            match groups.iter_mut().find(|(k, _)| *k == key) {
+                Some((_, members)) => members.push(idx),
+                None => groups.push((key, vec![idx])),
+            }
+        }
+        groups.sort_by(|a, b| a.0.cmp(&b.0));
+
+        let mut rows = Vec::with_capacity(schedules.len() + groups.len());
+        for (key, members) in groups {
+            let collapsed = self.collapsed_schedule_groups.contains(&key);
+            rows.push(ScheduleListRow::Group {
+                key,
+                count: members.len(),
+                collapsed,
+            });
+            if !collapsed {
+                rows.extend(members.into_iter().map(ScheduleListRow::Schedule));
+            }
+        }
+        rows
+    }
+
+    fn set_schedule_grouping(&mut self, grouping: ScheduleGrouping) {
+        self.schedule_grouping = grouping;
+        self.collapsed_schedule_groups.clear();
+        self.schedule_table_state = TableState::default();
+        self.schedule_table_state.select_first();
+    }
+
     fn selected_activity_summary(&self) -> Option<&ActivityExecutionSummary> {
         match self.view {
             View::Collection(KindId::ActivityExecution) => {
@@ -1054,20 +3389,10 @@ impl App {
     }
 
     fn navigate_down(&mut self) {
-        let len = match self.view {
-            View::Collection(KindId::WorkflowExecution) => {
-                self.workflows.data().map(|w| w.len()).unwrap_or(0)
-            }
-            View::Collection(KindId::Schedule) => {
-                self.schedules.data().map(|s| s.len()).unwrap_or(0)
-            }
-            View::Collection(KindId::ActivityExecution) => self
-                .activity_executions
-                .data()
-                .map(|a| a.len())
-                .unwrap_or(0),
-            _ => return,
+        let View::Collection(kind) = self.view else {
+            return;
         };
+        let len = self.collection_len(kind);
 
         if len == 0 {
             return;
@@ -1118,71 +3443,772 @@ impl App {
         }
     }
 
-    fn is_detail_view(&self) -> bool {
-        matches!(self.view, View::Detail(_))
+    pub fn namespace_recent_signals(&self) -> &[RecentSignal] {
+        self.recent_signals
+            .get(&self.namespace)
+            .map(Vec::as_slice)
+            .unwrap_or_default()
     }
 
-    fn load_workflow_tab_data(&mut self) -> Vec<Effect> {
-        if let Some(ref wf) = self.selected_workflow {
-            match self.workflow_detail_tab {
-                2 => {
-                    // History tab
-                    vec![Effect::LoadHistory(
-                        wf.summary.workflow_id.clone(),
-                        Some(wf.summary.run_id.clone()),
-                    )]
-                }
-                4 => {
-                    // Task Queue tab
-                    self.task_queue_detail = LoadState::Loading;
-                    vec![Effect::LoadTaskQueueDetail(wf.summary.task_queue.clone())]
-                }
-                _ => vec![],
-            }
-        } else {
-            vec![]
+    fn remember_signal(&mut self, name: String, input: Option<String>) {
+        let recent = self
+            .recent_signals
+            .entry(self.namespace.clone())
+            .or_default();
+        let signal = RecentSignal { name, input };
+        recent.retain(|s| *s != signal);
+        recent.insert(0, signal);
+        recent.truncate(RECENT_SIGNAL_LIMIT);
+    }
+
+    /// Prefills the command line with the chosen signal so its payload can be
+    /// edited before sending.
+    fn pick_recent_signal(&mut self) {
+        let Some(signal) = self
+            .namespace_recent_signals()
+            .get(self.signal_picker_cursor)
+            .cloned()
+        else {
+            return;
+        };
+        self.overlay = Overlay::None;
+        self.input_mode = InputMode::Command;
+        self.input_buffer = match signal.input {
+            Some(input) => format!("signal {} {}", signal.name, input),
+            None => format!("signal {} ", signal.name),
+        };
+    }
+
+    /// Worker context id of the active connection.
+    pub fn context_id(&self) -> &str {
+        self.active_profile.as_deref().unwrap_or(DEFAULT_CONTEXT)
+    }
+
+    /// Starts switching to profile `name`.
+    fn request_context(&mut self, name: String) -> Vec<Effect> {
+        self.notice = Some((format!("connecting to {}", name), Instant::now()));
+        self.switching_context = Some(name.clone());
+        vec![Effect::SwitchContext(name)]
+    }
+
+    pub fn apply_saved_queries(&mut self, queries: &[QueryConfig]) {
+        for config in queries {
+            let kind = match config.kind.as_deref() {
+                None => KindId::WorkflowExecution,
+                Some(name) => match self.kinds.builtin_by_label(name) {
+                    Some(kind) => kind,
+                    None => {
+                        self.push_error(format!(
+                            "config: unknown kind '{}' for query '{}'",
+                            name, config.name
+                        ));
+                        continue;
+                    }
+                },
+            };
+            self.saved_queries.push(SavedQuery {
+                name: config.name.clone(),
+                kind,
+                namespace: config.namespace.clone(),
+                query: config.query.clone(),
+            });
         }
     }
 
-    fn load_activity_tab_data(&mut self) -> Vec<Effect> {
-        let task_queue = match self.selected_activity_summary() {
-            Some(summary) => summary,
-            None => return vec![],
+    pub fn apply_custom_kinds(&mut self, kinds: &[KindConfig]) {
+        let (registry, errors) = KindRegistry::load(kinds);
+        self.kinds = registry;
+        for e in errors {
+            self.push_error(e);
         }
-        .task_queue
-        .clone();
+    }
 
-        if self.activity_detail_tab == 2 {
-            self.activity_execution_task_queue = LoadState::Loading;
-            return vec![Effect::LoadTaskQueueDetail(task_queue)];
+    /// The `[[kinds]]` list on screen, if any.
+    pub fn active_custom_kind(&self) -> Option<&CustomKind> {
+        let kind = self.kinds.custom().get(self.custom_kind?)?;
+        (kind.base == self.current_kind_id()).then_some(kind)
+    }
+
+    /// `kind`'s search query, ANDed with the fixed query of the `[[kinds]]`
+    /// list on screen.
+    fn kind_query(&self, kind: KindId) -> Option<String> {
+        let query = self.search_queries.get(&kind).cloned();
+        match self.active_custom_kind().filter(|k| k.base == kind) {
+            Some(custom) => Some(match query {
+                Some(query) => format!("({}) AND ({})", custom.query, query),
+                None => custom.query.clone(),
+            }),
+            None => query,
         }
+    }
 
-        vec![]
+    fn namespace_saved_queries(&self) -> impl Iterator<Item = &SavedQuery> {
+        self.saved_queries
+            .iter()
+            .filter(|q| q.namespace.as_deref().is_none_or(|ns| ns == self.namespace))
     }
 
-    pub fn location(&self) -> Location {
-        let segments = match self.view {
-            View::Collection(KindId::WorkflowExecution) => {
-                vec![RouteSegment::Workflows(WorkflowsRoute::Collection {
-                    query: self.search_queries.get(&KindId::WorkflowExecution).cloned(),
-                })]
+    /// Saved queries offered by the picker: those for the current namespace and kind.
+    pub fn picker_saved_queries(&self) -> Vec<&SavedQuery> {
+        let kind = self.current_kind_id();
+        self.namespace_saved_queries()
+            .filter(|q| q.kind == kind)
+            .collect()
+    }
+
+    /// Sets the saved query as the filter for its kind and switches to that list.
+    fn apply_saved_query(&mut self, query: &SavedQuery) -> Vec<Effect> {
+        self.search_queries.insert(query.kind, query.query.clone());
+        let command = query.kind.label().to_lowercase();
+        self.execute_command(&command)
+    }
+
+    /// Saves the current location under `name`, replacing a bookmark of the
+    /// same name.
+    fn add_bookmark(&mut self, name: &str) -> Vec<Effect> {
+        let bookmark = Bookmark {
+            name: name.to_string(),
+            uri: format_deep_link(&self.location()),
+        };
+        match self.bookmarks.iter_mut().find(|b| b.name == name) {
+            Some(existing) => *existing = bookmark,
+            None => self.bookmarks.push(bookmark),
+        }
+        vec![Effect::SaveBookmarks(self.bookmarks.clone())]
+    }
+
+    pub fn push_error(&mut self, msg: impl Into<String>) {
+        let msg = msg.into();
+        self.log_error(msg.clone());
+        self.error_toasts.push_back((msg, Instant::now()));
+    }
+
+    /// Record an error in the log without raising a toast.
+    fn log_error(&mut self, msg: String) {
+        self.error_log.push((chrono::Local::now(), msg));
+        if self.error_log.len() > ERROR_LOG_LIMIT {
+            self.error_log.remove(0);
+        }
+    }
+
+    /// Reload a kind's list after its search query changed.
+    fn search_effects(&mut self, kind: KindId) -> Vec<Effect> {
+        match kind {
+            KindId::WorkflowExecution => {
+                vec![Effect::LoadWorkflows, Effect::LoadWorkflowCount]
             }
-            View::Detail(KindId::WorkflowExecution) => {
-                if let Some(ref detail) = self.selected_workflow {
-                    vec![RouteSegment::Workflows(WorkflowsRoute::Detail {
-                        workflow_id: detail.summary.workflow_id.clone(),
-                        run_id: Some(detail.summary.run_id.clone()),
-                        tab: None,
-                    })]
-                } else {
-                    vec![RouteSegment::Workflows(WorkflowsRoute::Collection {
-                        query: self.search_queries.get(&KindId::WorkflowExecution).cloned(),
-                    })]
-                }
+            KindId::Schedule => {
+                // Row indexes from the old result (and its groups)
+                // mean nothing in the new one.
+                self.schedule_table_state = TableState::default();
+                vec![Effect::LoadSchedules]
             }
-            View::Collection(KindId::Schedule) => {
+            KindId::ActivityExecution => vec![
+                Effect::LoadActivityExecutions {
+                    namespace: self.namespace.clone(),
+                    query: self.search_query_for_kind(KindId::ActivityExecution),
+                    page_size: self.activity_page_size,
+                    next_page_token: vec![],
+                },
+                Effect::CountActivityExecutions {
+                    namespace: self.namespace.clone(),
+                    query: self.search_query_for_kind(KindId::ActivityExecution),
+                },
+            ],
+        }
+    }
+
+    /// Drop the visible toast and start the timer on the next queued one.
+    fn dismiss_error(&mut self) {
+        self.error_toasts.pop_front();
+        match self.error_toasts.front_mut() {
+            Some((_, at)) => *at = Instant::now(),
+            None => self.toast_focused = false,
+        }
+    }
+
+    /// Applies the config file's UI settings, replacing those from a
+    /// previous load so edits take effect without a restart.
+    pub fn apply_config(&mut self, config: &ConfigFile) {
+        let custom_command = self.active_custom_kind().map(|kind| kind.command.clone());
+        self.profiles = config.profiles.iter().map(|p| p.name.clone()).collect();
+        self.pipes = config.pipes.clone();
+        self.typed_confirm = config.typed_confirm.clone();
+        self.audit_file = config.audit_file.as_ref().map(std::path::PathBuf::from);
+        self.favorite_namespaces = config.favorite_namespaces.clone();
+        self.mask_rules.clear();
+        for (i, rule) in config.mask.iter().enumerate() {
+            match MaskRule::parse(rule) {
+                Ok(rule) => self.mask_rules.push(rule),
+                Err(e) => self.push_error(format!("config: mask rule {}: {}", i + 1, e)),
+            }
+        }
+        self.columns.clear();
+        self.wide_columns.clear();
+        self.apply_column_config(&config.columns);
+        self.saved_queries.clear();
+        self.apply_saved_queries(&config.queries);
+        self.apply_custom_kinds(&config.kinds);
+        self.custom_kind =
+            custom_command.and_then(|command| self.kinds.custom_by_command(&command));
+        self.apply_time_config(config.relative_times, config.timezone.as_deref());
+        self.apply_poll_config(&config.poll_intervals);
+        self.web_url = config.web_url.clone();
+        if let Some(open) = config.preview_pane {
+            self.preview_pane = open;
+        }
+    }
+
+    pub fn apply_time_config(&mut self, relative: Option<bool>, timezone: Option<&str>) {
+        if let Some(relative) = relative {
+            self.time_display.relative = relative;
+        }
+        if let Some(name) = timezone {
+            match DisplayZone::parse(name) {
+                Some(zone) => self.time_display.zone = zone,
+                None => self.push_error(format!("config: unknown timezone {}", name)),
+            }
+        }
+    }
+
+    /// Applies the `[columns]` table from the config file. Invalid entries are
+    /// reported and that kind keeps its default columns.
+    pub fn apply_column_config(&mut self, config: &HashMap<String, Vec<String>>) {
+        for (key, entries) in config {
+            let (name, wide) = match key.strip_suffix("-wide") {
+                Some(name) => (name, true),
+                None => (key.as_str(), false),
+            };
+            let Some(kind) = self.kinds.builtin_by_label(name) else {
+                self.push_error(format!("config: unknown collection '{}' in [columns]", key));
+                continue;
+            };
+            match resolve_columns(kind, entries) {
+                Ok(columns) if !columns.is_empty() => {
+                    if wide {
+                        self.wide_columns.insert(kind, columns);
+                    } else {
+                        self.columns.insert(kind, columns);
+                    }
+                }
+                Ok(_) => {}
+                Err(e) => self.push_error(format!("config: {}", e)),
+            }
+        }
+    }
+
+    /// Attributes offered by the query builder: the namespace's list once
+    /// loaded, otherwise the common system ones.
+    pub fn query_builder_attributes(&self) -> Vec<(String, Option<SearchAttributeType>)> {
+        match self.search_attributes.data() {
+            Some(attributes) if !attributes.is_empty() => attributes
+                .iter()
+                .map(|a| (a.name.clone(), a.value_type))
+                .collect(),
+            _ => DEFAULT_ATTRIBUTES
+                .iter()
+                .map(|(name, value_type)| (name.to_string(), Some(*value_type)))
+                .collect(),
+        }
+    }
+
+    /// Names and values the search modal completes from.
+    pub fn search_vocabulary(&self) -> Vocabulary {
+        let mut workflow_types: Vec<String> = match self.current_kind_id() {
+            KindId::Schedule => self
+                .schedules
+                .data()
+                .into_iter()
+                .flatten()
+                .map(|s| s.workflow_type.clone())
+                .collect(),
+            _ => self
+                .workflows
+                .data()
+                .into_iter()
+                .flatten()
+                .map(|w| w.workflow_type.clone())
+                .collect(),
+        };
+        workflow_types.sort();
+        workflow_types.dedup();
+        Vocabulary {
+            attributes: self
+                .query_builder_attributes()
+                .into_iter()
+                .map(|(name, _)| name)
+                .collect(),
+            values: HashMap::from([
+                (
+                    "ExecutionStatus".to_string(),
+                    WorkflowStatus::ALL
+                        .iter()
+                        .map(|s| s.as_str().to_string())
+                        .collect(),
+                ),
+                ("WorkflowType".to_string(), workflow_types),
+            ]),
+        }
+    }
+
+    /// Completion for the search being typed; JSON tree searches get none.
+    pub fn search_completion(&self) -> Completion {
+        if self.input_mode != InputMode::Search
+            || self.is_detail_view()
+            || self.overlay == Overlay::Pager
+        {
+            return Completion::default();
+        }
+        complete(&self.input_buffer, &self.search_vocabulary())
+    }
+
+    pub fn columns_for(&self, kind: KindId) -> Vec<ColumnSpec> {
+        if let Some(custom) = self.active_custom_kind() {
+            if custom.base == kind && !custom.columns.is_empty() {
+                return custom.columns.clone();
+            }
+        }
+        self.columns
+            .get(&kind)
+            .cloned()
+            .unwrap_or_else(|| default_columns(kind))
+    }
+
+    /// Columns to render in a table `width` cells wide. Wide mode only applies
+    /// once the terminal is at least [`WIDE_MIN_WIDTH`] columns.
+    pub fn columns_for_width(&self, kind: KindId, width: u16) -> Vec<ColumnSpec> {
+        let mut columns = if !self.wide_mode || width < WIDE_MIN_WIDTH {
+            self.columns_for(kind)
+        } else {
+            self.wide_columns
+                .get(&kind)
+                .cloned()
+                .unwrap_or_else(|| wide_columns(kind))
+        };
+        // A list spanning namespaces needs to say which each row is from.
+        if kind == KindId::WorkflowExecution
+            && !self.namespace_fanout.is_empty()
+            && !columns.iter().any(|c| c.id == "namespace")
+        {
+            let at = columns.len().min(1);
+            columns.splice(at..at, preset_columns(kind, &["namespace"]));
+        }
+        columns
+    }
+
+    fn is_detail_view(&self) -> bool {
+        matches!(self.view, View::Detail(_))
+    }
+
+    fn is_history_tab(&self) -> bool {
+        self.view == View::Detail(KindId::WorkflowExecution) && self.workflow_detail_tab == 2
+    }
+
+    fn is_children_tab(&self) -> bool {
+        self.view == View::Detail(KindId::WorkflowExecution) && self.workflow_detail_tab == 6
+    }
+
+    /// The Pending tab with activities to select; otherwise it scrolls.
+    fn is_pending_tab(&self) -> bool {
+        self.view == View::Detail(KindId::WorkflowExecution)
+            && self.workflow_detail_tab == 3
+            && self
+                .selected_workflow
+                .as_ref()
+                .is_some_and(|d| !d.pending_activities.is_empty())
+    }
+
+    fn is_io_tab(&self) -> bool {
+        self.view == View::Detail(KindId::WorkflowExecution) && self.workflow_detail_tab == 1
+    }
+
+    /// The JSON tree that `/`, `n`/`N` and folding act on in the current tab.
+    fn active_json_tree(&mut self) -> Option<&mut JsonTreeState> {
+        if self.is_io_tab() {
+            Some(&mut self.io_tree)
+        } else if self.is_history_tab() {
+            Some(&mut self.history_tree)
+        } else {
+            None
+        }
+    }
+
+    /// `value` with the `[[mask]]` rules applied, for drawing.
+    pub fn masked(&self, value: &serde_json::Value) -> serde_json::Value {
+        mask(value, &self.mask_rules)
+    }
+
+    /// A decoded input or output with the mask rules applied; bytes are
+    /// left alone.
+    pub fn masked_payload(&self, payload: Option<&Payload>) -> Payload {
+        match payload {
+            Some(Payload::Json(value)) => Payload::Json(self.masked(value)),
+            Some(binary @ Payload::Binary(_)) => binary.clone(),
+            None => Payload::Json(serde_json::Value::Null),
+        }
+    }
+
+    /// A memo or search attribute map as one masked object.
+    pub fn masked_fields(&self, fields: &HashMap<String, serde_json::Value>) -> serde_json::Value {
+        self.masked(&serde_json::Value::Object(
+            fields.iter().map(|(k, v)| (k.clone(), v.clone())).collect(),
+        ))
+    }
+
+    /// Payload text such as user metadata, masked when it holds JSON.
+    pub fn masked_text(&self, text: &str) -> String {
+        mask_text(text, &self.mask_rules)
+    }
+
+    /// The Raw tab's describe response, masked and pretty-printed.
+    pub fn raw_text(&self) -> Option<String> {
+        let value = self.masked(self.raw_detail.data()?);
+        Some(serde_json::to_string_pretty(&value).unwrap_or_else(|_| value.to_string()))
+    }
+
+    /// Visible rows of the Input/Output tab tree: input, output, and memo and
+    /// search attributes when present.
+    pub fn io_tree_rows(&self) -> Vec<TreeRow> {
+        let Some(detail) = self.selected_workflow.as_ref() else {
+            return vec![];
+        };
+        let input = self.masked_payload(detail.input.as_ref());
+        let output = self.masked_payload(detail.output.as_ref());
+        let memo = Payload::Json(self.masked_fields(&detail.memo));
+        let search_attributes = Payload::Json(self.masked_fields(&detail.search_attributes));
+        let failure = detail
+            .failure
+            .as_ref()
+            .map(|f| Payload::Json(self.masked(&f.to_json())));
+
+        let mut roots = vec![("Input", &input), ("Output", &output)];
+        if let Some(ref failure) = failure {
+            roots.push(("Failure", failure));
+        }
+        if !detail.memo.is_empty() {
+            roots.push(("Memo", &memo));
+        }
+        if !detail.search_attributes.is_empty() {
+            roots.push(("Search Attributes", &search_attributes));
+        }
+        json_tree::rows(&roots, &self.io_tree)
+    }
+
+    fn move_io_cursor(&mut self, delta: isize) {
+        let len = self.io_tree_rows().len();
+        self.io_tree.cursor = self
+            .io_tree
+            .cursor
+            .saturating_add_signed(delta)
+            .min(len.saturating_sub(1));
+    }
+
+    /// Folds or unfolds the node under the cursor: the selected container in the
+    /// Input/Output tab, or every payload of the selected history event.
+    fn toggle_fold(&mut self) {
+        if self.is_io_tab() {
+            if let Some(row) = self.io_tree_rows().get(self.io_tree.cursor) {
+                if row.is_container() {
+                    self.io_tree.toggle(&row.path);
+                }
+            }
+        } else if self.is_history_tab() {
+            if let Some(event) = self
+                .workflow_history
+                .data()
+                .and_then(|events| events.get(self.history_cursor))
+            {
+                self.history_tree.toggle_root(&event.event_id.to_string());
+            }
+        }
+    }
+
+    /// Moves the cursor to the next (or previous) search match in the active tree.
+    fn jump_to_match(&mut self, forward: bool) {
+        if self.overlay == Overlay::Pager {
+            self.pager.jump_to_match(forward);
+        } else if self.is_io_tab() {
+            if let Some(idx) =
+                json_tree::find_match(&self.io_tree_rows(), self.io_tree.cursor, forward)
+            {
+                self.io_tree.cursor = idx;
+            }
+        } else if self.is_history_tab() {
+            let Some(needle) = self.history_tree.search.as_deref().map(str::to_lowercase) else {
+                return;
+            };
+            let Some(events) = self.workflow_history.data() else {
+                return;
+            };
+            let len = events.len();
+            let found = (1..=len)
+                .map(|step| {
+                    if forward {
+                        (self.history_cursor + step) % len
+                    } else {
+                        (self.history_cursor + len - step) % len
+                    }
+                })
+                .find(|&i| {
+                    let e = &events[i];
+                    e.event_type.to_lowercase().contains(&needle)
+                        || json_tree::subtree_matches("", &self.masked(&e.details), &needle)
+                });
+            if let Some(idx) = found {
+                self.history_cursor = idx;
+            }
+        }
+    }
+
+    fn move_child_cursor(&mut self, delta: isize) {
+        let len = self
+            .selected_workflow
+            .as_ref()
+            .map(|d| d.pending_children.len())
+            .unwrap_or(0);
+        self.child_cursor = self
+            .child_cursor
+            .saturating_add_signed(delta)
+            .min(len.saturating_sub(1));
+    }
+
+    fn move_pending_cursor(&mut self, delta: isize) {
+        let len = self
+            .selected_workflow
+            .as_ref()
+            .map(|d| d.pending_activities.len())
+            .unwrap_or(0);
+        self.pending_cursor = self
+            .pending_cursor
+            .saturating_add_signed(delta)
+            .min(len.saturating_sub(1));
+    }
+
+    /// The activity under the Pending tab's cursor.
+    pub fn selected_pending_activity(&self) -> Option<&PendingActivity> {
+        self.selected_workflow
+            .as_ref()?
+            .pending_activities
+            .get(self.pending_cursor)
+    }
+
+    fn has_schedule_actions(&self) -> bool {
+        self.view == View::Detail(KindId::Schedule)
+            && self.schedule_detail_tab == 0
+            && self
+                .selected_schedule
+                .as_ref()
+                .is_some_and(|s| !s.recent_actions.is_empty())
+    }
+
+    fn move_schedule_action_cursor(&mut self, delta: isize) {
+        let len = self
+            .selected_schedule
+            .as_ref()
+            .map(|s| s.recent_actions.len())
+            .unwrap_or(0);
+        self.schedule_action_cursor = self
+            .schedule_action_cursor
+            .saturating_add_signed(delta)
+            .min(len.saturating_sub(1));
+    }
+
+    /// Jump to the workflow run started by the selected recent action.
+    fn open_schedule_action(&mut self) -> Vec<Effect> {
+        let target = self
+            .selected_schedule
+            .as_ref()
+            .and_then(|s| s.recent_actions.get(self.schedule_action_cursor))
+            .filter(|a| !a.workflow_id.is_empty())
+            .map(|a| (a.workflow_id.clone(), a.run_id.clone()));
+        match target {
+            Some((workflow_id, run_id)) => {
+                self.open_workflow_detail(workflow_id, Some(run_id).filter(|r| !r.is_empty()), None)
+            }
+            None => vec![],
+        }
+    }
+
+    fn move_history_cursor(&mut self, delta: isize) {
+        let len = self.workflow_history.data().map(|e| e.len()).unwrap_or(0);
+        if len == 0 {
+            self.history_cursor = 0;
+            return;
+        }
+        self.history_cursor = self
+            .history_cursor
+            .saturating_add_signed(delta)
+            .min(len - 1);
+    }
+
+    /// Navigate to a child workflow, remembering the current workflow as its
+    /// parent for breadcrumbs and `Esc`.
+    fn open_child_workflow(&mut self, workflow_id: String, run_id: Option<String>) -> Vec<Effect> {
+        let Some(parent) = self.selected_workflow.as_ref().map(|d| WorkflowRef {
+            workflow_id: d.summary.workflow_id.clone(),
+            run_id: d.summary.run_id.clone(),
+        }) else {
+            return vec![];
+        };
+
+        let mut parents = std::mem::take(&mut self.workflow_parents);
+        let effects = self.open_workflow_detail(workflow_id, run_id, None);
+        parents.push(parent);
+        self.workflow_parents = parents;
+        effects
+    }
+
+    fn open_history_child(&mut self) -> Vec<Effect> {
+        let target = self
+            .workflow_history
+            .data()
+            .and_then(|events| child_workflow_target(events, self.history_cursor));
+        match target {
+            Some((workflow_id, run_id)) => self.open_child_workflow(workflow_id, run_id),
+            None => vec![],
+        }
+    }
+
+    fn open_pending_child(&mut self) -> Vec<Effect> {
+        let target = self
+            .selected_workflow
+            .as_ref()
+            .and_then(|d| d.pending_children.get(self.child_cursor))
+            .map(|c| (c.workflow_id.clone(), c.run_id.clone()));
+        match target {
+            Some((workflow_id, run_id)) => {
+                self.open_child_workflow(workflow_id, Some(run_id).filter(|r| !r.is_empty()))
+            }
+            None => vec![],
+        }
+    }
+
+    fn open_workflow_detail(
+        &mut self,
+        workflow_id: String,
+        run_id: Option<String>,
+        tab: Option<&str>,
+    ) -> Vec<Effect> {
+        self.apply_location(Location::new(
+            self.namespace.clone(),
+            vec![RouteSegment::Workflows(WorkflowsRoute::Detail {
+                workflow_id,
+                run_id,
+                tab: tab.map(str::to_string),
+            })],
+        ))
+    }
+
+    fn load_workflow_tab_data(&mut self) -> Vec<Effect> {
+        if let Some(ref wf) = self.selected_workflow {
+            match self.workflow_detail_tab {
+                2 => {
+                    // History tab
+                    vec![Effect::LoadHistory(
+                        wf.summary.workflow_id.clone(),
+                        Some(wf.summary.run_id.clone()),
+                    )]
+                }
+                4 => {
+                    // Task Queue tab
+                    if !self.capabilities.task_queue_reader {
+                        self.task_queue_detail = LoadState::Error(
+                            "task queue details are not supported by this backend".to_string(),
+                        );
+                        return vec![];
+                    }
+                    self.task_queue_detail = LoadState::Loading;
+                    vec![Effect::LoadTaskQueueDetail(wf.summary.task_queue.clone())]
+                }
+                5 => {
+                    // Stack Trace tab: the query needs a worker, so skip closed runs
+                    if wf.summary.status != WorkflowStatus::Running {
+                        self.stack_trace = LoadState::NotLoaded;
+                        return vec![];
+                    }
+                    self.stack_trace = LoadState::Loading;
+                    vec![Effect::LoadStackTrace(
+                        wf.summary.workflow_id.clone(),
+                        Some(wf.summary.run_id.clone()),
+                    )]
+                }
+                7 => {
+                    self.raw_detail = LoadState::Loading;
+                    vec![Effect::LoadRawWorkflow(
+                        wf.summary.workflow_id.clone(),
+                        Some(wf.summary.run_id.clone()),
+                    )]
+                }
+                8 => {
+                    // Like the stack trace, the metadata query needs a worker.
+                    if wf.summary.status != WorkflowStatus::Running {
+                        self.workflow_metadata = LoadState::NotLoaded;
+                        return vec![];
+                    }
+                    self.workflow_metadata = LoadState::Loading;
+                    vec![Effect::LoadWorkflowMetadata(
+                        wf.summary.workflow_id.clone(),
+                        Some(wf.summary.run_id.clone()),
+                    )]
+                }
+                _ => vec![],
+            }
+        } else {
+            vec![]
+        }
+    }
+
+    fn load_schedule_tab_data(&mut self) -> Vec<Effect> {
+        match (&self.selected_schedule, self.schedule_detail_tab) {
+            (Some(schedule), 1) => {
+                self.raw_detail = LoadState::Loading;
+                vec![Effect::LoadRawSchedule(schedule.schedule_id.clone())]
+            }
+            _ => vec![],
+        }
+    }
+
+    fn load_activity_tab_data(&mut self) -> Vec<Effect> {
+        let task_queue = match self.selected_activity_summary() {
+            Some(summary) => summary,
+            None => return vec![],
+        }
+        .task_queue
+        .clone();
+
+        if self.activity_detail_tab == 2 {
+            self.activity_execution_task_queue = LoadState::Loading;
+            return vec![Effect::LoadTaskQueueDetail(task_queue)];
+        }
+
+        vec![]
+    }
+
+    pub fn location(&self) -> Location {
+        let segments = match self.view {
+            View::Collection(KindId::WorkflowExecution) => {
+                vec![RouteSegment::Workflows(WorkflowsRoute::Collection {
+                    query: self.kind_query(KindId::WorkflowExecution),
+                    since: self.time_range.map(|r| r.label().to_string()),
+                })]
+            }
+            View::Detail(KindId::WorkflowExecution) => {
+                if let Some(ref detail) = self.selected_workflow {
+                    vec![RouteSegment::Workflows(WorkflowsRoute::Detail {
+                        workflow_id: detail.summary.workflow_id.clone(),
+                        run_id: Some(detail.summary.run_id.clone()),
+                        tab: None,
+                    })]
+                } else {
+                    vec![RouteSegment::Workflows(WorkflowsRoute::Collection {
+                        query: self.kind_query(KindId::WorkflowExecution),
+                        since: self.time_range.map(|r| r.label().to_string()),
+                    })]
+                }
+            }
+            View::Collection(KindId::Schedule) => {
                 vec![RouteSegment::Schedules(SchedulesRoute::Collection {
-                    query: self.search_queries.get(&KindId::Schedule).cloned(),
+                    query: self.kind_query(KindId::Schedule),
                 })]
             }
             View::Detail(KindId::Schedule) => {
@@ -1192,13 +4218,13 @@ impl App {
                     })]
                 } else {
                     vec![RouteSegment::Schedules(SchedulesRoute::Collection {
-                        query: self.search_queries.get(&KindId::Schedule).cloned(),
+                        query: self.kind_query(KindId::Schedule),
                     })]
                 }
             }
             View::Collection(KindId::ActivityExecution) => {
                 vec![RouteSegment::Activities(ActivitiesRoute::Collection {
-                    query: self.search_queries.get(&KindId::ActivityExecution).cloned(),
+                    query: self.kind_query(KindId::ActivityExecution),
                 })]
             }
             View::Detail(KindId::ActivityExecution) => {
@@ -1210,7 +4236,7 @@ impl App {
                     })]
                 } else {
                     vec![RouteSegment::Activities(ActivitiesRoute::Collection {
-                        query: self.search_queries.get(&KindId::ActivityExecution).cloned(),
+                        query: self.kind_query(KindId::ActivityExecution),
                     })]
                 }
             }
@@ -1220,35 +4246,49 @@ impl App {
     }
 
     fn apply_location(&mut self, location: Location) -> Vec<Effect> {
+        self.workflow_parents.clear();
+        // Links carry a custom kind's fixed query in their own.
+        self.custom_kind = None;
         let namespace = location.namespace.clone();
         let namespace_changed = self.namespace != namespace;
         if namespace_changed {
             self.namespace = namespace;
-            self.workflows = LoadState::NotLoaded;
+            // The list spanning namespaces (`:allns`) stays valid in any of
+            // them, query included.
+            if self.namespace_fanout.is_empty() {
+                self.marked_workflows.clear();
+                self.workflows = LoadState::NotLoaded;
+                self.workflow_table_state = TableState::default();
+                self.next_page_token = vec![];
+                self.search_queries.clear();
+            } else {
+                self.search_queries
+                    .retain(|kind, _| *kind == KindId::WorkflowExecution);
+            }
             self.schedules = LoadState::NotLoaded;
             self.activity_executions = LoadState::NotLoaded;
             self.activity_execution_detail = LoadState::NotLoaded;
             self.activity_execution_task_queue = LoadState::NotLoaded;
             self.workflow_history = LoadState::NotLoaded;
             self.task_queue_detail = LoadState::NotLoaded;
-            self.workflow_table_state = TableState::default();
             self.schedule_table_state = TableState::default();
             self.activity_execution_table_state = TableState::default();
             self.selected_workflow = None;
             self.selected_schedule = None;
             self.workflow_detail_tab = 0;
+            self.schedule_detail_tab = 0;
             self.activity_detail_tab = 0;
             self.detail_scroll = 0;
-            self.next_page_token = vec![];
+            self.schedule_next_page_token = vec![];
             self.activity_next_page_token = vec![];
             self.activity_count = None;
             self.activities_supported = false;
             self.loading_more = false;
-            self.search_queries.clear();
+            self.pending_export = None;
         }
 
         let Some(segment) = location.leaf() else {
-            self.last_error = Some(("invalid uri: missing route".to_string(), Instant::now()));
+            self.push_error("invalid uri: missing route".to_string());
             return vec![];
         };
 
@@ -1262,8 +4302,13 @@ impl App {
 
         let mut effects = match segment {
             RouteSegment::Workflows(route) => match route {
-                WorkflowsRoute::Collection { query } => {
+                WorkflowsRoute::Collection { query, since } => {
                     self.set_kind_query(KindId::WorkflowExecution, query.clone());
+                    self.time_range = since.as_deref().and_then(TimeRange::parse);
+                    self.time_range_anchor = chrono::Utc::now();
+                    if let Some(since) = since.as_deref().filter(|_| self.time_range.is_none()) {
+                        self.push_error(format!("invalid uri: unknown time range '{}'", since));
+                    }
                     self.active_tab = ViewType::Workflows;
                     self.view = View::Collection(KindId::WorkflowExecution);
                     vec![Effect::LoadWorkflows, Effect::LoadWorkflowCount]
@@ -1278,8 +4323,21 @@ impl App {
                     self.workflow_detail_tab =
                         tab.as_deref().map(workflow_tab_from_param).unwrap_or(0);
                     self.detail_scroll = 0;
+                    self.history_cursor = 0;
+                    self.child_cursor = 0;
+                    self.pending_cursor = 0;
+                    self.io_tree = JsonTreeState::default();
+                    self.history_tree = JsonTreeState::default();
                     self.workflow_history = LoadState::Loading;
                     self.task_queue_detail = LoadState::NotLoaded;
+                    self.stack_trace = LoadState::NotLoaded;
+                    self.workflow_metadata = LoadState::NotLoaded;
+                    // Archived runs can't be described, but their history
+                    // still loads from the archive.
+                    if let Some(summary) = self.archived_summary(workflow_id, run_id.as_deref()) {
+                        self.selected_workflow = Some(WorkflowDetail::from_summary(summary));
+                        return vec![Effect::LoadHistory(workflow_id.clone(), run_id.clone())];
+                    }
                     vec![
                         Effect::LoadWorkflowDetail(workflow_id.clone(), run_id.clone()),
                         Effect::LoadHistory(workflow_id.clone(), run_id.clone()),
@@ -1290,8 +4348,11 @@ impl App {
                     self.view = View::Detail(KindId::WorkflowExecution);
                     self.workflow_detail_tab = 3;
                     self.detail_scroll = 0;
+                    self.pending_cursor = 0;
                     self.workflow_history = LoadState::Loading;
                     self.task_queue_detail = LoadState::NotLoaded;
+                    self.stack_trace = LoadState::NotLoaded;
+                    self.workflow_metadata = LoadState::NotLoaded;
                     vec![
                         Effect::LoadWorkflowDetail(workflow_id.clone(), None),
                         Effect::LoadHistory(workflow_id.clone(), None),
@@ -1321,10 +4382,7 @@ impl App {
             },
             RouteSegment::Activities(route) => {
                 if !self.activities_supported {
-                    self.last_error = Some((
-                        "activities not supported by this server".to_string(),
-                        Instant::now(),
-                    ));
+                    self.push_error("activities not supported by this server".to_string());
                     return vec![];
                 }
                 match route {
@@ -1371,15 +4429,40 @@ impl App {
         prefix_effects
     }
 
+    /// The query a kind's list loads with: the search query, narrowed by
+    /// the time range for workflows.
     pub fn search_query_for_kind(&self, kind: KindId) -> Option<String> {
-        self.search_queries.get(&kind).cloned()
+        let query = self.kind_query(kind);
+        match self.time_range {
+            Some(range) if kind == KindId::WorkflowExecution => {
+                Some(range.apply(query.as_deref(), self.time_range_anchor))
+            }
+            _ => query,
+        }
+    }
+
+    /// Whether the workflow query sets its own ORDER BY, which the list
+    /// then keeps exactly as the server returns it.
+    fn workflows_ordered_by_query(&self) -> bool {
+        self.kind_query(KindId::WorkflowExecution)
+            .is_some_and(|query| !query_syntax::split_order_by(&query).1.is_empty())
     }
 
     fn current_search_query(&self) -> Option<String> {
-        self.search_query_for_kind(self.current_kind_id())
+        self.search_queries.get(&self.current_kind_id()).cloned()
+    }
+
+    /// Picking a range, even the current one, counts it back from now.
+    fn set_time_range(&mut self, range: Option<TimeRange>) -> Vec<Effect> {
+        if self.time_range.is_none() && range.is_none() {
+            return vec![];
+        }
+        self.time_range = range;
+        self.time_range_anchor = chrono::Utc::now();
+        self.search_effects(KindId::WorkflowExecution)
     }
 
-    fn current_kind_id(&self) -> KindId {
+    pub fn current_kind_id(&self) -> KindId {
         match self.view {
             View::Collection(kind) | View::Detail(kind) => kind,
         }
@@ -1393,31 +4476,150 @@ impl App {
         }
     }
 
-    fn run_operation(&mut self, op_id: OperationId) -> Vec<Effect> {
+    /// The quick actions menu's entries for the selected row: the kind's
+    /// operations the backend supports, then the rest.
+    pub fn quick_actions(&self) -> Vec<QuickAction> {
         let kind = self.current_kind_id();
-        let Some(spec) = operation_spec(kind, op_id) else {
-            return vec![];
-        };
-        let Some(effect_spec) = operation_effect_spec(op_id, kind) else {
-            return vec![];
-        };
+        let mut actions: Vec<QuickAction> = kind_spec(kind)
+            .operations
+            .iter()
+            .filter(|op| op.id.is_supported(&self.capabilities))
+            .map(|op| QuickAction::Operation(op.id))
+            .collect();
+        if kind == KindId::WorkflowExecution && self.capabilities.execution_admin {
+            actions.push(QuickAction::Signal);
+        }
+        actions.extend([QuickAction::CopyId, QuickAction::CopyLink]);
+        if self.web_ui_link().is_some() {
+            actions.push(QuickAction::OpenWebUi);
+        }
+        actions
+    }
 
-        match kind {
-            KindId::WorkflowExecution => {
-                let Some(wf) = self.selected_workflow_summary() else {
-                    self.last_error = Some(("no workflow selected".to_string(), Instant::now()));
-                    return vec![];
-                };
-                let target = OperationTarget::Workflow {
-                    workflow_id: wf.workflow_id.clone(),
-                    run_id: Some(wf.run_id.clone()),
-                };
-                if spec.requires_confirm {
-                    self.overlay = Overlay::Confirm(ConfirmAction::Operation(OperationConfirm {
+    fn run_quick_action(&mut self, action: QuickAction) -> Vec<Effect> {
+        match action {
+            QuickAction::Operation(op) => self.run_operation(op),
+            QuickAction::Signal if self.namespace_recent_signals().is_empty() => {
+                self.input_mode = InputMode::Command;
+                self.input_buffer = "signal ".to_string();
+                vec![]
+            }
+            QuickAction::Signal => self.apply(Action::OpenSignalPicker),
+            QuickAction::CopyId => match self.selected_row_id() {
+                Some(id) => {
+                    self.notice = Some((tf(Msg::NoticeCopied, &[&id]), Instant::now()));
+                    vec![Effect::CopyToClipboard(id)]
+                }
+                None => vec![],
+            },
+            QuickAction::CopyLink => self.apply(Action::CopyLink),
+            QuickAction::OpenWebUi => self.web_ui_link().map_or_else(Vec::new, |url| {
+                self.notice = Some((tf(Msg::NoticeOpening, &[&url]), Instant::now()));
+                vec![Effect::OpenUrl(url)]
+            }),
+        }
+    }
+
+    /// The selected workflow run or schedule in the Web UI, when there is
+    /// one to link to: the config's `web_url`, or Temporal Cloud's.
+    fn web_ui_link(&self) -> Option<String> {
+        let base = match &self.web_url {
+            Some(url) => url.as_str(),
+            None if self.cluster_info.as_ref().is_some_and(|info| info.is_cloud) => {
+                "https://cloud.temporal.io"
+            }
+            None => return None,
+        };
+        match self.current_kind_id() {
+            KindId::WorkflowExecution => {
+                let wf = self.selected_workflow_summary()?;
+                let page = WebUiPage::Workflow {
+                    workflow_id: &wf.workflow_id,
+                    run_id: &wf.run_id,
+                };
+                let namespace = wf.namespace.as_deref().unwrap_or(&self.namespace);
+                Some(web_ui_url(base, namespace, page))
+            }
+            KindId::Schedule => {
+                let schedule = self.selected_schedule_summary()?;
+                let page = WebUiPage::Schedule(&schedule.schedule_id);
+                Some(web_ui_url(base, &self.namespace, page))
+            }
+            _ => None,
+        }
+    }
+
+    /// The ID of the selected workflow, schedule or activity.
+    fn selected_row_id(&self) -> Option<String> {
+        match self.current_kind_id() {
+            KindId::WorkflowExecution => self
+                .selected_workflow_summary()
+                .map(|wf| wf.workflow_id.clone()),
+            KindId::Schedule => self
+                .selected_schedule_summary()
+                .map(|sch| sch.schedule_id.clone()),
+            KindId::ActivityExecution => self
+                .selected_activity_summary()
+                .map(|activity| activity.activity_id.clone()),
+        }
+    }
+
+    fn run_operation(&mut self, op_id: OperationId) -> Vec<Effect> {
+        let kind = self.current_kind_id();
+        let Some(spec) = operation_spec(kind, op_id) else {
+            return vec![];
+        };
+        let Some(effect_spec) = operation_effect_spec(op_id, kind) else {
+            return vec![];
+        };
+        if !op_id.is_supported(&self.capabilities) {
+            self.push_error(format!(
+                "{} is not supported by this backend",
+                spec.label.to_lowercase()
+            ));
+            return vec![];
+        }
+
+        match kind {
+            KindId::WorkflowExecution if !self.marked_workflows.is_empty() => {
+                let operation = match op_id {
+                    OperationId::CancelWorkflow => BatchOperation::Cancel,
+                    OperationId::TerminateWorkflow => BatchOperation::Terminate,
+                    _ => {
+                        self.push_error(format!(
+                            "{} works on one workflow; Esc clears the marks",
+                            spec.label.to_lowercase()
+                        ));
+                        return vec![];
+                    }
+                };
+                let marked = self.marked_workflows.clone();
+                self.confirm_batch(BatchConfirm {
+                    namespace: self.namespace.clone(),
+                    operation,
+                    count: marked.len() as u64,
+                    target: BatchTarget::Executions(marked),
+                });
+                vec![]
+            }
+            KindId::WorkflowExecution => {
+                if self.reject_foreign_row() {
+                    return vec![];
+                }
+                let Some(wf) = self.selected_workflow_summary() else {
+                    self.push_error("no workflow selected".to_string());
+                    return vec![];
+                };
+                let target = OperationTarget::Workflow {
+                    workflow_id: wf.workflow_id.clone(),
+                    run_id: Some(wf.run_id.clone()),
+                };
+                if spec.requires_confirm {
+                    self.confirm_operation(OperationConfirm {
                         kind,
                         op: op_id,
                         target,
-                    }));
+                    });
                     vec![]
                 } else {
                     (effect_spec.to_effects)(&target, self)
@@ -1425,18 +4627,28 @@ impl App {
             }
             KindId::Schedule => {
                 let Some(sch) = self.selected_schedule_summary() else {
-                    self.last_error = Some(("no schedule selected".to_string(), Instant::now()));
+                    self.push_error("no schedule selected".to_string());
                     return vec![];
                 };
+                if op_id == OperationId::PauseSchedule {
+                    // Prompt for a note; `:pause` / `:unpause` sends it.
+                    let verb = match sch.state {
+                        ScheduleState::Paused => "unpause",
+                        ScheduleState::Active => "pause",
+                    };
+                    self.input_mode = InputMode::Command;
+                    self.input_buffer = format!("{} ", verb);
+                    return vec![];
+                }
                 let target = OperationTarget::Schedule {
                     schedule_id: sch.schedule_id.clone(),
                 };
                 if spec.requires_confirm {
-                    self.overlay = Overlay::Confirm(ConfirmAction::Operation(OperationConfirm {
+                    self.confirm_operation(OperationConfirm {
                         kind,
                         op: op_id,
                         target,
-                    }));
+                    });
                     vec![]
                 } else {
                     (effect_spec.to_effects)(&target, self)
@@ -1444,7 +4656,7 @@ impl App {
             }
             KindId::ActivityExecution => {
                 let Some(activity) = self.selected_activity_summary() else {
-                    self.last_error = Some(("no activity selected".to_string(), Instant::now()));
+                    self.push_error("no activity selected".to_string());
                     return vec![];
                 };
                 let target = OperationTarget::ActivityExecution {
@@ -1452,11 +4664,11 @@ impl App {
                     run_id: activity.run_id.clone(),
                 };
                 if spec.requires_confirm {
-                    self.overlay = Overlay::Confirm(ConfirmAction::Operation(OperationConfirm {
+                    self.confirm_operation(OperationConfirm {
                         kind,
                         op: op_id,
                         target,
-                    }));
+                    });
                     vec![]
                 } else {
                     (effect_spec.to_effects)(&target, self)
@@ -1465,151 +4677,1026 @@ impl App {
         }
     }
 
-    fn reset_backoff(&mut self) {
-        self.error_count = 0;
-        self.polling_interval = self.base_polling_interval;
+    /// The `[[typed_confirm]]` entry covering the current namespace.
+    fn typed_confirm_rule(&self) -> Option<&TypedConfirmConfig> {
+        self.typed_confirm
+            .iter()
+            .find(|c| c.namespace == self.namespace)
+            .or_else(|| self.typed_confirm.iter().find(|c| c.namespace == "*"))
     }
 
-    fn apply_backoff(&mut self) {
-        let multiplier = 2u64.pow(self.error_count.min(5));
-        let backoff_secs = self.base_polling_interval.as_secs() * multiplier;
-        self.polling_interval = Duration::from_secs(backoff_secs.min(60));
+    /// Opens `action` for confirmation, needing `phrase` typed first if set.
+    pub fn open_confirm(&mut self, action: ConfirmAction, phrase: Option<String>) {
+        self.confirm_phrase = phrase;
+        self.confirm_input.clear();
+        self.overlay = Overlay::Confirm(action);
     }
 
-    fn maybe_load_more(&mut self) -> Vec<Effect> {
-        match self.view {
-            View::Collection(KindId::WorkflowExecution) => {
-                if self.loading_more || self.next_page_token.is_empty() {
-                    return vec![];
-                }
-                if let Some(workflows) = self.workflows.data() {
-                    if let Some(selected) = self.workflow_table_state.selected() {
-                        if selected + 5 >= workflows.len() {
-                            self.loading_more = true;
-                            return vec![Effect::LoadMoreWorkflows];
-                        }
-                    }
-                }
+    /// Closes the open overlay, dropping any half-typed confirmation with it
+    /// so the next confirm doesn't inherit its phrase.
+    pub fn close_overlay(&mut self) {
+        self.overlay = Overlay::None;
+        self.confirm_phrase = None;
+        self.confirm_input.clear();
+    }
+
+    /// Opens the confirmation for `confirm`, asking for the target's id (or
+    /// the configured phrase) to be typed when the operation is destructive
+    /// and the namespace is in `[[typed_confirm]]`.
+    fn confirm_operation(&mut self, confirm: OperationConfirm) {
+        let destructive = operation_spec(confirm.kind, confirm.op).is_some_and(|s| s.destructive);
+        let phrase = self.typed_confirm_rule().filter(|_| destructive).map(|c| {
+            c.phrase.clone().unwrap_or_else(|| match &confirm.target {
+                OperationTarget::Workflow { workflow_id, .. } => workflow_id.clone(),
+                OperationTarget::Schedule { schedule_id } => schedule_id.clone(),
+                OperationTarget::ActivityExecution { activity_id, .. } => activity_id.clone(),
+            })
+        });
+        self.open_confirm(ConfirmAction::Operation(confirm), phrase);
+    }
+
+    /// Terminating in bulk asks for the workflow count typed, where a
+    /// single terminate would ask for the id.
+    fn confirm_batch(&mut self, confirm: BatchConfirm) {
+        let phrase = self
+            .typed_confirm_rule()
+            .filter(|_| confirm.operation == BatchOperation::Terminate)
+            .map(|c| {
+                c.phrase
+                    .clone()
+                    .unwrap_or_else(|| confirm.count.to_string())
+            });
+        self.open_confirm(ConfirmAction::Batch(confirm), phrase);
+    }
+
+    /// `:bulk cancel|terminate` — the marked workflows, or else everything
+    /// the current workflow query matches once the server has counted it.
+    fn start_bulk(&mut self, args: Option<&str>) -> Vec<Effect> {
+        let operation = match args {
+            Some("cancel") => BatchOperation::Cancel,
+            Some("terminate") => BatchOperation::Terminate,
+            _ => {
+                self.push_error("usage: :bulk <cancel|terminate>");
+                return vec![];
+            }
+        };
+        if self.view != View::Collection(KindId::WorkflowExecution) {
+            self.push_error("bulk operations work on the workflow list");
+            return vec![];
+        }
+        if !self.namespace_fanout.is_empty() {
+            self.push_error("bulk operations work on one namespace; leave :allns first");
+            return vec![];
+        }
+        if !self.capabilities.execution_admin {
+            self.push_error("bulk operations are not supported by this backend");
+            return vec![];
+        }
+        if !self.marked_workflows.is_empty() {
+            let marked = self.marked_workflows.clone();
+            self.confirm_batch(BatchConfirm {
+                namespace: self.namespace.clone(),
+                operation,
+                count: marked.len() as u64,
+                target: BatchTarget::Executions(marked),
+            });
+            return vec![];
+        }
+        match self.search_query_for_kind(KindId::WorkflowExecution) {
+            Some(query) => vec![Effect::CountBatch(operation, query)],
+            None => {
+                self.push_error(
+                    "mark workflows with m or search first; :bulk won't touch every workflow",
+                );
                 vec![]
             }
-            View::Collection(KindId::ActivityExecution) => {
-                if self.loading_more || self.activity_next_page_token.is_empty() {
-                    return vec![];
-                }
-                if let Some(activities) = self.activity_executions.data() {
-                    if let Some(selected) = self.activity_execution_table_state.selected() {
-                        if selected + 5 >= activities.len() {
-                            self.loading_more = true;
-                            return vec![Effect::LoadMoreActivityExecutions {
-                                namespace: self.namespace.clone(),
-                                query: self.search_query_for_kind(KindId::ActivityExecution),
-                                page_size: self.activity_page_size,
-                                next_page_token: self.activity_next_page_token.clone(),
-                            }];
-                        }
-                    }
+        }
+    }
+
+    /// Whether the open confirmation may be accepted: always, unless it
+    /// needs a phrase typed that doesn't match yet.
+    pub fn confirm_ready(&self) -> bool {
+        self.confirm_phrase
+            .as_deref()
+            .is_none_or(|phrase| phrase == self.confirm_input)
+    }
+
+    fn reset_backoff(&mut self) {
+        self.error_count = 0;
+    }
+
+    pub fn poll_target(&self) -> PollTarget {
+        if self.is_history_tab() {
+            PollTarget::History
+        } else if self.is_detail_view() {
+            PollTarget::Detail
+        } else {
+            PollTarget::List
+        }
+    }
+
+    /// Polling holds while a modal is open or a command, search or filter
+    /// is being typed, so rows don't move under a confirm, picker or cursor.
+    pub fn polling_active(&self) -> bool {
+        self.polling_enabled
+            && self.overlay == Overlay::None
+            && matches!(self.input_mode, InputMode::Normal | InputMode::PendingG)
+    }
+
+    /// The current view's poll interval, backed off after errors.
+    pub fn polling_interval(&self) -> Duration {
+        let base = self
+            .poll_intervals
+            .get(&self.poll_target())
+            .copied()
+            .unwrap_or(self.base_polling_interval);
+        if self.error_count == 0 {
+            return base;
+        }
+        let multiplier = 2u32.pow(self.error_count.min(5));
+        (base * multiplier).min(Duration::from_secs(60))
+    }
+
+    /// Time until the next poll, or `None` before the first load.
+    pub fn next_poll_in(&self) -> Option<Duration> {
+        let since = self.last_refresh.max(self.last_poll)?;
+        Some(self.polling_interval().saturating_sub(since.elapsed()))
+    }
+
+    /// `[poll_intervals]` from the config file, keyed by list, detail or
+    /// history.
+    pub fn apply_poll_config(&mut self, config: &HashMap<String, u64>) {
+        for (key, secs) in config {
+            match PollTarget::parse(key) {
+                Some(target) if *secs > 0 => {
+                    self.poll_intervals
+                        .insert(target, Duration::from_secs(*secs));
                 }
-                vec![]
+                Some(_) => self.push_error(format!(
+                    "config: poll interval for {} must be positive",
+                    key
+                )),
+                None => self.push_error(format!(
+                    "config: unknown view '{}' in [poll_intervals] (use list, detail or history)",
+                    key
+                )),
             }
-            _ => vec![],
         }
     }
 
-    fn page_height(&self) -> usize {
-        20 // approximate; could be made dynamic
+    /// The footer's position in `kind`'s list: cursor, rows shown, the
+    /// server's total and the pages left to load.
+    pub fn list_position(&self, kind: KindId) -> ListPosition {
+        let loaded = self.loaded_rows(kind);
+        let (state, total, page_size) = match kind {
+            KindId::WorkflowExecution => (
+                &self.workflow_table_state,
+                self.workflow_count,
+                self.page_size,
+            ),
+            KindId::Schedule => (&self.schedule_table_state, None, self.schedule_page_size),
+            KindId::ActivityExecution => (
+                &self.activity_execution_table_state,
+                self.activity_count,
+                self.activity_page_size,
+            ),
+        };
+        let more_pages = self.has_more_pages(kind);
+        let pages_left = match total {
+            Some(total) if more_pages && page_size > 0 => Some(
+                total
+                    .saturating_sub(loaded as u64)
+                    .div_ceil(page_size as u64),
+            ),
+            _ if !more_pages => Some(0),
+            _ => None,
+        };
+        ListPosition {
+            selected: state.selected().map_or(0, |i| i + 1),
+            shown: self.collection_len(kind),
+            total,
+            more_pages,
+            pages_left,
+        }
     }
-}
 
-fn workflow_tab_from_param(tab: &str) -> usize {
-    match tab.to_lowercase().as_str() {
-        "summary" => 0,
-        "io" | "input" | "output" | "input-output" | "input_output" => 1,
-        "history" => 2,
-        "pending" | "pending-activities" | "pending_activities" | "activities" => 3,
-        "task-queue" | "task_queue" | "taskqueue" => 4,
-        _ => 0,
+    /// Rows in `kind`'s table, after the local filter and grouping.
+    fn collection_len(&self, kind: KindId) -> usize {
+        match kind {
+            KindId::WorkflowExecution => self.visible_workflow_indices().len(),
+            KindId::Schedule => self.schedule_list_rows().len(),
+            KindId::ActivityExecution => self.activity_executions.data().map_or(0, Vec::len),
+        }
     }
-}
 
-fn activity_tab_from_param(tab: &str) -> usize {
-    match tab.to_lowercase().as_str() {
-        "summary" => 0,
-        "io" | "input" | "output" | "input-output" | "input_output" => 1,
-        "task-queue" | "task_queue" | "taskqueue" => 2,
-        _ => 0,
+    fn maybe_load_more(&mut self) -> Vec<Effect> {
+        let View::Collection(kind) = self.view else {
+            return vec![];
+        };
+        if self.loading_more || !self.has_more_pages(kind) {
+            return vec![];
+        }
+        let near_end = match kind {
+            // Past halfway, so the next page is usually in before the
+            // cursor reaches the bottom.
+            // Measured in loaded rows, so a narrow local filter does not
+            // keep fetching pages.
+            KindId::WorkflowExecution => self.workflows.data().is_some_and(|w| {
+                self.workflow_table_state
+                    .selected()
+                    .and_then(|s| self.visible_workflow_indices().get(s).copied())
+                    .is_some_and(|i| (i + 1) * 2 > w.len())
+            }),
+            KindId::Schedule => {
+                self.schedules.data().is_some()
+                    && self
+                        .schedule_table_state
+                        .selected()
+                        .is_some_and(|s| s + 5 >= self.schedule_list_rows().len())
+            }
+            KindId::ActivityExecution => self.activity_executions.data().is_some_and(|a| {
+                self.activity_execution_table_state
+                    .selected()
+                    .is_some_and(|s| s + 5 >= a.len())
+            }),
+        };
+        if near_end {
+            self.load_more(kind)
+        } else {
+            vec![]
+        }
     }
-}
 
-fn activity_tab_to_param(tab: usize) -> &'static str {
-    match tab {
-        0 => "summary",
-        1 => "io",
-        2 => "task-queue",
-        _ => "summary",
+    /// Rows of `kind` loaded so far, before the local filter.
+    fn loaded_rows(&self, kind: KindId) -> usize {
+        match kind {
+            KindId::WorkflowExecution => self.workflows.data().map_or(0, Vec::len),
+            KindId::Schedule => self.schedules.data().map_or(0, Vec::len),
+            KindId::ActivityExecution => self.activity_executions.data().map_or(0, Vec::len),
+        }
     }
-}
 
-fn combine_schedule_workflow_query(schedule_id: &str, extra: Option<&str>) -> String {
-    let base = format!(
-        "TemporalScheduledById = '{}'",
-        escape_single_quotes(schedule_id)
-    );
-    let Some(extra) = extra else {
-        return base;
-    };
+    fn has_more_pages(&self, kind: KindId) -> bool {
+        match kind {
+            KindId::WorkflowExecution => !self.next_page_token.is_empty(),
+            KindId::Schedule => !self.schedule_next_page_token.is_empty(),
+            KindId::ActivityExecution => !self.activity_next_page_token.is_empty(),
+        }
+    }
 
-    let trimmed = extra.trim();
-    if trimmed.is_empty() {
-        return base;
+    /// Requests the next page of `kind`'s list.
+    fn load_more(&mut self, kind: KindId) -> Vec<Effect> {
+        self.loading_more = true;
+        vec![match kind {
+            KindId::WorkflowExecution => Effect::LoadMoreWorkflows,
+            KindId::Schedule => Effect::LoadMoreSchedules,
+            KindId::ActivityExecution => Effect::LoadMoreActivityExecutions {
+                namespace: self.namespace.clone(),
+                query: self.search_query_for_kind(KindId::ActivityExecution),
+                page_size: self.activity_page_size,
+                next_page_token: self.activity_next_page_token.clone(),
+            },
+        }]
     }
 
-    format!("({}) AND ({})", base, trimmed)
-}
+    /// The payload under the cursor in the Input/Output or History tab, with
+    /// a label for it.
+    fn selected_payload(&self) -> Option<(String, Payload)> {
+        if self.is_io_tab() {
+            let row = self.io_tree_rows().into_iter().nth(self.io_tree.cursor)?;
+            let detail = self.selected_workflow.as_ref()?;
+            let (root, rest) = row.path.split_once('/').unwrap_or((&row.path, ""));
+            let payload = match root {
+                "Input" => self.masked_payload(detail.input.as_ref()),
+                "Output" => self.masked_payload(detail.output.as_ref()),
+                "Failure" => Payload::Json(self.masked(&detail.failure.as_ref()?.to_json())),
+                "Memo" => Payload::Json(self.masked_fields(&detail.memo)),
+                _ => Payload::Json(self.masked_fields(&detail.search_attributes)),
+            };
+            let payload = match payload {
+                Payload::Json(value) if !rest.is_empty() => {
+                    Payload::Json(value.pointer(&format!("/{}", rest))?.clone())
+                }
+                payload => payload,
+            };
+            Some((row.path.replace('/', "."), payload))
+        } else if self.is_history_tab() {
+            let event = self.workflow_history.data()?.get(self.history_cursor)?;
+            Some((
+                format!("#{} {}", event.event_id, event.event_type),
+                Payload::Json(self.masked(&event.details)),
+            ))
+        } else {
+            None
+        }
+    }
 
-fn escape_single_quotes(input: &str) -> String {
-    input.replace('\'', "\\'")
-}
+    /// The open workflow's decoded input or output as pretty JSON, or an
+    /// error toast when it has none.
+    fn payload_json(&mut self, side: PayloadSide) -> Option<String> {
+        let Some(detail) = self
+            .selected_workflow
+            .as_ref()
+            .filter(|_| self.view == View::Detail(KindId::WorkflowExecution))
+        else {
+            self.push_error(format!("open a workflow to copy its {}", side.label()));
+            return None;
+        };
+        let payload = match side {
+            PayloadSide::Input => detail.input.as_ref(),
+            PayloadSide::Output => detail.output.as_ref(),
+        };
+        match payload {
+            Some(payload) => {
+                Some(serde_json::to_string_pretty(&payload.to_json()).unwrap_or_default())
+            }
+            None => {
+                self.push_error(format!("the workflow has no {}", side.label()));
+                None
+            }
+        }
+    }
+
+    /// The current workflow tab's long text for the pager: the failure on
+    /// Summary, the selected payload on Input/Output, the whole history, or
+    /// the stack trace.
+    fn pager_text(&self) -> Option<(String, String)> {
+        if self.view == View::Detail(KindId::Schedule) {
+            let schedule = self.selected_schedule.as_ref()?;
+            if self.schedule_detail_tab != 1 {
+                return None;
+            }
+            let text = self.raw_text()?;
+            return Some((format!("{} raw", schedule.schedule_id), text));
+        }
+        let detail = self.selected_workflow.as_ref()?;
+        let id = &detail.summary.workflow_id;
+        match self.workflow_detail_tab {
+            0 => {
+                let text = failure_chain_text(detail.failure.as_ref()?, &self.mask_rules);
+                Some((format!("{} failure", id), text))
+            }
+            1 => {
+                let (label, payload) = self.selected_payload()?;
+                // Stack traces and other text read better unquoted.
+                let text = match payload {
+                    Payload::Binary(bytes) => {
+                        return Some((format!("{} {} hex", id, label), hexdump(&bytes)))
+                    }
+                    Payload::Json(serde_json::Value::String(s)) => s,
+                    Payload::Json(other) => {
+                        serde_json::to_string_pretty(&other).unwrap_or_default()
+                    }
+                };
+                Some((format!("{} {}", id, label), text))
+            }
+            2 => {
+                let mut text = String::new();
+                for e in self.workflow_history.data()? {
+                    text.push_str(&format!(
+                        "{:>4} {} {}\n",
+                        e.event_id,
+                        e.event_type,
+                        self.time_display.format(&e.timestamp)
+                    ));
+                    let metadata = &e.user_metadata;
+                    let details =
+                        serde_json::to_string_pretty(&self.masked(&e.details)).unwrap_or_default();
+                    for line in metadata
+                        .summary
+                        .iter()
+                        .chain(metadata.details.iter())
+                        .flat_map(|text| text.lines())
+                        .chain(details.lines())
+                    {
+                        text.push_str(&format!("       {}\n", line));
+                    }
+                }
+                Some((format!("{} history", id), text))
+            }
+            3 => {
+                let activity = self.selected_pending_activity()?;
+                let text = failure_chain_text(activity.last_failure.as_ref()?, &self.mask_rules);
+                Some((
+                    format!("{} activity {} failure", id, activity.activity_id),
+                    text,
+                ))
+            }
+            5 => {
+                let trace = self.stack_trace.data()?;
+                Some((format!("{} stack trace", id), trace.clone()))
+            }
+            7 => {
+                let text = self.raw_text()?;
+                Some((format!("{} raw", id), text))
+            }
+            8 => {
+                let metadata = self.workflow_metadata.data()?;
+                Some((format!("{} metadata", id), metadata_text(metadata)))
+            }
+            9 => {
+                let spans = timeline_spans(self.workflow_history.data()?);
+                let text = timeline_text(&spans, detail.summary.start_time);
+                Some((format!("{} timeline", id), text))
+            }
+            _ => None,
+        }
+    }
 
-fn format_uri_error(err: UriError) -> &'static str {
-    match err {
-        UriError::InvalidScheme => "invalid scheme",
-        UriError::InvalidAuthority => "invalid authority",
-        UriError::MissingNamespace => "missing namespace",
-        UriError::InvalidPath => "invalid path",
-        UriError::UnsupportedRoute => "unsupported route",
+    /// `T9S_*` variables describing the selection, for `:!` commands.
+    fn shell_env(&self) -> Vec<(String, String)> {
+        let namespace = self
+            .selected_workflow_summary()
+            .and_then(|wf| wf.namespace.clone())
+            .unwrap_or_else(|| self.namespace.clone());
+        let mut env = vec![("T9S_NAMESPACE".to_string(), namespace)];
+        if let Some(wf) = self.selected_workflow_summary() {
+            env.push(("T9S_WORKFLOW_ID".to_string(), wf.workflow_id.clone()));
+            env.push(("T9S_RUN_ID".to_string(), wf.run_id.clone()));
+        }
+        if let Some(schedule) = self.selected_schedule_summary() {
+            env.push(("T9S_SCHEDULE_ID".to_string(), schedule.schedule_id.clone()));
+        }
+        if let Some(activity) = self.selected_activity_summary() {
+            env.push(("T9S_ACTIVITY_ID".to_string(), activity.activity_id.clone()));
+            env.push(("T9S_RUN_ID".to_string(), activity.run_id.clone()));
+        }
+        env
     }
-}
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+    /// Queues the selected payload for the `[[pipes]]` command called `name`.
+    fn start_pipe(&mut self, name: &str) -> Vec<Effect> {
+        let Some(pipe) = self.pipes.iter().find(|p| p.name == name) else {
+            self.push_error(format!("unknown pipe {}", name));
+            return vec![];
+        };
+        let command = pipe.command.clone();
+        let Some((label, payload)) = self.selected_payload() else {
+            self.push_error("select a payload in the Input/Output or History tab to pipe");
+            return vec![];
+        };
+        vec![Effect::RunPipe(PipeJob {
+            title: format!("{} | {}", label, name),
+            command,
+            input: serde_json::to_string_pretty(&payload.to_json()).unwrap_or_default(),
+        })]
+    }
 
-    #[test]
-    fn apply_schedule_workflows_location_sets_query() {
-        let mut app = App::new("default".to_string());
-        app.selected_schedule = Some(Schedule {
-            schedule_id: "nightly".to_string(),
-            workflow_type: "SyncWorkflow".to_string(),
-            state: ScheduleState::Active,
-            spec_description: String::new(),
-            next_run: None,
-            recent_action_count: 0,
-            notes: String::new(),
+    /// Starts `:export [--all] [path]` for the list on screen.
+    fn start_export(&mut self, args: Option<&str>, overwrite: bool) -> Vec<Effect> {
+        let View::Collection(kind) = self.view else {
+            self.push_error("export works in list views");
+            return vec![];
+        };
+        let mut all = false;
+        let mut path = None;
+        for word in split_quoted(args.unwrap_or_default()) {
+            match word.as_str() {
+                "--all" | "-a" => all = true,
+                _ => path = Some(word),
+            }
+        }
+        let path = path.map(std::path::PathBuf::from).unwrap_or_else(|| {
+            format!(
+                "t9s-{}-{}.csv",
+                kind_spec(kind).label.to_lowercase(),
+                chrono::Local::now().format("%Y%m%d-%H%M%S")
+            )
+            .into()
         });
+        self.pending_export = Some(ExportJob {
+            kind,
+            path,
+            all,
+            overwrite,
+        });
+        self.continue_export()
+    }
 
-        let location = Location::new(
-            "default".to_string(),
-            vec![RouteSegment::Schedules(SchedulesRoute::Workflows {
-                schedule_id: "nightly".to_string(),
-                query: Some("ExecutionStatus = 'Failed'".to_string()),
-            })],
-        );
+    /// Whether an `--all` export of `kind` still has pages to load.
+    fn export_wants_more(&self, kind: KindId) -> bool {
+        self.has_more_pages(kind) && self.loaded_rows(kind) < EXPORT_ROW_CAP
+    }
 
-        let effects = app.apply_location(location);
+    /// Loads the next page for an `--all` export while there is one.
+    fn continue_export(&mut self) -> Vec<Effect> {
+        match &self.pending_export {
+            Some(job) if job.all && !self.loading_more && self.export_wants_more(job.kind) => {
+                self.load_more(job.kind)
+            }
+            _ => vec![],
+        }
+    }
 
-        assert!(matches!(
-            app.view,
-            View::Collection(KindId::WorkflowExecution)
+    /// Writes the pending export once its rows are all loaded.
+    fn ready_export(&mut self) -> Option<Effect> {
+        let job = self.pending_export.as_ref()?;
+        if job.all && (self.loading_more || self.export_wants_more(job.kind)) {
+            return None;
+        }
+        let job = self.pending_export.take()?;
+        let Some(mut table) = self.export_table(job.kind) else {
+            self.push_error("nothing to export until the list has loaded");
+            return None;
+        };
+        if job.all {
+            table.truncated = self.has_more_pages(job.kind) || table.rows.len() > EXPORT_ROW_CAP;
+            table.rows.truncate(EXPORT_ROW_CAP);
+        }
+        let exported_at = chrono::Utc::now().to_rfc3339_opts(chrono::SecondsFormat::Secs, true);
+        let mut done = format!(
+            "exported {} {} to {}",
+            table.rows.len(),
+            table.kind,
+            job.path.display()
+        );
+        if table.truncated {
+            done.push_str(" (row cap reached; narrow the query for the rest)");
+        }
+        Some(Effect::WriteFile(FileWrite {
+            contents: table.render(ExportFormat::for_path(&job.path), &exported_at),
+            path: job.path,
+            overwrite: job.overwrite,
+            command: "export",
+            done,
+        }))
+    }
+
+    /// The loaded rows of `kind` with raw values: full ids and RFC 3339
+    /// times.
+    fn export_table(&self, kind: KindId) -> Option<ExportTable> {
+        let time = |t: Option<&chrono::DateTime<chrono::Utc>>| {
+            t.map(|t| t.to_rfc3339()).unwrap_or_default()
+        };
+        let (headers, rows) = match kind {
+            KindId::WorkflowExecution => {
+                let workflows = self.workflows.data()?;
+                let rows = self
+                    .visible_workflow_indices()
+                    .into_iter()
+                    .map(|i| &workflows[i])
+                    .map(|wf| {
+                        let attributes: std::collections::BTreeMap<_, _> =
+                            wf.search_attributes.iter().collect();
+                        vec![
+                            wf.workflow_id.clone(),
+                            wf.run_id.clone(),
+                            wf.workflow_type.clone(),
+                            wf.status.as_str().to_string(),
+                            time(Some(&wf.start_time)),
+                            time(wf.close_time.as_ref()),
+                            wf.task_queue.clone(),
+                            serde_json::to_string(&attributes).unwrap_or_default(),
+                        ]
+                    })
+                    .collect();
+                (
+                    vec![
+                        "workflow_id",
+                        "run_id",
+                        "type",
+                        "status",
+                        "start_time",
+                        "close_time",
+                        "task_queue",
+                        "search_attributes",
+                    ],
+                    rows,
+                )
+            }
+            KindId::Schedule => {
+                let rows = self
+                    .schedules
+                    .data()?
+                    .iter()
+                    .map(|sch| {
+                        vec![
+                            sch.schedule_id.clone(),
+                            sch.workflow_type.clone(),
+                            sch.state.as_str().to_string(),
+                            sch.spec_description.clone(),
+                            time(sch.next_run.as_ref()),
+                            sch.recent_action_count.to_string(),
+                            sch.notes.clone(),
+                        ]
+                    })
+                    .collect();
+                (
+                    vec![
+                        "schedule_id",
+                        "workflow_type",
+                        "state",
+                        "spec",
+                        "next_run",
+                        "actions",
+                        "notes",
+                    ],
+                    rows,
+                )
+            }
+            KindId::ActivityExecution => {
+                let rows = self
+                    .activity_executions
+                    .data()?
+                    .iter()
+                    .map(|act| {
+                        vec![
+                            act.activity_id.clone(),
+                            act.run_id.clone(),
+                            act.activity_type.clone(),
+                            act.status.as_str().to_string(),
+                            time(act.schedule_time.as_ref()),
+                            time(act.close_time.as_ref()),
+                            act.task_queue.clone(),
+                        ]
+                    })
+                    .collect();
+                (
+                    vec![
+                        "activity_id",
+                        "run_id",
+                        "type",
+                        "status",
+                        "schedule_time",
+                        "close_time",
+                        "task_queue",
+                    ],
+                    rows,
+                )
+            }
+        };
+        Some(ExportTable {
+            kind: kind_spec(kind).label.to_lowercase(),
+            namespace: self.namespace.clone(),
+            query: self.search_query_for_kind(kind),
+            headers,
+            rows,
+            truncated: false,
+        })
+    }
+
+    fn page_height(&self) -> usize {
+        20 // approximate; could be made dynamic
+    }
+}
+
+/// Orders runs of rows with the same server sort key by id, so rows don't
+/// shuffle when the server returns ties in a different order. Rows with
+/// different keys keep the server's order.
+fn order_ties(workflows: &mut [WorkflowSummary]) {
+    for run in workflows.chunk_by_mut(|a, b| server_order(a, b) == std::cmp::Ordering::Equal) {
+        run.sort_by(|a, b| (&a.workflow_id, &a.run_id).cmp(&(&b.workflow_id, &b.run_id)));
+    }
+}
+
+/// The server's default visibility order: open runs first, then close time
+/// and start time, newest first.
+fn server_order(a: &WorkflowSummary, b: &WorkflowSummary) -> std::cmp::Ordering {
+    let key = |wf: &WorkflowSummary| (wf.close_time.is_none(), wf.close_time, wf.start_time);
+    key(b).cmp(&key(a))
+}
+
+/// Merges a refreshed first page into the loaded list. The page comes
+/// first, as the server ordered it; every loaded row it doesn't contain
+/// follows in its loaded order, keyed by workflow and run id, along with the
+/// token that continues after them. Comparing times would drop later-page
+/// rows whose start or close time sorts above the page's last row.
+fn merge_workflow_page(
+    existing: &[WorkflowSummary],
+    mut page: Vec<WorkflowSummary>,
+    page_token: Vec<u8>,
+    existing_token: &[u8],
+    ordered_by_query: bool,
+) -> (Vec<WorkflowSummary>, Vec<u8>) {
+    if !ordered_by_query {
+        order_ties(&mut page);
+    }
+    // An empty token means the page is the whole list.
+    if page_token.is_empty() {
+        return (page, page_token);
+    }
+    let keys: HashSet<(&str, &str)> = page
+        .iter()
+        .map(|wf| (wf.workflow_id.as_str(), wf.run_id.as_str()))
+        .collect();
+    let rest: Vec<WorkflowSummary> = existing
+        .iter()
+        .filter(|wf| !keys.contains(&(wf.workflow_id.as_str(), wf.run_id.as_str())))
+        .cloned()
+        .collect();
+    if rest.is_empty() {
+        return (page, page_token);
+    }
+    page.extend(rest);
+    (page, existing_token.to_vec())
+}
+
+/// Merge a polled first page of schedules into `existing`, keeping the rows
+/// loaded from later pages. The server lists schedules in a stable order, so
+/// those are the rows after the fresh page's last one.
+fn merge_schedule_page(
+    existing: &[Schedule],
+    mut page: Vec<Schedule>,
+    page_token: Vec<u8>,
+    existing_token: &[u8],
+) -> (Vec<Schedule>, Vec<u8>) {
+    let Some(last) = page.last().filter(|_| !page_token.is_empty()) else {
+        return (page, page_token);
+    };
+    let Some(pos) = existing
+        .iter()
+        .position(|s| s.schedule_id == last.schedule_id)
+    else {
+        return (page, page_token);
+    };
+    let ids: HashSet<&str> = page.iter().map(|s| s.schedule_id.as_str()).collect();
+    let later: Vec<Schedule> = existing[pos + 1..]
+        .iter()
+        .filter(|s| !ids.contains(s.schedule_id.as_str()))
+        .cloned()
+        .collect();
+    if later.is_empty() {
+        return (page, page_token);
+    }
+    page.extend(later);
+    (page, existing_token.to_vec())
+}
+
+/// Resolve the child workflow started by the history event at `idx`. Initiated
+/// events carry no run id, so it is taken from the matching started event.
+fn child_workflow_target(events: &[HistoryEvent], idx: usize) -> Option<(String, Option<String>)> {
+    let event = events.get(idx)?;
+    let is_child_start = matches!(
+        event.event_type.as_str(),
+        "StartChildWorkflowExecutionInitiated" | "ChildWorkflowExecutionStarted"
+    );
+    if !is_child_start {
+        return None;
+    }
+    let workflow_id = event.details.get("workflow_id")?.as_str()?.to_string();
+    let run_id = event
+        .details
+        .get("run_id")
+        .and_then(|v| v.as_str())
+        .map(str::to_string)
+        .or_else(|| {
+            events[idx..]
+                .iter()
+                .filter(|e| e.event_type == "ChildWorkflowExecutionStarted")
+                .find(|e| {
+                    e.details.get("workflow_id").and_then(|v| v.as_str())
+                        == Some(workflow_id.as_str())
+                })
+                .and_then(|e| e.details.get("run_id"))
+                .and_then(|v| v.as_str())
+                .map(str::to_string)
+        })
+        .filter(|run_id| !run_id.is_empty());
+    Some((workflow_id, run_id))
+}
+
+/// One line per timeline span: start offset, duration, outcome and name,
+/// with the critical path starred, then the activity hotspots.
+fn timeline_text(spans: &[TimelineSpan], start: chrono::DateTime<chrono::Utc>) -> String {
+    let now = chrono::Utc::now();
+    let mut text = String::new();
+    for span in spans {
+        let mut line = format!(
+            "{} +{:<10} {:<10} {:<10} {}",
+            if span.critical { "*" } else { " " },
+            format_span(span.start - start),
+            format_span(span.duration(now)),
+            span.outcome,
+            span.name
+        );
+        if span.attempts > 1 {
+            line.push_str(&format!(" ({} attempts)", span.attempts));
+        }
+        text.push_str(line.trim_end());
+        text.push('\n');
+    }
+    let hotspots = activity_hotspots(spans, now);
+    if !hotspots.is_empty() {
+        text.push_str("\nActivity type, count, total, average, max, retries, failed:\n");
+    }
+    for h in hotspots {
+        text.push_str(&format!(
+            "  {} {} {} {} {} {} {}\n",
+            h.activity_type,
+            h.count,
+            format_span(h.total),
+            format_span(h.average()),
+            format_span(h.max),
+            h.retries,
+            h.failed
+        ));
+    }
+    text
+}
+
+/// The Metadata tab as plain text for the pager.
+fn metadata_text(metadata: &WorkflowMetadata) -> String {
+    let mut text = String::new();
+    for (title, handlers) in [
+        ("Signals", &metadata.signals),
+        ("Queries", &metadata.queries),
+        ("Updates", &metadata.updates),
+    ] {
+        text.push_str(&format!("{}:\n", title));
+        for handler in handlers {
+            let name = if handler.name.is_empty() {
+                "(dynamic)"
+            } else {
+                &handler.name
+            };
+            text.push_str(&format!("  {}  {}\n", name, handler.description));
+        }
+        text.push('\n');
+    }
+    text.push_str("Current details:\n");
+    text.push_str(&metadata.current_details);
+    text.push('\n');
+    text
+}
+
+/// A failure and its causes, each with its stack trace and masked details.
+fn failure_chain_text(failure: &FailureInfo, rules: &[MaskRule]) -> String {
+    let mut text = String::new();
+    let mut failure = Some(failure);
+    while let Some(f) = failure {
+        if !text.is_empty() {
+            text.push_str("\nCaused by:\n");
+        }
+        text.push_str(&format!("{}: {}\n", f.failure_type, f.message));
+        if let Some(details) = &f.details {
+            text.push_str(&serde_json::to_string_pretty(&mask(details, rules)).unwrap_or_default());
+            text.push('\n');
+        }
+        if let Some(trace) = &f.stack_trace {
+            text.push_str(trace);
+            text.push('\n');
+        }
+        failure = f.cause.as_deref();
+    }
+    text
+}
+
+fn workflow_tab_from_param(tab: &str) -> usize {
+    match tab.to_lowercase().as_str() {
+        "summary" => 0,
+        "io" | "input" | "output" | "input-output" | "input_output" => 1,
+        "history" => 2,
+        "pending" | "pending-activities" | "pending_activities" | "activities" => 3,
+        "task-queue" | "task_queue" | "taskqueue" => 4,
+        "stack" | "stack-trace" | "stack_trace" | "stacktrace" => 5,
+        "children" | "child" => 6,
+        "raw" => 7,
+        "metadata" | "handlers" => 8,
+        "timeline" | "gantt" => 9,
+        _ => 0,
+    }
+}
+
+fn activity_tab_from_param(tab: &str) -> usize {
+    match tab.to_lowercase().as_str() {
+        "summary" => 0,
+        "io" | "input" | "output" | "input-output" | "input_output" => 1,
+        "task-queue" | "task_queue" | "taskqueue" => 2,
+        _ => 0,
+    }
+}
+
+fn activity_tab_to_param(tab: usize) -> &'static str {
+    match tab {
+        0 => "summary",
+        1 => "io",
+        2 => "task-queue",
+        _ => "summary",
+    }
+}
+
+fn combine_schedule_workflow_query(schedule_id: &str, extra: Option<&str>) -> String {
+    let base = format!(
+        "TemporalScheduledById = '{}'",
+        query_syntax::escape_single_quotes(schedule_id)
+    );
+    let Some(extra) = extra else {
+        return base;
+    };
+
+    let trimmed = extra.trim();
+    if trimmed.is_empty() {
+        return base;
+    }
+
+    format!("({}) AND ({})", base, trimmed)
+}
+
+/// Parses `:nsadmin` arguments: `register <name> <retention> [description]`,
+/// `retention <name> <retention>`, `describe <name> <description>` or
+/// `deprecate <name>`. Retention is a duration such as `30d`.
+/// Splits command arguments at whitespace, keeping text in single or double
+/// quotes together so paths can hold spaces.
+fn split_quoted(args: &str) -> Vec<String> {
+    let mut words = Vec::new();
+    let mut word: Option<String> = None;
+    let mut quote = None;
+    for c in args.chars() {
+        match (quote, c) {
+            (Some(q), c) if c == q => quote = None,
+            (Some(_), c) => word.get_or_insert_with(String::new).push(c),
+            (None, '"' | '\'') => {
+                quote = Some(c);
+                word.get_or_insert_with(String::new);
+            }
+            (None, c) if c.is_whitespace() => words.extend(word.take()),
+            (None, c) => word.get_or_insert_with(String::new).push(c),
+        }
+    }
+    words.extend(word);
+    words
+}
+
+fn parse_namespace_change(args: &str) -> Result<NamespaceChange, String> {
+    const USAGE: &str = "usage: :nsadmin register <name> <retention> [description] | \
+                         retention <name> <retention> | describe <name> <text> | deprecate <name>";
+    let mut words = args.splitn(3, ' ').map(str::trim);
+    let (Some(sub), Some(name)) = (words.next(), words.next().filter(|n| !n.is_empty())) else {
+        return Err(USAGE.to_string());
+    };
+    let name = name.to_string();
+    let rest = words.next().unwrap_or("");
+    let retention = |text: &str| {
+        parse_duration_text(text)
+            .filter(|secs| *secs > 0)
+            .map(|secs| Duration::from_secs(secs as u64))
+            .ok_or_else(|| format!("invalid retention '{}' (e.g. 30d or 72h)", text))
+    };
+    match sub {
+        "register" => {
+            let (period, description) = rest.split_once(' ').unwrap_or((rest, ""));
+            Ok(NamespaceChange::Register {
+                name,
+                retention: retention(period)?,
+                description: description.trim().to_string(),
+            })
+        }
+        "retention" => Ok(NamespaceChange::Update {
+            name,
+            retention: Some(retention(rest)?),
+            description: None,
+        }),
+        "describe" if !rest.is_empty() => Ok(NamespaceChange::Update {
+            name,
+            retention: None,
+            description: Some(rest.to_string()),
+        }),
+        "deprecate" if rest.is_empty() => Ok(NamespaceChange::Deprecate { name }),
+        _ => Err(USAGE.to_string()),
+    }
+}
+
+/// Choices in the time range picker, "All time" first.
+pub fn time_range_options() -> Vec<Option<TimeRange>> {
+    std::iter::once(None)
+        .chain(TimeRange::ALL.into_iter().map(Some))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn apply_schedule_workflows_location_sets_query() {
+        let mut app = App::new("default".to_string());
+        app.selected_schedule = Some(Schedule {
+            schedule_id: "nightly".to_string(),
+            workflow_type: "SyncWorkflow".to_string(),
+            state: ScheduleState::Active,
+            spec_description: String::new(),
+            next_run: None,
+            recent_action_count: 0,
+            notes: String::new(),
+            timezone: None,
+            calendars: vec![],
+            recent_actions: vec![],
+            upcoming_runs: vec![],
+        });
+
+        let location = Location::new(
+            "default".to_string(),
+            vec![RouteSegment::Schedules(SchedulesRoute::Workflows {
+                schedule_id: "nightly".to_string(),
+                query: Some("ExecutionStatus = 'Failed'".to_string()),
+            })],
+        );
+
+        let effects = app.apply_location(location);
+
+        assert!(matches!(
+            app.view,
+            View::Collection(KindId::WorkflowExecution)
         ));
         assert!(effects
             .iter()
@@ -1625,4 +5712,2731 @@ mod tests {
             "(TemporalScheduledById = 'nightly') AND (ExecutionStatus = 'Failed')"
         );
     }
+
+    fn schedule(id: &str, workflow_type: &str, state: ScheduleState) -> Schedule {
+        Schedule {
+            schedule_id: id.to_string(),
+            workflow_type: workflow_type.to_string(),
+            state,
+            spec_description: String::new(),
+            next_run: None,
+            recent_action_count: 0,
+            notes: String::new(),
+            timezone: None,
+            calendars: vec![],
+            recent_actions: vec![],
+            upcoming_runs: vec![],
+        }
+    }
+
+    #[test]
+    fn export_all_pages_in_the_rest_of_the_list_first() {
+        let mut app = App::new("default".to_string());
+        app.view = View::Collection(KindId::Schedule);
+        let page = |ids: std::ops::Range<usize>| -> Vec<Schedule> {
+            ids.map(|i| schedule(&format!("s{:02}", i), "Sync", ScheduleState::Active))
+                .collect()
+        };
+        app.update(Action::SchedulesLoaded(page(0..10), b"p2".to_vec()));
+
+        let effects = app.update(Action::SubmitCommandInput(
+            "export --all out.json".to_string(),
+        ));
+        assert!(matches!(effects.as_slice(), [Effect::LoadMoreSchedules]));
+
+        let effects = app.update(Action::MoreSchedulesLoaded(page(10..12), b"p3".to_vec()));
+        assert!(matches!(effects.as_slice(), [Effect::LoadMoreSchedules]));
+        let effects = app.update(Action::MoreSchedulesLoaded(page(12..13), vec![]));
+
+        let [Effect::WriteFile(write)] = &effects[..] else {
+            panic!("expected the export to be written, got {:?}", effects);
+        };
+        assert_eq!(write.path, std::path::PathBuf::from("out.json"));
+        assert!(!write.overwrite);
+        assert_eq!(write.command, "export");
+        assert_eq!(write.done, "exported 13 schedules to out.json");
+        let json: serde_json::Value = serde_json::from_str(&write.contents).unwrap();
+        assert_eq!(json["kind"], "schedules");
+        assert_eq!(json["rows"][12]["schedule_id"], "s12");
+        assert_eq!(json["truncated"], false);
+        assert!(app.pending_export.is_none());
+    }
+
+    #[test]
+    fn export_stops_paging_at_the_row_cap() {
+        let mut app = App::new("default".to_string());
+        app.view = View::Collection(KindId::Schedule);
+        let page = (0..EXPORT_ROW_CAP + 5)
+            .map(|i| schedule(&format!("s{}", i), "Sync", ScheduleState::Active))
+            .collect();
+        app.update(Action::SchedulesLoaded(page, b"more".to_vec()));
+
+        let effects = app.update(Action::SubmitCommandInput(
+            "export! -a \"incident 42/out file.csv\"".to_string(),
+        ));
+        let [Effect::WriteFile(write)] = &effects[..] else {
+            panic!("expected the export to be written, got {:?}", effects);
+        };
+        assert_eq!(
+            write.path,
+            std::path::PathBuf::from("incident 42/out file.csv")
+        );
+        assert!(write.overwrite);
+        assert!(write
+            .done
+            .contains(&format!("exported {} schedules", EXPORT_ROW_CAP)));
+        assert!(write.contents.contains("# truncated"));
+    }
+
+    #[test]
+    fn schedule_list_pages_in_and_polls_keep_later_pages() {
+        let mut app = App::new("default".to_string());
+        app.view = View::Collection(KindId::Schedule);
+        let page = |ids: std::ops::Range<usize>| -> Vec<Schedule> {
+            ids.map(|i| schedule(&format!("s{:02}", i), "Sync", ScheduleState::Active))
+                .collect()
+        };
+        app.update(Action::SchedulesLoaded(page(0..10), b"p2".to_vec()));
+
+        for _ in 0..4 {
+            assert!(app.update(Action::NavigateDown).is_empty());
+        }
+        let effects = app.update(Action::NavigateDown);
+        assert!(matches!(effects.as_slice(), [Effect::LoadMoreSchedules]));
+        app.update(Action::MoreSchedulesLoaded(page(10..15), vec![]));
+        assert_eq!(app.schedules.data().map(Vec::len), Some(15));
+        assert!(app.schedule_next_page_token.is_empty());
+
+        // A poll returns the first page again; the second page stays loaded.
+        app.update(Action::SchedulesLoaded(page(0..10), b"p2".to_vec()));
+        assert_eq!(app.schedules.data().map(Vec::len), Some(15));
+        assert!(app.schedule_next_page_token.is_empty());
+        assert_eq!(app.schedule_table_state.selected(), Some(5));
+    }
+
+    #[test]
+    fn schedule_search_reloads_with_query_from_the_top() {
+        let mut app = App::new("default".to_string());
+        app.view = View::Collection(KindId::Schedule);
+        app.update(Action::SchedulesLoaded(
+            vec![
+                schedule("a", "Sync", ScheduleState::Active),
+                schedule("b", "Backup", ScheduleState::Paused),
+            ],
+            vec![],
+        ));
+        app.update(Action::NavigateDown);
+
+        app.update(Action::OpenSearch);
+        assert_eq!(app.input_mode, InputMode::Search);
+        let effects = app.update(Action::SubmitSearch(
+            "TemporalSchedulePaused = true".to_string(),
+        ));
+        assert!(matches!(effects.as_slice(), [Effect::LoadSchedules]));
+        assert_eq!(
+            app.search_query_for_kind(KindId::Schedule).as_deref(),
+            Some("TemporalSchedulePaused = true")
+        );
+        assert_eq!(app.search_query_for_kind(KindId::WorkflowExecution), None);
+
+        app.update(Action::SchedulesLoaded(
+            vec![schedule("b", "Backup", ScheduleState::Paused)],
+            vec![],
+        ));
+        assert_eq!(app.schedule_table_state.selected(), Some(0));
+    }
+
+    #[test]
+    fn schedule_recent_action_opens_started_run() {
+        let mut app = App::new("default".to_string());
+        app.view = View::Detail(KindId::Schedule);
+        let action = |id: &str| ScheduleAction {
+            scheduled_time: None,
+            actual_time: None,
+            workflow_id: id.to_string(),
+            run_id: format!("{}-run", id),
+            status: Some(WorkflowStatus::Completed),
+        };
+        let mut detail = schedule("nightly", "Sync", ScheduleState::Active);
+        detail.recent_actions = vec![action("nightly-2"), action("nightly-1")];
+        app.update(Action::ScheduleDetailLoaded(Box::new(detail)));
+
+        app.update(Action::NavigateDown);
+        app.update(Action::NavigateDown);
+        assert_eq!(app.schedule_action_cursor, 1);
+        let effects = app.update(Action::Select);
+        assert_eq!(app.view, View::Detail(KindId::WorkflowExecution));
+        assert!(effects.iter().any(|e| matches!(
+            e,
+            Effect::LoadWorkflowDetail(wf, Some(run)) if wf == "nightly-1" && run == "nightly-1-run"
+        )));
+    }
+
+    #[test]
+    fn schedule_raw_tab_loads_the_describe_response() {
+        let mut app = App::new("default".to_string());
+        app.view = View::Detail(KindId::Schedule);
+        let mut detail = schedule("nightly", "Sync", ScheduleState::Active);
+        detail.recent_actions = vec![ScheduleAction {
+            scheduled_time: None,
+            actual_time: None,
+            workflow_id: "nightly-1".to_string(),
+            run_id: "r".to_string(),
+            status: None,
+        }];
+        app.update(Action::ScheduleDetailLoaded(Box::new(detail)));
+
+        let effects = app.update(Action::NextTab);
+        assert!(matches!(&effects[..], [Effect::LoadRawSchedule(id)] if id == "nightly"));
+        assert!(app.raw_detail.is_loading());
+        // Enter no longer opens a recent action from the Raw tab.
+        assert!(app.update(Action::Select).is_empty());
+
+        app.update(Action::RawDetailLoaded(Box::new(
+            serde_json::json!({ "schedule": {} }),
+        )));
+        app.update(Action::OpenPager);
+        assert_eq!(app.overlay, Overlay::Pager);
+        assert_eq!(app.pager.title, "nightly raw");
+    }
+
+    #[test]
+    fn pending_activity_opens_from_the_cursor_and_pages_its_failure() {
+        let mut app = App::new("default".to_string());
+        app.view = View::Detail(KindId::WorkflowExecution);
+        app.workflow_detail_tab = 3;
+        let activity = |id: &str, failure: Option<FailureInfo>| PendingActivity {
+            activity_id: id.to_string(),
+            activity_type: "Charge".to_string(),
+            state: PendingActivityState::Started,
+            attempt: 3,
+            maximum_attempts: 0,
+            scheduled_time: None,
+            last_started_time: None,
+            last_heartbeat_time: None,
+            next_attempt_time: None,
+            heartbeat_details: Some(serde_json::json!({"progress": 40})),
+            last_failure: failure,
+            last_worker_identity: "worker-1".to_string(),
+            options: None,
+        };
+        let mut detail = workflow_detail("order-1", "run-1");
+        detail.pending_activities = vec![
+            activity("1", None),
+            activity(
+                "2",
+                Some(FailureInfo {
+                    message: "card declined".to_string(),
+                    failure_type: "PaymentError".to_string(),
+                    stack_trace: Some("at charge()".to_string()),
+                    cause: Some(Box::new(FailureInfo {
+                        message: "timeout".to_string(),
+                        failure_type: "IOError".to_string(),
+                        stack_trace: None,
+                        cause: None,
+                        details: None,
+                    })),
+                    details: None,
+                }),
+            ),
+        ];
+        app.update(Action::WorkflowDetailLoaded(Box::new(detail)));
+
+        app.update(Action::NavigateDown);
+        app.update(Action::NavigateDown);
+        assert_eq!(app.pending_cursor, 1);
+        app.update(Action::Select);
+        assert_eq!(app.overlay, Overlay::PendingActivity);
+        assert_eq!(
+            app.selected_pending_activity()
+                .map(|a| a.activity_id.as_str()),
+            Some("2")
+        );
+
+        app.update(Action::OpenPager);
+        assert_eq!(app.overlay, Overlay::Pager);
+        assert_eq!(app.pager.title, "order-1 activity 2 failure");
+        let text: String = app.pager.lines.join("\n");
+        assert!(text.contains("PaymentError: card declined\nat charge()"));
+        assert!(text.contains("Caused by:\nIOError: timeout"));
+    }
+
+    #[test]
+    fn edited_activity_options_are_confirmed_then_sent() {
+        let mut app = App::new("default".to_string());
+        app.capabilities.execution_admin = true;
+        app.view = View::Detail(KindId::WorkflowExecution);
+        app.workflow_detail_tab = 3;
+        let options = ActivityOptionsEdit {
+            start_to_close_timeout: Some("30s".to_string()),
+            retry_backoff_coefficient: 2.0,
+            ..Default::default()
+        };
+        let mut detail = workflow_detail("order-1", "run-1");
+        detail.pending_activities = vec![PendingActivity {
+            activity_id: "7".to_string(),
+            activity_type: "Charge".to_string(),
+            state: PendingActivityState::Scheduled,
+            attempt: 2,
+            maximum_attempts: 0,
+            scheduled_time: None,
+            last_started_time: None,
+            last_heartbeat_time: None,
+            next_attempt_time: None,
+            heartbeat_details: None,
+            last_failure: None,
+            last_worker_identity: String::new(),
+            options: Some(options.clone()),
+        }];
+        app.update(Action::WorkflowDetailLoaded(Box::new(detail)));
+        app.update(Action::Select);
+        let effects = app.update(Action::EditActivityOptions);
+        let [Effect::EditActivityOptions(draft, text)] = &effects[..] else {
+            panic!("expected the editor to open, got {:?}", effects);
+        };
+        let (draft, text) = (draft.as_ref().clone(), text.clone());
+        assert_eq!(draft.run_id, "run-1");
+        assert_eq!(draft.edit, options);
+
+        let edited = |saved: &str| {
+            Action::ActivityOptionsEdited(Box::new(draft.clone()), text.clone(), Ok(saved.into()))
+        };
+        app.update(edited(&text));
+        assert_eq!(app.overlay, Overlay::PendingActivity);
+
+        // A rejected edit reopens with the error until it is left as it was.
+        let bad = text.replace("\"30s\"", "\"soon\"");
+        let effects = app.update(edited(&bad));
+        assert!(!matches!(app.overlay, Overlay::Confirm(_)));
+        let [Effect::EditActivityOptions(_, reopened)] = &effects[..] else {
+            panic!("expected the editor to reopen, got {:?}", effects);
+        };
+        assert!(reopened.contains("invalid start_to_close_timeout"));
+        assert!(reopened.ends_with(&bad));
+        let effects = app.update(Action::ActivityOptionsEdited(
+            Box::new(draft.clone()),
+            reopened.clone(),
+            Ok(reopened.clone()),
+        ));
+        assert!(effects.is_empty());
+        assert!(app.error_toasts[0]
+            .0
+            .contains("invalid start_to_close_timeout"));
+
+        let edited = text.replace("\"30s\"", "\"1m\"").replace(
+            "\"retry_maximum_attempts\": 0",
+            "\"retry_maximum_attempts\": 5",
+        );
+        app.update(Action::ActivityOptionsEdited(
+            Box::new(draft),
+            text,
+            Ok(edited),
+        ));
+        let Overlay::Confirm(ConfirmAction::ActivityOptions(draft, edit)) = &app.overlay else {
+            panic!("expected a confirmation, got {:?}", app.overlay);
+        };
+        assert_eq!(
+            edit.changed_fields(&draft.edit),
+            vec!["start_to_close_timeout", "retry_policy.maximum_attempts"]
+        );
+    }
+
+    #[test]
+    fn schedule_edit_round_trips_through_the_editor() {
+        let mut app = App::new("default".to_string());
+        app.capabilities.schedule_admin = true;
+        app.view = View::Collection(KindId::Schedule);
+        app.update(Action::SchedulesLoaded(
+            vec![schedule("nightly", "Sync", ScheduleState::Active)],
+            vec![],
+        ));
+        let effects = app.update(Action::RunOperation(OperationId::EditSchedule));
+        assert!(matches!(&effects[..], [Effect::LoadScheduleDraft(id)] if id == "nightly"));
+
+        let draft = ScheduleDraft {
+            schedule_id: "nightly".to_string(),
+            edit: ScheduleEdit {
+                cron: vec!["0 2 * * *".to_string()],
+                workflow_type: "Sync".to_string(),
+                workflow_id: "nightly".to_string(),
+                task_queue: "main".to_string(),
+                ..Default::default()
+            },
+            conflict_token: b"token".to_vec(),
+        };
+        let effects = app.update(Action::ScheduleDraftLoaded(Box::new(draft.clone())));
+        let [Effect::EditSchedule(draft, text)] = &effects[..] else {
+            panic!("expected the editor to open, got {:?}", effects);
+        };
+        let (draft, text) = (draft.as_ref().clone(), text.clone());
+        let edited = |opened: &str, saved: &str| {
+            Action::ScheduleEdited(Box::new(draft.clone()), opened.into(), Ok(saved.into()))
+        };
+
+        // Saving without changes sends nothing; a broken file is reopened
+        // with the error on top, and given up once saved as it was.
+        assert!(app.update(edited(&text, &text)).is_empty());
+        let effects = app.update(edited(&text, "{"));
+        let [Effect::EditSchedule(_, reopened)] = &effects[..] else {
+            panic!("expected the editor to reopen, got {:?}", effects);
+        };
+        let reopened = reopened.clone();
+        assert!(reopened.starts_with("// error: "));
+        assert!(app.error_toasts.is_empty());
+        assert!(app.update(edited(&reopened, &reopened)).is_empty());
+        assert_eq!(app.error_toasts.len(), 1);
+
+        // Fixing the reopened file goes through.
+        let fixed = reopened.replace('{', &text.replace("0 2 * * *", "0 3 * * *"));
+        let effects = app.update(edited(&reopened, &fixed));
+        assert!(matches!(
+            &effects[..],
+            [Effect::UpdateSchedule { schedule_id, edit, conflict_token }]
+                if schedule_id == "nightly"
+                    && edit.cron == ["0 3 * * *"]
+                    && conflict_token == b"token"
+        ));
+    }
+
+    #[test]
+    fn schedule_pause_prompts_for_a_note() {
+        let mut app = App::new("default".to_string());
+        app.capabilities.schedule_admin = true;
+        app.view = View::Collection(KindId::Schedule);
+        app.update(Action::SchedulesLoaded(
+            vec![schedule("nightly", "Sync", ScheduleState::Paused)],
+            vec![],
+        ));
+
+        assert!(app
+            .update(Action::RunOperation(OperationId::PauseSchedule))
+            .is_empty());
+        assert_eq!(app.input_mode, InputMode::Command);
+        assert_eq!(app.input_buffer, "unpause ");
+
+        let effects = app.update(Action::SubmitCommandInput(
+            "unpause upstream fixed".to_string(),
+        ));
+        assert!(matches!(
+            &effects[..],
+            [Effect::PauseSchedule(id, false, note)] if id == "nightly" && note == "upstream fixed"
+        ));
+    }
+
+    #[test]
+    fn search_attributes_overlay_lists_and_adds() {
+        let mut app = App::new("default".to_string());
+        let effects = app.update(Action::SubmitCommandInput("attr".to_string()));
+        assert!(matches!(&effects[..], [Effect::LoadSearchAttributes]));
+        assert_eq!(app.overlay, Overlay::SearchAttributes);
+
+        let attribute = |name: &str, custom| SearchAttribute {
+            name: name.to_string(),
+            value_type: Some(SearchAttributeType::Keyword),
+            custom,
+        };
+        app.update(Action::SearchAttributesLoaded(Ok(vec![
+            attribute("CustomerId", true),
+            attribute("WorkflowType", false),
+        ])));
+        app.update(Action::NavigateDown);
+        app.update(Action::NavigateDown);
+        assert_eq!(app.search_attribute_cursor, 1);
+
+        app.update(Action::PromptSearchAttribute);
+        assert_eq!(app.overlay, Overlay::None);
+        assert_eq!(app.input_buffer, "attributes add ");
+        let effects = app.update(Action::SubmitCommandInput(
+            "attributes add Region keywordlist".to_string(),
+        ));
+        assert!(matches!(
+            &effects[..],
+            [Effect::AddSearchAttribute(name, SearchAttributeType::KeywordList)] if name == "Region"
+        ));
+        assert_eq!(app.overlay, Overlay::SearchAttributes);
+
+        assert!(app
+            .update(Action::SubmitCommandInput(
+                "attr add Region Blob".to_string()
+            ))
+            .is_empty());
+        assert!(!app.error_toasts.is_empty());
+    }
+
+    #[test]
+    fn schedule_grouping_collapses_groups() {
+        let mut app = App::new("default".to_string());
+        app.view = View::Collection(KindId::Schedule);
+        app.update(Action::SchedulesLoaded(
+            vec![
+                schedule("a", "Sync", ScheduleState::Active),
+                schedule("b", "Backup", ScheduleState::Paused),
+                schedule("c", "Sync", ScheduleState::Paused),
+            ],
+            vec![],
+        ));
+
+        app.update(Action::ToggleScheduleGrouping);
+        assert_eq!(app.schedule_grouping, ScheduleGrouping::WorkflowType);
+        assert_eq!(
+            app.schedule_list_rows(),
+            vec![
+                ScheduleListRow::Group {
+                    key: "Backup".to_string(),
+                    count: 1,
+                    collapsed: false,
+                },
+                ScheduleListRow::Schedule(1),
+                ScheduleListRow::Group {
+                    key: "Sync".to_string(),
+                    count: 2,
+                    collapsed: false,
+                },
+                ScheduleListRow::Schedule(0),
+                ScheduleListRow::Schedule(2),
+            ]
+        );
+        assert!(app.selected_schedule_summary().is_none());
+
+        app.update(Action::Select);
+        assert_eq!(app.schedule_list_rows().len(), 4);
+        app.update(Action::NavigateDown);
+        assert_eq!(
+            app.selected_schedule_summary()
+                .map(|s| s.schedule_id.as_str()),
+            None
+        );
+        app.update(Action::NavigateDown);
+        assert_eq!(
+            app.selected_schedule_summary()
+                .map(|s| s.schedule_id.as_str()),
+            Some("a")
+        );
+    }
+
+    fn workflow_detail(workflow_id: &str, run_id: &str) -> WorkflowDetail {
+        WorkflowDetail {
+            summary: WorkflowSummary {
+                workflow_id: workflow_id.to_string(),
+                run_id: run_id.to_string(),
+                workflow_type: "TestWorkflow".to_string(),
+                status: WorkflowStatus::Running,
+                start_time: chrono::Utc::now(),
+                close_time: None,
+                task_queue: "default".to_string(),
+                search_attributes: HashMap::new(),
+                namespace: None,
+            },
+            input: None,
+            output: None,
+            failure: None,
+            history_length: 0,
+            parent: None,
+            root: None,
+            memo: HashMap::new(),
+            search_attributes: HashMap::new(),
+            pending_activities: vec![],
+            pending_children: vec![],
+            pending_nexus_operations: vec![],
+            versioning: None,
+            user_metadata: UserMetadata::default(),
+            execution_config: ExecutionConfig::default(),
+            retry_policy: None,
+            cron_schedule: None,
+        }
+    }
+
+    #[test]
+    fn poll_refresh_keeps_cursor_and_later_pages() {
+        let base = chrono::Utc::now();
+        let wf = |id: &str, age: i64| WorkflowSummary {
+            start_time: base - chrono::Duration::minutes(age),
+            ..workflow_detail(id, "r").summary
+        };
+        let mut app = App::new("default".to_string());
+        app.update(Action::WorkflowsLoaded(
+            vec![wf("a", 1), wf("b", 2)],
+            b"p2".to_vec(),
+        ));
+        app.update(Action::MoreWorkflowsLoaded(vec![wf("c", 3)], vec![]));
+        app.update(Action::NavigateDown);
+        assert_eq!(app.selected_workflow_row().unwrap().0, "b");
+
+        // A new run arrives at the top; the page no longer reaches "b".
+        app.update(Action::WorkflowsLoaded(
+            vec![wf("new", 0), wf("a", 1)],
+            b"p2'".to_vec(),
+        ));
+        let ids: Vec<&str> = app
+            .workflows
+            .data()
+            .unwrap()
+            .iter()
+            .map(|w| w.workflow_id.as_str())
+            .collect();
+        assert_eq!(ids, vec!["new", "a", "b", "c"]);
+        assert_eq!(app.selected_workflow_row().unwrap().0, "b");
+        assert!(app.next_page_token.is_empty());
+
+        // A different query replaces the list.
+        app.update(Action::SubmitSearch("WorkflowType='x'".to_string()));
+        app.update(Action::WorkflowsLoaded(vec![wf("x", 5)], vec![]));
+        assert_eq!(app.workflows.data().unwrap().len(), 1);
+    }
+
+    #[test]
+    fn poll_refresh_keeps_later_page_rows_that_sort_above_the_page() {
+        let base = chrono::Utc::now();
+        let wf = |id: &str, age: i64| WorkflowSummary {
+            start_time: base - chrono::Duration::minutes(age),
+            ..workflow_detail(id, "r").summary
+        };
+        let mut app = App::new("default".to_string());
+        app.update(Action::WorkflowsLoaded(
+            vec![wf("a", 10), wf("b", 20)],
+            b"p2".to_vec(),
+        ));
+        // Not every visibility store orders by start time, so a later page
+        // can hold a run that started after the first page's last row.
+        app.update(Action::MoreWorkflowsLoaded(
+            vec![wf("late", 5)],
+            b"p3".to_vec(),
+        ));
+
+        app.update(Action::WorkflowsLoaded(
+            vec![wf("a", 10), wf("b", 20)],
+            b"p2".to_vec(),
+        ));
+        let ids: Vec<&str> = app
+            .workflows
+            .data()
+            .unwrap()
+            .iter()
+            .map(|w| w.workflow_id.as_str())
+            .collect();
+        assert_eq!(ids, vec!["a", "b", "late"]);
+        assert_eq!(app.next_page_token, b"p3".to_vec());
+    }
+
+    fn history_event(event_id: i64, event_type: &str, details: serde_json::Value) -> HistoryEvent {
+        HistoryEvent {
+            event_id,
+            event_type: event_type.to_string(),
+            timestamp: chrono::Utc::now(),
+            details,
+            binary: vec![],
+            user_metadata: UserMetadata::default(),
+        }
+    }
+
+    #[test]
+    fn history_enter_opens_child_and_esc_returns_to_parent() {
+        let mut app = App::new("default".to_string());
+        app.update(Action::WorkflowDetailLoaded(Box::new(workflow_detail(
+            "parent",
+            "parent-run",
+        ))));
+        app.view = View::Detail(KindId::WorkflowExecution);
+        app.workflow_detail_tab = 2;
+        app.update(Action::HistoryLoaded(vec![
+            history_event(1, "WorkflowExecutionStarted", serde_json::json!({})),
+            history_event(
+                2,
+                "StartChildWorkflowExecutionInitiated",
+                serde_json::json!({ "workflow_id": "child" }),
+            ),
+            history_event(
+                3,
+                "ChildWorkflowExecutionStarted",
+                serde_json::json!({ "workflow_id": "child", "run_id": "child-run" }),
+            ),
+        ]));
+
+        app.update(Action::NavigateDown);
+        let effects = app.update(Action::Select);
+        assert!(effects.iter().any(|effect| matches!(
+            effect,
+            Effect::LoadWorkflowDetail(id, Some(run)) if id == "child" && run == "child-run"
+        )));
+        assert_eq!(
+            app.workflow_parents,
+            vec![WorkflowRef {
+                workflow_id: "parent".to_string(),
+                run_id: "parent-run".to_string(),
+            }]
+        );
+
+        let effects = app.update(Action::Back);
+        assert!(effects.iter().any(|effect| matches!(
+            effect,
+            Effect::LoadWorkflowDetail(id, _) if id == "parent"
+        )));
+        assert!(app.workflow_parents.is_empty());
+        assert_eq!(app.workflow_detail_tab, 2);
+    }
+
+    #[test]
+    fn workflows_keep_stable_order_across_refresh() {
+        let mut app = App::new("default".to_string());
+        let started = chrono::Utc::now();
+        let mut a = workflow_detail("a", "run-a").summary;
+        let mut b = workflow_detail("b", "run-b").summary;
+        let mut older = workflow_detail("older", "run-o").summary;
+        a.start_time = started;
+        b.start_time = started;
+        older.start_time = started - chrono::Duration::seconds(60);
+
+        app.update(Action::WorkflowsLoaded(
+            vec![b.clone(), a.clone(), older.clone()],
+            vec![],
+        ));
+        let ids = |app: &App| -> Vec<String> {
+            app.workflows
+                .data()
+                .unwrap()
+                .iter()
+                .map(|w| w.workflow_id.clone())
+                .collect()
+        };
+        assert_eq!(ids(&app), vec!["a", "b", "older"]);
+
+        app.update(Action::WorkflowsLoaded(vec![a, b, older], vec![]));
+        assert_eq!(ids(&app), vec!["a", "b", "older"]);
+    }
+
+    #[test]
+    fn workflows_keep_the_server_order() {
+        let mut app = App::new("default".to_string());
+        let started = chrono::Utc::now();
+        let mut running = workflow_detail("running", "run-r").summary;
+        let mut closed = workflow_detail("closed", "run-c").summary;
+        running.start_time = started - chrono::Duration::seconds(60);
+        closed.start_time = started;
+        closed.close_time = Some(started);
+        let ids = |app: &App| -> Vec<String> {
+            app.workflows
+                .data()
+                .unwrap()
+                .iter()
+                .map(|w| w.workflow_id.clone())
+                .collect()
+        };
+
+        // The default order puts the open run first though it started earlier.
+        app.update(Action::WorkflowsLoaded(
+            vec![running.clone(), closed.clone()],
+            vec![],
+        ));
+        assert_eq!(ids(&app), vec!["running", "closed"]);
+
+        // An ORDER BY is the server's to apply, ties and all.
+        app.search_queries.insert(
+            KindId::WorkflowExecution,
+            "ORDER BY WorkflowId DESC".to_string(),
+        );
+        let mut b = closed.clone();
+        b.workflow_id = "b".to_string();
+        let mut a = closed.clone();
+        a.workflow_id = "a".to_string();
+        app.update(Action::WorkflowsLoaded(vec![b, a, running], vec![]));
+        assert_eq!(ids(&app), vec!["b", "a", "running"]);
+    }
+
+    #[test]
+    fn error_toasts_queue_and_dismiss_in_order() {
+        let mut app = App::new("default".to_string());
+        app.update(Action::Error("first".to_string()));
+        app.update(Action::Error("second".to_string()));
+        assert_eq!(app.error_toasts.len(), 2);
+        assert_eq!(app.error_log.len(), 2);
+
+        app.update(Action::FocusError);
+        assert!(app.toast_focused);
+        app.error_toasts[0].1 = Instant::now() - Duration::from_secs(60);
+        app.update(Action::Tick);
+        assert_eq!(app.error_toasts.front().unwrap().0, "first");
+
+        app.update(Action::Back);
+        assert_eq!(app.error_toasts.front().unwrap().0, "second");
+        app.update(Action::Back);
+        assert!(app.error_toasts.is_empty());
+        assert!(!app.toast_focused);
+        assert_eq!(app.error_log.len(), 2);
+    }
+
+    #[test]
+    fn io_tree_folds_and_search_reveals_matches() {
+        let mut app = App::new("default".to_string());
+        let mut detail = workflow_detail("wf", "run");
+        detail.input = Some(Payload::Json(serde_json::json!({
+            "order": { "customer": { "id": "c-1" }, "items": [1, 2] }
+        })));
+        app.update(Action::WorkflowDetailLoaded(Box::new(detail)));
+        app.view = View::Detail(KindId::WorkflowExecution);
+        app.workflow_detail_tab = 1;
+
+        let keys =
+            |app: &App| -> Vec<String> { app.io_tree_rows().into_iter().map(|r| r.key).collect() };
+        assert_eq!(
+            keys(&app),
+            vec!["Input", "order", "customer", "items", "Output"]
+        );
+
+        app.update(Action::NavigateDown);
+        app.update(Action::NavigateDown);
+        app.update(Action::Select);
+        assert_eq!(
+            keys(&app),
+            vec!["Input", "order", "customer", "id", "items", "Output"]
+        );
+        app.update(Action::ToggleFold);
+        assert_eq!(keys(&app).len(), 5);
+
+        app.update(Action::SubmitSearch("C-1".to_string()));
+        assert_eq!(app.io_tree_rows()[app.io_tree.cursor].key, "id");
+    }
+
+    #[test]
+    fn retry_policy_and_cron_come_from_the_started_event() {
+        let mut app = App::new("default".to_string());
+        app.update(Action::WorkflowDetailLoaded(Box::new(workflow_detail(
+            "wf", "run",
+        ))));
+        app.update(Action::HistoryLoaded(vec![history_event(
+            1,
+            "WorkflowExecutionStarted",
+            serde_json::json!({
+                "cron_schedule": "0 * * * *",
+                "retry_policy": {
+                    "initial_interval": "1.5s",
+                    "backoff_coefficient": 2.0,
+                    "maximum_attempts": "5",
+                    "non_retryable_error_types": ["InvalidOrder"],
+                },
+            }),
+        )]));
+        // A describe refresh keeps what history provided.
+        app.update(Action::WorkflowDetailLoaded(Box::new(workflow_detail(
+            "wf", "run",
+        ))));
+
+        let detail = app.selected_workflow.as_ref().unwrap();
+        assert_eq!(detail.cron_schedule.as_deref(), Some("0 * * * *"));
+        let policy = detail.retry_policy.as_ref().unwrap();
+        assert_eq!(
+            policy.initial_interval,
+            Some(std::time::Duration::from_millis(1500))
+        );
+        assert_eq!(policy.maximum_interval, None);
+        assert_eq!(policy.maximum_attempts, 5);
+        assert_eq!(policy.non_retryable_error_types, ["InvalidOrder"]);
+    }
+
+    #[test]
+    fn failure_cause_chain_unfolds_in_the_io_tree() {
+        let mut app = App::new("default".to_string());
+        app.update(Action::WorkflowDetailLoaded(Box::new(workflow_detail(
+            "wf", "run",
+        ))));
+        app.view = View::Detail(KindId::WorkflowExecution);
+        app.workflow_detail_tab = 1;
+        let failure = serde_json::json!({
+            "message": "activity error",
+            "source": "GoSDK",
+            "kind": "activity",
+            "activity_id": "3",
+            "cause": {
+                "message": "card declined",
+                "type": "PaymentError",
+                "kind": "application",
+                "details": {"code": 51},
+                "stack_trace": "at charge()",
+                "cause": {"message": "socket closed", "source": "GoSDK"},
+            },
+        });
+        app.update(Action::HistoryLoaded(vec![history_event(
+            9,
+            "WorkflowExecutionFailed",
+            serde_json::json!({ "failure": failure }),
+        )]));
+
+        let cause = app
+            .selected_workflow
+            .as_ref()
+            .unwrap()
+            .failure
+            .as_ref()
+            .unwrap()
+            .cause
+            .clone()
+            .unwrap();
+        assert_eq!(cause.failure_type, "PaymentError");
+        assert_eq!(
+            cause.details,
+            Some(serde_json::json!({"kind": "application", "details": {"code": 51}}))
+        );
+
+        let row = |app: &App, path: &str| app.io_tree_rows().iter().position(|r| r.path == path);
+        assert!(row(&app, "Failure/cause/details").is_some());
+        assert!(row(&app, "Failure/cause/cause/message").is_none());
+        app.io_tree.cursor = row(&app, "Failure/cause/cause").unwrap();
+        app.update(Action::Select);
+        assert!(row(&app, "Failure/cause/cause/message").is_some());
+
+        app.io_tree.cursor = row(&app, "Failure/cause/stack_trace").unwrap();
+        app.update(Action::OpenPager);
+        assert_eq!(app.pager.lines, ["at charge()"]);
+    }
+
+    #[test]
+    fn mask_rules_hide_payload_values_on_screen_only() {
+        let mut app = App::new("default".to_string());
+        app.apply_config(&ConfigFile {
+            mask: vec![
+                crate::config::MaskConfig {
+                    pointer: None,
+                    key: Some("(?i)email".to_string()),
+                    replacement: None,
+                },
+                crate::config::MaskConfig {
+                    pointer: Some("no-slash".to_string()),
+                    key: None,
+                    replacement: None,
+                },
+            ],
+            ..ConfigFile::default()
+        });
+        assert_eq!(app.mask_rules.len(), 1);
+        assert_eq!(app.error_toasts.len(), 1);
+
+        let mut detail = workflow_detail("wf", "run");
+        detail.input = Some(Payload::Json(
+            serde_json::json!({ "email": "a@example.com" }),
+        ));
+        app.update(Action::WorkflowDetailLoaded(Box::new(detail)));
+        app.view = View::Detail(KindId::WorkflowExecution);
+        app.workflow_detail_tab = 1;
+
+        let rows = app.io_tree_rows();
+        let email = rows.iter().find(|row| row.path == "Input/email").unwrap();
+        assert!(matches!(&email.value, json_tree::RowValue::Scalar(v) if v == "***"));
+        let effects = app.update(Action::CopyPayload(PayloadSide::Input));
+        assert!(
+            matches!(&effects[..], [Effect::CopyToClipboard(json)] if json.contains("a@example.com"))
+        );
+    }
+
+    #[test]
+    fn mask_rules_cover_every_tab_that_draws_payloads() {
+        let mut app = App::new("default".to_string());
+        app.apply_config(&ConfigFile {
+            mask: vec![crate::config::MaskConfig {
+                pointer: None,
+                key: Some("(?i)email".to_string()),
+                replacement: None,
+            }],
+            ..ConfigFile::default()
+        });
+        let secret = serde_json::json!({ "email": "a@example.com" });
+        let hidden = |text: String| !text.contains("a@example.com") && text.contains("***");
+
+        let mut detail = workflow_detail("wf", "run");
+        detail.memo.insert("owner".to_string(), secret.clone());
+        detail
+            .search_attributes
+            .insert("Email".to_string(), "a@example.com".into());
+        detail.failure = Some(FailureInfo {
+            message: "boom".to_string(),
+            failure_type: "ApplicationFailure".to_string(),
+            stack_trace: None,
+            cause: None,
+            details: Some(serde_json::json!({ "details": [secret.clone()] })),
+        });
+        detail.user_metadata.details = Some(secret.to_string());
+        app.update(Action::WorkflowDetailLoaded(Box::new(detail)));
+        app.view = View::Detail(KindId::WorkflowExecution);
+        let detail = app.selected_workflow.clone().unwrap();
+
+        // Summary: memo, search attributes and user metadata.
+        assert!(hidden(app.masked_fields(&detail.memo).to_string()));
+        assert!(hidden(
+            app.masked_fields(&detail.search_attributes).to_string()
+        ));
+        assert!(hidden(
+            app.masked_text(detail.user_metadata.details.as_deref().unwrap())
+        ));
+        // The failure in the pager and under the I/O tree's Failure root.
+        app.update(Action::OpenPager);
+        assert!(hidden(app.pager.lines.join("\n")));
+        app.overlay = Overlay::None;
+        app.workflow_detail_tab = 1;
+        app.io_tree.cursor = app
+            .io_tree_rows()
+            .iter()
+            .position(|row| row.path == "Failure")
+            .unwrap();
+        app.update(Action::OpenPager);
+        assert!(hidden(app.pager.lines.join("\n")));
+        // Raw.
+        app.update(Action::RawDetailLoaded(Box::new(serde_json::json!({
+            "workflowExecutionInfo": { "memo": secret.clone() },
+        }))));
+        assert!(hidden(app.raw_text().unwrap()));
+    }
+
+    #[test]
+    fn pipe_sends_the_payload_under_the_cursor() {
+        let mut app = App::new("default".to_string());
+        let mut detail = workflow_detail("wf", "run");
+        detail.input = Some(Payload::Json(
+            serde_json::json!({ "order": { "id": "o-1" } }),
+        ));
+        app.update(Action::WorkflowDetailLoaded(Box::new(detail)));
+        app.view = View::Detail(KindId::WorkflowExecution);
+        app.workflow_detail_tab = 1;
+
+        assert!(app.update(Action::PipePayload).is_empty());
+        assert_eq!(app.error_toasts.len(), 1);
+
+        app.pipes = vec![PipeConfig {
+            name: "jq".to_string(),
+            command: "jq .".to_string(),
+        }];
+        app.update(Action::NavigateDown);
+        let effects = app.update(Action::PipePayload);
+        let [Effect::RunPipe(job)] = &effects[..] else {
+            panic!("expected the pipe to run, got {:?}", effects);
+        };
+        let job = job.clone();
+        assert_eq!(job.title, "Input.order | jq");
+        assert_eq!(job.command, "jq .");
+        assert_eq!(
+            serde_json::from_str::<serde_json::Value>(&job.input).unwrap(),
+            serde_json::json!({ "id": "o-1" })
+        );
+
+        app.update(Action::PipeFinished(job.title, Ok("a\nb\n".to_string())));
+        assert_eq!(app.overlay, Overlay::Pager);
+        assert_eq!(app.pager.lines, vec!["a", "b"]);
+    }
+
+    #[test]
+    fn pager_shows_binary_payloads_as_a_hexdump() {
+        let mut app = App::new("default".to_string());
+        let mut detail = workflow_detail("wf", "run");
+        detail.input = Some(Payload::Binary(vec![0xde, 0xad, b'o', b'k']));
+        app.update(Action::WorkflowDetailLoaded(Box::new(detail)));
+        app.view = View::Detail(KindId::WorkflowExecution);
+        app.workflow_detail_tab = 1;
+
+        app.update(Action::OpenPager);
+        assert_eq!(app.pager.title, "wf Input hex");
+        assert_eq!(
+            app.pager.lines,
+            ["00000000  de ad 6f 6b                                       |..ok|"]
+        );
+        assert_eq!(app.io_tree_rows()[0].value, json_tree::RowValue::Binary(4));
+        // Copied, the bytes are base64 without any label around them.
+        let effects = app.update(Action::CopyPayload(PayloadSide::Input));
+        assert!(matches!(&effects[..], [Effect::CopyToClipboard(json)] if json == "\"3q1vaw==\""));
+
+        // A string that only looks like a binary label stays a string.
+        let mut detail = workflow_detail("wf", "run");
+        detail.input = Some(Payload::Json(serde_json::json!("<binary 2 bytes:AAA=>")));
+        app.update(Action::WorkflowDetailLoaded(Box::new(detail)));
+        app.update(Action::OpenPager);
+        assert_eq!(app.pager.title, "wf Input");
+    }
+
+    #[test]
+    fn input_and_output_copy_and_save_as_json() {
+        let mut app = App::new("default".to_string());
+        let mut detail = workflow_detail("wf", "run");
+        detail.input = Some(Payload::Json(serde_json::json!({ "order": "o-1" })));
+        app.update(Action::WorkflowDetailLoaded(Box::new(detail)));
+        app.view = View::Detail(KindId::WorkflowExecution);
+
+        assert!(app
+            .update(Action::CopyPayload(PayloadSide::Input))
+            .is_empty());
+        app.workflow_detail_tab = 1;
+        let effects = app.update(Action::CopyPayload(PayloadSide::Input));
+        let expected = "{\n  \"order\": \"o-1\"\n}";
+        assert!(matches!(&effects[..], [Effect::CopyToClipboard(json)] if json == expected));
+
+        let effects = app.update(Action::SubmitCommandInput("save-input in.json".to_string()));
+        let [Effect::WriteFile(write)] = &effects[..] else {
+            panic!("expected the input to be saved, got {:?}", effects);
+        };
+        assert_eq!(write.path, std::path::PathBuf::from("in.json"));
+        assert_eq!(write.contents, expected);
+        assert!(!write.overwrite);
+        assert_eq!(write.command, "save-input");
+
+        let effects = app.update(Action::SubmitCommandInput(
+            "save-input! 'my input.json'".to_string(),
+        ));
+        let [Effect::WriteFile(write)] = &effects[..] else {
+            panic!("expected the input to be saved, got {:?}", effects);
+        };
+        assert_eq!(write.path, std::path::PathBuf::from("my input.json"));
+        assert!(write.overwrite);
+
+        app.error_toasts.clear();
+        let effects = app.update(Action::SubmitCommandInput(
+            "save-output out.json".to_string(),
+        ));
+        assert!(effects.is_empty());
+        assert_eq!(app.error_toasts.len(), 1);
+    }
+
+    #[test]
+    fn metadata_tab_queries_running_workflows_for_their_handlers() {
+        let mut app = App::new("default".to_string());
+        app.view = View::Detail(KindId::WorkflowExecution);
+        app.workflow_detail_tab = 7;
+        app.update(Action::WorkflowDetailLoaded(Box::new(workflow_detail(
+            "order-1", "run-1",
+        ))));
+        let effects = app.update(Action::NextTab);
+        assert!(matches!(
+            &effects[..],
+            [Effect::LoadWorkflowMetadata(id, Some(run))] if id == "order-1" && run == "run-1"
+        ));
+        assert!(app.workflow_metadata.is_loading());
+
+        let metadata = WorkflowMetadata::from_json(&serde_json::json!({
+            "definition": {
+                "type": "OrderWorkflow",
+                "signalDefinitions": [{"name": "approve", "description": "Approve the order"}],
+                "query_definitions": [{"name": "status"}, {"name": ""}],
+            },
+            "currentDetails": "waiting for approval",
+        }));
+        assert_eq!(metadata.workflow_type, "OrderWorkflow");
+        assert_eq!(metadata.signals[0].description, "Approve the order");
+        assert_eq!(metadata.queries.len(), 2);
+        assert!(metadata.updates.is_empty());
+        app.update(Action::WorkflowMetadataLoaded(Box::new(metadata)));
+
+        app.update(Action::OpenPager);
+        assert_eq!(app.pager.title, "order-1 metadata");
+        assert!(app.pager.lines.iter().any(|l| l.contains("(dynamic)")));
+        assert!(app.pager.lines.iter().any(|l| l == "waiting for approval"));
+    }
+
+    #[test]
+    fn timeline_pairs_close_events_and_marks_the_critical_path() {
+        let at = |secs: i64, event: HistoryEvent| HistoryEvent {
+            timestamp: chrono::DateTime::from_timestamp(1_700_000_000 + secs, 0).unwrap(),
+            ..event
+        };
+        use serde_json::json;
+        let events = vec![
+            at(
+                0,
+                history_event(
+                    5,
+                    "ActivityTaskScheduled",
+                    json!({"activity_type": "Charge", "activity_id": "1"}),
+                ),
+            ),
+            at(
+                0,
+                history_event(6, "TimerStarted", json!({"timer_id": "t1"})),
+            ),
+            // The HTTP API sends int64 fields as strings.
+            at(
+                40,
+                history_event(
+                    9,
+                    "ActivityTaskStarted",
+                    json!({"scheduled_event_id": "5", "attempt": 3}),
+                ),
+            ),
+            at(
+                45,
+                history_event(
+                    10,
+                    "ActivityTaskCompleted",
+                    json!({"scheduled_event_id": "5"}),
+                ),
+            ),
+            at(
+                50,
+                history_event(11, "TimerFired", json!({"timer_id": "t1"})),
+            ),
+            at(
+                50,
+                history_event(
+                    14,
+                    "StartChildWorkflowExecutionInitiated",
+                    json!({"workflow_type": "Ship", "workflow_id": "ship-1"}),
+                ),
+            ),
+            at(
+                60,
+                history_event(
+                    16,
+                    "ChildWorkflowExecutionFailed",
+                    json!({"initiated_event_id": 14}),
+                ),
+            ),
+        ];
+        let spans = timeline_spans(&events);
+        assert_eq!(spans.len(), 3);
+        assert_eq!(spans[0].name, "Charge (1)");
+        assert_eq!(
+            (spans[0].attempts, spans[0].outcome.as_str()),
+            (3, "Completed")
+        );
+        assert_eq!(spans[1].outcome, "Fired");
+        assert_eq!(spans[2].name, "Ship (ship-1)");
+        assert_eq!(spans[2].outcome, "Failed");
+        // The timer, not the shorter activity, held up the child.
+        let critical: Vec<bool> = spans.iter().map(|s| s.critical).collect();
+        assert_eq!(critical, [false, true, true]);
+
+        let mut app = App::new("default".to_string());
+        app.update(Action::WorkflowDetailLoaded(Box::new(workflow_detail(
+            "order-1", "run-1",
+        ))));
+        app.view = View::Detail(KindId::WorkflowExecution);
+        app.workflow_detail_tab = 9;
+        app.update(Action::HistoryLoaded(events));
+        app.update(Action::OpenPager);
+        assert_eq!(app.pager.title, "order-1 timeline");
+        assert!(app.pager.lines[0].ends_with("Charge (1) (3 attempts)"));
+        assert!(app.pager.lines[2].starts_with('*'));
+    }
+
+    #[test]
+    fn history_pager_includes_event_user_metadata() {
+        let mut app = App::new("default".to_string());
+        app.update(Action::WorkflowDetailLoaded(Box::new(workflow_detail(
+            "order-1", "run-1",
+        ))));
+        app.view = View::Detail(KindId::WorkflowExecution);
+        app.workflow_detail_tab = 2;
+        let mut timer = history_event(5, "TimerStarted", serde_json::json!({}));
+        timer.user_metadata = UserMetadata {
+            summary: Some("Wait for payment".to_string()),
+            details: Some("Retries after\nten minutes".to_string()),
+        };
+        app.update(Action::HistoryLoaded(vec![timer]));
+
+        app.update(Action::OpenPager);
+        let lines: Vec<&str> = app.pager.lines.iter().map(|l| l.trim()).collect();
+        assert_eq!(
+            lines[1..4],
+            ["Wait for payment", "Retries after", "ten minutes"]
+        );
+    }
+
+    #[test]
+    fn pager_searches_the_stack_trace() {
+        let mut app = App::new("default".to_string());
+        app.update(Action::WorkflowDetailLoaded(Box::new(workflow_detail(
+            "wf", "run",
+        ))));
+        app.view = View::Detail(KindId::WorkflowExecution);
+        app.workflow_detail_tab = 5;
+        app.stack_trace =
+            LoadState::Loaded("main.go:10\nworker.Poll\nmain.go:42\nruntime.goexit".to_string());
+
+        app.update(Action::OpenPager);
+        assert_eq!(app.overlay, Overlay::Pager);
+        assert_eq!(app.pager.percent(2), 50);
+
+        app.update(Action::OpenSearch);
+        assert_eq!(app.input_mode, InputMode::Search);
+        app.update(Action::SubmitSearch("MAIN.go".to_string()));
+        assert_eq!(app.pager.scroll, 0);
+        app.update(Action::NextMatch);
+        assert_eq!(app.pager.scroll, 2);
+        assert_eq!(app.pager.percent(2), 100);
+        app.update(Action::NextMatch);
+        assert_eq!(app.pager.scroll, 0);
+        app.update(Action::PrevMatch);
+        assert_eq!(app.pager.scroll, 2);
+
+        app.update(Action::OpenSearch);
+        app.update(Action::CloseOverlay);
+        assert_eq!(app.overlay, Overlay::Pager);
+        app.update(Action::CloseOverlay);
+        assert_eq!(app.overlay, Overlay::None);
+    }
+
+    #[test]
+    fn shell_command_gets_the_selected_workflow() {
+        let mut app = App::new("prod".to_string());
+        app.update(Action::WorkflowDetailLoaded(Box::new(workflow_detail(
+            "order-1", "run-1",
+        ))));
+        app.view = View::Detail(KindId::WorkflowExecution);
+
+        let effects = app.update(Action::SubmitCommandInput(
+            "!temporal workflow show -w $T9S_WORKFLOW_ID".to_string(),
+        ));
+        let [Effect::RunShell(command, env)] = &effects[..] else {
+            panic!("expected the shell command to run, got {:?}", effects);
+        };
+        assert_eq!(command, "temporal workflow show -w $T9S_WORKFLOW_ID");
+        let var = |name: &str| env.iter().find(|(k, _)| k == name).map(|(_, v)| v.as_str());
+        assert_eq!(var("T9S_NAMESPACE"), Some("prod"));
+        assert_eq!(var("T9S_WORKFLOW_ID"), Some("order-1"));
+        assert_eq!(var("T9S_RUN_ID"), Some("run-1"));
+        assert_eq!(var("T9S_SCHEDULE_ID"), None);
+    }
+
+    #[test]
+    fn custom_kind_narrows_its_base_list() {
+        let mut app = App::new("default".to_string());
+        app.apply_custom_kinds(&[
+            KindConfig {
+                name: "Failed Payments".to_string(),
+                command: None,
+                base: None,
+                query: "WorkflowType = 'Payment' AND ExecutionStatus = 'Failed'".to_string(),
+                columns: vec!["workflow_id:40%".to_string(), "close_time".to_string()],
+            },
+            KindConfig {
+                name: "Clash".to_string(),
+                command: Some("wf".to_string()),
+                base: None,
+                query: String::new(),
+                columns: vec![],
+            },
+        ]);
+        assert_eq!(app.kinds.custom().len(), 1);
+        assert_eq!(app.error_toasts.len(), 1);
+
+        let effects = app.update(Action::SubmitCommandInput("failed-payments".to_string()));
+        assert!(matches!(
+            effects.as_slice(),
+            [
+                Effect::CancelRequests(CancelScope::Namespace),
+                Effect::LoadWorkflows,
+                ..
+            ]
+        ));
+        assert_eq!(app.view, View::Collection(KindId::WorkflowExecution));
+        assert_eq!(
+            app.active_custom_kind().map(|k| k.label.as_str()),
+            Some("Failed Payments")
+        );
+        let ids: Vec<&str> = app
+            .columns_for(KindId::WorkflowExecution)
+            .iter()
+            .map(|c| c.id)
+            .collect();
+        assert_eq!(ids, vec!["workflow_id", "close_time"]);
+
+        app.search_queries
+            .insert(KindId::WorkflowExecution, "CustomerId = 'c-1'".to_string());
+        assert_eq!(
+            app.search_query_for_kind(KindId::WorkflowExecution)
+                .as_deref(),
+            Some("(WorkflowType = 'Payment' AND ExecutionStatus = 'Failed') AND (CustomerId = 'c-1')")
+        );
+
+        let generation = app.generation;
+        let effects = app.update(Action::SubmitCommandInput("wf".to_string()));
+        assert!(app.active_custom_kind().is_none());
+        assert!(app.generation > generation);
+        assert!(matches!(
+            effects.first(),
+            Some(Effect::CancelRequests(CancelScope::Namespace))
+        ));
+        assert_eq!(
+            app.search_query_for_kind(KindId::WorkflowExecution)
+                .as_deref(),
+            Some("CustomerId = 'c-1'")
+        );
+    }
+
+    #[test]
+    fn reloaded_config_replaces_the_previous_one() {
+        let mut app = App::new("default".to_string());
+        let mut config: ConfigFile = toml::from_str(
+            r#"
+            [columns]
+            workflows = ["workflow_id", "status"]
+
+            [[queries]]
+            name = "failed"
+            query = "ExecutionStatus = 'Failed'"
+
+            [[kinds]]
+            name = "Payments"
+            query = "WorkflowType = 'Payment'"
+            "#,
+        )
+        .unwrap();
+        app.apply_config(&config);
+        app.update(Action::SubmitCommandInput("payments".to_string()));
+        assert!(app.active_custom_kind().is_some());
+
+        config
+            .columns
+            .insert("workflows".to_string(), vec!["type".to_string()]);
+        config.queries.clear();
+        app.apply_config(&config);
+        let ids: Vec<&str> = app
+            .columns_for(KindId::WorkflowExecution)
+            .iter()
+            .map(|c| c.id)
+            .collect();
+        assert_eq!(ids, vec!["type"]);
+        assert!(app.saved_queries.is_empty());
+        assert_eq!(app.kinds.custom().len(), 1);
+        assert!(app.active_custom_kind().is_some());
+        assert!(app.error_toasts.is_empty());
+    }
+
+    #[test]
+    fn reloading_a_changed_kind_query_reloads_its_list() {
+        let mut app = App::new("default".to_string());
+        let mut config: ConfigFile = toml::from_str(
+            r#"
+            [[kinds]]
+            name = "Payments"
+            query = "WorkflowType = 'Payment'"
+            "#,
+        )
+        .unwrap();
+        app.apply_config(&config);
+        app.update(Action::SubmitCommandInput("payments".to_string()));
+
+        // An unrelated edit leaves the list alone.
+        let generation = app.generation;
+        let effects = app.update(Action::ConfigReloaded(Box::new(config.clone())));
+        assert!(effects.is_empty());
+        assert_eq!(app.generation, generation);
+
+        config.kinds[0].query = "WorkflowType = 'Refund'".to_string();
+        let effects = app.update(Action::ConfigReloaded(Box::new(config)));
+        assert!(app.generation > generation);
+        assert!(matches!(
+            effects.as_slice(),
+            [
+                Effect::CancelRequests(_),
+                Effect::LoadWorkflows,
+                Effect::LoadWorkflowCount
+            ]
+        ));
+        assert_eq!(
+            app.kind_query(KindId::WorkflowExecution).as_deref(),
+            Some("WorkflowType = 'Refund'")
+        );
+    }
+
+    #[test]
+    fn destructive_operations_need_the_id_typed_in_listed_namespaces() {
+        let mut app = App::new("prod".to_string());
+        app.typed_confirm = vec![TypedConfirmConfig {
+            namespace: "prod".to_string(),
+            phrase: None,
+        }];
+        app.update(Action::WorkflowDetailLoaded(Box::new(workflow_detail(
+            "order-1", "run-1",
+        ))));
+        app.view = View::Detail(KindId::WorkflowExecution);
+
+        app.update(Action::RunOperation(OperationId::CancelWorkflow));
+        assert!(matches!(app.overlay, Overlay::Confirm(_)));
+        assert_eq!(app.confirm_phrase, None);
+
+        app.update(Action::RunOperation(OperationId::TerminateWorkflow));
+        assert_eq!(app.confirm_phrase.as_deref(), Some("order-1"));
+        assert!(!app.confirm_ready());
+        app.confirm_input = "order-1".to_string();
+        assert!(app.confirm_ready());
+
+        app.namespace = "dev".to_string();
+        app.update(Action::RunOperation(OperationId::TerminateWorkflow));
+        assert_eq!(app.confirm_phrase, None);
+        assert!(app.confirm_ready());
+    }
+
+    #[test]
+    fn restarts_are_confirmed_twice_before_anything_runs() {
+        let mut app = App::new("default".to_string());
+        app.update(Action::WorkflowDetailLoaded(Box::new(workflow_detail(
+            "order-1", "run-1",
+        ))));
+        app.view = View::Detail(KindId::WorkflowExecution);
+
+        assert!(app
+            .update(Action::RunOperation(OperationId::TerminateAndRestart))
+            .is_empty());
+        let Overlay::Confirm(first) = app.overlay.clone() else {
+            panic!("expected the terminate step");
+        };
+        let second = first.next_step().expect("a second step");
+        let ConfirmAction::Restart(confirm) = &second else {
+            panic!("expected the restart step, got {:?}", second);
+        };
+        assert_eq!(second.next_step(), None);
+
+        // Only accepting the second step has something to run.
+        let spec = crate::kinds::operation_effect_spec(confirm.op, confirm.kind).unwrap();
+        assert!(matches!(
+            &(spec.to_effects)(&confirm.target, &app)[..],
+            [Effect::TerminateAndRestartWorkflow(id, Some(run))] if id == "order-1" && run == "run-1"
+        ));
+    }
+
+    #[test]
+    fn escaped_typed_confirms_leave_no_phrase_behind() {
+        let mut app = App::new("prod".to_string());
+        app.typed_confirm = vec![TypedConfirmConfig {
+            namespace: "prod".to_string(),
+            phrase: None,
+        }];
+        app.capabilities.namespace_admin = true;
+        app.update(Action::WorkflowDetailLoaded(Box::new(workflow_detail(
+            "order-1", "run-1",
+        ))));
+        app.view = View::Detail(KindId::WorkflowExecution);
+
+        app.update(Action::RunOperation(OperationId::TerminateWorkflow));
+        app.confirm_input = "order".to_string();
+        app.update(Action::CloseOverlay);
+        assert_eq!(app.overlay, Overlay::None);
+        assert_eq!(app.confirm_phrase, None);
+        assert!(app.confirm_input.is_empty());
+
+        app.update(Action::SubmitCommandInput(
+            "nsadmin describe prod orders".to_string(),
+        ));
+        assert!(matches!(
+            app.overlay,
+            Overlay::Confirm(ConfirmAction::Namespace(_))
+        ));
+        assert!(app.confirm_ready());
+    }
+
+    #[test]
+    fn marked_workflows_are_cancelled_together_and_queries_counted_first() {
+        let mut app = App::new("default".to_string());
+        app.view = View::Collection(KindId::WorkflowExecution);
+        let wf = |id: &str| workflow_detail(id, "r").summary;
+        app.update(Action::WorkflowsLoaded(
+            vec![wf("a"), wf("b"), wf("c")],
+            vec![],
+        ));
+        app.update(Action::ToggleMark);
+        app.update(Action::NavigateDown);
+        app.update(Action::ToggleMark);
+        let mut marked = app.marked_workflows.clone();
+        marked.sort();
+        assert_eq!(
+            marked,
+            vec![
+                ("a".to_string(), "r".to_string()),
+                ("c".to_string(), "r".to_string())
+            ]
+        );
+
+        app.update(Action::RunOperation(OperationId::CancelWorkflow));
+        let Overlay::Confirm(ConfirmAction::Batch(confirm)) = &app.overlay else {
+            panic!("expected a batch confirmation");
+        };
+        assert_eq!(confirm.count, 2);
+        let effects = confirm.effects();
+        assert_eq!(effects.len(), 2);
+        assert!(effects
+            .iter()
+            .all(|e| matches!(e, Effect::CancelWorkflow(id, _) if id != "b")));
+
+        app.overlay = Overlay::None;
+        app.update(Action::Back);
+        assert!(app.marked_workflows.is_empty());
+
+        // Without marks, :bulk needs a query and counts it before asking.
+        app.update(Action::SubmitCommandInput("bulk terminate".to_string()));
+        assert!(app.overlay == Overlay::None);
+        app.update(Action::SubmitSearch("WorkflowType='x'".to_string()));
+        let effects = app.update(Action::SubmitCommandInput("bulk terminate".to_string()));
+        assert!(matches!(
+            effects.as_slice(),
+            [Effect::CountBatch(BatchOperation::Terminate, q)] if q == "WorkflowType='x'"
+        ));
+        let counted = |namespace: &str| {
+            Action::BatchCounted(
+                namespace.to_string(),
+                BatchOperation::Terminate,
+                "WorkflowType='x'".to_string(),
+                500,
+            )
+        };
+        // A count from another namespace or for another list is dropped.
+        app.update(counted("other"));
+        assert_eq!(app.overlay, Overlay::None);
+        app.overlay = Overlay::Help;
+        app.update(counted("default"));
+        assert_eq!(app.overlay, Overlay::Help);
+        app.overlay = Overlay::None;
+
+        app.update(counted("default"));
+        let Overlay::Confirm(ConfirmAction::Batch(confirm)) = &app.overlay else {
+            panic!("expected a batch confirmation");
+        };
+        assert!(matches!(
+            confirm.effects().as_slice(),
+            [Effect::StartBatchOperation(
+                namespace,
+                BatchOperation::Terminate,
+                BatchTarget::Query(_)
+            )] if namespace == "default"
+        ));
+    }
+
+    #[test]
+    fn column_config_overrides_defaults() {
+        let mut app = App::new("default".to_string());
+        let config = HashMap::from([
+            (
+                "workflows".to_string(),
+                vec!["workflow_id:40%".to_string(), "duration:10".to_string()],
+            ),
+            ("schedules".to_string(), vec!["bogus".to_string()]),
+        ]);
+        app.apply_column_config(&config);
+
+        let columns = app.columns_for(KindId::WorkflowExecution);
+        assert_eq!(
+            columns.iter().map(|c| (c.id, c.width)).collect::<Vec<_>>(),
+            vec![
+                ("workflow_id", ratatui::layout::Constraint::Percentage(40)),
+                ("duration", ratatui::layout::Constraint::Length(10)),
+            ]
+        );
+        assert_eq!(
+            app.columns_for(KindId::Schedule),
+            default_columns(KindId::Schedule)
+        );
+        assert_eq!(app.error_log.len(), 1);
+    }
+
+    #[test]
+    fn query_builder_applies_the_built_query() {
+        let mut app = App::new("default".to_string());
+        app.view = View::Collection(KindId::WorkflowExecution);
+        let effects = app.update(Action::OpenQueryBuilder);
+        assert!(matches!(&effects[..], [Effect::LoadSearchAttributes]));
+        assert_eq!(app.overlay, Overlay::QueryBuilder);
+
+        // WorkflowType, then `!=`, then a value containing j and k.
+        app.update(Action::QueryBuilderInput('j'));
+        app.update(Action::Select);
+        app.update(Action::QueryBuilderInput('j'));
+        app.update(Action::Select);
+        for c in "jkSync".chars() {
+            app.update(Action::QueryBuilderInput(c));
+        }
+        app.update(Action::QueryBuilderBack);
+        app.update(Action::Select);
+        app.update(Action::NavigateDown);
+        app.update(Action::NavigateDown);
+        let effects = app.update(Action::Select);
+
+        assert_eq!(app.overlay, Overlay::None);
+        assert!(matches!(
+            &effects[..],
+            [Effect::LoadWorkflows, Effect::LoadWorkflowCount]
+        ));
+        assert_eq!(
+            app.search_query_for_kind(KindId::WorkflowExecution)
+                .as_deref(),
+            Some("WorkflowType != 'jkSyn'")
+        );
+    }
+
+    #[test]
+    fn search_completes_types_from_loaded_workflows() {
+        let mut app = App::new("default".to_string());
+        app.view = View::Collection(KindId::WorkflowExecution);
+        app.update(Action::WorkflowsLoaded(
+            vec![WorkflowSummary {
+                workflow_type: "OrderWorkflow".to_string(),
+                ..workflow_detail("a", "r").summary
+            }],
+            vec![],
+        ));
+        app.update(Action::OpenSearch);
+        app.update(Action::UpdateInputBuffer("workflowt".to_string()));
+        app.update(Action::CompleteSearch);
+        assert_eq!(app.input_buffer, "WorkflowType ");
+        app.update(Action::UpdateInputBuffer("WorkflowType = 'O".to_string()));
+        app.update(Action::CompleteSearch);
+        assert_eq!(app.input_buffer, "WorkflowType = 'OrderWorkflow' ");
+        assert_eq!(
+            app.search_completion().candidates,
+            vec!["AND", "OR", "ORDER BY"]
+        );
+    }
+
+    #[test]
+    fn rejected_queries_stay_in_the_search_modal() {
+        let mut app = App::new("default".to_string());
+        app.view = View::Collection(KindId::WorkflowExecution);
+        let effects = app.update(Action::OpenSearch);
+        assert!(matches!(&effects[..], [Effect::LoadSearchAttributes]));
+        app.update(Action::SearchAttributesLoaded(Ok(vec![SearchAttribute {
+            name: "WorkflowType".to_string(),
+            value_type: Some(SearchAttributeType::Keyword),
+            custom: false,
+        }])));
+
+        let effects = app.update(Action::SubmitSearch("WorkflowType = 'Order".to_string()));
+        assert!(effects.is_empty());
+        assert_eq!(app.input_mode, InputMode::Search);
+        assert_eq!(
+            app.search_error.as_deref(),
+            Some("unterminated ' at column 16")
+        );
+        app.update(Action::UpdateInputBuffer(
+            "workflowtype = 'Order'".to_string(),
+        ));
+        assert_eq!(app.search_error, None);
+        app.update(Action::SubmitSearch("workflowtype = 'Order'".to_string()));
+        assert!(app
+            .search_error
+            .as_deref()
+            .is_some_and(|e| e.contains("did you mean WorkflowType")));
+
+        // The server has the last word; its rejection reopens the modal.
+        let effects = app.update(Action::SubmitSearch("WorkflowType = 'Order'".to_string()));
+        assert_eq!(effects.len(), 2);
+        assert_eq!(app.input_mode, InputMode::Normal);
+        let effects = app.update(Action::InvalidQuery(
+            KindId::WorkflowExecution,
+            "invalid operator".to_string(),
+        ));
+        assert!(matches!(
+            &effects[..],
+            [Effect::LoadWorkflows, Effect::LoadWorkflowCount]
+        ));
+        assert_eq!(app.input_mode, InputMode::Search);
+        assert_eq!(app.input_buffer, "WorkflowType = 'Order'");
+        assert_eq!(app.search_error.as_deref(), Some("invalid operator"));
+        assert_eq!(app.search_query_for_kind(KindId::WorkflowExecution), None);
+        assert!(app.error_toasts.is_empty());
+        assert!(app
+            .update(Action::InvalidQuery(
+                KindId::WorkflowExecution,
+                "invalid operator".to_string()
+            ))
+            .is_empty());
+    }
+
+    #[test]
+    fn search_attribute_columns_come_from_config() {
+        let mut app = App::new("default".to_string());
+        app.apply_column_config(&HashMap::from([
+            (
+                "workflows".to_string(),
+                vec!["workflow_id".to_string(), "sa.CustomerId:16".to_string()],
+            ),
+            ("schedules".to_string(), vec!["sa.CustomerId".to_string()]),
+        ]));
+        let columns = app.columns_for(KindId::WorkflowExecution);
+        assert_eq!(columns[1].id, "sa.CustomerId");
+        assert_eq!(columns[1].header, "CustomerId");
+        assert_eq!(columns[1].width, ratatui::layout::Constraint::Length(16));
+        assert_eq!(
+            app.error_log.len(),
+            1,
+            "only workflows have attribute columns"
+        );
+    }
+
+    #[test]
+    fn wide_mode_uses_wide_preset_on_wide_terminals() {
+        let mut app = App::new("default".to_string());
+        app.apply_column_config(&HashMap::from([(
+            "activities-wide".to_string(),
+            vec!["activity_id".to_string(), "run_id".to_string()],
+        )]));
+
+        let ids = |columns: Vec<ColumnSpec>| columns.iter().map(|c| c.id).collect::<Vec<_>>();
+        assert_eq!(
+            app.columns_for_width(KindId::WorkflowExecution, 200),
+            default_columns(KindId::WorkflowExecution)
+        );
+
+        app.update(Action::ToggleWideMode);
+        let wide = ids(app.columns_for_width(KindId::WorkflowExecution, 200));
+        assert!(wide.contains(&"run_id") && wide.contains(&"search_attributes"));
+        assert_eq!(
+            app.columns_for_width(KindId::WorkflowExecution, 120),
+            default_columns(KindId::WorkflowExecution)
+        );
+        assert_eq!(
+            ids(app.columns_for_width(KindId::ActivityExecution, 200)),
+            vec!["activity_id", "run_id"]
+        );
+    }
+
+    #[test]
+    fn next_workflow_page_loads_once_past_halfway() {
+        let mut app = App::new("default".to_string());
+        app.workflows = LoadState::Loaded(
+            (0..50)
+                .map(|i| workflow_detail(&format!("wf-{}", i), "run").summary)
+                .collect(),
+        );
+        app.next_page_token = vec![1];
+        app.workflow_table_state.select(Some(23));
+
+        assert!(app.update(Action::NavigateDown).is_empty());
+        let effects = app.update(Action::NavigateDown);
+        assert!(matches!(effects[..], [Effect::LoadMoreWorkflows]));
+        assert!(app.update(Action::NavigateDown).is_empty());
+
+        // Past halfway through the filtered rows, but still in the first
+        // half of the loaded ones.
+        app.loading_more = false;
+        app.update(Action::OpenFilter);
+        app.update(Action::UpdateInputBuffer("wf-1".to_string()));
+        app.update(Action::SubmitFilter);
+        app.workflow_table_state.select(Some(8));
+        assert!(app.update(Action::NavigateDown).is_empty());
+        assert!(app.update(Action::NavigateDown).is_empty());
+    }
+
+    #[test]
+    fn pagesize_reloads_the_list_on_screen() {
+        let mut app = App::new("default".to_string());
+        let effects = app.update(Action::SubmitCommandInput("pagesize 200".to_string()));
+        assert_eq!(app.page_size, 200);
+        assert!(matches!(effects[..], [Effect::LoadWorkflows, ..]));
+
+        app.update(Action::SubmitCommandInput("sch".to_string()));
+        app.update(Action::SubmitCommandInput("ps 25".to_string()));
+        assert_eq!(app.schedule_page_size, 25);
+        assert_eq!(app.page_size, 200);
+
+        assert!(app
+            .update(Action::SubmitCommandInput("pagesize 0".to_string()))
+            .is_empty());
+        assert_eq!(app.schedule_page_size, 25);
+        assert!(!app.error_toasts.is_empty());
+    }
+
+    #[test]
+    fn list_position_counts_rows_total_and_pages_left() {
+        let mut app = App::new("default".to_string());
+        app.workflows = LoadState::Loaded(
+            (0..50)
+                .map(|i| workflow_detail(&format!("wf-{}", i), "run").summary)
+                .collect(),
+        );
+        app.workflow_table_state.select(Some(41));
+        app.next_page_token = vec![1];
+        let position = app.list_position(KindId::WorkflowExecution);
+        assert_eq!(position.selected, 42);
+        assert_eq!(position.shown, 50);
+        assert_eq!(position.pages_left, None);
+        assert!(position.more_pages);
+
+        app.update(Action::WorkflowCountLoaded(1204));
+        let position = app.list_position(KindId::WorkflowExecution);
+        assert_eq!(position.total, Some(1204));
+        assert_eq!(position.pages_left, Some(24));
+
+        app.next_page_token.clear();
+        assert_eq!(
+            app.list_position(KindId::WorkflowExecution).pages_left,
+            Some(0)
+        );
+    }
+
+    #[test]
+    fn local_filter_narrows_loaded_workflows() {
+        let mut app = App::new("default".to_string());
+        let mut payment = workflow_detail("payment-42", "run-1").summary;
+        payment.workflow_type = "PaymentWorkflow".to_string();
+        app.workflows = LoadState::Loaded(vec![
+            workflow_detail("order-1", "run-2").summary,
+            payment,
+            workflow_detail("order-2", "run-3").summary,
+        ]);
+
+        app.update(Action::OpenFilter);
+        assert_eq!(app.input_mode, InputMode::Filter);
+        let effects = app.update(Action::UpdateInputBuffer("pay wf".to_string()));
+        assert!(effects.is_empty());
+        assert_eq!(app.visible_workflow_indices(), vec![1]);
+        app.update(Action::SubmitFilter);
+        assert_eq!(
+            app.selected_workflow_summary()
+                .map(|wf| wf.workflow_id.as_str()),
+            Some("payment-42")
+        );
+
+        app.update(Action::OpenFilter);
+        app.update(Action::CloseOverlay);
+        assert_eq!(app.visible_workflow_indices(), vec![0, 1, 2]);
+    }
+
+    #[test]
+    fn saved_queries_apply_by_name_and_from_picker() {
+        let mut app = App::new("payments".to_string());
+        let query = |name: &str, kind: Option<&str>, namespace: Option<&str>| QueryConfig {
+            name: name.to_string(),
+            query: format!("{} query", name),
+            kind: kind.map(str::to_string),
+            namespace: namespace.map(str::to_string),
+        };
+        app.apply_saved_queries(&[
+            query("failed", None, Some("payments")),
+            query("elsewhere", None, Some("billing")),
+            query("paused", Some("schedules"), None),
+        ]);
+
+        let names: Vec<&str> = app
+            .picker_saved_queries()
+            .iter()
+            .map(|q| q.name.as_str())
+            .collect();
+        assert_eq!(names, vec!["failed"]);
+
+        app.update(Action::OpenQueryPicker);
+        assert_eq!(app.overlay, Overlay::QueryPicker);
+        let effects = app.update(Action::Select);
+        assert!(effects
+            .iter()
+            .any(|effect| matches!(effect, Effect::LoadWorkflows)));
+        assert_eq!(
+            app.search_query_for_kind(KindId::WorkflowExecution)
+                .as_deref(),
+            Some("failed query")
+        );
+
+        app.update(Action::SubmitCommandInput("q paused".to_string()));
+        assert!(!app.should_quit);
+        assert_eq!(app.view, View::Collection(KindId::Schedule));
+        assert_eq!(
+            app.search_queries
+                .get(&KindId::Schedule)
+                .map(String::as_str),
+            Some("paused query")
+        );
+
+        app.update(Action::SubmitCommandInput("q elsewhere".to_string()));
+        assert!(!app.should_quit);
+        assert!(!app.error_toasts.is_empty());
+    }
+
+    #[test]
+    fn recent_signals_prefill_command_for_resend() {
+        let mut app = App::new("default".to_string());
+        app.update(Action::WorkflowDetailLoaded(Box::new(workflow_detail(
+            "wf", "run",
+        ))));
+        app.view = View::Detail(KindId::WorkflowExecution);
+
+        app.update(Action::SubmitCommandInput(
+            "signal unblock {\"a\":1}".to_string(),
+        ));
+        app.update(Action::SubmitCommandInput("signal poke".to_string()));
+        app.update(Action::SubmitCommandInput(
+            "signal unblock {\"a\":1}".to_string(),
+        ));
+        let names: Vec<&str> = app
+            .namespace_recent_signals()
+            .iter()
+            .map(|s| s.name.as_str())
+            .collect();
+        assert_eq!(names, vec!["unblock", "poke"]);
+
+        app.update(Action::OpenSignalPicker);
+        assert_eq!(app.overlay, Overlay::SignalPicker);
+        app.update(Action::Select);
+        assert_eq!(app.overlay, Overlay::None);
+        assert_eq!(app.input_mode, InputMode::Command);
+        assert_eq!(app.input_buffer, "signal unblock {\"a\":1}");
+    }
+
+    #[test]
+    fn quick_actions_run_the_selected_rows_operations() {
+        let mut app = App::new("default".to_string());
+        app.update(Action::OpenQuickActions);
+        assert_eq!(app.overlay, Overlay::None);
+        assert_eq!(app.error_toasts.len(), 1);
+
+        app.update(Action::WorkflowsLoaded(
+            vec![workflow_detail("order-1", "run-1").summary],
+            vec![],
+        ));
+        app.update(Action::OpenQuickActions);
+        assert_eq!(app.overlay, Overlay::QuickActions);
+        assert_eq!(
+            app.quick_actions(),
+            [
+                QuickAction::Operation(OperationId::CancelWorkflow),
+                QuickAction::Operation(OperationId::TerminateWorkflow),
+                QuickAction::Operation(OperationId::TerminateAndRestart),
+                QuickAction::Signal,
+                QuickAction::CopyId,
+                QuickAction::CopyLink,
+            ]
+        );
+
+        // Entries the backend can't run are left out.
+        app.capabilities.execution_admin = false;
+        assert_eq!(app.quick_actions()[0], QuickAction::CopyId);
+        app.update(Action::SubmitCommandInput("signal approve".to_string()));
+        assert_eq!(app.error_toasts.len(), 2);
+        app.capabilities.execution_admin = true;
+
+        app.update(Action::NavigateDown);
+        app.update(Action::Select);
+        assert!(matches!(
+            &app.overlay,
+            Overlay::Confirm(ConfirmAction::Operation(confirm))
+                if confirm.op == OperationId::TerminateWorkflow
+        ));
+        app.update(Action::CloseOverlay);
+
+        // With no signal sent yet, Signal starts a :signal command.
+        app.update(Action::OpenQuickActions);
+        for _ in 0..3 {
+            app.update(Action::NavigateDown);
+        }
+        app.update(Action::Select);
+        assert_eq!(app.input_mode, InputMode::Command);
+        assert_eq!(app.input_buffer, "signal ");
+        app.update(Action::CloseOverlay);
+
+        app.update(Action::OpenQuickActions);
+        for _ in 0..9 {
+            app.update(Action::NavigateDown);
+        }
+        app.update(Action::NavigateUp);
+        let effects = app.update(Action::Select);
+        assert!(matches!(&effects[..], [Effect::CopyToClipboard(id)] if id == "order-1"));
+
+        // Space opens the menu on every list; marking moved to `m`.
+        use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+        let key = |c| {
+            crate::event::key_to_action(
+                KeyEvent::new(KeyCode::Char(c), KeyModifiers::NONE),
+                &app.view,
+                &app.input_mode,
+                &Overlay::None,
+                "",
+            )
+        };
+        assert!(matches!(key(' '), Some(Action::OpenQuickActions)));
+        assert!(matches!(key('m'), Some(Action::ToggleMark)));
+
+        // With a Web UI to link to, the run can be opened there.
+        app.web_url = Some("http://localhost:8233".to_string());
+        app.update(Action::OpenQuickActions);
+        assert_eq!(app.quick_actions().last(), Some(&QuickAction::OpenWebUi));
+        for _ in 0..9 {
+            app.update(Action::NavigateDown);
+        }
+        let effects = app.update(Action::Select);
+        assert!(matches!(
+            &effects[..],
+            [Effect::OpenUrl(url)]
+                if url == "http://localhost:8233/namespaces/default/workflows/order-1/run-1/history"
+        ));
+    }
+
+    #[test]
+    fn ctx_command_requests_profile_switch() {
+        let mut app = App::new("default".to_string());
+        app.update(Action::SubmitCommandInput("ctx".to_string()));
+        assert_eq!(app.overlay, Overlay::None);
+        assert_eq!(app.error_toasts.len(), 1);
+
+        app.profiles = vec!["local".to_string(), "prod".to_string()];
+        app.active_profile = Some("prod".to_string());
+        app.update(Action::SubmitCommandInput("ctx".to_string()));
+        assert_eq!(app.overlay, Overlay::ContextPicker);
+        assert_eq!(app.context_picker_cursor, 1);
+        app.update(Action::NavigateUp);
+        let effects = app.update(Action::Select);
+        assert!(matches!(&effects[..], [Effect::SwitchContext(name)] if name == "local"));
+
+        let effects = app.update(Action::SubmitCommandInput("ctx prod".to_string()));
+        assert!(matches!(&effects[..], [Effect::SwitchContext(name)] if name == "prod"));
+
+        let effects = app.update(Action::SubmitCommandInput("ctx staging".to_string()));
+        assert!(effects.is_empty());
+        assert_eq!(app.error_toasts.len(), 2);
+
+        // Results from a context that is no longer active are dropped.
+        app.update(Action::ForContext(
+            "local".to_string(),
+            None,
+            Box::new(Action::Error("stale".to_string())),
+        ));
+        assert_eq!(app.error_toasts.len(), 2);
+        app.update(Action::ForContext(
+            "prod".to_string(),
+            None,
+            Box::new(Action::Error("current".to_string())),
+        ));
+        assert_eq!(app.error_toasts.len(), 3);
+    }
+
+    #[test]
+    fn ctx_switches_once_its_connection_finishes() {
+        use clap::Parser;
+
+        let mut app = App::new("default".to_string());
+        assert_eq!(app.context_id(), DEFAULT_CONTEXT);
+        app.profiles = vec!["local".to_string(), "prod".to_string()];
+        app.update(Action::SubmitCommandInput("ctx local".to_string()));
+        app.update(Action::SubmitCommandInput("ctx prod".to_string()));
+        assert!(app.notice.is_some());
+
+        let connected = |namespace: &str| {
+            let mut cli = crate::config::Cli::try_parse_from(["t9s"]).unwrap();
+            cli.namespace = namespace.to_string();
+            Ok((Box::new(cli), Capabilities::default()))
+        };
+        // `local` was asked for first, but `prod` replaced it.
+        app.update(Action::ContextConnected(
+            "local".to_string(),
+            connected("dev"),
+        ));
+        assert_eq!(app.active_profile, None);
+        assert_eq!(app.namespace, "default");
+
+        app.update(Action::ContextConnected(
+            "prod".to_string(),
+            connected("orders"),
+        ));
+        assert_eq!(app.active_profile.as_deref(), Some("prod"));
+        assert_eq!(app.context_id(), "prod");
+        assert_eq!(app.namespace, "orders");
+        assert!(app.notice.is_none());
+
+        app.update(Action::SubmitCommandInput("ctx local".to_string()));
+        app.update(Action::ContextConnected(
+            "local".to_string(),
+            Err("connection refused".to_string()),
+        ));
+        assert_eq!(app.active_profile.as_deref(), Some("prod"));
+        assert!(app
+            .error_toasts
+            .iter()
+            .any(|(message, _)| message.contains("ctx local")));
+    }
+
+    #[test]
+    fn navigation_cancels_outstanding_loads() {
+        let mut app = App::new("default".to_string());
+        app.view = View::Detail(KindId::WorkflowExecution);
+        let effects = app.update(Action::Back);
+        assert!(matches!(
+            effects.first(),
+            Some(Effect::CancelRequests(CancelScope::Detail))
+        ));
+
+        let effects = app.update(Action::SwitchNamespace("other".to_string()));
+        assert!(matches!(
+            effects.first(),
+            Some(Effect::CancelRequests(CancelScope::Namespace))
+        ));
+        assert!(effects.len() > 1);
+    }
+
+    #[test]
+    fn loads_from_before_a_query_change_are_dropped() {
+        let mut app = App::new("default".to_string());
+        let before = app.generation;
+        app.update(Action::SubmitSearch("WorkflowType='a'".to_string()));
+        assert_ne!(app.generation, before);
+
+        let loaded = |generation| {
+            Action::ForContext(
+                DEFAULT_CONTEXT.to_string(),
+                Some(generation),
+                Box::new(Action::WorkflowCountLoaded(7)),
+            )
+        };
+        app.update(loaded(before));
+        assert_eq!(app.workflow_count, None);
+        app.update(loaded(app.generation));
+        assert_eq!(app.workflow_count, Some(7));
+
+        // Ticks and other actions leave the generation alone.
+        let generation = app.generation;
+        app.update(Action::Tick);
+        assert_eq!(app.generation, generation);
+    }
+
+    #[test]
+    fn overview_rows_drill_into_workflows() {
+        let mut app = App::new("default".to_string());
+        app.view = View::Collection(KindId::Schedule);
+        let effects = app.update(Action::SubmitCommandInput("overview".to_string()));
+        assert!(matches!(&effects[..], [Effect::LoadOverview]));
+        assert_eq!(app.overlay, Overlay::Overview);
+
+        app.update(Action::OverviewLoaded(Box::new(NamespaceOverview {
+            status_counts: vec![(WorkflowStatus::Running, 12), (WorkflowStatus::Failed, 3)],
+            ..Default::default()
+        })));
+        app.update(Action::NavigateDown);
+        let effects = app.update(Action::Select);
+
+        assert_eq!(app.overlay, Overlay::None);
+        assert_eq!(app.view, View::Collection(KindId::WorkflowExecution));
+        assert!(matches!(
+            &effects[..],
+            [Effect::LoadWorkflows, Effect::LoadWorkflowCount]
+        ));
+        assert_eq!(
+            app.search_query_for_kind(KindId::WorkflowExecution)
+                .as_deref(),
+            Some("ExecutionStatus = 'Failed'")
+        );
+    }
+
+    #[test]
+    fn time_range_narrows_the_workflow_query() {
+        let mut app = App::new("default".to_string());
+        app.search_queries.insert(
+            KindId::WorkflowExecution,
+            "ExecutionStatus = 'Failed'".to_string(),
+        );
+        app.update(Action::OpenTimeRangePicker);
+        assert_eq!(app.overlay, Overlay::TimeRange);
+        app.update(Action::NavigateDown);
+        app.update(Action::NavigateDown);
+        let generation = app.generation;
+        let effects = app.update(Action::Select);
+
+        assert_eq!(app.time_range, Some(TimeRange::LastHour));
+        assert!(app.generation > generation);
+        assert!(matches!(
+            &effects[..],
+            [Effect::LoadWorkflows, Effect::LoadWorkflowCount]
+        ));
+        let query = app
+            .search_query_for_kind(KindId::WorkflowExecution)
+            .unwrap();
+        assert!(query.starts_with("(ExecutionStatus = 'Failed') AND StartTime > '"));
+        // Polls and pages reuse the query; picking the range again moves it.
+        app.time_range_anchor -= chrono::Duration::minutes(5);
+        let pinned = app.search_query_for_kind(KindId::WorkflowExecution);
+        app.update(Action::Tick);
+        assert_eq!(app.search_query_for_kind(KindId::WorkflowExecution), pinned);
+        let generation = app.generation;
+        app.update(Action::SubmitCommandInput("last 1h".to_string()));
+        assert!(app.generation > generation);
+        assert_ne!(app.search_query_for_kind(KindId::WorkflowExecution), pinned);
+        assert!(matches!(
+            app.location().leaf(),
+            Some(RouteSegment::Workflows(WorkflowsRoute::Collection { since: Some(since), .. }))
+                if since == "1h"
+        ));
+
+        app.update(Action::SubmitCommandInput("last all".to_string()));
+        assert_eq!(app.time_range, None);
+    }
+
+    #[test]
+    fn opened_links_switch_namespace_once() {
+        let mut app = App::new("default".to_string());
+        let generation = app.generation;
+        let effects = app.update(Action::SubmitCommandInput(
+            "open temporal://tui/namespaces/prod/workflows".to_string(),
+        ));
+        assert_eq!(app.namespace, "prod");
+        assert_eq!(app.generation, generation + 1);
+        assert_eq!(
+            effects
+                .iter()
+                .filter(|e| matches!(e, Effect::CancelRequests(_)))
+                .count(),
+            1
+        );
+    }
+
+    #[test]
+    fn startup_location_opens_the_linked_tab() {
+        let location =
+            parse_deep_link("temporal://tui/namespaces/prod/workflows/order-123?tab=history")
+                .unwrap();
+        let mut app = App::new(location.namespace.clone());
+        let effects = app.update(Action::OpenLocation(location));
+
+        assert_eq!(app.view, View::Detail(KindId::WorkflowExecution));
+        assert_eq!(app.workflow_detail_tab, workflow_tab_from_param("history"));
+        assert!(matches!(
+            &effects[..],
+            [Effect::LoadWorkflowDetail(id, None), Effect::LoadHistory(_, None)] if id == "order-123"
+        ));
+    }
+
+    #[test]
+    fn copy_link_puts_the_current_view_on_the_clipboard() {
+        let mut app = App::new("prod".to_string());
+        app.update(Action::SubmitCommandInput("sch".to_string()));
+        let effects = app.update(Action::SubmitCommandInput("link".to_string()));
+
+        let expected = "temporal://tui/namespaces/prod/schedules";
+        assert!(matches!(&effects[..], [Effect::CopyToClipboard(link)] if link == expected));
+        assert!(app
+            .notice
+            .as_ref()
+            .is_some_and(|(msg, _)| msg.contains(expected)));
+    }
+
+    #[test]
+    fn bookmarks_jump_back_to_a_saved_location() {
+        let mut app = App::new("default".to_string());
+        app.update(Action::OpenBookmarks);
+        assert_eq!(app.overlay, Overlay::None);
+
+        app.search_queries.insert(
+            KindId::WorkflowExecution,
+            "ExecutionStatus = 'Failed'".to_string(),
+        );
+        let effects = app.update(Action::SubmitCommandInput("bm failures".to_string()));
+        assert!(matches!(&effects[..], [Effect::SaveBookmarks(saved)] if saved.len() == 1));
+        app.update(Action::SubmitCommandInput("bm failures".to_string()));
+        assert_eq!(app.bookmarks.len(), 1);
+
+        app.update(Action::SubmitCommandInput("sch".to_string()));
+        app.search_queries.clear();
+        app.update(Action::SubmitCommandInput("bm".to_string()));
+        assert_eq!(app.overlay, Overlay::Bookmarks);
+        app.update(Action::Select);
+        assert_eq!(app.view, View::Collection(KindId::WorkflowExecution));
+        assert_eq!(
+            app.search_queries
+                .get(&KindId::WorkflowExecution)
+                .map(String::as_str),
+            Some("ExecutionStatus = 'Failed'")
+        );
+
+        app.update(Action::OpenBookmarks);
+        let effects = app.update(Action::DeleteBookmark);
+        assert!(app.bookmarks.is_empty());
+        assert_eq!(app.overlay, Overlay::None);
+        assert!(matches!(&effects[..], [Effect::SaveBookmarks(saved)] if saved.is_empty()));
+    }
+
+    #[test]
+    fn each_view_polls_on_its_own_interval() {
+        let mut app = App::new("default".to_string());
+        app.update(Action::SubmitCommandInput("poll 10".to_string()));
+        app.update(Action::SubmitCommandInput("poll history 30".to_string()));
+        assert_eq!(app.polling_interval(), Duration::from_secs(10));
+        app.error_count = 2;
+        assert_eq!(app.polling_interval(), Duration::from_secs(40));
+        app.error_count = 0;
+
+        app.view = View::Detail(KindId::WorkflowExecution);
+        assert_eq!(app.polling_interval(), Duration::from_secs(3));
+        app.workflow_detail_tab = 2;
+        assert_eq!(app.poll_target(), PollTarget::History);
+        assert_eq!(app.polling_interval(), Duration::from_secs(30));
+
+        // A poll that hasn't answered yet isn't sent again on the next tick.
+        app.view = View::Collection(KindId::WorkflowExecution);
+        let polls =
+            |effects: Vec<Effect>| effects.iter().any(|e| matches!(e, Effect::LoadWorkflows));
+        assert!(polls(app.update(Action::Tick)));
+        assert!(!polls(app.update(Action::Tick)));
+    }
+
+    #[test]
+    fn polling_holds_while_a_modal_is_open() {
+        let mut app = App::new("default".to_string());
+        app.overlay = Overlay::Help;
+        let effects = app.update(Action::Tick);
+        assert!(!effects.iter().any(|e| matches!(e, Effect::LoadWorkflows)));
+
+        app.update(Action::CloseOverlay);
+        let effects = app.update(Action::Tick);
+        assert!(effects.iter().any(|e| matches!(e, Effect::LoadWorkflows)));
+
+        for open in [
+            Action::OpenCommandInput,
+            Action::OpenSearch,
+            Action::OpenFilter,
+        ] {
+            app.last_refresh = None;
+            app.update(open);
+            assert!(!app.polling_active());
+            let effects = app.update(Action::Tick);
+            assert!(!effects.iter().any(|e| matches!(e, Effect::LoadWorkflows)));
+            app.update(Action::CloseOverlay);
+            assert!(app.polling_active());
+        }
+    }
+
+    #[test]
+    fn namespace_admin_commands_ask_for_confirmation() {
+        let mut app = App::new("default".to_string());
+        app.capabilities.namespace_admin = true;
+        app.update(Action::SubmitCommandInput(
+            "nsadmin register payments 30d Payment workflows".to_string(),
+        ));
+        assert_eq!(
+            app.overlay,
+            Overlay::Confirm(ConfirmAction::Namespace(NamespaceChange::Register {
+                name: "payments".to_string(),
+                retention: Duration::from_secs(30 * 86400),
+                description: "Payment workflows".to_string(),
+            }))
+        );
+
+        app.overlay = Overlay::None;
+        app.update(Action::SubmitCommandInput(
+            "nsadmin retention payments 72h".to_string(),
+        ));
+        assert_eq!(
+            app.overlay,
+            Overlay::Confirm(ConfirmAction::Namespace(NamespaceChange::Update {
+                name: "payments".to_string(),
+                retention: Some(Duration::from_secs(72 * 3600)),
+                description: None,
+            }))
+        );
+
+        app.overlay = Overlay::None;
+        app.update(Action::SubmitCommandInput(
+            "nsadmin retention payments soon".to_string(),
+        ));
+        assert_eq!(app.overlay, Overlay::None);
+        assert!(!app.error_toasts.is_empty());
+    }
+
+    #[test]
+    fn allns_merges_namespaces_and_opens_rows_in_theirs() {
+        let mut app = App::new("default".to_string());
+        app.update(Action::SubmitCommandInput(
+            "allns payments orders".to_string(),
+        ));
+        assert_eq!(app.namespace_fanout, ["payments", "orders"]);
+        let columns = app.columns_for_width(KindId::WorkflowExecution, 100);
+        assert_eq!(columns[1].id, "namespace");
+
+        let now = chrono::Utc::now();
+        let row = |ns: &str, id: &str, age: i64| WorkflowSummary {
+            namespace: Some(ns.to_string()),
+            start_time: now - chrono::Duration::minutes(age),
+            ..workflow_detail(id, "r").summary
+        };
+        let failed = vec![("billing".to_string(), "permission denied".to_string())];
+        let loaded = || {
+            Action::FanoutLoaded(
+                vec![row("orders", "order-1", 2), row("payments", "charge-1", 1)],
+                failed.clone(),
+            )
+        };
+        app.update(loaded());
+        assert_eq!(app.fanout_failed, ["billing"]);
+        assert_eq!(app.error_toasts.len(), 1);
+        // A poll failing the same way doesn't report it again.
+        app.update(loaded());
+        assert_eq!(app.error_toasts.len(), 1);
+        app.error_toasts.clear();
+        assert_eq!(app.selected_workflow_row().unwrap().0, "charge-1");
+        // Acting on a row from another namespace is refused.
+        assert!(app.update(Action::ToggleMark).is_empty());
+        assert!(app.marked_workflows.is_empty());
+        assert!(!app.error_toasts.is_empty());
+
+        let effects = app.update(Action::Select);
+        assert_eq!(app.namespace, "payments");
+        assert!(effects
+            .iter()
+            .any(|e| matches!(e, Effect::LoadWorkflowDetail(id, _) if id == "charge-1")));
+        // The merged list survives the namespace change.
+        app.error_toasts.clear();
+        let effects = app.update(Action::Back);
+        assert!(!effects.iter().any(|e| matches!(e, Effect::LoadWorkflows)));
+        assert_eq!(app.view, View::Collection(KindId::WorkflowExecution));
+        assert_eq!(app.workflows.data().map(|w| w.len()), Some(2));
+
+        app.update(Action::SubmitCommandInput("allns".to_string()));
+        assert!(app.namespace_fanout.is_empty());
+    }
+
+    #[test]
+    fn favorite_namespaces_return_to_where_they_were_left() {
+        let mut app = App::new("payments".to_string());
+        app.favorite_namespaces = vec!["payments".to_string(), "orders".to_string()];
+        app.update(Action::SubmitSearch(
+            "ExecutionStatus = 'Failed'".to_string(),
+        ));
+        app.update(Action::SubmitCommandInput("sch".to_string()));
+
+        let effects = app.update(Action::SwitchFavoriteNamespace(1));
+        assert_eq!(app.namespace, "orders");
+        assert!(app.search_queries.is_empty());
+        assert!(!effects.is_empty());
+
+        app.update(Action::SwitchFavoriteNamespace(0));
+        assert_eq!(app.namespace, "payments");
+        assert_eq!(app.view, View::Collection(KindId::Schedule));
+        assert_eq!(
+            app.search_queries
+                .get(&KindId::WorkflowExecution)
+                .map(String::as_str),
+            Some("ExecutionStatus = 'Failed'")
+        );
+
+        assert!(app.update(Action::SwitchFavoriteNamespace(5)).is_empty());
+        assert!(!app.error_toasts.is_empty());
+    }
+
+    #[test]
+    fn namespace_selector_filters_by_name() {
+        let mut app = App::new("default".to_string());
+        app.update(Action::SubmitCommandInput("ns".to_string()));
+        let namespace = |name: &str| Namespace {
+            name: name.to_string(),
+            state: "Registered".to_string(),
+            description: String::new(),
+            owner_email: String::new(),
+            retention: None,
+        };
+        app.update(Action::NamespacesLoaded(vec![
+            namespace("default"),
+            namespace("payments-prod"),
+            namespace("payments-staging"),
+        ]));
+
+        app.update(Action::OpenFilter);
+        assert_eq!(app.input_mode, InputMode::Filter);
+        app.update(Action::UpdateInputBuffer("PAY".to_string()));
+        assert_eq!(app.visible_namespaces().len(), 2);
+        app.namespace_selector_state.select_next();
+        assert_eq!(
+            app.selected_namespace_name().as_deref(),
+            Some("payments-staging")
+        );
+        // The list's own filter is untouched.
+        assert_eq!(app.local_filter(KindId::WorkflowExecution), None);
+
+        // Esc clears the filter but keeps the selector open.
+        app.update(Action::CloseOverlay);
+        assert_eq!(app.overlay, Overlay::NamespaceSelector);
+        assert_eq!(app.visible_namespaces().len(), 3);
+
+        app.update(Action::OpenFilter);
+        app.update(Action::UpdateInputBuffer("staging".to_string()));
+        let name = app.selected_namespace_name().unwrap();
+        app.update(Action::SwitchNamespace(name));
+        assert_eq!(app.namespace, "payments-staging");
+        assert_eq!(app.input_mode, InputMode::Normal);
+        assert_eq!(app.overlay, Overlay::None);
+    }
+
+    fn delayed_preview(effects: &[Effect]) -> Option<PreviewRow> {
+        effects.iter().find_map(|e| match e {
+            Effect::DelayPreview(row) => Some(row.clone()),
+            _ => None,
+        })
+    }
+
+    #[test]
+    fn split_view_previews_the_highlighted_row() {
+        let mut app = App::new("default".to_string());
+        app.update(Action::WorkflowsLoaded(
+            vec![
+                workflow_detail("b", "rb").summary,
+                workflow_detail("a", "ra").summary,
+            ],
+            vec![],
+        ));
+        // Too narrow for the pane, so nothing is described.
+        app.update(Action::Resize(SPLIT_MIN_WIDTH - 1));
+        assert!(app.update(Action::ToggleSplitView).is_empty());
+
+        let first = delayed_preview(&app.update(Action::Resize(SPLIT_MIN_WIDTH))).unwrap();
+        assert_eq!(
+            first,
+            PreviewRow::Split(KindId::WorkflowExecution, "b".to_string())
+        );
+
+        // Moving on before the debounce ends skips the row passed over.
+        let second = delayed_preview(&app.update(Action::NavigateDown)).unwrap();
+        assert!(app.update(Action::PreviewDue(first)).is_empty());
+        let effects = app.update(Action::PreviewDue(second));
+        assert!(matches!(
+            &effects[..],
+            [Effect::LoadWorkflowDetail(id, Some(run))] if id == "a" && run == "ra"
+        ));
+
+        // The answer for the row left behind is dropped.
+        app.update(Action::WorkflowDetailLoaded(Box::new(workflow_detail(
+            "b", "rb",
+        ))));
+        assert!(app.selected_workflow.is_none());
+        app.update(Action::WorkflowDetailLoaded(Box::new(workflow_detail(
+            "a", "ra",
+        ))));
+        assert_eq!(
+            app.selected_workflow.as_ref().unwrap().summary.workflow_id,
+            "a"
+        );
+    }
+
+    #[test]
+    fn preview_pane_describes_the_row_the_cursor_rests_on() {
+        let mut app = App::new("default".to_string());
+        app.update(Action::WorkflowsLoaded(
+            vec![
+                workflow_detail("a", "ra").summary,
+                workflow_detail("b", "rb").summary,
+            ],
+            vec![],
+        ));
+        let effects = app.update(Action::SubmitCommandInput("preview".to_string()));
+        assert!(app.preview_pane);
+        let first = delayed_preview(&effects).unwrap();
+        assert!(app.workflow_preview.is_loading());
+
+        // Moving on before the debounce ends skips the row passed over.
+        let second = delayed_preview(&app.update(Action::NavigateDown)).unwrap();
+        assert_ne!(first, second);
+        let (PreviewRow::Workflow(first_id, first_run), PreviewRow::Workflow(id, run)) =
+            (first.clone(), second.clone())
+        else {
+            panic!("the pane waits on workflow rows");
+        };
+        assert!(app.update(Action::PreviewDue(first)).is_empty());
+        let effects = app.update(Action::PreviewDue(second));
+        assert!(matches!(
+            &effects[..],
+            [Effect::LoadWorkflowPreview(i, r)] if *i == id && *r == run
+        ));
+
+        app.update(Action::WorkflowPreviewLoaded(
+            first_id.clone(),
+            first_run.clone(),
+            Ok(Box::new(workflow_detail(&first_id, &first_run))),
+        ));
+        assert!(app.workflow_preview.is_loading());
+        // Nor does a failure for the row passed over.
+        app.update(Action::WorkflowPreviewLoaded(
+            first_id,
+            first_run,
+            Err("not found".to_string()),
+        ));
+        assert!(app.workflow_preview.is_loading());
+        app.update(Action::WorkflowPreviewLoaded(
+            id.clone(),
+            run.clone(),
+            Ok(Box::new(workflow_detail(&id, &run))),
+        ));
+        assert_eq!(app.workflow_preview.data().unwrap().summary.workflow_id, id);
+        // The pane never touches the detail view's workflow.
+        assert!(app.selected_workflow.is_none());
+    }
+
+    #[test]
+    fn archived_runs_open_from_the_list_without_describing() {
+        let mut app = App::new("default".to_string());
+        let generation = app.generation;
+        let effects = app.update(Action::SubmitCommandInput("archived".to_string()));
+        assert!(app.archived_workflows);
+        assert!(app.generation > generation);
+        assert!(matches!(
+            &effects[..],
+            [Effect::LoadWorkflows, Effect::LoadWorkflowCount]
+        ));
+
+        let mut run = workflow_detail("order-1", "run-1").summary;
+        run.status = WorkflowStatus::Completed;
+        app.update(Action::WorkflowsLoaded(vec![run], vec![]));
+        let effects = app.update(Action::Select);
+        assert!(matches!(
+            &effects[..],
+            [Effect::LoadHistory(id, Some(run))] if id == "order-1" && run == "run-1"
+        ));
+        let detail = app.selected_workflow.as_ref().unwrap();
+        assert_eq!(detail.summary.status, WorkflowStatus::Completed);
+        assert!(app.update(Action::Refresh).is_empty());
+
+        app.update(Action::SubmitCommandInput("archived".to_string()));
+        assert!(!app.archived_workflows);
+    }
+
+    #[test]
+    fn status_breakdown_sets_the_total() {
+        let mut app = App::new("default".to_string());
+        app.update(Action::WorkflowStatusCountsLoaded(vec![
+            (WorkflowStatus::Running, 12),
+            (WorkflowStatus::Failed, 3),
+        ]));
+        assert_eq!(app.workflow_count, Some(15));
+        app.update(Action::WorkflowCountLoaded(4));
+        assert!(app.workflow_status_counts.is_empty());
+    }
+
+    #[test]
+    fn connection_loss_shows_reconnecting_once() {
+        let mut app = App::new("default".to_string());
+        app.connection_status = ConnectionStatus::Connected;
+        app.update(Action::ConnectionLost("unavailable".to_string()));
+        app.update(Action::ConnectionLost("unavailable".to_string()));
+        assert_eq!(app.connection_status, ConnectionStatus::Connecting);
+        assert_eq!(app.error_toasts.len(), 1);
+
+        app.update(Action::WorkflowsLoaded(vec![], vec![]));
+        assert_eq!(app.connection_status, ConnectionStatus::Connected);
+    }
+
+    #[test]
+    fn a_key_restarts_reconnecting_after_it_gave_up() {
+        let mut app = App::new("default".to_string());
+        app.connection_status = ConnectionStatus::Connected;
+        app.update(Action::ConnectionLost("unavailable".to_string()));
+        app.update(Action::Disconnected("unavailable".to_string()));
+        app.update(Action::Disconnected("unavailable".to_string()));
+        assert_eq!(app.connection_status, ConnectionStatus::Disconnected);
+        assert_eq!(app.error_toasts.len(), 2);
+        // Polling and health checks wait for the key.
+        app.last_poll = None;
+        app.last_health_check = None;
+        assert!(app.update(Action::Tick).is_empty());
+
+        let effects = app.update(Action::Reconnect);
+        assert!(matches!(effects.as_slice(), [Effect::Reconnect]));
+        assert_eq!(app.connection_status, ConnectionStatus::Connecting);
+        assert!(!app.update(Action::Tick).is_empty());
+    }
+
+    #[test]
+    fn health_checks_track_latency_and_failures() {
+        let mut app = App::new("default".to_string());
+        let effects = app.update(Action::Tick);
+        assert!(effects.iter().any(|e| matches!(e, Effect::HealthCheck)));
+        let effects = app.update(Action::Tick);
+        assert!(!effects.iter().any(|e| matches!(e, Effect::HealthCheck)));
+
+        let info = ClusterInfo {
+            server_version: "1.24.0".to_string(),
+            cluster_name: "active".to_string(),
+            is_cloud: false,
+        };
+        app.update(Action::HealthChecked(Ok((
+            Duration::from_millis(450),
+            Box::new(info),
+        ))));
+        assert_eq!(app.health.level(), HealthLevel::Slow);
+        assert_eq!(app.connection_status, ConnectionStatus::Connected);
+
+        app.update(Action::HealthChecked(Err("unavailable".to_string())));
+        assert_eq!(app.health.level(), HealthLevel::Bad);
+        assert!(app.error_toasts.is_empty());
+    }
 }
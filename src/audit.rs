@@ -0,0 +1,64 @@
+//! Mutations sent this session, listed by `:audit` and, with `audit_file`
+//! set in the config, appended to that file as JSON lines.
+
+use std::io::Write;
+use std::path::Path;
+
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct AuditEntry {
+    pub time: DateTime<Utc>,
+    /// Profile the request went to.
+    pub context: String,
+    pub namespace: String,
+    /// e.g. `terminate workflow`.
+    pub operation: String,
+    /// e.g. `order-1/run-1`.
+    pub target: String,
+    /// `None` when the server accepted the request.
+    pub error: Option<String>,
+}
+
+/// Appends `entry` to `path` as one line of JSON.
+pub fn append(path: &Path, entry: &AuditEntry) -> Result<(), String> {
+    let line = serde_json::to_string(entry).map_err(|e| e.to_string())?;
+    std::fs::OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(path)
+        .and_then(|mut file| writeln!(file, "{}", line))
+        .map_err(|e| format!("{}: {}", path.display(), e))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn entries_append_as_json_lines() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("audit.jsonl");
+        let entry = |error: Option<&str>| AuditEntry {
+            time: Utc::now(),
+            context: "default".to_string(),
+            namespace: "prod".to_string(),
+            operation: "terminate workflow".to_string(),
+            target: "order-1/run-1".to_string(),
+            error: error.map(str::to_string),
+        };
+        append(&path, &entry(None)).unwrap();
+        append(&path, &entry(Some("not found"))).unwrap();
+
+        let content = std::fs::read_to_string(&path).unwrap();
+        let lines: Vec<serde_json::Value> = content
+            .lines()
+            .map(|line| serde_json::from_str(line).unwrap())
+            .collect();
+        assert_eq!(lines.len(), 2);
+        assert_eq!(lines[0]["target"], "order-1/run-1");
+        assert_eq!(lines[0]["error"], serde_json::Value::Null);
+        assert_eq!(lines[1]["error"], "not found");
+    }
+}
@@ -0,0 +1,73 @@
+//! Named deep links saved with `:bookmark`, kept in `bookmarks.toml` next to
+//! the config file so they survive restarts.
+
+use std::path::PathBuf;
+
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Bookmark {
+    pub name: String,
+    /// A `temporal://tui/...` deep link.
+    pub uri: String,
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct BookmarksFile {
+    #[serde(default)]
+    bookmarks: Vec<Bookmark>,
+}
+
+fn path() -> Option<PathBuf> {
+    Some(dirs::config_dir()?.join("t9s").join("bookmarks.toml"))
+}
+
+/// Saved bookmarks; none when the file doesn't exist yet.
+pub fn load() -> Result<Vec<Bookmark>, String> {
+    let Some(path) = path() else {
+        return Ok(vec![]);
+    };
+    match std::fs::read_to_string(&path) {
+        Ok(content) => parse(&content).map_err(|e| format!("{}: {}", path.display(), e)),
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(vec![]),
+        Err(e) => Err(format!("{}: {}", path.display(), e)),
+    }
+}
+
+pub fn save(bookmarks: &[Bookmark]) -> Result<(), String> {
+    let path = path().ok_or("no config directory for bookmarks")?;
+    if let Some(dir) = path.parent() {
+        std::fs::create_dir_all(dir).map_err(|e| format!("{}: {}", dir.display(), e))?;
+    }
+    std::fs::write(&path, render(bookmarks)?).map_err(|e| format!("{}: {}", path.display(), e))
+}
+
+fn parse(content: &str) -> Result<Vec<Bookmark>, String> {
+    toml::from_str::<BookmarksFile>(content)
+        .map(|file| file.bookmarks)
+        .map_err(|e| e.to_string())
+}
+
+fn render(bookmarks: &[Bookmark]) -> Result<String, String> {
+    toml::to_string(&BookmarksFile {
+        bookmarks: bookmarks.to_vec(),
+    })
+    .map_err(|e| e.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn bookmarks_round_trip_through_toml() {
+        let bookmarks = vec![Bookmark {
+            name: "incident".to_string(),
+            uri: "temporal://tui/namespaces/default/workflows?query=ExecutionStatus%3D'Failed'"
+                .to_string(),
+        }];
+        let text = render(&bookmarks).unwrap();
+        assert_eq!(parse(&text).unwrap(), bookmarks);
+        assert_eq!(parse("").unwrap(), vec![]);
+    }
+}
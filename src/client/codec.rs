@@ -0,0 +1,210 @@
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use base64::engine::general_purpose::STANDARD;
+use base64::Engine;
+use serde::{Deserialize, Serialize};
+
+use super::{ClientError, ClientResult};
+use crate::proto::temporal::api::common::v1::Payload;
+
+/// Client for a remote Temporal codec server (the `/decode` endpoint used by
+/// the Web UI and CLI), so encrypted or compressed payloads render as JSON.
+#[derive(Clone)]
+pub struct CodecClient {
+    http: reqwest::Client,
+    endpoint: String,
+    authorization: Option<String>,
+    /// Set while decoding fails, so a codec server that stays down is
+    /// reported once rather than on every refresh.
+    failing: Arc<AtomicBool>,
+    decode_errors: Arc<Mutex<Vec<String>>>,
+}
+
+#[derive(Serialize, Deserialize)]
+struct JsonPayloads {
+    payloads: Vec<JsonPayload>,
+}
+
+#[derive(Serialize, Deserialize)]
+struct JsonPayload {
+    #[serde(default)]
+    metadata: HashMap<String, String>,
+    #[serde(default)]
+    data: String,
+}
+
+impl CodecClient {
+    /// A client whose requests give up after `timeout`, like the Temporal
+    /// requests they decode for.
+    pub fn new(endpoint: &str, authorization: Option<String>, timeout: Duration) -> Self {
+        Self {
+            http: reqwest::Client::builder()
+                .timeout(timeout)
+                .build()
+                .unwrap_or_default(),
+            endpoint: endpoint.trim_end_matches('/').to_string(),
+            authorization,
+            failing: Arc::default(),
+            decode_errors: Arc::default(),
+        }
+    }
+
+    /// Decode payloads in place. Payloads are sent in one request and written
+    /// back in order. The first failure after a success is also kept for
+    /// [`take_decode_errors`](Self::take_decode_errors).
+    pub async fn decode(&self, namespace: &str, payloads: Vec<&mut Payload>) -> ClientResult<()> {
+        if payloads.is_empty() {
+            return Ok(());
+        }
+        let result = self.transform("decode", namespace, payloads).await;
+        match &result {
+            Ok(()) => self.failing.store(false, Ordering::Relaxed),
+            Err(e) => {
+                if !self.failing.swap(true, Ordering::Relaxed) {
+                    if let Ok(mut errors) = self.decode_errors.lock() {
+                        errors.push(e.to_string());
+                    }
+                }
+            }
+        }
+        result
+    }
+
+    /// Decode failures not yet reported.
+    pub fn take_decode_errors(&self) -> Vec<String> {
+        self.decode_errors
+            .lock()
+            .map(|mut errors| std::mem::take(&mut *errors))
+            .unwrap_or_default()
+    }
+
+    /// Encode payloads in place, the way the SDK's codec would before they
+    /// are sent to the server.
+    pub async fn encode(&self, namespace: &str, payloads: Vec<&mut Payload>) -> ClientResult<()> {
+        self.transform("encode", namespace, payloads).await
+    }
+
+    async fn transform(
+        &self,
+        route: &str,
+        namespace: &str,
+        payloads: Vec<&mut Payload>,
+    ) -> ClientResult<()> {
+        if payloads.is_empty() {
+            return Ok(());
+        }
+
+        let body = JsonPayloads {
+            payloads: payloads.iter().map(|p| to_json(p)).collect(),
+        };
+
+        let mut request = self
+            .http
+            .post(format!("{}/{}", self.endpoint, route))
+            .header("X-Namespace", namespace)
+            .json(&body);
+        if let Some(ref auth) = self.authorization {
+            request = request.header("Authorization", auth);
+        }
+
+        let response = request
+            .send()
+            .await
+            .map_err(|e| ClientError::ConnectionError(format!("codec server: {}", e)))?;
+        if !response.status().is_success() {
+            return Err(ClientError::RequestFailed(format!(
+                "codec server returned {}",
+                response.status()
+            )));
+        }
+
+        let decoded: JsonPayloads = response
+            .json()
+            .await
+            .map_err(|e| ClientError::ParseError(format!("codec response: {}", e)))?;
+        if decoded.payloads.len() != payloads.len() {
+            return Err(ClientError::ParseError(format!(
+                "codec returned {} payloads, expected {}",
+                decoded.payloads.len(),
+                payloads.len()
+            )));
+        }
+
+        for (target, json) in payloads.into_iter().zip(decoded.payloads) {
+            *target = from_json(json)?;
+        }
+        Ok(())
+    }
+}
+
+fn to_json(payload: &Payload) -> JsonPayload {
+    JsonPayload {
+        metadata: payload
+            .metadata
+            .iter()
+            .map(|(k, v)| (k.clone(), STANDARD.encode(v)))
+            .collect(),
+        data: STANDARD.encode(&payload.data),
+    }
+}
+
+fn from_json(json: JsonPayload) -> ClientResult<Payload> {
+    let decode = |value: &str| {
+        STANDARD
+            .decode(value)
+            .map_err(|e| ClientError::ParseError(format!("codec base64: {}", e)))
+    };
+    let mut metadata = HashMap::new();
+    for (key, value) in json.metadata {
+        metadata.insert(key, decode(&value)?);
+    }
+    Ok(Payload {
+        metadata,
+        data: decode(&json.data)?,
+        ..Default::default()
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn payloads_travel_as_base64_json() {
+        let payload = Payload {
+            metadata: HashMap::from([("encoding".to_string(), b"binary/encrypted".to_vec())]),
+            data: vec![0, 1, 2],
+            ..Default::default()
+        };
+        let body = JsonPayloads {
+            payloads: vec![to_json(&payload)],
+        };
+        assert_eq!(
+            serde_json::to_value(&body).unwrap(),
+            serde_json::json!({
+                "payloads": [{
+                    "metadata": {"encoding": "YmluYXJ5L2VuY3J5cHRlZA=="},
+                    "data": "AAEC",
+                }]
+            })
+        );
+
+        // The codec server's answer, with metadata left out.
+        let response: JsonPayloads = serde_json::from_value(serde_json::json!({
+            "payloads": [{"data": "eyJhIjoxfQ=="}]
+        }))
+        .unwrap();
+        let decoded = from_json(response.payloads.into_iter().next().unwrap()).unwrap();
+        assert!(decoded.metadata.is_empty());
+        assert_eq!(decoded.data, br#"{"a":1}"#);
+
+        let bad = JsonPayload {
+            metadata: HashMap::new(),
+            data: "not base64!".to_string(),
+        };
+        assert!(from_json(bad).is_err());
+    }
+}
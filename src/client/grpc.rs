@@ -1,23 +1,51 @@
+use std::collections::HashMap;
+use std::future::Future;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, RwLock};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
 use async_trait::async_trait;
 use chrono::{DateTime, TimeZone, Utc};
 use tonic::metadata::AsciiMetadataValue;
 use tonic::service::Interceptor;
 use tonic::transport::{Channel, ClientTlsConfig, Endpoint};
-use tonic::{Request, Status};
-
-use super::{ClientError, ClientResult, TemporalClient};
+use tonic::{Request, Response, Status};
+
+use super::oidc::OidcSession;
+use super::tls::{InsecureConnector, TlsOptions};
+use super::visibility;
+use super::{
+    ClientError, ClientResult, CodecClient, ExecutionAdmin, NamespaceAdmin, ScheduleAdmin,
+    StartAttributes, TaskQueueReader, TemporalClient, VisibilityReader,
+};
 use crate::domain::*;
-use crate::proto::{self, WorkflowServiceClient};
+use crate::input::query_syntax;
+use crate::proto::{self, OperatorServiceClient, WorkflowServiceClient};
+
+/// Credentials sent in the `authorization` header.
+#[derive(Clone)]
+pub enum Auth {
+    ApiKey(String),
+    /// Bearer token read from the session on every call, so background
+    /// refreshes take effect without reconnecting.
+    Oidc(Arc<OidcSession>),
+}
 
 #[derive(Clone)]
 struct ApiKeyInterceptor {
     api_key: Option<AsciiMetadataValue>,
+    oidc: Option<Arc<OidcSession>>,
     namespace: Option<AsciiMetadataValue>,
 }
 
 impl Interceptor for ApiKeyInterceptor {
     fn call(&mut self, mut request: Request<()>) -> Result<Request<()>, Status> {
-        if let Some(ref token) = self.api_key {
+        if let Some(ref session) = self.oidc {
+            let token = format!("Bearer {}", session.access_token())
+                .parse::<AsciiMetadataValue>()
+                .map_err(|_| Status::unauthenticated("OIDC token is not a valid header"))?;
+            request.metadata_mut().insert("authorization", token);
+        } else if let Some(ref token) = self.api_key {
             request
                 .metadata_mut()
                 .insert("authorization", token.clone());
@@ -31,52 +59,173 @@ impl Interceptor for ApiKeyInterceptor {
     }
 }
 
-type InterceptedClient = WorkflowServiceClient<
-    tonic::service::interceptor::InterceptedService<Channel, ApiKeyInterceptor>,
->;
+type InterceptedChannel =
+    tonic::service::interceptor::InterceptedService<Channel, ApiKeyInterceptor>;
+type InterceptedClient = WorkflowServiceClient<InterceptedChannel>;
+type InterceptedOperator = OperatorServiceClient<InterceptedChannel>;
+
+/// The channel in use plus what is needed to open a new one after the server
+/// becomes unreachable.
+struct Connection {
+    channel: RwLock<Channel>,
+    endpoint: Endpoint,
+    insecure: Option<InsecureConnector>,
+    interceptor: ApiKeyInterceptor,
+    reconnecting: AtomicBool,
+    /// Set when a reconnect loop runs out of attempts. No new one starts
+    /// until [`Connection::retry`] is called.
+    gave_up: AtomicBool,
+}
+
+async fn open_channel(
+    endpoint: &Endpoint,
+    insecure: Option<&InsecureConnector>,
+) -> Result<Channel, tonic::transport::Error> {
+    match insecure {
+        Some(connector) => endpoint.connect_with_connector(connector.clone()).await,
+        None => endpoint.connect().await,
+    }
+}
+
+impl Connection {
+    /// Starts a background loop that reconnects with exponential backoff and
+    /// swaps the new channel in. Does nothing if one is already running or
+    /// the last one gave up.
+    fn start_reconnect(self: &Arc<Self>) {
+        if self.gave_up.load(Ordering::SeqCst) || self.reconnecting.swap(true, Ordering::SeqCst) {
+            return;
+        }
+        let conn = Arc::clone(self);
+        tokio::spawn(async move {
+            let mut delay = Duration::from_millis(500);
+            for _ in 0..RECONNECT_ATTEMPTS {
+                tokio::time::sleep(delay).await;
+                match open_channel(&conn.endpoint, conn.insecure.as_ref()).await {
+                    Ok(channel) => {
+                        *conn.channel.write().unwrap_or_else(|e| e.into_inner()) = channel;
+                        conn.reconnecting.store(false, Ordering::SeqCst);
+                        tracing::info!("Reconnected to {}", conn.endpoint.uri());
+                        return;
+                    }
+                    Err(e) => {
+                        tracing::warn!("Reconnect to {} failed: {}", conn.endpoint.uri(), e);
+                        delay = (delay * 2).min(RECONNECT_MAX_DELAY);
+                    }
+                }
+            }
+            tracing::warn!(
+                "Gave up reconnecting to {} after {} attempts",
+                conn.endpoint.uri(),
+                RECONNECT_ATTEMPTS
+            );
+            // Set first so requests never see neither flag.
+            conn.gave_up.store(true, Ordering::SeqCst);
+            conn.reconnecting.store(false, Ordering::SeqCst);
+        });
+    }
+
+    /// Starts reconnecting again after a loop gave up.
+    fn retry(self: &Arc<Self>) {
+        if self.gave_up.swap(false, Ordering::SeqCst) {
+            self.start_reconnect();
+        }
+    }
+}
+
+/// Attempts before reconnecting waits for [`TemporalClient::reconnect`];
+/// with the backoff, about a minute and a half.
+const RECONNECT_ATTEMPTS: u32 = 8;
+const RECONNECT_MAX_DELAY: Duration = Duration::from_secs(30);
+
+/// Deadline and retry settings applied to every gRPC call.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RequestPolicy {
+    pub timeout: Duration,
+    /// Extra attempts for reads that fail with Unavailable, DeadlineExceeded
+    /// or ResourceExhausted. Writes are never retried.
+    pub read_retries: u32,
+    pub base_delay: Duration,
+}
+
+impl Default for RequestPolicy {
+    fn default() -> Self {
+        Self {
+            timeout: Duration::from_secs(10),
+            read_retries: 2,
+            base_delay: Duration::from_millis(200),
+        }
+    }
+}
+
+impl RequestPolicy {
+    /// Full jitter: a random delay up to `base_delay * 2^(attempt-1)`, capped
+    /// at the timeout.
+    pub fn backoff(&self, attempt: u32) -> Duration {
+        let ceiling = self
+            .base_delay
+            .saturating_mul(1 << attempt.saturating_sub(1).min(16))
+            .min(self.timeout);
+        let nanos = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|d| d.subsec_nanos())
+            .unwrap_or(0);
+        ceiling.mul_f64(f64::from(nanos % 1000) / 1000.0)
+    }
+}
 
 pub struct GrpcTemporalClient {
-    client: InterceptedClient,
+    conn: Arc<Connection>,
+    policy: RequestPolicy,
+    address: String,
+    codec: Option<CodecClient>,
     #[allow(dead_code)]
     namespace: String,
+    /// Namespaces where `ListWorkflowExecutions` turned out to need advanced
+    /// visibility this server doesn't have.
+    standard_visibility: visibility::StandardNamespaces,
+    /// Set once a `GROUP BY` count is rejected, so later polls go straight
+    /// to one count per status.
+    ungrouped_counts: AtomicBool,
 }
 
 impl GrpcTemporalClient {
     pub async fn connect(
         address: &str,
         namespace: String,
-        api_key: Option<String>,
-        tls_cert: Option<String>,
-        tls_key: Option<String>,
+        auth: Option<Auth>,
+        tls: TlsOptions,
     ) -> ClientResult<Self> {
         tracing::info!("Connecting to Temporal at {}", address);
 
-        let is_localhost = address.starts_with("localhost")
-            || address.starts_with("127.0.0.1")
-            || address.starts_with("[::1]");
-
-        let use_tls = !is_localhost || api_key.is_some();
+        let use_tls = uses_tls(address, auth.is_some()) || tls.requires_tls();
+        let insecure = if use_tls && tls.insecure_skip_verify {
+            tracing::warn!("TLS certificate verification is disabled for {}", address);
+            Some(InsecureConnector::new(&tls)?)
+        } else {
+            None
+        };
 
-        let scheme = if use_tls { "https" } else { "http" };
+        // The insecure connector does its own TLS over a plain endpoint.
+        let scheme = if use_tls && insecure.is_none() {
+            "https"
+        } else {
+            "http"
+        };
         let endpoint_url = format!("{}://{}", scheme, address);
 
         let mut endpoint = Endpoint::from_shared(endpoint_url.clone())
             .map_err(|e| ClientError::ConnectionError(format!("invalid endpoint: {}", e)))?;
 
-        if use_tls {
+        if use_tls && insecure.is_none() {
             let mut tls_config = ClientTlsConfig::new().with_native_roots();
-
+            if let Some(ca) = tls.ca_pem()? {
+                tls_config = tls_config.ca_certificate(tonic::transport::Certificate::from_pem(ca));
+            }
+            if let Some(name) = &tls.server_name {
+                tls_config = tls_config.domain_name(name.clone());
+            }
             // mTLS client certificates
-            if let (Some(cert_path), Some(key_path)) = (tls_cert, tls_key) {
-                let cert = std::fs::read(&cert_path).map_err(|e| {
-                    ClientError::ConfigError(format!(
-                        "failed to read TLS cert {}: {}",
-                        cert_path, e
-                    ))
-                })?;
-                let key = std::fs::read(&key_path).map_err(|e| {
-                    ClientError::ConfigError(format!("failed to read TLS key {}: {}", key_path, e))
-                })?;
+            if let Some((cert, key)) = tls.identity()? {
                 let identity = tonic::transport::Identity::from_pem(cert, key);
                 tls_config = tls_config.identity(identity);
             }
@@ -86,644 +235,1194 @@ impl GrpcTemporalClient {
                 .map_err(|e| ClientError::ConnectionError(format!("TLS config error: {}", e)))?;
         }
 
-        let channel = endpoint.connect().await.map_err(|e| {
-            tracing::error!("Connection failed to {}: {}", endpoint_url, e);
-            ClientError::ConnectionError(format!("failed to connect: {}", e))
-        })?;
+        let channel = open_channel(&endpoint, insecure.as_ref())
+            .await
+            .map_err(|e| {
+                tracing::error!("Connection failed to {}: {}", endpoint_url, e);
+                ClientError::ConnectionError(format!("failed to connect: {}", e))
+            })?;
 
         tracing::info!("Connected to Temporal successfully");
 
         let interceptor = ApiKeyInterceptor {
-            api_key: api_key
-                .as_ref()
-                .and_then(|key| format!("Bearer {}", key).parse::<AsciiMetadataValue>().ok()),
+            api_key: match &auth {
+                Some(Auth::ApiKey(key)) => format!("Bearer {}", key).parse().ok(),
+                _ => None,
+            },
+            oidc: match auth {
+                Some(Auth::Oidc(session)) => Some(session),
+                _ => None,
+            },
             namespace: namespace.parse::<AsciiMetadataValue>().ok(),
         };
 
-        let client = WorkflowServiceClient::with_interceptor(channel, interceptor);
+        Ok(Self {
+            conn: Arc::new(Connection {
+                channel: RwLock::new(channel),
+                endpoint,
+                insecure,
+                interceptor,
+                reconnecting: AtomicBool::new(false),
+                gave_up: AtomicBool::new(false),
+            }),
+            policy: RequestPolicy::default(),
+            address: address.to_string(),
+            codec: None,
+            namespace,
+            standard_visibility: Default::default(),
+            ungrouped_counts: AtomicBool::new(false),
+        })
+    }
 
-        Ok(Self { client, namespace })
+    fn channel(&self) -> InterceptedChannel {
+        let channel = self
+            .conn
+            .channel
+            .read()
+            .unwrap_or_else(|e| e.into_inner())
+            .clone();
+        InterceptedChannel::new(channel, self.conn.interceptor.clone())
     }
 
-    fn make_request<T>(&self, inner: T) -> Request<T> {
-        Request::new(inner)
+    fn client(&self) -> InterceptedClient {
+        WorkflowServiceClient::new(self.channel())
     }
 
-    fn wf_execution(
-        workflow_id: &str,
-        run_id: Option<&str>,
-    ) -> proto::temporal::api::common::v1::WorkflowExecution {
-        proto::temporal::api::common::v1::WorkflowExecution {
-            workflow_id: workflow_id.to_string(),
-            run_id: run_id.unwrap_or("").to_string(),
-        }
+    fn operator(&self) -> InterceptedOperator {
+        OperatorServiceClient::new(self.channel())
     }
-}
 
-#[async_trait]
-impl TemporalClient for GrpcTemporalClient {
-    async fn list_namespaces(&self) -> ClientResult<Vec<Namespace>> {
-        let inner = proto::ListNamespacesRequest {
-            page_size: 100,
-            next_page_token: vec![],
-            namespace_filter: None,
-        };
+    /// Maps a failed call, starting a reconnect when the server is unreachable.
+    fn status_error(&self, status: Status) -> ClientError {
+        if status.code() == tonic::Code::Unavailable {
+            self.conn.start_reconnect();
+            self.standard_visibility.clear();
+        }
+        grpc_error(status)
+    }
 
+    /// Calls `GetSystemInfo`, which requires valid credentials, and returns the
+    /// server version.
+    pub async fn get_system_info(&self) -> ClientResult<String> {
         let response = self
-            .client
-            .clone()
-            .list_namespaces(self.make_request(inner))
-            .await
-            .map_err(grpc_error)?;
-
-        let namespaces = response
-            .into_inner()
-            .namespaces
-            .into_iter()
-            .filter_map(|desc| {
-                let info = desc.namespace_info?;
-                let config = desc.config;
-                Some(Namespace {
-                    name: info.name,
-                    state: format!("{:?}", info.state),
-                    description: info.description,
-                    owner_email: info.owner_email,
-                    retention: config
-                        .and_then(|c| c.workflow_execution_retention_ttl)
-                        .map(|d| std::time::Duration::new(d.seconds as u64, d.nanos as u32)),
-                })
-            })
-            .collect();
-
-        Ok(namespaces)
+            .read(
+                proto::GetSystemInfoRequest {},
+                |mut client, request| async move { client.get_system_info(request).await },
+            )
+            .await?;
+        Ok(response.into_inner().server_version)
     }
 
-    async fn list_workflows(
-        &self,
-        namespace: &str,
-        query: Option<&str>,
-        page_size: i32,
-        next_page_token: Vec<u8>,
-    ) -> ClientResult<(Vec<WorkflowSummary>, Vec<u8>)> {
-        let inner = proto::ListWorkflowExecutionsRequest {
+    pub async fn describe_namespace(&self, namespace: &str) -> ClientResult<()> {
+        let inner = proto::DescribeNamespaceRequest {
             namespace: namespace.to_string(),
-            page_size,
-            next_page_token,
-            query: query.unwrap_or("").to_string(),
+            id: String::new(),
+            weak_consistency: false,
         };
-
-        let response = self
-            .client
-            .clone()
-            .list_workflow_executions(self.make_request(inner))
-            .await
-            .map_err(grpc_error)?;
-
-        let resp = response.into_inner();
-        let workflows = resp
-            .executions
-            .into_iter()
-            .map(workflow_info_to_summary)
-            .collect::<Result<Vec<_>, _>>()?;
-
-        Ok((workflows, resp.next_page_token))
+        self.read(inner, |mut client, request| async move {
+            client.describe_namespace(request).await
+        })
+        .await?;
+        Ok(())
     }
 
-    async fn describe_workflow(
+    /// Starts a workflow with a JSON input and returns its run id.
+    pub async fn start_workflow(
         &self,
         namespace: &str,
         workflow_id: &str,
-        run_id: Option<&str>,
-    ) -> ClientResult<WorkflowDetail> {
-        let inner = proto::DescribeWorkflowExecutionRequest {
+        workflow_type: &str,
+        task_queue: &str,
+        input: Option<&serde_json::Value>,
+    ) -> ClientResult<String> {
+        let inner = proto::StartWorkflowExecutionRequest {
             namespace: namespace.to_string(),
-            execution: Some(Self::wf_execution(workflow_id, run_id)),
+            workflow_id: workflow_id.to_string(),
+            workflow_type: Some(proto::temporal::api::common::v1::WorkflowType {
+                name: workflow_type.to_string(),
+            }),
+            task_queue: Some(proto::temporal::api::taskqueue::v1::TaskQueue {
+                name: task_queue.to_string(),
+                ..Default::default()
+            }),
+            input: input.map(json_payloads),
+            identity: "t9s".to_string(),
+            request_id: uuid::Uuid::new_v4().to_string(),
+            ..Default::default()
         };
-
         let response = self
-            .client
-            .clone()
-            .describe_workflow_execution(self.make_request(inner))
-            .await
-            .map_err(grpc_error)?;
-
-        let resp = response.into_inner();
-        let info = resp
-            .workflow_execution_info
-            .ok_or_else(|| ClientError::ParseError("missing workflow execution info".into()))?;
+            .write(inner, |mut client, request| async move {
+                client.start_workflow_execution(request).await
+            })
+            .await?;
+        Ok(response.into_inner().run_id)
+    }
 
-        let history_length = info.history_length as u64;
-        let summary = workflow_info_to_summary(info)?;
+    /// Creates a schedule that starts `workflow_type` on either an interval
+    /// (in seconds) or a cron string.
+    #[allow(clippy::too_many_arguments)]
+    pub async fn create_schedule(
+        &self,
+        namespace: &str,
+        schedule_id: &str,
+        workflow_type: &str,
+        task_queue: &str,
+        interval_secs: Option<i64>,
+        cron: Option<&str>,
+        paused: bool,
+    ) -> ClientResult<()> {
+        use proto::temporal::api::schedule::v1 as schedule;
 
-        let pending_activities = resp
-            .pending_activities
-            .into_iter()
-            .map(|pa| PendingActivity {
-                activity_id: pa.activity_id,
-                activity_type: pa.activity_type.map(|t| t.name).unwrap_or_default(),
-                state: match pa.state {
-                    1 => PendingActivityState::Scheduled,
-                    2 => PendingActivityState::Started,
-                    3 => PendingActivityState::CancelRequested,
-                    _ => PendingActivityState::Scheduled,
+        let spec = schedule::ScheduleSpec {
+            interval: interval_secs
+                .map(|seconds| schedule::IntervalSpec {
+                    interval: Some(pbjson_types::Duration { seconds, nanos: 0 }),
+                    phase: None,
+                })
+                .into_iter()
+                .collect(),
+            cron_string: cron.map(str::to_string).into_iter().collect(),
+            ..Default::default()
+        };
+        let action = schedule::ScheduleAction {
+            action: Some(schedule::schedule_action::Action::StartWorkflow(
+                proto::temporal::api::workflow::v1::NewWorkflowExecutionInfo {
+                    workflow_id: format!("{}-run", schedule_id),
+                    workflow_type: Some(proto::temporal::api::common::v1::WorkflowType {
+                        name: workflow_type.to_string(),
+                    }),
+                    task_queue: Some(proto::temporal::api::taskqueue::v1::TaskQueue {
+                        name: task_queue.to_string(),
+                        ..Default::default()
+                    }),
+                    ..Default::default()
                 },
-                attempt: pa.attempt,
-                scheduled_time: pa.scheduled_time.map(|t| timestamp_to_datetime(&t)),
-                last_started_time: pa.last_started_time.map(|t| timestamp_to_datetime(&t)),
-                last_heartbeat_time: pa.last_heartbeat_time.map(|t| timestamp_to_datetime(&t)),
-                last_failure_message: pa.last_failure.map(|f| f.message),
-            })
-            .collect();
-
-        Ok(WorkflowDetail {
-            summary,
-            input: None,
-            output: None,
-            failure: None,
-            history_length,
-            memo: std::collections::HashMap::new(),
-            search_attributes: std::collections::HashMap::new(),
-            pending_activities,
+            )),
+        };
+        let inner = proto::CreateScheduleRequest {
+            namespace: namespace.to_string(),
+            schedule_id: schedule_id.to_string(),
+            schedule: Some(schedule::Schedule {
+                spec: Some(spec),
+                action: Some(action),
+                policies: None,
+                state: Some(schedule::ScheduleState {
+                    paused,
+                    notes: if paused {
+                        "paused by t9s dev seed".to_string()
+                    } else {
+                        String::new()
+                    },
+                    ..Default::default()
+                }),
+            }),
+            identity: "t9s".to_string(),
+            request_id: uuid::Uuid::new_v4().to_string(),
+            ..Default::default()
+        };
+        self.write(inner, |mut client, request| async move {
+            client.create_schedule(request).await
         })
+        .await?;
+        Ok(())
     }
 
-    async fn get_history(
+    pub fn with_codec(mut self, codec: CodecClient) -> Self {
+        self.codec = Some(codec);
+        self
+    }
+
+    pub fn with_request_policy(mut self, policy: RequestPolicy) -> Self {
+        self.policy = policy;
+        self
+    }
+
+    /// Run payloads through the codec server when one is configured. On a
+    /// failure the raw payloads are kept so the view still renders, and the
+    /// error is shown through [`TemporalClient::take_decode_errors`].
+    async fn codec_decode(
         &self,
         namespace: &str,
-        workflow_id: &str,
-        run_id: Option<&str>,
-    ) -> ClientResult<Vec<HistoryEvent>> {
-        let mut all_events = Vec::new();
-        let mut next_page_token = vec![];
-
-        loop {
-            let inner = proto::GetWorkflowExecutionHistoryRequest {
-                namespace: namespace.to_string(),
-                execution: Some(Self::wf_execution(workflow_id, run_id)),
-                maximum_page_size: 200,
-                next_page_token: next_page_token.clone(),
-                wait_new_event: false,
-                history_event_filter_type: 0,
-                skip_archival: false,
-            };
+        payloads: Vec<&mut proto::temporal::api::common::v1::Payload>,
+    ) {
+        let Some(ref codec) = self.codec else {
+            return;
+        };
+        if let Err(e) = codec.decode(namespace, payloads).await {
+            tracing::warn!("codec decode failed: {}", e);
+        }
+    }
 
-            let response = self
-                .client
-                .clone()
-                .get_workflow_execution_history(self.make_request(inner))
-                .await
-                .map_err(grpc_error)?;
+    /// Encodes payloads headed for the server through the codec server, when
+    /// one is configured. Unlike decoding, a failure is an error: sending the
+    /// plaintext instead would bypass the codec.
+    async fn codec_encode(
+        &self,
+        namespace: &str,
+        payloads: Vec<&mut proto::temporal::api::common::v1::Payload>,
+    ) -> ClientResult<()> {
+        match self.codec {
+            Some(ref codec) => codec.encode(namespace, payloads).await,
+            None => Ok(()),
+        }
+    }
 
-            let resp = response.into_inner();
-            if let Some(history) = resp.history {
-                for e in history.events {
-                    let details = extract_event_details(&e);
-                    all_events.push(HistoryEvent {
-                        event_id: e.event_id,
-                        event_type: event_type_name(e.event_type),
-                        timestamp: e
-                            .event_time
-                            .map(|t| timestamp_to_datetime(&t))
-                            .unwrap_or_else(Utc::now),
-                        details,
-                    });
-                }
-            }
+    fn make_request<T>(&self, inner: T) -> Request<T> {
+        let mut request = Request::new(inner);
+        request.set_timeout(self.policy.timeout);
+        request
+    }
 
-            if resp.next_page_token.is_empty() {
-                break;
+    /// Sends one RPC under the request policy: every attempt gets the timeout,
+    /// and with `retry` transient failures are retried after a jittered
+    /// backoff. Only idempotent calls should retry.
+    async fn call<C, T, R, F, Fut>(
+        &self,
+        client: impl Fn() -> C,
+        inner: T,
+        retry: bool,
+        rpc: F,
+    ) -> Result<Response<R>, Status>
+    where
+        T: Clone,
+        F: Fn(C, Request<T>) -> Fut,
+        Fut: Future<Output = Result<Response<R>, Status>>,
+    {
+        let mut attempt = 0;
+        loop {
+            let request = self.make_request(inner.clone());
+            let status =
+                match tokio::time::timeout(self.policy.timeout, rpc(client(), request)).await {
+                    Ok(Ok(response)) => return Ok(response),
+                    Ok(Err(status)) => status,
+                    Err(_) => Status::deadline_exceeded(format!(
+                        "no response within {:?}",
+                        self.policy.timeout
+                    )),
+                };
+            let transient = matches!(
+                status.code(),
+                tonic::Code::Unavailable
+                    | tonic::Code::DeadlineExceeded
+                    | tonic::Code::ResourceExhausted
+            );
+            if !retry || !transient || attempt >= self.policy.read_retries {
+                return Err(status);
             }
-            next_page_token = resp.next_page_token;
+            attempt += 1;
+            let delay = self.policy.backoff(attempt);
+            tracing::debug!("retrying after {:?} ({}): {}", delay, attempt, status);
+            tokio::time::sleep(delay).await;
         }
+    }
 
-        Ok(all_events)
+    async fn read<T, R, F, Fut>(&self, inner: T, rpc: F) -> ClientResult<Response<R>>
+    where
+        T: Clone,
+        F: Fn(InterceptedClient, Request<T>) -> Fut,
+        Fut: Future<Output = Result<Response<R>, Status>>,
+    {
+        self.call(|| self.client(), inner, true, rpc)
+            .await
+            .map_err(|status| self.status_error(status))
     }
 
-    async fn count_workflows(&self, namespace: &str, query: Option<&str>) -> ClientResult<u64> {
-        let inner = proto::CountWorkflowExecutionsRequest {
-            namespace: namespace.to_string(),
-            query: query.unwrap_or("").to_string(),
-        };
+    async fn write<T, R, F, Fut>(&self, inner: T, rpc: F) -> ClientResult<Response<R>>
+    where
+        T: Clone,
+        F: Fn(InterceptedClient, Request<T>) -> Fut,
+        Fut: Future<Output = Result<Response<R>, Status>>,
+    {
+        self.call(|| self.client(), inner, false, rpc)
+            .await
+            .map_err(|status| self.status_error(status))
+    }
 
-        let response = self
-            .client
-            .clone()
-            .count_workflow_executions(self.make_request(inner))
+    async fn operator_read<T, R, F, Fut>(&self, inner: T, rpc: F) -> ClientResult<Response<R>>
+    where
+        T: Clone,
+        F: Fn(InterceptedOperator, Request<T>) -> Fut,
+        Fut: Future<Output = Result<Response<R>, Status>>,
+    {
+        self.call(|| self.operator(), inner, true, rpc)
             .await
-            .map_err(grpc_error)?;
+            .map_err(|status| self.status_error(status))
+    }
 
-        Ok(response.into_inner().count as u64)
+    async fn operator_write<T, R, F, Fut>(&self, inner: T, rpc: F) -> ClientResult<Response<R>>
+    where
+        T: Clone,
+        F: Fn(InterceptedOperator, Request<T>) -> Fut,
+        Fut: Future<Output = Result<Response<R>, Status>>,
+    {
+        self.call(|| self.operator(), inner, false, rpc)
+            .await
+            .map_err(|status| self.status_error(status))
     }
 
-    async fn cancel_workflow(
-        &self,
-        namespace: &str,
+    fn wf_execution(
         workflow_id: &str,
         run_id: Option<&str>,
-    ) -> ClientResult<()> {
-        let inner = proto::RequestCancelWorkflowExecutionRequest {
+    ) -> proto::temporal::api::common::v1::WorkflowExecution {
+        proto::temporal::api::common::v1::WorkflowExecution {
+            workflow_id: workflow_id.to_string(),
+            run_id: run_id.unwrap_or("").to_string(),
+        }
+    }
+
+    async fn describe_schedule_response(
+        &self,
+        namespace: &str,
+        schedule_id: &str,
+    ) -> ClientResult<proto::DescribeScheduleResponse> {
+        let inner = proto::DescribeScheduleRequest {
             namespace: namespace.to_string(),
-            workflow_execution: Some(Self::wf_execution(workflow_id, run_id)),
-            identity: "t9s".to_string(),
-            request_id: uuid::Uuid::new_v4().to_string(),
-            first_execution_run_id: String::new(),
-            reason: String::new(),
-            links: vec![],
+            schedule_id: schedule_id.to_string(),
         };
 
-        self.client
-            .clone()
-            .request_cancel_workflow_execution(self.make_request(inner))
-            .await
-            .map_err(grpc_error)?;
+        let response = self
+            .read(inner, |mut client, request| async move {
+                client.describe_schedule(request).await
+            })
+            .await?;
 
-        Ok(())
+        Ok(response.into_inner())
     }
 
-    async fn terminate_workflow(
+    /// Task queue state for one task type.
+    async fn describe_task_queue_type(
         &self,
         namespace: &str,
-        workflow_id: &str,
-        run_id: Option<&str>,
-        reason: &str,
-    ) -> ClientResult<()> {
-        let inner = proto::TerminateWorkflowExecutionRequest {
+        task_queue: &str,
+        task_queue_type: i32,
+    ) -> ClientResult<proto::DescribeTaskQueueResponse> {
+        #[allow(deprecated)]
+        let inner = proto::DescribeTaskQueueRequest {
             namespace: namespace.to_string(),
-            workflow_execution: Some(Self::wf_execution(workflow_id, run_id)),
-            reason: reason.to_string(),
-            identity: "t9s".to_string(),
-            details: None,
-            first_execution_run_id: String::new(),
-            links: vec![],
+            task_queue: Some(proto::temporal::api::taskqueue::v1::TaskQueue {
+                name: task_queue.to_string(),
+                kind: 0,
+                normal_name: String::new(),
+            }),
+            task_queue_type,
+            include_task_queue_status: true,
+            api_mode: 0,
+            versions: None,
+            task_queue_types: vec![],
+            report_stats: true,
+            report_config: false,
+            report_pollers: true,
+            report_task_reachability: false,
         };
 
-        self.client
-            .clone()
-            .terminate_workflow_execution(self.make_request(inner))
-            .await
-            .map_err(grpc_error)?;
-
-        Ok(())
+        let response = self
+            .read(inner, |mut client, request| async move {
+                client.describe_task_queue(request).await
+            })
+            .await?;
+        Ok(response.into_inner())
     }
 
-    async fn signal_workflow(
+    /// [`schedule_edit`] with the input decoded, as the user edits it.
+    async fn decoded_schedule_edit(
         &self,
         namespace: &str,
-        workflow_id: &str,
-        run_id: Option<&str>,
-        signal_name: &str,
-        input: Option<&str>,
-    ) -> ClientResult<()> {
-        let signal_input = input.map(|i| proto::temporal::api::common::v1::Payloads {
-            payloads: vec![proto::temporal::api::common::v1::Payload {
-                metadata: std::collections::HashMap::new(),
-                data: i.as_bytes().to_vec(),
-                external_payloads: vec![],
-            }],
-        });
+        schedule: &proto::temporal::api::schedule::v1::Schedule,
+    ) -> ScheduleEdit {
+        let mut schedule = schedule.clone();
+        self.codec_decode(namespace, schedule_input_mut(&mut schedule))
+            .await;
+        schedule_edit(&schedule)
+    }
 
-        #[allow(deprecated)]
-        let inner = proto::SignalWorkflowExecutionRequest {
+    /// Lists through `ListOpen`/`ListClosedWorkflowExecutions`, open runs
+    /// first. A page token's first byte says which of the two it continues.
+    async fn list_workflow_executions(
+        &self,
+        namespace: &str,
+        query: Option<&str>,
+        page_size: i32,
+        next_page_token: Vec<u8>,
+    ) -> ClientResult<proto::ListWorkflowExecutionsResponse> {
+        let inner = proto::ListWorkflowExecutionsRequest {
             namespace: namespace.to_string(),
-            workflow_execution: Some(Self::wf_execution(workflow_id, run_id)),
-            signal_name: signal_name.to_string(),
-            input: signal_input,
-            identity: "t9s".to_string(),
-            request_id: uuid::Uuid::new_v4().to_string(),
-            control: String::new(),
-            header: None,
-            links: vec![],
+            page_size,
+            next_page_token,
+            query: query.unwrap_or("").to_string(),
         };
-
-        self.client
-            .clone()
-            .signal_workflow_execution(self.make_request(inner))
-            .await
-            .map_err(grpc_error)?;
-
-        Ok(())
+        let response = self
+            .read(inner, |mut client, request| async move {
+                client.list_workflow_executions(request).await
+            })
+            .await?;
+        Ok(response.into_inner())
     }
 
-    async fn list_schedules(
+    async fn list_workflows_standard(
         &self,
         namespace: &str,
         query: Option<&str>,
-    ) -> ClientResult<Vec<Schedule>> {
-        let inner = proto::ListSchedulesRequest {
-            namespace: namespace.to_string(),
-            maximum_page_size: 100,
-            next_page_token: vec![],
-            query: query.unwrap_or("").to_string(),
+        page_size: i32,
+        next_page_token: Vec<u8>,
+    ) -> ClientResult<(Vec<WorkflowSummary>, Vec<u8>)> {
+        use proto::temporal::api::filter::v1::{
+            StartTimeFilter, StatusFilter, WorkflowExecutionFilter, WorkflowTypeFilter,
+        };
+        use proto::temporal::api::workflowservice::v1::{
+            list_closed_workflow_executions_request as closed_request,
+            list_open_workflow_executions_request as open_request,
+            ListClosedWorkflowExecutionsRequest, ListOpenWorkflowExecutionsRequest,
         };
 
-        let response = self
-            .client
+        let filter = visibility::StandardQuery::parse(query.unwrap_or(""))
+            .map_err(ClientError::InvalidArgument)?;
+        let (closed, token) = match next_page_token.split_first() {
+            Some((&phase, rest)) => (phase == 1, rest.to_vec()),
+            None => (!filter.includes_open(), vec![]),
+        };
+        let start_time_filter = (filter.earliest.is_some() || filter.latest.is_some()).then(|| {
+            let timestamp = |t: DateTime<Utc>| pbjson_types::Timestamp {
+                seconds: t.timestamp(),
+                nanos: t.timestamp_subsec_nanos() as i32,
+            };
+            StartTimeFilter {
+                earliest_time: filter.earliest.map(timestamp),
+                latest_time: filter.latest.map(timestamp),
+            }
+        });
+        let execution = filter
+            .workflow_id
             .clone()
-            .list_schedules(self.make_request(inner))
-            .await
-            .map_err(grpc_error)?;
+            .map(|workflow_id| WorkflowExecutionFilter {
+                workflow_id,
+                run_id: String::new(),
+            });
+        let workflow_type = filter
+            .workflow_type
+            .clone()
+            .map(|name| WorkflowTypeFilter { name });
 
-        let schedules = response
-            .into_inner()
-            .schedules
+        let (executions, next) = if closed {
+            let status = filter.status.as_ref().map(|status| StatusFilter {
+                status: domain_status_to_proto(status),
+            });
+            let inner = ListClosedWorkflowExecutionsRequest {
+                namespace: namespace.to_string(),
+                maximum_page_size: page_size,
+                next_page_token: token,
+                start_time_filter,
+                filters: execution
+                    .map(closed_request::Filters::ExecutionFilter)
+                    .or(workflow_type.map(closed_request::Filters::TypeFilter))
+                    .or(status.map(closed_request::Filters::StatusFilter)),
+            };
+            let resp = self
+                .read(inner, |mut client, request| async move {
+                    client.list_closed_workflow_executions(request).await
+                })
+                .await?
+                .into_inner();
+            (resp.executions, resp.next_page_token)
+        } else {
+            let inner = ListOpenWorkflowExecutionsRequest {
+                namespace: namespace.to_string(),
+                maximum_page_size: page_size,
+                next_page_token: token,
+                start_time_filter,
+                filters: execution
+                    .map(open_request::Filters::ExecutionFilter)
+                    .or(workflow_type.map(open_request::Filters::TypeFilter)),
+            };
+            let resp = self
+                .read(inner, |mut client, request| async move {
+                    client.list_open_workflow_executions(request).await
+                })
+                .await?
+                .into_inner();
+            (resp.executions, resp.next_page_token)
+        };
+
+        let workflows = executions
             .into_iter()
-            .map(|entry| {
-                let info = entry.info.as_ref();
-                Schedule {
-                    schedule_id: entry.schedule_id,
-                    workflow_type: info
-                        .and_then(|i| i.workflow_type.as_ref())
-                        .map(|t| t.name.clone())
+            .map(workflow_info_to_summary)
+            .collect::<Result<Vec<_>, _>>()?
+            .into_iter()
+            .filter(|wf| filter.matches(wf))
+            .collect();
+        let next = if !next.is_empty() {
+            [vec![u8::from(closed)], next].concat()
+        } else if !closed && filter.includes_closed() {
+            vec![1]
+        } else {
+            vec![]
+        };
+        Ok((workflows, next))
+    }
+}
+
+#[async_trait]
+impl VisibilityReader for GrpcTemporalClient {
+    async fn list_namespaces(&self) -> ClientResult<Vec<Namespace>> {
+        let mut descriptions = Vec::new();
+        let mut next_page_token = vec![];
+        loop {
+            let inner = proto::ListNamespacesRequest {
+                page_size: 100,
+                next_page_token,
+                namespace_filter: None,
+            };
+            let resp = self
+                .read(inner, |mut client, request| async move {
+                    client.list_namespaces(request).await
+                })
+                .await?
+                .into_inner();
+            descriptions.extend(resp.namespaces);
+
+            next_page_token = resp.next_page_token;
+            if next_page_token.is_empty() {
+                break;
+            }
+        }
+
+        let namespaces = descriptions
+            .into_iter()
+            .filter_map(|desc| {
+                let info = desc.namespace_info?;
+                let config = desc.config;
+                Some(Namespace {
+                    name: info.name,
+                    state: proto::temporal::api::enums::v1::NamespaceState::try_from(info.state)
+                        .map(|s| {
+                            s.as_str_name()
+                                .trim_start_matches("NAMESPACE_STATE_")
+                                .to_string()
+                        })
                         .unwrap_or_default(),
-                    state: if info.map(|i| i.paused).unwrap_or(false) {
-                        ScheduleState::Paused
-                    } else {
-                        ScheduleState::Active
-                    },
-                    spec_description: String::new(),
-                    next_run: info
-                        .and_then(|i| i.future_action_times.first())
-                        .map(timestamp_to_datetime),
-                    recent_action_count: info.map(|i| i.recent_actions.len() as u64).unwrap_or(0),
-                    notes: info.map(|i| i.notes.clone()).unwrap_or_default(),
-                }
+                    description: info.description,
+                    owner_email: info.owner_email,
+                    retention: config
+                        .and_then(|c| c.workflow_execution_retention_ttl)
+                        .map(|d| std::time::Duration::new(d.seconds as u64, d.nanos as u32)),
+                })
             })
             .collect();
 
-        Ok(schedules)
+        Ok(namespaces)
     }
 
-    async fn describe_schedule(
-        &self,
-        namespace: &str,
-        schedule_id: &str,
-    ) -> ClientResult<Schedule> {
-        let inner = proto::DescribeScheduleRequest {
-            namespace: namespace.to_string(),
-            schedule_id: schedule_id.to_string(),
-        };
-
+    async fn get_cluster_info(&self) -> ClientResult<ClusterInfo> {
         let response = self
-            .client
-            .clone()
-            .describe_schedule(self.make_request(inner))
-            .await
-            .map_err(grpc_error)?;
+            .read(
+                proto::GetClusterInfoRequest {},
+                |mut client, request| async move { client.get_cluster_info(request).await },
+            )
+            .await?;
 
         let resp = response.into_inner();
-        let info = resp.info;
-        let schedule = resp.schedule;
+        let host = self.address.split(':').next().unwrap_or_default();
+        Ok(ClusterInfo {
+            server_version: resp.server_version,
+            cluster_name: resp.cluster_name,
+            is_cloud: host.ends_with(".tmprl.cloud"),
+        })
+    }
 
-        Ok(Schedule {
-            schedule_id: schedule_id.to_string(),
-            workflow_type: schedule
-                .as_ref()
-                .and_then(|s| s.action.as_ref())
-                .and_then(|a| a.action.as_ref())
-                .and_then(|a| match a {
-                    proto::temporal::api::schedule::v1::schedule_action::Action::StartWorkflow(
-                        wf,
-                    ) => wf.workflow_type.as_ref().map(|t| t.name.clone()),
-                })
-                .unwrap_or_default(),
-            state: {
-                let paused = schedule
-                    .as_ref()
-                    .and_then(|s| s.state.as_ref())
-                    .map(|s| s.paused)
-                    .unwrap_or(false);
-                if paused {
-                    ScheduleState::Paused
-                } else {
-                    ScheduleState::Active
+    async fn list_workflows(
+        &self,
+        namespace: &str,
+        query: Option<&str>,
+        page_size: i32,
+        next_page_token: Vec<u8>,
+    ) -> ClientResult<(Vec<WorkflowSummary>, Vec<u8>)> {
+        if self.standard_visibility.contains(namespace) {
+            return self
+                .list_workflows_standard(namespace, query, page_size, next_page_token)
+                .await;
+        }
+        let resp = match self
+            .list_workflow_executions(namespace, query, page_size, next_page_token)
+            .await
+        {
+            Ok(resp) => resp,
+            Err(e) => {
+                let unsupported = visibility::is_unsupported(&e, query)
+                    || (visibility::needs_probe(&e, query)
+                        && self
+                            .list_workflow_executions(namespace, None, 1, vec![])
+                            .await
+                            .is_err_and(|probe| visibility::is_unsupported(&probe, None)));
+                if !unsupported {
+                    return Err(e);
                 }
-            },
-            spec_description: String::new(),
-            next_run: info
-                .as_ref()
-                .and_then(|i| i.future_action_times.first())
-                .map(timestamp_to_datetime),
-            recent_action_count: info
-                .as_ref()
-                .map(|i| i.recent_actions.len() as u64)
-                .unwrap_or(0),
-            notes: schedule
-                .as_ref()
-                .and_then(|s| s.state.as_ref())
-                .map(|s| s.notes.clone())
-                .unwrap_or_default(),
-        })
+                tracing::warn!("{}; falling back to the standard visibility list APIs", e);
+                self.standard_visibility.insert(namespace);
+                return self
+                    .list_workflows_standard(namespace, query, page_size, vec![])
+                    .await;
+            }
+        };
+
+        let workflows = resp
+            .executions
+            .into_iter()
+            .map(workflow_info_to_summary)
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok((workflows, resp.next_page_token))
     }
 
-    async fn patch_schedule(
+    async fn list_archived_workflows(
         &self,
         namespace: &str,
-        schedule_id: &str,
-        pause: bool,
-    ) -> ClientResult<()> {
-        let inner = proto::PatchScheduleRequest {
+        query: Option<&str>,
+        page_size: i32,
+        next_page_token: Vec<u8>,
+    ) -> ClientResult<(Vec<WorkflowSummary>, Vec<u8>)> {
+        let inner = proto::ListArchivedWorkflowExecutionsRequest {
             namespace: namespace.to_string(),
-            schedule_id: schedule_id.to_string(),
-            patch: Some(proto::temporal::api::schedule::v1::SchedulePatch {
-                pause: if pause {
-                    "paused by t9s".to_string()
-                } else {
-                    String::new()
-                },
-                unpause: if !pause {
-                    "unpaused by t9s".to_string()
-                } else {
-                    String::new()
-                },
-                ..Default::default()
-            }),
-            identity: "t9s".to_string(),
-            request_id: uuid::Uuid::new_v4().to_string(),
+            page_size,
+            next_page_token,
+            query: query.unwrap_or("").to_string(),
         };
 
-        self.client
-            .clone()
-            .patch_schedule(self.make_request(inner))
-            .await
-            .map_err(grpc_error)?;
+        let resp = self
+            .read(inner, |mut client, request| async move {
+                client.list_archived_workflow_executions(request).await
+            })
+            .await?
+            .into_inner();
+        let workflows = resp
+            .executions
+            .into_iter()
+            .map(workflow_info_to_summary)
+            .collect::<Result<Vec<_>, _>>()?;
 
-        Ok(())
+        Ok((workflows, resp.next_page_token))
     }
 
-    async fn trigger_schedule(&self, namespace: &str, schedule_id: &str) -> ClientResult<()> {
-        let inner = proto::PatchScheduleRequest {
-            namespace: namespace.to_string(),
-            schedule_id: schedule_id.to_string(),
-            patch: Some(proto::temporal::api::schedule::v1::SchedulePatch {
-                trigger_immediately: Some(
-                    proto::temporal::api::schedule::v1::TriggerImmediatelyRequest {
-                        overlap_policy: 0,
-                        scheduled_time: None,
-                    },
-                ),
-                ..Default::default()
-            }),
-            identity: "t9s".to_string(),
-            request_id: uuid::Uuid::new_v4().to_string(),
+    async fn describe_workflow(
+        &self,
+        namespace: &str,
+        workflow_id: &str,
+        run_id: Option<&str>,
+    ) -> ClientResult<WorkflowDetail> {
+        let inner = proto::DescribeWorkflowExecutionRequest {
+            namespace: namespace.to_string(),
+            execution: Some(Self::wf_execution(workflow_id, run_id)),
         };
 
-        self.client
-            .clone()
-            .patch_schedule(self.make_request(inner))
-            .await
-            .map_err(grpc_error)?;
+        let response = self
+            .read(inner, |mut client, request| async move {
+                client.describe_workflow_execution(request).await
+            })
+            .await?;
 
-        Ok(())
-    }
+        let mut resp = response.into_inner();
+        let mut info = resp
+            .workflow_execution_info
+            .ok_or_else(|| ClientError::ParseError("missing workflow execution info".into()))?;
+        self.codec_decode(namespace, memo_payloads_mut(&mut info))
+            .await;
+        self.codec_decode(
+            namespace,
+            resp.pending_activities
+                .iter_mut()
+                .flat_map(|pa| {
+                    let heartbeat = pa
+                        .heartbeat_details
+                        .iter_mut()
+                        .flat_map(|p| p.payloads.iter_mut());
+                    heartbeat.chain(failure_payloads_mut(&mut pa.last_failure))
+                })
+                .collect(),
+        )
+        .await;
+        let config = resp.execution_config.unwrap_or_default();
+        let execution_config = execution_config(&config);
+        let mut metadata = config.user_metadata;
+        self.codec_decode(namespace, user_metadata_payloads_mut(&mut metadata))
+            .await;
 
-    async fn delete_schedule(&self, namespace: &str, schedule_id: &str) -> ClientResult<()> {
-        let inner = proto::DeleteScheduleRequest {
-            namespace: namespace.to_string(),
-            schedule_id: schedule_id.to_string(),
-            identity: "t9s".to_string(),
+        let history_length = info.history_length as u64;
+        let to_ref = |exec: proto::temporal::api::common::v1::WorkflowExecution| WorkflowRef {
+            workflow_id: exec.workflow_id,
+            run_id: exec.run_id,
         };
+        let parent = info.parent_execution.clone().map(to_ref);
+        let root = info.root_execution.clone().map(to_ref);
+        let versioning = workflow_versioning(&info);
+        let memo = memo_fields(&info);
+        let summary = workflow_info_to_summary(info)?;
 
-        self.client
-            .clone()
-            .delete_schedule(self.make_request(inner))
-            .await
-            .map_err(grpc_error)?;
+        let pending_activities = resp
+            .pending_activities
+            .into_iter()
+            .map(|pa| PendingActivity {
+                activity_id: pa.activity_id,
+                activity_type: pa.activity_type.map(|t| t.name).unwrap_or_default(),
+                state: pending_activity_state(pa.state),
+                attempt: pa.attempt,
+                scheduled_time: pa.scheduled_time.map(|t| timestamp_to_datetime(&t)),
+                last_started_time: pa.last_started_time.map(|t| timestamp_to_datetime(&t)),
+                last_heartbeat_time: pa.last_heartbeat_time.map(|t| timestamp_to_datetime(&t)),
+                maximum_attempts: pa.maximum_attempts,
+                next_attempt_time: pa
+                    .next_attempt_schedule_time
+                    .map(|t| timestamp_to_datetime(&t)),
+                heartbeat_details: pa
+                    .heartbeat_details
+                    .is_some()
+                    .then(|| decode_payloads(&pa.heartbeat_details)),
+                last_failure: pa.last_failure.as_ref().map(failure_info),
+                last_worker_identity: pa.last_worker_identity,
+                options: pa.activity_options.as_ref().map(activity_options_edit),
+            })
+            .collect();
 
-        Ok(())
+        let pending_children = resp
+            .pending_children
+            .into_iter()
+            .map(|pc| PendingChild {
+                workflow_id: pc.workflow_id,
+                run_id: pc.run_id,
+                workflow_type: pc.workflow_type_name,
+                initiated_id: pc.initiated_id,
+                parent_close_policy: parent_close_policy_string(pc.parent_close_policy),
+            })
+            .collect();
+
+        let pending_nexus_operations = resp
+            .pending_nexus_operations
+            .into_iter()
+            .map(|op| PendingNexusOperation {
+                endpoint: op.endpoint,
+                service: op.service,
+                operation: op.operation,
+                state: nexus_operation_state_string(op.state),
+                attempt: op.attempt,
+                scheduled_time: op.scheduled_time.map(|t| timestamp_to_datetime(&t)),
+                last_failure_message: op.last_attempt_failure.map(|f| f.message),
+            })
+            .collect();
+
+        Ok(WorkflowDetail {
+            search_attributes: summary.search_attributes.clone(),
+            summary,
+            input: None,
+            output: None,
+            failure: None,
+            history_length,
+            parent,
+            root,
+            memo,
+            pending_activities,
+            pending_children,
+            pending_nexus_operations,
+            versioning,
+            user_metadata: user_metadata(&metadata),
+            execution_config,
+            retry_policy: None,
+            cron_schedule: None,
+        })
     }
 
-    async fn describe_task_queue(
+    async fn get_history(
         &self,
         namespace: &str,
-        task_queue: &str,
-    ) -> ClientResult<TaskQueueInfo> {
-        #[allow(deprecated)]
-        let inner = proto::DescribeTaskQueueRequest {
+        workflow_id: &str,
+        run_id: Option<&str>,
+    ) -> ClientResult<Vec<HistoryEvent>> {
+        let mut all_events = Vec::new();
+        let mut next_page_token = vec![];
+
+        loop {
+            let inner = proto::GetWorkflowExecutionHistoryRequest {
+                namespace: namespace.to_string(),
+                execution: Some(Self::wf_execution(workflow_id, run_id)),
+                maximum_page_size: 200,
+                next_page_token: next_page_token.clone(),
+                wait_new_event: false,
+                history_event_filter_type: 0,
+                skip_archival: false,
+            };
+
+            let response = self
+                .read(inner, |mut client, request| async move {
+                    client.get_workflow_execution_history(request).await
+                })
+                .await?;
+
+            let resp = response.into_inner();
+            if let Some(history) = resp.history {
+                all_events.extend(history.events);
+            }
+
+            if resp.next_page_token.is_empty() {
+                break;
+            }
+            next_page_token = resp.next_page_token;
+        }
+
+        self.codec_decode(
+            namespace,
+            all_events.iter_mut().flat_map(event_payloads_mut).collect(),
+        )
+        .await;
+
+        Ok(all_events
+            .into_iter()
+            .map(|e| HistoryEvent {
+                event_id: e.event_id,
+                event_type: event_type_name(e.event_type),
+                timestamp: e
+                    .event_time
+                    .map(|t| timestamp_to_datetime(&t))
+                    .unwrap_or_else(Utc::now),
+                details: extract_event_details(&e),
+                binary: event_binary_payloads(&e),
+                user_metadata: user_metadata(&e.user_metadata),
+            })
+            .collect())
+    }
+
+    async fn query_workflow(
+        &self,
+        namespace: &str,
+        workflow_id: &str,
+        run_id: Option<&str>,
+        query_type: &str,
+    ) -> ClientResult<serde_json::Value> {
+        let inner = proto::QueryWorkflowRequest {
             namespace: namespace.to_string(),
-            task_queue: Some(proto::temporal::api::taskqueue::v1::TaskQueue {
-                name: task_queue.to_string(),
-                kind: 0,
-                normal_name: String::new(),
+            execution: Some(Self::wf_execution(workflow_id, run_id)),
+            query: Some(proto::temporal::api::query::v1::WorkflowQuery {
+                query_type: query_type.to_string(),
+                query_args: None,
+                header: None,
             }),
-            task_queue_type: 1, // WORKFLOW
-            include_task_queue_status: true,
-            api_mode: 0,
-            versions: None,
-            task_queue_types: vec![],
-            report_stats: true,
-            report_config: false,
-            report_pollers: true,
-            report_task_reachability: false,
+            query_reject_condition: 0,
         };
 
         let response = self
-            .client
-            .clone()
-            .describe_task_queue(self.make_request(inner))
-            .await
-            .map_err(grpc_error)?;
+            .read(inner, |mut client, request| async move {
+                client.query_workflow(request).await
+            })
+            .await?;
+
+        let mut resp = response.into_inner();
+        self.codec_decode(namespace, payloads_mut(&mut resp.query_result))
+            .await;
+        if let Some(rejected) = resp.query_rejected {
+            return Err(ClientError::RequestFailed(format!(
+                "query rejected (workflow status {})",
+                rejected.status
+            )));
+        }
+        Ok(decode_payloads(&resp.query_result))
+    }
 
-        let resp = response.into_inner();
-        let pollers = resp
-            .pollers
-            .into_iter()
-            .map(|p| Poller {
-                identity: p.identity,
-                last_access_time: p.last_access_time.map(|t| timestamp_to_datetime(&t)),
-                rate_per_second: p.rate_per_second,
+    async fn count_workflows(&self, namespace: &str, query: Option<&str>) -> ClientResult<u64> {
+        let inner = proto::CountWorkflowExecutionsRequest {
+            namespace: namespace.to_string(),
+            query: query.unwrap_or("").to_string(),
+        };
+
+        let response = self
+            .read(inner, |mut client, request| async move {
+                client.count_workflow_executions(request).await
             })
-            .collect();
+            .await?;
 
-        Ok(TaskQueueInfo {
-            name: task_queue.to_string(),
-            pollers,
-        })
+        Ok(response.into_inner().count as u64)
     }
 
-    async fn list_activity_executions(
+    async fn count_workflows_by_status(
+        &self,
+        namespace: &str,
+        query: Option<&str>,
+    ) -> ClientResult<Vec<(WorkflowStatus, u64)>> {
+        if self.standard_visibility.contains(namespace) {
+            return Err(ClientError::Unimplemented(
+                "counting workflows needs advanced visibility".to_string(),
+            ));
+        }
+        if self.ungrouped_counts.load(Ordering::Relaxed) {
+            return count_each_status(self, namespace, query).await;
+        }
+        let inner = proto::CountWorkflowExecutionsRequest {
+            namespace: namespace.to_string(),
+            query: grouped_by_status(query),
+        };
+
+        let result = self
+            .read(inner, |mut client, request| async move {
+                client.count_workflow_executions(request).await
+            })
+            .await;
+        match result {
+            Ok(response) => Ok(status_counts(response.into_inner().groups.iter().map(
+                |group| {
+                    let value = group.group_values.first().map(decode_payload);
+                    (value.unwrap_or_default(), group.count)
+                },
+            ))),
+            Err(ClientError::InvalidArgument(_) | ClientError::Unimplemented(_)) => {
+                self.ungrouped_counts.store(true, Ordering::Relaxed);
+                count_each_status(self, namespace, query).await
+            }
+            Err(e) => Err(e),
+        }
+    }
+
+    async fn list_schedules(
         &self,
         namespace: &str,
         query: Option<&str>,
         page_size: i32,
         next_page_token: Vec<u8>,
-    ) -> ClientResult<(Vec<ActivityExecutionSummary>, Vec<u8>)> {
-        let inner = proto::ListActivityExecutionsRequest {
+    ) -> ClientResult<(Vec<Schedule>, Vec<u8>)> {
+        let inner = proto::ListSchedulesRequest {
             namespace: namespace.to_string(),
-            page_size,
+            maximum_page_size: page_size,
             next_page_token,
             query: query.unwrap_or("").to_string(),
         };
 
         let response = self
-            .client
-            .clone()
-            .list_activity_executions(self.make_request(inner))
-            .await
-            .map_err(grpc_error)?;
+            .read(inner, |mut client, request| async move {
+                client.list_schedules(request).await
+            })
+            .await?
+            .into_inner();
 
-        let resp = response.into_inner();
-        let activities = resp
-            .executions
+        let schedules = response
+            .schedules
             .into_iter()
-            .map(activity_list_info_to_summary)
-            .collect::<Result<Vec<_>, _>>()?;
+            .map(|entry| {
+                let info = entry.info.as_ref();
+                let spec = schedule_spec_fields(info.and_then(|i| i.spec.as_ref()));
+                Schedule {
+                    schedule_id: entry.schedule_id,
+                    workflow_type: info
+                        .and_then(|i| i.workflow_type.as_ref())
+                        .map(|t| t.name.clone())
+                        .unwrap_or_default(),
+                    state: if info.map(|i| i.paused).unwrap_or(false) {
+                        ScheduleState::Paused
+                    } else {
+                        ScheduleState::Active
+                    },
+                    spec_description: spec.description,
+                    next_run: info
+                        .and_then(|i| i.future_action_times.first())
+                        .map(timestamp_to_datetime),
+                    recent_action_count: info.map(|i| i.recent_actions.len() as u64).unwrap_or(0),
+                    notes: info.map(|i| i.notes.clone()).unwrap_or_default(),
+                    timezone: spec.timezone,
+                    calendars: spec.calendars,
+                    recent_actions: vec![],
+                    upcoming_runs: vec![],
+                }
+            })
+            .collect();
 
-        Ok((activities, resp.next_page_token))
+        Ok((schedules, response.next_page_token))
     }
 
-    async fn describe_activity_execution(
+    async fn describe_workflow_raw(
         &self,
         namespace: &str,
-        activity_id: &str,
-        run_id: &str,
-    ) -> ClientResult<ActivityExecutionDetail> {
-        let inner = proto::DescribeActivityExecutionRequest {
+        workflow_id: &str,
+        run_id: Option<&str>,
+    ) -> ClientResult<serde_json::Value> {
+        let inner = proto::DescribeWorkflowExecutionRequest {
             namespace: namespace.to_string(),
-            activity_id: activity_id.to_string(),
-            run_id: run_id.to_string(),
-            include_input: true,
-            include_outcome: true,
-            long_poll_token: vec![],
+            execution: Some(Self::wf_execution(workflow_id, run_id)),
         };
-
         let response = self
-            .client
-            .clone()
-            .describe_activity_execution(self.make_request(inner))
-            .await
-            .map_err(grpc_error)?;
-
-        let resp = response.into_inner();
-        let info = resp
-            .info
-            .ok_or_else(|| ClientError::ParseError("missing activity execution info".into()))?;
+            .read(inner, |mut client, request| async move {
+                client.describe_workflow_execution(request).await
+            })
+            .await?;
+        proto_json(&response.into_inner())
+    }
 
-        let summary = ActivityExecutionSummary {
-            activity_id: info.activity_id.clone(),
-            run_id: if info.run_id.is_empty() {
-                resp.run_id.clone()
-            } else {
-                info.run_id.clone()
-            },
-            activity_type: info
-                .activity_type
-                .as_ref()
-                .map(|t| t.name.clone())
-                .unwrap_or_else(|| "Unknown".to_string()),
-            status: proto_activity_status_to_domain(info.status),
-            schedule_time: info.schedule_time.as_ref().map(timestamp_to_datetime),
-            close_time: info.close_time.as_ref().map(timestamp_to_datetime),
-            task_queue: info.task_queue.clone(),
-        };
+    async fn describe_schedule_raw(
+        &self,
+        namespace: &str,
+        schedule_id: &str,
+    ) -> ClientResult<serde_json::Value> {
+        let resp = self
+            .describe_schedule_response(namespace, schedule_id)
+            .await?;
+        proto_json(&resp)
+    }
 
-        let retry_state = info
-            .last_failure
-            .as_ref()
-            .and_then(failure_retry_state)
-            .unwrap_or_else(|| "Unknown".to_string());
+    async fn describe_schedule(
+        &self,
+        namespace: &str,
+        schedule_id: &str,
+    ) -> ClientResult<Schedule> {
+        let resp = self
+            .describe_schedule_response(namespace, schedule_id)
+            .await?;
+        let info = resp.info;
+        let schedule = resp.schedule;
+        let spec = schedule_spec_fields(schedule.as_ref().and_then(|s| s.spec.as_ref()));
 
-        let (output, failure) = match resp.outcome.and_then(|o| o.value) {
-            Some(
+        Ok(Schedule {
+            schedule_id: schedule_id.to_string(),
+            workflow_type: schedule
+                .as_ref()
+                .and_then(|s| s.action.as_ref())
+                .and_then(|a| a.action.as_ref())
+                .and_then(|a| match a {
+                    proto::temporal::api::schedule::v1::schedule_action::Action::StartWorkflow(
+                        wf,
+                    ) => wf.workflow_type.as_ref().map(|t| t.name.clone()),
+                })
+                .unwrap_or_default(),
+            state: {
+                let paused = schedule
+                    .as_ref()
+                    .and_then(|s| s.state.as_ref())
+                    .map(|s| s.paused)
+                    .unwrap_or(false);
+                if paused {
+                    ScheduleState::Paused
+                } else {
+                    ScheduleState::Active
+                }
+            },
+            spec_description: spec.description,
+            next_run: info
+                .as_ref()
+                .and_then(|i| i.future_action_times.first())
+                .map(timestamp_to_datetime),
+            recent_action_count: info
+                .as_ref()
+                .map(|i| i.recent_actions.len() as u64)
+                .unwrap_or(0),
+            notes: schedule
+                .as_ref()
+                .and_then(|s| s.state.as_ref())
+                .map(|s| s.notes.clone())
+                .unwrap_or_default(),
+            timezone: spec.timezone,
+            calendars: spec.calendars,
+            recent_actions: info
+                .as_ref()
+                .map(|i| i.recent_actions.iter().rev().map(schedule_action).collect())
+                .unwrap_or_default(),
+            upcoming_runs: info
+                .as_ref()
+                .map(|i| {
+                    i.future_action_times
+                        .iter()
+                        .map(timestamp_to_datetime)
+                        .collect()
+                })
+                .unwrap_or_default(),
+        })
+    }
+
+    async fn list_activity_executions(
+        &self,
+        namespace: &str,
+        query: Option<&str>,
+        page_size: i32,
+        next_page_token: Vec<u8>,
+    ) -> ClientResult<(Vec<ActivityExecutionSummary>, Vec<u8>)> {
+        let inner = proto::ListActivityExecutionsRequest {
+            namespace: namespace.to_string(),
+            page_size,
+            next_page_token,
+            query: query.unwrap_or("").to_string(),
+        };
+
+        let response = self
+            .read(inner, |mut client, request| async move {
+                client.list_activity_executions(request).await
+            })
+            .await?;
+
+        let resp = response.into_inner();
+        let activities = resp
+            .executions
+            .into_iter()
+            .map(activity_list_info_to_summary)
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok((activities, resp.next_page_token))
+    }
+
+    async fn describe_activity_execution(
+        &self,
+        namespace: &str,
+        activity_id: &str,
+        run_id: &str,
+    ) -> ClientResult<ActivityExecutionDetail> {
+        let inner = proto::DescribeActivityExecutionRequest {
+            namespace: namespace.to_string(),
+            activity_id: activity_id.to_string(),
+            run_id: run_id.to_string(),
+            include_input: true,
+            include_outcome: true,
+            long_poll_token: vec![],
+            include_heartbeat_details: true,
+            include_last_failure: true,
+        };
+
+        let response = self
+            .read(inner, |mut client, request| async move {
+                client.describe_activity_execution(request).await
+            })
+            .await?;
+
+        let mut resp = response.into_inner();
+        let info = resp
+            .info
+            .ok_or_else(|| ClientError::ParseError("missing activity execution info".into()))?;
+
+        let summary = ActivityExecutionSummary {
+            activity_id: info.activity_id.clone(),
+            run_id: if info.run_id.is_empty() {
+                resp.run_id.clone()
+            } else {
+                info.run_id.clone()
+            },
+            activity_type: info
+                .activity_type
+                .as_ref()
+                .map(|t| t.name.clone())
+                .unwrap_or_else(|| "Unknown".to_string()),
+            status: proto_activity_status_to_domain(info.status),
+            schedule_time: info.schedule_time.as_ref().map(timestamp_to_datetime),
+            close_time: info.close_time.as_ref().map(timestamp_to_datetime),
+            task_queue: info.task_queue.clone(),
+        };
+
+        let retry_state = info
+            .last_failure
+            .as_ref()
+            .and_then(failure_retry_state)
+            .unwrap_or_else(|| "Unknown".to_string());
+
+        let mut outcome = resp.outcome.and_then(|o| o.value);
+        let mut codec_payloads = payloads_mut(&mut resp.input);
+        if let Some(
+            proto::temporal::api::activity::v1::activity_execution_outcome::Value::Result(
+                ref mut payloads,
+            ),
+        ) = outcome
+        {
+            codec_payloads.extend(payloads.payloads.iter_mut());
+        }
+        self.codec_decode(namespace, codec_payloads).await;
+
+        let (output, failure) = match outcome {
+            Some(
                 proto::temporal::api::activity::v1::activity_execution_outcome::Value::Result(
                     payloads,
                 ),
@@ -774,173 +1473,1256 @@ impl TemporalClient for GrpcTemporalClient {
         };
 
         let response = self
-            .client
-            .clone()
-            .count_activity_executions(self.make_request(inner))
-            .await
-            .map_err(grpc_error)?;
+            .read(inner, |mut client, request| async move {
+                client.count_activity_executions(request).await
+            })
+            .await?;
 
         Ok(response.into_inner().count as u64)
     }
 
-    async fn request_cancel_activity_execution(
+    async fn check_activity_support(&self, namespace: &str) -> ClientResult<bool> {
+        let inner = proto::ListActivityExecutionsRequest {
+            namespace: namespace.to_string(),
+            page_size: 1,
+            next_page_token: vec![],
+            query: String::new(),
+        };
+
+        match self
+            .call(
+                || self.client(),
+                inner,
+                true,
+                |mut client, request| async move { client.list_activity_executions(request).await },
+            )
+            .await
+        {
+            Ok(_) => Ok(true),
+            Err(status) if status.code() == tonic::Code::Unimplemented => Ok(false),
+            Err(status) => Err(self.status_error(status)),
+        }
+    }
+
+    async fn list_search_attributes(&self, namespace: &str) -> ClientResult<Vec<SearchAttribute>> {
+        let inner = proto::temporal::api::operatorservice::v1::ListSearchAttributesRequest {
+            namespace: namespace.to_string(),
+        };
+        let response = self
+            .operator_read(inner, |mut client, request| async move {
+                client.list_search_attributes(request).await
+            })
+            .await?
+            .into_inner();
+        Ok(search_attributes(
+            &response.custom_attributes,
+            &response.system_attributes,
+        ))
+    }
+}
+
+#[async_trait]
+impl ExecutionAdmin for GrpcTemporalClient {
+    async fn cancel_workflow(
         &self,
         namespace: &str,
-        activity_id: &str,
-        run_id: &str,
+        workflow_id: &str,
+        run_id: Option<&str>,
     ) -> ClientResult<()> {
-        let inner = proto::RequestCancelActivityExecutionRequest {
+        let inner = proto::RequestCancelWorkflowExecutionRequest {
             namespace: namespace.to_string(),
-            activity_id: activity_id.to_string(),
-            run_id: run_id.to_string(),
+            workflow_execution: Some(Self::wf_execution(workflow_id, run_id)),
             identity: "t9s".to_string(),
             request_id: uuid::Uuid::new_v4().to_string(),
+            first_execution_run_id: String::new(),
             reason: String::new(),
+            links: vec![],
         };
 
-        self.client
-            .clone()
-            .request_cancel_activity_execution(self.make_request(inner))
-            .await
-            .map_err(grpc_error)?;
+        self.write(inner, |mut client, request| async move {
+            client.request_cancel_workflow_execution(request).await
+        })
+        .await?;
 
         Ok(())
     }
 
-    async fn terminate_activity_execution(
+    async fn terminate_workflow(
         &self,
         namespace: &str,
-        activity_id: &str,
-        run_id: &str,
+        workflow_id: &str,
+        run_id: Option<&str>,
         reason: &str,
     ) -> ClientResult<()> {
-        let inner = proto::TerminateActivityExecutionRequest {
+        let inner = proto::TerminateWorkflowExecutionRequest {
             namespace: namespace.to_string(),
-            activity_id: activity_id.to_string(),
-            run_id: run_id.to_string(),
-            identity: "t9s".to_string(),
-            request_id: uuid::Uuid::new_v4().to_string(),
+            workflow_execution: Some(Self::wf_execution(workflow_id, run_id)),
             reason: reason.to_string(),
+            identity: "t9s".to_string(),
+            details: None,
+            first_execution_run_id: String::new(),
+            links: vec![],
         };
 
-        self.client
-            .clone()
-            .terminate_activity_execution(self.make_request(inner))
-            .await
-            .map_err(grpc_error)?;
+        self.write(inner, |mut client, request| async move {
+            client.terminate_workflow_execution(request).await
+        })
+        .await?;
 
         Ok(())
     }
 
-    async fn delete_activity_execution(
+    async fn start_attributes(
         &self,
         namespace: &str,
-        activity_id: &str,
-        run_id: &str,
-    ) -> ClientResult<()> {
-        let inner = proto::DeleteActivityExecutionRequest {
+        workflow_id: &str,
+        run_id: Option<&str>,
+    ) -> ClientResult<StartAttributes> {
+        use proto::temporal::api::history::v1::history_event::Attributes;
+
+        let inner = proto::GetWorkflowExecutionHistoryRequest {
             namespace: namespace.to_string(),
-            activity_id: activity_id.to_string(),
-            run_id: run_id.to_string(),
+            execution: Some(Self::wf_execution(workflow_id, run_id)),
+            maximum_page_size: 1,
+            next_page_token: vec![],
+            wait_new_event: false,
+            history_event_filter_type: 0,
+            skip_archival: false,
         };
 
-        self.client
-            .clone()
-            .delete_activity_execution(self.make_request(inner))
-            .await
-            .map_err(grpc_error)?;
+        let history = self
+            .read(inner, |mut client, request| async move {
+                client.get_workflow_execution_history(request).await
+            })
+            .await?
+            .into_inner()
+            .history
+            .unwrap_or_default();
 
-        Ok(())
+        match history.events.into_iter().next().and_then(|e| e.attributes) {
+            Some(Attributes::WorkflowExecutionStartedEventAttributes(started)) => {
+                Ok(StartAttributes::Grpc(Box::new(started)))
+            }
+            _ => Err(ClientError::NotFound(format!(
+                "start event for workflow {}",
+                workflow_id
+            ))),
+        }
     }
 
-    async fn check_activity_support(&self, namespace: &str) -> ClientResult<bool> {
-        let inner = proto::ListActivityExecutionsRequest {
+    async fn start_workflow_from(
+        &self,
+        namespace: &str,
+        workflow_id: &str,
+        attributes: StartAttributes,
+    ) -> ClientResult<String> {
+        let StartAttributes::Grpc(started) = attributes else {
+            return Err(ClientError::InvalidArgument(
+                "start attributes from another transport".into(),
+            ));
+        };
+        let started = *started;
+        let inner = proto::StartWorkflowExecutionRequest {
             namespace: namespace.to_string(),
-            page_size: 1,
-            next_page_token: vec![],
-            query: String::new(),
+            workflow_id: workflow_id.to_string(),
+            workflow_type: started.workflow_type,
+            task_queue: started.task_queue,
+            input: started.input,
+            workflow_execution_timeout: started.workflow_execution_timeout,
+            workflow_run_timeout: started.workflow_run_timeout,
+            workflow_task_timeout: started.workflow_task_timeout,
+            identity: "t9s".to_string(),
+            request_id: uuid::Uuid::new_v4().to_string(),
+            retry_policy: started.retry_policy,
+            cron_schedule: started.cron_schedule,
+            memo: started.memo,
+            search_attributes: started.search_attributes,
+            header: started.header,
+            priority: started.priority,
+            ..Default::default()
         };
 
-        match self
-            .client
-            .clone()
-            .list_activity_executions(self.make_request(inner))
-            .await
-        {
-            Ok(_) => Ok(true),
-            Err(status) if status.code() == tonic::Code::Unimplemented => Ok(false),
-            Err(status) => Err(grpc_error(status)),
+        let resp = self
+            .write(inner, |mut client, request| async move {
+                client.start_workflow_execution(request).await
+            })
+            .await?;
+
+        Ok(resp.into_inner().run_id)
+    }
+
+    async fn signal_workflow(
+        &self,
+        namespace: &str,
+        workflow_id: &str,
+        run_id: Option<&str>,
+        signal_name: &str,
+        input: Option<&str>,
+    ) -> ClientResult<()> {
+        let signal_input = input.map(|i| proto::temporal::api::common::v1::Payloads {
+            payloads: vec![proto::temporal::api::common::v1::Payload {
+                metadata: std::collections::HashMap::new(),
+                data: i.as_bytes().to_vec(),
+                external_payloads: vec![],
+            }],
+        });
+
+        #[allow(deprecated)]
+        let inner = proto::SignalWorkflowExecutionRequest {
+            namespace: namespace.to_string(),
+            workflow_execution: Some(Self::wf_execution(workflow_id, run_id)),
+            signal_name: signal_name.to_string(),
+            input: signal_input,
+            identity: "t9s".to_string(),
+            request_id: uuid::Uuid::new_v4().to_string(),
+            control: String::new(),
+            header: None,
+            links: vec![],
+        };
+
+        self.write(inner, |mut client, request| async move {
+            client.signal_workflow_execution(request).await
+        })
+        .await?;
+
+        Ok(())
+    }
+
+    async fn request_cancel_activity_execution(
+        &self,
+        namespace: &str,
+        activity_id: &str,
+        run_id: &str,
+    ) -> ClientResult<()> {
+        let inner = proto::RequestCancelActivityExecutionRequest {
+            namespace: namespace.to_string(),
+            activity_id: activity_id.to_string(),
+            run_id: run_id.to_string(),
+            identity: "t9s".to_string(),
+            request_id: uuid::Uuid::new_v4().to_string(),
+            reason: String::new(),
+        };
+
+        self.write(inner, |mut client, request| async move {
+            client.request_cancel_activity_execution(request).await
+        })
+        .await?;
+
+        Ok(())
+    }
+
+    async fn terminate_activity_execution(
+        &self,
+        namespace: &str,
+        activity_id: &str,
+        run_id: &str,
+        reason: &str,
+    ) -> ClientResult<()> {
+        let inner = proto::TerminateActivityExecutionRequest {
+            namespace: namespace.to_string(),
+            activity_id: activity_id.to_string(),
+            run_id: run_id.to_string(),
+            identity: "t9s".to_string(),
+            request_id: uuid::Uuid::new_v4().to_string(),
+            reason: reason.to_string(),
+        };
+
+        self.write(inner, |mut client, request| async move {
+            client.terminate_activity_execution(request).await
+        })
+        .await?;
+
+        Ok(())
+    }
+
+    async fn delete_activity_execution(
+        &self,
+        namespace: &str,
+        activity_id: &str,
+        run_id: &str,
+    ) -> ClientResult<()> {
+        let inner = proto::DeleteActivityExecutionRequest {
+            namespace: namespace.to_string(),
+            activity_id: activity_id.to_string(),
+            run_id: run_id.to_string(),
+        };
+
+        self.write(inner, |mut client, request| async move {
+            client.delete_activity_execution(request).await
+        })
+        .await?;
+
+        Ok(())
+    }
+
+    async fn update_activity_options(
+        &self,
+        namespace: &str,
+        draft: &ActivityOptionsDraft,
+        edit: &ActivityOptionsEdit,
+    ) -> ClientResult<()> {
+        use proto::update_activity_options_request::Activity;
+
+        let duration = |text: &Option<String>| {
+            text.as_deref()
+                .and_then(parse_duration_text)
+                .map(|seconds| pbjson_types::Duration { seconds, nanos: 0 })
+        };
+        let options = proto::temporal::api::activity::v1::ActivityOptions {
+            schedule_to_close_timeout: duration(&edit.schedule_to_close_timeout),
+            schedule_to_start_timeout: duration(&edit.schedule_to_start_timeout),
+            start_to_close_timeout: duration(&edit.start_to_close_timeout),
+            heartbeat_timeout: duration(&edit.heartbeat_timeout),
+            retry_policy: Some(proto::temporal::api::common::v1::RetryPolicy {
+                initial_interval: duration(&edit.retry_initial_interval),
+                backoff_coefficient: edit.retry_backoff_coefficient,
+                maximum_interval: duration(&edit.retry_maximum_interval),
+                maximum_attempts: edit.retry_maximum_attempts,
+                non_retryable_error_types: edit.non_retryable_error_types.clone(),
+            }),
+            ..Default::default()
+        };
+        let paths = edit
+            .changed_fields(&draft.edit)
+            .into_iter()
+            .map(str::to_string)
+            .collect();
+        let inner = proto::UpdateActivityOptionsRequest {
+            namespace: namespace.to_string(),
+            execution: Some(Self::wf_execution(&draft.workflow_id, Some(&draft.run_id))),
+            identity: "t9s".to_string(),
+            activity_options: Some(options),
+            update_mask: Some(pbjson_types::FieldMask { paths }),
+            restore_original: false,
+            activity: Some(Activity::Id(draft.activity_id.clone())),
+        };
+
+        self.write(inner, |mut client, request| async move {
+            client.update_activity_options(request).await
+        })
+        .await?;
+
+        Ok(())
+    }
+
+    async fn start_batch_operation(
+        &self,
+        namespace: &str,
+        operation: BatchOperation,
+        target: &BatchTarget,
+        reason: &str,
+    ) -> ClientResult<String> {
+        use proto::start_batch_operation_request::Operation;
+        use proto::temporal::api::batch::v1::{
+            BatchOperationCancellation, BatchOperationTermination,
+        };
+
+        let job_id = uuid::Uuid::new_v4().to_string();
+        let (visibility_query, executions) = match target {
+            BatchTarget::Query(query) => (query.clone(), vec![]),
+            BatchTarget::Executions(executions) => (
+                String::new(),
+                executions
+                    .iter()
+                    .map(|(workflow_id, run_id)| Self::wf_execution(workflow_id, Some(run_id)))
+                    .collect(),
+            ),
+        };
+        let inner = proto::StartBatchOperationRequest {
+            namespace: namespace.to_string(),
+            visibility_query,
+            job_id: job_id.clone(),
+            reason: reason.to_string(),
+            executions,
+            max_operations_per_second: 0.0,
+            operation: Some(match operation {
+                BatchOperation::Cancel => {
+                    Operation::CancellationOperation(BatchOperationCancellation {
+                        identity: "t9s".to_string(),
+                    })
+                }
+                BatchOperation::Terminate => {
+                    Operation::TerminationOperation(BatchOperationTermination {
+                        details: None,
+                        identity: "t9s".to_string(),
+                    })
+                }
+            }),
+        };
+
+        self.write(inner, |mut client, request| async move {
+            client.start_batch_operation(request).await
+        })
+        .await?;
+
+        Ok(job_id)
+    }
+}
+
+#[async_trait]
+impl ScheduleAdmin for GrpcTemporalClient {
+    async fn patch_schedule(
+        &self,
+        namespace: &str,
+        schedule_id: &str,
+        pause: bool,
+        note: &str,
+    ) -> ClientResult<()> {
+        let note = pause_note(pause, note);
+        let inner = proto::PatchScheduleRequest {
+            namespace: namespace.to_string(),
+            schedule_id: schedule_id.to_string(),
+            patch: Some(proto::temporal::api::schedule::v1::SchedulePatch {
+                pause: if pause { note.clone() } else { String::new() },
+                unpause: if !pause { note } else { String::new() },
+                ..Default::default()
+            }),
+            identity: "t9s".to_string(),
+            request_id: uuid::Uuid::new_v4().to_string(),
+        };
+
+        self.write(inner, |mut client, request| async move {
+            client.patch_schedule(request).await
+        })
+        .await?;
+
+        Ok(())
+    }
+
+    async fn trigger_schedule(&self, namespace: &str, schedule_id: &str) -> ClientResult<()> {
+        let inner = proto::PatchScheduleRequest {
+            namespace: namespace.to_string(),
+            schedule_id: schedule_id.to_string(),
+            patch: Some(proto::temporal::api::schedule::v1::SchedulePatch {
+                trigger_immediately: Some(
+                    proto::temporal::api::schedule::v1::TriggerImmediatelyRequest {
+                        overlap_policy: 0,
+                        scheduled_time: None,
+                    },
+                ),
+                ..Default::default()
+            }),
+            identity: "t9s".to_string(),
+            request_id: uuid::Uuid::new_v4().to_string(),
+        };
+
+        self.write(inner, |mut client, request| async move {
+            client.patch_schedule(request).await
+        })
+        .await?;
+
+        Ok(())
+    }
+
+    async fn delete_schedule(&self, namespace: &str, schedule_id: &str) -> ClientResult<()> {
+        let inner = proto::DeleteScheduleRequest {
+            namespace: namespace.to_string(),
+            schedule_id: schedule_id.to_string(),
+            identity: "t9s".to_string(),
+        };
+
+        self.write(inner, |mut client, request| async move {
+            client.delete_schedule(request).await
+        })
+        .await?;
+
+        Ok(())
+    }
+
+    async fn schedule_draft(
+        &self,
+        namespace: &str,
+        schedule_id: &str,
+    ) -> ClientResult<ScheduleDraft> {
+        let resp = self
+            .describe_schedule_response(namespace, schedule_id)
+            .await?;
+        let schedule = resp.schedule.unwrap_or_default();
+        Ok(ScheduleDraft {
+            schedule_id: schedule_id.to_string(),
+            edit: self.decoded_schedule_edit(namespace, &schedule).await,
+            conflict_token: resp.conflict_token,
+        })
+    }
+
+    async fn update_schedule(
+        &self,
+        namespace: &str,
+        schedule_id: &str,
+        edit: &ScheduleEdit,
+        conflict_token: &[u8],
+    ) -> ClientResult<()> {
+        // The scheduler drops an update with a stale token without reporting
+        // it, so check first.
+        let resp = self
+            .describe_schedule_response(namespace, schedule_id)
+            .await?;
+        if resp.conflict_token != conflict_token {
+            return Err(schedule_conflict(schedule_id));
+        }
+        let mut schedule = resp.schedule.unwrap_or_default();
+        let original = self.decoded_schedule_edit(namespace, &schedule).await;
+        let mut input = schedule_edit_input(edit);
+        if edit.input != original.input {
+            self.codec_encode(namespace, input.payloads.iter_mut().collect())
+                .await?;
+        }
+        apply_schedule_edit(&mut schedule, edit, &original, input);
+
+        let inner = proto::UpdateScheduleRequest {
+            namespace: namespace.to_string(),
+            schedule_id: schedule_id.to_string(),
+            schedule: Some(schedule),
+            conflict_token: conflict_token.to_vec(),
+            identity: "t9s".to_string(),
+            request_id: uuid::Uuid::new_v4().to_string(),
+            search_attributes: None,
+            memo: None,
+        };
+
+        self.write(inner, |mut client, request| async move {
+            client.update_schedule(request).await
+        })
+        .await?;
+
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl TaskQueueReader for GrpcTemporalClient {
+    async fn describe_task_queue(
+        &self,
+        namespace: &str,
+        task_queue: &str,
+    ) -> ClientResult<TaskQueueInfo> {
+        let rules = proto::GetWorkerVersioningRulesRequest {
+            namespace: namespace.to_string(),
+            task_queue: task_queue.to_string(),
+        };
+        let (workflow, activity, rules) = futures::join!(
+            self.describe_task_queue_type(namespace, task_queue, 1), // WORKFLOW
+            self.describe_task_queue_type(namespace, task_queue, 2), // ACTIVITY
+            // Build-id rules are off on many servers; their absence isn't an error.
+            self.read(rules, |mut client, request| async move {
+                client.get_worker_versioning_rules(request).await
+            }),
+        );
+        let (workflow, activity) = (workflow?, activity?);
+        let rules = rules.ok().map(Response::into_inner);
+        Ok(TaskQueueInfo {
+            name: task_queue.to_string(),
+            versioning: task_queue_versioning(workflow.versioning_info.as_ref(), rules.as_ref()),
+            workflow_pollers: workflow.pollers.into_iter().map(poller).collect(),
+            activity_pollers: activity.pollers.into_iter().map(poller).collect(),
+        })
+    }
+}
+
+#[async_trait]
+impl NamespaceAdmin for GrpcTemporalClient {
+    async fn add_search_attribute(
+        &self,
+        namespace: &str,
+        name: &str,
+        value_type: SearchAttributeType,
+    ) -> ClientResult<()> {
+        let inner = proto::temporal::api::operatorservice::v1::AddSearchAttributesRequest {
+            search_attributes: [(name.to_string(), indexed_value_type(value_type) as i32)].into(),
+            namespace: namespace.to_string(),
+        };
+        self.operator_write(inner, |mut client, request| async move {
+            client.add_search_attributes(request).await
+        })
+        .await?;
+        Ok(())
+    }
+
+    async fn register_namespace(
+        &self,
+        name: &str,
+        description: &str,
+        retention: Duration,
+    ) -> ClientResult<()> {
+        let inner = proto::RegisterNamespaceRequest {
+            namespace: name.to_string(),
+            description: description.to_string(),
+            workflow_execution_retention_period: Some(proto_duration(retention)),
+            ..Default::default()
+        };
+        self.write(inner, |mut client, request| async move {
+            client.register_namespace(request).await
+        })
+        .await?;
+        Ok(())
+    }
+
+    async fn update_namespace(
+        &self,
+        name: &str,
+        description: Option<&str>,
+        retention: Option<Duration>,
+    ) -> ClientResult<()> {
+        use proto::temporal::api::namespace::v1::{NamespaceConfig, UpdateNamespaceInfo};
+        // The server leaves empty and unspecified fields as they are.
+        let inner = proto::UpdateNamespaceRequest {
+            namespace: name.to_string(),
+            update_info: description.map(|description| UpdateNamespaceInfo {
+                description: description.to_string(),
+                ..Default::default()
+            }),
+            config: retention.map(|retention| NamespaceConfig {
+                workflow_execution_retention_ttl: Some(proto_duration(retention)),
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+        self.write(inner, |mut client, request| async move {
+            client.update_namespace(request).await
+        })
+        .await?;
+        Ok(())
+    }
+
+    async fn deprecate_namespace(&self, name: &str) -> ClientResult<()> {
+        use proto::temporal::api::enums::v1::NamespaceState;
+        use proto::temporal::api::namespace::v1::UpdateNamespaceInfo;
+        let inner = proto::UpdateNamespaceRequest {
+            namespace: name.to_string(),
+            update_info: Some(UpdateNamespaceInfo {
+                state: NamespaceState::Deprecated as i32,
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+        self.write(inner, |mut client, request| async move {
+            client.update_namespace(request).await
+        })
+        .await?;
+        Ok(())
+    }
+}
+
+/// `message` in the proto JSON mapping, the shape the HTTP API returns.
+fn proto_json(message: &impl serde::Serialize) -> ClientResult<serde_json::Value> {
+    serde_json::to_value(message).map_err(|e| ClientError::ParseError(e.to_string()))
+}
+
+fn proto_duration(d: Duration) -> pbjson_types::Duration {
+    pbjson_types::Duration {
+        seconds: d.as_secs() as i64,
+        nanos: d.subsec_nanos() as i32,
+    }
+}
+
+impl TemporalClient for GrpcTemporalClient {
+    fn execution_admin(&self) -> Option<&dyn ExecutionAdmin> {
+        Some(self)
+    }
+
+    fn schedule_admin(&self) -> Option<&dyn ScheduleAdmin> {
+        Some(self)
+    }
+
+    fn task_queue_reader(&self) -> Option<&dyn TaskQueueReader> {
+        Some(self)
+    }
+
+    fn namespace_admin(&self) -> Option<&dyn NamespaceAdmin> {
+        Some(self)
+    }
+
+    fn is_reconnecting(&self) -> bool {
+        self.conn.reconnecting.load(Ordering::SeqCst)
+    }
+
+    fn is_disconnected(&self) -> bool {
+        self.conn.gave_up.load(Ordering::SeqCst)
+    }
+
+    fn reconnect(&self) {
+        self.conn.retry();
+    }
+
+    fn take_decode_errors(&self) -> Vec<String> {
+        self.codec
+            .as_ref()
+            .map(CodecClient::take_decode_errors)
+            .unwrap_or_default()
+    }
+}
+
+/// Local addresses are dialed in plaintext unless an API key is set.
+pub fn uses_tls(address: &str, has_api_key: bool) -> bool {
+    let is_localhost = address.starts_with("localhost")
+        || address.starts_with("127.0.0.1")
+        || address.starts_with("[::1]");
+    !is_localhost || has_api_key
+}
+
+/// Merges the operator service's custom and system maps, sorted by name.
+pub(super) fn search_attributes(
+    custom: &HashMap<String, i32>,
+    system: &HashMap<String, i32>,
+) -> Vec<SearchAttribute> {
+    let mut attributes: Vec<SearchAttribute> = custom
+        .iter()
+        .map(|entry| (entry, true))
+        .chain(system.iter().map(|entry| (entry, false)))
+        .map(|((name, value), custom)| SearchAttribute {
+            name: name.clone(),
+            value_type: search_attribute_type(*value),
+            custom,
+        })
+        .collect();
+    attributes.sort_by(|a, b| a.name.cmp(&b.name));
+    attributes
+}
+
+fn search_attribute_type(value: i32) -> Option<SearchAttributeType> {
+    use proto::temporal::api::enums::v1::IndexedValueType as V;
+    Some(match V::try_from(value).ok()? {
+        V::Keyword => SearchAttributeType::Keyword,
+        V::Text => SearchAttributeType::Text,
+        V::Int => SearchAttributeType::Int,
+        V::Double => SearchAttributeType::Double,
+        V::Bool => SearchAttributeType::Bool,
+        V::Datetime => SearchAttributeType::Datetime,
+        V::KeywordList => SearchAttributeType::KeywordList,
+        V::Unspecified => return None,
+    })
+}
+
+pub(super) fn indexed_value_type(
+    value_type: SearchAttributeType,
+) -> proto::temporal::api::enums::v1::IndexedValueType {
+    use proto::temporal::api::enums::v1::IndexedValueType as V;
+    match value_type {
+        SearchAttributeType::Keyword => V::Keyword,
+        SearchAttributeType::Text => V::Text,
+        SearchAttributeType::Int => V::Int,
+        SearchAttributeType::Double => V::Double,
+        SearchAttributeType::Bool => V::Bool,
+        SearchAttributeType::Datetime => V::Datetime,
+        SearchAttributeType::KeywordList => V::KeywordList,
+    }
+}
+
+/// `query` grouped by status, for a per-status count in one call. Counts
+/// can't be ordered, so an ORDER BY is dropped.
+pub(super) fn grouped_by_status(query: Option<&str>) -> String {
+    let (filter, _) = query_syntax::split_order_by(query.unwrap_or(""));
+    format!("{} GROUP BY ExecutionStatus", filter)
+        .trim_start()
+        .to_string()
+}
+
+/// Per-status counts from `(group value, count)` pairs, in the order of
+/// [`WorkflowStatus::ALL`].
+pub(super) fn status_counts(
+    groups: impl IntoIterator<Item = (serde_json::Value, i64)>,
+) -> Vec<(WorkflowStatus, u64)> {
+    let mut counts: Vec<(WorkflowStatus, u64)> = groups
+        .into_iter()
+        .filter_map(|(value, count)| {
+            Some((WorkflowStatus::parse(value.as_str()?)?, count.max(0) as u64))
+        })
+        .collect();
+    counts.sort_by_key(|(status, _)| WorkflowStatus::ALL.iter().position(|s| s == status));
+    counts
+}
+
+/// One count per status, for servers whose visibility store can't group.
+pub(super) async fn count_each_status(
+    reader: &(impl VisibilityReader + ?Sized),
+    namespace: &str,
+    query: Option<&str>,
+) -> ClientResult<Vec<(WorkflowStatus, u64)>> {
+    let filter = query.map(|q| query_syntax::split_order_by(q).0);
+    let counts = futures::future::try_join_all(WorkflowStatus::ALL.iter().map(|status| {
+        let clause = format!("ExecutionStatus = '{}'", status.as_str());
+        let query = match filter.filter(|q| !q.is_empty()) {
+            Some(q) => format!("({}) AND {}", q, clause),
+            None => clause,
+        };
+        async move {
+            let count = reader.count_workflows(namespace, Some(&query)).await?;
+            Ok::<_, ClientError>((status.clone(), count))
+        }
+    }))
+    .await?;
+    Ok(counts.into_iter().filter(|(_, count)| *count > 0).collect())
+}
+
+fn grpc_error(status: Status) -> ClientError {
+    match status.code() {
+        tonic::Code::NotFound => ClientError::NotFound(status.message().to_string()),
+        tonic::Code::Unimplemented => ClientError::Unimplemented(status.message().to_string()),
+        tonic::Code::DeadlineExceeded => ClientError::Timeout,
+        tonic::Code::InvalidArgument => ClientError::InvalidArgument(status.message().to_string()),
+        tonic::Code::Unavailable => ClientError::ConnectionError(status.message().to_string()),
+        _ => ClientError::RequestFailed(format!("{}: {}", status.code(), status.message())),
+    }
+}
+
+pub(super) fn workflow_info_to_summary(
+    info: proto::temporal::api::workflow::v1::WorkflowExecutionInfo,
+) -> ClientResult<WorkflowSummary> {
+    let execution = info
+        .execution
+        .ok_or_else(|| ClientError::ParseError("missing execution".into()))?;
+
+    let workflow_type = info
+        .r#type
+        .map(|t| t.name)
+        .unwrap_or_else(|| "Unknown".to_string());
+
+    let status = proto_status_to_domain(info.status);
+
+    let start_time = info
+        .start_time
+        .map(|t| timestamp_to_datetime(&t))
+        .unwrap_or_else(Utc::now);
+
+    let close_time = info.close_time.map(|t| timestamp_to_datetime(&t));
+
+    let task_queue = info.task_queue;
+
+    let search_attributes = info
+        .search_attributes
+        .map(|sa| {
+            sa.indexed_fields
+                .iter()
+                .map(|(k, v)| (k.clone(), decode_payload(v)))
+                .collect()
+        })
+        .unwrap_or_default();
+
+    Ok(WorkflowSummary {
+        workflow_id: execution.workflow_id,
+        run_id: execution.run_id,
+        workflow_type,
+        status,
+        start_time,
+        close_time,
+        task_queue,
+        search_attributes,
+        namespace: None,
+    })
+}
+
+/// The memo's payloads, for the codec to decode in place.
+pub(super) fn memo_payloads_mut(
+    info: &mut proto::temporal::api::workflow::v1::WorkflowExecutionInfo,
+) -> Vec<&mut proto::temporal::api::common::v1::Payload> {
+    memo_mut(&mut info.memo)
+}
+
+pub(super) fn memo_fields(
+    info: &proto::temporal::api::workflow::v1::WorkflowExecutionInfo,
+) -> HashMap<String, serde_json::Value> {
+    info.memo
+        .as_ref()
+        .map(|memo| {
+            memo.fields
+                .iter()
+                .map(|(k, v)| (k.clone(), decode_payload(v)))
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+pub(super) fn activity_list_info_to_summary(
+    info: proto::temporal::api::activity::v1::ActivityExecutionListInfo,
+) -> ClientResult<ActivityExecutionSummary> {
+    Ok(ActivityExecutionSummary {
+        activity_id: info.activity_id.clone(),
+        run_id: info.run_id.clone(),
+        activity_type: info
+            .activity_type
+            .as_ref()
+            .map(|t| t.name.clone())
+            .unwrap_or_else(|| "Unknown".to_string()),
+        status: proto_activity_status_to_domain(info.status),
+        schedule_time: info.schedule_time.as_ref().map(timestamp_to_datetime),
+        close_time: info.close_time.as_ref().map(timestamp_to_datetime),
+        task_queue: info.task_queue,
+    })
+}
+
+pub(super) fn schedule_action(
+    result: &proto::temporal::api::schedule::v1::ScheduleActionResult,
+) -> ScheduleAction {
+    let execution = result.start_workflow_result.as_ref();
+    ScheduleAction {
+        scheduled_time: result.schedule_time.as_ref().map(timestamp_to_datetime),
+        actual_time: result.actual_time.as_ref().map(timestamp_to_datetime),
+        workflow_id: execution.map(|e| e.workflow_id.clone()).unwrap_or_default(),
+        run_id: execution.map(|e| e.run_id.clone()).unwrap_or_default(),
+        status: (result.start_workflow_status != 0)
+            .then(|| proto_status_to_domain(result.start_workflow_status)),
+    }
+}
+
+pub(super) fn schedule_conflict(schedule_id: &str) -> ClientError {
+    ClientError::Conflict(format!(
+        "schedule {} changed since it was opened for editing; edit it again",
+        schedule_id
+    ))
+}
+
+/// The editable fields of `schedule`, with every calendar flattened to a
+/// cron line.
+pub(super) fn schedule_edit(
+    schedule: &proto::temporal::api::schedule::v1::Schedule,
+) -> ScheduleEdit {
+    use proto::temporal::api::schedule::v1::schedule_action::Action;
+
+    let mut edit = ScheduleEdit::default();
+    if let Some(spec) = &schedule.spec {
+        edit.cron = spec.cron_string.clone();
+        edit.cron.extend(spec.calendar.iter().map(calendar_line));
+        edit.cron.extend(
+            spec.structured_calendar
+                .iter()
+                .map(structured_calendar_line),
+        );
+        edit.intervals = spec
+            .interval
+            .iter()
+            .filter_map(|i| {
+                let every = format_duration_text(i.interval.as_ref()?.seconds);
+                Some(match i.phase.as_ref().filter(|p| p.seconds > 0) {
+                    Some(phase) => format!("{}/{}", every, format_duration_text(phase.seconds)),
+                    None => every,
+                })
+            })
+            .collect();
+        edit.timezone = spec.timezone_name.clone();
+        edit.jitter = spec
+            .jitter
+            .as_ref()
+            .map(|d| format_duration_text(d.seconds));
+    }
+    let start = schedule
+        .action
+        .as_ref()
+        .and_then(|a| a.action.as_ref())
+        .map(|Action::StartWorkflow(wf)| wf);
+    if let Some(wf) = start {
+        edit.workflow_type = wf
+            .workflow_type
+            .as_ref()
+            .map(|t| t.name.clone())
+            .unwrap_or_default();
+        edit.workflow_id = wf.workflow_id.clone();
+        edit.task_queue = wf
+            .task_queue
+            .as_ref()
+            .map(|t| t.name.clone())
+            .unwrap_or_default();
+        edit.input = wf
+            .input
+            .as_ref()
+            .map(|p| p.payloads.iter().map(decode_payload).collect())
+            .unwrap_or_default();
+        let text = |d: &Option<pbjson_types::Duration>| {
+            d.as_ref().map(|d| format_duration_text(d.seconds))
+        };
+        edit.execution_timeout = text(&wf.workflow_execution_timeout);
+        edit.run_timeout = text(&wf.workflow_run_timeout);
+        edit.task_timeout = text(&wf.workflow_task_timeout);
+    }
+    edit
+}
+
+/// The edited schedule input as JSON payloads, before any codec encoding.
+pub(super) fn schedule_edit_input(
+    edit: &ScheduleEdit,
+) -> proto::temporal::api::common::v1::Payloads {
+    proto::temporal::api::common::v1::Payloads {
+        payloads: edit
+            .input
+            .iter()
+            .flat_map(|value| json_payloads(value).payloads)
+            .collect(),
+    }
+}
+
+/// Which of a spec's calendars and structured calendars the edited cron
+/// lines still name, and the lines left over as cron strings. Calendars
+/// whose line is untouched are kept as the server had them, rather than
+/// being replaced by their (lossier) cron text.
+pub(super) struct CronSplit {
+    pub(super) keep_calendar: Vec<bool>,
+    pub(super) keep_structured: Vec<bool>,
+    pub(super) cron_strings: Vec<String>,
+}
+
+pub(super) fn split_cron_lines(
+    spec: &proto::temporal::api::schedule::v1::ScheduleSpec,
+    cron: &[String],
+) -> CronSplit {
+    let mut remaining: Vec<&String> = cron.iter().collect();
+    let mut take = |line: String| match remaining.iter().position(|l| **l == line) {
+        Some(i) => {
+            remaining.remove(i);
+            true
+        }
+        None => false,
+    };
+    let keep_calendar = spec
+        .calendar
+        .iter()
+        .map(|c| take(calendar_line(c)))
+        .collect();
+    let keep_structured = spec
+        .structured_calendar
+        .iter()
+        .map(|c| take(structured_calendar_line(c)))
+        .collect();
+    CronSplit {
+        keep_calendar,
+        keep_structured,
+        cron_strings: remaining.into_iter().cloned().collect(),
+    }
+}
+
+/// Writes the fields of `edit` that differ from `original` into `schedule`,
+/// leaving everything else as the server had it. `input` is the edited
+/// input, already encoded.
+fn apply_schedule_edit(
+    schedule: &mut proto::temporal::api::schedule::v1::Schedule,
+    edit: &ScheduleEdit,
+    original: &ScheduleEdit,
+    input: proto::temporal::api::common::v1::Payloads,
+) {
+    use proto::temporal::api::schedule::v1::{schedule_action::Action, IntervalSpec};
+
+    let duration = |text: &Option<String>| {
+        text.as_deref()
+            .and_then(parse_duration_text)
+            .map(|seconds| pbjson_types::Duration { seconds, nanos: 0 })
+    };
+    let spec = schedule.spec.get_or_insert_with(Default::default);
+    if edit.cron != original.cron {
+        let split = split_cron_lines(spec, &edit.cron);
+        let mut keep = split.keep_calendar.into_iter();
+        spec.calendar.retain(|_| keep.next().unwrap_or(false));
+        let mut keep = split.keep_structured.into_iter();
+        spec.structured_calendar
+            .retain(|_| keep.next().unwrap_or(false));
+        spec.cron_string = split.cron_strings;
+    }
+    if edit.intervals != original.intervals {
+        spec.interval = edit
+            .intervals
+            .iter()
+            .filter_map(|i| ScheduleEdit::parse_interval(i))
+            .map(|(every, phase)| IntervalSpec {
+                interval: Some(pbjson_types::Duration {
+                    seconds: every,
+                    nanos: 0,
+                }),
+                phase: Some(pbjson_types::Duration {
+                    seconds: phase,
+                    nanos: 0,
+                }),
+            })
+            .collect();
+    }
+    if edit.timezone != original.timezone {
+        spec.timezone_name = edit.timezone.clone();
+        spec.timezone_data.clear();
+    }
+    if edit.jitter != original.jitter {
+        spec.jitter = duration(&edit.jitter);
+    }
+
+    let action = schedule.action.get_or_insert_with(Default::default);
+    let Action::StartWorkflow(wf) = action
+        .action
+        .get_or_insert_with(|| Action::StartWorkflow(Default::default()));
+    if edit.workflow_type != original.workflow_type {
+        wf.workflow_type = Some(proto::temporal::api::common::v1::WorkflowType {
+            name: edit.workflow_type.clone(),
+        });
+    }
+    if edit.workflow_id != original.workflow_id {
+        wf.workflow_id = edit.workflow_id.clone();
+    }
+    if edit.task_queue != original.task_queue {
+        wf.task_queue = Some(proto::temporal::api::taskqueue::v1::TaskQueue {
+            name: edit.task_queue.clone(),
+            ..Default::default()
+        });
+    }
+    // Untouched input keeps its original encoding.
+    if edit.input != original.input {
+        wf.input = Some(input);
+    }
+    if edit.execution_timeout != original.execution_timeout {
+        wf.workflow_execution_timeout = duration(&edit.execution_timeout);
+    }
+    if edit.run_timeout != original.run_timeout {
+        wf.workflow_run_timeout = duration(&edit.run_timeout);
+    }
+    if edit.task_timeout != original.task_timeout {
+        wf.workflow_task_timeout = duration(&edit.task_timeout);
+    }
+}
+
+pub(super) struct ScheduleSpecFields {
+    pub(super) description: String,
+    pub(super) timezone: Option<String>,
+    pub(super) calendars: Vec<ScheduleCalendar>,
+}
+
+/// Summarises a schedule spec as cron-like text and extracts the calendar
+/// times and zone used for next-run display and DST warnings.
+pub(super) fn schedule_spec_fields(
+    spec: Option<&proto::temporal::api::schedule::v1::ScheduleSpec>,
+) -> ScheduleSpecFields {
+    let mut fields = ScheduleSpecFields {
+        description: String::new(),
+        timezone: None,
+        calendars: vec![],
+    };
+    let Some(spec) = spec else {
+        return fields;
+    };
+    if !spec.timezone_name.is_empty() {
+        fields.timezone = Some(spec.timezone_name.clone());
+    }
+
+    let mut parts = Vec::new();
+    for cron in &spec.cron_string {
+        parts.push(cron.clone());
+        if let Some((tz, calendar)) = ScheduleCalendar::from_cron(cron) {
+            if fields.timezone.is_none() {
+                fields.timezone = tz;
+            }
+            fields.calendars.push(calendar);
+        }
+    }
+    for cal in &spec.calendar {
+        parts.push(calendar_line(cal));
+        let minute = if cal.minute.is_empty() {
+            "0"
+        } else {
+            &cal.minute
+        };
+        let hour = if cal.hour.is_empty() { "0" } else { &cal.hour };
+        if let Some(calendar) = ScheduleCalendar::from_fields(minute, hour) {
+            fields.calendars.push(calendar);
+        }
+    }
+    for cal in &spec.structured_calendar {
+        parts.push(structured_calendar_line(cal));
+        fields.calendars.push(ScheduleCalendar {
+            hours: expand_ranges(&cal.hour),
+            minutes: expand_ranges(&cal.minute),
+        });
+    }
+    for interval in &spec.interval {
+        if let Some(every) = interval.interval.as_ref() {
+            parts.push(format!("every {}s", every.seconds));
         }
     }
+    fields.description = parts.join("; ");
+    fields
 }
 
-fn grpc_error(status: Status) -> ClientError {
-    match status.code() {
-        tonic::Code::NotFound => ClientError::NotFound(status.message().to_string()),
-        tonic::Code::DeadlineExceeded => ClientError::Timeout,
-        tonic::Code::Unavailable => ClientError::ConnectionError(status.message().to_string()),
-        _ => ClientError::RequestFailed(format!("{}: {}", status.code(), status.message())),
-    }
+fn calendar_line(cal: &proto::temporal::api::schedule::v1::CalendarSpec) -> String {
+    let or = |s: &str, empty: &str| {
+        if s.is_empty() {
+            empty.to_string()
+        } else {
+            s.to_string()
+        }
+    };
+    format!(
+        "{} {} {} {} {}",
+        or(&cal.minute, "0"),
+        or(&cal.hour, "0"),
+        or(&cal.day_of_month, "*"),
+        or(&cal.month, "*"),
+        or(&cal.day_of_week, "*")
+    )
 }
 
-fn workflow_info_to_summary(
-    info: proto::temporal::api::workflow::v1::WorkflowExecutionInfo,
-) -> ClientResult<WorkflowSummary> {
-    let execution = info
-        .execution
-        .ok_or_else(|| ClientError::ParseError("missing execution".into()))?;
-
-    let workflow_type = info
-        .r#type
-        .map(|t| t.name)
-        .unwrap_or_else(|| "Unknown".to_string());
-
-    let status = proto_status_to_domain(info.status);
-
-    let start_time = info
-        .start_time
-        .map(|t| timestamp_to_datetime(&t))
-        .unwrap_or_else(Utc::now);
-
-    let close_time = info.close_time.map(|t| timestamp_to_datetime(&t));
-
-    let task_queue = info.task_queue;
+fn structured_calendar_line(
+    cal: &proto::temporal::api::schedule::v1::StructuredCalendarSpec,
+) -> String {
+    format!(
+        "{} {} {} {} {}",
+        format_ranges(&cal.minute, "0"),
+        format_ranges(&cal.hour, "0"),
+        format_ranges(&cal.day_of_month, "*"),
+        format_ranges(&cal.month, "*"),
+        format_ranges(&cal.day_of_week, "*")
+    )
+}
 
-    Ok(WorkflowSummary {
-        workflow_id: execution.workflow_id,
-        run_id: execution.run_id,
-        workflow_type,
-        status,
-        start_time,
-        close_time,
-        task_queue,
-    })
+fn format_ranges(ranges: &[proto::temporal::api::schedule::v1::Range], empty: &str) -> String {
+    if ranges.is_empty() {
+        return empty.to_string();
+    }
+    ranges
+        .iter()
+        .map(|r| {
+            let mut s = r.start.to_string();
+            if r.end > r.start {
+                s.push_str(&format!("-{}", r.end));
+            }
+            if r.step > 1 {
+                s.push_str(&format!("/{}", r.step));
+            }
+            s
+        })
+        .collect::<Vec<_>>()
+        .join(",")
 }
 
-fn activity_list_info_to_summary(
-    info: proto::temporal::api::activity::v1::ActivityExecutionListInfo,
-) -> ClientResult<ActivityExecutionSummary> {
-    Ok(ActivityExecutionSummary {
-        activity_id: info.activity_id.clone(),
-        run_id: info.run_id.clone(),
-        activity_type: info
-            .activity_type
-            .as_ref()
-            .map(|t| t.name.clone())
-            .unwrap_or_else(|| "Unknown".to_string()),
-        status: proto_activity_status_to_domain(info.status),
-        schedule_time: info.schedule_time.as_ref().map(timestamp_to_datetime),
-        close_time: info.close_time.as_ref().map(timestamp_to_datetime),
-        task_queue: info.task_queue,
-    })
+/// Unset hour and minute ranges default to 0, as on the server.
+fn expand_ranges(ranges: &[proto::temporal::api::schedule::v1::Range]) -> Vec<u32> {
+    if ranges.is_empty() {
+        return vec![0];
+    }
+    let mut values: Vec<u32> = ranges
+        .iter()
+        .flat_map(|r| {
+            let start = r.start.max(0) as u32;
+            let end = r.end.max(r.start).max(0) as u32;
+            (start..=end).step_by(r.step.max(1) as usize)
+        })
+        .collect();
+    values.sort_unstable();
+    values.dedup();
+    values
 }
 
-fn timestamp_to_datetime(ts: &prost_types::Timestamp) -> DateTime<Utc> {
+fn timestamp_to_datetime(ts: &pbjson_types::Timestamp) -> DateTime<Utc> {
     Utc.timestamp_opt(ts.seconds, ts.nanos as u32)
         .single()
         .unwrap_or_else(Utc::now)
 }
 
+fn domain_status_to_proto(status: &WorkflowStatus) -> i32 {
+    use crate::proto::temporal::api::enums::v1::WorkflowExecutionStatus;
+
+    let status = match status {
+        WorkflowStatus::Running => WorkflowExecutionStatus::Running,
+        WorkflowStatus::Completed => WorkflowExecutionStatus::Completed,
+        WorkflowStatus::Failed => WorkflowExecutionStatus::Failed,
+        WorkflowStatus::Canceled => WorkflowExecutionStatus::Canceled,
+        WorkflowStatus::Terminated => WorkflowExecutionStatus::Terminated,
+        WorkflowStatus::ContinuedAsNew => WorkflowExecutionStatus::ContinuedAsNew,
+        WorkflowStatus::TimedOut => WorkflowExecutionStatus::TimedOut,
+    };
+    status as i32
+}
+
 fn proto_status_to_domain(status: i32) -> WorkflowStatus {
     use crate::proto::temporal::api::enums::v1::WorkflowExecutionStatus;
 
@@ -969,14 +2751,14 @@ fn proto_activity_status_to_domain(status: i32) -> ActivityExecutionStatus {
     }
 }
 
-fn duration_to_std(d: &prost_types::Duration) -> std::time::Duration {
+pub(super) fn duration_to_std(d: &pbjson_types::Duration) -> std::time::Duration {
     if d.seconds < 0 {
         return std::time::Duration::from_secs(0);
     }
     std::time::Duration::new(d.seconds as u64, d.nanos.max(0) as u32)
 }
 
-fn deployment_version_string(
+pub(super) fn deployment_version_string(
     version: &proto::temporal::api::deployment::v1::WorkerDeploymentVersion,
 ) -> String {
     if version.deployment_name.is_empty() && version.build_id.is_empty() {
@@ -991,7 +2773,28 @@ fn deployment_version_string(
     format!("{}@{}", version.deployment_name, version.build_id)
 }
 
-fn failure_retry_state(failure: &proto::temporal::api::failure::v1::Failure) -> Option<String> {
+pub(super) fn activity_options_edit(
+    options: &proto::temporal::api::activity::v1::ActivityOptions,
+) -> ActivityOptionsEdit {
+    let text =
+        |d: &Option<pbjson_types::Duration>| d.as_ref().map(|d| format_duration_text(d.seconds));
+    let retry = options.retry_policy.clone().unwrap_or_default();
+    ActivityOptionsEdit {
+        schedule_to_close_timeout: text(&options.schedule_to_close_timeout),
+        schedule_to_start_timeout: text(&options.schedule_to_start_timeout),
+        start_to_close_timeout: text(&options.start_to_close_timeout),
+        heartbeat_timeout: text(&options.heartbeat_timeout),
+        retry_initial_interval: text(&retry.initial_interval),
+        retry_backoff_coefficient: retry.backoff_coefficient,
+        retry_maximum_interval: text(&retry.maximum_interval),
+        retry_maximum_attempts: retry.maximum_attempts,
+        non_retryable_error_types: retry.non_retryable_error_types,
+    }
+}
+
+pub(super) fn failure_retry_state(
+    failure: &proto::temporal::api::failure::v1::Failure,
+) -> Option<String> {
     use crate::proto::temporal::api::failure::v1::failure::FailureInfo;
     let retry_state = match &failure.failure_info {
         Some(FailureInfo::ActivityFailureInfo(info)) => info.retry_state,
@@ -1008,7 +2811,133 @@ fn retry_state_string(retry_state: i32) -> String {
         .unwrap_or_else(|_| "Unknown".to_string())
 }
 
-fn event_type_name(event_type: i32) -> String {
+pub(super) fn poller(p: proto::temporal::api::taskqueue::v1::PollerInfo) -> Poller {
+    use proto::temporal::api::deployment::v1::WorkerDeploymentVersion;
+
+    #[allow(deprecated)]
+    let version = match (&p.deployment_options, &p.worker_version_capabilities) {
+        (Some(options), _) => Some(WorkerDeploymentVersion {
+            deployment_name: options.deployment_name.clone(),
+            build_id: options.build_id.clone(),
+        }),
+        (None, Some(caps)) => Some(WorkerDeploymentVersion {
+            deployment_name: caps.deployment_series_name.clone(),
+            build_id: caps.build_id.clone(),
+        }),
+        (None, None) => None,
+    };
+    Poller {
+        identity: p.identity,
+        last_access_time: p.last_access_time.map(|t| timestamp_to_datetime(&t)),
+        rate_per_second: p.rate_per_second,
+        version: deployment_version(version.as_ref()),
+    }
+}
+
+/// [`deployment_version_string`], or `None` when the version is unset.
+fn deployment_version(
+    version: Option<&proto::temporal::api::deployment::v1::WorkerDeploymentVersion>,
+) -> Option<String> {
+    version
+        .map(deployment_version_string)
+        .filter(|v| !v.is_empty())
+}
+
+pub(super) fn task_queue_versioning(
+    info: Option<&proto::temporal::api::taskqueue::v1::TaskQueueVersioningInfo>,
+    rules: Option<&proto::GetWorkerVersioningRulesResponse>,
+) -> TaskQueueVersioning {
+    use proto::temporal::api::taskqueue::v1::build_id_assignment_rule::Ramp;
+
+    let mut versioning = TaskQueueVersioning::default();
+    if let Some(info) = info {
+        versioning.current_version = deployment_version(info.current_deployment_version.as_ref());
+        versioning.ramping_version = deployment_version(info.ramping_deployment_version.as_ref());
+        versioning.ramping_percentage = info.ramping_version_percentage;
+    }
+    if let Some(rules) = rules {
+        versioning.assignment_rules = rules
+            .assignment_rules
+            .iter()
+            .filter_map(|r| r.rule.as_ref())
+            .map(|rule| match rule.ramp {
+                Some(Ramp::PercentageRamp(ramp)) => {
+                    format!("{} (ramp {}%)", rule.target_build_id, ramp.ramp_percentage)
+                }
+                None => rule.target_build_id.clone(),
+            })
+            .collect();
+        versioning.redirect_rules = rules
+            .compatible_redirect_rules
+            .iter()
+            .filter_map(|r| r.rule.as_ref())
+            .map(|rule| format!("{} -> {}", rule.source_build_id, rule.target_build_id))
+            .collect();
+    }
+    versioning
+}
+
+/// `None` for executions that never ran on a versioned worker.
+pub(super) fn workflow_versioning(
+    info: &proto::temporal::api::workflow::v1::WorkflowExecutionInfo,
+) -> Option<WorkflowVersioning> {
+    use proto::temporal::api::enums::v1::VersioningBehavior;
+    use proto::temporal::api::workflow::v1::versioning_override::Override;
+
+    let info = info.versioning_info.as_ref()?;
+    let behavior = match VersioningBehavior::try_from(info.behavior) {
+        Ok(VersioningBehavior::Pinned) => "Pinned",
+        Ok(VersioningBehavior::AutoUpgrade) => "AutoUpgrade",
+        _ => "Unversioned",
+    };
+    let version = deployment_version(info.deployment_version.as_ref());
+    let versioning_override = info
+        .versioning_override
+        .as_ref()
+        .and_then(|o| match &o.r#override {
+            Some(Override::Pinned(pinned)) => {
+                Some(match deployment_version(pinned.version.as_ref()) {
+                    Some(version) => format!("Pinned to {}", version),
+                    None => "Pinned".to_string(),
+                })
+            }
+            Some(Override::AutoUpgrade(true)) => Some("AutoUpgrade".to_string()),
+            _ => None,
+        });
+    if behavior == "Unversioned" && version.is_none() && versioning_override.is_none() {
+        return None;
+    }
+    Some(WorkflowVersioning {
+        behavior: behavior.to_string(),
+        version,
+        versioning_override,
+    })
+}
+
+pub(super) fn pending_activity_state(state: i32) -> PendingActivityState {
+    match state {
+        2 => PendingActivityState::Started,
+        3 => PendingActivityState::CancelRequested,
+        _ => PendingActivityState::Scheduled,
+    }
+}
+
+pub(super) fn parent_close_policy_string(policy: i32) -> String {
+    use crate::proto::temporal::api::enums::v1::ParentClosePolicy;
+    match ParentClosePolicy::try_from(policy) {
+        Ok(ParentClosePolicy::Unspecified) | Err(_) => "Terminate".to_string(),
+        Ok(p) => format!("{:?}", p),
+    }
+}
+
+pub(super) fn nexus_operation_state_string(state: i32) -> String {
+    use crate::proto::temporal::api::enums::v1::PendingNexusOperationState;
+    PendingNexusOperationState::try_from(state)
+        .map(|s| format!("{:?}", s))
+        .unwrap_or_else(|_| "Unknown".to_string())
+}
+
+pub(super) fn event_type_name(event_type: i32) -> String {
     use crate::proto::temporal::api::enums::v1::EventType;
     match EventType::try_from(event_type) {
         Ok(et) => format!("{:?}", et),
@@ -1016,21 +2945,238 @@ fn event_type_name(event_type: i32) -> String {
     }
 }
 
-fn decode_payloads(
+fn payloads_mut(
+    payloads: &mut Option<proto::temporal::api::common::v1::Payloads>,
+) -> Vec<&mut proto::temporal::api::common::v1::Payload> {
+    payloads
+        .as_mut()
+        .map(|p| p.payloads.iter_mut().collect())
+        .unwrap_or_default()
+}
+
+/// Note recorded by a pause or unpause, defaulting when none was typed.
+pub(super) fn pause_note(pause: bool, note: &str) -> String {
+    match (note.trim(), pause) {
+        ("", true) => "paused by t9s".to_string(),
+        ("", false) => "unpaused by t9s".to_string(),
+        (note, _) => note.to_string(),
+    }
+}
+
+/// The start-workflow input of a schedule, for codec decoding.
+pub(super) fn schedule_input_mut(
+    schedule: &mut proto::temporal::api::schedule::v1::Schedule,
+) -> Vec<&mut proto::temporal::api::common::v1::Payload> {
+    use proto::temporal::api::schedule::v1::schedule_action::Action;
+
+    match schedule.action.as_mut().and_then(|a| a.action.as_mut()) {
+        Some(Action::StartWorkflow(wf)) => payloads_mut(&mut wf.input),
+        None => vec![],
+    }
+}
+
+/// Payloads of `event` for codec decoding: its inputs, results, details and
+/// failures, and the memo and search attributes it sets.
+fn event_payloads_mut(
+    event: &mut proto::temporal::api::history::v1::HistoryEvent,
+) -> Vec<&mut proto::temporal::api::common::v1::Payload> {
+    use proto::temporal::api::history::v1::history_event::Attributes;
+
+    let mut payloads = user_metadata_payloads_mut(&mut event.user_metadata);
+    payloads.extend(match event.attributes.as_mut() {
+        Some(Attributes::WorkflowExecutionStartedEventAttributes(a)) => {
+            let mut out = payloads_mut(&mut a.input);
+            out.extend(failure_payloads_mut(&mut a.continued_failure));
+            out.extend(payloads_mut(&mut a.last_completion_result));
+            out.extend(memo_mut(&mut a.memo));
+            out.extend(search_attributes_mut(&mut a.search_attributes));
+            out
+        }
+        Some(Attributes::WorkflowExecutionCompletedEventAttributes(a)) => {
+            payloads_mut(&mut a.result)
+        }
+        Some(Attributes::WorkflowExecutionContinuedAsNewEventAttributes(a)) => {
+            let mut out = payloads_mut(&mut a.input);
+            out.extend(payloads_mut(&mut a.last_completion_result));
+            out.extend(memo_mut(&mut a.memo));
+            out.extend(search_attributes_mut(&mut a.search_attributes));
+            out
+        }
+        Some(Attributes::WorkflowExecutionCanceledEventAttributes(a)) => {
+            payloads_mut(&mut a.details)
+        }
+        Some(Attributes::WorkflowExecutionTerminatedEventAttributes(a)) => {
+            payloads_mut(&mut a.details)
+        }
+        Some(Attributes::WorkflowTaskFailedEventAttributes(a)) => {
+            failure_payloads_mut(&mut a.failure)
+        }
+        Some(Attributes::ActivityTaskScheduledEventAttributes(a)) => payloads_mut(&mut a.input),
+        Some(Attributes::ActivityTaskCompletedEventAttributes(a)) => payloads_mut(&mut a.result),
+        Some(Attributes::ActivityTaskCanceledEventAttributes(a)) => payloads_mut(&mut a.details),
+        Some(Attributes::MarkerRecordedEventAttributes(a)) => {
+            let mut out: Vec<_> = a
+                .details
+                .values_mut()
+                .flat_map(|p| p.payloads.iter_mut())
+                .collect();
+            out.extend(failure_payloads_mut(&mut a.failure));
+            out
+        }
+        Some(Attributes::WorkflowExecutionSignaledEventAttributes(a)) => payloads_mut(&mut a.input),
+        Some(Attributes::SignalExternalWorkflowExecutionInitiatedEventAttributes(a)) => {
+            payloads_mut(&mut a.input)
+        }
+        Some(Attributes::UpsertWorkflowSearchAttributesEventAttributes(a)) => {
+            search_attributes_mut(&mut a.search_attributes)
+        }
+        Some(Attributes::WorkflowPropertiesModifiedEventAttributes(a)) => {
+            memo_mut(&mut a.upserted_memo)
+        }
+        Some(Attributes::ChildWorkflowExecutionCompletedEventAttributes(a)) => {
+            payloads_mut(&mut a.result)
+        }
+        Some(Attributes::ChildWorkflowExecutionCanceledEventAttributes(a)) => {
+            payloads_mut(&mut a.details)
+        }
+        Some(Attributes::StartChildWorkflowExecutionInitiatedEventAttributes(a)) => {
+            let mut out = payloads_mut(&mut a.input);
+            out.extend(memo_mut(&mut a.memo));
+            out.extend(search_attributes_mut(&mut a.search_attributes));
+            out
+        }
+        Some(Attributes::WorkflowExecutionFailedEventAttributes(a)) => {
+            failure_payloads_mut(&mut a.failure)
+        }
+        Some(Attributes::ActivityTaskFailedEventAttributes(a)) => {
+            failure_payloads_mut(&mut a.failure)
+        }
+        Some(Attributes::ActivityTaskTimedOutEventAttributes(a)) => {
+            failure_payloads_mut(&mut a.failure)
+        }
+        Some(Attributes::ChildWorkflowExecutionFailedEventAttributes(a)) => {
+            failure_payloads_mut(&mut a.failure)
+        }
+        _ => vec![],
+    });
+    payloads
+}
+
+fn memo_mut(
+    memo: &mut Option<proto::temporal::api::common::v1::Memo>,
+) -> Vec<&mut proto::temporal::api::common::v1::Payload> {
+    memo.as_mut()
+        .map(|m| m.fields.values_mut().collect())
+        .unwrap_or_default()
+}
+
+fn search_attributes_mut(
+    attributes: &mut Option<proto::temporal::api::common::v1::SearchAttributes>,
+) -> Vec<&mut proto::temporal::api::common::v1::Payload> {
+    attributes
+        .as_mut()
+        .map(|sa| sa.indexed_fields.values_mut().collect())
+        .unwrap_or_default()
+}
+
+/// Payloads of `event` that aren't text, under the keys
+/// `extract_event_details` gives them.
+fn event_binary_payloads(
+    event: &proto::temporal::api::history::v1::HistoryEvent,
+) -> Vec<(String, Vec<u8>)> {
+    use proto::temporal::api::history::v1::history_event::Attributes;
+
+    let (key, payloads) = match event.attributes.as_ref() {
+        Some(Attributes::WorkflowExecutionStartedEventAttributes(a)) => ("input", &a.input),
+        Some(Attributes::WorkflowExecutionCompletedEventAttributes(a)) => ("result", &a.result),
+        Some(Attributes::ActivityTaskScheduledEventAttributes(a)) => ("input", &a.input),
+        Some(Attributes::ActivityTaskCompletedEventAttributes(a)) => ("result", &a.result),
+        Some(Attributes::WorkflowExecutionSignaledEventAttributes(a)) => ("input", &a.input),
+        Some(Attributes::ChildWorkflowExecutionCompletedEventAttributes(a)) => {
+            ("result", &a.result)
+        }
+        Some(Attributes::StartChildWorkflowExecutionInitiatedEventAttributes(a)) => {
+            ("input", &a.input)
+        }
+        _ => return vec![],
+    };
+    match payloads_value(payloads) {
+        Payload::Binary(bytes) => vec![(key.to_string(), bytes)],
+        Payload::Json(_) => vec![],
+    }
+}
+
+pub(super) fn user_metadata_payloads_mut(
+    metadata: &mut Option<proto::temporal::api::sdk::v1::UserMetadata>,
+) -> Vec<&mut proto::temporal::api::common::v1::Payload> {
+    metadata
+        .iter_mut()
+        .flat_map(|m| m.summary.iter_mut().chain(m.details.iter_mut()))
+        .collect()
+}
+
+/// Decoded summary and details; non-string payloads show as JSON.
+pub(super) fn user_metadata(
+    metadata: &Option<proto::temporal::api::sdk::v1::UserMetadata>,
+) -> UserMetadata {
+    let text = |payload: &Option<proto::temporal::api::common::v1::Payload>| match payload
+        .as_ref()
+        .map(decode_payload)?
+    {
+        serde_json::Value::Null => None,
+        serde_json::Value::String(s) => Some(s),
+        other => Some(other.to_string()),
+    };
+    match metadata {
+        Some(m) => UserMetadata {
+            summary: text(&m.summary),
+            details: text(&m.details),
+        },
+        None => UserMetadata::default(),
+    }
+}
+
+pub(super) fn decode_payloads(
     payloads: &Option<proto::temporal::api::common::v1::Payloads>,
 ) -> serde_json::Value {
+    payloads_value(payloads).to_json()
+}
+
+/// `payloads` decoded: one payload as itself, several as a JSON array.
+pub(super) fn payloads_value(
+    payloads: &Option<proto::temporal::api::common::v1::Payloads>,
+) -> Payload {
     let Some(payloads) = payloads else {
-        return serde_json::Value::Null;
+        return Payload::Json(serde_json::Value::Null);
     };
-    let values: Vec<serde_json::Value> = payloads.payloads.iter().map(decode_payload).collect();
-    if values.len() == 1 {
-        values.into_iter().next().unwrap()
-    } else {
-        serde_json::Value::Array(values)
+    match &payloads.payloads[..] {
+        [payload] => payload_value(payload),
+        payloads => Payload::Json(serde_json::Value::Array(
+            payloads.iter().map(decode_payload).collect(),
+        )),
+    }
+}
+
+pub(super) fn json_payloads(
+    value: &serde_json::Value,
+) -> proto::temporal::api::common::v1::Payloads {
+    proto::temporal::api::common::v1::Payloads {
+        payloads: vec![proto::temporal::api::common::v1::Payload {
+            metadata: std::collections::HashMap::from([(
+                "encoding".to_string(),
+                b"json/plain".to_vec(),
+            )]),
+            data: value.to_string().into_bytes(),
+            external_payloads: vec![],
+        }],
     }
 }
 
 fn decode_payload(payload: &proto::temporal::api::common::v1::Payload) -> serde_json::Value {
+    payload_value(payload).to_json()
+}
+
+fn payload_value(payload: &proto::temporal::api::common::v1::Payload) -> Payload {
     let encoding = payload
         .metadata
         .get("encoding")
@@ -1038,42 +3184,165 @@ fn decode_payload(payload: &proto::temporal::api::common::v1::Payload) -> serde_
         .unwrap_or_default();
 
     match encoding.as_str() {
-        "json/plain" => serde_json::from_slice(&payload.data).unwrap_or_else(|_| {
+        "json/plain" => Payload::Json(serde_json::from_slice(&payload.data).unwrap_or_else(|_| {
             serde_json::Value::String(String::from_utf8_lossy(&payload.data).to_string())
-        }),
-        "binary/null" => serde_json::Value::Null,
+        })),
+        "binary/null" => Payload::Json(serde_json::Value::Null),
         _ => {
             if let Ok(s) = std::str::from_utf8(&payload.data) {
                 // Try parsing as JSON first
-                serde_json::from_str(s).unwrap_or_else(|_| serde_json::Value::String(s.to_string()))
+                Payload::Json(
+                    serde_json::from_str(s)
+                        .unwrap_or_else(|_| serde_json::Value::String(s.to_string())),
+                )
             } else {
-                serde_json::Value::String(format!("<binary {} bytes>", payload.data.len()))
+                Payload::Binary(payload.data.clone())
             }
         }
     }
 }
 
-fn decode_failure(
+pub(super) fn failure_info(f: &proto::temporal::api::failure::v1::Failure) -> FailureInfo {
+    FailureInfo::from_json(&decode_failure(&Some(f.clone())))
+}
+
+pub(super) fn execution_config(
+    config: &proto::temporal::api::workflow::v1::WorkflowExecutionConfig,
+) -> ExecutionConfig {
+    let timeout = |d: &Option<pbjson_types::Duration>| {
+        d.as_ref().map(duration_to_std).filter(|d| !d.is_zero())
+    };
+    ExecutionConfig {
+        task_queue: config
+            .task_queue
+            .as_ref()
+            .map(|q| q.name.clone())
+            .unwrap_or_default(),
+        execution_timeout: timeout(&config.workflow_execution_timeout),
+        run_timeout: timeout(&config.workflow_run_timeout),
+        task_timeout: timeout(&config.default_workflow_task_timeout),
+    }
+}
+
+/// Payloads in a failure and its causes, for the codec.
+pub(super) fn failure_payloads_mut(
+    failure: &mut Option<proto::temporal::api::failure::v1::Failure>,
+) -> Vec<&mut proto::temporal::api::common::v1::Payload> {
+    use proto::temporal::api::failure::v1::failure::FailureInfo as Info;
+    let mut out = Vec::new();
+    let mut next = failure.as_mut();
+    while let Some(f) = next {
+        out.extend(f.encoded_attributes.as_mut());
+        out.extend(match f.failure_info.as_mut() {
+            Some(Info::ApplicationFailureInfo(info)) => payloads_mut(&mut info.details),
+            Some(Info::CanceledFailureInfo(info)) => payloads_mut(&mut info.details),
+            Some(Info::TimeoutFailureInfo(info)) => payloads_mut(&mut info.last_heartbeat_details),
+            Some(Info::ResetWorkflowFailureInfo(info)) => {
+                payloads_mut(&mut info.last_heartbeat_details)
+            }
+            _ => vec![],
+        });
+        next = f.cause.as_deref_mut();
+    }
+    out
+}
+
+pub(super) fn decode_failure(
     failure: &Option<proto::temporal::api::failure::v1::Failure>,
 ) -> serde_json::Value {
+    use proto::temporal::api::failure::v1::failure::FailureInfo as Info;
+
     let Some(f) = failure else {
         return serde_json::Value::Null;
     };
+    // SDKs with failure encoding on move the message and stack trace here.
+    let encoded = f.encoded_attributes.as_ref().map(decode_payload);
+    let encoded_text = |key: &str| {
+        encoded
+            .as_ref()
+            .and_then(|e| e.get(key))
+            .and_then(|v| v.as_str())
+            .filter(|s| !s.is_empty())
+            .map(str::to_string)
+    };
+    let message = encoded_text("message").unwrap_or_else(|| f.message.clone());
+    let stack_trace = encoded_text("stack_trace").unwrap_or_else(|| f.stack_trace.clone());
+
     let mut map = serde_json::Map::new();
-    if !f.message.is_empty() {
-        map.insert(
-            "message".into(),
-            serde_json::Value::String(f.message.clone()),
-        );
+    if !message.is_empty() {
+        map.insert("message".into(), serde_json::Value::String(message));
     }
     if !f.source.is_empty() {
         map.insert("source".into(), serde_json::Value::String(f.source.clone()));
     }
-    if !f.stack_trace.is_empty() {
-        map.insert(
-            "stack_trace".into(),
-            serde_json::Value::String(f.stack_trace.clone()),
-        );
+    let payloads = |p: &Option<proto::temporal::api::common::v1::Payloads>| {
+        Some(decode_payloads(p)).filter(|v| !v.is_null())
+    };
+    let (kind, info) = match &f.failure_info {
+        Some(Info::ApplicationFailureInfo(info)) => {
+            if !info.r#type.is_empty() {
+                map.insert("type".into(), info.r#type.clone().into());
+            }
+            if info.non_retryable {
+                map.insert("non_retryable".into(), true.into());
+            }
+            (
+                "application",
+                payloads(&info.details).map(|d| ("details", d)),
+            )
+        }
+        Some(Info::TimeoutFailureInfo(info)) => {
+            let timeout_type =
+                proto::temporal::api::enums::v1::TimeoutType::try_from(info.timeout_type)
+                    .map(|t| {
+                        t.as_str_name()
+                            .trim_start_matches("TIMEOUT_TYPE_")
+                            .to_string()
+                    })
+                    .unwrap_or_default();
+            map.insert("timeout_type".into(), timeout_type.into());
+            let details = payloads(&info.last_heartbeat_details);
+            ("timeout", details.map(|d| ("last_heartbeat_details", d)))
+        }
+        Some(Info::CanceledFailureInfo(info)) => {
+            ("canceled", payloads(&info.details).map(|d| ("details", d)))
+        }
+        Some(Info::TerminatedFailureInfo(_)) => ("terminated", None),
+        Some(Info::ServerFailureInfo(_)) => ("server", None),
+        Some(Info::ActivityFailureInfo(info)) => {
+            if let Some(ref t) = info.activity_type {
+                map.insert("activity_type".into(), t.name.clone().into());
+            }
+            map.insert("activity_id".into(), info.activity_id.clone().into());
+            map.insert(
+                "retry_state".into(),
+                retry_state_string(info.retry_state).into(),
+            );
+            ("activity", None)
+        }
+        Some(Info::ChildWorkflowExecutionFailureInfo(info)) => {
+            if let Some(ref t) = info.workflow_type {
+                map.insert("workflow_type".into(), t.name.clone().into());
+            }
+            if let Some(ref exec) = info.workflow_execution {
+                map.insert("workflow_id".into(), exec.workflow_id.clone().into());
+            }
+            map.insert(
+                "retry_state".into(),
+                retry_state_string(info.retry_state).into(),
+            );
+            ("child_workflow", None)
+        }
+        _ => ("", None),
+    };
+    if !kind.is_empty() {
+        map.insert("kind".into(), kind.into());
+    }
+    if let Some((key, value)) = info {
+        map.insert(key.into(), value);
+    }
+    if !stack_trace.is_empty() {
+        map.insert("stack_trace".into(), serde_json::Value::String(stack_trace));
     }
     if let Some(ref cause) = f.cause {
         map.insert("cause".into(), decode_failure(&Some(*cause.clone())));
@@ -1109,6 +3378,25 @@ fn extract_event_details(
             if !input.is_null() {
                 map.insert("input".into(), input);
             }
+            if let Some(ref policy) = a.retry_policy {
+                let duration = |d: &Option<pbjson_types::Duration>| {
+                    d.as_ref()
+                        .map(|d| format!("{}s", duration_to_std(d).as_secs_f64()))
+                };
+                map.insert(
+                    "retry_policy".into(),
+                    serde_json::json!({
+                        "initial_interval": duration(&policy.initial_interval),
+                        "backoff_coefficient": policy.backoff_coefficient,
+                        "maximum_interval": duration(&policy.maximum_interval),
+                        "maximum_attempts": policy.maximum_attempts,
+                        "non_retryable_error_types": policy.non_retryable_error_types,
+                    }),
+                );
+            }
+            if !a.cron_schedule.is_empty() {
+                map.insert("cron_schedule".into(), a.cron_schedule.clone().into());
+            }
             serde_json::Value::Object(map)
         }
         Attributes::WorkflowExecutionCompletedEventAttributes(a) => {
@@ -1129,6 +3417,10 @@ fn extract_event_details(
         }
         Attributes::ActivityTaskScheduledEventAttributes(a) => {
             let mut map = serde_json::Map::new();
+            map.insert(
+                "activity_id".into(),
+                serde_json::Value::String(a.activity_id.clone()),
+            );
             if let Some(ref at) = a.activity_type {
                 map.insert(
                     "activity_type".into(),
@@ -1147,8 +3439,15 @@ fn extract_event_details(
             }
             serde_json::Value::Object(map)
         }
+        Attributes::ActivityTaskStartedEventAttributes(a) => {
+            serde_json::json!({
+                "scheduled_event_id": a.scheduled_event_id,
+                "attempt": a.attempt,
+            })
+        }
         Attributes::ActivityTaskCompletedEventAttributes(a) => {
             let mut map = serde_json::Map::new();
+            map.insert("scheduled_event_id".into(), a.scheduled_event_id.into());
             let result = decode_payloads(&a.result);
             if !result.is_null() {
                 map.insert("result".into(), result);
@@ -1157,12 +3456,28 @@ fn extract_event_details(
         }
         Attributes::ActivityTaskFailedEventAttributes(a) => {
             let mut map = serde_json::Map::new();
+            map.insert("scheduled_event_id".into(), a.scheduled_event_id.into());
+            let failure = decode_failure(&a.failure);
+            if !failure.is_null() {
+                map.insert("failure".into(), failure);
+            }
+            serde_json::Value::Object(map)
+        }
+        Attributes::ActivityTaskTimedOutEventAttributes(a) => {
+            let mut map = serde_json::Map::new();
+            map.insert("scheduled_event_id".into(), a.scheduled_event_id.into());
             let failure = decode_failure(&a.failure);
             if !failure.is_null() {
                 map.insert("failure".into(), failure);
             }
             serde_json::Value::Object(map)
         }
+        Attributes::ActivityTaskCanceledEventAttributes(a) => {
+            serde_json::json!({ "scheduled_event_id": a.scheduled_event_id })
+        }
+        Attributes::TimerCanceledEventAttributes(a) => {
+            serde_json::json!({ "timer_id": a.timer_id })
+        }
         Attributes::TimerStartedEventAttributes(a) => {
             let mut map = serde_json::Map::new();
             map.insert(
@@ -1209,6 +3524,7 @@ fn extract_event_details(
         }
         Attributes::ChildWorkflowExecutionStartedEventAttributes(a) => {
             let mut map = serde_json::Map::new();
+            map.insert("initiated_event_id".into(), a.initiated_event_id.into());
             if let Some(ref wt) = a.workflow_type {
                 map.insert(
                     "workflow_type".into(),
@@ -1220,11 +3536,16 @@ fn extract_event_details(
                     "workflow_id".into(),
                     serde_json::Value::String(exec.workflow_id.clone()),
                 );
+                map.insert(
+                    "run_id".into(),
+                    serde_json::Value::String(exec.run_id.clone()),
+                );
             }
             serde_json::Value::Object(map)
         }
         Attributes::ChildWorkflowExecutionCompletedEventAttributes(a) => {
             let mut map = serde_json::Map::new();
+            map.insert("initiated_event_id".into(), a.initiated_event_id.into());
             let result = decode_payloads(&a.result);
             if !result.is_null() {
                 map.insert("result".into(), result);
@@ -1233,12 +3554,25 @@ fn extract_event_details(
         }
         Attributes::ChildWorkflowExecutionFailedEventAttributes(a) => {
             let mut map = serde_json::Map::new();
+            map.insert("initiated_event_id".into(), a.initiated_event_id.into());
             let failure = decode_failure(&a.failure);
             if !failure.is_null() {
                 map.insert("failure".into(), failure);
             }
             serde_json::Value::Object(map)
         }
+        Attributes::ChildWorkflowExecutionCanceledEventAttributes(a) => {
+            serde_json::json!({ "initiated_event_id": a.initiated_event_id })
+        }
+        Attributes::ChildWorkflowExecutionTimedOutEventAttributes(a) => {
+            serde_json::json!({ "initiated_event_id": a.initiated_event_id })
+        }
+        Attributes::ChildWorkflowExecutionTerminatedEventAttributes(a) => {
+            serde_json::json!({ "initiated_event_id": a.initiated_event_id })
+        }
+        Attributes::StartChildWorkflowExecutionFailedEventAttributes(a) => {
+            serde_json::json!({ "initiated_event_id": a.initiated_event_id })
+        }
         Attributes::StartChildWorkflowExecutionInitiatedEventAttributes(a) => {
             let mut map = serde_json::Map::new();
             if let Some(ref wt) = a.workflow_type {
@@ -1260,3 +3594,139 @@ fn extract_event_details(
         _ => serde_json::json!({}),
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn raw_responses_use_the_proto_json_mapping() {
+        use proto::temporal::api::common::v1::{Payload, WorkflowExecution};
+        use proto::temporal::api::workflow::v1::WorkflowExecutionInfo;
+
+        let info = WorkflowExecutionInfo {
+            execution: Some(WorkflowExecution {
+                workflow_id: "order-1".to_string(),
+                run_id: String::new(),
+            }),
+            start_time: Some(pbjson_types::Timestamp {
+                seconds: 1_700_000_000,
+                nanos: 0,
+            }),
+            execution_duration: Some(pbjson_types::Duration {
+                seconds: 2,
+                nanos: 500_000_000,
+            }),
+            status: 1,
+            history_length: 12,
+            ..Default::default()
+        };
+        let json = proto_json(&info).unwrap();
+        assert_eq!(json["execution"]["workflowId"], "order-1");
+        assert_eq!(json["startTime"], "2023-11-14T22:13:20+00:00");
+        assert_eq!(json["executionDuration"], "2.500s");
+        assert_eq!(json["status"], "WORKFLOW_EXECUTION_STATUS_RUNNING");
+        assert_eq!(json["historyLength"], "12");
+        assert!(json.get("closeTime").is_none());
+
+        let payload = Payload {
+            metadata: [("encoding".to_string(), b"json/plain".to_vec())].into(),
+            data: b"{\"a\":1}".to_vec(),
+            ..Default::default()
+        };
+        let json = proto_json(&payload).unwrap();
+        assert_eq!(json["metadata"]["encoding"], "anNvbi9wbGFpbg==");
+        assert_eq!(json["data"], "eyJhIjoxfQ==");
+    }
+
+    #[test]
+    fn backoff_stays_under_ceiling() {
+        let policy = RequestPolicy {
+            timeout: Duration::from_secs(1),
+            read_retries: 5,
+            base_delay: Duration::from_millis(200),
+        };
+        for _ in 0..50 {
+            assert!(policy.backoff(1) <= Duration::from_millis(200));
+            assert!(policy.backoff(2) <= Duration::from_millis(400));
+            assert!(policy.backoff(10) <= policy.timeout);
+        }
+    }
+
+    #[test]
+    fn failures_decode_encoded_attributes_and_details() {
+        use proto::temporal::api::common::v1::{Payload, Payloads};
+        use proto::temporal::api::failure::v1::{failure, ApplicationFailureInfo, Failure};
+
+        let json = |value: serde_json::Value| Payload {
+            metadata: [("encoding".to_string(), b"json/plain".to_vec())].into(),
+            data: value.to_string().into_bytes(),
+            ..Default::default()
+        };
+        let cause = Failure {
+            message: "Encoded failure".to_string(),
+            encoded_attributes: Some(json(serde_json::json!({
+                "message": "card declined",
+                "stack_trace": "at charge()",
+            }))),
+            failure_info: Some(failure::FailureInfo::ApplicationFailureInfo(
+                ApplicationFailureInfo {
+                    r#type: "PaymentError".to_string(),
+                    details: Some(Payloads {
+                        payloads: vec![json(serde_json::json!({"code": 51}))],
+                    }),
+                    ..Default::default()
+                },
+            )),
+            ..Default::default()
+        };
+        let top = Failure {
+            message: "activity error".to_string(),
+            source: "GoSDK".to_string(),
+            cause: Some(Box::new(cause)),
+            ..Default::default()
+        };
+
+        let info = failure_info(&top);
+        assert_eq!(info.failure_type, "GoSDK");
+        let cause = info.cause.unwrap();
+        assert_eq!(cause.message, "card declined");
+        assert_eq!(cause.stack_trace.as_deref(), Some("at charge()"));
+        assert_eq!(cause.failure_type, "PaymentError");
+        assert_eq!(
+            cause.details,
+            Some(serde_json::json!({"kind": "application", "details": {"code": 51}}))
+        );
+        assert_eq!(failure_payloads_mut(&mut Some(top)).len(), 2);
+    }
+
+    #[test]
+    fn cron_edits_keep_the_calendars_left_alone() {
+        use proto::temporal::api::schedule::v1::{CalendarSpec, Schedule, ScheduleSpec};
+
+        let calendar = CalendarSpec {
+            minute: "30".to_string(),
+            hour: "9".to_string(),
+            day_of_week: "1-5".to_string(),
+            comment: "weekday standup".to_string(),
+            ..Default::default()
+        };
+        let mut schedule = Schedule {
+            spec: Some(ScheduleSpec {
+                cron_string: vec!["0 2 * * *".to_string()],
+                calendar: vec![calendar.clone()],
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+        let original = schedule_edit(&schedule);
+        assert_eq!(original.cron, ["0 2 * * *", "30 9 * * 1-5"]);
+
+        let mut edit = original.clone();
+        edit.cron[0] = "0 3 * * *".to_string();
+        apply_schedule_edit(&mut schedule, &edit, &original, schedule_edit_input(&edit));
+        let spec = schedule.spec.unwrap();
+        assert_eq!(spec.cron_string, ["0 3 * * *"]);
+        assert_eq!(spec.calendar, [calendar]);
+    }
+}
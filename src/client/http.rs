@@ -0,0 +1,2489 @@
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+use async_trait::async_trait;
+use base64::engine::general_purpose::STANDARD;
+use base64::Engine;
+use chrono::{DateTime, Utc};
+use reqwest::{Method, StatusCode, Url};
+use serde_json::{json, Value};
+
+use super::grpc::{
+    activity_list_info_to_summary, activity_options_edit, count_each_status, decode_failure,
+    decode_payloads, deployment_version_string, duration_to_std, event_type_name, failure_info,
+    failure_payloads_mut, failure_retry_state, grouped_by_status, indexed_value_type,
+    json_payloads, memo_fields, memo_payloads_mut, nexus_operation_state_string,
+    parent_close_policy_string, pause_note, payloads_value, pending_activity_state, poller,
+    schedule_action, schedule_conflict, schedule_edit, schedule_edit_input, schedule_input_mut,
+    schedule_spec_fields, search_attributes, split_cron_lines, status_counts,
+    task_queue_versioning, user_metadata, user_metadata_payloads_mut, workflow_info_to_summary,
+    workflow_versioning,
+};
+use super::visibility::{self, StandardNamespaces};
+use super::{
+    uses_tls, ClientError, ClientResult, CodecClient, ExecutionAdmin, NamespaceAdmin,
+    ScheduleAdmin, StartAttributes, TaskQueueReader, TemporalClient, VisibilityReader,
+};
+use crate::domain::*;
+use crate::proto;
+use crate::proto::temporal::api::common::v1::{Payload, Payloads};
+use crate::proto::temporal::api::enums::v1 as enums;
+
+/// Default port of the frontend's HTTP API, next to gRPC on 7233.
+const HTTP_PORT: &str = "7243";
+const GRPC_PORT: &str = "7233";
+
+/// Client for Temporal's HTTP API (`/api/v1/...`), for networks where gRPC
+/// egress is blocked. Responses are read into the same proto types the gRPC
+/// client uses where that is cheap, so both backends share their conversions.
+pub struct HttpTemporalClient {
+    http: reqwest::Client,
+    base_url: Url,
+    codec: Option<CodecClient>,
+    /// Set once a `GROUP BY` count is rejected, so later polls go straight
+    /// to one count per status.
+    ungrouped_counts: AtomicBool,
+    /// Namespaces where listing with a query turned out to need advanced
+    /// visibility this server doesn't have.
+    standard_visibility: StandardNamespaces,
+}
+
+impl HttpTemporalClient {
+    /// Builds the client and probes `/api/v1/system-info`, so a server without
+    /// the HTTP API fails here rather than on the first list call.
+    pub async fn connect(
+        address: &str,
+        namespace: String,
+        api_key: Option<String>,
+        tls_cert: Option<String>,
+        tls_key: Option<String>,
+    ) -> ClientResult<Self> {
+        let base = http_base_url(address, api_key.is_some());
+        tracing::info!("Connecting to Temporal HTTP API at {}", base);
+        let base_url = Url::parse(&base)
+            .map_err(|e| ClientError::ConfigError(format!("invalid HTTP address: {}", e)))?;
+
+        let mut headers = reqwest::header::HeaderMap::new();
+        if let Some(ref key) = api_key {
+            let value = format!("Bearer {}", key).parse().map_err(|_| {
+                ClientError::ConfigError("API key is not a valid header value".into())
+            })?;
+            headers.insert(reqwest::header::AUTHORIZATION, value);
+        }
+        if let Ok(value) = namespace.parse() {
+            headers.insert("temporal-namespace", value);
+        }
+
+        let mut builder = reqwest::Client::builder().default_headers(headers);
+        if let (Some(cert_path), Some(key_path)) = (tls_cert, tls_key) {
+            let mut pem = std::fs::read(&cert_path).map_err(|e| {
+                ClientError::ConfigError(format!("failed to read TLS cert {}: {}", cert_path, e))
+            })?;
+            let key = std::fs::read(&key_path).map_err(|e| {
+                ClientError::ConfigError(format!("failed to read TLS key {}: {}", key_path, e))
+            })?;
+            pem.push(b'\n');
+            pem.extend(key);
+            let identity = reqwest::Identity::from_pem(&pem)
+                .map_err(|e| ClientError::ConfigError(format!("invalid TLS identity: {}", e)))?;
+            builder = builder.identity(identity);
+        }
+        let http = builder
+            .build()
+            .map_err(|e| ClientError::ConfigError(format!("HTTP client: {}", e)))?;
+
+        let client = Self {
+            http,
+            base_url,
+            codec: None,
+            ungrouped_counts: AtomicBool::new(false),
+            standard_visibility: Default::default(),
+        };
+        match client.get(&["system-info"], &[]).await {
+            Ok(_) => {}
+            Err(ClientError::NotFound(_)) => {
+                return Err(ClientError::ConnectionError(format!(
+                    "{} does not serve the Temporal HTTP API",
+                    base
+                )))
+            }
+            Err(e) => return Err(e),
+        }
+        tracing::info!("Connected to Temporal HTTP API successfully");
+
+        Ok(client)
+    }
+
+    pub fn with_codec(mut self, codec: CodecClient) -> Self {
+        self.codec = Some(codec);
+        self
+    }
+
+    /// Encodes payloads headed for the server; a codec failure is an error
+    /// rather than a reason to send plaintext.
+    async fn codec_encode(&self, namespace: &str, payloads: Vec<&mut Payload>) -> ClientResult<()> {
+        match self.codec {
+            Some(ref codec) => codec.encode(namespace, payloads).await,
+            None => Ok(()),
+        }
+    }
+
+    async fn codec_decode(&self, namespace: &str, payloads: Vec<&mut Payload>) {
+        let Some(ref codec) = self.codec else {
+            return;
+        };
+        if let Err(e) = codec.decode(namespace, payloads).await {
+            tracing::warn!("codec decode failed: {}", e);
+        }
+    }
+
+    /// Task queue state for one task type.
+    async fn describe_task_queue_type(
+        &self,
+        namespace: &str,
+        task_queue: &str,
+        task_queue_type: &str,
+    ) -> ClientResult<Value> {
+        self.send(
+            Method::GET,
+            self.ns_url(namespace, &["task-queues", task_queue]),
+            &[
+                ("taskQueueType", task_queue_type.to_string()),
+                ("reportPollers", "true".to_string()),
+            ],
+            None,
+        )
+        .await
+    }
+
+    /// The raw `schedule` object of a describe, with its conflict token.
+    async fn schedule_json(
+        &self,
+        namespace: &str,
+        schedule_id: &str,
+    ) -> ClientResult<(Value, Vec<u8>)> {
+        let mut resp = self
+            .send(
+                Method::GET,
+                self.ns_url(namespace, &["schedules", schedule_id]),
+                &[],
+                None,
+            )
+            .await?;
+        let token = STANDARD
+            .decode(string(&resp, "conflictToken"))
+            .unwrap_or_default();
+        Ok((resp["schedule"].take(), token))
+    }
+
+    async fn decoded_schedule_edit(&self, namespace: &str, schedule: &Value) -> ScheduleEdit {
+        let mut schedule = schedule_proto(schedule);
+        self.codec_decode(namespace, schedule_input_mut(&mut schedule))
+            .await;
+        schedule_edit(&schedule)
+    }
+
+    fn url(&self, segments: &[&str]) -> Url {
+        let mut url = self.base_url.clone();
+        if let Ok(mut path) = url.path_segments_mut() {
+            path.pop_if_empty().extend(["api", "v1"]).extend(segments);
+        }
+        url
+    }
+
+    fn ns_url(&self, namespace: &str, segments: &[&str]) -> Url {
+        let mut all = vec!["namespaces", namespace];
+        all.extend_from_slice(segments);
+        self.url(&all)
+    }
+
+    async fn get(&self, segments: &[&str], query: &[(&str, String)]) -> ClientResult<Value> {
+        self.send(Method::GET, self.url(segments), query, None)
+            .await
+    }
+
+    async fn list_workflow_page(
+        &self,
+        namespace: &str,
+        query: Option<&str>,
+        page_size: i32,
+        next_page_token: &[u8],
+    ) -> ClientResult<(Vec<WorkflowSummary>, Vec<u8>)> {
+        let resp = self
+            .send(
+                Method::GET,
+                self.ns_url(namespace, &["workflows"]),
+                &page_query(query, page_size, next_page_token),
+                None,
+            )
+            .await?;
+
+        let workflows = array(&resp, "executions")
+            .iter()
+            .map(|info| workflow_info_to_summary(workflow_info(info)))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok((workflows, page_token(&resp)))
+    }
+
+    /// Lists without advanced visibility. The HTTP API has no routes for the
+    /// open/closed list calls, so the unqueried list is filtered here.
+    async fn list_workflows_standard(
+        &self,
+        namespace: &str,
+        query: Option<&str>,
+        page_size: i32,
+        next_page_token: Vec<u8>,
+    ) -> ClientResult<(Vec<WorkflowSummary>, Vec<u8>)> {
+        let filter = visibility::StandardQuery::parse(query.unwrap_or(""))
+            .map_err(ClientError::InvalidArgument)?;
+        let (workflows, next) = self
+            .list_workflow_page(namespace, None, page_size, &next_page_token)
+            .await?;
+        let workflows = workflows
+            .into_iter()
+            .filter(|wf| filter.matches(wf))
+            .collect();
+        Ok((workflows, next))
+    }
+
+    async fn send(
+        &self,
+        method: Method,
+        url: Url,
+        query: &[(&str, String)],
+        body: Option<Value>,
+    ) -> ClientResult<Value> {
+        let mut request = self.http.request(method, url).query(query);
+        if let Some(body) = body {
+            request = request.json(&body);
+        }
+        let response = request.send().await.map_err(|e| {
+            if e.is_timeout() {
+                ClientError::Timeout
+            } else {
+                // The server may come back upgraded.
+                self.standard_visibility.clear();
+                ClientError::ConnectionError(e.to_string())
+            }
+        })?;
+
+        let status = response.status();
+        let text = response
+            .text()
+            .await
+            .map_err(|e| ClientError::ConnectionError(e.to_string()))?;
+        if !status.is_success() {
+            return Err(http_error(status, &text));
+        }
+        if text.trim().is_empty() {
+            return Ok(Value::Null);
+        }
+        serde_json::from_str(&text).map_err(|e| ClientError::ParseError(e.to_string()))
+    }
+}
+
+#[async_trait]
+impl VisibilityReader for HttpTemporalClient {
+    async fn list_namespaces(&self) -> ClientResult<Vec<Namespace>> {
+        let mut descriptions = Vec::new();
+        let mut next_page_token = vec![];
+        loop {
+            let mut query = vec![("pageSize", "100".to_string())];
+            if !next_page_token.is_empty() {
+                query.push(("nextPageToken", STANDARD.encode(&next_page_token)));
+            }
+            let resp = self.get(&["namespaces"], &query).await?;
+            descriptions.extend(array(&resp, "namespaces").iter().cloned());
+
+            next_page_token = page_token(&resp);
+            if next_page_token.is_empty() {
+                break;
+            }
+        }
+
+        Ok(descriptions
+            .iter()
+            .filter_map(|desc| {
+                let info = desc.get("namespaceInfo")?;
+                Some(Namespace {
+                    name: string(info, "name"),
+                    state: string(info, "state")
+                        .trim_start_matches("NAMESPACE_STATE_")
+                        .to_string(),
+                    description: string(info, "description"),
+                    owner_email: string(info, "ownerEmail"),
+                    retention: desc
+                        .get("config")
+                        .and_then(|c| duration(c, "workflowExecutionRetentionTtl"))
+                        .map(|d| duration_to_std(&d)),
+                })
+            })
+            .collect())
+    }
+
+    async fn get_cluster_info(&self) -> ClientResult<ClusterInfo> {
+        let resp = self.get(&["cluster-info"], &[]).await?;
+        Ok(ClusterInfo {
+            server_version: string(&resp, "serverVersion"),
+            cluster_name: string(&resp, "clusterName"),
+            is_cloud: self
+                .base_url
+                .host_str()
+                .is_some_and(|host| host.ends_with(".tmprl.cloud")),
+        })
+    }
+
+    async fn list_workflows(
+        &self,
+        namespace: &str,
+        query: Option<&str>,
+        page_size: i32,
+        next_page_token: Vec<u8>,
+    ) -> ClientResult<(Vec<WorkflowSummary>, Vec<u8>)> {
+        if self.standard_visibility.contains(namespace) {
+            return self
+                .list_workflows_standard(namespace, query, page_size, next_page_token)
+                .await;
+        }
+        match self
+            .list_workflow_page(namespace, query, page_size, &next_page_token)
+            .await
+        {
+            Ok(page) => Ok(page),
+            Err(e) => {
+                // The fallback lists without a query, so it only helps where
+                // that works. A server with advanced visibility never rejects
+                // a query the standard filters can serve.
+                let standard = match &e {
+                    ClientError::Unimplemented(_) => true,
+                    e if visibility::needs_probe(e, query) => {
+                        visibility::StandardQuery::parse(query.unwrap_or("")).is_ok()
+                            && self
+                                .list_workflow_page(namespace, None, 1, &[])
+                                .await
+                                .is_ok()
+                    }
+                    _ => false,
+                };
+                if !standard {
+                    return Err(e);
+                }
+                tracing::warn!("{}; filtering the unqueried list instead", e);
+                self.standard_visibility.insert(namespace);
+                self.list_workflows_standard(namespace, query, page_size, vec![])
+                    .await
+            }
+        }
+    }
+
+    async fn list_archived_workflows(
+        &self,
+        namespace: &str,
+        query: Option<&str>,
+        page_size: i32,
+        next_page_token: Vec<u8>,
+    ) -> ClientResult<(Vec<WorkflowSummary>, Vec<u8>)> {
+        let resp = self
+            .send(
+                Method::GET,
+                self.ns_url(namespace, &["archived-workflows"]),
+                &page_query(query, page_size, &next_page_token),
+                None,
+            )
+            .await?;
+
+        let workflows = array(&resp, "executions")
+            .iter()
+            .map(|info| workflow_info_to_summary(workflow_info(info)))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok((workflows, page_token(&resp)))
+    }
+
+    async fn describe_workflow(
+        &self,
+        namespace: &str,
+        workflow_id: &str,
+        run_id: Option<&str>,
+    ) -> ClientResult<WorkflowDetail> {
+        let resp = self
+            .send(
+                Method::GET,
+                self.ns_url(namespace, &["workflows", workflow_id]),
+                &run_query("execution.runId", run_id),
+                None,
+            )
+            .await?;
+
+        let mut info = resp
+            .get("workflowExecutionInfo")
+            .map(workflow_info)
+            .ok_or_else(|| ClientError::ParseError("missing workflow execution info".into()))?;
+        self.codec_decode(namespace, memo_payloads_mut(&mut info))
+            .await;
+
+        let history_length = info.history_length as u64;
+        let to_ref = |exec: proto::temporal::api::common::v1::WorkflowExecution| WorkflowRef {
+            workflow_id: exec.workflow_id,
+            run_id: exec.run_id,
+        };
+        let parent = info.parent_execution.clone().map(to_ref);
+        let root = info.root_execution.clone().map(to_ref);
+        let versioning = workflow_versioning(&info);
+        let memo = memo_fields(&info);
+        let summary = workflow_info_to_summary(info)?;
+
+        let mut heartbeats: Vec<Option<Payloads>> = array(&resp, "pendingActivities")
+            .iter()
+            .map(|pa| pa.get("heartbeatDetails").and_then(payloads))
+            .collect();
+        self.codec_decode(
+            namespace,
+            heartbeats
+                .iter_mut()
+                .flatten()
+                .flat_map(|p| p.payloads.iter_mut())
+                .collect(),
+        )
+        .await;
+        let mut metadata = resp
+            .get("executionConfig")
+            .and_then(|c| user_metadata_proto(c.get("userMetadata")?));
+        self.codec_decode(namespace, user_metadata_payloads_mut(&mut metadata))
+            .await;
+
+        let pending_activities = array(&resp, "pendingActivities")
+            .iter()
+            .zip(heartbeats)
+            .map(|(pa, heartbeat)| PendingActivity {
+                activity_id: string(pa, "activityId"),
+                activity_type: name(pa, "activityType"),
+                state: pending_activity_state(enum_value(
+                    pa,
+                    "state",
+                    enums::PendingActivityState::from_str_name,
+                )),
+                attempt: int(pa, "attempt") as i32,
+                scheduled_time: time(pa, "scheduledTime"),
+                last_started_time: time(pa, "lastStartedTime"),
+                last_heartbeat_time: time(pa, "lastHeartbeatTime"),
+                maximum_attempts: int(pa, "maximumAttempts") as i32,
+                next_attempt_time: time(pa, "nextAttemptScheduleTime"),
+                heartbeat_details: heartbeat.is_some().then(|| decode_payloads(&heartbeat)),
+                last_failure: pa.get("lastFailure").map(|f| failure_info(&failure(f))),
+                last_worker_identity: string(pa, "lastWorkerIdentity"),
+                options: pa
+                    .get("activityOptions")
+                    .map(|o| activity_options_edit(&activity_options(o))),
+            })
+            .collect();
+
+        let pending_children = array(&resp, "pendingChildren")
+            .iter()
+            .map(|pc| PendingChild {
+                workflow_id: string(pc, "workflowId"),
+                run_id: string(pc, "runId"),
+                workflow_type: string(pc, "workflowTypeName"),
+                initiated_id: int(pc, "initiatedId"),
+                parent_close_policy: parent_close_policy_string(enum_value(
+                    pc,
+                    "parentClosePolicy",
+                    enums::ParentClosePolicy::from_str_name,
+                )),
+            })
+            .collect();
+
+        let pending_nexus_operations = array(&resp, "pendingNexusOperations")
+            .iter()
+            .map(|op| PendingNexusOperation {
+                endpoint: string(op, "endpoint"),
+                service: string(op, "service"),
+                operation: string(op, "operation"),
+                state: nexus_operation_state_string(enum_value(
+                    op,
+                    "state",
+                    enums::PendingNexusOperationState::from_str_name,
+                )),
+                attempt: int(op, "attempt") as i32,
+                scheduled_time: time(op, "scheduledTime"),
+                last_failure_message: op.get("lastAttemptFailure").map(|f| string(f, "message")),
+            })
+            .collect();
+
+        Ok(WorkflowDetail {
+            search_attributes: summary.search_attributes.clone(),
+            summary,
+            input: None,
+            output: None,
+            failure: None,
+            history_length,
+            parent,
+            root,
+            memo,
+            pending_activities,
+            pending_children,
+            pending_nexus_operations,
+            versioning,
+            user_metadata: user_metadata(&metadata),
+            execution_config: execution_config(&resp),
+            retry_policy: None,
+            cron_schedule: None,
+        })
+    }
+
+    async fn get_history(
+        &self,
+        namespace: &str,
+        workflow_id: &str,
+        run_id: Option<&str>,
+    ) -> ClientResult<Vec<HistoryEvent>> {
+        let mut raw_events = Vec::new();
+        let mut next_page_token = vec![];
+
+        loop {
+            let mut query = run_query("execution.runId", run_id);
+            query.push(("maximumPageSize", "200".to_string()));
+            if !next_page_token.is_empty() {
+                query.push(("nextPageToken", STANDARD.encode(&next_page_token)));
+            }
+            let resp = self
+                .send(
+                    Method::GET,
+                    self.ns_url(namespace, &["workflows", workflow_id, "history"]),
+                    &query,
+                    None,
+                )
+                .await?;
+            if let Some(history) = resp.get("history") {
+                raw_events.extend(array(history, "events").iter().cloned());
+            }
+
+            next_page_token = page_token(&resp);
+            if next_page_token.is_empty() {
+                break;
+            }
+        }
+
+        let mut events: Vec<EventPayloads> = raw_events
+            .into_iter()
+            .map(|event| {
+                let attrs = event_attributes(&event);
+                let fields = attrs
+                    .map(|attrs| {
+                        PAYLOAD_FIELDS
+                            .iter()
+                            .filter_map(|field| {
+                                let p = payloads(attrs.get(*field)?)?;
+                                Some((field.to_string(), p))
+                            })
+                            .collect()
+                    })
+                    .unwrap_or_default();
+                let failure = attrs.and_then(|a| a.get("failure")).map(failure);
+                let metadata = event.get("userMetadata").and_then(user_metadata_proto);
+                EventPayloads {
+                    event,
+                    fields,
+                    failure,
+                    metadata,
+                }
+            })
+            .collect();
+
+        self.codec_decode(
+            namespace,
+            events
+                .iter_mut()
+                .flat_map(|e| {
+                    e.fields
+                        .iter_mut()
+                        .flat_map(|(_, p)| p.payloads.iter_mut())
+                        .chain(failure_payloads_mut(&mut e.failure))
+                        .chain(user_metadata_payloads_mut(&mut e.metadata))
+                })
+                .collect(),
+        )
+        .await;
+
+        Ok(events
+            .into_iter()
+            .map(|e| {
+                let (details, binary) = match event_attributes(&e.event) {
+                    Some(attrs) => {
+                        let (mut details, binary) = event_details(attrs, e.fields);
+                        if e.failure.is_some() {
+                            details["failure"] = decode_failure(&e.failure);
+                        }
+                        (details, binary)
+                    }
+                    None => (json!({}), vec![]),
+                };
+                HistoryEvent {
+                    event_id: int(&e.event, "eventId"),
+                    event_type: event_type_name(enum_value(
+                        &e.event,
+                        "eventType",
+                        enums::EventType::from_str_name,
+                    )),
+                    timestamp: time(&e.event, "eventTime").unwrap_or_else(Utc::now),
+                    details,
+                    binary,
+                    user_metadata: user_metadata(&e.metadata),
+                }
+            })
+            .collect())
+    }
+
+    async fn query_workflow(
+        &self,
+        namespace: &str,
+        workflow_id: &str,
+        run_id: Option<&str>,
+        query_type: &str,
+    ) -> ClientResult<Value> {
+        let body = json!({
+            "execution": execution_json(workflow_id, run_id),
+            "query": { "queryType": query_type },
+        });
+        let resp = self
+            .send(
+                Method::POST,
+                self.ns_url(namespace, &["workflows", workflow_id, "query", query_type]),
+                &[],
+                Some(body),
+            )
+            .await?;
+
+        if let Some(rejected) = resp.get("queryRejected") {
+            return Err(ClientError::RequestFailed(format!(
+                "query rejected (workflow status {})",
+                string(rejected, "status")
+            )));
+        }
+        let mut result = resp.get("queryResult").and_then(payloads);
+        if let Some(ref mut p) = result {
+            self.codec_decode(namespace, p.payloads.iter_mut().collect())
+                .await;
+        }
+        Ok(decode_payloads(&result))
+    }
+
+    async fn count_workflows(&self, namespace: &str, query: Option<&str>) -> ClientResult<u64> {
+        let resp = self
+            .send(
+                Method::GET,
+                self.ns_url(namespace, &["workflow-count"]),
+                &[("query", query.unwrap_or("").to_string())],
+                None,
+            )
+            .await?;
+        Ok(int(&resp, "count").max(0) as u64)
+    }
+
+    async fn count_workflows_by_status(
+        &self,
+        namespace: &str,
+        query: Option<&str>,
+    ) -> ClientResult<Vec<(WorkflowStatus, u64)>> {
+        if self.standard_visibility.contains(namespace) {
+            return Err(ClientError::Unimplemented(
+                "counting workflows needs advanced visibility".to_string(),
+            ));
+        }
+        if self.ungrouped_counts.load(Ordering::Relaxed) {
+            return count_each_status(self, namespace, query).await;
+        }
+        let result = self
+            .send(
+                Method::GET,
+                self.ns_url(namespace, &["workflow-count"]),
+                &[("query", grouped_by_status(query))],
+                None,
+            )
+            .await;
+        match result {
+            Ok(resp) => Ok(status_groups(&resp)),
+            Err(ClientError::InvalidArgument(_) | ClientError::Unimplemented(_)) => {
+                self.ungrouped_counts.store(true, Ordering::Relaxed);
+                count_each_status(self, namespace, query).await
+            }
+            Err(e) => Err(e),
+        }
+    }
+
+    async fn list_schedules(
+        &self,
+        namespace: &str,
+        query: Option<&str>,
+        page_size: i32,
+        next_page_token: Vec<u8>,
+    ) -> ClientResult<(Vec<Schedule>, Vec<u8>)> {
+        let mut params = vec![
+            ("maximumPageSize", page_size.to_string()),
+            ("query", query.unwrap_or("").to_string()),
+        ];
+        if !next_page_token.is_empty() {
+            params.push(("nextPageToken", STANDARD.encode(&next_page_token)));
+        }
+        let resp = self
+            .send(
+                Method::GET,
+                self.ns_url(namespace, &["schedules"]),
+                &params,
+                None,
+            )
+            .await?;
+
+        let schedules = array(&resp, "schedules")
+            .iter()
+            .map(|entry| {
+                let info = entry.get("info").unwrap_or(&Value::Null);
+                let spec = schedule_spec_fields(info.get("spec").map(schedule_spec).as_ref());
+                Schedule {
+                    schedule_id: string(entry, "scheduleId"),
+                    workflow_type: name(info, "workflowType"),
+                    state: schedule_state(info),
+                    spec_description: spec.description,
+                    next_run: first_time(info, "futureActionTimes"),
+                    recent_action_count: array(info, "recentActions").len() as u64,
+                    notes: string(info, "notes"),
+                    timezone: spec.timezone,
+                    calendars: spec.calendars,
+                    recent_actions: vec![],
+                    upcoming_runs: vec![],
+                }
+            })
+            .collect();
+        Ok((schedules, page_token(&resp)))
+    }
+
+    async fn describe_workflow_raw(
+        &self,
+        namespace: &str,
+        workflow_id: &str,
+        run_id: Option<&str>,
+    ) -> ClientResult<serde_json::Value> {
+        self.send(
+            Method::GET,
+            self.ns_url(namespace, &["workflows", workflow_id]),
+            &run_query("execution.runId", run_id),
+            None,
+        )
+        .await
+    }
+
+    async fn describe_schedule_raw(
+        &self,
+        namespace: &str,
+        schedule_id: &str,
+    ) -> ClientResult<serde_json::Value> {
+        self.send(
+            Method::GET,
+            self.ns_url(namespace, &["schedules", schedule_id]),
+            &[],
+            None,
+        )
+        .await
+    }
+
+    async fn describe_schedule(
+        &self,
+        namespace: &str,
+        schedule_id: &str,
+    ) -> ClientResult<Schedule> {
+        let resp = self
+            .send(
+                Method::GET,
+                self.ns_url(namespace, &["schedules", schedule_id]),
+                &[],
+                None,
+            )
+            .await?;
+
+        let schedule = resp.get("schedule").unwrap_or(&Value::Null);
+        let info = resp.get("info").unwrap_or(&Value::Null);
+        let state = schedule.get("state").unwrap_or(&Value::Null);
+        let spec = schedule_spec_fields(schedule.get("spec").map(schedule_spec).as_ref());
+
+        Ok(Schedule {
+            schedule_id: schedule_id.to_string(),
+            workflow_type: schedule
+                .pointer("/action/startWorkflow")
+                .map(|wf| name(wf, "workflowType"))
+                .unwrap_or_default(),
+            state: schedule_state(state),
+            spec_description: spec.description,
+            next_run: first_time(info, "futureActionTimes"),
+            recent_action_count: array(info, "recentActions").len() as u64,
+            notes: string(state, "notes"),
+            timezone: spec.timezone,
+            calendars: spec.calendars,
+            recent_actions: array(info, "recentActions")
+                .iter()
+                .rev()
+                .map(|action| schedule_action(&schedule_action_result(action)))
+                .collect(),
+            upcoming_runs: array(info, "futureActionTimes")
+                .iter()
+                .filter_map(Value::as_str)
+                .filter_map(parse_time)
+                .collect(),
+        })
+    }
+
+    async fn list_activity_executions(
+        &self,
+        namespace: &str,
+        query: Option<&str>,
+        page_size: i32,
+        next_page_token: Vec<u8>,
+    ) -> ClientResult<(Vec<ActivityExecutionSummary>, Vec<u8>)> {
+        let resp = self
+            .send(
+                Method::GET,
+                self.ns_url(namespace, &["activities"]),
+                &page_query(query, page_size, &next_page_token),
+                None,
+            )
+            .await?;
+
+        let activities = array(&resp, "executions")
+            .iter()
+            .map(|info| activity_list_info_to_summary(activity_list_info(info)))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok((activities, page_token(&resp)))
+    }
+
+    async fn describe_activity_execution(
+        &self,
+        namespace: &str,
+        activity_id: &str,
+        run_id: &str,
+    ) -> ClientResult<ActivityExecutionDetail> {
+        let resp = self
+            .send(
+                Method::GET,
+                self.ns_url(namespace, &["activities", activity_id]),
+                &[
+                    ("runId", run_id.to_string()),
+                    ("includeInput", "true".to_string()),
+                    ("includeOutcome", "true".to_string()),
+                ],
+                None,
+            )
+            .await?;
+
+        let info = resp
+            .get("info")
+            .ok_or_else(|| ClientError::ParseError("missing activity execution info".into()))?;
+
+        let mut summary = activity_list_info_to_summary(activity_list_info(info))?;
+        if summary.run_id.is_empty() {
+            summary.run_id = string(&resp, "runId");
+        }
+        if summary.activity_type.is_empty() {
+            summary.activity_type = "Unknown".to_string();
+        }
+
+        let last_failure = info.get("lastFailure").map(failure);
+        let retry_state = last_failure
+            .as_ref()
+            .and_then(failure_retry_state)
+            .unwrap_or_else(|| "Unknown".to_string());
+
+        let mut input = resp.get("input").and_then(payloads);
+        let outcome = resp.get("outcome").unwrap_or(&Value::Null);
+        let mut result = outcome.get("result").and_then(payloads);
+        let mut codec_payloads: Vec<&mut Payload> = Vec::new();
+        if let Some(ref mut p) = input {
+            codec_payloads.extend(p.payloads.iter_mut());
+        }
+        if let Some(ref mut p) = result {
+            codec_payloads.extend(p.payloads.iter_mut());
+        }
+        self.codec_decode(namespace, codec_payloads).await;
+
+        Ok(ActivityExecutionDetail {
+            summary,
+            attempt: int(info, "attempt") as i32,
+            retry_state,
+            last_heartbeat_time: time(info, "lastHeartbeatTime"),
+            last_started_time: time(info, "lastStartedTime"),
+            last_failure_message: last_failure.as_ref().map(|f| f.message.clone()),
+            schedule_to_close_timeout: duration(info, "scheduleToCloseTimeout")
+                .map(|d| duration_to_std(&d)),
+            start_to_close_timeout: duration(info, "startToCloseTimeout")
+                .map(|d| duration_to_std(&d)),
+            heartbeat_timeout: duration(info, "heartbeatTimeout").map(|d| duration_to_std(&d)),
+            input: input.map(|p| decode_payloads(&Some(p))),
+            output: result.map(|p| decode_payloads(&Some(p))),
+            failure: outcome
+                .get("failure")
+                .map(|f| decode_failure(&Some(failure(f)))),
+            deployment_info: info.get("lastDeploymentVersion").map(|v| {
+                deployment_version_string(
+                    &proto::temporal::api::deployment::v1::WorkerDeploymentVersion {
+                        deployment_name: string(v, "deploymentName"),
+                        build_id: string(v, "buildId"),
+                    },
+                )
+            }),
+        })
+    }
+
+    async fn count_activity_executions(
+        &self,
+        namespace: &str,
+        query: Option<&str>,
+    ) -> ClientResult<u64> {
+        let resp = self
+            .send(
+                Method::GET,
+                self.ns_url(namespace, &["activity-count"]),
+                &[("query", query.unwrap_or("").to_string())],
+                None,
+            )
+            .await?;
+        Ok(int(&resp, "count").max(0) as u64)
+    }
+
+    async fn check_activity_support(&self, namespace: &str) -> ClientResult<bool> {
+        match self
+            .send(
+                Method::GET,
+                self.ns_url(namespace, &["activities"]),
+                &[("pageSize", "1".to_string())],
+                None,
+            )
+            .await
+        {
+            Ok(_) => Ok(true),
+            Err(ClientError::NotFound(_)) | Err(ClientError::Unimplemented(_)) => Ok(false),
+            Err(e) => Err(e),
+        }
+    }
+
+    async fn list_search_attributes(&self, namespace: &str) -> ClientResult<Vec<SearchAttribute>> {
+        let resp = self
+            .send(
+                Method::GET,
+                self.ns_url(namespace, &["search-attributes"]),
+                &[],
+                None,
+            )
+            .await?;
+        Ok(search_attributes(
+            &indexed_value_types(&resp, "customAttributes"),
+            &indexed_value_types(&resp, "systemAttributes"),
+        ))
+    }
+}
+
+#[async_trait]
+impl ExecutionAdmin for HttpTemporalClient {
+    async fn cancel_workflow(
+        &self,
+        namespace: &str,
+        workflow_id: &str,
+        run_id: Option<&str>,
+    ) -> ClientResult<()> {
+        let body = json!({
+            "workflowExecution": execution_json(workflow_id, run_id),
+            "identity": "t9s",
+            "requestId": uuid::Uuid::new_v4().to_string(),
+        });
+        self.send(
+            Method::POST,
+            self.ns_url(namespace, &["workflows", workflow_id, "cancel"]),
+            &[],
+            Some(body),
+        )
+        .await?;
+        Ok(())
+    }
+
+    async fn terminate_workflow(
+        &self,
+        namespace: &str,
+        workflow_id: &str,
+        run_id: Option<&str>,
+        reason: &str,
+    ) -> ClientResult<()> {
+        let body = json!({
+            "workflowExecution": execution_json(workflow_id, run_id),
+            "reason": reason,
+            "identity": "t9s",
+        });
+        self.send(
+            Method::POST,
+            self.ns_url(namespace, &["workflows", workflow_id, "terminate"]),
+            &[],
+            Some(body),
+        )
+        .await?;
+        Ok(())
+    }
+
+    async fn start_attributes(
+        &self,
+        namespace: &str,
+        workflow_id: &str,
+        run_id: Option<&str>,
+    ) -> ClientResult<StartAttributes> {
+        let mut query = run_query("execution.runId", run_id);
+        query.push(("maximumPageSize", "1".to_string()));
+        let history = self
+            .send(
+                Method::GET,
+                self.ns_url(namespace, &["workflows", workflow_id, "history"]),
+                &query,
+                None,
+            )
+            .await?;
+
+        let Some(started) =
+            history.pointer("/history/events/0/workflowExecutionStartedEventAttributes")
+        else {
+            return Err(ClientError::NotFound(format!(
+                "start event for workflow {}",
+                workflow_id
+            )));
+        };
+
+        // The start event carries the request fields under the same JSON
+        // names, so they are copied across verbatim.
+        let mut body = serde_json::Map::new();
+        for field in [
+            "workflowType",
+            "taskQueue",
+            "input",
+            "workflowExecutionTimeout",
+            "workflowRunTimeout",
+            "workflowTaskTimeout",
+            "retryPolicy",
+            "cronSchedule",
+            "memo",
+            "searchAttributes",
+            "header",
+            "priority",
+        ] {
+            if let Some(value) = started.get(field) {
+                body.insert(field.to_string(), value.clone());
+            }
+        }
+        Ok(StartAttributes::Http(body))
+    }
+
+    async fn start_workflow_from(
+        &self,
+        namespace: &str,
+        workflow_id: &str,
+        attributes: StartAttributes,
+    ) -> ClientResult<String> {
+        let StartAttributes::Http(mut body) = attributes else {
+            return Err(ClientError::InvalidArgument(
+                "start attributes from another transport".into(),
+            ));
+        };
+        body.insert("workflowId".into(), json!(workflow_id));
+        body.insert("identity".into(), json!("t9s"));
+        body.insert("requestId".into(), json!(uuid::Uuid::new_v4().to_string()));
+
+        let resp = self
+            .send(
+                Method::POST,
+                self.ns_url(namespace, &["workflows", workflow_id]),
+                &[],
+                Some(Value::Object(body)),
+            )
+            .await?;
+        Ok(string(&resp, "runId"))
+    }
+
+    async fn signal_workflow(
+        &self,
+        namespace: &str,
+        workflow_id: &str,
+        run_id: Option<&str>,
+        signal_name: &str,
+        input: Option<&str>,
+    ) -> ClientResult<()> {
+        let mut body = json!({
+            "workflowExecution": execution_json(workflow_id, run_id),
+            "signalName": signal_name,
+            "identity": "t9s",
+            "requestId": uuid::Uuid::new_v4().to_string(),
+        });
+        if let Some(input) = input {
+            let value = serde_json::from_str(input).unwrap_or_else(|_| json!(input));
+            body["input"] = payloads_json(&json_payloads(&value));
+        }
+        self.send(
+            Method::POST,
+            self.ns_url(
+                namespace,
+                &["workflows", workflow_id, "signal", signal_name],
+            ),
+            &[],
+            Some(body),
+        )
+        .await?;
+        Ok(())
+    }
+
+    async fn request_cancel_activity_execution(
+        &self,
+        namespace: &str,
+        activity_id: &str,
+        run_id: &str,
+    ) -> ClientResult<()> {
+        let body = json!({
+            "runId": run_id,
+            "identity": "t9s",
+            "requestId": uuid::Uuid::new_v4().to_string(),
+        });
+        self.send(
+            Method::POST,
+            self.ns_url(namespace, &["activities", activity_id, "cancel"]),
+            &[],
+            Some(body),
+        )
+        .await?;
+        Ok(())
+    }
+
+    async fn terminate_activity_execution(
+        &self,
+        namespace: &str,
+        activity_id: &str,
+        run_id: &str,
+        reason: &str,
+    ) -> ClientResult<()> {
+        let body = json!({
+            "runId": run_id,
+            "reason": reason,
+            "identity": "t9s",
+            "requestId": uuid::Uuid::new_v4().to_string(),
+        });
+        self.send(
+            Method::POST,
+            self.ns_url(namespace, &["activities", activity_id, "terminate"]),
+            &[],
+            Some(body),
+        )
+        .await?;
+        Ok(())
+    }
+
+    async fn delete_activity_execution(
+        &self,
+        namespace: &str,
+        activity_id: &str,
+        run_id: &str,
+    ) -> ClientResult<()> {
+        self.send(
+            Method::DELETE,
+            self.ns_url(namespace, &["activities", activity_id]),
+            &[("runId", run_id.to_string())],
+            None,
+        )
+        .await?;
+        Ok(())
+    }
+
+    async fn update_activity_options(
+        &self,
+        namespace: &str,
+        draft: &ActivityOptionsDraft,
+        edit: &ActivityOptionsEdit,
+    ) -> ClientResult<()> {
+        let duration = |text: &Option<String>| {
+            text.as_deref()
+                .and_then(parse_duration_text)
+                .map(|secs| json!(format!("{}s", secs)))
+                .unwrap_or(Value::Null)
+        };
+        // JSON field masks are one string of camelCase paths.
+        let mask: Vec<String> = edit
+            .changed_fields(&draft.edit)
+            .into_iter()
+            .map(|path| {
+                path.split('_')
+                    .enumerate()
+                    .map(|(i, word)| match i {
+                        0 => word.to_string(),
+                        _ => word[..1].to_uppercase() + &word[1..],
+                    })
+                    .collect()
+            })
+            .collect();
+        let body = json!({
+            "execution": execution_json(&draft.workflow_id, Some(&draft.run_id)),
+            "identity": "t9s",
+            "id": draft.activity_id,
+            "activityOptions": {
+                "scheduleToCloseTimeout": duration(&edit.schedule_to_close_timeout),
+                "scheduleToStartTimeout": duration(&edit.schedule_to_start_timeout),
+                "startToCloseTimeout": duration(&edit.start_to_close_timeout),
+                "heartbeatTimeout": duration(&edit.heartbeat_timeout),
+                "retryPolicy": {
+                    "initialInterval": duration(&edit.retry_initial_interval),
+                    "backoffCoefficient": edit.retry_backoff_coefficient,
+                    "maximumInterval": duration(&edit.retry_maximum_interval),
+                    "maximumAttempts": edit.retry_maximum_attempts,
+                    "nonRetryableErrorTypes": edit.non_retryable_error_types,
+                },
+            },
+            "updateMask": mask.join(","),
+        });
+        self.send(
+            Method::POST,
+            self.ns_url(namespace, &["activities", "update-options"]),
+            &[],
+            Some(body),
+        )
+        .await?;
+        Ok(())
+    }
+
+    async fn start_batch_operation(
+        &self,
+        namespace: &str,
+        operation: BatchOperation,
+        target: &BatchTarget,
+        reason: &str,
+    ) -> ClientResult<String> {
+        let job_id = uuid::Uuid::new_v4().to_string();
+        let mut body = json!({ "reason": reason });
+        match target {
+            BatchTarget::Query(query) => body["visibilityQuery"] = json!(query),
+            BatchTarget::Executions(executions) => {
+                body["executions"] = executions
+                    .iter()
+                    .map(|(workflow_id, run_id)| execution_json(workflow_id, Some(run_id)))
+                    .collect();
+            }
+        }
+        match operation {
+            BatchOperation::Cancel => {
+                body["cancellationOperation"] = json!({ "identity": "t9s" });
+            }
+            BatchOperation::Terminate => {
+                body["terminationOperation"] = json!({ "identity": "t9s" });
+            }
+        }
+        self.send(
+            Method::POST,
+            self.ns_url(namespace, &["batch-operations", &job_id]),
+            &[],
+            Some(body),
+        )
+        .await?;
+        Ok(job_id)
+    }
+}
+
+#[async_trait]
+impl ScheduleAdmin for HttpTemporalClient {
+    async fn patch_schedule(
+        &self,
+        namespace: &str,
+        schedule_id: &str,
+        pause: bool,
+        note: &str,
+    ) -> ClientResult<()> {
+        let note = pause_note(pause, note);
+        let patch = if pause {
+            json!({ "pause": note })
+        } else {
+            json!({ "unpause": note })
+        };
+        self.send(
+            Method::POST,
+            self.ns_url(namespace, &["schedules", schedule_id, "patch"]),
+            &[],
+            Some(json!({
+                "patch": patch,
+                "identity": "t9s",
+                "requestId": uuid::Uuid::new_v4().to_string(),
+            })),
+        )
+        .await?;
+        Ok(())
+    }
+
+    async fn trigger_schedule(&self, namespace: &str, schedule_id: &str) -> ClientResult<()> {
+        self.send(
+            Method::POST,
+            self.ns_url(namespace, &["schedules", schedule_id, "patch"]),
+            &[],
+            Some(json!({
+                "patch": { "triggerImmediately": {} },
+                "identity": "t9s",
+                "requestId": uuid::Uuid::new_v4().to_string(),
+            })),
+        )
+        .await?;
+        Ok(())
+    }
+
+    async fn delete_schedule(&self, namespace: &str, schedule_id: &str) -> ClientResult<()> {
+        self.send(
+            Method::DELETE,
+            self.ns_url(namespace, &["schedules", schedule_id]),
+            &[("identity", "t9s".to_string())],
+            None,
+        )
+        .await?;
+        Ok(())
+    }
+
+    async fn schedule_draft(
+        &self,
+        namespace: &str,
+        schedule_id: &str,
+    ) -> ClientResult<ScheduleDraft> {
+        let (schedule, conflict_token) = self.schedule_json(namespace, schedule_id).await?;
+        Ok(ScheduleDraft {
+            schedule_id: schedule_id.to_string(),
+            edit: self.decoded_schedule_edit(namespace, &schedule).await,
+            conflict_token,
+        })
+    }
+
+    async fn update_schedule(
+        &self,
+        namespace: &str,
+        schedule_id: &str,
+        edit: &ScheduleEdit,
+        conflict_token: &[u8],
+    ) -> ClientResult<()> {
+        let (mut schedule, current_token) = self.schedule_json(namespace, schedule_id).await?;
+        if current_token != conflict_token {
+            return Err(schedule_conflict(schedule_id));
+        }
+        let original = self.decoded_schedule_edit(namespace, &schedule).await;
+        let mut input = schedule_edit_input(edit);
+        if edit.input != original.input {
+            self.codec_encode(namespace, input.payloads.iter_mut().collect())
+                .await?;
+        }
+        apply_schedule_edit_json(&mut schedule, edit, &original, &input);
+        self.send(
+            Method::POST,
+            self.ns_url(namespace, &["schedules", schedule_id, "update"]),
+            &[],
+            Some(json!({
+                "schedule": schedule,
+                "conflictToken": STANDARD.encode(conflict_token),
+                "identity": "t9s",
+                "requestId": uuid::Uuid::new_v4().to_string(),
+            })),
+        )
+        .await?;
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl TaskQueueReader for HttpTemporalClient {
+    async fn describe_task_queue(
+        &self,
+        namespace: &str,
+        task_queue: &str,
+    ) -> ClientResult<TaskQueueInfo> {
+        let (workflow, activity, rules) = futures::join!(
+            self.describe_task_queue_type(namespace, task_queue, "TASK_QUEUE_TYPE_WORKFLOW"),
+            self.describe_task_queue_type(namespace, task_queue, "TASK_QUEUE_TYPE_ACTIVITY"),
+            // Build-id rules are off on many servers; their absence isn't an error.
+            self.send(
+                Method::GET,
+                self.ns_url(
+                    namespace,
+                    &["task-queues", task_queue, "worker-versioning-rules"],
+                ),
+                &[],
+                None,
+            ),
+        );
+        let (workflow, activity) = (workflow?, activity?);
+        let pollers = |resp: &Value| {
+            array(resp, "pollers")
+                .iter()
+                .map(poller_info)
+                .map(poller)
+                .collect()
+        };
+        Ok(TaskQueueInfo {
+            name: task_queue.to_string(),
+            workflow_pollers: pollers(&workflow),
+            activity_pollers: pollers(&activity),
+            versioning: task_queue_versioning(
+                workflow
+                    .get("versioningInfo")
+                    .map(task_queue_versioning_info)
+                    .as_ref(),
+                rules.ok().as_ref().map(versioning_rules).as_ref(),
+            ),
+        })
+    }
+}
+
+#[async_trait]
+impl NamespaceAdmin for HttpTemporalClient {
+    async fn add_search_attribute(
+        &self,
+        namespace: &str,
+        name: &str,
+        value_type: SearchAttributeType,
+    ) -> ClientResult<()> {
+        self.send(
+            Method::POST,
+            self.ns_url(namespace, &["search-attributes"]),
+            &[],
+            Some(json!({
+                "searchAttributes": { name: indexed_value_type(value_type).as_str_name() },
+            })),
+        )
+        .await?;
+        Ok(())
+    }
+
+    async fn register_namespace(
+        &self,
+        name: &str,
+        description: &str,
+        retention: std::time::Duration,
+    ) -> ClientResult<()> {
+        self.send(
+            Method::POST,
+            self.url(&["namespaces"]),
+            &[],
+            Some(json!({
+                "namespace": name,
+                "description": description,
+                "workflowExecutionRetentionPeriod": format!("{}s", retention.as_secs()),
+            })),
+        )
+        .await?;
+        Ok(())
+    }
+
+    async fn update_namespace(
+        &self,
+        name: &str,
+        description: Option<&str>,
+        retention: Option<std::time::Duration>,
+    ) -> ClientResult<()> {
+        let mut body = json!({ "namespace": name });
+        if let Some(description) = description {
+            body["updateInfo"] = json!({ "description": description });
+        }
+        if let Some(retention) = retention {
+            body["config"] =
+                json!({ "workflowExecutionRetentionTtl": format!("{}s", retention.as_secs()) });
+        }
+        self.send(
+            Method::POST,
+            self.ns_url(name, &["update"]),
+            &[],
+            Some(body),
+        )
+        .await?;
+        Ok(())
+    }
+
+    async fn deprecate_namespace(&self, name: &str) -> ClientResult<()> {
+        self.send(
+            Method::POST,
+            self.ns_url(name, &["update"]),
+            &[],
+            Some(json!({
+                "namespace": name,
+                "updateInfo": { "state": "NAMESPACE_STATE_DEPRECATED" },
+            })),
+        )
+        .await?;
+        Ok(())
+    }
+}
+
+impl TemporalClient for HttpTemporalClient {
+    fn execution_admin(&self) -> Option<&dyn ExecutionAdmin> {
+        Some(self)
+    }
+
+    fn schedule_admin(&self) -> Option<&dyn ScheduleAdmin> {
+        Some(self)
+    }
+
+    fn task_queue_reader(&self) -> Option<&dyn TaskQueueReader> {
+        Some(self)
+    }
+
+    fn namespace_admin(&self) -> Option<&dyn NamespaceAdmin> {
+        Some(self)
+    }
+
+    fn take_decode_errors(&self) -> Vec<String> {
+        self.codec
+            .as_ref()
+            .map(CodecClient::take_decode_errors)
+            .unwrap_or_default()
+    }
+}
+
+/// Base URL for the HTTP API. A bare `host:port` gets a scheme by the same
+/// rule as gRPC, and the default gRPC port is swapped for the HTTP one.
+pub fn http_base_url(address: &str, has_api_key: bool) -> String {
+    if address.contains("://") {
+        return address.trim_end_matches('/').to_string();
+    }
+    let scheme = if uses_tls(address, has_api_key) {
+        "https"
+    } else {
+        "http"
+    };
+    let host = match address.strip_suffix(GRPC_PORT) {
+        Some(host) if host.ends_with(':') => format!("{}{}", host, HTTP_PORT),
+        _ => address.to_string(),
+    };
+    format!("{}://{}", scheme, host)
+}
+
+fn http_error(status: StatusCode, body: &str) -> ClientError {
+    let message = serde_json::from_str::<Value>(body)
+        .ok()
+        .and_then(|v| v.get("message").and_then(Value::as_str).map(str::to_string))
+        .unwrap_or_else(|| body.trim().to_string());
+    match status {
+        StatusCode::NOT_FOUND => ClientError::NotFound(message),
+        StatusCode::NOT_IMPLEMENTED => ClientError::Unimplemented(message),
+        StatusCode::BAD_REQUEST => ClientError::InvalidArgument(message),
+        StatusCode::GATEWAY_TIMEOUT | StatusCode::REQUEST_TIMEOUT => ClientError::Timeout,
+        StatusCode::SERVICE_UNAVAILABLE | StatusCode::BAD_GATEWAY => {
+            ClientError::ConnectionError(message)
+        }
+        _ => ClientError::RequestFailed(format!("{}: {}", status, message)),
+    }
+}
+
+const PAYLOAD_FIELDS: &[&str] = &["input", "result", "details", "heartbeatDetails"];
+
+fn event_attributes(event: &Value) -> Option<&Value> {
+    event
+        .as_object()?
+        .iter()
+        .find(|(key, _)| key.ends_with("EventAttributes"))
+        .map(|(_, attrs)| attrs)
+}
+
+/// A history event with the payloads the codec should see pulled out of it.
+struct EventPayloads {
+    event: Value,
+    fields: Vec<(String, Payloads)>,
+    failure: Option<proto::temporal::api::failure::v1::Failure>,
+    metadata: Option<proto::temporal::api::sdk::v1::UserMetadata>,
+}
+
+/// History event attributes with snake_case keys, `{name}` wrappers
+/// flattened and payload fields replaced by their decoded values, and the
+/// payloads that aren't text by key.
+fn event_details(
+    attrs: &Value,
+    decoded: Vec<(String, Payloads)>,
+) -> (Value, Vec<(String, Vec<u8>)>) {
+    let mut map = serde_json::Map::new();
+    if let Some(obj) = attrs.as_object() {
+        for (key, value) in obj {
+            if PAYLOAD_FIELDS.contains(&key.as_str()) {
+                continue;
+            }
+            let value = match value.get("name") {
+                Some(name) if value.as_object().is_some_and(|o| o.len() <= 2) => name.clone(),
+                _ => snake_keys(value),
+            };
+            map.insert(snake_case(key), value);
+        }
+    }
+    let mut binary = vec![];
+    for (key, payloads) in decoded {
+        let payload = payloads_value(&Some(payloads));
+        if payload.is_null() {
+            continue;
+        }
+        let key = snake_case(&key);
+        map.insert(key.clone(), payload.to_json());
+        if let crate::domain::Payload::Binary(bytes) = payload {
+            binary.push((key, bytes));
+        }
+    }
+    (Value::Object(map), binary)
+}
+
+fn snake_keys(value: &Value) -> Value {
+    match value {
+        Value::Object(obj) => Value::Object(
+            obj.iter()
+                .map(|(k, v)| (snake_case(k), snake_keys(v)))
+                .collect(),
+        ),
+        Value::Array(items) => Value::Array(items.iter().map(snake_keys).collect()),
+        other => other.clone(),
+    }
+}
+
+fn snake_case(key: &str) -> String {
+    let mut out = String::with_capacity(key.len() + 4);
+    for c in key.chars() {
+        if c.is_ascii_uppercase() {
+            out.push('_');
+            out.push(c.to_ascii_lowercase());
+        } else {
+            out.push(c);
+        }
+    }
+    out
+}
+
+fn page_query(query: Option<&str>, page_size: i32, token: &[u8]) -> Vec<(&'static str, String)> {
+    let mut params = vec![
+        ("pageSize", page_size.to_string()),
+        ("query", query.unwrap_or("").to_string()),
+    ];
+    if !token.is_empty() {
+        params.push(("nextPageToken", STANDARD.encode(token)));
+    }
+    params
+}
+
+fn run_query(key: &'static str, run_id: Option<&str>) -> Vec<(&'static str, String)> {
+    run_id
+        .filter(|r| !r.is_empty())
+        .map(|r| vec![(key, r.to_string())])
+        .unwrap_or_default()
+}
+
+fn activity_options(v: &Value) -> proto::temporal::api::activity::v1::ActivityOptions {
+    let retry = v.get("retryPolicy").unwrap_or(&Value::Null);
+    proto::temporal::api::activity::v1::ActivityOptions {
+        schedule_to_close_timeout: duration(v, "scheduleToCloseTimeout"),
+        schedule_to_start_timeout: duration(v, "scheduleToStartTimeout"),
+        start_to_close_timeout: duration(v, "startToCloseTimeout"),
+        heartbeat_timeout: duration(v, "heartbeatTimeout"),
+        retry_policy: Some(proto::temporal::api::common::v1::RetryPolicy {
+            initial_interval: duration(retry, "initialInterval"),
+            backoff_coefficient: retry
+                .get("backoffCoefficient")
+                .and_then(Value::as_f64)
+                .unwrap_or_default(),
+            maximum_interval: duration(retry, "maximumInterval"),
+            maximum_attempts: int(retry, "maximumAttempts") as i32,
+            non_retryable_error_types: array(retry, "nonRetryableErrorTypes")
+                .iter()
+                .filter_map(Value::as_str)
+                .map(str::to_string)
+                .collect(),
+        }),
+        ..Default::default()
+    }
+}
+
+fn execution_json(workflow_id: &str, run_id: Option<&str>) -> Value {
+    json!({ "workflowId": workflow_id, "runId": run_id.unwrap_or("") })
+}
+
+fn page_token(v: &Value) -> Vec<u8> {
+    v.get("nextPageToken")
+        .and_then(Value::as_str)
+        .and_then(|t| STANDARD.decode(t).ok())
+        .unwrap_or_default()
+}
+
+fn array<'a>(v: &'a Value, key: &str) -> &'a [Value] {
+    v.get(key)
+        .and_then(Value::as_array)
+        .map(Vec::as_slice)
+        .unwrap_or_default()
+}
+
+fn string(v: &Value, key: &str) -> String {
+    v.get(key)
+        .and_then(Value::as_str)
+        .unwrap_or_default()
+        .to_string()
+}
+
+fn name(v: &Value, key: &str) -> String {
+    v.get(key).map(|t| string(t, "name")).unwrap_or_default()
+}
+
+/// proto3 JSON writes 64-bit integers as strings.
+fn int(v: &Value, key: &str) -> i64 {
+    match v.get(key) {
+        Some(Value::Number(n)) => n.as_i64().unwrap_or_default(),
+        Some(Value::String(s)) => s.parse().unwrap_or_default(),
+        _ => 0,
+    }
+}
+
+fn enum_value<E: Into<i32>>(v: &Value, key: &str, from_name: fn(&str) -> Option<E>) -> i32 {
+    v.get(key)
+        .and_then(Value::as_str)
+        .and_then(from_name)
+        .map(Into::into)
+        .unwrap_or_default()
+}
+
+fn time(v: &Value, key: &str) -> Option<DateTime<Utc>> {
+    v.get(key).and_then(Value::as_str).and_then(parse_time)
+}
+
+fn first_time(v: &Value, key: &str) -> Option<DateTime<Utc>> {
+    array(v, key)
+        .first()
+        .and_then(Value::as_str)
+        .and_then(parse_time)
+}
+
+fn parse_time(s: &str) -> Option<DateTime<Utc>> {
+    DateTime::parse_from_rfc3339(s)
+        .ok()
+        .map(|t| t.with_timezone(&Utc))
+}
+
+fn timestamp(v: &Value, key: &str) -> Option<pbjson_types::Timestamp> {
+    time(v, key).map(|t| pbjson_types::Timestamp {
+        seconds: t.timestamp(),
+        nanos: t.timestamp_subsec_nanos() as i32,
+    })
+}
+
+/// proto3 JSON durations look like `"3600s"` or `"1.5s"`.
+fn duration(v: &Value, key: &str) -> Option<pbjson_types::Duration> {
+    let secs: f64 = v.get(key)?.as_str()?.strip_suffix('s')?.parse().ok()?;
+    Some(pbjson_types::Duration {
+        seconds: secs.trunc() as i64,
+        nanos: (secs.fract() * 1e9) as i32,
+    })
+}
+
+fn schedule_state(v: &Value) -> ScheduleState {
+    if v.get("paused").and_then(Value::as_bool).unwrap_or(false) {
+        ScheduleState::Paused
+    } else {
+        ScheduleState::Active
+    }
+}
+
+/// A payload in full form (`metadata`/`data`, base64) or in the shorthand the
+/// HTTP API uses for JSON payloads, where the value is inlined.
+fn payload(v: &Value) -> Payload {
+    let full = v.as_object().filter(|o| {
+        o.contains_key("metadata")
+            && o.keys()
+                .all(|k| matches!(k.as_str(), "metadata" | "data" | "externalPayloads"))
+    });
+    let Some(obj) = full else {
+        return json_payloads(v).payloads.remove(0);
+    };
+    let decode = |s: &Value| {
+        s.as_str()
+            .and_then(|s| STANDARD.decode(s).ok())
+            .unwrap_or_default()
+    };
+    Payload {
+        metadata: obj
+            .get("metadata")
+            .and_then(Value::as_object)
+            .map(|m| m.iter().map(|(k, v)| (k.clone(), decode(v))).collect())
+            .unwrap_or_default(),
+        data: obj.get("data").map(decode).unwrap_or_default(),
+        ..Default::default()
+    }
+}
+
+fn execution_config(resp: &Value) -> ExecutionConfig {
+    let Some(config) = resp.get("executionConfig") else {
+        return ExecutionConfig::default();
+    };
+    let timeout = |key: &str| {
+        duration(config, key)
+            .map(|d| duration_to_std(&d))
+            .filter(|d| !d.is_zero())
+    };
+    ExecutionConfig {
+        task_queue: config
+            .get("taskQueue")
+            .map(|q| string(q, "name"))
+            .unwrap_or_default(),
+        execution_timeout: timeout("workflowExecutionTimeout"),
+        run_timeout: timeout("workflowRunTimeout"),
+        task_timeout: timeout("defaultWorkflowTaskTimeout"),
+    }
+}
+
+fn user_metadata_proto(v: &Value) -> Option<proto::temporal::api::sdk::v1::UserMetadata> {
+    Some(proto::temporal::api::sdk::v1::UserMetadata {
+        summary: v.get("summary").map(payload),
+        details: v.get("details").map(payload),
+    })
+}
+
+fn payloads(v: &Value) -> Option<Payloads> {
+    let items = match v {
+        Value::Array(items) => items,
+        Value::Object(obj) => obj.get("payloads")?.as_array()?,
+        _ => return None,
+    };
+    Some(Payloads {
+        payloads: items.iter().map(payload).collect(),
+    })
+}
+
+fn payloads_json(payloads: &Payloads) -> Value {
+    let encode = |bytes: &Vec<u8>| Value::String(STANDARD.encode(bytes));
+    json!({
+        "payloads": payloads
+            .payloads
+            .iter()
+            .map(|p| json!({
+                "metadata": p.metadata.iter().map(|(k, v)| (k.clone(), encode(v))).collect::<serde_json::Map<_, _>>(),
+                "data": encode(&p.data),
+            }))
+            .collect::<Vec<_>>(),
+    })
+}
+
+fn execution(v: &Value) -> proto::temporal::api::common::v1::WorkflowExecution {
+    proto::temporal::api::common::v1::WorkflowExecution {
+        workflow_id: string(v, "workflowId"),
+        run_id: string(v, "runId"),
+    }
+}
+
+fn schedule_action_result(v: &Value) -> proto::temporal::api::schedule::v1::ScheduleActionResult {
+    proto::temporal::api::schedule::v1::ScheduleActionResult {
+        schedule_time: timestamp(v, "scheduleTime"),
+        actual_time: timestamp(v, "actualTime"),
+        start_workflow_result: v.get("startWorkflowResult").map(execution),
+        start_workflow_status: enum_value(
+            v,
+            "startWorkflowStatus",
+            enums::WorkflowExecutionStatus::from_str_name,
+        ),
+    }
+}
+
+fn workflow_info(v: &Value) -> proto::temporal::api::workflow::v1::WorkflowExecutionInfo {
+    proto::temporal::api::workflow::v1::WorkflowExecutionInfo {
+        execution: v.get("execution").map(execution),
+        r#type: v
+            .get("type")
+            .map(|t| proto::temporal::api::common::v1::WorkflowType {
+                name: string(t, "name"),
+            }),
+        start_time: timestamp(v, "startTime"),
+        close_time: timestamp(v, "closeTime"),
+        status: enum_value(v, "status", enums::WorkflowExecutionStatus::from_str_name),
+        history_length: int(v, "historyLength"),
+        parent_execution: v.get("parentExecution").map(execution),
+        root_execution: v.get("rootExecution").map(execution),
+        task_queue: string(v, "taskQueue"),
+        search_attributes: v.get("searchAttributes").map(|sa| {
+            proto::temporal::api::common::v1::SearchAttributes {
+                indexed_fields: sa
+                    .get("indexedFields")
+                    .and_then(Value::as_object)
+                    .map(|m| m.iter().map(|(k, v)| (k.clone(), payload(v))).collect())
+                    .unwrap_or_default(),
+            }
+        }),
+        memo: v
+            .get("memo")
+            .map(|m| proto::temporal::api::common::v1::Memo {
+                fields: m
+                    .get("fields")
+                    .and_then(Value::as_object)
+                    .map(|m| m.iter().map(|(k, v)| (k.clone(), payload(v))).collect())
+                    .unwrap_or_default(),
+            }),
+        versioning_info: v.get("versioningInfo").map(workflow_versioning_info),
+        ..Default::default()
+    }
+}
+
+fn worker_deployment_version(
+    v: &Value,
+) -> proto::temporal::api::deployment::v1::WorkerDeploymentVersion {
+    proto::temporal::api::deployment::v1::WorkerDeploymentVersion {
+        deployment_name: string(v, "deploymentName"),
+        build_id: string(v, "buildId"),
+    }
+}
+
+fn workflow_versioning_info(
+    v: &Value,
+) -> proto::temporal::api::workflow::v1::WorkflowExecutionVersioningInfo {
+    use proto::temporal::api::workflow::v1::versioning_override::{Override, PinnedOverride};
+
+    let versioning_override = v.get("versioningOverride").and_then(|o| {
+        let r#override = match (
+            o.get("pinned"),
+            o.get("autoUpgrade").and_then(Value::as_bool),
+        ) {
+            (Some(pinned), _) => Override::Pinned(PinnedOverride {
+                version: pinned.get("version").map(worker_deployment_version),
+                ..Default::default()
+            }),
+            (None, Some(auto_upgrade)) => Override::AutoUpgrade(auto_upgrade),
+            (None, None) => return None,
+        };
+        Some(proto::temporal::api::workflow::v1::VersioningOverride {
+            r#override: Some(r#override),
+            ..Default::default()
+        })
+    });
+    proto::temporal::api::workflow::v1::WorkflowExecutionVersioningInfo {
+        behavior: enum_value(v, "behavior", enums::VersioningBehavior::from_str_name),
+        deployment_version: v.get("deploymentVersion").map(worker_deployment_version),
+        versioning_override,
+        ..Default::default()
+    }
+}
+
+fn poller_info(v: &Value) -> proto::temporal::api::taskqueue::v1::PollerInfo {
+    #[allow(deprecated)]
+    proto::temporal::api::taskqueue::v1::PollerInfo {
+        last_access_time: timestamp(v, "lastAccessTime"),
+        identity: string(v, "identity"),
+        rate_per_second: v
+            .get("ratePerSecond")
+            .and_then(Value::as_f64)
+            .unwrap_or_default(),
+        worker_version_capabilities: v.get("workerVersionCapabilities").map(|c| {
+            proto::temporal::api::common::v1::WorkerVersionCapabilities {
+                build_id: string(c, "buildId"),
+                deployment_series_name: string(c, "deploymentSeriesName"),
+                ..Default::default()
+            }
+        }),
+        deployment_options: v.get("deploymentOptions").map(|o| {
+            proto::temporal::api::deployment::v1::WorkerDeploymentOptions {
+                deployment_name: string(o, "deploymentName"),
+                build_id: string(o, "buildId"),
+                ..Default::default()
+            }
+        }),
+    }
+}
+
+fn task_queue_versioning_info(
+    v: &Value,
+) -> proto::temporal::api::taskqueue::v1::TaskQueueVersioningInfo {
+    proto::temporal::api::taskqueue::v1::TaskQueueVersioningInfo {
+        current_deployment_version: v
+            .get("currentDeploymentVersion")
+            .map(worker_deployment_version),
+        ramping_deployment_version: v
+            .get("rampingDeploymentVersion")
+            .map(worker_deployment_version),
+        ramping_version_percentage: v
+            .get("rampingVersionPercentage")
+            .and_then(Value::as_f64)
+            .unwrap_or_default() as f32,
+        ..Default::default()
+    }
+}
+
+fn versioning_rules(v: &Value) -> proto::GetWorkerVersioningRulesResponse {
+    use proto::temporal::api::taskqueue::v1::{
+        build_id_assignment_rule::Ramp, BuildIdAssignmentRule, CompatibleBuildIdRedirectRule,
+        RampByPercentage, TimestampedBuildIdAssignmentRule,
+        TimestampedCompatibleBuildIdRedirectRule,
+    };
+
+    proto::GetWorkerVersioningRulesResponse {
+        assignment_rules: array(v, "assignmentRules")
+            .iter()
+            .map(|r| TimestampedBuildIdAssignmentRule {
+                rule: r.get("rule").map(|rule| BuildIdAssignmentRule {
+                    target_build_id: string(rule, "targetBuildId"),
+                    ramp: rule.get("percentageRamp").map(|ramp| {
+                        Ramp::PercentageRamp(RampByPercentage {
+                            ramp_percentage: ramp
+                                .get("rampPercentage")
+                                .and_then(Value::as_f64)
+                                .unwrap_or_default()
+                                as f32,
+                        })
+                    }),
+                }),
+                create_time: timestamp(r, "createTime"),
+            })
+            .collect(),
+        compatible_redirect_rules: array(v, "compatibleRedirectRules")
+            .iter()
+            .map(|r| TimestampedCompatibleBuildIdRedirectRule {
+                rule: r.get("rule").map(|rule| CompatibleBuildIdRedirectRule {
+                    source_build_id: string(rule, "sourceBuildId"),
+                    target_build_id: string(rule, "targetBuildId"),
+                }),
+                create_time: timestamp(r, "createTime"),
+            })
+            .collect(),
+        ..Default::default()
+    }
+}
+
+fn activity_list_info(v: &Value) -> proto::temporal::api::activity::v1::ActivityExecutionListInfo {
+    proto::temporal::api::activity::v1::ActivityExecutionListInfo {
+        activity_id: string(v, "activityId"),
+        run_id: string(v, "runId"),
+        activity_type: v.get("activityType").map(|t| {
+            proto::temporal::api::common::v1::ActivityType {
+                name: string(t, "name"),
+            }
+        }),
+        schedule_time: timestamp(v, "scheduleTime"),
+        close_time: timestamp(v, "closeTime"),
+        status: enum_value(v, "status", enums::ActivityExecutionStatus::from_str_name),
+        task_queue: string(v, "taskQueue"),
+        ..Default::default()
+    }
+}
+
+fn failure(v: &Value) -> proto::temporal::api::failure::v1::Failure {
+    use proto::temporal::api::common::v1::{ActivityType, WorkflowExecution, WorkflowType};
+    use proto::temporal::api::failure::v1::failure::FailureInfo;
+    use proto::temporal::api::failure::v1::{
+        ActivityFailureInfo, ApplicationFailureInfo, CanceledFailureInfo,
+        ChildWorkflowExecutionFailureInfo, Failure, ServerFailureInfo, TerminatedFailureInfo,
+        TimeoutFailureInfo,
+    };
+
+    let retry_state =
+        |info: &Value| enum_value(info, "retryState", enums::RetryState::from_str_name);
+    let flag = |info: &Value, key: &str| info.get(key).and_then(Value::as_bool).unwrap_or_default();
+    let failure_info = if let Some(info) = v.get("applicationFailureInfo") {
+        Some(FailureInfo::ApplicationFailureInfo(
+            ApplicationFailureInfo {
+                r#type: string(info, "type"),
+                non_retryable: flag(info, "nonRetryable"),
+                details: info.get("details").and_then(payloads),
+                ..Default::default()
+            },
+        ))
+    } else if let Some(info) = v.get("timeoutFailureInfo") {
+        Some(FailureInfo::TimeoutFailureInfo(TimeoutFailureInfo {
+            timeout_type: enum_value(info, "timeoutType", enums::TimeoutType::from_str_name),
+            last_heartbeat_details: info.get("lastHeartbeatDetails").and_then(payloads),
+        }))
+    } else if let Some(info) = v.get("canceledFailureInfo") {
+        Some(FailureInfo::CanceledFailureInfo(CanceledFailureInfo {
+            details: info.get("details").and_then(payloads),
+            identity: string(info, "identity"),
+        }))
+    } else if let Some(info) = v.get("terminatedFailureInfo") {
+        Some(FailureInfo::TerminatedFailureInfo(TerminatedFailureInfo {
+            identity: string(info, "identity"),
+        }))
+    } else if let Some(info) = v.get("serverFailureInfo") {
+        Some(FailureInfo::ServerFailureInfo(ServerFailureInfo {
+            non_retryable: flag(info, "nonRetryable"),
+        }))
+    } else if let Some(info) = v.get("activityFailureInfo") {
+        Some(FailureInfo::ActivityFailureInfo(ActivityFailureInfo {
+            activity_type: info.get("activityType").map(|t| ActivityType {
+                name: string(t, "name"),
+            }),
+            activity_id: string(info, "activityId"),
+            retry_state: retry_state(info),
+            ..Default::default()
+        }))
+    } else {
+        v.get("childWorkflowExecutionFailureInfo").map(|info| {
+            FailureInfo::ChildWorkflowExecutionFailureInfo(ChildWorkflowExecutionFailureInfo {
+                workflow_execution: info.get("workflowExecution").map(|e| WorkflowExecution {
+                    workflow_id: string(e, "workflowId"),
+                    run_id: string(e, "runId"),
+                }),
+                workflow_type: info.get("workflowType").map(|t| WorkflowType {
+                    name: string(t, "name"),
+                }),
+                retry_state: retry_state(info),
+                ..Default::default()
+            })
+        })
+    };
+
+    Failure {
+        message: string(v, "message"),
+        source: string(v, "source"),
+        stack_trace: string(v, "stackTrace"),
+        encoded_attributes: v.get("encodedAttributes").map(payload),
+        cause: v.get("cause").map(|c| Box::new(failure(c))),
+        failure_info,
+    }
+}
+
+fn schedule_spec(v: &Value) -> proto::temporal::api::schedule::v1::ScheduleSpec {
+    use proto::temporal::api::schedule::v1::{
+        CalendarSpec, IntervalSpec, Range, ScheduleSpec, StructuredCalendarSpec,
+    };
+
+    let ranges = |cal: &Value, key: &str| -> Vec<Range> {
+        array(cal, key)
+            .iter()
+            .map(|r| Range {
+                start: int(r, "start") as i32,
+                end: int(r, "end") as i32,
+                step: int(r, "step") as i32,
+            })
+            .collect()
+    };
+
+    ScheduleSpec {
+        cron_string: array(v, "cronString")
+            .iter()
+            .filter_map(Value::as_str)
+            .map(str::to_string)
+            .collect(),
+        calendar: array(v, "calendar")
+            .iter()
+            .map(|cal| CalendarSpec {
+                second: string(cal, "second"),
+                minute: string(cal, "minute"),
+                hour: string(cal, "hour"),
+                day_of_month: string(cal, "dayOfMonth"),
+                month: string(cal, "month"),
+                year: string(cal, "year"),
+                day_of_week: string(cal, "dayOfWeek"),
+                comment: string(cal, "comment"),
+            })
+            .collect(),
+        structured_calendar: array(v, "structuredCalendar")
+            .iter()
+            .map(|cal| StructuredCalendarSpec {
+                second: ranges(cal, "second"),
+                minute: ranges(cal, "minute"),
+                hour: ranges(cal, "hour"),
+                day_of_month: ranges(cal, "dayOfMonth"),
+                month: ranges(cal, "month"),
+                year: ranges(cal, "year"),
+                day_of_week: ranges(cal, "dayOfWeek"),
+                comment: string(cal, "comment"),
+            })
+            .collect(),
+        interval: array(v, "interval")
+            .iter()
+            .map(|i| IntervalSpec {
+                interval: duration(i, "interval"),
+                phase: duration(i, "phase"),
+            })
+            .collect(),
+        timezone_name: string(v, "timezoneName"),
+        jitter: duration(v, "jitter"),
+        ..Default::default()
+    }
+}
+
+fn schedule_proto(v: &Value) -> proto::temporal::api::schedule::v1::Schedule {
+    use proto::temporal::api::schedule::v1::{schedule_action::Action, Schedule, ScheduleAction};
+
+    Schedule {
+        spec: v.get("spec").map(schedule_spec),
+        action: v.pointer("/action/startWorkflow").map(|wf| ScheduleAction {
+            action: Some(Action::StartWorkflow(new_workflow_info(wf))),
+        }),
+        ..Default::default()
+    }
+}
+
+fn new_workflow_info(v: &Value) -> proto::temporal::api::workflow::v1::NewWorkflowExecutionInfo {
+    proto::temporal::api::workflow::v1::NewWorkflowExecutionInfo {
+        workflow_id: string(v, "workflowId"),
+        workflow_type: Some(proto::temporal::api::common::v1::WorkflowType {
+            name: name(v, "workflowType"),
+        }),
+        task_queue: Some(proto::temporal::api::taskqueue::v1::TaskQueue {
+            name: name(v, "taskQueue"),
+            ..Default::default()
+        }),
+        input: v.get("input").and_then(payloads),
+        workflow_execution_timeout: duration(v, "workflowExecutionTimeout"),
+        workflow_run_timeout: duration(v, "workflowRunTimeout"),
+        workflow_task_timeout: duration(v, "workflowTaskTimeout"),
+        ..Default::default()
+    }
+}
+
+/// JSON counterpart of the gRPC client's edit: only fields that differ from
+/// `original` are written, the rest of `schedule` is sent back untouched.
+/// `input` is the edited input, already encoded.
+fn apply_schedule_edit_json(
+    schedule: &mut Value,
+    edit: &ScheduleEdit,
+    original: &ScheduleEdit,
+    input: &Payloads,
+) {
+    let seconds = |secs: i64| json!(format!("{}s", secs));
+    let duration = |text: &Option<String>| {
+        text.as_deref()
+            .and_then(parse_duration_text)
+            .map(seconds)
+            .unwrap_or(Value::Null)
+    };
+
+    let spec = object_entry(schedule, "spec");
+    if edit.cron != original.cron {
+        let split = split_cron_lines(&schedule_spec(spec), &edit.cron);
+        let kept = |entries: &Value, keep: Vec<bool>| -> Value {
+            let entries = entries.as_array().cloned().unwrap_or_default();
+            entries
+                .into_iter()
+                .zip(keep)
+                .filter_map(|(entry, keep)| keep.then_some(entry))
+                .collect()
+        };
+        spec["calendar"] = kept(&spec["calendar"], split.keep_calendar);
+        spec["structuredCalendar"] = kept(&spec["structuredCalendar"], split.keep_structured);
+        spec["cronString"] = json!(split.cron_strings);
+    }
+    if edit.intervals != original.intervals {
+        spec["interval"] = edit
+            .intervals
+            .iter()
+            .filter_map(|i| ScheduleEdit::parse_interval(i))
+            .map(|(every, phase)| json!({ "interval": seconds(every), "phase": seconds(phase) }))
+            .collect();
+    }
+    if edit.timezone != original.timezone {
+        spec["timezoneName"] = json!(edit.timezone);
+        spec["timezoneData"] = Value::Null;
+    }
+    if edit.jitter != original.jitter {
+        spec["jitter"] = duration(&edit.jitter);
+    }
+
+    let wf = object_entry(object_entry(schedule, "action"), "startWorkflow");
+    if edit.workflow_type != original.workflow_type {
+        wf["workflowType"] = json!({ "name": edit.workflow_type });
+    }
+    if edit.workflow_id != original.workflow_id {
+        wf["workflowId"] = json!(edit.workflow_id);
+    }
+    if edit.task_queue != original.task_queue {
+        wf["taskQueue"] = json!({ "name": edit.task_queue });
+    }
+    if edit.input != original.input {
+        wf["input"] = payloads_json(input);
+    }
+    if edit.execution_timeout != original.execution_timeout {
+        wf["workflowExecutionTimeout"] = duration(&edit.execution_timeout);
+    }
+    if edit.run_timeout != original.run_timeout {
+        wf["workflowRunTimeout"] = duration(&edit.run_timeout);
+    }
+    if edit.task_timeout != original.task_timeout {
+        wf["workflowTaskTimeout"] = duration(&edit.task_timeout);
+    }
+}
+
+/// Per-status counts from a grouped `workflow-count` response.
+fn status_groups(resp: &Value) -> Vec<(WorkflowStatus, u64)> {
+    status_counts(array(resp, "groups").iter().map(|group| {
+        let value = decode_payloads(&payloads(&group["groupValues"]));
+        (value, int(group, "count"))
+    }))
+}
+
+/// A `{name: "INDEXED_VALUE_TYPE_..."}` map.
+fn indexed_value_types(v: &Value, key: &str) -> HashMap<String, i32> {
+    v.get(key)
+        .and_then(Value::as_object)
+        .map(|map| {
+            map.iter()
+                .map(|(name, value)| {
+                    let value = value
+                        .as_str()
+                        .and_then(enums::IndexedValueType::from_str_name)
+                        .unwrap_or_default();
+                    (name.clone(), value as i32)
+                })
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+fn object_entry<'a>(v: &'a mut Value, key: &str) -> &'a mut Value {
+    if !v.get(key).is_some_and(Value::is_object) {
+        v[key] = json!({});
+    }
+    &mut v[key]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn http_base_url_and_payload_shorthand() {
+        assert_eq!(
+            http_base_url("localhost:7233", false),
+            "http://localhost:7243"
+        );
+        assert_eq!(
+            http_base_url("ns.acct.tmprl.cloud:7233", true),
+            "https://ns.acct.tmprl.cloud:7243"
+        );
+        assert_eq!(
+            http_base_url("https://gateway.internal/temporal/", false),
+            "https://gateway.internal/temporal"
+        );
+
+        let full = json!({"metadata": {"encoding": "anNvbi9wbGFpbg=="}, "data": "eyJhIjoxfQ=="});
+        let shorthand = json!({"a": 1});
+        for value in [full, shorthand] {
+            assert_eq!(decode_payloads(&payloads(&json!([value]))), json!({"a": 1}));
+        }
+    }
+
+    #[test]
+    fn versioning_json_maps_to_domain() {
+        let info = workflow_info(&json!({
+            "versioningInfo": {
+                "behavior": "VERSIONING_BEHAVIOR_AUTO_UPGRADE",
+                "deploymentVersion": {"deploymentName": "orders", "buildId": "b1"},
+                "versioningOverride": {"pinned": {"version": {"deploymentName": "orders", "buildId": "b2"}}},
+            }
+        }));
+        assert_eq!(
+            workflow_versioning(&info),
+            Some(WorkflowVersioning {
+                behavior: "AutoUpgrade".to_string(),
+                version: Some("orders@b1".to_string()),
+                versioning_override: Some("Pinned to orders@b2".to_string()),
+            })
+        );
+        assert_eq!(workflow_versioning(&workflow_info(&json!({}))), None);
+
+        let versioning = task_queue_versioning(
+            Some(&task_queue_versioning_info(&json!({
+                "currentDeploymentVersion": {"deploymentName": "orders", "buildId": "b1"},
+                "rampingDeploymentVersion": {"deploymentName": "orders", "buildId": "b2"},
+                "rampingVersionPercentage": 25,
+            }))),
+            Some(&versioning_rules(&json!({
+                "assignmentRules": [{"rule": {"targetBuildId": "b2", "percentageRamp": {"rampPercentage": 10}}}],
+                "compatibleRedirectRules": [{"rule": {"sourceBuildId": "b0", "targetBuildId": "b1"}}],
+            }))),
+        );
+        assert_eq!(versioning.current_version.as_deref(), Some("orders@b1"));
+        assert_eq!(versioning.ramping_version.as_deref(), Some("orders@b2"));
+        assert_eq!(versioning.ramping_percentage, 25.0);
+        assert_eq!(versioning.assignment_rules, ["b2 (ramp 10%)"]);
+        assert_eq!(versioning.redirect_rules, ["b0 -> b1"]);
+
+        let poller = poller(poller_info(&json!({
+            "identity": "worker@host",
+            "deploymentOptions": {"deploymentName": "orders", "buildId": "b1"},
+        })));
+        assert_eq!(poller.version.as_deref(), Some("orders@b1"));
+    }
+
+    #[test]
+    fn workflow_info_reads_memo() {
+        let info = workflow_info(&json!({
+            "memo": {"fields": {
+                "owner": {"metadata": {"encoding": "anNvbi9wbGFpbg=="}, "data": "InRlYW0i"},
+            }},
+        }));
+        assert_eq!(
+            memo_fields(&info),
+            HashMap::from([("owner".to_string(), json!("team"))])
+        );
+        assert!(memo_fields(&workflow_info(&json!({}))).is_empty());
+    }
+
+    #[test]
+    fn user_metadata_reads_text_payloads() {
+        let metadata = user_metadata_proto(&json!({
+            "summary": {"metadata": {"encoding": "anNvbi9wbGFpbg=="}, "data": "IkNoYXJnZSBjYXJkIg=="},
+            "details": {"step": 2},
+        }));
+        assert_eq!(
+            user_metadata(&metadata),
+            UserMetadata {
+                summary: Some("Charge card".to_string()),
+                details: Some("{\"step\":2}".to_string()),
+            }
+        );
+        assert!(user_metadata(&None).is_empty());
+    }
+
+    #[test]
+    fn grouped_count_json_maps_to_statuses() {
+        let resp = json!({
+            "count": "15",
+            "groups": [
+                {
+                    "groupValues": [{
+                        "metadata": {"encoding": STANDARD.encode("json/plain")},
+                        "data": STANDARD.encode("\"Failed\""),
+                    }],
+                    "count": "3",
+                },
+                {"groupValues": ["Running"], "count": "12"},
+            ],
+        });
+        assert_eq!(
+            status_groups(&resp),
+            vec![(WorkflowStatus::Running, 12), (WorkflowStatus::Failed, 3)]
+        );
+        assert_eq!(
+            grouped_by_status(Some("WorkflowType = 'a' ")),
+            "WorkflowType = 'a' GROUP BY ExecutionStatus"
+        );
+        assert_eq!(grouped_by_status(None), "GROUP BY ExecutionStatus");
+        assert_eq!(
+            grouped_by_status(Some("WorkflowType = 'a' ORDER BY StartTime DESC")),
+            "WorkflowType = 'a' GROUP BY ExecutionStatus"
+        );
+    }
+
+    #[test]
+    fn search_attributes_json_maps_to_domain() {
+        let resp = json!({
+            "customAttributes": {"CustomerId": "INDEXED_VALUE_TYPE_KEYWORD"},
+            "systemAttributes": {
+                "WorkflowType": "INDEXED_VALUE_TYPE_KEYWORD",
+                "StartTime": "INDEXED_VALUE_TYPE_DATETIME",
+                "Future": "INDEXED_VALUE_TYPE_VECTOR",
+            },
+        });
+        let attributes = search_attributes(
+            &indexed_value_types(&resp, "customAttributes"),
+            &indexed_value_types(&resp, "systemAttributes"),
+        );
+        let summary: Vec<_> = attributes
+            .iter()
+            .map(|a| (a.name.as_str(), a.value_type, a.custom))
+            .collect();
+        assert_eq!(
+            summary,
+            [
+                ("CustomerId", Some(SearchAttributeType::Keyword), true),
+                ("Future", None, false),
+                ("StartTime", Some(SearchAttributeType::Datetime), false),
+                ("WorkflowType", Some(SearchAttributeType::Keyword), false),
+            ]
+        );
+        assert_eq!(
+            indexed_value_type(SearchAttributeType::KeywordList).as_str_name(),
+            "INDEXED_VALUE_TYPE_KEYWORD_LIST"
+        );
+    }
+}
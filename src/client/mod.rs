@@ -1,5 +1,14 @@
+pub mod codec;
 pub mod grpc;
+pub mod http;
+pub mod oidc;
+pub mod tls;
 pub mod traits;
+pub mod visibility;
 
+pub use codec::*;
 pub use grpc::*;
+pub use http::*;
+pub use oidc::{DeviceLogin, OidcConfig, OidcSession};
+pub use tls::TlsOptions;
 pub use traits::*;
@@ -0,0 +1,435 @@
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex, OnceLock, RwLock, Weak};
+use std::time::{Duration, Instant};
+
+use serde::Deserialize;
+
+use super::{ClientError, ClientResult};
+
+/// Refresh this long before the access token expires.
+const REFRESH_MARGIN: Duration = Duration::from_secs(60);
+/// Wait before retrying a failed refresh.
+const REFRESH_RETRY: Duration = Duration::from_secs(30);
+
+/// Identity provider settings. With a client secret the client-credentials
+/// grant is used; without one, the device authorization flow.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OidcConfig {
+    pub issuer: String,
+    pub client_id: String,
+    pub client_secret: Option<String>,
+    pub scope: Option<String>,
+    pub audience: Option<String>,
+}
+
+impl OidcConfig {
+    pub fn is_device_flow(&self) -> bool {
+        self.client_secret.is_none()
+    }
+
+    fn cache_key(&self) -> (String, String) {
+        (self.issuer.clone(), self.client_id.clone())
+    }
+}
+
+#[derive(Deserialize)]
+struct Discovery {
+    token_endpoint: String,
+    device_authorization_endpoint: Option<String>,
+}
+
+#[derive(Deserialize)]
+struct TokenResponse {
+    access_token: String,
+    expires_in: Option<u64>,
+    refresh_token: Option<String>,
+}
+
+#[derive(Deserialize)]
+struct ErrorResponse {
+    error: String,
+    error_description: Option<String>,
+}
+
+#[derive(Deserialize)]
+struct DeviceResponse {
+    device_code: String,
+    user_code: String,
+    #[serde(alias = "verification_url")]
+    verification_uri: String,
+    verification_uri_complete: Option<String>,
+    expires_in: u64,
+    interval: Option<u64>,
+}
+
+struct Token {
+    access_token: String,
+    refresh_token: Option<String>,
+    expires_at: Option<Instant>,
+}
+
+/// A logged-in OIDC client whose access token is refreshed in the background
+/// before it expires. Sessions are shared per issuer and client id.
+pub struct OidcSession {
+    config: OidcConfig,
+    http: reqwest::Client,
+    token_endpoint: String,
+    token: RwLock<Token>,
+}
+
+/// A pending device login: the user opens `verification_uri` and enters
+/// `user_code` while [`DeviceLogin::finish`] polls the token endpoint.
+pub struct DeviceLogin {
+    pub user_code: String,
+    pub verification_uri: String,
+    pub verification_uri_complete: Option<String>,
+    config: OidcConfig,
+    http: reqwest::Client,
+    token_endpoint: String,
+    device_code: String,
+    interval: Duration,
+    deadline: Instant,
+}
+
+type Sessions = Mutex<HashMap<(String, String), Arc<OidcSession>>>;
+
+fn sessions() -> &'static Sessions {
+    static SESSIONS: OnceLock<Sessions> = OnceLock::new();
+    SESSIONS.get_or_init(Default::default)
+}
+
+/// The cached session for `config`, if one has logged in.
+pub fn cached_session(config: &OidcConfig) -> Option<Arc<OidcSession>> {
+    sessions()
+        .lock()
+        .unwrap_or_else(|e| e.into_inner())
+        .get(&config.cache_key())
+        .cloned()
+}
+
+/// Returns the cached session or logs in with client credentials. Device
+/// logins need the user, so they must go through [`DeviceLogin`] first.
+pub async fn session(config: &OidcConfig) -> ClientResult<Arc<OidcSession>> {
+    if let Some(session) = cached_session(config) {
+        return Ok(session);
+    }
+    if config.is_device_flow() {
+        return Err(ClientError::ConfigError(format!(
+            "OIDC device login for {} has not been completed",
+            config.issuer
+        )));
+    }
+    let http = reqwest::Client::new();
+    let discovery = discover(&http, &config.issuer).await?;
+    let token = request_token(&http, &discovery.token_endpoint, &credentials_form(config)).await?;
+    Ok(OidcSession::start(
+        config.clone(),
+        http,
+        discovery.token_endpoint,
+        token,
+    ))
+}
+
+impl OidcSession {
+    fn start(
+        config: OidcConfig,
+        http: reqwest::Client,
+        token_endpoint: String,
+        token: TokenResponse,
+    ) -> Arc<Self> {
+        let session = Arc::new(Self {
+            config,
+            http,
+            token_endpoint,
+            token: RwLock::new(Token::from(token)),
+        });
+        sessions()
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .insert(session.config.cache_key(), session.clone());
+        tokio::spawn(refresh_loop(Arc::downgrade(&session)));
+        session
+    }
+
+    /// Current access token.
+    pub fn access_token(&self) -> String {
+        self.token
+            .read()
+            .unwrap_or_else(|e| e.into_inner())
+            .access_token
+            .clone()
+    }
+
+    fn expires_at(&self) -> Option<Instant> {
+        self.token
+            .read()
+            .unwrap_or_else(|e| e.into_inner())
+            .expires_at
+    }
+
+    async fn refresh(&self) -> ClientResult<()> {
+        let refresh_token = self
+            .token
+            .read()
+            .unwrap_or_else(|e| e.into_inner())
+            .refresh_token
+            .clone();
+        let form = match refresh_token {
+            Some(refresh_token) => {
+                let mut form = vec![
+                    ("grant_type", "refresh_token".to_string()),
+                    ("refresh_token", refresh_token),
+                    ("client_id", self.config.client_id.clone()),
+                ];
+                if let Some(secret) = &self.config.client_secret {
+                    form.push(("client_secret", secret.clone()));
+                }
+                form
+            }
+            None if !self.config.is_device_flow() => credentials_form(&self.config),
+            None => {
+                return Err(ClientError::ConfigError(
+                    "no refresh token; log in again when the token expires".into(),
+                ))
+            }
+        };
+        let response = request_token(&self.http, &self.token_endpoint, &form).await?;
+        let mut token = self.token.write().unwrap_or_else(|e| e.into_inner());
+        let previous_refresh = token.refresh_token.take();
+        *token = Token::from(response);
+        // Providers may keep the refresh token valid without returning it.
+        if token.refresh_token.is_none() {
+            token.refresh_token = previous_refresh;
+        }
+        Ok(())
+    }
+}
+
+impl From<TokenResponse> for Token {
+    fn from(response: TokenResponse) -> Self {
+        Self {
+            access_token: response.access_token,
+            refresh_token: response.refresh_token,
+            expires_at: response
+                .expires_in
+                .map(|secs| Instant::now() + Duration::from_secs(secs)),
+        }
+    }
+}
+
+/// Refreshes ahead of expiry until the session is dropped or can no longer
+/// refresh.
+async fn refresh_loop(session: Weak<OidcSession>) {
+    loop {
+        let Some(expires_at) = session.upgrade().and_then(|s| s.expires_at()) else {
+            return;
+        };
+        let wait = refresh_delay(expires_at.saturating_duration_since(Instant::now()));
+        tokio::time::sleep(wait).await;
+        let Some(current) = session.upgrade() else {
+            return;
+        };
+        match current.refresh().await {
+            Ok(()) => tracing::debug!("refreshed OIDC token from {}", current.config.issuer),
+            Err(ClientError::ConfigError(e)) => {
+                tracing::warn!("OIDC token refresh stopped: {}", e);
+                return;
+            }
+            Err(e) => {
+                tracing::warn!("OIDC token refresh failed: {}", e);
+                drop(current);
+                tokio::time::sleep(REFRESH_RETRY).await;
+            }
+        }
+    }
+}
+
+/// How long to wait before refreshing a token that expires in `remaining`.
+fn refresh_delay(remaining: Duration) -> Duration {
+    remaining
+        .saturating_sub(REFRESH_MARGIN)
+        .max(remaining / 2)
+        .max(Duration::from_secs(5))
+}
+
+impl DeviceLogin {
+    /// Asks the provider for a user code.
+    pub async fn start(config: &OidcConfig) -> ClientResult<Self> {
+        let http = reqwest::Client::new();
+        let discovery = discover(&http, &config.issuer).await?;
+        let device_endpoint = discovery.device_authorization_endpoint.ok_or_else(|| {
+            ClientError::ConfigError(format!(
+                "{} does not support the device flow; set a client secret",
+                config.issuer
+            ))
+        })?;
+        let mut form = vec![("client_id", config.client_id.clone())];
+        if let Some(scope) = &config.scope {
+            form.push(("scope", scope.clone()));
+        }
+        if let Some(audience) = &config.audience {
+            form.push(("audience", audience.clone()));
+        }
+        let response = http
+            .post(&device_endpoint)
+            .form(&form)
+            .send()
+            .await
+            .map_err(|e| ClientError::ConnectionError(format!("OIDC device login: {}", e)))?;
+        if !response.status().is_success() {
+            return Err(provider_error(response).await);
+        }
+        let device: DeviceResponse = response
+            .json()
+            .await
+            .map_err(|e| ClientError::ParseError(format!("OIDC device response: {}", e)))?;
+        Ok(Self {
+            user_code: device.user_code,
+            verification_uri: device.verification_uri,
+            verification_uri_complete: device.verification_uri_complete,
+            config: config.clone(),
+            http,
+            token_endpoint: discovery.token_endpoint,
+            device_code: device.device_code,
+            interval: Duration::from_secs(device.interval.unwrap_or(5)),
+            deadline: Instant::now() + Duration::from_secs(device.expires_in),
+        })
+    }
+
+    /// Polls until the user approves the login, then caches the session.
+    pub async fn finish(mut self) -> ClientResult<Arc<OidcSession>> {
+        let form = [
+            (
+                "grant_type",
+                "urn:ietf:params:oauth:grant-type:device_code".to_string(),
+            ),
+            ("device_code", self.device_code.clone()),
+            ("client_id", self.config.client_id.clone()),
+        ];
+        loop {
+            if Instant::now() >= self.deadline {
+                return Err(ClientError::RequestFailed(
+                    "OIDC device login expired".into(),
+                ));
+            }
+            tokio::time::sleep(self.interval).await;
+            let response = self
+                .http
+                .post(&self.token_endpoint)
+                .form(&form)
+                .send()
+                .await
+                .map_err(|e| ClientError::ConnectionError(format!("OIDC token: {}", e)))?;
+            if response.status().is_success() {
+                let token: TokenResponse = response
+                    .json()
+                    .await
+                    .map_err(|e| ClientError::ParseError(format!("OIDC token response: {}", e)))?;
+                return Ok(OidcSession::start(
+                    self.config,
+                    self.http,
+                    self.token_endpoint,
+                    token,
+                ));
+            }
+            let body = response.text().await.unwrap_or_default();
+            match serde_json::from_str::<ErrorResponse>(&body) {
+                Ok(e) if e.error == "authorization_pending" => {}
+                Ok(e) if e.error == "slow_down" => self.interval += Duration::from_secs(5),
+                Ok(e) => {
+                    return Err(ClientError::RequestFailed(format!(
+                        "OIDC device login: {}",
+                        e.error_description.unwrap_or(e.error)
+                    )))
+                }
+                Err(_) => {
+                    return Err(ClientError::RequestFailed(format!(
+                        "OIDC device login: {}",
+                        body
+                    )))
+                }
+            }
+        }
+    }
+}
+
+async fn discover(http: &reqwest::Client, issuer: &str) -> ClientResult<Discovery> {
+    let url = format!(
+        "{}/.well-known/openid-configuration",
+        issuer.trim_end_matches('/')
+    );
+    http.get(&url)
+        .send()
+        .await
+        .and_then(|r| r.error_for_status())
+        .map_err(|e| ClientError::ConnectionError(format!("OIDC discovery: {}", e)))?
+        .json()
+        .await
+        .map_err(|e| ClientError::ParseError(format!("OIDC discovery: {}", e)))
+}
+
+fn credentials_form(config: &OidcConfig) -> Vec<(&'static str, String)> {
+    let mut form = vec![
+        ("grant_type", "client_credentials".to_string()),
+        ("client_id", config.client_id.clone()),
+        (
+            "client_secret",
+            config.client_secret.clone().unwrap_or_default(),
+        ),
+    ];
+    if let Some(scope) = &config.scope {
+        form.push(("scope", scope.clone()));
+    }
+    if let Some(audience) = &config.audience {
+        form.push(("audience", audience.clone()));
+    }
+    form
+}
+
+async fn request_token(
+    http: &reqwest::Client,
+    endpoint: &str,
+    form: &[(&str, String)],
+) -> ClientResult<TokenResponse> {
+    let response = http
+        .post(endpoint)
+        .form(form)
+        .send()
+        .await
+        .map_err(|e| ClientError::ConnectionError(format!("OIDC token: {}", e)))?;
+    if !response.status().is_success() {
+        return Err(provider_error(response).await);
+    }
+    response
+        .json()
+        .await
+        .map_err(|e| ClientError::ParseError(format!("OIDC token response: {}", e)))
+}
+
+async fn provider_error(response: reqwest::Response) -> ClientError {
+    let status = response.status();
+    let body = response.text().await.unwrap_or_default();
+    let detail = serde_json::from_str::<ErrorResponse>(&body)
+        .map(|e| e.error_description.unwrap_or(e.error))
+        .unwrap_or(body);
+    ClientError::RequestFailed(format!("OIDC provider returned {}: {}", status, detail))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn refreshes_ahead_of_expiry() {
+        assert_eq!(
+            refresh_delay(Duration::from_secs(3600)),
+            Duration::from_secs(3540)
+        );
+        // Short-lived tokens refresh halfway through instead of immediately.
+        assert_eq!(
+            refresh_delay(Duration::from_secs(90)),
+            Duration::from_secs(45)
+        );
+        assert_eq!(refresh_delay(Duration::ZERO), Duration::from_secs(5));
+    }
+}
@@ -0,0 +1,193 @@
+use std::future::Future;
+use std::io;
+use std::pin::Pin;
+use std::sync::Arc;
+use std::task::{Context, Poll};
+
+use hyper_util::rt::TokioIo;
+use rustls::client::danger::{HandshakeSignatureValid, ServerCertVerified, ServerCertVerifier};
+use rustls::crypto::CryptoProvider;
+use rustls::pki_types::{CertificateDer, ServerName, UnixTime};
+use rustls::{ClientConfig, DigitallySignedStruct, SignatureScheme};
+use tokio::net::TcpStream;
+use tokio_rustls::client::TlsStream;
+use tokio_rustls::TlsConnector;
+use tonic::transport::Uri;
+
+use super::{ClientError, ClientResult};
+
+/// TLS settings for the gRPC channel beyond the TLS-or-not decision.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct TlsOptions {
+    /// Client certificate and key for mTLS.
+    pub cert: Option<String>,
+    pub key: Option<String>,
+    /// PEM bundle trusted in addition to the system roots.
+    pub ca: Option<String>,
+    /// Name checked against the server certificate instead of the host.
+    pub server_name: Option<String>,
+    /// Accept any server certificate. For test clusters only.
+    pub insecure_skip_verify: bool,
+}
+
+impl TlsOptions {
+    /// Whether any option only makes sense over TLS, so it should be used
+    /// even for local addresses.
+    pub fn requires_tls(&self) -> bool {
+        self.ca.is_some() || self.server_name.is_some() || self.insecure_skip_verify
+    }
+
+    /// Reads the client certificate and key, when both are set.
+    pub(super) fn identity(&self) -> ClientResult<Option<(Vec<u8>, Vec<u8>)>> {
+        let (Some(cert_path), Some(key_path)) = (&self.cert, &self.key) else {
+            return Ok(None);
+        };
+        let cert = std::fs::read(cert_path).map_err(|e| {
+            ClientError::ConfigError(format!("failed to read TLS cert {}: {}", cert_path, e))
+        })?;
+        let key = std::fs::read(key_path).map_err(|e| {
+            ClientError::ConfigError(format!("failed to read TLS key {}: {}", key_path, e))
+        })?;
+        Ok(Some((cert, key)))
+    }
+
+    pub(super) fn ca_pem(&self) -> ClientResult<Option<Vec<u8>>> {
+        self.ca
+            .as_ref()
+            .map(|path| {
+                std::fs::read(path).map_err(|e| {
+                    ClientError::ConfigError(format!("failed to read TLS CA {}: {}", path, e))
+                })
+            })
+            .transpose()
+    }
+}
+
+/// Connector that does its own TLS handshake without verifying the server
+/// certificate, for `--tls-insecure-skip-verify`. tonic's built-in TLS has no
+/// such switch, so the endpoint is dialed as `http://` and wrapped here.
+#[derive(Clone)]
+pub(super) struct InsecureConnector {
+    config: Arc<ClientConfig>,
+    server_name: Option<String>,
+}
+
+impl InsecureConnector {
+    pub(super) fn new(options: &TlsOptions) -> ClientResult<Self> {
+        let provider = Arc::new(rustls::crypto::ring::default_provider());
+        let builder = ClientConfig::builder_with_provider(provider.clone())
+            .with_safe_default_protocol_versions()
+            .map_err(|e| ClientError::ConfigError(format!("TLS config error: {}", e)))?
+            .dangerous()
+            .with_custom_certificate_verifier(Arc::new(NoVerifier(provider)));
+        let mut config = match options.identity()? {
+            Some((cert, key)) => {
+                let certs = rustls_pemfile::certs(&mut cert.as_slice())
+                    .collect::<Result<Vec<_>, _>>()
+                    .map_err(|e| ClientError::ConfigError(format!("invalid TLS cert: {}", e)))?;
+                let key = rustls_pemfile::private_key(&mut key.as_slice())
+                    .map_err(|e| ClientError::ConfigError(format!("invalid TLS key: {}", e)))?
+                    .ok_or_else(|| ClientError::ConfigError("no private key in TLS key".into()))?;
+                builder
+                    .with_client_auth_cert(certs, key)
+                    .map_err(|e| ClientError::ConfigError(format!("TLS config error: {}", e)))?
+            }
+            None => builder.with_no_client_auth(),
+        };
+        config.alpn_protocols = vec![b"h2".to_vec()];
+        Ok(Self {
+            config: Arc::new(config),
+            server_name: options.server_name.clone(),
+        })
+    }
+}
+
+impl tower_service::Service<Uri> for InsecureConnector {
+    type Response = TokioIo<TlsStream<TcpStream>>;
+    type Error = io::Error;
+    type Future = Pin<Box<dyn Future<Output = io::Result<Self::Response>> + Send>>;
+
+    fn poll_ready(&mut self, _cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        Poll::Ready(Ok(()))
+    }
+
+    fn call(&mut self, uri: Uri) -> Self::Future {
+        let connector = TlsConnector::from(self.config.clone());
+        let server_name = self.server_name.clone();
+        Box::pin(async move {
+            let host = uri.host().unwrap_or("localhost").to_string();
+            let port = uri.port_u16().unwrap_or(443);
+            let tcp = TcpStream::connect((host.trim_matches(['[', ']']), port)).await?;
+            let name = ServerName::try_from(server_name.unwrap_or(host))
+                .map_err(|e| io::Error::new(io::ErrorKind::InvalidInput, e))?;
+            let tls = connector.connect(name, tcp).await?;
+            Ok(TokioIo::new(tls))
+        })
+    }
+}
+
+/// Accepts any certificate but still checks handshake signatures.
+#[derive(Debug)]
+struct NoVerifier(Arc<CryptoProvider>);
+
+impl ServerCertVerifier for NoVerifier {
+    fn verify_server_cert(
+        &self,
+        _end_entity: &CertificateDer<'_>,
+        _intermediates: &[CertificateDer<'_>],
+        _server_name: &ServerName<'_>,
+        _ocsp_response: &[u8],
+        _now: UnixTime,
+    ) -> Result<ServerCertVerified, rustls::Error> {
+        Ok(ServerCertVerified::assertion())
+    }
+
+    fn verify_tls12_signature(
+        &self,
+        message: &[u8],
+        cert: &CertificateDer<'_>,
+        dss: &DigitallySignedStruct,
+    ) -> Result<HandshakeSignatureValid, rustls::Error> {
+        rustls::crypto::verify_tls12_signature(
+            message,
+            cert,
+            dss,
+            &self.0.signature_verification_algorithms,
+        )
+    }
+
+    fn verify_tls13_signature(
+        &self,
+        message: &[u8],
+        cert: &CertificateDer<'_>,
+        dss: &DigitallySignedStruct,
+    ) -> Result<HandshakeSignatureValid, rustls::Error> {
+        rustls::crypto::verify_tls13_signature(
+            message,
+            cert,
+            dss,
+            &self.0.signature_verification_algorithms,
+        )
+    }
+
+    fn supported_verify_schemes(&self) -> Vec<SignatureScheme> {
+        self.0.signature_verification_algorithms.supported_schemes()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn insecure_connector_negotiates_h2() {
+        let options = TlsOptions {
+            insecure_skip_verify: true,
+            ..Default::default()
+        };
+        assert!(options.requires_tls());
+        assert!(!TlsOptions::default().requires_tls());
+        let connector = InsecureConnector::new(&options).unwrap();
+        assert_eq!(connector.config.alpn_protocols, vec![b"h2".to_vec()]);
+    }
+}
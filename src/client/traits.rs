@@ -9,22 +9,32 @@ pub enum ClientError {
     ConnectionError(String),
     #[error("not found: {0}")]
     NotFound(String),
+    #[error("not implemented by the server: {0}")]
+    Unimplemented(String),
     #[error("request failed: {0}")]
     RequestFailed(String),
     #[error("parse error: {0}")]
     ParseError(String),
     #[error("config error: {0}")]
     ConfigError(String),
+    #[error("conflict: {0}")]
+    Conflict(String),
+    #[error("invalid argument: {0}")]
+    InvalidArgument(String),
     #[error("timeout")]
     Timeout,
 }
 
 pub type ClientResult<T> = Result<T, ClientError>;
 
+/// Read-only access to namespaces, executions and schedules. Every backend
+/// has to provide this much for the views to render.
 #[async_trait]
-pub trait TemporalClient: Send + Sync {
+pub trait VisibilityReader: Send + Sync {
     async fn list_namespaces(&self) -> ClientResult<Vec<Namespace>>;
 
+    async fn get_cluster_info(&self) -> ClientResult<ClusterInfo>;
+
     async fn list_workflows(
         &self,
         namespace: &str,
@@ -33,6 +43,15 @@ pub trait TemporalClient: Send + Sync {
         next_page_token: Vec<u8>,
     ) -> ClientResult<(Vec<WorkflowSummary>, Vec<u8>)>;
 
+    /// Runs past retention, from the namespace's archival visibility store.
+    async fn list_archived_workflows(
+        &self,
+        namespace: &str,
+        query: Option<&str>,
+        page_size: i32,
+        next_page_token: Vec<u8>,
+    ) -> ClientResult<(Vec<WorkflowSummary>, Vec<u8>)>;
+
     async fn describe_workflow(
         &self,
         namespace: &str,
@@ -47,57 +66,48 @@ pub trait TemporalClient: Send + Sync {
         run_id: Option<&str>,
     ) -> ClientResult<Vec<HistoryEvent>>;
 
-    async fn count_workflows(&self, namespace: &str, query: Option<&str>) -> ClientResult<u64>;
-
-    async fn cancel_workflow(
+    async fn query_workflow(
         &self,
         namespace: &str,
         workflow_id: &str,
         run_id: Option<&str>,
-    ) -> ClientResult<()>;
+        query_type: &str,
+    ) -> ClientResult<serde_json::Value>;
 
-    async fn terminate_workflow(
-        &self,
-        namespace: &str,
-        workflow_id: &str,
-        run_id: Option<&str>,
-        reason: &str,
-    ) -> ClientResult<()>;
+    async fn count_workflows(&self, namespace: &str, query: Option<&str>) -> ClientResult<u64>;
 
-    async fn signal_workflow(
+    /// Counts of the workflows matching `query`, per status that has any.
+    async fn count_workflows_by_status(
         &self,
         namespace: &str,
-        workflow_id: &str,
-        run_id: Option<&str>,
-        signal_name: &str,
-        input: Option<&str>,
-    ) -> ClientResult<()>;
+        query: Option<&str>,
+    ) -> ClientResult<Vec<(WorkflowStatus, u64)>>;
 
     async fn list_schedules(
         &self,
         namespace: &str,
         query: Option<&str>,
-    ) -> ClientResult<Vec<Schedule>>;
+        page_size: i32,
+        next_page_token: Vec<u8>,
+    ) -> ClientResult<(Vec<Schedule>, Vec<u8>)>;
 
     async fn describe_schedule(&self, namespace: &str, schedule_id: &str)
         -> ClientResult<Schedule>;
 
-    async fn patch_schedule(
+    /// The `DescribeWorkflowExecution` response as JSON, for the Raw tab.
+    async fn describe_workflow_raw(
         &self,
         namespace: &str,
-        schedule_id: &str,
-        pause: bool,
-    ) -> ClientResult<()>;
-
-    async fn trigger_schedule(&self, namespace: &str, schedule_id: &str) -> ClientResult<()>;
-
-    async fn delete_schedule(&self, namespace: &str, schedule_id: &str) -> ClientResult<()>;
+        workflow_id: &str,
+        run_id: Option<&str>,
+    ) -> ClientResult<serde_json::Value>;
 
-    async fn describe_task_queue(
+    /// The `DescribeSchedule` response as JSON, for the Raw tab.
+    async fn describe_schedule_raw(
         &self,
         namespace: &str,
-        task_queue: &str,
-    ) -> ClientResult<TaskQueueInfo>;
+        schedule_id: &str,
+    ) -> ClientResult<serde_json::Value>;
 
     async fn list_activity_executions(
         &self,
@@ -120,6 +130,57 @@ pub trait TemporalClient: Send + Sync {
         query: Option<&str>,
     ) -> ClientResult<u64>;
 
+    async fn check_activity_support(&self, namespace: &str) -> ClientResult<bool>;
+
+    /// System and custom search attributes, sorted by name.
+    async fn list_search_attributes(&self, namespace: &str) -> ClientResult<Vec<SearchAttribute>>;
+}
+
+/// Mutating operations on workflow and standalone activity executions.
+#[async_trait]
+pub trait ExecutionAdmin: Send + Sync {
+    async fn cancel_workflow(
+        &self,
+        namespace: &str,
+        workflow_id: &str,
+        run_id: Option<&str>,
+    ) -> ClientResult<()>;
+
+    async fn terminate_workflow(
+        &self,
+        namespace: &str,
+        workflow_id: &str,
+        run_id: Option<&str>,
+        reason: &str,
+    ) -> ClientResult<()>;
+
+    /// The type, task queue, input and options `run_id` was started with,
+    /// for [`start_workflow_from`](Self::start_workflow_from).
+    async fn start_attributes(
+        &self,
+        namespace: &str,
+        workflow_id: &str,
+        run_id: Option<&str>,
+    ) -> ClientResult<StartAttributes>;
+
+    /// Starts a new run of `workflow_id` from `attributes`. Returns the new
+    /// run id.
+    async fn start_workflow_from(
+        &self,
+        namespace: &str,
+        workflow_id: &str,
+        attributes: StartAttributes,
+    ) -> ClientResult<String>;
+
+    async fn signal_workflow(
+        &self,
+        namespace: &str,
+        workflow_id: &str,
+        run_id: Option<&str>,
+        signal_name: &str,
+        input: Option<&str>,
+    ) -> ClientResult<()>;
+
     async fn request_cancel_activity_execution(
         &self,
         namespace: &str,
@@ -142,5 +203,173 @@ pub trait TemporalClient: Send + Sync {
         run_id: &str,
     ) -> ClientResult<()>;
 
-    async fn check_activity_support(&self, namespace: &str) -> ClientResult<bool>;
+    /// Sends the options in `edit` that differ from `draft`'s to the pending
+    /// activity `draft` was opened for.
+    async fn update_activity_options(
+        &self,
+        namespace: &str,
+        draft: &ActivityOptionsDraft,
+        edit: &ActivityOptionsEdit,
+    ) -> ClientResult<()>;
+
+    /// Starts a server-side batch job applying `operation` to `target`.
+    /// Returns the job id.
+    async fn start_batch_operation(
+        &self,
+        namespace: &str,
+        operation: BatchOperation,
+        target: &BatchTarget,
+        reason: &str,
+    ) -> ClientResult<String>;
+}
+
+#[async_trait]
+pub trait ScheduleAdmin: Send + Sync {
+    /// Pauses or unpauses, recording `note` on the schedule; an empty note
+    /// records a default one.
+    async fn patch_schedule(
+        &self,
+        namespace: &str,
+        schedule_id: &str,
+        pause: bool,
+        note: &str,
+    ) -> ClientResult<()>;
+
+    async fn trigger_schedule(&self, namespace: &str, schedule_id: &str) -> ClientResult<()>;
+
+    async fn delete_schedule(&self, namespace: &str, schedule_id: &str) -> ClientResult<()>;
+
+    /// The schedule's editable fields and current conflict token.
+    async fn schedule_draft(
+        &self,
+        namespace: &str,
+        schedule_id: &str,
+    ) -> ClientResult<ScheduleDraft>;
+
+    /// Applies `edit` to the schedule, failing with [`ClientError::Conflict`]
+    /// if it changed since `conflict_token` was read.
+    async fn update_schedule(
+        &self,
+        namespace: &str,
+        schedule_id: &str,
+        edit: &ScheduleEdit,
+        conflict_token: &[u8],
+    ) -> ClientResult<()>;
+}
+
+/// Namespace-level administration through the operator service.
+#[async_trait]
+pub trait NamespaceAdmin: Send + Sync {
+    async fn add_search_attribute(
+        &self,
+        namespace: &str,
+        name: &str,
+        value_type: SearchAttributeType,
+    ) -> ClientResult<()>;
+
+    async fn register_namespace(
+        &self,
+        name: &str,
+        description: &str,
+        retention: std::time::Duration,
+    ) -> ClientResult<()>;
+
+    /// Changes only the fields given.
+    async fn update_namespace(
+        &self,
+        name: &str,
+        description: Option<&str>,
+        retention: Option<std::time::Duration>,
+    ) -> ClientResult<()>;
+
+    async fn deprecate_namespace(&self, name: &str) -> ClientResult<()>;
+}
+
+#[async_trait]
+pub trait TaskQueueReader: Send + Sync {
+    async fn describe_task_queue(
+        &self,
+        namespace: &str,
+        task_queue: &str,
+    ) -> ClientResult<TaskQueueInfo>;
+}
+
+/// A backend as seen by the UI. Only [`VisibilityReader`] is required; the
+/// other capabilities are optional so partial backends (mocks, recorded
+/// replays, REST gateways) can leave them out and the matching operations
+/// are disabled.
+pub trait TemporalClient: VisibilityReader {
+    fn execution_admin(&self) -> Option<&dyn ExecutionAdmin> {
+        None
+    }
+
+    fn schedule_admin(&self) -> Option<&dyn ScheduleAdmin> {
+        None
+    }
+
+    fn task_queue_reader(&self) -> Option<&dyn TaskQueueReader> {
+        None
+    }
+
+    fn namespace_admin(&self) -> Option<&dyn NamespaceAdmin> {
+        None
+    }
+
+    fn capabilities(&self) -> Capabilities {
+        Capabilities {
+            execution_admin: self.execution_admin().is_some(),
+            schedule_admin: self.schedule_admin().is_some(),
+            task_queue_reader: self.task_queue_reader().is_some(),
+            namespace_admin: self.namespace_admin().is_some(),
+        }
+    }
+
+    /// True while the client is re-establishing a lost connection.
+    fn is_reconnecting(&self) -> bool {
+        false
+    }
+
+    /// True once reconnecting has given up; requests fail until
+    /// [`reconnect`](Self::reconnect) is called.
+    fn is_disconnected(&self) -> bool {
+        false
+    }
+
+    /// Starts reconnecting again after [`is_disconnected`](Self::is_disconnected).
+    fn reconnect(&self) {}
+
+    /// Codec server failures since the last call. The payloads they were
+    /// for are shown still encoded.
+    fn take_decode_errors(&self) -> Vec<String> {
+        vec![]
+    }
+}
+
+/// A run's start event fields, as fetched by
+/// [`ExecutionAdmin::start_attributes`] in the form the same client's
+/// [`ExecutionAdmin::start_workflow_from`] sends back.
+#[derive(Debug, Clone)]
+pub enum StartAttributes {
+    Grpc(Box<crate::proto::temporal::api::history::v1::WorkflowExecutionStartedEventAttributes>),
+    Http(serde_json::Map<String, serde_json::Value>),
+}
+
+/// Which optional traits the active client implements.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Capabilities {
+    pub execution_admin: bool,
+    pub schedule_admin: bool,
+    pub task_queue_reader: bool,
+    pub namespace_admin: bool,
+}
+
+impl Default for Capabilities {
+    fn default() -> Self {
+        Self {
+            execution_admin: true,
+            schedule_admin: true,
+            task_queue_reader: true,
+            namespace_admin: true,
+        }
+    }
 }
@@ -0,0 +1,238 @@
+//! Listing for servers without advanced visibility, which only have the
+//! `ListOpen`/`ListClosedWorkflowExecutions` APIs. Simple queries translate
+//! to their filters; anything else is refused rather than ignored.
+
+use std::collections::HashSet;
+use std::sync::Mutex;
+
+use chrono::{DateTime, Utc};
+
+use super::ClientError;
+use crate::domain::{WorkflowStatus, WorkflowSummary};
+
+/// A query made only of `AND`ed clauses the standard list APIs can serve.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct StandardQuery {
+    pub status: Option<WorkflowStatus>,
+    pub workflow_id: Option<String>,
+    pub workflow_type: Option<String>,
+    pub earliest: Option<DateTime<Utc>>,
+    pub latest: Option<DateTime<Utc>>,
+}
+
+impl StandardQuery {
+    pub fn parse(query: &str) -> Result<Self, String> {
+        let query = match query.to_ascii_uppercase().find("ORDER BY") {
+            Some(at) => &query[..at],
+            None => query,
+        };
+        let mut out = StandardQuery::default();
+        for clause in split_and(query) {
+            let clause = clause.trim().trim_matches(|c| c == '(' || c == ')').trim();
+            if clause.is_empty() {
+                continue;
+            }
+            let unsupported = || {
+                format!(
+                    "`{}` needs advanced visibility; this server only filters by \
+                     ExecutionStatus, WorkflowId, WorkflowType and StartTime",
+                    clause
+                )
+            };
+            let (field, op, value) = split_clause(clause).ok_or_else(unsupported)?;
+            match (field, op) {
+                ("ExecutionStatus", "=") => {
+                    out.status = Some(WorkflowStatus::parse(value).ok_or_else(unsupported)?);
+                }
+                ("WorkflowId", "=") => out.workflow_id = Some(value.to_string()),
+                ("WorkflowType", "=") => out.workflow_type = Some(value.to_string()),
+                ("StartTime", ">" | ">=") => {
+                    out.earliest = Some(time(value).ok_or_else(unsupported)?)
+                }
+                ("StartTime", "<" | "<=") => {
+                    out.latest = Some(time(value).ok_or_else(unsupported)?)
+                }
+                _ => return Err(unsupported()),
+            }
+        }
+        Ok(out)
+    }
+
+    pub fn includes_open(&self) -> bool {
+        matches!(self.status, None | Some(WorkflowStatus::Running))
+    }
+
+    pub fn includes_closed(&self) -> bool {
+        self.status != Some(WorkflowStatus::Running)
+    }
+
+    /// The list APIs take one filter besides the time range, so the rest are
+    /// checked on each result; the time range too, for lists that ignore it.
+    pub fn matches(&self, wf: &WorkflowSummary) -> bool {
+        self.status.as_ref().is_none_or(|s| *s == wf.status)
+            && self.earliest.is_none_or(|t| wf.start_time >= t)
+            && self.latest.is_none_or(|t| wf.start_time <= t)
+            && self
+                .workflow_id
+                .as_ref()
+                .is_none_or(|id| *id == wf.workflow_id)
+            && self
+                .workflow_type
+                .as_ref()
+                .is_none_or(|t| *t == wf.workflow_type)
+    }
+}
+
+/// Whether a `ListWorkflowExecutions` error, for a request with `query`,
+/// shows by its status code that the server has no advanced visibility.
+/// `InvalidArgument` may just mean a bad query, so it only counts for a
+/// request without one; clients probe with an empty query to tell.
+pub fn is_unsupported(err: &ClientError, query: Option<&str>) -> bool {
+    match err {
+        ClientError::Unimplemented(_) => true,
+        ClientError::InvalidArgument(_) => query.is_none_or(|q| q.trim().is_empty()),
+        _ => false,
+    }
+}
+
+/// Whether a failed list is worth probing with an empty query.
+pub fn needs_probe(err: &ClientError, query: Option<&str>) -> bool {
+    matches!(err, ClientError::InvalidArgument(_)) && !is_unsupported(err, query)
+}
+
+/// Namespaces found to lack advanced visibility. Forgotten when the
+/// connection drops, since the server may come back upgraded.
+#[derive(Debug, Default)]
+pub struct StandardNamespaces(Mutex<HashSet<String>>);
+
+impl StandardNamespaces {
+    pub fn contains(&self, namespace: &str) -> bool {
+        self.lock().contains(namespace)
+    }
+
+    pub fn insert(&self, namespace: &str) {
+        self.lock().insert(namespace.to_string());
+    }
+
+    pub fn clear(&self) {
+        self.lock().clear();
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, HashSet<String>> {
+        self.0.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+/// Splits on `AND` outside quotes. An `OR` stays inside its clause, which
+/// then fails to parse.
+fn split_and(query: &str) -> Vec<&str> {
+    let mut parts = Vec::new();
+    let mut quote = None;
+    let mut start = 0;
+    let bytes = query.as_bytes();
+    let mut i = 0;
+    while i < bytes.len() {
+        let c = bytes[i];
+        match quote {
+            Some(q) if c == q => quote = None,
+            Some(_) => {}
+            None if c == b'\'' || c == b'"' => quote = Some(c),
+            None if bytes[i..].len() >= 5 && bytes[i..i + 5].eq_ignore_ascii_case(b" and ") => {
+                parts.push(&query[start..i]);
+                i += 5;
+                start = i;
+                continue;
+            }
+            None => {}
+        }
+        i += 1;
+    }
+    parts.push(&query[start..]);
+    parts
+}
+
+/// `Field op 'value'` with the quotes removed.
+fn split_clause(clause: &str) -> Option<(&str, &str, &str)> {
+    let at = clause.find(['=', '>', '<', '!'])?;
+    let field = clause[..at].trim();
+    let rest = &clause[at..];
+    let op_len = if rest[1..].starts_with('=') { 2 } else { 1 };
+    let (op, value) = rest.split_at(op_len);
+    let value = value.trim();
+    let unquoted = value
+        .strip_prefix('\'')
+        .and_then(|v| v.strip_suffix('\''))
+        .or_else(|| value.strip_prefix('"').and_then(|v| v.strip_suffix('"')))?;
+    let single = !field.contains(' ') && !unquoted.contains(['\'', '"']);
+    single.then_some((field, op, unquoted))
+}
+
+fn time(value: &str) -> Option<DateTime<Utc>> {
+    DateTime::parse_from_rfc3339(value)
+        .ok()
+        .map(|t| t.with_timezone(&Utc))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn simple_queries_map_to_standard_filters() {
+        let query = StandardQuery::parse(
+            "(WorkflowType = 'Order' AND ExecutionStatus = \"Failed\") \
+             AND StartTime > '2024-01-01T11:00:00Z' ORDER BY StartTime DESC",
+        )
+        .unwrap();
+        assert_eq!(query.workflow_type.as_deref(), Some("Order"));
+        assert_eq!(query.status, Some(WorkflowStatus::Failed));
+        assert!(query.earliest.is_some());
+        assert!(!query.includes_open() && query.includes_closed());
+
+        let running = StandardQuery::parse("ExecutionStatus = 'Running'").unwrap();
+        assert!(running.includes_open() && !running.includes_closed());
+        assert_eq!(
+            StandardQuery::parse("  ").unwrap(),
+            StandardQuery::default()
+        );
+
+        for query in [
+            "WorkflowId = 'a' OR WorkflowId = 'b'",
+            "CustomKeyword = 'x'",
+            "WorkflowType != 'Order'",
+            "WorkflowId = 'a and b' AND TaskQueue = 'q'",
+        ] {
+            assert!(StandardQuery::parse(query).is_err(), "{}", query);
+        }
+        assert_eq!(
+            StandardQuery::parse("WorkflowId = 'a and b'")
+                .unwrap()
+                .workflow_id
+                .as_deref(),
+            Some("a and b")
+        );
+    }
+
+    #[test]
+    fn only_status_codes_mark_a_server_without_advanced_visibility() {
+        let bad = ClientError::InvalidArgument("operation is not supported".to_string());
+        assert!(is_unsupported(
+            &ClientError::Unimplemented(String::new()),
+            Some("a")
+        ));
+        assert!(!is_unsupported(&bad, Some("WorkflowType = 'a'")));
+        assert!(needs_probe(&bad, Some("WorkflowType = 'a'")));
+        assert!(is_unsupported(&bad, None));
+        assert!(!needs_probe(&bad, Some(" ")));
+        assert!(!is_unsupported(
+            &ClientError::RequestFailed("elasticsearch".to_string()),
+            None
+        ));
+
+        let namespaces = StandardNamespaces::default();
+        namespaces.insert("legacy");
+        assert!(namespaces.contains("legacy") && !namespaces.contains("prod"));
+        namespaces.clear();
+        assert!(!namespaces.contains("legacy"));
+    }
+}
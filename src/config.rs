@@ -1,53 +1,697 @@
-use clap::Parser;
-use serde::Deserialize;
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::time::SystemTime;
 
-#[derive(Parser, Debug)]
+use clap::parser::ValueSource;
+use clap::{ArgMatches, Args, CommandFactory, FromArgMatches, Parser, Subcommand};
+use serde::{Deserialize, Deserializer};
+
+use crate::client::{OidcConfig, TlsOptions};
+use crate::theme::ColorMode;
+
+const DEFAULT_ADDRESS: &str = "localhost:7233";
+const DEFAULT_NAMESPACE: &str = "default";
+
+#[derive(Parser, Debug, Clone)]
 #[command(name = "t9s", about = "k9s-style terminal UI for Temporal")]
 pub struct Cli {
     /// Temporal server address (host:port)
-    #[arg(long, env = "TEMPORAL_ADDRESS", default_value = "localhost:7233")]
+    #[arg(
+        long,
+        global = true,
+        env = "TEMPORAL_ADDRESS",
+        default_value = DEFAULT_ADDRESS
+    )]
     pub address: String,
 
     /// Temporal namespace
-    #[arg(long, env = "TEMPORAL_NAMESPACE", default_value = "default")]
+    #[arg(
+        long,
+        global = true,
+        env = "TEMPORAL_NAMESPACE",
+        default_value = DEFAULT_NAMESPACE
+    )]
     pub namespace: String,
 
     /// Temporal API key for authentication
-    #[arg(long, env = "TEMPORAL_API_KEY")]
+    #[arg(long, global = true, env = "TEMPORAL_API_KEY")]
     pub api_key: Option<String>,
 
     /// Path to TLS client certificate (for mTLS)
-    #[arg(long, env = "TEMPORAL_TLS_CERT")]
+    #[arg(long, global = true, env = "TEMPORAL_TLS_CERT")]
     pub tls_cert: Option<String>,
 
     /// Path to TLS client key (for mTLS)
-    #[arg(long, env = "TEMPORAL_TLS_KEY")]
+    #[arg(long, global = true, env = "TEMPORAL_TLS_KEY")]
     pub tls_key: Option<String>,
 
+    /// Path to a PEM CA bundle to trust for the server certificate
+    #[arg(long, global = true, env = "TEMPORAL_TLS_CA")]
+    pub tls_ca: Option<String>,
+
+    /// Server name to verify the certificate against, when it differs from
+    /// the address host
+    #[arg(long, global = true, env = "TEMPORAL_TLS_SERVER_NAME")]
+    pub tls_server_name: Option<String>,
+
+    /// Accept any server certificate (test clusters only)
+    #[arg(long, global = true, env = "TEMPORAL_TLS_INSECURE_SKIP_VERIFY")]
+    pub tls_insecure_skip_verify: bool,
+
+    /// OIDC issuer URL; authenticates with tokens from this identity provider
+    /// instead of --api-key
+    #[arg(long, global = true, env = "T9S_OIDC_ISSUER")]
+    pub oidc_issuer: Option<String>,
+
+    /// OIDC client id
+    #[arg(long, global = true, env = "T9S_OIDC_CLIENT_ID")]
+    pub oidc_client_id: Option<String>,
+
+    /// OIDC client secret; selects the client-credentials grant instead of
+    /// the device flow
+    #[arg(long, global = true, env = "T9S_OIDC_CLIENT_SECRET")]
+    pub oidc_client_secret: Option<String>,
+
+    /// Space-separated OIDC scopes to request
+    #[arg(long, global = true, env = "T9S_OIDC_SCOPE")]
+    pub oidc_scope: Option<String>,
+
+    /// OIDC audience, for providers that require one
+    #[arg(long, global = true, env = "T9S_OIDC_AUDIENCE")]
+    pub oidc_audience: Option<String>,
+
+    /// Codec server URL used to decode payloads (e.g. http://localhost:8081)
+    #[arg(long, global = true, env = "TEMPORAL_CODEC_ENDPOINT")]
+    pub codec_endpoint: Option<String>,
+
+    /// Authorization header value sent to the codec server
+    #[arg(long, global = true, env = "TEMPORAL_CODEC_AUTH")]
+    pub codec_auth: Option<String>,
+
+    /// Wire protocol: grpc, http (Temporal's HTTP API, default port 7243) or
+    /// auto (gRPC, falling back to HTTP when it cannot connect)
+    #[arg(long, global = true, env = "TEMPORAL_TRANSPORT", value_enum, default_value_t = Transport::Grpc)]
+    pub transport: Transport,
+
+    /// Connection profile from the config file's [[profiles]]
+    #[arg(long, global = true, env = "T9S_PROFILE")]
+    pub profile: Option<String>,
+
     /// Polling interval in seconds
-    #[arg(long, default_value = "3")]
+    #[arg(long, global = true, default_value = "3")]
     pub poll_interval: u64,
 
+    /// Workflows fetched per list page
+    #[arg(long, global = true, env = "T9S_PAGE_SIZE", default_value = "50", value_parser = clap::value_parser!(i32).range(1..=MAX_PAGE_SIZE as i64))]
+    pub page_size: i32,
+
+    /// Seconds before connecting or a gRPC request is abandoned
+    #[arg(long, global = true, env = "T9S_REQUEST_TIMEOUT", default_value = "10")]
+    pub request_timeout: u64,
+
+    /// Retries for reads that time out or hit an unavailable server
+    #[arg(long, global = true, env = "T9S_READ_RETRIES", default_value = "2")]
+    pub read_retries: u32,
+
+    /// Color support: auto (from NO_COLOR/COLORTERM/TERM), truecolor, 256, 16 or never
+    #[arg(long, global = true, env = "T9S_COLOR", value_enum, default_value_t = ColorMode::Auto)]
+    pub color: ColorMode,
+
+    /// Color theme: dark (default), light, high-contrast, a skin file path, or
+    /// the name of a skin in <config dir>/t9s/skins/
+    #[arg(long, global = true, env = "T9S_THEME")]
+    pub theme: Option<String>,
+
+    /// Start with mouse capture off so terminal text selection works (see :copy-mode)
+    #[arg(long, global = true)]
+    pub no_mouse: bool,
+
     /// Log file path
-    #[arg(long, env = "T9S_LOG_FILE")]
+    #[arg(long, global = true, env = "T9S_LOG_FILE")]
     pub log_file: Option<String>,
+
+    /// Deep link to open at startup, e.g.
+    /// temporal://tui/namespaces/prod/workflows/order-123?tab=history
+    #[arg(value_name = "URI")]
+    pub uri_arg: Option<String>,
+
+    /// Same as the positional URI
+    #[arg(long = "uri", value_name = "URI", conflicts_with = "uri_arg")]
+    pub uri: Option<String>,
+
+    #[command(subcommand)]
+    pub command: Option<Command>,
 }
 
-#[derive(Debug, Deserialize, Default)]
+impl Cli {
+    /// Parses arguments, then fills every connection setting left at its
+    /// default from the config file's top level and then the selected
+    /// profile. Flags and environment variables always win.
+    pub fn parse_with_config(config: Option<&ConfigFile>) -> Result<Self, String> {
+        let matches = Self::command().get_matches();
+        let mut cli = Self::from_arg_matches(&matches).map_err(|e| e.to_string())?;
+        let explicit = |id: &str| is_explicit(&matches, id);
+
+        if let Some(config) = config {
+            let mut merged = cli.clone();
+            merged.address = config.address.clone().unwrap_or(merged.address);
+            merged.namespace = config.namespace.clone().unwrap_or(merged.namespace);
+            merged.api_key = merged.api_key.or_else(|| config.api_key.clone());
+            merged.tls_cert = merged.tls_cert.or_else(|| config.tls_cert.clone());
+            merged.tls_key = merged.tls_key.or_else(|| config.tls_key.clone());
+            merged.tls_ca = merged.tls_ca.or_else(|| config.tls_ca.clone());
+            merged.tls_server_name = merged
+                .tls_server_name
+                .or_else(|| config.tls_server_name.clone());
+            merged.tls_insecure_skip_verify = config
+                .tls_insecure_skip_verify
+                .unwrap_or(merged.tls_insecure_skip_verify);
+            config.oidc.apply(&mut merged);
+            merged.poll_interval = config.poll_interval.unwrap_or(merged.poll_interval);
+            merged.page_size = config.page_size.unwrap_or(merged.page_size);
+            merged.request_timeout = config.request_timeout.unwrap_or(merged.request_timeout);
+            merged.read_retries = config.read_retries.unwrap_or(merged.read_retries);
+            cli.merge_unset(merged, &explicit);
+        }
+
+        let profile_name = cli
+            .profile
+            .clone()
+            .or_else(|| config.and_then(|c| c.profile.clone()));
+        if let Some(name) = profile_name {
+            let profile = config
+                .and_then(|c| c.profile(&name))
+                .ok_or_else(|| format!("profile {:?} not found in config file", name))?;
+            let mut merged = cli.clone();
+            profile.apply(&mut merged);
+            cli.merge_unset(merged, &explicit);
+            cli.profile = Some(name);
+        }
+        Ok(cli)
+    }
+
+    /// The deep link given on the command line, positionally or with `--uri`.
+    pub fn startup_uri(&self) -> Option<&str> {
+        self.uri.as_deref().or(self.uri_arg.as_deref())
+    }
+
+    pub fn tls_options(&self) -> TlsOptions {
+        TlsOptions {
+            cert: self.tls_cert.clone(),
+            key: self.tls_key.clone(),
+            ca: self.tls_ca.clone(),
+            server_name: self.tls_server_name.clone(),
+            insecure_skip_verify: self.tls_insecure_skip_verify,
+        }
+    }
+
+    /// OIDC settings, when both an issuer and a client id are configured.
+    pub fn oidc_config(&self) -> Option<OidcConfig> {
+        Some(OidcConfig {
+            issuer: self.oidc_issuer.clone()?,
+            client_id: self.oidc_client_id.clone()?,
+            client_secret: self.oidc_client_secret.clone(),
+            scope: self.oidc_scope.clone(),
+            audience: self.oidc_audience.clone(),
+        })
+    }
+
+    /// Takes each connection setting from `other` unless `keep(id)` says the
+    /// current value was set on purpose.
+    fn merge_unset(&mut self, other: Cli, keep: &dyn Fn(&str) -> bool) {
+        if !keep("address") {
+            self.address = other.address;
+        }
+        if !keep("namespace") {
+            self.namespace = other.namespace;
+        }
+        if !keep("api_key") {
+            self.api_key = other.api_key;
+        }
+        if !keep("tls_cert") {
+            self.tls_cert = other.tls_cert;
+        }
+        if !keep("tls_key") {
+            self.tls_key = other.tls_key;
+        }
+        if !keep("tls_ca") {
+            self.tls_ca = other.tls_ca;
+        }
+        if !keep("tls_server_name") {
+            self.tls_server_name = other.tls_server_name;
+        }
+        if !keep("tls_insecure_skip_verify") {
+            self.tls_insecure_skip_verify = other.tls_insecure_skip_verify;
+        }
+        if !keep("oidc_issuer") {
+            self.oidc_issuer = other.oidc_issuer;
+        }
+        if !keep("oidc_client_id") {
+            self.oidc_client_id = other.oidc_client_id;
+        }
+        if !keep("oidc_client_secret") {
+            self.oidc_client_secret = other.oidc_client_secret;
+        }
+        if !keep("oidc_scope") {
+            self.oidc_scope = other.oidc_scope;
+        }
+        if !keep("oidc_audience") {
+            self.oidc_audience = other.oidc_audience;
+        }
+        if !keep("poll_interval") {
+            self.poll_interval = other.poll_interval;
+        }
+        if !keep("page_size") {
+            self.page_size = other.page_size;
+        }
+        if !keep("request_timeout") {
+            self.request_timeout = other.request_timeout;
+        }
+        if !keep("read_retries") {
+            self.read_retries = other.read_retries;
+        }
+    }
+}
+
+/// Whether `id` came from a flag or environment variable rather than its
+/// default. Global flags may have been given after the subcommand.
+fn is_explicit(matches: &ArgMatches, id: &str) -> bool {
+    let given = |m: &ArgMatches| {
+        m.try_get_raw(id).is_ok_and(|v| v.is_some())
+            && m.value_source(id)
+                .is_some_and(|source| source != ValueSource::DefaultValue)
+    };
+    given(matches) || matches.subcommand().is_some_and(|(_, sub)| given(sub))
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, clap::ValueEnum)]
+pub enum Transport {
+    #[default]
+    Grpc,
+    Http,
+    Auto,
+}
+
+#[derive(Subcommand, Debug, Clone)]
+pub enum Command {
+    /// Check DNS, TCP, TLS, auth, namespace and visibility against the server
+    Doctor,
+    /// Print a shell completion script to stdout
+    Completions {
+        #[arg(value_enum)]
+        shell: clap_complete::Shell,
+    },
+    /// Print the man page (roff) to stdout
+    Man,
+    /// Run one `:` command against the cluster and exit: 0 on success, 1 when
+    /// the command fails, 2 when the server can't be reached
+    Exec(ExecArgs),
+    /// Start a local Temporal dev server, seed it with sample data and open the TUI
+    #[cfg(feature = "dev")]
+    Dev {
+        /// Temporal CLI binary to run instead of downloading one
+        #[arg(long, env = "TEMPORAL_BIN")]
+        temporal_bin: Option<std::path::PathBuf>,
+        /// Start the server without creating sample workflows and schedules
+        #[arg(long)]
+        no_seed: bool,
+    },
+}
+
+/// Arguments of `t9s exec`.
+#[derive(Args, Debug, Clone)]
+pub struct ExecArgs {
+    /// Command as typed after `:` in the TUI, e.g. ':signal mysignal {"k":1}'
+    pub command: String,
+
+    /// Workflow the command acts on
+    #[arg(long, conflicts_with = "schedule_id")]
+    pub workflow_id: Option<String>,
+
+    /// Run of --workflow-id; the latest run when unset
+    #[arg(long, requires = "workflow_id")]
+    pub run_id: Option<String>,
+
+    /// Schedule the command acts on
+    #[arg(long)]
+    pub schedule_id: Option<String>,
+
+    /// Accept the confirmation the command would ask for in the TUI
+    #[arg(long, short)]
+    pub yes: bool,
+}
+
+#[derive(Debug, Clone, Deserialize, Default)]
 pub struct ConfigFile {
     pub address: Option<String>,
     pub namespace: Option<String>,
     pub api_key: Option<String>,
     pub tls_cert: Option<String>,
     pub tls_key: Option<String>,
+    pub tls_ca: Option<String>,
+    pub tls_server_name: Option<String>,
+    pub tls_insecure_skip_verify: Option<bool>,
+    #[serde(flatten)]
+    pub oidc: OidcSettings,
     pub poll_interval: Option<u64>,
+    /// Seconds between polls per view, keyed by `list`, `detail` or
+    /// `history`; views without an entry use `poll_interval`.
+    #[serde(default)]
+    pub poll_intervals: HashMap<String, u64>,
+    /// Workflows fetched per list page.
+    #[serde(default, deserialize_with = "page_size")]
+    pub page_size: Option<i32>,
+    /// Seconds before connecting or a gRPC request is abandoned.
+    pub request_timeout: Option<u64>,
+    /// Retries for idempotent reads after a timeout or unavailable server.
+    pub read_retries: Option<u32>,
+    /// Profile used when `--profile` is not given.
+    pub profile: Option<String>,
+    /// Theme used when `--theme` is not given.
+    pub theme: Option<String>,
+    /// UI language; anything but `en` reads `t9s/locales/<locale>.toml`.
+    pub locale: Option<String>,
+    /// Show "5m ago" style times instead of timestamps.
+    pub relative_times: Option<bool>,
+    /// `local`, `utc` or an IANA zone name for absolute times.
+    pub timezone: Option<String>,
+    /// Visible columns per collection, keyed by `workflows`, `schedules` or
+    /// `activities`. Entries are column ids with an optional `:width`.
+    #[serde(default)]
+    pub columns: HashMap<String, Vec<String>>,
+    #[serde(default)]
+    pub queries: Vec<QueryConfig>,
+    /// Named connection settings from `[[profiles]]`.
+    #[serde(default)]
+    pub profiles: Vec<ProfileConfig>,
+    #[serde(default)]
+    pub pipes: Vec<PipeConfig>,
+    /// Extra lists from `[[kinds]]`, each a built-in kind narrowed by a query.
+    #[serde(default)]
+    pub kinds: Vec<KindConfig>,
+    #[serde(default)]
+    pub typed_confirm: Vec<TypedConfirmConfig>,
+    /// File every mutation is appended to as a line of JSON.
+    pub audit_file: Option<String>,
+    /// Namespaces `1`–`9` switch to, in order.
+    #[serde(default)]
+    pub favorite_namespaces: Vec<String>,
+    /// Payload values hidden on screen.
+    #[serde(default)]
+    pub mask: Vec<MaskConfig>,
+    /// Start with the workflow preview pane open.
+    pub preview_pane: Option<bool>,
+    /// Temporal Web UI the quick actions menu opens rows in. Temporal
+    /// Cloud's is used on Cloud when this is unset.
+    pub web_url: Option<String>,
+}
+
+/// The largest page the list APIs are asked for.
+pub const MAX_PAGE_SIZE: i32 = 1000;
+
+/// Rejects a `page_size` outside `1..=MAX_PAGE_SIZE` when the file is read,
+/// as `--page-size` is.
+fn page_size<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Option<i32>, D::Error> {
+    let size = Option::<i32>::deserialize(deserializer)?;
+    match size {
+        Some(n) if !(1..=MAX_PAGE_SIZE).contains(&n) => Err(serde::de::Error::custom(format!(
+            "page_size must be between 1 and {}, got {}",
+            MAX_PAGE_SIZE, n
+        ))),
+        _ => Ok(size),
+    }
+}
+
+/// A `[[mask]]` rule: the payload value at `pointer`, or under any key
+/// matching `key`, is drawn as `replacement`.
+#[derive(Debug, Clone, Deserialize, PartialEq, Eq)]
+pub struct MaskConfig {
+    /// JSON pointer from the payload's root, e.g. `/customer/email`.
+    pub pointer: Option<String>,
+    /// Regex matched against object keys at any depth.
+    pub key: Option<String>,
+    /// Defaults to `***`.
+    pub replacement: Option<String>,
+}
+
+/// A namespace from `[[typed_confirm]]` where terminating or deleting needs
+/// the target's id (or `phrase`) typed into the confirmation.
+#[derive(Debug, Clone, Deserialize, PartialEq, Eq)]
+pub struct TypedConfirmConfig {
+    /// `*` for every namespace.
+    pub namespace: String,
+    pub phrase: Option<String>,
+}
+
+/// A custom list from `[[kinds]]`, opened with `:<command>`.
+#[derive(Debug, Clone, Deserialize, PartialEq, Eq)]
+pub struct KindConfig {
+    /// Shown in the header, e.g. `Failed Payments`.
+    pub name: String,
+    /// Defaults to the name in lowercase with dashes for spaces.
+    pub command: Option<String>,
+    /// `workflows` (default), `schedules` or `activities`.
+    pub base: Option<String>,
+    /// Always ANDed with whatever the list is searched for.
+    pub query: String,
+    /// Column ids with an optional `:width`, as in `[columns]`; unset keeps
+    /// the base kind's.
+    #[serde(default)]
+    pub columns: Vec<String>,
+}
+
+/// An external command from `[[pipes]]` that payloads can be piped through
+/// with `|` or `:pipe <name>`.
+#[derive(Debug, Clone, Deserialize, PartialEq, Eq)]
+pub struct PipeConfig {
+    pub name: String,
+    /// Run by `sh -c` with the payload as pretty-printed JSON on stdin.
+    pub command: String,
+}
+
+/// Connection settings that can be swapped in as a unit, with `--profile`,
+/// `:ctx` or from the connection screen.
+#[derive(Debug, Clone, Deserialize, PartialEq, Eq)]
+pub struct ProfileConfig {
+    pub name: String,
+    pub address: Option<String>,
+    pub namespace: Option<String>,
+    pub api_key: Option<String>,
+    pub tls_cert: Option<String>,
+    pub tls_key: Option<String>,
+    pub tls_ca: Option<String>,
+    pub tls_server_name: Option<String>,
+    pub tls_insecure_skip_verify: Option<bool>,
+    #[serde(flatten)]
+    pub oidc: OidcSettings,
+}
+
+/// `oidc_*` keys, accepted at the top level and in profiles.
+#[derive(Debug, Clone, Default, Deserialize, PartialEq, Eq)]
+pub struct OidcSettings {
+    pub oidc_issuer: Option<String>,
+    pub oidc_client_id: Option<String>,
+    pub oidc_client_secret: Option<String>,
+    pub oidc_scope: Option<String>,
+    pub oidc_audience: Option<String>,
+}
+
+impl OidcSettings {
+    /// Overwrites the OIDC fields of `cli` that are set here.
+    pub fn apply(&self, cli: &mut Cli) {
+        let fields = [
+            (&self.oidc_issuer, &mut cli.oidc_issuer),
+            (&self.oidc_client_id, &mut cli.oidc_client_id),
+            (&self.oidc_client_secret, &mut cli.oidc_client_secret),
+            (&self.oidc_scope, &mut cli.oidc_scope),
+            (&self.oidc_audience, &mut cli.oidc_audience),
+        ];
+        for (value, target) in fields {
+            if value.is_some() {
+                *target = value.clone();
+            }
+        }
+    }
+}
+
+impl ProfileConfig {
+    /// Switches `cli` to this profile: every connection setting it leaves
+    /// unset goes back to its default, so no credential or TLS setting of
+    /// the previous profile carries over.
+    pub fn switch_to(&self, cli: &mut Cli) {
+        cli.address = DEFAULT_ADDRESS.to_string();
+        cli.namespace = DEFAULT_NAMESPACE.to_string();
+        cli.api_key = None;
+        cli.tls_cert = None;
+        cli.tls_key = None;
+        cli.tls_ca = None;
+        cli.tls_server_name = None;
+        cli.tls_insecure_skip_verify = false;
+        cli.oidc_issuer = None;
+        cli.oidc_client_id = None;
+        cli.oidc_client_secret = None;
+        cli.oidc_scope = None;
+        cli.oidc_audience = None;
+        self.apply(cli);
+        cli.profile = Some(self.name.clone());
+    }
+
+    /// Overwrites the connection fields of `cli` that this profile sets.
+    pub fn apply(&self, cli: &mut Cli) {
+        if let Some(address) = &self.address {
+            cli.address = address.clone();
+        }
+        if let Some(namespace) = &self.namespace {
+            cli.namespace = namespace.clone();
+        }
+        if self.api_key.is_some() {
+            cli.api_key = self.api_key.clone();
+        }
+        if self.tls_cert.is_some() {
+            cli.tls_cert = self.tls_cert.clone();
+        }
+        if self.tls_key.is_some() {
+            cli.tls_key = self.tls_key.clone();
+        }
+        if self.tls_ca.is_some() {
+            cli.tls_ca = self.tls_ca.clone();
+        }
+        if self.tls_server_name.is_some() {
+            cli.tls_server_name = self.tls_server_name.clone();
+        }
+        if let Some(insecure) = self.tls_insecure_skip_verify {
+            cli.tls_insecure_skip_verify = insecure;
+        }
+        self.oidc.apply(cli);
+    }
+}
+
+/// A named list filter from `[[queries]]`, applied with `:q <name>`.
+#[derive(Debug, Clone, Deserialize, PartialEq, Eq)]
+pub struct QueryConfig {
+    pub name: String,
+    pub query: String,
+    /// `workflows` (default), `schedules` or `activities`.
+    pub kind: Option<String>,
+    /// Only offer the query in this namespace; unset means every namespace.
+    pub namespace: Option<String>,
 }
 
 impl ConfigFile {
+    pub fn profile(&self, name: &str) -> Option<&ProfileConfig> {
+        self.profiles.iter().find(|p| p.name == name)
+    }
+
+    /// `<config dir>/t9s/config.toml`.
+    pub fn path() -> Option<PathBuf> {
+        Some(dirs::config_dir()?.join("t9s").join("config.toml"))
+    }
+
     pub fn load() -> Option<Self> {
-        let config_dir = dirs::config_dir()?;
-        let config_path = config_dir.join("t9s").join("config.toml");
-        let content = std::fs::read_to_string(config_path).ok()?;
-        toml::from_str(&content).ok()
+        Self::read(&Self::path()?).ok()
+    }
+
+    /// Reads `path`, reporting why it can't be used; a missing file is an
+    /// empty config.
+    pub fn read(path: &Path) -> Result<Self, String> {
+        match std::fs::read_to_string(path) {
+            Ok(content) => {
+                toml::from_str(&content).map_err(|e| format!("{}: {}", path.display(), e))
+            }
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(e) => Err(format!("{}: {}", path.display(), e)),
+        }
+    }
+}
+
+/// Notices edits to the config and skin files by comparing modification
+/// times, checked on the UI tick.
+#[derive(Debug, Default)]
+pub struct FileWatcher {
+    files: Vec<(PathBuf, Option<SystemTime>)>,
+}
+
+impl FileWatcher {
+    pub fn new(paths: impl IntoIterator<Item = PathBuf>) -> Self {
+        let files = paths
+            .into_iter()
+            .map(|path| {
+                let modified = modified(&path);
+                (path, modified)
+            })
+            .collect();
+        Self { files }
+    }
+
+    /// Whether a file was written, created or removed since the last call.
+    pub fn changed(&mut self) -> bool {
+        let mut changed = false;
+        for (path, seen) in &mut self.files {
+            let now = modified(path);
+            if now != *seen {
+                *seen = now;
+                changed = true;
+            }
+        }
+        changed
+    }
+}
+
+fn modified(path: &Path) -> Option<SystemTime> {
+    std::fs::metadata(path).and_then(|m| m.modified()).ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn out_of_range_page_sizes_fail_to_load() {
+        let config: ConfigFile = toml::from_str("page_size = 200").unwrap();
+        assert_eq!(config.page_size, Some(200));
+        assert_eq!(toml::from_str::<ConfigFile>("").unwrap().page_size, None);
+        for size in ["0", "-5", "1001"] {
+            let err = toml::from_str::<ConfigFile>(&format!("page_size = {}", size)).unwrap_err();
+            assert!(err
+                .to_string()
+                .contains("page_size must be between 1 and 1000"));
+        }
+    }
+
+    #[test]
+    fn switching_profiles_drops_the_previous_credentials() {
+        let profiles: ConfigFile = toml::from_str(
+            r#"
+            [[profiles]]
+            name = "staging"
+            address = "staging:7233"
+            api_key = "staging-key"
+            tls_cert = "staging.pem"
+            tls_key = "staging-key.pem"
+            tls_ca = "staging-ca.pem"
+            tls_server_name = "staging.internal"
+            tls_insecure_skip_verify = true
+            oidc_issuer = "https://idp.staging"
+            oidc_client_id = "t9s"
+
+            [[profiles]]
+            name = "prod"
+            address = "prod:7233"
+            "#,
+        )
+        .unwrap();
+        let mut cli = Cli::try_parse_from(["t9s"]).unwrap();
+        profiles.profile("staging").unwrap().switch_to(&mut cli);
+        assert!(cli.tls_insecure_skip_verify);
+        assert_eq!(cli.api_key.as_deref(), Some("staging-key"));
+
+        profiles.profile("prod").unwrap().switch_to(&mut cli);
+        assert_eq!(cli.address, "prod:7233");
+        assert_eq!(cli.namespace, DEFAULT_NAMESPACE);
+        assert_eq!(cli.profile.as_deref(), Some("prod"));
+        assert!(!cli.tls_insecure_skip_verify);
+        assert_eq!(cli.api_key, None);
+        assert_eq!(cli.tls_options(), TlsOptions::default());
+        assert!(cli.oidc_config().is_none());
     }
 }
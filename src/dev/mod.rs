@@ -0,0 +1,176 @@
+//! `t9s dev`: a one-command sandbox. Downloads the Temporal CLI if needed, runs
+//! its dev server, seeds it and hands the address to the TUI.
+
+pub mod seed;
+
+use std::io::Read;
+use std::path::{Path, PathBuf};
+use std::process::Stdio;
+use std::time::{Duration, Instant};
+
+use color_eyre::eyre::{bail, eyre, Result};
+use tokio::process::{Child, Command};
+
+use crate::client::{GrpcTemporalClient, TlsOptions};
+
+/// Temporal CLI release used for the sandbox. It includes standalone activities.
+pub const CLI_TAG: &str = "v1.6.2-standalone-activity";
+const CLI_VERSION: &str = "1.6.2-standalone-activity";
+const READY_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// A dev server started by t9s. The process is killed when this is dropped.
+pub struct DevServer {
+    pub address: String,
+    pub log_path: PathBuf,
+    child: Option<Child>,
+}
+
+impl DevServer {
+    /// Whether this process started the server, as opposed to reusing one that
+    /// was already listening.
+    pub fn is_owned(&self) -> bool {
+        self.child.is_some()
+    }
+}
+
+/// Directory for the downloaded CLI and server logs.
+pub fn dev_dir() -> Result<PathBuf> {
+    let base = dirs::cache_dir().ok_or_else(|| eyre!("no cache directory on this platform"))?;
+    Ok(base.join("t9s").join("dev"))
+}
+
+/// Returns a Temporal CLI binary: `explicit` if given, otherwise the cached
+/// release, downloading it on first use.
+pub async fn ensure_cli(explicit: Option<PathBuf>) -> Result<PathBuf> {
+    if let Some(path) = explicit {
+        return Ok(path);
+    }
+    let bin = dev_dir()?.join(format!("temporal-{}", CLI_VERSION));
+    if bin.exists() {
+        return Ok(bin);
+    }
+    download_cli(&bin).await?;
+    Ok(bin)
+}
+
+async fn download_cli(dest: &Path) -> Result<()> {
+    let os = match std::env::consts::OS {
+        "linux" => "linux",
+        "macos" => "darwin",
+        other => bail!("no Temporal CLI release for {}; pass --temporal-bin", other),
+    };
+    let arch = match std::env::consts::ARCH {
+        "x86_64" => "amd64",
+        "aarch64" => "arm64",
+        other => bail!("no Temporal CLI release for {}; pass --temporal-bin", other),
+    };
+    let url = format!(
+        "https://github.com/temporalio/cli/releases/download/{}/temporal_cli_{}_{}_{}.tar.gz",
+        CLI_TAG, CLI_VERSION, os, arch
+    );
+    println!("Downloading Temporal CLI {}...", CLI_TAG);
+    let bytes = reqwest::get(&url)
+        .await?
+        .error_for_status()?
+        .bytes()
+        .await?;
+
+    let mut archive = tar::Archive::new(flate2::read::GzDecoder::new(bytes.as_ref()));
+    for entry in archive.entries()? {
+        let mut entry = entry?;
+        if entry.path()?.file_name().and_then(|n| n.to_str()) != Some("temporal") {
+            continue;
+        }
+        let mut data = Vec::new();
+        entry.read_to_end(&mut data)?;
+        if let Some(parent) = dest.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        std::fs::write(dest, data)?;
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+            std::fs::set_permissions(dest, std::fs::Permissions::from_mode(0o755))?;
+        }
+        return Ok(());
+    }
+    bail!("no 'temporal' binary in {}", url)
+}
+
+/// Starts `temporal server start-dev` on the port of `address`, or reuses a
+/// server that is already answering there. Waits until the default namespace
+/// is reachable.
+pub async fn start_server(bin: &Path, address: &str) -> Result<DevServer> {
+    let (host, port) = address
+        .rsplit_once(':')
+        .ok_or_else(|| eyre!("address '{}' is not host:port", address))?;
+    if !matches!(host, "localhost" | "127.0.0.1") {
+        bail!(
+            "t9s dev runs a local server; use a localhost --address, not {}",
+            address
+        );
+    }
+    let log_path = dev_dir()?.join("dev-server.log");
+
+    if connect_ready(address).await.is_ok() {
+        println!("Reusing Temporal server already running at {}", address);
+        return Ok(DevServer {
+            address: address.to_string(),
+            log_path,
+            child: None,
+        });
+    }
+
+    std::fs::create_dir_all(dev_dir()?)?;
+    let log = std::fs::File::create(&log_path)?;
+    println!("Starting Temporal dev server on {}...", address);
+    let mut child = Command::new(bin)
+        .args(["server", "start-dev", "--ip", host, "--port", port])
+        .stdin(Stdio::null())
+        .stdout(log.try_clone()?)
+        .stderr(log)
+        .kill_on_drop(true)
+        .spawn()
+        .map_err(|e| eyre!("failed to run {}: {}", bin.display(), e))?;
+
+    let started = Instant::now();
+    loop {
+        if let Some(status) = child.try_wait()? {
+            bail!("dev server exited ({}); see {}", status, log_path.display());
+        }
+        match connect_ready(address).await {
+            Ok(()) => break,
+            Err(e) if started.elapsed() > READY_TIMEOUT => {
+                bail!(
+                    "dev server not ready after {}s ({}); see {}",
+                    READY_TIMEOUT.as_secs(),
+                    e,
+                    log_path.display()
+                );
+            }
+            Err(_) => tokio::time::sleep(Duration::from_millis(500)).await,
+        }
+    }
+
+    Ok(DevServer {
+        address: address.to_string(),
+        log_path,
+        child: Some(child),
+    })
+}
+
+async fn connect_ready(address: &str) -> Result<()> {
+    let client =
+        GrpcTemporalClient::connect(address, "default".to_string(), None, TlsOptions::default())
+            .await?;
+    client.describe_namespace("default").await?;
+    Ok(())
+}
+
+/// Seeds `namespace` on the dev server with the sample data from [`seed`].
+pub async fn seed_server(address: &str, namespace: &str) -> Result<seed::SeedReport> {
+    let client =
+        GrpcTemporalClient::connect(address, namespace.to_string(), None, TlsOptions::default())
+            .await?;
+    Ok(seed::seed(&client, namespace).await?)
+}
@@ -0,0 +1,125 @@
+//! Sample data for a fresh dev server: workflows in a few states and a handful
+//! of schedules. Nothing polls the seeded task queues, so started workflows stay
+//! running until terminated.
+
+use crate::client::{ClientError, ClientResult, ExecutionAdmin, GrpcTemporalClient, ScheduleAdmin};
+
+pub const TASK_QUEUE: &str = "t9s-sandbox";
+
+struct SampleSchedule {
+    id: &'static str,
+    workflow_type: &'static str,
+    interval_secs: Option<i64>,
+    cron: Option<&'static str>,
+    paused: bool,
+}
+
+const fn schedule(
+    id: &'static str,
+    workflow_type: &'static str,
+    interval_secs: Option<i64>,
+    cron: Option<&'static str>,
+    paused: bool,
+) -> SampleSchedule {
+    SampleSchedule {
+        id,
+        workflow_type,
+        interval_secs,
+        cron,
+        paused,
+    }
+}
+
+const SCHEDULES: &[SampleSchedule] = &[
+    schedule("hello-every-2m", "HelloWorkflow", Some(120), None, false),
+    schedule(
+        "greeting-every-5m",
+        "GreetingWorkflow",
+        Some(300),
+        None,
+        false,
+    ),
+    schedule(
+        "cron-every-10m",
+        "CronWorkflow",
+        None,
+        Some("*/10 * * * *"),
+        false,
+    ),
+    // Fires inside the US DST gap and overlap, so the detail view shows warnings.
+    schedule(
+        "nightly-report",
+        "ReportWorkflow",
+        None,
+        Some("CRON_TZ=America/New_York 30 1,2 * * *"),
+        false,
+    ),
+    schedule("saga-hourly", "TransferWorkflow", Some(3600), None, true),
+];
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct SeedReport {
+    pub workflows: usize,
+    pub terminated: usize,
+    pub schedules: usize,
+}
+
+/// Starts sample workflows and (re)creates the sample schedules. Safe to run
+/// repeatedly: workflow ids carry a timestamp and schedules are replaced.
+pub async fn seed(client: &GrpcTemporalClient, namespace: &str) -> ClientResult<SeedReport> {
+    let mut report = SeedReport::default();
+    let stamp = chrono::Utc::now().format("%H%M%S");
+
+    for (workflow_type, prefix, count) in [
+        ("OrderWorkflow", "order", 5),
+        ("PaymentWorkflow", "payment", 3),
+        ("ShipmentWorkflow", "shipment", 2),
+    ] {
+        for i in 1..=count {
+            let workflow_id = format!("{}-{}-{}", prefix, i, stamp);
+            let input = serde_json::json!({
+                "id": workflow_id,
+                "amount": i * 25,
+                "items": [{"sku": format!("SKU-{}", i), "qty": i}],
+            });
+            let run_id = client
+                .start_workflow(
+                    namespace,
+                    &workflow_id,
+                    workflow_type,
+                    TASK_QUEUE,
+                    Some(&input),
+                )
+                .await?;
+            report.workflows += 1;
+
+            if i == count {
+                client
+                    .terminate_workflow(namespace, &workflow_id, Some(&run_id), "seeded by t9s dev")
+                    .await?;
+                report.terminated += 1;
+            }
+        }
+    }
+
+    for sample in SCHEDULES {
+        match client.delete_schedule(namespace, sample.id).await {
+            Ok(()) | Err(ClientError::NotFound(_)) => {}
+            Err(e) => return Err(e),
+        }
+        client
+            .create_schedule(
+                namespace,
+                sample.id,
+                sample.workflow_type,
+                TASK_QUEUE,
+                sample.interval_secs,
+                sample.cron,
+                sample.paused,
+            )
+            .await?;
+        report.schedules += 1;
+    }
+
+    Ok(report)
+}
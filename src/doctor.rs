@@ -0,0 +1,270 @@
+use std::net::SocketAddr;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use crate::client::{
+    oidc, uses_tls, Auth, ClientError, ClientResult, DeviceLogin, GrpcTemporalClient, OidcConfig,
+    OidcSession, VisibilityReader,
+};
+use crate::config::Cli;
+
+const STEP_TIMEOUT: Duration = Duration::from_secs(5);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Outcome {
+    Pass,
+    Fail,
+    Skip,
+}
+
+struct Check {
+    name: &'static str,
+    outcome: Outcome,
+    detail: String,
+    hint: Option<String>,
+}
+
+impl Check {
+    fn pass(name: &'static str, detail: impl Into<String>) -> Self {
+        Self {
+            name,
+            outcome: Outcome::Pass,
+            detail: detail.into(),
+            hint: None,
+        }
+    }
+
+    fn fail(name: &'static str, detail: impl Into<String>, hint: impl Into<String>) -> Self {
+        Self {
+            name,
+            outcome: Outcome::Fail,
+            detail: detail.into(),
+            hint: Some(hint.into()),
+        }
+    }
+
+    fn skip(name: &'static str, detail: impl Into<String>) -> Self {
+        Self {
+            name,
+            outcome: Outcome::Skip,
+            detail: detail.into(),
+            hint: None,
+        }
+    }
+}
+
+/// Runs every check against the configured server, prints the report and
+/// returns whether all of them passed. Stops at the first failing step.
+pub async fn run(cli: &Cli) -> bool {
+    println!(
+        "t9s doctor: {} (namespace {})\n",
+        cli.address, cli.namespace
+    );
+
+    let mut checks = Vec::new();
+    let ok = diagnose(cli, &mut checks).await;
+
+    for check in &checks {
+        let mark = match check.outcome {
+            Outcome::Pass => "✓",
+            Outcome::Fail => "✗",
+            Outcome::Skip => "-",
+        };
+        println!("  {} {:<22} {}", mark, check.name, check.detail);
+        if let Some(ref hint) = check.hint {
+            println!("    hint: {}", hint);
+        }
+    }
+
+    println!();
+    if ok {
+        println!("All checks passed.");
+    } else {
+        println!("Some checks failed.");
+    }
+    ok
+}
+
+/// Logs in with client credentials, or with the device flow printing its
+/// prompt to stdout.
+async fn oidc_login(config: &OidcConfig) -> ClientResult<Arc<OidcSession>> {
+    if !config.is_device_flow() {
+        return oidc::session(config).await;
+    }
+    let login = DeviceLogin::start(config).await?;
+    println!(
+        "Open {} and enter code {}\n",
+        login.verification_uri, login.user_code
+    );
+    login.finish().await
+}
+
+async fn diagnose(cli: &Cli, checks: &mut Vec<Check>) -> bool {
+    let addrs: Vec<SocketAddr> =
+        match tokio::time::timeout(STEP_TIMEOUT, tokio::net::lookup_host(&cli.address)).await {
+            Ok(Ok(addrs)) => addrs.collect(),
+            Ok(Err(e)) => {
+                checks.push(Check::fail(
+                    "DNS resolution",
+                    e.to_string(),
+                    "check --address / TEMPORAL_ADDRESS is host:port and the host name is correct",
+                ));
+                return false;
+            }
+            Err(_) => {
+                checks.push(Check::fail(
+                    "DNS resolution",
+                    "timed out",
+                    "check your DNS resolver or VPN",
+                ));
+                return false;
+            }
+        };
+    let Some(addr) = addrs.first().copied() else {
+        checks.push(Check::fail(
+            "DNS resolution",
+            "no addresses returned",
+            "check the host name in --address",
+        ));
+        return false;
+    };
+    checks.push(Check::pass(
+        "DNS resolution",
+        format!("{} ({} resolved)", addr, addrs.len()),
+    ));
+
+    let started = Instant::now();
+    match tokio::time::timeout(STEP_TIMEOUT, tokio::net::TcpStream::connect(addr)).await {
+        Ok(Ok(_)) => checks.push(Check::pass(
+            "TCP connect",
+            format!("{} in {}ms", addr, started.elapsed().as_millis()),
+        )),
+        Ok(Err(e)) => {
+            checks.push(Check::fail(
+                "TCP connect",
+                e.to_string(),
+                "is the server running, and is the port open through any firewall?",
+            ));
+            return false;
+        }
+        Err(_) => {
+            checks.push(Check::fail(
+                "TCP connect",
+                "timed out",
+                "a firewall may be dropping traffic to this port",
+            ));
+            return false;
+        }
+    }
+
+    let auth = match cli.oidc_config() {
+        Some(config) => match oidc_login(&config).await {
+            Ok(session) => {
+                checks.push(Check::pass("OIDC token", format!("from {}", config.issuer)));
+                Some(Auth::Oidc(session))
+            }
+            Err(e) => {
+                checks.push(Check::fail(
+                    "OIDC token",
+                    e.to_string(),
+                    "check --oidc-issuer, --oidc-client-id and --oidc-client-secret",
+                ));
+                return false;
+            }
+        },
+        None => cli.api_key.clone().map(Auth::ApiKey),
+    };
+
+    let tls_options = cli.tls_options();
+    let tls = uses_tls(&cli.address, auth.is_some()) || tls_options.requires_tls();
+    let client = match GrpcTemporalClient::connect(
+        &cli.address,
+        cli.namespace.clone(),
+        auth,
+        tls_options.clone(),
+    )
+    .await
+    {
+        Ok(client) => {
+            if tls {
+                let detail = if tls_options.insecure_skip_verify {
+                    "ok (certificate NOT verified)"
+                } else if cli.tls_cert.is_some() {
+                    "ok (mTLS client certificate)"
+                } else if tls_options.ca.is_some() {
+                    "ok (custom CA)"
+                } else {
+                    "ok"
+                };
+                checks.push(Check::pass("TLS handshake", detail));
+            } else {
+                checks.push(Check::skip("TLS handshake", "plaintext connection"));
+            }
+            client
+        }
+        Err(e) => {
+            let hint = match (&e, tls) {
+                (ClientError::ConfigError(_), _) => {
+                    "check the --tls-cert / --tls-key / --tls-ca paths"
+                }
+                (_, true) => {
+                    "the server may not speak TLS, or its certificate is not trusted; try --tls-ca \
+                     or --tls-server-name"
+                }
+                (_, false) => {
+                    "the server may require TLS; set --api-key or use a non-local address"
+                }
+            };
+            checks.push(Check::fail("TLS handshake", e.to_string(), hint));
+            return false;
+        }
+    };
+
+    match client.get_system_info().await {
+        Ok(version) => checks.push(Check::pass(
+            "Auth (GetSystemInfo)",
+            format!("server {}", version),
+        )),
+        Err(e) => {
+            let hint = if cli.api_key.is_some() || cli.tls_cert.is_some() {
+                "the credentials were rejected; check they are valid and not expired"
+            } else {
+                "the server requires credentials; set --api-key or --tls-cert/--tls-key"
+            };
+            checks.push(Check::fail("Auth (GetSystemInfo)", e.to_string(), hint));
+            return false;
+        }
+    }
+
+    match client.describe_namespace(&cli.namespace).await {
+        Ok(()) => checks.push(Check::pass("Namespace", cli.namespace.clone())),
+        Err(e) => {
+            checks.push(Check::fail(
+                "Namespace",
+                e.to_string(),
+                "check --namespace / TEMPORAL_NAMESPACE; Cloud namespaces look like name.account",
+            ));
+            return false;
+        }
+    }
+
+    match client
+        .count_workflows(&cli.namespace, Some("ExecutionStatus = 'Running'"))
+        .await
+    {
+        Ok(count) => checks.push(Check::pass(
+            "Visibility queries",
+            format!("{} running workflows", count),
+        )),
+        Err(e) => {
+            checks.push(Check::fail(
+                "Visibility queries",
+                e.to_string(),
+                "list filters need advanced visibility (SQL or Elasticsearch) on the server",
+            ));
+            return false;
+        }
+    }
+
+    true
+}
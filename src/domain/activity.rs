@@ -1,4 +1,7 @@
 use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use super::{parse_duration_text, FailureInfo};
 
 #[derive(Debug, Clone)]
 pub struct PendingActivity {
@@ -6,10 +9,20 @@ pub struct PendingActivity {
     pub activity_type: String,
     pub state: PendingActivityState,
     pub attempt: i32,
+    /// 0 means unlimited.
+    pub maximum_attempts: i32,
     pub scheduled_time: Option<DateTime<Utc>>,
     pub last_started_time: Option<DateTime<Utc>>,
     pub last_heartbeat_time: Option<DateTime<Utc>>,
-    pub last_failure_message: Option<String>,
+    /// When the next attempt starts, while waiting out a retry backoff.
+    pub next_attempt_time: Option<DateTime<Utc>>,
+    /// Details from the last heartbeat, decoded.
+    pub heartbeat_details: Option<serde_json::Value>,
+    pub last_failure: Option<FailureInfo>,
+    pub last_worker_identity: String,
+    /// The activity's current timeouts and retry policy; `None` from servers
+    /// that don't report them.
+    pub options: Option<ActivityOptionsEdit>,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -34,3 +47,106 @@ impl std::fmt::Display for PendingActivityState {
         write!(f, "{}", self.as_str())
     }
 }
+
+/// A pending activity's timeouts and retry policy, round-tripped through
+/// `$EDITOR` as JSON. Durations are text like "30s" or "1h30m".
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct ActivityOptionsEdit {
+    pub schedule_to_close_timeout: Option<String>,
+    pub schedule_to_start_timeout: Option<String>,
+    pub start_to_close_timeout: Option<String>,
+    pub heartbeat_timeout: Option<String>,
+    pub retry_initial_interval: Option<String>,
+    pub retry_backoff_coefficient: f64,
+    pub retry_maximum_interval: Option<String>,
+    /// 0 means unlimited.
+    pub retry_maximum_attempts: i32,
+    pub non_retryable_error_types: Vec<String>,
+}
+
+impl ActivityOptionsEdit {
+    /// Parses an edited document, rejecting values the server would refuse.
+    pub fn parse(text: &str) -> Result<Self, String> {
+        let edit: Self = serde_json::from_str(text).map_err(|e| e.to_string())?;
+        let durations = [
+            ("schedule_to_close_timeout", &edit.schedule_to_close_timeout),
+            ("schedule_to_start_timeout", &edit.schedule_to_start_timeout),
+            ("start_to_close_timeout", &edit.start_to_close_timeout),
+            ("heartbeat_timeout", &edit.heartbeat_timeout),
+            ("retry_initial_interval", &edit.retry_initial_interval),
+            ("retry_maximum_interval", &edit.retry_maximum_interval),
+        ];
+        for (field, value) in durations {
+            if let Some(value) = value {
+                if parse_duration_text(value).is_none() {
+                    return Err(format!("invalid {} {:?}", field, value));
+                }
+            }
+        }
+        if edit.retry_backoff_coefficient != 0.0 && edit.retry_backoff_coefficient < 1.0 {
+            return Err("retry_backoff_coefficient must be at least 1".to_string());
+        }
+        if edit.retry_maximum_attempts < 0 {
+            return Err("retry_maximum_attempts can't be negative".to_string());
+        }
+        Ok(edit)
+    }
+
+    /// Proto field paths of the options that differ from `original`, as
+    /// sent in the update mask.
+    pub fn changed_fields(&self, original: &Self) -> Vec<&'static str> {
+        let mut fields = Vec::new();
+        let mut check = |changed: bool, path: &'static str| {
+            if changed {
+                fields.push(path);
+            }
+        };
+        check(
+            self.schedule_to_close_timeout != original.schedule_to_close_timeout,
+            "schedule_to_close_timeout",
+        );
+        check(
+            self.schedule_to_start_timeout != original.schedule_to_start_timeout,
+            "schedule_to_start_timeout",
+        );
+        check(
+            self.start_to_close_timeout != original.start_to_close_timeout,
+            "start_to_close_timeout",
+        );
+        check(
+            self.heartbeat_timeout != original.heartbeat_timeout,
+            "heartbeat_timeout",
+        );
+        check(
+            self.retry_initial_interval != original.retry_initial_interval,
+            "retry_policy.initial_interval",
+        );
+        check(
+            self.retry_backoff_coefficient != original.retry_backoff_coefficient,
+            "retry_policy.backoff_coefficient",
+        );
+        check(
+            self.retry_maximum_interval != original.retry_maximum_interval,
+            "retry_policy.maximum_interval",
+        );
+        check(
+            self.retry_maximum_attempts != original.retry_maximum_attempts,
+            "retry_policy.maximum_attempts",
+        );
+        check(
+            self.non_retryable_error_types != original.non_retryable_error_types,
+            "retry_policy.non_retryable_error_types",
+        );
+        fields
+    }
+}
+
+/// A pending activity's options opened for editing.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ActivityOptionsDraft {
+    pub workflow_id: String,
+    pub run_id: String,
+    pub activity_id: String,
+    pub edit: ActivityOptionsEdit,
+}
@@ -0,0 +1,91 @@
+use base64::engine::general_purpose::STANDARD;
+use base64::Engine;
+
+/// Bytes per hexdump line.
+const HEXDUMP_WIDTH: usize = 16;
+
+/// A decoded payload: JSON, or bytes that aren't text, which the pager can
+/// show as a hexdump.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Payload {
+    Json(serde_json::Value),
+    Binary(Vec<u8>),
+}
+
+impl Payload {
+    /// The payload as JSON. Bytes become a base64 string, as in the proto3
+    /// JSON mapping.
+    pub fn to_json(&self) -> serde_json::Value {
+        match self {
+            Payload::Json(value) => value.clone(),
+            Payload::Binary(bytes) => serde_json::Value::String(STANDARD.encode(bytes)),
+        }
+    }
+
+    pub fn is_null(&self) -> bool {
+        matches!(self, Payload::Json(serde_json::Value::Null))
+    }
+}
+
+/// How a binary payload of `len` bytes is shown in payload trees.
+pub fn binary_label(len: usize) -> String {
+    format!("<binary {} bytes> (v: hexdump)", len)
+}
+
+/// `hexdump -C` style lines: offset, sixteen hex bytes split in two groups
+/// of eight, and the printable ASCII.
+pub fn hexdump(bytes: &[u8]) -> String {
+    let mut out = String::new();
+    for (line, chunk) in bytes.chunks(HEXDUMP_WIDTH).enumerate() {
+        let mut hex = String::new();
+        for i in 0..HEXDUMP_WIDTH {
+            if i == HEXDUMP_WIDTH / 2 {
+                hex.push(' ');
+            }
+            match chunk.get(i) {
+                Some(b) => hex.push_str(&format!("{:02x} ", b)),
+                None => hex.push_str("   "),
+            }
+        }
+        let ascii: String = chunk
+            .iter()
+            .map(|&b| match b {
+                0x20..=0x7e => b as char,
+                _ => '.',
+            })
+            .collect();
+        out.push_str(&format!(
+            "{:08x}  {} |{}|\n",
+            line * HEXDUMP_WIDTH,
+            hex,
+            ascii
+        ));
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn binary_payloads_become_base64_json() {
+        let payload = Payload::Binary(vec![0, 159, 146, 150, b'a']);
+        assert_eq!(payload.to_json(), serde_json::json!("AJ+SlmE="));
+        assert!(!payload.is_null());
+        assert_eq!(binary_label(5), "<binary 5 bytes> (v: hexdump)");
+    }
+
+    #[test]
+    fn hexdump_shows_offset_hex_and_ascii() {
+        let dump = hexdump(b"Hello, world!\n\x00\x01\xffabc");
+        let lines: Vec<&str> = dump.lines().collect();
+        assert_eq!(
+            lines,
+            [
+                "00000000  48 65 6c 6c 6f 2c 20 77  6f 72 6c 64 21 0a 00 01  |Hello, world!...|",
+                "00000010  ff 61 62 63                                       |.abc|",
+            ]
+        );
+    }
+}
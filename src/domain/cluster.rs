@@ -0,0 +1,68 @@
+use std::time::Duration;
+
+/// Oldest server release t9s is tested against; older servers get a warning.
+pub const MIN_SERVER_VERSION: (u64, u64, u64) = (1, 20, 0);
+
+#[derive(Debug, Clone)]
+pub struct ClusterInfo {
+    pub server_version: String,
+    pub cluster_name: String,
+    pub is_cloud: bool,
+}
+
+impl ClusterInfo {
+    /// Parse `server_version` ("1.24.2", "v1.22.0-rc1") into a comparable tuple.
+    pub fn version(&self) -> Option<(u64, u64, u64)> {
+        let version = self.server_version.trim().trim_start_matches('v');
+        let core = version.split(['-', '+']).next()?;
+        let mut parts = core.split('.').map(|p| p.parse::<u64>().ok());
+        let major = parts.next()??;
+        let minor = parts.next().flatten().unwrap_or(0);
+        let patch = parts.next().flatten().unwrap_or(0);
+        Some((major, minor, patch))
+    }
+
+    /// Cloud does not report a meaningful server version, so it is never
+    /// flagged; unparsable versions are given the benefit of the doubt too.
+    pub fn is_outdated(&self) -> bool {
+        !self.is_cloud
+            && self
+                .version()
+                .map(|v| v < MIN_SERVER_VERSION)
+                .unwrap_or(false)
+    }
+}
+
+/// Round trips slower than this show the connection as slow.
+pub const LATENCY_SLOW: Duration = Duration::from_millis(300);
+/// Round trips slower than this, or any failed check, show it as unhealthy.
+pub const LATENCY_BAD: Duration = Duration::from_secs(1);
+
+/// Result of the periodic health checks against the server.
+#[derive(Debug, Clone, Default)]
+pub struct Health {
+    /// Round trip of the last successful check.
+    pub latency: Option<Duration>,
+    /// Checks failed in a row.
+    pub failures: u32,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HealthLevel {
+    Unknown,
+    Good,
+    Slow,
+    Bad,
+}
+
+impl Health {
+    pub fn level(&self) -> HealthLevel {
+        match self.latency {
+            _ if self.failures > 0 => HealthLevel::Bad,
+            None => HealthLevel::Unknown,
+            Some(latency) if latency >= LATENCY_BAD => HealthLevel::Bad,
+            Some(latency) if latency >= LATENCY_SLOW => HealthLevel::Slow,
+            Some(_) => HealthLevel::Good,
+        }
+    }
+}
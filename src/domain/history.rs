@@ -6,4 +6,279 @@ pub struct HistoryEvent {
     pub event_type: String,
     pub timestamp: DateTime<Utc>,
     pub details: serde_json::Value,
+    /// Payloads among `details` that aren't text, by key; `details` holds
+    /// them in base64.
+    pub binary: Vec<(String, Vec<u8>)>,
+    pub user_metadata: super::UserMetadata,
+}
+
+impl HistoryEvent {
+    /// The payload at `key` in `details`.
+    pub fn payload(&self, key: &str) -> Option<super::Payload> {
+        match self.binary.iter().find(|(k, _)| k == key) {
+            Some((_, bytes)) => Some(super::Payload::Binary(bytes.clone())),
+            None => self.details.get(key).cloned().map(super::Payload::Json),
+        }
+    }
+}
+
+/// Scheduled→started latency of each workflow task, in history order. Workflow
+/// tasks never overlap, so each start pairs with the schedule before it.
+pub fn workflow_task_queue_times(events: &[HistoryEvent]) -> Vec<chrono::Duration> {
+    let mut scheduled: Option<DateTime<Utc>> = None;
+    let mut out = Vec::new();
+    for event in events {
+        match event.event_type.as_str() {
+            "WorkflowTaskScheduled" => scheduled = Some(event.timestamp),
+            "WorkflowTaskStarted" => {
+                if let Some(at) = scheduled.take() {
+                    out.push(event.timestamp - at);
+                }
+            }
+            _ => {}
+        }
+    }
+    out
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SpanKind {
+    Activity,
+    Timer,
+    ChildWorkflow,
+}
+
+/// An activity, timer or child workflow from scheduling to its close event.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TimelineSpan {
+    pub kind: SpanKind,
+    pub name: String,
+    /// Activity or child workflow type; empty for timers.
+    pub type_name: String,
+    pub start: DateTime<Utc>,
+    /// `None` while it's still open.
+    pub end: Option<DateTime<Utc>>,
+    /// e.g. `Completed` or `TimedOut`; `Running` while open.
+    pub outcome: String,
+    /// Activity attempts; 1 for timers and children.
+    pub attempts: i64,
+    /// On the chain of spans that kept the workflow from finishing sooner.
+    pub critical: bool,
+}
+
+impl TimelineSpan {
+    pub fn duration(&self, now: DateTime<Utc>) -> chrono::Duration {
+        self.end.unwrap_or(now) - self.start
+    }
+}
+
+/// Spans pieced together from history, in the order they started. Close
+/// events find their span through `scheduled_event_id`/`initiated_event_id`,
+/// or the timer ID.
+pub fn timeline_spans(events: &[HistoryEvent]) -> Vec<TimelineSpan> {
+    let text = |event: &HistoryEvent, key: &str| {
+        event
+            .details
+            .get(key)
+            .and_then(|v| v.as_str())
+            .unwrap_or_default()
+            .to_string()
+    };
+    // Int64 fields arrive as strings from the HTTP API.
+    let id = |event: &HistoryEvent, key: &str| match event.details.get(key) {
+        Some(serde_json::Value::String(s)) => s.parse::<i64>().ok(),
+        Some(v) => v.as_i64(),
+        None => None,
+    };
+
+    let mut spans: Vec<TimelineSpan> = Vec::new();
+    let mut by_event: std::collections::HashMap<i64, usize> = Default::default();
+    let mut timers: std::collections::HashMap<String, usize> = Default::default();
+    for event in events {
+        let event_type = event.event_type.as_str();
+        let open = |kind, name, type_name| TimelineSpan {
+            kind,
+            name,
+            type_name,
+            start: event.timestamp,
+            end: None,
+            outcome: "Running".to_string(),
+            attempts: 1,
+            critical: false,
+        };
+        match event_type {
+            "ActivityTaskScheduled" => {
+                let name = match text(event, "activity_id") {
+                    id if id.is_empty() => text(event, "activity_type"),
+                    id => format!("{} ({})", text(event, "activity_type"), id),
+                };
+                by_event.insert(event.event_id, spans.len());
+                let activity_type = text(event, "activity_type");
+                spans.push(open(SpanKind::Activity, name, activity_type));
+            }
+            "TimerStarted" => {
+                let timer_id = text(event, "timer_id");
+                timers.insert(timer_id.clone(), spans.len());
+                spans.push(open(
+                    SpanKind::Timer,
+                    format!("timer {}", timer_id),
+                    String::new(),
+                ));
+            }
+            "StartChildWorkflowExecutionInitiated" => {
+                let name = match text(event, "workflow_type") {
+                    t if t.is_empty() => text(event, "workflow_id"),
+                    t => format!("{} ({})", t, text(event, "workflow_id")),
+                };
+                by_event.insert(event.event_id, spans.len());
+                let workflow_type = text(event, "workflow_type");
+                spans.push(open(SpanKind::ChildWorkflow, name, workflow_type));
+            }
+            "ActivityTaskStarted" => {
+                let span = id(event, "scheduled_event_id").and_then(|i| by_event.get(&i));
+                if let (Some(&i), Some(attempt)) = (span, id(event, "attempt")) {
+                    spans[i].attempts = attempt.max(1);
+                }
+            }
+            "ChildWorkflowExecutionStarted" => {}
+            _ => {
+                let (span, outcome) = if let Some(outcome) = event_type
+                    .strip_prefix("ActivityTask")
+                    .filter(|o| matches!(*o, "Completed" | "Failed" | "TimedOut" | "Canceled"))
+                {
+                    let i = id(event, "scheduled_event_id").and_then(|i| by_event.get(&i));
+                    (i.copied(), outcome)
+                } else if let Some(outcome) = event_type.strip_prefix("Timer") {
+                    (timers.get(&text(event, "timer_id")).copied(), outcome)
+                } else if let Some(outcome) = event_type
+                    .strip_prefix("ChildWorkflowExecution")
+                    .or_else(|| {
+                        (event_type == "StartChildWorkflowExecutionFailed").then_some("Failed")
+                    })
+                {
+                    let i = id(event, "initiated_event_id").and_then(|i| by_event.get(&i));
+                    (i.copied(), outcome)
+                } else {
+                    (None, "")
+                };
+                if let Some(i) = span {
+                    spans[i].end = Some(event.timestamp);
+                    spans[i].outcome = outcome.to_string();
+                }
+            }
+        }
+    }
+    mark_critical_path(&mut spans);
+    spans
+}
+
+/// Walks back from the span that ended last, each time to the latest span
+/// that ended before the current one started.
+fn mark_critical_path(spans: &mut [TimelineSpan]) {
+    let far_future = DateTime::<Utc>::MAX_UTC;
+    let mut before = far_future;
+    loop {
+        let next = spans
+            .iter()
+            .enumerate()
+            .filter(|(_, s)| s.end.unwrap_or(far_future) <= before && s.start < before)
+            .max_by_key(|(_, s)| (s.end.unwrap_or(far_future), std::cmp::Reverse(s.start)))
+            .map(|(i, _)| i);
+        let Some(i) = next else { break };
+        spans[i].critical = true;
+        before = spans[i].start;
+    }
+}
+
+/// Totals for one activity type across a history.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ActivityHotspot {
+    pub activity_type: String,
+    pub count: usize,
+    pub total: chrono::Duration,
+    pub max: chrono::Duration,
+    /// Attempts beyond the first, summed over the activities.
+    pub retries: i64,
+    /// Activities that closed as anything but `Completed`.
+    pub failed: usize,
+}
+
+impl ActivityHotspot {
+    pub fn average(&self) -> chrono::Duration {
+        self.total / self.count.max(1) as i32
+    }
+}
+
+/// Activity spans grouped by type, slowest total first. Open activities
+/// count up to `now`.
+pub fn activity_hotspots(spans: &[TimelineSpan], now: DateTime<Utc>) -> Vec<ActivityHotspot> {
+    let mut hotspots: Vec<ActivityHotspot> = Vec::new();
+    for span in spans.iter().filter(|s| s.kind == SpanKind::Activity) {
+        let index = match hotspots
+            .iter()
+            .position(|h| h.activity_type == span.type_name)
+        {
+            Some(i) => i,
+            None => {
+                hotspots.push(ActivityHotspot {
+                    activity_type: span.type_name.clone(),
+                    count: 0,
+                    total: chrono::Duration::zero(),
+                    max: chrono::Duration::zero(),
+                    retries: 0,
+                    failed: 0,
+                });
+                hotspots.len() - 1
+            }
+        };
+        let hotspot = &mut hotspots[index];
+        let duration = span.duration(now);
+        hotspot.count += 1;
+        hotspot.total += duration;
+        hotspot.max = hotspot.max.max(duration);
+        hotspot.retries += span.attempts - 1;
+        if !matches!(span.outcome.as_str(), "Completed" | "Running") {
+            hotspot.failed += 1;
+        }
+    }
+    hotspots.sort_by_key(|h| std::cmp::Reverse(h.total));
+    hotspots
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Duration;
+
+    #[test]
+    fn hotspots_group_activities_by_type() {
+        let start = DateTime::from_timestamp(1_700_000_000, 0).unwrap();
+        let span = |type_name: &str, secs: i64, attempts: i64, outcome: &str| TimelineSpan {
+            kind: SpanKind::Activity,
+            name: type_name.to_string(),
+            type_name: type_name.to_string(),
+            start,
+            end: Some(start + Duration::seconds(secs)),
+            outcome: outcome.to_string(),
+            attempts,
+            critical: false,
+        };
+        let mut timer = span("", 500, 1, "Fired");
+        timer.kind = SpanKind::Timer;
+        let spans = [
+            span("Charge", 10, 1, "Completed"),
+            span("Ship", 30, 4, "Failed"),
+            span("Charge", 20, 2, "Completed"),
+            timer,
+        ];
+
+        let hotspots = activity_hotspots(&spans, start);
+        assert_eq!(hotspots.len(), 2);
+        assert_eq!(hotspots[0].activity_type, "Charge");
+        assert_eq!(hotspots[0].count, 2);
+        assert_eq!(hotspots[0].average(), Duration::seconds(15));
+        assert_eq!(hotspots[0].max, Duration::seconds(20));
+        assert_eq!((hotspots[0].retries, hotspots[0].failed), (1, 0));
+        assert_eq!((hotspots[1].retries, hotspots[1].failed), (3, 1));
+    }
 }
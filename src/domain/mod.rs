@@ -1,15 +1,27 @@
 pub mod activity;
 pub mod activity_execution;
+pub mod binary;
+pub mod cluster;
 pub mod history;
 pub mod namespace;
+pub mod overview;
 pub mod schedule;
+pub mod search_attribute;
 pub mod task_queue;
+pub mod time;
+pub mod versioning;
 pub mod workflow;
 
 pub use activity::*;
 pub use activity_execution::*;
+pub use binary::*;
+pub use cluster::*;
 pub use history::*;
 pub use namespace::*;
+pub use overview::*;
 pub use schedule::*;
+pub use search_attribute::*;
 pub use task_queue::*;
+pub use time::*;
+pub use versioning::*;
 pub use workflow::*;
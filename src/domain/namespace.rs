@@ -6,3 +6,31 @@ pub struct Namespace {
     pub owner_email: String,
     pub retention: Option<std::time::Duration>,
 }
+
+/// A namespace administration request, confirmed before it is sent.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum NamespaceChange {
+    Register {
+        name: String,
+        retention: std::time::Duration,
+        description: String,
+    },
+    Update {
+        name: String,
+        retention: Option<std::time::Duration>,
+        description: Option<String>,
+    },
+    Deprecate {
+        name: String,
+    },
+}
+
+impl NamespaceChange {
+    pub fn name(&self) -> &str {
+        match self {
+            Self::Register { name, .. } | Self::Update { name, .. } | Self::Deprecate { name } => {
+                name
+            }
+        }
+    }
+}
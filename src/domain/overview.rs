@@ -0,0 +1,192 @@
+use chrono::{DateTime, SecondsFormat, Utc};
+
+use super::WorkflowStatus;
+use crate::input::query_syntax::escape_single_quotes;
+
+pub const STATUS_SECTION: &str = "Workflows by status";
+pub const TYPE_SECTION: &str = "Workflows by type";
+pub const SCHEDULE_SECTION: &str = "Schedules";
+pub const TASK_QUEUE_SECTION: &str = "Task queues without pollers";
+
+/// A namespace at a glance, for the `:overview` landing page.
+#[derive(Debug, Clone, Default)]
+pub struct NamespaceOverview {
+    pub status_counts: Vec<(WorkflowStatus, u64)>,
+    /// Workflow types seen among recent runs with their total counts,
+    /// largest first.
+    pub type_counts: Vec<(String, u64)>,
+    pub schedules: usize,
+    pub paused_schedules: usize,
+    /// Whether there are more schedules than the ones counted.
+    pub more_schedules: bool,
+    /// Schedules whose runs failed or timed out since `failing_since`, with
+    /// how many did.
+    pub failing_schedules: Vec<(String, u64)>,
+    pub failing_since: DateTime<Utc>,
+    /// Task queues of running workflows that no worker polls. `None` when
+    /// the backend can't describe task queues.
+    pub idle_task_queues: Option<Vec<String>>,
+    /// Sections that failed to load, with why. The rest still show.
+    pub errors: Vec<(&'static str, String)>,
+}
+
+/// One line of the overview; rows with a query drill into the workflow list.
+#[derive(Debug, Clone, PartialEq)]
+pub struct OverviewRow {
+    pub section: &'static str,
+    pub label: String,
+    pub count: Option<u64>,
+    pub query: Option<String>,
+    /// Whether the label is why the section failed to load.
+    pub error: bool,
+}
+
+impl OverviewRow {
+    fn new(
+        section: &'static str,
+        label: String,
+        count: Option<u64>,
+        query: Option<String>,
+    ) -> Self {
+        Self {
+            section,
+            label,
+            count,
+            query,
+            error: false,
+        }
+    }
+}
+
+impl NamespaceOverview {
+    pub fn rows(&self) -> Vec<OverviewRow> {
+        let mut rows = Vec::new();
+        for (status, count) in &self.status_counts {
+            rows.push(OverviewRow::new(
+                STATUS_SECTION,
+                status.as_str().to_string(),
+                Some(*count),
+                Some(format!("ExecutionStatus = '{}'", status.as_str())),
+            ));
+        }
+        self.push_errors(&mut rows, STATUS_SECTION);
+        for (workflow_type, count) in &self.type_counts {
+            rows.push(OverviewRow::new(
+                TYPE_SECTION,
+                workflow_type.clone(),
+                Some(*count),
+                Some(format!(
+                    "WorkflowType = '{}'",
+                    escape_single_quotes(workflow_type)
+                )),
+            ));
+        }
+        self.push_errors(&mut rows, TYPE_SECTION);
+        if !self.failed(SCHEDULE_SECTION) {
+            let plus = if self.more_schedules { "+" } else { "" };
+            rows.push(OverviewRow::new(
+                SCHEDULE_SECTION,
+                format!(
+                    "{}{} schedules, {} paused",
+                    self.schedules, plus, self.paused_schedules
+                ),
+                None,
+                None,
+            ));
+        }
+        let since = self
+            .failing_since
+            .to_rfc3339_opts(SecondsFormat::Secs, true);
+        for (schedule_id, count) in &self.failing_schedules {
+            rows.push(OverviewRow::new(
+                SCHEDULE_SECTION,
+                format!("{} failing", schedule_id),
+                Some(*count),
+                Some(format!(
+                    "TemporalScheduledById = '{}' AND ExecutionStatus IN ('Failed', 'TimedOut') \
+                     AND CloseTime > '{}'",
+                    escape_single_quotes(schedule_id),
+                    since
+                )),
+            ));
+        }
+        self.push_errors(&mut rows, SCHEDULE_SECTION);
+        match &self.idle_task_queues {
+            Some(queues) if queues.is_empty() && !self.failed(TASK_QUEUE_SECTION) => rows.push(
+                OverviewRow::new(TASK_QUEUE_SECTION, "none".to_string(), None, None),
+            ),
+            Some(queues) => rows.extend(queues.iter().map(|queue| {
+                OverviewRow::new(
+                    TASK_QUEUE_SECTION,
+                    queue.clone(),
+                    None,
+                    Some(format!(
+                        "TaskQueue = '{}' AND ExecutionStatus = 'Running'",
+                        escape_single_quotes(queue)
+                    )),
+                )
+            })),
+            None => {}
+        }
+        self.push_errors(&mut rows, TASK_QUEUE_SECTION);
+        rows
+    }
+
+    fn failed(&self, section: &str) -> bool {
+        self.errors.iter().any(|(s, _)| *s == section)
+    }
+
+    fn push_errors(&self, rows: &mut Vec<OverviewRow>, section: &'static str) {
+        rows.extend(
+            self.errors
+                .iter()
+                .filter(|(s, _)| *s == section)
+                .map(|(_, error)| OverviewRow {
+                    error: true,
+                    ..OverviewRow::new(section, error.clone(), None, None)
+                }),
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rows_drill_into_matching_workflows() {
+        let overview = NamespaceOverview {
+            status_counts: vec![(WorkflowStatus::Failed, 3)],
+            type_counts: vec![("Order's".to_string(), 40)],
+            schedules: 100,
+            paused_schedules: 2,
+            more_schedules: true,
+            failing_schedules: vec![("nightly".to_string(), 2)],
+            failing_since: "2024-05-01T12:00:00Z".parse().unwrap(),
+            idle_task_queues: None,
+            errors: vec![(TYPE_SECTION, "deadline exceeded".to_string())],
+        };
+        let rows = overview.rows();
+        let summary: Vec<(&str, Option<&str>)> = rows
+            .iter()
+            .map(|r| (r.label.as_str(), r.query.as_deref()))
+            .collect();
+        assert_eq!(
+            summary,
+            vec![
+                ("Failed", Some("ExecutionStatus = 'Failed'")),
+                ("Order's", Some("WorkflowType = 'Order\\'s'")),
+                ("deadline exceeded", None),
+                ("100+ schedules, 2 paused", None),
+                (
+                    "nightly failing",
+                    Some(
+                        "TemporalScheduledById = 'nightly' AND ExecutionStatus IN ('Failed', 'TimedOut') \
+                         AND CloseTime > '2024-05-01T12:00:00Z'"
+                    )
+                ),
+            ]
+        );
+        assert!(rows[2].error);
+    }
+}
@@ -1,4 +1,14 @@
-use chrono::{DateTime, Utc};
+use chrono::{DateTime, Duration, NaiveDate, NaiveDateTime, NaiveTime, Offset, TimeZone, Utc};
+use chrono_tz::Tz;
+use serde::{Deserialize, Serialize};
+
+use super::{parse_duration_text, WorkflowStatus};
+
+/// How far ahead [`Schedule::dst_warnings`] looks for clock changes.
+pub const DST_LOOKAHEAD_DAYS: i64 = 366;
+
+/// How many upcoming runs the schedule detail lists.
+pub const UPCOMING_RUNS: usize = 10;
 
 #[derive(Debug, Clone)]
 pub struct Schedule {
@@ -9,6 +19,286 @@ pub struct Schedule {
     pub next_run: Option<DateTime<Utc>>,
     pub recent_action_count: u64,
     pub notes: String,
+    /// IANA zone the calendar specs are interpreted in. `None` means UTC.
+    pub timezone: Option<String>,
+    pub calendars: Vec<ScheduleCalendar>,
+    /// Most recent actions first. Only filled in by describe.
+    pub recent_actions: Vec<ScheduleAction>,
+    /// Next action times, soonest first. Only filled in by describe.
+    pub upcoming_runs: Vec<DateTime<Utc>>,
+}
+
+/// A workflow start the schedule has taken.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ScheduleAction {
+    /// When the schedule called for the action, including jitter.
+    pub scheduled_time: Option<DateTime<Utc>>,
+    pub actual_time: Option<DateTime<Utc>>,
+    pub workflow_id: String,
+    pub run_id: String,
+    /// Eventually consistent; `None` when the server didn't report one.
+    pub status: Option<WorkflowStatus>,
+}
+
+impl Schedule {
+    pub fn tz(&self) -> Option<Tz> {
+        self.timezone.as_deref().and_then(|name| name.parse().ok())
+    }
+
+    /// Formats `t` as wall-clock time in the schedule's zone, falling back to
+    /// local time when the schedule has no (known) zone.
+    pub fn format_in_zone(&self, t: &DateTime<Utc>) -> String {
+        match self.tz() {
+            Some(tz) => t
+                .with_timezone(&tz)
+                .format("%Y-%m-%d %H:%M:%S %Z")
+                .to_string(),
+            None => t
+                .with_timezone(&chrono::Local)
+                .format("%Y-%m-%d %H:%M:%S")
+                .to_string(),
+        }
+    }
+
+    /// Calendar times that fall into a DST gap (skipped) or overlap (repeated)
+    /// within `days` of `from`.
+    pub fn dst_warnings(&self, from: DateTime<Utc>, days: i64) -> Vec<DstWarning> {
+        let Some(tz) = self.tz() else {
+            return vec![];
+        };
+        if self.calendars.is_empty() {
+            return vec![];
+        }
+        let offset_at = |t: DateTime<Utc>| {
+            tz.offset_from_utc_datetime(&t.naive_utc())
+                .fix()
+                .local_minus_utc()
+        };
+
+        let mut out: Vec<DstWarning> = Vec::new();
+        let end = from + Duration::days(days);
+        let mut t = from;
+        while t < end {
+            let next = t + Duration::days(1);
+            let before = offset_at(t);
+            let after = offset_at(next);
+            if before != after {
+                let (mut lo, mut hi) = (t, next);
+                while hi - lo > Duration::seconds(1) {
+                    let mid = lo + (hi - lo) / 2;
+                    if offset_at(mid) == before {
+                        lo = mid;
+                    } else {
+                        hi = mid;
+                    }
+                }
+                let wall_old = hi.naive_utc() + Duration::seconds(before as i64);
+                let wall_new = hi.naive_utc() + Duration::seconds(after as i64);
+                let (start, stop, kind) = if after > before {
+                    (wall_old, wall_new, DstKind::Skipped)
+                } else {
+                    (wall_new, wall_old, DstKind::Repeated)
+                };
+                for at in self.local_times_between(start, stop) {
+                    if !out.iter().any(|w| w.at == at) {
+                        out.push(DstWarning { at, kind });
+                    }
+                }
+            }
+            t = next;
+        }
+        out
+    }
+
+    fn local_times_between(&self, start: NaiveDateTime, stop: NaiveDateTime) -> Vec<NaiveDateTime> {
+        let mut dates: Vec<NaiveDate> = vec![start.date()];
+        if stop.date() != start.date() {
+            dates.push(stop.date());
+        }
+        let mut out = Vec::new();
+        for cal in &self.calendars {
+            for date in &dates {
+                for &hour in &cal.hours {
+                    for &minute in &cal.minutes {
+                        let Some(time) = NaiveTime::from_hms_opt(hour, minute, 0) else {
+                            continue;
+                        };
+                        let at = date.and_time(time);
+                        if at >= start && at < stop && !out.contains(&at) {
+                            out.push(at);
+                        }
+                    }
+                }
+            }
+        }
+        out.sort();
+        out
+    }
+}
+
+/// The editable parts of a schedule, round-tripped through `$EDITOR` as JSON.
+/// Fields left as they were keep the schedule's original (possibly richer)
+/// definition when the edit is applied.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct ScheduleEdit {
+    /// "minute hour day-of-month month day-of-week" lines.
+    pub cron: Vec<String>,
+    /// "1h", or "1h/15m" to fire 15 minutes into each hour.
+    pub intervals: Vec<String>,
+    pub timezone: String,
+    pub jitter: Option<String>,
+    pub workflow_type: String,
+    pub workflow_id: String,
+    pub task_queue: String,
+    /// Workflow arguments, one JSON value each.
+    pub input: Vec<serde_json::Value>,
+    pub execution_timeout: Option<String>,
+    pub run_timeout: Option<String>,
+    pub task_timeout: Option<String>,
+}
+
+impl ScheduleEdit {
+    /// Parses an edited document, rejecting ones the server would refuse or
+    /// misread.
+    pub fn parse(text: &str) -> Result<Self, String> {
+        let edit: Self = serde_json::from_str(text).map_err(|e| e.to_string())?;
+        if edit.cron.is_empty() && edit.intervals.is_empty() {
+            return Err("a schedule needs at least one cron line or interval".to_string());
+        }
+        if edit.workflow_type.trim().is_empty() || edit.task_queue.trim().is_empty() {
+            return Err("workflow_type and task_queue are required".to_string());
+        }
+        for interval in &edit.intervals {
+            if Self::parse_interval(interval).is_none() {
+                return Err(format!("invalid interval {:?}", interval));
+            }
+        }
+        let durations = [
+            ("jitter", &edit.jitter),
+            ("execution_timeout", &edit.execution_timeout),
+            ("run_timeout", &edit.run_timeout),
+            ("task_timeout", &edit.task_timeout),
+        ];
+        for (field, value) in durations {
+            if let Some(value) = value {
+                if parse_duration_text(value).is_none() {
+                    return Err(format!("invalid {} {:?}", field, value));
+                }
+            }
+        }
+        Ok(edit)
+    }
+
+    /// Splits "every/offset" into seconds.
+    pub fn parse_interval(interval: &str) -> Option<(i64, i64)> {
+        let (every, offset) = match interval.split_once('/') {
+            Some((every, offset)) => (every, parse_duration_text(offset)?),
+            None => (interval, 0),
+        };
+        let every = parse_duration_text(every).filter(|s| *s > 0)?;
+        Some((every, offset))
+    }
+}
+
+/// A schedule opened for editing, with the token that makes the update fail
+/// if someone else changed it meanwhile.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ScheduleDraft {
+    pub schedule_id: String,
+    pub edit: ScheduleEdit,
+    pub conflict_token: Vec<u8>,
+}
+
+/// The hours and minutes a calendar spec fires at. Day fields are ignored;
+/// they only narrow which days a DST warning actually applies to.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ScheduleCalendar {
+    pub hours: Vec<u32>,
+    pub minutes: Vec<u32>,
+}
+
+impl ScheduleCalendar {
+    /// Builds a calendar from cron-style minute and hour fields ("*", "*/15",
+    /// "1-5", "0,30", "9-17/2").
+    pub fn from_fields(minute: &str, hour: &str) -> Option<Self> {
+        Some(Self {
+            minutes: parse_cron_field(minute, 59)?,
+            hours: parse_cron_field(hour, 23)?,
+        })
+    }
+
+    /// Parses a Temporal cron string. Returns the `CRON_TZ=` zone, if any,
+    /// alongside the calendar. `@every` intervals have no calendar.
+    pub fn from_cron(cron: &str) -> Option<(Option<String>, Self)> {
+        let mut cron = cron.trim();
+        let mut tz = None;
+        if let Some(rest) = cron
+            .strip_prefix("CRON_TZ=")
+            .or_else(|| cron.strip_prefix("TZ="))
+        {
+            let (zone, rest) = rest.split_once(char::is_whitespace)?;
+            tz = Some(zone.to_string());
+            cron = rest.trim();
+        }
+        let calendar = match cron {
+            "@yearly" | "@annually" | "@monthly" | "@weekly" | "@daily" | "@midnight" => {
+                Self::from_fields("0", "0")?
+            }
+            "@hourly" => Self::from_fields("0", "*")?,
+            _ => {
+                let fields: Vec<&str> = cron.split_whitespace().collect();
+                match fields.len() {
+                    5 | 6 => Self::from_fields(fields[0], fields[1])?,
+                    7 => Self::from_fields(fields[1], fields[2])?,
+                    _ => return None,
+                }
+            }
+        };
+        Some((tz, calendar))
+    }
+}
+
+fn parse_cron_field(field: &str, max: u32) -> Option<Vec<u32>> {
+    let mut values = Vec::new();
+    for term in field.split(',') {
+        let (range, step) = match term.split_once('/') {
+            Some((range, step)) => (range, step.parse::<u32>().ok().filter(|s| *s > 0)?),
+            None => (term, 1),
+        };
+        let (start, end) = match range {
+            "*" | "?" => (0, max),
+            _ => match range.split_once('-') {
+                Some((a, b)) => (a.parse().ok()?, b.parse().ok()?),
+                None => {
+                    let a: u32 = range.parse().ok()?;
+                    (a, if term.contains('/') { max } else { a })
+                }
+            },
+        };
+        if start > end || end > max {
+            return None;
+        }
+        values.extend((start..=end).step_by(step as usize));
+    }
+    values.sort_unstable();
+    values.dedup();
+    Some(values)
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DstKind {
+    /// The wall-clock time does not exist; the run is skipped.
+    Skipped,
+    /// The wall-clock time happens twice; the run may fire twice.
+    Repeated,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DstWarning {
+    /// Local wall-clock time of the affected run.
+    pub at: NaiveDateTime,
+    pub kind: DstKind,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -31,3 +321,119 @@ impl std::fmt::Display for ScheduleState {
         write!(f, "{}", self.as_str())
     }
 }
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ScheduleGrouping {
+    #[default]
+    None,
+    WorkflowType,
+    State,
+}
+
+impl ScheduleGrouping {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::None => "none",
+            Self::WorkflowType => "type",
+            Self::State => "state",
+        }
+    }
+
+    pub fn from_param(value: &str) -> Option<Self> {
+        match value.to_lowercase().as_str() {
+            "none" | "off" => Some(Self::None),
+            "type" | "workflow-type" | "workflow_type" => Some(Self::WorkflowType),
+            "state" | "status" => Some(Self::State),
+            _ => None,
+        }
+    }
+
+    /// Cycle none -> workflow type -> state -> none.
+    pub fn next(self) -> Self {
+        match self {
+            Self::None => Self::WorkflowType,
+            Self::WorkflowType => Self::State,
+            Self::State => Self::None,
+        }
+    }
+
+    pub fn group_key(&self, schedule: &Schedule) -> String {
+        match self {
+            Self::None => String::new(),
+            Self::WorkflowType if schedule.workflow_type.is_empty() => "(unknown)".to_string(),
+            Self::WorkflowType => schedule.workflow_type.clone(),
+            Self::State => schedule.state.as_str().to_string(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn dst_warnings_flag_skipped_and_repeated_runs() {
+        let (tz, calendar) = ScheduleCalendar::from_cron("CRON_TZ=America/New_York 30 1,2 * * *")
+            .expect("cron parses");
+        assert_eq!(calendar.minutes, vec![30]);
+        assert_eq!(calendar.hours, vec![1, 2]);
+        let schedule = Schedule {
+            schedule_id: "nightly".to_string(),
+            workflow_type: String::new(),
+            state: ScheduleState::Active,
+            spec_description: String::new(),
+            next_run: None,
+            recent_action_count: 0,
+            notes: String::new(),
+            timezone: tz,
+            calendars: vec![calendar],
+            recent_actions: vec![],
+            upcoming_runs: vec![],
+        };
+
+        let from = Utc.with_ymd_and_hms(2026, 1, 1, 0, 0, 0).unwrap();
+        let warnings = schedule.dst_warnings(from, DST_LOOKAHEAD_DAYS);
+        let at = |d: u32, m: u32, h: u32| {
+            NaiveDate::from_ymd_opt(2026, m, d)
+                .unwrap()
+                .and_hms_opt(h, 30, 0)
+                .unwrap()
+        };
+        assert_eq!(
+            warnings,
+            vec![
+                DstWarning {
+                    at: at(8, 3, 2),
+                    kind: DstKind::Skipped,
+                },
+                DstWarning {
+                    at: at(1, 11, 1),
+                    kind: DstKind::Repeated,
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn schedule_edit_parses_and_validates() {
+        let edit = ScheduleEdit::parse(
+            r#"{"intervals": ["1h/15m"], "workflow_type": "Sync", "task_queue": "q",
+                "run_timeout": "30m"}"#,
+        )
+        .unwrap();
+        assert_eq!(
+            ScheduleEdit::parse_interval(&edit.intervals[0]),
+            Some((3600, 900))
+        );
+        assert_eq!(edit.run_timeout.as_deref(), Some("30m"));
+
+        let err = |text: &str| ScheduleEdit::parse(text).unwrap_err();
+        assert!(err(r#"{"workflow_type": "Sync", "task_queue": "q"}"#).contains("cron"));
+        assert!(err(
+            r#"{"cron": ["0 * * * *"], "workflow_type": "Sync", "task_queue": "q",
+                        "jitter": "soon"}"#
+        )
+        .contains("jitter"));
+        assert!(err(r#"{"crons": []}"#).contains("unknown field"));
+    }
+}
@@ -0,0 +1,52 @@
+/// Value type of a search attribute, as registered on the namespace.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum SearchAttributeType {
+    Keyword,
+    Text,
+    Int,
+    Double,
+    Bool,
+    Datetime,
+    KeywordList,
+}
+
+impl SearchAttributeType {
+    pub const ALL: &'static [SearchAttributeType] = &[
+        Self::Keyword,
+        Self::Text,
+        Self::Int,
+        Self::Double,
+        Self::Bool,
+        Self::Datetime,
+        Self::KeywordList,
+    ];
+
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::Keyword => "Keyword",
+            Self::Text => "Text",
+            Self::Int => "Int",
+            Self::Double => "Double",
+            Self::Bool => "Bool",
+            Self::Datetime => "Datetime",
+            Self::KeywordList => "KeywordList",
+        }
+    }
+
+    /// Parses a label, ignoring case.
+    pub fn parse(s: &str) -> Option<Self> {
+        Self::ALL
+            .iter()
+            .copied()
+            .find(|t| t.label().eq_ignore_ascii_case(s))
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct SearchAttribute {
+    pub name: String,
+    /// `None` when the server reports a type t9s doesn't know.
+    pub value_type: Option<SearchAttributeType>,
+    /// Registered by users rather than predefined by the server.
+    pub custom: bool,
+}
@@ -3,7 +3,9 @@ use chrono::{DateTime, Utc};
 #[derive(Debug, Clone)]
 pub struct TaskQueueInfo {
     pub name: String,
-    pub pollers: Vec<Poller>,
+    pub workflow_pollers: Vec<Poller>,
+    pub activity_pollers: Vec<Poller>,
+    pub versioning: super::TaskQueueVersioning,
 }
 
 #[derive(Debug, Clone)]
@@ -11,4 +13,6 @@ pub struct Poller {
     pub identity: String,
     pub last_access_time: Option<DateTime<Utc>>,
     pub rate_per_second: f64,
+    /// Deployment version the worker polls with, if it is versioned.
+    pub version: Option<String>,
 }
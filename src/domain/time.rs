@@ -0,0 +1,282 @@
+use chrono::{DateTime, Utc};
+use chrono_tz::Tz;
+
+use crate::input::query_syntax::split_order_by;
+
+/// Zone absolute timestamps are shown in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum DisplayZone {
+    #[default]
+    Local,
+    Utc,
+    Named(Tz),
+}
+
+impl DisplayZone {
+    /// `local`, `utc` or an IANA zone name such as `Europe/Berlin`.
+    pub fn parse(s: &str) -> Option<Self> {
+        match s.trim().to_ascii_lowercase().as_str() {
+            "local" => Some(Self::Local),
+            "utc" | "z" => Some(Self::Utc),
+            _ => s.trim().parse().ok().map(Self::Named),
+        }
+    }
+
+    pub fn label(&self) -> String {
+        match self {
+            Self::Local => "local".to_string(),
+            Self::Utc => "UTC".to_string(),
+            Self::Named(tz) => tz.name().to_string(),
+        }
+    }
+}
+
+/// How timestamps render in lists and detail views.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct TimeDisplay {
+    pub relative: bool,
+    pub zone: DisplayZone,
+}
+
+impl TimeDisplay {
+    pub fn format(&self, t: &DateTime<Utc>) -> String {
+        if self.relative {
+            format_relative(t, Utc::now())
+        } else {
+            self.absolute(t)
+        }
+    }
+
+    pub fn absolute(&self, t: &DateTime<Utc>) -> String {
+        const FMT: &str = "%Y-%m-%d %H:%M:%S";
+        match self.zone {
+            DisplayZone::Local => t.with_timezone(&chrono::Local).format(FMT).to_string(),
+            DisplayZone::Utc => t.format(FMT).to_string(),
+            DisplayZone::Named(tz) => t.with_timezone(&tz).format(FMT).to_string(),
+        }
+    }
+}
+
+/// "5m ago" / "in 2h" using the largest whole unit.
+pub fn format_relative(t: &DateTime<Utc>, now: DateTime<Utc>) -> String {
+    let secs = (*t - now).num_seconds();
+    let abs = secs.unsigned_abs();
+    if abs < 5 {
+        return "just now".to_string();
+    }
+    let span = match abs {
+        s if s < 60 => format!("{}s", s),
+        s if s < 3600 => format!("{}m", s / 60),
+        s if s < 86400 => format!("{}h", s / 3600),
+        s if s < 86400 * 60 => format!("{}d", s / 86400),
+        s if s < 86400 * 730 => format!("{}mo", s / (86400 * 30)),
+        s => format!("{}y", s / (86400 * 365)),
+    };
+    if secs < 0 {
+        format!("{} ago", span)
+    } else {
+        format!("in {}", span)
+    }
+}
+
+/// Compact span for latencies and run times: "35ms", "1.2s", "4m12s", "3h5m", "2d4h".
+pub fn format_span(d: chrono::Duration) -> String {
+    let ms = d.num_milliseconds().max(0);
+    let secs = ms / 1000;
+    match secs {
+        _ if ms < 1000 => format!("{}ms", ms),
+        s if s < 60 => format!("{:.1}s", ms as f64 / 1000.0),
+        s if s < 3600 => format!("{}m{}s", s / 60, s % 60),
+        s if s < 86400 => format!("{}h{}m", s / 3600, (s % 3600) / 60),
+        s => format!("{}d{}h", s / 86400, (s % 86400) / 3600),
+    }
+}
+
+/// Whole seconds as "1h30m", "45s" or "0s", the form schedule edits use.
+pub fn format_duration_text(secs: i64) -> String {
+    if secs <= 0 {
+        return "0s".to_string();
+    }
+    let mut out = String::new();
+    let mut rest = secs;
+    for (unit, size) in [("d", 86400), ("h", 3600), ("m", 60), ("s", 1)] {
+        if rest >= size {
+            out.push_str(&format!("{}{}", rest / size, unit));
+            rest %= size;
+        }
+    }
+    out
+}
+
+/// Parses [`format_duration_text`] output back into seconds. A bare number
+/// is seconds.
+pub fn parse_duration_text(s: &str) -> Option<i64> {
+    let s = s.trim();
+    if s.is_empty() {
+        return None;
+    }
+    if let Ok(secs) = s.parse::<i64>() {
+        return (secs >= 0).then_some(secs);
+    }
+    let mut total = 0i64;
+    let mut digits = String::new();
+    for c in s.chars() {
+        if c.is_ascii_digit() {
+            digits.push(c);
+            continue;
+        }
+        let size = match c {
+            'd' => 86400,
+            'h' => 3600,
+            'm' => 60,
+            's' => 1,
+            _ => return None,
+        };
+        total += digits.parse::<i64>().ok()? * size;
+        digits.clear();
+    }
+    digits.is_empty().then_some(total)
+}
+
+/// Quick StartTime filter for the workflow list, composed with the search
+/// query each time the list loads so "last hour" keeps meaning that.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TimeRange {
+    Last15m,
+    LastHour,
+    LastDay,
+    LastWeek,
+}
+
+impl TimeRange {
+    pub const ALL: [TimeRange; 4] = [Self::Last15m, Self::LastHour, Self::LastDay, Self::LastWeek];
+
+    /// Short form, as used in deep links (`since=1h`).
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::Last15m => "15m",
+            Self::LastHour => "1h",
+            Self::LastDay => "24h",
+            Self::LastWeek => "7d",
+        }
+    }
+
+    pub fn description(self) -> &'static str {
+        match self {
+            Self::Last15m => "Last 15 minutes",
+            Self::LastHour => "Last hour",
+            Self::LastDay => "Last 24 hours",
+            Self::LastWeek => "Last 7 days",
+        }
+    }
+
+    pub fn parse(s: &str) -> Option<Self> {
+        let secs = parse_duration_text(s)?;
+        Self::ALL
+            .into_iter()
+            .find(|range| range.duration().num_seconds() == secs)
+    }
+
+    pub fn duration(self) -> chrono::Duration {
+        match self {
+            Self::Last15m => chrono::Duration::minutes(15),
+            Self::LastHour => chrono::Duration::hours(1),
+            Self::LastDay => chrono::Duration::days(1),
+            Self::LastWeek => chrono::Duration::days(7),
+        }
+    }
+
+    /// `query` narrowed to workflows started within the range before `now`.
+    /// A trailing ORDER BY stays at the end.
+    pub fn apply(self, query: Option<&str>, now: DateTime<Utc>) -> String {
+        let clause = format!(
+            "StartTime > '{}'",
+            (now - self.duration()).to_rfc3339_opts(chrono::SecondsFormat::Secs, true)
+        );
+        let (filter, order) = split_order_by(query.unwrap_or(""));
+        let filtered = if filter.is_empty() {
+            clause
+        } else {
+            format!("({}) AND {}", filter, clause)
+        };
+        if order.is_empty() {
+            filtered
+        } else {
+            format!("{} {}", filtered, order)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Duration;
+
+    #[test]
+    fn time_ranges_compose_with_the_query() {
+        let now = DateTime::parse_from_rfc3339("2024-01-01T12:00:00Z")
+            .unwrap()
+            .with_timezone(&Utc);
+        assert_eq!(
+            TimeRange::LastHour.apply(None, now),
+            "StartTime > '2024-01-01T11:00:00Z'"
+        );
+        assert_eq!(
+            TimeRange::Last15m.apply(
+                Some("WorkflowType = 'a' OR WorkflowType = 'b' order by StartTime"),
+                now
+            ),
+            "(WorkflowType = 'a' OR WorkflowType = 'b') AND StartTime > '2024-01-01T11:45:00Z' \
+             order by StartTime"
+        );
+        // Quoted words aren't an ORDER BY.
+        assert_eq!(
+            TimeRange::LastHour.apply(Some("WorkflowId = 'sort order by date'"), now),
+            "(WorkflowId = 'sort order by date') AND StartTime > '2024-01-01T11:00:00Z'"
+        );
+        assert_eq!(TimeRange::parse("24h"), Some(TimeRange::LastDay));
+        assert_eq!(TimeRange::parse("7d"), Some(TimeRange::LastWeek));
+        assert_eq!(TimeRange::parse("2h"), None);
+    }
+
+    #[test]
+    fn relative_times_and_zones() {
+        let now = Utc::now();
+        assert_eq!(format_relative(&now, now), "just now");
+        assert_eq!(
+            format_relative(&(now - Duration::minutes(5)), now),
+            "5m ago"
+        );
+        assert_eq!(format_relative(&(now + Duration::hours(2)), now), "in 2h");
+        assert_eq!(format_relative(&(now - Duration::days(3)), now), "3d ago");
+
+        assert_eq!(DisplayZone::parse("UTC"), Some(DisplayZone::Utc));
+        assert_eq!(DisplayZone::parse("local"), Some(DisplayZone::Local));
+        assert_eq!(
+            DisplayZone::parse("Asia/Tokyo"),
+            Some(DisplayZone::Named(chrono_tz::Asia::Tokyo))
+        );
+        assert_eq!(DisplayZone::parse("Mars/Olympus"), None);
+
+        let display = TimeDisplay {
+            relative: false,
+            zone: DisplayZone::Named(chrono_tz::Asia::Tokyo),
+        };
+        let t = DateTime::parse_from_rfc3339("2024-01-01T00:00:00Z")
+            .unwrap()
+            .with_timezone(&Utc);
+        assert_eq!(display.absolute(&t), "2024-01-01 09:00:00");
+
+        assert_eq!(format_span(Duration::milliseconds(35)), "35ms");
+        assert_eq!(format_span(Duration::milliseconds(1250)), "1.2s");
+        assert_eq!(format_span(Duration::seconds(252)), "4m12s");
+        assert_eq!(format_span(Duration::hours(50)), "2d2h");
+
+        assert_eq!(format_duration_text(5400), "1h30m");
+        assert_eq!(format_duration_text(0), "0s");
+        assert_eq!(parse_duration_text("1h30m"), Some(5400));
+        assert_eq!(parse_duration_text("90"), Some(90));
+        assert_eq!(parse_duration_text("1h30"), None);
+        assert_eq!(parse_duration_text("5x"), None);
+    }
+}
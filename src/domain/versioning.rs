@@ -0,0 +1,24 @@
+/// Worker versioning state of a task queue: where new work is routed, and
+/// the older build-id rules if any are set.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct TaskQueueVersioning {
+    /// `None` routes to unversioned workers.
+    pub current_version: Option<String>,
+    pub ramping_version: Option<String>,
+    pub ramping_percentage: f32,
+    /// Build-id assignment rules in precedence order, e.g. `build-2 (ramp 10%)`.
+    pub assignment_rules: Vec<String>,
+    /// Compatible redirect rules, e.g. `build-1 -> build-2`.
+    pub redirect_rules: Vec<String>,
+}
+
+/// Worker versioning state of a workflow execution.
+#[derive(Debug, Clone, PartialEq)]
+pub struct WorkflowVersioning {
+    /// `Pinned`, `AutoUpgrade` or `Unversioned`.
+    pub behavior: String,
+    /// Version that completed the last workflow task.
+    pub version: Option<String>,
+    /// Override set on the execution, e.g. `Pinned to orders@build-2`.
+    pub versioning_override: Option<String>,
+}
@@ -13,6 +13,16 @@ pub enum WorkflowStatus {
 }
 
 impl WorkflowStatus {
+    pub const ALL: [WorkflowStatus; 7] = [
+        Self::Running,
+        Self::Completed,
+        Self::Failed,
+        Self::Canceled,
+        Self::Terminated,
+        Self::TimedOut,
+        Self::ContinuedAsNew,
+    ];
+
     pub fn as_str(&self) -> &'static str {
         match self {
             Self::Running => "Running",
@@ -25,6 +35,13 @@ impl WorkflowStatus {
         }
     }
 
+    /// Parses a status as written in visibility queries, e.g. `Running`.
+    pub fn parse(s: &str) -> Option<Self> {
+        Self::ALL
+            .into_iter()
+            .find(|status| status.as_str().eq_ignore_ascii_case(s))
+    }
+
     pub fn symbol(&self) -> &'static str {
         match self {
             Self::Running => "●",
@@ -53,6 +70,15 @@ pub struct WorkflowSummary {
     pub start_time: DateTime<Utc>,
     pub close_time: Option<DateTime<Utc>>,
     pub task_queue: String,
+    pub search_attributes: HashMap<String, serde_json::Value>,
+    /// Set when the list spans namespaces (`:allns`).
+    pub namespace: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WorkflowRef {
+    pub workflow_id: String,
+    pub run_id: String,
 }
 
 #[derive(Debug, Clone)]
@@ -61,16 +87,279 @@ pub struct FailureInfo {
     pub failure_type: String,
     pub stack_trace: Option<String>,
     pub cause: Option<Box<FailureInfo>>,
+    /// Everything else the failure carried: an application error's `details`
+    /// payloads, a timeout's `timeout_type`, the failed activity's ID...
+    pub details: Option<serde_json::Value>,
+}
+
+impl FailureInfo {
+    /// From a decoded failure as it appears in history event details.
+    pub fn from_json(v: &serde_json::Value) -> Self {
+        let text = |key: &str| v.get(key).and_then(|s| s.as_str()).unwrap_or_default();
+        let failure_type = match text("type") {
+            "" => text("source"),
+            t => t,
+        };
+        let details: serde_json::Map<String, serde_json::Value> = v
+            .as_object()
+            .into_iter()
+            .flatten()
+            .filter(|(k, _)| {
+                !matches!(
+                    k.as_str(),
+                    "message" | "source" | "type" | "stack_trace" | "cause"
+                )
+            })
+            .map(|(k, v)| (k.clone(), v.clone()))
+            .collect();
+        FailureInfo {
+            message: text("message").to_string(),
+            failure_type: failure_type.to_string(),
+            stack_trace: Some(text("stack_trace"))
+                .filter(|s| !s.is_empty())
+                .map(str::to_string),
+            cause: v
+                .get("cause")
+                .filter(|c| !c.is_null())
+                .map(|c| Box::new(FailureInfo::from_json(c))),
+            details: (!details.is_empty()).then_some(serde_json::Value::Object(details)),
+        }
+    }
+
+    /// The whole chain as nested JSON, causes under `cause`.
+    pub fn to_json(&self) -> serde_json::Value {
+        let mut map = serde_json::Map::new();
+        map.insert("type".into(), self.failure_type.clone().into());
+        map.insert("message".into(), self.message.clone().into());
+        if let Some(serde_json::Value::Object(details)) = &self.details {
+            map.extend(details.clone());
+        }
+        if let Some(ref trace) = self.stack_trace {
+            map.insert("stack_trace".into(), trace.clone().into());
+        }
+        if let Some(ref cause) = self.cause {
+            map.insert("cause".into(), cause.to_json());
+        }
+        serde_json::Value::Object(map)
+    }
 }
 
 #[derive(Debug, Clone)]
 pub struct WorkflowDetail {
     pub summary: WorkflowSummary,
-    pub input: Option<serde_json::Value>,
-    pub output: Option<serde_json::Value>,
+    pub input: Option<super::Payload>,
+    pub output: Option<super::Payload>,
     pub failure: Option<FailureInfo>,
     pub history_length: u64,
+    pub parent: Option<WorkflowRef>,
+    pub root: Option<WorkflowRef>,
     pub memo: HashMap<String, serde_json::Value>,
     pub search_attributes: HashMap<String, serde_json::Value>,
     pub pending_activities: Vec<super::PendingActivity>,
+    pub pending_children: Vec<PendingChild>,
+    pub pending_nexus_operations: Vec<PendingNexusOperation>,
+    pub versioning: Option<super::WorkflowVersioning>,
+    /// Set by whoever started the workflow.
+    pub user_metadata: UserMetadata,
+    pub execution_config: ExecutionConfig,
+    /// From the started event, so set once history loads.
+    pub retry_policy: Option<WorkflowRetryPolicy>,
+    pub cron_schedule: Option<String>,
+}
+
+impl WorkflowDetail {
+    /// Just the list row, for archived runs that can't be described; the
+    /// rest fills in from history.
+    pub fn from_summary(summary: WorkflowSummary) -> Self {
+        WorkflowDetail {
+            execution_config: ExecutionConfig {
+                task_queue: summary.task_queue.clone(),
+                ..Default::default()
+            },
+            search_attributes: summary.search_attributes.clone(),
+            summary,
+            input: None,
+            output: None,
+            failure: None,
+            history_length: 0,
+            parent: None,
+            root: None,
+            memo: HashMap::new(),
+            pending_activities: vec![],
+            pending_children: vec![],
+            pending_nexus_operations: vec![],
+            versioning: None,
+            user_metadata: UserMetadata::default(),
+            retry_policy: None,
+            cron_schedule: None,
+        }
+    }
+}
+
+/// Task queue and timeouts from `WorkflowExecutionConfig`; a `None` timeout
+/// is unlimited.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ExecutionConfig {
+    pub task_queue: String,
+    pub execution_timeout: Option<std::time::Duration>,
+    pub run_timeout: Option<std::time::Duration>,
+    pub task_timeout: Option<std::time::Duration>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct WorkflowRetryPolicy {
+    pub initial_interval: Option<std::time::Duration>,
+    pub backoff_coefficient: f64,
+    pub maximum_interval: Option<std::time::Duration>,
+    /// 0 means unlimited.
+    pub maximum_attempts: i64,
+    pub non_retryable_error_types: Vec<String>,
+}
+
+impl WorkflowRetryPolicy {
+    /// From the `retry_policy` of a started event, whose durations are
+    /// proto JSON strings like `"1.5s"`.
+    pub fn from_json(v: &serde_json::Value) -> Self {
+        let duration = |key: &str| {
+            v.get(key)
+                .and_then(|d| d.as_str())
+                .and_then(|d| d.strip_suffix('s'))
+                .and_then(|d| d.parse::<f64>().ok())
+                .filter(|secs| *secs > 0.0)
+                .map(std::time::Duration::from_secs_f64)
+        };
+        let number = |key: &str| match v.get(key) {
+            Some(serde_json::Value::String(s)) => s.parse::<f64>().ok(),
+            Some(n) => n.as_f64(),
+            None => None,
+        };
+        WorkflowRetryPolicy {
+            initial_interval: duration("initial_interval"),
+            backoff_coefficient: number("backoff_coefficient").unwrap_or_default(),
+            maximum_interval: duration("maximum_interval"),
+            maximum_attempts: number("maximum_attempts").unwrap_or_default() as i64,
+            non_retryable_error_types: v
+                .get("non_retryable_error_types")
+                .and_then(|t| t.as_array())
+                .map(|types| {
+                    types
+                        .iter()
+                        .filter_map(|t| t.as_str().map(str::to_string))
+                        .collect()
+                })
+                .unwrap_or_default(),
+        }
+    }
+}
+
+/// Summary and details text attached when a workflow, or a timer, activity
+/// or other command, is created.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct UserMetadata {
+    pub summary: Option<String>,
+    pub details: Option<String>,
+}
+
+impl UserMetadata {
+    pub fn is_empty(&self) -> bool {
+        self.summary.is_none() && self.details.is_none()
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct PendingChild {
+    pub workflow_id: String,
+    pub run_id: String,
+    pub workflow_type: String,
+    pub initiated_id: i64,
+    pub parent_close_policy: String,
+}
+
+#[derive(Debug, Clone)]
+pub struct PendingNexusOperation {
+    pub endpoint: String,
+    pub service: String,
+    pub operation: String,
+    pub state: String,
+    pub attempt: i32,
+    pub scheduled_time: Option<DateTime<Utc>>,
+    pub last_failure_message: Option<String>,
+}
+
+/// A running workflow's handlers and current details, from the
+/// `__temporal_workflow_metadata` query.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct WorkflowMetadata {
+    pub workflow_type: String,
+    pub queries: Vec<HandlerDefinition>,
+    pub signals: Vec<HandlerDefinition>,
+    pub updates: Vec<HandlerDefinition>,
+    /// Text (often Markdown) the workflow sets to describe what it's doing.
+    pub current_details: String,
+}
+
+/// A registered handler; an empty name is the dynamic handler.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HandlerDefinition {
+    pub name: String,
+    pub description: String,
+}
+
+impl WorkflowMetadata {
+    /// Reads the query result, which SDKs send as proto JSON.
+    pub fn from_json(value: &serde_json::Value) -> Self {
+        // Proto JSON parsers accept either key style, so SDKs may send both.
+        let field = |v: &serde_json::Value, camel: &str, snake: &str| {
+            v.get(camel).or_else(|| v.get(snake)).cloned()
+        };
+        let text = |v: &serde_json::Value, camel: &str, snake: &str| {
+            field(v, camel, snake)
+                .and_then(|s| s.as_str().map(str::to_string))
+                .unwrap_or_default()
+        };
+        let definition = field(value, "definition", "definition").unwrap_or_default();
+        let handlers = |camel: &str, snake: &str| {
+            field(&definition, camel, snake)
+                .and_then(|list| list.as_array().cloned())
+                .unwrap_or_default()
+                .iter()
+                .map(|h| HandlerDefinition {
+                    name: text(h, "name", "name"),
+                    description: text(h, "description", "description"),
+                })
+                .collect()
+        };
+        Self {
+            workflow_type: text(&definition, "type", "type"),
+            queries: handlers("queryDefinitions", "query_definitions"),
+            signals: handlers("signalDefinitions", "signal_definitions"),
+            updates: handlers("updateDefinitions", "update_definitions"),
+            current_details: text(value, "currentDetails", "current_details"),
+        }
+    }
+}
+
+/// What a batch job does to each workflow it covers.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BatchOperation {
+    Cancel,
+    Terminate,
+}
+
+impl BatchOperation {
+    pub fn verb(self) -> &'static str {
+        match self {
+            BatchOperation::Cancel => "cancel",
+            BatchOperation::Terminate => "terminate",
+        }
+    }
+}
+
+/// The workflows a batch job covers.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum BatchTarget {
+    /// (workflow id, run id) pairs.
+    Executions(Vec<(String, String)>),
+    /// Everything matching a visibility query.
+    Query(String),
 }
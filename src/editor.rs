@@ -0,0 +1,119 @@
+//! Round trip of a text buffer through `$VISUAL` / `$EDITOR`.
+
+use std::io::Write;
+use std::path::Path;
+
+/// Starts the comment lines [`with_error`] puts above a rejected document.
+const ERROR_PREFIX: &str = "//";
+
+/// Opens `text` in the user's editor and returns the saved contents. `name`
+/// becomes the temp file's suffix, so editors can pick syntax from it.
+pub async fn edit(text: &str, name: &str) -> Result<String, String> {
+    // Created exclusively and readable only by the user: the text may hold
+    // decoded payloads.
+    let mut file = tempfile::Builder::new()
+        .prefix("t9s-")
+        .suffix(&format!("-{}", file_name(name)))
+        .tempfile()
+        .map_err(|e| format!("creating a temp file: {}", e))?;
+    let path = file.path().to_path_buf();
+    file.write_all(text.as_bytes())
+        .and_then(|()| file.flush())
+        .map_err(|e| format!("{}: {}", path.display(), e))?;
+    run(&path).await?;
+    std::fs::read_to_string(&path).map_err(|e| format!("{}: {}", path.display(), e))
+}
+
+/// `text` reopened after it was rejected, with `error` in comment lines
+/// above it.
+pub fn with_error(text: &str, error: &str) -> String {
+    format!(
+        "{prefix} error: {}\n{prefix} Fix and save to retry, or quit without saving to cancel.\n{}",
+        error,
+        strip_error(text),
+        prefix = ERROR_PREFIX
+    )
+}
+
+/// `text` without the comment lines [`with_error`] put above it.
+pub fn strip_error(text: &str) -> &str {
+    let mut rest = text;
+    while rest.starts_with(ERROR_PREFIX) {
+        rest = rest.split_once('\n').map_or("", |(_, rest)| rest);
+    }
+    rest
+}
+
+async fn run(path: &Path) -> Result<(), String> {
+    let command = editor_command(
+        std::env::var("VISUAL").ok().as_deref(),
+        std::env::var("EDITOR").ok().as_deref(),
+    );
+    let status = tokio::process::Command::new(&command[0])
+        .args(&command[1..])
+        .arg(path)
+        .status()
+        .await
+        .map_err(|e| format!("failed to run {}: {}", command[0], e))?;
+    if status.success() {
+        Ok(())
+    } else {
+        Err(format!("{} exited with {}", command[0], status))
+    }
+}
+
+/// `$VISUAL`, then `$EDITOR`, then `vi`, split on whitespace so values like
+/// `code --wait` work.
+fn editor_command(visual: Option<&str>, editor: Option<&str>) -> Vec<String> {
+    [visual, editor]
+        .into_iter()
+        .flatten()
+        .map(|value| {
+            value
+                .split_whitespace()
+                .map(str::to_string)
+                .collect::<Vec<_>>()
+        })
+        .find(|words| !words.is_empty())
+        .unwrap_or_else(|| vec!["vi".to_string()])
+}
+
+fn file_name(name: &str) -> String {
+    name.chars()
+        .map(|c| {
+            if c.is_ascii_alphanumeric() || matches!(c, '.' | '-' | '_') {
+                c
+            } else {
+                '_'
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn editor_command_prefers_visual_and_splits_args() {
+        assert_eq!(
+            editor_command(Some("code --wait"), Some("nano")),
+            vec!["code", "--wait"]
+        );
+        assert_eq!(editor_command(Some("  "), Some("nano")), vec!["nano"]);
+        assert_eq!(editor_command(None, None), vec!["vi"]);
+        assert_eq!(file_name("schedule-a/b.json"), "schedule-a_b.json");
+    }
+
+    #[test]
+    fn rejected_documents_reopen_with_the_error_on_top() {
+        let text = "{\n  \"cron\": [\"soon\"]\n}\n";
+        let reopened = with_error(text, "invalid cron");
+        assert!(reopened.starts_with("// error: invalid cron\n"));
+        assert_eq!(strip_error(&reopened), text);
+        // A second rejection replaces the first error.
+        let again = with_error(&reopened, "still invalid");
+        assert_eq!(again.matches("error:").count(), 1);
+        assert_eq!(strip_error(&again), text);
+    }
+}
@@ -2,10 +2,10 @@ use std::time::Duration;
 
 use crossterm::event::{Event, EventStream, KeyCode, KeyEvent, KeyModifiers};
 use futures::StreamExt;
-use tokio::sync::mpsc;
+use tokio::sync::{mpsc, watch};
 
 use crate::action::Action;
-use crate::app::{InputMode, Overlay, View};
+use crate::app::{InputMode, Overlay, PayloadSide, View};
 use crate::kinds::{operation_for_key, KindId};
 
 pub struct EventHandler {
@@ -55,45 +55,80 @@ impl EventHandler {
 /// A simpler event handler that returns raw crossterm events
 pub struct RawEventHandler {
     rx: mpsc::UnboundedReceiver<AppEvent>,
+    paused: watch::Sender<bool>,
 }
 
 pub enum AppEvent {
     Key(KeyEvent),
+    /// The terminal's new width.
+    Resize(u16),
     Tick,
 }
 
 impl RawEventHandler {
     pub fn new(tick_rate: Duration) -> Self {
         let (tx, rx) = mpsc::unbounded_channel();
+        let (paused, mut paused_rx) = watch::channel(false);
 
         tokio::spawn(async move {
-            let mut reader = EventStream::new();
             let mut tick = tokio::time::interval(tick_rate);
 
-            loop {
-                tokio::select! {
-                    _ = tick.tick() => {
-                        if tx.send(AppEvent::Tick).is_err() {
-                            break;
-                        }
+            'stream: loop {
+                // While paused another program owns the terminal, so the
+                // reader is dropped rather than left competing for input.
+                if *paused_rx.borrow_and_update() {
+                    if paused_rx.changed().await.is_err() {
+                        break;
                     }
-                    event = reader.next() => {
-                        match event {
-                            Some(Ok(Event::Key(key))) => {
-                                if tx.send(AppEvent::Key(key)).is_err() {
-                                    break;
+                    continue;
+                }
+                let mut reader = EventStream::new();
+
+                loop {
+                    tokio::select! {
+                        _ = tick.tick() => {
+                            if tx.send(AppEvent::Tick).is_err() {
+                                break 'stream;
+                            }
+                        }
+                        changed = paused_rx.changed() => {
+                            if changed.is_err() {
+                                break 'stream;
+                            }
+                            continue 'stream;
+                        }
+                        event = reader.next() => {
+                            match event {
+                                Some(Ok(Event::Key(key))) => {
+                                    if tx.send(AppEvent::Key(key)).is_err() {
+                                        break 'stream;
+                                    }
+                                }
+                                Some(Ok(Event::Resize(width, _))) => {
+                                    if tx.send(AppEvent::Resize(width)).is_err() {
+                                        break 'stream;
+                                    }
                                 }
+                                Some(Ok(_)) => {}
+                                Some(Err(_)) => break 'stream,
+                                None => break 'stream,
                             }
-                            Some(Ok(_)) => {}
-                            Some(Err(_)) => break,
-                            None => break,
                         }
                     }
                 }
             }
         });
 
-        Self { rx }
+        Self { rx, paused }
+    }
+
+    /// Stops reading the terminal until [`resume`](Self::resume).
+    pub fn pause(&self) {
+        let _ = self.paused.send(true);
+    }
+
+    pub fn resume(&self) {
+        let _ = self.paused.send(false);
     }
 
     pub async fn next(&mut self) -> Option<AppEvent> {
@@ -127,14 +162,107 @@ pub fn key_to_action(
                 _ => None,
             };
         }
-        Overlay::NamespaceSelector => {
-            // Navigation handled specially in main.rs since we need app state
+        // Navigation handled specially in main.rs since we need app state;
+        // while filtering, keys go to the filter input below.
+        Overlay::NamespaceSelector if *input_mode == InputMode::Normal => {
             return match key.code {
                 KeyCode::Esc => Some(Action::CloseOverlay),
+                KeyCode::Char('/') => Some(Action::OpenFilter),
+                _ => None,
+            };
+        }
+        Overlay::Logs | Overlay::Audit | Overlay::PendingActivity => {
+            return match key.code {
+                KeyCode::Esc | KeyCode::Char('q') => Some(Action::CloseOverlay),
+                KeyCode::Char('v') if *overlay == Overlay::PendingActivity => {
+                    Some(Action::OpenPager)
+                }
+                KeyCode::Char('e') if *overlay == Overlay::PendingActivity => {
+                    Some(Action::EditActivityOptions)
+                }
+                KeyCode::Char('j') | KeyCode::Down => Some(Action::NavigateDown),
+                KeyCode::Char('k') | KeyCode::Up => Some(Action::NavigateUp),
                 _ => None,
             };
         }
-        Overlay::None => {}
+        Overlay::SignalPicker
+        | Overlay::QuickActions
+        | Overlay::QueryPicker
+        | Overlay::ContextPicker
+        | Overlay::TimeRange => {
+            return match key.code {
+                KeyCode::Esc | KeyCode::Char('q') => Some(Action::CloseOverlay),
+                KeyCode::Char('j') | KeyCode::Down => Some(Action::NavigateDown),
+                KeyCode::Char('k') | KeyCode::Up => Some(Action::NavigateUp),
+                KeyCode::Enter => Some(Action::Select),
+                _ => None,
+            };
+        }
+        Overlay::SearchAttributes => {
+            return match key.code {
+                KeyCode::Esc | KeyCode::Char('q') => Some(Action::CloseOverlay),
+                KeyCode::Char('j') | KeyCode::Down => Some(Action::NavigateDown),
+                KeyCode::Char('k') | KeyCode::Up => Some(Action::NavigateUp),
+                KeyCode::Char('a') => Some(Action::PromptSearchAttribute),
+                _ => None,
+            };
+        }
+        // While searching, keys go to the search input below.
+        Overlay::Pager if *input_mode == InputMode::Normal => {
+            return match key.code {
+                KeyCode::Esc | KeyCode::Char('q') => Some(Action::CloseOverlay),
+                KeyCode::Char('j') | KeyCode::Down => Some(Action::NavigateDown),
+                KeyCode::Char('k') | KeyCode::Up => Some(Action::NavigateUp),
+                KeyCode::Char('g') | KeyCode::Home => Some(Action::NavigateTop),
+                KeyCode::Char('G') | KeyCode::End => Some(Action::NavigateBottom),
+                KeyCode::Char('/') => Some(Action::OpenSearch),
+                KeyCode::Char('n') => Some(Action::NextMatch),
+                KeyCode::Char('N') => Some(Action::PrevMatch),
+                KeyCode::Char('d') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+                    Some(Action::PageDown)
+                }
+                KeyCode::Char('u') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+                    Some(Action::PageUp)
+                }
+                KeyCode::PageDown | KeyCode::Char(' ') => Some(Action::PageDown),
+                KeyCode::PageUp => Some(Action::PageUp),
+                _ => None,
+            };
+        }
+        Overlay::Bookmarks => {
+            return match key.code {
+                KeyCode::Esc | KeyCode::Char('q') => Some(Action::CloseOverlay),
+                KeyCode::Char('j') | KeyCode::Down => Some(Action::NavigateDown),
+                KeyCode::Char('k') | KeyCode::Up => Some(Action::NavigateUp),
+                KeyCode::Char('d') => Some(Action::DeleteBookmark),
+                KeyCode::Enter => Some(Action::Select),
+                _ => None,
+            };
+        }
+        Overlay::Overview => {
+            return match key.code {
+                KeyCode::Esc | KeyCode::Char('q') => Some(Action::CloseOverlay),
+                KeyCode::Char('j') | KeyCode::Down => Some(Action::NavigateDown),
+                KeyCode::Char('k') | KeyCode::Up => Some(Action::NavigateUp),
+                KeyCode::Char('r') => Some(Action::OpenOverview),
+                KeyCode::Enter => Some(Action::Select),
+                _ => None,
+            };
+        }
+        Overlay::QueryBuilder => {
+            // j/k navigate or type depending on the builder's step, which the
+            // app decides.
+            return match key.code {
+                KeyCode::Esc => Some(Action::CloseOverlay),
+                KeyCode::Down => Some(Action::NavigateDown),
+                KeyCode::Up => Some(Action::NavigateUp),
+                KeyCode::Enter => Some(Action::Select),
+                KeyCode::Backspace => Some(Action::QueryBuilderBack),
+                KeyCode::Char(c) => Some(Action::QueryBuilderInput(c)),
+                _ => None,
+            };
+        }
+        Overlay::NamespaceSelector | Overlay::Pager | Overlay::None => {}
     }
 
     // Handle input mode keys
@@ -171,6 +299,24 @@ pub fn key_to_action(
             return match key.code {
                 KeyCode::Esc => Some(Action::CloseOverlay),
                 KeyCode::Enter => Some(Action::SubmitSearch(input_buffer.to_string())),
+                KeyCode::Tab => Some(Action::CompleteSearch),
+                KeyCode::Backspace => {
+                    let mut buf = input_buffer.to_string();
+                    buf.pop();
+                    Some(Action::UpdateInputBuffer(buf))
+                }
+                KeyCode::Char(c) => {
+                    let mut buf = input_buffer.to_string();
+                    buf.push(c);
+                    Some(Action::UpdateInputBuffer(buf))
+                }
+                _ => None,
+            };
+        }
+        InputMode::Filter => {
+            return match key.code {
+                KeyCode::Esc => Some(Action::CloseOverlay),
+                KeyCode::Enter => Some(Action::SubmitFilter),
                 KeyCode::Backspace => {
                     let mut buf = input_buffer.to_string();
                     buf.pop();
@@ -200,6 +346,7 @@ pub fn key_to_action(
             KeyCode::Char('r') => Some(Action::Refresh),
             KeyCode::Char('d') => Some(Action::PageDown),
             KeyCode::Char('u') => Some(Action::PageUp),
+            KeyCode::Char('f') if matches!(view, View::Collection(_)) => Some(Action::OpenFilter),
             _ => None,
         };
     }
@@ -209,7 +356,14 @@ pub fn key_to_action(
         // Global
         KeyCode::Char('q') => Some(Action::Quit),
         KeyCode::Char(':') => Some(Action::OpenCommandInput),
-        KeyCode::Char('/') if matches!(view, View::Collection(_)) => Some(Action::OpenSearch),
+        KeyCode::Char('/')
+            if matches!(
+                view,
+                View::Collection(_) | View::Detail(KindId::WorkflowExecution)
+            ) =>
+        {
+            Some(Action::OpenSearch)
+        }
         KeyCode::Char('?') => Some(Action::ToggleHelp),
         KeyCode::Char('j') | KeyCode::Down => Some(Action::NavigateDown),
         KeyCode::Char('k') | KeyCode::Up => Some(Action::NavigateUp),
@@ -217,13 +371,20 @@ pub fn key_to_action(
         KeyCode::Char('G') => Some(Action::NavigateBottom),
         KeyCode::Enter => Some(Action::Select),
         KeyCode::Esc => Some(Action::Back),
+        KeyCode::Char('E') => Some(Action::FocusError),
+        KeyCode::Backspace => Some(Action::ClearError),
+        KeyCode::Char('P') => Some(Action::TogglePolling),
+        KeyCode::Char('A') => Some(Action::ToggleRelativeTime),
+        KeyCode::Char('Y') => Some(Action::CopyLink),
         KeyCode::Tab => Some(Action::NextTab),
         KeyCode::BackTab => Some(Action::PrevTab),
 
         KeyCode::Char('l')
             if matches!(
                 view,
-                View::Detail(KindId::WorkflowExecution) | View::Detail(KindId::ActivityExecution)
+                View::Detail(KindId::WorkflowExecution)
+                    | View::Detail(KindId::Schedule)
+                    | View::Detail(KindId::ActivityExecution)
             ) =>
         {
             Some(Action::NextTab)
@@ -231,7 +392,9 @@ pub fn key_to_action(
         KeyCode::Char('h')
             if matches!(
                 view,
-                View::Detail(KindId::WorkflowExecution) | View::Detail(KindId::ActivityExecution)
+                View::Detail(KindId::WorkflowExecution)
+                    | View::Detail(KindId::Schedule)
+                    | View::Detail(KindId::ActivityExecution)
             ) =>
         {
             Some(Action::PrevTab)
@@ -239,6 +402,61 @@ pub fn key_to_action(
         KeyCode::Char('a') if matches!(view, View::Detail(KindId::WorkflowExecution)) => {
             Some(Action::OpenWorkflowActivities)
         }
+        KeyCode::Char('r') if matches!(view, View::Detail(KindId::WorkflowExecution)) => {
+            Some(Action::Refresh)
+        }
+        KeyCode::Char('u') if matches!(view, View::Detail(KindId::WorkflowExecution)) => {
+            Some(Action::OpenParentWorkflow)
+        }
+        KeyCode::Char('U') if matches!(view, View::Detail(KindId::WorkflowExecution)) => {
+            Some(Action::OpenRootWorkflow)
+        }
+        KeyCode::Char('S')
+            if matches!(
+                view,
+                View::Collection(KindId::WorkflowExecution)
+                    | View::Detail(KindId::WorkflowExecution)
+            ) =>
+        {
+            Some(Action::OpenSignalPicker)
+        }
+        KeyCode::Char('f') if matches!(view, View::Collection(_)) => Some(Action::OpenFilter),
+        KeyCode::Char('W') if matches!(view, View::Collection(_)) => Some(Action::ToggleWideMode),
+        KeyCode::Char('V') if matches!(view, View::Collection(_)) => Some(Action::ToggleSplitView),
+        KeyCode::Char('Q') if matches!(view, View::Collection(_)) => Some(Action::OpenQueryPicker),
+        KeyCode::Char('b') if matches!(view, View::Collection(_)) => Some(Action::OpenQueryBuilder),
+        KeyCode::Char('L') if matches!(view, View::Collection(KindId::WorkflowExecution)) => {
+            Some(Action::OpenTimeRangePicker)
+        }
+        KeyCode::Char(' ') if matches!(view, View::Detail(KindId::WorkflowExecution)) => {
+            Some(Action::ToggleFold)
+        }
+        KeyCode::Char('m') if matches!(view, View::Collection(KindId::WorkflowExecution)) => {
+            Some(Action::ToggleMark)
+        }
+        KeyCode::Char('v')
+            if matches!(
+                view,
+                View::Detail(KindId::WorkflowExecution) | View::Detail(KindId::Schedule)
+            ) =>
+        {
+            Some(Action::OpenPager)
+        }
+        KeyCode::Char('|') if matches!(view, View::Detail(KindId::WorkflowExecution)) => {
+            Some(Action::PipePayload)
+        }
+        KeyCode::Char('i') if matches!(view, View::Detail(KindId::WorkflowExecution)) => {
+            Some(Action::CopyPayload(PayloadSide::Input))
+        }
+        KeyCode::Char('o') if matches!(view, View::Detail(KindId::WorkflowExecution)) => {
+            Some(Action::CopyPayload(PayloadSide::Output))
+        }
+        KeyCode::Char('n') if matches!(view, View::Detail(KindId::WorkflowExecution)) => {
+            Some(Action::NextMatch)
+        }
+        KeyCode::Char('N') if matches!(view, View::Detail(KindId::WorkflowExecution)) => {
+            Some(Action::PrevMatch)
+        }
         KeyCode::Char('w')
             if matches!(
                 view,
@@ -247,6 +465,14 @@ pub fn key_to_action(
         {
             Some(Action::OpenScheduleWorkflows)
         }
+        KeyCode::Char('z') if matches!(view, View::Collection(KindId::Schedule)) => {
+            Some(Action::ToggleScheduleGrouping)
+        }
+        KeyCode::Char('.') => Some(Action::OpenQuickActions),
+        KeyCode::Char(' ') if matches!(view, View::Collection(_)) => Some(Action::OpenQuickActions),
+        KeyCode::Char(c @ '1'..='9') => {
+            Some(Action::SwitchFavoriteNamespace(c as usize - '1' as usize))
+        }
         KeyCode::Char(c) => {
             let kind = match view {
                 View::Collection(kind) | View::Detail(kind) => *kind,
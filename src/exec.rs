@@ -0,0 +1,102 @@
+//! What `t9s exec` may run and the exit status it ends with.
+
+use std::fmt;
+
+use crate::action::Action;
+use crate::input::commands::COMMANDS;
+
+/// Commands that only change what the TUI shows or where it is connected,
+/// so run without it they would do nothing.
+const INTERACTIVE_ONLY: &[&str] = &[
+    "ctx",
+    "overview",
+    "quit",
+    "help",
+    "preview",
+    "link",
+    "bookmark",
+    "logs",
+    "audit",
+    "copy-mode",
+];
+
+/// Why `t9s exec` did not succeed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ExecError {
+    /// The command can't run without the TUI.
+    Usage(String),
+    /// The command ran and failed.
+    Failed(String),
+    /// The server couldn't be reached, or went away mid-command.
+    Unreachable(String),
+}
+
+impl ExecError {
+    /// 1 when the command failed, 2 when it never got to run.
+    pub fn exit_code(&self) -> i32 {
+        match self {
+            ExecError::Failed(_) => 1,
+            ExecError::Usage(_) | ExecError::Unreachable(_) => 2,
+        }
+    }
+}
+
+impl fmt::Display for ExecError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ExecError::Usage(msg) | ExecError::Failed(msg) | ExecError::Unreachable(msg) => {
+                f.write_str(msg)
+            }
+        }
+    }
+}
+
+/// Rejects a command, as typed after `:`, that only works in the TUI.
+pub fn check_command(command: &str) -> Result<(), ExecError> {
+    let name = command.split_whitespace().next().unwrap_or("");
+    let interactive = COMMANDS
+        .iter()
+        .find(|cmd| cmd.name == name || cmd.aliases.contains(&name))
+        .is_some_and(|cmd| INTERACTIVE_ONLY.contains(&cmd.name));
+    if interactive {
+        return Err(ExecError::Usage(format!(":{} only works in the TUI", name)));
+    }
+    Ok(())
+}
+
+/// The error a worker result ends the command with, if it is one.
+pub fn action_error(action: &Action) -> Option<ExecError> {
+    match action {
+        Action::Error(msg) => Some(ExecError::Failed(msg.clone())),
+        Action::ConnectionLost(msg) | Action::Disconnected(msg) => {
+            Some(ExecError::Unreachable(msg.clone()))
+        }
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn tui_commands_are_usage_errors() {
+        for command in [
+            "ctx prod", "context", "overview", "ov", "q", "q failed", "help",
+        ] {
+            let err = check_command(command).unwrap_err();
+            assert_eq!(err.exit_code(), 2, "{}", command);
+        }
+        assert!(check_command("signal mysignal {\"k\":1}").is_ok());
+        assert!(check_command("pause deploy freeze").is_ok());
+    }
+
+    #[test]
+    fn lost_connections_exit_like_unreachable_servers() {
+        let lost = action_error(&Action::ConnectionLost("unavailable".to_string())).unwrap();
+        assert_eq!(lost.exit_code(), 2);
+        let failed = action_error(&Action::Error("not found".to_string())).unwrap();
+        assert_eq!(failed.exit_code(), 1);
+        assert!(action_error(&Action::Tick).is_none());
+    }
+}
@@ -0,0 +1,161 @@
+//! `:export` of a list's rows to CSV or JSON, for pasting into incident
+//! reports. The format follows the file extension; anything but `.json` is
+//! CSV.
+
+use std::io::Write;
+use std::path::Path;
+
+/// Rows `:export --all` loads at most; past this the export says it is
+/// incomplete and a narrower query gets the rest.
+pub const EXPORT_ROW_CAP: usize = 10_000;
+
+/// Rows of one list with the context needed to make sense of them later.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ExportTable {
+    /// Lowercase kind label, e.g. `workflows`.
+    pub kind: String,
+    pub namespace: String,
+    /// The query the list was loaded with, if any.
+    pub query: Option<String>,
+    pub headers: Vec<&'static str>,
+    pub rows: Vec<Vec<String>>,
+    /// More rows matched than [`EXPORT_ROW_CAP`] let through.
+    pub truncated: bool,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ExportFormat {
+    Csv,
+    Json,
+}
+
+impl ExportFormat {
+    pub fn for_path(path: &Path) -> Self {
+        match path.extension().and_then(|e| e.to_str()) {
+            Some(ext) if ext.eq_ignore_ascii_case("json") => ExportFormat::Json,
+            _ => ExportFormat::Csv,
+        }
+    }
+}
+
+impl ExportTable {
+    pub fn render(&self, format: ExportFormat, exported_at: &str) -> String {
+        match format {
+            ExportFormat::Csv => self.csv(exported_at),
+            ExportFormat::Json => self.json(exported_at),
+        }
+    }
+
+    /// CSV preceded by `#` comment lines naming the namespace and query.
+    fn csv(&self, exported_at: &str) -> String {
+        let mut out = format!(
+            "# t9s export of {} in namespace {} at {}\n",
+            self.kind, self.namespace, exported_at
+        );
+        if let Some(query) = &self.query {
+            out.push_str(&format!("# query: {}\n", query));
+        }
+        if self.truncated {
+            out.push_str(&format!(
+                "# truncated: only the first {} rows\n",
+                self.rows.len()
+            ));
+        }
+        let line = |cells: &mut dyn Iterator<Item = &str>| {
+            cells.map(csv_field).collect::<Vec<_>>().join(",") + "\n"
+        };
+        out.push_str(&line(&mut self.headers.iter().copied()));
+        for row in &self.rows {
+            out.push_str(&line(&mut row.iter().map(String::as_str)));
+        }
+        out
+    }
+
+    fn json(&self, exported_at: &str) -> String {
+        let rows: Vec<serde_json::Map<String, serde_json::Value>> = self
+            .rows
+            .iter()
+            .map(|row| {
+                self.headers
+                    .iter()
+                    .zip(row)
+                    .map(|(header, value)| (header.to_string(), value.clone().into()))
+                    .collect()
+            })
+            .collect();
+        let document = serde_json::json!({
+            "kind": self.kind,
+            "namespace": self.namespace,
+            "query": self.query,
+            "exported_at": exported_at,
+            "truncated": self.truncated,
+            "rows": rows,
+        });
+        serde_json::to_string_pretty(&document).unwrap_or_default() + "\n"
+    }
+}
+
+/// Writes `contents` to `path`, failing with `AlreadyExists` rather than
+/// replacing a file unless `overwrite`.
+pub fn write_file(path: &Path, contents: &str, overwrite: bool) -> std::io::Result<()> {
+    let mut options = std::fs::OpenOptions::new();
+    if overwrite {
+        options.write(true).create(true).truncate(true);
+    } else {
+        options.write(true).create_new(true);
+    }
+    options.open(path)?.write_all(contents.as_bytes())
+}
+
+fn csv_field(value: &str) -> String {
+    if value.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn csv_quotes_fields_and_notes_the_query() {
+        let table = ExportTable {
+            kind: "workflows".to_string(),
+            namespace: "prod".to_string(),
+            query: Some("WorkflowType = 'Order'".to_string()),
+            headers: vec!["workflow_id", "status"],
+            rows: vec![vec!["order, \"big\"".to_string(), "Failed".to_string()]],
+            truncated: false,
+        };
+        assert_eq!(
+            table.render(ExportFormat::Csv, "2026-01-02T03:04:05Z"),
+            "# t9s export of workflows in namespace prod at 2026-01-02T03:04:05Z\n\
+             # query: WorkflowType = 'Order'\n\
+             workflow_id,status\n\
+             \"order, \"\"big\"\"\",Failed\n"
+        );
+
+        let json: serde_json::Value =
+            serde_json::from_str(&table.render(ExportFormat::Json, "now")).unwrap();
+        assert_eq!(json["rows"][0]["status"], "Failed");
+        assert_eq!(json["query"], "WorkflowType = 'Order'");
+        assert_eq!(
+            ExportFormat::for_path(Path::new("out.JSON")),
+            ExportFormat::Json
+        );
+    }
+
+    #[test]
+    fn write_file_keeps_an_existing_file_unless_told_to_overwrite() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("out file.csv");
+        write_file(&path, "first", false).unwrap();
+        let err = write_file(&path, "second", false).unwrap_err();
+        assert_eq!(err.kind(), std::io::ErrorKind::AlreadyExists);
+        assert_eq!(std::fs::read_to_string(&path).unwrap(), "first");
+        write_file(&path, "second", true).unwrap();
+        assert_eq!(std::fs::read_to_string(&path).unwrap(), "second");
+    }
+}
@@ -0,0 +1,392 @@
+//! Message catalog for user-facing UI strings. English is built in; a locale
+//! named in the config file loads `<config dir>/t9s/locales/<locale>.toml`, a
+//! flat table of `key = "text"`, and falls back to English for missing keys.
+
+use std::collections::HashMap;
+use std::sync::OnceLock;
+
+macro_rules! catalog {
+    ($($variant:ident = $key:literal => $english:literal,)*) => {
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+        pub enum Msg {
+            $($variant,)*
+        }
+
+        impl Msg {
+            pub const ALL: &'static [Msg] = &[$(Msg::$variant,)*];
+
+            /// Key used in locale files.
+            pub fn key(self) -> &'static str {
+                match self {
+                    $(Msg::$variant => $key,)*
+                }
+            }
+
+            pub fn english(self) -> &'static str {
+                match self {
+                    $(Msg::$variant => $english,)*
+                }
+            }
+        }
+    };
+}
+
+catalog! {
+    // Help overlay
+    HelpTitle = "help.title" => " Help (? to close) ",
+    HelpNavigation = "help.navigation" => "Navigation",
+    HelpViews = "help.views" => "Views",
+    HelpCommands = "help.commands" => "Commands",
+    HelpWorkflowActions = "help.workflow_actions" => "Workflow Actions",
+    HelpScheduleActions = "help.schedule_actions" => "Schedule Actions",
+    HelpActivityActions = "help.activity_actions" => "Activity Actions",
+    HelpGeneral = "help.general" => "General",
+    HelpNavigate = "help.navigate" => "Navigate up/down",
+    HelpTopBottom = "help.top_bottom" => "Go to top / bottom",
+    HelpPage = "help.page" => "Page down / up",
+    HelpSelect = "help.select" => "Select / drill in",
+    HelpBackToList = "help.back_to_list" => "Back to list",
+    HelpCommandMode = "help.command_mode" => "Command mode",
+    HelpSwitchWorkflows = "help.switch_workflows" => "Switch to workflows",
+    HelpSwitchSchedules = "help.switch_schedules" => "Switch to schedules",
+    HelpSwitchActivities = "help.switch_activities" => "Switch to activities",
+    HelpSearch = "help.search" => "Search",
+    HelpFilter = "help.filter" => "Filter loaded rows",
+    HelpSavedQueries = "help.saved_queries" => "Saved queries",
+    HelpQueryBuilder = "help.query_builder" => "Build a query step by step",
+    HelpTimeRange = "help.time_range" => "Only workflows started recently",
+    HelpArchived = "help.archived" => "Toggle listing archived workflows",
+    HelpAllNamespaces = "help.all_namespaces" => "List workflows across namespaces",
+    HelpSplit = "help.split" => "Preview the highlighted row beside the list (140+ cols)",
+    HelpWide = "help.wide" => "Toggle wide columns (160+ cols)",
+    HelpSwitchNamespace = "help.switch_namespace" => "Switch namespace",
+    HelpFavoriteNamespace = "help.favorite_namespace" => "Switch to a favorite namespace",
+    HelpSwitchProfile = "help.switch_profile" => "Reconnect with a config profile",
+    HelpOpenUri = "help.open_uri" => "Open a deep link URI",
+    HelpBookmarks = "help.bookmarks" => "Bookmark this view; no name lists bookmarks",
+    HelpLogs = "help.logs" => "Review recent errors",
+    HelpAudit = "help.audit" => "Review the operations sent this session",
+    HelpSearchAttributes = "help.search_attributes" => "List or add search attributes",
+    HelpOverview = "help.overview" => "Namespace overview",
+    HelpCopyMode = "help.copy_mode" => "Toggle mouse capture for copying text",
+    HelpSignal = "help.signal" => "Signal selected workflow",
+    HelpResendSignal = "help.resend_signal" => "Resend a recent signal",
+    HelpApplyQuery = "help.apply_query" => "Apply a saved query",
+    HelpQuit = "help.quit" => "Quit",
+    HelpDetailTabs = "help.detail_tabs" => "Switch detail tabs",
+    HelpPendingActivities = "help.pending_activities" => "Pending activities",
+    HelpOpenChild = "help.open_child" => "Open child workflow (History)",
+    HelpPendingActivityDetail = "help.pending_activity_detail" => "Pending activity times, heartbeat and last failure (Pending)",
+    HelpEditActivityOptions = "help.edit_activity_options" => "Edit the open pending activity's timeouts and retry policy",
+    HelpBackToParent = "help.back_to_parent" => "Back to parent workflow",
+    HelpParentRoot = "help.parent_root" => "Jump to parent / root workflow",
+    HelpRefreshStack = "help.refresh_stack" => "Refresh (re-query Stack Trace / Metadata)",
+    HelpFoldNode = "help.fold_node" => "Fold node (Input/Output)",
+    HelpExpandPayloads = "help.expand_payloads" => "Expand event payloads (History)",
+    HelpShell = "help.shell" => "Run a shell command with the selection in T9S_* env vars",
+    HelpOpenPager = "help.open_pager" => "View the tab's failure, payload, history, stack trace or raw response in a pager",
+    HelpMarkWorkflow = "help.mark_workflow" => "Mark the workflow for c / t / :bulk (Esc clears)",
+    HelpBulk = "help.bulk" => "Cancel or terminate the marked workflows, or all matching the search",
+    HelpPipePayload = "help.pipe_payload" => "Pipe the selected payload through a [[pipes]] command",
+    HelpCopyPayload = "help.copy_payload" => "Copy the workflow input / output (Input/Output)",
+    HelpSavePayload = "help.save_payload" => "Write the workflow input or output to a file",
+    HelpFindPayloads = "help.find_payloads" => "Find in payloads, next / previous",
+    HelpScheduleWorkflows = "help.schedule_workflows" => "Schedule workflows",
+    HelpGroupSchedules = "help.group_schedules" => "Group by type / state",
+    HelpOpenRecentAction = "help.open_recent_action" => "Open run started by recent action",
+    HelpRefresh = "help.refresh" => "Refresh",
+    HelpDismissError = "help.dismiss_error" => "Dismiss error toast",
+    HelpHoldError = "help.hold_error" => "Hold error toast open",
+    HelpClearErrors = "help.clear_errors" => "Clear all error toasts",
+    HelpPolling = "help.polling" => "Pause / resume polling",
+    HelpRelativeTimes = "help.relative_times" => "Relative / absolute times",
+    HelpTimezone = "help.timezone" => "Show times in local, utc or a named zone",
+    HelpCopyLink = "help.copy_link" => "Copy a deep link to this view",
+    HelpPageSize = "help.page_size" => "Rows fetched per page for this list",
+    HelpQuickActions = "help.quick_actions" => "Menu of everything that can be done to the selected row",
+    HelpPreviewPane = "help.preview_pane" => "Status, duration and last failure of the highlighted workflow",
+    HelpExport = "help.export" => "Write the list to CSV or JSON (--all loads every page)",
+    HelpToggleHelp = "help.toggle_help" => "Toggle this help",
+
+    // Footer hints
+    HintCancel = "hint.cancel" => "cancel",
+    HintExecute = "hint.execute" => "execute",
+    HintApply = "hint.apply" => "apply",
+    HintClear = "hint.clear" => "clear",
+    HintKeep = "hint.keep" => "keep",
+    HintTop = "hint.top" => "top",
+    HintWide = "hint.wide" => "wide",
+    HintNarrow = "hint.narrow" => "narrow",
+    HintWideNeedsWidth = "hint.wide_needs_width" => "wide needs 160 cols",
+    HintSplit = "hint.split" => "split",
+    HintUnsplit = "hint.unsplit" => "unsplit",
+    HintSplitNeedsWidth = "hint.split_needs_width" => "split needs 140 cols",
+    HintPausePoll = "hint.pause_poll" => "pause poll",
+    HintResumePoll = "hint.resume_poll" => "resume poll",
+    HintAbsolute = "hint.absolute" => "absolute",
+    HintRelative = "hint.relative" => "relative",
+    HintClearErrors = "hint.clear_errors" => "clear errors",
+    HintMouseOff = "hint.mouse_off" => "mouse off",
+    HintNav = "hint.nav" => "nav",
+    HintSelect = "hint.select" => "select",
+    HintSearch = "hint.search" => "search",
+    HintFilter = "hint.filter" => "filter",
+    HintCmd = "hint.cmd" => "cmd",
+    HintWorkflows = "hint.workflows" => "workflows",
+    HintGroup = "hint.group" => "group",
+    HintHelp = "hint.help" => "help",
+    HintQuit = "hint.quit" => "quit",
+    HintScroll = "hint.scroll" => "scroll",
+    HintBack = "hint.back" => "back",
+    HintTabs = "hint.tabs" => "tabs",
+    HintActivities = "hint.activities" => "activities",
+    HintParentRoot = "hint.parent_root" => "parent/root",
+    HintFind = "hint.find" => "find",
+    HintOpenRun = "hint.open_run" => "open run",
+    HintConnect = "hint.connect" => "connect",
+    HintRetry = "hint.retry" => "retry",
+    HintEditAddress = "hint.edit_address" => "edit address",
+    HintEditNamespace = "hint.edit_namespace" => "edit namespace",
+    HintNextProfile = "hint.next_profile" => "next profile",
+    ListPosition = "footer.position" => "{0}/{1}",
+    ListTotal = "footer.total" => "({0} total)",
+    PagesLeft = "footer.pages_left" => "{0} more pages",
+    MorePages = "footer.more_pages" => "more pages",
+
+    // Loading and empty states
+    LoadingWorkflows = "empty.loading_workflows" => " Loading workflows...",
+    NoWorkflows = "empty.no_workflows" => " No workflows loaded",
+    LoadingSchedules = "empty.loading_schedules" => " Loading schedules...",
+    NoSchedules = "empty.no_schedules" => " No schedules loaded",
+    LoadingActivities = "empty.loading_activities" => " Loading activities...",
+    NoActivities = "empty.no_activities" => " No activities loaded",
+    LoadingWorkflowDetail = "empty.loading_workflow_detail" => " Loading workflow detail...",
+    LoadingScheduleDetail = "empty.loading_schedule_detail" => " Loading schedule detail...",
+    LoadingActivityDetail = "empty.loading_activity_detail" => " Loading activity detail...",
+    LoadingHistory = "empty.loading_history" => " Loading history...",
+    LoadingTaskQueue = "empty.loading_task_queue" => " Loading task queue info...",
+
+    // Connection screen
+    ConnectionAddress = "connection.address" => "Address",
+    ConnectionNamespace = "connection.namespace" => "Namespace",
+    ConnectionProfile = "connection.profile" => "Profile",
+    ConnectionOpen = "connection.open" => "Open",
+    ConnectionCode = "connection.code" => "Code",
+    ConnectionWaiting = "connection.waiting" => "Waiting for approval...",
+    ConnectionConnecting = "connection.connecting" => "Connecting...",
+    ConnectionFailed = "connection.failed" => "Failed to connect: {0}",
+    ConnectionDoctor = "connection.doctor" => "Run `t9s doctor` for step-by-step connection diagnostics.",
+    ConnectionNewAddress = "connection.new_address" => "New address:",
+    ConnectionNewNamespace = "connection.new_namespace" => "New namespace:",
+    ConnectionSignInTitle = "connection.sign_in_title" => " Sign in ",
+    ConnectionConnectingTitle = "connection.connecting_title" => " Connecting ",
+    ConnectionFailedTitle = "connection.failed_title" => " Connection failed ",
+
+    // Tab bar and connection status
+    TabWorkflows = "tab.workflows" => "Workflows",
+    TabArchivedWorkflows = "tab.archived_workflows" => "Archived Workflows",
+    TabSchedules = "tab.schedules" => "Schedules",
+    TabActivities = "tab.activities" => "Activities",
+    TabFanout = "tab.fanout" => "in {0} namespaces, first {1} each",
+    TabFanoutFailed = "tab.fanout_failed" => "({0} failed)",
+    TabLast = "tab.last" => "last",
+    TabMarked = "tab.marked" => "{0} marked",
+    StatusConnected = "status.connected" => "Connected",
+    StatusConnectedLatency = "status.connected_latency" => "Connected {0}ms",
+    StatusFailedChecks = "status.failed_checks" => "Connected ({0} failed checks)",
+    StatusConnecting = "status.connecting" => "Connecting...",
+    StatusDisconnected = "status.disconnected" => "Disconnected, press a key to retry",
+    StatusCloud = "status.cloud" => "Cloud",
+    StatusPaused = "status.paused" => "paused",
+    StatusRefreshedAgo = "status.refreshed_ago" => "refreshed {0} ago",
+    StatusBackoff = "status.backoff" => "backoff",
+    StatusNextPoll = "status.next_poll" => "in {0}",
+    StatusWorkflowCount = "status.workflow_count" => "[{0} workflows]",
+
+    // Pending activity detail
+    ActivityTitle = "pending_activity.title" => " Activity {0} (e edits options, v pages the failure, Esc to close) ",
+    ActivityType = "pending_activity.type" => "Type",
+    ActivityState = "pending_activity.state" => "State",
+    ActivityAttempt = "pending_activity.attempt" => "Attempt",
+    ActivityAttemptOf = "pending_activity.attempt_of" => "{0} of {1}",
+    ActivityAttemptUnlimited = "pending_activity.attempt_unlimited" => "{0} (unlimited)",
+    ActivityUnlimited = "pending_activity.unlimited" => "unlimited",
+    ActivityScheduled = "pending_activity.scheduled" => "Scheduled",
+    ActivityLastStarted = "pending_activity.last_started" => "Last Started",
+    ActivityLastHeartbeat = "pending_activity.last_heartbeat" => "Last Heartbeat",
+    ActivityNextRetry = "pending_activity.next_retry" => "Next Retry",
+    ActivityLastWorker = "pending_activity.last_worker" => "Last Worker",
+    ActivityOptions = "pending_activity.options" => "Options",
+    ActivityScheduleToClose = "pending_activity.schedule_to_close" => "Schedule to Close",
+    ActivityScheduleToStart = "pending_activity.schedule_to_start" => "Schedule to Start",
+    ActivityStartToClose = "pending_activity.start_to_close" => "Start to Close",
+    ActivityHeartbeat = "pending_activity.heartbeat" => "Heartbeat",
+    ActivityRetryInterval = "pending_activity.retry_interval" => "Retry Interval",
+    ActivityRetryIntervalValue = "pending_activity.retry_interval_value" => "{0} x{1} up to {2}",
+    ActivityRetryAttempts = "pending_activity.retry_attempts" => "Retry Attempts",
+    ActivityNonRetryable = "pending_activity.non_retryable" => "Non-retryable",
+    ActivityHeartbeatDetails = "pending_activity.heartbeat_details" => "Heartbeat Details",
+    ActivityLastFailure = "pending_activity.last_failure" => "Last Failure",
+    ActivityNone = "pending_activity.none" => "(none)",
+    ActivityCausedBy = "pending_activity.caused_by" => "Caused by:",
+
+    // Task queue detail
+    TaskQueueName = "task_queue.name" => "Task Queue",
+    TaskQueueWorkflowPollers = "task_queue.workflow_pollers" => "Workflow pollers",
+    TaskQueueActivityPollers = "task_queue.activity_pollers" => "Activity pollers",
+    TaskQueueNoPollers = "task_queue.no_pollers" => "No pollers",
+    TaskQueuePollerLast = "task_queue.poller_last" => "last",
+    TaskQueuePollerRate = "task_queue.poller_rate" => "rate",
+    TaskQueuePollerVersion = "task_queue.poller_version" => "version",
+    TaskQueueVersioning = "task_queue.versioning" => "Versioning",
+    TaskQueueWorkflowBehavior = "task_queue.workflow_behavior" => "Workflow behavior",
+    TaskQueueWorkflowVersion = "task_queue.workflow_version" => "Workflow version",
+    TaskQueueOverride = "task_queue.override" => "Override",
+    TaskQueueCurrentVersion = "task_queue.current_version" => "Current version",
+    TaskQueueUnversioned = "task_queue.unversioned" => "unversioned",
+    TaskQueueRampingVersion = "task_queue.ramping_version" => "Ramping version",
+    TaskQueueAssignmentRules = "task_queue.assignment_rules" => "Assignment rules",
+    TaskQueueRedirectRules = "task_queue.redirect_rules" => "Redirect rules",
+
+    // Workflow preview pane
+    PreviewTitle = "preview.title" => " Preview ",
+    PreviewStatus = "preview.status" => "Status",
+    PreviewType = "preview.type" => "Type",
+    PreviewDuration = "preview.duration" => "Duration",
+    PreviewHistory = "preview.history" => "History",
+    PreviewLastFailure = "preview.last_failure" => "Last Failure",
+    PreviewRunning = "preview.running" => "{0} (running)",
+    PreviewEvents = "preview.events" => "{0} events",
+
+    // Quick actions menu
+    QuickActionsTitle = "quick_actions.title" => " Actions ",
+    QuickActionsHint = "quick_actions.hint" => " Enter to run | Esc to cancel",
+    QuickActionSignal = "quick_actions.signal" => "Signal workflow",
+    QuickActionCopyId = "quick_actions.copy_id" => "Copy ID",
+    QuickActionCopyLink = "quick_actions.copy_link" => "Copy link",
+    QuickActionOpenWebUi = "quick_actions.open_web_ui" => "Open in Web UI",
+
+    // Notices
+    NoticeCopied = "notice.copied" => "copied {0}",
+    NoticeOpening = "notice.opening" => "opening {0}",
+
+    // Confirmations
+    ConfirmTitle = "confirm.title" => " Confirm ",
+    ConfirmYes = "confirm.yes" => " confirm  ",
+    ConfirmNo = "confirm.no" => " cancel",
+    ConfirmTypePhrase = "confirm.type_phrase" => "Type {0} to confirm:",
+    ConfirmCancelWorkflow = "confirm.cancel_workflow" => "Cancel workflow {0}?",
+    ConfirmTerminateWorkflow = "confirm.terminate_workflow" => "Terminate workflow {0}?",
+    ConfirmBatchMarked = "confirm.batch_marked" => "{0} {1} marked workflows?",
+    ConfirmBatchQuery = "confirm.batch_query" => "{0} {1} workflows matching {2}?",
+    ConfirmRestartStep1 = "confirm.restart_step1" => "Step 1/2: Terminate workflow {0}?",
+    ConfirmRestartStep2 = "confirm.restart_step2" => "Step 2/2: Start a new run of {0} with the same input?",
+    ConfirmTriggerSchedule = "confirm.trigger_schedule" => "Trigger schedule {0}?",
+    ConfirmDeleteSchedule = "confirm.delete_schedule" => "Delete schedule {0}?",
+    ConfirmPauseSchedule = "confirm.pause_schedule" => "Pause schedule {0}?",
+    ConfirmEditSchedule = "confirm.edit_schedule" => "Edit schedule {0}?",
+    ConfirmCancelActivity = "confirm.cancel_activity" => "Cancel activity {0}?",
+    ConfirmTerminateActivity = "confirm.terminate_activity" => "Terminate activity {0}?",
+    ConfirmDeleteActivity = "confirm.delete_activity" => "Delete activity {0}?",
+    ConfirmActivityOptions = "confirm.activity_options" => "Update {0} of activity {1}?",
+    ConfirmRegisterNamespace = "confirm.register_namespace" => "Register namespace {0} with {1} retention?",
+    ConfirmRetention = "confirm.retention" => "Set retention of namespace {0} to {1}?",
+    ConfirmDescribeNamespace = "confirm.describe_namespace" => "Set description of namespace {0} to \"{1}\"?",
+    ConfirmDeprecateNamespace = "confirm.deprecate_namespace" => "Deprecate namespace {0}? New workflows can no longer start in it.",
+}
+
+static CATALOG: OnceLock<HashMap<Msg, String>> = OnceLock::new();
+
+/// Loads `locale` over the English catalog. `en` needs no file. Only the first
+/// successful call takes effect.
+pub fn load(locale: &str) -> Result<(), String> {
+    if locale == "en" {
+        return Ok(());
+    }
+    let path = dirs::config_dir()
+        .map(|dir| {
+            dir.join("t9s")
+                .join("locales")
+                .join(format!("{}.toml", locale))
+        })
+        .ok_or_else(|| "no config directory for locale files".to_string())?;
+    let content = std::fs::read_to_string(&path)
+        .map_err(|e| format!("locale {}: {}: {}", locale, path.display(), e))?;
+    let (catalog, unknown) = parse(&content)?;
+    let _ = CATALOG.set(catalog);
+    if unknown.is_empty() {
+        Ok(())
+    } else {
+        Err(format!(
+            "locale {}: unknown keys {}",
+            locale,
+            unknown.join(", ")
+        ))
+    }
+}
+
+/// Parses a locale file into translations, plus any keys that don't exist.
+pub fn parse(content: &str) -> Result<(HashMap<Msg, String>, Vec<String>), String> {
+    let table: HashMap<String, String> = toml::from_str(content).map_err(|e| e.to_string())?;
+    let mut catalog = HashMap::new();
+    let mut unknown = Vec::new();
+    for (key, text) in table {
+        match Msg::ALL.iter().find(|m| m.key() == key) {
+            Some(msg) => {
+                catalog.insert(*msg, text);
+            }
+            None => unknown.push(key),
+        }
+    }
+    unknown.sort();
+    Ok((catalog, unknown))
+}
+
+/// The text for `msg` in the active locale.
+pub fn t(msg: Msg) -> &'static str {
+    CATALOG
+        .get()
+        .and_then(|catalog| catalog.get(&msg))
+        .map_or_else(|| msg.english(), String::as_str)
+}
+
+/// [`t`] with `{0}`, `{1}`, ... replaced by `args`.
+pub fn tf(msg: Msg, args: &[&str]) -> String {
+    args.iter()
+        .enumerate()
+        .fold(t(msg).to_string(), |text, (i, arg)| {
+            text.replace(&format!("{{{}}}", i), arg)
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn locale_file_overrides_and_placeholders() {
+        let (catalog, unknown) = parse(
+            r#"
+            "hint.quit" = "beenden"
+            "confirm.cancel_workflow" = "Workflow {0} abbrechen?"
+            "hint.nope" = "x"
+            "#,
+        )
+        .unwrap();
+        assert_eq!(
+            catalog.get(&Msg::HintQuit).map(String::as_str),
+            Some("beenden")
+        );
+        assert_eq!(unknown, vec!["hint.nope"]);
+
+        assert_eq!(
+            tf(Msg::ConfirmCancelWorkflow, &["wf-1"]),
+            "Cancel workflow wf-1?"
+        );
+
+        let mut keys: Vec<&str> = Msg::ALL.iter().map(|m| m.key()).collect();
+        keys.sort();
+        keys.dedup();
+        assert_eq!(keys.len(), Msg::ALL.len(), "duplicate catalog keys");
+    }
+}
@@ -36,10 +36,135 @@ pub static COMMANDS: &[CommandDef] = &[
         description:
             "Open a deep link URI (e.g. :open temporal://tui/namespaces/default/workflows)",
     },
+    CommandDef {
+        name: "ctx",
+        aliases: &["context"],
+        description: "Switch cluster context (e.g. :ctx prod); no name opens a picker",
+    },
+    CommandDef {
+        name: "pause",
+        aliases: &[],
+        description: "Pause the selected schedule with a note (e.g. :pause deploy freeze)",
+    },
+    CommandDef {
+        name: "unpause",
+        aliases: &[],
+        description: "Unpause the selected schedule with a note",
+    },
+    CommandDef {
+        name: "attributes",
+        aliases: &["attr"],
+        description: "List search attributes, or add one (e.g. :attr add CustomerId Keyword)",
+    },
+    CommandDef {
+        name: "group",
+        aliases: &["grp"],
+        description: "Group schedules (e.g. :group type, :group state, :group none)",
+    },
+    CommandDef {
+        name: "timezone",
+        aliases: &["tz"],
+        description: "Show times in a zone (e.g. :tz utc, :tz local, :tz Europe/Berlin)",
+    },
+    CommandDef {
+        name: "overview",
+        aliases: &["ov"],
+        description: "Namespace overview: counts by status and type, schedules, idle task queues",
+    },
+    CommandDef {
+        name: "nsadmin",
+        aliases: &[],
+        description: "Register, describe, set retention of or deprecate a namespace",
+    },
+    CommandDef {
+        name: "poll",
+        aliases: &[],
+        description: "Poll interval for this view, or list/detail/history (e.g. :poll history 10)",
+    },
+    CommandDef {
+        name: "last",
+        aliases: &[],
+        description: "Only workflows started recently (e.g. :last 1h; 15m, 24h, 7d, all)",
+    },
+    CommandDef {
+        name: "!",
+        aliases: &[],
+        description: "Run a shell command with the selection in T9S_* env vars (e.g. :!temporal workflow show -w $T9S_WORKFLOW_ID)",
+    },
+    CommandDef {
+        name: "pipe",
+        aliases: &[],
+        description: "Pipe the selected payload through a [[pipes]] command (e.g. :pipe jq)",
+    },
+    CommandDef {
+        name: "bulk",
+        aliases: &[],
+        description: "Cancel or terminate the marked workflows or the whole search (e.g. :bulk terminate)",
+    },
+    CommandDef {
+        name: "export",
+        aliases: &[],
+        description: "Write the list to CSV or JSON (e.g. :export --all failed.json); :export! overwrites",
+    },
+    CommandDef {
+        name: "save-input",
+        aliases: &[],
+        description: "Write the workflow's decoded input to a file (e.g. :save-input input.json)",
+    },
+    CommandDef {
+        name: "save-output",
+        aliases: &[],
+        description: "Write the workflow's decoded output to a file (e.g. :save-output output.json)",
+    },
+    CommandDef {
+        name: "pagesize",
+        aliases: &["ps"],
+        description: "Rows fetched per page for this list, reloading it (e.g. :pagesize 200)",
+    },
+    CommandDef {
+        name: "preview",
+        aliases: &[],
+        description: "Toggle a compact preview of the highlighted workflow under the list",
+    },
+    CommandDef {
+        name: "link",
+        aliases: &[],
+        description: "Copy a deep link to this view to the clipboard (also Y)",
+    },
+    CommandDef {
+        name: "bookmark",
+        aliases: &["bm"],
+        description: "Bookmark this view (e.g. :bm incident-42); no name opens the bookmarks",
+    },
+    CommandDef {
+        name: "logs",
+        aliases: &["log"],
+        description: "Show errors from this session",
+    },
+    CommandDef {
+        name: "archived",
+        aliases: &[],
+        description: "Toggle the workflow list between live and archived runs",
+    },
+    CommandDef {
+        name: "allns",
+        aliases: &[],
+        description: "List workflows across namespaces (e.g. :allns payments orders); again to leave",
+    },
+    CommandDef {
+        name: "audit",
+        aliases: &[],
+        description: "Show the operations sent this session and their outcome",
+    },
+    CommandDef {
+        name: "copy-mode",
+        aliases: &["copy"],
+        description: "Toggle mouse capture so terminal text selection works",
+    },
     CommandDef {
         name: "quit",
         aliases: &["q"],
-        description: "Quit t9s",
+        description: "Quit t9s, or apply a saved query with :q <name>",
     },
     CommandDef {
         name: "help",
@@ -71,7 +196,7 @@ mod tests {
         assert_eq!(matching_commands("wf").len(), 1);
         assert_eq!(matching_commands("wf")[0].name, "workflows");
 
-        assert_eq!(matching_commands("s").len(), 2); // schedules + signal
+        assert_eq!(matching_commands("s").len(), 4); // schedules, signal, save-input, save-output
         assert_eq!(matching_commands("sch").len(), 1);
         assert_eq!(matching_commands("sch")[0].name, "schedules");
 
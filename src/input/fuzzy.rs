@@ -0,0 +1,112 @@
+use std::ops::Range;
+
+/// Whether every whitespace-separated term of `needle` appears in `haystack` as
+/// a case-insensitive subsequence, e.g. `"pay fail"` matches
+/// `"payment-42 PaymentWorkflow Failed"`.
+pub fn fuzzy_matches(needle: &str, haystack: &str) -> bool {
+    let haystack = haystack.to_lowercase();
+    needle
+        .split_whitespace()
+        .all(|term| is_subsequence(&term.to_lowercase(), &haystack))
+}
+
+fn is_subsequence(term: &str, haystack: &str) -> bool {
+    let mut chars = haystack.chars();
+    term.chars().all(|c| chars.any(|h| h == c))
+}
+
+/// Byte ranges of `text` to highlight for `needle`'s terms: where a term
+/// appears whole, otherwise the characters of its subsequence, ignoring case.
+/// Terms found in neither way highlight nothing.
+pub fn fuzzy_ranges(needle: &str, text: &str) -> Vec<Range<usize>> {
+    let mut ranges = Vec::new();
+    for term in needle.split_whitespace() {
+        let whole = substring_ranges(term, text);
+        if !whole.is_empty() {
+            ranges.extend(whole);
+            continue;
+        }
+        let chars: Vec<(usize, char)> = text.char_indices().collect();
+        let mut at = 0;
+        let mut found = Vec::new();
+        for c in term.chars().map(lower) {
+            match chars[at..].iter().position(|&(_, h)| lower(h) == c) {
+                Some(offset) => {
+                    let (i, h) = chars[at + offset];
+                    found.push(i..i + h.len_utf8());
+                    at += offset + 1;
+                }
+                None => {
+                    found.clear();
+                    break;
+                }
+            }
+        }
+        ranges.extend(found);
+    }
+    ranges
+}
+
+/// Byte ranges of every occurrence of `term` in `text`, ignoring case.
+pub fn substring_ranges(term: &str, text: &str) -> Vec<Range<usize>> {
+    let term: Vec<char> = term.chars().map(lower).collect();
+    let chars: Vec<(usize, char)> = text.char_indices().collect();
+    if term.is_empty() {
+        return vec![];
+    }
+    let mut ranges = Vec::new();
+    let mut i = 0;
+    while i + term.len() <= chars.len() {
+        let matched = chars[i..i + term.len()]
+            .iter()
+            .zip(&term)
+            .all(|(&(_, h), &c)| lower(h) == c);
+        if matched {
+            let (last, c) = chars[i + term.len() - 1];
+            ranges.push(chars[i].0..last + c.len_utf8());
+            i += term.len();
+        } else {
+            i += 1;
+        }
+    }
+    ranges
+}
+
+fn lower(c: char) -> char {
+    c.to_lowercase().next().unwrap_or(c)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fuzzy_matches() {
+        assert!(fuzzy_matches("", "anything"));
+        assert!(fuzzy_matches("ordwf", "order-1 OrderWorkflow Running"));
+        assert!(fuzzy_matches(
+            "pay FAIL",
+            "payment-42 PaymentWorkflow Failed"
+        ));
+        assert!(!fuzzy_matches(
+            "pay run",
+            "payment-42 PaymentWorkflow Failed"
+        ));
+        assert!(!fuzzy_matches("wfo", "wof"));
+    }
+
+    #[test]
+    fn ranges_prefer_whole_terms_over_subsequences() {
+        assert_eq!(fuzzy_ranges("PAY", "payment-pay"), vec![0..3, 8..11]);
+        assert_eq!(
+            fuzzy_ranges("pwf", "PaymentWorkflow"),
+            vec![0..1, 7..8, 11..12]
+        );
+        assert_eq!(
+            fuzzy_ranges("order zzz", "order-1"),
+            vec![0..5],
+            "an unmatched term highlights nothing"
+        );
+        assert!(substring_ranges("", "anything").is_empty());
+    }
+}
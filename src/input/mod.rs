@@ -1,3 +1,8 @@
 pub mod commands;
+pub mod fuzzy;
+pub mod query_builder;
+pub mod query_complete;
+pub mod query_syntax;
 
 pub use commands::*;
+pub use fuzzy::*;
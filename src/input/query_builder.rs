@@ -0,0 +1,272 @@
+//! Guided construction of a visibility query: pick an attribute, then an
+//! operator, then type a value, and chain clauses with AND / OR. Clauses are
+//! joined left to right, so the usual SQL precedence (AND before OR) applies.
+
+use crate::domain::SearchAttributeType;
+
+/// Attributes offered before the namespace's own list has loaded.
+pub const DEFAULT_ATTRIBUTES: &[(&str, SearchAttributeType)] = &[
+    ("ExecutionStatus", SearchAttributeType::Keyword),
+    ("WorkflowType", SearchAttributeType::Keyword),
+    ("WorkflowId", SearchAttributeType::Keyword),
+    ("TaskQueue", SearchAttributeType::Keyword),
+    ("StartTime", SearchAttributeType::Datetime),
+    ("CloseTime", SearchAttributeType::Datetime),
+    ("ExecutionDuration", SearchAttributeType::Int),
+];
+
+/// Choices once a clause is complete.
+pub const NEXT_CHOICES: &[&str] = &["AND", "OR", "Apply"];
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum BuilderStep {
+    #[default]
+    Attribute,
+    Operator,
+    Value,
+    Next,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+struct Clause {
+    connective: &'static str,
+    attribute: String,
+    operator: &'static str,
+    value: String,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct QueryBuilder {
+    pub step: BuilderStep,
+    pub cursor: usize,
+    /// Value typed in the Value step.
+    pub value: String,
+    clauses: Vec<Clause>,
+    /// AND / OR joining the clause being built to the previous one.
+    connective: &'static str,
+    attribute: Option<(String, Option<SearchAttributeType>)>,
+    operator: Option<&'static str>,
+}
+
+impl QueryBuilder {
+    /// Operators that make sense for the chosen attribute's type.
+    pub fn operators(&self) -> &'static [&'static str] {
+        let value_type = self.attribute.as_ref().and_then(|(_, t)| *t);
+        match value_type {
+            Some(SearchAttributeType::Keyword) => {
+                &["=", "!=", "IN", "STARTS_WITH", "IS NULL", "IS NOT NULL"]
+            }
+            Some(SearchAttributeType::KeywordList) => &["=", "!=", "IN", "IS NULL", "IS NOT NULL"],
+            Some(SearchAttributeType::Text) => &["=", "!=", "IS NULL", "IS NOT NULL"],
+            Some(
+                SearchAttributeType::Int
+                | SearchAttributeType::Double
+                | SearchAttributeType::Datetime,
+            ) => &["=", "!=", ">", ">=", "<", "<=", "IS NULL", "IS NOT NULL"],
+            Some(SearchAttributeType::Bool) | None => &["=", "!="],
+        }
+    }
+
+    /// Labels of the choices in the current step; empty while typing a value.
+    pub fn options(&self, attributes: &[(String, Option<SearchAttributeType>)]) -> Vec<String> {
+        match self.step {
+            BuilderStep::Attribute => attributes.iter().map(|(name, _)| name.clone()).collect(),
+            BuilderStep::Operator => self.operators().iter().map(|o| o.to_string()).collect(),
+            BuilderStep::Value => vec![],
+            BuilderStep::Next => NEXT_CHOICES.iter().map(|c| c.to_string()).collect(),
+        }
+    }
+
+    /// Confirms the current step. Returns the finished query once Apply is
+    /// chosen.
+    pub fn select(
+        &mut self,
+        attributes: &[(String, Option<SearchAttributeType>)],
+    ) -> Option<String> {
+        match self.step {
+            BuilderStep::Attribute => {
+                let attribute = attributes.get(self.cursor)?.clone();
+                self.attribute = Some(attribute);
+                self.advance(BuilderStep::Operator);
+            }
+            BuilderStep::Operator => {
+                let operator = *self.operators().get(self.cursor)?;
+                self.operator = Some(operator);
+                if operator.starts_with("IS ") {
+                    self.finish_clause();
+                } else {
+                    self.advance(BuilderStep::Value);
+                }
+            }
+            BuilderStep::Value => {
+                if !self.value.trim().is_empty() {
+                    self.finish_clause();
+                }
+            }
+            BuilderStep::Next => match NEXT_CHOICES.get(self.cursor).copied()? {
+                "Apply" => return Some(self.query()),
+                connective => {
+                    self.connective = connective;
+                    self.advance(BuilderStep::Attribute);
+                }
+            },
+        }
+        None
+    }
+
+    /// Steps back, dropping the last finished clause from the Next step.
+    pub fn back(&mut self) {
+        self.step = match self.step {
+            BuilderStep::Attribute if !self.clauses.is_empty() => {
+                self.connective = "";
+                BuilderStep::Next
+            }
+            BuilderStep::Attribute => BuilderStep::Attribute,
+            BuilderStep::Operator => BuilderStep::Attribute,
+            BuilderStep::Value => BuilderStep::Operator,
+            BuilderStep::Next => {
+                let clause = self.clauses.pop();
+                self.connective = clause.as_ref().map_or("", |c| c.connective);
+                if self.clauses.is_empty() {
+                    self.connective = "";
+                }
+                BuilderStep::Attribute
+            }
+        };
+        self.cursor = 0;
+    }
+
+    /// The query built from the finished clauses.
+    pub fn query(&self) -> String {
+        self.clauses
+            .iter()
+            .map(|c| {
+                let clause = if c.operator.starts_with("IS ") {
+                    format!("{} {}", c.attribute, c.operator)
+                } else {
+                    format!("{} {} {}", c.attribute, c.operator, c.value)
+                };
+                if c.connective.is_empty() {
+                    clause
+                } else {
+                    format!("{} {}", c.connective, clause)
+                }
+            })
+            .collect::<Vec<_>>()
+            .join(" ")
+    }
+
+    /// The clause under construction, e.g. `AND WorkflowType =`.
+    pub fn pending(&self) -> String {
+        [
+            self.connective,
+            self.attribute
+                .as_ref()
+                .filter(|_| self.step != BuilderStep::Attribute)
+                .map_or("", |(name, _)| name.as_str()),
+            self.operator
+                .filter(|_| self.step == BuilderStep::Value)
+                .unwrap_or(""),
+        ]
+        .iter()
+        .filter(|part| !part.is_empty())
+        .copied()
+        .collect::<Vec<_>>()
+        .join(" ")
+    }
+
+    fn advance(&mut self, step: BuilderStep) {
+        self.step = step;
+        self.cursor = 0;
+    }
+
+    fn finish_clause(&mut self) {
+        let (Some((attribute, value_type)), Some(operator)) =
+            (self.attribute.take(), self.operator.take())
+        else {
+            return;
+        };
+        let value = std::mem::take(&mut self.value);
+        self.clauses.push(Clause {
+            connective: std::mem::take(&mut self.connective),
+            attribute,
+            operator,
+            value: if operator == "IN" {
+                let items: Vec<String> = value
+                    .split(',')
+                    .map(str::trim)
+                    .filter(|v| !v.is_empty())
+                    .map(|v| literal(v, value_type))
+                    .collect();
+                format!("({})", items.join(", "))
+            } else {
+                literal(value.trim(), value_type)
+            },
+        });
+        self.advance(BuilderStep::Next);
+    }
+}
+
+/// Numbers and booleans go in bare; everything else is quoted.
+fn literal(value: &str, value_type: Option<SearchAttributeType>) -> String {
+    match value_type {
+        Some(
+            SearchAttributeType::Int | SearchAttributeType::Double | SearchAttributeType::Bool,
+        ) => value.to_string(),
+        _ if value.contains('\'') => format!("\"{}\"", value),
+        _ => format!("'{}'", value),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn builds_chained_clauses() {
+        let attributes = vec![
+            (
+                "ExecutionStatus".to_string(),
+                Some(SearchAttributeType::Keyword),
+            ),
+            ("Priority".to_string(), Some(SearchAttributeType::Int)),
+            ("CloseTime".to_string(), Some(SearchAttributeType::Datetime)),
+        ];
+        let mut builder = QueryBuilder::default();
+        let pick = |builder: &mut QueryBuilder, label: &str| {
+            builder.cursor = builder
+                .options(&attributes)
+                .iter()
+                .position(|o| o == label)
+                .unwrap();
+            builder.select(&attributes)
+        };
+
+        pick(&mut builder, "ExecutionStatus");
+        pick(&mut builder, "IN");
+        builder.value = "Running, Failed".to_string();
+        assert_eq!(builder.pending(), "ExecutionStatus IN");
+        builder.select(&attributes);
+        pick(&mut builder, "AND");
+        pick(&mut builder, "Priority");
+        pick(&mut builder, ">=");
+        builder.value = "3".to_string();
+        builder.select(&attributes);
+        pick(&mut builder, "OR");
+        pick(&mut builder, "CloseTime");
+        pick(&mut builder, "IS NULL");
+
+        assert_eq!(
+            pick(&mut builder, "Apply").as_deref(),
+            Some("ExecutionStatus IN ('Running', 'Failed') AND Priority >= 3 OR CloseTime IS NULL")
+        );
+
+        builder.back();
+        assert_eq!(builder.step, BuilderStep::Attribute);
+        assert_eq!(builder.pending(), "OR");
+        assert_eq!(
+            builder.query(),
+            "ExecutionStatus IN ('Running', 'Failed') AND Priority >= 3"
+        );
+    }
+}
@@ -0,0 +1,246 @@
+//! Completion for the `/` search modal: attribute names at the start of a
+//! clause, operators after an attribute, known values after an operator and
+//! AND / OR after a value.
+
+use std::collections::HashMap;
+
+use super::query_syntax::{lex, Token};
+
+pub const OPERATORS: &[&str] = &[
+    "=",
+    "!=",
+    ">",
+    ">=",
+    "<",
+    "<=",
+    "IN",
+    "STARTS_WITH",
+    "BETWEEN",
+    "IS NULL",
+    "IS NOT NULL",
+];
+
+const CONNECTIVES: &[&str] = &["AND", "OR", "ORDER BY"];
+
+/// Words after which a new clause (so an attribute name) starts.
+const CLAUSE_STARTS: &[&str] = &["AND", "OR", "NOT", "BY"];
+
+/// Words after which a value follows.
+const VALUE_OPERATORS: &[&str] = &["IN", "STARTS_WITH", "BETWEEN"];
+
+/// What completion draws from.
+#[derive(Debug, Default)]
+pub struct Vocabulary {
+    pub attributes: Vec<String>,
+    /// Known values per attribute, e.g. statuses or workflow types.
+    pub values: HashMap<String, Vec<String>>,
+}
+
+#[derive(Debug, Default, PartialEq)]
+pub struct Completion {
+    /// Byte offset where the word being completed starts.
+    pub start: usize,
+    pub candidates: Vec<String>,
+}
+
+impl Completion {
+    /// `query` with the first candidate in place of the word being typed.
+    pub fn accept(&self, query: &str) -> Option<String> {
+        let candidate = self.candidates.first()?;
+        let head = &query[..self.start];
+        let gap = if head.is_empty() || head.ends_with([' ', '(']) {
+            ""
+        } else {
+            " "
+        };
+        Some(format!("{head}{gap}{candidate} "))
+    }
+
+    /// What the first candidate would add to the word being typed, for
+    /// showing as ghost text after the cursor.
+    pub fn ghost<'a>(&'a self, query: &str) -> Option<&'a str> {
+        let typed = &query[self.start..];
+        self.candidates
+            .first()?
+            .strip_prefix(typed)
+            .filter(|rest| !rest.is_empty())
+    }
+}
+
+/// Candidates for the word at the end of `query`.
+pub fn complete(query: &str, vocabulary: &Vocabulary) -> Completion {
+    let (tokens, start) = tokenize(query);
+    let typed = &query[start..];
+    let bare = typed.trim_start_matches(['\'', '"']);
+    let matches = |candidate: &str| {
+        candidate
+            .get(..bare.len())
+            .is_some_and(|head| head.eq_ignore_ascii_case(bare))
+            && !candidate.eq_ignore_ascii_case(typed)
+    };
+
+    let candidates = match context(&tokens) {
+        Context::Attribute => vocabulary
+            .attributes
+            .iter()
+            .filter(|a| matches(a))
+            .cloned()
+            .collect(),
+        Context::Operator => OPERATORS
+            .iter()
+            .filter(|o| matches(o))
+            .map(|o| o.to_string())
+            .collect(),
+        Context::Value(attribute) => vocabulary
+            .values
+            .get(attribute)
+            .into_iter()
+            .flatten()
+            .filter(|v| matches(v))
+            .map(|v| format!("'{}'", v))
+            .collect(),
+        Context::Connective => CONNECTIVES
+            .iter()
+            .filter(|c| matches(c))
+            .map(|c| c.to_string())
+            .collect(),
+    };
+    Completion { start, candidates }
+}
+
+enum Context<'a> {
+    Attribute,
+    Operator,
+    Value(&'a str),
+    Connective,
+}
+
+fn context<'a>(tokens: &[Token<'a>]) -> Context<'a> {
+    let Some((last, before)) = tokens.split_last() else {
+        return Context::Attribute;
+    };
+    match *last {
+        Token::Word(w) if is_one_of(w, CLAUSE_STARTS) => Context::Attribute,
+        Token::Word(w) if is_one_of(w, VALUE_OPERATORS) => value_of(before),
+        Token::Operator => value_of(before),
+        // Inside an IN list.
+        Token::Open | Token::Comma => match in_list_attribute(before) {
+            Some(attribute) => Context::Value(attribute),
+            None if *last == Token::Open => Context::Attribute,
+            None => Context::Connective,
+        },
+        Token::Word(_) => match before.last() {
+            Some(Token::Operator) => Context::Connective,
+            Some(Token::Word(w)) if !is_one_of(w, CLAUSE_STARTS) => Context::Connective,
+            _ => Context::Operator,
+        },
+        Token::Quoted(_) | Token::Literal(_) | Token::Close => Context::Connective,
+    }
+}
+
+fn value_of<'a>(before: &[Token<'a>]) -> Context<'a> {
+    match before.last() {
+        Some(Token::Word(attribute)) => Context::Value(attribute),
+        _ => Context::Connective,
+    }
+}
+
+/// The attribute of the IN list the tokens end inside, if any.
+fn in_list_attribute<'a>(tokens: &[Token<'a>]) -> Option<&'a str> {
+    let open = tokens.iter().rposition(|t| *t == Token::Open)?;
+    if tokens[open..].contains(&Token::Close) {
+        return None;
+    }
+    match tokens[..open] {
+        [.., Token::Word(attribute), Token::Word(op)] if op.eq_ignore_ascii_case("IN") => {
+            Some(attribute)
+        }
+        _ => None,
+    }
+}
+
+fn is_one_of(word: &str, words: &[&str]) -> bool {
+    words.iter().any(|w| w.eq_ignore_ascii_case(word))
+}
+
+/// Tokens before the word being typed, and where that word starts. An
+/// unterminated quote counts as a word being typed, as does a word or
+/// operator running to the end of `query`.
+fn tokenize(query: &str) -> (Vec<Token<'_>>, usize) {
+    let (mut tokens, unterminated) = lex(query);
+    let start = match (unterminated, tokens.last()) {
+        (Some(at), _) => at,
+        (None, Some(&(at, token))) if runs_to_end(query, at, token) => {
+            tokens.pop();
+            at
+        }
+        _ => query.len(),
+    };
+    (tokens.into_iter().map(|(_, token)| token).collect(), start)
+}
+
+/// Whether `token`, starting at `at`, is still being typed.
+fn runs_to_end(query: &str, at: usize, token: Token) -> bool {
+    match token {
+        Token::Word(w) => at + w.len() == query.len(),
+        Token::Operator => query[at..].chars().all(|c| "=!<>".contains(c)),
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn completes_by_position_in_the_clause() {
+        let vocabulary = Vocabulary {
+            attributes: vec!["WorkflowId".into(), "WorkflowType".into()],
+            values: HashMap::from([(
+                "ExecutionStatus".to_string(),
+                vec!["Running".to_string(), "Failed".to_string()],
+            )]),
+        };
+        let first = |q: &str| {
+            let completion = complete(q, &vocabulary);
+            completion.accept(q)
+        };
+
+        assert_eq!(
+            complete("workflowt", &vocabulary).candidates,
+            vec!["WorkflowType"]
+        );
+        assert_eq!(first("WorkflowT").as_deref(), Some("WorkflowType "));
+        assert_eq!(first("WorkflowType !").as_deref(), Some("WorkflowType != "));
+        assert_eq!(
+            first("ExecutionStatus = 'f").as_deref(),
+            Some("ExecutionStatus = 'Failed' ")
+        );
+        assert_eq!(
+            first("ExecutionStatus IN ('Running', 'fa").as_deref(),
+            Some("ExecutionStatus IN ('Running', 'Failed' ")
+        );
+        assert_eq!(
+            first("ExecutionStatus = 'Running'").as_deref(),
+            Some("ExecutionStatus = 'Running' AND ")
+        );
+        assert_eq!(
+            first("ExecutionStatus = 'Running' or ").as_deref(),
+            Some("ExecutionStatus = 'Running' or WorkflowId ")
+        );
+        assert_eq!(
+            complete("WorkflowType ", &vocabulary).candidates.len(),
+            OPERATORS.len()
+        );
+        assert_eq!(
+            complete("WorkflowType", &vocabulary).ghost("WorkflowType"),
+            None
+        );
+        assert_eq!(complete("Workf", &vocabulary).ghost("Workf"), Some("lowId"));
+        // Words inside a closed quote are not part of the clause.
+        assert_eq!(
+            first("WorkflowId = 'a or b'").as_deref(),
+            Some("WorkflowId = 'a or b' AND ")
+        );
+    }
+}
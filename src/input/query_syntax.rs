@@ -0,0 +1,283 @@
+//! Client-side checks on a visibility query before it is sent: balanced
+//! quotes and parentheses, and attribute names the namespace knows about.
+//! Anything subtler is left to the server.
+
+/// Words that may follow an attribute name in a comparison.
+const PREDICATE_WORDS: &[&str] = &["IN", "NOT", "IS", "STARTS_WITH", "BETWEEN"];
+
+/// Words that are never attribute names.
+const KEYWORDS: &[&str] = &[
+    "AND",
+    "OR",
+    "NOT",
+    "IN",
+    "IS",
+    "NULL",
+    "BETWEEN",
+    "STARTS_WITH",
+    "ORDER",
+    "BY",
+    "ASC",
+    "DESC",
+    "TRUE",
+    "FALSE",
+];
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(super) enum Token<'a> {
+    Word(&'a str),
+    /// A backquoted attribute name.
+    Quoted(&'a str),
+    /// A quoted value, without its quotes.
+    Literal(&'a str),
+    Operator,
+    Open,
+    Close,
+    Comma,
+}
+
+/// Checks `query`, returning a message pointing at the first problem.
+/// Attribute names are only checked when `known` is given.
+pub fn check(query: &str, known: Option<&[String]>) -> Result<(), String> {
+    let tokens = tokenize(query)?;
+    let Some(known) = known.filter(|k| !k.is_empty()) else {
+        return Ok(());
+    };
+    for (i, token) in tokens.iter().enumerate() {
+        let name = match *token {
+            Token::Word(w) if !is_keyword(w) && !is_value(w) => w,
+            Token::Quoted(w) => w,
+            _ => continue,
+        };
+        let compared = match tokens.get(i + 1) {
+            Some(Token::Operator) => true,
+            Some(Token::Word(next)) => PREDICATE_WORDS.iter().any(|p| p.eq_ignore_ascii_case(next)),
+            _ => false,
+        };
+        let is_operand = i > 0 && matches!(tokens[i - 1], Token::Operator);
+        if !compared || is_operand || known.iter().any(|k| k == name) {
+            continue;
+        }
+        return Err(match known.iter().find(|k| k.eq_ignore_ascii_case(name)) {
+            Some(close) => format!("unknown search attribute '{name}' (did you mean {close}?)"),
+            None => format!("unknown search attribute '{name}'"),
+        });
+    }
+    Ok(())
+}
+
+/// The quoted values `attribute` is compared with in `query`, e.g. `a` and
+/// `b` for `WorkflowId IN ('a', 'b')`. Empty when the query doesn't parse.
+pub fn compared_values(query: &str, attribute: &str) -> Vec<String> {
+    let Ok(tokens) = tokenize(query) else {
+        return vec![];
+    };
+    let mut values = Vec::new();
+    for (i, token) in tokens.iter().enumerate() {
+        match *token {
+            Token::Word(w) | Token::Quoted(w) if w.eq_ignore_ascii_case(attribute) => {}
+            _ => continue,
+        }
+        let mut rest = tokens[i + 1..].iter().skip_while(|t| match t {
+            Token::Operator => true,
+            Token::Word(w) => PREDICATE_WORDS.iter().any(|p| p.eq_ignore_ascii_case(w)),
+            _ => false,
+        });
+        match rest.next() {
+            Some(Token::Literal(value)) => values.push(value.to_string()),
+            Some(Token::Open) => values.extend(
+                rest.take_while(|t| !matches!(t, Token::Close))
+                    .filter_map(|t| match t {
+                        Token::Literal(value) => Some(value.to_string()),
+                        _ => None,
+                    }),
+            ),
+            _ => {}
+        }
+    }
+    values
+}
+
+/// `query` split before its ORDER BY, ignoring the words inside quotes:
+/// the filter and the ORDER BY clause, both trimmed. The clause is empty
+/// when there is none.
+pub fn split_order_by(query: &str) -> (&str, &str) {
+    let (tokens, _) = lex(query);
+    let at = tokens.windows(2).find_map(|pair| match pair {
+        [(at, Token::Word(order)), (_, Token::Word(by))]
+            if order.eq_ignore_ascii_case("ORDER") && by.eq_ignore_ascii_case("BY") =>
+        {
+            Some(*at)
+        }
+        _ => None,
+    });
+    match at {
+        Some(at) => (query[..at].trim(), query[at..].trim()),
+        None => (query.trim(), ""),
+    }
+}
+
+/// `input` with its single quotes escaped, to go between single quotes in
+/// a query.
+pub fn escape_single_quotes(input: &str) -> String {
+    input.replace('\'', "\\'")
+}
+
+fn tokenize(query: &str) -> Result<Vec<Token<'_>>, String> {
+    let (tokens, unterminated) = lex(query);
+    let mut opened = Vec::new();
+    for (at, token) in &tokens {
+        match token {
+            Token::Open => opened.push(*at),
+            Token::Close if opened.pop().is_none() => {
+                return Err(format!("unmatched ) at column {}", at + 1));
+            }
+            _ => {}
+        }
+    }
+    if let Some(at) = unterminated {
+        let quote = query[at..].chars().next().unwrap_or('\'');
+        return Err(format!("unterminated {quote} at column {}", at + 1));
+    }
+    match opened.last() {
+        Some(at) => Err(format!("unclosed ( at column {}", at + 1)),
+        None => Ok(tokens.into_iter().map(|(_, token)| token).collect()),
+    }
+}
+
+/// Every token with the byte offset it starts at. Stops at an unterminated
+/// quote and returns where it opened.
+pub(super) fn lex(query: &str) -> (Vec<(usize, Token<'_>)>, Option<usize>) {
+    let mut tokens = Vec::new();
+    let mut chars = query.char_indices().peekable();
+    while let Some((start, c)) = chars.next() {
+        let token = match c {
+            c if c.is_whitespace() => continue,
+            '\'' | '"' | '`' => {
+                let mut end = None;
+                while let Some((i, next)) = chars.next() {
+                    if next == '\\' {
+                        chars.next();
+                    } else if next == c {
+                        end = Some(i);
+                        break;
+                    }
+                }
+                let Some(end) = end else {
+                    return (tokens, Some(start));
+                };
+                if c == '`' {
+                    Token::Quoted(&query[start + 1..end])
+                } else {
+                    Token::Literal(&query[start + 1..end])
+                }
+            }
+            '(' => Token::Open,
+            ')' => Token::Close,
+            ',' => Token::Comma,
+            '=' | '!' | '<' | '>' => {
+                while chars
+                    .next_if(|(_, c)| matches!(c, '=' | '<' | '>'))
+                    .is_some()
+                {}
+                Token::Operator
+            }
+            _ => {
+                let mut end = query.len();
+                while let Some(&(i, next)) = chars.peek() {
+                    if next.is_whitespace() || "()'\"`,=!<>".contains(next) {
+                        end = i;
+                        break;
+                    }
+                    chars.next();
+                }
+                Token::Word(&query[start..end])
+            }
+        };
+        tokens.push((start, token));
+    }
+    (tokens, None)
+}
+
+fn is_keyword(word: &str) -> bool {
+    KEYWORDS.iter().any(|k| k.eq_ignore_ascii_case(word))
+}
+
+/// Bare numbers are values, not attribute names.
+fn is_value(word: &str) -> bool {
+    word.parse::<f64>().is_ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reports_syntax_and_unknown_attributes() {
+        let known: Vec<String> = ["ExecutionStatus", "WorkflowType", "CustomerId"]
+            .iter()
+            .map(|s| s.to_string())
+            .collect();
+        let check = |q: &str| check(q, Some(&known));
+
+        assert_eq!(
+            check(concat!(
+                "ExecutionStatus = 'Running' AND (WorkflowType STARTS_WITH \"Order\" ",
+                "OR `CustomerId` IN ('a', 'b')) ORDER BY StartTime DESC"
+            )),
+            Ok(())
+        );
+        assert_eq!(
+            check("WorkflowType = 'it''s"),
+            Err("unterminated ' at column 20".to_string())
+        );
+        assert_eq!(
+            check("(ExecutionStatus = 'Running'"),
+            Err("unclosed ( at column 1".to_string())
+        );
+        assert_eq!(
+            check("ExecutionStatus = 'Running')"),
+            Err("unmatched ) at column 28".to_string())
+        );
+        assert_eq!(
+            check("executionstatus != 'Running'"),
+            Err(
+                "unknown search attribute 'executionstatus' (did you mean ExecutionStatus?)"
+                    .to_string()
+            )
+        );
+        assert_eq!(
+            check("Region IS NOT NULL"),
+            Err("unknown search attribute 'Region'".to_string())
+        );
+        assert_eq!(super::check("Region IS NOT NULL", None), Ok(()));
+    }
+
+    #[test]
+    fn finds_the_values_an_attribute_is_compared_with() {
+        let query = concat!(
+            "WorkflowType STARTS_WITH 'Pay' AND (WorkflowId IN ('order-1', \"order-2\") ",
+            "OR ExecutionStatus = 'Running')"
+        );
+        assert_eq!(compared_values(query, "WorkflowType"), ["Pay"]);
+        assert_eq!(compared_values(query, "workflowid"), ["order-1", "order-2"]);
+        assert!(compared_values(query, "TaskQueue").is_empty());
+        assert!(compared_values("WorkflowId = 'open", "WorkflowId").is_empty());
+    }
+
+    #[test]
+    fn splits_at_an_order_by_outside_quotes() {
+        assert_eq!(
+            split_order_by("WorkflowType = 'a' ORDER BY StartTime DESC"),
+            ("WorkflowType = 'a'", "ORDER BY StartTime DESC")
+        );
+        assert_eq!(
+            split_order_by("WorkflowId = 'x order by y' "),
+            ("WorkflowId = 'x order by y'", "")
+        );
+        assert_eq!(
+            split_order_by("order by StartTime"),
+            ("", "order by StartTime")
+        );
+    }
+}
@@ -1,3 +1,10 @@
+use ratatui::layout::Constraint::{Length, Percentage};
+
+use crate::client::Capabilities;
+use crate::config::KindConfig;
+use crate::i18n::Msg;
+use crate::input::commands::COMMANDS;
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum KindId {
     WorkflowExecution,
@@ -24,14 +31,27 @@ pub struct KindSpec {
     pub operations: &'static [OperationSpec],
 }
 
+/// A column a collection can show. `width` is the default, which the config
+/// file may override.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ColumnSpec {
+    pub id: &'static str,
+    pub header: &'static str,
+    pub width: ratatui::layout::Constraint,
+}
+
 #[derive(Debug, Clone, Copy)]
 pub struct CollectionSpec {
-    pub header: &'static [&'static str],
-    pub widths: fn() -> Vec<ratatui::layout::Constraint>,
-    pub rows: fn(&crate::app::App) -> Option<Vec<ratatui::widgets::Row<'static>>>,
+    /// Every column this kind can render, in default order.
+    pub columns: &'static [ColumnSpec],
+    /// Ids of the columns shown when the config doesn't choose.
+    pub default_columns: &'static [&'static str],
+    /// Ids of the columns shown in wide mode (`W`) when the config doesn't choose.
+    pub wide_columns: &'static [&'static str],
+    pub rows: fn(&crate::app::App, &[ColumnSpec]) -> Option<Vec<ratatui::widgets::Row<'static>>>,
     pub is_loading: fn(&crate::app::App) -> bool,
-    pub loading_label: &'static str,
-    pub empty_label: &'static str,
+    pub loading_label: Msg,
+    pub empty_label: Msg,
     pub table_state: fn(&mut crate::app::App) -> &mut ratatui::widgets::TableState,
 }
 
@@ -50,20 +70,43 @@ pub struct OperationEffectSpec {
 pub enum OperationId {
     CancelWorkflow,
     TerminateWorkflow,
+    TerminateAndRestart,
     PauseSchedule,
     TriggerSchedule,
     DeleteSchedule,
+    EditSchedule,
     CancelActivityExecution,
     TerminateActivityExecution,
     DeleteActivityExecution,
 }
 
+impl OperationId {
+    /// Whether the active client exposes the admin trait this operation needs.
+    pub fn is_supported(self, caps: &Capabilities) -> bool {
+        match self {
+            OperationId::CancelWorkflow
+            | OperationId::TerminateWorkflow
+            | OperationId::TerminateAndRestart
+            | OperationId::CancelActivityExecution
+            | OperationId::TerminateActivityExecution
+            | OperationId::DeleteActivityExecution => caps.execution_admin,
+            OperationId::PauseSchedule
+            | OperationId::TriggerSchedule
+            | OperationId::DeleteSchedule
+            | OperationId::EditSchedule => caps.schedule_admin,
+        }
+    }
+}
+
 #[derive(Debug, Clone, Copy)]
 pub struct OperationSpec {
     pub id: OperationId,
     pub label: &'static str,
     pub key: char,
     pub requires_confirm: bool,
+    /// Can't be undone, so namespaces listed in `[[typed_confirm]]` make the
+    /// user type the target's id before confirming.
+    pub destructive: bool,
 }
 
 static KIND_SPECS: &[KindSpec] = &[
@@ -90,8 +133,95 @@ static KIND_SPECS: &[KindSpec] = &[
     },
 ];
 
-pub fn registry() -> &'static [KindSpec] {
-    KIND_SPECS
+/// A list from the config file's `[[kinds]]`: a built-in kind's collection
+/// with a fixed query and its own columns. Unlike [`KindSpec`]s these are
+/// only known at runtime.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CustomKind {
+    pub label: String,
+    pub command: String,
+    pub base: KindId,
+    pub query: String,
+    /// Empty keeps the base kind's columns.
+    pub columns: Vec<ColumnSpec>,
+}
+
+/// Every kind that can be listed: the built-in [`KindSpec`]s followed by
+/// the config file's `[[kinds]]`. Rebuilt each time the config is loaded.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct KindRegistry {
+    custom: Vec<CustomKind>,
+}
+
+impl KindRegistry {
+    /// Builds the registry from `[[kinds]]`. Entries that can't be used are
+    /// skipped and described in the returned errors.
+    pub fn load(configs: &[KindConfig]) -> (Self, Vec<String>) {
+        let mut registry = Self::default();
+        let mut errors = Vec::new();
+        for config in configs {
+            match registry.custom_kind(config) {
+                Ok(kind) => registry.custom.push(kind),
+                Err(e) => errors.push(format!("config: {}", e)),
+            }
+        }
+        (registry, errors)
+    }
+
+    fn custom_kind(&self, config: &KindConfig) -> Result<CustomKind, String> {
+        let base = match config.base.as_deref() {
+            None => KindId::WorkflowExecution,
+            Some(name) => self
+                .builtin_by_label(name)
+                .ok_or_else(|| format!("unknown base '{}' for kind '{}'", name, config.name))?,
+        };
+        let command = config
+            .command
+            .clone()
+            .unwrap_or_else(|| config.name.replace(' ', "-"))
+            .to_lowercase();
+        let taken = COMMANDS
+            .iter()
+            .any(|c| c.name == command || c.aliases.contains(&command.as_str()))
+            || self.custom_by_command(&command).is_some();
+        if command.is_empty() || command.contains(' ') || taken {
+            return Err(format!(
+                "kind '{}' needs a free one-word command, not '{}'",
+                config.name, command
+            ));
+        }
+        let columns = resolve_columns(base, &config.columns)
+            .map_err(|e| format!("kind '{}': {}", config.name, e))?;
+        Ok(CustomKind {
+            label: config.name.clone(),
+            command,
+            base,
+            query: config.query.clone(),
+            columns,
+        })
+    }
+
+    pub fn builtin(&self) -> &'static [KindSpec] {
+        KIND_SPECS
+    }
+
+    /// The built-in kind named `label`, case-insensitively, as in the
+    /// config file's `kind = "schedules"`.
+    pub fn builtin_by_label(&self, label: &str) -> Option<KindId> {
+        self.builtin()
+            .iter()
+            .map(|spec| spec.id)
+            .find(|kind| kind.label().eq_ignore_ascii_case(label))
+    }
+
+    pub fn custom(&self) -> &[CustomKind] {
+        &self.custom
+    }
+
+    /// Index into [`Self::custom`] of the list opened with `:command`.
+    pub fn custom_by_command(&self, command: &str) -> Option<usize> {
+        self.custom.iter().position(|k| k.command == command)
+    }
 }
 
 pub fn kind_spec(kind: KindId) -> &'static KindSpec {
@@ -133,7 +263,7 @@ pub fn operation_spec(kind: KindId, op_id: OperationId) -> Option<&'static Opera
 pub fn detail_tabs_for_kind(kind: KindId) -> Option<&'static [&'static str]> {
     match kind {
         KindId::WorkflowExecution => Some(WORKFLOW_DETAIL_TABS),
-        KindId::Schedule => None,
+        KindId::Schedule => Some(SCHEDULE_DETAIL_TABS),
         KindId::ActivityExecution => Some(ACTIVITY_DETAIL_TABS),
     }
 }
@@ -150,12 +280,21 @@ static WORKFLOW_OPS: &[OperationSpec] = &[
         label: "Cancel workflow",
         key: 'c',
         requires_confirm: true,
+        destructive: false,
     },
     OperationSpec {
         id: OperationId::TerminateWorkflow,
         label: "Terminate workflow",
         key: 't',
         requires_confirm: true,
+        destructive: true,
+    },
+    OperationSpec {
+        id: OperationId::TerminateAndRestart,
+        label: "Terminate and restart workflow",
+        key: 'R',
+        requires_confirm: true,
+        destructive: true,
     },
 ];
 
@@ -165,18 +304,28 @@ static SCHEDULE_OPS: &[OperationSpec] = &[
         label: "Pause/unpause schedule",
         key: 'p',
         requires_confirm: false,
+        destructive: false,
     },
     OperationSpec {
         id: OperationId::TriggerSchedule,
         label: "Trigger schedule",
         key: 'T',
         requires_confirm: true,
+        destructive: false,
     },
     OperationSpec {
         id: OperationId::DeleteSchedule,
         label: "Delete schedule",
         key: 'd',
         requires_confirm: true,
+        destructive: true,
+    },
+    OperationSpec {
+        id: OperationId::EditSchedule,
+        label: "Edit schedule",
+        key: 'e',
+        requires_confirm: false,
+        destructive: false,
     },
 ];
 
@@ -186,18 +335,21 @@ static ACTIVITY_OPS: &[OperationSpec] = &[
         label: "Cancel activity",
         key: 'c',
         requires_confirm: true,
+        destructive: false,
     },
     OperationSpec {
         id: OperationId::TerminateActivityExecution,
         label: "Terminate activity",
         key: 't',
         requires_confirm: true,
+        destructive: true,
     },
     OperationSpec {
         id: OperationId::DeleteActivityExecution,
         label: "Delete activity",
         key: 'd',
         requires_confirm: true,
+        destructive: true,
     },
 ];
 
@@ -205,10 +357,17 @@ static WORKFLOW_DETAIL_TABS: &[&str] = &[
     "Summary",
     "Input/Output",
     "History",
-    "Pending Activities",
+    "Pending",
     "Task Queue",
+    "Stack Trace",
+    "Children",
+    "Raw",
+    "Metadata",
+    "Timeline",
 ];
 
+static SCHEDULE_DETAIL_TABS: &[&str] = &["Summary", "Raw"];
+
 static ACTIVITY_DETAIL_TABS: &[&str] = &["Summary", "Input/Output", "Task Queue"];
 
 static OPERATION_EFFECTS: &[OperationEffectSpec] = &[
@@ -222,6 +381,11 @@ static OPERATION_EFFECTS: &[OperationEffectSpec] = &[
         kind: KindId::WorkflowExecution,
         to_effects: workflow_terminate_effects,
     },
+    OperationEffectSpec {
+        op: OperationId::TerminateAndRestart,
+        kind: KindId::WorkflowExecution,
+        to_effects: workflow_restart_effects,
+    },
     OperationEffectSpec {
         op: OperationId::TriggerSchedule,
         kind: KindId::Schedule,
@@ -237,6 +401,11 @@ static OPERATION_EFFECTS: &[OperationEffectSpec] = &[
         kind: KindId::Schedule,
         to_effects: schedule_pause_effects,
     },
+    OperationEffectSpec {
+        op: OperationId::EditSchedule,
+        kind: KindId::Schedule,
+        to_effects: schedule_edit_effects,
+    },
     OperationEffectSpec {
         op: OperationId::CancelActivityExecution,
         kind: KindId::ActivityExecution,
@@ -267,141 +436,502 @@ static ACTIVITY_DETAIL: DetailSpec = DetailSpec {
 };
 
 static WORKFLOW_COLLECTION: CollectionSpec = CollectionSpec {
-    header: &[" Status", "Workflow ID", "Type", "Started", "Task Queue"],
-    widths: workflow_widths,
+    columns: &[
+        column("status", " Status", Length(18)),
+        column("workflow_id", "Workflow ID", Percentage(30)),
+        column("run_id", "Run ID", Length(38)),
+        column("type", "Type", Percentage(20)),
+        column("start_time", "Started", Length(20)),
+        column("close_time", "Closed", Length(20)),
+        column("duration", "Duration", Length(12)),
+        column("task_queue", "Task Queue", Percentage(20)),
+        column("search_attributes", "Search Attributes", Percentage(25)),
+        column("namespace", "Namespace", Percentage(15)),
+    ],
+    default_columns: &["status", "workflow_id", "type", "start_time", "task_queue"],
+    wide_columns: &[
+        "status",
+        "workflow_id",
+        "run_id",
+        "type",
+        "start_time",
+        "close_time",
+        "duration",
+        "task_queue",
+        "search_attributes",
+    ],
     rows: workflow_rows,
     is_loading: workflow_is_loading,
-    loading_label: " Loading workflows...",
-    empty_label: " No workflows loaded",
+    loading_label: Msg::LoadingWorkflows,
+    empty_label: Msg::NoWorkflows,
     table_state: workflow_table_state,
 };
 
 static SCHEDULE_COLLECTION: CollectionSpec = CollectionSpec {
-    header: &[
-        " State",
-        "Schedule ID",
-        "Workflow Type",
-        "Next Run",
-        "Actions",
+    columns: &[
+        column("state", " State", Length(12)),
+        column("schedule_id", "Schedule ID", Percentage(30)),
+        column("workflow_type", "Workflow Type", Percentage(25)),
+        column("spec", "Spec", Percentage(25)),
+        column("next_run", "Next Run", Length(20)),
+        column("actions", "Actions", Length(10)),
+        column("notes", "Notes", Percentage(20)),
+    ],
+    default_columns: &[
+        "state",
+        "schedule_id",
+        "workflow_type",
+        "next_run",
+        "actions",
+        "notes",
+    ],
+    wide_columns: &[
+        "state",
+        "schedule_id",
+        "workflow_type",
+        "spec",
+        "next_run",
+        "actions",
+        "notes",
     ],
-    widths: schedule_widths,
     rows: schedule_rows,
     is_loading: schedule_is_loading,
-    loading_label: " Loading schedules...",
-    empty_label: " No schedules loaded",
+    loading_label: Msg::LoadingSchedules,
+    empty_label: Msg::NoSchedules,
     table_state: schedule_table_state,
 };
 
 static ACTIVITY_COLLECTION: CollectionSpec = CollectionSpec {
-    header: &[
-        " Status",
-        "Activity ID",
-        "Type",
-        "Scheduled",
-        "Close Time",
-        "Task Queue",
+    columns: &[
+        column("status", " Status", Length(16)),
+        column("activity_id", "Activity ID", Percentage(28)),
+        column("run_id", "Run ID", Length(38)),
+        column("type", "Type", Percentage(20)),
+        column("schedule_time", "Scheduled", Length(20)),
+        column("close_time", "Close Time", Length(20)),
+        column("duration", "Duration", Length(12)),
+        column("task_queue", "Task Queue", Percentage(24)),
+    ],
+    default_columns: &[
+        "status",
+        "activity_id",
+        "type",
+        "schedule_time",
+        "close_time",
+        "task_queue",
+    ],
+    wide_columns: &[
+        "status",
+        "activity_id",
+        "run_id",
+        "type",
+        "schedule_time",
+        "close_time",
+        "duration",
+        "task_queue",
     ],
-    widths: activity_widths,
     rows: activity_rows,
     is_loading: activity_is_loading,
-    loading_label: " Loading activities...",
-    empty_label: " No activities loaded",
+    loading_label: Msg::LoadingActivities,
+    empty_label: Msg::NoActivities,
     table_state: activity_table_state,
 };
 
-fn workflow_rows(app: &crate::app::App) -> Option<Vec<ratatui::widgets::Row<'static>>> {
+const fn column(
+    id: &'static str,
+    header: &'static str,
+    width: ratatui::layout::Constraint,
+) -> ColumnSpec {
+    ColumnSpec { id, header, width }
+}
+
+/// Terminal width from which wide mode shows its extra columns.
+pub const WIDE_MIN_WIDTH: u16 = 160;
+
+/// Terminal width from which the split view shows its preview pane.
+pub const SPLIT_MIN_WIDTH: u16 = 140;
+
+/// The default columns of a collection.
+pub fn default_columns(kind: KindId) -> Vec<ColumnSpec> {
+    preset_columns(kind, collection_spec(kind).default_columns)
+}
+
+/// The default wide-mode columns of a collection.
+pub fn wide_columns(kind: KindId) -> Vec<ColumnSpec> {
+    preset_columns(kind, collection_spec(kind).wide_columns)
+}
+
+/// `kind`'s columns with these ids, skipping unknown ones.
+pub fn preset_columns(kind: KindId, ids: &[&str]) -> Vec<ColumnSpec> {
+    let spec = collection_spec(kind);
+    ids.iter()
+        .filter_map(|id| spec.columns.iter().find(|c| c.id == *id).copied())
+        .collect()
+}
+
+/// Prefix of workflow columns that show one search attribute, e.g. `sa.CustomerId`.
+pub const SEARCH_ATTRIBUTE_COLUMN_PREFIX: &str = "sa.";
+
+/// Parses a config column list such as `["status", "workflow_id:40%", "duration:10"]`.
+/// A width suffix is either a cell count or a percentage of the table.
+pub fn resolve_columns(kind: KindId, entries: &[String]) -> Result<Vec<ColumnSpec>, String> {
+    let spec = collection_spec(kind);
+    entries
+        .iter()
+        .map(|entry| {
+            let (id, width) = match entry.split_once(':') {
+                Some((id, width)) => (id.trim(), Some(width.trim())),
+                None => (entry.trim(), None),
+            };
+            let attribute = id
+                .strip_prefix(SEARCH_ATTRIBUTE_COLUMN_PREFIX)
+                .filter(|name| kind == KindId::WorkflowExecution && !name.is_empty());
+            let known = spec.columns.iter().find(|c| c.id == id).copied();
+            let mut column = match (known, attribute) {
+                (Some(column), _) => column,
+                (None, Some(name)) => column(intern(id), intern(name), Length(20)),
+                (None, None) => {
+                    let known: Vec<&str> = spec.columns.iter().map(|c| c.id).collect();
+                    return Err(format!(
+                        "unknown {} column '{}' (expected one of {})",
+                        kind.label().to_lowercase(),
+                        id,
+                        known.join(", ")
+                    ));
+                }
+            };
+            if let Some(width) = width {
+                column.width = parse_width(width)
+                    .ok_or_else(|| format!("invalid width '{}' for column '{}'", width, id))?;
+            }
+            Ok(column)
+        })
+        .collect()
+}
+
+/// Column ids and headers are `&'static str`; names from the config file are
+/// leaked once each so reloading the config doesn't leak them again.
+fn intern(s: &str) -> &'static str {
+    static INTERNED: std::sync::Mutex<Vec<&'static str>> = std::sync::Mutex::new(Vec::new());
+    let mut interned = INTERNED.lock().unwrap_or_else(|e| e.into_inner());
+    if let Some(existing) = interned.iter().find(|i| **i == s) {
+        return existing;
+    }
+    let leaked: &'static str = Box::leak(s.to_string().into_boxed_str());
+    interned.push(leaked);
+    leaked
+}
+
+fn parse_width(width: &str) -> Option<ratatui::layout::Constraint> {
+    match width.strip_suffix('%') {
+        Some(pct) => pct.parse().ok().filter(|p| *p <= 100).map(Percentage),
+        None => width.parse().ok().map(Length),
+    }
+}
+
+fn workflow_rows(
+    app: &crate::app::App,
+    columns: &[ColumnSpec],
+) -> Option<Vec<ratatui::widgets::Row<'static>>> {
     let workflows = app.workflows.data()?;
+    let query = app
+        .search_query_for_kind(KindId::WorkflowExecution)
+        .unwrap_or_default();
+    let highlight = WorkflowHighlight {
+        filter: app.local_filter(KindId::WorkflowExecution),
+        ids: crate::input::query_syntax::compared_values(&query, "WorkflowId"),
+        types: crate::input::query_syntax::compared_values(&query, "WorkflowType"),
+    };
     Some(
-        workflows
-            .iter()
+        app.visible_workflow_indices()
+            .into_iter()
+            .map(|i| &workflows[i])
             .map(|wf| {
-                let status_style = workflow_status_color(&wf.status);
-                ratatui::widgets::Row::new(vec![
-                    ratatui::widgets::Cell::from(format!(
-                        " {} {}",
-                        wf.status.symbol(),
-                        wf.status.as_str()
-                    ))
-                    .style(status_style),
-                    ratatui::widgets::Cell::from(wf.workflow_id.clone()),
-                    ratatui::widgets::Cell::from(wf.workflow_type.clone()),
-                    ratatui::widgets::Cell::from(format_time(&wf.start_time)),
-                    ratatui::widgets::Cell::from(wf.task_queue.clone()),
-                ])
+                let row = ratatui::widgets::Row::new(
+                    columns
+                        .iter()
+                        .map(|c| workflow_cell(&app.time_display, &highlight, wf, c.id))
+                        .collect::<Vec<_>>(),
+                );
+                let marked = app
+                    .marked_workflows
+                    .iter()
+                    .any(|(id, run)| *id == wf.workflow_id && *run == wf.run_id);
+                if marked {
+                    row.style(
+                        ratatui::style::Style::default()
+                            .fg(crate::theme::current().magenta)
+                            .add_modifier(ratatui::style::Modifier::BOLD),
+                    )
+                } else {
+                    row
+                }
             })
             .collect(),
     )
 }
 
-fn schedule_rows(app: &crate::app::App) -> Option<Vec<ratatui::widgets::Row<'static>>> {
+/// What narrowed the workflow list, so the Workflow ID and Type cells can
+/// show why each row matched.
+struct WorkflowHighlight<'a> {
+    /// The local fuzzy filter.
+    filter: Option<&'a str>,
+    /// Values the query compares `WorkflowId` with.
+    ids: Vec<String>,
+    /// Values the query compares `WorkflowType` with.
+    types: Vec<String>,
+}
+
+impl WorkflowHighlight<'_> {
+    /// `text` with the filter's and `values`' matches highlighted.
+    fn cell(&self, text: &str, values: &[String]) -> ratatui::widgets::Cell<'static> {
+        use ratatui::text::{Line, Span};
+
+        let mut ranges: Vec<std::ops::Range<usize>> = values
+            .iter()
+            .flat_map(|value| crate::input::substring_ranges(value, text))
+            .collect();
+        if let Some(filter) = self.filter {
+            ranges.extend(crate::input::fuzzy_ranges(filter, text));
+        }
+        if ranges.is_empty() {
+            return ratatui::widgets::Cell::from(text.to_string());
+        }
+        ranges.sort_by_key(|r| r.start);
+        let style = ratatui::style::Style::default()
+            .fg(crate::theme::current().yellow)
+            .add_modifier(ratatui::style::Modifier::BOLD);
+        let mut spans = Vec::new();
+        let mut at = 0;
+        for range in ranges {
+            if range.end <= at {
+                continue;
+            }
+            let start = range.start.max(at);
+            if start > at {
+                spans.push(Span::raw(text[at..start].to_string()));
+            }
+            spans.push(Span::styled(text[start..range.end].to_string(), style));
+            at = range.end;
+        }
+        spans.push(Span::raw(text[at..].to_string()));
+        ratatui::widgets::Cell::from(Line::from(spans))
+    }
+}
+
+fn workflow_cell(
+    times: &crate::domain::TimeDisplay,
+    highlight: &WorkflowHighlight,
+    wf: &crate::domain::WorkflowSummary,
+    column: &str,
+) -> ratatui::widgets::Cell<'static> {
+    match column {
+        "status" => {
+            ratatui::widgets::Cell::from(format!(" {} {}", wf.status.symbol(), wf.status.as_str()))
+                .style(workflow_status_color(&wf.status))
+        }
+        "workflow_id" => highlight.cell(&wf.workflow_id, &highlight.ids),
+        "run_id" => ratatui::widgets::Cell::from(wf.run_id.clone()),
+        "type" => highlight.cell(&wf.workflow_type, &highlight.types),
+        "start_time" => ratatui::widgets::Cell::from(times.format(&wf.start_time)),
+        "close_time" => ratatui::widgets::Cell::from(
+            wf.close_time
+                .as_ref()
+                .map(|t| times.format(t))
+                .unwrap_or_else(|| "-".to_string()),
+        ),
+        "duration" => {
+            ratatui::widgets::Cell::from(format_elapsed(Some(wf.start_time), wf.close_time))
+        }
+        "task_queue" => ratatui::widgets::Cell::from(wf.task_queue.clone()),
+        "namespace" => ratatui::widgets::Cell::from(wf.namespace.clone().unwrap_or_default()),
+        attribute if attribute.starts_with(SEARCH_ATTRIBUTE_COLUMN_PREFIX) => {
+            let name = &attribute[SEARCH_ATTRIBUTE_COLUMN_PREFIX.len()..];
+            ratatui::widgets::Cell::from(
+                wf.search_attributes
+                    .get(name)
+                    .map(|value| search_attribute_text(times, value))
+                    .unwrap_or_default(),
+            )
+        }
+        "search_attributes" => {
+            let mut attrs: Vec<(&String, &serde_json::Value)> =
+                wf.search_attributes.iter().collect();
+            attrs.sort_by(|a, b| a.0.cmp(b.0));
+            ratatui::widgets::Cell::from(
+                attrs
+                    .iter()
+                    .map(|(k, v)| match v {
+                        serde_json::Value::String(s) => format!("{}={}", k, s),
+                        other => format!("{}={}", k, other),
+                    })
+                    .collect::<Vec<_>>()
+                    .join(" "),
+            )
+        }
+        _ => ratatui::widgets::Cell::from(""),
+    }
+}
+
+/// A search attribute value as a cell: datetimes in the display zone and
+/// keyword lists comma-separated.
+fn search_attribute_text(times: &crate::domain::TimeDisplay, value: &serde_json::Value) -> String {
+    match value {
+        serde_json::Value::String(s) => chrono::DateTime::parse_from_rfc3339(s)
+            .map(|t| times.format(&t.with_timezone(&chrono::Utc)))
+            .unwrap_or_else(|_| s.clone()),
+        serde_json::Value::Array(items) => items
+            .iter()
+            .map(|item| search_attribute_text(times, item))
+            .collect::<Vec<_>>()
+            .join(", "),
+        serde_json::Value::Null => String::new(),
+        other => other.to_string(),
+    }
+}
+
+fn schedule_rows(
+    app: &crate::app::App,
+    columns: &[ColumnSpec],
+) -> Option<Vec<ratatui::widgets::Row<'static>>> {
     let schedules = app.schedules.data()?;
     Some(
-        schedules
-            .iter()
-            .map(|sch| {
-                let state_style = match sch.state {
-                    crate::domain::ScheduleState::Active => {
-                        ratatui::style::Style::default().fg(crate::theme::GREEN)
-                    }
-                    crate::domain::ScheduleState::Paused => {
-                        ratatui::style::Style::default().fg(crate::theme::YELLOW)
-                    }
-                };
-                ratatui::widgets::Row::new(vec![
-                    ratatui::widgets::Cell::from(format!(" {}", sch.state.as_str()))
-                        .style(state_style),
-                    ratatui::widgets::Cell::from(sch.schedule_id.clone()),
-                    ratatui::widgets::Cell::from(sch.workflow_type.clone()),
-                    ratatui::widgets::Cell::from(
-                        sch.next_run
-                            .map(|t| {
-                                let local = t.with_timezone(&chrono::Local);
-                                local.format("%Y-%m-%d %H:%M:%S").to_string()
-                            })
-                            .unwrap_or_else(|| "-".to_string()),
-                    ),
-                    ratatui::widgets::Cell::from(sch.recent_action_count.to_string()),
-                ])
+        app.schedule_list_rows()
+            .into_iter()
+            .filter_map(|row| match row {
+                crate::app::ScheduleListRow::Group {
+                    key,
+                    count,
+                    collapsed,
+                } => {
+                    let marker = if collapsed { "▸" } else { "▾" };
+                    Some(
+                        ratatui::widgets::Row::new(vec![
+                            ratatui::widgets::Cell::from(format!(" {} ({})", marker, count)),
+                            ratatui::widgets::Cell::from(key),
+                        ])
+                        .style(
+                            ratatui::style::Style::default()
+                                .fg(crate::theme::current().accent)
+                                .add_modifier(ratatui::style::Modifier::BOLD),
+                        ),
+                    )
+                }
+                crate::app::ScheduleListRow::Schedule(idx) => schedules.get(idx).map(|sch| {
+                    ratatui::widgets::Row::new(
+                        columns
+                            .iter()
+                            .map(|c| schedule_cell(&app.time_display, sch, c.id))
+                            .collect::<Vec<_>>(),
+                    )
+                }),
             })
             .collect(),
     )
 }
 
-fn activity_rows(app: &crate::app::App) -> Option<Vec<ratatui::widgets::Row<'static>>> {
+fn schedule_cell(
+    times: &crate::domain::TimeDisplay,
+    sch: &crate::domain::Schedule,
+    column: &str,
+) -> ratatui::widgets::Cell<'static> {
+    match column {
+        "state" => {
+            let state_style = match sch.state {
+                crate::domain::ScheduleState::Active => {
+                    ratatui::style::Style::default().fg(crate::theme::current().green)
+                }
+                crate::domain::ScheduleState::Paused => {
+                    ratatui::style::Style::default().fg(crate::theme::current().yellow)
+                }
+            };
+            ratatui::widgets::Cell::from(format!(" {}", sch.state.as_str())).style(state_style)
+        }
+        "schedule_id" => ratatui::widgets::Cell::from(sch.schedule_id.clone()),
+        "workflow_type" => ratatui::widgets::Cell::from(sch.workflow_type.clone()),
+        "spec" => ratatui::widgets::Cell::from(sch.spec_description.clone()),
+        "next_run" => ratatui::widgets::Cell::from(
+            sch.next_run
+                .as_ref()
+                .map(|t| {
+                    if times.relative {
+                        times.format(t)
+                    } else {
+                        sch.format_in_zone(t)
+                    }
+                })
+                .unwrap_or_else(|| "-".to_string()),
+        ),
+        "actions" => ratatui::widgets::Cell::from(sch.recent_action_count.to_string()),
+        "notes" => {
+            let cell = ratatui::widgets::Cell::from(
+                sch.notes.lines().next().unwrap_or_default().to_string(),
+            );
+            if sch.state == crate::domain::ScheduleState::Paused {
+                cell.style(ratatui::style::Style::default().fg(crate::theme::current().yellow))
+            } else {
+                cell
+            }
+        }
+        _ => ratatui::widgets::Cell::from(""),
+    }
+}
+
+fn activity_rows(
+    app: &crate::app::App,
+    columns: &[ColumnSpec],
+) -> Option<Vec<ratatui::widgets::Row<'static>>> {
     let activities = app.activity_executions.data()?;
     Some(
         activities
             .iter()
             .map(|act| {
-                let status_style = activity_status_color(&act.status);
-                ratatui::widgets::Row::new(vec![
-                    ratatui::widgets::Cell::from(format!(
-                        " {} {}",
-                        act.status.symbol(),
-                        act.status.as_str()
-                    ))
-                    .style(status_style),
-                    ratatui::widgets::Cell::from(act.activity_id.clone()),
-                    ratatui::widgets::Cell::from(act.activity_type.clone()),
-                    ratatui::widgets::Cell::from(
-                        act.schedule_time
-                            .as_ref()
-                            .map(format_time)
-                            .unwrap_or_else(|| "-".to_string()),
-                    ),
-                    ratatui::widgets::Cell::from(
-                        act.close_time
-                            .as_ref()
-                            .map(format_time)
-                            .unwrap_or_else(|| "-".to_string()),
-                    ),
-                    ratatui::widgets::Cell::from(act.task_queue.clone()),
-                ])
+                ratatui::widgets::Row::new(
+                    columns
+                        .iter()
+                        .map(|c| activity_cell(&app.time_display, act, c.id))
+                        .collect::<Vec<_>>(),
+                )
             })
             .collect(),
     )
 }
 
+fn activity_cell(
+    times: &crate::domain::TimeDisplay,
+    act: &crate::domain::ActivityExecutionSummary,
+    column: &str,
+) -> ratatui::widgets::Cell<'static> {
+    match column {
+        "status" => ratatui::widgets::Cell::from(format!(
+            " {} {}",
+            act.status.symbol(),
+            act.status.as_str()
+        ))
+        .style(activity_status_color(&act.status)),
+        "activity_id" => ratatui::widgets::Cell::from(act.activity_id.clone()),
+        "run_id" => ratatui::widgets::Cell::from(act.run_id.clone()),
+        "type" => ratatui::widgets::Cell::from(act.activity_type.clone()),
+        "schedule_time" => ratatui::widgets::Cell::from(
+            act.schedule_time
+                .as_ref()
+                .map(|t| times.format(t))
+                .unwrap_or_else(|| "-".to_string()),
+        ),
+        "close_time" => ratatui::widgets::Cell::from(
+            act.close_time
+                .as_ref()
+                .map(|t| times.format(t))
+                .unwrap_or_else(|| "-".to_string()),
+        ),
+        "duration" => {
+            ratatui::widgets::Cell::from(format_elapsed(act.schedule_time, act.close_time))
+        }
+        "task_queue" => ratatui::widgets::Cell::from(act.task_queue.clone()),
+        _ => ratatui::widgets::Cell::from(""),
+    }
+}
+
 fn workflow_is_loading(app: &crate::app::App) -> bool {
     app.workflows.is_loading()
 }
@@ -426,59 +956,28 @@ fn activity_table_state(app: &mut crate::app::App) -> &mut ratatui::widgets::Tab
     &mut app.activity_execution_table_state
 }
 
-fn workflow_widths() -> Vec<ratatui::layout::Constraint> {
-    vec![
-        ratatui::layout::Constraint::Length(18),
-        ratatui::layout::Constraint::Percentage(30),
-        ratatui::layout::Constraint::Percentage(20),
-        ratatui::layout::Constraint::Length(20),
-        ratatui::layout::Constraint::Percentage(20),
-    ]
-}
-
-fn schedule_widths() -> Vec<ratatui::layout::Constraint> {
-    vec![
-        ratatui::layout::Constraint::Length(12),
-        ratatui::layout::Constraint::Percentage(30),
-        ratatui::layout::Constraint::Percentage(25),
-        ratatui::layout::Constraint::Length(20),
-        ratatui::layout::Constraint::Length(10),
-    ]
-}
-
-fn activity_widths() -> Vec<ratatui::layout::Constraint> {
-    vec![
-        ratatui::layout::Constraint::Length(16),
-        ratatui::layout::Constraint::Percentage(28),
-        ratatui::layout::Constraint::Percentage(20),
-        ratatui::layout::Constraint::Length(20),
-        ratatui::layout::Constraint::Length(20),
-        ratatui::layout::Constraint::Percentage(24),
-    ]
-}
-
-fn workflow_status_color(status: &crate::domain::WorkflowStatus) -> ratatui::style::Style {
+pub fn workflow_status_color(status: &crate::domain::WorkflowStatus) -> ratatui::style::Style {
     match status {
         crate::domain::WorkflowStatus::Running => {
-            ratatui::style::Style::default().fg(crate::theme::GREEN)
+            ratatui::style::Style::default().fg(crate::theme::current().green)
         }
         crate::domain::WorkflowStatus::Completed => {
-            ratatui::style::Style::default().fg(crate::theme::BLUE)
+            ratatui::style::Style::default().fg(crate::theme::current().blue)
         }
         crate::domain::WorkflowStatus::Failed => {
-            ratatui::style::Style::default().fg(crate::theme::RED)
+            ratatui::style::Style::default().fg(crate::theme::current().red)
         }
         crate::domain::WorkflowStatus::Canceled => {
-            ratatui::style::Style::default().fg(crate::theme::YELLOW)
+            ratatui::style::Style::default().fg(crate::theme::current().yellow)
         }
         crate::domain::WorkflowStatus::Terminated => {
-            ratatui::style::Style::default().fg(crate::theme::MAGENTA)
+            ratatui::style::Style::default().fg(crate::theme::current().magenta)
         }
         crate::domain::WorkflowStatus::TimedOut => {
-            ratatui::style::Style::default().fg(crate::theme::RED)
+            ratatui::style::Style::default().fg(crate::theme::current().red)
         }
         crate::domain::WorkflowStatus::ContinuedAsNew => {
-            ratatui::style::Style::default().fg(crate::theme::CYAN)
+            ratatui::style::Style::default().fg(crate::theme::current().cyan)
         }
     }
 }
@@ -486,29 +985,43 @@ fn workflow_status_color(status: &crate::domain::WorkflowStatus) -> ratatui::sty
 fn activity_status_color(status: &crate::domain::ActivityExecutionStatus) -> ratatui::style::Style {
     match status {
         crate::domain::ActivityExecutionStatus::Running => {
-            ratatui::style::Style::default().fg(crate::theme::GREEN)
+            ratatui::style::Style::default().fg(crate::theme::current().green)
         }
         crate::domain::ActivityExecutionStatus::Completed => {
-            ratatui::style::Style::default().fg(crate::theme::BLUE)
+            ratatui::style::Style::default().fg(crate::theme::current().blue)
         }
         crate::domain::ActivityExecutionStatus::Failed => {
-            ratatui::style::Style::default().fg(crate::theme::RED)
+            ratatui::style::Style::default().fg(crate::theme::current().red)
         }
         crate::domain::ActivityExecutionStatus::Canceled => {
-            ratatui::style::Style::default().fg(crate::theme::YELLOW)
+            ratatui::style::Style::default().fg(crate::theme::current().yellow)
         }
         crate::domain::ActivityExecutionStatus::Terminated => {
-            ratatui::style::Style::default().fg(crate::theme::MAGENTA)
+            ratatui::style::Style::default().fg(crate::theme::current().magenta)
         }
         crate::domain::ActivityExecutionStatus::TimedOut => {
-            ratatui::style::Style::default().fg(crate::theme::RED)
+            ratatui::style::Style::default().fg(crate::theme::current().red)
         }
     }
 }
 
-fn format_time(dt: &chrono::DateTime<chrono::Utc>) -> String {
-    let local = dt.with_timezone(&chrono::Local);
-    local.format("%Y-%m-%d %H:%M:%S").to_string()
+/// Time from `start` to `end`, or to now while still running.
+fn format_elapsed(
+    start: Option<chrono::DateTime<chrono::Utc>>,
+    end: Option<chrono::DateTime<chrono::Utc>>,
+) -> String {
+    let Some(start) = start else {
+        return "-".to_string();
+    };
+    let secs = (end.unwrap_or_else(chrono::Utc::now) - start)
+        .num_seconds()
+        .max(0);
+    match secs {
+        s if s < 60 => format!("{}s", s),
+        s if s < 3600 => format!("{}m{}s", s / 60, s % 60),
+        s if s < 86400 => format!("{}h{}m", s / 3600, (s % 3600) / 60),
+        s => format!("{}d{}h", s / 86400, (s % 86400) / 3600),
+    }
 }
 
 fn workflow_cancel_effects(
@@ -527,6 +1040,22 @@ fn workflow_cancel_effects(
     }
 }
 
+fn workflow_restart_effects(
+    target: &crate::app::OperationTarget,
+    _app: &crate::app::App,
+) -> Vec<crate::app::Effect> {
+    match target {
+        crate::app::OperationTarget::Workflow {
+            workflow_id,
+            run_id,
+        } => vec![crate::app::Effect::TerminateAndRestartWorkflow(
+            workflow_id.clone(),
+            run_id.clone(),
+        )],
+        _ => vec![],
+    }
+}
+
 fn workflow_terminate_effects(
     target: &crate::app::OperationTarget,
     _app: &crate::app::App,
@@ -584,9 +1113,22 @@ fn schedule_pause_effects(
     vec![crate::app::Effect::PauseSchedule(
         schedule_id.clone(),
         pause,
+        String::new(),
     )]
 }
 
+fn schedule_edit_effects(
+    target: &crate::app::OperationTarget,
+    _app: &crate::app::App,
+) -> Vec<crate::app::Effect> {
+    match target {
+        crate::app::OperationTarget::Schedule { schedule_id } => {
+            vec![crate::app::Effect::LoadScheduleDraft(schedule_id.clone())]
+        }
+        _ => vec![],
+    }
+}
+
 fn activity_cancel_effects(
     target: &crate::app::OperationTarget,
     _app: &crate::app::App,
@@ -1,14 +1,27 @@
 pub mod action;
 pub mod app;
+pub mod audit;
+pub mod bookmarks;
 pub mod client;
 pub mod config;
+#[cfg(feature = "dev")]
+pub mod dev;
+pub mod doctor;
 pub mod domain;
+pub mod editor;
 pub mod event;
+pub mod exec;
+pub mod export;
+pub mod i18n;
 pub mod input;
 pub mod kinds;
+pub mod mask;
 pub mod nav;
+pub mod pager;
+pub mod pipe;
 #[doc(hidden)]
 pub mod proto;
+pub mod shell;
 pub mod theme;
 pub mod tui;
 pub mod widgets;
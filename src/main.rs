@@ -1,27 +1,40 @@
+use std::collections::HashMap;
 use std::sync::Arc;
 use std::time::Duration;
 
-use clap::Parser;
-use color_eyre::eyre::Result;
+use clap::CommandFactory;
+use color_eyre::eyre::{eyre, Result};
 use ratatui::layout::{Constraint, Layout};
+use ratatui::style::Style;
+use ratatui::widgets::{Block, Borders};
 use tokio::sync::mpsc;
 
 use t9s::action::Action;
-use t9s::app::{App, ConfirmAction, Effect, InputMode, Overlay, View};
-use t9s::client::GrpcTemporalClient;
-use t9s::config::Cli;
+use t9s::app::{App, ConfirmAction, Effect, FileWrite, InputMode, Overlay, View};
+use t9s::client::{
+    oidc, Auth, ClientError, ClientResult, CodecClient, DeviceLogin, GrpcTemporalClient,
+    HttpTemporalClient, OidcConfig, RequestPolicy, TemporalClient,
+};
+use t9s::config::{Cli, Command, ConfigFile, ExecArgs, FileWatcher, Transport};
 use t9s::event::{key_to_action, AppEvent, RawEventHandler};
+use t9s::exec::ExecError;
+use t9s::i18n::{tf, Msg};
 use t9s::kinds::KindId;
 use t9s::kinds::{detail_spec, operation_effect_spec};
-use t9s::widgets;
 use t9s::worker::{CliRequest, CliWorker};
+use t9s::{theme, widgets};
 
 #[tokio::main]
 async fn main() -> Result<()> {
     color_eyre::install()?;
     dotenvy::dotenv().ok();
 
-    let cli = Cli::parse();
+    // A config that doesn't parse stops startup rather than being ignored.
+    let config = ConfigFile::path()
+        .map(|path| ConfigFile::read(&path))
+        .transpose()
+        .map_err(|e| eyre!("{}", e))?;
+    let cli = Cli::parse_with_config(config.as_ref()).map_err(|e| eyre!("{}", e))?;
 
     // Set up logging
     if let Some(ref log_file) = cli.log_file {
@@ -32,98 +45,478 @@ async fn main() -> Result<()> {
             .init();
     }
 
-    run_tui(cli).await
+    match cli.command {
+        Some(Command::Doctor) => {
+            let ok = t9s::doctor::run(&cli).await;
+            std::process::exit(if ok { 0 } else { 1 });
+        }
+        Some(Command::Completions { shell }) => {
+            clap_complete::generate(shell, &mut Cli::command(), "t9s", &mut std::io::stdout());
+            Ok(())
+        }
+        Some(Command::Man) => {
+            clap_mangen::Man::new(Cli::command()).render(&mut std::io::stdout())?;
+            Ok(())
+        }
+        Some(Command::Exec(ref args)) => {
+            std::process::exit(run_exec(&cli, args).await);
+        }
+        #[cfg(feature = "dev")]
+        Some(Command::Dev {
+            ref temporal_bin,
+            no_seed,
+        }) => {
+            let temporal_bin = temporal_bin.clone();
+            run_dev(cli, temporal_bin, no_seed).await
+        }
+        None => run_tui(cli).await,
+    }
 }
 
-async fn run_tui(cli: Cli) -> Result<()> {
-    // Connect to Temporal
-    let client = GrpcTemporalClient::connect(
-        &cli.address,
-        cli.namespace.clone(),
-        cli.api_key.clone(),
-        cli.tls_cert.clone(),
-        cli.tls_key.clone(),
-    )
-    .await;
+#[cfg(feature = "dev")]
+async fn run_dev(
+    mut cli: Cli,
+    temporal_bin: Option<std::path::PathBuf>,
+    no_seed: bool,
+) -> Result<()> {
+    let bin = t9s::dev::ensure_cli(temporal_bin).await?;
+    let server = t9s::dev::start_server(&bin, &cli.address).await?;
+    if !no_seed {
+        let report = t9s::dev::seed_server(&server.address, &cli.namespace).await?;
+        println!(
+            "Seeded {} workflows ({} terminated) and {} schedules",
+            report.workflows, report.terminated, report.schedules
+        );
+    }
+    if server.is_owned() {
+        println!("Server log: {}", server.log_path.display());
+    }
+    cli.address = server.address.clone();
+    let result = run_tui(cli).await;
+    drop(server);
+    result
+}
 
-    let client: Arc<dyn t9s::client::TemporalClient> = match client {
-        Ok(c) => Arc::new(c),
-        Err(e) => {
-            eprintln!("Failed to connect to Temporal at {}: {}", cli.address, e);
-            eprintln!();
-            eprintln!("Make sure Temporal is running and accessible.");
-            eprintln!("  TEMPORAL_ADDRESS={}", cli.address);
-            eprintln!("  TEMPORAL_NAMESPACE={}", cli.namespace);
-            if cli.api_key.is_some() {
-                eprintln!("  TEMPORAL_API_KEY=<set>");
+/// Connects for `cli`, giving up after `--request-timeout` so an address
+/// that never answers doesn't hang the caller.
+async fn connect(cli: &Cli) -> ClientResult<Arc<dyn TemporalClient>> {
+    let timeout = Duration::from_secs(cli.request_timeout.max(1));
+    tokio::time::timeout(timeout, connect_transport(cli))
+        .await
+        .unwrap_or(Err(ClientError::Timeout))
+}
+
+/// Opens the client for `--transport`. `auto` tries gRPC first and falls back
+/// to the HTTP API, reporting the gRPC error if neither works.
+async fn connect_transport(cli: &Cli) -> ClientResult<Arc<dyn TemporalClient>> {
+    let codec = || {
+        cli.codec_endpoint.as_ref().map(|endpoint| {
+            CodecClient::new(
+                endpoint,
+                cli.codec_auth.clone(),
+                Duration::from_secs(cli.request_timeout.max(1)),
+            )
+        })
+    };
+    let grpc = || async {
+        let auth = match cli.oidc_config() {
+            Some(config) => Some(Auth::Oidc(oidc::session(&config).await?)),
+            None => cli.api_key.clone().map(Auth::ApiKey),
+        };
+        let client = GrpcTemporalClient::connect(
+            &cli.address,
+            cli.namespace.clone(),
+            auth,
+            cli.tls_options(),
+        )
+        .await?
+        .with_request_policy(RequestPolicy {
+            timeout: Duration::from_secs(cli.request_timeout.max(1)),
+            read_retries: cli.read_retries,
+            ..RequestPolicy::default()
+        });
+        let client = match codec() {
+            Some(codec) => client.with_codec(codec),
+            None => client,
+        };
+        Ok::<Arc<dyn TemporalClient>, _>(Arc::new(client))
+    };
+    let http = || async {
+        if cli.oidc_config().is_some() {
+            return Err(ClientError::ConfigError(
+                "OIDC login needs the gRPC transport".into(),
+            ));
+        }
+        let client = HttpTemporalClient::connect(
+            &cli.address,
+            cli.namespace.clone(),
+            cli.api_key.clone(),
+            cli.tls_cert.clone(),
+            cli.tls_key.clone(),
+        )
+        .await?;
+        let client = match codec() {
+            Some(codec) => client.with_codec(codec),
+            None => client,
+        };
+        Ok::<Arc<dyn TemporalClient>, _>(Arc::new(client))
+    };
+
+    match cli.transport {
+        Transport::Grpc => grpc().await,
+        Transport::Http => http().await,
+        Transport::Auto => match grpc().await {
+            Ok(client) => Ok(client),
+            Err(grpc_err) => {
+                tracing::warn!("gRPC connect failed, trying HTTP API: {}", grpc_err);
+                http().await.map_err(|_| grpc_err)
+            }
+        },
+    }
+}
+
+/// Connects for `cli`, showing the connection screen while connecting and
+/// after a failure so the address, namespace or profile can be changed and
+/// retried. Returns `None` when the user quits from the screen.
+async fn connect_interactive(
+    cli: &mut Cli,
+    terminal: &mut t9s::tui::Tui,
+    events: &mut RawEventHandler,
+    color_mode: theme::ColorMode,
+) -> Result<Option<Arc<dyn TemporalClient>>> {
+    use crossterm::event::{KeyCode, KeyModifiers};
+    use widgets::connection_error::{ConnectionField, ConnectionScreen};
+
+    let profiles = ConfigFile::load()
+        .map(|config| config.profiles)
+        .unwrap_or_default();
+    let mut screen = ConnectionScreen {
+        profile: cli.profile.clone(),
+        profiles: profiles.iter().map(|p| p.name.clone()).collect(),
+        ..Default::default()
+    };
+
+    loop {
+        screen.address = cli.address.clone();
+        screen.namespace = cli.namespace.clone();
+        screen.error = None;
+        terminal.draw(|frame| {
+            widgets::connection_error::render(&screen, frame, frame.area());
+            theme::adapt_buffer(frame.buffer_mut(), color_mode);
+        })?;
+
+        let device = cli
+            .oidc_config()
+            .filter(|config| config.is_device_flow() && oidc::cached_session(config).is_none());
+        let login = match device {
+            Some(config) => {
+                device_login(&config, &mut screen, terminal, events, color_mode).await?
+            }
+            None => Some(Ok(())),
+        };
+        let result = match login {
+            None => return Ok(None),
+            Some(Ok(())) => connect(cli).await,
+            Some(Err(e)) => Err(e),
+        };
+
+        match result {
+            Ok(client) => return Ok(Some(client)),
+            Err(e) => {
+                tracing::warn!("connect to {} failed: {}", cli.address, e);
+                screen.error = Some(tf(Msg::ConnectionFailed, &[&e.to_string()]));
             }
-            std::process::exit(1);
         }
+
+        // Wait for the user to pick what to try next.
+        loop {
+            terminal.draw(|frame| {
+                widgets::connection_error::render(&screen, frame, frame.area());
+                theme::adapt_buffer(frame.buffer_mut(), color_mode);
+            })?;
+            let Some(AppEvent::Key(key)) = events.next().await else {
+                continue;
+            };
+            if key.modifiers.contains(KeyModifiers::CONTROL) && key.code == KeyCode::Char('c') {
+                return Ok(None);
+            }
+            if let Some((field, buffer)) = &mut screen.editing {
+                match key.code {
+                    KeyCode::Esc => screen.editing = None,
+                    KeyCode::Backspace => {
+                        buffer.pop();
+                    }
+                    KeyCode::Char(c) => buffer.push(c),
+                    KeyCode::Enter => {
+                        let value = buffer.trim().to_string();
+                        if !value.is_empty() {
+                            match field {
+                                ConnectionField::Address => cli.address = value,
+                                ConnectionField::Namespace => cli.namespace = value,
+                            }
+                        }
+                        screen.editing = None;
+                        break;
+                    }
+                    _ => {}
+                }
+                continue;
+            }
+            match key.code {
+                KeyCode::Char('q') | KeyCode::Esc => return Ok(None),
+                KeyCode::Char('r') | KeyCode::Enter => break,
+                KeyCode::Char('a') => {
+                    screen.editing = Some((ConnectionField::Address, cli.address.clone()))
+                }
+                KeyCode::Char('n') => {
+                    screen.editing = Some((ConnectionField::Namespace, cli.namespace.clone()))
+                }
+                KeyCode::Char('p') => {
+                    let Some(name) = screen.next_profile().map(str::to_string) else {
+                        continue;
+                    };
+                    if let Some(profile) = profiles.iter().find(|p| p.name == name) {
+                        profile.switch_to(cli);
+                    }
+                    cli.profile = Some(name.clone());
+                    screen.profile = Some(name);
+                    break;
+                }
+                _ => {}
+            }
+        }
+    }
+}
+
+/// Runs the OIDC device flow, showing the user code on the connection screen
+/// until the login is approved. `None` means the user quit.
+async fn device_login(
+    config: &OidcConfig,
+    screen: &mut widgets::connection_error::ConnectionScreen,
+    terminal: &mut t9s::tui::Tui,
+    events: &mut RawEventHandler,
+    color_mode: theme::ColorMode,
+) -> Result<Option<ClientResult<()>>> {
+    use crossterm::event::{KeyCode, KeyModifiers};
+
+    let login = match DeviceLogin::start(config).await {
+        Ok(login) => login,
+        Err(e) => return Ok(Some(Err(e))),
+    };
+    screen.device_code = Some((
+        login
+            .verification_uri_complete
+            .clone()
+            .unwrap_or_else(|| login.verification_uri.clone()),
+        login.user_code.clone(),
+    ));
+    let finish = login.finish();
+    tokio::pin!(finish);
+    let result = loop {
+        terminal.draw(|frame| {
+            widgets::connection_error::render(screen, frame, frame.area());
+            theme::adapt_buffer(frame.buffer_mut(), color_mode);
+        })?;
+        tokio::select! {
+            result = &mut finish => break Some(result.map(|_| ())),
+            Some(AppEvent::Key(key)) = events.next() => {
+                let ctrl_c = key.modifiers.contains(KeyModifiers::CONTROL)
+                    && key.code == KeyCode::Char('c');
+                if ctrl_c || matches!(key.code, KeyCode::Char('q') | KeyCode::Esc) {
+                    break None;
+                }
+            }
+        }
+    };
+    screen.device_code = None;
+    Ok(result)
+}
+
+/// Connects with profile `name` applied over `cli`, returning the settings
+/// the new context runs with.
+async fn connect_profile(cli: &Cli, name: &str) -> ClientResult<(Cli, Arc<dyn TemporalClient>)> {
+    let profile = ConfigFile::load()
+        .and_then(|config| config.profile(name).cloned())
+        .ok_or_else(|| ClientError::ConfigError(format!("profile {} not found", name)))?;
+    let mut next = cli.clone();
+    profile.switch_to(&mut next);
+    let client = connect(&next).await?;
+    Ok((next, client))
+}
+
+async fn run_tui(mut cli: Cli) -> Result<()> {
+    // A startup deep link picks the namespace to connect to
+    let start = cli
+        .startup_uri()
+        .map(t9s::nav::parse_deep_link)
+        .transpose()
+        .map_err(|e| eyre!("invalid uri: {}", e))?;
+    if let Some(ref location) = start {
+        cli.namespace = location.namespace.clone();
+    }
+
+    let config = ConfigFile::load();
+    // Loaded before connecting so the connection screen is translated too
+    let locale_error = config
+        .as_ref()
+        .and_then(|c| c.locale.as_deref())
+        .map(t9s::i18n::load)
+        .and_then(Result::err);
+    let theme_error = cli
+        .theme
+        .clone()
+        .or_else(|| config.as_ref().and_then(|c| c.theme.clone()))
+        .and_then(|spec| match theme::Theme::load(&spec) {
+            Ok(theme) => {
+                theme::set(theme);
+                None
+            }
+            // Shown once the app is up; the skin is watched, so creating or
+            // fixing it applies it.
+            Err(e) => Some(e),
+        });
+
+    // Set up terminal
+    let color_mode = cli.color.resolve();
+    let mut terminal = t9s::tui::init(!cli.no_mouse)?;
+    let _restore = t9s::tui::RestoreGuard;
+
+    // Set up event handler
+    let mut events = RawEventHandler::new(Duration::from_secs(1));
+
+    // Connect to Temporal, staying on the connection screen until it works
+    let Some(client) =
+        connect_interactive(&mut cli, &mut terminal, &mut events, color_mode).await?
+    else {
+        return Ok(());
     };
 
     // Initialize app state
     let mut app = App::new(cli.namespace.clone());
-    app.polling_interval = Duration::from_secs(cli.poll_interval);
     app.base_polling_interval = Duration::from_secs(cli.poll_interval);
+    app.page_size = cli.page_size;
     app.connection_status = t9s::app::ConnectionStatus::Connected;
+    app.capabilities = client.capabilities();
+    app.active_profile = cli.profile.clone();
+    app.screen_width = terminal.size()?.width;
+    if let Some(e) = locale_error {
+        app.update(Action::Error(e));
+    }
+    if let Some(e) = theme_error {
+        app.update(Action::Error(e));
+    }
+    let mut config_watcher = config_watcher(&cli, config.as_ref());
+    if let Some(config) = config {
+        app.apply_config(&config);
+    }
+    match t9s::bookmarks::load() {
+        Ok(bookmarks) => app.bookmarks = bookmarks,
+        Err(e) => {
+            app.update(Action::Error(format!("loading bookmarks: {}", e)));
+        }
+    }
 
     // Set up channels
     let (action_tx, mut action_rx) = mpsc::unbounded_channel::<Action>();
+    // Effects the loop carries out itself: the terminal, files and contexts
+    let (local_tx, mut local_rx) = mpsc::unbounded_channel::<Effect>();
 
     // Create worker
-    let (worker, cli_handle) = CliWorker::new(client, action_tx.clone());
+    let capabilities = client.capabilities();
+    let (worker, cli_handle) = CliWorker::new(app.context_id(), client, action_tx.clone());
     tokio::spawn(worker.run());
+    cli_handle.set_audit_file(app.audit_file.clone());
+
+    // Settings of every connected profile, so switching back skips connecting
+    let mut contexts = HashMap::new();
+    if let Some(name) = &app.active_profile {
+        contexts.insert(name.clone(), (cli.clone(), capabilities));
+    }
+    let mut active_profile = app.active_profile.clone();
 
     // Initial data load
     cli_handle.send(CliRequest::LoadNamespaces);
-    cli_handle.send(CliRequest::LoadWorkflows {
-        namespace: cli.namespace.clone(),
-        query: None,
-        page_size: app.page_size,
-        next_page_token: vec![],
-    });
-    cli_handle.send(CliRequest::LoadWorkflowCount {
-        namespace: cli.namespace.clone(),
-        query: None,
-    });
+    cli_handle.send(CliRequest::LoadClusterInfo);
     cli_handle.send(CliRequest::CheckActivitySupport {
         namespace: cli.namespace.clone(),
     });
+    match start {
+        Some(location) => {
+            let effects = app.update(Action::OpenLocation(location));
+            handle_effects(effects, &cli_handle, &app, &local_tx);
+        }
+        None => {
+            cli_handle.send(CliRequest::LoadWorkflows {
+                namespace: cli.namespace.clone(),
+                query: None,
+                page_size: app.page_size,
+                next_page_token: vec![],
+                archived: false,
+                fanout: vec![],
+            });
+            cli_handle.send(CliRequest::LoadWorkflowCount {
+                namespace: cli.namespace.clone(),
+                query: None,
+            });
+        }
+    }
 
-    // Set up terminal
-    let mut terminal = t9s::tui::init()?;
-
-    // Set up event handler
-    let mut events = RawEventHandler::new(Duration::from_secs(1));
+    app.mouse_capture = !cli.no_mouse;
 
     // Main loop
     loop {
         // Render
-        terminal.draw(|frame| render(&mut app, frame))?;
+        terminal.draw(|frame| {
+            render(&mut app, frame);
+            theme::adapt_buffer(frame.buffer_mut(), color_mode);
+        })?;
 
         // Handle events
         tokio::select! {
             Some(event) = events.next() => {
                 match event {
                     AppEvent::Key(key) => {
+                        if app.connection_status == t9s::app::ConnectionStatus::Disconnected {
+                            let effects = app.update(Action::Reconnect);
+                            handle_effects(effects, &cli_handle, &app, &local_tx);
+                        }
                         // Special handling for confirm modal
                         if let Overlay::Confirm(ref confirm_action) = app.overlay {
+                            // A typed confirmation takes text; only Enter accepts it.
+                            if app.confirm_phrase.is_some() {
+                                match key.code {
+                                    crossterm::event::KeyCode::Char(c) => {
+                                        app.confirm_input.push(c);
+                                        continue;
+                                    }
+                                    crossterm::event::KeyCode::Backspace => {
+                                        app.confirm_input.pop();
+                                        continue;
+                                    }
+                                    crossterm::event::KeyCode::Enter if app.confirm_ready() => {}
+                                    crossterm::event::KeyCode::Esc => {
+                                        app.close_overlay();
+                                        continue;
+                                    }
+                                    _ => continue,
+                                }
+                            }
                             match key.code {
                                 crossterm::event::KeyCode::Char('y') | crossterm::event::KeyCode::Enter => {
-                                    let effects = match confirm_action.clone() {
-                                        ConfirmAction::Operation(confirm) => operation_effect_spec(
-                                            confirm.op,
-                                            confirm.kind,
-                                        )
-                                        .map(|spec| (spec.to_effects)(&confirm.target, &app))
-                                        .unwrap_or_default(),
-                                    };
-                                    app.overlay = Overlay::None;
-                                    handle_effects(effects, &cli_handle, &app);
+                                    if let Some(next) = confirm_action.next_step() {
+                                        app.open_confirm(next, None);
+                                        continue;
+                                    }
+                                    let effects = confirm_effects(confirm_action.clone(), &app);
+                                    if matches!(confirm_action, ConfirmAction::Batch(_)) {
+                                        app.marked_workflows.clear();
+                                    }
+                                    app.close_overlay();
+                                    handle_effects(effects, &cli_handle, &app, &local_tx);
                                     continue;
                                 }
                                 crossterm::event::KeyCode::Char('n') | crossterm::event::KeyCode::Esc => {
-                                    app.overlay = Overlay::None;
+                                    app.close_overlay();
                                     continue;
                                 }
                                 _ => continue,
@@ -132,33 +525,58 @@ async fn run_tui(cli: Cli) -> Result<()> {
 
                         // Handle namespace selector keys (needs app state)
                         if matches!(app.overlay, Overlay::NamespaceSelector) {
+                            // While filtering, letters go to the filter.
+                            let typing = app.input_mode == InputMode::Filter;
                             match key.code {
-                                crossterm::event::KeyCode::Char('j') | crossterm::event::KeyCode::Down => {
+                                crossterm::event::KeyCode::Down => {
                                     app.namespace_selector_state.select_next();
                                     continue;
                                 }
-                                crossterm::event::KeyCode::Char('k') | crossterm::event::KeyCode::Up => {
+                                crossterm::event::KeyCode::Char('j') if !typing => {
+                                    app.namespace_selector_state.select_next();
+                                    continue;
+                                }
+                                crossterm::event::KeyCode::Up => {
+                                    app.namespace_selector_state.select_previous();
+                                    continue;
+                                }
+                                crossterm::event::KeyCode::Char('k') if !typing => {
                                     app.namespace_selector_state.select_previous();
                                     continue;
                                 }
                                 crossterm::event::KeyCode::Enter => {
-                                    if let Some(idx) = app.namespace_selector_state.selected() {
-                                        if let Some(ns) = app.namespaces.get(idx) {
-                                            let ns_name = ns.name.clone();
-                                            let effects = app.update(Action::SwitchNamespace(ns_name));
-                                            handle_effects(effects, &cli_handle, &app);
-                                        }
+                                    if let Some(ns_name) = app.selected_namespace_name() {
+                                        let effects = app.update(Action::SwitchNamespace(ns_name));
+                                        handle_effects(effects, &cli_handle, &app, &local_tx);
                                     }
                                     continue;
                                 }
-                                crossterm::event::KeyCode::Char('g') => {
+                                crossterm::event::KeyCode::Char('g') if !typing => {
                                     app.namespace_selector_state.select_first();
                                     continue;
                                 }
-                                crossterm::event::KeyCode::Char('G') => {
+                                crossterm::event::KeyCode::Char('G') if !typing => {
                                     app.namespace_selector_state.select_last();
                                     continue;
                                 }
+                                crossterm::event::KeyCode::Char(c @ ('n' | 'e' | 't' | 'D'))
+                                    if app.capabilities.namespace_admin && !typing =>
+                                {
+                                    let selected = app.selected_namespace_name().unwrap_or_default();
+                                    let command = match c {
+                                        'n' => "nsadmin register ".to_string(),
+                                        'e' => format!("nsadmin describe {} ", selected),
+                                        't' => format!("nsadmin retention {} ", selected),
+                                        _ => format!("nsadmin deprecate {}", selected),
+                                    };
+                                    let effects = if c == 'D' {
+                                        app.update(Action::SubmitCommandInput(command))
+                                    } else {
+                                        app.update(Action::PromptCommand(command))
+                                    };
+                                    handle_effects(effects, &cli_handle, &app, &local_tx);
+                                    continue;
+                                }
                                 _ => {} // Fall through to key_to_action for Esc etc
                             }
                         }
@@ -171,39 +589,148 @@ async fn run_tui(cli: Cli) -> Result<()> {
                             &app.input_buffer,
                         ) {
                             let effects = app.update(action);
-                            handle_effects(effects, &cli_handle, &app);
+                            handle_effects(effects, &cli_handle, &app, &local_tx);
                         }
                     }
+                    AppEvent::Resize(width) => {
+                        let effects = app.update(Action::Resize(width));
+                        handle_effects(effects, &cli_handle, &app, &local_tx);
+                    }
                     AppEvent::Tick => {
+                        if config_watcher.changed() {
+                            let effects = reload_config(&cli, &mut app, &mut config_watcher);
+                            cli_handle.set_audit_file(app.audit_file.clone());
+                            handle_effects(effects, &cli_handle, &app, &local_tx);
+                        }
                         let effects = app.update(Action::Tick);
-                        handle_effects(effects, &cli_handle, &app);
+                        handle_effects(effects, &cli_handle, &app, &local_tx);
                     }
                 }
             }
             Some(action) = action_rx.recv() => {
+                if let Action::ContextConnected(name, Ok((next, capabilities))) = &action {
+                    contexts.insert(name.clone(), ((**next).clone(), *capabilities));
+                }
                 let effects = app.update(action);
-                handle_effects(effects, &cli_handle, &app);
+                handle_effects(effects, &cli_handle, &app, &local_tx);
             }
         }
 
+        while let Ok(effect) = local_rx.try_recv() {
+            match effect {
+                Effect::SwitchContext(name) => match contexts.get(&name) {
+                    Some((next, capabilities)) => {
+                        let known = Ok((Box::new(next.clone()), *capabilities));
+                        let effects = app.update(Action::ContextConnected(name, known));
+                        handle_effects(effects, &cli_handle, &app, &local_tx);
+                    }
+                    None => {
+                        let (tx, handle, cli) =
+                            (action_tx.clone(), cli_handle.clone(), cli.clone());
+                        tokio::spawn(async move {
+                            let result = connect_profile(&cli, &name)
+                                .await
+                                .map(|(next, client)| {
+                                    let capabilities = client.capabilities();
+                                    handle.register(&name, client);
+                                    (Box::new(next), capabilities)
+                                })
+                                .map_err(|e| e.to_string());
+                            let _ = tx.send(Action::ContextConnected(name, result));
+                        });
+                    }
+                },
+                Effect::SaveBookmarks(bookmarks) => {
+                    if let Err(e) = t9s::bookmarks::save(&bookmarks) {
+                        app.update(Action::Error(format!("saving bookmarks: {}", e)));
+                    }
+                }
+                Effect::RunPipe(job) => {
+                    let tx = action_tx.clone();
+                    tokio::spawn(async move {
+                        let result = t9s::pipe::run(&job.command, &job.input).await;
+                        let _ = tx.send(Action::PipeFinished(job.title, result));
+                    });
+                }
+                Effect::DelayPreview(row) => {
+                    let tx = action_tx.clone();
+                    tokio::spawn(async move {
+                        tokio::time::sleep(t9s::app::PREVIEW_DEBOUNCE).await;
+                        let _ = tx.send(Action::PreviewDue(row));
+                    });
+                }
+                Effect::WriteFile(write) => match write_file(&write) {
+                    Ok(()) => app.notice = Some((write.done, std::time::Instant::now())),
+                    Err(e) => {
+                        app.update(Action::Error(e));
+                    }
+                },
+                Effect::RunShell(command, env) => {
+                    events.pause();
+                    t9s::tui::suspend()?;
+                    let result = t9s::shell::run(&command, &env).await;
+                    t9s::tui::resume(&mut terminal, app.mouse_capture)?;
+                    events.resume();
+                    if let Err(e) = result {
+                        app.update(Action::Error(e));
+                    }
+                }
+                Effect::OpenUrl(url) => {
+                    if let Err(e) = t9s::shell::open_url(&url) {
+                        app.update(Action::Error(e));
+                    }
+                }
+                Effect::EditSchedule(draft, text) => {
+                    events.pause();
+                    t9s::tui::suspend()?;
+                    let edited =
+                        t9s::editor::edit(&text, &format!("schedule-{}.json", draft.schedule_id))
+                            .await;
+                    t9s::tui::resume(&mut terminal, app.mouse_capture)?;
+                    events.resume();
+                    let effects = app.update(Action::ScheduleEdited(draft, text, edited));
+                    handle_effects(effects, &cli_handle, &app, &local_tx);
+                }
+                Effect::EditActivityOptions(draft, text) => {
+                    events.pause();
+                    t9s::tui::suspend()?;
+                    let edited =
+                        t9s::editor::edit(&text, &format!("activity-{}.json", draft.activity_id))
+                            .await;
+                    t9s::tui::resume(&mut terminal, app.mouse_capture)?;
+                    events.resume();
+                    let effects = app.update(Action::ActivityOptionsEdited(draft, text, edited));
+                    handle_effects(effects, &cli_handle, &app, &local_tx);
+                }
+                _ => {}
+            }
+        }
+
+        if app.active_profile != active_profile {
+            active_profile = app.active_profile.clone();
+            if let Some((next, _)) = active_profile.as_ref().and_then(|name| contexts.get(name)) {
+                cli = next.clone();
+            }
+            let handle = cli_handle.for_context(app.context_id());
+            handle.send(CliRequest::LoadNamespaces);
+            handle.send(CliRequest::LoadClusterInfo);
+        }
+
         if app.should_quit {
             break;
         }
     }
 
-    // Restore terminal
-    t9s::tui::restore()?;
-
     Ok(())
 }
 
 fn render(app: &mut App, frame: &mut ratatui::Frame) {
     let area = frame.area();
 
-    // Dark navy background
+    // Theme background
     frame.render_widget(
         ratatui::widgets::Block::default()
-            .style(ratatui::style::Style::default().bg(t9s::theme::BG_DARK)),
+            .style(ratatui::style::Style::default().bg(t9s::theme::current().bg)),
         area,
     );
 
@@ -217,8 +744,37 @@ fn render(app: &mut App, frame: &mut ratatui::Frame) {
     // Tab bar
     widgets::tab_bar::render(app, frame, layout[0]);
 
-    // Content area
-    let content_area = layout[1];
+    // Content area, with the highlighted row's detail beside the list in
+    // the split view or a compact workflow preview under it
+    let mut content_area = layout[1];
+    if let View::Collection(kind) = app.view {
+        if app.split_visible() {
+            let panes =
+                Layout::horizontal([Constraint::Percentage(50), Constraint::Percentage(50)])
+                    .split(content_area);
+            content_area = panes[0];
+            let block = Block::default()
+                .borders(Borders::LEFT)
+                .border_style(Style::default().fg(theme::current().text_muted))
+                .title(" Preview ");
+            let preview_area = block.inner(panes[1]);
+            frame.render_widget(block, panes[1]);
+            if let Some(spec) = detail_spec(kind) {
+                (spec.render)(app, frame, preview_area);
+            }
+        } else if app.preview_pane
+            && !app.split_visible()
+            && kind == t9s::kinds::KindId::WorkflowExecution
+        {
+            let panes = Layout::vertical([
+                Constraint::Fill(1),
+                Constraint::Length(widgets::workflow_preview::PREVIEW_HEIGHT),
+            ])
+            .split(content_area);
+            content_area = panes[0];
+            widgets::workflow_preview::render(app, frame, panes[1]);
+        }
+    }
     match app.view {
         View::Collection(t9s::kinds::KindId::WorkflowExecution) => {
             widgets::collection::render_kind_collection(
@@ -267,10 +823,23 @@ fn render(app: &mut App, frame: &mut ratatui::Frame) {
     // Overlays
     match &app.overlay {
         Overlay::Help => widgets::help_overlay::render(&app.view, frame, area),
-        Overlay::Confirm(action) => widgets::confirm_modal::render(action, frame, area),
+        Overlay::Confirm(action) => widgets::confirm_modal::render(app, action, frame, area),
         Overlay::NamespaceSelector => {
             widgets::namespace_selector::render(app, frame, area);
         }
+        Overlay::Logs => widgets::logs_panel::render(app, frame, area),
+        Overlay::Audit => widgets::audit_log::render(app, frame, area),
+        Overlay::PendingActivity => widgets::pending_activity::render(app, frame, area),
+        Overlay::SignalPicker => widgets::signal_picker::render(app, frame, area),
+        Overlay::QuickActions => widgets::quick_actions::render(app, frame, area),
+        Overlay::QueryPicker => widgets::query_picker::render(app, frame, area),
+        Overlay::ContextPicker => widgets::context_picker::render(app, frame, area),
+        Overlay::SearchAttributes => widgets::search_attributes::render(app, frame, area),
+        Overlay::QueryBuilder => widgets::query_builder::render(app, frame, area),
+        Overlay::Overview => widgets::overview::render(app, frame, area),
+        Overlay::TimeRange => widgets::time_range::render(app, frame, area),
+        Overlay::Bookmarks => widgets::bookmarks::render(app, frame, area),
+        Overlay::Pager => widgets::pager::render(app, frame, area),
         Overlay::None => {}
     }
 
@@ -278,6 +847,7 @@ fn render(app: &mut App, frame: &mut ratatui::Frame) {
     match app.input_mode {
         InputMode::Command => widgets::command_input::render_command_modal(app, frame, area),
         InputMode::Search => widgets::command_input::render_search_modal(app, frame, area),
+        InputMode::Filter => widgets::command_input::render_filter_bar(app, frame, layout[2]),
         _ => {}
     }
 
@@ -285,174 +855,460 @@ fn render(app: &mut App, frame: &mut ratatui::Frame) {
     widgets::error_toast::render(app, frame, area);
 }
 
-fn handle_effects(effects: Vec<Effect>, cli_handle: &t9s::worker::CliHandle, app: &App) {
+/// Watches the config file and the skin it (or `--theme`) names.
+fn config_watcher(cli: &Cli, config: Option<&ConfigFile>) -> FileWatcher {
+    let theme = cli
+        .theme
+        .as_deref()
+        .or_else(|| config.and_then(|c| c.theme.as_deref()));
+    FileWatcher::new(
+        ConfigFile::path()
+            .into_iter()
+            .chain(theme.into_iter().flat_map(theme::Theme::skin_paths)),
+    )
+}
+
+/// Re-reads the config file after an edit and applies its theme, columns,
+/// queries and other UI settings. A file that doesn't parse, or names a
+/// broken theme, changes nothing and raises an error toast instead.
+fn reload_config(cli: &Cli, app: &mut App, watcher: &mut FileWatcher) -> Vec<Effect> {
+    let config = match ConfigFile::path()
+        .ok_or_else(|| "no config directory".to_string())
+        .and_then(|path| ConfigFile::read(&path))
+    {
+        Ok(config) => config,
+        Err(e) => return app.update(Action::Error(format!("config reload: {}", e))),
+    };
+    // The theme may now name a different skin file, or one not written yet.
+    *watcher = config_watcher(cli, Some(&config));
+    // `--theme` wins over the file, as at startup.
+    let theme = match cli.theme.as_deref().or(config.theme.as_deref()) {
+        Some(spec) => match theme::Theme::load(spec) {
+            Ok(theme) => theme,
+            Err(e) => return app.update(Action::Error(format!("config reload: {}", e))),
+        },
+        None => theme::Theme::dark(),
+    };
+    theme::set(theme);
+    app.update(Action::ConfigReloaded(Box::new(config)))
+}
+
+/// Writes a `:` command's file, refusing to replace one unless asked to.
+fn write_file(write: &FileWrite) -> Result<(), String> {
+    let path = &write.path;
+    t9s::export::write_file(path, &write.contents, write.overwrite).map_err(|e| match e.kind() {
+        std::io::ErrorKind::AlreadyExists => format!(
+            "{} exists; use :{}! to overwrite it",
+            path.display(),
+            write.command
+        ),
+        _ => format!("{} to {}: {}", write.command, path.display(), e),
+    })
+}
+
+/// What accepting `confirm` runs.
+fn confirm_effects(confirm: ConfirmAction, app: &App) -> Vec<Effect> {
+    match confirm {
+        ConfirmAction::Operation(confirm) | ConfirmAction::Restart(confirm) => {
+            operation_effect_spec(confirm.op, confirm.kind)
+                .map(|spec| (spec.to_effects)(&confirm.target, app))
+                .unwrap_or_default()
+        }
+        ConfirmAction::Namespace(change) => vec![Effect::ChangeNamespace(change)],
+        ConfirmAction::Batch(confirm) => confirm.effects(),
+        ConfirmAction::ActivityOptions(draft, edit) => {
+            vec![Effect::UpdateActivityOptions(draft, edit)]
+        }
+    }
+}
+
+/// Runs one `:` command without the TUI, after opening the workflow or
+/// schedule it acts on. Returns the exit status: 0 when it succeeded, 1 when
+/// it failed and 2 when it can't run without the TUI or the server can't be
+/// reached.
+async fn run_exec(cli: &Cli, args: &ExecArgs) -> i32 {
+    match exec(cli, args).await {
+        Ok(()) => 0,
+        Err(e) => {
+            eprintln!("error: {}", e);
+            e.exit_code()
+        }
+    }
+}
+
+async fn exec(cli: &Cli, args: &ExecArgs) -> Result<(), ExecError> {
+    use t9s::nav::{Location, RouteSegment, SchedulesRoute, WorkflowsRoute};
+
+    let command = args.command.trim().trim_start_matches(':').to_string();
+    t9s::exec::check_command(&command)?;
+    let client = connect(cli)
+        .await
+        .map_err(|e| ExecError::Unreachable(e.to_string()))?;
+    let mut app = App::new(cli.namespace.clone());
+    app.capabilities = client.capabilities();
+    app.page_size = cli.page_size;
+    app.active_profile = cli.profile.clone();
+    app.audit_file = ConfigFile::load()
+        .and_then(|config| config.audit_file)
+        .map(std::path::PathBuf::from);
+
+    let target = match (&args.workflow_id, &args.schedule_id) {
+        (Some(workflow_id), _) => Some(RouteSegment::Workflows(WorkflowsRoute::Detail {
+            workflow_id: workflow_id.clone(),
+            run_id: args.run_id.clone(),
+            tab: None,
+        })),
+        (None, Some(schedule_id)) => Some(RouteSegment::Schedules(SchedulesRoute::Detail {
+            schedule_id: schedule_id.clone(),
+        })),
+        (None, None) => None,
+    };
+    if let Some(segment) = target {
+        let location = Location::new(cli.namespace.clone(), vec![segment]);
+        let effects = app.update(Action::OpenLocation(location));
+        exec_effects(effects, client.as_ref(), &mut app).await?;
+    }
+
+    let mut effects = app.update(Action::SubmitCommandInput(command));
+    if let Overlay::Confirm(confirm) = app.overlay.clone() {
+        if !args.yes {
+            return Err(ExecError::Failed(format!(
+                "{}: needs confirmation; rerun with --yes",
+                args.command
+            )));
+        }
+        effects = confirm_effects(confirm.clone(), &app);
+        app.close_overlay();
+    }
+    exec_effects(effects, client.as_ref(), &mut app).await
+}
+
+/// Runs the requests behind `effects` one at a time, feeding each result
+/// back into `app`. Stops at the first error, including one the command
+/// raised itself.
+async fn exec_effects(
+    effects: Vec<Effect>,
+    client: &dyn TemporalClient,
+    app: &mut App,
+) -> Result<(), ExecError> {
+    for effect in effects {
+        if let Some((msg, _)) = app.error_toasts.back() {
+            return Err(ExecError::Failed(msg.clone()));
+        }
+        if let Effect::WriteFile(write) = &effect {
+            write_file(write).map_err(ExecError::Failed)?;
+            println!("{}", write.done);
+            continue;
+        }
+        let Some(request) = effect_request(effect, app) else {
+            continue;
+        };
+        let audit = request.audit();
+        let action = t9s::worker::process(client, request).await;
+        let audit_file = app.audit_file.clone();
+        let context = app.context_id().to_string();
+        for audited in
+            t9s::worker::record_audit(&context, audit, &action, audit_file.as_deref()).await
+        {
+            app.update(audited);
+        }
+        if let Some(e) = t9s::exec::action_error(&action) {
+            return Err(e);
+        }
+        app.update(action);
+    }
+    match app.error_toasts.back() {
+        Some((msg, _)) => Err(ExecError::Failed(msg.clone())),
+        None => Ok(()),
+    }
+}
+
+/// Sends the client requests behind `effects` to the worker and the effects
+/// the main loop runs itself to `local`.
+fn handle_effects(
+    effects: Vec<Effect>,
+    cli_handle: &t9s::worker::CliHandle,
+    app: &App,
+    local: &mpsc::UnboundedSender<Effect>,
+) {
+    let cli_handle = &cli_handle
+        .for_context(app.context_id())
+        .with_generation(app.generation);
     for effect in effects {
         match effect {
-            Effect::LoadWorkflows => {
-                cli_handle.send(CliRequest::LoadWorkflows {
-                    namespace: app.namespace.clone(),
-                    query: app.search_query_for_kind(KindId::WorkflowExecution),
-                    page_size: app.page_size,
-                    next_page_token: vec![],
-                });
-            }
-            Effect::LoadMoreWorkflows => {
-                cli_handle.send(CliRequest::LoadMoreWorkflows {
-                    namespace: app.namespace.clone(),
-                    query: app.search_query_for_kind(KindId::WorkflowExecution),
-                    page_size: app.page_size,
-                    next_page_token: app.next_page_token.clone(),
-                });
-            }
-            Effect::LoadWorkflowDetail(wf_id, run_id) => {
-                cli_handle.send(CliRequest::LoadWorkflowDetail {
-                    namespace: app.namespace.clone(),
-                    workflow_id: wf_id,
-                    run_id,
-                });
-            }
-            Effect::LoadHistory(wf_id, run_id) => {
-                cli_handle.send(CliRequest::LoadHistory {
-                    namespace: app.namespace.clone(),
-                    workflow_id: wf_id,
-                    run_id,
-                });
-            }
-            Effect::LoadNamespaces => {
-                cli_handle.send(CliRequest::LoadNamespaces);
-            }
-            Effect::LoadSchedules => {
-                cli_handle.send(CliRequest::LoadSchedules {
-                    namespace: app.namespace.clone(),
-                    query: app.search_query_for_kind(KindId::Schedule),
-                });
-            }
-            Effect::LoadScheduleDetail(schedule_id) => {
-                cli_handle.send(CliRequest::LoadScheduleDetail {
-                    namespace: app.namespace.clone(),
-                    schedule_id,
-                });
-            }
-            Effect::LoadWorkflowCount => {
-                cli_handle.send(CliRequest::LoadWorkflowCount {
-                    namespace: app.namespace.clone(),
-                    query: app.search_query_for_kind(KindId::WorkflowExecution),
-                });
-            }
-            Effect::CancelWorkflow(wf_id, run_id) => {
-                cli_handle.send(CliRequest::CancelWorkflow {
-                    namespace: app.namespace.clone(),
-                    workflow_id: wf_id,
-                    run_id,
-                });
-            }
-            Effect::TerminateWorkflow(wf_id, run_id) => {
-                cli_handle.send(CliRequest::TerminateWorkflow {
-                    namespace: app.namespace.clone(),
-                    workflow_id: wf_id,
-                    run_id,
-                });
-            }
-            Effect::PauseSchedule(schedule_id, pause) => {
-                cli_handle.send(CliRequest::PauseSchedule {
-                    namespace: app.namespace.clone(),
-                    schedule_id,
-                    pause,
-                });
-            }
-            Effect::TriggerSchedule(schedule_id) => {
-                cli_handle.send(CliRequest::TriggerSchedule {
-                    namespace: app.namespace.clone(),
-                    schedule_id,
-                });
-            }
-            Effect::DeleteSchedule(schedule_id) => {
-                cli_handle.send(CliRequest::DeleteSchedule {
-                    namespace: app.namespace.clone(),
-                    schedule_id,
-                });
+            Effect::CancelRequests(scope) => cli_handle.cancel(scope),
+            Effect::Reconnect => cli_handle.reconnect(),
+            Effect::SetMouseCapture(enabled) => {
+                if let Err(e) = t9s::tui::set_mouse_capture(enabled) {
+                    tracing::warn!("failed to toggle mouse capture: {}", e);
+                }
             }
-            Effect::LoadTaskQueueDetail(task_queue) => {
-                cli_handle.send(CliRequest::DescribeTaskQueue {
-                    namespace: app.namespace.clone(),
-                    task_queue,
-                });
+            Effect::CopyToClipboard(text) => {
+                if let Err(e) = t9s::tui::copy_to_clipboard(&text) {
+                    tracing::warn!("failed to copy to clipboard: {}", e);
+                }
             }
-            Effect::LoadActivityExecutions {
-                namespace,
-                query,
-                page_size,
-                next_page_token,
-            } => {
-                cli_handle.send(CliRequest::LoadActivityExecutions {
-                    namespace,
-                    query,
-                    page_size,
-                    next_page_token,
-                });
+            Effect::Quit => {}
+            effect @ (Effect::SwitchContext(_)
+            | Effect::DelayPreview(_)
+            | Effect::EditSchedule(..)
+            | Effect::EditActivityOptions(..)
+            | Effect::RunPipe(_)
+            | Effect::RunShell(..)
+            | Effect::OpenUrl(_)
+            | Effect::WriteFile(_)
+            | Effect::SaveBookmarks(_)) => {
+                let _ = local.send(effect);
             }
-            Effect::LoadMoreActivityExecutions {
-                namespace,
-                query,
-                page_size,
-                next_page_token,
-            } => {
-                cli_handle.send(CliRequest::LoadMoreActivityExecutions {
-                    namespace,
-                    query,
-                    page_size,
-                    next_page_token,
-                });
+            effect => {
+                if let Some(request) = effect_request(effect, app) {
+                    cli_handle.send(request);
+                }
             }
-            Effect::LoadActivityExecutionDetail {
-                namespace,
+        }
+    }
+}
+
+/// The client request that carries out `effect`, for effects that need one.
+fn effect_request(effect: Effect, app: &App) -> Option<CliRequest> {
+    match effect {
+        Effect::HealthCheck => Some(CliRequest::HealthCheck),
+        Effect::LoadWorkflows => Some(CliRequest::LoadWorkflows {
+            namespace: app.namespace.clone(),
+            query: app.search_query_for_kind(KindId::WorkflowExecution),
+            page_size: app.page_size,
+            next_page_token: vec![],
+            archived: app.archived_workflows,
+            fanout: app.namespace_fanout.clone(),
+        }),
+        Effect::LoadMoreWorkflows => Some(CliRequest::LoadMoreWorkflows {
+            namespace: app.namespace.clone(),
+            query: app.search_query_for_kind(KindId::WorkflowExecution),
+            page_size: app.page_size,
+            next_page_token: app.next_page_token.clone(),
+            archived: app.archived_workflows,
+        }),
+        Effect::LoadWorkflowDetail(wf_id, run_id) => Some(CliRequest::LoadWorkflowDetail {
+            namespace: app.namespace.clone(),
+            workflow_id: wf_id,
+            run_id,
+        }),
+        Effect::LoadWorkflowPreview(workflow_id, run_id) => Some(CliRequest::LoadWorkflowPreview {
+            namespace: app.namespace.clone(),
+            workflow_id,
+            run_id,
+        }),
+        Effect::LoadHistory(wf_id, run_id) => Some(CliRequest::LoadHistory {
+            namespace: app.namespace.clone(),
+            workflow_id: wf_id,
+            run_id,
+        }),
+        Effect::LoadWorkflowMetadata(workflow_id, run_id) => {
+            Some(CliRequest::LoadWorkflowMetadata {
+                namespace: app.namespace.clone(),
+                workflow_id,
+                run_id,
+            })
+        }
+        Effect::LoadStackTrace(wf_id, run_id) => Some(CliRequest::LoadStackTrace {
+            namespace: app.namespace.clone(),
+            workflow_id: wf_id,
+            run_id,
+        }),
+        Effect::LoadRawWorkflow(wf_id, run_id) => Some(CliRequest::LoadRawWorkflow {
+            namespace: app.namespace.clone(),
+            workflow_id: wf_id,
+            run_id,
+        }),
+        Effect::LoadRawSchedule(schedule_id) => Some(CliRequest::LoadRawSchedule {
+            namespace: app.namespace.clone(),
+            schedule_id,
+        }),
+        Effect::LoadNamespaces => Some(CliRequest::LoadNamespaces),
+        Effect::LoadSchedules => Some(CliRequest::LoadSchedules {
+            namespace: app.namespace.clone(),
+            query: app.search_query_for_kind(KindId::Schedule),
+            page_size: app.schedule_page_size,
+            next_page_token: vec![],
+        }),
+        Effect::LoadMoreSchedules => Some(CliRequest::LoadMoreSchedules {
+            namespace: app.namespace.clone(),
+            query: app.search_query_for_kind(KindId::Schedule),
+            page_size: app.schedule_page_size,
+            next_page_token: app.schedule_next_page_token.clone(),
+        }),
+        Effect::LoadScheduleDetail(schedule_id) => Some(CliRequest::LoadScheduleDetail {
+            namespace: app.namespace.clone(),
+            schedule_id,
+        }),
+        // Archival visibility has no count API, and a list spanning
+        // namespaces has no one count.
+        Effect::LoadWorkflowCount if app.archived_workflows || !app.namespace_fanout.is_empty() => {
+            None
+        }
+        Effect::LoadWorkflowCount => Some(CliRequest::LoadWorkflowCount {
+            namespace: app.namespace.clone(),
+            query: app.search_query_for_kind(KindId::WorkflowExecution),
+        }),
+        Effect::CancelWorkflow(wf_id, run_id) => Some(CliRequest::CancelWorkflow {
+            namespace: app.namespace.clone(),
+            workflow_id: wf_id,
+            run_id,
+        }),
+        Effect::TerminateWorkflow(wf_id, run_id) => Some(CliRequest::TerminateWorkflow {
+            namespace: app.namespace.clone(),
+            workflow_id: wf_id,
+            run_id,
+        }),
+        Effect::TerminateAndRestartWorkflow(wf_id, run_id) => {
+            Some(CliRequest::TerminateAndRestartWorkflow {
+                namespace: app.namespace.clone(),
+                workflow_id: wf_id,
+                run_id,
+            })
+        }
+        Effect::PauseSchedule(schedule_id, pause, note) => Some(CliRequest::PauseSchedule {
+            namespace: app.namespace.clone(),
+            schedule_id,
+            pause,
+            note,
+        }),
+        Effect::TriggerSchedule(schedule_id) => Some(CliRequest::TriggerSchedule {
+            namespace: app.namespace.clone(),
+            schedule_id,
+        }),
+        Effect::LoadScheduleDraft(schedule_id) => Some(CliRequest::LoadScheduleDraft {
+            namespace: app.namespace.clone(),
+            schedule_id,
+        }),
+        Effect::UpdateSchedule {
+            schedule_id,
+            edit,
+            conflict_token,
+        } => Some(CliRequest::UpdateSchedule {
+            namespace: app.namespace.clone(),
+            schedule_id,
+            edit,
+            conflict_token,
+        }),
+        Effect::UpdateActivityOptions(draft, edit) => Some(CliRequest::UpdateActivityOptions {
+            namespace: app.namespace.clone(),
+            draft,
+            edit,
+        }),
+        Effect::DeleteSchedule(schedule_id) => Some(CliRequest::DeleteSchedule {
+            namespace: app.namespace.clone(),
+            schedule_id,
+        }),
+        Effect::LoadOverview => Some(CliRequest::LoadOverview {
+            namespace: app.namespace.clone(),
+        }),
+        Effect::LoadSearchAttributes => Some(CliRequest::LoadSearchAttributes {
+            namespace: app.namespace.clone(),
+        }),
+        Effect::ChangeNamespace(change) => Some(CliRequest::ChangeNamespace(change)),
+        Effect::AddSearchAttribute(name, value_type) => Some(CliRequest::AddSearchAttribute {
+            namespace: app.namespace.clone(),
+            name,
+            value_type,
+        }),
+        Effect::LoadTaskQueueDetail(task_queue) => Some(CliRequest::DescribeTaskQueue {
+            namespace: app.namespace.clone(),
+            task_queue,
+        }),
+        Effect::LoadActivityExecutions {
+            namespace,
+            query,
+            page_size,
+            next_page_token,
+        } => Some(CliRequest::LoadActivityExecutions {
+            namespace,
+            query,
+            page_size,
+            next_page_token,
+        }),
+        Effect::LoadMoreActivityExecutions {
+            namespace,
+            query,
+            page_size,
+            next_page_token,
+        } => Some(CliRequest::LoadMoreActivityExecutions {
+            namespace,
+            query,
+            page_size,
+            next_page_token,
+        }),
+        Effect::LoadActivityExecutionDetail {
+            namespace,
+            activity_id,
+            run_id,
+        } => Some(CliRequest::DescribeActivityExecution {
+            namespace,
+            activity_id,
+            run_id,
+        }),
+        Effect::CountActivityExecutions { namespace, query } => {
+            Some(CliRequest::CountActivityExecutions { namespace, query })
+        }
+        Effect::RequestCancelActivityExecution(activity_id, run_id) => {
+            Some(CliRequest::RequestCancelActivityExecution {
+                namespace: app.namespace.clone(),
                 activity_id,
                 run_id,
-            } => {
-                cli_handle.send(CliRequest::DescribeActivityExecution {
-                    namespace,
-                    activity_id,
-                    run_id,
-                });
-            }
-            Effect::CountActivityExecutions { namespace, query } => {
-                cli_handle.send(CliRequest::CountActivityExecutions { namespace, query });
-            }
-            Effect::RequestCancelActivityExecution(activity_id, run_id) => {
-                cli_handle.send(CliRequest::RequestCancelActivityExecution {
-                    namespace: app.namespace.clone(),
-                    activity_id,
-                    run_id,
-                });
-            }
-            Effect::TerminateActivityExecution(activity_id, run_id) => {
-                cli_handle.send(CliRequest::TerminateActivityExecution {
-                    namespace: app.namespace.clone(),
-                    activity_id,
-                    run_id,
-                    reason: "terminated via t9s".to_string(),
-                });
-            }
-            Effect::DeleteActivityExecution(activity_id, run_id) => {
-                cli_handle.send(CliRequest::DeleteActivityExecution {
-                    namespace: app.namespace.clone(),
-                    activity_id,
-                    run_id,
-                });
-            }
-            Effect::CheckActivitySupport { namespace } => {
-                cli_handle.send(CliRequest::CheckActivitySupport { namespace });
-            }
-            Effect::SignalWorkflow(wf_id, run_id, signal_name, input) => {
-                cli_handle.send(CliRequest::SignalWorkflow {
-                    namespace: app.namespace.clone(),
-                    workflow_id: wf_id,
-                    run_id,
-                    signal_name,
-                    input,
-                });
-            }
-            Effect::Quit => {}
+            })
+        }
+        Effect::TerminateActivityExecution(activity_id, run_id) => {
+            Some(CliRequest::TerminateActivityExecution {
+                namespace: app.namespace.clone(),
+                activity_id,
+                run_id,
+                reason: "terminated via t9s".to_string(),
+            })
+        }
+        Effect::DeleteActivityExecution(activity_id, run_id) => {
+            Some(CliRequest::DeleteActivityExecution {
+                namespace: app.namespace.clone(),
+                activity_id,
+                run_id,
+            })
+        }
+        Effect::CheckActivitySupport { namespace } => {
+            Some(CliRequest::CheckActivitySupport { namespace })
+        }
+        Effect::CountBatch(operation, query) => Some(CliRequest::CountBatch {
+            namespace: app.namespace.clone(),
+            operation,
+            query,
+        }),
+        Effect::StartBatchOperation(namespace, operation, target) => {
+            Some(CliRequest::StartBatchOperation {
+                namespace,
+                operation,
+                target,
+            })
+        }
+        Effect::SignalWorkflow(wf_id, run_id, signal_name, input) => {
+            Some(CliRequest::SignalWorkflow {
+                namespace: app.namespace.clone(),
+                workflow_id: wf_id,
+                run_id,
+                signal_name,
+                input,
+            })
         }
+        Effect::CancelRequests(_)
+        | Effect::Reconnect
+        | Effect::SetMouseCapture(_)
+        | Effect::CopyToClipboard(_)
+        | Effect::SwitchContext(_)
+        | Effect::DelayPreview(_)
+        | Effect::EditSchedule(..)
+        | Effect::EditActivityOptions(..)
+        | Effect::RunPipe(_)
+        | Effect::RunShell(..)
+        | Effect::OpenUrl(_)
+        | Effect::WriteFile(_)
+        | Effect::SaveBookmarks(_)
+        | Effect::Quit => None,
     }
 }
@@ -0,0 +1,164 @@
+//! `[[mask]]` rules from the config file, which hide payload values such as
+//! emails or tokens wherever payloads are drawn.
+
+use regex::Regex;
+use serde_json::Value;
+
+use crate::config::MaskConfig;
+
+/// Shown instead of a masked value when the rule gives no `replacement`.
+const DEFAULT_REPLACEMENT: &str = "***";
+
+#[derive(Debug, Clone)]
+enum MaskTarget {
+    /// A JSON pointer from the payload's root.
+    Pointer(String),
+    /// Object keys at any depth.
+    Key(Regex),
+}
+
+/// One compiled `[[mask]]` rule.
+#[derive(Debug, Clone)]
+pub struct MaskRule {
+    target: MaskTarget,
+    replacement: String,
+}
+
+impl MaskRule {
+    pub fn parse(config: &MaskConfig) -> Result<Self, String> {
+        let target = match (&config.pointer, &config.key) {
+            (Some(pointer), None) if pointer.starts_with('/') => {
+                MaskTarget::Pointer(pointer.clone())
+            }
+            (Some(pointer), None) => {
+                return Err(format!("pointer '{}' must start with /", pointer))
+            }
+            (None, Some(key)) => {
+                MaskTarget::Key(Regex::new(key).map_err(|e| format!("key regex '{}': {}", key, e))?)
+            }
+            _ => return Err("set exactly one of pointer or key".to_string()),
+        };
+        Ok(Self {
+            target,
+            replacement: config
+                .replacement
+                .clone()
+                .unwrap_or_else(|| DEFAULT_REPLACEMENT.to_string()),
+        })
+    }
+}
+
+/// `value` with every value the rules match replaced.
+pub fn mask(value: &Value, rules: &[MaskRule]) -> Value {
+    let mut value = value.clone();
+    for rule in rules {
+        match &rule.target {
+            MaskTarget::Pointer(pointer) => {
+                if let Some(found) = value.pointer_mut(pointer) {
+                    *found = Value::String(rule.replacement.clone());
+                }
+            }
+            MaskTarget::Key(key) => mask_keys(&mut value, key, &rule.replacement),
+        }
+    }
+    value
+}
+
+/// `text` masked as JSON when it holds an object or array, for payloads
+/// that arrive as text such as user metadata. Other text is returned as is.
+pub fn mask_text(text: &str, rules: &[MaskRule]) -> String {
+    if rules.is_empty() {
+        return text.to_string();
+    }
+    match serde_json::from_str::<Value>(text) {
+        Ok(value @ (Value::Object(_) | Value::Array(_))) => {
+            let masked = mask(&value, rules);
+            if text.contains('\n') {
+                serde_json::to_string_pretty(&masked).unwrap_or_default()
+            } else {
+                masked.to_string()
+            }
+        }
+        _ => text.to_string(),
+    }
+}
+
+fn mask_keys(value: &mut Value, key: &Regex, replacement: &str) {
+    match value {
+        Value::Object(fields) => {
+            for (name, field) in fields.iter_mut() {
+                if key.is_match(name) {
+                    *field = Value::String(replacement.to_string());
+                } else {
+                    mask_keys(field, key, replacement);
+                }
+            }
+        }
+        Value::Array(items) => {
+            for item in items {
+                mask_keys(item, key, replacement);
+            }
+        }
+        _ => {}
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rule(pointer: Option<&str>, key: Option<&str>, replacement: Option<&str>) -> MaskRule {
+        MaskRule::parse(&MaskConfig {
+            pointer: pointer.map(str::to_string),
+            key: key.map(str::to_string),
+            replacement: replacement.map(str::to_string),
+        })
+        .unwrap()
+    }
+
+    #[test]
+    fn rules_mask_pointers_and_keys_at_any_depth() {
+        let payload = serde_json::json!({
+            "customer": { "email": "a@example.com", "name": "Ann" },
+            "items": [{ "apiToken": "secret", "sku": "x-1" }],
+        });
+        let rules = [
+            rule(Some("/customer/name"), None, Some("<name>")),
+            rule(None, Some("(?i)email|token"), None),
+        ];
+        assert_eq!(
+            mask(&payload, &rules),
+            serde_json::json!({
+                "customer": { "email": "***", "name": "<name>" },
+                "items": [{ "apiToken": "***", "sku": "x-1" }],
+            })
+        );
+    }
+
+    #[test]
+    fn json_text_is_masked_and_other_text_left_alone() {
+        let rules = [rule(None, Some("email"), None)];
+        assert_eq!(
+            mask_text(r#"{"email":"a@example.com"}"#, &rules),
+            r#"{"email":"***"}"#
+        );
+        assert_eq!(
+            mask_text("{\n  \"email\": \"a@example.com\"\n}", &rules),
+            "{\n  \"email\": \"***\"\n}"
+        );
+        assert_eq!(mask_text("email me", &rules), "email me");
+    }
+
+    #[test]
+    fn rules_need_one_valid_target() {
+        let config = |pointer: Option<&str>, key: Option<&str>| MaskConfig {
+            pointer: pointer.map(str::to_string),
+            key: key.map(str::to_string),
+            replacement: None,
+        };
+        assert!(MaskRule::parse(&config(None, None)).is_err());
+        assert!(MaskRule::parse(&config(Some("/a"), Some("b"))).is_err());
+        assert!(MaskRule::parse(&config(Some("a"), None)).is_err());
+        assert!(MaskRule::parse(&config(None, Some("("))).is_err());
+    }
+}
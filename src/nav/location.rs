@@ -28,6 +28,8 @@ pub enum RouteSegment {
 pub enum WorkflowsRoute {
     Collection {
         query: Option<String>,
+        /// StartTime preset such as `1h`, kept apart from the query.
+        since: Option<String>,
     },
     Detail {
         workflow_id: String,
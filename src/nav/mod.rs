@@ -2,4 +2,4 @@ pub mod location;
 pub mod uri;
 
 pub use location::{ActivitiesRoute, Location, RouteSegment, SchedulesRoute, WorkflowsRoute};
-pub use uri::{format_deep_link, parse_deep_link, UriError};
+pub use uri::{format_deep_link, parse_deep_link, web_ui_url, UriError, WebUiPage};
@@ -11,6 +11,18 @@ pub enum UriError {
     UnsupportedRoute,
 }
 
+impl std::fmt::Display for UriError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            UriError::InvalidScheme => "invalid scheme",
+            UriError::InvalidAuthority => "invalid authority",
+            UriError::MissingNamespace => "missing namespace",
+            UriError::InvalidPath => "invalid path",
+            UriError::UnsupportedRoute => "unsupported route",
+        })
+    }
+}
+
 pub fn parse_deep_link(input: &str) -> Result<Location, UriError> {
     let (scheme, rest) = input.split_once("://").ok_or(UriError::InvalidScheme)?;
     if scheme != "temporal" {
@@ -69,6 +81,42 @@ pub fn format_deep_link(location: &Location) -> String {
     }
 }
 
+/// A row's page in the Temporal Web UI.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WebUiPage<'a> {
+    Workflow {
+        workflow_id: &'a str,
+        run_id: &'a str,
+    },
+    Schedule(&'a str),
+}
+
+/// The URL of `page` in the Web UI served at `base`.
+pub fn web_ui_url(base: &str, namespace: &str, page: WebUiPage) -> String {
+    let mut url = format!(
+        "{}/namespaces/{}",
+        base.trim_end_matches('/'),
+        percent_encode(namespace)
+    );
+    match page {
+        WebUiPage::Workflow {
+            workflow_id,
+            run_id,
+        } => {
+            url.push_str("/workflows/");
+            url.push_str(&percent_encode(workflow_id));
+            url.push('/');
+            url.push_str(&percent_encode(run_id));
+            url.push_str("/history");
+        }
+        WebUiPage::Schedule(schedule_id) => {
+            url.push_str("/schedules/");
+            url.push_str(&percent_encode(schedule_id));
+        }
+    }
+    url
+}
+
 fn parse_route(
     segments: &[String],
     params: &HashMap<String, String>,
@@ -115,6 +163,7 @@ fn parse_workflows_route(
     if segments.is_empty() {
         return Ok(vec![RouteSegment::Workflows(WorkflowsRoute::Collection {
             query: params.get("q").cloned(),
+            since: params.get("since").cloned(),
         })]);
     }
 
@@ -223,8 +272,13 @@ fn build_query(location: &Location) -> String {
 
     if let Some(segment) = location.leaf() {
         match segment {
-            RouteSegment::Workflows(WorkflowsRoute::Collection { query: Some(q) }) => {
-                params.push((String::from("q"), q.clone()));
+            RouteSegment::Workflows(WorkflowsRoute::Collection { query, since }) => {
+                if let Some(q) = query {
+                    params.push((String::from("q"), q.clone()));
+                }
+                if let Some(since) = since {
+                    params.push((String::from("since"), since.clone()));
+                }
             }
             RouteSegment::Workflows(WorkflowsRoute::Detail { run_id, tab, .. }) => {
                 if let Some(run_id) = run_id {
@@ -337,12 +391,33 @@ fn percent_decode_inner(input: &str, plus_as_space: bool) -> String {
 mod tests {
     use super::*;
 
+    #[test]
+    fn web_ui_urls_encode_ids() {
+        let page = WebUiPage::Workflow {
+            workflow_id: "order 1/a",
+            run_id: "run-1",
+        };
+        assert_eq!(
+            web_ui_url("http://localhost:8233/", "default", page),
+            "http://localhost:8233/namespaces/default/workflows/order%201%2Fa/run-1/history"
+        );
+        assert_eq!(
+            web_ui_url(
+                "https://cloud.temporal.io",
+                "ns.acct",
+                WebUiPage::Schedule("nightly")
+            ),
+            "https://cloud.temporal.io/namespaces/ns.acct/schedules/nightly"
+        );
+    }
+
     #[test]
     fn roundtrip_workflows_collection_with_query() {
         let location = Location::new(
             "default".to_string(),
             vec![RouteSegment::Workflows(WorkflowsRoute::Collection {
                 query: Some("ExecutionStatus = 'Running'".to_string()),
+                since: Some("1h".to_string()),
             })],
         );
 
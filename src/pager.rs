@@ -0,0 +1,97 @@
+//! State of the pager overlay, which shows long text such as stack traces,
+//! payloads, histories and pipe output.
+
+/// Long text shown in the pager overlay: stack traces, payloads, histories
+/// and pipe output.
+#[derive(Debug, Clone, Default)]
+pub struct PagerState {
+    pub title: String,
+    pub lines: Vec<String>,
+    /// First visible line.
+    pub scroll: usize,
+    /// Lowercased `/` search text.
+    pub search: Option<String>,
+}
+
+impl PagerState {
+    pub fn new(title: String, text: &str) -> Self {
+        Self {
+            title,
+            lines: text.lines().map(str::to_string).collect(),
+            scroll: 0,
+            search: None,
+        }
+    }
+
+    pub fn scroll_by(&mut self, delta: isize) {
+        self.scroll = self
+            .scroll
+            .saturating_add_signed(delta)
+            .min(self.lines.len().saturating_sub(1));
+    }
+
+    /// Indices of the lines containing the search text.
+    pub fn matches(&self) -> Vec<usize> {
+        let Some(needle) = &self.search else {
+            return vec![];
+        };
+        self.lines
+            .iter()
+            .enumerate()
+            .filter(|(_, line)| line.to_ascii_lowercase().contains(needle.as_str()))
+            .map(|(i, _)| i)
+            .collect()
+    }
+
+    /// Searches for `query` from the top of the screen; an empty query
+    /// clears the search.
+    pub fn set_search(&mut self, query: &str) -> bool {
+        self.search = Some(query.to_ascii_lowercase()).filter(|q| !q.is_empty());
+        let matches = self.matches();
+        match matches
+            .iter()
+            .find(|&&i| i >= self.scroll)
+            .or(matches.first())
+        {
+            Some(&line) => {
+                self.scroll = line;
+                true
+            }
+            None => self.search.is_none(),
+        }
+    }
+
+    /// Scrolls the next (or previous) match to the top, wrapping around.
+    /// False when nothing matches.
+    pub fn jump_to_match(&mut self, forward: bool) -> bool {
+        let matches = self.matches();
+        let next = if forward {
+            matches
+                .iter()
+                .find(|&&i| i > self.scroll)
+                .or(matches.first())
+        } else {
+            matches
+                .iter()
+                .rev()
+                .find(|&&i| i < self.scroll)
+                .or(matches.last())
+        };
+        match next {
+            Some(&line) => {
+                self.scroll = line;
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// How far through the text the bottom of a `height`-line window is.
+    pub fn percent(&self, height: usize) -> usize {
+        let total = self.lines.len();
+        if total <= height {
+            return 100;
+        }
+        (self.scroll + height).min(total) * 100 / total
+    }
+}
@@ -0,0 +1,96 @@
+//! Runs a payload through a `[[pipes]]` command from the config file, such
+//! as `jq` or a custom decryptor, and captures what it prints.
+
+use std::process::Stdio;
+use std::time::Duration;
+
+use tokio::io::AsyncWriteExt;
+
+/// How long a pipe may run before it is killed.
+const PIPE_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// Feeds `input` to `command` (run by `sh -c`) and returns its stdout with
+/// terminal escapes removed, or its stderr when it fails.
+pub async fn run(command: &str, input: &str) -> Result<String, String> {
+    tokio::time::timeout(PIPE_TIMEOUT, exchange(command, input))
+        .await
+        .map_err(|_| format!("{} timed out after {}s", command, PIPE_TIMEOUT.as_secs()))?
+}
+
+async fn exchange(command: &str, input: &str) -> Result<String, String> {
+    let mut child = tokio::process::Command::new("sh")
+        .arg("-c")
+        .arg(command)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .kill_on_drop(true)
+        .spawn()
+        .map_err(|e| format!("failed to run {}: {}", command, e))?;
+    // Written alongside the reads below: a filter that fills its stdout
+    // before it has read all its input would otherwise block both sides.
+    let writer = child.stdin.take().map(|mut stdin| {
+        let input = input.to_string();
+        tokio::spawn(async move {
+            // A command that exits without reading its input closes the
+            // pipe; its output still counts.
+            let _ = stdin.write_all(input.as_bytes()).await;
+        })
+    });
+    let output = child.wait_with_output().await;
+    if let Some(writer) = writer {
+        writer.abort();
+    }
+    let output = output.map_err(|e| format!("{}: {}", command, e))?;
+    if output.status.success() {
+        Ok(strip_escapes(&String::from_utf8_lossy(&output.stdout)))
+    } else {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        Err(format!(
+            "{} exited with {}: {}",
+            command,
+            output.status,
+            stderr.trim()
+        ))
+    }
+}
+
+/// Removes ANSI CSI sequences (colors, cursor movement) so output from tools
+/// like `jq -C` reads as plain text.
+fn strip_escapes(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    let mut chars = text.chars().peekable();
+    while let Some(c) = chars.next() {
+        if c == '\x1b' && chars.peek() == Some(&'[') {
+            chars.next();
+            for next in chars.by_ref() {
+                if ('@'..='~').contains(&next) {
+                    break;
+                }
+            }
+        } else {
+            out.push(c);
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn large_payloads_pass_through_without_blocking() {
+        let input = "x".repeat(1 << 20);
+        let output = run("cat", &input).await.unwrap();
+        assert_eq!(output.len(), input.len());
+    }
+
+    #[test]
+    fn strips_color_codes() {
+        assert_eq!(
+            strip_escapes("\x1b[1;39m{\x1b[0m\n  \x1b[34;1m\"a\"\x1b[0m: 1\n}"),
+            "{\n  \"a\": 1\n}"
+        );
+    }
+}
@@ -0,0 +1,19 @@
+// This file is @generated by prost-build.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct OperationOptions {
+    /// Nexus operation name (defaults to proto method name).
+    #[prost(string, tag = "1")]
+    pub name: ::prost::alloc::string::String,
+    /// Tags to attach to the operation. Used by code generators to include and exclude operations.
+    #[prost(string, repeated, tag = "2")]
+    pub tags: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ServiceOptions {
+    /// Nexus service name (defaults to proto service full name).
+    #[prost(string, tag = "1")]
+    pub name: ::prost::alloc::string::String,
+    /// Tags to attach to the service. Used by code generators to include and exclude services.
+    #[prost(string, repeated, tag = "2")]
+    pub tags: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+}
@@ -27,7 +27,7 @@ pub struct ActivityOptions {
     /// (-- api-linter: core::0140::prepositions=disabled
     ///      aip.dev/not-precedent: "to" is used to indicate interval. --)
     #[prost(message, optional, tag = "2")]
-    pub schedule_to_close_timeout: ::core::option::Option<::prost_types::Duration>,
+    pub schedule_to_close_timeout: ::core::option::Option<::pbjson_types::Duration>,
     /// Limits time an activity task can stay in a task queue before a worker picks it up. This
     /// timeout is always non retryable, as all a retry would achieve is to put it back into the same
     /// queue. Defaults to `schedule_to_close_timeout` or workflow execution timeout if not
@@ -36,7 +36,7 @@ pub struct ActivityOptions {
     /// (-- api-linter: core::0140::prepositions=disabled
     ///      aip.dev/not-precedent: "to" is used to indicate interval. --)
     #[prost(message, optional, tag = "3")]
-    pub schedule_to_start_timeout: ::core::option::Option<::prost_types::Duration>,
+    pub schedule_to_start_timeout: ::core::option::Option<::pbjson_types::Duration>,
     /// Maximum time an activity is allowed to execute after being picked up by a worker. This
     /// timeout is always retryable. Either this or `schedule_to_close_timeout` must be
     /// specified.
@@ -44,10 +44,10 @@ pub struct ActivityOptions {
     /// (-- api-linter: core::0140::prepositions=disabled
     ///      aip.dev/not-precedent: "to" is used to indicate interval. --)
     #[prost(message, optional, tag = "4")]
-    pub start_to_close_timeout: ::core::option::Option<::prost_types::Duration>,
+    pub start_to_close_timeout: ::core::option::Option<::pbjson_types::Duration>,
     /// Maximum permitted time between successful worker heartbeats.
     #[prost(message, optional, tag = "5")]
-    pub heartbeat_timeout: ::core::option::Option<::prost_types::Duration>,
+    pub heartbeat_timeout: ::core::option::Option<::pbjson_types::Duration>,
     /// The retry policy for the activity. Will never exceed `schedule_to_close_timeout`.
     #[prost(message, optional, tag = "6")]
     pub retry_policy: ::core::option::Option<super::super::common::v1::RetryPolicy>,
@@ -81,7 +81,7 @@ pub struct ActivityExecutionInfo {
     /// (-- api-linter: core::0140::prepositions=disabled
     ///      aip.dev/not-precedent: "to" is used to indicate interval. --)
     #[prost(message, optional, tag = "7")]
-    pub schedule_to_close_timeout: ::core::option::Option<::prost_types::Duration>,
+    pub schedule_to_close_timeout: ::core::option::Option<::pbjson_types::Duration>,
     /// Limits time an activity task can stay in a task queue before a worker picks it up. This
     /// timeout is always non retryable, as all a retry would achieve is to put it back into the same
     /// queue. Defaults to `schedule_to_close_timeout`.
@@ -89,45 +89,47 @@ pub struct ActivityExecutionInfo {
     /// (-- api-linter: core::0140::prepositions=disabled
     ///      aip.dev/not-precedent: "to" is used to indicate interval. --)
     #[prost(message, optional, tag = "8")]
-    pub schedule_to_start_timeout: ::core::option::Option<::prost_types::Duration>,
+    pub schedule_to_start_timeout: ::core::option::Option<::pbjson_types::Duration>,
     /// Maximum time a single activity attempt is allowed to execute after being picked up by a worker. This
     /// timeout is always retryable.
     ///
     /// (-- api-linter: core::0140::prepositions=disabled
     ///      aip.dev/not-precedent: "to" is used to indicate interval. --)
     #[prost(message, optional, tag = "9")]
-    pub start_to_close_timeout: ::core::option::Option<::prost_types::Duration>,
+    pub start_to_close_timeout: ::core::option::Option<::pbjson_types::Duration>,
     /// Maximum permitted time between successful worker heartbeats.
     #[prost(message, optional, tag = "10")]
-    pub heartbeat_timeout: ::core::option::Option<::prost_types::Duration>,
+    pub heartbeat_timeout: ::core::option::Option<::pbjson_types::Duration>,
     /// The retry policy for the activity. Will never exceed `schedule_to_close_timeout`.
     #[prost(message, optional, tag = "11")]
     pub retry_policy: ::core::option::Option<super::super::common::v1::RetryPolicy>,
     /// Details provided in the last recorded activity heartbeat.
+    /// DescribeActivityExecution does not set this field unless include_heartbeat_details was true in the request.
     #[prost(message, optional, tag = "12")]
     pub heartbeat_details: ::core::option::Option<super::super::common::v1::Payloads>,
     /// Time the last heartbeat was recorded.
     #[prost(message, optional, tag = "13")]
-    pub last_heartbeat_time: ::core::option::Option<::prost_types::Timestamp>,
+    pub last_heartbeat_time: ::core::option::Option<::pbjson_types::Timestamp>,
     /// Time the last attempt was started.
     #[prost(message, optional, tag = "14")]
-    pub last_started_time: ::core::option::Option<::prost_types::Timestamp>,
+    pub last_started_time: ::core::option::Option<::pbjson_types::Timestamp>,
     /// The attempt this activity is currently on. Incremented each time a new attempt is scheduled.
     #[prost(int32, tag = "15")]
     pub attempt: i32,
     /// How long this activity has been running for, including all attempts and backoff between attempts.
     #[prost(message, optional, tag = "16")]
-    pub execution_duration: ::core::option::Option<::prost_types::Duration>,
+    pub execution_duration: ::core::option::Option<::pbjson_types::Duration>,
     /// Time the activity was originally scheduled via a StartActivityExecution request.
     #[prost(message, optional, tag = "17")]
-    pub schedule_time: ::core::option::Option<::prost_types::Timestamp>,
+    pub schedule_time: ::core::option::Option<::pbjson_types::Timestamp>,
     /// Scheduled time + schedule to close timeout.
     #[prost(message, optional, tag = "18")]
-    pub expiration_time: ::core::option::Option<::prost_types::Timestamp>,
+    pub expiration_time: ::core::option::Option<::pbjson_types::Timestamp>,
     /// Time when the activity transitioned to a closed state.
     #[prost(message, optional, tag = "19")]
-    pub close_time: ::core::option::Option<::prost_types::Timestamp>,
+    pub close_time: ::core::option::Option<::pbjson_types::Timestamp>,
     /// Failure details from the last failed attempt.
+    /// DescribeActivityExecution does not set this field unless include_last_failure was true in the request.
     #[prost(message, optional, tag = "20")]
     pub last_failure: ::core::option::Option<super::super::failure::v1::Failure>,
     #[prost(string, tag = "21")]
@@ -139,14 +141,14 @@ pub struct ActivityExecutionInfo {
     /// This interval is typically calculated from the specified retry policy, but may be modified if an activity fails
     /// with a retryable application failure specifying a retry delay.
     #[prost(message, optional, tag = "22")]
-    pub current_retry_interval: ::core::option::Option<::prost_types::Duration>,
+    pub current_retry_interval: ::core::option::Option<::pbjson_types::Duration>,
     /// The time when the last activity attempt completed. If activity has not been completed yet, it will be null.
     #[prost(message, optional, tag = "23")]
-    pub last_attempt_complete_time: ::core::option::Option<::prost_types::Timestamp>,
+    pub last_attempt_complete_time: ::core::option::Option<::pbjson_types::Timestamp>,
     /// The time when the next activity attempt will be scheduled.
     /// If activity is currently scheduled or started, this field will be null.
     #[prost(message, optional, tag = "24")]
-    pub next_attempt_schedule_time: ::core::option::Option<::prost_types::Timestamp>,
+    pub next_attempt_schedule_time: ::core::option::Option<::pbjson_types::Timestamp>,
     /// The Worker Deployment Version this activity was dispatched to most recently.
     /// If nil, the activity has not yet been dispatched or was last dispatched to an unversioned worker.
     #[prost(message, optional, tag = "25")]
@@ -174,6 +176,23 @@ pub struct ActivityExecutionInfo {
     /// Set if activity cancelation was requested.
     #[prost(string, tag = "32")]
     pub canceled_reason: ::prost::alloc::string::String,
+    /// Links to related entities, such as the entity that started this activity.
+    #[prost(message, repeated, tag = "33")]
+    pub links: ::prost::alloc::vec::Vec<super::super::common::v1::Link>,
+    /// Total number of heartbeats recorded across all attempts of this activity, including retries.
+    #[prost(int64, tag = "34")]
+    pub total_heartbeat_count: i64,
+    /// The name of the SDK of the worker that most recently picked up an attempt of this activity.
+    /// Overwritten on each new attempt. Empty if unknown.
+    #[prost(string, tag = "35")]
+    pub sdk_name: ::prost::alloc::string::String,
+    /// The version of the SDK of the worker that most recently picked up an attempt of this activity.
+    /// Overwritten on each new attempt. Empty if unknown.
+    #[prost(string, tag = "36")]
+    pub sdk_version: ::prost::alloc::string::String,
+    /// Time to wait before dispatching the first activity task. This delay is not applied to retry attempts.
+    #[prost(message, optional, tag = "37")]
+    pub start_delay: ::core::option::Option<::pbjson_types::Duration>,
 }
 /// Limited activity information returned in the list response.
 /// When adding fields here, ensure that it is also present in ActivityExecutionInfo (note that it
@@ -191,10 +210,10 @@ pub struct ActivityExecutionListInfo {
     pub activity_type: ::core::option::Option<super::super::common::v1::ActivityType>,
     /// Time the activity was originally scheduled via a StartActivityExecution request.
     #[prost(message, optional, tag = "4")]
-    pub schedule_time: ::core::option::Option<::prost_types::Timestamp>,
+    pub schedule_time: ::core::option::Option<::pbjson_types::Timestamp>,
     /// If the activity is in a terminal status, this field represents the time the activity transitioned to that status.
     #[prost(message, optional, tag = "5")]
-    pub close_time: ::core::option::Option<::prost_types::Timestamp>,
+    pub close_time: ::core::option::Option<::pbjson_types::Timestamp>,
     /// Only scheduled and terminal statuses appear here. More detailed information in PendingActivityInfo but not
     /// available in the list response.
     #[prost(enumeration = "super::super::enums::v1::ActivityExecutionStatus", tag = "6")]
@@ -216,5 +235,34 @@ pub struct ActivityExecutionListInfo {
     /// The difference between close time and scheduled time.
     /// This field is only populated if the activity is closed.
     #[prost(message, optional, tag = "11")]
-    pub execution_duration: ::core::option::Option<::prost_types::Duration>,
+    pub execution_duration: ::core::option::Option<::pbjson_types::Duration>,
+}
+/// CallbackInfo contains the state of an attached activity callback.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct CallbackInfo {
+    /// Trigger for this callback.
+    #[prost(message, optional, tag = "1")]
+    pub trigger: ::core::option::Option<callback_info::Trigger>,
+    /// Common callback info.
+    #[prost(message, optional, tag = "2")]
+    pub info: ::core::option::Option<super::super::callback::v1::CallbackInfo>,
+}
+/// Nested message and enum types in `CallbackInfo`.
+pub mod callback_info {
+    /// Trigger for when the activity is closed.
+    #[derive(Clone, Copy, PartialEq, ::prost::Message)]
+    pub struct ActivityClosed {}
+    #[derive(Clone, Copy, PartialEq, ::prost::Message)]
+    pub struct Trigger {
+        #[prost(oneof = "trigger::Variant", tags = "1")]
+        pub variant: ::core::option::Option<trigger::Variant>,
+    }
+    /// Nested message and enum types in `Trigger`.
+    pub mod trigger {
+        #[derive(Clone, Copy, PartialEq, ::prost::Oneof)]
+        pub enum Variant {
+            #[prost(message, tag = "1")]
+            ActivityClosed(super::ActivityClosed),
+        }
+    }
 }
@@ -0,0 +1,1618 @@
+impl serde::Serialize for ActivityExecutionInfo {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.activity_id.is_empty() {
+            len += 1;
+        }
+        if !self.run_id.is_empty() {
+            len += 1;
+        }
+        if self.activity_type.is_some() {
+            len += 1;
+        }
+        if self.status != 0 {
+            len += 1;
+        }
+        if self.run_state != 0 {
+            len += 1;
+        }
+        if !self.task_queue.is_empty() {
+            len += 1;
+        }
+        if self.schedule_to_close_timeout.is_some() {
+            len += 1;
+        }
+        if self.schedule_to_start_timeout.is_some() {
+            len += 1;
+        }
+        if self.start_to_close_timeout.is_some() {
+            len += 1;
+        }
+        if self.heartbeat_timeout.is_some() {
+            len += 1;
+        }
+        if self.retry_policy.is_some() {
+            len += 1;
+        }
+        if self.heartbeat_details.is_some() {
+            len += 1;
+        }
+        if self.last_heartbeat_time.is_some() {
+            len += 1;
+        }
+        if self.last_started_time.is_some() {
+            len += 1;
+        }
+        if self.attempt != 0 {
+            len += 1;
+        }
+        if self.execution_duration.is_some() {
+            len += 1;
+        }
+        if self.schedule_time.is_some() {
+            len += 1;
+        }
+        if self.expiration_time.is_some() {
+            len += 1;
+        }
+        if self.close_time.is_some() {
+            len += 1;
+        }
+        if self.last_failure.is_some() {
+            len += 1;
+        }
+        if !self.last_worker_identity.is_empty() {
+            len += 1;
+        }
+        if self.current_retry_interval.is_some() {
+            len += 1;
+        }
+        if self.last_attempt_complete_time.is_some() {
+            len += 1;
+        }
+        if self.next_attempt_schedule_time.is_some() {
+            len += 1;
+        }
+        if self.last_deployment_version.is_some() {
+            len += 1;
+        }
+        if self.priority.is_some() {
+            len += 1;
+        }
+        if self.state_transition_count != 0 {
+            len += 1;
+        }
+        if self.state_size_bytes != 0 {
+            len += 1;
+        }
+        if self.search_attributes.is_some() {
+            len += 1;
+        }
+        if self.header.is_some() {
+            len += 1;
+        }
+        if self.user_metadata.is_some() {
+            len += 1;
+        }
+        if !self.canceled_reason.is_empty() {
+            len += 1;
+        }
+        if !self.links.is_empty() {
+            len += 1;
+        }
+        if self.total_heartbeat_count != 0 {
+            len += 1;
+        }
+        if !self.sdk_name.is_empty() {
+            len += 1;
+        }
+        if !self.sdk_version.is_empty() {
+            len += 1;
+        }
+        if self.start_delay.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("temporal.api.activity.v1.ActivityExecutionInfo", len)?;
+        if !self.activity_id.is_empty() {
+            struct_ser.serialize_field("activityId", &self.activity_id)?;
+        }
+        if !self.run_id.is_empty() {
+            struct_ser.serialize_field("runId", &self.run_id)?;
+        }
+        if let Some(v) = self.activity_type.as_ref() {
+            struct_ser.serialize_field("activityType", v)?;
+        }
+        if self.status != 0 {
+            let v = super::super::enums::v1::ActivityExecutionStatus::try_from(self.status)
+                .map_err(|_| serde::ser::Error::custom(format!("Invalid variant {}", self.status)))?;
+            struct_ser.serialize_field("status", &v)?;
+        }
+        if self.run_state != 0 {
+            let v = super::super::enums::v1::PendingActivityState::try_from(self.run_state)
+                .map_err(|_| serde::ser::Error::custom(format!("Invalid variant {}", self.run_state)))?;
+            struct_ser.serialize_field("runState", &v)?;
+        }
+        if !self.task_queue.is_empty() {
+            struct_ser.serialize_field("taskQueue", &self.task_queue)?;
+        }
+        if let Some(v) = self.schedule_to_close_timeout.as_ref() {
+            struct_ser.serialize_field("scheduleToCloseTimeout", v)?;
+        }
+        if let Some(v) = self.schedule_to_start_timeout.as_ref() {
+            struct_ser.serialize_field("scheduleToStartTimeout", v)?;
+        }
+        if let Some(v) = self.start_to_close_timeout.as_ref() {
+            struct_ser.serialize_field("startToCloseTimeout", v)?;
+        }
+        if let Some(v) = self.heartbeat_timeout.as_ref() {
+            struct_ser.serialize_field("heartbeatTimeout", v)?;
+        }
+        if let Some(v) = self.retry_policy.as_ref() {
+            struct_ser.serialize_field("retryPolicy", v)?;
+        }
+        if let Some(v) = self.heartbeat_details.as_ref() {
+            struct_ser.serialize_field("heartbeatDetails", v)?;
+        }
+        if let Some(v) = self.last_heartbeat_time.as_ref() {
+            struct_ser.serialize_field("lastHeartbeatTime", v)?;
+        }
+        if let Some(v) = self.last_started_time.as_ref() {
+            struct_ser.serialize_field("lastStartedTime", v)?;
+        }
+        if self.attempt != 0 {
+            struct_ser.serialize_field("attempt", &self.attempt)?;
+        }
+        if let Some(v) = self.execution_duration.as_ref() {
+            struct_ser.serialize_field("executionDuration", v)?;
+        }
+        if let Some(v) = self.schedule_time.as_ref() {
+            struct_ser.serialize_field("scheduleTime", v)?;
+        }
+        if let Some(v) = self.expiration_time.as_ref() {
+            struct_ser.serialize_field("expirationTime", v)?;
+        }
+        if let Some(v) = self.close_time.as_ref() {
+            struct_ser.serialize_field("closeTime", v)?;
+        }
+        if let Some(v) = self.last_failure.as_ref() {
+            struct_ser.serialize_field("lastFailure", v)?;
+        }
+        if !self.last_worker_identity.is_empty() {
+            struct_ser.serialize_field("lastWorkerIdentity", &self.last_worker_identity)?;
+        }
+        if let Some(v) = self.current_retry_interval.as_ref() {
+            struct_ser.serialize_field("currentRetryInterval", v)?;
+        }
+        if let Some(v) = self.last_attempt_complete_time.as_ref() {
+            struct_ser.serialize_field("lastAttemptCompleteTime", v)?;
+        }
+        if let Some(v) = self.next_attempt_schedule_time.as_ref() {
+            struct_ser.serialize_field("nextAttemptScheduleTime", v)?;
+        }
+        if let Some(v) = self.last_deployment_version.as_ref() {
+            struct_ser.serialize_field("lastDeploymentVersion", v)?;
+        }
+        if let Some(v) = self.priority.as_ref() {
+            struct_ser.serialize_field("priority", v)?;
+        }
+        if self.state_transition_count != 0 {
+            #[allow(clippy::needless_borrow)]
+            #[allow(clippy::needless_borrows_for_generic_args)]
+            struct_ser.serialize_field("stateTransitionCount", ToString::to_string(&self.state_transition_count).as_str())?;
+        }
+        if self.state_size_bytes != 0 {
+            #[allow(clippy::needless_borrow)]
+            #[allow(clippy::needless_borrows_for_generic_args)]
+            struct_ser.serialize_field("stateSizeBytes", ToString::to_string(&self.state_size_bytes).as_str())?;
+        }
+        if let Some(v) = self.search_attributes.as_ref() {
+            struct_ser.serialize_field("searchAttributes", v)?;
+        }
+        if let Some(v) = self.header.as_ref() {
+            struct_ser.serialize_field("header", v)?;
+        }
+        if let Some(v) = self.user_metadata.as_ref() {
+            struct_ser.serialize_field("userMetadata", v)?;
+        }
+        if !self.canceled_reason.is_empty() {
+            struct_ser.serialize_field("canceledReason", &self.canceled_reason)?;
+        }
+        if !self.links.is_empty() {
+            struct_ser.serialize_field("links", &self.links)?;
+        }
+        if self.total_heartbeat_count != 0 {
+            #[allow(clippy::needless_borrow)]
+            #[allow(clippy::needless_borrows_for_generic_args)]
+            struct_ser.serialize_field("totalHeartbeatCount", ToString::to_string(&self.total_heartbeat_count).as_str())?;
+        }
+        if !self.sdk_name.is_empty() {
+            struct_ser.serialize_field("sdkName", &self.sdk_name)?;
+        }
+        if !self.sdk_version.is_empty() {
+            struct_ser.serialize_field("sdkVersion", &self.sdk_version)?;
+        }
+        if let Some(v) = self.start_delay.as_ref() {
+            struct_ser.serialize_field("startDelay", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for ActivityExecutionInfo {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "activity_id",
+            "activityId",
+            "run_id",
+            "runId",
+            "activity_type",
+            "activityType",
+            "status",
+            "run_state",
+            "runState",
+            "task_queue",
+            "taskQueue",
+            "schedule_to_close_timeout",
+            "scheduleToCloseTimeout",
+            "schedule_to_start_timeout",
+            "scheduleToStartTimeout",
+            "start_to_close_timeout",
+            "startToCloseTimeout",
+            "heartbeat_timeout",
+            "heartbeatTimeout",
+            "retry_policy",
+            "retryPolicy",
+            "heartbeat_details",
+            "heartbeatDetails",
+            "last_heartbeat_time",
+            "lastHeartbeatTime",
+            "last_started_time",
+            "lastStartedTime",
+            "attempt",
+            "execution_duration",
+            "executionDuration",
+            "schedule_time",
+            "scheduleTime",
+            "expiration_time",
+            "expirationTime",
+            "close_time",
+            "closeTime",
+            "last_failure",
+            "lastFailure",
+            "last_worker_identity",
+            "lastWorkerIdentity",
+            "current_retry_interval",
+            "currentRetryInterval",
+            "last_attempt_complete_time",
+            "lastAttemptCompleteTime",
+            "next_attempt_schedule_time",
+            "nextAttemptScheduleTime",
+            "last_deployment_version",
+            "lastDeploymentVersion",
+            "priority",
+            "state_transition_count",
+            "stateTransitionCount",
+            "state_size_bytes",
+            "stateSizeBytes",
+            "search_attributes",
+            "searchAttributes",
+            "header",
+            "user_metadata",
+            "userMetadata",
+            "canceled_reason",
+            "canceledReason",
+            "links",
+            "total_heartbeat_count",
+            "totalHeartbeatCount",
+            "sdk_name",
+            "sdkName",
+            "sdk_version",
+            "sdkVersion",
+            "start_delay",
+            "startDelay",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            ActivityId,
+            RunId,
+            ActivityType,
+            Status,
+            RunState,
+            TaskQueue,
+            ScheduleToCloseTimeout,
+            ScheduleToStartTimeout,
+            StartToCloseTimeout,
+            HeartbeatTimeout,
+            RetryPolicy,
+            HeartbeatDetails,
+            LastHeartbeatTime,
+            LastStartedTime,
+            Attempt,
+            ExecutionDuration,
+            ScheduleTime,
+            ExpirationTime,
+            CloseTime,
+            LastFailure,
+            LastWorkerIdentity,
+            CurrentRetryInterval,
+            LastAttemptCompleteTime,
+            NextAttemptScheduleTime,
+            LastDeploymentVersion,
+            Priority,
+            StateTransitionCount,
+            StateSizeBytes,
+            SearchAttributes,
+            Header,
+            UserMetadata,
+            CanceledReason,
+            Links,
+            TotalHeartbeatCount,
+            SdkName,
+            SdkVersion,
+            StartDelay,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "activityId" | "activity_id" => Ok(GeneratedField::ActivityId),
+                            "runId" | "run_id" => Ok(GeneratedField::RunId),
+                            "activityType" | "activity_type" => Ok(GeneratedField::ActivityType),
+                            "status" => Ok(GeneratedField::Status),
+                            "runState" | "run_state" => Ok(GeneratedField::RunState),
+                            "taskQueue" | "task_queue" => Ok(GeneratedField::TaskQueue),
+                            "scheduleToCloseTimeout" | "schedule_to_close_timeout" => Ok(GeneratedField::ScheduleToCloseTimeout),
+                            "scheduleToStartTimeout" | "schedule_to_start_timeout" => Ok(GeneratedField::ScheduleToStartTimeout),
+                            "startToCloseTimeout" | "start_to_close_timeout" => Ok(GeneratedField::StartToCloseTimeout),
+                            "heartbeatTimeout" | "heartbeat_timeout" => Ok(GeneratedField::HeartbeatTimeout),
+                            "retryPolicy" | "retry_policy" => Ok(GeneratedField::RetryPolicy),
+                            "heartbeatDetails" | "heartbeat_details" => Ok(GeneratedField::HeartbeatDetails),
+                            "lastHeartbeatTime" | "last_heartbeat_time" => Ok(GeneratedField::LastHeartbeatTime),
+                            "lastStartedTime" | "last_started_time" => Ok(GeneratedField::LastStartedTime),
+                            "attempt" => Ok(GeneratedField::Attempt),
+                            "executionDuration" | "execution_duration" => Ok(GeneratedField::ExecutionDuration),
+                            "scheduleTime" | "schedule_time" => Ok(GeneratedField::ScheduleTime),
+                            "expirationTime" | "expiration_time" => Ok(GeneratedField::ExpirationTime),
+                            "closeTime" | "close_time" => Ok(GeneratedField::CloseTime),
+                            "lastFailure" | "last_failure" => Ok(GeneratedField::LastFailure),
+                            "lastWorkerIdentity" | "last_worker_identity" => Ok(GeneratedField::LastWorkerIdentity),
+                            "currentRetryInterval" | "current_retry_interval" => Ok(GeneratedField::CurrentRetryInterval),
+                            "lastAttemptCompleteTime" | "last_attempt_complete_time" => Ok(GeneratedField::LastAttemptCompleteTime),
+                            "nextAttemptScheduleTime" | "next_attempt_schedule_time" => Ok(GeneratedField::NextAttemptScheduleTime),
+                            "lastDeploymentVersion" | "last_deployment_version" => Ok(GeneratedField::LastDeploymentVersion),
+                            "priority" => Ok(GeneratedField::Priority),
+                            "stateTransitionCount" | "state_transition_count" => Ok(GeneratedField::StateTransitionCount),
+                            "stateSizeBytes" | "state_size_bytes" => Ok(GeneratedField::StateSizeBytes),
+                            "searchAttributes" | "search_attributes" => Ok(GeneratedField::SearchAttributes),
+                            "header" => Ok(GeneratedField::Header),
+                            "userMetadata" | "user_metadata" => Ok(GeneratedField::UserMetadata),
+                            "canceledReason" | "canceled_reason" => Ok(GeneratedField::CanceledReason),
+                            "links" => Ok(GeneratedField::Links),
+                            "totalHeartbeatCount" | "total_heartbeat_count" => Ok(GeneratedField::TotalHeartbeatCount),
+                            "sdkName" | "sdk_name" => Ok(GeneratedField::SdkName),
+                            "sdkVersion" | "sdk_version" => Ok(GeneratedField::SdkVersion),
+                            "startDelay" | "start_delay" => Ok(GeneratedField::StartDelay),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = ActivityExecutionInfo;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct temporal.api.activity.v1.ActivityExecutionInfo")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<ActivityExecutionInfo, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut activity_id__ = None;
+                let mut run_id__ = None;
+                let mut activity_type__ = None;
+                let mut status__ = None;
+                let mut run_state__ = None;
+                let mut task_queue__ = None;
+                let mut schedule_to_close_timeout__ = None;
+                let mut schedule_to_start_timeout__ = None;
+                let mut start_to_close_timeout__ = None;
+                let mut heartbeat_timeout__ = None;
+                let mut retry_policy__ = None;
+                let mut heartbeat_details__ = None;
+                let mut last_heartbeat_time__ = None;
+                let mut last_started_time__ = None;
+                let mut attempt__ = None;
+                let mut execution_duration__ = None;
+                let mut schedule_time__ = None;
+                let mut expiration_time__ = None;
+                let mut close_time__ = None;
+                let mut last_failure__ = None;
+                let mut last_worker_identity__ = None;
+                let mut current_retry_interval__ = None;
+                let mut last_attempt_complete_time__ = None;
+                let mut next_attempt_schedule_time__ = None;
+                let mut last_deployment_version__ = None;
+                let mut priority__ = None;
+                let mut state_transition_count__ = None;
+                let mut state_size_bytes__ = None;
+                let mut search_attributes__ = None;
+                let mut header__ = None;
+                let mut user_metadata__ = None;
+                let mut canceled_reason__ = None;
+                let mut links__ = None;
+                let mut total_heartbeat_count__ = None;
+                let mut sdk_name__ = None;
+                let mut sdk_version__ = None;
+                let mut start_delay__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::ActivityId => {
+                            if activity_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("activityId"));
+                            }
+                            activity_id__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::RunId => {
+                            if run_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("runId"));
+                            }
+                            run_id__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::ActivityType => {
+                            if activity_type__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("activityType"));
+                            }
+                            activity_type__ = map_.next_value()?;
+                        }
+                        GeneratedField::Status => {
+                            if status__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("status"));
+                            }
+                            status__ = Some(map_.next_value::<super::super::enums::v1::ActivityExecutionStatus>()? as i32);
+                        }
+                        GeneratedField::RunState => {
+                            if run_state__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("runState"));
+                            }
+                            run_state__ = Some(map_.next_value::<super::super::enums::v1::PendingActivityState>()? as i32);
+                        }
+                        GeneratedField::TaskQueue => {
+                            if task_queue__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("taskQueue"));
+                            }
+                            task_queue__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::ScheduleToCloseTimeout => {
+                            if schedule_to_close_timeout__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("scheduleToCloseTimeout"));
+                            }
+                            schedule_to_close_timeout__ = map_.next_value()?;
+                        }
+                        GeneratedField::ScheduleToStartTimeout => {
+                            if schedule_to_start_timeout__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("scheduleToStartTimeout"));
+                            }
+                            schedule_to_start_timeout__ = map_.next_value()?;
+                        }
+                        GeneratedField::StartToCloseTimeout => {
+                            if start_to_close_timeout__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("startToCloseTimeout"));
+                            }
+                            start_to_close_timeout__ = map_.next_value()?;
+                        }
+                        GeneratedField::HeartbeatTimeout => {
+                            if heartbeat_timeout__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("heartbeatTimeout"));
+                            }
+                            heartbeat_timeout__ = map_.next_value()?;
+                        }
+                        GeneratedField::RetryPolicy => {
+                            if retry_policy__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("retryPolicy"));
+                            }
+                            retry_policy__ = map_.next_value()?;
+                        }
+                        GeneratedField::HeartbeatDetails => {
+                            if heartbeat_details__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("heartbeatDetails"));
+                            }
+                            heartbeat_details__ = map_.next_value()?;
+                        }
+                        GeneratedField::LastHeartbeatTime => {
+                            if last_heartbeat_time__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("lastHeartbeatTime"));
+                            }
+                            last_heartbeat_time__ = map_.next_value()?;
+                        }
+                        GeneratedField::LastStartedTime => {
+                            if last_started_time__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("lastStartedTime"));
+                            }
+                            last_started_time__ = map_.next_value()?;
+                        }
+                        GeneratedField::Attempt => {
+                            if attempt__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("attempt"));
+                            }
+                            attempt__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::ExecutionDuration => {
+                            if execution_duration__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("executionDuration"));
+                            }
+                            execution_duration__ = map_.next_value()?;
+                        }
+                        GeneratedField::ScheduleTime => {
+                            if schedule_time__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("scheduleTime"));
+                            }
+                            schedule_time__ = map_.next_value()?;
+                        }
+                        GeneratedField::ExpirationTime => {
+                            if expiration_time__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("expirationTime"));
+                            }
+                            expiration_time__ = map_.next_value()?;
+                        }
+                        GeneratedField::CloseTime => {
+                            if close_time__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("closeTime"));
+                            }
+                            close_time__ = map_.next_value()?;
+                        }
+                        GeneratedField::LastFailure => {
+                            if last_failure__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("lastFailure"));
+                            }
+                            last_failure__ = map_.next_value()?;
+                        }
+                        GeneratedField::LastWorkerIdentity => {
+                            if last_worker_identity__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("lastWorkerIdentity"));
+                            }
+                            last_worker_identity__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::CurrentRetryInterval => {
+                            if current_retry_interval__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("currentRetryInterval"));
+                            }
+                            current_retry_interval__ = map_.next_value()?;
+                        }
+                        GeneratedField::LastAttemptCompleteTime => {
+                            if last_attempt_complete_time__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("lastAttemptCompleteTime"));
+                            }
+                            last_attempt_complete_time__ = map_.next_value()?;
+                        }
+                        GeneratedField::NextAttemptScheduleTime => {
+                            if next_attempt_schedule_time__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("nextAttemptScheduleTime"));
+                            }
+                            next_attempt_schedule_time__ = map_.next_value()?;
+                        }
+                        GeneratedField::LastDeploymentVersion => {
+                            if last_deployment_version__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("lastDeploymentVersion"));
+                            }
+                            last_deployment_version__ = map_.next_value()?;
+                        }
+                        GeneratedField::Priority => {
+                            if priority__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("priority"));
+                            }
+                            priority__ = map_.next_value()?;
+                        }
+                        GeneratedField::StateTransitionCount => {
+                            if state_transition_count__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("stateTransitionCount"));
+                            }
+                            state_transition_count__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::StateSizeBytes => {
+                            if state_size_bytes__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("stateSizeBytes"));
+                            }
+                            state_size_bytes__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::SearchAttributes => {
+                            if search_attributes__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("searchAttributes"));
+                            }
+                            search_attributes__ = map_.next_value()?;
+                        }
+                        GeneratedField::Header => {
+                            if header__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("header"));
+                            }
+                            header__ = map_.next_value()?;
+                        }
+                        GeneratedField::UserMetadata => {
+                            if user_metadata__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("userMetadata"));
+                            }
+                            user_metadata__ = map_.next_value()?;
+                        }
+                        GeneratedField::CanceledReason => {
+                            if canceled_reason__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("canceledReason"));
+                            }
+                            canceled_reason__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::Links => {
+                            if links__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("links"));
+                            }
+                            links__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::TotalHeartbeatCount => {
+                            if total_heartbeat_count__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("totalHeartbeatCount"));
+                            }
+                            total_heartbeat_count__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::SdkName => {
+                            if sdk_name__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("sdkName"));
+                            }
+                            sdk_name__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::SdkVersion => {
+                            if sdk_version__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("sdkVersion"));
+                            }
+                            sdk_version__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::StartDelay => {
+                            if start_delay__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("startDelay"));
+                            }
+                            start_delay__ = map_.next_value()?;
+                        }
+                    }
+                }
+                Ok(ActivityExecutionInfo {
+                    activity_id: activity_id__.unwrap_or_default(),
+                    run_id: run_id__.unwrap_or_default(),
+                    activity_type: activity_type__,
+                    status: status__.unwrap_or_default(),
+                    run_state: run_state__.unwrap_or_default(),
+                    task_queue: task_queue__.unwrap_or_default(),
+                    schedule_to_close_timeout: schedule_to_close_timeout__,
+                    schedule_to_start_timeout: schedule_to_start_timeout__,
+                    start_to_close_timeout: start_to_close_timeout__,
+                    heartbeat_timeout: heartbeat_timeout__,
+                    retry_policy: retry_policy__,
+                    heartbeat_details: heartbeat_details__,
+                    last_heartbeat_time: last_heartbeat_time__,
+                    last_started_time: last_started_time__,
+                    attempt: attempt__.unwrap_or_default(),
+                    execution_duration: execution_duration__,
+                    schedule_time: schedule_time__,
+                    expiration_time: expiration_time__,
+                    close_time: close_time__,
+                    last_failure: last_failure__,
+                    last_worker_identity: last_worker_identity__.unwrap_or_default(),
+                    current_retry_interval: current_retry_interval__,
+                    last_attempt_complete_time: last_attempt_complete_time__,
+                    next_attempt_schedule_time: next_attempt_schedule_time__,
+                    last_deployment_version: last_deployment_version__,
+                    priority: priority__,
+                    state_transition_count: state_transition_count__.unwrap_or_default(),
+                    state_size_bytes: state_size_bytes__.unwrap_or_default(),
+                    search_attributes: search_attributes__,
+                    header: header__,
+                    user_metadata: user_metadata__,
+                    canceled_reason: canceled_reason__.unwrap_or_default(),
+                    links: links__.unwrap_or_default(),
+                    total_heartbeat_count: total_heartbeat_count__.unwrap_or_default(),
+                    sdk_name: sdk_name__.unwrap_or_default(),
+                    sdk_version: sdk_version__.unwrap_or_default(),
+                    start_delay: start_delay__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("temporal.api.activity.v1.ActivityExecutionInfo", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for ActivityExecutionListInfo {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.activity_id.is_empty() {
+            len += 1;
+        }
+        if !self.run_id.is_empty() {
+            len += 1;
+        }
+        if self.activity_type.is_some() {
+            len += 1;
+        }
+        if self.schedule_time.is_some() {
+            len += 1;
+        }
+        if self.close_time.is_some() {
+            len += 1;
+        }
+        if self.status != 0 {
+            len += 1;
+        }
+        if self.search_attributes.is_some() {
+            len += 1;
+        }
+        if !self.task_queue.is_empty() {
+            len += 1;
+        }
+        if self.state_transition_count != 0 {
+            len += 1;
+        }
+        if self.state_size_bytes != 0 {
+            len += 1;
+        }
+        if self.execution_duration.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("temporal.api.activity.v1.ActivityExecutionListInfo", len)?;
+        if !self.activity_id.is_empty() {
+            struct_ser.serialize_field("activityId", &self.activity_id)?;
+        }
+        if !self.run_id.is_empty() {
+            struct_ser.serialize_field("runId", &self.run_id)?;
+        }
+        if let Some(v) = self.activity_type.as_ref() {
+            struct_ser.serialize_field("activityType", v)?;
+        }
+        if let Some(v) = self.schedule_time.as_ref() {
+            struct_ser.serialize_field("scheduleTime", v)?;
+        }
+        if let Some(v) = self.close_time.as_ref() {
+            struct_ser.serialize_field("closeTime", v)?;
+        }
+        if self.status != 0 {
+            let v = super::super::enums::v1::ActivityExecutionStatus::try_from(self.status)
+                .map_err(|_| serde::ser::Error::custom(format!("Invalid variant {}", self.status)))?;
+            struct_ser.serialize_field("status", &v)?;
+        }
+        if let Some(v) = self.search_attributes.as_ref() {
+            struct_ser.serialize_field("searchAttributes", v)?;
+        }
+        if !self.task_queue.is_empty() {
+            struct_ser.serialize_field("taskQueue", &self.task_queue)?;
+        }
+        if self.state_transition_count != 0 {
+            #[allow(clippy::needless_borrow)]
+            #[allow(clippy::needless_borrows_for_generic_args)]
+            struct_ser.serialize_field("stateTransitionCount", ToString::to_string(&self.state_transition_count).as_str())?;
+        }
+        if self.state_size_bytes != 0 {
+            #[allow(clippy::needless_borrow)]
+            #[allow(clippy::needless_borrows_for_generic_args)]
+            struct_ser.serialize_field("stateSizeBytes", ToString::to_string(&self.state_size_bytes).as_str())?;
+        }
+        if let Some(v) = self.execution_duration.as_ref() {
+            struct_ser.serialize_field("executionDuration", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for ActivityExecutionListInfo {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "activity_id",
+            "activityId",
+            "run_id",
+            "runId",
+            "activity_type",
+            "activityType",
+            "schedule_time",
+            "scheduleTime",
+            "close_time",
+            "closeTime",
+            "status",
+            "search_attributes",
+            "searchAttributes",
+            "task_queue",
+            "taskQueue",
+            "state_transition_count",
+            "stateTransitionCount",
+            "state_size_bytes",
+            "stateSizeBytes",
+            "execution_duration",
+            "executionDuration",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            ActivityId,
+            RunId,
+            ActivityType,
+            ScheduleTime,
+            CloseTime,
+            Status,
+            SearchAttributes,
+            TaskQueue,
+            StateTransitionCount,
+            StateSizeBytes,
+            ExecutionDuration,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "activityId" | "activity_id" => Ok(GeneratedField::ActivityId),
+                            "runId" | "run_id" => Ok(GeneratedField::RunId),
+                            "activityType" | "activity_type" => Ok(GeneratedField::ActivityType),
+                            "scheduleTime" | "schedule_time" => Ok(GeneratedField::ScheduleTime),
+                            "closeTime" | "close_time" => Ok(GeneratedField::CloseTime),
+                            "status" => Ok(GeneratedField::Status),
+                            "searchAttributes" | "search_attributes" => Ok(GeneratedField::SearchAttributes),
+                            "taskQueue" | "task_queue" => Ok(GeneratedField::TaskQueue),
+                            "stateTransitionCount" | "state_transition_count" => Ok(GeneratedField::StateTransitionCount),
+                            "stateSizeBytes" | "state_size_bytes" => Ok(GeneratedField::StateSizeBytes),
+                            "executionDuration" | "execution_duration" => Ok(GeneratedField::ExecutionDuration),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = ActivityExecutionListInfo;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct temporal.api.activity.v1.ActivityExecutionListInfo")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<ActivityExecutionListInfo, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut activity_id__ = None;
+                let mut run_id__ = None;
+                let mut activity_type__ = None;
+                let mut schedule_time__ = None;
+                let mut close_time__ = None;
+                let mut status__ = None;
+                let mut search_attributes__ = None;
+                let mut task_queue__ = None;
+                let mut state_transition_count__ = None;
+                let mut state_size_bytes__ = None;
+                let mut execution_duration__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::ActivityId => {
+                            if activity_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("activityId"));
+                            }
+                            activity_id__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::RunId => {
+                            if run_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("runId"));
+                            }
+                            run_id__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::ActivityType => {
+                            if activity_type__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("activityType"));
+                            }
+                            activity_type__ = map_.next_value()?;
+                        }
+                        GeneratedField::ScheduleTime => {
+                            if schedule_time__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("scheduleTime"));
+                            }
+                            schedule_time__ = map_.next_value()?;
+                        }
+                        GeneratedField::CloseTime => {
+                            if close_time__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("closeTime"));
+                            }
+                            close_time__ = map_.next_value()?;
+                        }
+                        GeneratedField::Status => {
+                            if status__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("status"));
+                            }
+                            status__ = Some(map_.next_value::<super::super::enums::v1::ActivityExecutionStatus>()? as i32);
+                        }
+                        GeneratedField::SearchAttributes => {
+                            if search_attributes__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("searchAttributes"));
+                            }
+                            search_attributes__ = map_.next_value()?;
+                        }
+                        GeneratedField::TaskQueue => {
+                            if task_queue__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("taskQueue"));
+                            }
+                            task_queue__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::StateTransitionCount => {
+                            if state_transition_count__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("stateTransitionCount"));
+                            }
+                            state_transition_count__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::StateSizeBytes => {
+                            if state_size_bytes__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("stateSizeBytes"));
+                            }
+                            state_size_bytes__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::ExecutionDuration => {
+                            if execution_duration__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("executionDuration"));
+                            }
+                            execution_duration__ = map_.next_value()?;
+                        }
+                    }
+                }
+                Ok(ActivityExecutionListInfo {
+                    activity_id: activity_id__.unwrap_or_default(),
+                    run_id: run_id__.unwrap_or_default(),
+                    activity_type: activity_type__,
+                    schedule_time: schedule_time__,
+                    close_time: close_time__,
+                    status: status__.unwrap_or_default(),
+                    search_attributes: search_attributes__,
+                    task_queue: task_queue__.unwrap_or_default(),
+                    state_transition_count: state_transition_count__.unwrap_or_default(),
+                    state_size_bytes: state_size_bytes__.unwrap_or_default(),
+                    execution_duration: execution_duration__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("temporal.api.activity.v1.ActivityExecutionListInfo", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for ActivityExecutionOutcome {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.value.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("temporal.api.activity.v1.ActivityExecutionOutcome", len)?;
+        if let Some(v) = self.value.as_ref() {
+            match v {
+                activity_execution_outcome::Value::Result(v) => {
+                    struct_ser.serialize_field("result", v)?;
+                }
+                activity_execution_outcome::Value::Failure(v) => {
+                    struct_ser.serialize_field("failure", v)?;
+                }
+            }
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for ActivityExecutionOutcome {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "result",
+            "failure",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Result,
+            Failure,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "result" => Ok(GeneratedField::Result),
+                            "failure" => Ok(GeneratedField::Failure),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = ActivityExecutionOutcome;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct temporal.api.activity.v1.ActivityExecutionOutcome")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<ActivityExecutionOutcome, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut value__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Result => {
+                            if value__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("result"));
+                            }
+                            value__ = map_.next_value::<::std::option::Option<_>>()?.map(activity_execution_outcome::Value::Result)
+;
+                        }
+                        GeneratedField::Failure => {
+                            if value__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("failure"));
+                            }
+                            value__ = map_.next_value::<::std::option::Option<_>>()?.map(activity_execution_outcome::Value::Failure)
+;
+                        }
+                    }
+                }
+                Ok(ActivityExecutionOutcome {
+                    value: value__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("temporal.api.activity.v1.ActivityExecutionOutcome", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for ActivityOptions {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.task_queue.is_some() {
+            len += 1;
+        }
+        if self.schedule_to_close_timeout.is_some() {
+            len += 1;
+        }
+        if self.schedule_to_start_timeout.is_some() {
+            len += 1;
+        }
+        if self.start_to_close_timeout.is_some() {
+            len += 1;
+        }
+        if self.heartbeat_timeout.is_some() {
+            len += 1;
+        }
+        if self.retry_policy.is_some() {
+            len += 1;
+        }
+        if self.priority.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("temporal.api.activity.v1.ActivityOptions", len)?;
+        if let Some(v) = self.task_queue.as_ref() {
+            struct_ser.serialize_field("taskQueue", v)?;
+        }
+        if let Some(v) = self.schedule_to_close_timeout.as_ref() {
+            struct_ser.serialize_field("scheduleToCloseTimeout", v)?;
+        }
+        if let Some(v) = self.schedule_to_start_timeout.as_ref() {
+            struct_ser.serialize_field("scheduleToStartTimeout", v)?;
+        }
+        if let Some(v) = self.start_to_close_timeout.as_ref() {
+            struct_ser.serialize_field("startToCloseTimeout", v)?;
+        }
+        if let Some(v) = self.heartbeat_timeout.as_ref() {
+            struct_ser.serialize_field("heartbeatTimeout", v)?;
+        }
+        if let Some(v) = self.retry_policy.as_ref() {
+            struct_ser.serialize_field("retryPolicy", v)?;
+        }
+        if let Some(v) = self.priority.as_ref() {
+            struct_ser.serialize_field("priority", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for ActivityOptions {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "task_queue",
+            "taskQueue",
+            "schedule_to_close_timeout",
+            "scheduleToCloseTimeout",
+            "schedule_to_start_timeout",
+            "scheduleToStartTimeout",
+            "start_to_close_timeout",
+            "startToCloseTimeout",
+            "heartbeat_timeout",
+            "heartbeatTimeout",
+            "retry_policy",
+            "retryPolicy",
+            "priority",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            TaskQueue,
+            ScheduleToCloseTimeout,
+            ScheduleToStartTimeout,
+            StartToCloseTimeout,
+            HeartbeatTimeout,
+            RetryPolicy,
+            Priority,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "taskQueue" | "task_queue" => Ok(GeneratedField::TaskQueue),
+                            "scheduleToCloseTimeout" | "schedule_to_close_timeout" => Ok(GeneratedField::ScheduleToCloseTimeout),
+                            "scheduleToStartTimeout" | "schedule_to_start_timeout" => Ok(GeneratedField::ScheduleToStartTimeout),
+                            "startToCloseTimeout" | "start_to_close_timeout" => Ok(GeneratedField::StartToCloseTimeout),
+                            "heartbeatTimeout" | "heartbeat_timeout" => Ok(GeneratedField::HeartbeatTimeout),
+                            "retryPolicy" | "retry_policy" => Ok(GeneratedField::RetryPolicy),
+                            "priority" => Ok(GeneratedField::Priority),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = ActivityOptions;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct temporal.api.activity.v1.ActivityOptions")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<ActivityOptions, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut task_queue__ = None;
+                let mut schedule_to_close_timeout__ = None;
+                let mut schedule_to_start_timeout__ = None;
+                let mut start_to_close_timeout__ = None;
+                let mut heartbeat_timeout__ = None;
+                let mut retry_policy__ = None;
+                let mut priority__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::TaskQueue => {
+                            if task_queue__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("taskQueue"));
+                            }
+                            task_queue__ = map_.next_value()?;
+                        }
+                        GeneratedField::ScheduleToCloseTimeout => {
+                            if schedule_to_close_timeout__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("scheduleToCloseTimeout"));
+                            }
+                            schedule_to_close_timeout__ = map_.next_value()?;
+                        }
+                        GeneratedField::ScheduleToStartTimeout => {
+                            if schedule_to_start_timeout__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("scheduleToStartTimeout"));
+                            }
+                            schedule_to_start_timeout__ = map_.next_value()?;
+                        }
+                        GeneratedField::StartToCloseTimeout => {
+                            if start_to_close_timeout__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("startToCloseTimeout"));
+                            }
+                            start_to_close_timeout__ = map_.next_value()?;
+                        }
+                        GeneratedField::HeartbeatTimeout => {
+                            if heartbeat_timeout__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("heartbeatTimeout"));
+                            }
+                            heartbeat_timeout__ = map_.next_value()?;
+                        }
+                        GeneratedField::RetryPolicy => {
+                            if retry_policy__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("retryPolicy"));
+                            }
+                            retry_policy__ = map_.next_value()?;
+                        }
+                        GeneratedField::Priority => {
+                            if priority__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("priority"));
+                            }
+                            priority__ = map_.next_value()?;
+                        }
+                    }
+                }
+                Ok(ActivityOptions {
+                    task_queue: task_queue__,
+                    schedule_to_close_timeout: schedule_to_close_timeout__,
+                    schedule_to_start_timeout: schedule_to_start_timeout__,
+                    start_to_close_timeout: start_to_close_timeout__,
+                    heartbeat_timeout: heartbeat_timeout__,
+                    retry_policy: retry_policy__,
+                    priority: priority__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("temporal.api.activity.v1.ActivityOptions", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for CallbackInfo {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.trigger.is_some() {
+            len += 1;
+        }
+        if self.info.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("temporal.api.activity.v1.CallbackInfo", len)?;
+        if let Some(v) = self.trigger.as_ref() {
+            struct_ser.serialize_field("trigger", v)?;
+        }
+        if let Some(v) = self.info.as_ref() {
+            struct_ser.serialize_field("info", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for CallbackInfo {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "trigger",
+            "info",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Trigger,
+            Info,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "trigger" => Ok(GeneratedField::Trigger),
+                            "info" => Ok(GeneratedField::Info),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = CallbackInfo;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct temporal.api.activity.v1.CallbackInfo")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<CallbackInfo, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut trigger__ = None;
+                let mut info__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Trigger => {
+                            if trigger__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("trigger"));
+                            }
+                            trigger__ = map_.next_value()?;
+                        }
+                        GeneratedField::Info => {
+                            if info__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("info"));
+                            }
+                            info__ = map_.next_value()?;
+                        }
+                    }
+                }
+                Ok(CallbackInfo {
+                    trigger: trigger__,
+                    info: info__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("temporal.api.activity.v1.CallbackInfo", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for callback_info::ActivityClosed {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let len = 0;
+        let struct_ser = serializer.serialize_struct("temporal.api.activity.v1.CallbackInfo.ActivityClosed", len)?;
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for callback_info::ActivityClosed {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                            Err(serde::de::Error::unknown_field(value, FIELDS))
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = callback_info::ActivityClosed;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct temporal.api.activity.v1.CallbackInfo.ActivityClosed")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<callback_info::ActivityClosed, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                while map_.next_key::<GeneratedField>()?.is_some() {
+                    let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                }
+                Ok(callback_info::ActivityClosed {
+                })
+            }
+        }
+        deserializer.deserialize_struct("temporal.api.activity.v1.CallbackInfo.ActivityClosed", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for callback_info::Trigger {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.variant.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("temporal.api.activity.v1.CallbackInfo.Trigger", len)?;
+        if let Some(v) = self.variant.as_ref() {
+            match v {
+                callback_info::trigger::Variant::ActivityClosed(v) => {
+                    struct_ser.serialize_field("activityClosed", v)?;
+                }
+            }
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for callback_info::Trigger {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "activity_closed",
+            "activityClosed",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            ActivityClosed,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "activityClosed" | "activity_closed" => Ok(GeneratedField::ActivityClosed),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = callback_info::Trigger;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct temporal.api.activity.v1.CallbackInfo.Trigger")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<callback_info::Trigger, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut variant__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::ActivityClosed => {
+                            if variant__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("activityClosed"));
+                            }
+                            variant__ = map_.next_value::<::std::option::Option<_>>()?.map(callback_info::trigger::Variant::ActivityClosed)
+;
+                        }
+                    }
+                }
+                Ok(callback_info::Trigger {
+                    variant: variant__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("temporal.api.activity.v1.CallbackInfo.Trigger", FIELDS, GeneratedVisitor)
+    }
+}
@@ -9,10 +9,10 @@ pub struct BatchOperationInfo {
     pub state: i32,
     /// Batch operation start time
     #[prost(message, optional, tag = "3")]
-    pub start_time: ::core::option::Option<::prost_types::Timestamp>,
+    pub start_time: ::core::option::Option<::pbjson_types::Timestamp>,
     /// Batch operation close time
     #[prost(message, optional, tag = "4")]
-    pub close_time: ::core::option::Option<::prost_types::Timestamp>,
+    pub close_time: ::core::option::Option<::pbjson_types::Timestamp>,
 }
 /// BatchOperationTermination sends terminate requests to batch workflows.
 /// Keep the parameter in sync with temporal.api.workflowservice.v1.TerminateWorkflowExecutionRequest.
@@ -102,7 +102,7 @@ pub struct BatchOperationUpdateWorkflowExecutionOptions {
     /// Controls which fields from `workflow_execution_options` will be applied.
     /// To unset a field, set it to null and use the update mask to indicate that it should be mutated.
     #[prost(message, optional, tag = "3")]
-    pub update_mask: ::core::option::Option<::prost_types::FieldMask>,
+    pub update_mask: ::core::option::Option<::pbjson_types::FieldMask>,
 }
 /// BatchOperationUnpauseActivities sends unpause requests to batch workflows.
 #[derive(Clone, PartialEq, ::prost::Message)]
@@ -119,7 +119,7 @@ pub struct BatchOperationUnpauseActivities {
     /// If set, the activity will start at a random time within the specified jitter
     /// duration, introducing variability to the start time.
     #[prost(message, optional, tag = "6")]
-    pub jitter: ::core::option::Option<::prost_types::Duration>,
+    pub jitter: ::core::option::Option<::pbjson_types::Duration>,
     /// The activity to unpause. If match_all is set to true, all activities will be unpaused.
     #[prost(oneof = "batch_operation_unpause_activities::Activity", tags = "2, 3")]
     pub activity: ::core::option::Option<batch_operation_unpause_activities::Activity>,
@@ -175,7 +175,7 @@ pub struct BatchOperationResetActivities {
     /// If set, the activity will start at a random time within the specified jitter
     /// duration, introducing variability to the start time.
     #[prost(message, optional, tag = "7")]
-    pub jitter: ::core::option::Option<::prost_types::Duration>,
+    pub jitter: ::core::option::Option<::pbjson_types::Duration>,
     /// If set, the activity options will be restored to the defaults.
     /// Default options are then options activity was created with.
     /// They are part of the first ActivityTaskScheduled event.
@@ -210,7 +210,7 @@ pub struct BatchOperationUpdateActivityOptions {
     >,
     /// Controls which fields from `activity_options` will be applied
     #[prost(message, optional, tag = "5")]
-    pub update_mask: ::core::option::Option<::prost_types::FieldMask>,
+    pub update_mask: ::core::option::Option<::pbjson_types::FieldMask>,
     /// If set, the activity options will be restored to the default.
     /// Default options are then options activity was created with.
     /// They are part of the first ActivityTaskScheduled event.
@@ -0,0 +1,1568 @@
+impl serde::Serialize for BatchOperationCancellation {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.identity.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("temporal.api.batch.v1.BatchOperationCancellation", len)?;
+        if !self.identity.is_empty() {
+            struct_ser.serialize_field("identity", &self.identity)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for BatchOperationCancellation {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "identity",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Identity,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "identity" => Ok(GeneratedField::Identity),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = BatchOperationCancellation;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct temporal.api.batch.v1.BatchOperationCancellation")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<BatchOperationCancellation, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut identity__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Identity => {
+                            if identity__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("identity"));
+                            }
+                            identity__ = Some(map_.next_value()?);
+                        }
+                    }
+                }
+                Ok(BatchOperationCancellation {
+                    identity: identity__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("temporal.api.batch.v1.BatchOperationCancellation", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for BatchOperationDeletion {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.identity.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("temporal.api.batch.v1.BatchOperationDeletion", len)?;
+        if !self.identity.is_empty() {
+            struct_ser.serialize_field("identity", &self.identity)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for BatchOperationDeletion {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "identity",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Identity,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "identity" => Ok(GeneratedField::Identity),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = BatchOperationDeletion;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct temporal.api.batch.v1.BatchOperationDeletion")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<BatchOperationDeletion, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut identity__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Identity => {
+                            if identity__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("identity"));
+                            }
+                            identity__ = Some(map_.next_value()?);
+                        }
+                    }
+                }
+                Ok(BatchOperationDeletion {
+                    identity: identity__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("temporal.api.batch.v1.BatchOperationDeletion", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for BatchOperationInfo {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.job_id.is_empty() {
+            len += 1;
+        }
+        if self.state != 0 {
+            len += 1;
+        }
+        if self.start_time.is_some() {
+            len += 1;
+        }
+        if self.close_time.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("temporal.api.batch.v1.BatchOperationInfo", len)?;
+        if !self.job_id.is_empty() {
+            struct_ser.serialize_field("jobId", &self.job_id)?;
+        }
+        if self.state != 0 {
+            let v = super::super::enums::v1::BatchOperationState::try_from(self.state)
+                .map_err(|_| serde::ser::Error::custom(format!("Invalid variant {}", self.state)))?;
+            struct_ser.serialize_field("state", &v)?;
+        }
+        if let Some(v) = self.start_time.as_ref() {
+            struct_ser.serialize_field("startTime", v)?;
+        }
+        if let Some(v) = self.close_time.as_ref() {
+            struct_ser.serialize_field("closeTime", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for BatchOperationInfo {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "job_id",
+            "jobId",
+            "state",
+            "start_time",
+            "startTime",
+            "close_time",
+            "closeTime",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            JobId,
+            State,
+            StartTime,
+            CloseTime,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "jobId" | "job_id" => Ok(GeneratedField::JobId),
+                            "state" => Ok(GeneratedField::State),
+                            "startTime" | "start_time" => Ok(GeneratedField::StartTime),
+                            "closeTime" | "close_time" => Ok(GeneratedField::CloseTime),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = BatchOperationInfo;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct temporal.api.batch.v1.BatchOperationInfo")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<BatchOperationInfo, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut job_id__ = None;
+                let mut state__ = None;
+                let mut start_time__ = None;
+                let mut close_time__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::JobId => {
+                            if job_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("jobId"));
+                            }
+                            job_id__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::State => {
+                            if state__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("state"));
+                            }
+                            state__ = Some(map_.next_value::<super::super::enums::v1::BatchOperationState>()? as i32);
+                        }
+                        GeneratedField::StartTime => {
+                            if start_time__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("startTime"));
+                            }
+                            start_time__ = map_.next_value()?;
+                        }
+                        GeneratedField::CloseTime => {
+                            if close_time__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("closeTime"));
+                            }
+                            close_time__ = map_.next_value()?;
+                        }
+                    }
+                }
+                Ok(BatchOperationInfo {
+                    job_id: job_id__.unwrap_or_default(),
+                    state: state__.unwrap_or_default(),
+                    start_time: start_time__,
+                    close_time: close_time__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("temporal.api.batch.v1.BatchOperationInfo", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for BatchOperationReset {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.identity.is_empty() {
+            len += 1;
+        }
+        if self.options.is_some() {
+            len += 1;
+        }
+        if self.reset_type != 0 {
+            len += 1;
+        }
+        if self.reset_reapply_type != 0 {
+            len += 1;
+        }
+        if !self.post_reset_operations.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("temporal.api.batch.v1.BatchOperationReset", len)?;
+        if !self.identity.is_empty() {
+            struct_ser.serialize_field("identity", &self.identity)?;
+        }
+        if let Some(v) = self.options.as_ref() {
+            struct_ser.serialize_field("options", v)?;
+        }
+        if self.reset_type != 0 {
+            let v = super::super::enums::v1::ResetType::try_from(self.reset_type)
+                .map_err(|_| serde::ser::Error::custom(format!("Invalid variant {}", self.reset_type)))?;
+            struct_ser.serialize_field("resetType", &v)?;
+        }
+        if self.reset_reapply_type != 0 {
+            let v = super::super::enums::v1::ResetReapplyType::try_from(self.reset_reapply_type)
+                .map_err(|_| serde::ser::Error::custom(format!("Invalid variant {}", self.reset_reapply_type)))?;
+            struct_ser.serialize_field("resetReapplyType", &v)?;
+        }
+        if !self.post_reset_operations.is_empty() {
+            struct_ser.serialize_field("postResetOperations", &self.post_reset_operations)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for BatchOperationReset {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "identity",
+            "options",
+            "reset_type",
+            "resetType",
+            "reset_reapply_type",
+            "resetReapplyType",
+            "post_reset_operations",
+            "postResetOperations",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Identity,
+            Options,
+            ResetType,
+            ResetReapplyType,
+            PostResetOperations,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "identity" => Ok(GeneratedField::Identity),
+                            "options" => Ok(GeneratedField::Options),
+                            "resetType" | "reset_type" => Ok(GeneratedField::ResetType),
+                            "resetReapplyType" | "reset_reapply_type" => Ok(GeneratedField::ResetReapplyType),
+                            "postResetOperations" | "post_reset_operations" => Ok(GeneratedField::PostResetOperations),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = BatchOperationReset;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct temporal.api.batch.v1.BatchOperationReset")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<BatchOperationReset, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut identity__ = None;
+                let mut options__ = None;
+                let mut reset_type__ = None;
+                let mut reset_reapply_type__ = None;
+                let mut post_reset_operations__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Identity => {
+                            if identity__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("identity"));
+                            }
+                            identity__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::Options => {
+                            if options__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("options"));
+                            }
+                            options__ = map_.next_value()?;
+                        }
+                        GeneratedField::ResetType => {
+                            if reset_type__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("resetType"));
+                            }
+                            reset_type__ = Some(map_.next_value::<super::super::enums::v1::ResetType>()? as i32);
+                        }
+                        GeneratedField::ResetReapplyType => {
+                            if reset_reapply_type__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("resetReapplyType"));
+                            }
+                            reset_reapply_type__ = Some(map_.next_value::<super::super::enums::v1::ResetReapplyType>()? as i32);
+                        }
+                        GeneratedField::PostResetOperations => {
+                            if post_reset_operations__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("postResetOperations"));
+                            }
+                            post_reset_operations__ = Some(map_.next_value()?);
+                        }
+                    }
+                }
+                Ok(BatchOperationReset {
+                    identity: identity__.unwrap_or_default(),
+                    options: options__,
+                    reset_type: reset_type__.unwrap_or_default(),
+                    reset_reapply_type: reset_reapply_type__.unwrap_or_default(),
+                    post_reset_operations: post_reset_operations__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("temporal.api.batch.v1.BatchOperationReset", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for BatchOperationResetActivities {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.identity.is_empty() {
+            len += 1;
+        }
+        if self.reset_attempts {
+            len += 1;
+        }
+        if self.reset_heartbeat {
+            len += 1;
+        }
+        if self.keep_paused {
+            len += 1;
+        }
+        if self.jitter.is_some() {
+            len += 1;
+        }
+        if self.restore_original_options {
+            len += 1;
+        }
+        if self.activity.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("temporal.api.batch.v1.BatchOperationResetActivities", len)?;
+        if !self.identity.is_empty() {
+            struct_ser.serialize_field("identity", &self.identity)?;
+        }
+        if self.reset_attempts {
+            struct_ser.serialize_field("resetAttempts", &self.reset_attempts)?;
+        }
+        if self.reset_heartbeat {
+            struct_ser.serialize_field("resetHeartbeat", &self.reset_heartbeat)?;
+        }
+        if self.keep_paused {
+            struct_ser.serialize_field("keepPaused", &self.keep_paused)?;
+        }
+        if let Some(v) = self.jitter.as_ref() {
+            struct_ser.serialize_field("jitter", v)?;
+        }
+        if self.restore_original_options {
+            struct_ser.serialize_field("restoreOriginalOptions", &self.restore_original_options)?;
+        }
+        if let Some(v) = self.activity.as_ref() {
+            match v {
+                batch_operation_reset_activities::Activity::Type(v) => {
+                    struct_ser.serialize_field("type", v)?;
+                }
+                batch_operation_reset_activities::Activity::MatchAll(v) => {
+                    struct_ser.serialize_field("matchAll", v)?;
+                }
+            }
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for BatchOperationResetActivities {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "identity",
+            "reset_attempts",
+            "resetAttempts",
+            "reset_heartbeat",
+            "resetHeartbeat",
+            "keep_paused",
+            "keepPaused",
+            "jitter",
+            "restore_original_options",
+            "restoreOriginalOptions",
+            "type",
+            "match_all",
+            "matchAll",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Identity,
+            ResetAttempts,
+            ResetHeartbeat,
+            KeepPaused,
+            Jitter,
+            RestoreOriginalOptions,
+            Type,
+            MatchAll,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "identity" => Ok(GeneratedField::Identity),
+                            "resetAttempts" | "reset_attempts" => Ok(GeneratedField::ResetAttempts),
+                            "resetHeartbeat" | "reset_heartbeat" => Ok(GeneratedField::ResetHeartbeat),
+                            "keepPaused" | "keep_paused" => Ok(GeneratedField::KeepPaused),
+                            "jitter" => Ok(GeneratedField::Jitter),
+                            "restoreOriginalOptions" | "restore_original_options" => Ok(GeneratedField::RestoreOriginalOptions),
+                            "type" => Ok(GeneratedField::Type),
+                            "matchAll" | "match_all" => Ok(GeneratedField::MatchAll),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = BatchOperationResetActivities;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct temporal.api.batch.v1.BatchOperationResetActivities")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<BatchOperationResetActivities, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut identity__ = None;
+                let mut reset_attempts__ = None;
+                let mut reset_heartbeat__ = None;
+                let mut keep_paused__ = None;
+                let mut jitter__ = None;
+                let mut restore_original_options__ = None;
+                let mut activity__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Identity => {
+                            if identity__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("identity"));
+                            }
+                            identity__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::ResetAttempts => {
+                            if reset_attempts__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("resetAttempts"));
+                            }
+                            reset_attempts__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::ResetHeartbeat => {
+                            if reset_heartbeat__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("resetHeartbeat"));
+                            }
+                            reset_heartbeat__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::KeepPaused => {
+                            if keep_paused__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("keepPaused"));
+                            }
+                            keep_paused__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::Jitter => {
+                            if jitter__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("jitter"));
+                            }
+                            jitter__ = map_.next_value()?;
+                        }
+                        GeneratedField::RestoreOriginalOptions => {
+                            if restore_original_options__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("restoreOriginalOptions"));
+                            }
+                            restore_original_options__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::Type => {
+                            if activity__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("type"));
+                            }
+                            activity__ = map_.next_value::<::std::option::Option<_>>()?.map(batch_operation_reset_activities::Activity::Type);
+                        }
+                        GeneratedField::MatchAll => {
+                            if activity__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("matchAll"));
+                            }
+                            activity__ = map_.next_value::<::std::option::Option<_>>()?.map(batch_operation_reset_activities::Activity::MatchAll);
+                        }
+                    }
+                }
+                Ok(BatchOperationResetActivities {
+                    identity: identity__.unwrap_or_default(),
+                    reset_attempts: reset_attempts__.unwrap_or_default(),
+                    reset_heartbeat: reset_heartbeat__.unwrap_or_default(),
+                    keep_paused: keep_paused__.unwrap_or_default(),
+                    jitter: jitter__,
+                    restore_original_options: restore_original_options__.unwrap_or_default(),
+                    activity: activity__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("temporal.api.batch.v1.BatchOperationResetActivities", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for BatchOperationSignal {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.signal.is_empty() {
+            len += 1;
+        }
+        if self.input.is_some() {
+            len += 1;
+        }
+        if self.header.is_some() {
+            len += 1;
+        }
+        if !self.identity.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("temporal.api.batch.v1.BatchOperationSignal", len)?;
+        if !self.signal.is_empty() {
+            struct_ser.serialize_field("signal", &self.signal)?;
+        }
+        if let Some(v) = self.input.as_ref() {
+            struct_ser.serialize_field("input", v)?;
+        }
+        if let Some(v) = self.header.as_ref() {
+            struct_ser.serialize_field("header", v)?;
+        }
+        if !self.identity.is_empty() {
+            struct_ser.serialize_field("identity", &self.identity)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for BatchOperationSignal {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "signal",
+            "input",
+            "header",
+            "identity",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Signal,
+            Input,
+            Header,
+            Identity,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "signal" => Ok(GeneratedField::Signal),
+                            "input" => Ok(GeneratedField::Input),
+                            "header" => Ok(GeneratedField::Header),
+                            "identity" => Ok(GeneratedField::Identity),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = BatchOperationSignal;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct temporal.api.batch.v1.BatchOperationSignal")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<BatchOperationSignal, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut signal__ = None;
+                let mut input__ = None;
+                let mut header__ = None;
+                let mut identity__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Signal => {
+                            if signal__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("signal"));
+                            }
+                            signal__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::Input => {
+                            if input__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("input"));
+                            }
+                            input__ = map_.next_value()?;
+                        }
+                        GeneratedField::Header => {
+                            if header__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("header"));
+                            }
+                            header__ = map_.next_value()?;
+                        }
+                        GeneratedField::Identity => {
+                            if identity__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("identity"));
+                            }
+                            identity__ = Some(map_.next_value()?);
+                        }
+                    }
+                }
+                Ok(BatchOperationSignal {
+                    signal: signal__.unwrap_or_default(),
+                    input: input__,
+                    header: header__,
+                    identity: identity__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("temporal.api.batch.v1.BatchOperationSignal", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for BatchOperationTermination {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.details.is_some() {
+            len += 1;
+        }
+        if !self.identity.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("temporal.api.batch.v1.BatchOperationTermination", len)?;
+        if let Some(v) = self.details.as_ref() {
+            struct_ser.serialize_field("details", v)?;
+        }
+        if !self.identity.is_empty() {
+            struct_ser.serialize_field("identity", &self.identity)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for BatchOperationTermination {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "details",
+            "identity",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Details,
+            Identity,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "details" => Ok(GeneratedField::Details),
+                            "identity" => Ok(GeneratedField::Identity),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = BatchOperationTermination;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct temporal.api.batch.v1.BatchOperationTermination")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<BatchOperationTermination, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut details__ = None;
+                let mut identity__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Details => {
+                            if details__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("details"));
+                            }
+                            details__ = map_.next_value()?;
+                        }
+                        GeneratedField::Identity => {
+                            if identity__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("identity"));
+                            }
+                            identity__ = Some(map_.next_value()?);
+                        }
+                    }
+                }
+                Ok(BatchOperationTermination {
+                    details: details__,
+                    identity: identity__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("temporal.api.batch.v1.BatchOperationTermination", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for BatchOperationTriggerWorkflowRule {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.identity.is_empty() {
+            len += 1;
+        }
+        if self.rule.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("temporal.api.batch.v1.BatchOperationTriggerWorkflowRule", len)?;
+        if !self.identity.is_empty() {
+            struct_ser.serialize_field("identity", &self.identity)?;
+        }
+        if let Some(v) = self.rule.as_ref() {
+            match v {
+                batch_operation_trigger_workflow_rule::Rule::Id(v) => {
+                    struct_ser.serialize_field("id", v)?;
+                }
+                batch_operation_trigger_workflow_rule::Rule::Spec(v) => {
+                    struct_ser.serialize_field("spec", v)?;
+                }
+            }
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for BatchOperationTriggerWorkflowRule {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "identity",
+            "id",
+            "spec",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Identity,
+            Id,
+            Spec,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "identity" => Ok(GeneratedField::Identity),
+                            "id" => Ok(GeneratedField::Id),
+                            "spec" => Ok(GeneratedField::Spec),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = BatchOperationTriggerWorkflowRule;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct temporal.api.batch.v1.BatchOperationTriggerWorkflowRule")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<BatchOperationTriggerWorkflowRule, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut identity__ = None;
+                let mut rule__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Identity => {
+                            if identity__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("identity"));
+                            }
+                            identity__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::Id => {
+                            if rule__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("id"));
+                            }
+                            rule__ = map_.next_value::<::std::option::Option<_>>()?.map(batch_operation_trigger_workflow_rule::Rule::Id);
+                        }
+                        GeneratedField::Spec => {
+                            if rule__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("spec"));
+                            }
+                            rule__ = map_.next_value::<::std::option::Option<_>>()?.map(batch_operation_trigger_workflow_rule::Rule::Spec)
+;
+                        }
+                    }
+                }
+                Ok(BatchOperationTriggerWorkflowRule {
+                    identity: identity__.unwrap_or_default(),
+                    rule: rule__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("temporal.api.batch.v1.BatchOperationTriggerWorkflowRule", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for BatchOperationUnpauseActivities {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.identity.is_empty() {
+            len += 1;
+        }
+        if self.reset_attempts {
+            len += 1;
+        }
+        if self.reset_heartbeat {
+            len += 1;
+        }
+        if self.jitter.is_some() {
+            len += 1;
+        }
+        if self.activity.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("temporal.api.batch.v1.BatchOperationUnpauseActivities", len)?;
+        if !self.identity.is_empty() {
+            struct_ser.serialize_field("identity", &self.identity)?;
+        }
+        if self.reset_attempts {
+            struct_ser.serialize_field("resetAttempts", &self.reset_attempts)?;
+        }
+        if self.reset_heartbeat {
+            struct_ser.serialize_field("resetHeartbeat", &self.reset_heartbeat)?;
+        }
+        if let Some(v) = self.jitter.as_ref() {
+            struct_ser.serialize_field("jitter", v)?;
+        }
+        if let Some(v) = self.activity.as_ref() {
+            match v {
+                batch_operation_unpause_activities::Activity::Type(v) => {
+                    struct_ser.serialize_field("type", v)?;
+                }
+                batch_operation_unpause_activities::Activity::MatchAll(v) => {
+                    struct_ser.serialize_field("matchAll", v)?;
+                }
+            }
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for BatchOperationUnpauseActivities {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "identity",
+            "reset_attempts",
+            "resetAttempts",
+            "reset_heartbeat",
+            "resetHeartbeat",
+            "jitter",
+            "type",
+            "match_all",
+            "matchAll",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Identity,
+            ResetAttempts,
+            ResetHeartbeat,
+            Jitter,
+            Type,
+            MatchAll,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "identity" => Ok(GeneratedField::Identity),
+                            "resetAttempts" | "reset_attempts" => Ok(GeneratedField::ResetAttempts),
+                            "resetHeartbeat" | "reset_heartbeat" => Ok(GeneratedField::ResetHeartbeat),
+                            "jitter" => Ok(GeneratedField::Jitter),
+                            "type" => Ok(GeneratedField::Type),
+                            "matchAll" | "match_all" => Ok(GeneratedField::MatchAll),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = BatchOperationUnpauseActivities;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct temporal.api.batch.v1.BatchOperationUnpauseActivities")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<BatchOperationUnpauseActivities, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut identity__ = None;
+                let mut reset_attempts__ = None;
+                let mut reset_heartbeat__ = None;
+                let mut jitter__ = None;
+                let mut activity__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Identity => {
+                            if identity__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("identity"));
+                            }
+                            identity__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::ResetAttempts => {
+                            if reset_attempts__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("resetAttempts"));
+                            }
+                            reset_attempts__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::ResetHeartbeat => {
+                            if reset_heartbeat__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("resetHeartbeat"));
+                            }
+                            reset_heartbeat__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::Jitter => {
+                            if jitter__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("jitter"));
+                            }
+                            jitter__ = map_.next_value()?;
+                        }
+                        GeneratedField::Type => {
+                            if activity__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("type"));
+                            }
+                            activity__ = map_.next_value::<::std::option::Option<_>>()?.map(batch_operation_unpause_activities::Activity::Type);
+                        }
+                        GeneratedField::MatchAll => {
+                            if activity__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("matchAll"));
+                            }
+                            activity__ = map_.next_value::<::std::option::Option<_>>()?.map(batch_operation_unpause_activities::Activity::MatchAll);
+                        }
+                    }
+                }
+                Ok(BatchOperationUnpauseActivities {
+                    identity: identity__.unwrap_or_default(),
+                    reset_attempts: reset_attempts__.unwrap_or_default(),
+                    reset_heartbeat: reset_heartbeat__.unwrap_or_default(),
+                    jitter: jitter__,
+                    activity: activity__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("temporal.api.batch.v1.BatchOperationUnpauseActivities", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for BatchOperationUpdateActivityOptions {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.identity.is_empty() {
+            len += 1;
+        }
+        if self.activity_options.is_some() {
+            len += 1;
+        }
+        if self.update_mask.is_some() {
+            len += 1;
+        }
+        if self.restore_original {
+            len += 1;
+        }
+        if self.activity.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("temporal.api.batch.v1.BatchOperationUpdateActivityOptions", len)?;
+        if !self.identity.is_empty() {
+            struct_ser.serialize_field("identity", &self.identity)?;
+        }
+        if let Some(v) = self.activity_options.as_ref() {
+            struct_ser.serialize_field("activityOptions", v)?;
+        }
+        if let Some(v) = self.update_mask.as_ref() {
+            struct_ser.serialize_field("updateMask", v)?;
+        }
+        if self.restore_original {
+            struct_ser.serialize_field("restoreOriginal", &self.restore_original)?;
+        }
+        if let Some(v) = self.activity.as_ref() {
+            match v {
+                batch_operation_update_activity_options::Activity::Type(v) => {
+                    struct_ser.serialize_field("type", v)?;
+                }
+                batch_operation_update_activity_options::Activity::MatchAll(v) => {
+                    struct_ser.serialize_field("matchAll", v)?;
+                }
+            }
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for BatchOperationUpdateActivityOptions {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "identity",
+            "activity_options",
+            "activityOptions",
+            "update_mask",
+            "updateMask",
+            "restore_original",
+            "restoreOriginal",
+            "type",
+            "match_all",
+            "matchAll",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Identity,
+            ActivityOptions,
+            UpdateMask,
+            RestoreOriginal,
+            Type,
+            MatchAll,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "identity" => Ok(GeneratedField::Identity),
+                            "activityOptions" | "activity_options" => Ok(GeneratedField::ActivityOptions),
+                            "updateMask" | "update_mask" => Ok(GeneratedField::UpdateMask),
+                            "restoreOriginal" | "restore_original" => Ok(GeneratedField::RestoreOriginal),
+                            "type" => Ok(GeneratedField::Type),
+                            "matchAll" | "match_all" => Ok(GeneratedField::MatchAll),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = BatchOperationUpdateActivityOptions;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct temporal.api.batch.v1.BatchOperationUpdateActivityOptions")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<BatchOperationUpdateActivityOptions, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut identity__ = None;
+                let mut activity_options__ = None;
+                let mut update_mask__ = None;
+                let mut restore_original__ = None;
+                let mut activity__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Identity => {
+                            if identity__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("identity"));
+                            }
+                            identity__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::ActivityOptions => {
+                            if activity_options__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("activityOptions"));
+                            }
+                            activity_options__ = map_.next_value()?;
+                        }
+                        GeneratedField::UpdateMask => {
+                            if update_mask__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("updateMask"));
+                            }
+                            update_mask__ = map_.next_value()?;
+                        }
+                        GeneratedField::RestoreOriginal => {
+                            if restore_original__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("restoreOriginal"));
+                            }
+                            restore_original__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::Type => {
+                            if activity__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("type"));
+                            }
+                            activity__ = map_.next_value::<::std::option::Option<_>>()?.map(batch_operation_update_activity_options::Activity::Type);
+                        }
+                        GeneratedField::MatchAll => {
+                            if activity__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("matchAll"));
+                            }
+                            activity__ = map_.next_value::<::std::option::Option<_>>()?.map(batch_operation_update_activity_options::Activity::MatchAll);
+                        }
+                    }
+                }
+                Ok(BatchOperationUpdateActivityOptions {
+                    identity: identity__.unwrap_or_default(),
+                    activity_options: activity_options__,
+                    update_mask: update_mask__,
+                    restore_original: restore_original__.unwrap_or_default(),
+                    activity: activity__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("temporal.api.batch.v1.BatchOperationUpdateActivityOptions", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for BatchOperationUpdateWorkflowExecutionOptions {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.identity.is_empty() {
+            len += 1;
+        }
+        if self.workflow_execution_options.is_some() {
+            len += 1;
+        }
+        if self.update_mask.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("temporal.api.batch.v1.BatchOperationUpdateWorkflowExecutionOptions", len)?;
+        if !self.identity.is_empty() {
+            struct_ser.serialize_field("identity", &self.identity)?;
+        }
+        if let Some(v) = self.workflow_execution_options.as_ref() {
+            struct_ser.serialize_field("workflowExecutionOptions", v)?;
+        }
+        if let Some(v) = self.update_mask.as_ref() {
+            struct_ser.serialize_field("updateMask", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for BatchOperationUpdateWorkflowExecutionOptions {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "identity",
+            "workflow_execution_options",
+            "workflowExecutionOptions",
+            "update_mask",
+            "updateMask",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Identity,
+            WorkflowExecutionOptions,
+            UpdateMask,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "identity" => Ok(GeneratedField::Identity),
+                            "workflowExecutionOptions" | "workflow_execution_options" => Ok(GeneratedField::WorkflowExecutionOptions),
+                            "updateMask" | "update_mask" => Ok(GeneratedField::UpdateMask),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = BatchOperationUpdateWorkflowExecutionOptions;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct temporal.api.batch.v1.BatchOperationUpdateWorkflowExecutionOptions")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<BatchOperationUpdateWorkflowExecutionOptions, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut identity__ = None;
+                let mut workflow_execution_options__ = None;
+                let mut update_mask__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Identity => {
+                            if identity__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("identity"));
+                            }
+                            identity__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::WorkflowExecutionOptions => {
+                            if workflow_execution_options__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("workflowExecutionOptions"));
+                            }
+                            workflow_execution_options__ = map_.next_value()?;
+                        }
+                        GeneratedField::UpdateMask => {
+                            if update_mask__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("updateMask"));
+                            }
+                            update_mask__ = map_.next_value()?;
+                        }
+                    }
+                }
+                Ok(BatchOperationUpdateWorkflowExecutionOptions {
+                    identity: identity__.unwrap_or_default(),
+                    workflow_execution_options: workflow_execution_options__,
+                    update_mask: update_mask__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("temporal.api.batch.v1.BatchOperationUpdateWorkflowExecutionOptions", FIELDS, GeneratedVisitor)
+    }
+}
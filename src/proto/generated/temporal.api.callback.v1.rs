@@ -0,0 +1,30 @@
+// This file is @generated by prost-build.
+/// Common callback information. Specific CallbackInfo messages should embed this and may include additional fields.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct CallbackInfo {
+    /// Information on how this callback should be invoked (e.g. its URL and type).
+    #[prost(message, optional, tag = "1")]
+    pub callback: ::core::option::Option<super::super::common::v1::Callback>,
+    /// The time when the callback was registered.
+    #[prost(message, optional, tag = "2")]
+    pub registration_time: ::core::option::Option<::pbjson_types::Timestamp>,
+    /// The current state of the callback.
+    #[prost(enumeration = "super::super::enums::v1::CallbackState", tag = "3")]
+    pub state: i32,
+    /// The number of attempts made to deliver the callback.
+    /// This number represents a minimum bound since the attempt is incremented after the callback request completes.
+    #[prost(int32, tag = "4")]
+    pub attempt: i32,
+    /// The time when the last attempt completed.
+    #[prost(message, optional, tag = "5")]
+    pub last_attempt_complete_time: ::core::option::Option<::pbjson_types::Timestamp>,
+    /// The last attempt's failure, if any.
+    #[prost(message, optional, tag = "6")]
+    pub last_attempt_failure: ::core::option::Option<super::super::failure::v1::Failure>,
+    /// The time when the next attempt is scheduled.
+    #[prost(message, optional, tag = "7")]
+    pub next_attempt_schedule_time: ::core::option::Option<::pbjson_types::Timestamp>,
+    /// If the state is BLOCKED, blocked reason provides additional information.
+    #[prost(string, tag = "8")]
+    pub blocked_reason: ::prost::alloc::string::String,
+}
@@ -0,0 +1,219 @@
+impl serde::Serialize for CallbackInfo {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.callback.is_some() {
+            len += 1;
+        }
+        if self.registration_time.is_some() {
+            len += 1;
+        }
+        if self.state != 0 {
+            len += 1;
+        }
+        if self.attempt != 0 {
+            len += 1;
+        }
+        if self.last_attempt_complete_time.is_some() {
+            len += 1;
+        }
+        if self.last_attempt_failure.is_some() {
+            len += 1;
+        }
+        if self.next_attempt_schedule_time.is_some() {
+            len += 1;
+        }
+        if !self.blocked_reason.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("temporal.api.callback.v1.CallbackInfo", len)?;
+        if let Some(v) = self.callback.as_ref() {
+            struct_ser.serialize_field("callback", v)?;
+        }
+        if let Some(v) = self.registration_time.as_ref() {
+            struct_ser.serialize_field("registrationTime", v)?;
+        }
+        if self.state != 0 {
+            let v = super::super::enums::v1::CallbackState::try_from(self.state)
+                .map_err(|_| serde::ser::Error::custom(format!("Invalid variant {}", self.state)))?;
+            struct_ser.serialize_field("state", &v)?;
+        }
+        if self.attempt != 0 {
+            struct_ser.serialize_field("attempt", &self.attempt)?;
+        }
+        if let Some(v) = self.last_attempt_complete_time.as_ref() {
+            struct_ser.serialize_field("lastAttemptCompleteTime", v)?;
+        }
+        if let Some(v) = self.last_attempt_failure.as_ref() {
+            struct_ser.serialize_field("lastAttemptFailure", v)?;
+        }
+        if let Some(v) = self.next_attempt_schedule_time.as_ref() {
+            struct_ser.serialize_field("nextAttemptScheduleTime", v)?;
+        }
+        if !self.blocked_reason.is_empty() {
+            struct_ser.serialize_field("blockedReason", &self.blocked_reason)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for CallbackInfo {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "callback",
+            "registration_time",
+            "registrationTime",
+            "state",
+            "attempt",
+            "last_attempt_complete_time",
+            "lastAttemptCompleteTime",
+            "last_attempt_failure",
+            "lastAttemptFailure",
+            "next_attempt_schedule_time",
+            "nextAttemptScheduleTime",
+            "blocked_reason",
+            "blockedReason",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Callback,
+            RegistrationTime,
+            State,
+            Attempt,
+            LastAttemptCompleteTime,
+            LastAttemptFailure,
+            NextAttemptScheduleTime,
+            BlockedReason,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "callback" => Ok(GeneratedField::Callback),
+                            "registrationTime" | "registration_time" => Ok(GeneratedField::RegistrationTime),
+                            "state" => Ok(GeneratedField::State),
+                            "attempt" => Ok(GeneratedField::Attempt),
+                            "lastAttemptCompleteTime" | "last_attempt_complete_time" => Ok(GeneratedField::LastAttemptCompleteTime),
+                            "lastAttemptFailure" | "last_attempt_failure" => Ok(GeneratedField::LastAttemptFailure),
+                            "nextAttemptScheduleTime" | "next_attempt_schedule_time" => Ok(GeneratedField::NextAttemptScheduleTime),
+                            "blockedReason" | "blocked_reason" => Ok(GeneratedField::BlockedReason),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = CallbackInfo;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct temporal.api.callback.v1.CallbackInfo")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<CallbackInfo, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut callback__ = None;
+                let mut registration_time__ = None;
+                let mut state__ = None;
+                let mut attempt__ = None;
+                let mut last_attempt_complete_time__ = None;
+                let mut last_attempt_failure__ = None;
+                let mut next_attempt_schedule_time__ = None;
+                let mut blocked_reason__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Callback => {
+                            if callback__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("callback"));
+                            }
+                            callback__ = map_.next_value()?;
+                        }
+                        GeneratedField::RegistrationTime => {
+                            if registration_time__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("registrationTime"));
+                            }
+                            registration_time__ = map_.next_value()?;
+                        }
+                        GeneratedField::State => {
+                            if state__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("state"));
+                            }
+                            state__ = Some(map_.next_value::<super::super::enums::v1::CallbackState>()? as i32);
+                        }
+                        GeneratedField::Attempt => {
+                            if attempt__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("attempt"));
+                            }
+                            attempt__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::LastAttemptCompleteTime => {
+                            if last_attempt_complete_time__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("lastAttemptCompleteTime"));
+                            }
+                            last_attempt_complete_time__ = map_.next_value()?;
+                        }
+                        GeneratedField::LastAttemptFailure => {
+                            if last_attempt_failure__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("lastAttemptFailure"));
+                            }
+                            last_attempt_failure__ = map_.next_value()?;
+                        }
+                        GeneratedField::NextAttemptScheduleTime => {
+                            if next_attempt_schedule_time__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("nextAttemptScheduleTime"));
+                            }
+                            next_attempt_schedule_time__ = map_.next_value()?;
+                        }
+                        GeneratedField::BlockedReason => {
+                            if blocked_reason__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("blockedReason"));
+                            }
+                            blocked_reason__ = Some(map_.next_value()?);
+                        }
+                    }
+                }
+                Ok(CallbackInfo {
+                    callback: callback__,
+                    registration_time: registration_time__,
+                    state: state__.unwrap_or_default(),
+                    attempt: attempt__.unwrap_or_default(),
+                    last_attempt_complete_time: last_attempt_complete_time__,
+                    last_attempt_failure: last_attempt_failure__,
+                    next_attempt_schedule_time: next_attempt_schedule_time__,
+                    blocked_reason: blocked_reason__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("temporal.api.callback.v1.CallbackInfo", FIELDS, GeneratedVisitor)
+    }
+}
@@ -19,7 +19,7 @@ pub struct ScheduleActivityTaskCommandAttributes {
     /// (-- api-linter: core::0140::prepositions=disabled
     ///      aip.dev/not-precedent: "to" is used to indicate interval. --)
     #[prost(message, optional, tag = "7")]
-    pub schedule_to_close_timeout: ::core::option::Option<::prost_types::Duration>,
+    pub schedule_to_close_timeout: ::core::option::Option<::pbjson_types::Duration>,
     /// Limits the time an activity task can stay in a task queue before a worker picks it up. The
     /// "schedule" time is when the most recent retry is scheduled. This timeout should usually not
     /// be set: it's useful in specific scenarios like worker-specific task queues. This timeout is
@@ -31,17 +31,17 @@ pub struct ScheduleActivityTaskCommandAttributes {
     /// (-- api-linter: core::0140::prepositions=disabled
     ///      aip.dev/not-precedent: "to" is used to indicate interval. --)
     #[prost(message, optional, tag = "8")]
-    pub schedule_to_start_timeout: ::core::option::Option<::prost_types::Duration>,
+    pub schedule_to_start_timeout: ::core::option::Option<::pbjson_types::Duration>,
     /// Maximum time an activity is allowed to execute after being picked up by a worker. This
     /// timeout is always retryable. Either this or `schedule_to_close_timeout` must be specified.
     ///
     /// (-- api-linter: core::0140::prepositions=disabled
     ///      aip.dev/not-precedent: "to" is used to indicate interval. --)
     #[prost(message, optional, tag = "9")]
-    pub start_to_close_timeout: ::core::option::Option<::prost_types::Duration>,
+    pub start_to_close_timeout: ::core::option::Option<::pbjson_types::Duration>,
     /// Maximum permitted time between successful worker heartbeats.
     #[prost(message, optional, tag = "10")]
-    pub heartbeat_timeout: ::core::option::Option<::prost_types::Duration>,
+    pub heartbeat_timeout: ::core::option::Option<::pbjson_types::Duration>,
     /// Activities are provided by a default retry policy which is controlled through the service's
     /// dynamic configuration. Retries will be attempted until `schedule_to_close_timeout` has
     /// elapsed. To disable retries set retry_policy.maximum_attempts to 1.
@@ -77,7 +77,7 @@ pub struct StartTimerCommandAttributes {
     /// (-- api-linter: core::0140::prepositions=disabled
     ///      aip.dev/not-precedent: "to" is used to indicate interval. --)
     #[prost(message, optional, tag = "2")]
-    pub start_to_fire_timeout: ::core::option::Option<::prost_types::Duration>,
+    pub start_to_fire_timeout: ::core::option::Option<::pbjson_types::Duration>,
 }
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CompleteWorkflowExecutionCommandAttributes {
@@ -102,6 +102,8 @@ pub struct CancelWorkflowExecutionCommandAttributes {
 }
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct RequestCancelExternalWorkflowExecutionCommandAttributes {
+    /// Deprecated. Cross-namespace operations are disabled by default as of server 1.30.1.
+    #[deprecated]
     #[prost(string, tag = "1")]
     pub namespace: ::prost::alloc::string::String,
     #[prost(string, tag = "2")]
@@ -123,6 +125,8 @@ pub struct RequestCancelExternalWorkflowExecutionCommandAttributes {
 }
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct SignalExternalWorkflowExecutionCommandAttributes {
+    /// Deprecated. Cross-namespace operations are disabled by default as of server 1.30.1.
+    #[deprecated]
     #[prost(string, tag = "1")]
     pub namespace: ::prost::alloc::string::String,
     #[prost(message, optional, tag = "2")]
@@ -186,13 +190,13 @@ pub struct ContinueAsNewWorkflowExecutionCommandAttributes {
     pub input: ::core::option::Option<super::super::common::v1::Payloads>,
     /// Timeout of a single workflow run.
     #[prost(message, optional, tag = "4")]
-    pub workflow_run_timeout: ::core::option::Option<::prost_types::Duration>,
+    pub workflow_run_timeout: ::core::option::Option<::pbjson_types::Duration>,
     /// Timeout of a single workflow task.
     #[prost(message, optional, tag = "5")]
-    pub workflow_task_timeout: ::core::option::Option<::prost_types::Duration>,
+    pub workflow_task_timeout: ::core::option::Option<::pbjson_types::Duration>,
     /// How long the workflow start will be delayed - not really a "backoff" in the traditional sense.
     #[prost(message, optional, tag = "6")]
-    pub backoff_start_interval: ::core::option::Option<::prost_types::Duration>,
+    pub backoff_start_interval: ::core::option::Option<::pbjson_types::Duration>,
     #[prost(message, optional, tag = "7")]
     pub retry_policy: ::core::option::Option<super::super::common::v1::RetryPolicy>,
     /// Should be removed
@@ -234,6 +238,8 @@ pub struct ContinueAsNewWorkflowExecutionCommandAttributes {
 }
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct StartChildWorkflowExecutionCommandAttributes {
+    /// Deprecated. Cross-namespace operations are disabled by default as of server 1.30.1.
+    #[deprecated]
     #[prost(string, tag = "1")]
     pub namespace: ::prost::alloc::string::String,
     #[prost(string, tag = "2")]
@@ -246,13 +252,13 @@ pub struct StartChildWorkflowExecutionCommandAttributes {
     pub input: ::core::option::Option<super::super::common::v1::Payloads>,
     /// Total workflow execution timeout including retries and continue as new.
     #[prost(message, optional, tag = "6")]
-    pub workflow_execution_timeout: ::core::option::Option<::prost_types::Duration>,
+    pub workflow_execution_timeout: ::core::option::Option<::pbjson_types::Duration>,
     /// Timeout of a single workflow run.
     #[prost(message, optional, tag = "7")]
-    pub workflow_run_timeout: ::core::option::Option<::prost_types::Duration>,
+    pub workflow_run_timeout: ::core::option::Option<::pbjson_types::Duration>,
     /// Timeout of a single workflow task.
     #[prost(message, optional, tag = "8")]
-    pub workflow_task_timeout: ::core::option::Option<::prost_types::Duration>,
+    pub workflow_task_timeout: ::core::option::Option<::pbjson_types::Duration>,
     /// Default: PARENT_CLOSE_POLICY_TERMINATE.
     #[prost(enumeration = "super::super::enums::v1::ParentClosePolicy", tag = "9")]
     pub parent_close_policy: i32,
@@ -313,7 +319,7 @@ pub struct ScheduleNexusOperationCommandAttributes {
     /// (-- api-linter: core::0140::prepositions=disabled
     ///      aip.dev/not-precedent: "to" is used to indicate interval. --)
     #[prost(message, optional, tag = "5")]
-    pub schedule_to_close_timeout: ::core::option::Option<::prost_types::Duration>,
+    pub schedule_to_close_timeout: ::core::option::Option<::pbjson_types::Duration>,
     /// Header to attach to the Nexus request.
     /// Users are responsible for encrypting sensitive data in this header as it is stored in workflow history and
     /// transmitted to external services as-is.
@@ -334,7 +340,7 @@ pub struct ScheduleNexusOperationCommandAttributes {
     ///      aip.dev/not-precedent: "to" is used to indicate interval. --)
     /// Requires server version 1.31.0 or later.
     #[prost(message, optional, tag = "7")]
-    pub schedule_to_start_timeout: ::core::option::Option<::prost_types::Duration>,
+    pub schedule_to_start_timeout: ::core::option::Option<::pbjson_types::Duration>,
     /// Start-to-close timeout for this operation.
     /// Indicates how long the caller is willing to wait for an asynchronous operation to complete after it has been
     /// started. If the operation does not complete within this timeout after starting, it will fail with
@@ -345,7 +351,7 @@ pub struct ScheduleNexusOperationCommandAttributes {
     ///      aip.dev/not-precedent: "to" is used to indicate interval. --)
     /// Requires server version 1.31.0 or later.
     #[prost(message, optional, tag = "8")]
-    pub start_to_close_timeout: ::core::option::Option<::prost_types::Duration>,
+    pub start_to_close_timeout: ::core::option::Option<::pbjson_types::Duration>,
 }
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct RequestCancelNexusOperationCommandAttributes {
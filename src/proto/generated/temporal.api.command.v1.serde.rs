@@ -0,0 +1,3099 @@
+impl serde::Serialize for CancelTimerCommandAttributes {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.timer_id.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("temporal.api.command.v1.CancelTimerCommandAttributes", len)?;
+        if !self.timer_id.is_empty() {
+            struct_ser.serialize_field("timerId", &self.timer_id)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for CancelTimerCommandAttributes {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "timer_id",
+            "timerId",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            TimerId,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "timerId" | "timer_id" => Ok(GeneratedField::TimerId),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = CancelTimerCommandAttributes;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct temporal.api.command.v1.CancelTimerCommandAttributes")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<CancelTimerCommandAttributes, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut timer_id__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::TimerId => {
+                            if timer_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("timerId"));
+                            }
+                            timer_id__ = Some(map_.next_value()?);
+                        }
+                    }
+                }
+                Ok(CancelTimerCommandAttributes {
+                    timer_id: timer_id__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("temporal.api.command.v1.CancelTimerCommandAttributes", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for CancelWorkflowExecutionCommandAttributes {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.details.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("temporal.api.command.v1.CancelWorkflowExecutionCommandAttributes", len)?;
+        if let Some(v) = self.details.as_ref() {
+            struct_ser.serialize_field("details", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for CancelWorkflowExecutionCommandAttributes {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "details",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Details,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "details" => Ok(GeneratedField::Details),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = CancelWorkflowExecutionCommandAttributes;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct temporal.api.command.v1.CancelWorkflowExecutionCommandAttributes")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<CancelWorkflowExecutionCommandAttributes, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut details__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Details => {
+                            if details__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("details"));
+                            }
+                            details__ = map_.next_value()?;
+                        }
+                    }
+                }
+                Ok(CancelWorkflowExecutionCommandAttributes {
+                    details: details__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("temporal.api.command.v1.CancelWorkflowExecutionCommandAttributes", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for Command {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.command_type != 0 {
+            len += 1;
+        }
+        if self.user_metadata.is_some() {
+            len += 1;
+        }
+        if self.attributes.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("temporal.api.command.v1.Command", len)?;
+        if self.command_type != 0 {
+            let v = super::super::enums::v1::CommandType::try_from(self.command_type)
+                .map_err(|_| serde::ser::Error::custom(format!("Invalid variant {}", self.command_type)))?;
+            struct_ser.serialize_field("commandType", &v)?;
+        }
+        if let Some(v) = self.user_metadata.as_ref() {
+            struct_ser.serialize_field("userMetadata", v)?;
+        }
+        if let Some(v) = self.attributes.as_ref() {
+            match v {
+                command::Attributes::ScheduleActivityTaskCommandAttributes(v) => {
+                    struct_ser.serialize_field("scheduleActivityTaskCommandAttributes", v)?;
+                }
+                command::Attributes::StartTimerCommandAttributes(v) => {
+                    struct_ser.serialize_field("startTimerCommandAttributes", v)?;
+                }
+                command::Attributes::CompleteWorkflowExecutionCommandAttributes(v) => {
+                    struct_ser.serialize_field("completeWorkflowExecutionCommandAttributes", v)?;
+                }
+                command::Attributes::FailWorkflowExecutionCommandAttributes(v) => {
+                    struct_ser.serialize_field("failWorkflowExecutionCommandAttributes", v)?;
+                }
+                command::Attributes::RequestCancelActivityTaskCommandAttributes(v) => {
+                    struct_ser.serialize_field("requestCancelActivityTaskCommandAttributes", v)?;
+                }
+                command::Attributes::CancelTimerCommandAttributes(v) => {
+                    struct_ser.serialize_field("cancelTimerCommandAttributes", v)?;
+                }
+                command::Attributes::CancelWorkflowExecutionCommandAttributes(v) => {
+                    struct_ser.serialize_field("cancelWorkflowExecutionCommandAttributes", v)?;
+                }
+                command::Attributes::RequestCancelExternalWorkflowExecutionCommandAttributes(v) => {
+                    struct_ser.serialize_field("requestCancelExternalWorkflowExecutionCommandAttributes", v)?;
+                }
+                command::Attributes::RecordMarkerCommandAttributes(v) => {
+                    struct_ser.serialize_field("recordMarkerCommandAttributes", v)?;
+                }
+                command::Attributes::ContinueAsNewWorkflowExecutionCommandAttributes(v) => {
+                    struct_ser.serialize_field("continueAsNewWorkflowExecutionCommandAttributes", v)?;
+                }
+                command::Attributes::StartChildWorkflowExecutionCommandAttributes(v) => {
+                    struct_ser.serialize_field("startChildWorkflowExecutionCommandAttributes", v)?;
+                }
+                command::Attributes::SignalExternalWorkflowExecutionCommandAttributes(v) => {
+                    struct_ser.serialize_field("signalExternalWorkflowExecutionCommandAttributes", v)?;
+                }
+                command::Attributes::UpsertWorkflowSearchAttributesCommandAttributes(v) => {
+                    struct_ser.serialize_field("upsertWorkflowSearchAttributesCommandAttributes", v)?;
+                }
+                command::Attributes::ProtocolMessageCommandAttributes(v) => {
+                    struct_ser.serialize_field("protocolMessageCommandAttributes", v)?;
+                }
+                command::Attributes::ModifyWorkflowPropertiesCommandAttributes(v) => {
+                    struct_ser.serialize_field("modifyWorkflowPropertiesCommandAttributes", v)?;
+                }
+                command::Attributes::ScheduleNexusOperationCommandAttributes(v) => {
+                    struct_ser.serialize_field("scheduleNexusOperationCommandAttributes", v)?;
+                }
+                command::Attributes::RequestCancelNexusOperationCommandAttributes(v) => {
+                    struct_ser.serialize_field("requestCancelNexusOperationCommandAttributes", v)?;
+                }
+            }
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for Command {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "command_type",
+            "commandType",
+            "user_metadata",
+            "userMetadata",
+            "schedule_activity_task_command_attributes",
+            "scheduleActivityTaskCommandAttributes",
+            "start_timer_command_attributes",
+            "startTimerCommandAttributes",
+            "complete_workflow_execution_command_attributes",
+            "completeWorkflowExecutionCommandAttributes",
+            "fail_workflow_execution_command_attributes",
+            "failWorkflowExecutionCommandAttributes",
+            "request_cancel_activity_task_command_attributes",
+            "requestCancelActivityTaskCommandAttributes",
+            "cancel_timer_command_attributes",
+            "cancelTimerCommandAttributes",
+            "cancel_workflow_execution_command_attributes",
+            "cancelWorkflowExecutionCommandAttributes",
+            "request_cancel_external_workflow_execution_command_attributes",
+            "requestCancelExternalWorkflowExecutionCommandAttributes",
+            "record_marker_command_attributes",
+            "recordMarkerCommandAttributes",
+            "continue_as_new_workflow_execution_command_attributes",
+            "continueAsNewWorkflowExecutionCommandAttributes",
+            "start_child_workflow_execution_command_attributes",
+            "startChildWorkflowExecutionCommandAttributes",
+            "signal_external_workflow_execution_command_attributes",
+            "signalExternalWorkflowExecutionCommandAttributes",
+            "upsert_workflow_search_attributes_command_attributes",
+            "upsertWorkflowSearchAttributesCommandAttributes",
+            "protocol_message_command_attributes",
+            "protocolMessageCommandAttributes",
+            "modify_workflow_properties_command_attributes",
+            "modifyWorkflowPropertiesCommandAttributes",
+            "schedule_nexus_operation_command_attributes",
+            "scheduleNexusOperationCommandAttributes",
+            "request_cancel_nexus_operation_command_attributes",
+            "requestCancelNexusOperationCommandAttributes",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            CommandType,
+            UserMetadata,
+            ScheduleActivityTaskCommandAttributes,
+            StartTimerCommandAttributes,
+            CompleteWorkflowExecutionCommandAttributes,
+            FailWorkflowExecutionCommandAttributes,
+            RequestCancelActivityTaskCommandAttributes,
+            CancelTimerCommandAttributes,
+            CancelWorkflowExecutionCommandAttributes,
+            RequestCancelExternalWorkflowExecutionCommandAttributes,
+            RecordMarkerCommandAttributes,
+            ContinueAsNewWorkflowExecutionCommandAttributes,
+            StartChildWorkflowExecutionCommandAttributes,
+            SignalExternalWorkflowExecutionCommandAttributes,
+            UpsertWorkflowSearchAttributesCommandAttributes,
+            ProtocolMessageCommandAttributes,
+            ModifyWorkflowPropertiesCommandAttributes,
+            ScheduleNexusOperationCommandAttributes,
+            RequestCancelNexusOperationCommandAttributes,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "commandType" | "command_type" => Ok(GeneratedField::CommandType),
+                            "userMetadata" | "user_metadata" => Ok(GeneratedField::UserMetadata),
+                            "scheduleActivityTaskCommandAttributes" | "schedule_activity_task_command_attributes" => Ok(GeneratedField::ScheduleActivityTaskCommandAttributes),
+                            "startTimerCommandAttributes" | "start_timer_command_attributes" => Ok(GeneratedField::StartTimerCommandAttributes),
+                            "completeWorkflowExecutionCommandAttributes" | "complete_workflow_execution_command_attributes" => Ok(GeneratedField::CompleteWorkflowExecutionCommandAttributes),
+                            "failWorkflowExecutionCommandAttributes" | "fail_workflow_execution_command_attributes" => Ok(GeneratedField::FailWorkflowExecutionCommandAttributes),
+                            "requestCancelActivityTaskCommandAttributes" | "request_cancel_activity_task_command_attributes" => Ok(GeneratedField::RequestCancelActivityTaskCommandAttributes),
+                            "cancelTimerCommandAttributes" | "cancel_timer_command_attributes" => Ok(GeneratedField::CancelTimerCommandAttributes),
+                            "cancelWorkflowExecutionCommandAttributes" | "cancel_workflow_execution_command_attributes" => Ok(GeneratedField::CancelWorkflowExecutionCommandAttributes),
+                            "requestCancelExternalWorkflowExecutionCommandAttributes" | "request_cancel_external_workflow_execution_command_attributes" => Ok(GeneratedField::RequestCancelExternalWorkflowExecutionCommandAttributes),
+                            "recordMarkerCommandAttributes" | "record_marker_command_attributes" => Ok(GeneratedField::RecordMarkerCommandAttributes),
+                            "continueAsNewWorkflowExecutionCommandAttributes" | "continue_as_new_workflow_execution_command_attributes" => Ok(GeneratedField::ContinueAsNewWorkflowExecutionCommandAttributes),
+                            "startChildWorkflowExecutionCommandAttributes" | "start_child_workflow_execution_command_attributes" => Ok(GeneratedField::StartChildWorkflowExecutionCommandAttributes),
+                            "signalExternalWorkflowExecutionCommandAttributes" | "signal_external_workflow_execution_command_attributes" => Ok(GeneratedField::SignalExternalWorkflowExecutionCommandAttributes),
+                            "upsertWorkflowSearchAttributesCommandAttributes" | "upsert_workflow_search_attributes_command_attributes" => Ok(GeneratedField::UpsertWorkflowSearchAttributesCommandAttributes),
+                            "protocolMessageCommandAttributes" | "protocol_message_command_attributes" => Ok(GeneratedField::ProtocolMessageCommandAttributes),
+                            "modifyWorkflowPropertiesCommandAttributes" | "modify_workflow_properties_command_attributes" => Ok(GeneratedField::ModifyWorkflowPropertiesCommandAttributes),
+                            "scheduleNexusOperationCommandAttributes" | "schedule_nexus_operation_command_attributes" => Ok(GeneratedField::ScheduleNexusOperationCommandAttributes),
+                            "requestCancelNexusOperationCommandAttributes" | "request_cancel_nexus_operation_command_attributes" => Ok(GeneratedField::RequestCancelNexusOperationCommandAttributes),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = Command;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct temporal.api.command.v1.Command")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<Command, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut command_type__ = None;
+                let mut user_metadata__ = None;
+                let mut attributes__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::CommandType => {
+                            if command_type__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("commandType"));
+                            }
+                            command_type__ = Some(map_.next_value::<super::super::enums::v1::CommandType>()? as i32);
+                        }
+                        GeneratedField::UserMetadata => {
+                            if user_metadata__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("userMetadata"));
+                            }
+                            user_metadata__ = map_.next_value()?;
+                        }
+                        GeneratedField::ScheduleActivityTaskCommandAttributes => {
+                            if attributes__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("scheduleActivityTaskCommandAttributes"));
+                            }
+                            attributes__ = map_.next_value::<::std::option::Option<_>>()?.map(command::Attributes::ScheduleActivityTaskCommandAttributes)
+;
+                        }
+                        GeneratedField::StartTimerCommandAttributes => {
+                            if attributes__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("startTimerCommandAttributes"));
+                            }
+                            attributes__ = map_.next_value::<::std::option::Option<_>>()?.map(command::Attributes::StartTimerCommandAttributes)
+;
+                        }
+                        GeneratedField::CompleteWorkflowExecutionCommandAttributes => {
+                            if attributes__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("completeWorkflowExecutionCommandAttributes"));
+                            }
+                            attributes__ = map_.next_value::<::std::option::Option<_>>()?.map(command::Attributes::CompleteWorkflowExecutionCommandAttributes)
+;
+                        }
+                        GeneratedField::FailWorkflowExecutionCommandAttributes => {
+                            if attributes__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("failWorkflowExecutionCommandAttributes"));
+                            }
+                            attributes__ = map_.next_value::<::std::option::Option<_>>()?.map(command::Attributes::FailWorkflowExecutionCommandAttributes)
+;
+                        }
+                        GeneratedField::RequestCancelActivityTaskCommandAttributes => {
+                            if attributes__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("requestCancelActivityTaskCommandAttributes"));
+                            }
+                            attributes__ = map_.next_value::<::std::option::Option<_>>()?.map(command::Attributes::RequestCancelActivityTaskCommandAttributes)
+;
+                        }
+                        GeneratedField::CancelTimerCommandAttributes => {
+                            if attributes__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("cancelTimerCommandAttributes"));
+                            }
+                            attributes__ = map_.next_value::<::std::option::Option<_>>()?.map(command::Attributes::CancelTimerCommandAttributes)
+;
+                        }
+                        GeneratedField::CancelWorkflowExecutionCommandAttributes => {
+                            if attributes__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("cancelWorkflowExecutionCommandAttributes"));
+                            }
+                            attributes__ = map_.next_value::<::std::option::Option<_>>()?.map(command::Attributes::CancelWorkflowExecutionCommandAttributes)
+;
+                        }
+                        GeneratedField::RequestCancelExternalWorkflowExecutionCommandAttributes => {
+                            if attributes__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("requestCancelExternalWorkflowExecutionCommandAttributes"));
+                            }
+                            attributes__ = map_.next_value::<::std::option::Option<_>>()?.map(command::Attributes::RequestCancelExternalWorkflowExecutionCommandAttributes)
+;
+                        }
+                        GeneratedField::RecordMarkerCommandAttributes => {
+                            if attributes__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("recordMarkerCommandAttributes"));
+                            }
+                            attributes__ = map_.next_value::<::std::option::Option<_>>()?.map(command::Attributes::RecordMarkerCommandAttributes)
+;
+                        }
+                        GeneratedField::ContinueAsNewWorkflowExecutionCommandAttributes => {
+                            if attributes__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("continueAsNewWorkflowExecutionCommandAttributes"));
+                            }
+                            attributes__ = map_.next_value::<::std::option::Option<_>>()?.map(command::Attributes::ContinueAsNewWorkflowExecutionCommandAttributes)
+;
+                        }
+                        GeneratedField::StartChildWorkflowExecutionCommandAttributes => {
+                            if attributes__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("startChildWorkflowExecutionCommandAttributes"));
+                            }
+                            attributes__ = map_.next_value::<::std::option::Option<_>>()?.map(command::Attributes::StartChildWorkflowExecutionCommandAttributes)
+;
+                        }
+                        GeneratedField::SignalExternalWorkflowExecutionCommandAttributes => {
+                            if attributes__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("signalExternalWorkflowExecutionCommandAttributes"));
+                            }
+                            attributes__ = map_.next_value::<::std::option::Option<_>>()?.map(command::Attributes::SignalExternalWorkflowExecutionCommandAttributes)
+;
+                        }
+                        GeneratedField::UpsertWorkflowSearchAttributesCommandAttributes => {
+                            if attributes__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("upsertWorkflowSearchAttributesCommandAttributes"));
+                            }
+                            attributes__ = map_.next_value::<::std::option::Option<_>>()?.map(command::Attributes::UpsertWorkflowSearchAttributesCommandAttributes)
+;
+                        }
+                        GeneratedField::ProtocolMessageCommandAttributes => {
+                            if attributes__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("protocolMessageCommandAttributes"));
+                            }
+                            attributes__ = map_.next_value::<::std::option::Option<_>>()?.map(command::Attributes::ProtocolMessageCommandAttributes)
+;
+                        }
+                        GeneratedField::ModifyWorkflowPropertiesCommandAttributes => {
+                            if attributes__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("modifyWorkflowPropertiesCommandAttributes"));
+                            }
+                            attributes__ = map_.next_value::<::std::option::Option<_>>()?.map(command::Attributes::ModifyWorkflowPropertiesCommandAttributes)
+;
+                        }
+                        GeneratedField::ScheduleNexusOperationCommandAttributes => {
+                            if attributes__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("scheduleNexusOperationCommandAttributes"));
+                            }
+                            attributes__ = map_.next_value::<::std::option::Option<_>>()?.map(command::Attributes::ScheduleNexusOperationCommandAttributes)
+;
+                        }
+                        GeneratedField::RequestCancelNexusOperationCommandAttributes => {
+                            if attributes__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("requestCancelNexusOperationCommandAttributes"));
+                            }
+                            attributes__ = map_.next_value::<::std::option::Option<_>>()?.map(command::Attributes::RequestCancelNexusOperationCommandAttributes)
+;
+                        }
+                    }
+                }
+                Ok(Command {
+                    command_type: command_type__.unwrap_or_default(),
+                    user_metadata: user_metadata__,
+                    attributes: attributes__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("temporal.api.command.v1.Command", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for CompleteWorkflowExecutionCommandAttributes {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.result.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("temporal.api.command.v1.CompleteWorkflowExecutionCommandAttributes", len)?;
+        if let Some(v) = self.result.as_ref() {
+            struct_ser.serialize_field("result", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for CompleteWorkflowExecutionCommandAttributes {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "result",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Result,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "result" => Ok(GeneratedField::Result),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = CompleteWorkflowExecutionCommandAttributes;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct temporal.api.command.v1.CompleteWorkflowExecutionCommandAttributes")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<CompleteWorkflowExecutionCommandAttributes, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut result__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Result => {
+                            if result__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("result"));
+                            }
+                            result__ = map_.next_value()?;
+                        }
+                    }
+                }
+                Ok(CompleteWorkflowExecutionCommandAttributes {
+                    result: result__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("temporal.api.command.v1.CompleteWorkflowExecutionCommandAttributes", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for ContinueAsNewWorkflowExecutionCommandAttributes {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.workflow_type.is_some() {
+            len += 1;
+        }
+        if self.task_queue.is_some() {
+            len += 1;
+        }
+        if self.input.is_some() {
+            len += 1;
+        }
+        if self.workflow_run_timeout.is_some() {
+            len += 1;
+        }
+        if self.workflow_task_timeout.is_some() {
+            len += 1;
+        }
+        if self.backoff_start_interval.is_some() {
+            len += 1;
+        }
+        if self.retry_policy.is_some() {
+            len += 1;
+        }
+        if self.initiator != 0 {
+            len += 1;
+        }
+        if self.failure.is_some() {
+            len += 1;
+        }
+        if self.last_completion_result.is_some() {
+            len += 1;
+        }
+        if !self.cron_schedule.is_empty() {
+            len += 1;
+        }
+        if self.header.is_some() {
+            len += 1;
+        }
+        if self.memo.is_some() {
+            len += 1;
+        }
+        if self.search_attributes.is_some() {
+            len += 1;
+        }
+        if self.inherit_build_id {
+            len += 1;
+        }
+        if self.initial_versioning_behavior != 0 {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("temporal.api.command.v1.ContinueAsNewWorkflowExecutionCommandAttributes", len)?;
+        if let Some(v) = self.workflow_type.as_ref() {
+            struct_ser.serialize_field("workflowType", v)?;
+        }
+        if let Some(v) = self.task_queue.as_ref() {
+            struct_ser.serialize_field("taskQueue", v)?;
+        }
+        if let Some(v) = self.input.as_ref() {
+            struct_ser.serialize_field("input", v)?;
+        }
+        if let Some(v) = self.workflow_run_timeout.as_ref() {
+            struct_ser.serialize_field("workflowRunTimeout", v)?;
+        }
+        if let Some(v) = self.workflow_task_timeout.as_ref() {
+            struct_ser.serialize_field("workflowTaskTimeout", v)?;
+        }
+        if let Some(v) = self.backoff_start_interval.as_ref() {
+            struct_ser.serialize_field("backoffStartInterval", v)?;
+        }
+        if let Some(v) = self.retry_policy.as_ref() {
+            struct_ser.serialize_field("retryPolicy", v)?;
+        }
+        if self.initiator != 0 {
+            let v = super::super::enums::v1::ContinueAsNewInitiator::try_from(self.initiator)
+                .map_err(|_| serde::ser::Error::custom(format!("Invalid variant {}", self.initiator)))?;
+            struct_ser.serialize_field("initiator", &v)?;
+        }
+        if let Some(v) = self.failure.as_ref() {
+            struct_ser.serialize_field("failure", v)?;
+        }
+        if let Some(v) = self.last_completion_result.as_ref() {
+            struct_ser.serialize_field("lastCompletionResult", v)?;
+        }
+        if !self.cron_schedule.is_empty() {
+            struct_ser.serialize_field("cronSchedule", &self.cron_schedule)?;
+        }
+        if let Some(v) = self.header.as_ref() {
+            struct_ser.serialize_field("header", v)?;
+        }
+        if let Some(v) = self.memo.as_ref() {
+            struct_ser.serialize_field("memo", v)?;
+        }
+        if let Some(v) = self.search_attributes.as_ref() {
+            struct_ser.serialize_field("searchAttributes", v)?;
+        }
+        if self.inherit_build_id {
+            struct_ser.serialize_field("inheritBuildId", &self.inherit_build_id)?;
+        }
+        if self.initial_versioning_behavior != 0 {
+            let v = super::super::enums::v1::ContinueAsNewVersioningBehavior::try_from(self.initial_versioning_behavior)
+                .map_err(|_| serde::ser::Error::custom(format!("Invalid variant {}", self.initial_versioning_behavior)))?;
+            struct_ser.serialize_field("initialVersioningBehavior", &v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for ContinueAsNewWorkflowExecutionCommandAttributes {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "workflow_type",
+            "workflowType",
+            "task_queue",
+            "taskQueue",
+            "input",
+            "workflow_run_timeout",
+            "workflowRunTimeout",
+            "workflow_task_timeout",
+            "workflowTaskTimeout",
+            "backoff_start_interval",
+            "backoffStartInterval",
+            "retry_policy",
+            "retryPolicy",
+            "initiator",
+            "failure",
+            "last_completion_result",
+            "lastCompletionResult",
+            "cron_schedule",
+            "cronSchedule",
+            "header",
+            "memo",
+            "search_attributes",
+            "searchAttributes",
+            "inherit_build_id",
+            "inheritBuildId",
+            "initial_versioning_behavior",
+            "initialVersioningBehavior",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            WorkflowType,
+            TaskQueue,
+            Input,
+            WorkflowRunTimeout,
+            WorkflowTaskTimeout,
+            BackoffStartInterval,
+            RetryPolicy,
+            Initiator,
+            Failure,
+            LastCompletionResult,
+            CronSchedule,
+            Header,
+            Memo,
+            SearchAttributes,
+            InheritBuildId,
+            InitialVersioningBehavior,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "workflowType" | "workflow_type" => Ok(GeneratedField::WorkflowType),
+                            "taskQueue" | "task_queue" => Ok(GeneratedField::TaskQueue),
+                            "input" => Ok(GeneratedField::Input),
+                            "workflowRunTimeout" | "workflow_run_timeout" => Ok(GeneratedField::WorkflowRunTimeout),
+                            "workflowTaskTimeout" | "workflow_task_timeout" => Ok(GeneratedField::WorkflowTaskTimeout),
+                            "backoffStartInterval" | "backoff_start_interval" => Ok(GeneratedField::BackoffStartInterval),
+                            "retryPolicy" | "retry_policy" => Ok(GeneratedField::RetryPolicy),
+                            "initiator" => Ok(GeneratedField::Initiator),
+                            "failure" => Ok(GeneratedField::Failure),
+                            "lastCompletionResult" | "last_completion_result" => Ok(GeneratedField::LastCompletionResult),
+                            "cronSchedule" | "cron_schedule" => Ok(GeneratedField::CronSchedule),
+                            "header" => Ok(GeneratedField::Header),
+                            "memo" => Ok(GeneratedField::Memo),
+                            "searchAttributes" | "search_attributes" => Ok(GeneratedField::SearchAttributes),
+                            "inheritBuildId" | "inherit_build_id" => Ok(GeneratedField::InheritBuildId),
+                            "initialVersioningBehavior" | "initial_versioning_behavior" => Ok(GeneratedField::InitialVersioningBehavior),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = ContinueAsNewWorkflowExecutionCommandAttributes;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct temporal.api.command.v1.ContinueAsNewWorkflowExecutionCommandAttributes")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<ContinueAsNewWorkflowExecutionCommandAttributes, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut workflow_type__ = None;
+                let mut task_queue__ = None;
+                let mut input__ = None;
+                let mut workflow_run_timeout__ = None;
+                let mut workflow_task_timeout__ = None;
+                let mut backoff_start_interval__ = None;
+                let mut retry_policy__ = None;
+                let mut initiator__ = None;
+                let mut failure__ = None;
+                let mut last_completion_result__ = None;
+                let mut cron_schedule__ = None;
+                let mut header__ = None;
+                let mut memo__ = None;
+                let mut search_attributes__ = None;
+                let mut inherit_build_id__ = None;
+                let mut initial_versioning_behavior__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::WorkflowType => {
+                            if workflow_type__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("workflowType"));
+                            }
+                            workflow_type__ = map_.next_value()?;
+                        }
+                        GeneratedField::TaskQueue => {
+                            if task_queue__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("taskQueue"));
+                            }
+                            task_queue__ = map_.next_value()?;
+                        }
+                        GeneratedField::Input => {
+                            if input__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("input"));
+                            }
+                            input__ = map_.next_value()?;
+                        }
+                        GeneratedField::WorkflowRunTimeout => {
+                            if workflow_run_timeout__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("workflowRunTimeout"));
+                            }
+                            workflow_run_timeout__ = map_.next_value()?;
+                        }
+                        GeneratedField::WorkflowTaskTimeout => {
+                            if workflow_task_timeout__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("workflowTaskTimeout"));
+                            }
+                            workflow_task_timeout__ = map_.next_value()?;
+                        }
+                        GeneratedField::BackoffStartInterval => {
+                            if backoff_start_interval__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("backoffStartInterval"));
+                            }
+                            backoff_start_interval__ = map_.next_value()?;
+                        }
+                        GeneratedField::RetryPolicy => {
+                            if retry_policy__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("retryPolicy"));
+                            }
+                            retry_policy__ = map_.next_value()?;
+                        }
+                        GeneratedField::Initiator => {
+                            if initiator__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("initiator"));
+                            }
+                            initiator__ = Some(map_.next_value::<super::super::enums::v1::ContinueAsNewInitiator>()? as i32);
+                        }
+                        GeneratedField::Failure => {
+                            if failure__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("failure"));
+                            }
+                            failure__ = map_.next_value()?;
+                        }
+                        GeneratedField::LastCompletionResult => {
+                            if last_completion_result__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("lastCompletionResult"));
+                            }
+                            last_completion_result__ = map_.next_value()?;
+                        }
+                        GeneratedField::CronSchedule => {
+                            if cron_schedule__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("cronSchedule"));
+                            }
+                            cron_schedule__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::Header => {
+                            if header__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("header"));
+                            }
+                            header__ = map_.next_value()?;
+                        }
+                        GeneratedField::Memo => {
+                            if memo__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("memo"));
+                            }
+                            memo__ = map_.next_value()?;
+                        }
+                        GeneratedField::SearchAttributes => {
+                            if search_attributes__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("searchAttributes"));
+                            }
+                            search_attributes__ = map_.next_value()?;
+                        }
+                        GeneratedField::InheritBuildId => {
+                            if inherit_build_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("inheritBuildId"));
+                            }
+                            inherit_build_id__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::InitialVersioningBehavior => {
+                            if initial_versioning_behavior__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("initialVersioningBehavior"));
+                            }
+                            initial_versioning_behavior__ = Some(map_.next_value::<super::super::enums::v1::ContinueAsNewVersioningBehavior>()? as i32);
+                        }
+                    }
+                }
+                Ok(ContinueAsNewWorkflowExecutionCommandAttributes {
+                    workflow_type: workflow_type__,
+                    task_queue: task_queue__,
+                    input: input__,
+                    workflow_run_timeout: workflow_run_timeout__,
+                    workflow_task_timeout: workflow_task_timeout__,
+                    backoff_start_interval: backoff_start_interval__,
+                    retry_policy: retry_policy__,
+                    initiator: initiator__.unwrap_or_default(),
+                    failure: failure__,
+                    last_completion_result: last_completion_result__,
+                    cron_schedule: cron_schedule__.unwrap_or_default(),
+                    header: header__,
+                    memo: memo__,
+                    search_attributes: search_attributes__,
+                    inherit_build_id: inherit_build_id__.unwrap_or_default(),
+                    initial_versioning_behavior: initial_versioning_behavior__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("temporal.api.command.v1.ContinueAsNewWorkflowExecutionCommandAttributes", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for FailWorkflowExecutionCommandAttributes {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.failure.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("temporal.api.command.v1.FailWorkflowExecutionCommandAttributes", len)?;
+        if let Some(v) = self.failure.as_ref() {
+            struct_ser.serialize_field("failure", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for FailWorkflowExecutionCommandAttributes {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "failure",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Failure,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "failure" => Ok(GeneratedField::Failure),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = FailWorkflowExecutionCommandAttributes;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct temporal.api.command.v1.FailWorkflowExecutionCommandAttributes")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<FailWorkflowExecutionCommandAttributes, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut failure__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Failure => {
+                            if failure__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("failure"));
+                            }
+                            failure__ = map_.next_value()?;
+                        }
+                    }
+                }
+                Ok(FailWorkflowExecutionCommandAttributes {
+                    failure: failure__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("temporal.api.command.v1.FailWorkflowExecutionCommandAttributes", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for ModifyWorkflowPropertiesCommandAttributes {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.upserted_memo.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("temporal.api.command.v1.ModifyWorkflowPropertiesCommandAttributes", len)?;
+        if let Some(v) = self.upserted_memo.as_ref() {
+            struct_ser.serialize_field("upsertedMemo", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for ModifyWorkflowPropertiesCommandAttributes {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "upserted_memo",
+            "upsertedMemo",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            UpsertedMemo,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "upsertedMemo" | "upserted_memo" => Ok(GeneratedField::UpsertedMemo),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = ModifyWorkflowPropertiesCommandAttributes;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct temporal.api.command.v1.ModifyWorkflowPropertiesCommandAttributes")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<ModifyWorkflowPropertiesCommandAttributes, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut upserted_memo__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::UpsertedMemo => {
+                            if upserted_memo__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("upsertedMemo"));
+                            }
+                            upserted_memo__ = map_.next_value()?;
+                        }
+                    }
+                }
+                Ok(ModifyWorkflowPropertiesCommandAttributes {
+                    upserted_memo: upserted_memo__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("temporal.api.command.v1.ModifyWorkflowPropertiesCommandAttributes", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for ProtocolMessageCommandAttributes {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.message_id.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("temporal.api.command.v1.ProtocolMessageCommandAttributes", len)?;
+        if !self.message_id.is_empty() {
+            struct_ser.serialize_field("messageId", &self.message_id)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for ProtocolMessageCommandAttributes {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "message_id",
+            "messageId",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            MessageId,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "messageId" | "message_id" => Ok(GeneratedField::MessageId),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = ProtocolMessageCommandAttributes;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct temporal.api.command.v1.ProtocolMessageCommandAttributes")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<ProtocolMessageCommandAttributes, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut message_id__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::MessageId => {
+                            if message_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("messageId"));
+                            }
+                            message_id__ = Some(map_.next_value()?);
+                        }
+                    }
+                }
+                Ok(ProtocolMessageCommandAttributes {
+                    message_id: message_id__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("temporal.api.command.v1.ProtocolMessageCommandAttributes", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for RecordMarkerCommandAttributes {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.marker_name.is_empty() {
+            len += 1;
+        }
+        if !self.details.is_empty() {
+            len += 1;
+        }
+        if self.header.is_some() {
+            len += 1;
+        }
+        if self.failure.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("temporal.api.command.v1.RecordMarkerCommandAttributes", len)?;
+        if !self.marker_name.is_empty() {
+            struct_ser.serialize_field("markerName", &self.marker_name)?;
+        }
+        if !self.details.is_empty() {
+            struct_ser.serialize_field("details", &self.details)?;
+        }
+        if let Some(v) = self.header.as_ref() {
+            struct_ser.serialize_field("header", v)?;
+        }
+        if let Some(v) = self.failure.as_ref() {
+            struct_ser.serialize_field("failure", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for RecordMarkerCommandAttributes {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "marker_name",
+            "markerName",
+            "details",
+            "header",
+            "failure",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            MarkerName,
+            Details,
+            Header,
+            Failure,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "markerName" | "marker_name" => Ok(GeneratedField::MarkerName),
+                            "details" => Ok(GeneratedField::Details),
+                            "header" => Ok(GeneratedField::Header),
+                            "failure" => Ok(GeneratedField::Failure),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = RecordMarkerCommandAttributes;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct temporal.api.command.v1.RecordMarkerCommandAttributes")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<RecordMarkerCommandAttributes, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut marker_name__ = None;
+                let mut details__ = None;
+                let mut header__ = None;
+                let mut failure__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::MarkerName => {
+                            if marker_name__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("markerName"));
+                            }
+                            marker_name__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::Details => {
+                            if details__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("details"));
+                            }
+                            details__ = Some(
+                                map_.next_value::<std::collections::HashMap<_, _>>()?
+                            );
+                        }
+                        GeneratedField::Header => {
+                            if header__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("header"));
+                            }
+                            header__ = map_.next_value()?;
+                        }
+                        GeneratedField::Failure => {
+                            if failure__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("failure"));
+                            }
+                            failure__ = map_.next_value()?;
+                        }
+                    }
+                }
+                Ok(RecordMarkerCommandAttributes {
+                    marker_name: marker_name__.unwrap_or_default(),
+                    details: details__.unwrap_or_default(),
+                    header: header__,
+                    failure: failure__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("temporal.api.command.v1.RecordMarkerCommandAttributes", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for RequestCancelActivityTaskCommandAttributes {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.scheduled_event_id != 0 {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("temporal.api.command.v1.RequestCancelActivityTaskCommandAttributes", len)?;
+        if self.scheduled_event_id != 0 {
+            #[allow(clippy::needless_borrow)]
+            #[allow(clippy::needless_borrows_for_generic_args)]
+            struct_ser.serialize_field("scheduledEventId", ToString::to_string(&self.scheduled_event_id).as_str())?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for RequestCancelActivityTaskCommandAttributes {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "scheduled_event_id",
+            "scheduledEventId",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            ScheduledEventId,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "scheduledEventId" | "scheduled_event_id" => Ok(GeneratedField::ScheduledEventId),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = RequestCancelActivityTaskCommandAttributes;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct temporal.api.command.v1.RequestCancelActivityTaskCommandAttributes")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<RequestCancelActivityTaskCommandAttributes, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut scheduled_event_id__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::ScheduledEventId => {
+                            if scheduled_event_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("scheduledEventId"));
+                            }
+                            scheduled_event_id__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                    }
+                }
+                Ok(RequestCancelActivityTaskCommandAttributes {
+                    scheduled_event_id: scheduled_event_id__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("temporal.api.command.v1.RequestCancelActivityTaskCommandAttributes", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for RequestCancelExternalWorkflowExecutionCommandAttributes {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.namespace.is_empty() {
+            len += 1;
+        }
+        if !self.workflow_id.is_empty() {
+            len += 1;
+        }
+        if !self.run_id.is_empty() {
+            len += 1;
+        }
+        if !self.control.is_empty() {
+            len += 1;
+        }
+        if self.child_workflow_only {
+            len += 1;
+        }
+        if !self.reason.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("temporal.api.command.v1.RequestCancelExternalWorkflowExecutionCommandAttributes", len)?;
+        if !self.namespace.is_empty() {
+            struct_ser.serialize_field("namespace", &self.namespace)?;
+        }
+        if !self.workflow_id.is_empty() {
+            struct_ser.serialize_field("workflowId", &self.workflow_id)?;
+        }
+        if !self.run_id.is_empty() {
+            struct_ser.serialize_field("runId", &self.run_id)?;
+        }
+        if !self.control.is_empty() {
+            struct_ser.serialize_field("control", &self.control)?;
+        }
+        if self.child_workflow_only {
+            struct_ser.serialize_field("childWorkflowOnly", &self.child_workflow_only)?;
+        }
+        if !self.reason.is_empty() {
+            struct_ser.serialize_field("reason", &self.reason)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for RequestCancelExternalWorkflowExecutionCommandAttributes {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "namespace",
+            "workflow_id",
+            "workflowId",
+            "run_id",
+            "runId",
+            "control",
+            "child_workflow_only",
+            "childWorkflowOnly",
+            "reason",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Namespace,
+            WorkflowId,
+            RunId,
+            Control,
+            ChildWorkflowOnly,
+            Reason,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "namespace" => Ok(GeneratedField::Namespace),
+                            "workflowId" | "workflow_id" => Ok(GeneratedField::WorkflowId),
+                            "runId" | "run_id" => Ok(GeneratedField::RunId),
+                            "control" => Ok(GeneratedField::Control),
+                            "childWorkflowOnly" | "child_workflow_only" => Ok(GeneratedField::ChildWorkflowOnly),
+                            "reason" => Ok(GeneratedField::Reason),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = RequestCancelExternalWorkflowExecutionCommandAttributes;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct temporal.api.command.v1.RequestCancelExternalWorkflowExecutionCommandAttributes")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<RequestCancelExternalWorkflowExecutionCommandAttributes, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut namespace__ = None;
+                let mut workflow_id__ = None;
+                let mut run_id__ = None;
+                let mut control__ = None;
+                let mut child_workflow_only__ = None;
+                let mut reason__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Namespace => {
+                            if namespace__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("namespace"));
+                            }
+                            namespace__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::WorkflowId => {
+                            if workflow_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("workflowId"));
+                            }
+                            workflow_id__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::RunId => {
+                            if run_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("runId"));
+                            }
+                            run_id__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::Control => {
+                            if control__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("control"));
+                            }
+                            control__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::ChildWorkflowOnly => {
+                            if child_workflow_only__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("childWorkflowOnly"));
+                            }
+                            child_workflow_only__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::Reason => {
+                            if reason__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("reason"));
+                            }
+                            reason__ = Some(map_.next_value()?);
+                        }
+                    }
+                }
+                Ok(RequestCancelExternalWorkflowExecutionCommandAttributes {
+                    namespace: namespace__.unwrap_or_default(),
+                    workflow_id: workflow_id__.unwrap_or_default(),
+                    run_id: run_id__.unwrap_or_default(),
+                    control: control__.unwrap_or_default(),
+                    child_workflow_only: child_workflow_only__.unwrap_or_default(),
+                    reason: reason__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("temporal.api.command.v1.RequestCancelExternalWorkflowExecutionCommandAttributes", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for RequestCancelNexusOperationCommandAttributes {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.scheduled_event_id != 0 {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("temporal.api.command.v1.RequestCancelNexusOperationCommandAttributes", len)?;
+        if self.scheduled_event_id != 0 {
+            #[allow(clippy::needless_borrow)]
+            #[allow(clippy::needless_borrows_for_generic_args)]
+            struct_ser.serialize_field("scheduledEventId", ToString::to_string(&self.scheduled_event_id).as_str())?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for RequestCancelNexusOperationCommandAttributes {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "scheduled_event_id",
+            "scheduledEventId",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            ScheduledEventId,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "scheduledEventId" | "scheduled_event_id" => Ok(GeneratedField::ScheduledEventId),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = RequestCancelNexusOperationCommandAttributes;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct temporal.api.command.v1.RequestCancelNexusOperationCommandAttributes")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<RequestCancelNexusOperationCommandAttributes, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut scheduled_event_id__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::ScheduledEventId => {
+                            if scheduled_event_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("scheduledEventId"));
+                            }
+                            scheduled_event_id__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                    }
+                }
+                Ok(RequestCancelNexusOperationCommandAttributes {
+                    scheduled_event_id: scheduled_event_id__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("temporal.api.command.v1.RequestCancelNexusOperationCommandAttributes", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for ScheduleActivityTaskCommandAttributes {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.activity_id.is_empty() {
+            len += 1;
+        }
+        if self.activity_type.is_some() {
+            len += 1;
+        }
+        if self.task_queue.is_some() {
+            len += 1;
+        }
+        if self.header.is_some() {
+            len += 1;
+        }
+        if self.input.is_some() {
+            len += 1;
+        }
+        if self.schedule_to_close_timeout.is_some() {
+            len += 1;
+        }
+        if self.schedule_to_start_timeout.is_some() {
+            len += 1;
+        }
+        if self.start_to_close_timeout.is_some() {
+            len += 1;
+        }
+        if self.heartbeat_timeout.is_some() {
+            len += 1;
+        }
+        if self.retry_policy.is_some() {
+            len += 1;
+        }
+        if self.request_eager_execution {
+            len += 1;
+        }
+        if self.use_workflow_build_id {
+            len += 1;
+        }
+        if self.priority.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("temporal.api.command.v1.ScheduleActivityTaskCommandAttributes", len)?;
+        if !self.activity_id.is_empty() {
+            struct_ser.serialize_field("activityId", &self.activity_id)?;
+        }
+        if let Some(v) = self.activity_type.as_ref() {
+            struct_ser.serialize_field("activityType", v)?;
+        }
+        if let Some(v) = self.task_queue.as_ref() {
+            struct_ser.serialize_field("taskQueue", v)?;
+        }
+        if let Some(v) = self.header.as_ref() {
+            struct_ser.serialize_field("header", v)?;
+        }
+        if let Some(v) = self.input.as_ref() {
+            struct_ser.serialize_field("input", v)?;
+        }
+        if let Some(v) = self.schedule_to_close_timeout.as_ref() {
+            struct_ser.serialize_field("scheduleToCloseTimeout", v)?;
+        }
+        if let Some(v) = self.schedule_to_start_timeout.as_ref() {
+            struct_ser.serialize_field("scheduleToStartTimeout", v)?;
+        }
+        if let Some(v) = self.start_to_close_timeout.as_ref() {
+            struct_ser.serialize_field("startToCloseTimeout", v)?;
+        }
+        if let Some(v) = self.heartbeat_timeout.as_ref() {
+            struct_ser.serialize_field("heartbeatTimeout", v)?;
+        }
+        if let Some(v) = self.retry_policy.as_ref() {
+            struct_ser.serialize_field("retryPolicy", v)?;
+        }
+        if self.request_eager_execution {
+            struct_ser.serialize_field("requestEagerExecution", &self.request_eager_execution)?;
+        }
+        if self.use_workflow_build_id {
+            struct_ser.serialize_field("useWorkflowBuildId", &self.use_workflow_build_id)?;
+        }
+        if let Some(v) = self.priority.as_ref() {
+            struct_ser.serialize_field("priority", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for ScheduleActivityTaskCommandAttributes {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "activity_id",
+            "activityId",
+            "activity_type",
+            "activityType",
+            "task_queue",
+            "taskQueue",
+            "header",
+            "input",
+            "schedule_to_close_timeout",
+            "scheduleToCloseTimeout",
+            "schedule_to_start_timeout",
+            "scheduleToStartTimeout",
+            "start_to_close_timeout",
+            "startToCloseTimeout",
+            "heartbeat_timeout",
+            "heartbeatTimeout",
+            "retry_policy",
+            "retryPolicy",
+            "request_eager_execution",
+            "requestEagerExecution",
+            "use_workflow_build_id",
+            "useWorkflowBuildId",
+            "priority",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            ActivityId,
+            ActivityType,
+            TaskQueue,
+            Header,
+            Input,
+            ScheduleToCloseTimeout,
+            ScheduleToStartTimeout,
+            StartToCloseTimeout,
+            HeartbeatTimeout,
+            RetryPolicy,
+            RequestEagerExecution,
+            UseWorkflowBuildId,
+            Priority,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "activityId" | "activity_id" => Ok(GeneratedField::ActivityId),
+                            "activityType" | "activity_type" => Ok(GeneratedField::ActivityType),
+                            "taskQueue" | "task_queue" => Ok(GeneratedField::TaskQueue),
+                            "header" => Ok(GeneratedField::Header),
+                            "input" => Ok(GeneratedField::Input),
+                            "scheduleToCloseTimeout" | "schedule_to_close_timeout" => Ok(GeneratedField::ScheduleToCloseTimeout),
+                            "scheduleToStartTimeout" | "schedule_to_start_timeout" => Ok(GeneratedField::ScheduleToStartTimeout),
+                            "startToCloseTimeout" | "start_to_close_timeout" => Ok(GeneratedField::StartToCloseTimeout),
+                            "heartbeatTimeout" | "heartbeat_timeout" => Ok(GeneratedField::HeartbeatTimeout),
+                            "retryPolicy" | "retry_policy" => Ok(GeneratedField::RetryPolicy),
+                            "requestEagerExecution" | "request_eager_execution" => Ok(GeneratedField::RequestEagerExecution),
+                            "useWorkflowBuildId" | "use_workflow_build_id" => Ok(GeneratedField::UseWorkflowBuildId),
+                            "priority" => Ok(GeneratedField::Priority),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = ScheduleActivityTaskCommandAttributes;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct temporal.api.command.v1.ScheduleActivityTaskCommandAttributes")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<ScheduleActivityTaskCommandAttributes, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut activity_id__ = None;
+                let mut activity_type__ = None;
+                let mut task_queue__ = None;
+                let mut header__ = None;
+                let mut input__ = None;
+                let mut schedule_to_close_timeout__ = None;
+                let mut schedule_to_start_timeout__ = None;
+                let mut start_to_close_timeout__ = None;
+                let mut heartbeat_timeout__ = None;
+                let mut retry_policy__ = None;
+                let mut request_eager_execution__ = None;
+                let mut use_workflow_build_id__ = None;
+                let mut priority__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::ActivityId => {
+                            if activity_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("activityId"));
+                            }
+                            activity_id__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::ActivityType => {
+                            if activity_type__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("activityType"));
+                            }
+                            activity_type__ = map_.next_value()?;
+                        }
+                        GeneratedField::TaskQueue => {
+                            if task_queue__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("taskQueue"));
+                            }
+                            task_queue__ = map_.next_value()?;
+                        }
+                        GeneratedField::Header => {
+                            if header__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("header"));
+                            }
+                            header__ = map_.next_value()?;
+                        }
+                        GeneratedField::Input => {
+                            if input__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("input"));
+                            }
+                            input__ = map_.next_value()?;
+                        }
+                        GeneratedField::ScheduleToCloseTimeout => {
+                            if schedule_to_close_timeout__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("scheduleToCloseTimeout"));
+                            }
+                            schedule_to_close_timeout__ = map_.next_value()?;
+                        }
+                        GeneratedField::ScheduleToStartTimeout => {
+                            if schedule_to_start_timeout__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("scheduleToStartTimeout"));
+                            }
+                            schedule_to_start_timeout__ = map_.next_value()?;
+                        }
+                        GeneratedField::StartToCloseTimeout => {
+                            if start_to_close_timeout__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("startToCloseTimeout"));
+                            }
+                            start_to_close_timeout__ = map_.next_value()?;
+                        }
+                        GeneratedField::HeartbeatTimeout => {
+                            if heartbeat_timeout__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("heartbeatTimeout"));
+                            }
+                            heartbeat_timeout__ = map_.next_value()?;
+                        }
+                        GeneratedField::RetryPolicy => {
+                            if retry_policy__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("retryPolicy"));
+                            }
+                            retry_policy__ = map_.next_value()?;
+                        }
+                        GeneratedField::RequestEagerExecution => {
+                            if request_eager_execution__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("requestEagerExecution"));
+                            }
+                            request_eager_execution__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::UseWorkflowBuildId => {
+                            if use_workflow_build_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("useWorkflowBuildId"));
+                            }
+                            use_workflow_build_id__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::Priority => {
+                            if priority__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("priority"));
+                            }
+                            priority__ = map_.next_value()?;
+                        }
+                    }
+                }
+                Ok(ScheduleActivityTaskCommandAttributes {
+                    activity_id: activity_id__.unwrap_or_default(),
+                    activity_type: activity_type__,
+                    task_queue: task_queue__,
+                    header: header__,
+                    input: input__,
+                    schedule_to_close_timeout: schedule_to_close_timeout__,
+                    schedule_to_start_timeout: schedule_to_start_timeout__,
+                    start_to_close_timeout: start_to_close_timeout__,
+                    heartbeat_timeout: heartbeat_timeout__,
+                    retry_policy: retry_policy__,
+                    request_eager_execution: request_eager_execution__.unwrap_or_default(),
+                    use_workflow_build_id: use_workflow_build_id__.unwrap_or_default(),
+                    priority: priority__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("temporal.api.command.v1.ScheduleActivityTaskCommandAttributes", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for ScheduleNexusOperationCommandAttributes {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.endpoint.is_empty() {
+            len += 1;
+        }
+        if !self.service.is_empty() {
+            len += 1;
+        }
+        if !self.operation.is_empty() {
+            len += 1;
+        }
+        if self.input.is_some() {
+            len += 1;
+        }
+        if self.schedule_to_close_timeout.is_some() {
+            len += 1;
+        }
+        if !self.nexus_header.is_empty() {
+            len += 1;
+        }
+        if self.schedule_to_start_timeout.is_some() {
+            len += 1;
+        }
+        if self.start_to_close_timeout.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("temporal.api.command.v1.ScheduleNexusOperationCommandAttributes", len)?;
+        if !self.endpoint.is_empty() {
+            struct_ser.serialize_field("endpoint", &self.endpoint)?;
+        }
+        if !self.service.is_empty() {
+            struct_ser.serialize_field("service", &self.service)?;
+        }
+        if !self.operation.is_empty() {
+            struct_ser.serialize_field("operation", &self.operation)?;
+        }
+        if let Some(v) = self.input.as_ref() {
+            struct_ser.serialize_field("input", v)?;
+        }
+        if let Some(v) = self.schedule_to_close_timeout.as_ref() {
+            struct_ser.serialize_field("scheduleToCloseTimeout", v)?;
+        }
+        if !self.nexus_header.is_empty() {
+            struct_ser.serialize_field("nexusHeader", &self.nexus_header)?;
+        }
+        if let Some(v) = self.schedule_to_start_timeout.as_ref() {
+            struct_ser.serialize_field("scheduleToStartTimeout", v)?;
+        }
+        if let Some(v) = self.start_to_close_timeout.as_ref() {
+            struct_ser.serialize_field("startToCloseTimeout", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for ScheduleNexusOperationCommandAttributes {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "endpoint",
+            "service",
+            "operation",
+            "input",
+            "schedule_to_close_timeout",
+            "scheduleToCloseTimeout",
+            "nexus_header",
+            "nexusHeader",
+            "schedule_to_start_timeout",
+            "scheduleToStartTimeout",
+            "start_to_close_timeout",
+            "startToCloseTimeout",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Endpoint,
+            Service,
+            Operation,
+            Input,
+            ScheduleToCloseTimeout,
+            NexusHeader,
+            ScheduleToStartTimeout,
+            StartToCloseTimeout,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "endpoint" => Ok(GeneratedField::Endpoint),
+                            "service" => Ok(GeneratedField::Service),
+                            "operation" => Ok(GeneratedField::Operation),
+                            "input" => Ok(GeneratedField::Input),
+                            "scheduleToCloseTimeout" | "schedule_to_close_timeout" => Ok(GeneratedField::ScheduleToCloseTimeout),
+                            "nexusHeader" | "nexus_header" => Ok(GeneratedField::NexusHeader),
+                            "scheduleToStartTimeout" | "schedule_to_start_timeout" => Ok(GeneratedField::ScheduleToStartTimeout),
+                            "startToCloseTimeout" | "start_to_close_timeout" => Ok(GeneratedField::StartToCloseTimeout),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = ScheduleNexusOperationCommandAttributes;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct temporal.api.command.v1.ScheduleNexusOperationCommandAttributes")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<ScheduleNexusOperationCommandAttributes, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut endpoint__ = None;
+                let mut service__ = None;
+                let mut operation__ = None;
+                let mut input__ = None;
+                let mut schedule_to_close_timeout__ = None;
+                let mut nexus_header__ = None;
+                let mut schedule_to_start_timeout__ = None;
+                let mut start_to_close_timeout__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Endpoint => {
+                            if endpoint__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("endpoint"));
+                            }
+                            endpoint__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::Service => {
+                            if service__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("service"));
+                            }
+                            service__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::Operation => {
+                            if operation__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("operation"));
+                            }
+                            operation__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::Input => {
+                            if input__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("input"));
+                            }
+                            input__ = map_.next_value()?;
+                        }
+                        GeneratedField::ScheduleToCloseTimeout => {
+                            if schedule_to_close_timeout__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("scheduleToCloseTimeout"));
+                            }
+                            schedule_to_close_timeout__ = map_.next_value()?;
+                        }
+                        GeneratedField::NexusHeader => {
+                            if nexus_header__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("nexusHeader"));
+                            }
+                            nexus_header__ = Some(
+                                map_.next_value::<std::collections::HashMap<_, _>>()?
+                            );
+                        }
+                        GeneratedField::ScheduleToStartTimeout => {
+                            if schedule_to_start_timeout__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("scheduleToStartTimeout"));
+                            }
+                            schedule_to_start_timeout__ = map_.next_value()?;
+                        }
+                        GeneratedField::StartToCloseTimeout => {
+                            if start_to_close_timeout__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("startToCloseTimeout"));
+                            }
+                            start_to_close_timeout__ = map_.next_value()?;
+                        }
+                    }
+                }
+                Ok(ScheduleNexusOperationCommandAttributes {
+                    endpoint: endpoint__.unwrap_or_default(),
+                    service: service__.unwrap_or_default(),
+                    operation: operation__.unwrap_or_default(),
+                    input: input__,
+                    schedule_to_close_timeout: schedule_to_close_timeout__,
+                    nexus_header: nexus_header__.unwrap_or_default(),
+                    schedule_to_start_timeout: schedule_to_start_timeout__,
+                    start_to_close_timeout: start_to_close_timeout__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("temporal.api.command.v1.ScheduleNexusOperationCommandAttributes", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for SignalExternalWorkflowExecutionCommandAttributes {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.namespace.is_empty() {
+            len += 1;
+        }
+        if self.execution.is_some() {
+            len += 1;
+        }
+        if !self.signal_name.is_empty() {
+            len += 1;
+        }
+        if self.input.is_some() {
+            len += 1;
+        }
+        if !self.control.is_empty() {
+            len += 1;
+        }
+        if self.child_workflow_only {
+            len += 1;
+        }
+        if self.header.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("temporal.api.command.v1.SignalExternalWorkflowExecutionCommandAttributes", len)?;
+        if !self.namespace.is_empty() {
+            struct_ser.serialize_field("namespace", &self.namespace)?;
+        }
+        if let Some(v) = self.execution.as_ref() {
+            struct_ser.serialize_field("execution", v)?;
+        }
+        if !self.signal_name.is_empty() {
+            struct_ser.serialize_field("signalName", &self.signal_name)?;
+        }
+        if let Some(v) = self.input.as_ref() {
+            struct_ser.serialize_field("input", v)?;
+        }
+        if !self.control.is_empty() {
+            struct_ser.serialize_field("control", &self.control)?;
+        }
+        if self.child_workflow_only {
+            struct_ser.serialize_field("childWorkflowOnly", &self.child_workflow_only)?;
+        }
+        if let Some(v) = self.header.as_ref() {
+            struct_ser.serialize_field("header", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for SignalExternalWorkflowExecutionCommandAttributes {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "namespace",
+            "execution",
+            "signal_name",
+            "signalName",
+            "input",
+            "control",
+            "child_workflow_only",
+            "childWorkflowOnly",
+            "header",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Namespace,
+            Execution,
+            SignalName,
+            Input,
+            Control,
+            ChildWorkflowOnly,
+            Header,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "namespace" => Ok(GeneratedField::Namespace),
+                            "execution" => Ok(GeneratedField::Execution),
+                            "signalName" | "signal_name" => Ok(GeneratedField::SignalName),
+                            "input" => Ok(GeneratedField::Input),
+                            "control" => Ok(GeneratedField::Control),
+                            "childWorkflowOnly" | "child_workflow_only" => Ok(GeneratedField::ChildWorkflowOnly),
+                            "header" => Ok(GeneratedField::Header),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = SignalExternalWorkflowExecutionCommandAttributes;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct temporal.api.command.v1.SignalExternalWorkflowExecutionCommandAttributes")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<SignalExternalWorkflowExecutionCommandAttributes, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut namespace__ = None;
+                let mut execution__ = None;
+                let mut signal_name__ = None;
+                let mut input__ = None;
+                let mut control__ = None;
+                let mut child_workflow_only__ = None;
+                let mut header__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Namespace => {
+                            if namespace__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("namespace"));
+                            }
+                            namespace__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::Execution => {
+                            if execution__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("execution"));
+                            }
+                            execution__ = map_.next_value()?;
+                        }
+                        GeneratedField::SignalName => {
+                            if signal_name__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("signalName"));
+                            }
+                            signal_name__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::Input => {
+                            if input__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("input"));
+                            }
+                            input__ = map_.next_value()?;
+                        }
+                        GeneratedField::Control => {
+                            if control__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("control"));
+                            }
+                            control__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::ChildWorkflowOnly => {
+                            if child_workflow_only__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("childWorkflowOnly"));
+                            }
+                            child_workflow_only__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::Header => {
+                            if header__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("header"));
+                            }
+                            header__ = map_.next_value()?;
+                        }
+                    }
+                }
+                Ok(SignalExternalWorkflowExecutionCommandAttributes {
+                    namespace: namespace__.unwrap_or_default(),
+                    execution: execution__,
+                    signal_name: signal_name__.unwrap_or_default(),
+                    input: input__,
+                    control: control__.unwrap_or_default(),
+                    child_workflow_only: child_workflow_only__.unwrap_or_default(),
+                    header: header__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("temporal.api.command.v1.SignalExternalWorkflowExecutionCommandAttributes", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for StartChildWorkflowExecutionCommandAttributes {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.namespace.is_empty() {
+            len += 1;
+        }
+        if !self.workflow_id.is_empty() {
+            len += 1;
+        }
+        if self.workflow_type.is_some() {
+            len += 1;
+        }
+        if self.task_queue.is_some() {
+            len += 1;
+        }
+        if self.input.is_some() {
+            len += 1;
+        }
+        if self.workflow_execution_timeout.is_some() {
+            len += 1;
+        }
+        if self.workflow_run_timeout.is_some() {
+            len += 1;
+        }
+        if self.workflow_task_timeout.is_some() {
+            len += 1;
+        }
+        if self.parent_close_policy != 0 {
+            len += 1;
+        }
+        if !self.control.is_empty() {
+            len += 1;
+        }
+        if self.workflow_id_reuse_policy != 0 {
+            len += 1;
+        }
+        if self.retry_policy.is_some() {
+            len += 1;
+        }
+        if !self.cron_schedule.is_empty() {
+            len += 1;
+        }
+        if self.header.is_some() {
+            len += 1;
+        }
+        if self.memo.is_some() {
+            len += 1;
+        }
+        if self.search_attributes.is_some() {
+            len += 1;
+        }
+        if self.inherit_build_id {
+            len += 1;
+        }
+        if self.priority.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("temporal.api.command.v1.StartChildWorkflowExecutionCommandAttributes", len)?;
+        if !self.namespace.is_empty() {
+            struct_ser.serialize_field("namespace", &self.namespace)?;
+        }
+        if !self.workflow_id.is_empty() {
+            struct_ser.serialize_field("workflowId", &self.workflow_id)?;
+        }
+        if let Some(v) = self.workflow_type.as_ref() {
+            struct_ser.serialize_field("workflowType", v)?;
+        }
+        if let Some(v) = self.task_queue.as_ref() {
+            struct_ser.serialize_field("taskQueue", v)?;
+        }
+        if let Some(v) = self.input.as_ref() {
+            struct_ser.serialize_field("input", v)?;
+        }
+        if let Some(v) = self.workflow_execution_timeout.as_ref() {
+            struct_ser.serialize_field("workflowExecutionTimeout", v)?;
+        }
+        if let Some(v) = self.workflow_run_timeout.as_ref() {
+            struct_ser.serialize_field("workflowRunTimeout", v)?;
+        }
+        if let Some(v) = self.workflow_task_timeout.as_ref() {
+            struct_ser.serialize_field("workflowTaskTimeout", v)?;
+        }
+        if self.parent_close_policy != 0 {
+            let v = super::super::enums::v1::ParentClosePolicy::try_from(self.parent_close_policy)
+                .map_err(|_| serde::ser::Error::custom(format!("Invalid variant {}", self.parent_close_policy)))?;
+            struct_ser.serialize_field("parentClosePolicy", &v)?;
+        }
+        if !self.control.is_empty() {
+            struct_ser.serialize_field("control", &self.control)?;
+        }
+        if self.workflow_id_reuse_policy != 0 {
+            let v = super::super::enums::v1::WorkflowIdReusePolicy::try_from(self.workflow_id_reuse_policy)
+                .map_err(|_| serde::ser::Error::custom(format!("Invalid variant {}", self.workflow_id_reuse_policy)))?;
+            struct_ser.serialize_field("workflowIdReusePolicy", &v)?;
+        }
+        if let Some(v) = self.retry_policy.as_ref() {
+            struct_ser.serialize_field("retryPolicy", v)?;
+        }
+        if !self.cron_schedule.is_empty() {
+            struct_ser.serialize_field("cronSchedule", &self.cron_schedule)?;
+        }
+        if let Some(v) = self.header.as_ref() {
+            struct_ser.serialize_field("header", v)?;
+        }
+        if let Some(v) = self.memo.as_ref() {
+            struct_ser.serialize_field("memo", v)?;
+        }
+        if let Some(v) = self.search_attributes.as_ref() {
+            struct_ser.serialize_field("searchAttributes", v)?;
+        }
+        if self.inherit_build_id {
+            struct_ser.serialize_field("inheritBuildId", &self.inherit_build_id)?;
+        }
+        if let Some(v) = self.priority.as_ref() {
+            struct_ser.serialize_field("priority", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for StartChildWorkflowExecutionCommandAttributes {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "namespace",
+            "workflow_id",
+            "workflowId",
+            "workflow_type",
+            "workflowType",
+            "task_queue",
+            "taskQueue",
+            "input",
+            "workflow_execution_timeout",
+            "workflowExecutionTimeout",
+            "workflow_run_timeout",
+            "workflowRunTimeout",
+            "workflow_task_timeout",
+            "workflowTaskTimeout",
+            "parent_close_policy",
+            "parentClosePolicy",
+            "control",
+            "workflow_id_reuse_policy",
+            "workflowIdReusePolicy",
+            "retry_policy",
+            "retryPolicy",
+            "cron_schedule",
+            "cronSchedule",
+            "header",
+            "memo",
+            "search_attributes",
+            "searchAttributes",
+            "inherit_build_id",
+            "inheritBuildId",
+            "priority",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Namespace,
+            WorkflowId,
+            WorkflowType,
+            TaskQueue,
+            Input,
+            WorkflowExecutionTimeout,
+            WorkflowRunTimeout,
+            WorkflowTaskTimeout,
+            ParentClosePolicy,
+            Control,
+            WorkflowIdReusePolicy,
+            RetryPolicy,
+            CronSchedule,
+            Header,
+            Memo,
+            SearchAttributes,
+            InheritBuildId,
+            Priority,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "namespace" => Ok(GeneratedField::Namespace),
+                            "workflowId" | "workflow_id" => Ok(GeneratedField::WorkflowId),
+                            "workflowType" | "workflow_type" => Ok(GeneratedField::WorkflowType),
+                            "taskQueue" | "task_queue" => Ok(GeneratedField::TaskQueue),
+                            "input" => Ok(GeneratedField::Input),
+                            "workflowExecutionTimeout" | "workflow_execution_timeout" => Ok(GeneratedField::WorkflowExecutionTimeout),
+                            "workflowRunTimeout" | "workflow_run_timeout" => Ok(GeneratedField::WorkflowRunTimeout),
+                            "workflowTaskTimeout" | "workflow_task_timeout" => Ok(GeneratedField::WorkflowTaskTimeout),
+                            "parentClosePolicy" | "parent_close_policy" => Ok(GeneratedField::ParentClosePolicy),
+                            "control" => Ok(GeneratedField::Control),
+                            "workflowIdReusePolicy" | "workflow_id_reuse_policy" => Ok(GeneratedField::WorkflowIdReusePolicy),
+                            "retryPolicy" | "retry_policy" => Ok(GeneratedField::RetryPolicy),
+                            "cronSchedule" | "cron_schedule" => Ok(GeneratedField::CronSchedule),
+                            "header" => Ok(GeneratedField::Header),
+                            "memo" => Ok(GeneratedField::Memo),
+                            "searchAttributes" | "search_attributes" => Ok(GeneratedField::SearchAttributes),
+                            "inheritBuildId" | "inherit_build_id" => Ok(GeneratedField::InheritBuildId),
+                            "priority" => Ok(GeneratedField::Priority),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = StartChildWorkflowExecutionCommandAttributes;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct temporal.api.command.v1.StartChildWorkflowExecutionCommandAttributes")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<StartChildWorkflowExecutionCommandAttributes, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut namespace__ = None;
+                let mut workflow_id__ = None;
+                let mut workflow_type__ = None;
+                let mut task_queue__ = None;
+                let mut input__ = None;
+                let mut workflow_execution_timeout__ = None;
+                let mut workflow_run_timeout__ = None;
+                let mut workflow_task_timeout__ = None;
+                let mut parent_close_policy__ = None;
+                let mut control__ = None;
+                let mut workflow_id_reuse_policy__ = None;
+                let mut retry_policy__ = None;
+                let mut cron_schedule__ = None;
+                let mut header__ = None;
+                let mut memo__ = None;
+                let mut search_attributes__ = None;
+                let mut inherit_build_id__ = None;
+                let mut priority__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Namespace => {
+                            if namespace__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("namespace"));
+                            }
+                            namespace__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::WorkflowId => {
+                            if workflow_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("workflowId"));
+                            }
+                            workflow_id__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::WorkflowType => {
+                            if workflow_type__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("workflowType"));
+                            }
+                            workflow_type__ = map_.next_value()?;
+                        }
+                        GeneratedField::TaskQueue => {
+                            if task_queue__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("taskQueue"));
+                            }
+                            task_queue__ = map_.next_value()?;
+                        }
+                        GeneratedField::Input => {
+                            if input__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("input"));
+                            }
+                            input__ = map_.next_value()?;
+                        }
+                        GeneratedField::WorkflowExecutionTimeout => {
+                            if workflow_execution_timeout__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("workflowExecutionTimeout"));
+                            }
+                            workflow_execution_timeout__ = map_.next_value()?;
+                        }
+                        GeneratedField::WorkflowRunTimeout => {
+                            if workflow_run_timeout__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("workflowRunTimeout"));
+                            }
+                            workflow_run_timeout__ = map_.next_value()?;
+                        }
+                        GeneratedField::WorkflowTaskTimeout => {
+                            if workflow_task_timeout__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("workflowTaskTimeout"));
+                            }
+                            workflow_task_timeout__ = map_.next_value()?;
+                        }
+                        GeneratedField::ParentClosePolicy => {
+                            if parent_close_policy__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("parentClosePolicy"));
+                            }
+                            parent_close_policy__ = Some(map_.next_value::<super::super::enums::v1::ParentClosePolicy>()? as i32);
+                        }
+                        GeneratedField::Control => {
+                            if control__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("control"));
+                            }
+                            control__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::WorkflowIdReusePolicy => {
+                            if workflow_id_reuse_policy__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("workflowIdReusePolicy"));
+                            }
+                            workflow_id_reuse_policy__ = Some(map_.next_value::<super::super::enums::v1::WorkflowIdReusePolicy>()? as i32);
+                        }
+                        GeneratedField::RetryPolicy => {
+                            if retry_policy__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("retryPolicy"));
+                            }
+                            retry_policy__ = map_.next_value()?;
+                        }
+                        GeneratedField::CronSchedule => {
+                            if cron_schedule__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("cronSchedule"));
+                            }
+                            cron_schedule__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::Header => {
+                            if header__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("header"));
+                            }
+                            header__ = map_.next_value()?;
+                        }
+                        GeneratedField::Memo => {
+                            if memo__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("memo"));
+                            }
+                            memo__ = map_.next_value()?;
+                        }
+                        GeneratedField::SearchAttributes => {
+                            if search_attributes__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("searchAttributes"));
+                            }
+                            search_attributes__ = map_.next_value()?;
+                        }
+                        GeneratedField::InheritBuildId => {
+                            if inherit_build_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("inheritBuildId"));
+                            }
+                            inherit_build_id__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::Priority => {
+                            if priority__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("priority"));
+                            }
+                            priority__ = map_.next_value()?;
+                        }
+                    }
+                }
+                Ok(StartChildWorkflowExecutionCommandAttributes {
+                    namespace: namespace__.unwrap_or_default(),
+                    workflow_id: workflow_id__.unwrap_or_default(),
+                    workflow_type: workflow_type__,
+                    task_queue: task_queue__,
+                    input: input__,
+                    workflow_execution_timeout: workflow_execution_timeout__,
+                    workflow_run_timeout: workflow_run_timeout__,
+                    workflow_task_timeout: workflow_task_timeout__,
+                    parent_close_policy: parent_close_policy__.unwrap_or_default(),
+                    control: control__.unwrap_or_default(),
+                    workflow_id_reuse_policy: workflow_id_reuse_policy__.unwrap_or_default(),
+                    retry_policy: retry_policy__,
+                    cron_schedule: cron_schedule__.unwrap_or_default(),
+                    header: header__,
+                    memo: memo__,
+                    search_attributes: search_attributes__,
+                    inherit_build_id: inherit_build_id__.unwrap_or_default(),
+                    priority: priority__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("temporal.api.command.v1.StartChildWorkflowExecutionCommandAttributes", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for StartTimerCommandAttributes {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.timer_id.is_empty() {
+            len += 1;
+        }
+        if self.start_to_fire_timeout.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("temporal.api.command.v1.StartTimerCommandAttributes", len)?;
+        if !self.timer_id.is_empty() {
+            struct_ser.serialize_field("timerId", &self.timer_id)?;
+        }
+        if let Some(v) = self.start_to_fire_timeout.as_ref() {
+            struct_ser.serialize_field("startToFireTimeout", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for StartTimerCommandAttributes {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "timer_id",
+            "timerId",
+            "start_to_fire_timeout",
+            "startToFireTimeout",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            TimerId,
+            StartToFireTimeout,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "timerId" | "timer_id" => Ok(GeneratedField::TimerId),
+                            "startToFireTimeout" | "start_to_fire_timeout" => Ok(GeneratedField::StartToFireTimeout),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = StartTimerCommandAttributes;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct temporal.api.command.v1.StartTimerCommandAttributes")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<StartTimerCommandAttributes, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut timer_id__ = None;
+                let mut start_to_fire_timeout__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::TimerId => {
+                            if timer_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("timerId"));
+                            }
+                            timer_id__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::StartToFireTimeout => {
+                            if start_to_fire_timeout__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("startToFireTimeout"));
+                            }
+                            start_to_fire_timeout__ = map_.next_value()?;
+                        }
+                    }
+                }
+                Ok(StartTimerCommandAttributes {
+                    timer_id: timer_id__.unwrap_or_default(),
+                    start_to_fire_timeout: start_to_fire_timeout__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("temporal.api.command.v1.StartTimerCommandAttributes", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for UpsertWorkflowSearchAttributesCommandAttributes {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.search_attributes.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("temporal.api.command.v1.UpsertWorkflowSearchAttributesCommandAttributes", len)?;
+        if let Some(v) = self.search_attributes.as_ref() {
+            struct_ser.serialize_field("searchAttributes", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for UpsertWorkflowSearchAttributesCommandAttributes {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "search_attributes",
+            "searchAttributes",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            SearchAttributes,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "searchAttributes" | "search_attributes" => Ok(GeneratedField::SearchAttributes),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = UpsertWorkflowSearchAttributesCommandAttributes;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct temporal.api.command.v1.UpsertWorkflowSearchAttributesCommandAttributes")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<UpsertWorkflowSearchAttributesCommandAttributes, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut search_attributes__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::SearchAttributes => {
+                            if search_attributes__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("searchAttributes"));
+                            }
+                            search_attributes__ = map_.next_value()?;
+                        }
+                    }
+                }
+                Ok(UpsertWorkflowSearchAttributesCommandAttributes {
+                    search_attributes: search_attributes__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("temporal.api.command.v1.UpsertWorkflowSearchAttributesCommandAttributes", FIELDS, GeneratedVisitor)
+    }
+}
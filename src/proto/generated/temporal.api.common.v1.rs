@@ -90,7 +90,7 @@ pub struct ActivityType {
 pub struct RetryPolicy {
     /// Interval of the first retry. If retryBackoffCoefficient is 1.0 then it is used for all retries.
     #[prost(message, optional, tag = "1")]
-    pub initial_interval: ::core::option::Option<::prost_types::Duration>,
+    pub initial_interval: ::core::option::Option<::pbjson_types::Duration>,
     /// Coefficient used to calculate the next retry interval.
     /// The next retry interval is previous interval multiplied by the coefficient.
     /// Must be 1 or larger.
@@ -99,7 +99,7 @@ pub struct RetryPolicy {
     /// Maximum interval between retries. Exponential backoff leads to interval increase.
     /// This value is the cap of the increase. Default is 100x of the initial interval.
     #[prost(message, optional, tag = "3")]
-    pub maximum_interval: ::core::option::Option<::prost_types::Duration>,
+    pub maximum_interval: ::core::option::Option<::pbjson_types::Duration>,
     /// Maximum number of attempts. When exceeded the retries stop even if not expired yet.
     /// 1 disables retries. 0 means unlimited (up to the timeouts)
     #[prost(int32, tag = "4")]
@@ -184,10 +184,10 @@ pub mod reset_options {
     pub enum Target {
         /// Resets to the first workflow task completed or started event.
         #[prost(message, tag = "1")]
-        FirstWorkflowTask(()),
+        FirstWorkflowTask(::pbjson_types::Empty),
         /// Resets to the last workflow task completed or started event.
         #[prost(message, tag = "2")]
-        LastWorkflowTask(()),
+        LastWorkflowTask(::pbjson_types::Empty),
         /// The id of a specific `WORKFLOW_TASK_COMPLETED`,`WORKFLOW_TASK_TIMED_OUT`, `WORKFLOW_TASK_FAILED`, or
         /// `WORKFLOW_TASK_STARTED` event to reset to.
         /// Note that this option doesn't make sense when used as part of a batch request.
@@ -250,7 +250,7 @@ pub mod callback {
 /// workflow B, and vice-versa.
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct Link {
-    #[prost(oneof = "link::Variant", tags = "1, 2")]
+    #[prost(oneof = "link::Variant", tags = "1, 2, 3, 4, 5")]
     pub variant: ::core::option::Option<link::Variant>,
 }
 /// Nested message and enum types in `Link`.
@@ -310,14 +310,65 @@ pub mod link {
         #[prost(string, tag = "1")]
         pub job_id: ::prost::alloc::string::String,
     }
+    /// A link to an activity.
+    #[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct Activity {
+        #[prost(string, tag = "1")]
+        pub namespace: ::prost::alloc::string::String,
+        #[prost(string, tag = "2")]
+        pub activity_id: ::prost::alloc::string::String,
+        #[prost(string, tag = "3")]
+        pub run_id: ::prost::alloc::string::String,
+    }
+    /// A link to a standalone Nexus operation.
+    #[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct NexusOperation {
+        #[prost(string, tag = "1")]
+        pub namespace: ::prost::alloc::string::String,
+        #[prost(string, tag = "2")]
+        pub operation_id: ::prost::alloc::string::String,
+        #[prost(string, tag = "3")]
+        pub run_id: ::prost::alloc::string::String,
+    }
+    /// A link to a workflow execution. This is a more general version of WorkflowEvent that doesn't specify a
+    /// particular event within the workflow, useful when you want to link to a workflow but there is no particular event to link to,
+    /// such as a Query or a Rejected Update.
+    #[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct Workflow {
+        #[prost(string, tag = "1")]
+        pub namespace: ::prost::alloc::string::String,
+        #[prost(string, tag = "2")]
+        pub workflow_id: ::prost::alloc::string::String,
+        #[prost(string, tag = "3")]
+        pub run_id: ::prost::alloc::string::String,
+        #[prost(string, tag = "4")]
+        pub reason: ::prost::alloc::string::String,
+    }
     #[derive(Clone, PartialEq, ::prost::Oneof)]
     pub enum Variant {
         #[prost(message, tag = "1")]
         WorkflowEvent(WorkflowEvent),
         #[prost(message, tag = "2")]
         BatchJob(BatchJob),
+        #[prost(message, tag = "3")]
+        Activity(Activity),
+        #[prost(message, tag = "4")]
+        NexusOperation(NexusOperation),
+        #[prost(message, tag = "5")]
+        Workflow(Workflow),
     }
 }
+/// Principal is an authenticated caller identity computed by the server from trusted
+/// authentication context.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Principal {
+    /// Low-cardinality category of the principal (e.g., "jwt", "users").
+    #[prost(string, tag = "1")]
+    pub r#type: ::prost::alloc::string::String,
+    /// Identifier within that category (e.g., sub JWT claim, email address).
+    #[prost(string, tag = "2")]
+    pub name: ::prost::alloc::string::String,
+}
 /// Priority contains metadata that controls relative ordering of task processing
 /// when tasks are backed up in a queue. Initially, Priority will be used in
 /// matching (workflow and activity) task queues. Later it may be used in history
@@ -435,3 +486,17 @@ pub mod worker_selector {
         WorkerInstanceKey(::prost::alloc::string::String),
     }
 }
+/// When starting an execution with a conflict policy that uses an existing execution and there is already an existing
+/// running execution, OnConflictOptions defines actions to be taken on the existing running execution.
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct OnConflictOptions {
+    /// Attaches the request ID to the running execution.
+    #[prost(bool, tag = "1")]
+    pub attach_request_id: bool,
+    /// Attaches the completion callbacks to the running execution.
+    #[prost(bool, tag = "2")]
+    pub attach_completion_callbacks: bool,
+    /// Attaches the links to the running execution.
+    #[prost(bool, tag = "3")]
+    pub attach_links: bool,
+}
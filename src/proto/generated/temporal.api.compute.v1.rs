@@ -0,0 +1,106 @@
+// This file is @generated by prost-build.
+/// ComputeProvider stores information used by a worker control plane controller
+/// to respond to worker lifecycle events. For example, when a Task is received
+/// on a TaskQueue that has no active pollers, a serverless worker lifecycle
+/// controller might need to invoke an AWS Lambda Function that itself ends up
+/// calling the SDK's worker.New() function.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ComputeProvider {
+    /// Type of the compute provider. This string is implementation-specific and
+    /// can be used by implementations to understand how to interpret the
+    /// contents of the provider_details field.
+    #[prost(string, tag = "1")]
+    pub r#type: ::prost::alloc::string::String,
+    /// Contains provider-specific instructions and configuration.
+    /// For server-implemented providers, use the SDK's default content
+    /// converter to ensure the server can understand it.
+    /// For remote-implemented providers, you might use your own content
+    /// converters according to what the remote endpoints understand.
+    #[prost(message, optional, tag = "2")]
+    pub details: ::core::option::Option<super::super::common::v1::Payload>,
+    /// Optional. If the compute provider is a Nexus service, this should point
+    /// there.
+    #[prost(string, tag = "10")]
+    pub nexus_endpoint: ::prost::alloc::string::String,
+}
+/// ComputeScaler instructs the Temporal Service when to scale up or down the number of
+/// Workers that comprise a WorkerDeployment.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ComputeScaler {
+    /// Type of the compute scaler. this string is implementation-specific and
+    /// can be used by implementations to understand how to interpret the
+    /// contents of the scaler_details field.
+    #[prost(string, tag = "1")]
+    pub r#type: ::prost::alloc::string::String,
+    /// Contains scaler-specific instructions and configuration.
+    /// For server-implemented scalers, use the SDK's default data
+    /// converter to ensure the server can understand it.
+    /// For remote-implemented scalers, you might use your own data
+    /// converters according to what the remote endpoints understand.
+    #[prost(message, optional, tag = "2")]
+    pub details: ::core::option::Option<super::super::common::v1::Payload>,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ComputeConfigScalingGroup {
+    /// Optional. The set of task queue types this scaling group serves.
+    /// If not provided, this scaling group serves all not otherwise defined
+    /// task types.
+    #[prost(enumeration = "super::super::enums::v1::TaskQueueType", repeated, tag = "1")]
+    pub task_queue_types: ::prost::alloc::vec::Vec<i32>,
+    /// Stores instructions for a worker control plane controller how to respond
+    /// to worker lifeycle events.
+    #[prost(message, optional, tag = "3")]
+    pub provider: ::core::option::Option<ComputeProvider>,
+    /// Informs a worker lifecycle controller *when* and *how often* to perform
+    /// certain worker lifecycle actions like starting a serverless worker.
+    #[prost(message, optional, tag = "4")]
+    pub scaler: ::core::option::Option<ComputeScaler>,
+}
+/// ComputeConfig stores configuration that helps a worker control plane
+/// controller understand *when* and *how* to respond to worker lifecycle
+/// events.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ComputeConfig {
+    /// Each scaling group describes a compute config for a specific subset of the worker
+    /// deployment version: covering a specific set of task types and/or regions.
+    /// Having different configurations for different task types, allows independent
+    /// tuning of activity and workflow task processing (for example).
+    ///
+    /// The key of the map is the ID of the scaling group used to reference it in subsequent
+    /// update calls.
+    #[prost(map = "string, message", tag = "1")]
+    pub scaling_groups: ::std::collections::HashMap<
+        ::prost::alloc::string::String,
+        ComputeConfigScalingGroup,
+    >,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ComputeConfigScalingGroupUpdate {
+    #[prost(message, optional, tag = "1")]
+    pub scaling_group: ::core::option::Option<ComputeConfigScalingGroup>,
+    /// Controls which fields from `scaling_group` will be applied. Semantics:
+    /// - Mask is ignored for new scaling groups (only applicable when scaling group already exists).
+    /// - Empty mask for an existing scaling group is no-op: no change.
+    /// - Non-empty mask for an existing scaling group will update/unset only to the fields
+    ///    mentioned in the mask.
+    /// - Accepted paths: "task_queue_types", "provider", "provider.type", "provider.details",
+    ///    "provider.nexus_endpoint", "scaler", "scaler.type", "scaler.details"
+    #[prost(message, optional, tag = "2")]
+    pub update_mask: ::core::option::Option<::pbjson_types::FieldMask>,
+}
+/// A subset of information in ComputeConfig optimized for list views.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ComputeConfigSummary {
+    #[prost(map = "string, message", tag = "1")]
+    pub scaling_groups: ::std::collections::HashMap<
+        ::prost::alloc::string::String,
+        ComputeConfigScalingGroupSummary,
+    >,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ComputeConfigScalingGroupSummary {
+    #[prost(enumeration = "super::super::enums::v1::TaskQueueType", repeated, tag = "1")]
+    pub task_queue_types: ::prost::alloc::vec::Vec<i32>,
+    #[prost(string, tag = "2")]
+    pub provider_type: ::prost::alloc::string::String,
+}
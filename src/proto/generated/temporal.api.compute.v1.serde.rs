@@ -0,0 +1,776 @@
+impl serde::Serialize for ComputeConfig {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.scaling_groups.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("temporal.api.compute.v1.ComputeConfig", len)?;
+        if !self.scaling_groups.is_empty() {
+            struct_ser.serialize_field("scalingGroups", &self.scaling_groups)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for ComputeConfig {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "scaling_groups",
+            "scalingGroups",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            ScalingGroups,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "scalingGroups" | "scaling_groups" => Ok(GeneratedField::ScalingGroups),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = ComputeConfig;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct temporal.api.compute.v1.ComputeConfig")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<ComputeConfig, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut scaling_groups__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::ScalingGroups => {
+                            if scaling_groups__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("scalingGroups"));
+                            }
+                            scaling_groups__ = Some(
+                                map_.next_value::<std::collections::HashMap<_, _>>()?
+                            );
+                        }
+                    }
+                }
+                Ok(ComputeConfig {
+                    scaling_groups: scaling_groups__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("temporal.api.compute.v1.ComputeConfig", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for ComputeConfigScalingGroup {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.task_queue_types.is_empty() {
+            len += 1;
+        }
+        if self.provider.is_some() {
+            len += 1;
+        }
+        if self.scaler.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("temporal.api.compute.v1.ComputeConfigScalingGroup", len)?;
+        if !self.task_queue_types.is_empty() {
+            let v = self.task_queue_types.iter().cloned().map(|v| {
+                super::super::enums::v1::TaskQueueType::try_from(v)
+                    .map_err(|_| serde::ser::Error::custom(format!("Invalid variant {}", v)))
+                }).collect::<std::result::Result<Vec<_>, _>>()?;
+            struct_ser.serialize_field("taskQueueTypes", &v)?;
+        }
+        if let Some(v) = self.provider.as_ref() {
+            struct_ser.serialize_field("provider", v)?;
+        }
+        if let Some(v) = self.scaler.as_ref() {
+            struct_ser.serialize_field("scaler", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for ComputeConfigScalingGroup {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "task_queue_types",
+            "taskQueueTypes",
+            "provider",
+            "scaler",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            TaskQueueTypes,
+            Provider,
+            Scaler,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "taskQueueTypes" | "task_queue_types" => Ok(GeneratedField::TaskQueueTypes),
+                            "provider" => Ok(GeneratedField::Provider),
+                            "scaler" => Ok(GeneratedField::Scaler),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = ComputeConfigScalingGroup;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct temporal.api.compute.v1.ComputeConfigScalingGroup")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<ComputeConfigScalingGroup, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut task_queue_types__ = None;
+                let mut provider__ = None;
+                let mut scaler__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::TaskQueueTypes => {
+                            if task_queue_types__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("taskQueueTypes"));
+                            }
+                            task_queue_types__ = Some(map_.next_value::<Vec<super::super::enums::v1::TaskQueueType>>()?.into_iter().map(|x| x as i32).collect());
+                        }
+                        GeneratedField::Provider => {
+                            if provider__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("provider"));
+                            }
+                            provider__ = map_.next_value()?;
+                        }
+                        GeneratedField::Scaler => {
+                            if scaler__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("scaler"));
+                            }
+                            scaler__ = map_.next_value()?;
+                        }
+                    }
+                }
+                Ok(ComputeConfigScalingGroup {
+                    task_queue_types: task_queue_types__.unwrap_or_default(),
+                    provider: provider__,
+                    scaler: scaler__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("temporal.api.compute.v1.ComputeConfigScalingGroup", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for ComputeConfigScalingGroupSummary {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.task_queue_types.is_empty() {
+            len += 1;
+        }
+        if !self.provider_type.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("temporal.api.compute.v1.ComputeConfigScalingGroupSummary", len)?;
+        if !self.task_queue_types.is_empty() {
+            let v = self.task_queue_types.iter().cloned().map(|v| {
+                super::super::enums::v1::TaskQueueType::try_from(v)
+                    .map_err(|_| serde::ser::Error::custom(format!("Invalid variant {}", v)))
+                }).collect::<std::result::Result<Vec<_>, _>>()?;
+            struct_ser.serialize_field("taskQueueTypes", &v)?;
+        }
+        if !self.provider_type.is_empty() {
+            struct_ser.serialize_field("providerType", &self.provider_type)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for ComputeConfigScalingGroupSummary {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "task_queue_types",
+            "taskQueueTypes",
+            "provider_type",
+            "providerType",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            TaskQueueTypes,
+            ProviderType,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "taskQueueTypes" | "task_queue_types" => Ok(GeneratedField::TaskQueueTypes),
+                            "providerType" | "provider_type" => Ok(GeneratedField::ProviderType),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = ComputeConfigScalingGroupSummary;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct temporal.api.compute.v1.ComputeConfigScalingGroupSummary")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<ComputeConfigScalingGroupSummary, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut task_queue_types__ = None;
+                let mut provider_type__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::TaskQueueTypes => {
+                            if task_queue_types__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("taskQueueTypes"));
+                            }
+                            task_queue_types__ = Some(map_.next_value::<Vec<super::super::enums::v1::TaskQueueType>>()?.into_iter().map(|x| x as i32).collect());
+                        }
+                        GeneratedField::ProviderType => {
+                            if provider_type__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("providerType"));
+                            }
+                            provider_type__ = Some(map_.next_value()?);
+                        }
+                    }
+                }
+                Ok(ComputeConfigScalingGroupSummary {
+                    task_queue_types: task_queue_types__.unwrap_or_default(),
+                    provider_type: provider_type__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("temporal.api.compute.v1.ComputeConfigScalingGroupSummary", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for ComputeConfigScalingGroupUpdate {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.scaling_group.is_some() {
+            len += 1;
+        }
+        if self.update_mask.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("temporal.api.compute.v1.ComputeConfigScalingGroupUpdate", len)?;
+        if let Some(v) = self.scaling_group.as_ref() {
+            struct_ser.serialize_field("scalingGroup", v)?;
+        }
+        if let Some(v) = self.update_mask.as_ref() {
+            struct_ser.serialize_field("updateMask", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for ComputeConfigScalingGroupUpdate {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "scaling_group",
+            "scalingGroup",
+            "update_mask",
+            "updateMask",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            ScalingGroup,
+            UpdateMask,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "scalingGroup" | "scaling_group" => Ok(GeneratedField::ScalingGroup),
+                            "updateMask" | "update_mask" => Ok(GeneratedField::UpdateMask),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = ComputeConfigScalingGroupUpdate;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct temporal.api.compute.v1.ComputeConfigScalingGroupUpdate")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<ComputeConfigScalingGroupUpdate, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut scaling_group__ = None;
+                let mut update_mask__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::ScalingGroup => {
+                            if scaling_group__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("scalingGroup"));
+                            }
+                            scaling_group__ = map_.next_value()?;
+                        }
+                        GeneratedField::UpdateMask => {
+                            if update_mask__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("updateMask"));
+                            }
+                            update_mask__ = map_.next_value()?;
+                        }
+                    }
+                }
+                Ok(ComputeConfigScalingGroupUpdate {
+                    scaling_group: scaling_group__,
+                    update_mask: update_mask__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("temporal.api.compute.v1.ComputeConfigScalingGroupUpdate", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for ComputeConfigSummary {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.scaling_groups.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("temporal.api.compute.v1.ComputeConfigSummary", len)?;
+        if !self.scaling_groups.is_empty() {
+            struct_ser.serialize_field("scalingGroups", &self.scaling_groups)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for ComputeConfigSummary {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "scaling_groups",
+            "scalingGroups",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            ScalingGroups,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "scalingGroups" | "scaling_groups" => Ok(GeneratedField::ScalingGroups),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = ComputeConfigSummary;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct temporal.api.compute.v1.ComputeConfigSummary")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<ComputeConfigSummary, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut scaling_groups__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::ScalingGroups => {
+                            if scaling_groups__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("scalingGroups"));
+                            }
+                            scaling_groups__ = Some(
+                                map_.next_value::<std::collections::HashMap<_, _>>()?
+                            );
+                        }
+                    }
+                }
+                Ok(ComputeConfigSummary {
+                    scaling_groups: scaling_groups__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("temporal.api.compute.v1.ComputeConfigSummary", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for ComputeProvider {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.r#type.is_empty() {
+            len += 1;
+        }
+        if self.details.is_some() {
+            len += 1;
+        }
+        if !self.nexus_endpoint.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("temporal.api.compute.v1.ComputeProvider", len)?;
+        if !self.r#type.is_empty() {
+            struct_ser.serialize_field("type", &self.r#type)?;
+        }
+        if let Some(v) = self.details.as_ref() {
+            struct_ser.serialize_field("details", v)?;
+        }
+        if !self.nexus_endpoint.is_empty() {
+            struct_ser.serialize_field("nexusEndpoint", &self.nexus_endpoint)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for ComputeProvider {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "type",
+            "details",
+            "nexus_endpoint",
+            "nexusEndpoint",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Type,
+            Details,
+            NexusEndpoint,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "type" => Ok(GeneratedField::Type),
+                            "details" => Ok(GeneratedField::Details),
+                            "nexusEndpoint" | "nexus_endpoint" => Ok(GeneratedField::NexusEndpoint),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = ComputeProvider;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct temporal.api.compute.v1.ComputeProvider")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<ComputeProvider, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut r#type__ = None;
+                let mut details__ = None;
+                let mut nexus_endpoint__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Type => {
+                            if r#type__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("type"));
+                            }
+                            r#type__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::Details => {
+                            if details__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("details"));
+                            }
+                            details__ = map_.next_value()?;
+                        }
+                        GeneratedField::NexusEndpoint => {
+                            if nexus_endpoint__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("nexusEndpoint"));
+                            }
+                            nexus_endpoint__ = Some(map_.next_value()?);
+                        }
+                    }
+                }
+                Ok(ComputeProvider {
+                    r#type: r#type__.unwrap_or_default(),
+                    details: details__,
+                    nexus_endpoint: nexus_endpoint__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("temporal.api.compute.v1.ComputeProvider", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for ComputeScaler {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.r#type.is_empty() {
+            len += 1;
+        }
+        if self.details.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("temporal.api.compute.v1.ComputeScaler", len)?;
+        if !self.r#type.is_empty() {
+            struct_ser.serialize_field("type", &self.r#type)?;
+        }
+        if let Some(v) = self.details.as_ref() {
+            struct_ser.serialize_field("details", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for ComputeScaler {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "type",
+            "details",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Type,
+            Details,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "type" => Ok(GeneratedField::Type),
+                            "details" => Ok(GeneratedField::Details),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = ComputeScaler;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct temporal.api.compute.v1.ComputeScaler")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<ComputeScaler, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut r#type__ = None;
+                let mut details__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Type => {
+                            if r#type__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("type"));
+                            }
+                            r#type__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::Details => {
+                            if details__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("details"));
+                            }
+                            details__ = map_.next_value()?;
+                        }
+                    }
+                }
+                Ok(ComputeScaler {
+                    r#type: r#type__.unwrap_or_default(),
+                    details: details__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("temporal.api.compute.v1.ComputeScaler", FIELDS, GeneratedVisitor)
+    }
+}
@@ -1,6 +1,5 @@
 // This file is @generated by prost-build.
 /// Worker Deployment options set in SDK that need to be sent to server in every poll.
-/// Experimental. Worker Deployments are experimental and might significantly change in the future.
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct WorkerDeploymentOptions {
     /// Required when `worker_versioning_mode==VERSIONED`.
@@ -43,7 +42,7 @@ pub struct DeploymentInfo {
     #[prost(message, optional, tag = "1")]
     pub deployment: ::core::option::Option<Deployment>,
     #[prost(message, optional, tag = "2")]
-    pub create_time: ::core::option::Option<::prost_types::Timestamp>,
+    pub create_time: ::core::option::Option<::pbjson_types::Timestamp>,
     #[prost(message, repeated, tag = "3")]
     pub task_queue_infos: ::prost::alloc::vec::Vec<deployment_info::TaskQueueInfo>,
     /// A user-defined set of key-values. Can be updated as part of write operations to the
@@ -70,7 +69,7 @@ pub mod deployment_info {
         pub r#type: i32,
         /// When server saw the first poller for this task queue in this deployment.
         #[prost(message, optional, tag = "3")]
-        pub first_poller_time: ::core::option::Option<::prost_types::Timestamp>,
+        pub first_poller_time: ::core::option::Option<::pbjson_types::Timestamp>,
     }
 }
 /// Used as part of Deployment write APIs to update metadata attached to a deployment.
@@ -94,7 +93,7 @@ pub struct DeploymentListInfo {
     #[prost(message, optional, tag = "1")]
     pub deployment: ::core::option::Option<Deployment>,
     #[prost(message, optional, tag = "2")]
-    pub create_time: ::core::option::Option<::prost_types::Timestamp>,
+    pub create_time: ::core::option::Option<::pbjson_types::Timestamp>,
     /// If this deployment is the current deployment of its deployment series.
     #[prost(bool, tag = "3")]
     pub is_current: bool,
@@ -105,7 +104,6 @@ pub struct DeploymentListInfo {
 /// non-determinism issues.
 /// Worker Deployment Versions are created in Temporal server automatically when
 /// their first poller arrives to the server.
-/// Experimental. Worker Deployments are experimental and might significantly change in the future.
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct WorkerDeploymentVersionInfo {
     /// Deprecated. Use `deployment_version`.
@@ -121,34 +119,35 @@ pub struct WorkerDeploymentVersionInfo {
     /// Required.
     #[prost(message, optional, tag = "11")]
     pub deployment_version: ::core::option::Option<WorkerDeploymentVersion>,
+    /// Deprecated. User deployment_version.deployment_name.
     #[prost(string, tag = "2")]
     pub deployment_name: ::prost::alloc::string::String,
     #[prost(message, optional, tag = "3")]
-    pub create_time: ::core::option::Option<::prost_types::Timestamp>,
+    pub create_time: ::core::option::Option<::pbjson_types::Timestamp>,
     /// Last time `current_since_time`, `ramping_since_time, or `ramp_percentage` of this version changed.
     #[prost(message, optional, tag = "4")]
-    pub routing_changed_time: ::core::option::Option<::prost_types::Timestamp>,
+    pub routing_changed_time: ::core::option::Option<::pbjson_types::Timestamp>,
     /// (-- api-linter: core::0140::prepositions=disabled
     ///      aip.dev/not-precedent: 'Since' captures the field semantics despite being a preposition. --)
     /// Unset if not current.
     #[prost(message, optional, tag = "5")]
-    pub current_since_time: ::core::option::Option<::prost_types::Timestamp>,
+    pub current_since_time: ::core::option::Option<::pbjson_types::Timestamp>,
     /// (-- api-linter: core::0140::prepositions=disabled
     ///      aip.dev/not-precedent: 'Since' captures the field semantics despite being a preposition. --)
     /// Unset if not ramping. Updated when the version first starts ramping, not on each ramp change.
     #[prost(message, optional, tag = "6")]
-    pub ramping_since_time: ::core::option::Option<::prost_types::Timestamp>,
+    pub ramping_since_time: ::core::option::Option<::pbjson_types::Timestamp>,
     /// Timestamp when this version first became current or ramping.
     #[prost(message, optional, tag = "12")]
-    pub first_activation_time: ::core::option::Option<::prost_types::Timestamp>,
+    pub first_activation_time: ::core::option::Option<::pbjson_types::Timestamp>,
     /// Timestamp when this version last became current.
     /// Can be used to determine whether a version has ever been Current.
     #[prost(message, optional, tag = "15")]
-    pub last_current_time: ::core::option::Option<::prost_types::Timestamp>,
+    pub last_current_time: ::core::option::Option<::pbjson_types::Timestamp>,
     /// Timestamp when this version last stopped being current or ramping.
     /// Cleared if the version becomes current or ramping again.
     #[prost(message, optional, tag = "13")]
-    pub last_deactivation_time: ::core::option::Option<::prost_types::Timestamp>,
+    pub last_deactivation_time: ::core::option::Option<::pbjson_types::Timestamp>,
     /// Range: \[0, 100\]. Must be zero if the version is not ramping (i.e. `ramping_since_time` is nil).
     /// Can be in the range \[0, 100\] if the version is ramping.
     #[prost(float, tag = "7")]
@@ -177,6 +176,17 @@ pub struct WorkerDeploymentVersionInfo {
     /// Arbitrary user-provided metadata attached to this version.
     #[prost(message, optional, tag = "10")]
     pub metadata: ::core::option::Option<VersionMetadata>,
+    /// Optional. Contains the new worker compute configuration for the Worker
+    /// Deployment. Used for worker scale management.
+    #[prost(message, optional, tag = "16")]
+    pub compute_config: ::core::option::Option<super::super::compute::v1::ComputeConfig>,
+    /// Identity of the last client who modified the configuration of this Version.
+    /// As of now, this field only covers changes through the following APIs:
+    /// - `CreateWorkerDeploymentVersion`
+    /// - `UpdateWorkerDeploymentVersionComputeConfig`
+    /// - `UpdateWorkerDeploymentVersionMetadata`
+    #[prost(string, tag = "17")]
+    pub last_modifier_identity: ::prost::alloc::string::String,
 }
 /// Nested message and enum types in `WorkerDeploymentVersionInfo`.
 pub mod worker_deployment_version_info {
@@ -193,7 +203,6 @@ pub mod worker_deployment_version_info {
 }
 /// Information about workflow drainage to help the user determine when it is safe
 /// to decommission a Version. Not present while version is current or ramping.
-/// Experimental. Worker Deployments are experimental and might significantly change in the future.
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct VersionDrainageInfo {
     /// Set to DRAINING when the version first stops accepting new executions (is no longer current or ramping).
@@ -202,10 +211,10 @@ pub struct VersionDrainageInfo {
     pub status: i32,
     /// Last time the drainage status changed.
     #[prost(message, optional, tag = "2")]
-    pub last_changed_time: ::core::option::Option<::prost_types::Timestamp>,
+    pub last_changed_time: ::core::option::Option<::pbjson_types::Timestamp>,
     /// Last time the system checked for drainage of this version.
     #[prost(message, optional, tag = "3")]
-    pub last_checked_time: ::core::option::Option<::prost_types::Timestamp>,
+    pub last_checked_time: ::core::option::Option<::pbjson_types::Timestamp>,
 }
 /// A Worker Deployment (Deployment, for short) represents all workers serving
 /// a shared set of Task Queues. Typically, a Deployment represents one service or
@@ -214,7 +223,6 @@ pub struct VersionDrainageInfo {
 /// version of workers. (see documentation of WorkerDeploymentVersionInfo)
 /// Deployment records are created in Temporal server automatically when their
 /// first poller arrives to the server.
-/// Experimental. Worker Deployments are experimental and might significantly change in the future.
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct WorkerDeploymentInfo {
     /// Identifies a Worker Deployment. Must be unique within the namespace.
@@ -230,7 +238,7 @@ pub struct WorkerDeploymentInfo {
         worker_deployment_info::WorkerDeploymentVersionSummary,
     >,
     #[prost(message, optional, tag = "3")]
-    pub create_time: ::core::option::Option<::prost_types::Timestamp>,
+    pub create_time: ::core::option::Option<::pbjson_types::Timestamp>,
     #[prost(message, optional, tag = "4")]
     pub routing_config: ::core::option::Option<RoutingConfig>,
     /// Identity of the last client who modified the configuration of this Deployment. Set to the
@@ -270,7 +278,7 @@ pub mod worker_deployment_info {
         #[prost(message, optional, tag = "4")]
         pub deployment_version: ::core::option::Option<super::WorkerDeploymentVersion>,
         #[prost(message, optional, tag = "2")]
-        pub create_time: ::core::option::Option<::prost_types::Timestamp>,
+        pub create_time: ::core::option::Option<::pbjson_types::Timestamp>,
         /// Deprecated. Use `drainage_info` instead.
         #[prost(
             enumeration = "super::super::super::enums::v1::VersionDrainageStatus",
@@ -285,26 +293,30 @@ pub mod worker_deployment_info {
         /// (-- api-linter: core::0140::prepositions=disabled
         ///      aip.dev/not-precedent: 'Since' captures the field semantics despite being a preposition. --)
         #[prost(message, optional, tag = "6")]
-        pub current_since_time: ::core::option::Option<::prost_types::Timestamp>,
+        pub current_since_time: ::core::option::Option<::pbjson_types::Timestamp>,
         /// Unset if not ramping. Updated when the version first starts ramping, not on each ramp change.
         /// (-- api-linter: core::0140::prepositions=disabled
         ///      aip.dev/not-precedent: 'Since' captures the field semantics despite being a preposition. --)
         #[prost(message, optional, tag = "7")]
-        pub ramping_since_time: ::core::option::Option<::prost_types::Timestamp>,
+        pub ramping_since_time: ::core::option::Option<::pbjson_types::Timestamp>,
         /// Last time `current_since_time`, `ramping_since_time, or `ramp_percentage` of this version changed.
         #[prost(message, optional, tag = "8")]
-        pub routing_update_time: ::core::option::Option<::prost_types::Timestamp>,
+        pub routing_update_time: ::core::option::Option<::pbjson_types::Timestamp>,
         /// Timestamp when this version first became current or ramping.
         #[prost(message, optional, tag = "9")]
-        pub first_activation_time: ::core::option::Option<::prost_types::Timestamp>,
+        pub first_activation_time: ::core::option::Option<::pbjson_types::Timestamp>,
         /// Timestamp when this version last became current.
         /// Can be used to determine whether a version has ever been Current.
         #[prost(message, optional, tag = "12")]
-        pub last_current_time: ::core::option::Option<::prost_types::Timestamp>,
+        pub last_current_time: ::core::option::Option<::pbjson_types::Timestamp>,
         /// Timestamp when this version last stopped being current or ramping.
         /// Cleared if the version becomes current or ramping again.
         #[prost(message, optional, tag = "10")]
-        pub last_deactivation_time: ::core::option::Option<::prost_types::Timestamp>,
+        pub last_deactivation_time: ::core::option::Option<::pbjson_types::Timestamp>,
+        #[prost(message, optional, tag = "13")]
+        pub compute_config: ::core::option::Option<
+            super::super::super::compute::v1::ComputeConfigSummary,
+        >,
     }
 }
 /// A Worker Deployment Version (Version, for short) represents a
@@ -365,15 +377,15 @@ pub struct RoutingConfig {
     pub ramping_version_percentage: f32,
     /// Last time current version was changed.
     #[prost(message, optional, tag = "4")]
-    pub current_version_changed_time: ::core::option::Option<::prost_types::Timestamp>,
+    pub current_version_changed_time: ::core::option::Option<::pbjson_types::Timestamp>,
     /// Last time ramping version was changed. Not updated if only the ramp percentage changes.
     #[prost(message, optional, tag = "5")]
-    pub ramping_version_changed_time: ::core::option::Option<::prost_types::Timestamp>,
+    pub ramping_version_changed_time: ::core::option::Option<::pbjson_types::Timestamp>,
     /// Last time ramping version percentage was changed.
     /// If ramping version is changed, this is also updated, even if the percentage stays the same.
     #[prost(message, optional, tag = "6")]
     pub ramping_version_percentage_changed_time: ::core::option::Option<
-        ::prost_types::Timestamp,
+        ::pbjson_types::Timestamp,
     >,
     /// Monotonically increasing value which is incremented on every mutation
     /// to any field of this message to achieve eventual consistency between task queues and their partitions.
@@ -382,6 +394,7 @@ pub struct RoutingConfig {
 }
 /// Used as part of WorkflowExecutionStartedEventAttributes to pass down the AutoUpgrade behavior and source deployment version
 /// to a workflow execution whose parent/previous workflow has an AutoUpgrade behavior.
+/// Also used for Upgrade-on-CaN behaviors AutoUpgrade and UseRampingVersion.
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct InheritedAutoUpgradeInfo {
     /// The source deployment version of the parent/previous workflow.
@@ -390,4 +403,19 @@ pub struct InheritedAutoUpgradeInfo {
     /// The revision number of the source deployment version of the parent/previous workflow.
     #[prost(int64, tag = "2")]
     pub source_deployment_revision_number: i64,
+    /// Experimental.
+    /// If this workflow is the result of a continue-as-new, this field is set to the initial_versioning_behavior
+    /// specified in that command.
+    /// Only used for the initial task of this run and the initial task of any retries of this run.
+    /// Not passed to children or to future continue-as-new.
+    ///
+    /// Note: In the first release of Upgrade-on-CaN, when the only ContinueAsNewVersioningBehavior was AutoUpgrade,
+    /// a non-empty InheritedAutoUpgradeInfo meant that the workflow should start as AutoUpgrade. So for compatibility
+    /// with history events generated during that time, know that an UNSPECIFIED value here is equivalent to AutoUpgrade
+    /// value if the InheritedAutoUpgradeInfo is non-empty.
+    #[prost(
+        enumeration = "super::super::enums::v1::ContinueAsNewVersioningBehavior",
+        tag = "3"
+    )]
+    pub continue_as_new_initial_versioning_behavior: i32,
 }
@@ -0,0 +1,2481 @@
+impl serde::Serialize for Deployment {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.series_name.is_empty() {
+            len += 1;
+        }
+        if !self.build_id.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("temporal.api.deployment.v1.Deployment", len)?;
+        if !self.series_name.is_empty() {
+            struct_ser.serialize_field("seriesName", &self.series_name)?;
+        }
+        if !self.build_id.is_empty() {
+            struct_ser.serialize_field("buildId", &self.build_id)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for Deployment {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "series_name",
+            "seriesName",
+            "build_id",
+            "buildId",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            SeriesName,
+            BuildId,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "seriesName" | "series_name" => Ok(GeneratedField::SeriesName),
+                            "buildId" | "build_id" => Ok(GeneratedField::BuildId),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = Deployment;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct temporal.api.deployment.v1.Deployment")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<Deployment, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut series_name__ = None;
+                let mut build_id__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::SeriesName => {
+                            if series_name__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("seriesName"));
+                            }
+                            series_name__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::BuildId => {
+                            if build_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("buildId"));
+                            }
+                            build_id__ = Some(map_.next_value()?);
+                        }
+                    }
+                }
+                Ok(Deployment {
+                    series_name: series_name__.unwrap_or_default(),
+                    build_id: build_id__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("temporal.api.deployment.v1.Deployment", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for DeploymentInfo {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.deployment.is_some() {
+            len += 1;
+        }
+        if self.create_time.is_some() {
+            len += 1;
+        }
+        if !self.task_queue_infos.is_empty() {
+            len += 1;
+        }
+        if !self.metadata.is_empty() {
+            len += 1;
+        }
+        if self.is_current {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("temporal.api.deployment.v1.DeploymentInfo", len)?;
+        if let Some(v) = self.deployment.as_ref() {
+            struct_ser.serialize_field("deployment", v)?;
+        }
+        if let Some(v) = self.create_time.as_ref() {
+            struct_ser.serialize_field("createTime", v)?;
+        }
+        if !self.task_queue_infos.is_empty() {
+            struct_ser.serialize_field("taskQueueInfos", &self.task_queue_infos)?;
+        }
+        if !self.metadata.is_empty() {
+            struct_ser.serialize_field("metadata", &self.metadata)?;
+        }
+        if self.is_current {
+            struct_ser.serialize_field("isCurrent", &self.is_current)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for DeploymentInfo {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "deployment",
+            "create_time",
+            "createTime",
+            "task_queue_infos",
+            "taskQueueInfos",
+            "metadata",
+            "is_current",
+            "isCurrent",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Deployment,
+            CreateTime,
+            TaskQueueInfos,
+            Metadata,
+            IsCurrent,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "deployment" => Ok(GeneratedField::Deployment),
+                            "createTime" | "create_time" => Ok(GeneratedField::CreateTime),
+                            "taskQueueInfos" | "task_queue_infos" => Ok(GeneratedField::TaskQueueInfos),
+                            "metadata" => Ok(GeneratedField::Metadata),
+                            "isCurrent" | "is_current" => Ok(GeneratedField::IsCurrent),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = DeploymentInfo;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct temporal.api.deployment.v1.DeploymentInfo")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<DeploymentInfo, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut deployment__ = None;
+                let mut create_time__ = None;
+                let mut task_queue_infos__ = None;
+                let mut metadata__ = None;
+                let mut is_current__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Deployment => {
+                            if deployment__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("deployment"));
+                            }
+                            deployment__ = map_.next_value()?;
+                        }
+                        GeneratedField::CreateTime => {
+                            if create_time__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("createTime"));
+                            }
+                            create_time__ = map_.next_value()?;
+                        }
+                        GeneratedField::TaskQueueInfos => {
+                            if task_queue_infos__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("taskQueueInfos"));
+                            }
+                            task_queue_infos__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::Metadata => {
+                            if metadata__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("metadata"));
+                            }
+                            metadata__ = Some(
+                                map_.next_value::<std::collections::HashMap<_, _>>()?
+                            );
+                        }
+                        GeneratedField::IsCurrent => {
+                            if is_current__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("isCurrent"));
+                            }
+                            is_current__ = Some(map_.next_value()?);
+                        }
+                    }
+                }
+                Ok(DeploymentInfo {
+                    deployment: deployment__,
+                    create_time: create_time__,
+                    task_queue_infos: task_queue_infos__.unwrap_or_default(),
+                    metadata: metadata__.unwrap_or_default(),
+                    is_current: is_current__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("temporal.api.deployment.v1.DeploymentInfo", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for deployment_info::TaskQueueInfo {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.name.is_empty() {
+            len += 1;
+        }
+        if self.r#type != 0 {
+            len += 1;
+        }
+        if self.first_poller_time.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("temporal.api.deployment.v1.DeploymentInfo.TaskQueueInfo", len)?;
+        if !self.name.is_empty() {
+            struct_ser.serialize_field("name", &self.name)?;
+        }
+        if self.r#type != 0 {
+            let v = super::super::enums::v1::TaskQueueType::try_from(self.r#type)
+                .map_err(|_| serde::ser::Error::custom(format!("Invalid variant {}", self.r#type)))?;
+            struct_ser.serialize_field("type", &v)?;
+        }
+        if let Some(v) = self.first_poller_time.as_ref() {
+            struct_ser.serialize_field("firstPollerTime", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for deployment_info::TaskQueueInfo {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "name",
+            "type",
+            "first_poller_time",
+            "firstPollerTime",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Name,
+            Type,
+            FirstPollerTime,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "name" => Ok(GeneratedField::Name),
+                            "type" => Ok(GeneratedField::Type),
+                            "firstPollerTime" | "first_poller_time" => Ok(GeneratedField::FirstPollerTime),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = deployment_info::TaskQueueInfo;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct temporal.api.deployment.v1.DeploymentInfo.TaskQueueInfo")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<deployment_info::TaskQueueInfo, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut name__ = None;
+                let mut r#type__ = None;
+                let mut first_poller_time__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Name => {
+                            if name__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("name"));
+                            }
+                            name__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::Type => {
+                            if r#type__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("type"));
+                            }
+                            r#type__ = Some(map_.next_value::<super::super::enums::v1::TaskQueueType>()? as i32);
+                        }
+                        GeneratedField::FirstPollerTime => {
+                            if first_poller_time__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("firstPollerTime"));
+                            }
+                            first_poller_time__ = map_.next_value()?;
+                        }
+                    }
+                }
+                Ok(deployment_info::TaskQueueInfo {
+                    name: name__.unwrap_or_default(),
+                    r#type: r#type__.unwrap_or_default(),
+                    first_poller_time: first_poller_time__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("temporal.api.deployment.v1.DeploymentInfo.TaskQueueInfo", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for DeploymentListInfo {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.deployment.is_some() {
+            len += 1;
+        }
+        if self.create_time.is_some() {
+            len += 1;
+        }
+        if self.is_current {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("temporal.api.deployment.v1.DeploymentListInfo", len)?;
+        if let Some(v) = self.deployment.as_ref() {
+            struct_ser.serialize_field("deployment", v)?;
+        }
+        if let Some(v) = self.create_time.as_ref() {
+            struct_ser.serialize_field("createTime", v)?;
+        }
+        if self.is_current {
+            struct_ser.serialize_field("isCurrent", &self.is_current)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for DeploymentListInfo {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "deployment",
+            "create_time",
+            "createTime",
+            "is_current",
+            "isCurrent",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Deployment,
+            CreateTime,
+            IsCurrent,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "deployment" => Ok(GeneratedField::Deployment),
+                            "createTime" | "create_time" => Ok(GeneratedField::CreateTime),
+                            "isCurrent" | "is_current" => Ok(GeneratedField::IsCurrent),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = DeploymentListInfo;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct temporal.api.deployment.v1.DeploymentListInfo")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<DeploymentListInfo, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut deployment__ = None;
+                let mut create_time__ = None;
+                let mut is_current__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Deployment => {
+                            if deployment__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("deployment"));
+                            }
+                            deployment__ = map_.next_value()?;
+                        }
+                        GeneratedField::CreateTime => {
+                            if create_time__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("createTime"));
+                            }
+                            create_time__ = map_.next_value()?;
+                        }
+                        GeneratedField::IsCurrent => {
+                            if is_current__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("isCurrent"));
+                            }
+                            is_current__ = Some(map_.next_value()?);
+                        }
+                    }
+                }
+                Ok(DeploymentListInfo {
+                    deployment: deployment__,
+                    create_time: create_time__,
+                    is_current: is_current__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("temporal.api.deployment.v1.DeploymentListInfo", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for InheritedAutoUpgradeInfo {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.source_deployment_version.is_some() {
+            len += 1;
+        }
+        if self.source_deployment_revision_number != 0 {
+            len += 1;
+        }
+        if self.continue_as_new_initial_versioning_behavior != 0 {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("temporal.api.deployment.v1.InheritedAutoUpgradeInfo", len)?;
+        if let Some(v) = self.source_deployment_version.as_ref() {
+            struct_ser.serialize_field("sourceDeploymentVersion", v)?;
+        }
+        if self.source_deployment_revision_number != 0 {
+            #[allow(clippy::needless_borrow)]
+            #[allow(clippy::needless_borrows_for_generic_args)]
+            struct_ser.serialize_field("sourceDeploymentRevisionNumber", ToString::to_string(&self.source_deployment_revision_number).as_str())?;
+        }
+        if self.continue_as_new_initial_versioning_behavior != 0 {
+            let v = super::super::enums::v1::ContinueAsNewVersioningBehavior::try_from(self.continue_as_new_initial_versioning_behavior)
+                .map_err(|_| serde::ser::Error::custom(format!("Invalid variant {}", self.continue_as_new_initial_versioning_behavior)))?;
+            struct_ser.serialize_field("continueAsNewInitialVersioningBehavior", &v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for InheritedAutoUpgradeInfo {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "source_deployment_version",
+            "sourceDeploymentVersion",
+            "source_deployment_revision_number",
+            "sourceDeploymentRevisionNumber",
+            "continue_as_new_initial_versioning_behavior",
+            "continueAsNewInitialVersioningBehavior",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            SourceDeploymentVersion,
+            SourceDeploymentRevisionNumber,
+            ContinueAsNewInitialVersioningBehavior,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "sourceDeploymentVersion" | "source_deployment_version" => Ok(GeneratedField::SourceDeploymentVersion),
+                            "sourceDeploymentRevisionNumber" | "source_deployment_revision_number" => Ok(GeneratedField::SourceDeploymentRevisionNumber),
+                            "continueAsNewInitialVersioningBehavior" | "continue_as_new_initial_versioning_behavior" => Ok(GeneratedField::ContinueAsNewInitialVersioningBehavior),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = InheritedAutoUpgradeInfo;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct temporal.api.deployment.v1.InheritedAutoUpgradeInfo")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<InheritedAutoUpgradeInfo, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut source_deployment_version__ = None;
+                let mut source_deployment_revision_number__ = None;
+                let mut continue_as_new_initial_versioning_behavior__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::SourceDeploymentVersion => {
+                            if source_deployment_version__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("sourceDeploymentVersion"));
+                            }
+                            source_deployment_version__ = map_.next_value()?;
+                        }
+                        GeneratedField::SourceDeploymentRevisionNumber => {
+                            if source_deployment_revision_number__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("sourceDeploymentRevisionNumber"));
+                            }
+                            source_deployment_revision_number__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::ContinueAsNewInitialVersioningBehavior => {
+                            if continue_as_new_initial_versioning_behavior__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("continueAsNewInitialVersioningBehavior"));
+                            }
+                            continue_as_new_initial_versioning_behavior__ = Some(map_.next_value::<super::super::enums::v1::ContinueAsNewVersioningBehavior>()? as i32);
+                        }
+                    }
+                }
+                Ok(InheritedAutoUpgradeInfo {
+                    source_deployment_version: source_deployment_version__,
+                    source_deployment_revision_number: source_deployment_revision_number__.unwrap_or_default(),
+                    continue_as_new_initial_versioning_behavior: continue_as_new_initial_versioning_behavior__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("temporal.api.deployment.v1.InheritedAutoUpgradeInfo", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for RoutingConfig {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.current_deployment_version.is_some() {
+            len += 1;
+        }
+        if !self.current_version.is_empty() {
+            len += 1;
+        }
+        if self.ramping_deployment_version.is_some() {
+            len += 1;
+        }
+        if !self.ramping_version.is_empty() {
+            len += 1;
+        }
+        if self.ramping_version_percentage != 0. {
+            len += 1;
+        }
+        if self.current_version_changed_time.is_some() {
+            len += 1;
+        }
+        if self.ramping_version_changed_time.is_some() {
+            len += 1;
+        }
+        if self.ramping_version_percentage_changed_time.is_some() {
+            len += 1;
+        }
+        if self.revision_number != 0 {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("temporal.api.deployment.v1.RoutingConfig", len)?;
+        if let Some(v) = self.current_deployment_version.as_ref() {
+            struct_ser.serialize_field("currentDeploymentVersion", v)?;
+        }
+        if !self.current_version.is_empty() {
+            struct_ser.serialize_field("currentVersion", &self.current_version)?;
+        }
+        if let Some(v) = self.ramping_deployment_version.as_ref() {
+            struct_ser.serialize_field("rampingDeploymentVersion", v)?;
+        }
+        if !self.ramping_version.is_empty() {
+            struct_ser.serialize_field("rampingVersion", &self.ramping_version)?;
+        }
+        if self.ramping_version_percentage != 0. {
+            struct_ser.serialize_field("rampingVersionPercentage", &self.ramping_version_percentage)?;
+        }
+        if let Some(v) = self.current_version_changed_time.as_ref() {
+            struct_ser.serialize_field("currentVersionChangedTime", v)?;
+        }
+        if let Some(v) = self.ramping_version_changed_time.as_ref() {
+            struct_ser.serialize_field("rampingVersionChangedTime", v)?;
+        }
+        if let Some(v) = self.ramping_version_percentage_changed_time.as_ref() {
+            struct_ser.serialize_field("rampingVersionPercentageChangedTime", v)?;
+        }
+        if self.revision_number != 0 {
+            #[allow(clippy::needless_borrow)]
+            #[allow(clippy::needless_borrows_for_generic_args)]
+            struct_ser.serialize_field("revisionNumber", ToString::to_string(&self.revision_number).as_str())?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for RoutingConfig {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "current_deployment_version",
+            "currentDeploymentVersion",
+            "current_version",
+            "currentVersion",
+            "ramping_deployment_version",
+            "rampingDeploymentVersion",
+            "ramping_version",
+            "rampingVersion",
+            "ramping_version_percentage",
+            "rampingVersionPercentage",
+            "current_version_changed_time",
+            "currentVersionChangedTime",
+            "ramping_version_changed_time",
+            "rampingVersionChangedTime",
+            "ramping_version_percentage_changed_time",
+            "rampingVersionPercentageChangedTime",
+            "revision_number",
+            "revisionNumber",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            CurrentDeploymentVersion,
+            CurrentVersion,
+            RampingDeploymentVersion,
+            RampingVersion,
+            RampingVersionPercentage,
+            CurrentVersionChangedTime,
+            RampingVersionChangedTime,
+            RampingVersionPercentageChangedTime,
+            RevisionNumber,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "currentDeploymentVersion" | "current_deployment_version" => Ok(GeneratedField::CurrentDeploymentVersion),
+                            "currentVersion" | "current_version" => Ok(GeneratedField::CurrentVersion),
+                            "rampingDeploymentVersion" | "ramping_deployment_version" => Ok(GeneratedField::RampingDeploymentVersion),
+                            "rampingVersion" | "ramping_version" => Ok(GeneratedField::RampingVersion),
+                            "rampingVersionPercentage" | "ramping_version_percentage" => Ok(GeneratedField::RampingVersionPercentage),
+                            "currentVersionChangedTime" | "current_version_changed_time" => Ok(GeneratedField::CurrentVersionChangedTime),
+                            "rampingVersionChangedTime" | "ramping_version_changed_time" => Ok(GeneratedField::RampingVersionChangedTime),
+                            "rampingVersionPercentageChangedTime" | "ramping_version_percentage_changed_time" => Ok(GeneratedField::RampingVersionPercentageChangedTime),
+                            "revisionNumber" | "revision_number" => Ok(GeneratedField::RevisionNumber),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = RoutingConfig;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct temporal.api.deployment.v1.RoutingConfig")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<RoutingConfig, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut current_deployment_version__ = None;
+                let mut current_version__ = None;
+                let mut ramping_deployment_version__ = None;
+                let mut ramping_version__ = None;
+                let mut ramping_version_percentage__ = None;
+                let mut current_version_changed_time__ = None;
+                let mut ramping_version_changed_time__ = None;
+                let mut ramping_version_percentage_changed_time__ = None;
+                let mut revision_number__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::CurrentDeploymentVersion => {
+                            if current_deployment_version__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("currentDeploymentVersion"));
+                            }
+                            current_deployment_version__ = map_.next_value()?;
+                        }
+                        GeneratedField::CurrentVersion => {
+                            if current_version__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("currentVersion"));
+                            }
+                            current_version__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::RampingDeploymentVersion => {
+                            if ramping_deployment_version__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("rampingDeploymentVersion"));
+                            }
+                            ramping_deployment_version__ = map_.next_value()?;
+                        }
+                        GeneratedField::RampingVersion => {
+                            if ramping_version__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("rampingVersion"));
+                            }
+                            ramping_version__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::RampingVersionPercentage => {
+                            if ramping_version_percentage__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("rampingVersionPercentage"));
+                            }
+                            ramping_version_percentage__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::CurrentVersionChangedTime => {
+                            if current_version_changed_time__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("currentVersionChangedTime"));
+                            }
+                            current_version_changed_time__ = map_.next_value()?;
+                        }
+                        GeneratedField::RampingVersionChangedTime => {
+                            if ramping_version_changed_time__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("rampingVersionChangedTime"));
+                            }
+                            ramping_version_changed_time__ = map_.next_value()?;
+                        }
+                        GeneratedField::RampingVersionPercentageChangedTime => {
+                            if ramping_version_percentage_changed_time__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("rampingVersionPercentageChangedTime"));
+                            }
+                            ramping_version_percentage_changed_time__ = map_.next_value()?;
+                        }
+                        GeneratedField::RevisionNumber => {
+                            if revision_number__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("revisionNumber"));
+                            }
+                            revision_number__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                    }
+                }
+                Ok(RoutingConfig {
+                    current_deployment_version: current_deployment_version__,
+                    current_version: current_version__.unwrap_or_default(),
+                    ramping_deployment_version: ramping_deployment_version__,
+                    ramping_version: ramping_version__.unwrap_or_default(),
+                    ramping_version_percentage: ramping_version_percentage__.unwrap_or_default(),
+                    current_version_changed_time: current_version_changed_time__,
+                    ramping_version_changed_time: ramping_version_changed_time__,
+                    ramping_version_percentage_changed_time: ramping_version_percentage_changed_time__,
+                    revision_number: revision_number__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("temporal.api.deployment.v1.RoutingConfig", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for UpdateDeploymentMetadata {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.upsert_entries.is_empty() {
+            len += 1;
+        }
+        if !self.remove_entries.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("temporal.api.deployment.v1.UpdateDeploymentMetadata", len)?;
+        if !self.upsert_entries.is_empty() {
+            struct_ser.serialize_field("upsertEntries", &self.upsert_entries)?;
+        }
+        if !self.remove_entries.is_empty() {
+            struct_ser.serialize_field("removeEntries", &self.remove_entries)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for UpdateDeploymentMetadata {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "upsert_entries",
+            "upsertEntries",
+            "remove_entries",
+            "removeEntries",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            UpsertEntries,
+            RemoveEntries,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "upsertEntries" | "upsert_entries" => Ok(GeneratedField::UpsertEntries),
+                            "removeEntries" | "remove_entries" => Ok(GeneratedField::RemoveEntries),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = UpdateDeploymentMetadata;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct temporal.api.deployment.v1.UpdateDeploymentMetadata")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<UpdateDeploymentMetadata, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut upsert_entries__ = None;
+                let mut remove_entries__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::UpsertEntries => {
+                            if upsert_entries__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("upsertEntries"));
+                            }
+                            upsert_entries__ = Some(
+                                map_.next_value::<std::collections::HashMap<_, _>>()?
+                            );
+                        }
+                        GeneratedField::RemoveEntries => {
+                            if remove_entries__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("removeEntries"));
+                            }
+                            remove_entries__ = Some(map_.next_value()?);
+                        }
+                    }
+                }
+                Ok(UpdateDeploymentMetadata {
+                    upsert_entries: upsert_entries__.unwrap_or_default(),
+                    remove_entries: remove_entries__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("temporal.api.deployment.v1.UpdateDeploymentMetadata", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for VersionDrainageInfo {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.status != 0 {
+            len += 1;
+        }
+        if self.last_changed_time.is_some() {
+            len += 1;
+        }
+        if self.last_checked_time.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("temporal.api.deployment.v1.VersionDrainageInfo", len)?;
+        if self.status != 0 {
+            let v = super::super::enums::v1::VersionDrainageStatus::try_from(self.status)
+                .map_err(|_| serde::ser::Error::custom(format!("Invalid variant {}", self.status)))?;
+            struct_ser.serialize_field("status", &v)?;
+        }
+        if let Some(v) = self.last_changed_time.as_ref() {
+            struct_ser.serialize_field("lastChangedTime", v)?;
+        }
+        if let Some(v) = self.last_checked_time.as_ref() {
+            struct_ser.serialize_field("lastCheckedTime", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for VersionDrainageInfo {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "status",
+            "last_changed_time",
+            "lastChangedTime",
+            "last_checked_time",
+            "lastCheckedTime",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Status,
+            LastChangedTime,
+            LastCheckedTime,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "status" => Ok(GeneratedField::Status),
+                            "lastChangedTime" | "last_changed_time" => Ok(GeneratedField::LastChangedTime),
+                            "lastCheckedTime" | "last_checked_time" => Ok(GeneratedField::LastCheckedTime),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = VersionDrainageInfo;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct temporal.api.deployment.v1.VersionDrainageInfo")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<VersionDrainageInfo, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut status__ = None;
+                let mut last_changed_time__ = None;
+                let mut last_checked_time__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Status => {
+                            if status__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("status"));
+                            }
+                            status__ = Some(map_.next_value::<super::super::enums::v1::VersionDrainageStatus>()? as i32);
+                        }
+                        GeneratedField::LastChangedTime => {
+                            if last_changed_time__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("lastChangedTime"));
+                            }
+                            last_changed_time__ = map_.next_value()?;
+                        }
+                        GeneratedField::LastCheckedTime => {
+                            if last_checked_time__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("lastCheckedTime"));
+                            }
+                            last_checked_time__ = map_.next_value()?;
+                        }
+                    }
+                }
+                Ok(VersionDrainageInfo {
+                    status: status__.unwrap_or_default(),
+                    last_changed_time: last_changed_time__,
+                    last_checked_time: last_checked_time__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("temporal.api.deployment.v1.VersionDrainageInfo", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for VersionMetadata {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.entries.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("temporal.api.deployment.v1.VersionMetadata", len)?;
+        if !self.entries.is_empty() {
+            struct_ser.serialize_field("entries", &self.entries)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for VersionMetadata {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "entries",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Entries,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "entries" => Ok(GeneratedField::Entries),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = VersionMetadata;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct temporal.api.deployment.v1.VersionMetadata")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<VersionMetadata, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut entries__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Entries => {
+                            if entries__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("entries"));
+                            }
+                            entries__ = Some(
+                                map_.next_value::<std::collections::HashMap<_, _>>()?
+                            );
+                        }
+                    }
+                }
+                Ok(VersionMetadata {
+                    entries: entries__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("temporal.api.deployment.v1.VersionMetadata", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for WorkerDeploymentInfo {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.name.is_empty() {
+            len += 1;
+        }
+        if !self.version_summaries.is_empty() {
+            len += 1;
+        }
+        if self.create_time.is_some() {
+            len += 1;
+        }
+        if self.routing_config.is_some() {
+            len += 1;
+        }
+        if !self.last_modifier_identity.is_empty() {
+            len += 1;
+        }
+        if !self.manager_identity.is_empty() {
+            len += 1;
+        }
+        if self.routing_config_update_state != 0 {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("temporal.api.deployment.v1.WorkerDeploymentInfo", len)?;
+        if !self.name.is_empty() {
+            struct_ser.serialize_field("name", &self.name)?;
+        }
+        if !self.version_summaries.is_empty() {
+            struct_ser.serialize_field("versionSummaries", &self.version_summaries)?;
+        }
+        if let Some(v) = self.create_time.as_ref() {
+            struct_ser.serialize_field("createTime", v)?;
+        }
+        if let Some(v) = self.routing_config.as_ref() {
+            struct_ser.serialize_field("routingConfig", v)?;
+        }
+        if !self.last_modifier_identity.is_empty() {
+            struct_ser.serialize_field("lastModifierIdentity", &self.last_modifier_identity)?;
+        }
+        if !self.manager_identity.is_empty() {
+            struct_ser.serialize_field("managerIdentity", &self.manager_identity)?;
+        }
+        if self.routing_config_update_state != 0 {
+            let v = super::super::enums::v1::RoutingConfigUpdateState::try_from(self.routing_config_update_state)
+                .map_err(|_| serde::ser::Error::custom(format!("Invalid variant {}", self.routing_config_update_state)))?;
+            struct_ser.serialize_field("routingConfigUpdateState", &v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for WorkerDeploymentInfo {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "name",
+            "version_summaries",
+            "versionSummaries",
+            "create_time",
+            "createTime",
+            "routing_config",
+            "routingConfig",
+            "last_modifier_identity",
+            "lastModifierIdentity",
+            "manager_identity",
+            "managerIdentity",
+            "routing_config_update_state",
+            "routingConfigUpdateState",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Name,
+            VersionSummaries,
+            CreateTime,
+            RoutingConfig,
+            LastModifierIdentity,
+            ManagerIdentity,
+            RoutingConfigUpdateState,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "name" => Ok(GeneratedField::Name),
+                            "versionSummaries" | "version_summaries" => Ok(GeneratedField::VersionSummaries),
+                            "createTime" | "create_time" => Ok(GeneratedField::CreateTime),
+                            "routingConfig" | "routing_config" => Ok(GeneratedField::RoutingConfig),
+                            "lastModifierIdentity" | "last_modifier_identity" => Ok(GeneratedField::LastModifierIdentity),
+                            "managerIdentity" | "manager_identity" => Ok(GeneratedField::ManagerIdentity),
+                            "routingConfigUpdateState" | "routing_config_update_state" => Ok(GeneratedField::RoutingConfigUpdateState),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = WorkerDeploymentInfo;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct temporal.api.deployment.v1.WorkerDeploymentInfo")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<WorkerDeploymentInfo, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut name__ = None;
+                let mut version_summaries__ = None;
+                let mut create_time__ = None;
+                let mut routing_config__ = None;
+                let mut last_modifier_identity__ = None;
+                let mut manager_identity__ = None;
+                let mut routing_config_update_state__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Name => {
+                            if name__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("name"));
+                            }
+                            name__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::VersionSummaries => {
+                            if version_summaries__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("versionSummaries"));
+                            }
+                            version_summaries__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::CreateTime => {
+                            if create_time__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("createTime"));
+                            }
+                            create_time__ = map_.next_value()?;
+                        }
+                        GeneratedField::RoutingConfig => {
+                            if routing_config__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("routingConfig"));
+                            }
+                            routing_config__ = map_.next_value()?;
+                        }
+                        GeneratedField::LastModifierIdentity => {
+                            if last_modifier_identity__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("lastModifierIdentity"));
+                            }
+                            last_modifier_identity__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::ManagerIdentity => {
+                            if manager_identity__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("managerIdentity"));
+                            }
+                            manager_identity__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::RoutingConfigUpdateState => {
+                            if routing_config_update_state__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("routingConfigUpdateState"));
+                            }
+                            routing_config_update_state__ = Some(map_.next_value::<super::super::enums::v1::RoutingConfigUpdateState>()? as i32);
+                        }
+                    }
+                }
+                Ok(WorkerDeploymentInfo {
+                    name: name__.unwrap_or_default(),
+                    version_summaries: version_summaries__.unwrap_or_default(),
+                    create_time: create_time__,
+                    routing_config: routing_config__,
+                    last_modifier_identity: last_modifier_identity__.unwrap_or_default(),
+                    manager_identity: manager_identity__.unwrap_or_default(),
+                    routing_config_update_state: routing_config_update_state__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("temporal.api.deployment.v1.WorkerDeploymentInfo", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for worker_deployment_info::WorkerDeploymentVersionSummary {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.version.is_empty() {
+            len += 1;
+        }
+        if self.status != 0 {
+            len += 1;
+        }
+        if self.deployment_version.is_some() {
+            len += 1;
+        }
+        if self.create_time.is_some() {
+            len += 1;
+        }
+        if self.drainage_status != 0 {
+            len += 1;
+        }
+        if self.drainage_info.is_some() {
+            len += 1;
+        }
+        if self.current_since_time.is_some() {
+            len += 1;
+        }
+        if self.ramping_since_time.is_some() {
+            len += 1;
+        }
+        if self.routing_update_time.is_some() {
+            len += 1;
+        }
+        if self.first_activation_time.is_some() {
+            len += 1;
+        }
+        if self.last_current_time.is_some() {
+            len += 1;
+        }
+        if self.last_deactivation_time.is_some() {
+            len += 1;
+        }
+        if self.compute_config.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("temporal.api.deployment.v1.WorkerDeploymentInfo.WorkerDeploymentVersionSummary", len)?;
+        if !self.version.is_empty() {
+            struct_ser.serialize_field("version", &self.version)?;
+        }
+        if self.status != 0 {
+            let v = super::super::enums::v1::WorkerDeploymentVersionStatus::try_from(self.status)
+                .map_err(|_| serde::ser::Error::custom(format!("Invalid variant {}", self.status)))?;
+            struct_ser.serialize_field("status", &v)?;
+        }
+        if let Some(v) = self.deployment_version.as_ref() {
+            struct_ser.serialize_field("deploymentVersion", v)?;
+        }
+        if let Some(v) = self.create_time.as_ref() {
+            struct_ser.serialize_field("createTime", v)?;
+        }
+        if self.drainage_status != 0 {
+            let v = super::super::enums::v1::VersionDrainageStatus::try_from(self.drainage_status)
+                .map_err(|_| serde::ser::Error::custom(format!("Invalid variant {}", self.drainage_status)))?;
+            struct_ser.serialize_field("drainageStatus", &v)?;
+        }
+        if let Some(v) = self.drainage_info.as_ref() {
+            struct_ser.serialize_field("drainageInfo", v)?;
+        }
+        if let Some(v) = self.current_since_time.as_ref() {
+            struct_ser.serialize_field("currentSinceTime", v)?;
+        }
+        if let Some(v) = self.ramping_since_time.as_ref() {
+            struct_ser.serialize_field("rampingSinceTime", v)?;
+        }
+        if let Some(v) = self.routing_update_time.as_ref() {
+            struct_ser.serialize_field("routingUpdateTime", v)?;
+        }
+        if let Some(v) = self.first_activation_time.as_ref() {
+            struct_ser.serialize_field("firstActivationTime", v)?;
+        }
+        if let Some(v) = self.last_current_time.as_ref() {
+            struct_ser.serialize_field("lastCurrentTime", v)?;
+        }
+        if let Some(v) = self.last_deactivation_time.as_ref() {
+            struct_ser.serialize_field("lastDeactivationTime", v)?;
+        }
+        if let Some(v) = self.compute_config.as_ref() {
+            struct_ser.serialize_field("computeConfig", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for worker_deployment_info::WorkerDeploymentVersionSummary {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "version",
+            "status",
+            "deployment_version",
+            "deploymentVersion",
+            "create_time",
+            "createTime",
+            "drainage_status",
+            "drainageStatus",
+            "drainage_info",
+            "drainageInfo",
+            "current_since_time",
+            "currentSinceTime",
+            "ramping_since_time",
+            "rampingSinceTime",
+            "routing_update_time",
+            "routingUpdateTime",
+            "first_activation_time",
+            "firstActivationTime",
+            "last_current_time",
+            "lastCurrentTime",
+            "last_deactivation_time",
+            "lastDeactivationTime",
+            "compute_config",
+            "computeConfig",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Version,
+            Status,
+            DeploymentVersion,
+            CreateTime,
+            DrainageStatus,
+            DrainageInfo,
+            CurrentSinceTime,
+            RampingSinceTime,
+            RoutingUpdateTime,
+            FirstActivationTime,
+            LastCurrentTime,
+            LastDeactivationTime,
+            ComputeConfig,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "version" => Ok(GeneratedField::Version),
+                            "status" => Ok(GeneratedField::Status),
+                            "deploymentVersion" | "deployment_version" => Ok(GeneratedField::DeploymentVersion),
+                            "createTime" | "create_time" => Ok(GeneratedField::CreateTime),
+                            "drainageStatus" | "drainage_status" => Ok(GeneratedField::DrainageStatus),
+                            "drainageInfo" | "drainage_info" => Ok(GeneratedField::DrainageInfo),
+                            "currentSinceTime" | "current_since_time" => Ok(GeneratedField::CurrentSinceTime),
+                            "rampingSinceTime" | "ramping_since_time" => Ok(GeneratedField::RampingSinceTime),
+                            "routingUpdateTime" | "routing_update_time" => Ok(GeneratedField::RoutingUpdateTime),
+                            "firstActivationTime" | "first_activation_time" => Ok(GeneratedField::FirstActivationTime),
+                            "lastCurrentTime" | "last_current_time" => Ok(GeneratedField::LastCurrentTime),
+                            "lastDeactivationTime" | "last_deactivation_time" => Ok(GeneratedField::LastDeactivationTime),
+                            "computeConfig" | "compute_config" => Ok(GeneratedField::ComputeConfig),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = worker_deployment_info::WorkerDeploymentVersionSummary;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct temporal.api.deployment.v1.WorkerDeploymentInfo.WorkerDeploymentVersionSummary")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<worker_deployment_info::WorkerDeploymentVersionSummary, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut version__ = None;
+                let mut status__ = None;
+                let mut deployment_version__ = None;
+                let mut create_time__ = None;
+                let mut drainage_status__ = None;
+                let mut drainage_info__ = None;
+                let mut current_since_time__ = None;
+                let mut ramping_since_time__ = None;
+                let mut routing_update_time__ = None;
+                let mut first_activation_time__ = None;
+                let mut last_current_time__ = None;
+                let mut last_deactivation_time__ = None;
+                let mut compute_config__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Version => {
+                            if version__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("version"));
+                            }
+                            version__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::Status => {
+                            if status__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("status"));
+                            }
+                            status__ = Some(map_.next_value::<super::super::enums::v1::WorkerDeploymentVersionStatus>()? as i32);
+                        }
+                        GeneratedField::DeploymentVersion => {
+                            if deployment_version__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("deploymentVersion"));
+                            }
+                            deployment_version__ = map_.next_value()?;
+                        }
+                        GeneratedField::CreateTime => {
+                            if create_time__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("createTime"));
+                            }
+                            create_time__ = map_.next_value()?;
+                        }
+                        GeneratedField::DrainageStatus => {
+                            if drainage_status__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("drainageStatus"));
+                            }
+                            drainage_status__ = Some(map_.next_value::<super::super::enums::v1::VersionDrainageStatus>()? as i32);
+                        }
+                        GeneratedField::DrainageInfo => {
+                            if drainage_info__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("drainageInfo"));
+                            }
+                            drainage_info__ = map_.next_value()?;
+                        }
+                        GeneratedField::CurrentSinceTime => {
+                            if current_since_time__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("currentSinceTime"));
+                            }
+                            current_since_time__ = map_.next_value()?;
+                        }
+                        GeneratedField::RampingSinceTime => {
+                            if ramping_since_time__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("rampingSinceTime"));
+                            }
+                            ramping_since_time__ = map_.next_value()?;
+                        }
+                        GeneratedField::RoutingUpdateTime => {
+                            if routing_update_time__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("routingUpdateTime"));
+                            }
+                            routing_update_time__ = map_.next_value()?;
+                        }
+                        GeneratedField::FirstActivationTime => {
+                            if first_activation_time__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("firstActivationTime"));
+                            }
+                            first_activation_time__ = map_.next_value()?;
+                        }
+                        GeneratedField::LastCurrentTime => {
+                            if last_current_time__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("lastCurrentTime"));
+                            }
+                            last_current_time__ = map_.next_value()?;
+                        }
+                        GeneratedField::LastDeactivationTime => {
+                            if last_deactivation_time__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("lastDeactivationTime"));
+                            }
+                            last_deactivation_time__ = map_.next_value()?;
+                        }
+                        GeneratedField::ComputeConfig => {
+                            if compute_config__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("computeConfig"));
+                            }
+                            compute_config__ = map_.next_value()?;
+                        }
+                    }
+                }
+                Ok(worker_deployment_info::WorkerDeploymentVersionSummary {
+                    version: version__.unwrap_or_default(),
+                    status: status__.unwrap_or_default(),
+                    deployment_version: deployment_version__,
+                    create_time: create_time__,
+                    drainage_status: drainage_status__.unwrap_or_default(),
+                    drainage_info: drainage_info__,
+                    current_since_time: current_since_time__,
+                    ramping_since_time: ramping_since_time__,
+                    routing_update_time: routing_update_time__,
+                    first_activation_time: first_activation_time__,
+                    last_current_time: last_current_time__,
+                    last_deactivation_time: last_deactivation_time__,
+                    compute_config: compute_config__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("temporal.api.deployment.v1.WorkerDeploymentInfo.WorkerDeploymentVersionSummary", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for WorkerDeploymentOptions {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.deployment_name.is_empty() {
+            len += 1;
+        }
+        if !self.build_id.is_empty() {
+            len += 1;
+        }
+        if self.worker_versioning_mode != 0 {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("temporal.api.deployment.v1.WorkerDeploymentOptions", len)?;
+        if !self.deployment_name.is_empty() {
+            struct_ser.serialize_field("deploymentName", &self.deployment_name)?;
+        }
+        if !self.build_id.is_empty() {
+            struct_ser.serialize_field("buildId", &self.build_id)?;
+        }
+        if self.worker_versioning_mode != 0 {
+            let v = super::super::enums::v1::WorkerVersioningMode::try_from(self.worker_versioning_mode)
+                .map_err(|_| serde::ser::Error::custom(format!("Invalid variant {}", self.worker_versioning_mode)))?;
+            struct_ser.serialize_field("workerVersioningMode", &v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for WorkerDeploymentOptions {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "deployment_name",
+            "deploymentName",
+            "build_id",
+            "buildId",
+            "worker_versioning_mode",
+            "workerVersioningMode",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            DeploymentName,
+            BuildId,
+            WorkerVersioningMode,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "deploymentName" | "deployment_name" => Ok(GeneratedField::DeploymentName),
+                            "buildId" | "build_id" => Ok(GeneratedField::BuildId),
+                            "workerVersioningMode" | "worker_versioning_mode" => Ok(GeneratedField::WorkerVersioningMode),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = WorkerDeploymentOptions;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct temporal.api.deployment.v1.WorkerDeploymentOptions")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<WorkerDeploymentOptions, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut deployment_name__ = None;
+                let mut build_id__ = None;
+                let mut worker_versioning_mode__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::DeploymentName => {
+                            if deployment_name__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("deploymentName"));
+                            }
+                            deployment_name__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::BuildId => {
+                            if build_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("buildId"));
+                            }
+                            build_id__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::WorkerVersioningMode => {
+                            if worker_versioning_mode__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("workerVersioningMode"));
+                            }
+                            worker_versioning_mode__ = Some(map_.next_value::<super::super::enums::v1::WorkerVersioningMode>()? as i32);
+                        }
+                    }
+                }
+                Ok(WorkerDeploymentOptions {
+                    deployment_name: deployment_name__.unwrap_or_default(),
+                    build_id: build_id__.unwrap_or_default(),
+                    worker_versioning_mode: worker_versioning_mode__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("temporal.api.deployment.v1.WorkerDeploymentOptions", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for WorkerDeploymentVersion {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.build_id.is_empty() {
+            len += 1;
+        }
+        if !self.deployment_name.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("temporal.api.deployment.v1.WorkerDeploymentVersion", len)?;
+        if !self.build_id.is_empty() {
+            struct_ser.serialize_field("buildId", &self.build_id)?;
+        }
+        if !self.deployment_name.is_empty() {
+            struct_ser.serialize_field("deploymentName", &self.deployment_name)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for WorkerDeploymentVersion {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "build_id",
+            "buildId",
+            "deployment_name",
+            "deploymentName",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            BuildId,
+            DeploymentName,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "buildId" | "build_id" => Ok(GeneratedField::BuildId),
+                            "deploymentName" | "deployment_name" => Ok(GeneratedField::DeploymentName),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = WorkerDeploymentVersion;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct temporal.api.deployment.v1.WorkerDeploymentVersion")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<WorkerDeploymentVersion, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut build_id__ = None;
+                let mut deployment_name__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::BuildId => {
+                            if build_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("buildId"));
+                            }
+                            build_id__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::DeploymentName => {
+                            if deployment_name__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("deploymentName"));
+                            }
+                            deployment_name__ = Some(map_.next_value()?);
+                        }
+                    }
+                }
+                Ok(WorkerDeploymentVersion {
+                    build_id: build_id__.unwrap_or_default(),
+                    deployment_name: deployment_name__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("temporal.api.deployment.v1.WorkerDeploymentVersion", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for WorkerDeploymentVersionInfo {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.version.is_empty() {
+            len += 1;
+        }
+        if self.status != 0 {
+            len += 1;
+        }
+        if self.deployment_version.is_some() {
+            len += 1;
+        }
+        if !self.deployment_name.is_empty() {
+            len += 1;
+        }
+        if self.create_time.is_some() {
+            len += 1;
+        }
+        if self.routing_changed_time.is_some() {
+            len += 1;
+        }
+        if self.current_since_time.is_some() {
+            len += 1;
+        }
+        if self.ramping_since_time.is_some() {
+            len += 1;
+        }
+        if self.first_activation_time.is_some() {
+            len += 1;
+        }
+        if self.last_current_time.is_some() {
+            len += 1;
+        }
+        if self.last_deactivation_time.is_some() {
+            len += 1;
+        }
+        if self.ramp_percentage != 0. {
+            len += 1;
+        }
+        if !self.task_queue_infos.is_empty() {
+            len += 1;
+        }
+        if self.drainage_info.is_some() {
+            len += 1;
+        }
+        if self.metadata.is_some() {
+            len += 1;
+        }
+        if self.compute_config.is_some() {
+            len += 1;
+        }
+        if !self.last_modifier_identity.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("temporal.api.deployment.v1.WorkerDeploymentVersionInfo", len)?;
+        if !self.version.is_empty() {
+            struct_ser.serialize_field("version", &self.version)?;
+        }
+        if self.status != 0 {
+            let v = super::super::enums::v1::WorkerDeploymentVersionStatus::try_from(self.status)
+                .map_err(|_| serde::ser::Error::custom(format!("Invalid variant {}", self.status)))?;
+            struct_ser.serialize_field("status", &v)?;
+        }
+        if let Some(v) = self.deployment_version.as_ref() {
+            struct_ser.serialize_field("deploymentVersion", v)?;
+        }
+        if !self.deployment_name.is_empty() {
+            struct_ser.serialize_field("deploymentName", &self.deployment_name)?;
+        }
+        if let Some(v) = self.create_time.as_ref() {
+            struct_ser.serialize_field("createTime", v)?;
+        }
+        if let Some(v) = self.routing_changed_time.as_ref() {
+            struct_ser.serialize_field("routingChangedTime", v)?;
+        }
+        if let Some(v) = self.current_since_time.as_ref() {
+            struct_ser.serialize_field("currentSinceTime", v)?;
+        }
+        if let Some(v) = self.ramping_since_time.as_ref() {
+            struct_ser.serialize_field("rampingSinceTime", v)?;
+        }
+        if let Some(v) = self.first_activation_time.as_ref() {
+            struct_ser.serialize_field("firstActivationTime", v)?;
+        }
+        if let Some(v) = self.last_current_time.as_ref() {
+            struct_ser.serialize_field("lastCurrentTime", v)?;
+        }
+        if let Some(v) = self.last_deactivation_time.as_ref() {
+            struct_ser.serialize_field("lastDeactivationTime", v)?;
+        }
+        if self.ramp_percentage != 0. {
+            struct_ser.serialize_field("rampPercentage", &self.ramp_percentage)?;
+        }
+        if !self.task_queue_infos.is_empty() {
+            struct_ser.serialize_field("taskQueueInfos", &self.task_queue_infos)?;
+        }
+        if let Some(v) = self.drainage_info.as_ref() {
+            struct_ser.serialize_field("drainageInfo", v)?;
+        }
+        if let Some(v) = self.metadata.as_ref() {
+            struct_ser.serialize_field("metadata", v)?;
+        }
+        if let Some(v) = self.compute_config.as_ref() {
+            struct_ser.serialize_field("computeConfig", v)?;
+        }
+        if !self.last_modifier_identity.is_empty() {
+            struct_ser.serialize_field("lastModifierIdentity", &self.last_modifier_identity)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for WorkerDeploymentVersionInfo {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "version",
+            "status",
+            "deployment_version",
+            "deploymentVersion",
+            "deployment_name",
+            "deploymentName",
+            "create_time",
+            "createTime",
+            "routing_changed_time",
+            "routingChangedTime",
+            "current_since_time",
+            "currentSinceTime",
+            "ramping_since_time",
+            "rampingSinceTime",
+            "first_activation_time",
+            "firstActivationTime",
+            "last_current_time",
+            "lastCurrentTime",
+            "last_deactivation_time",
+            "lastDeactivationTime",
+            "ramp_percentage",
+            "rampPercentage",
+            "task_queue_infos",
+            "taskQueueInfos",
+            "drainage_info",
+            "drainageInfo",
+            "metadata",
+            "compute_config",
+            "computeConfig",
+            "last_modifier_identity",
+            "lastModifierIdentity",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Version,
+            Status,
+            DeploymentVersion,
+            DeploymentName,
+            CreateTime,
+            RoutingChangedTime,
+            CurrentSinceTime,
+            RampingSinceTime,
+            FirstActivationTime,
+            LastCurrentTime,
+            LastDeactivationTime,
+            RampPercentage,
+            TaskQueueInfos,
+            DrainageInfo,
+            Metadata,
+            ComputeConfig,
+            LastModifierIdentity,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "version" => Ok(GeneratedField::Version),
+                            "status" => Ok(GeneratedField::Status),
+                            "deploymentVersion" | "deployment_version" => Ok(GeneratedField::DeploymentVersion),
+                            "deploymentName" | "deployment_name" => Ok(GeneratedField::DeploymentName),
+                            "createTime" | "create_time" => Ok(GeneratedField::CreateTime),
+                            "routingChangedTime" | "routing_changed_time" => Ok(GeneratedField::RoutingChangedTime),
+                            "currentSinceTime" | "current_since_time" => Ok(GeneratedField::CurrentSinceTime),
+                            "rampingSinceTime" | "ramping_since_time" => Ok(GeneratedField::RampingSinceTime),
+                            "firstActivationTime" | "first_activation_time" => Ok(GeneratedField::FirstActivationTime),
+                            "lastCurrentTime" | "last_current_time" => Ok(GeneratedField::LastCurrentTime),
+                            "lastDeactivationTime" | "last_deactivation_time" => Ok(GeneratedField::LastDeactivationTime),
+                            "rampPercentage" | "ramp_percentage" => Ok(GeneratedField::RampPercentage),
+                            "taskQueueInfos" | "task_queue_infos" => Ok(GeneratedField::TaskQueueInfos),
+                            "drainageInfo" | "drainage_info" => Ok(GeneratedField::DrainageInfo),
+                            "metadata" => Ok(GeneratedField::Metadata),
+                            "computeConfig" | "compute_config" => Ok(GeneratedField::ComputeConfig),
+                            "lastModifierIdentity" | "last_modifier_identity" => Ok(GeneratedField::LastModifierIdentity),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = WorkerDeploymentVersionInfo;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct temporal.api.deployment.v1.WorkerDeploymentVersionInfo")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<WorkerDeploymentVersionInfo, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut version__ = None;
+                let mut status__ = None;
+                let mut deployment_version__ = None;
+                let mut deployment_name__ = None;
+                let mut create_time__ = None;
+                let mut routing_changed_time__ = None;
+                let mut current_since_time__ = None;
+                let mut ramping_since_time__ = None;
+                let mut first_activation_time__ = None;
+                let mut last_current_time__ = None;
+                let mut last_deactivation_time__ = None;
+                let mut ramp_percentage__ = None;
+                let mut task_queue_infos__ = None;
+                let mut drainage_info__ = None;
+                let mut metadata__ = None;
+                let mut compute_config__ = None;
+                let mut last_modifier_identity__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Version => {
+                            if version__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("version"));
+                            }
+                            version__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::Status => {
+                            if status__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("status"));
+                            }
+                            status__ = Some(map_.next_value::<super::super::enums::v1::WorkerDeploymentVersionStatus>()? as i32);
+                        }
+                        GeneratedField::DeploymentVersion => {
+                            if deployment_version__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("deploymentVersion"));
+                            }
+                            deployment_version__ = map_.next_value()?;
+                        }
+                        GeneratedField::DeploymentName => {
+                            if deployment_name__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("deploymentName"));
+                            }
+                            deployment_name__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::CreateTime => {
+                            if create_time__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("createTime"));
+                            }
+                            create_time__ = map_.next_value()?;
+                        }
+                        GeneratedField::RoutingChangedTime => {
+                            if routing_changed_time__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("routingChangedTime"));
+                            }
+                            routing_changed_time__ = map_.next_value()?;
+                        }
+                        GeneratedField::CurrentSinceTime => {
+                            if current_since_time__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("currentSinceTime"));
+                            }
+                            current_since_time__ = map_.next_value()?;
+                        }
+                        GeneratedField::RampingSinceTime => {
+                            if ramping_since_time__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("rampingSinceTime"));
+                            }
+                            ramping_since_time__ = map_.next_value()?;
+                        }
+                        GeneratedField::FirstActivationTime => {
+                            if first_activation_time__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("firstActivationTime"));
+                            }
+                            first_activation_time__ = map_.next_value()?;
+                        }
+                        GeneratedField::LastCurrentTime => {
+                            if last_current_time__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("lastCurrentTime"));
+                            }
+                            last_current_time__ = map_.next_value()?;
+                        }
+                        GeneratedField::LastDeactivationTime => {
+                            if last_deactivation_time__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("lastDeactivationTime"));
+                            }
+                            last_deactivation_time__ = map_.next_value()?;
+                        }
+                        GeneratedField::RampPercentage => {
+                            if ramp_percentage__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("rampPercentage"));
+                            }
+                            ramp_percentage__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::TaskQueueInfos => {
+                            if task_queue_infos__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("taskQueueInfos"));
+                            }
+                            task_queue_infos__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::DrainageInfo => {
+                            if drainage_info__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("drainageInfo"));
+                            }
+                            drainage_info__ = map_.next_value()?;
+                        }
+                        GeneratedField::Metadata => {
+                            if metadata__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("metadata"));
+                            }
+                            metadata__ = map_.next_value()?;
+                        }
+                        GeneratedField::ComputeConfig => {
+                            if compute_config__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("computeConfig"));
+                            }
+                            compute_config__ = map_.next_value()?;
+                        }
+                        GeneratedField::LastModifierIdentity => {
+                            if last_modifier_identity__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("lastModifierIdentity"));
+                            }
+                            last_modifier_identity__ = Some(map_.next_value()?);
+                        }
+                    }
+                }
+                Ok(WorkerDeploymentVersionInfo {
+                    version: version__.unwrap_or_default(),
+                    status: status__.unwrap_or_default(),
+                    deployment_version: deployment_version__,
+                    deployment_name: deployment_name__.unwrap_or_default(),
+                    create_time: create_time__,
+                    routing_changed_time: routing_changed_time__,
+                    current_since_time: current_since_time__,
+                    ramping_since_time: ramping_since_time__,
+                    first_activation_time: first_activation_time__,
+                    last_current_time: last_current_time__,
+                    last_deactivation_time: last_deactivation_time__,
+                    ramp_percentage: ramp_percentage__.unwrap_or_default(),
+                    task_queue_infos: task_queue_infos__.unwrap_or_default(),
+                    drainage_info: drainage_info__,
+                    metadata: metadata__,
+                    compute_config: compute_config__,
+                    last_modifier_identity: last_modifier_identity__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("temporal.api.deployment.v1.WorkerDeploymentVersionInfo", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for worker_deployment_version_info::VersionTaskQueueInfo {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.name.is_empty() {
+            len += 1;
+        }
+        if self.r#type != 0 {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("temporal.api.deployment.v1.WorkerDeploymentVersionInfo.VersionTaskQueueInfo", len)?;
+        if !self.name.is_empty() {
+            struct_ser.serialize_field("name", &self.name)?;
+        }
+        if self.r#type != 0 {
+            let v = super::super::enums::v1::TaskQueueType::try_from(self.r#type)
+                .map_err(|_| serde::ser::Error::custom(format!("Invalid variant {}", self.r#type)))?;
+            struct_ser.serialize_field("type", &v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for worker_deployment_version_info::VersionTaskQueueInfo {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "name",
+            "type",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Name,
+            Type,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "name" => Ok(GeneratedField::Name),
+                            "type" => Ok(GeneratedField::Type),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = worker_deployment_version_info::VersionTaskQueueInfo;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct temporal.api.deployment.v1.WorkerDeploymentVersionInfo.VersionTaskQueueInfo")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<worker_deployment_version_info::VersionTaskQueueInfo, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut name__ = None;
+                let mut r#type__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Name => {
+                            if name__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("name"));
+                            }
+                            name__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::Type => {
+                            if r#type__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("type"));
+                            }
+                            r#type__ = Some(map_.next_value::<super::super::enums::v1::TaskQueueType>()? as i32);
+                        }
+                    }
+                }
+                Ok(worker_deployment_version_info::VersionTaskQueueInfo {
+                    name: name__.unwrap_or_default(),
+                    r#type: r#type__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("temporal.api.deployment.v1.WorkerDeploymentVersionInfo.VersionTaskQueueInfo", FIELDS, GeneratedVisitor)
+    }
+}
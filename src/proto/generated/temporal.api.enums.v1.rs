@@ -823,7 +823,6 @@ impl TimeoutType {
 /// Versions. The Versioning Behavior of a workflow execution is typically specified by the worker
 /// who completes the first task of the execution, but is also overridable manually for new and
 /// existing workflows (see VersioningOverride).
-/// Experimental. Worker Deployments are experimental and might significantly change in the future.
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
 #[repr(i32)]
 pub enum VersioningBehavior {
@@ -898,16 +897,30 @@ impl VersioningBehavior {
 #[repr(i32)]
 pub enum ContinueAsNewVersioningBehavior {
     Unspecified = 0,
+    /// Experimental.
     /// Start the new run with AutoUpgrade behavior. Use the Target Version of the workflow's task queue at
     /// start-time, as AutoUpgrade workflows do. After the first workflow task completes, use whatever
     /// Versioning Behavior the workflow is annotated with in the workflow code.
     ///
-    /// Note that if the previous workflow had a Pinned override, that override will be inherited by the
+    /// Note that if the workflow being continued has a Pinned override, that override will be inherited by the
     /// new workflow run regardless of the ContinueAsNewVersioningBehavior specified in the continue-as-new
-    /// command. If a Pinned override is inherited by the new run, and the new run starts with AutoUpgrade
-    /// behavior, the base version of the new run will be the Target Version as described above, but the
-    /// effective version will be whatever is specified by the Versioning Override until the override is removed.
+    /// command. Versioning Override always takes precedence until it's removed manually via UpdateWorkflowExecutionOptions.
     AutoUpgrade = 1,
+    /// Experimental.
+    /// Use the Ramping Version of the workflow's task queue at start time, regardless of the workflow's
+    /// Target Version (according to f(workflow_id, ramp_percentage)). After the first workflow task completes,
+    /// the workflow will use whatever Versioning Behavior it is annotated with. If there is no Ramping
+    /// Version by the time that the first workflow task is dispatched, it will be sent to the Current Version.
+    ///
+    /// It is highly discouraged to use this if the workflow is annotated with AutoUpgrade behavior, because
+    /// this setting ONLY applies to the first task of the workflow. If, after the first task, the workflow
+    /// is AutoUpgrade, it will behave like a normal AutoUpgrade workflow and go to the Target Version, which
+    /// may be the Current Version instead of the Ramping Version.
+    ///
+    /// Note that if the workflow being continued has a Pinned override, that override will be inherited by the
+    /// new workflow run regardless of the ContinueAsNewVersioningBehavior specified in the continue-as-new
+    /// command. Versioning Override always takes precedence until it's removed manually via UpdateWorkflowExecutionOptions.
+    UseRampingVersion = 2,
 }
 impl ContinueAsNewVersioningBehavior {
     /// String value of the enum field names used in the ProtoBuf definition.
@@ -918,6 +931,9 @@ impl ContinueAsNewVersioningBehavior {
         match self {
             Self::Unspecified => "CONTINUE_AS_NEW_VERSIONING_BEHAVIOR_UNSPECIFIED",
             Self::AutoUpgrade => "CONTINUE_AS_NEW_VERSIONING_BEHAVIOR_AUTO_UPGRADE",
+            Self::UseRampingVersion => {
+                "CONTINUE_AS_NEW_VERSIONING_BEHAVIOR_USE_RAMPING_VERSION"
+            }
         }
     }
     /// Creates an enum from field names used in the ProtoBuf definition.
@@ -925,6 +941,9 @@ impl ContinueAsNewVersioningBehavior {
         match value {
             "CONTINUE_AS_NEW_VERSIONING_BEHAVIOR_UNSPECIFIED" => Some(Self::Unspecified),
             "CONTINUE_AS_NEW_VERSIONING_BEHAVIOR_AUTO_UPGRADE" => Some(Self::AutoUpgrade),
+            "CONTINUE_AS_NEW_VERSIONING_BEHAVIOR_USE_RAMPING_VERSION" => {
+                Some(Self::UseRampingVersion)
+            }
             _ => None,
         }
     }
@@ -940,9 +959,6 @@ pub enum SuggestContinueAsNewReason {
     TooManyHistoryEvents = 2,
     /// Workflow's count of completed plus in-flight updates is too large.
     TooManyUpdates = 3,
-    /// Workflow's Target Worker Deployment Version is different from its
-    /// Current Version and the workflow is versioned.
-    TargetWorkerDeploymentVersionChanged = 4,
 }
 impl SuggestContinueAsNewReason {
     /// String value of the enum field names used in the ProtoBuf definition.
@@ -959,9 +975,6 @@ impl SuggestContinueAsNewReason {
                 "SUGGEST_CONTINUE_AS_NEW_REASON_TOO_MANY_HISTORY_EVENTS"
             }
             Self::TooManyUpdates => "SUGGEST_CONTINUE_AS_NEW_REASON_TOO_MANY_UPDATES",
-            Self::TargetWorkerDeploymentVersionChanged => {
-                "SUGGEST_CONTINUE_AS_NEW_REASON_TARGET_WORKER_DEPLOYMENT_VERSION_CHANGED"
-            }
         }
     }
     /// Creates an enum from field names used in the ProtoBuf definition.
@@ -977,9 +990,6 @@ impl SuggestContinueAsNewReason {
             "SUGGEST_CONTINUE_AS_NEW_REASON_TOO_MANY_UPDATES" => {
                 Some(Self::TooManyUpdates)
             }
-            "SUGGEST_CONTINUE_AS_NEW_REASON_TARGET_WORKER_DEPLOYMENT_VERSION_CHANGED" => {
-                Some(Self::TargetWorkerDeploymentVersionChanged)
-            }
             _ => None,
         }
     }
@@ -1802,6 +1812,11 @@ pub enum TaskQueueKind {
     ///
     /// Sticky queues are only for workflow tasks. There are no sticky task queues for activities.
     Sticky = 2,
+    /// A worker-commands task queue is used for server-to-worker communication (e.g. activity
+    /// cancellations). These queues are ephemeral and per-worker-process — they exist only for
+    /// the lifetime of the worker process. Used with TASK_QUEUE_TYPE_NEXUS and polled via
+    /// PollNexusTaskQueue.
+    WorkerCommands = 3,
 }
 impl TaskQueueKind {
     /// String value of the enum field names used in the ProtoBuf definition.
@@ -1813,6 +1828,7 @@ impl TaskQueueKind {
             Self::Unspecified => "TASK_QUEUE_KIND_UNSPECIFIED",
             Self::Normal => "TASK_QUEUE_KIND_NORMAL",
             Self::Sticky => "TASK_QUEUE_KIND_STICKY",
+            Self::WorkerCommands => "TASK_QUEUE_KIND_WORKER_COMMANDS",
         }
     }
     /// Creates an enum from field names used in the ProtoBuf definition.
@@ -1821,6 +1837,7 @@ impl TaskQueueKind {
             "TASK_QUEUE_KIND_UNSPECIFIED" => Some(Self::Unspecified),
             "TASK_QUEUE_KIND_NORMAL" => Some(Self::Normal),
             "TASK_QUEUE_KIND_STICKY" => Some(Self::Sticky),
+            "TASK_QUEUE_KIND_WORKER_COMMANDS" => Some(Self::WorkerCommands),
             _ => None,
         }
     }
@@ -2106,7 +2123,6 @@ impl DeploymentReachability {
 ///      aip.dev/not-precedent: Call this status because it is . --)
 /// Specify the drainage status for a Worker Deployment Version so users can decide whether they
 /// can safely decommission the version.
-/// Experimental. Worker Deployments are experimental and might significantly change in the future.
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
 #[repr(i32)]
 pub enum VersionDrainageStatus {
@@ -2148,7 +2164,6 @@ impl VersionDrainageStatus {
 /// - Whether or not Temporal Server considers this worker's version (Build ID) when dispatching
 ///    tasks to it.
 /// - Whether or not the workflows processed by this worker are versioned using the worker's version.
-/// Experimental. Worker Deployments are experimental and might significantly change in the future.
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
 #[repr(i32)]
 pub enum WorkerVersioningMode {
@@ -2198,7 +2213,6 @@ impl WorkerVersioningMode {
 /// (-- api-linter: core::0216::synonyms=disabled
 ///      aip.dev/not-precedent: Call this status because it is . --)
 /// Specify the status of a Worker Deployment Version.
-/// Experimental. Worker Deployments are experimental and might significantly change in the future.
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
 #[repr(i32)]
 pub enum WorkerDeploymentVersionStatus {
@@ -2221,6 +2235,9 @@ pub enum WorkerDeploymentVersionStatus {
     /// not query closed workflows. If the user does query closed workflows for some time x after
     /// workflows are closed, they should decommission the version after it has been drained for that duration.
     Drained = 5,
+    /// The Worker Deployment Version is created by user (via `CreateWorkerDeploymentVersion` API)
+    /// but server has not seen any poller for it yet.
+    Created = 6,
 }
 impl WorkerDeploymentVersionStatus {
     /// String value of the enum field names used in the ProtoBuf definition.
@@ -2235,6 +2252,7 @@ impl WorkerDeploymentVersionStatus {
             Self::Ramping => "WORKER_DEPLOYMENT_VERSION_STATUS_RAMPING",
             Self::Draining => "WORKER_DEPLOYMENT_VERSION_STATUS_DRAINING",
             Self::Drained => "WORKER_DEPLOYMENT_VERSION_STATUS_DRAINED",
+            Self::Created => "WORKER_DEPLOYMENT_VERSION_STATUS_CREATED",
         }
     }
     /// Creates an enum from field names used in the ProtoBuf definition.
@@ -2246,6 +2264,7 @@ impl WorkerDeploymentVersionStatus {
             "WORKER_DEPLOYMENT_VERSION_STATUS_RAMPING" => Some(Self::Ramping),
             "WORKER_DEPLOYMENT_VERSION_STATUS_DRAINING" => Some(Self::Draining),
             "WORKER_DEPLOYMENT_VERSION_STATUS_DRAINED" => Some(Self::Drained),
+            "WORKER_DEPLOYMENT_VERSION_STATUS_CREATED" => Some(Self::Created),
             _ => None,
         }
     }
@@ -2483,6 +2502,213 @@ impl ActivityIdConflictPolicy {
         }
     }
 }
+/// NexusHandlerErrorRetryBehavior allows nexus handlers to explicity set the retry behavior of a HandlerError. If not
+/// specified, retry behavior is determined from the error type. For example internal errors are not retryable by default
+/// unless specified otherwise.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum NexusHandlerErrorRetryBehavior {
+    Unspecified = 0,
+    /// A handler error is explicitly marked as retryable.
+    Retryable = 1,
+    /// A handler error is explicitly marked as non-retryable.
+    NonRetryable = 2,
+}
+impl NexusHandlerErrorRetryBehavior {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            Self::Unspecified => "NEXUS_HANDLER_ERROR_RETRY_BEHAVIOR_UNSPECIFIED",
+            Self::Retryable => "NEXUS_HANDLER_ERROR_RETRY_BEHAVIOR_RETRYABLE",
+            Self::NonRetryable => "NEXUS_HANDLER_ERROR_RETRY_BEHAVIOR_NON_RETRYABLE",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "NEXUS_HANDLER_ERROR_RETRY_BEHAVIOR_UNSPECIFIED" => Some(Self::Unspecified),
+            "NEXUS_HANDLER_ERROR_RETRY_BEHAVIOR_RETRYABLE" => Some(Self::Retryable),
+            "NEXUS_HANDLER_ERROR_RETRY_BEHAVIOR_NON_RETRYABLE" => {
+                Some(Self::NonRetryable)
+            }
+            _ => None,
+        }
+    }
+}
+/// Status of a standalone Nexus operation execution.
+/// The status is updated once, when the operation is originally scheduled, and again when the
+/// operation reaches a terminal status.
+/// (-- api-linter: core::0216::synonyms=disabled
+///      aip.dev/not-precedent: Named consistently with WorkflowExecutionStatus. --)
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum NexusOperationExecutionStatus {
+    Unspecified = 0,
+    /// The operation is not in a terminal status. The operation may be attempting to start,
+    /// backing off between attempts, or already started.
+    Running = 1,
+    /// The operation completed successfully.
+    Completed = 2,
+    /// The operation completed with failure.
+    Failed = 3,
+    /// The operation completed as canceled.
+    /// Requesting to cancel an operation does not automatically transition the operation to canceled status, depending
+    /// on the current operation status and the cancelation type used.
+    Canceled = 4,
+    /// The operation was terminated. Termination happens immediately without notifying the handler.
+    Terminated = 5,
+    /// The operation has timed out by reaching one of the specified timeouts.
+    TimedOut = 6,
+}
+impl NexusOperationExecutionStatus {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            Self::Unspecified => "NEXUS_OPERATION_EXECUTION_STATUS_UNSPECIFIED",
+            Self::Running => "NEXUS_OPERATION_EXECUTION_STATUS_RUNNING",
+            Self::Completed => "NEXUS_OPERATION_EXECUTION_STATUS_COMPLETED",
+            Self::Failed => "NEXUS_OPERATION_EXECUTION_STATUS_FAILED",
+            Self::Canceled => "NEXUS_OPERATION_EXECUTION_STATUS_CANCELED",
+            Self::Terminated => "NEXUS_OPERATION_EXECUTION_STATUS_TERMINATED",
+            Self::TimedOut => "NEXUS_OPERATION_EXECUTION_STATUS_TIMED_OUT",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "NEXUS_OPERATION_EXECUTION_STATUS_UNSPECIFIED" => Some(Self::Unspecified),
+            "NEXUS_OPERATION_EXECUTION_STATUS_RUNNING" => Some(Self::Running),
+            "NEXUS_OPERATION_EXECUTION_STATUS_COMPLETED" => Some(Self::Completed),
+            "NEXUS_OPERATION_EXECUTION_STATUS_FAILED" => Some(Self::Failed),
+            "NEXUS_OPERATION_EXECUTION_STATUS_CANCELED" => Some(Self::Canceled),
+            "NEXUS_OPERATION_EXECUTION_STATUS_TERMINATED" => Some(Self::Terminated),
+            "NEXUS_OPERATION_EXECUTION_STATUS_TIMED_OUT" => Some(Self::TimedOut),
+            _ => None,
+        }
+    }
+}
+/// Stage that can be specified when waiting on a nexus operation.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum NexusOperationWaitStage {
+    Unspecified = 0,
+    /// Wait for the operation to be started.
+    Started = 1,
+    /// Wait for the operation to be in a terminal state, either successful or unsuccessful.
+    Closed = 2,
+}
+impl NexusOperationWaitStage {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            Self::Unspecified => "NEXUS_OPERATION_WAIT_STAGE_UNSPECIFIED",
+            Self::Started => "NEXUS_OPERATION_WAIT_STAGE_STARTED",
+            Self::Closed => "NEXUS_OPERATION_WAIT_STAGE_CLOSED",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "NEXUS_OPERATION_WAIT_STAGE_UNSPECIFIED" => Some(Self::Unspecified),
+            "NEXUS_OPERATION_WAIT_STAGE_STARTED" => Some(Self::Started),
+            "NEXUS_OPERATION_WAIT_STAGE_CLOSED" => Some(Self::Closed),
+            _ => None,
+        }
+    }
+}
+/// Defines whether to allow re-using an operation ID from a previously *closed* Nexus operation.
+/// If the request is denied, the server returns a `NexusOperationAlreadyStarted` error.
+///
+/// See `NexusOperationIdConflictPolicy` for handling ID duplication with a *running* operation.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum NexusOperationIdReusePolicy {
+    Unspecified = 0,
+    /// Always allow starting an operation using the same operation ID.
+    AllowDuplicate = 1,
+    /// Allow starting an operation using the same ID only when the last operation's final state is one
+    /// of {failed, canceled, terminated, timed out}.
+    AllowDuplicateFailedOnly = 2,
+    /// Do not permit re-use of the ID for this operation. Future start requests could potentially change the policy,
+    /// allowing re-use of the ID.
+    RejectDuplicate = 3,
+}
+impl NexusOperationIdReusePolicy {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            Self::Unspecified => "NEXUS_OPERATION_ID_REUSE_POLICY_UNSPECIFIED",
+            Self::AllowDuplicate => "NEXUS_OPERATION_ID_REUSE_POLICY_ALLOW_DUPLICATE",
+            Self::AllowDuplicateFailedOnly => {
+                "NEXUS_OPERATION_ID_REUSE_POLICY_ALLOW_DUPLICATE_FAILED_ONLY"
+            }
+            Self::RejectDuplicate => "NEXUS_OPERATION_ID_REUSE_POLICY_REJECT_DUPLICATE",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "NEXUS_OPERATION_ID_REUSE_POLICY_UNSPECIFIED" => Some(Self::Unspecified),
+            "NEXUS_OPERATION_ID_REUSE_POLICY_ALLOW_DUPLICATE" => {
+                Some(Self::AllowDuplicate)
+            }
+            "NEXUS_OPERATION_ID_REUSE_POLICY_ALLOW_DUPLICATE_FAILED_ONLY" => {
+                Some(Self::AllowDuplicateFailedOnly)
+            }
+            "NEXUS_OPERATION_ID_REUSE_POLICY_REJECT_DUPLICATE" => {
+                Some(Self::RejectDuplicate)
+            }
+            _ => None,
+        }
+    }
+}
+/// Defines what to do when trying to start a Nexus operation with the same ID as a *running* operation.
+/// Note that it is *never* valid to have two running instances of the same operation ID.
+///
+/// See `NexusOperationIdReusePolicy` for handling operation ID duplication with a *closed* operation.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum NexusOperationIdConflictPolicy {
+    Unspecified = 0,
+    /// Don't start a new operation; instead return `NexusOperationAlreadyStarted` error.
+    Fail = 1,
+    /// Don't start a new operation; instead return a handle for the running operation.
+    UseExisting = 2,
+}
+impl NexusOperationIdConflictPolicy {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            Self::Unspecified => "NEXUS_OPERATION_ID_CONFLICT_POLICY_UNSPECIFIED",
+            Self::Fail => "NEXUS_OPERATION_ID_CONFLICT_POLICY_FAIL",
+            Self::UseExisting => "NEXUS_OPERATION_ID_CONFLICT_POLICY_USE_EXISTING",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "NEXUS_OPERATION_ID_CONFLICT_POLICY_UNSPECIFIED" => Some(Self::Unspecified),
+            "NEXUS_OPERATION_ID_CONFLICT_POLICY_FAIL" => Some(Self::Fail),
+            "NEXUS_OPERATION_ID_CONFLICT_POLICY_USE_EXISTING" => Some(Self::UseExisting),
+            _ => None,
+        }
+    }
+}
 /// Whenever this list of events is changed do change the function shouldBufferEvent in mutableStateBuilder.go to make sure to do the correct event ordering
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
 #[repr(i32)]
@@ -2649,6 +2875,8 @@ pub enum EventType {
     WorkflowExecutionPaused = 58,
     /// An event that indicates that the previously paused workflow execution has been unpaused.
     WorkflowExecutionUnpaused = 59,
+    /// An event that indicates time skipping advanced time or was disabled automatically after a bound was reached.
+    WorkflowExecutionTimeSkippingTransitioned = 60,
 }
 impl EventType {
     /// String value of the enum field names used in the ProtoBuf definition.
@@ -2775,6 +3003,9 @@ impl EventType {
             }
             Self::WorkflowExecutionPaused => "EVENT_TYPE_WORKFLOW_EXECUTION_PAUSED",
             Self::WorkflowExecutionUnpaused => "EVENT_TYPE_WORKFLOW_EXECUTION_UNPAUSED",
+            Self::WorkflowExecutionTimeSkippingTransitioned => {
+                "EVENT_TYPE_WORKFLOW_EXECUTION_TIME_SKIPPING_TRANSITIONED"
+            }
         }
     }
     /// Creates an enum from field names used in the ProtoBuf definition.
@@ -2912,41 +3143,8 @@ impl EventType {
             "EVENT_TYPE_WORKFLOW_EXECUTION_UNPAUSED" => {
                 Some(Self::WorkflowExecutionUnpaused)
             }
-            _ => None,
-        }
-    }
-}
-/// NexusHandlerErrorRetryBehavior allows nexus handlers to explicity set the retry behavior of a HandlerError. If not
-/// specified, retry behavior is determined from the error type. For example internal errors are not retryable by default
-/// unless specified otherwise.
-#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
-#[repr(i32)]
-pub enum NexusHandlerErrorRetryBehavior {
-    Unspecified = 0,
-    /// A handler error is explicitly marked as retryable.
-    Retryable = 1,
-    /// A handler error is explicitly marked as non-retryable.
-    NonRetryable = 2,
-}
-impl NexusHandlerErrorRetryBehavior {
-    /// String value of the enum field names used in the ProtoBuf definition.
-    ///
-    /// The values are not transformed in any way and thus are considered stable
-    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
-    pub fn as_str_name(&self) -> &'static str {
-        match self {
-            Self::Unspecified => "NEXUS_HANDLER_ERROR_RETRY_BEHAVIOR_UNSPECIFIED",
-            Self::Retryable => "NEXUS_HANDLER_ERROR_RETRY_BEHAVIOR_RETRYABLE",
-            Self::NonRetryable => "NEXUS_HANDLER_ERROR_RETRY_BEHAVIOR_NON_RETRYABLE",
-        }
-    }
-    /// Creates an enum from field names used in the ProtoBuf definition.
-    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
-        match value {
-            "NEXUS_HANDLER_ERROR_RETRY_BEHAVIOR_UNSPECIFIED" => Some(Self::Unspecified),
-            "NEXUS_HANDLER_ERROR_RETRY_BEHAVIOR_RETRYABLE" => Some(Self::Retryable),
-            "NEXUS_HANDLER_ERROR_RETRY_BEHAVIOR_NON_RETRYABLE" => {
-                Some(Self::NonRetryable)
+            "EVENT_TYPE_WORKFLOW_EXECUTION_TIME_SKIPPING_TRANSITIONED" => {
+                Some(Self::WorkflowExecutionTimeSkippingTransitioned)
             }
             _ => None,
         }
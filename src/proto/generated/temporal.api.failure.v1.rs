@@ -12,7 +12,7 @@ pub struct ApplicationFailureInfo {
     /// still be subject to the maximum retries limit and total time limit
     /// defined by the policy.
     #[prost(message, optional, tag = "4")]
-    pub next_retry_delay: ::core::option::Option<::prost_types::Duration>,
+    pub next_retry_delay: ::core::option::Option<::pbjson_types::Duration>,
     #[prost(
         enumeration = "super::super::enums::v1::ApplicationErrorCategory",
         tag = "5"
@@ -32,9 +32,16 @@ pub struct TimeoutFailureInfo {
 pub struct CanceledFailureInfo {
     #[prost(message, optional, tag = "1")]
     pub details: ::core::option::Option<super::super::common::v1::Payloads>,
+    /// The identity of the worker or client that requested the cancellation.
+    #[prost(string, tag = "2")]
+    pub identity: ::prost::alloc::string::String,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct TerminatedFailureInfo {
+    /// The identity of the worker or client that requested the termination.
+    #[prost(string, tag = "1")]
+    pub identity: ::prost::alloc::string::String,
 }
-#[derive(Clone, Copy, PartialEq, ::prost::Message)]
-pub struct TerminatedFailureInfo {}
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct ServerFailureInfo {
     #[prost(bool, tag = "1")]
@@ -79,6 +86,7 @@ pub struct ChildWorkflowExecutionFailureInfo {
     #[prost(enumeration = "super::super::enums::v1::RetryState", tag = "6")]
     pub retry_state: i32,
 }
+/// Representation of the Temporal SDK NexusOperationError object that is returned to workflow callers.
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct NexusOperationFailureInfo {
     /// The NexusOperationScheduled event ID.
@@ -14,9 +14,9 @@ pub struct WorkflowTypeFilter {
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct StartTimeFilter {
     #[prost(message, optional, tag = "1")]
-    pub earliest_time: ::core::option::Option<::prost_types::Timestamp>,
+    pub earliest_time: ::core::option::Option<::pbjson_types::Timestamp>,
     #[prost(message, optional, tag = "2")]
-    pub latest_time: ::core::option::Option<::prost_types::Timestamp>,
+    pub latest_time: ::core::option::Option<::pbjson_types::Timestamp>,
 }
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct StatusFilter {
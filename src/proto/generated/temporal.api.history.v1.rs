@@ -26,13 +26,13 @@ pub struct WorkflowExecutionStartedEventAttributes {
     pub input: ::core::option::Option<super::super::common::v1::Payloads>,
     /// Total workflow execution timeout including retries and continue as new.
     #[prost(message, optional, tag = "7")]
-    pub workflow_execution_timeout: ::core::option::Option<::prost_types::Duration>,
+    pub workflow_execution_timeout: ::core::option::Option<::pbjson_types::Duration>,
     /// Timeout of a single workflow run.
     #[prost(message, optional, tag = "8")]
-    pub workflow_run_timeout: ::core::option::Option<::prost_types::Duration>,
+    pub workflow_run_timeout: ::core::option::Option<::pbjson_types::Duration>,
     /// Timeout of a single workflow task.
     #[prost(message, optional, tag = "9")]
-    pub workflow_task_timeout: ::core::option::Option<::prost_types::Duration>,
+    pub workflow_task_timeout: ::core::option::Option<::pbjson_types::Duration>,
     /// Run id of the previous workflow which continued-as-new or retried or cron executed into this
     /// workflow.
     #[prost(string, tag = "10")]
@@ -65,7 +65,7 @@ pub struct WorkflowExecutionStartedEventAttributes {
     /// This is passed without change to the next run/retry of a workflow.
     #[prost(message, optional, tag = "19")]
     pub workflow_execution_expiration_time: ::core::option::Option<
-        ::prost_types::Timestamp,
+        ::pbjson_types::Timestamp,
     >,
     /// If this workflow runs on a cron schedule, it will appear here
     #[prost(string, tag = "20")]
@@ -73,7 +73,7 @@ pub struct WorkflowExecutionStartedEventAttributes {
     /// For a cron workflow, this contains the amount of time between when this iteration of
     /// the cron workflow was scheduled and when it should run next per its cron_schedule.
     #[prost(message, optional, tag = "21")]
-    pub first_workflow_task_backoff: ::core::option::Option<::prost_types::Duration>,
+    pub first_workflow_task_backoff: ::core::option::Option<::pbjson_types::Duration>,
     #[prost(message, optional, tag = "22")]
     pub memo: ::core::option::Option<super::super::common::v1::Memo>,
     #[prost(message, optional, tag = "23")]
@@ -212,6 +212,49 @@ pub struct WorkflowExecutionStartedEventAttributes {
     /// Only populated by server with version >= 1.29.0.
     #[prost(bool, tag = "38")]
     pub eager_execution_accepted: bool,
+    /// During a previous run of this workflow, the server may have notified the SDK
+    /// that the Target Worker Deployment Version changed, but the SDK declined to
+    /// upgrade (e.g., by continuing-as-new with PINNED behavior). This field records
+    /// the target version that was declined.
+    ///
+    /// This is a wrapper message to distinguish "never declined" (nil wrapper) from
+    /// "declined an unversioned target" (non-nil wrapper with nil deployment_version).
+    ///
+    /// Used internally by the server during continue-as-new and retry.
+    /// Should not be read or interpreted by SDKs.
+    #[prost(message, optional, tag = "40")]
+    pub declined_target_version_upgrade: ::core::option::Option<
+        DeclinedTargetVersionUpgrade,
+    >,
+    /// Initial time-skipping configuration for this workflow execution, recorded at start time.
+    /// This may have been set explicitly via the start workflow request, or propagated from a
+    /// parent/previous execution.
+    ///
+    /// The configuration may be updated after start via UpdateWorkflowExecutionOptions, which
+    /// will be reflected in the WorkflowExecutionOptionsUpdatedEvent.
+    #[prost(message, optional, tag = "41")]
+    pub time_skipping_config: ::core::option::Option<
+        super::super::workflow::v1::TimeSkippingConfig,
+    >,
+    /// The time skipped by the previous execution that started this workflow.
+    /// It can happen in cases of child workflows and continue-as-new workflows.
+    #[prost(message, optional, tag = "42")]
+    pub initial_skipped_duration: ::core::option::Option<::pbjson_types::Duration>,
+}
+/// Wrapper for a target deployment version that the SDK declined to upgrade to.
+/// See declined_target_version_upgrade on WorkflowExecutionStartedEventAttributes.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct DeclinedTargetVersionUpgrade {
+    #[prost(message, optional, tag = "1")]
+    pub deployment_version: ::core::option::Option<
+        super::super::deployment::v1::WorkerDeploymentVersion,
+    >,
+    /// Revision number of the task queue routing config at the time the target
+    /// was declined. If an incoming target's revision is <= this value, it is
+    /// not newer and is not used for deciding whether or not to suppress the
+    /// upgrade signal.
+    #[prost(int64, tag = "2")]
+    pub revision_number: i64,
 }
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct WorkflowExecutionCompletedEventAttributes {
@@ -260,10 +303,10 @@ pub struct WorkflowExecutionContinuedAsNewEventAttributes {
     pub input: ::core::option::Option<super::super::common::v1::Payloads>,
     /// Timeout of a single workflow run.
     #[prost(message, optional, tag = "5")]
-    pub workflow_run_timeout: ::core::option::Option<::prost_types::Duration>,
+    pub workflow_run_timeout: ::core::option::Option<::pbjson_types::Duration>,
     /// Timeout of a single workflow task.
     #[prost(message, optional, tag = "6")]
-    pub workflow_task_timeout: ::core::option::Option<::prost_types::Duration>,
+    pub workflow_task_timeout: ::core::option::Option<::pbjson_types::Duration>,
     /// The `WORKFLOW_TASK_COMPLETED` event which this command was reported with
     #[prost(int64, tag = "7")]
     pub workflow_task_completed_event_id: i64,
@@ -271,7 +314,7 @@ pub struct WorkflowExecutionContinuedAsNewEventAttributes {
     /// Used for cron, retry, and other continue-as-new cases that server may enforce some minimal
     /// delay between new runs for system protection purpose.
     #[prost(message, optional, tag = "8")]
-    pub backoff_start_interval: ::core::option::Option<::prost_types::Duration>,
+    pub backoff_start_interval: ::core::option::Option<::pbjson_types::Duration>,
     #[prost(enumeration = "super::super::enums::v1::ContinueAsNewInitiator", tag = "9")]
     pub initiator: i32,
     /// Deprecated. If a workflow's retry policy would cause a new run to start when the current one
@@ -319,7 +362,7 @@ pub struct WorkflowTaskScheduledEventAttributes {
     /// (-- api-linter: core::0140::prepositions=disabled
     ///      aip.dev/not-precedent: "to" is used to indicate interval. --)
     #[prost(message, optional, tag = "2")]
-    pub start_to_close_timeout: ::core::option::Option<::prost_types::Duration>,
+    pub start_to_close_timeout: ::core::option::Option<::pbjson_types::Duration>,
     /// Starting at 1, how many attempts there have been to complete this task
     #[prost(int32, tag = "3")]
     pub attempt: i32,
@@ -351,6 +394,11 @@ pub struct WorkflowTaskStartedEventAttributes {
         tag = "8"
     )]
     pub suggest_continue_as_new_reasons: ::prost::alloc::vec::Vec<i32>,
+    /// True if Workflow's Target Worker Deployment Version is different from its Pinned Version and
+    /// the workflow is Pinned.
+    /// Experimental.
+    #[prost(bool, tag = "9")]
+    pub target_worker_deployment_version_changed: bool,
     /// Total history size in bytes, which the workflow might use to decide when to
     /// continue-as-new regardless of the suggestion. Note that history event count is
     /// just the event id of this event, so we don't include it explicitly here.
@@ -420,19 +468,16 @@ pub struct WorkflowTaskCompletedEventAttributes {
     pub versioning_behavior: i32,
     /// The Worker Deployment Version that completed this task. Must be set if `versioning_behavior`
     /// is set. This value updates workflow execution's `versioning_info.version`.
-    /// Experimental. Worker Deployments are experimental and might significantly change in the future.
     /// Deprecated. Replaced with `deployment_version`.
     #[deprecated]
     #[prost(string, tag = "9")]
     pub worker_deployment_version: ::prost::alloc::string::String,
     /// The name of Worker Deployment that completed this task. Must be set if `versioning_behavior`
     /// is set. This value updates workflow execution's `worker_deployment_name`.
-    /// Experimental. Worker Deployments are experimental and might significantly change in the future.
     #[prost(string, tag = "10")]
     pub worker_deployment_name: ::prost::alloc::string::String,
     /// The Worker Deployment Version that completed this task. Must be set if `versioning_behavior`
     /// is set. This value updates workflow execution's `versioning_info.deployment_version`.
-    /// Experimental. Worker Deployments are experimental and might significantly change in the future.
     #[prost(message, optional, tag = "11")]
     pub deployment_version: ::core::option::Option<
         super::super::deployment::v1::WorkerDeploymentVersion,
@@ -510,7 +555,7 @@ pub struct ActivityTaskScheduledEventAttributes {
     /// (-- api-linter: core::0140::prepositions=disabled
     ///      aip.dev/not-precedent: "to" is used to indicate interval. --)
     #[prost(message, optional, tag = "7")]
-    pub schedule_to_close_timeout: ::core::option::Option<::prost_types::Duration>,
+    pub schedule_to_close_timeout: ::core::option::Option<::pbjson_types::Duration>,
     /// Limits time an activity task can stay in a task queue before a worker picks it up. This
     /// timeout is always non retryable, as all a retry would achieve is to put it back into the same
     /// queue. Defaults to `schedule_to_close_timeout` or workflow execution timeout if not
@@ -519,7 +564,7 @@ pub struct ActivityTaskScheduledEventAttributes {
     /// (-- api-linter: core::0140::prepositions=disabled
     ///      aip.dev/not-precedent: "to" is used to indicate interval. --)
     #[prost(message, optional, tag = "8")]
-    pub schedule_to_start_timeout: ::core::option::Option<::prost_types::Duration>,
+    pub schedule_to_start_timeout: ::core::option::Option<::pbjson_types::Duration>,
     /// Maximum time an activity is allowed to execute after being picked up by a worker. This
     /// timeout is always retryable. Either this or `schedule_to_close_timeout` must be
     /// specified.
@@ -527,10 +572,10 @@ pub struct ActivityTaskScheduledEventAttributes {
     /// (-- api-linter: core::0140::prepositions=disabled
     ///      aip.dev/not-precedent: "to" is used to indicate interval. --)
     #[prost(message, optional, tag = "9")]
-    pub start_to_close_timeout: ::core::option::Option<::prost_types::Duration>,
+    pub start_to_close_timeout: ::core::option::Option<::pbjson_types::Duration>,
     /// Maximum permitted time between successful worker heartbeats.
     #[prost(message, optional, tag = "10")]
-    pub heartbeat_timeout: ::core::option::Option<::prost_types::Duration>,
+    pub heartbeat_timeout: ::core::option::Option<::pbjson_types::Duration>,
     /// The `WORKFLOW_TASK_COMPLETED` event which this command was reported with
     #[prost(int64, tag = "11")]
     pub workflow_task_completed_event_id: i64,
@@ -693,7 +738,7 @@ pub struct TimerStartedEventAttributes {
     /// (-- api-linter: core::0140::prepositions=disabled
     ///      aip.dev/not-precedent: "to" is used to indicate interval. --)
     #[prost(message, optional, tag = "2")]
-    pub start_to_fire_timeout: ::core::option::Option<::prost_types::Duration>,
+    pub start_to_fire_timeout: ::core::option::Option<::pbjson_types::Duration>,
     /// The `WORKFLOW_TASK_COMPLETED` event which this command was reported with
     #[prost(int64, tag = "3")]
     pub workflow_task_completed_event_id: i64,
@@ -791,6 +836,10 @@ pub struct WorkflowExecutionSignaledEventAttributes {
     pub external_workflow_execution: ::core::option::Option<
         super::super::common::v1::WorkflowExecution,
     >,
+    /// The request ID of the Signal request, used by the server to attach this to
+    /// the correct Event ID when generating link.
+    #[prost(string, tag = "7")]
+    pub request_id: ::prost::alloc::string::String,
 }
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct WorkflowExecutionTerminatedEventAttributes {
@@ -994,13 +1043,13 @@ pub struct StartChildWorkflowExecutionInitiatedEventAttributes {
     pub input: ::core::option::Option<super::super::common::v1::Payloads>,
     /// Total workflow execution timeout including retries and continue as new.
     #[prost(message, optional, tag = "6")]
-    pub workflow_execution_timeout: ::core::option::Option<::prost_types::Duration>,
+    pub workflow_execution_timeout: ::core::option::Option<::pbjson_types::Duration>,
     /// Timeout of a single workflow run.
     #[prost(message, optional, tag = "7")]
-    pub workflow_run_timeout: ::core::option::Option<::prost_types::Duration>,
+    pub workflow_run_timeout: ::core::option::Option<::pbjson_types::Duration>,
     /// Timeout of a single workflow task.
     #[prost(message, optional, tag = "8")]
-    pub workflow_task_timeout: ::core::option::Option<::prost_types::Duration>,
+    pub workflow_task_timeout: ::core::option::Option<::pbjson_types::Duration>,
     /// Default: PARENT_CLOSE_POLICY_TERMINATE.
     #[prost(enumeration = "super::super::enums::v1::ParentClosePolicy", tag = "9")]
     pub parent_close_policy: i32,
@@ -1036,6 +1085,14 @@ pub struct StartChildWorkflowExecutionInitiatedEventAttributes {
     /// Priority metadata
     #[prost(message, optional, tag = "20")]
     pub priority: ::core::option::Option<super::super::common::v1::Priority>,
+    /// The propagated time-skipping configuration for the child workflow.
+    #[prost(message, optional, tag = "21")]
+    pub time_skipping_config: ::core::option::Option<
+        super::super::workflow::v1::TimeSkippingConfig,
+    >,
+    /// Propagate the duration skipped to the child workflow.
+    #[prost(message, optional, tag = "30")]
+    pub initial_skipped_duration: ::core::option::Option<::pbjson_types::Duration>,
 }
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct StartChildWorkflowExecutionFailedEventAttributes {
@@ -1227,6 +1284,34 @@ pub struct WorkflowExecutionOptionsUpdatedEventAttributes {
     /// Ignored if nil.
     #[prost(message, optional, tag = "6")]
     pub priority: ::core::option::Option<super::super::common::v1::Priority>,
+    /// If set, the time-skipping configuration was changed. Contains the full updated configuration.
+    #[prost(message, optional, tag = "7")]
+    pub time_skipping_config: ::core::option::Option<
+        super::super::workflow::v1::TimeSkippingConfig,
+    >,
+    /// Updates to workflow updates options.
+    #[prost(message, repeated, tag = "8")]
+    pub workflow_update_options: ::prost::alloc::vec::Vec<
+        workflow_execution_options_updated_event_attributes::WorkflowUpdateOptionsUpdate,
+    >,
+}
+/// Nested message and enum types in `WorkflowExecutionOptionsUpdatedEventAttributes`.
+pub mod workflow_execution_options_updated_event_attributes {
+    #[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct WorkflowUpdateOptionsUpdate {
+        /// The ID of the workflow update this update options update corresponds to.
+        #[prost(string, tag = "1")]
+        pub update_id: ::prost::alloc::string::String,
+        /// Request ID attached to the running workflow update so that subsequent requests with same
+        /// request ID will be deduped
+        #[prost(string, tag = "2")]
+        pub attached_request_id: ::prost::alloc::string::String,
+        /// Completion callbacks attached to the running workflow update.
+        #[prost(message, repeated, tag = "3")]
+        pub attached_completion_callbacks: ::prost::alloc::vec::Vec<
+            super::super::super::common::v1::Callback,
+        >,
+    }
 }
 /// Not used anywhere. Use case is replaced by WorkflowExecutionOptionsUpdatedEventAttributes
 #[derive(Clone, PartialEq, ::prost::Message)]
@@ -1236,13 +1321,13 @@ pub struct WorkflowPropertiesModifiedExternallyEventAttributes {
     pub new_task_queue: ::prost::alloc::string::String,
     /// Not used.
     #[prost(message, optional, tag = "2")]
-    pub new_workflow_task_timeout: ::core::option::Option<::prost_types::Duration>,
+    pub new_workflow_task_timeout: ::core::option::Option<::pbjson_types::Duration>,
     /// Not used.
     #[prost(message, optional, tag = "3")]
-    pub new_workflow_run_timeout: ::core::option::Option<::prost_types::Duration>,
+    pub new_workflow_run_timeout: ::core::option::Option<::pbjson_types::Duration>,
     /// Not used.
     #[prost(message, optional, tag = "4")]
-    pub new_workflow_execution_timeout: ::core::option::Option<::prost_types::Duration>,
+    pub new_workflow_execution_timeout: ::core::option::Option<::pbjson_types::Duration>,
     /// Not used.
     #[prost(message, optional, tag = "5")]
     pub upserted_memo: ::core::option::Option<super::super::common::v1::Memo>,
@@ -1346,6 +1431,23 @@ pub struct WorkflowExecutionUnpausedEventAttributes {
     #[prost(string, tag = "3")]
     pub request_id: ::prost::alloc::string::String,
 }
+/// Attributes for an event indicating that time skipping state changed for a workflow execution,
+/// either time was advanced or time skipping was disabled automatically due to a bound being reached.
+/// The worker_may_ignore field in HistoryEvent should always be set true for this event.
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct WorkflowExecutionTimeSkippingTransitionedEventAttributes {
+    /// The virtual time after time skipping was applied.
+    #[prost(message, optional, tag = "1")]
+    pub target_time: ::core::option::Option<::pbjson_types::Timestamp>,
+    /// when true, time skipping was disabled automatically due to a bound being reached.
+    /// (-- api-linter: core::0140::prepositions=disabled
+    ///      aip.dev/not-precedent: "after" is used to indicate temporal ordering. --)
+    #[prost(bool, tag = "2")]
+    pub disabled_after_bound: bool,
+    /// The wall-clock time when the time-skipping state changed event was generated.
+    #[prost(message, optional, tag = "3")]
+    pub wall_clock_time: ::core::option::Option<::pbjson_types::Timestamp>,
+}
 /// Event marking that an operation was scheduled by a workflow via the ScheduleNexusOperation command.
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct NexusOperationScheduledEventAttributes {
@@ -1368,8 +1470,10 @@ pub struct NexusOperationScheduledEventAttributes {
     /// Calls are retried internally by the server.
     /// (-- api-linter: core::0140::prepositions=disabled
     ///      aip.dev/not-precedent: "to" is used to indicate interval. --)
+    /// (-- api-linter: core::0142::time-field-names=disabled
+    ///      aip.dev/not-precedent: "timeout" is an acceptable suffix for duration fields in this API. --)
     #[prost(message, optional, tag = "5")]
-    pub schedule_to_close_timeout: ::core::option::Option<::prost_types::Duration>,
+    pub schedule_to_close_timeout: ::core::option::Option<::pbjson_types::Duration>,
     /// Header to attach to the Nexus request. Note these headers are not the same as Temporal headers on internal
     /// activities and child workflows, these are transmitted to Nexus operations that may be external and are not
     /// traditional payloads.
@@ -1395,13 +1499,13 @@ pub struct NexusOperationScheduledEventAttributes {
     /// (-- api-linter: core::0140::prepositions=disabled
     ///      aip.dev/not-precedent: "to" is used to indicate interval. --)
     #[prost(message, optional, tag = "10")]
-    pub schedule_to_start_timeout: ::core::option::Option<::prost_types::Duration>,
+    pub schedule_to_start_timeout: ::core::option::Option<::pbjson_types::Duration>,
     /// Start-to-close timeout for this operation.
     /// See ScheduleNexusOperationCommandAttributes.start_to_close_timeout for details.
     /// (-- api-linter: core::0140::prepositions=disabled
     ///      aip.dev/not-precedent: "to" is used to indicate interval. --)
     #[prost(message, optional, tag = "11")]
-    pub start_to_close_timeout: ::core::option::Option<::prost_types::Duration>,
+    pub start_to_close_timeout: ::core::option::Option<::pbjson_types::Duration>,
 }
 /// Event marking an asynchronous operation was started by the responding Nexus handler.
 /// If the operation completes synchronously, this event is not generated.
@@ -1527,7 +1631,7 @@ pub struct HistoryEvent {
     #[prost(int64, tag = "1")]
     pub event_id: i64,
     #[prost(message, optional, tag = "2")]
-    pub event_time: ::core::option::Option<::prost_types::Timestamp>,
+    pub event_time: ::core::option::Option<::pbjson_types::Timestamp>,
     #[prost(enumeration = "super::super::enums::v1::EventType", tag = "3")]
     pub event_type: i32,
     /// Failover version of the event, used by the server for multi-cluster replication and history
@@ -1554,13 +1658,16 @@ pub struct HistoryEvent {
     ///     user interfaces.
     #[prost(message, optional, tag = "301")]
     pub user_metadata: ::core::option::Option<super::super::sdk::v1::UserMetadata>,
-    /// Links associated with the event.
+    /// Links to related entities, such as the entity that started this event's workflow.
     #[prost(message, repeated, tag = "302")]
     pub links: ::prost::alloc::vec::Vec<super::super::common::v1::Link>,
+    /// Server-computed authenticated caller identity associated with this event.
+    #[prost(message, optional, tag = "303")]
+    pub principal: ::core::option::Option<super::super::common::v1::Principal>,
     /// The event details. The type must match that in `event_type`.
     #[prost(
         oneof = "history_event::Attributes",
-        tags = "6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23, 24, 25, 26, 27, 28, 29, 30, 31, 32, 33, 34, 35, 36, 37, 38, 39, 40, 41, 42, 43, 44, 45, 46, 47, 48, 49, 50, 51, 52, 53, 54, 55, 56, 57, 58, 59, 60, 61, 62, 63, 64"
+        tags = "6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23, 24, 25, 26, 27, 28, 29, 30, 31, 32, 33, 34, 35, 36, 37, 38, 39, 40, 41, 42, 43, 44, 45, 46, 47, 48, 49, 50, 51, 52, 53, 54, 55, 56, 57, 58, 59, 60, 61, 62, 63, 64, 65"
     )]
     pub attributes: ::core::option::Option<history_event::Attributes>,
 }
@@ -1781,6 +1888,10 @@ pub mod history_event {
         WorkflowExecutionUnpausedEventAttributes(
             super::WorkflowExecutionUnpausedEventAttributes,
         ),
+        #[prost(message, tag = "65")]
+        WorkflowExecutionTimeSkippingTransitionedEventAttributes(
+            super::WorkflowExecutionTimeSkippingTransitionedEventAttributes,
+        ),
     }
 }
 #[derive(Clone, PartialEq, ::prost::Message)]
@@ -0,0 +1,12682 @@
+impl serde::Serialize for ActivityPropertiesModifiedExternallyEventAttributes {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.scheduled_event_id != 0 {
+            len += 1;
+        }
+        if self.new_retry_policy.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("temporal.api.history.v1.ActivityPropertiesModifiedExternallyEventAttributes", len)?;
+        if self.scheduled_event_id != 0 {
+            #[allow(clippy::needless_borrow)]
+            #[allow(clippy::needless_borrows_for_generic_args)]
+            struct_ser.serialize_field("scheduledEventId", ToString::to_string(&self.scheduled_event_id).as_str())?;
+        }
+        if let Some(v) = self.new_retry_policy.as_ref() {
+            struct_ser.serialize_field("newRetryPolicy", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for ActivityPropertiesModifiedExternallyEventAttributes {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "scheduled_event_id",
+            "scheduledEventId",
+            "new_retry_policy",
+            "newRetryPolicy",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            ScheduledEventId,
+            NewRetryPolicy,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "scheduledEventId" | "scheduled_event_id" => Ok(GeneratedField::ScheduledEventId),
+                            "newRetryPolicy" | "new_retry_policy" => Ok(GeneratedField::NewRetryPolicy),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = ActivityPropertiesModifiedExternallyEventAttributes;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct temporal.api.history.v1.ActivityPropertiesModifiedExternallyEventAttributes")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<ActivityPropertiesModifiedExternallyEventAttributes, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut scheduled_event_id__ = None;
+                let mut new_retry_policy__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::ScheduledEventId => {
+                            if scheduled_event_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("scheduledEventId"));
+                            }
+                            scheduled_event_id__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::NewRetryPolicy => {
+                            if new_retry_policy__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("newRetryPolicy"));
+                            }
+                            new_retry_policy__ = map_.next_value()?;
+                        }
+                    }
+                }
+                Ok(ActivityPropertiesModifiedExternallyEventAttributes {
+                    scheduled_event_id: scheduled_event_id__.unwrap_or_default(),
+                    new_retry_policy: new_retry_policy__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("temporal.api.history.v1.ActivityPropertiesModifiedExternallyEventAttributes", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for ActivityTaskCancelRequestedEventAttributes {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.scheduled_event_id != 0 {
+            len += 1;
+        }
+        if self.workflow_task_completed_event_id != 0 {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("temporal.api.history.v1.ActivityTaskCancelRequestedEventAttributes", len)?;
+        if self.scheduled_event_id != 0 {
+            #[allow(clippy::needless_borrow)]
+            #[allow(clippy::needless_borrows_for_generic_args)]
+            struct_ser.serialize_field("scheduledEventId", ToString::to_string(&self.scheduled_event_id).as_str())?;
+        }
+        if self.workflow_task_completed_event_id != 0 {
+            #[allow(clippy::needless_borrow)]
+            #[allow(clippy::needless_borrows_for_generic_args)]
+            struct_ser.serialize_field("workflowTaskCompletedEventId", ToString::to_string(&self.workflow_task_completed_event_id).as_str())?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for ActivityTaskCancelRequestedEventAttributes {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "scheduled_event_id",
+            "scheduledEventId",
+            "workflow_task_completed_event_id",
+            "workflowTaskCompletedEventId",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            ScheduledEventId,
+            WorkflowTaskCompletedEventId,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "scheduledEventId" | "scheduled_event_id" => Ok(GeneratedField::ScheduledEventId),
+                            "workflowTaskCompletedEventId" | "workflow_task_completed_event_id" => Ok(GeneratedField::WorkflowTaskCompletedEventId),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = ActivityTaskCancelRequestedEventAttributes;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct temporal.api.history.v1.ActivityTaskCancelRequestedEventAttributes")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<ActivityTaskCancelRequestedEventAttributes, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut scheduled_event_id__ = None;
+                let mut workflow_task_completed_event_id__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::ScheduledEventId => {
+                            if scheduled_event_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("scheduledEventId"));
+                            }
+                            scheduled_event_id__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::WorkflowTaskCompletedEventId => {
+                            if workflow_task_completed_event_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("workflowTaskCompletedEventId"));
+                            }
+                            workflow_task_completed_event_id__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                    }
+                }
+                Ok(ActivityTaskCancelRequestedEventAttributes {
+                    scheduled_event_id: scheduled_event_id__.unwrap_or_default(),
+                    workflow_task_completed_event_id: workflow_task_completed_event_id__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("temporal.api.history.v1.ActivityTaskCancelRequestedEventAttributes", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for ActivityTaskCanceledEventAttributes {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.details.is_some() {
+            len += 1;
+        }
+        if self.latest_cancel_requested_event_id != 0 {
+            len += 1;
+        }
+        if self.scheduled_event_id != 0 {
+            len += 1;
+        }
+        if self.started_event_id != 0 {
+            len += 1;
+        }
+        if !self.identity.is_empty() {
+            len += 1;
+        }
+        if self.worker_version.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("temporal.api.history.v1.ActivityTaskCanceledEventAttributes", len)?;
+        if let Some(v) = self.details.as_ref() {
+            struct_ser.serialize_field("details", v)?;
+        }
+        if self.latest_cancel_requested_event_id != 0 {
+            #[allow(clippy::needless_borrow)]
+            #[allow(clippy::needless_borrows_for_generic_args)]
+            struct_ser.serialize_field("latestCancelRequestedEventId", ToString::to_string(&self.latest_cancel_requested_event_id).as_str())?;
+        }
+        if self.scheduled_event_id != 0 {
+            #[allow(clippy::needless_borrow)]
+            #[allow(clippy::needless_borrows_for_generic_args)]
+            struct_ser.serialize_field("scheduledEventId", ToString::to_string(&self.scheduled_event_id).as_str())?;
+        }
+        if self.started_event_id != 0 {
+            #[allow(clippy::needless_borrow)]
+            #[allow(clippy::needless_borrows_for_generic_args)]
+            struct_ser.serialize_field("startedEventId", ToString::to_string(&self.started_event_id).as_str())?;
+        }
+        if !self.identity.is_empty() {
+            struct_ser.serialize_field("identity", &self.identity)?;
+        }
+        if let Some(v) = self.worker_version.as_ref() {
+            struct_ser.serialize_field("workerVersion", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for ActivityTaskCanceledEventAttributes {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "details",
+            "latest_cancel_requested_event_id",
+            "latestCancelRequestedEventId",
+            "scheduled_event_id",
+            "scheduledEventId",
+            "started_event_id",
+            "startedEventId",
+            "identity",
+            "worker_version",
+            "workerVersion",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Details,
+            LatestCancelRequestedEventId,
+            ScheduledEventId,
+            StartedEventId,
+            Identity,
+            WorkerVersion,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "details" => Ok(GeneratedField::Details),
+                            "latestCancelRequestedEventId" | "latest_cancel_requested_event_id" => Ok(GeneratedField::LatestCancelRequestedEventId),
+                            "scheduledEventId" | "scheduled_event_id" => Ok(GeneratedField::ScheduledEventId),
+                            "startedEventId" | "started_event_id" => Ok(GeneratedField::StartedEventId),
+                            "identity" => Ok(GeneratedField::Identity),
+                            "workerVersion" | "worker_version" => Ok(GeneratedField::WorkerVersion),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = ActivityTaskCanceledEventAttributes;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct temporal.api.history.v1.ActivityTaskCanceledEventAttributes")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<ActivityTaskCanceledEventAttributes, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut details__ = None;
+                let mut latest_cancel_requested_event_id__ = None;
+                let mut scheduled_event_id__ = None;
+                let mut started_event_id__ = None;
+                let mut identity__ = None;
+                let mut worker_version__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Details => {
+                            if details__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("details"));
+                            }
+                            details__ = map_.next_value()?;
+                        }
+                        GeneratedField::LatestCancelRequestedEventId => {
+                            if latest_cancel_requested_event_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("latestCancelRequestedEventId"));
+                            }
+                            latest_cancel_requested_event_id__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::ScheduledEventId => {
+                            if scheduled_event_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("scheduledEventId"));
+                            }
+                            scheduled_event_id__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::StartedEventId => {
+                            if started_event_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("startedEventId"));
+                            }
+                            started_event_id__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::Identity => {
+                            if identity__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("identity"));
+                            }
+                            identity__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::WorkerVersion => {
+                            if worker_version__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("workerVersion"));
+                            }
+                            worker_version__ = map_.next_value()?;
+                        }
+                    }
+                }
+                Ok(ActivityTaskCanceledEventAttributes {
+                    details: details__,
+                    latest_cancel_requested_event_id: latest_cancel_requested_event_id__.unwrap_or_default(),
+                    scheduled_event_id: scheduled_event_id__.unwrap_or_default(),
+                    started_event_id: started_event_id__.unwrap_or_default(),
+                    identity: identity__.unwrap_or_default(),
+                    worker_version: worker_version__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("temporal.api.history.v1.ActivityTaskCanceledEventAttributes", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for ActivityTaskCompletedEventAttributes {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.result.is_some() {
+            len += 1;
+        }
+        if self.scheduled_event_id != 0 {
+            len += 1;
+        }
+        if self.started_event_id != 0 {
+            len += 1;
+        }
+        if !self.identity.is_empty() {
+            len += 1;
+        }
+        if self.worker_version.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("temporal.api.history.v1.ActivityTaskCompletedEventAttributes", len)?;
+        if let Some(v) = self.result.as_ref() {
+            struct_ser.serialize_field("result", v)?;
+        }
+        if self.scheduled_event_id != 0 {
+            #[allow(clippy::needless_borrow)]
+            #[allow(clippy::needless_borrows_for_generic_args)]
+            struct_ser.serialize_field("scheduledEventId", ToString::to_string(&self.scheduled_event_id).as_str())?;
+        }
+        if self.started_event_id != 0 {
+            #[allow(clippy::needless_borrow)]
+            #[allow(clippy::needless_borrows_for_generic_args)]
+            struct_ser.serialize_field("startedEventId", ToString::to_string(&self.started_event_id).as_str())?;
+        }
+        if !self.identity.is_empty() {
+            struct_ser.serialize_field("identity", &self.identity)?;
+        }
+        if let Some(v) = self.worker_version.as_ref() {
+            struct_ser.serialize_field("workerVersion", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for ActivityTaskCompletedEventAttributes {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "result",
+            "scheduled_event_id",
+            "scheduledEventId",
+            "started_event_id",
+            "startedEventId",
+            "identity",
+            "worker_version",
+            "workerVersion",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Result,
+            ScheduledEventId,
+            StartedEventId,
+            Identity,
+            WorkerVersion,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "result" => Ok(GeneratedField::Result),
+                            "scheduledEventId" | "scheduled_event_id" => Ok(GeneratedField::ScheduledEventId),
+                            "startedEventId" | "started_event_id" => Ok(GeneratedField::StartedEventId),
+                            "identity" => Ok(GeneratedField::Identity),
+                            "workerVersion" | "worker_version" => Ok(GeneratedField::WorkerVersion),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = ActivityTaskCompletedEventAttributes;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct temporal.api.history.v1.ActivityTaskCompletedEventAttributes")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<ActivityTaskCompletedEventAttributes, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut result__ = None;
+                let mut scheduled_event_id__ = None;
+                let mut started_event_id__ = None;
+                let mut identity__ = None;
+                let mut worker_version__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Result => {
+                            if result__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("result"));
+                            }
+                            result__ = map_.next_value()?;
+                        }
+                        GeneratedField::ScheduledEventId => {
+                            if scheduled_event_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("scheduledEventId"));
+                            }
+                            scheduled_event_id__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::StartedEventId => {
+                            if started_event_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("startedEventId"));
+                            }
+                            started_event_id__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::Identity => {
+                            if identity__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("identity"));
+                            }
+                            identity__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::WorkerVersion => {
+                            if worker_version__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("workerVersion"));
+                            }
+                            worker_version__ = map_.next_value()?;
+                        }
+                    }
+                }
+                Ok(ActivityTaskCompletedEventAttributes {
+                    result: result__,
+                    scheduled_event_id: scheduled_event_id__.unwrap_or_default(),
+                    started_event_id: started_event_id__.unwrap_or_default(),
+                    identity: identity__.unwrap_or_default(),
+                    worker_version: worker_version__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("temporal.api.history.v1.ActivityTaskCompletedEventAttributes", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for ActivityTaskFailedEventAttributes {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.failure.is_some() {
+            len += 1;
+        }
+        if self.scheduled_event_id != 0 {
+            len += 1;
+        }
+        if self.started_event_id != 0 {
+            len += 1;
+        }
+        if !self.identity.is_empty() {
+            len += 1;
+        }
+        if self.retry_state != 0 {
+            len += 1;
+        }
+        if self.worker_version.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("temporal.api.history.v1.ActivityTaskFailedEventAttributes", len)?;
+        if let Some(v) = self.failure.as_ref() {
+            struct_ser.serialize_field("failure", v)?;
+        }
+        if self.scheduled_event_id != 0 {
+            #[allow(clippy::needless_borrow)]
+            #[allow(clippy::needless_borrows_for_generic_args)]
+            struct_ser.serialize_field("scheduledEventId", ToString::to_string(&self.scheduled_event_id).as_str())?;
+        }
+        if self.started_event_id != 0 {
+            #[allow(clippy::needless_borrow)]
+            #[allow(clippy::needless_borrows_for_generic_args)]
+            struct_ser.serialize_field("startedEventId", ToString::to_string(&self.started_event_id).as_str())?;
+        }
+        if !self.identity.is_empty() {
+            struct_ser.serialize_field("identity", &self.identity)?;
+        }
+        if self.retry_state != 0 {
+            let v = super::super::enums::v1::RetryState::try_from(self.retry_state)
+                .map_err(|_| serde::ser::Error::custom(format!("Invalid variant {}", self.retry_state)))?;
+            struct_ser.serialize_field("retryState", &v)?;
+        }
+        if let Some(v) = self.worker_version.as_ref() {
+            struct_ser.serialize_field("workerVersion", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for ActivityTaskFailedEventAttributes {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "failure",
+            "scheduled_event_id",
+            "scheduledEventId",
+            "started_event_id",
+            "startedEventId",
+            "identity",
+            "retry_state",
+            "retryState",
+            "worker_version",
+            "workerVersion",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Failure,
+            ScheduledEventId,
+            StartedEventId,
+            Identity,
+            RetryState,
+            WorkerVersion,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "failure" => Ok(GeneratedField::Failure),
+                            "scheduledEventId" | "scheduled_event_id" => Ok(GeneratedField::ScheduledEventId),
+                            "startedEventId" | "started_event_id" => Ok(GeneratedField::StartedEventId),
+                            "identity" => Ok(GeneratedField::Identity),
+                            "retryState" | "retry_state" => Ok(GeneratedField::RetryState),
+                            "workerVersion" | "worker_version" => Ok(GeneratedField::WorkerVersion),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = ActivityTaskFailedEventAttributes;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct temporal.api.history.v1.ActivityTaskFailedEventAttributes")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<ActivityTaskFailedEventAttributes, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut failure__ = None;
+                let mut scheduled_event_id__ = None;
+                let mut started_event_id__ = None;
+                let mut identity__ = None;
+                let mut retry_state__ = None;
+                let mut worker_version__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Failure => {
+                            if failure__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("failure"));
+                            }
+                            failure__ = map_.next_value()?;
+                        }
+                        GeneratedField::ScheduledEventId => {
+                            if scheduled_event_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("scheduledEventId"));
+                            }
+                            scheduled_event_id__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::StartedEventId => {
+                            if started_event_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("startedEventId"));
+                            }
+                            started_event_id__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::Identity => {
+                            if identity__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("identity"));
+                            }
+                            identity__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::RetryState => {
+                            if retry_state__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("retryState"));
+                            }
+                            retry_state__ = Some(map_.next_value::<super::super::enums::v1::RetryState>()? as i32);
+                        }
+                        GeneratedField::WorkerVersion => {
+                            if worker_version__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("workerVersion"));
+                            }
+                            worker_version__ = map_.next_value()?;
+                        }
+                    }
+                }
+                Ok(ActivityTaskFailedEventAttributes {
+                    failure: failure__,
+                    scheduled_event_id: scheduled_event_id__.unwrap_or_default(),
+                    started_event_id: started_event_id__.unwrap_or_default(),
+                    identity: identity__.unwrap_or_default(),
+                    retry_state: retry_state__.unwrap_or_default(),
+                    worker_version: worker_version__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("temporal.api.history.v1.ActivityTaskFailedEventAttributes", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for ActivityTaskScheduledEventAttributes {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.activity_id.is_empty() {
+            len += 1;
+        }
+        if self.activity_type.is_some() {
+            len += 1;
+        }
+        if self.task_queue.is_some() {
+            len += 1;
+        }
+        if self.header.is_some() {
+            len += 1;
+        }
+        if self.input.is_some() {
+            len += 1;
+        }
+        if self.schedule_to_close_timeout.is_some() {
+            len += 1;
+        }
+        if self.schedule_to_start_timeout.is_some() {
+            len += 1;
+        }
+        if self.start_to_close_timeout.is_some() {
+            len += 1;
+        }
+        if self.heartbeat_timeout.is_some() {
+            len += 1;
+        }
+        if self.workflow_task_completed_event_id != 0 {
+            len += 1;
+        }
+        if self.retry_policy.is_some() {
+            len += 1;
+        }
+        if self.use_workflow_build_id {
+            len += 1;
+        }
+        if self.priority.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("temporal.api.history.v1.ActivityTaskScheduledEventAttributes", len)?;
+        if !self.activity_id.is_empty() {
+            struct_ser.serialize_field("activityId", &self.activity_id)?;
+        }
+        if let Some(v) = self.activity_type.as_ref() {
+            struct_ser.serialize_field("activityType", v)?;
+        }
+        if let Some(v) = self.task_queue.as_ref() {
+            struct_ser.serialize_field("taskQueue", v)?;
+        }
+        if let Some(v) = self.header.as_ref() {
+            struct_ser.serialize_field("header", v)?;
+        }
+        if let Some(v) = self.input.as_ref() {
+            struct_ser.serialize_field("input", v)?;
+        }
+        if let Some(v) = self.schedule_to_close_timeout.as_ref() {
+            struct_ser.serialize_field("scheduleToCloseTimeout", v)?;
+        }
+        if let Some(v) = self.schedule_to_start_timeout.as_ref() {
+            struct_ser.serialize_field("scheduleToStartTimeout", v)?;
+        }
+        if let Some(v) = self.start_to_close_timeout.as_ref() {
+            struct_ser.serialize_field("startToCloseTimeout", v)?;
+        }
+        if let Some(v) = self.heartbeat_timeout.as_ref() {
+            struct_ser.serialize_field("heartbeatTimeout", v)?;
+        }
+        if self.workflow_task_completed_event_id != 0 {
+            #[allow(clippy::needless_borrow)]
+            #[allow(clippy::needless_borrows_for_generic_args)]
+            struct_ser.serialize_field("workflowTaskCompletedEventId", ToString::to_string(&self.workflow_task_completed_event_id).as_str())?;
+        }
+        if let Some(v) = self.retry_policy.as_ref() {
+            struct_ser.serialize_field("retryPolicy", v)?;
+        }
+        if self.use_workflow_build_id {
+            struct_ser.serialize_field("useWorkflowBuildId", &self.use_workflow_build_id)?;
+        }
+        if let Some(v) = self.priority.as_ref() {
+            struct_ser.serialize_field("priority", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for ActivityTaskScheduledEventAttributes {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "activity_id",
+            "activityId",
+            "activity_type",
+            "activityType",
+            "task_queue",
+            "taskQueue",
+            "header",
+            "input",
+            "schedule_to_close_timeout",
+            "scheduleToCloseTimeout",
+            "schedule_to_start_timeout",
+            "scheduleToStartTimeout",
+            "start_to_close_timeout",
+            "startToCloseTimeout",
+            "heartbeat_timeout",
+            "heartbeatTimeout",
+            "workflow_task_completed_event_id",
+            "workflowTaskCompletedEventId",
+            "retry_policy",
+            "retryPolicy",
+            "use_workflow_build_id",
+            "useWorkflowBuildId",
+            "priority",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            ActivityId,
+            ActivityType,
+            TaskQueue,
+            Header,
+            Input,
+            ScheduleToCloseTimeout,
+            ScheduleToStartTimeout,
+            StartToCloseTimeout,
+            HeartbeatTimeout,
+            WorkflowTaskCompletedEventId,
+            RetryPolicy,
+            UseWorkflowBuildId,
+            Priority,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "activityId" | "activity_id" => Ok(GeneratedField::ActivityId),
+                            "activityType" | "activity_type" => Ok(GeneratedField::ActivityType),
+                            "taskQueue" | "task_queue" => Ok(GeneratedField::TaskQueue),
+                            "header" => Ok(GeneratedField::Header),
+                            "input" => Ok(GeneratedField::Input),
+                            "scheduleToCloseTimeout" | "schedule_to_close_timeout" => Ok(GeneratedField::ScheduleToCloseTimeout),
+                            "scheduleToStartTimeout" | "schedule_to_start_timeout" => Ok(GeneratedField::ScheduleToStartTimeout),
+                            "startToCloseTimeout" | "start_to_close_timeout" => Ok(GeneratedField::StartToCloseTimeout),
+                            "heartbeatTimeout" | "heartbeat_timeout" => Ok(GeneratedField::HeartbeatTimeout),
+                            "workflowTaskCompletedEventId" | "workflow_task_completed_event_id" => Ok(GeneratedField::WorkflowTaskCompletedEventId),
+                            "retryPolicy" | "retry_policy" => Ok(GeneratedField::RetryPolicy),
+                            "useWorkflowBuildId" | "use_workflow_build_id" => Ok(GeneratedField::UseWorkflowBuildId),
+                            "priority" => Ok(GeneratedField::Priority),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = ActivityTaskScheduledEventAttributes;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct temporal.api.history.v1.ActivityTaskScheduledEventAttributes")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<ActivityTaskScheduledEventAttributes, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut activity_id__ = None;
+                let mut activity_type__ = None;
+                let mut task_queue__ = None;
+                let mut header__ = None;
+                let mut input__ = None;
+                let mut schedule_to_close_timeout__ = None;
+                let mut schedule_to_start_timeout__ = None;
+                let mut start_to_close_timeout__ = None;
+                let mut heartbeat_timeout__ = None;
+                let mut workflow_task_completed_event_id__ = None;
+                let mut retry_policy__ = None;
+                let mut use_workflow_build_id__ = None;
+                let mut priority__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::ActivityId => {
+                            if activity_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("activityId"));
+                            }
+                            activity_id__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::ActivityType => {
+                            if activity_type__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("activityType"));
+                            }
+                            activity_type__ = map_.next_value()?;
+                        }
+                        GeneratedField::TaskQueue => {
+                            if task_queue__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("taskQueue"));
+                            }
+                            task_queue__ = map_.next_value()?;
+                        }
+                        GeneratedField::Header => {
+                            if header__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("header"));
+                            }
+                            header__ = map_.next_value()?;
+                        }
+                        GeneratedField::Input => {
+                            if input__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("input"));
+                            }
+                            input__ = map_.next_value()?;
+                        }
+                        GeneratedField::ScheduleToCloseTimeout => {
+                            if schedule_to_close_timeout__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("scheduleToCloseTimeout"));
+                            }
+                            schedule_to_close_timeout__ = map_.next_value()?;
+                        }
+                        GeneratedField::ScheduleToStartTimeout => {
+                            if schedule_to_start_timeout__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("scheduleToStartTimeout"));
+                            }
+                            schedule_to_start_timeout__ = map_.next_value()?;
+                        }
+                        GeneratedField::StartToCloseTimeout => {
+                            if start_to_close_timeout__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("startToCloseTimeout"));
+                            }
+                            start_to_close_timeout__ = map_.next_value()?;
+                        }
+                        GeneratedField::HeartbeatTimeout => {
+                            if heartbeat_timeout__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("heartbeatTimeout"));
+                            }
+                            heartbeat_timeout__ = map_.next_value()?;
+                        }
+                        GeneratedField::WorkflowTaskCompletedEventId => {
+                            if workflow_task_completed_event_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("workflowTaskCompletedEventId"));
+                            }
+                            workflow_task_completed_event_id__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::RetryPolicy => {
+                            if retry_policy__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("retryPolicy"));
+                            }
+                            retry_policy__ = map_.next_value()?;
+                        }
+                        GeneratedField::UseWorkflowBuildId => {
+                            if use_workflow_build_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("useWorkflowBuildId"));
+                            }
+                            use_workflow_build_id__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::Priority => {
+                            if priority__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("priority"));
+                            }
+                            priority__ = map_.next_value()?;
+                        }
+                    }
+                }
+                Ok(ActivityTaskScheduledEventAttributes {
+                    activity_id: activity_id__.unwrap_or_default(),
+                    activity_type: activity_type__,
+                    task_queue: task_queue__,
+                    header: header__,
+                    input: input__,
+                    schedule_to_close_timeout: schedule_to_close_timeout__,
+                    schedule_to_start_timeout: schedule_to_start_timeout__,
+                    start_to_close_timeout: start_to_close_timeout__,
+                    heartbeat_timeout: heartbeat_timeout__,
+                    workflow_task_completed_event_id: workflow_task_completed_event_id__.unwrap_or_default(),
+                    retry_policy: retry_policy__,
+                    use_workflow_build_id: use_workflow_build_id__.unwrap_or_default(),
+                    priority: priority__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("temporal.api.history.v1.ActivityTaskScheduledEventAttributes", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for ActivityTaskStartedEventAttributes {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.scheduled_event_id != 0 {
+            len += 1;
+        }
+        if !self.identity.is_empty() {
+            len += 1;
+        }
+        if !self.request_id.is_empty() {
+            len += 1;
+        }
+        if self.attempt != 0 {
+            len += 1;
+        }
+        if self.last_failure.is_some() {
+            len += 1;
+        }
+        if self.worker_version.is_some() {
+            len += 1;
+        }
+        if self.build_id_redirect_counter != 0 {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("temporal.api.history.v1.ActivityTaskStartedEventAttributes", len)?;
+        if self.scheduled_event_id != 0 {
+            #[allow(clippy::needless_borrow)]
+            #[allow(clippy::needless_borrows_for_generic_args)]
+            struct_ser.serialize_field("scheduledEventId", ToString::to_string(&self.scheduled_event_id).as_str())?;
+        }
+        if !self.identity.is_empty() {
+            struct_ser.serialize_field("identity", &self.identity)?;
+        }
+        if !self.request_id.is_empty() {
+            struct_ser.serialize_field("requestId", &self.request_id)?;
+        }
+        if self.attempt != 0 {
+            struct_ser.serialize_field("attempt", &self.attempt)?;
+        }
+        if let Some(v) = self.last_failure.as_ref() {
+            struct_ser.serialize_field("lastFailure", v)?;
+        }
+        if let Some(v) = self.worker_version.as_ref() {
+            struct_ser.serialize_field("workerVersion", v)?;
+        }
+        if self.build_id_redirect_counter != 0 {
+            #[allow(clippy::needless_borrow)]
+            #[allow(clippy::needless_borrows_for_generic_args)]
+            struct_ser.serialize_field("buildIdRedirectCounter", ToString::to_string(&self.build_id_redirect_counter).as_str())?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for ActivityTaskStartedEventAttributes {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "scheduled_event_id",
+            "scheduledEventId",
+            "identity",
+            "request_id",
+            "requestId",
+            "attempt",
+            "last_failure",
+            "lastFailure",
+            "worker_version",
+            "workerVersion",
+            "build_id_redirect_counter",
+            "buildIdRedirectCounter",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            ScheduledEventId,
+            Identity,
+            RequestId,
+            Attempt,
+            LastFailure,
+            WorkerVersion,
+            BuildIdRedirectCounter,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "scheduledEventId" | "scheduled_event_id" => Ok(GeneratedField::ScheduledEventId),
+                            "identity" => Ok(GeneratedField::Identity),
+                            "requestId" | "request_id" => Ok(GeneratedField::RequestId),
+                            "attempt" => Ok(GeneratedField::Attempt),
+                            "lastFailure" | "last_failure" => Ok(GeneratedField::LastFailure),
+                            "workerVersion" | "worker_version" => Ok(GeneratedField::WorkerVersion),
+                            "buildIdRedirectCounter" | "build_id_redirect_counter" => Ok(GeneratedField::BuildIdRedirectCounter),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = ActivityTaskStartedEventAttributes;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct temporal.api.history.v1.ActivityTaskStartedEventAttributes")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<ActivityTaskStartedEventAttributes, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut scheduled_event_id__ = None;
+                let mut identity__ = None;
+                let mut request_id__ = None;
+                let mut attempt__ = None;
+                let mut last_failure__ = None;
+                let mut worker_version__ = None;
+                let mut build_id_redirect_counter__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::ScheduledEventId => {
+                            if scheduled_event_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("scheduledEventId"));
+                            }
+                            scheduled_event_id__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::Identity => {
+                            if identity__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("identity"));
+                            }
+                            identity__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::RequestId => {
+                            if request_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("requestId"));
+                            }
+                            request_id__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::Attempt => {
+                            if attempt__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("attempt"));
+                            }
+                            attempt__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::LastFailure => {
+                            if last_failure__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("lastFailure"));
+                            }
+                            last_failure__ = map_.next_value()?;
+                        }
+                        GeneratedField::WorkerVersion => {
+                            if worker_version__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("workerVersion"));
+                            }
+                            worker_version__ = map_.next_value()?;
+                        }
+                        GeneratedField::BuildIdRedirectCounter => {
+                            if build_id_redirect_counter__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("buildIdRedirectCounter"));
+                            }
+                            build_id_redirect_counter__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                    }
+                }
+                Ok(ActivityTaskStartedEventAttributes {
+                    scheduled_event_id: scheduled_event_id__.unwrap_or_default(),
+                    identity: identity__.unwrap_or_default(),
+                    request_id: request_id__.unwrap_or_default(),
+                    attempt: attempt__.unwrap_or_default(),
+                    last_failure: last_failure__,
+                    worker_version: worker_version__,
+                    build_id_redirect_counter: build_id_redirect_counter__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("temporal.api.history.v1.ActivityTaskStartedEventAttributes", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for ActivityTaskTimedOutEventAttributes {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.failure.is_some() {
+            len += 1;
+        }
+        if self.scheduled_event_id != 0 {
+            len += 1;
+        }
+        if self.started_event_id != 0 {
+            len += 1;
+        }
+        if self.retry_state != 0 {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("temporal.api.history.v1.ActivityTaskTimedOutEventAttributes", len)?;
+        if let Some(v) = self.failure.as_ref() {
+            struct_ser.serialize_field("failure", v)?;
+        }
+        if self.scheduled_event_id != 0 {
+            #[allow(clippy::needless_borrow)]
+            #[allow(clippy::needless_borrows_for_generic_args)]
+            struct_ser.serialize_field("scheduledEventId", ToString::to_string(&self.scheduled_event_id).as_str())?;
+        }
+        if self.started_event_id != 0 {
+            #[allow(clippy::needless_borrow)]
+            #[allow(clippy::needless_borrows_for_generic_args)]
+            struct_ser.serialize_field("startedEventId", ToString::to_string(&self.started_event_id).as_str())?;
+        }
+        if self.retry_state != 0 {
+            let v = super::super::enums::v1::RetryState::try_from(self.retry_state)
+                .map_err(|_| serde::ser::Error::custom(format!("Invalid variant {}", self.retry_state)))?;
+            struct_ser.serialize_field("retryState", &v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for ActivityTaskTimedOutEventAttributes {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "failure",
+            "scheduled_event_id",
+            "scheduledEventId",
+            "started_event_id",
+            "startedEventId",
+            "retry_state",
+            "retryState",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Failure,
+            ScheduledEventId,
+            StartedEventId,
+            RetryState,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "failure" => Ok(GeneratedField::Failure),
+                            "scheduledEventId" | "scheduled_event_id" => Ok(GeneratedField::ScheduledEventId),
+                            "startedEventId" | "started_event_id" => Ok(GeneratedField::StartedEventId),
+                            "retryState" | "retry_state" => Ok(GeneratedField::RetryState),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = ActivityTaskTimedOutEventAttributes;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct temporal.api.history.v1.ActivityTaskTimedOutEventAttributes")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<ActivityTaskTimedOutEventAttributes, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut failure__ = None;
+                let mut scheduled_event_id__ = None;
+                let mut started_event_id__ = None;
+                let mut retry_state__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Failure => {
+                            if failure__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("failure"));
+                            }
+                            failure__ = map_.next_value()?;
+                        }
+                        GeneratedField::ScheduledEventId => {
+                            if scheduled_event_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("scheduledEventId"));
+                            }
+                            scheduled_event_id__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::StartedEventId => {
+                            if started_event_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("startedEventId"));
+                            }
+                            started_event_id__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::RetryState => {
+                            if retry_state__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("retryState"));
+                            }
+                            retry_state__ = Some(map_.next_value::<super::super::enums::v1::RetryState>()? as i32);
+                        }
+                    }
+                }
+                Ok(ActivityTaskTimedOutEventAttributes {
+                    failure: failure__,
+                    scheduled_event_id: scheduled_event_id__.unwrap_or_default(),
+                    started_event_id: started_event_id__.unwrap_or_default(),
+                    retry_state: retry_state__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("temporal.api.history.v1.ActivityTaskTimedOutEventAttributes", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for ChildWorkflowExecutionCanceledEventAttributes {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.details.is_some() {
+            len += 1;
+        }
+        if !self.namespace.is_empty() {
+            len += 1;
+        }
+        if !self.namespace_id.is_empty() {
+            len += 1;
+        }
+        if self.workflow_execution.is_some() {
+            len += 1;
+        }
+        if self.workflow_type.is_some() {
+            len += 1;
+        }
+        if self.initiated_event_id != 0 {
+            len += 1;
+        }
+        if self.started_event_id != 0 {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("temporal.api.history.v1.ChildWorkflowExecutionCanceledEventAttributes", len)?;
+        if let Some(v) = self.details.as_ref() {
+            struct_ser.serialize_field("details", v)?;
+        }
+        if !self.namespace.is_empty() {
+            struct_ser.serialize_field("namespace", &self.namespace)?;
+        }
+        if !self.namespace_id.is_empty() {
+            struct_ser.serialize_field("namespaceId", &self.namespace_id)?;
+        }
+        if let Some(v) = self.workflow_execution.as_ref() {
+            struct_ser.serialize_field("workflowExecution", v)?;
+        }
+        if let Some(v) = self.workflow_type.as_ref() {
+            struct_ser.serialize_field("workflowType", v)?;
+        }
+        if self.initiated_event_id != 0 {
+            #[allow(clippy::needless_borrow)]
+            #[allow(clippy::needless_borrows_for_generic_args)]
+            struct_ser.serialize_field("initiatedEventId", ToString::to_string(&self.initiated_event_id).as_str())?;
+        }
+        if self.started_event_id != 0 {
+            #[allow(clippy::needless_borrow)]
+            #[allow(clippy::needless_borrows_for_generic_args)]
+            struct_ser.serialize_field("startedEventId", ToString::to_string(&self.started_event_id).as_str())?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for ChildWorkflowExecutionCanceledEventAttributes {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "details",
+            "namespace",
+            "namespace_id",
+            "namespaceId",
+            "workflow_execution",
+            "workflowExecution",
+            "workflow_type",
+            "workflowType",
+            "initiated_event_id",
+            "initiatedEventId",
+            "started_event_id",
+            "startedEventId",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Details,
+            Namespace,
+            NamespaceId,
+            WorkflowExecution,
+            WorkflowType,
+            InitiatedEventId,
+            StartedEventId,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "details" => Ok(GeneratedField::Details),
+                            "namespace" => Ok(GeneratedField::Namespace),
+                            "namespaceId" | "namespace_id" => Ok(GeneratedField::NamespaceId),
+                            "workflowExecution" | "workflow_execution" => Ok(GeneratedField::WorkflowExecution),
+                            "workflowType" | "workflow_type" => Ok(GeneratedField::WorkflowType),
+                            "initiatedEventId" | "initiated_event_id" => Ok(GeneratedField::InitiatedEventId),
+                            "startedEventId" | "started_event_id" => Ok(GeneratedField::StartedEventId),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = ChildWorkflowExecutionCanceledEventAttributes;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct temporal.api.history.v1.ChildWorkflowExecutionCanceledEventAttributes")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<ChildWorkflowExecutionCanceledEventAttributes, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut details__ = None;
+                let mut namespace__ = None;
+                let mut namespace_id__ = None;
+                let mut workflow_execution__ = None;
+                let mut workflow_type__ = None;
+                let mut initiated_event_id__ = None;
+                let mut started_event_id__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Details => {
+                            if details__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("details"));
+                            }
+                            details__ = map_.next_value()?;
+                        }
+                        GeneratedField::Namespace => {
+                            if namespace__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("namespace"));
+                            }
+                            namespace__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::NamespaceId => {
+                            if namespace_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("namespaceId"));
+                            }
+                            namespace_id__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::WorkflowExecution => {
+                            if workflow_execution__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("workflowExecution"));
+                            }
+                            workflow_execution__ = map_.next_value()?;
+                        }
+                        GeneratedField::WorkflowType => {
+                            if workflow_type__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("workflowType"));
+                            }
+                            workflow_type__ = map_.next_value()?;
+                        }
+                        GeneratedField::InitiatedEventId => {
+                            if initiated_event_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("initiatedEventId"));
+                            }
+                            initiated_event_id__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::StartedEventId => {
+                            if started_event_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("startedEventId"));
+                            }
+                            started_event_id__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                    }
+                }
+                Ok(ChildWorkflowExecutionCanceledEventAttributes {
+                    details: details__,
+                    namespace: namespace__.unwrap_or_default(),
+                    namespace_id: namespace_id__.unwrap_or_default(),
+                    workflow_execution: workflow_execution__,
+                    workflow_type: workflow_type__,
+                    initiated_event_id: initiated_event_id__.unwrap_or_default(),
+                    started_event_id: started_event_id__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("temporal.api.history.v1.ChildWorkflowExecutionCanceledEventAttributes", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for ChildWorkflowExecutionCompletedEventAttributes {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.result.is_some() {
+            len += 1;
+        }
+        if !self.namespace.is_empty() {
+            len += 1;
+        }
+        if !self.namespace_id.is_empty() {
+            len += 1;
+        }
+        if self.workflow_execution.is_some() {
+            len += 1;
+        }
+        if self.workflow_type.is_some() {
+            len += 1;
+        }
+        if self.initiated_event_id != 0 {
+            len += 1;
+        }
+        if self.started_event_id != 0 {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("temporal.api.history.v1.ChildWorkflowExecutionCompletedEventAttributes", len)?;
+        if let Some(v) = self.result.as_ref() {
+            struct_ser.serialize_field("result", v)?;
+        }
+        if !self.namespace.is_empty() {
+            struct_ser.serialize_field("namespace", &self.namespace)?;
+        }
+        if !self.namespace_id.is_empty() {
+            struct_ser.serialize_field("namespaceId", &self.namespace_id)?;
+        }
+        if let Some(v) = self.workflow_execution.as_ref() {
+            struct_ser.serialize_field("workflowExecution", v)?;
+        }
+        if let Some(v) = self.workflow_type.as_ref() {
+            struct_ser.serialize_field("workflowType", v)?;
+        }
+        if self.initiated_event_id != 0 {
+            #[allow(clippy::needless_borrow)]
+            #[allow(clippy::needless_borrows_for_generic_args)]
+            struct_ser.serialize_field("initiatedEventId", ToString::to_string(&self.initiated_event_id).as_str())?;
+        }
+        if self.started_event_id != 0 {
+            #[allow(clippy::needless_borrow)]
+            #[allow(clippy::needless_borrows_for_generic_args)]
+            struct_ser.serialize_field("startedEventId", ToString::to_string(&self.started_event_id).as_str())?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for ChildWorkflowExecutionCompletedEventAttributes {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "result",
+            "namespace",
+            "namespace_id",
+            "namespaceId",
+            "workflow_execution",
+            "workflowExecution",
+            "workflow_type",
+            "workflowType",
+            "initiated_event_id",
+            "initiatedEventId",
+            "started_event_id",
+            "startedEventId",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Result,
+            Namespace,
+            NamespaceId,
+            WorkflowExecution,
+            WorkflowType,
+            InitiatedEventId,
+            StartedEventId,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "result" => Ok(GeneratedField::Result),
+                            "namespace" => Ok(GeneratedField::Namespace),
+                            "namespaceId" | "namespace_id" => Ok(GeneratedField::NamespaceId),
+                            "workflowExecution" | "workflow_execution" => Ok(GeneratedField::WorkflowExecution),
+                            "workflowType" | "workflow_type" => Ok(GeneratedField::WorkflowType),
+                            "initiatedEventId" | "initiated_event_id" => Ok(GeneratedField::InitiatedEventId),
+                            "startedEventId" | "started_event_id" => Ok(GeneratedField::StartedEventId),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = ChildWorkflowExecutionCompletedEventAttributes;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct temporal.api.history.v1.ChildWorkflowExecutionCompletedEventAttributes")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<ChildWorkflowExecutionCompletedEventAttributes, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut result__ = None;
+                let mut namespace__ = None;
+                let mut namespace_id__ = None;
+                let mut workflow_execution__ = None;
+                let mut workflow_type__ = None;
+                let mut initiated_event_id__ = None;
+                let mut started_event_id__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Result => {
+                            if result__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("result"));
+                            }
+                            result__ = map_.next_value()?;
+                        }
+                        GeneratedField::Namespace => {
+                            if namespace__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("namespace"));
+                            }
+                            namespace__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::NamespaceId => {
+                            if namespace_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("namespaceId"));
+                            }
+                            namespace_id__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::WorkflowExecution => {
+                            if workflow_execution__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("workflowExecution"));
+                            }
+                            workflow_execution__ = map_.next_value()?;
+                        }
+                        GeneratedField::WorkflowType => {
+                            if workflow_type__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("workflowType"));
+                            }
+                            workflow_type__ = map_.next_value()?;
+                        }
+                        GeneratedField::InitiatedEventId => {
+                            if initiated_event_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("initiatedEventId"));
+                            }
+                            initiated_event_id__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::StartedEventId => {
+                            if started_event_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("startedEventId"));
+                            }
+                            started_event_id__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                    }
+                }
+                Ok(ChildWorkflowExecutionCompletedEventAttributes {
+                    result: result__,
+                    namespace: namespace__.unwrap_or_default(),
+                    namespace_id: namespace_id__.unwrap_or_default(),
+                    workflow_execution: workflow_execution__,
+                    workflow_type: workflow_type__,
+                    initiated_event_id: initiated_event_id__.unwrap_or_default(),
+                    started_event_id: started_event_id__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("temporal.api.history.v1.ChildWorkflowExecutionCompletedEventAttributes", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for ChildWorkflowExecutionFailedEventAttributes {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.failure.is_some() {
+            len += 1;
+        }
+        if !self.namespace.is_empty() {
+            len += 1;
+        }
+        if !self.namespace_id.is_empty() {
+            len += 1;
+        }
+        if self.workflow_execution.is_some() {
+            len += 1;
+        }
+        if self.workflow_type.is_some() {
+            len += 1;
+        }
+        if self.initiated_event_id != 0 {
+            len += 1;
+        }
+        if self.started_event_id != 0 {
+            len += 1;
+        }
+        if self.retry_state != 0 {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("temporal.api.history.v1.ChildWorkflowExecutionFailedEventAttributes", len)?;
+        if let Some(v) = self.failure.as_ref() {
+            struct_ser.serialize_field("failure", v)?;
+        }
+        if !self.namespace.is_empty() {
+            struct_ser.serialize_field("namespace", &self.namespace)?;
+        }
+        if !self.namespace_id.is_empty() {
+            struct_ser.serialize_field("namespaceId", &self.namespace_id)?;
+        }
+        if let Some(v) = self.workflow_execution.as_ref() {
+            struct_ser.serialize_field("workflowExecution", v)?;
+        }
+        if let Some(v) = self.workflow_type.as_ref() {
+            struct_ser.serialize_field("workflowType", v)?;
+        }
+        if self.initiated_event_id != 0 {
+            #[allow(clippy::needless_borrow)]
+            #[allow(clippy::needless_borrows_for_generic_args)]
+            struct_ser.serialize_field("initiatedEventId", ToString::to_string(&self.initiated_event_id).as_str())?;
+        }
+        if self.started_event_id != 0 {
+            #[allow(clippy::needless_borrow)]
+            #[allow(clippy::needless_borrows_for_generic_args)]
+            struct_ser.serialize_field("startedEventId", ToString::to_string(&self.started_event_id).as_str())?;
+        }
+        if self.retry_state != 0 {
+            let v = super::super::enums::v1::RetryState::try_from(self.retry_state)
+                .map_err(|_| serde::ser::Error::custom(format!("Invalid variant {}", self.retry_state)))?;
+            struct_ser.serialize_field("retryState", &v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for ChildWorkflowExecutionFailedEventAttributes {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "failure",
+            "namespace",
+            "namespace_id",
+            "namespaceId",
+            "workflow_execution",
+            "workflowExecution",
+            "workflow_type",
+            "workflowType",
+            "initiated_event_id",
+            "initiatedEventId",
+            "started_event_id",
+            "startedEventId",
+            "retry_state",
+            "retryState",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Failure,
+            Namespace,
+            NamespaceId,
+            WorkflowExecution,
+            WorkflowType,
+            InitiatedEventId,
+            StartedEventId,
+            RetryState,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "failure" => Ok(GeneratedField::Failure),
+                            "namespace" => Ok(GeneratedField::Namespace),
+                            "namespaceId" | "namespace_id" => Ok(GeneratedField::NamespaceId),
+                            "workflowExecution" | "workflow_execution" => Ok(GeneratedField::WorkflowExecution),
+                            "workflowType" | "workflow_type" => Ok(GeneratedField::WorkflowType),
+                            "initiatedEventId" | "initiated_event_id" => Ok(GeneratedField::InitiatedEventId),
+                            "startedEventId" | "started_event_id" => Ok(GeneratedField::StartedEventId),
+                            "retryState" | "retry_state" => Ok(GeneratedField::RetryState),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = ChildWorkflowExecutionFailedEventAttributes;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct temporal.api.history.v1.ChildWorkflowExecutionFailedEventAttributes")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<ChildWorkflowExecutionFailedEventAttributes, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut failure__ = None;
+                let mut namespace__ = None;
+                let mut namespace_id__ = None;
+                let mut workflow_execution__ = None;
+                let mut workflow_type__ = None;
+                let mut initiated_event_id__ = None;
+                let mut started_event_id__ = None;
+                let mut retry_state__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Failure => {
+                            if failure__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("failure"));
+                            }
+                            failure__ = map_.next_value()?;
+                        }
+                        GeneratedField::Namespace => {
+                            if namespace__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("namespace"));
+                            }
+                            namespace__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::NamespaceId => {
+                            if namespace_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("namespaceId"));
+                            }
+                            namespace_id__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::WorkflowExecution => {
+                            if workflow_execution__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("workflowExecution"));
+                            }
+                            workflow_execution__ = map_.next_value()?;
+                        }
+                        GeneratedField::WorkflowType => {
+                            if workflow_type__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("workflowType"));
+                            }
+                            workflow_type__ = map_.next_value()?;
+                        }
+                        GeneratedField::InitiatedEventId => {
+                            if initiated_event_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("initiatedEventId"));
+                            }
+                            initiated_event_id__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::StartedEventId => {
+                            if started_event_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("startedEventId"));
+                            }
+                            started_event_id__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::RetryState => {
+                            if retry_state__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("retryState"));
+                            }
+                            retry_state__ = Some(map_.next_value::<super::super::enums::v1::RetryState>()? as i32);
+                        }
+                    }
+                }
+                Ok(ChildWorkflowExecutionFailedEventAttributes {
+                    failure: failure__,
+                    namespace: namespace__.unwrap_or_default(),
+                    namespace_id: namespace_id__.unwrap_or_default(),
+                    workflow_execution: workflow_execution__,
+                    workflow_type: workflow_type__,
+                    initiated_event_id: initiated_event_id__.unwrap_or_default(),
+                    started_event_id: started_event_id__.unwrap_or_default(),
+                    retry_state: retry_state__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("temporal.api.history.v1.ChildWorkflowExecutionFailedEventAttributes", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for ChildWorkflowExecutionStartedEventAttributes {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.namespace.is_empty() {
+            len += 1;
+        }
+        if !self.namespace_id.is_empty() {
+            len += 1;
+        }
+        if self.initiated_event_id != 0 {
+            len += 1;
+        }
+        if self.workflow_execution.is_some() {
+            len += 1;
+        }
+        if self.workflow_type.is_some() {
+            len += 1;
+        }
+        if self.header.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("temporal.api.history.v1.ChildWorkflowExecutionStartedEventAttributes", len)?;
+        if !self.namespace.is_empty() {
+            struct_ser.serialize_field("namespace", &self.namespace)?;
+        }
+        if !self.namespace_id.is_empty() {
+            struct_ser.serialize_field("namespaceId", &self.namespace_id)?;
+        }
+        if self.initiated_event_id != 0 {
+            #[allow(clippy::needless_borrow)]
+            #[allow(clippy::needless_borrows_for_generic_args)]
+            struct_ser.serialize_field("initiatedEventId", ToString::to_string(&self.initiated_event_id).as_str())?;
+        }
+        if let Some(v) = self.workflow_execution.as_ref() {
+            struct_ser.serialize_field("workflowExecution", v)?;
+        }
+        if let Some(v) = self.workflow_type.as_ref() {
+            struct_ser.serialize_field("workflowType", v)?;
+        }
+        if let Some(v) = self.header.as_ref() {
+            struct_ser.serialize_field("header", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for ChildWorkflowExecutionStartedEventAttributes {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "namespace",
+            "namespace_id",
+            "namespaceId",
+            "initiated_event_id",
+            "initiatedEventId",
+            "workflow_execution",
+            "workflowExecution",
+            "workflow_type",
+            "workflowType",
+            "header",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Namespace,
+            NamespaceId,
+            InitiatedEventId,
+            WorkflowExecution,
+            WorkflowType,
+            Header,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "namespace" => Ok(GeneratedField::Namespace),
+                            "namespaceId" | "namespace_id" => Ok(GeneratedField::NamespaceId),
+                            "initiatedEventId" | "initiated_event_id" => Ok(GeneratedField::InitiatedEventId),
+                            "workflowExecution" | "workflow_execution" => Ok(GeneratedField::WorkflowExecution),
+                            "workflowType" | "workflow_type" => Ok(GeneratedField::WorkflowType),
+                            "header" => Ok(GeneratedField::Header),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = ChildWorkflowExecutionStartedEventAttributes;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct temporal.api.history.v1.ChildWorkflowExecutionStartedEventAttributes")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<ChildWorkflowExecutionStartedEventAttributes, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut namespace__ = None;
+                let mut namespace_id__ = None;
+                let mut initiated_event_id__ = None;
+                let mut workflow_execution__ = None;
+                let mut workflow_type__ = None;
+                let mut header__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Namespace => {
+                            if namespace__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("namespace"));
+                            }
+                            namespace__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::NamespaceId => {
+                            if namespace_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("namespaceId"));
+                            }
+                            namespace_id__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::InitiatedEventId => {
+                            if initiated_event_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("initiatedEventId"));
+                            }
+                            initiated_event_id__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::WorkflowExecution => {
+                            if workflow_execution__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("workflowExecution"));
+                            }
+                            workflow_execution__ = map_.next_value()?;
+                        }
+                        GeneratedField::WorkflowType => {
+                            if workflow_type__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("workflowType"));
+                            }
+                            workflow_type__ = map_.next_value()?;
+                        }
+                        GeneratedField::Header => {
+                            if header__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("header"));
+                            }
+                            header__ = map_.next_value()?;
+                        }
+                    }
+                }
+                Ok(ChildWorkflowExecutionStartedEventAttributes {
+                    namespace: namespace__.unwrap_or_default(),
+                    namespace_id: namespace_id__.unwrap_or_default(),
+                    initiated_event_id: initiated_event_id__.unwrap_or_default(),
+                    workflow_execution: workflow_execution__,
+                    workflow_type: workflow_type__,
+                    header: header__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("temporal.api.history.v1.ChildWorkflowExecutionStartedEventAttributes", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for ChildWorkflowExecutionTerminatedEventAttributes {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.namespace.is_empty() {
+            len += 1;
+        }
+        if !self.namespace_id.is_empty() {
+            len += 1;
+        }
+        if self.workflow_execution.is_some() {
+            len += 1;
+        }
+        if self.workflow_type.is_some() {
+            len += 1;
+        }
+        if self.initiated_event_id != 0 {
+            len += 1;
+        }
+        if self.started_event_id != 0 {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("temporal.api.history.v1.ChildWorkflowExecutionTerminatedEventAttributes", len)?;
+        if !self.namespace.is_empty() {
+            struct_ser.serialize_field("namespace", &self.namespace)?;
+        }
+        if !self.namespace_id.is_empty() {
+            struct_ser.serialize_field("namespaceId", &self.namespace_id)?;
+        }
+        if let Some(v) = self.workflow_execution.as_ref() {
+            struct_ser.serialize_field("workflowExecution", v)?;
+        }
+        if let Some(v) = self.workflow_type.as_ref() {
+            struct_ser.serialize_field("workflowType", v)?;
+        }
+        if self.initiated_event_id != 0 {
+            #[allow(clippy::needless_borrow)]
+            #[allow(clippy::needless_borrows_for_generic_args)]
+            struct_ser.serialize_field("initiatedEventId", ToString::to_string(&self.initiated_event_id).as_str())?;
+        }
+        if self.started_event_id != 0 {
+            #[allow(clippy::needless_borrow)]
+            #[allow(clippy::needless_borrows_for_generic_args)]
+            struct_ser.serialize_field("startedEventId", ToString::to_string(&self.started_event_id).as_str())?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for ChildWorkflowExecutionTerminatedEventAttributes {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "namespace",
+            "namespace_id",
+            "namespaceId",
+            "workflow_execution",
+            "workflowExecution",
+            "workflow_type",
+            "workflowType",
+            "initiated_event_id",
+            "initiatedEventId",
+            "started_event_id",
+            "startedEventId",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Namespace,
+            NamespaceId,
+            WorkflowExecution,
+            WorkflowType,
+            InitiatedEventId,
+            StartedEventId,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "namespace" => Ok(GeneratedField::Namespace),
+                            "namespaceId" | "namespace_id" => Ok(GeneratedField::NamespaceId),
+                            "workflowExecution" | "workflow_execution" => Ok(GeneratedField::WorkflowExecution),
+                            "workflowType" | "workflow_type" => Ok(GeneratedField::WorkflowType),
+                            "initiatedEventId" | "initiated_event_id" => Ok(GeneratedField::InitiatedEventId),
+                            "startedEventId" | "started_event_id" => Ok(GeneratedField::StartedEventId),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = ChildWorkflowExecutionTerminatedEventAttributes;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct temporal.api.history.v1.ChildWorkflowExecutionTerminatedEventAttributes")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<ChildWorkflowExecutionTerminatedEventAttributes, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut namespace__ = None;
+                let mut namespace_id__ = None;
+                let mut workflow_execution__ = None;
+                let mut workflow_type__ = None;
+                let mut initiated_event_id__ = None;
+                let mut started_event_id__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Namespace => {
+                            if namespace__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("namespace"));
+                            }
+                            namespace__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::NamespaceId => {
+                            if namespace_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("namespaceId"));
+                            }
+                            namespace_id__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::WorkflowExecution => {
+                            if workflow_execution__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("workflowExecution"));
+                            }
+                            workflow_execution__ = map_.next_value()?;
+                        }
+                        GeneratedField::WorkflowType => {
+                            if workflow_type__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("workflowType"));
+                            }
+                            workflow_type__ = map_.next_value()?;
+                        }
+                        GeneratedField::InitiatedEventId => {
+                            if initiated_event_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("initiatedEventId"));
+                            }
+                            initiated_event_id__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::StartedEventId => {
+                            if started_event_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("startedEventId"));
+                            }
+                            started_event_id__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                    }
+                }
+                Ok(ChildWorkflowExecutionTerminatedEventAttributes {
+                    namespace: namespace__.unwrap_or_default(),
+                    namespace_id: namespace_id__.unwrap_or_default(),
+                    workflow_execution: workflow_execution__,
+                    workflow_type: workflow_type__,
+                    initiated_event_id: initiated_event_id__.unwrap_or_default(),
+                    started_event_id: started_event_id__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("temporal.api.history.v1.ChildWorkflowExecutionTerminatedEventAttributes", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for ChildWorkflowExecutionTimedOutEventAttributes {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.namespace.is_empty() {
+            len += 1;
+        }
+        if !self.namespace_id.is_empty() {
+            len += 1;
+        }
+        if self.workflow_execution.is_some() {
+            len += 1;
+        }
+        if self.workflow_type.is_some() {
+            len += 1;
+        }
+        if self.initiated_event_id != 0 {
+            len += 1;
+        }
+        if self.started_event_id != 0 {
+            len += 1;
+        }
+        if self.retry_state != 0 {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("temporal.api.history.v1.ChildWorkflowExecutionTimedOutEventAttributes", len)?;
+        if !self.namespace.is_empty() {
+            struct_ser.serialize_field("namespace", &self.namespace)?;
+        }
+        if !self.namespace_id.is_empty() {
+            struct_ser.serialize_field("namespaceId", &self.namespace_id)?;
+        }
+        if let Some(v) = self.workflow_execution.as_ref() {
+            struct_ser.serialize_field("workflowExecution", v)?;
+        }
+        if let Some(v) = self.workflow_type.as_ref() {
+            struct_ser.serialize_field("workflowType", v)?;
+        }
+        if self.initiated_event_id != 0 {
+            #[allow(clippy::needless_borrow)]
+            #[allow(clippy::needless_borrows_for_generic_args)]
+            struct_ser.serialize_field("initiatedEventId", ToString::to_string(&self.initiated_event_id).as_str())?;
+        }
+        if self.started_event_id != 0 {
+            #[allow(clippy::needless_borrow)]
+            #[allow(clippy::needless_borrows_for_generic_args)]
+            struct_ser.serialize_field("startedEventId", ToString::to_string(&self.started_event_id).as_str())?;
+        }
+        if self.retry_state != 0 {
+            let v = super::super::enums::v1::RetryState::try_from(self.retry_state)
+                .map_err(|_| serde::ser::Error::custom(format!("Invalid variant {}", self.retry_state)))?;
+            struct_ser.serialize_field("retryState", &v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for ChildWorkflowExecutionTimedOutEventAttributes {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "namespace",
+            "namespace_id",
+            "namespaceId",
+            "workflow_execution",
+            "workflowExecution",
+            "workflow_type",
+            "workflowType",
+            "initiated_event_id",
+            "initiatedEventId",
+            "started_event_id",
+            "startedEventId",
+            "retry_state",
+            "retryState",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Namespace,
+            NamespaceId,
+            WorkflowExecution,
+            WorkflowType,
+            InitiatedEventId,
+            StartedEventId,
+            RetryState,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "namespace" => Ok(GeneratedField::Namespace),
+                            "namespaceId" | "namespace_id" => Ok(GeneratedField::NamespaceId),
+                            "workflowExecution" | "workflow_execution" => Ok(GeneratedField::WorkflowExecution),
+                            "workflowType" | "workflow_type" => Ok(GeneratedField::WorkflowType),
+                            "initiatedEventId" | "initiated_event_id" => Ok(GeneratedField::InitiatedEventId),
+                            "startedEventId" | "started_event_id" => Ok(GeneratedField::StartedEventId),
+                            "retryState" | "retry_state" => Ok(GeneratedField::RetryState),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = ChildWorkflowExecutionTimedOutEventAttributes;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct temporal.api.history.v1.ChildWorkflowExecutionTimedOutEventAttributes")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<ChildWorkflowExecutionTimedOutEventAttributes, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut namespace__ = None;
+                let mut namespace_id__ = None;
+                let mut workflow_execution__ = None;
+                let mut workflow_type__ = None;
+                let mut initiated_event_id__ = None;
+                let mut started_event_id__ = None;
+                let mut retry_state__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Namespace => {
+                            if namespace__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("namespace"));
+                            }
+                            namespace__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::NamespaceId => {
+                            if namespace_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("namespaceId"));
+                            }
+                            namespace_id__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::WorkflowExecution => {
+                            if workflow_execution__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("workflowExecution"));
+                            }
+                            workflow_execution__ = map_.next_value()?;
+                        }
+                        GeneratedField::WorkflowType => {
+                            if workflow_type__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("workflowType"));
+                            }
+                            workflow_type__ = map_.next_value()?;
+                        }
+                        GeneratedField::InitiatedEventId => {
+                            if initiated_event_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("initiatedEventId"));
+                            }
+                            initiated_event_id__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::StartedEventId => {
+                            if started_event_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("startedEventId"));
+                            }
+                            started_event_id__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::RetryState => {
+                            if retry_state__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("retryState"));
+                            }
+                            retry_state__ = Some(map_.next_value::<super::super::enums::v1::RetryState>()? as i32);
+                        }
+                    }
+                }
+                Ok(ChildWorkflowExecutionTimedOutEventAttributes {
+                    namespace: namespace__.unwrap_or_default(),
+                    namespace_id: namespace_id__.unwrap_or_default(),
+                    workflow_execution: workflow_execution__,
+                    workflow_type: workflow_type__,
+                    initiated_event_id: initiated_event_id__.unwrap_or_default(),
+                    started_event_id: started_event_id__.unwrap_or_default(),
+                    retry_state: retry_state__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("temporal.api.history.v1.ChildWorkflowExecutionTimedOutEventAttributes", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for DeclinedTargetVersionUpgrade {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.deployment_version.is_some() {
+            len += 1;
+        }
+        if self.revision_number != 0 {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("temporal.api.history.v1.DeclinedTargetVersionUpgrade", len)?;
+        if let Some(v) = self.deployment_version.as_ref() {
+            struct_ser.serialize_field("deploymentVersion", v)?;
+        }
+        if self.revision_number != 0 {
+            #[allow(clippy::needless_borrow)]
+            #[allow(clippy::needless_borrows_for_generic_args)]
+            struct_ser.serialize_field("revisionNumber", ToString::to_string(&self.revision_number).as_str())?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for DeclinedTargetVersionUpgrade {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "deployment_version",
+            "deploymentVersion",
+            "revision_number",
+            "revisionNumber",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            DeploymentVersion,
+            RevisionNumber,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "deploymentVersion" | "deployment_version" => Ok(GeneratedField::DeploymentVersion),
+                            "revisionNumber" | "revision_number" => Ok(GeneratedField::RevisionNumber),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = DeclinedTargetVersionUpgrade;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct temporal.api.history.v1.DeclinedTargetVersionUpgrade")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<DeclinedTargetVersionUpgrade, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut deployment_version__ = None;
+                let mut revision_number__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::DeploymentVersion => {
+                            if deployment_version__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("deploymentVersion"));
+                            }
+                            deployment_version__ = map_.next_value()?;
+                        }
+                        GeneratedField::RevisionNumber => {
+                            if revision_number__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("revisionNumber"));
+                            }
+                            revision_number__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                    }
+                }
+                Ok(DeclinedTargetVersionUpgrade {
+                    deployment_version: deployment_version__,
+                    revision_number: revision_number__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("temporal.api.history.v1.DeclinedTargetVersionUpgrade", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for ExternalWorkflowExecutionCancelRequestedEventAttributes {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.initiated_event_id != 0 {
+            len += 1;
+        }
+        if !self.namespace.is_empty() {
+            len += 1;
+        }
+        if !self.namespace_id.is_empty() {
+            len += 1;
+        }
+        if self.workflow_execution.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("temporal.api.history.v1.ExternalWorkflowExecutionCancelRequestedEventAttributes", len)?;
+        if self.initiated_event_id != 0 {
+            #[allow(clippy::needless_borrow)]
+            #[allow(clippy::needless_borrows_for_generic_args)]
+            struct_ser.serialize_field("initiatedEventId", ToString::to_string(&self.initiated_event_id).as_str())?;
+        }
+        if !self.namespace.is_empty() {
+            struct_ser.serialize_field("namespace", &self.namespace)?;
+        }
+        if !self.namespace_id.is_empty() {
+            struct_ser.serialize_field("namespaceId", &self.namespace_id)?;
+        }
+        if let Some(v) = self.workflow_execution.as_ref() {
+            struct_ser.serialize_field("workflowExecution", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for ExternalWorkflowExecutionCancelRequestedEventAttributes {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "initiated_event_id",
+            "initiatedEventId",
+            "namespace",
+            "namespace_id",
+            "namespaceId",
+            "workflow_execution",
+            "workflowExecution",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            InitiatedEventId,
+            Namespace,
+            NamespaceId,
+            WorkflowExecution,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "initiatedEventId" | "initiated_event_id" => Ok(GeneratedField::InitiatedEventId),
+                            "namespace" => Ok(GeneratedField::Namespace),
+                            "namespaceId" | "namespace_id" => Ok(GeneratedField::NamespaceId),
+                            "workflowExecution" | "workflow_execution" => Ok(GeneratedField::WorkflowExecution),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = ExternalWorkflowExecutionCancelRequestedEventAttributes;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct temporal.api.history.v1.ExternalWorkflowExecutionCancelRequestedEventAttributes")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<ExternalWorkflowExecutionCancelRequestedEventAttributes, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut initiated_event_id__ = None;
+                let mut namespace__ = None;
+                let mut namespace_id__ = None;
+                let mut workflow_execution__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::InitiatedEventId => {
+                            if initiated_event_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("initiatedEventId"));
+                            }
+                            initiated_event_id__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::Namespace => {
+                            if namespace__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("namespace"));
+                            }
+                            namespace__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::NamespaceId => {
+                            if namespace_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("namespaceId"));
+                            }
+                            namespace_id__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::WorkflowExecution => {
+                            if workflow_execution__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("workflowExecution"));
+                            }
+                            workflow_execution__ = map_.next_value()?;
+                        }
+                    }
+                }
+                Ok(ExternalWorkflowExecutionCancelRequestedEventAttributes {
+                    initiated_event_id: initiated_event_id__.unwrap_or_default(),
+                    namespace: namespace__.unwrap_or_default(),
+                    namespace_id: namespace_id__.unwrap_or_default(),
+                    workflow_execution: workflow_execution__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("temporal.api.history.v1.ExternalWorkflowExecutionCancelRequestedEventAttributes", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for ExternalWorkflowExecutionSignaledEventAttributes {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.initiated_event_id != 0 {
+            len += 1;
+        }
+        if !self.namespace.is_empty() {
+            len += 1;
+        }
+        if !self.namespace_id.is_empty() {
+            len += 1;
+        }
+        if self.workflow_execution.is_some() {
+            len += 1;
+        }
+        if !self.control.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("temporal.api.history.v1.ExternalWorkflowExecutionSignaledEventAttributes", len)?;
+        if self.initiated_event_id != 0 {
+            #[allow(clippy::needless_borrow)]
+            #[allow(clippy::needless_borrows_for_generic_args)]
+            struct_ser.serialize_field("initiatedEventId", ToString::to_string(&self.initiated_event_id).as_str())?;
+        }
+        if !self.namespace.is_empty() {
+            struct_ser.serialize_field("namespace", &self.namespace)?;
+        }
+        if !self.namespace_id.is_empty() {
+            struct_ser.serialize_field("namespaceId", &self.namespace_id)?;
+        }
+        if let Some(v) = self.workflow_execution.as_ref() {
+            struct_ser.serialize_field("workflowExecution", v)?;
+        }
+        if !self.control.is_empty() {
+            struct_ser.serialize_field("control", &self.control)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for ExternalWorkflowExecutionSignaledEventAttributes {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "initiated_event_id",
+            "initiatedEventId",
+            "namespace",
+            "namespace_id",
+            "namespaceId",
+            "workflow_execution",
+            "workflowExecution",
+            "control",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            InitiatedEventId,
+            Namespace,
+            NamespaceId,
+            WorkflowExecution,
+            Control,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "initiatedEventId" | "initiated_event_id" => Ok(GeneratedField::InitiatedEventId),
+                            "namespace" => Ok(GeneratedField::Namespace),
+                            "namespaceId" | "namespace_id" => Ok(GeneratedField::NamespaceId),
+                            "workflowExecution" | "workflow_execution" => Ok(GeneratedField::WorkflowExecution),
+                            "control" => Ok(GeneratedField::Control),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = ExternalWorkflowExecutionSignaledEventAttributes;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct temporal.api.history.v1.ExternalWorkflowExecutionSignaledEventAttributes")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<ExternalWorkflowExecutionSignaledEventAttributes, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut initiated_event_id__ = None;
+                let mut namespace__ = None;
+                let mut namespace_id__ = None;
+                let mut workflow_execution__ = None;
+                let mut control__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::InitiatedEventId => {
+                            if initiated_event_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("initiatedEventId"));
+                            }
+                            initiated_event_id__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::Namespace => {
+                            if namespace__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("namespace"));
+                            }
+                            namespace__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::NamespaceId => {
+                            if namespace_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("namespaceId"));
+                            }
+                            namespace_id__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::WorkflowExecution => {
+                            if workflow_execution__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("workflowExecution"));
+                            }
+                            workflow_execution__ = map_.next_value()?;
+                        }
+                        GeneratedField::Control => {
+                            if control__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("control"));
+                            }
+                            control__ = Some(map_.next_value()?);
+                        }
+                    }
+                }
+                Ok(ExternalWorkflowExecutionSignaledEventAttributes {
+                    initiated_event_id: initiated_event_id__.unwrap_or_default(),
+                    namespace: namespace__.unwrap_or_default(),
+                    namespace_id: namespace_id__.unwrap_or_default(),
+                    workflow_execution: workflow_execution__,
+                    control: control__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("temporal.api.history.v1.ExternalWorkflowExecutionSignaledEventAttributes", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for History {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.events.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("temporal.api.history.v1.History", len)?;
+        if !self.events.is_empty() {
+            struct_ser.serialize_field("events", &self.events)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for History {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "events",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Events,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "events" => Ok(GeneratedField::Events),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = History;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct temporal.api.history.v1.History")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<History, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut events__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Events => {
+                            if events__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("events"));
+                            }
+                            events__ = Some(map_.next_value()?);
+                        }
+                    }
+                }
+                Ok(History {
+                    events: events__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("temporal.api.history.v1.History", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for HistoryEvent {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.event_id != 0 {
+            len += 1;
+        }
+        if self.event_time.is_some() {
+            len += 1;
+        }
+        if self.event_type != 0 {
+            len += 1;
+        }
+        if self.version != 0 {
+            len += 1;
+        }
+        if self.task_id != 0 {
+            len += 1;
+        }
+        if self.worker_may_ignore {
+            len += 1;
+        }
+        if self.user_metadata.is_some() {
+            len += 1;
+        }
+        if !self.links.is_empty() {
+            len += 1;
+        }
+        if self.principal.is_some() {
+            len += 1;
+        }
+        if self.attributes.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("temporal.api.history.v1.HistoryEvent", len)?;
+        if self.event_id != 0 {
+            #[allow(clippy::needless_borrow)]
+            #[allow(clippy::needless_borrows_for_generic_args)]
+            struct_ser.serialize_field("eventId", ToString::to_string(&self.event_id).as_str())?;
+        }
+        if let Some(v) = self.event_time.as_ref() {
+            struct_ser.serialize_field("eventTime", v)?;
+        }
+        if self.event_type != 0 {
+            let v = super::super::enums::v1::EventType::try_from(self.event_type)
+                .map_err(|_| serde::ser::Error::custom(format!("Invalid variant {}", self.event_type)))?;
+            struct_ser.serialize_field("eventType", &v)?;
+        }
+        if self.version != 0 {
+            #[allow(clippy::needless_borrow)]
+            #[allow(clippy::needless_borrows_for_generic_args)]
+            struct_ser.serialize_field("version", ToString::to_string(&self.version).as_str())?;
+        }
+        if self.task_id != 0 {
+            #[allow(clippy::needless_borrow)]
+            #[allow(clippy::needless_borrows_for_generic_args)]
+            struct_ser.serialize_field("taskId", ToString::to_string(&self.task_id).as_str())?;
+        }
+        if self.worker_may_ignore {
+            struct_ser.serialize_field("workerMayIgnore", &self.worker_may_ignore)?;
+        }
+        if let Some(v) = self.user_metadata.as_ref() {
+            struct_ser.serialize_field("userMetadata", v)?;
+        }
+        if !self.links.is_empty() {
+            struct_ser.serialize_field("links", &self.links)?;
+        }
+        if let Some(v) = self.principal.as_ref() {
+            struct_ser.serialize_field("principal", v)?;
+        }
+        if let Some(v) = self.attributes.as_ref() {
+            match v {
+                history_event::Attributes::WorkflowExecutionStartedEventAttributes(v) => {
+                    struct_ser.serialize_field("workflowExecutionStartedEventAttributes", v)?;
+                }
+                history_event::Attributes::WorkflowExecutionCompletedEventAttributes(v) => {
+                    struct_ser.serialize_field("workflowExecutionCompletedEventAttributes", v)?;
+                }
+                history_event::Attributes::WorkflowExecutionFailedEventAttributes(v) => {
+                    struct_ser.serialize_field("workflowExecutionFailedEventAttributes", v)?;
+                }
+                history_event::Attributes::WorkflowExecutionTimedOutEventAttributes(v) => {
+                    struct_ser.serialize_field("workflowExecutionTimedOutEventAttributes", v)?;
+                }
+                history_event::Attributes::WorkflowTaskScheduledEventAttributes(v) => {
+                    struct_ser.serialize_field("workflowTaskScheduledEventAttributes", v)?;
+                }
+                history_event::Attributes::WorkflowTaskStartedEventAttributes(v) => {
+                    struct_ser.serialize_field("workflowTaskStartedEventAttributes", v)?;
+                }
+                history_event::Attributes::WorkflowTaskCompletedEventAttributes(v) => {
+                    struct_ser.serialize_field("workflowTaskCompletedEventAttributes", v)?;
+                }
+                history_event::Attributes::WorkflowTaskTimedOutEventAttributes(v) => {
+                    struct_ser.serialize_field("workflowTaskTimedOutEventAttributes", v)?;
+                }
+                history_event::Attributes::WorkflowTaskFailedEventAttributes(v) => {
+                    struct_ser.serialize_field("workflowTaskFailedEventAttributes", v)?;
+                }
+                history_event::Attributes::ActivityTaskScheduledEventAttributes(v) => {
+                    struct_ser.serialize_field("activityTaskScheduledEventAttributes", v)?;
+                }
+                history_event::Attributes::ActivityTaskStartedEventAttributes(v) => {
+                    struct_ser.serialize_field("activityTaskStartedEventAttributes", v)?;
+                }
+                history_event::Attributes::ActivityTaskCompletedEventAttributes(v) => {
+                    struct_ser.serialize_field("activityTaskCompletedEventAttributes", v)?;
+                }
+                history_event::Attributes::ActivityTaskFailedEventAttributes(v) => {
+                    struct_ser.serialize_field("activityTaskFailedEventAttributes", v)?;
+                }
+                history_event::Attributes::ActivityTaskTimedOutEventAttributes(v) => {
+                    struct_ser.serialize_field("activityTaskTimedOutEventAttributes", v)?;
+                }
+                history_event::Attributes::TimerStartedEventAttributes(v) => {
+                    struct_ser.serialize_field("timerStartedEventAttributes", v)?;
+                }
+                history_event::Attributes::TimerFiredEventAttributes(v) => {
+                    struct_ser.serialize_field("timerFiredEventAttributes", v)?;
+                }
+                history_event::Attributes::ActivityTaskCancelRequestedEventAttributes(v) => {
+                    struct_ser.serialize_field("activityTaskCancelRequestedEventAttributes", v)?;
+                }
+                history_event::Attributes::ActivityTaskCanceledEventAttributes(v) => {
+                    struct_ser.serialize_field("activityTaskCanceledEventAttributes", v)?;
+                }
+                history_event::Attributes::TimerCanceledEventAttributes(v) => {
+                    struct_ser.serialize_field("timerCanceledEventAttributes", v)?;
+                }
+                history_event::Attributes::MarkerRecordedEventAttributes(v) => {
+                    struct_ser.serialize_field("markerRecordedEventAttributes", v)?;
+                }
+                history_event::Attributes::WorkflowExecutionSignaledEventAttributes(v) => {
+                    struct_ser.serialize_field("workflowExecutionSignaledEventAttributes", v)?;
+                }
+                history_event::Attributes::WorkflowExecutionTerminatedEventAttributes(v) => {
+                    struct_ser.serialize_field("workflowExecutionTerminatedEventAttributes", v)?;
+                }
+                history_event::Attributes::WorkflowExecutionCancelRequestedEventAttributes(v) => {
+                    struct_ser.serialize_field("workflowExecutionCancelRequestedEventAttributes", v)?;
+                }
+                history_event::Attributes::WorkflowExecutionCanceledEventAttributes(v) => {
+                    struct_ser.serialize_field("workflowExecutionCanceledEventAttributes", v)?;
+                }
+                history_event::Attributes::RequestCancelExternalWorkflowExecutionInitiatedEventAttributes(v) => {
+                    struct_ser.serialize_field("requestCancelExternalWorkflowExecutionInitiatedEventAttributes", v)?;
+                }
+                history_event::Attributes::RequestCancelExternalWorkflowExecutionFailedEventAttributes(v) => {
+                    struct_ser.serialize_field("requestCancelExternalWorkflowExecutionFailedEventAttributes", v)?;
+                }
+                history_event::Attributes::ExternalWorkflowExecutionCancelRequestedEventAttributes(v) => {
+                    struct_ser.serialize_field("externalWorkflowExecutionCancelRequestedEventAttributes", v)?;
+                }
+                history_event::Attributes::WorkflowExecutionContinuedAsNewEventAttributes(v) => {
+                    struct_ser.serialize_field("workflowExecutionContinuedAsNewEventAttributes", v)?;
+                }
+                history_event::Attributes::StartChildWorkflowExecutionInitiatedEventAttributes(v) => {
+                    struct_ser.serialize_field("startChildWorkflowExecutionInitiatedEventAttributes", v)?;
+                }
+                history_event::Attributes::StartChildWorkflowExecutionFailedEventAttributes(v) => {
+                    struct_ser.serialize_field("startChildWorkflowExecutionFailedEventAttributes", v)?;
+                }
+                history_event::Attributes::ChildWorkflowExecutionStartedEventAttributes(v) => {
+                    struct_ser.serialize_field("childWorkflowExecutionStartedEventAttributes", v)?;
+                }
+                history_event::Attributes::ChildWorkflowExecutionCompletedEventAttributes(v) => {
+                    struct_ser.serialize_field("childWorkflowExecutionCompletedEventAttributes", v)?;
+                }
+                history_event::Attributes::ChildWorkflowExecutionFailedEventAttributes(v) => {
+                    struct_ser.serialize_field("childWorkflowExecutionFailedEventAttributes", v)?;
+                }
+                history_event::Attributes::ChildWorkflowExecutionCanceledEventAttributes(v) => {
+                    struct_ser.serialize_field("childWorkflowExecutionCanceledEventAttributes", v)?;
+                }
+                history_event::Attributes::ChildWorkflowExecutionTimedOutEventAttributes(v) => {
+                    struct_ser.serialize_field("childWorkflowExecutionTimedOutEventAttributes", v)?;
+                }
+                history_event::Attributes::ChildWorkflowExecutionTerminatedEventAttributes(v) => {
+                    struct_ser.serialize_field("childWorkflowExecutionTerminatedEventAttributes", v)?;
+                }
+                history_event::Attributes::SignalExternalWorkflowExecutionInitiatedEventAttributes(v) => {
+                    struct_ser.serialize_field("signalExternalWorkflowExecutionInitiatedEventAttributes", v)?;
+                }
+                history_event::Attributes::SignalExternalWorkflowExecutionFailedEventAttributes(v) => {
+                    struct_ser.serialize_field("signalExternalWorkflowExecutionFailedEventAttributes", v)?;
+                }
+                history_event::Attributes::ExternalWorkflowExecutionSignaledEventAttributes(v) => {
+                    struct_ser.serialize_field("externalWorkflowExecutionSignaledEventAttributes", v)?;
+                }
+                history_event::Attributes::UpsertWorkflowSearchAttributesEventAttributes(v) => {
+                    struct_ser.serialize_field("upsertWorkflowSearchAttributesEventAttributes", v)?;
+                }
+                history_event::Attributes::WorkflowExecutionUpdateAcceptedEventAttributes(v) => {
+                    struct_ser.serialize_field("workflowExecutionUpdateAcceptedEventAttributes", v)?;
+                }
+                history_event::Attributes::WorkflowExecutionUpdateRejectedEventAttributes(v) => {
+                    struct_ser.serialize_field("workflowExecutionUpdateRejectedEventAttributes", v)?;
+                }
+                history_event::Attributes::WorkflowExecutionUpdateCompletedEventAttributes(v) => {
+                    struct_ser.serialize_field("workflowExecutionUpdateCompletedEventAttributes", v)?;
+                }
+                history_event::Attributes::WorkflowPropertiesModifiedExternallyEventAttributes(v) => {
+                    struct_ser.serialize_field("workflowPropertiesModifiedExternallyEventAttributes", v)?;
+                }
+                history_event::Attributes::ActivityPropertiesModifiedExternallyEventAttributes(v) => {
+                    struct_ser.serialize_field("activityPropertiesModifiedExternallyEventAttributes", v)?;
+                }
+                history_event::Attributes::WorkflowPropertiesModifiedEventAttributes(v) => {
+                    struct_ser.serialize_field("workflowPropertiesModifiedEventAttributes", v)?;
+                }
+                history_event::Attributes::WorkflowExecutionUpdateAdmittedEventAttributes(v) => {
+                    struct_ser.serialize_field("workflowExecutionUpdateAdmittedEventAttributes", v)?;
+                }
+                history_event::Attributes::NexusOperationScheduledEventAttributes(v) => {
+                    struct_ser.serialize_field("nexusOperationScheduledEventAttributes", v)?;
+                }
+                history_event::Attributes::NexusOperationStartedEventAttributes(v) => {
+                    struct_ser.serialize_field("nexusOperationStartedEventAttributes", v)?;
+                }
+                history_event::Attributes::NexusOperationCompletedEventAttributes(v) => {
+                    struct_ser.serialize_field("nexusOperationCompletedEventAttributes", v)?;
+                }
+                history_event::Attributes::NexusOperationFailedEventAttributes(v) => {
+                    struct_ser.serialize_field("nexusOperationFailedEventAttributes", v)?;
+                }
+                history_event::Attributes::NexusOperationCanceledEventAttributes(v) => {
+                    struct_ser.serialize_field("nexusOperationCanceledEventAttributes", v)?;
+                }
+                history_event::Attributes::NexusOperationTimedOutEventAttributes(v) => {
+                    struct_ser.serialize_field("nexusOperationTimedOutEventAttributes", v)?;
+                }
+                history_event::Attributes::NexusOperationCancelRequestedEventAttributes(v) => {
+                    struct_ser.serialize_field("nexusOperationCancelRequestedEventAttributes", v)?;
+                }
+                history_event::Attributes::WorkflowExecutionOptionsUpdatedEventAttributes(v) => {
+                    struct_ser.serialize_field("workflowExecutionOptionsUpdatedEventAttributes", v)?;
+                }
+                history_event::Attributes::NexusOperationCancelRequestCompletedEventAttributes(v) => {
+                    struct_ser.serialize_field("nexusOperationCancelRequestCompletedEventAttributes", v)?;
+                }
+                history_event::Attributes::NexusOperationCancelRequestFailedEventAttributes(v) => {
+                    struct_ser.serialize_field("nexusOperationCancelRequestFailedEventAttributes", v)?;
+                }
+                history_event::Attributes::WorkflowExecutionPausedEventAttributes(v) => {
+                    struct_ser.serialize_field("workflowExecutionPausedEventAttributes", v)?;
+                }
+                history_event::Attributes::WorkflowExecutionUnpausedEventAttributes(v) => {
+                    struct_ser.serialize_field("workflowExecutionUnpausedEventAttributes", v)?;
+                }
+                history_event::Attributes::WorkflowExecutionTimeSkippingTransitionedEventAttributes(v) => {
+                    struct_ser.serialize_field("workflowExecutionTimeSkippingTransitionedEventAttributes", v)?;
+                }
+            }
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for HistoryEvent {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "event_id",
+            "eventId",
+            "event_time",
+            "eventTime",
+            "event_type",
+            "eventType",
+            "version",
+            "task_id",
+            "taskId",
+            "worker_may_ignore",
+            "workerMayIgnore",
+            "user_metadata",
+            "userMetadata",
+            "links",
+            "principal",
+            "workflow_execution_started_event_attributes",
+            "workflowExecutionStartedEventAttributes",
+            "workflow_execution_completed_event_attributes",
+            "workflowExecutionCompletedEventAttributes",
+            "workflow_execution_failed_event_attributes",
+            "workflowExecutionFailedEventAttributes",
+            "workflow_execution_timed_out_event_attributes",
+            "workflowExecutionTimedOutEventAttributes",
+            "workflow_task_scheduled_event_attributes",
+            "workflowTaskScheduledEventAttributes",
+            "workflow_task_started_event_attributes",
+            "workflowTaskStartedEventAttributes",
+            "workflow_task_completed_event_attributes",
+            "workflowTaskCompletedEventAttributes",
+            "workflow_task_timed_out_event_attributes",
+            "workflowTaskTimedOutEventAttributes",
+            "workflow_task_failed_event_attributes",
+            "workflowTaskFailedEventAttributes",
+            "activity_task_scheduled_event_attributes",
+            "activityTaskScheduledEventAttributes",
+            "activity_task_started_event_attributes",
+            "activityTaskStartedEventAttributes",
+            "activity_task_completed_event_attributes",
+            "activityTaskCompletedEventAttributes",
+            "activity_task_failed_event_attributes",
+            "activityTaskFailedEventAttributes",
+            "activity_task_timed_out_event_attributes",
+            "activityTaskTimedOutEventAttributes",
+            "timer_started_event_attributes",
+            "timerStartedEventAttributes",
+            "timer_fired_event_attributes",
+            "timerFiredEventAttributes",
+            "activity_task_cancel_requested_event_attributes",
+            "activityTaskCancelRequestedEventAttributes",
+            "activity_task_canceled_event_attributes",
+            "activityTaskCanceledEventAttributes",
+            "timer_canceled_event_attributes",
+            "timerCanceledEventAttributes",
+            "marker_recorded_event_attributes",
+            "markerRecordedEventAttributes",
+            "workflow_execution_signaled_event_attributes",
+            "workflowExecutionSignaledEventAttributes",
+            "workflow_execution_terminated_event_attributes",
+            "workflowExecutionTerminatedEventAttributes",
+            "workflow_execution_cancel_requested_event_attributes",
+            "workflowExecutionCancelRequestedEventAttributes",
+            "workflow_execution_canceled_event_attributes",
+            "workflowExecutionCanceledEventAttributes",
+            "request_cancel_external_workflow_execution_initiated_event_attributes",
+            "requestCancelExternalWorkflowExecutionInitiatedEventAttributes",
+            "request_cancel_external_workflow_execution_failed_event_attributes",
+            "requestCancelExternalWorkflowExecutionFailedEventAttributes",
+            "external_workflow_execution_cancel_requested_event_attributes",
+            "externalWorkflowExecutionCancelRequestedEventAttributes",
+            "workflow_execution_continued_as_new_event_attributes",
+            "workflowExecutionContinuedAsNewEventAttributes",
+            "start_child_workflow_execution_initiated_event_attributes",
+            "startChildWorkflowExecutionInitiatedEventAttributes",
+            "start_child_workflow_execution_failed_event_attributes",
+            "startChildWorkflowExecutionFailedEventAttributes",
+            "child_workflow_execution_started_event_attributes",
+            "childWorkflowExecutionStartedEventAttributes",
+            "child_workflow_execution_completed_event_attributes",
+            "childWorkflowExecutionCompletedEventAttributes",
+            "child_workflow_execution_failed_event_attributes",
+            "childWorkflowExecutionFailedEventAttributes",
+            "child_workflow_execution_canceled_event_attributes",
+            "childWorkflowExecutionCanceledEventAttributes",
+            "child_workflow_execution_timed_out_event_attributes",
+            "childWorkflowExecutionTimedOutEventAttributes",
+            "child_workflow_execution_terminated_event_attributes",
+            "childWorkflowExecutionTerminatedEventAttributes",
+            "signal_external_workflow_execution_initiated_event_attributes",
+            "signalExternalWorkflowExecutionInitiatedEventAttributes",
+            "signal_external_workflow_execution_failed_event_attributes",
+            "signalExternalWorkflowExecutionFailedEventAttributes",
+            "external_workflow_execution_signaled_event_attributes",
+            "externalWorkflowExecutionSignaledEventAttributes",
+            "upsert_workflow_search_attributes_event_attributes",
+            "upsertWorkflowSearchAttributesEventAttributes",
+            "workflow_execution_update_accepted_event_attributes",
+            "workflowExecutionUpdateAcceptedEventAttributes",
+            "workflow_execution_update_rejected_event_attributes",
+            "workflowExecutionUpdateRejectedEventAttributes",
+            "workflow_execution_update_completed_event_attributes",
+            "workflowExecutionUpdateCompletedEventAttributes",
+            "workflow_properties_modified_externally_event_attributes",
+            "workflowPropertiesModifiedExternallyEventAttributes",
+            "activity_properties_modified_externally_event_attributes",
+            "activityPropertiesModifiedExternallyEventAttributes",
+            "workflow_properties_modified_event_attributes",
+            "workflowPropertiesModifiedEventAttributes",
+            "workflow_execution_update_admitted_event_attributes",
+            "workflowExecutionUpdateAdmittedEventAttributes",
+            "nexus_operation_scheduled_event_attributes",
+            "nexusOperationScheduledEventAttributes",
+            "nexus_operation_started_event_attributes",
+            "nexusOperationStartedEventAttributes",
+            "nexus_operation_completed_event_attributes",
+            "nexusOperationCompletedEventAttributes",
+            "nexus_operation_failed_event_attributes",
+            "nexusOperationFailedEventAttributes",
+            "nexus_operation_canceled_event_attributes",
+            "nexusOperationCanceledEventAttributes",
+            "nexus_operation_timed_out_event_attributes",
+            "nexusOperationTimedOutEventAttributes",
+            "nexus_operation_cancel_requested_event_attributes",
+            "nexusOperationCancelRequestedEventAttributes",
+            "workflow_execution_options_updated_event_attributes",
+            "workflowExecutionOptionsUpdatedEventAttributes",
+            "nexus_operation_cancel_request_completed_event_attributes",
+            "nexusOperationCancelRequestCompletedEventAttributes",
+            "nexus_operation_cancel_request_failed_event_attributes",
+            "nexusOperationCancelRequestFailedEventAttributes",
+            "workflow_execution_paused_event_attributes",
+            "workflowExecutionPausedEventAttributes",
+            "workflow_execution_unpaused_event_attributes",
+            "workflowExecutionUnpausedEventAttributes",
+            "workflow_execution_time_skipping_transitioned_event_attributes",
+            "workflowExecutionTimeSkippingTransitionedEventAttributes",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            EventId,
+            EventTime,
+            EventType,
+            Version,
+            TaskId,
+            WorkerMayIgnore,
+            UserMetadata,
+            Links,
+            Principal,
+            WorkflowExecutionStartedEventAttributes,
+            WorkflowExecutionCompletedEventAttributes,
+            WorkflowExecutionFailedEventAttributes,
+            WorkflowExecutionTimedOutEventAttributes,
+            WorkflowTaskScheduledEventAttributes,
+            WorkflowTaskStartedEventAttributes,
+            WorkflowTaskCompletedEventAttributes,
+            WorkflowTaskTimedOutEventAttributes,
+            WorkflowTaskFailedEventAttributes,
+            ActivityTaskScheduledEventAttributes,
+            ActivityTaskStartedEventAttributes,
+            ActivityTaskCompletedEventAttributes,
+            ActivityTaskFailedEventAttributes,
+            ActivityTaskTimedOutEventAttributes,
+            TimerStartedEventAttributes,
+            TimerFiredEventAttributes,
+            ActivityTaskCancelRequestedEventAttributes,
+            ActivityTaskCanceledEventAttributes,
+            TimerCanceledEventAttributes,
+            MarkerRecordedEventAttributes,
+            WorkflowExecutionSignaledEventAttributes,
+            WorkflowExecutionTerminatedEventAttributes,
+            WorkflowExecutionCancelRequestedEventAttributes,
+            WorkflowExecutionCanceledEventAttributes,
+            RequestCancelExternalWorkflowExecutionInitiatedEventAttributes,
+            RequestCancelExternalWorkflowExecutionFailedEventAttributes,
+            ExternalWorkflowExecutionCancelRequestedEventAttributes,
+            WorkflowExecutionContinuedAsNewEventAttributes,
+            StartChildWorkflowExecutionInitiatedEventAttributes,
+            StartChildWorkflowExecutionFailedEventAttributes,
+            ChildWorkflowExecutionStartedEventAttributes,
+            ChildWorkflowExecutionCompletedEventAttributes,
+            ChildWorkflowExecutionFailedEventAttributes,
+            ChildWorkflowExecutionCanceledEventAttributes,
+            ChildWorkflowExecutionTimedOutEventAttributes,
+            ChildWorkflowExecutionTerminatedEventAttributes,
+            SignalExternalWorkflowExecutionInitiatedEventAttributes,
+            SignalExternalWorkflowExecutionFailedEventAttributes,
+            ExternalWorkflowExecutionSignaledEventAttributes,
+            UpsertWorkflowSearchAttributesEventAttributes,
+            WorkflowExecutionUpdateAcceptedEventAttributes,
+            WorkflowExecutionUpdateRejectedEventAttributes,
+            WorkflowExecutionUpdateCompletedEventAttributes,
+            WorkflowPropertiesModifiedExternallyEventAttributes,
+            ActivityPropertiesModifiedExternallyEventAttributes,
+            WorkflowPropertiesModifiedEventAttributes,
+            WorkflowExecutionUpdateAdmittedEventAttributes,
+            NexusOperationScheduledEventAttributes,
+            NexusOperationStartedEventAttributes,
+            NexusOperationCompletedEventAttributes,
+            NexusOperationFailedEventAttributes,
+            NexusOperationCanceledEventAttributes,
+            NexusOperationTimedOutEventAttributes,
+            NexusOperationCancelRequestedEventAttributes,
+            WorkflowExecutionOptionsUpdatedEventAttributes,
+            NexusOperationCancelRequestCompletedEventAttributes,
+            NexusOperationCancelRequestFailedEventAttributes,
+            WorkflowExecutionPausedEventAttributes,
+            WorkflowExecutionUnpausedEventAttributes,
+            WorkflowExecutionTimeSkippingTransitionedEventAttributes,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "eventId" | "event_id" => Ok(GeneratedField::EventId),
+                            "eventTime" | "event_time" => Ok(GeneratedField::EventTime),
+                            "eventType" | "event_type" => Ok(GeneratedField::EventType),
+                            "version" => Ok(GeneratedField::Version),
+                            "taskId" | "task_id" => Ok(GeneratedField::TaskId),
+                            "workerMayIgnore" | "worker_may_ignore" => Ok(GeneratedField::WorkerMayIgnore),
+                            "userMetadata" | "user_metadata" => Ok(GeneratedField::UserMetadata),
+                            "links" => Ok(GeneratedField::Links),
+                            "principal" => Ok(GeneratedField::Principal),
+                            "workflowExecutionStartedEventAttributes" | "workflow_execution_started_event_attributes" => Ok(GeneratedField::WorkflowExecutionStartedEventAttributes),
+                            "workflowExecutionCompletedEventAttributes" | "workflow_execution_completed_event_attributes" => Ok(GeneratedField::WorkflowExecutionCompletedEventAttributes),
+                            "workflowExecutionFailedEventAttributes" | "workflow_execution_failed_event_attributes" => Ok(GeneratedField::WorkflowExecutionFailedEventAttributes),
+                            "workflowExecutionTimedOutEventAttributes" | "workflow_execution_timed_out_event_attributes" => Ok(GeneratedField::WorkflowExecutionTimedOutEventAttributes),
+                            "workflowTaskScheduledEventAttributes" | "workflow_task_scheduled_event_attributes" => Ok(GeneratedField::WorkflowTaskScheduledEventAttributes),
+                            "workflowTaskStartedEventAttributes" | "workflow_task_started_event_attributes" => Ok(GeneratedField::WorkflowTaskStartedEventAttributes),
+                            "workflowTaskCompletedEventAttributes" | "workflow_task_completed_event_attributes" => Ok(GeneratedField::WorkflowTaskCompletedEventAttributes),
+                            "workflowTaskTimedOutEventAttributes" | "workflow_task_timed_out_event_attributes" => Ok(GeneratedField::WorkflowTaskTimedOutEventAttributes),
+                            "workflowTaskFailedEventAttributes" | "workflow_task_failed_event_attributes" => Ok(GeneratedField::WorkflowTaskFailedEventAttributes),
+                            "activityTaskScheduledEventAttributes" | "activity_task_scheduled_event_attributes" => Ok(GeneratedField::ActivityTaskScheduledEventAttributes),
+                            "activityTaskStartedEventAttributes" | "activity_task_started_event_attributes" => Ok(GeneratedField::ActivityTaskStartedEventAttributes),
+                            "activityTaskCompletedEventAttributes" | "activity_task_completed_event_attributes" => Ok(GeneratedField::ActivityTaskCompletedEventAttributes),
+                            "activityTaskFailedEventAttributes" | "activity_task_failed_event_attributes" => Ok(GeneratedField::ActivityTaskFailedEventAttributes),
+                            "activityTaskTimedOutEventAttributes" | "activity_task_timed_out_event_attributes" => Ok(GeneratedField::ActivityTaskTimedOutEventAttributes),
+                            "timerStartedEventAttributes" | "timer_started_event_attributes" => Ok(GeneratedField::TimerStartedEventAttributes),
+                            "timerFiredEventAttributes" | "timer_fired_event_attributes" => Ok(GeneratedField::TimerFiredEventAttributes),
+                            "activityTaskCancelRequestedEventAttributes" | "activity_task_cancel_requested_event_attributes" => Ok(GeneratedField::ActivityTaskCancelRequestedEventAttributes),
+                            "activityTaskCanceledEventAttributes" | "activity_task_canceled_event_attributes" => Ok(GeneratedField::ActivityTaskCanceledEventAttributes),
+                            "timerCanceledEventAttributes" | "timer_canceled_event_attributes" => Ok(GeneratedField::TimerCanceledEventAttributes),
+                            "markerRecordedEventAttributes" | "marker_recorded_event_attributes" => Ok(GeneratedField::MarkerRecordedEventAttributes),
+                            "workflowExecutionSignaledEventAttributes" | "workflow_execution_signaled_event_attributes" => Ok(GeneratedField::WorkflowExecutionSignaledEventAttributes),
+                            "workflowExecutionTerminatedEventAttributes" | "workflow_execution_terminated_event_attributes" => Ok(GeneratedField::WorkflowExecutionTerminatedEventAttributes),
+                            "workflowExecutionCancelRequestedEventAttributes" | "workflow_execution_cancel_requested_event_attributes" => Ok(GeneratedField::WorkflowExecutionCancelRequestedEventAttributes),
+                            "workflowExecutionCanceledEventAttributes" | "workflow_execution_canceled_event_attributes" => Ok(GeneratedField::WorkflowExecutionCanceledEventAttributes),
+                            "requestCancelExternalWorkflowExecutionInitiatedEventAttributes" | "request_cancel_external_workflow_execution_initiated_event_attributes" => Ok(GeneratedField::RequestCancelExternalWorkflowExecutionInitiatedEventAttributes),
+                            "requestCancelExternalWorkflowExecutionFailedEventAttributes" | "request_cancel_external_workflow_execution_failed_event_attributes" => Ok(GeneratedField::RequestCancelExternalWorkflowExecutionFailedEventAttributes),
+                            "externalWorkflowExecutionCancelRequestedEventAttributes" | "external_workflow_execution_cancel_requested_event_attributes" => Ok(GeneratedField::ExternalWorkflowExecutionCancelRequestedEventAttributes),
+                            "workflowExecutionContinuedAsNewEventAttributes" | "workflow_execution_continued_as_new_event_attributes" => Ok(GeneratedField::WorkflowExecutionContinuedAsNewEventAttributes),
+                            "startChildWorkflowExecutionInitiatedEventAttributes" | "start_child_workflow_execution_initiated_event_attributes" => Ok(GeneratedField::StartChildWorkflowExecutionInitiatedEventAttributes),
+                            "startChildWorkflowExecutionFailedEventAttributes" | "start_child_workflow_execution_failed_event_attributes" => Ok(GeneratedField::StartChildWorkflowExecutionFailedEventAttributes),
+                            "childWorkflowExecutionStartedEventAttributes" | "child_workflow_execution_started_event_attributes" => Ok(GeneratedField::ChildWorkflowExecutionStartedEventAttributes),
+                            "childWorkflowExecutionCompletedEventAttributes" | "child_workflow_execution_completed_event_attributes" => Ok(GeneratedField::ChildWorkflowExecutionCompletedEventAttributes),
+                            "childWorkflowExecutionFailedEventAttributes" | "child_workflow_execution_failed_event_attributes" => Ok(GeneratedField::ChildWorkflowExecutionFailedEventAttributes),
+                            "childWorkflowExecutionCanceledEventAttributes" | "child_workflow_execution_canceled_event_attributes" => Ok(GeneratedField::ChildWorkflowExecutionCanceledEventAttributes),
+                            "childWorkflowExecutionTimedOutEventAttributes" | "child_workflow_execution_timed_out_event_attributes" => Ok(GeneratedField::ChildWorkflowExecutionTimedOutEventAttributes),
+                            "childWorkflowExecutionTerminatedEventAttributes" | "child_workflow_execution_terminated_event_attributes" => Ok(GeneratedField::ChildWorkflowExecutionTerminatedEventAttributes),
+                            "signalExternalWorkflowExecutionInitiatedEventAttributes" | "signal_external_workflow_execution_initiated_event_attributes" => Ok(GeneratedField::SignalExternalWorkflowExecutionInitiatedEventAttributes),
+                            "signalExternalWorkflowExecutionFailedEventAttributes" | "signal_external_workflow_execution_failed_event_attributes" => Ok(GeneratedField::SignalExternalWorkflowExecutionFailedEventAttributes),
+                            "externalWorkflowExecutionSignaledEventAttributes" | "external_workflow_execution_signaled_event_attributes" => Ok(GeneratedField::ExternalWorkflowExecutionSignaledEventAttributes),
+                            "upsertWorkflowSearchAttributesEventAttributes" | "upsert_workflow_search_attributes_event_attributes" => Ok(GeneratedField::UpsertWorkflowSearchAttributesEventAttributes),
+                            "workflowExecutionUpdateAcceptedEventAttributes" | "workflow_execution_update_accepted_event_attributes" => Ok(GeneratedField::WorkflowExecutionUpdateAcceptedEventAttributes),
+                            "workflowExecutionUpdateRejectedEventAttributes" | "workflow_execution_update_rejected_event_attributes" => Ok(GeneratedField::WorkflowExecutionUpdateRejectedEventAttributes),
+                            "workflowExecutionUpdateCompletedEventAttributes" | "workflow_execution_update_completed_event_attributes" => Ok(GeneratedField::WorkflowExecutionUpdateCompletedEventAttributes),
+                            "workflowPropertiesModifiedExternallyEventAttributes" | "workflow_properties_modified_externally_event_attributes" => Ok(GeneratedField::WorkflowPropertiesModifiedExternallyEventAttributes),
+                            "activityPropertiesModifiedExternallyEventAttributes" | "activity_properties_modified_externally_event_attributes" => Ok(GeneratedField::ActivityPropertiesModifiedExternallyEventAttributes),
+                            "workflowPropertiesModifiedEventAttributes" | "workflow_properties_modified_event_attributes" => Ok(GeneratedField::WorkflowPropertiesModifiedEventAttributes),
+                            "workflowExecutionUpdateAdmittedEventAttributes" | "workflow_execution_update_admitted_event_attributes" => Ok(GeneratedField::WorkflowExecutionUpdateAdmittedEventAttributes),
+                            "nexusOperationScheduledEventAttributes" | "nexus_operation_scheduled_event_attributes" => Ok(GeneratedField::NexusOperationScheduledEventAttributes),
+                            "nexusOperationStartedEventAttributes" | "nexus_operation_started_event_attributes" => Ok(GeneratedField::NexusOperationStartedEventAttributes),
+                            "nexusOperationCompletedEventAttributes" | "nexus_operation_completed_event_attributes" => Ok(GeneratedField::NexusOperationCompletedEventAttributes),
+                            "nexusOperationFailedEventAttributes" | "nexus_operation_failed_event_attributes" => Ok(GeneratedField::NexusOperationFailedEventAttributes),
+                            "nexusOperationCanceledEventAttributes" | "nexus_operation_canceled_event_attributes" => Ok(GeneratedField::NexusOperationCanceledEventAttributes),
+                            "nexusOperationTimedOutEventAttributes" | "nexus_operation_timed_out_event_attributes" => Ok(GeneratedField::NexusOperationTimedOutEventAttributes),
+                            "nexusOperationCancelRequestedEventAttributes" | "nexus_operation_cancel_requested_event_attributes" => Ok(GeneratedField::NexusOperationCancelRequestedEventAttributes),
+                            "workflowExecutionOptionsUpdatedEventAttributes" | "workflow_execution_options_updated_event_attributes" => Ok(GeneratedField::WorkflowExecutionOptionsUpdatedEventAttributes),
+                            "nexusOperationCancelRequestCompletedEventAttributes" | "nexus_operation_cancel_request_completed_event_attributes" => Ok(GeneratedField::NexusOperationCancelRequestCompletedEventAttributes),
+                            "nexusOperationCancelRequestFailedEventAttributes" | "nexus_operation_cancel_request_failed_event_attributes" => Ok(GeneratedField::NexusOperationCancelRequestFailedEventAttributes),
+                            "workflowExecutionPausedEventAttributes" | "workflow_execution_paused_event_attributes" => Ok(GeneratedField::WorkflowExecutionPausedEventAttributes),
+                            "workflowExecutionUnpausedEventAttributes" | "workflow_execution_unpaused_event_attributes" => Ok(GeneratedField::WorkflowExecutionUnpausedEventAttributes),
+                            "workflowExecutionTimeSkippingTransitionedEventAttributes" | "workflow_execution_time_skipping_transitioned_event_attributes" => Ok(GeneratedField::WorkflowExecutionTimeSkippingTransitionedEventAttributes),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = HistoryEvent;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct temporal.api.history.v1.HistoryEvent")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<HistoryEvent, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut event_id__ = None;
+                let mut event_time__ = None;
+                let mut event_type__ = None;
+                let mut version__ = None;
+                let mut task_id__ = None;
+                let mut worker_may_ignore__ = None;
+                let mut user_metadata__ = None;
+                let mut links__ = None;
+                let mut principal__ = None;
+                let mut attributes__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::EventId => {
+                            if event_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("eventId"));
+                            }
+                            event_id__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::EventTime => {
+                            if event_time__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("eventTime"));
+                            }
+                            event_time__ = map_.next_value()?;
+                        }
+                        GeneratedField::EventType => {
+                            if event_type__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("eventType"));
+                            }
+                            event_type__ = Some(map_.next_value::<super::super::enums::v1::EventType>()? as i32);
+                        }
+                        GeneratedField::Version => {
+                            if version__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("version"));
+                            }
+                            version__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::TaskId => {
+                            if task_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("taskId"));
+                            }
+                            task_id__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::WorkerMayIgnore => {
+                            if worker_may_ignore__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("workerMayIgnore"));
+                            }
+                            worker_may_ignore__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::UserMetadata => {
+                            if user_metadata__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("userMetadata"));
+                            }
+                            user_metadata__ = map_.next_value()?;
+                        }
+                        GeneratedField::Links => {
+                            if links__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("links"));
+                            }
+                            links__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::Principal => {
+                            if principal__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("principal"));
+                            }
+                            principal__ = map_.next_value()?;
+                        }
+                        GeneratedField::WorkflowExecutionStartedEventAttributes => {
+                            if attributes__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("workflowExecutionStartedEventAttributes"));
+                            }
+                            attributes__ = map_.next_value::<::std::option::Option<_>>()?.map(history_event::Attributes::WorkflowExecutionStartedEventAttributes)
+;
+                        }
+                        GeneratedField::WorkflowExecutionCompletedEventAttributes => {
+                            if attributes__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("workflowExecutionCompletedEventAttributes"));
+                            }
+                            attributes__ = map_.next_value::<::std::option::Option<_>>()?.map(history_event::Attributes::WorkflowExecutionCompletedEventAttributes)
+;
+                        }
+                        GeneratedField::WorkflowExecutionFailedEventAttributes => {
+                            if attributes__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("workflowExecutionFailedEventAttributes"));
+                            }
+                            attributes__ = map_.next_value::<::std::option::Option<_>>()?.map(history_event::Attributes::WorkflowExecutionFailedEventAttributes)
+;
+                        }
+                        GeneratedField::WorkflowExecutionTimedOutEventAttributes => {
+                            if attributes__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("workflowExecutionTimedOutEventAttributes"));
+                            }
+                            attributes__ = map_.next_value::<::std::option::Option<_>>()?.map(history_event::Attributes::WorkflowExecutionTimedOutEventAttributes)
+;
+                        }
+                        GeneratedField::WorkflowTaskScheduledEventAttributes => {
+                            if attributes__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("workflowTaskScheduledEventAttributes"));
+                            }
+                            attributes__ = map_.next_value::<::std::option::Option<_>>()?.map(history_event::Attributes::WorkflowTaskScheduledEventAttributes)
+;
+                        }
+                        GeneratedField::WorkflowTaskStartedEventAttributes => {
+                            if attributes__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("workflowTaskStartedEventAttributes"));
+                            }
+                            attributes__ = map_.next_value::<::std::option::Option<_>>()?.map(history_event::Attributes::WorkflowTaskStartedEventAttributes)
+;
+                        }
+                        GeneratedField::WorkflowTaskCompletedEventAttributes => {
+                            if attributes__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("workflowTaskCompletedEventAttributes"));
+                            }
+                            attributes__ = map_.next_value::<::std::option::Option<_>>()?.map(history_event::Attributes::WorkflowTaskCompletedEventAttributes)
+;
+                        }
+                        GeneratedField::WorkflowTaskTimedOutEventAttributes => {
+                            if attributes__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("workflowTaskTimedOutEventAttributes"));
+                            }
+                            attributes__ = map_.next_value::<::std::option::Option<_>>()?.map(history_event::Attributes::WorkflowTaskTimedOutEventAttributes)
+;
+                        }
+                        GeneratedField::WorkflowTaskFailedEventAttributes => {
+                            if attributes__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("workflowTaskFailedEventAttributes"));
+                            }
+                            attributes__ = map_.next_value::<::std::option::Option<_>>()?.map(history_event::Attributes::WorkflowTaskFailedEventAttributes)
+;
+                        }
+                        GeneratedField::ActivityTaskScheduledEventAttributes => {
+                            if attributes__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("activityTaskScheduledEventAttributes"));
+                            }
+                            attributes__ = map_.next_value::<::std::option::Option<_>>()?.map(history_event::Attributes::ActivityTaskScheduledEventAttributes)
+;
+                        }
+                        GeneratedField::ActivityTaskStartedEventAttributes => {
+                            if attributes__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("activityTaskStartedEventAttributes"));
+                            }
+                            attributes__ = map_.next_value::<::std::option::Option<_>>()?.map(history_event::Attributes::ActivityTaskStartedEventAttributes)
+;
+                        }
+                        GeneratedField::ActivityTaskCompletedEventAttributes => {
+                            if attributes__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("activityTaskCompletedEventAttributes"));
+                            }
+                            attributes__ = map_.next_value::<::std::option::Option<_>>()?.map(history_event::Attributes::ActivityTaskCompletedEventAttributes)
+;
+                        }
+                        GeneratedField::ActivityTaskFailedEventAttributes => {
+                            if attributes__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("activityTaskFailedEventAttributes"));
+                            }
+                            attributes__ = map_.next_value::<::std::option::Option<_>>()?.map(history_event::Attributes::ActivityTaskFailedEventAttributes)
+;
+                        }
+                        GeneratedField::ActivityTaskTimedOutEventAttributes => {
+                            if attributes__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("activityTaskTimedOutEventAttributes"));
+                            }
+                            attributes__ = map_.next_value::<::std::option::Option<_>>()?.map(history_event::Attributes::ActivityTaskTimedOutEventAttributes)
+;
+                        }
+                        GeneratedField::TimerStartedEventAttributes => {
+                            if attributes__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("timerStartedEventAttributes"));
+                            }
+                            attributes__ = map_.next_value::<::std::option::Option<_>>()?.map(history_event::Attributes::TimerStartedEventAttributes)
+;
+                        }
+                        GeneratedField::TimerFiredEventAttributes => {
+                            if attributes__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("timerFiredEventAttributes"));
+                            }
+                            attributes__ = map_.next_value::<::std::option::Option<_>>()?.map(history_event::Attributes::TimerFiredEventAttributes)
+;
+                        }
+                        GeneratedField::ActivityTaskCancelRequestedEventAttributes => {
+                            if attributes__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("activityTaskCancelRequestedEventAttributes"));
+                            }
+                            attributes__ = map_.next_value::<::std::option::Option<_>>()?.map(history_event::Attributes::ActivityTaskCancelRequestedEventAttributes)
+;
+                        }
+                        GeneratedField::ActivityTaskCanceledEventAttributes => {
+                            if attributes__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("activityTaskCanceledEventAttributes"));
+                            }
+                            attributes__ = map_.next_value::<::std::option::Option<_>>()?.map(history_event::Attributes::ActivityTaskCanceledEventAttributes)
+;
+                        }
+                        GeneratedField::TimerCanceledEventAttributes => {
+                            if attributes__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("timerCanceledEventAttributes"));
+                            }
+                            attributes__ = map_.next_value::<::std::option::Option<_>>()?.map(history_event::Attributes::TimerCanceledEventAttributes)
+;
+                        }
+                        GeneratedField::MarkerRecordedEventAttributes => {
+                            if attributes__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("markerRecordedEventAttributes"));
+                            }
+                            attributes__ = map_.next_value::<::std::option::Option<_>>()?.map(history_event::Attributes::MarkerRecordedEventAttributes)
+;
+                        }
+                        GeneratedField::WorkflowExecutionSignaledEventAttributes => {
+                            if attributes__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("workflowExecutionSignaledEventAttributes"));
+                            }
+                            attributes__ = map_.next_value::<::std::option::Option<_>>()?.map(history_event::Attributes::WorkflowExecutionSignaledEventAttributes)
+;
+                        }
+                        GeneratedField::WorkflowExecutionTerminatedEventAttributes => {
+                            if attributes__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("workflowExecutionTerminatedEventAttributes"));
+                            }
+                            attributes__ = map_.next_value::<::std::option::Option<_>>()?.map(history_event::Attributes::WorkflowExecutionTerminatedEventAttributes)
+;
+                        }
+                        GeneratedField::WorkflowExecutionCancelRequestedEventAttributes => {
+                            if attributes__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("workflowExecutionCancelRequestedEventAttributes"));
+                            }
+                            attributes__ = map_.next_value::<::std::option::Option<_>>()?.map(history_event::Attributes::WorkflowExecutionCancelRequestedEventAttributes)
+;
+                        }
+                        GeneratedField::WorkflowExecutionCanceledEventAttributes => {
+                            if attributes__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("workflowExecutionCanceledEventAttributes"));
+                            }
+                            attributes__ = map_.next_value::<::std::option::Option<_>>()?.map(history_event::Attributes::WorkflowExecutionCanceledEventAttributes)
+;
+                        }
+                        GeneratedField::RequestCancelExternalWorkflowExecutionInitiatedEventAttributes => {
+                            if attributes__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("requestCancelExternalWorkflowExecutionInitiatedEventAttributes"));
+                            }
+                            attributes__ = map_.next_value::<::std::option::Option<_>>()?.map(history_event::Attributes::RequestCancelExternalWorkflowExecutionInitiatedEventAttributes)
+;
+                        }
+                        GeneratedField::RequestCancelExternalWorkflowExecutionFailedEventAttributes => {
+                            if attributes__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("requestCancelExternalWorkflowExecutionFailedEventAttributes"));
+                            }
+                            attributes__ = map_.next_value::<::std::option::Option<_>>()?.map(history_event::Attributes::RequestCancelExternalWorkflowExecutionFailedEventAttributes)
+;
+                        }
+                        GeneratedField::ExternalWorkflowExecutionCancelRequestedEventAttributes => {
+                            if attributes__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("externalWorkflowExecutionCancelRequestedEventAttributes"));
+                            }
+                            attributes__ = map_.next_value::<::std::option::Option<_>>()?.map(history_event::Attributes::ExternalWorkflowExecutionCancelRequestedEventAttributes)
+;
+                        }
+                        GeneratedField::WorkflowExecutionContinuedAsNewEventAttributes => {
+                            if attributes__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("workflowExecutionContinuedAsNewEventAttributes"));
+                            }
+                            attributes__ = map_.next_value::<::std::option::Option<_>>()?.map(history_event::Attributes::WorkflowExecutionContinuedAsNewEventAttributes)
+;
+                        }
+                        GeneratedField::StartChildWorkflowExecutionInitiatedEventAttributes => {
+                            if attributes__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("startChildWorkflowExecutionInitiatedEventAttributes"));
+                            }
+                            attributes__ = map_.next_value::<::std::option::Option<_>>()?.map(history_event::Attributes::StartChildWorkflowExecutionInitiatedEventAttributes)
+;
+                        }
+                        GeneratedField::StartChildWorkflowExecutionFailedEventAttributes => {
+                            if attributes__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("startChildWorkflowExecutionFailedEventAttributes"));
+                            }
+                            attributes__ = map_.next_value::<::std::option::Option<_>>()?.map(history_event::Attributes::StartChildWorkflowExecutionFailedEventAttributes)
+;
+                        }
+                        GeneratedField::ChildWorkflowExecutionStartedEventAttributes => {
+                            if attributes__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("childWorkflowExecutionStartedEventAttributes"));
+                            }
+                            attributes__ = map_.next_value::<::std::option::Option<_>>()?.map(history_event::Attributes::ChildWorkflowExecutionStartedEventAttributes)
+;
+                        }
+                        GeneratedField::ChildWorkflowExecutionCompletedEventAttributes => {
+                            if attributes__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("childWorkflowExecutionCompletedEventAttributes"));
+                            }
+                            attributes__ = map_.next_value::<::std::option::Option<_>>()?.map(history_event::Attributes::ChildWorkflowExecutionCompletedEventAttributes)
+;
+                        }
+                        GeneratedField::ChildWorkflowExecutionFailedEventAttributes => {
+                            if attributes__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("childWorkflowExecutionFailedEventAttributes"));
+                            }
+                            attributes__ = map_.next_value::<::std::option::Option<_>>()?.map(history_event::Attributes::ChildWorkflowExecutionFailedEventAttributes)
+;
+                        }
+                        GeneratedField::ChildWorkflowExecutionCanceledEventAttributes => {
+                            if attributes__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("childWorkflowExecutionCanceledEventAttributes"));
+                            }
+                            attributes__ = map_.next_value::<::std::option::Option<_>>()?.map(history_event::Attributes::ChildWorkflowExecutionCanceledEventAttributes)
+;
+                        }
+                        GeneratedField::ChildWorkflowExecutionTimedOutEventAttributes => {
+                            if attributes__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("childWorkflowExecutionTimedOutEventAttributes"));
+                            }
+                            attributes__ = map_.next_value::<::std::option::Option<_>>()?.map(history_event::Attributes::ChildWorkflowExecutionTimedOutEventAttributes)
+;
+                        }
+                        GeneratedField::ChildWorkflowExecutionTerminatedEventAttributes => {
+                            if attributes__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("childWorkflowExecutionTerminatedEventAttributes"));
+                            }
+                            attributes__ = map_.next_value::<::std::option::Option<_>>()?.map(history_event::Attributes::ChildWorkflowExecutionTerminatedEventAttributes)
+;
+                        }
+                        GeneratedField::SignalExternalWorkflowExecutionInitiatedEventAttributes => {
+                            if attributes__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("signalExternalWorkflowExecutionInitiatedEventAttributes"));
+                            }
+                            attributes__ = map_.next_value::<::std::option::Option<_>>()?.map(history_event::Attributes::SignalExternalWorkflowExecutionInitiatedEventAttributes)
+;
+                        }
+                        GeneratedField::SignalExternalWorkflowExecutionFailedEventAttributes => {
+                            if attributes__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("signalExternalWorkflowExecutionFailedEventAttributes"));
+                            }
+                            attributes__ = map_.next_value::<::std::option::Option<_>>()?.map(history_event::Attributes::SignalExternalWorkflowExecutionFailedEventAttributes)
+;
+                        }
+                        GeneratedField::ExternalWorkflowExecutionSignaledEventAttributes => {
+                            if attributes__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("externalWorkflowExecutionSignaledEventAttributes"));
+                            }
+                            attributes__ = map_.next_value::<::std::option::Option<_>>()?.map(history_event::Attributes::ExternalWorkflowExecutionSignaledEventAttributes)
+;
+                        }
+                        GeneratedField::UpsertWorkflowSearchAttributesEventAttributes => {
+                            if attributes__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("upsertWorkflowSearchAttributesEventAttributes"));
+                            }
+                            attributes__ = map_.next_value::<::std::option::Option<_>>()?.map(history_event::Attributes::UpsertWorkflowSearchAttributesEventAttributes)
+;
+                        }
+                        GeneratedField::WorkflowExecutionUpdateAcceptedEventAttributes => {
+                            if attributes__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("workflowExecutionUpdateAcceptedEventAttributes"));
+                            }
+                            attributes__ = map_.next_value::<::std::option::Option<_>>()?.map(history_event::Attributes::WorkflowExecutionUpdateAcceptedEventAttributes)
+;
+                        }
+                        GeneratedField::WorkflowExecutionUpdateRejectedEventAttributes => {
+                            if attributes__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("workflowExecutionUpdateRejectedEventAttributes"));
+                            }
+                            attributes__ = map_.next_value::<::std::option::Option<_>>()?.map(history_event::Attributes::WorkflowExecutionUpdateRejectedEventAttributes)
+;
+                        }
+                        GeneratedField::WorkflowExecutionUpdateCompletedEventAttributes => {
+                            if attributes__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("workflowExecutionUpdateCompletedEventAttributes"));
+                            }
+                            attributes__ = map_.next_value::<::std::option::Option<_>>()?.map(history_event::Attributes::WorkflowExecutionUpdateCompletedEventAttributes)
+;
+                        }
+                        GeneratedField::WorkflowPropertiesModifiedExternallyEventAttributes => {
+                            if attributes__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("workflowPropertiesModifiedExternallyEventAttributes"));
+                            }
+                            attributes__ = map_.next_value::<::std::option::Option<_>>()?.map(history_event::Attributes::WorkflowPropertiesModifiedExternallyEventAttributes)
+;
+                        }
+                        GeneratedField::ActivityPropertiesModifiedExternallyEventAttributes => {
+                            if attributes__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("activityPropertiesModifiedExternallyEventAttributes"));
+                            }
+                            attributes__ = map_.next_value::<::std::option::Option<_>>()?.map(history_event::Attributes::ActivityPropertiesModifiedExternallyEventAttributes)
+;
+                        }
+                        GeneratedField::WorkflowPropertiesModifiedEventAttributes => {
+                            if attributes__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("workflowPropertiesModifiedEventAttributes"));
+                            }
+                            attributes__ = map_.next_value::<::std::option::Option<_>>()?.map(history_event::Attributes::WorkflowPropertiesModifiedEventAttributes)
+;
+                        }
+                        GeneratedField::WorkflowExecutionUpdateAdmittedEventAttributes => {
+                            if attributes__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("workflowExecutionUpdateAdmittedEventAttributes"));
+                            }
+                            attributes__ = map_.next_value::<::std::option::Option<_>>()?.map(history_event::Attributes::WorkflowExecutionUpdateAdmittedEventAttributes)
+;
+                        }
+                        GeneratedField::NexusOperationScheduledEventAttributes => {
+                            if attributes__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("nexusOperationScheduledEventAttributes"));
+                            }
+                            attributes__ = map_.next_value::<::std::option::Option<_>>()?.map(history_event::Attributes::NexusOperationScheduledEventAttributes)
+;
+                        }
+                        GeneratedField::NexusOperationStartedEventAttributes => {
+                            if attributes__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("nexusOperationStartedEventAttributes"));
+                            }
+                            attributes__ = map_.next_value::<::std::option::Option<_>>()?.map(history_event::Attributes::NexusOperationStartedEventAttributes)
+;
+                        }
+                        GeneratedField::NexusOperationCompletedEventAttributes => {
+                            if attributes__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("nexusOperationCompletedEventAttributes"));
+                            }
+                            attributes__ = map_.next_value::<::std::option::Option<_>>()?.map(history_event::Attributes::NexusOperationCompletedEventAttributes)
+;
+                        }
+                        GeneratedField::NexusOperationFailedEventAttributes => {
+                            if attributes__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("nexusOperationFailedEventAttributes"));
+                            }
+                            attributes__ = map_.next_value::<::std::option::Option<_>>()?.map(history_event::Attributes::NexusOperationFailedEventAttributes)
+;
+                        }
+                        GeneratedField::NexusOperationCanceledEventAttributes => {
+                            if attributes__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("nexusOperationCanceledEventAttributes"));
+                            }
+                            attributes__ = map_.next_value::<::std::option::Option<_>>()?.map(history_event::Attributes::NexusOperationCanceledEventAttributes)
+;
+                        }
+                        GeneratedField::NexusOperationTimedOutEventAttributes => {
+                            if attributes__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("nexusOperationTimedOutEventAttributes"));
+                            }
+                            attributes__ = map_.next_value::<::std::option::Option<_>>()?.map(history_event::Attributes::NexusOperationTimedOutEventAttributes)
+;
+                        }
+                        GeneratedField::NexusOperationCancelRequestedEventAttributes => {
+                            if attributes__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("nexusOperationCancelRequestedEventAttributes"));
+                            }
+                            attributes__ = map_.next_value::<::std::option::Option<_>>()?.map(history_event::Attributes::NexusOperationCancelRequestedEventAttributes)
+;
+                        }
+                        GeneratedField::WorkflowExecutionOptionsUpdatedEventAttributes => {
+                            if attributes__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("workflowExecutionOptionsUpdatedEventAttributes"));
+                            }
+                            attributes__ = map_.next_value::<::std::option::Option<_>>()?.map(history_event::Attributes::WorkflowExecutionOptionsUpdatedEventAttributes)
+;
+                        }
+                        GeneratedField::NexusOperationCancelRequestCompletedEventAttributes => {
+                            if attributes__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("nexusOperationCancelRequestCompletedEventAttributes"));
+                            }
+                            attributes__ = map_.next_value::<::std::option::Option<_>>()?.map(history_event::Attributes::NexusOperationCancelRequestCompletedEventAttributes)
+;
+                        }
+                        GeneratedField::NexusOperationCancelRequestFailedEventAttributes => {
+                            if attributes__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("nexusOperationCancelRequestFailedEventAttributes"));
+                            }
+                            attributes__ = map_.next_value::<::std::option::Option<_>>()?.map(history_event::Attributes::NexusOperationCancelRequestFailedEventAttributes)
+;
+                        }
+                        GeneratedField::WorkflowExecutionPausedEventAttributes => {
+                            if attributes__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("workflowExecutionPausedEventAttributes"));
+                            }
+                            attributes__ = map_.next_value::<::std::option::Option<_>>()?.map(history_event::Attributes::WorkflowExecutionPausedEventAttributes)
+;
+                        }
+                        GeneratedField::WorkflowExecutionUnpausedEventAttributes => {
+                            if attributes__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("workflowExecutionUnpausedEventAttributes"));
+                            }
+                            attributes__ = map_.next_value::<::std::option::Option<_>>()?.map(history_event::Attributes::WorkflowExecutionUnpausedEventAttributes)
+;
+                        }
+                        GeneratedField::WorkflowExecutionTimeSkippingTransitionedEventAttributes => {
+                            if attributes__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("workflowExecutionTimeSkippingTransitionedEventAttributes"));
+                            }
+                            attributes__ = map_.next_value::<::std::option::Option<_>>()?.map(history_event::Attributes::WorkflowExecutionTimeSkippingTransitionedEventAttributes)
+;
+                        }
+                    }
+                }
+                Ok(HistoryEvent {
+                    event_id: event_id__.unwrap_or_default(),
+                    event_time: event_time__,
+                    event_type: event_type__.unwrap_or_default(),
+                    version: version__.unwrap_or_default(),
+                    task_id: task_id__.unwrap_or_default(),
+                    worker_may_ignore: worker_may_ignore__.unwrap_or_default(),
+                    user_metadata: user_metadata__,
+                    links: links__.unwrap_or_default(),
+                    principal: principal__,
+                    attributes: attributes__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("temporal.api.history.v1.HistoryEvent", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for MarkerRecordedEventAttributes {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.marker_name.is_empty() {
+            len += 1;
+        }
+        if !self.details.is_empty() {
+            len += 1;
+        }
+        if self.workflow_task_completed_event_id != 0 {
+            len += 1;
+        }
+        if self.header.is_some() {
+            len += 1;
+        }
+        if self.failure.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("temporal.api.history.v1.MarkerRecordedEventAttributes", len)?;
+        if !self.marker_name.is_empty() {
+            struct_ser.serialize_field("markerName", &self.marker_name)?;
+        }
+        if !self.details.is_empty() {
+            struct_ser.serialize_field("details", &self.details)?;
+        }
+        if self.workflow_task_completed_event_id != 0 {
+            #[allow(clippy::needless_borrow)]
+            #[allow(clippy::needless_borrows_for_generic_args)]
+            struct_ser.serialize_field("workflowTaskCompletedEventId", ToString::to_string(&self.workflow_task_completed_event_id).as_str())?;
+        }
+        if let Some(v) = self.header.as_ref() {
+            struct_ser.serialize_field("header", v)?;
+        }
+        if let Some(v) = self.failure.as_ref() {
+            struct_ser.serialize_field("failure", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for MarkerRecordedEventAttributes {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "marker_name",
+            "markerName",
+            "details",
+            "workflow_task_completed_event_id",
+            "workflowTaskCompletedEventId",
+            "header",
+            "failure",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            MarkerName,
+            Details,
+            WorkflowTaskCompletedEventId,
+            Header,
+            Failure,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "markerName" | "marker_name" => Ok(GeneratedField::MarkerName),
+                            "details" => Ok(GeneratedField::Details),
+                            "workflowTaskCompletedEventId" | "workflow_task_completed_event_id" => Ok(GeneratedField::WorkflowTaskCompletedEventId),
+                            "header" => Ok(GeneratedField::Header),
+                            "failure" => Ok(GeneratedField::Failure),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = MarkerRecordedEventAttributes;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct temporal.api.history.v1.MarkerRecordedEventAttributes")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<MarkerRecordedEventAttributes, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut marker_name__ = None;
+                let mut details__ = None;
+                let mut workflow_task_completed_event_id__ = None;
+                let mut header__ = None;
+                let mut failure__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::MarkerName => {
+                            if marker_name__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("markerName"));
+                            }
+                            marker_name__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::Details => {
+                            if details__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("details"));
+                            }
+                            details__ = Some(
+                                map_.next_value::<std::collections::HashMap<_, _>>()?
+                            );
+                        }
+                        GeneratedField::WorkflowTaskCompletedEventId => {
+                            if workflow_task_completed_event_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("workflowTaskCompletedEventId"));
+                            }
+                            workflow_task_completed_event_id__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::Header => {
+                            if header__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("header"));
+                            }
+                            header__ = map_.next_value()?;
+                        }
+                        GeneratedField::Failure => {
+                            if failure__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("failure"));
+                            }
+                            failure__ = map_.next_value()?;
+                        }
+                    }
+                }
+                Ok(MarkerRecordedEventAttributes {
+                    marker_name: marker_name__.unwrap_or_default(),
+                    details: details__.unwrap_or_default(),
+                    workflow_task_completed_event_id: workflow_task_completed_event_id__.unwrap_or_default(),
+                    header: header__,
+                    failure: failure__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("temporal.api.history.v1.MarkerRecordedEventAttributes", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for NexusOperationCancelRequestCompletedEventAttributes {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.requested_event_id != 0 {
+            len += 1;
+        }
+        if self.workflow_task_completed_event_id != 0 {
+            len += 1;
+        }
+        if self.scheduled_event_id != 0 {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("temporal.api.history.v1.NexusOperationCancelRequestCompletedEventAttributes", len)?;
+        if self.requested_event_id != 0 {
+            #[allow(clippy::needless_borrow)]
+            #[allow(clippy::needless_borrows_for_generic_args)]
+            struct_ser.serialize_field("requestedEventId", ToString::to_string(&self.requested_event_id).as_str())?;
+        }
+        if self.workflow_task_completed_event_id != 0 {
+            #[allow(clippy::needless_borrow)]
+            #[allow(clippy::needless_borrows_for_generic_args)]
+            struct_ser.serialize_field("workflowTaskCompletedEventId", ToString::to_string(&self.workflow_task_completed_event_id).as_str())?;
+        }
+        if self.scheduled_event_id != 0 {
+            #[allow(clippy::needless_borrow)]
+            #[allow(clippy::needless_borrows_for_generic_args)]
+            struct_ser.serialize_field("scheduledEventId", ToString::to_string(&self.scheduled_event_id).as_str())?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for NexusOperationCancelRequestCompletedEventAttributes {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "requested_event_id",
+            "requestedEventId",
+            "workflow_task_completed_event_id",
+            "workflowTaskCompletedEventId",
+            "scheduled_event_id",
+            "scheduledEventId",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            RequestedEventId,
+            WorkflowTaskCompletedEventId,
+            ScheduledEventId,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "requestedEventId" | "requested_event_id" => Ok(GeneratedField::RequestedEventId),
+                            "workflowTaskCompletedEventId" | "workflow_task_completed_event_id" => Ok(GeneratedField::WorkflowTaskCompletedEventId),
+                            "scheduledEventId" | "scheduled_event_id" => Ok(GeneratedField::ScheduledEventId),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = NexusOperationCancelRequestCompletedEventAttributes;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct temporal.api.history.v1.NexusOperationCancelRequestCompletedEventAttributes")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<NexusOperationCancelRequestCompletedEventAttributes, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut requested_event_id__ = None;
+                let mut workflow_task_completed_event_id__ = None;
+                let mut scheduled_event_id__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::RequestedEventId => {
+                            if requested_event_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("requestedEventId"));
+                            }
+                            requested_event_id__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::WorkflowTaskCompletedEventId => {
+                            if workflow_task_completed_event_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("workflowTaskCompletedEventId"));
+                            }
+                            workflow_task_completed_event_id__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::ScheduledEventId => {
+                            if scheduled_event_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("scheduledEventId"));
+                            }
+                            scheduled_event_id__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                    }
+                }
+                Ok(NexusOperationCancelRequestCompletedEventAttributes {
+                    requested_event_id: requested_event_id__.unwrap_or_default(),
+                    workflow_task_completed_event_id: workflow_task_completed_event_id__.unwrap_or_default(),
+                    scheduled_event_id: scheduled_event_id__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("temporal.api.history.v1.NexusOperationCancelRequestCompletedEventAttributes", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for NexusOperationCancelRequestFailedEventAttributes {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.requested_event_id != 0 {
+            len += 1;
+        }
+        if self.workflow_task_completed_event_id != 0 {
+            len += 1;
+        }
+        if self.failure.is_some() {
+            len += 1;
+        }
+        if self.scheduled_event_id != 0 {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("temporal.api.history.v1.NexusOperationCancelRequestFailedEventAttributes", len)?;
+        if self.requested_event_id != 0 {
+            #[allow(clippy::needless_borrow)]
+            #[allow(clippy::needless_borrows_for_generic_args)]
+            struct_ser.serialize_field("requestedEventId", ToString::to_string(&self.requested_event_id).as_str())?;
+        }
+        if self.workflow_task_completed_event_id != 0 {
+            #[allow(clippy::needless_borrow)]
+            #[allow(clippy::needless_borrows_for_generic_args)]
+            struct_ser.serialize_field("workflowTaskCompletedEventId", ToString::to_string(&self.workflow_task_completed_event_id).as_str())?;
+        }
+        if let Some(v) = self.failure.as_ref() {
+            struct_ser.serialize_field("failure", v)?;
+        }
+        if self.scheduled_event_id != 0 {
+            #[allow(clippy::needless_borrow)]
+            #[allow(clippy::needless_borrows_for_generic_args)]
+            struct_ser.serialize_field("scheduledEventId", ToString::to_string(&self.scheduled_event_id).as_str())?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for NexusOperationCancelRequestFailedEventAttributes {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "requested_event_id",
+            "requestedEventId",
+            "workflow_task_completed_event_id",
+            "workflowTaskCompletedEventId",
+            "failure",
+            "scheduled_event_id",
+            "scheduledEventId",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            RequestedEventId,
+            WorkflowTaskCompletedEventId,
+            Failure,
+            ScheduledEventId,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "requestedEventId" | "requested_event_id" => Ok(GeneratedField::RequestedEventId),
+                            "workflowTaskCompletedEventId" | "workflow_task_completed_event_id" => Ok(GeneratedField::WorkflowTaskCompletedEventId),
+                            "failure" => Ok(GeneratedField::Failure),
+                            "scheduledEventId" | "scheduled_event_id" => Ok(GeneratedField::ScheduledEventId),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = NexusOperationCancelRequestFailedEventAttributes;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct temporal.api.history.v1.NexusOperationCancelRequestFailedEventAttributes")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<NexusOperationCancelRequestFailedEventAttributes, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut requested_event_id__ = None;
+                let mut workflow_task_completed_event_id__ = None;
+                let mut failure__ = None;
+                let mut scheduled_event_id__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::RequestedEventId => {
+                            if requested_event_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("requestedEventId"));
+                            }
+                            requested_event_id__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::WorkflowTaskCompletedEventId => {
+                            if workflow_task_completed_event_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("workflowTaskCompletedEventId"));
+                            }
+                            workflow_task_completed_event_id__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::Failure => {
+                            if failure__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("failure"));
+                            }
+                            failure__ = map_.next_value()?;
+                        }
+                        GeneratedField::ScheduledEventId => {
+                            if scheduled_event_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("scheduledEventId"));
+                            }
+                            scheduled_event_id__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                    }
+                }
+                Ok(NexusOperationCancelRequestFailedEventAttributes {
+                    requested_event_id: requested_event_id__.unwrap_or_default(),
+                    workflow_task_completed_event_id: workflow_task_completed_event_id__.unwrap_or_default(),
+                    failure: failure__,
+                    scheduled_event_id: scheduled_event_id__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("temporal.api.history.v1.NexusOperationCancelRequestFailedEventAttributes", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for NexusOperationCancelRequestedEventAttributes {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.scheduled_event_id != 0 {
+            len += 1;
+        }
+        if self.workflow_task_completed_event_id != 0 {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("temporal.api.history.v1.NexusOperationCancelRequestedEventAttributes", len)?;
+        if self.scheduled_event_id != 0 {
+            #[allow(clippy::needless_borrow)]
+            #[allow(clippy::needless_borrows_for_generic_args)]
+            struct_ser.serialize_field("scheduledEventId", ToString::to_string(&self.scheduled_event_id).as_str())?;
+        }
+        if self.workflow_task_completed_event_id != 0 {
+            #[allow(clippy::needless_borrow)]
+            #[allow(clippy::needless_borrows_for_generic_args)]
+            struct_ser.serialize_field("workflowTaskCompletedEventId", ToString::to_string(&self.workflow_task_completed_event_id).as_str())?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for NexusOperationCancelRequestedEventAttributes {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "scheduled_event_id",
+            "scheduledEventId",
+            "workflow_task_completed_event_id",
+            "workflowTaskCompletedEventId",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            ScheduledEventId,
+            WorkflowTaskCompletedEventId,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "scheduledEventId" | "scheduled_event_id" => Ok(GeneratedField::ScheduledEventId),
+                            "workflowTaskCompletedEventId" | "workflow_task_completed_event_id" => Ok(GeneratedField::WorkflowTaskCompletedEventId),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = NexusOperationCancelRequestedEventAttributes;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct temporal.api.history.v1.NexusOperationCancelRequestedEventAttributes")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<NexusOperationCancelRequestedEventAttributes, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut scheduled_event_id__ = None;
+                let mut workflow_task_completed_event_id__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::ScheduledEventId => {
+                            if scheduled_event_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("scheduledEventId"));
+                            }
+                            scheduled_event_id__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::WorkflowTaskCompletedEventId => {
+                            if workflow_task_completed_event_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("workflowTaskCompletedEventId"));
+                            }
+                            workflow_task_completed_event_id__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                    }
+                }
+                Ok(NexusOperationCancelRequestedEventAttributes {
+                    scheduled_event_id: scheduled_event_id__.unwrap_or_default(),
+                    workflow_task_completed_event_id: workflow_task_completed_event_id__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("temporal.api.history.v1.NexusOperationCancelRequestedEventAttributes", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for NexusOperationCanceledEventAttributes {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.scheduled_event_id != 0 {
+            len += 1;
+        }
+        if self.failure.is_some() {
+            len += 1;
+        }
+        if !self.request_id.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("temporal.api.history.v1.NexusOperationCanceledEventAttributes", len)?;
+        if self.scheduled_event_id != 0 {
+            #[allow(clippy::needless_borrow)]
+            #[allow(clippy::needless_borrows_for_generic_args)]
+            struct_ser.serialize_field("scheduledEventId", ToString::to_string(&self.scheduled_event_id).as_str())?;
+        }
+        if let Some(v) = self.failure.as_ref() {
+            struct_ser.serialize_field("failure", v)?;
+        }
+        if !self.request_id.is_empty() {
+            struct_ser.serialize_field("requestId", &self.request_id)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for NexusOperationCanceledEventAttributes {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "scheduled_event_id",
+            "scheduledEventId",
+            "failure",
+            "request_id",
+            "requestId",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            ScheduledEventId,
+            Failure,
+            RequestId,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "scheduledEventId" | "scheduled_event_id" => Ok(GeneratedField::ScheduledEventId),
+                            "failure" => Ok(GeneratedField::Failure),
+                            "requestId" | "request_id" => Ok(GeneratedField::RequestId),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = NexusOperationCanceledEventAttributes;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct temporal.api.history.v1.NexusOperationCanceledEventAttributes")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<NexusOperationCanceledEventAttributes, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut scheduled_event_id__ = None;
+                let mut failure__ = None;
+                let mut request_id__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::ScheduledEventId => {
+                            if scheduled_event_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("scheduledEventId"));
+                            }
+                            scheduled_event_id__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::Failure => {
+                            if failure__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("failure"));
+                            }
+                            failure__ = map_.next_value()?;
+                        }
+                        GeneratedField::RequestId => {
+                            if request_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("requestId"));
+                            }
+                            request_id__ = Some(map_.next_value()?);
+                        }
+                    }
+                }
+                Ok(NexusOperationCanceledEventAttributes {
+                    scheduled_event_id: scheduled_event_id__.unwrap_or_default(),
+                    failure: failure__,
+                    request_id: request_id__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("temporal.api.history.v1.NexusOperationCanceledEventAttributes", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for NexusOperationCompletedEventAttributes {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.scheduled_event_id != 0 {
+            len += 1;
+        }
+        if self.result.is_some() {
+            len += 1;
+        }
+        if !self.request_id.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("temporal.api.history.v1.NexusOperationCompletedEventAttributes", len)?;
+        if self.scheduled_event_id != 0 {
+            #[allow(clippy::needless_borrow)]
+            #[allow(clippy::needless_borrows_for_generic_args)]
+            struct_ser.serialize_field("scheduledEventId", ToString::to_string(&self.scheduled_event_id).as_str())?;
+        }
+        if let Some(v) = self.result.as_ref() {
+            struct_ser.serialize_field("result", v)?;
+        }
+        if !self.request_id.is_empty() {
+            struct_ser.serialize_field("requestId", &self.request_id)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for NexusOperationCompletedEventAttributes {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "scheduled_event_id",
+            "scheduledEventId",
+            "result",
+            "request_id",
+            "requestId",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            ScheduledEventId,
+            Result,
+            RequestId,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "scheduledEventId" | "scheduled_event_id" => Ok(GeneratedField::ScheduledEventId),
+                            "result" => Ok(GeneratedField::Result),
+                            "requestId" | "request_id" => Ok(GeneratedField::RequestId),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = NexusOperationCompletedEventAttributes;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct temporal.api.history.v1.NexusOperationCompletedEventAttributes")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<NexusOperationCompletedEventAttributes, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut scheduled_event_id__ = None;
+                let mut result__ = None;
+                let mut request_id__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::ScheduledEventId => {
+                            if scheduled_event_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("scheduledEventId"));
+                            }
+                            scheduled_event_id__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::Result => {
+                            if result__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("result"));
+                            }
+                            result__ = map_.next_value()?;
+                        }
+                        GeneratedField::RequestId => {
+                            if request_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("requestId"));
+                            }
+                            request_id__ = Some(map_.next_value()?);
+                        }
+                    }
+                }
+                Ok(NexusOperationCompletedEventAttributes {
+                    scheduled_event_id: scheduled_event_id__.unwrap_or_default(),
+                    result: result__,
+                    request_id: request_id__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("temporal.api.history.v1.NexusOperationCompletedEventAttributes", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for NexusOperationFailedEventAttributes {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.scheduled_event_id != 0 {
+            len += 1;
+        }
+        if self.failure.is_some() {
+            len += 1;
+        }
+        if !self.request_id.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("temporal.api.history.v1.NexusOperationFailedEventAttributes", len)?;
+        if self.scheduled_event_id != 0 {
+            #[allow(clippy::needless_borrow)]
+            #[allow(clippy::needless_borrows_for_generic_args)]
+            struct_ser.serialize_field("scheduledEventId", ToString::to_string(&self.scheduled_event_id).as_str())?;
+        }
+        if let Some(v) = self.failure.as_ref() {
+            struct_ser.serialize_field("failure", v)?;
+        }
+        if !self.request_id.is_empty() {
+            struct_ser.serialize_field("requestId", &self.request_id)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for NexusOperationFailedEventAttributes {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "scheduled_event_id",
+            "scheduledEventId",
+            "failure",
+            "request_id",
+            "requestId",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            ScheduledEventId,
+            Failure,
+            RequestId,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "scheduledEventId" | "scheduled_event_id" => Ok(GeneratedField::ScheduledEventId),
+                            "failure" => Ok(GeneratedField::Failure),
+                            "requestId" | "request_id" => Ok(GeneratedField::RequestId),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = NexusOperationFailedEventAttributes;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct temporal.api.history.v1.NexusOperationFailedEventAttributes")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<NexusOperationFailedEventAttributes, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut scheduled_event_id__ = None;
+                let mut failure__ = None;
+                let mut request_id__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::ScheduledEventId => {
+                            if scheduled_event_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("scheduledEventId"));
+                            }
+                            scheduled_event_id__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::Failure => {
+                            if failure__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("failure"));
+                            }
+                            failure__ = map_.next_value()?;
+                        }
+                        GeneratedField::RequestId => {
+                            if request_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("requestId"));
+                            }
+                            request_id__ = Some(map_.next_value()?);
+                        }
+                    }
+                }
+                Ok(NexusOperationFailedEventAttributes {
+                    scheduled_event_id: scheduled_event_id__.unwrap_or_default(),
+                    failure: failure__,
+                    request_id: request_id__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("temporal.api.history.v1.NexusOperationFailedEventAttributes", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for NexusOperationScheduledEventAttributes {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.endpoint.is_empty() {
+            len += 1;
+        }
+        if !self.service.is_empty() {
+            len += 1;
+        }
+        if !self.operation.is_empty() {
+            len += 1;
+        }
+        if self.input.is_some() {
+            len += 1;
+        }
+        if self.schedule_to_close_timeout.is_some() {
+            len += 1;
+        }
+        if !self.nexus_header.is_empty() {
+            len += 1;
+        }
+        if self.workflow_task_completed_event_id != 0 {
+            len += 1;
+        }
+        if !self.request_id.is_empty() {
+            len += 1;
+        }
+        if !self.endpoint_id.is_empty() {
+            len += 1;
+        }
+        if self.schedule_to_start_timeout.is_some() {
+            len += 1;
+        }
+        if self.start_to_close_timeout.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("temporal.api.history.v1.NexusOperationScheduledEventAttributes", len)?;
+        if !self.endpoint.is_empty() {
+            struct_ser.serialize_field("endpoint", &self.endpoint)?;
+        }
+        if !self.service.is_empty() {
+            struct_ser.serialize_field("service", &self.service)?;
+        }
+        if !self.operation.is_empty() {
+            struct_ser.serialize_field("operation", &self.operation)?;
+        }
+        if let Some(v) = self.input.as_ref() {
+            struct_ser.serialize_field("input", v)?;
+        }
+        if let Some(v) = self.schedule_to_close_timeout.as_ref() {
+            struct_ser.serialize_field("scheduleToCloseTimeout", v)?;
+        }
+        if !self.nexus_header.is_empty() {
+            struct_ser.serialize_field("nexusHeader", &self.nexus_header)?;
+        }
+        if self.workflow_task_completed_event_id != 0 {
+            #[allow(clippy::needless_borrow)]
+            #[allow(clippy::needless_borrows_for_generic_args)]
+            struct_ser.serialize_field("workflowTaskCompletedEventId", ToString::to_string(&self.workflow_task_completed_event_id).as_str())?;
+        }
+        if !self.request_id.is_empty() {
+            struct_ser.serialize_field("requestId", &self.request_id)?;
+        }
+        if !self.endpoint_id.is_empty() {
+            struct_ser.serialize_field("endpointId", &self.endpoint_id)?;
+        }
+        if let Some(v) = self.schedule_to_start_timeout.as_ref() {
+            struct_ser.serialize_field("scheduleToStartTimeout", v)?;
+        }
+        if let Some(v) = self.start_to_close_timeout.as_ref() {
+            struct_ser.serialize_field("startToCloseTimeout", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for NexusOperationScheduledEventAttributes {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "endpoint",
+            "service",
+            "operation",
+            "input",
+            "schedule_to_close_timeout",
+            "scheduleToCloseTimeout",
+            "nexus_header",
+            "nexusHeader",
+            "workflow_task_completed_event_id",
+            "workflowTaskCompletedEventId",
+            "request_id",
+            "requestId",
+            "endpoint_id",
+            "endpointId",
+            "schedule_to_start_timeout",
+            "scheduleToStartTimeout",
+            "start_to_close_timeout",
+            "startToCloseTimeout",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Endpoint,
+            Service,
+            Operation,
+            Input,
+            ScheduleToCloseTimeout,
+            NexusHeader,
+            WorkflowTaskCompletedEventId,
+            RequestId,
+            EndpointId,
+            ScheduleToStartTimeout,
+            StartToCloseTimeout,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "endpoint" => Ok(GeneratedField::Endpoint),
+                            "service" => Ok(GeneratedField::Service),
+                            "operation" => Ok(GeneratedField::Operation),
+                            "input" => Ok(GeneratedField::Input),
+                            "scheduleToCloseTimeout" | "schedule_to_close_timeout" => Ok(GeneratedField::ScheduleToCloseTimeout),
+                            "nexusHeader" | "nexus_header" => Ok(GeneratedField::NexusHeader),
+                            "workflowTaskCompletedEventId" | "workflow_task_completed_event_id" => Ok(GeneratedField::WorkflowTaskCompletedEventId),
+                            "requestId" | "request_id" => Ok(GeneratedField::RequestId),
+                            "endpointId" | "endpoint_id" => Ok(GeneratedField::EndpointId),
+                            "scheduleToStartTimeout" | "schedule_to_start_timeout" => Ok(GeneratedField::ScheduleToStartTimeout),
+                            "startToCloseTimeout" | "start_to_close_timeout" => Ok(GeneratedField::StartToCloseTimeout),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = NexusOperationScheduledEventAttributes;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct temporal.api.history.v1.NexusOperationScheduledEventAttributes")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<NexusOperationScheduledEventAttributes, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut endpoint__ = None;
+                let mut service__ = None;
+                let mut operation__ = None;
+                let mut input__ = None;
+                let mut schedule_to_close_timeout__ = None;
+                let mut nexus_header__ = None;
+                let mut workflow_task_completed_event_id__ = None;
+                let mut request_id__ = None;
+                let mut endpoint_id__ = None;
+                let mut schedule_to_start_timeout__ = None;
+                let mut start_to_close_timeout__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Endpoint => {
+                            if endpoint__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("endpoint"));
+                            }
+                            endpoint__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::Service => {
+                            if service__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("service"));
+                            }
+                            service__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::Operation => {
+                            if operation__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("operation"));
+                            }
+                            operation__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::Input => {
+                            if input__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("input"));
+                            }
+                            input__ = map_.next_value()?;
+                        }
+                        GeneratedField::ScheduleToCloseTimeout => {
+                            if schedule_to_close_timeout__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("scheduleToCloseTimeout"));
+                            }
+                            schedule_to_close_timeout__ = map_.next_value()?;
+                        }
+                        GeneratedField::NexusHeader => {
+                            if nexus_header__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("nexusHeader"));
+                            }
+                            nexus_header__ = Some(
+                                map_.next_value::<std::collections::HashMap<_, _>>()?
+                            );
+                        }
+                        GeneratedField::WorkflowTaskCompletedEventId => {
+                            if workflow_task_completed_event_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("workflowTaskCompletedEventId"));
+                            }
+                            workflow_task_completed_event_id__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::RequestId => {
+                            if request_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("requestId"));
+                            }
+                            request_id__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::EndpointId => {
+                            if endpoint_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("endpointId"));
+                            }
+                            endpoint_id__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::ScheduleToStartTimeout => {
+                            if schedule_to_start_timeout__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("scheduleToStartTimeout"));
+                            }
+                            schedule_to_start_timeout__ = map_.next_value()?;
+                        }
+                        GeneratedField::StartToCloseTimeout => {
+                            if start_to_close_timeout__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("startToCloseTimeout"));
+                            }
+                            start_to_close_timeout__ = map_.next_value()?;
+                        }
+                    }
+                }
+                Ok(NexusOperationScheduledEventAttributes {
+                    endpoint: endpoint__.unwrap_or_default(),
+                    service: service__.unwrap_or_default(),
+                    operation: operation__.unwrap_or_default(),
+                    input: input__,
+                    schedule_to_close_timeout: schedule_to_close_timeout__,
+                    nexus_header: nexus_header__.unwrap_or_default(),
+                    workflow_task_completed_event_id: workflow_task_completed_event_id__.unwrap_or_default(),
+                    request_id: request_id__.unwrap_or_default(),
+                    endpoint_id: endpoint_id__.unwrap_or_default(),
+                    schedule_to_start_timeout: schedule_to_start_timeout__,
+                    start_to_close_timeout: start_to_close_timeout__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("temporal.api.history.v1.NexusOperationScheduledEventAttributes", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for NexusOperationStartedEventAttributes {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.scheduled_event_id != 0 {
+            len += 1;
+        }
+        if !self.operation_id.is_empty() {
+            len += 1;
+        }
+        if !self.request_id.is_empty() {
+            len += 1;
+        }
+        if !self.operation_token.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("temporal.api.history.v1.NexusOperationStartedEventAttributes", len)?;
+        if self.scheduled_event_id != 0 {
+            #[allow(clippy::needless_borrow)]
+            #[allow(clippy::needless_borrows_for_generic_args)]
+            struct_ser.serialize_field("scheduledEventId", ToString::to_string(&self.scheduled_event_id).as_str())?;
+        }
+        if !self.operation_id.is_empty() {
+            struct_ser.serialize_field("operationId", &self.operation_id)?;
+        }
+        if !self.request_id.is_empty() {
+            struct_ser.serialize_field("requestId", &self.request_id)?;
+        }
+        if !self.operation_token.is_empty() {
+            struct_ser.serialize_field("operationToken", &self.operation_token)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for NexusOperationStartedEventAttributes {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "scheduled_event_id",
+            "scheduledEventId",
+            "operation_id",
+            "operationId",
+            "request_id",
+            "requestId",
+            "operation_token",
+            "operationToken",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            ScheduledEventId,
+            OperationId,
+            RequestId,
+            OperationToken,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "scheduledEventId" | "scheduled_event_id" => Ok(GeneratedField::ScheduledEventId),
+                            "operationId" | "operation_id" => Ok(GeneratedField::OperationId),
+                            "requestId" | "request_id" => Ok(GeneratedField::RequestId),
+                            "operationToken" | "operation_token" => Ok(GeneratedField::OperationToken),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = NexusOperationStartedEventAttributes;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct temporal.api.history.v1.NexusOperationStartedEventAttributes")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<NexusOperationStartedEventAttributes, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut scheduled_event_id__ = None;
+                let mut operation_id__ = None;
+                let mut request_id__ = None;
+                let mut operation_token__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::ScheduledEventId => {
+                            if scheduled_event_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("scheduledEventId"));
+                            }
+                            scheduled_event_id__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::OperationId => {
+                            if operation_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("operationId"));
+                            }
+                            operation_id__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::RequestId => {
+                            if request_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("requestId"));
+                            }
+                            request_id__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::OperationToken => {
+                            if operation_token__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("operationToken"));
+                            }
+                            operation_token__ = Some(map_.next_value()?);
+                        }
+                    }
+                }
+                Ok(NexusOperationStartedEventAttributes {
+                    scheduled_event_id: scheduled_event_id__.unwrap_or_default(),
+                    operation_id: operation_id__.unwrap_or_default(),
+                    request_id: request_id__.unwrap_or_default(),
+                    operation_token: operation_token__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("temporal.api.history.v1.NexusOperationStartedEventAttributes", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for NexusOperationTimedOutEventAttributes {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.scheduled_event_id != 0 {
+            len += 1;
+        }
+        if self.failure.is_some() {
+            len += 1;
+        }
+        if !self.request_id.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("temporal.api.history.v1.NexusOperationTimedOutEventAttributes", len)?;
+        if self.scheduled_event_id != 0 {
+            #[allow(clippy::needless_borrow)]
+            #[allow(clippy::needless_borrows_for_generic_args)]
+            struct_ser.serialize_field("scheduledEventId", ToString::to_string(&self.scheduled_event_id).as_str())?;
+        }
+        if let Some(v) = self.failure.as_ref() {
+            struct_ser.serialize_field("failure", v)?;
+        }
+        if !self.request_id.is_empty() {
+            struct_ser.serialize_field("requestId", &self.request_id)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for NexusOperationTimedOutEventAttributes {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "scheduled_event_id",
+            "scheduledEventId",
+            "failure",
+            "request_id",
+            "requestId",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            ScheduledEventId,
+            Failure,
+            RequestId,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "scheduledEventId" | "scheduled_event_id" => Ok(GeneratedField::ScheduledEventId),
+                            "failure" => Ok(GeneratedField::Failure),
+                            "requestId" | "request_id" => Ok(GeneratedField::RequestId),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = NexusOperationTimedOutEventAttributes;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct temporal.api.history.v1.NexusOperationTimedOutEventAttributes")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<NexusOperationTimedOutEventAttributes, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut scheduled_event_id__ = None;
+                let mut failure__ = None;
+                let mut request_id__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::ScheduledEventId => {
+                            if scheduled_event_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("scheduledEventId"));
+                            }
+                            scheduled_event_id__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::Failure => {
+                            if failure__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("failure"));
+                            }
+                            failure__ = map_.next_value()?;
+                        }
+                        GeneratedField::RequestId => {
+                            if request_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("requestId"));
+                            }
+                            request_id__ = Some(map_.next_value()?);
+                        }
+                    }
+                }
+                Ok(NexusOperationTimedOutEventAttributes {
+                    scheduled_event_id: scheduled_event_id__.unwrap_or_default(),
+                    failure: failure__,
+                    request_id: request_id__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("temporal.api.history.v1.NexusOperationTimedOutEventAttributes", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for RequestCancelExternalWorkflowExecutionFailedEventAttributes {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.cause != 0 {
+            len += 1;
+        }
+        if self.workflow_task_completed_event_id != 0 {
+            len += 1;
+        }
+        if !self.namespace.is_empty() {
+            len += 1;
+        }
+        if !self.namespace_id.is_empty() {
+            len += 1;
+        }
+        if self.workflow_execution.is_some() {
+            len += 1;
+        }
+        if self.initiated_event_id != 0 {
+            len += 1;
+        }
+        if !self.control.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("temporal.api.history.v1.RequestCancelExternalWorkflowExecutionFailedEventAttributes", len)?;
+        if self.cause != 0 {
+            let v = super::super::enums::v1::CancelExternalWorkflowExecutionFailedCause::try_from(self.cause)
+                .map_err(|_| serde::ser::Error::custom(format!("Invalid variant {}", self.cause)))?;
+            struct_ser.serialize_field("cause", &v)?;
+        }
+        if self.workflow_task_completed_event_id != 0 {
+            #[allow(clippy::needless_borrow)]
+            #[allow(clippy::needless_borrows_for_generic_args)]
+            struct_ser.serialize_field("workflowTaskCompletedEventId", ToString::to_string(&self.workflow_task_completed_event_id).as_str())?;
+        }
+        if !self.namespace.is_empty() {
+            struct_ser.serialize_field("namespace", &self.namespace)?;
+        }
+        if !self.namespace_id.is_empty() {
+            struct_ser.serialize_field("namespaceId", &self.namespace_id)?;
+        }
+        if let Some(v) = self.workflow_execution.as_ref() {
+            struct_ser.serialize_field("workflowExecution", v)?;
+        }
+        if self.initiated_event_id != 0 {
+            #[allow(clippy::needless_borrow)]
+            #[allow(clippy::needless_borrows_for_generic_args)]
+            struct_ser.serialize_field("initiatedEventId", ToString::to_string(&self.initiated_event_id).as_str())?;
+        }
+        if !self.control.is_empty() {
+            struct_ser.serialize_field("control", &self.control)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for RequestCancelExternalWorkflowExecutionFailedEventAttributes {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "cause",
+            "workflow_task_completed_event_id",
+            "workflowTaskCompletedEventId",
+            "namespace",
+            "namespace_id",
+            "namespaceId",
+            "workflow_execution",
+            "workflowExecution",
+            "initiated_event_id",
+            "initiatedEventId",
+            "control",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Cause,
+            WorkflowTaskCompletedEventId,
+            Namespace,
+            NamespaceId,
+            WorkflowExecution,
+            InitiatedEventId,
+            Control,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "cause" => Ok(GeneratedField::Cause),
+                            "workflowTaskCompletedEventId" | "workflow_task_completed_event_id" => Ok(GeneratedField::WorkflowTaskCompletedEventId),
+                            "namespace" => Ok(GeneratedField::Namespace),
+                            "namespaceId" | "namespace_id" => Ok(GeneratedField::NamespaceId),
+                            "workflowExecution" | "workflow_execution" => Ok(GeneratedField::WorkflowExecution),
+                            "initiatedEventId" | "initiated_event_id" => Ok(GeneratedField::InitiatedEventId),
+                            "control" => Ok(GeneratedField::Control),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = RequestCancelExternalWorkflowExecutionFailedEventAttributes;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct temporal.api.history.v1.RequestCancelExternalWorkflowExecutionFailedEventAttributes")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<RequestCancelExternalWorkflowExecutionFailedEventAttributes, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut cause__ = None;
+                let mut workflow_task_completed_event_id__ = None;
+                let mut namespace__ = None;
+                let mut namespace_id__ = None;
+                let mut workflow_execution__ = None;
+                let mut initiated_event_id__ = None;
+                let mut control__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Cause => {
+                            if cause__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("cause"));
+                            }
+                            cause__ = Some(map_.next_value::<super::super::enums::v1::CancelExternalWorkflowExecutionFailedCause>()? as i32);
+                        }
+                        GeneratedField::WorkflowTaskCompletedEventId => {
+                            if workflow_task_completed_event_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("workflowTaskCompletedEventId"));
+                            }
+                            workflow_task_completed_event_id__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::Namespace => {
+                            if namespace__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("namespace"));
+                            }
+                            namespace__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::NamespaceId => {
+                            if namespace_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("namespaceId"));
+                            }
+                            namespace_id__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::WorkflowExecution => {
+                            if workflow_execution__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("workflowExecution"));
+                            }
+                            workflow_execution__ = map_.next_value()?;
+                        }
+                        GeneratedField::InitiatedEventId => {
+                            if initiated_event_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("initiatedEventId"));
+                            }
+                            initiated_event_id__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::Control => {
+                            if control__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("control"));
+                            }
+                            control__ = Some(map_.next_value()?);
+                        }
+                    }
+                }
+                Ok(RequestCancelExternalWorkflowExecutionFailedEventAttributes {
+                    cause: cause__.unwrap_or_default(),
+                    workflow_task_completed_event_id: workflow_task_completed_event_id__.unwrap_or_default(),
+                    namespace: namespace__.unwrap_or_default(),
+                    namespace_id: namespace_id__.unwrap_or_default(),
+                    workflow_execution: workflow_execution__,
+                    initiated_event_id: initiated_event_id__.unwrap_or_default(),
+                    control: control__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("temporal.api.history.v1.RequestCancelExternalWorkflowExecutionFailedEventAttributes", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for RequestCancelExternalWorkflowExecutionInitiatedEventAttributes {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.workflow_task_completed_event_id != 0 {
+            len += 1;
+        }
+        if !self.namespace.is_empty() {
+            len += 1;
+        }
+        if !self.namespace_id.is_empty() {
+            len += 1;
+        }
+        if self.workflow_execution.is_some() {
+            len += 1;
+        }
+        if !self.control.is_empty() {
+            len += 1;
+        }
+        if self.child_workflow_only {
+            len += 1;
+        }
+        if !self.reason.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("temporal.api.history.v1.RequestCancelExternalWorkflowExecutionInitiatedEventAttributes", len)?;
+        if self.workflow_task_completed_event_id != 0 {
+            #[allow(clippy::needless_borrow)]
+            #[allow(clippy::needless_borrows_for_generic_args)]
+            struct_ser.serialize_field("workflowTaskCompletedEventId", ToString::to_string(&self.workflow_task_completed_event_id).as_str())?;
+        }
+        if !self.namespace.is_empty() {
+            struct_ser.serialize_field("namespace", &self.namespace)?;
+        }
+        if !self.namespace_id.is_empty() {
+            struct_ser.serialize_field("namespaceId", &self.namespace_id)?;
+        }
+        if let Some(v) = self.workflow_execution.as_ref() {
+            struct_ser.serialize_field("workflowExecution", v)?;
+        }
+        if !self.control.is_empty() {
+            struct_ser.serialize_field("control", &self.control)?;
+        }
+        if self.child_workflow_only {
+            struct_ser.serialize_field("childWorkflowOnly", &self.child_workflow_only)?;
+        }
+        if !self.reason.is_empty() {
+            struct_ser.serialize_field("reason", &self.reason)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for RequestCancelExternalWorkflowExecutionInitiatedEventAttributes {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "workflow_task_completed_event_id",
+            "workflowTaskCompletedEventId",
+            "namespace",
+            "namespace_id",
+            "namespaceId",
+            "workflow_execution",
+            "workflowExecution",
+            "control",
+            "child_workflow_only",
+            "childWorkflowOnly",
+            "reason",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            WorkflowTaskCompletedEventId,
+            Namespace,
+            NamespaceId,
+            WorkflowExecution,
+            Control,
+            ChildWorkflowOnly,
+            Reason,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "workflowTaskCompletedEventId" | "workflow_task_completed_event_id" => Ok(GeneratedField::WorkflowTaskCompletedEventId),
+                            "namespace" => Ok(GeneratedField::Namespace),
+                            "namespaceId" | "namespace_id" => Ok(GeneratedField::NamespaceId),
+                            "workflowExecution" | "workflow_execution" => Ok(GeneratedField::WorkflowExecution),
+                            "control" => Ok(GeneratedField::Control),
+                            "childWorkflowOnly" | "child_workflow_only" => Ok(GeneratedField::ChildWorkflowOnly),
+                            "reason" => Ok(GeneratedField::Reason),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = RequestCancelExternalWorkflowExecutionInitiatedEventAttributes;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct temporal.api.history.v1.RequestCancelExternalWorkflowExecutionInitiatedEventAttributes")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<RequestCancelExternalWorkflowExecutionInitiatedEventAttributes, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut workflow_task_completed_event_id__ = None;
+                let mut namespace__ = None;
+                let mut namespace_id__ = None;
+                let mut workflow_execution__ = None;
+                let mut control__ = None;
+                let mut child_workflow_only__ = None;
+                let mut reason__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::WorkflowTaskCompletedEventId => {
+                            if workflow_task_completed_event_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("workflowTaskCompletedEventId"));
+                            }
+                            workflow_task_completed_event_id__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::Namespace => {
+                            if namespace__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("namespace"));
+                            }
+                            namespace__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::NamespaceId => {
+                            if namespace_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("namespaceId"));
+                            }
+                            namespace_id__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::WorkflowExecution => {
+                            if workflow_execution__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("workflowExecution"));
+                            }
+                            workflow_execution__ = map_.next_value()?;
+                        }
+                        GeneratedField::Control => {
+                            if control__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("control"));
+                            }
+                            control__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::ChildWorkflowOnly => {
+                            if child_workflow_only__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("childWorkflowOnly"));
+                            }
+                            child_workflow_only__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::Reason => {
+                            if reason__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("reason"));
+                            }
+                            reason__ = Some(map_.next_value()?);
+                        }
+                    }
+                }
+                Ok(RequestCancelExternalWorkflowExecutionInitiatedEventAttributes {
+                    workflow_task_completed_event_id: workflow_task_completed_event_id__.unwrap_or_default(),
+                    namespace: namespace__.unwrap_or_default(),
+                    namespace_id: namespace_id__.unwrap_or_default(),
+                    workflow_execution: workflow_execution__,
+                    control: control__.unwrap_or_default(),
+                    child_workflow_only: child_workflow_only__.unwrap_or_default(),
+                    reason: reason__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("temporal.api.history.v1.RequestCancelExternalWorkflowExecutionInitiatedEventAttributes", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for SignalExternalWorkflowExecutionFailedEventAttributes {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.cause != 0 {
+            len += 1;
+        }
+        if self.workflow_task_completed_event_id != 0 {
+            len += 1;
+        }
+        if !self.namespace.is_empty() {
+            len += 1;
+        }
+        if !self.namespace_id.is_empty() {
+            len += 1;
+        }
+        if self.workflow_execution.is_some() {
+            len += 1;
+        }
+        if self.initiated_event_id != 0 {
+            len += 1;
+        }
+        if !self.control.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("temporal.api.history.v1.SignalExternalWorkflowExecutionFailedEventAttributes", len)?;
+        if self.cause != 0 {
+            let v = super::super::enums::v1::SignalExternalWorkflowExecutionFailedCause::try_from(self.cause)
+                .map_err(|_| serde::ser::Error::custom(format!("Invalid variant {}", self.cause)))?;
+            struct_ser.serialize_field("cause", &v)?;
+        }
+        if self.workflow_task_completed_event_id != 0 {
+            #[allow(clippy::needless_borrow)]
+            #[allow(clippy::needless_borrows_for_generic_args)]
+            struct_ser.serialize_field("workflowTaskCompletedEventId", ToString::to_string(&self.workflow_task_completed_event_id).as_str())?;
+        }
+        if !self.namespace.is_empty() {
+            struct_ser.serialize_field("namespace", &self.namespace)?;
+        }
+        if !self.namespace_id.is_empty() {
+            struct_ser.serialize_field("namespaceId", &self.namespace_id)?;
+        }
+        if let Some(v) = self.workflow_execution.as_ref() {
+            struct_ser.serialize_field("workflowExecution", v)?;
+        }
+        if self.initiated_event_id != 0 {
+            #[allow(clippy::needless_borrow)]
+            #[allow(clippy::needless_borrows_for_generic_args)]
+            struct_ser.serialize_field("initiatedEventId", ToString::to_string(&self.initiated_event_id).as_str())?;
+        }
+        if !self.control.is_empty() {
+            struct_ser.serialize_field("control", &self.control)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for SignalExternalWorkflowExecutionFailedEventAttributes {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "cause",
+            "workflow_task_completed_event_id",
+            "workflowTaskCompletedEventId",
+            "namespace",
+            "namespace_id",
+            "namespaceId",
+            "workflow_execution",
+            "workflowExecution",
+            "initiated_event_id",
+            "initiatedEventId",
+            "control",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Cause,
+            WorkflowTaskCompletedEventId,
+            Namespace,
+            NamespaceId,
+            WorkflowExecution,
+            InitiatedEventId,
+            Control,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "cause" => Ok(GeneratedField::Cause),
+                            "workflowTaskCompletedEventId" | "workflow_task_completed_event_id" => Ok(GeneratedField::WorkflowTaskCompletedEventId),
+                            "namespace" => Ok(GeneratedField::Namespace),
+                            "namespaceId" | "namespace_id" => Ok(GeneratedField::NamespaceId),
+                            "workflowExecution" | "workflow_execution" => Ok(GeneratedField::WorkflowExecution),
+                            "initiatedEventId" | "initiated_event_id" => Ok(GeneratedField::InitiatedEventId),
+                            "control" => Ok(GeneratedField::Control),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = SignalExternalWorkflowExecutionFailedEventAttributes;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct temporal.api.history.v1.SignalExternalWorkflowExecutionFailedEventAttributes")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<SignalExternalWorkflowExecutionFailedEventAttributes, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut cause__ = None;
+                let mut workflow_task_completed_event_id__ = None;
+                let mut namespace__ = None;
+                let mut namespace_id__ = None;
+                let mut workflow_execution__ = None;
+                let mut initiated_event_id__ = None;
+                let mut control__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Cause => {
+                            if cause__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("cause"));
+                            }
+                            cause__ = Some(map_.next_value::<super::super::enums::v1::SignalExternalWorkflowExecutionFailedCause>()? as i32);
+                        }
+                        GeneratedField::WorkflowTaskCompletedEventId => {
+                            if workflow_task_completed_event_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("workflowTaskCompletedEventId"));
+                            }
+                            workflow_task_completed_event_id__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::Namespace => {
+                            if namespace__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("namespace"));
+                            }
+                            namespace__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::NamespaceId => {
+                            if namespace_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("namespaceId"));
+                            }
+                            namespace_id__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::WorkflowExecution => {
+                            if workflow_execution__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("workflowExecution"));
+                            }
+                            workflow_execution__ = map_.next_value()?;
+                        }
+                        GeneratedField::InitiatedEventId => {
+                            if initiated_event_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("initiatedEventId"));
+                            }
+                            initiated_event_id__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::Control => {
+                            if control__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("control"));
+                            }
+                            control__ = Some(map_.next_value()?);
+                        }
+                    }
+                }
+                Ok(SignalExternalWorkflowExecutionFailedEventAttributes {
+                    cause: cause__.unwrap_or_default(),
+                    workflow_task_completed_event_id: workflow_task_completed_event_id__.unwrap_or_default(),
+                    namespace: namespace__.unwrap_or_default(),
+                    namespace_id: namespace_id__.unwrap_or_default(),
+                    workflow_execution: workflow_execution__,
+                    initiated_event_id: initiated_event_id__.unwrap_or_default(),
+                    control: control__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("temporal.api.history.v1.SignalExternalWorkflowExecutionFailedEventAttributes", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for SignalExternalWorkflowExecutionInitiatedEventAttributes {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.workflow_task_completed_event_id != 0 {
+            len += 1;
+        }
+        if !self.namespace.is_empty() {
+            len += 1;
+        }
+        if !self.namespace_id.is_empty() {
+            len += 1;
+        }
+        if self.workflow_execution.is_some() {
+            len += 1;
+        }
+        if !self.signal_name.is_empty() {
+            len += 1;
+        }
+        if self.input.is_some() {
+            len += 1;
+        }
+        if !self.control.is_empty() {
+            len += 1;
+        }
+        if self.child_workflow_only {
+            len += 1;
+        }
+        if self.header.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("temporal.api.history.v1.SignalExternalWorkflowExecutionInitiatedEventAttributes", len)?;
+        if self.workflow_task_completed_event_id != 0 {
+            #[allow(clippy::needless_borrow)]
+            #[allow(clippy::needless_borrows_for_generic_args)]
+            struct_ser.serialize_field("workflowTaskCompletedEventId", ToString::to_string(&self.workflow_task_completed_event_id).as_str())?;
+        }
+        if !self.namespace.is_empty() {
+            struct_ser.serialize_field("namespace", &self.namespace)?;
+        }
+        if !self.namespace_id.is_empty() {
+            struct_ser.serialize_field("namespaceId", &self.namespace_id)?;
+        }
+        if let Some(v) = self.workflow_execution.as_ref() {
+            struct_ser.serialize_field("workflowExecution", v)?;
+        }
+        if !self.signal_name.is_empty() {
+            struct_ser.serialize_field("signalName", &self.signal_name)?;
+        }
+        if let Some(v) = self.input.as_ref() {
+            struct_ser.serialize_field("input", v)?;
+        }
+        if !self.control.is_empty() {
+            struct_ser.serialize_field("control", &self.control)?;
+        }
+        if self.child_workflow_only {
+            struct_ser.serialize_field("childWorkflowOnly", &self.child_workflow_only)?;
+        }
+        if let Some(v) = self.header.as_ref() {
+            struct_ser.serialize_field("header", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for SignalExternalWorkflowExecutionInitiatedEventAttributes {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "workflow_task_completed_event_id",
+            "workflowTaskCompletedEventId",
+            "namespace",
+            "namespace_id",
+            "namespaceId",
+            "workflow_execution",
+            "workflowExecution",
+            "signal_name",
+            "signalName",
+            "input",
+            "control",
+            "child_workflow_only",
+            "childWorkflowOnly",
+            "header",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            WorkflowTaskCompletedEventId,
+            Namespace,
+            NamespaceId,
+            WorkflowExecution,
+            SignalName,
+            Input,
+            Control,
+            ChildWorkflowOnly,
+            Header,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "workflowTaskCompletedEventId" | "workflow_task_completed_event_id" => Ok(GeneratedField::WorkflowTaskCompletedEventId),
+                            "namespace" => Ok(GeneratedField::Namespace),
+                            "namespaceId" | "namespace_id" => Ok(GeneratedField::NamespaceId),
+                            "workflowExecution" | "workflow_execution" => Ok(GeneratedField::WorkflowExecution),
+                            "signalName" | "signal_name" => Ok(GeneratedField::SignalName),
+                            "input" => Ok(GeneratedField::Input),
+                            "control" => Ok(GeneratedField::Control),
+                            "childWorkflowOnly" | "child_workflow_only" => Ok(GeneratedField::ChildWorkflowOnly),
+                            "header" => Ok(GeneratedField::Header),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = SignalExternalWorkflowExecutionInitiatedEventAttributes;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct temporal.api.history.v1.SignalExternalWorkflowExecutionInitiatedEventAttributes")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<SignalExternalWorkflowExecutionInitiatedEventAttributes, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut workflow_task_completed_event_id__ = None;
+                let mut namespace__ = None;
+                let mut namespace_id__ = None;
+                let mut workflow_execution__ = None;
+                let mut signal_name__ = None;
+                let mut input__ = None;
+                let mut control__ = None;
+                let mut child_workflow_only__ = None;
+                let mut header__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::WorkflowTaskCompletedEventId => {
+                            if workflow_task_completed_event_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("workflowTaskCompletedEventId"));
+                            }
+                            workflow_task_completed_event_id__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::Namespace => {
+                            if namespace__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("namespace"));
+                            }
+                            namespace__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::NamespaceId => {
+                            if namespace_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("namespaceId"));
+                            }
+                            namespace_id__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::WorkflowExecution => {
+                            if workflow_execution__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("workflowExecution"));
+                            }
+                            workflow_execution__ = map_.next_value()?;
+                        }
+                        GeneratedField::SignalName => {
+                            if signal_name__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("signalName"));
+                            }
+                            signal_name__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::Input => {
+                            if input__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("input"));
+                            }
+                            input__ = map_.next_value()?;
+                        }
+                        GeneratedField::Control => {
+                            if control__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("control"));
+                            }
+                            control__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::ChildWorkflowOnly => {
+                            if child_workflow_only__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("childWorkflowOnly"));
+                            }
+                            child_workflow_only__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::Header => {
+                            if header__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("header"));
+                            }
+                            header__ = map_.next_value()?;
+                        }
+                    }
+                }
+                Ok(SignalExternalWorkflowExecutionInitiatedEventAttributes {
+                    workflow_task_completed_event_id: workflow_task_completed_event_id__.unwrap_or_default(),
+                    namespace: namespace__.unwrap_or_default(),
+                    namespace_id: namespace_id__.unwrap_or_default(),
+                    workflow_execution: workflow_execution__,
+                    signal_name: signal_name__.unwrap_or_default(),
+                    input: input__,
+                    control: control__.unwrap_or_default(),
+                    child_workflow_only: child_workflow_only__.unwrap_or_default(),
+                    header: header__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("temporal.api.history.v1.SignalExternalWorkflowExecutionInitiatedEventAttributes", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for StartChildWorkflowExecutionFailedEventAttributes {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.namespace.is_empty() {
+            len += 1;
+        }
+        if !self.namespace_id.is_empty() {
+            len += 1;
+        }
+        if !self.workflow_id.is_empty() {
+            len += 1;
+        }
+        if self.workflow_type.is_some() {
+            len += 1;
+        }
+        if self.cause != 0 {
+            len += 1;
+        }
+        if !self.control.is_empty() {
+            len += 1;
+        }
+        if self.initiated_event_id != 0 {
+            len += 1;
+        }
+        if self.workflow_task_completed_event_id != 0 {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("temporal.api.history.v1.StartChildWorkflowExecutionFailedEventAttributes", len)?;
+        if !self.namespace.is_empty() {
+            struct_ser.serialize_field("namespace", &self.namespace)?;
+        }
+        if !self.namespace_id.is_empty() {
+            struct_ser.serialize_field("namespaceId", &self.namespace_id)?;
+        }
+        if !self.workflow_id.is_empty() {
+            struct_ser.serialize_field("workflowId", &self.workflow_id)?;
+        }
+        if let Some(v) = self.workflow_type.as_ref() {
+            struct_ser.serialize_field("workflowType", v)?;
+        }
+        if self.cause != 0 {
+            let v = super::super::enums::v1::StartChildWorkflowExecutionFailedCause::try_from(self.cause)
+                .map_err(|_| serde::ser::Error::custom(format!("Invalid variant {}", self.cause)))?;
+            struct_ser.serialize_field("cause", &v)?;
+        }
+        if !self.control.is_empty() {
+            struct_ser.serialize_field("control", &self.control)?;
+        }
+        if self.initiated_event_id != 0 {
+            #[allow(clippy::needless_borrow)]
+            #[allow(clippy::needless_borrows_for_generic_args)]
+            struct_ser.serialize_field("initiatedEventId", ToString::to_string(&self.initiated_event_id).as_str())?;
+        }
+        if self.workflow_task_completed_event_id != 0 {
+            #[allow(clippy::needless_borrow)]
+            #[allow(clippy::needless_borrows_for_generic_args)]
+            struct_ser.serialize_field("workflowTaskCompletedEventId", ToString::to_string(&self.workflow_task_completed_event_id).as_str())?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for StartChildWorkflowExecutionFailedEventAttributes {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "namespace",
+            "namespace_id",
+            "namespaceId",
+            "workflow_id",
+            "workflowId",
+            "workflow_type",
+            "workflowType",
+            "cause",
+            "control",
+            "initiated_event_id",
+            "initiatedEventId",
+            "workflow_task_completed_event_id",
+            "workflowTaskCompletedEventId",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Namespace,
+            NamespaceId,
+            WorkflowId,
+            WorkflowType,
+            Cause,
+            Control,
+            InitiatedEventId,
+            WorkflowTaskCompletedEventId,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "namespace" => Ok(GeneratedField::Namespace),
+                            "namespaceId" | "namespace_id" => Ok(GeneratedField::NamespaceId),
+                            "workflowId" | "workflow_id" => Ok(GeneratedField::WorkflowId),
+                            "workflowType" | "workflow_type" => Ok(GeneratedField::WorkflowType),
+                            "cause" => Ok(GeneratedField::Cause),
+                            "control" => Ok(GeneratedField::Control),
+                            "initiatedEventId" | "initiated_event_id" => Ok(GeneratedField::InitiatedEventId),
+                            "workflowTaskCompletedEventId" | "workflow_task_completed_event_id" => Ok(GeneratedField::WorkflowTaskCompletedEventId),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = StartChildWorkflowExecutionFailedEventAttributes;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct temporal.api.history.v1.StartChildWorkflowExecutionFailedEventAttributes")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<StartChildWorkflowExecutionFailedEventAttributes, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut namespace__ = None;
+                let mut namespace_id__ = None;
+                let mut workflow_id__ = None;
+                let mut workflow_type__ = None;
+                let mut cause__ = None;
+                let mut control__ = None;
+                let mut initiated_event_id__ = None;
+                let mut workflow_task_completed_event_id__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Namespace => {
+                            if namespace__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("namespace"));
+                            }
+                            namespace__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::NamespaceId => {
+                            if namespace_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("namespaceId"));
+                            }
+                            namespace_id__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::WorkflowId => {
+                            if workflow_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("workflowId"));
+                            }
+                            workflow_id__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::WorkflowType => {
+                            if workflow_type__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("workflowType"));
+                            }
+                            workflow_type__ = map_.next_value()?;
+                        }
+                        GeneratedField::Cause => {
+                            if cause__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("cause"));
+                            }
+                            cause__ = Some(map_.next_value::<super::super::enums::v1::StartChildWorkflowExecutionFailedCause>()? as i32);
+                        }
+                        GeneratedField::Control => {
+                            if control__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("control"));
+                            }
+                            control__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::InitiatedEventId => {
+                            if initiated_event_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("initiatedEventId"));
+                            }
+                            initiated_event_id__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::WorkflowTaskCompletedEventId => {
+                            if workflow_task_completed_event_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("workflowTaskCompletedEventId"));
+                            }
+                            workflow_task_completed_event_id__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                    }
+                }
+                Ok(StartChildWorkflowExecutionFailedEventAttributes {
+                    namespace: namespace__.unwrap_or_default(),
+                    namespace_id: namespace_id__.unwrap_or_default(),
+                    workflow_id: workflow_id__.unwrap_or_default(),
+                    workflow_type: workflow_type__,
+                    cause: cause__.unwrap_or_default(),
+                    control: control__.unwrap_or_default(),
+                    initiated_event_id: initiated_event_id__.unwrap_or_default(),
+                    workflow_task_completed_event_id: workflow_task_completed_event_id__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("temporal.api.history.v1.StartChildWorkflowExecutionFailedEventAttributes", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for StartChildWorkflowExecutionInitiatedEventAttributes {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.namespace.is_empty() {
+            len += 1;
+        }
+        if !self.namespace_id.is_empty() {
+            len += 1;
+        }
+        if !self.workflow_id.is_empty() {
+            len += 1;
+        }
+        if self.workflow_type.is_some() {
+            len += 1;
+        }
+        if self.task_queue.is_some() {
+            len += 1;
+        }
+        if self.input.is_some() {
+            len += 1;
+        }
+        if self.workflow_execution_timeout.is_some() {
+            len += 1;
+        }
+        if self.workflow_run_timeout.is_some() {
+            len += 1;
+        }
+        if self.workflow_task_timeout.is_some() {
+            len += 1;
+        }
+        if self.parent_close_policy != 0 {
+            len += 1;
+        }
+        if !self.control.is_empty() {
+            len += 1;
+        }
+        if self.workflow_task_completed_event_id != 0 {
+            len += 1;
+        }
+        if self.workflow_id_reuse_policy != 0 {
+            len += 1;
+        }
+        if self.retry_policy.is_some() {
+            len += 1;
+        }
+        if !self.cron_schedule.is_empty() {
+            len += 1;
+        }
+        if self.header.is_some() {
+            len += 1;
+        }
+        if self.memo.is_some() {
+            len += 1;
+        }
+        if self.search_attributes.is_some() {
+            len += 1;
+        }
+        if self.inherit_build_id {
+            len += 1;
+        }
+        if self.priority.is_some() {
+            len += 1;
+        }
+        if self.time_skipping_config.is_some() {
+            len += 1;
+        }
+        if self.initial_skipped_duration.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("temporal.api.history.v1.StartChildWorkflowExecutionInitiatedEventAttributes", len)?;
+        if !self.namespace.is_empty() {
+            struct_ser.serialize_field("namespace", &self.namespace)?;
+        }
+        if !self.namespace_id.is_empty() {
+            struct_ser.serialize_field("namespaceId", &self.namespace_id)?;
+        }
+        if !self.workflow_id.is_empty() {
+            struct_ser.serialize_field("workflowId", &self.workflow_id)?;
+        }
+        if let Some(v) = self.workflow_type.as_ref() {
+            struct_ser.serialize_field("workflowType", v)?;
+        }
+        if let Some(v) = self.task_queue.as_ref() {
+            struct_ser.serialize_field("taskQueue", v)?;
+        }
+        if let Some(v) = self.input.as_ref() {
+            struct_ser.serialize_field("input", v)?;
+        }
+        if let Some(v) = self.workflow_execution_timeout.as_ref() {
+            struct_ser.serialize_field("workflowExecutionTimeout", v)?;
+        }
+        if let Some(v) = self.workflow_run_timeout.as_ref() {
+            struct_ser.serialize_field("workflowRunTimeout", v)?;
+        }
+        if let Some(v) = self.workflow_task_timeout.as_ref() {
+            struct_ser.serialize_field("workflowTaskTimeout", v)?;
+        }
+        if self.parent_close_policy != 0 {
+            let v = super::super::enums::v1::ParentClosePolicy::try_from(self.parent_close_policy)
+                .map_err(|_| serde::ser::Error::custom(format!("Invalid variant {}", self.parent_close_policy)))?;
+            struct_ser.serialize_field("parentClosePolicy", &v)?;
+        }
+        if !self.control.is_empty() {
+            struct_ser.serialize_field("control", &self.control)?;
+        }
+        if self.workflow_task_completed_event_id != 0 {
+            #[allow(clippy::needless_borrow)]
+            #[allow(clippy::needless_borrows_for_generic_args)]
+            struct_ser.serialize_field("workflowTaskCompletedEventId", ToString::to_string(&self.workflow_task_completed_event_id).as_str())?;
+        }
+        if self.workflow_id_reuse_policy != 0 {
+            let v = super::super::enums::v1::WorkflowIdReusePolicy::try_from(self.workflow_id_reuse_policy)
+                .map_err(|_| serde::ser::Error::custom(format!("Invalid variant {}", self.workflow_id_reuse_policy)))?;
+            struct_ser.serialize_field("workflowIdReusePolicy", &v)?;
+        }
+        if let Some(v) = self.retry_policy.as_ref() {
+            struct_ser.serialize_field("retryPolicy", v)?;
+        }
+        if !self.cron_schedule.is_empty() {
+            struct_ser.serialize_field("cronSchedule", &self.cron_schedule)?;
+        }
+        if let Some(v) = self.header.as_ref() {
+            struct_ser.serialize_field("header", v)?;
+        }
+        if let Some(v) = self.memo.as_ref() {
+            struct_ser.serialize_field("memo", v)?;
+        }
+        if let Some(v) = self.search_attributes.as_ref() {
+            struct_ser.serialize_field("searchAttributes", v)?;
+        }
+        if self.inherit_build_id {
+            struct_ser.serialize_field("inheritBuildId", &self.inherit_build_id)?;
+        }
+        if let Some(v) = self.priority.as_ref() {
+            struct_ser.serialize_field("priority", v)?;
+        }
+        if let Some(v) = self.time_skipping_config.as_ref() {
+            struct_ser.serialize_field("timeSkippingConfig", v)?;
+        }
+        if let Some(v) = self.initial_skipped_duration.as_ref() {
+            struct_ser.serialize_field("initialSkippedDuration", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for StartChildWorkflowExecutionInitiatedEventAttributes {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "namespace",
+            "namespace_id",
+            "namespaceId",
+            "workflow_id",
+            "workflowId",
+            "workflow_type",
+            "workflowType",
+            "task_queue",
+            "taskQueue",
+            "input",
+            "workflow_execution_timeout",
+            "workflowExecutionTimeout",
+            "workflow_run_timeout",
+            "workflowRunTimeout",
+            "workflow_task_timeout",
+            "workflowTaskTimeout",
+            "parent_close_policy",
+            "parentClosePolicy",
+            "control",
+            "workflow_task_completed_event_id",
+            "workflowTaskCompletedEventId",
+            "workflow_id_reuse_policy",
+            "workflowIdReusePolicy",
+            "retry_policy",
+            "retryPolicy",
+            "cron_schedule",
+            "cronSchedule",
+            "header",
+            "memo",
+            "search_attributes",
+            "searchAttributes",
+            "inherit_build_id",
+            "inheritBuildId",
+            "priority",
+            "time_skipping_config",
+            "timeSkippingConfig",
+            "initial_skipped_duration",
+            "initialSkippedDuration",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Namespace,
+            NamespaceId,
+            WorkflowId,
+            WorkflowType,
+            TaskQueue,
+            Input,
+            WorkflowExecutionTimeout,
+            WorkflowRunTimeout,
+            WorkflowTaskTimeout,
+            ParentClosePolicy,
+            Control,
+            WorkflowTaskCompletedEventId,
+            WorkflowIdReusePolicy,
+            RetryPolicy,
+            CronSchedule,
+            Header,
+            Memo,
+            SearchAttributes,
+            InheritBuildId,
+            Priority,
+            TimeSkippingConfig,
+            InitialSkippedDuration,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "namespace" => Ok(GeneratedField::Namespace),
+                            "namespaceId" | "namespace_id" => Ok(GeneratedField::NamespaceId),
+                            "workflowId" | "workflow_id" => Ok(GeneratedField::WorkflowId),
+                            "workflowType" | "workflow_type" => Ok(GeneratedField::WorkflowType),
+                            "taskQueue" | "task_queue" => Ok(GeneratedField::TaskQueue),
+                            "input" => Ok(GeneratedField::Input),
+                            "workflowExecutionTimeout" | "workflow_execution_timeout" => Ok(GeneratedField::WorkflowExecutionTimeout),
+                            "workflowRunTimeout" | "workflow_run_timeout" => Ok(GeneratedField::WorkflowRunTimeout),
+                            "workflowTaskTimeout" | "workflow_task_timeout" => Ok(GeneratedField::WorkflowTaskTimeout),
+                            "parentClosePolicy" | "parent_close_policy" => Ok(GeneratedField::ParentClosePolicy),
+                            "control" => Ok(GeneratedField::Control),
+                            "workflowTaskCompletedEventId" | "workflow_task_completed_event_id" => Ok(GeneratedField::WorkflowTaskCompletedEventId),
+                            "workflowIdReusePolicy" | "workflow_id_reuse_policy" => Ok(GeneratedField::WorkflowIdReusePolicy),
+                            "retryPolicy" | "retry_policy" => Ok(GeneratedField::RetryPolicy),
+                            "cronSchedule" | "cron_schedule" => Ok(GeneratedField::CronSchedule),
+                            "header" => Ok(GeneratedField::Header),
+                            "memo" => Ok(GeneratedField::Memo),
+                            "searchAttributes" | "search_attributes" => Ok(GeneratedField::SearchAttributes),
+                            "inheritBuildId" | "inherit_build_id" => Ok(GeneratedField::InheritBuildId),
+                            "priority" => Ok(GeneratedField::Priority),
+                            "timeSkippingConfig" | "time_skipping_config" => Ok(GeneratedField::TimeSkippingConfig),
+                            "initialSkippedDuration" | "initial_skipped_duration" => Ok(GeneratedField::InitialSkippedDuration),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = StartChildWorkflowExecutionInitiatedEventAttributes;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct temporal.api.history.v1.StartChildWorkflowExecutionInitiatedEventAttributes")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<StartChildWorkflowExecutionInitiatedEventAttributes, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut namespace__ = None;
+                let mut namespace_id__ = None;
+                let mut workflow_id__ = None;
+                let mut workflow_type__ = None;
+                let mut task_queue__ = None;
+                let mut input__ = None;
+                let mut workflow_execution_timeout__ = None;
+                let mut workflow_run_timeout__ = None;
+                let mut workflow_task_timeout__ = None;
+                let mut parent_close_policy__ = None;
+                let mut control__ = None;
+                let mut workflow_task_completed_event_id__ = None;
+                let mut workflow_id_reuse_policy__ = None;
+                let mut retry_policy__ = None;
+                let mut cron_schedule__ = None;
+                let mut header__ = None;
+                let mut memo__ = None;
+                let mut search_attributes__ = None;
+                let mut inherit_build_id__ = None;
+                let mut priority__ = None;
+                let mut time_skipping_config__ = None;
+                let mut initial_skipped_duration__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Namespace => {
+                            if namespace__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("namespace"));
+                            }
+                            namespace__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::NamespaceId => {
+                            if namespace_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("namespaceId"));
+                            }
+                            namespace_id__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::WorkflowId => {
+                            if workflow_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("workflowId"));
+                            }
+                            workflow_id__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::WorkflowType => {
+                            if workflow_type__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("workflowType"));
+                            }
+                            workflow_type__ = map_.next_value()?;
+                        }
+                        GeneratedField::TaskQueue => {
+                            if task_queue__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("taskQueue"));
+                            }
+                            task_queue__ = map_.next_value()?;
+                        }
+                        GeneratedField::Input => {
+                            if input__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("input"));
+                            }
+                            input__ = map_.next_value()?;
+                        }
+                        GeneratedField::WorkflowExecutionTimeout => {
+                            if workflow_execution_timeout__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("workflowExecutionTimeout"));
+                            }
+                            workflow_execution_timeout__ = map_.next_value()?;
+                        }
+                        GeneratedField::WorkflowRunTimeout => {
+                            if workflow_run_timeout__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("workflowRunTimeout"));
+                            }
+                            workflow_run_timeout__ = map_.next_value()?;
+                        }
+                        GeneratedField::WorkflowTaskTimeout => {
+                            if workflow_task_timeout__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("workflowTaskTimeout"));
+                            }
+                            workflow_task_timeout__ = map_.next_value()?;
+                        }
+                        GeneratedField::ParentClosePolicy => {
+                            if parent_close_policy__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("parentClosePolicy"));
+                            }
+                            parent_close_policy__ = Some(map_.next_value::<super::super::enums::v1::ParentClosePolicy>()? as i32);
+                        }
+                        GeneratedField::Control => {
+                            if control__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("control"));
+                            }
+                            control__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::WorkflowTaskCompletedEventId => {
+                            if workflow_task_completed_event_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("workflowTaskCompletedEventId"));
+                            }
+                            workflow_task_completed_event_id__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::WorkflowIdReusePolicy => {
+                            if workflow_id_reuse_policy__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("workflowIdReusePolicy"));
+                            }
+                            workflow_id_reuse_policy__ = Some(map_.next_value::<super::super::enums::v1::WorkflowIdReusePolicy>()? as i32);
+                        }
+                        GeneratedField::RetryPolicy => {
+                            if retry_policy__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("retryPolicy"));
+                            }
+                            retry_policy__ = map_.next_value()?;
+                        }
+                        GeneratedField::CronSchedule => {
+                            if cron_schedule__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("cronSchedule"));
+                            }
+                            cron_schedule__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::Header => {
+                            if header__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("header"));
+                            }
+                            header__ = map_.next_value()?;
+                        }
+                        GeneratedField::Memo => {
+                            if memo__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("memo"));
+                            }
+                            memo__ = map_.next_value()?;
+                        }
+                        GeneratedField::SearchAttributes => {
+                            if search_attributes__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("searchAttributes"));
+                            }
+                            search_attributes__ = map_.next_value()?;
+                        }
+                        GeneratedField::InheritBuildId => {
+                            if inherit_build_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("inheritBuildId"));
+                            }
+                            inherit_build_id__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::Priority => {
+                            if priority__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("priority"));
+                            }
+                            priority__ = map_.next_value()?;
+                        }
+                        GeneratedField::TimeSkippingConfig => {
+                            if time_skipping_config__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("timeSkippingConfig"));
+                            }
+                            time_skipping_config__ = map_.next_value()?;
+                        }
+                        GeneratedField::InitialSkippedDuration => {
+                            if initial_skipped_duration__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("initialSkippedDuration"));
+                            }
+                            initial_skipped_duration__ = map_.next_value()?;
+                        }
+                    }
+                }
+                Ok(StartChildWorkflowExecutionInitiatedEventAttributes {
+                    namespace: namespace__.unwrap_or_default(),
+                    namespace_id: namespace_id__.unwrap_or_default(),
+                    workflow_id: workflow_id__.unwrap_or_default(),
+                    workflow_type: workflow_type__,
+                    task_queue: task_queue__,
+                    input: input__,
+                    workflow_execution_timeout: workflow_execution_timeout__,
+                    workflow_run_timeout: workflow_run_timeout__,
+                    workflow_task_timeout: workflow_task_timeout__,
+                    parent_close_policy: parent_close_policy__.unwrap_or_default(),
+                    control: control__.unwrap_or_default(),
+                    workflow_task_completed_event_id: workflow_task_completed_event_id__.unwrap_or_default(),
+                    workflow_id_reuse_policy: workflow_id_reuse_policy__.unwrap_or_default(),
+                    retry_policy: retry_policy__,
+                    cron_schedule: cron_schedule__.unwrap_or_default(),
+                    header: header__,
+                    memo: memo__,
+                    search_attributes: search_attributes__,
+                    inherit_build_id: inherit_build_id__.unwrap_or_default(),
+                    priority: priority__,
+                    time_skipping_config: time_skipping_config__,
+                    initial_skipped_duration: initial_skipped_duration__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("temporal.api.history.v1.StartChildWorkflowExecutionInitiatedEventAttributes", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for TimerCanceledEventAttributes {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.timer_id.is_empty() {
+            len += 1;
+        }
+        if self.started_event_id != 0 {
+            len += 1;
+        }
+        if self.workflow_task_completed_event_id != 0 {
+            len += 1;
+        }
+        if !self.identity.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("temporal.api.history.v1.TimerCanceledEventAttributes", len)?;
+        if !self.timer_id.is_empty() {
+            struct_ser.serialize_field("timerId", &self.timer_id)?;
+        }
+        if self.started_event_id != 0 {
+            #[allow(clippy::needless_borrow)]
+            #[allow(clippy::needless_borrows_for_generic_args)]
+            struct_ser.serialize_field("startedEventId", ToString::to_string(&self.started_event_id).as_str())?;
+        }
+        if self.workflow_task_completed_event_id != 0 {
+            #[allow(clippy::needless_borrow)]
+            #[allow(clippy::needless_borrows_for_generic_args)]
+            struct_ser.serialize_field("workflowTaskCompletedEventId", ToString::to_string(&self.workflow_task_completed_event_id).as_str())?;
+        }
+        if !self.identity.is_empty() {
+            struct_ser.serialize_field("identity", &self.identity)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for TimerCanceledEventAttributes {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "timer_id",
+            "timerId",
+            "started_event_id",
+            "startedEventId",
+            "workflow_task_completed_event_id",
+            "workflowTaskCompletedEventId",
+            "identity",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            TimerId,
+            StartedEventId,
+            WorkflowTaskCompletedEventId,
+            Identity,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "timerId" | "timer_id" => Ok(GeneratedField::TimerId),
+                            "startedEventId" | "started_event_id" => Ok(GeneratedField::StartedEventId),
+                            "workflowTaskCompletedEventId" | "workflow_task_completed_event_id" => Ok(GeneratedField::WorkflowTaskCompletedEventId),
+                            "identity" => Ok(GeneratedField::Identity),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = TimerCanceledEventAttributes;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct temporal.api.history.v1.TimerCanceledEventAttributes")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<TimerCanceledEventAttributes, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut timer_id__ = None;
+                let mut started_event_id__ = None;
+                let mut workflow_task_completed_event_id__ = None;
+                let mut identity__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::TimerId => {
+                            if timer_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("timerId"));
+                            }
+                            timer_id__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::StartedEventId => {
+                            if started_event_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("startedEventId"));
+                            }
+                            started_event_id__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::WorkflowTaskCompletedEventId => {
+                            if workflow_task_completed_event_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("workflowTaskCompletedEventId"));
+                            }
+                            workflow_task_completed_event_id__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::Identity => {
+                            if identity__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("identity"));
+                            }
+                            identity__ = Some(map_.next_value()?);
+                        }
+                    }
+                }
+                Ok(TimerCanceledEventAttributes {
+                    timer_id: timer_id__.unwrap_or_default(),
+                    started_event_id: started_event_id__.unwrap_or_default(),
+                    workflow_task_completed_event_id: workflow_task_completed_event_id__.unwrap_or_default(),
+                    identity: identity__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("temporal.api.history.v1.TimerCanceledEventAttributes", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for TimerFiredEventAttributes {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.timer_id.is_empty() {
+            len += 1;
+        }
+        if self.started_event_id != 0 {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("temporal.api.history.v1.TimerFiredEventAttributes", len)?;
+        if !self.timer_id.is_empty() {
+            struct_ser.serialize_field("timerId", &self.timer_id)?;
+        }
+        if self.started_event_id != 0 {
+            #[allow(clippy::needless_borrow)]
+            #[allow(clippy::needless_borrows_for_generic_args)]
+            struct_ser.serialize_field("startedEventId", ToString::to_string(&self.started_event_id).as_str())?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for TimerFiredEventAttributes {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "timer_id",
+            "timerId",
+            "started_event_id",
+            "startedEventId",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            TimerId,
+            StartedEventId,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "timerId" | "timer_id" => Ok(GeneratedField::TimerId),
+                            "startedEventId" | "started_event_id" => Ok(GeneratedField::StartedEventId),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = TimerFiredEventAttributes;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct temporal.api.history.v1.TimerFiredEventAttributes")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<TimerFiredEventAttributes, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut timer_id__ = None;
+                let mut started_event_id__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::TimerId => {
+                            if timer_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("timerId"));
+                            }
+                            timer_id__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::StartedEventId => {
+                            if started_event_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("startedEventId"));
+                            }
+                            started_event_id__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                    }
+                }
+                Ok(TimerFiredEventAttributes {
+                    timer_id: timer_id__.unwrap_or_default(),
+                    started_event_id: started_event_id__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("temporal.api.history.v1.TimerFiredEventAttributes", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for TimerStartedEventAttributes {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.timer_id.is_empty() {
+            len += 1;
+        }
+        if self.start_to_fire_timeout.is_some() {
+            len += 1;
+        }
+        if self.workflow_task_completed_event_id != 0 {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("temporal.api.history.v1.TimerStartedEventAttributes", len)?;
+        if !self.timer_id.is_empty() {
+            struct_ser.serialize_field("timerId", &self.timer_id)?;
+        }
+        if let Some(v) = self.start_to_fire_timeout.as_ref() {
+            struct_ser.serialize_field("startToFireTimeout", v)?;
+        }
+        if self.workflow_task_completed_event_id != 0 {
+            #[allow(clippy::needless_borrow)]
+            #[allow(clippy::needless_borrows_for_generic_args)]
+            struct_ser.serialize_field("workflowTaskCompletedEventId", ToString::to_string(&self.workflow_task_completed_event_id).as_str())?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for TimerStartedEventAttributes {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "timer_id",
+            "timerId",
+            "start_to_fire_timeout",
+            "startToFireTimeout",
+            "workflow_task_completed_event_id",
+            "workflowTaskCompletedEventId",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            TimerId,
+            StartToFireTimeout,
+            WorkflowTaskCompletedEventId,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "timerId" | "timer_id" => Ok(GeneratedField::TimerId),
+                            "startToFireTimeout" | "start_to_fire_timeout" => Ok(GeneratedField::StartToFireTimeout),
+                            "workflowTaskCompletedEventId" | "workflow_task_completed_event_id" => Ok(GeneratedField::WorkflowTaskCompletedEventId),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = TimerStartedEventAttributes;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct temporal.api.history.v1.TimerStartedEventAttributes")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<TimerStartedEventAttributes, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut timer_id__ = None;
+                let mut start_to_fire_timeout__ = None;
+                let mut workflow_task_completed_event_id__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::TimerId => {
+                            if timer_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("timerId"));
+                            }
+                            timer_id__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::StartToFireTimeout => {
+                            if start_to_fire_timeout__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("startToFireTimeout"));
+                            }
+                            start_to_fire_timeout__ = map_.next_value()?;
+                        }
+                        GeneratedField::WorkflowTaskCompletedEventId => {
+                            if workflow_task_completed_event_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("workflowTaskCompletedEventId"));
+                            }
+                            workflow_task_completed_event_id__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                    }
+                }
+                Ok(TimerStartedEventAttributes {
+                    timer_id: timer_id__.unwrap_or_default(),
+                    start_to_fire_timeout: start_to_fire_timeout__,
+                    workflow_task_completed_event_id: workflow_task_completed_event_id__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("temporal.api.history.v1.TimerStartedEventAttributes", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for UpsertWorkflowSearchAttributesEventAttributes {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.workflow_task_completed_event_id != 0 {
+            len += 1;
+        }
+        if self.search_attributes.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("temporal.api.history.v1.UpsertWorkflowSearchAttributesEventAttributes", len)?;
+        if self.workflow_task_completed_event_id != 0 {
+            #[allow(clippy::needless_borrow)]
+            #[allow(clippy::needless_borrows_for_generic_args)]
+            struct_ser.serialize_field("workflowTaskCompletedEventId", ToString::to_string(&self.workflow_task_completed_event_id).as_str())?;
+        }
+        if let Some(v) = self.search_attributes.as_ref() {
+            struct_ser.serialize_field("searchAttributes", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for UpsertWorkflowSearchAttributesEventAttributes {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "workflow_task_completed_event_id",
+            "workflowTaskCompletedEventId",
+            "search_attributes",
+            "searchAttributes",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            WorkflowTaskCompletedEventId,
+            SearchAttributes,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "workflowTaskCompletedEventId" | "workflow_task_completed_event_id" => Ok(GeneratedField::WorkflowTaskCompletedEventId),
+                            "searchAttributes" | "search_attributes" => Ok(GeneratedField::SearchAttributes),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = UpsertWorkflowSearchAttributesEventAttributes;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct temporal.api.history.v1.UpsertWorkflowSearchAttributesEventAttributes")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<UpsertWorkflowSearchAttributesEventAttributes, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut workflow_task_completed_event_id__ = None;
+                let mut search_attributes__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::WorkflowTaskCompletedEventId => {
+                            if workflow_task_completed_event_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("workflowTaskCompletedEventId"));
+                            }
+                            workflow_task_completed_event_id__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::SearchAttributes => {
+                            if search_attributes__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("searchAttributes"));
+                            }
+                            search_attributes__ = map_.next_value()?;
+                        }
+                    }
+                }
+                Ok(UpsertWorkflowSearchAttributesEventAttributes {
+                    workflow_task_completed_event_id: workflow_task_completed_event_id__.unwrap_or_default(),
+                    search_attributes: search_attributes__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("temporal.api.history.v1.UpsertWorkflowSearchAttributesEventAttributes", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for WorkflowExecutionCancelRequestedEventAttributes {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.cause.is_empty() {
+            len += 1;
+        }
+        if self.external_initiated_event_id != 0 {
+            len += 1;
+        }
+        if self.external_workflow_execution.is_some() {
+            len += 1;
+        }
+        if !self.identity.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("temporal.api.history.v1.WorkflowExecutionCancelRequestedEventAttributes", len)?;
+        if !self.cause.is_empty() {
+            struct_ser.serialize_field("cause", &self.cause)?;
+        }
+        if self.external_initiated_event_id != 0 {
+            #[allow(clippy::needless_borrow)]
+            #[allow(clippy::needless_borrows_for_generic_args)]
+            struct_ser.serialize_field("externalInitiatedEventId", ToString::to_string(&self.external_initiated_event_id).as_str())?;
+        }
+        if let Some(v) = self.external_workflow_execution.as_ref() {
+            struct_ser.serialize_field("externalWorkflowExecution", v)?;
+        }
+        if !self.identity.is_empty() {
+            struct_ser.serialize_field("identity", &self.identity)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for WorkflowExecutionCancelRequestedEventAttributes {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "cause",
+            "external_initiated_event_id",
+            "externalInitiatedEventId",
+            "external_workflow_execution",
+            "externalWorkflowExecution",
+            "identity",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Cause,
+            ExternalInitiatedEventId,
+            ExternalWorkflowExecution,
+            Identity,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "cause" => Ok(GeneratedField::Cause),
+                            "externalInitiatedEventId" | "external_initiated_event_id" => Ok(GeneratedField::ExternalInitiatedEventId),
+                            "externalWorkflowExecution" | "external_workflow_execution" => Ok(GeneratedField::ExternalWorkflowExecution),
+                            "identity" => Ok(GeneratedField::Identity),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = WorkflowExecutionCancelRequestedEventAttributes;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct temporal.api.history.v1.WorkflowExecutionCancelRequestedEventAttributes")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<WorkflowExecutionCancelRequestedEventAttributes, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut cause__ = None;
+                let mut external_initiated_event_id__ = None;
+                let mut external_workflow_execution__ = None;
+                let mut identity__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Cause => {
+                            if cause__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("cause"));
+                            }
+                            cause__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::ExternalInitiatedEventId => {
+                            if external_initiated_event_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("externalInitiatedEventId"));
+                            }
+                            external_initiated_event_id__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::ExternalWorkflowExecution => {
+                            if external_workflow_execution__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("externalWorkflowExecution"));
+                            }
+                            external_workflow_execution__ = map_.next_value()?;
+                        }
+                        GeneratedField::Identity => {
+                            if identity__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("identity"));
+                            }
+                            identity__ = Some(map_.next_value()?);
+                        }
+                    }
+                }
+                Ok(WorkflowExecutionCancelRequestedEventAttributes {
+                    cause: cause__.unwrap_or_default(),
+                    external_initiated_event_id: external_initiated_event_id__.unwrap_or_default(),
+                    external_workflow_execution: external_workflow_execution__,
+                    identity: identity__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("temporal.api.history.v1.WorkflowExecutionCancelRequestedEventAttributes", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for WorkflowExecutionCanceledEventAttributes {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.workflow_task_completed_event_id != 0 {
+            len += 1;
+        }
+        if self.details.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("temporal.api.history.v1.WorkflowExecutionCanceledEventAttributes", len)?;
+        if self.workflow_task_completed_event_id != 0 {
+            #[allow(clippy::needless_borrow)]
+            #[allow(clippy::needless_borrows_for_generic_args)]
+            struct_ser.serialize_field("workflowTaskCompletedEventId", ToString::to_string(&self.workflow_task_completed_event_id).as_str())?;
+        }
+        if let Some(v) = self.details.as_ref() {
+            struct_ser.serialize_field("details", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for WorkflowExecutionCanceledEventAttributes {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "workflow_task_completed_event_id",
+            "workflowTaskCompletedEventId",
+            "details",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            WorkflowTaskCompletedEventId,
+            Details,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "workflowTaskCompletedEventId" | "workflow_task_completed_event_id" => Ok(GeneratedField::WorkflowTaskCompletedEventId),
+                            "details" => Ok(GeneratedField::Details),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = WorkflowExecutionCanceledEventAttributes;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct temporal.api.history.v1.WorkflowExecutionCanceledEventAttributes")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<WorkflowExecutionCanceledEventAttributes, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut workflow_task_completed_event_id__ = None;
+                let mut details__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::WorkflowTaskCompletedEventId => {
+                            if workflow_task_completed_event_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("workflowTaskCompletedEventId"));
+                            }
+                            workflow_task_completed_event_id__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::Details => {
+                            if details__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("details"));
+                            }
+                            details__ = map_.next_value()?;
+                        }
+                    }
+                }
+                Ok(WorkflowExecutionCanceledEventAttributes {
+                    workflow_task_completed_event_id: workflow_task_completed_event_id__.unwrap_or_default(),
+                    details: details__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("temporal.api.history.v1.WorkflowExecutionCanceledEventAttributes", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for WorkflowExecutionCompletedEventAttributes {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.result.is_some() {
+            len += 1;
+        }
+        if self.workflow_task_completed_event_id != 0 {
+            len += 1;
+        }
+        if !self.new_execution_run_id.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("temporal.api.history.v1.WorkflowExecutionCompletedEventAttributes", len)?;
+        if let Some(v) = self.result.as_ref() {
+            struct_ser.serialize_field("result", v)?;
+        }
+        if self.workflow_task_completed_event_id != 0 {
+            #[allow(clippy::needless_borrow)]
+            #[allow(clippy::needless_borrows_for_generic_args)]
+            struct_ser.serialize_field("workflowTaskCompletedEventId", ToString::to_string(&self.workflow_task_completed_event_id).as_str())?;
+        }
+        if !self.new_execution_run_id.is_empty() {
+            struct_ser.serialize_field("newExecutionRunId", &self.new_execution_run_id)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for WorkflowExecutionCompletedEventAttributes {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "result",
+            "workflow_task_completed_event_id",
+            "workflowTaskCompletedEventId",
+            "new_execution_run_id",
+            "newExecutionRunId",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Result,
+            WorkflowTaskCompletedEventId,
+            NewExecutionRunId,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "result" => Ok(GeneratedField::Result),
+                            "workflowTaskCompletedEventId" | "workflow_task_completed_event_id" => Ok(GeneratedField::WorkflowTaskCompletedEventId),
+                            "newExecutionRunId" | "new_execution_run_id" => Ok(GeneratedField::NewExecutionRunId),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = WorkflowExecutionCompletedEventAttributes;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct temporal.api.history.v1.WorkflowExecutionCompletedEventAttributes")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<WorkflowExecutionCompletedEventAttributes, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut result__ = None;
+                let mut workflow_task_completed_event_id__ = None;
+                let mut new_execution_run_id__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Result => {
+                            if result__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("result"));
+                            }
+                            result__ = map_.next_value()?;
+                        }
+                        GeneratedField::WorkflowTaskCompletedEventId => {
+                            if workflow_task_completed_event_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("workflowTaskCompletedEventId"));
+                            }
+                            workflow_task_completed_event_id__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::NewExecutionRunId => {
+                            if new_execution_run_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("newExecutionRunId"));
+                            }
+                            new_execution_run_id__ = Some(map_.next_value()?);
+                        }
+                    }
+                }
+                Ok(WorkflowExecutionCompletedEventAttributes {
+                    result: result__,
+                    workflow_task_completed_event_id: workflow_task_completed_event_id__.unwrap_or_default(),
+                    new_execution_run_id: new_execution_run_id__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("temporal.api.history.v1.WorkflowExecutionCompletedEventAttributes", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for WorkflowExecutionContinuedAsNewEventAttributes {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.new_execution_run_id.is_empty() {
+            len += 1;
+        }
+        if self.workflow_type.is_some() {
+            len += 1;
+        }
+        if self.task_queue.is_some() {
+            len += 1;
+        }
+        if self.input.is_some() {
+            len += 1;
+        }
+        if self.workflow_run_timeout.is_some() {
+            len += 1;
+        }
+        if self.workflow_task_timeout.is_some() {
+            len += 1;
+        }
+        if self.workflow_task_completed_event_id != 0 {
+            len += 1;
+        }
+        if self.backoff_start_interval.is_some() {
+            len += 1;
+        }
+        if self.initiator != 0 {
+            len += 1;
+        }
+        if self.failure.is_some() {
+            len += 1;
+        }
+        if self.last_completion_result.is_some() {
+            len += 1;
+        }
+        if self.header.is_some() {
+            len += 1;
+        }
+        if self.memo.is_some() {
+            len += 1;
+        }
+        if self.search_attributes.is_some() {
+            len += 1;
+        }
+        if self.inherit_build_id {
+            len += 1;
+        }
+        if self.initial_versioning_behavior != 0 {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("temporal.api.history.v1.WorkflowExecutionContinuedAsNewEventAttributes", len)?;
+        if !self.new_execution_run_id.is_empty() {
+            struct_ser.serialize_field("newExecutionRunId", &self.new_execution_run_id)?;
+        }
+        if let Some(v) = self.workflow_type.as_ref() {
+            struct_ser.serialize_field("workflowType", v)?;
+        }
+        if let Some(v) = self.task_queue.as_ref() {
+            struct_ser.serialize_field("taskQueue", v)?;
+        }
+        if let Some(v) = self.input.as_ref() {
+            struct_ser.serialize_field("input", v)?;
+        }
+        if let Some(v) = self.workflow_run_timeout.as_ref() {
+            struct_ser.serialize_field("workflowRunTimeout", v)?;
+        }
+        if let Some(v) = self.workflow_task_timeout.as_ref() {
+            struct_ser.serialize_field("workflowTaskTimeout", v)?;
+        }
+        if self.workflow_task_completed_event_id != 0 {
+            #[allow(clippy::needless_borrow)]
+            #[allow(clippy::needless_borrows_for_generic_args)]
+            struct_ser.serialize_field("workflowTaskCompletedEventId", ToString::to_string(&self.workflow_task_completed_event_id).as_str())?;
+        }
+        if let Some(v) = self.backoff_start_interval.as_ref() {
+            struct_ser.serialize_field("backoffStartInterval", v)?;
+        }
+        if self.initiator != 0 {
+            let v = super::super::enums::v1::ContinueAsNewInitiator::try_from(self.initiator)
+                .map_err(|_| serde::ser::Error::custom(format!("Invalid variant {}", self.initiator)))?;
+            struct_ser.serialize_field("initiator", &v)?;
+        }
+        if let Some(v) = self.failure.as_ref() {
+            struct_ser.serialize_field("failure", v)?;
+        }
+        if let Some(v) = self.last_completion_result.as_ref() {
+            struct_ser.serialize_field("lastCompletionResult", v)?;
+        }
+        if let Some(v) = self.header.as_ref() {
+            struct_ser.serialize_field("header", v)?;
+        }
+        if let Some(v) = self.memo.as_ref() {
+            struct_ser.serialize_field("memo", v)?;
+        }
+        if let Some(v) = self.search_attributes.as_ref() {
+            struct_ser.serialize_field("searchAttributes", v)?;
+        }
+        if self.inherit_build_id {
+            struct_ser.serialize_field("inheritBuildId", &self.inherit_build_id)?;
+        }
+        if self.initial_versioning_behavior != 0 {
+            let v = super::super::enums::v1::ContinueAsNewVersioningBehavior::try_from(self.initial_versioning_behavior)
+                .map_err(|_| serde::ser::Error::custom(format!("Invalid variant {}", self.initial_versioning_behavior)))?;
+            struct_ser.serialize_field("initialVersioningBehavior", &v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for WorkflowExecutionContinuedAsNewEventAttributes {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "new_execution_run_id",
+            "newExecutionRunId",
+            "workflow_type",
+            "workflowType",
+            "task_queue",
+            "taskQueue",
+            "input",
+            "workflow_run_timeout",
+            "workflowRunTimeout",
+            "workflow_task_timeout",
+            "workflowTaskTimeout",
+            "workflow_task_completed_event_id",
+            "workflowTaskCompletedEventId",
+            "backoff_start_interval",
+            "backoffStartInterval",
+            "initiator",
+            "failure",
+            "last_completion_result",
+            "lastCompletionResult",
+            "header",
+            "memo",
+            "search_attributes",
+            "searchAttributes",
+            "inherit_build_id",
+            "inheritBuildId",
+            "initial_versioning_behavior",
+            "initialVersioningBehavior",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            NewExecutionRunId,
+            WorkflowType,
+            TaskQueue,
+            Input,
+            WorkflowRunTimeout,
+            WorkflowTaskTimeout,
+            WorkflowTaskCompletedEventId,
+            BackoffStartInterval,
+            Initiator,
+            Failure,
+            LastCompletionResult,
+            Header,
+            Memo,
+            SearchAttributes,
+            InheritBuildId,
+            InitialVersioningBehavior,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "newExecutionRunId" | "new_execution_run_id" => Ok(GeneratedField::NewExecutionRunId),
+                            "workflowType" | "workflow_type" => Ok(GeneratedField::WorkflowType),
+                            "taskQueue" | "task_queue" => Ok(GeneratedField::TaskQueue),
+                            "input" => Ok(GeneratedField::Input),
+                            "workflowRunTimeout" | "workflow_run_timeout" => Ok(GeneratedField::WorkflowRunTimeout),
+                            "workflowTaskTimeout" | "workflow_task_timeout" => Ok(GeneratedField::WorkflowTaskTimeout),
+                            "workflowTaskCompletedEventId" | "workflow_task_completed_event_id" => Ok(GeneratedField::WorkflowTaskCompletedEventId),
+                            "backoffStartInterval" | "backoff_start_interval" => Ok(GeneratedField::BackoffStartInterval),
+                            "initiator" => Ok(GeneratedField::Initiator),
+                            "failure" => Ok(GeneratedField::Failure),
+                            "lastCompletionResult" | "last_completion_result" => Ok(GeneratedField::LastCompletionResult),
+                            "header" => Ok(GeneratedField::Header),
+                            "memo" => Ok(GeneratedField::Memo),
+                            "searchAttributes" | "search_attributes" => Ok(GeneratedField::SearchAttributes),
+                            "inheritBuildId" | "inherit_build_id" => Ok(GeneratedField::InheritBuildId),
+                            "initialVersioningBehavior" | "initial_versioning_behavior" => Ok(GeneratedField::InitialVersioningBehavior),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = WorkflowExecutionContinuedAsNewEventAttributes;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct temporal.api.history.v1.WorkflowExecutionContinuedAsNewEventAttributes")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<WorkflowExecutionContinuedAsNewEventAttributes, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut new_execution_run_id__ = None;
+                let mut workflow_type__ = None;
+                let mut task_queue__ = None;
+                let mut input__ = None;
+                let mut workflow_run_timeout__ = None;
+                let mut workflow_task_timeout__ = None;
+                let mut workflow_task_completed_event_id__ = None;
+                let mut backoff_start_interval__ = None;
+                let mut initiator__ = None;
+                let mut failure__ = None;
+                let mut last_completion_result__ = None;
+                let mut header__ = None;
+                let mut memo__ = None;
+                let mut search_attributes__ = None;
+                let mut inherit_build_id__ = None;
+                let mut initial_versioning_behavior__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::NewExecutionRunId => {
+                            if new_execution_run_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("newExecutionRunId"));
+                            }
+                            new_execution_run_id__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::WorkflowType => {
+                            if workflow_type__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("workflowType"));
+                            }
+                            workflow_type__ = map_.next_value()?;
+                        }
+                        GeneratedField::TaskQueue => {
+                            if task_queue__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("taskQueue"));
+                            }
+                            task_queue__ = map_.next_value()?;
+                        }
+                        GeneratedField::Input => {
+                            if input__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("input"));
+                            }
+                            input__ = map_.next_value()?;
+                        }
+                        GeneratedField::WorkflowRunTimeout => {
+                            if workflow_run_timeout__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("workflowRunTimeout"));
+                            }
+                            workflow_run_timeout__ = map_.next_value()?;
+                        }
+                        GeneratedField::WorkflowTaskTimeout => {
+                            if workflow_task_timeout__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("workflowTaskTimeout"));
+                            }
+                            workflow_task_timeout__ = map_.next_value()?;
+                        }
+                        GeneratedField::WorkflowTaskCompletedEventId => {
+                            if workflow_task_completed_event_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("workflowTaskCompletedEventId"));
+                            }
+                            workflow_task_completed_event_id__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::BackoffStartInterval => {
+                            if backoff_start_interval__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("backoffStartInterval"));
+                            }
+                            backoff_start_interval__ = map_.next_value()?;
+                        }
+                        GeneratedField::Initiator => {
+                            if initiator__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("initiator"));
+                            }
+                            initiator__ = Some(map_.next_value::<super::super::enums::v1::ContinueAsNewInitiator>()? as i32);
+                        }
+                        GeneratedField::Failure => {
+                            if failure__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("failure"));
+                            }
+                            failure__ = map_.next_value()?;
+                        }
+                        GeneratedField::LastCompletionResult => {
+                            if last_completion_result__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("lastCompletionResult"));
+                            }
+                            last_completion_result__ = map_.next_value()?;
+                        }
+                        GeneratedField::Header => {
+                            if header__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("header"));
+                            }
+                            header__ = map_.next_value()?;
+                        }
+                        GeneratedField::Memo => {
+                            if memo__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("memo"));
+                            }
+                            memo__ = map_.next_value()?;
+                        }
+                        GeneratedField::SearchAttributes => {
+                            if search_attributes__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("searchAttributes"));
+                            }
+                            search_attributes__ = map_.next_value()?;
+                        }
+                        GeneratedField::InheritBuildId => {
+                            if inherit_build_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("inheritBuildId"));
+                            }
+                            inherit_build_id__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::InitialVersioningBehavior => {
+                            if initial_versioning_behavior__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("initialVersioningBehavior"));
+                            }
+                            initial_versioning_behavior__ = Some(map_.next_value::<super::super::enums::v1::ContinueAsNewVersioningBehavior>()? as i32);
+                        }
+                    }
+                }
+                Ok(WorkflowExecutionContinuedAsNewEventAttributes {
+                    new_execution_run_id: new_execution_run_id__.unwrap_or_default(),
+                    workflow_type: workflow_type__,
+                    task_queue: task_queue__,
+                    input: input__,
+                    workflow_run_timeout: workflow_run_timeout__,
+                    workflow_task_timeout: workflow_task_timeout__,
+                    workflow_task_completed_event_id: workflow_task_completed_event_id__.unwrap_or_default(),
+                    backoff_start_interval: backoff_start_interval__,
+                    initiator: initiator__.unwrap_or_default(),
+                    failure: failure__,
+                    last_completion_result: last_completion_result__,
+                    header: header__,
+                    memo: memo__,
+                    search_attributes: search_attributes__,
+                    inherit_build_id: inherit_build_id__.unwrap_or_default(),
+                    initial_versioning_behavior: initial_versioning_behavior__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("temporal.api.history.v1.WorkflowExecutionContinuedAsNewEventAttributes", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for WorkflowExecutionFailedEventAttributes {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.failure.is_some() {
+            len += 1;
+        }
+        if self.retry_state != 0 {
+            len += 1;
+        }
+        if self.workflow_task_completed_event_id != 0 {
+            len += 1;
+        }
+        if !self.new_execution_run_id.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("temporal.api.history.v1.WorkflowExecutionFailedEventAttributes", len)?;
+        if let Some(v) = self.failure.as_ref() {
+            struct_ser.serialize_field("failure", v)?;
+        }
+        if self.retry_state != 0 {
+            let v = super::super::enums::v1::RetryState::try_from(self.retry_state)
+                .map_err(|_| serde::ser::Error::custom(format!("Invalid variant {}", self.retry_state)))?;
+            struct_ser.serialize_field("retryState", &v)?;
+        }
+        if self.workflow_task_completed_event_id != 0 {
+            #[allow(clippy::needless_borrow)]
+            #[allow(clippy::needless_borrows_for_generic_args)]
+            struct_ser.serialize_field("workflowTaskCompletedEventId", ToString::to_string(&self.workflow_task_completed_event_id).as_str())?;
+        }
+        if !self.new_execution_run_id.is_empty() {
+            struct_ser.serialize_field("newExecutionRunId", &self.new_execution_run_id)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for WorkflowExecutionFailedEventAttributes {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "failure",
+            "retry_state",
+            "retryState",
+            "workflow_task_completed_event_id",
+            "workflowTaskCompletedEventId",
+            "new_execution_run_id",
+            "newExecutionRunId",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Failure,
+            RetryState,
+            WorkflowTaskCompletedEventId,
+            NewExecutionRunId,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "failure" => Ok(GeneratedField::Failure),
+                            "retryState" | "retry_state" => Ok(GeneratedField::RetryState),
+                            "workflowTaskCompletedEventId" | "workflow_task_completed_event_id" => Ok(GeneratedField::WorkflowTaskCompletedEventId),
+                            "newExecutionRunId" | "new_execution_run_id" => Ok(GeneratedField::NewExecutionRunId),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = WorkflowExecutionFailedEventAttributes;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct temporal.api.history.v1.WorkflowExecutionFailedEventAttributes")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<WorkflowExecutionFailedEventAttributes, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut failure__ = None;
+                let mut retry_state__ = None;
+                let mut workflow_task_completed_event_id__ = None;
+                let mut new_execution_run_id__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Failure => {
+                            if failure__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("failure"));
+                            }
+                            failure__ = map_.next_value()?;
+                        }
+                        GeneratedField::RetryState => {
+                            if retry_state__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("retryState"));
+                            }
+                            retry_state__ = Some(map_.next_value::<super::super::enums::v1::RetryState>()? as i32);
+                        }
+                        GeneratedField::WorkflowTaskCompletedEventId => {
+                            if workflow_task_completed_event_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("workflowTaskCompletedEventId"));
+                            }
+                            workflow_task_completed_event_id__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::NewExecutionRunId => {
+                            if new_execution_run_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("newExecutionRunId"));
+                            }
+                            new_execution_run_id__ = Some(map_.next_value()?);
+                        }
+                    }
+                }
+                Ok(WorkflowExecutionFailedEventAttributes {
+                    failure: failure__,
+                    retry_state: retry_state__.unwrap_or_default(),
+                    workflow_task_completed_event_id: workflow_task_completed_event_id__.unwrap_or_default(),
+                    new_execution_run_id: new_execution_run_id__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("temporal.api.history.v1.WorkflowExecutionFailedEventAttributes", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for WorkflowExecutionOptionsUpdatedEventAttributes {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.versioning_override.is_some() {
+            len += 1;
+        }
+        if self.unset_versioning_override {
+            len += 1;
+        }
+        if !self.attached_request_id.is_empty() {
+            len += 1;
+        }
+        if !self.attached_completion_callbacks.is_empty() {
+            len += 1;
+        }
+        if !self.identity.is_empty() {
+            len += 1;
+        }
+        if self.priority.is_some() {
+            len += 1;
+        }
+        if self.time_skipping_config.is_some() {
+            len += 1;
+        }
+        if !self.workflow_update_options.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("temporal.api.history.v1.WorkflowExecutionOptionsUpdatedEventAttributes", len)?;
+        if let Some(v) = self.versioning_override.as_ref() {
+            struct_ser.serialize_field("versioningOverride", v)?;
+        }
+        if self.unset_versioning_override {
+            struct_ser.serialize_field("unsetVersioningOverride", &self.unset_versioning_override)?;
+        }
+        if !self.attached_request_id.is_empty() {
+            struct_ser.serialize_field("attachedRequestId", &self.attached_request_id)?;
+        }
+        if !self.attached_completion_callbacks.is_empty() {
+            struct_ser.serialize_field("attachedCompletionCallbacks", &self.attached_completion_callbacks)?;
+        }
+        if !self.identity.is_empty() {
+            struct_ser.serialize_field("identity", &self.identity)?;
+        }
+        if let Some(v) = self.priority.as_ref() {
+            struct_ser.serialize_field("priority", v)?;
+        }
+        if let Some(v) = self.time_skipping_config.as_ref() {
+            struct_ser.serialize_field("timeSkippingConfig", v)?;
+        }
+        if !self.workflow_update_options.is_empty() {
+            struct_ser.serialize_field("workflowUpdateOptions", &self.workflow_update_options)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for WorkflowExecutionOptionsUpdatedEventAttributes {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "versioning_override",
+            "versioningOverride",
+            "unset_versioning_override",
+            "unsetVersioningOverride",
+            "attached_request_id",
+            "attachedRequestId",
+            "attached_completion_callbacks",
+            "attachedCompletionCallbacks",
+            "identity",
+            "priority",
+            "time_skipping_config",
+            "timeSkippingConfig",
+            "workflow_update_options",
+            "workflowUpdateOptions",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            VersioningOverride,
+            UnsetVersioningOverride,
+            AttachedRequestId,
+            AttachedCompletionCallbacks,
+            Identity,
+            Priority,
+            TimeSkippingConfig,
+            WorkflowUpdateOptions,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "versioningOverride" | "versioning_override" => Ok(GeneratedField::VersioningOverride),
+                            "unsetVersioningOverride" | "unset_versioning_override" => Ok(GeneratedField::UnsetVersioningOverride),
+                            "attachedRequestId" | "attached_request_id" => Ok(GeneratedField::AttachedRequestId),
+                            "attachedCompletionCallbacks" | "attached_completion_callbacks" => Ok(GeneratedField::AttachedCompletionCallbacks),
+                            "identity" => Ok(GeneratedField::Identity),
+                            "priority" => Ok(GeneratedField::Priority),
+                            "timeSkippingConfig" | "time_skipping_config" => Ok(GeneratedField::TimeSkippingConfig),
+                            "workflowUpdateOptions" | "workflow_update_options" => Ok(GeneratedField::WorkflowUpdateOptions),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = WorkflowExecutionOptionsUpdatedEventAttributes;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct temporal.api.history.v1.WorkflowExecutionOptionsUpdatedEventAttributes")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<WorkflowExecutionOptionsUpdatedEventAttributes, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut versioning_override__ = None;
+                let mut unset_versioning_override__ = None;
+                let mut attached_request_id__ = None;
+                let mut attached_completion_callbacks__ = None;
+                let mut identity__ = None;
+                let mut priority__ = None;
+                let mut time_skipping_config__ = None;
+                let mut workflow_update_options__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::VersioningOverride => {
+                            if versioning_override__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("versioningOverride"));
+                            }
+                            versioning_override__ = map_.next_value()?;
+                        }
+                        GeneratedField::UnsetVersioningOverride => {
+                            if unset_versioning_override__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("unsetVersioningOverride"));
+                            }
+                            unset_versioning_override__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::AttachedRequestId => {
+                            if attached_request_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("attachedRequestId"));
+                            }
+                            attached_request_id__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::AttachedCompletionCallbacks => {
+                            if attached_completion_callbacks__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("attachedCompletionCallbacks"));
+                            }
+                            attached_completion_callbacks__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::Identity => {
+                            if identity__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("identity"));
+                            }
+                            identity__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::Priority => {
+                            if priority__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("priority"));
+                            }
+                            priority__ = map_.next_value()?;
+                        }
+                        GeneratedField::TimeSkippingConfig => {
+                            if time_skipping_config__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("timeSkippingConfig"));
+                            }
+                            time_skipping_config__ = map_.next_value()?;
+                        }
+                        GeneratedField::WorkflowUpdateOptions => {
+                            if workflow_update_options__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("workflowUpdateOptions"));
+                            }
+                            workflow_update_options__ = Some(map_.next_value()?);
+                        }
+                    }
+                }
+                Ok(WorkflowExecutionOptionsUpdatedEventAttributes {
+                    versioning_override: versioning_override__,
+                    unset_versioning_override: unset_versioning_override__.unwrap_or_default(),
+                    attached_request_id: attached_request_id__.unwrap_or_default(),
+                    attached_completion_callbacks: attached_completion_callbacks__.unwrap_or_default(),
+                    identity: identity__.unwrap_or_default(),
+                    priority: priority__,
+                    time_skipping_config: time_skipping_config__,
+                    workflow_update_options: workflow_update_options__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("temporal.api.history.v1.WorkflowExecutionOptionsUpdatedEventAttributes", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for workflow_execution_options_updated_event_attributes::WorkflowUpdateOptionsUpdate {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.update_id.is_empty() {
+            len += 1;
+        }
+        if !self.attached_request_id.is_empty() {
+            len += 1;
+        }
+        if !self.attached_completion_callbacks.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("temporal.api.history.v1.WorkflowExecutionOptionsUpdatedEventAttributes.WorkflowUpdateOptionsUpdate", len)?;
+        if !self.update_id.is_empty() {
+            struct_ser.serialize_field("updateId", &self.update_id)?;
+        }
+        if !self.attached_request_id.is_empty() {
+            struct_ser.serialize_field("attachedRequestId", &self.attached_request_id)?;
+        }
+        if !self.attached_completion_callbacks.is_empty() {
+            struct_ser.serialize_field("attachedCompletionCallbacks", &self.attached_completion_callbacks)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for workflow_execution_options_updated_event_attributes::WorkflowUpdateOptionsUpdate {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "update_id",
+            "updateId",
+            "attached_request_id",
+            "attachedRequestId",
+            "attached_completion_callbacks",
+            "attachedCompletionCallbacks",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            UpdateId,
+            AttachedRequestId,
+            AttachedCompletionCallbacks,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "updateId" | "update_id" => Ok(GeneratedField::UpdateId),
+                            "attachedRequestId" | "attached_request_id" => Ok(GeneratedField::AttachedRequestId),
+                            "attachedCompletionCallbacks" | "attached_completion_callbacks" => Ok(GeneratedField::AttachedCompletionCallbacks),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = workflow_execution_options_updated_event_attributes::WorkflowUpdateOptionsUpdate;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct temporal.api.history.v1.WorkflowExecutionOptionsUpdatedEventAttributes.WorkflowUpdateOptionsUpdate")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<workflow_execution_options_updated_event_attributes::WorkflowUpdateOptionsUpdate, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut update_id__ = None;
+                let mut attached_request_id__ = None;
+                let mut attached_completion_callbacks__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::UpdateId => {
+                            if update_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("updateId"));
+                            }
+                            update_id__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::AttachedRequestId => {
+                            if attached_request_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("attachedRequestId"));
+                            }
+                            attached_request_id__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::AttachedCompletionCallbacks => {
+                            if attached_completion_callbacks__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("attachedCompletionCallbacks"));
+                            }
+                            attached_completion_callbacks__ = Some(map_.next_value()?);
+                        }
+                    }
+                }
+                Ok(workflow_execution_options_updated_event_attributes::WorkflowUpdateOptionsUpdate {
+                    update_id: update_id__.unwrap_or_default(),
+                    attached_request_id: attached_request_id__.unwrap_or_default(),
+                    attached_completion_callbacks: attached_completion_callbacks__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("temporal.api.history.v1.WorkflowExecutionOptionsUpdatedEventAttributes.WorkflowUpdateOptionsUpdate", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for WorkflowExecutionPausedEventAttributes {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.identity.is_empty() {
+            len += 1;
+        }
+        if !self.reason.is_empty() {
+            len += 1;
+        }
+        if !self.request_id.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("temporal.api.history.v1.WorkflowExecutionPausedEventAttributes", len)?;
+        if !self.identity.is_empty() {
+            struct_ser.serialize_field("identity", &self.identity)?;
+        }
+        if !self.reason.is_empty() {
+            struct_ser.serialize_field("reason", &self.reason)?;
+        }
+        if !self.request_id.is_empty() {
+            struct_ser.serialize_field("requestId", &self.request_id)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for WorkflowExecutionPausedEventAttributes {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "identity",
+            "reason",
+            "request_id",
+            "requestId",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Identity,
+            Reason,
+            RequestId,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "identity" => Ok(GeneratedField::Identity),
+                            "reason" => Ok(GeneratedField::Reason),
+                            "requestId" | "request_id" => Ok(GeneratedField::RequestId),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = WorkflowExecutionPausedEventAttributes;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct temporal.api.history.v1.WorkflowExecutionPausedEventAttributes")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<WorkflowExecutionPausedEventAttributes, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut identity__ = None;
+                let mut reason__ = None;
+                let mut request_id__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Identity => {
+                            if identity__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("identity"));
+                            }
+                            identity__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::Reason => {
+                            if reason__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("reason"));
+                            }
+                            reason__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::RequestId => {
+                            if request_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("requestId"));
+                            }
+                            request_id__ = Some(map_.next_value()?);
+                        }
+                    }
+                }
+                Ok(WorkflowExecutionPausedEventAttributes {
+                    identity: identity__.unwrap_or_default(),
+                    reason: reason__.unwrap_or_default(),
+                    request_id: request_id__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("temporal.api.history.v1.WorkflowExecutionPausedEventAttributes", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for WorkflowExecutionSignaledEventAttributes {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.signal_name.is_empty() {
+            len += 1;
+        }
+        if self.input.is_some() {
+            len += 1;
+        }
+        if !self.identity.is_empty() {
+            len += 1;
+        }
+        if self.header.is_some() {
+            len += 1;
+        }
+        if self.skip_generate_workflow_task {
+            len += 1;
+        }
+        if self.external_workflow_execution.is_some() {
+            len += 1;
+        }
+        if !self.request_id.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("temporal.api.history.v1.WorkflowExecutionSignaledEventAttributes", len)?;
+        if !self.signal_name.is_empty() {
+            struct_ser.serialize_field("signalName", &self.signal_name)?;
+        }
+        if let Some(v) = self.input.as_ref() {
+            struct_ser.serialize_field("input", v)?;
+        }
+        if !self.identity.is_empty() {
+            struct_ser.serialize_field("identity", &self.identity)?;
+        }
+        if let Some(v) = self.header.as_ref() {
+            struct_ser.serialize_field("header", v)?;
+        }
+        if self.skip_generate_workflow_task {
+            struct_ser.serialize_field("skipGenerateWorkflowTask", &self.skip_generate_workflow_task)?;
+        }
+        if let Some(v) = self.external_workflow_execution.as_ref() {
+            struct_ser.serialize_field("externalWorkflowExecution", v)?;
+        }
+        if !self.request_id.is_empty() {
+            struct_ser.serialize_field("requestId", &self.request_id)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for WorkflowExecutionSignaledEventAttributes {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "signal_name",
+            "signalName",
+            "input",
+            "identity",
+            "header",
+            "skip_generate_workflow_task",
+            "skipGenerateWorkflowTask",
+            "external_workflow_execution",
+            "externalWorkflowExecution",
+            "request_id",
+            "requestId",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            SignalName,
+            Input,
+            Identity,
+            Header,
+            SkipGenerateWorkflowTask,
+            ExternalWorkflowExecution,
+            RequestId,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "signalName" | "signal_name" => Ok(GeneratedField::SignalName),
+                            "input" => Ok(GeneratedField::Input),
+                            "identity" => Ok(GeneratedField::Identity),
+                            "header" => Ok(GeneratedField::Header),
+                            "skipGenerateWorkflowTask" | "skip_generate_workflow_task" => Ok(GeneratedField::SkipGenerateWorkflowTask),
+                            "externalWorkflowExecution" | "external_workflow_execution" => Ok(GeneratedField::ExternalWorkflowExecution),
+                            "requestId" | "request_id" => Ok(GeneratedField::RequestId),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = WorkflowExecutionSignaledEventAttributes;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct temporal.api.history.v1.WorkflowExecutionSignaledEventAttributes")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<WorkflowExecutionSignaledEventAttributes, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut signal_name__ = None;
+                let mut input__ = None;
+                let mut identity__ = None;
+                let mut header__ = None;
+                let mut skip_generate_workflow_task__ = None;
+                let mut external_workflow_execution__ = None;
+                let mut request_id__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::SignalName => {
+                            if signal_name__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("signalName"));
+                            }
+                            signal_name__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::Input => {
+                            if input__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("input"));
+                            }
+                            input__ = map_.next_value()?;
+                        }
+                        GeneratedField::Identity => {
+                            if identity__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("identity"));
+                            }
+                            identity__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::Header => {
+                            if header__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("header"));
+                            }
+                            header__ = map_.next_value()?;
+                        }
+                        GeneratedField::SkipGenerateWorkflowTask => {
+                            if skip_generate_workflow_task__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("skipGenerateWorkflowTask"));
+                            }
+                            skip_generate_workflow_task__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::ExternalWorkflowExecution => {
+                            if external_workflow_execution__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("externalWorkflowExecution"));
+                            }
+                            external_workflow_execution__ = map_.next_value()?;
+                        }
+                        GeneratedField::RequestId => {
+                            if request_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("requestId"));
+                            }
+                            request_id__ = Some(map_.next_value()?);
+                        }
+                    }
+                }
+                Ok(WorkflowExecutionSignaledEventAttributes {
+                    signal_name: signal_name__.unwrap_or_default(),
+                    input: input__,
+                    identity: identity__.unwrap_or_default(),
+                    header: header__,
+                    skip_generate_workflow_task: skip_generate_workflow_task__.unwrap_or_default(),
+                    external_workflow_execution: external_workflow_execution__,
+                    request_id: request_id__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("temporal.api.history.v1.WorkflowExecutionSignaledEventAttributes", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for WorkflowExecutionStartedEventAttributes {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.workflow_type.is_some() {
+            len += 1;
+        }
+        if !self.parent_workflow_namespace.is_empty() {
+            len += 1;
+        }
+        if !self.parent_workflow_namespace_id.is_empty() {
+            len += 1;
+        }
+        if self.parent_workflow_execution.is_some() {
+            len += 1;
+        }
+        if self.parent_initiated_event_id != 0 {
+            len += 1;
+        }
+        if self.task_queue.is_some() {
+            len += 1;
+        }
+        if self.input.is_some() {
+            len += 1;
+        }
+        if self.workflow_execution_timeout.is_some() {
+            len += 1;
+        }
+        if self.workflow_run_timeout.is_some() {
+            len += 1;
+        }
+        if self.workflow_task_timeout.is_some() {
+            len += 1;
+        }
+        if !self.continued_execution_run_id.is_empty() {
+            len += 1;
+        }
+        if self.initiator != 0 {
+            len += 1;
+        }
+        if self.continued_failure.is_some() {
+            len += 1;
+        }
+        if self.last_completion_result.is_some() {
+            len += 1;
+        }
+        if !self.original_execution_run_id.is_empty() {
+            len += 1;
+        }
+        if !self.identity.is_empty() {
+            len += 1;
+        }
+        if !self.first_execution_run_id.is_empty() {
+            len += 1;
+        }
+        if self.retry_policy.is_some() {
+            len += 1;
+        }
+        if self.attempt != 0 {
+            len += 1;
+        }
+        if self.workflow_execution_expiration_time.is_some() {
+            len += 1;
+        }
+        if !self.cron_schedule.is_empty() {
+            len += 1;
+        }
+        if self.first_workflow_task_backoff.is_some() {
+            len += 1;
+        }
+        if self.memo.is_some() {
+            len += 1;
+        }
+        if self.search_attributes.is_some() {
+            len += 1;
+        }
+        if self.prev_auto_reset_points.is_some() {
+            len += 1;
+        }
+        if self.header.is_some() {
+            len += 1;
+        }
+        if self.parent_initiated_event_version != 0 {
+            len += 1;
+        }
+        if !self.workflow_id.is_empty() {
+            len += 1;
+        }
+        if self.source_version_stamp.is_some() {
+            len += 1;
+        }
+        if !self.completion_callbacks.is_empty() {
+            len += 1;
+        }
+        if self.root_workflow_execution.is_some() {
+            len += 1;
+        }
+        if !self.inherited_build_id.is_empty() {
+            len += 1;
+        }
+        if self.versioning_override.is_some() {
+            len += 1;
+        }
+        if !self.parent_pinned_worker_deployment_version.is_empty() {
+            len += 1;
+        }
+        if self.priority.is_some() {
+            len += 1;
+        }
+        if self.inherited_pinned_version.is_some() {
+            len += 1;
+        }
+        if self.inherited_auto_upgrade_info.is_some() {
+            len += 1;
+        }
+        if self.eager_execution_accepted {
+            len += 1;
+        }
+        if self.declined_target_version_upgrade.is_some() {
+            len += 1;
+        }
+        if self.time_skipping_config.is_some() {
+            len += 1;
+        }
+        if self.initial_skipped_duration.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("temporal.api.history.v1.WorkflowExecutionStartedEventAttributes", len)?;
+        if let Some(v) = self.workflow_type.as_ref() {
+            struct_ser.serialize_field("workflowType", v)?;
+        }
+        if !self.parent_workflow_namespace.is_empty() {
+            struct_ser.serialize_field("parentWorkflowNamespace", &self.parent_workflow_namespace)?;
+        }
+        if !self.parent_workflow_namespace_id.is_empty() {
+            struct_ser.serialize_field("parentWorkflowNamespaceId", &self.parent_workflow_namespace_id)?;
+        }
+        if let Some(v) = self.parent_workflow_execution.as_ref() {
+            struct_ser.serialize_field("parentWorkflowExecution", v)?;
+        }
+        if self.parent_initiated_event_id != 0 {
+            #[allow(clippy::needless_borrow)]
+            #[allow(clippy::needless_borrows_for_generic_args)]
+            struct_ser.serialize_field("parentInitiatedEventId", ToString::to_string(&self.parent_initiated_event_id).as_str())?;
+        }
+        if let Some(v) = self.task_queue.as_ref() {
+            struct_ser.serialize_field("taskQueue", v)?;
+        }
+        if let Some(v) = self.input.as_ref() {
+            struct_ser.serialize_field("input", v)?;
+        }
+        if let Some(v) = self.workflow_execution_timeout.as_ref() {
+            struct_ser.serialize_field("workflowExecutionTimeout", v)?;
+        }
+        if let Some(v) = self.workflow_run_timeout.as_ref() {
+            struct_ser.serialize_field("workflowRunTimeout", v)?;
+        }
+        if let Some(v) = self.workflow_task_timeout.as_ref() {
+            struct_ser.serialize_field("workflowTaskTimeout", v)?;
+        }
+        if !self.continued_execution_run_id.is_empty() {
+            struct_ser.serialize_field("continuedExecutionRunId", &self.continued_execution_run_id)?;
+        }
+        if self.initiator != 0 {
+            let v = super::super::enums::v1::ContinueAsNewInitiator::try_from(self.initiator)
+                .map_err(|_| serde::ser::Error::custom(format!("Invalid variant {}", self.initiator)))?;
+            struct_ser.serialize_field("initiator", &v)?;
+        }
+        if let Some(v) = self.continued_failure.as_ref() {
+            struct_ser.serialize_field("continuedFailure", v)?;
+        }
+        if let Some(v) = self.last_completion_result.as_ref() {
+            struct_ser.serialize_field("lastCompletionResult", v)?;
+        }
+        if !self.original_execution_run_id.is_empty() {
+            struct_ser.serialize_field("originalExecutionRunId", &self.original_execution_run_id)?;
+        }
+        if !self.identity.is_empty() {
+            struct_ser.serialize_field("identity", &self.identity)?;
+        }
+        if !self.first_execution_run_id.is_empty() {
+            struct_ser.serialize_field("firstExecutionRunId", &self.first_execution_run_id)?;
+        }
+        if let Some(v) = self.retry_policy.as_ref() {
+            struct_ser.serialize_field("retryPolicy", v)?;
+        }
+        if self.attempt != 0 {
+            struct_ser.serialize_field("attempt", &self.attempt)?;
+        }
+        if let Some(v) = self.workflow_execution_expiration_time.as_ref() {
+            struct_ser.serialize_field("workflowExecutionExpirationTime", v)?;
+        }
+        if !self.cron_schedule.is_empty() {
+            struct_ser.serialize_field("cronSchedule", &self.cron_schedule)?;
+        }
+        if let Some(v) = self.first_workflow_task_backoff.as_ref() {
+            struct_ser.serialize_field("firstWorkflowTaskBackoff", v)?;
+        }
+        if let Some(v) = self.memo.as_ref() {
+            struct_ser.serialize_field("memo", v)?;
+        }
+        if let Some(v) = self.search_attributes.as_ref() {
+            struct_ser.serialize_field("searchAttributes", v)?;
+        }
+        if let Some(v) = self.prev_auto_reset_points.as_ref() {
+            struct_ser.serialize_field("prevAutoResetPoints", v)?;
+        }
+        if let Some(v) = self.header.as_ref() {
+            struct_ser.serialize_field("header", v)?;
+        }
+        if self.parent_initiated_event_version != 0 {
+            #[allow(clippy::needless_borrow)]
+            #[allow(clippy::needless_borrows_for_generic_args)]
+            struct_ser.serialize_field("parentInitiatedEventVersion", ToString::to_string(&self.parent_initiated_event_version).as_str())?;
+        }
+        if !self.workflow_id.is_empty() {
+            struct_ser.serialize_field("workflowId", &self.workflow_id)?;
+        }
+        if let Some(v) = self.source_version_stamp.as_ref() {
+            struct_ser.serialize_field("sourceVersionStamp", v)?;
+        }
+        if !self.completion_callbacks.is_empty() {
+            struct_ser.serialize_field("completionCallbacks", &self.completion_callbacks)?;
+        }
+        if let Some(v) = self.root_workflow_execution.as_ref() {
+            struct_ser.serialize_field("rootWorkflowExecution", v)?;
+        }
+        if !self.inherited_build_id.is_empty() {
+            struct_ser.serialize_field("inheritedBuildId", &self.inherited_build_id)?;
+        }
+        if let Some(v) = self.versioning_override.as_ref() {
+            struct_ser.serialize_field("versioningOverride", v)?;
+        }
+        if !self.parent_pinned_worker_deployment_version.is_empty() {
+            struct_ser.serialize_field("parentPinnedWorkerDeploymentVersion", &self.parent_pinned_worker_deployment_version)?;
+        }
+        if let Some(v) = self.priority.as_ref() {
+            struct_ser.serialize_field("priority", v)?;
+        }
+        if let Some(v) = self.inherited_pinned_version.as_ref() {
+            struct_ser.serialize_field("inheritedPinnedVersion", v)?;
+        }
+        if let Some(v) = self.inherited_auto_upgrade_info.as_ref() {
+            struct_ser.serialize_field("inheritedAutoUpgradeInfo", v)?;
+        }
+        if self.eager_execution_accepted {
+            struct_ser.serialize_field("eagerExecutionAccepted", &self.eager_execution_accepted)?;
+        }
+        if let Some(v) = self.declined_target_version_upgrade.as_ref() {
+            struct_ser.serialize_field("declinedTargetVersionUpgrade", v)?;
+        }
+        if let Some(v) = self.time_skipping_config.as_ref() {
+            struct_ser.serialize_field("timeSkippingConfig", v)?;
+        }
+        if let Some(v) = self.initial_skipped_duration.as_ref() {
+            struct_ser.serialize_field("initialSkippedDuration", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for WorkflowExecutionStartedEventAttributes {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "workflow_type",
+            "workflowType",
+            "parent_workflow_namespace",
+            "parentWorkflowNamespace",
+            "parent_workflow_namespace_id",
+            "parentWorkflowNamespaceId",
+            "parent_workflow_execution",
+            "parentWorkflowExecution",
+            "parent_initiated_event_id",
+            "parentInitiatedEventId",
+            "task_queue",
+            "taskQueue",
+            "input",
+            "workflow_execution_timeout",
+            "workflowExecutionTimeout",
+            "workflow_run_timeout",
+            "workflowRunTimeout",
+            "workflow_task_timeout",
+            "workflowTaskTimeout",
+            "continued_execution_run_id",
+            "continuedExecutionRunId",
+            "initiator",
+            "continued_failure",
+            "continuedFailure",
+            "last_completion_result",
+            "lastCompletionResult",
+            "original_execution_run_id",
+            "originalExecutionRunId",
+            "identity",
+            "first_execution_run_id",
+            "firstExecutionRunId",
+            "retry_policy",
+            "retryPolicy",
+            "attempt",
+            "workflow_execution_expiration_time",
+            "workflowExecutionExpirationTime",
+            "cron_schedule",
+            "cronSchedule",
+            "first_workflow_task_backoff",
+            "firstWorkflowTaskBackoff",
+            "memo",
+            "search_attributes",
+            "searchAttributes",
+            "prev_auto_reset_points",
+            "prevAutoResetPoints",
+            "header",
+            "parent_initiated_event_version",
+            "parentInitiatedEventVersion",
+            "workflow_id",
+            "workflowId",
+            "source_version_stamp",
+            "sourceVersionStamp",
+            "completion_callbacks",
+            "completionCallbacks",
+            "root_workflow_execution",
+            "rootWorkflowExecution",
+            "inherited_build_id",
+            "inheritedBuildId",
+            "versioning_override",
+            "versioningOverride",
+            "parent_pinned_worker_deployment_version",
+            "parentPinnedWorkerDeploymentVersion",
+            "priority",
+            "inherited_pinned_version",
+            "inheritedPinnedVersion",
+            "inherited_auto_upgrade_info",
+            "inheritedAutoUpgradeInfo",
+            "eager_execution_accepted",
+            "eagerExecutionAccepted",
+            "declined_target_version_upgrade",
+            "declinedTargetVersionUpgrade",
+            "time_skipping_config",
+            "timeSkippingConfig",
+            "initial_skipped_duration",
+            "initialSkippedDuration",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            WorkflowType,
+            ParentWorkflowNamespace,
+            ParentWorkflowNamespaceId,
+            ParentWorkflowExecution,
+            ParentInitiatedEventId,
+            TaskQueue,
+            Input,
+            WorkflowExecutionTimeout,
+            WorkflowRunTimeout,
+            WorkflowTaskTimeout,
+            ContinuedExecutionRunId,
+            Initiator,
+            ContinuedFailure,
+            LastCompletionResult,
+            OriginalExecutionRunId,
+            Identity,
+            FirstExecutionRunId,
+            RetryPolicy,
+            Attempt,
+            WorkflowExecutionExpirationTime,
+            CronSchedule,
+            FirstWorkflowTaskBackoff,
+            Memo,
+            SearchAttributes,
+            PrevAutoResetPoints,
+            Header,
+            ParentInitiatedEventVersion,
+            WorkflowId,
+            SourceVersionStamp,
+            CompletionCallbacks,
+            RootWorkflowExecution,
+            InheritedBuildId,
+            VersioningOverride,
+            ParentPinnedWorkerDeploymentVersion,
+            Priority,
+            InheritedPinnedVersion,
+            InheritedAutoUpgradeInfo,
+            EagerExecutionAccepted,
+            DeclinedTargetVersionUpgrade,
+            TimeSkippingConfig,
+            InitialSkippedDuration,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "workflowType" | "workflow_type" => Ok(GeneratedField::WorkflowType),
+                            "parentWorkflowNamespace" | "parent_workflow_namespace" => Ok(GeneratedField::ParentWorkflowNamespace),
+                            "parentWorkflowNamespaceId" | "parent_workflow_namespace_id" => Ok(GeneratedField::ParentWorkflowNamespaceId),
+                            "parentWorkflowExecution" | "parent_workflow_execution" => Ok(GeneratedField::ParentWorkflowExecution),
+                            "parentInitiatedEventId" | "parent_initiated_event_id" => Ok(GeneratedField::ParentInitiatedEventId),
+                            "taskQueue" | "task_queue" => Ok(GeneratedField::TaskQueue),
+                            "input" => Ok(GeneratedField::Input),
+                            "workflowExecutionTimeout" | "workflow_execution_timeout" => Ok(GeneratedField::WorkflowExecutionTimeout),
+                            "workflowRunTimeout" | "workflow_run_timeout" => Ok(GeneratedField::WorkflowRunTimeout),
+                            "workflowTaskTimeout" | "workflow_task_timeout" => Ok(GeneratedField::WorkflowTaskTimeout),
+                            "continuedExecutionRunId" | "continued_execution_run_id" => Ok(GeneratedField::ContinuedExecutionRunId),
+                            "initiator" => Ok(GeneratedField::Initiator),
+                            "continuedFailure" | "continued_failure" => Ok(GeneratedField::ContinuedFailure),
+                            "lastCompletionResult" | "last_completion_result" => Ok(GeneratedField::LastCompletionResult),
+                            "originalExecutionRunId" | "original_execution_run_id" => Ok(GeneratedField::OriginalExecutionRunId),
+                            "identity" => Ok(GeneratedField::Identity),
+                            "firstExecutionRunId" | "first_execution_run_id" => Ok(GeneratedField::FirstExecutionRunId),
+                            "retryPolicy" | "retry_policy" => Ok(GeneratedField::RetryPolicy),
+                            "attempt" => Ok(GeneratedField::Attempt),
+                            "workflowExecutionExpirationTime" | "workflow_execution_expiration_time" => Ok(GeneratedField::WorkflowExecutionExpirationTime),
+                            "cronSchedule" | "cron_schedule" => Ok(GeneratedField::CronSchedule),
+                            "firstWorkflowTaskBackoff" | "first_workflow_task_backoff" => Ok(GeneratedField::FirstWorkflowTaskBackoff),
+                            "memo" => Ok(GeneratedField::Memo),
+                            "searchAttributes" | "search_attributes" => Ok(GeneratedField::SearchAttributes),
+                            "prevAutoResetPoints" | "prev_auto_reset_points" => Ok(GeneratedField::PrevAutoResetPoints),
+                            "header" => Ok(GeneratedField::Header),
+                            "parentInitiatedEventVersion" | "parent_initiated_event_version" => Ok(GeneratedField::ParentInitiatedEventVersion),
+                            "workflowId" | "workflow_id" => Ok(GeneratedField::WorkflowId),
+                            "sourceVersionStamp" | "source_version_stamp" => Ok(GeneratedField::SourceVersionStamp),
+                            "completionCallbacks" | "completion_callbacks" => Ok(GeneratedField::CompletionCallbacks),
+                            "rootWorkflowExecution" | "root_workflow_execution" => Ok(GeneratedField::RootWorkflowExecution),
+                            "inheritedBuildId" | "inherited_build_id" => Ok(GeneratedField::InheritedBuildId),
+                            "versioningOverride" | "versioning_override" => Ok(GeneratedField::VersioningOverride),
+                            "parentPinnedWorkerDeploymentVersion" | "parent_pinned_worker_deployment_version" => Ok(GeneratedField::ParentPinnedWorkerDeploymentVersion),
+                            "priority" => Ok(GeneratedField::Priority),
+                            "inheritedPinnedVersion" | "inherited_pinned_version" => Ok(GeneratedField::InheritedPinnedVersion),
+                            "inheritedAutoUpgradeInfo" | "inherited_auto_upgrade_info" => Ok(GeneratedField::InheritedAutoUpgradeInfo),
+                            "eagerExecutionAccepted" | "eager_execution_accepted" => Ok(GeneratedField::EagerExecutionAccepted),
+                            "declinedTargetVersionUpgrade" | "declined_target_version_upgrade" => Ok(GeneratedField::DeclinedTargetVersionUpgrade),
+                            "timeSkippingConfig" | "time_skipping_config" => Ok(GeneratedField::TimeSkippingConfig),
+                            "initialSkippedDuration" | "initial_skipped_duration" => Ok(GeneratedField::InitialSkippedDuration),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = WorkflowExecutionStartedEventAttributes;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct temporal.api.history.v1.WorkflowExecutionStartedEventAttributes")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<WorkflowExecutionStartedEventAttributes, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut workflow_type__ = None;
+                let mut parent_workflow_namespace__ = None;
+                let mut parent_workflow_namespace_id__ = None;
+                let mut parent_workflow_execution__ = None;
+                let mut parent_initiated_event_id__ = None;
+                let mut task_queue__ = None;
+                let mut input__ = None;
+                let mut workflow_execution_timeout__ = None;
+                let mut workflow_run_timeout__ = None;
+                let mut workflow_task_timeout__ = None;
+                let mut continued_execution_run_id__ = None;
+                let mut initiator__ = None;
+                let mut continued_failure__ = None;
+                let mut last_completion_result__ = None;
+                let mut original_execution_run_id__ = None;
+                let mut identity__ = None;
+                let mut first_execution_run_id__ = None;
+                let mut retry_policy__ = None;
+                let mut attempt__ = None;
+                let mut workflow_execution_expiration_time__ = None;
+                let mut cron_schedule__ = None;
+                let mut first_workflow_task_backoff__ = None;
+                let mut memo__ = None;
+                let mut search_attributes__ = None;
+                let mut prev_auto_reset_points__ = None;
+                let mut header__ = None;
+                let mut parent_initiated_event_version__ = None;
+                let mut workflow_id__ = None;
+                let mut source_version_stamp__ = None;
+                let mut completion_callbacks__ = None;
+                let mut root_workflow_execution__ = None;
+                let mut inherited_build_id__ = None;
+                let mut versioning_override__ = None;
+                let mut parent_pinned_worker_deployment_version__ = None;
+                let mut priority__ = None;
+                let mut inherited_pinned_version__ = None;
+                let mut inherited_auto_upgrade_info__ = None;
+                let mut eager_execution_accepted__ = None;
+                let mut declined_target_version_upgrade__ = None;
+                let mut time_skipping_config__ = None;
+                let mut initial_skipped_duration__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::WorkflowType => {
+                            if workflow_type__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("workflowType"));
+                            }
+                            workflow_type__ = map_.next_value()?;
+                        }
+                        GeneratedField::ParentWorkflowNamespace => {
+                            if parent_workflow_namespace__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("parentWorkflowNamespace"));
+                            }
+                            parent_workflow_namespace__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::ParentWorkflowNamespaceId => {
+                            if parent_workflow_namespace_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("parentWorkflowNamespaceId"));
+                            }
+                            parent_workflow_namespace_id__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::ParentWorkflowExecution => {
+                            if parent_workflow_execution__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("parentWorkflowExecution"));
+                            }
+                            parent_workflow_execution__ = map_.next_value()?;
+                        }
+                        GeneratedField::ParentInitiatedEventId => {
+                            if parent_initiated_event_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("parentInitiatedEventId"));
+                            }
+                            parent_initiated_event_id__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::TaskQueue => {
+                            if task_queue__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("taskQueue"));
+                            }
+                            task_queue__ = map_.next_value()?;
+                        }
+                        GeneratedField::Input => {
+                            if input__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("input"));
+                            }
+                            input__ = map_.next_value()?;
+                        }
+                        GeneratedField::WorkflowExecutionTimeout => {
+                            if workflow_execution_timeout__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("workflowExecutionTimeout"));
+                            }
+                            workflow_execution_timeout__ = map_.next_value()?;
+                        }
+                        GeneratedField::WorkflowRunTimeout => {
+                            if workflow_run_timeout__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("workflowRunTimeout"));
+                            }
+                            workflow_run_timeout__ = map_.next_value()?;
+                        }
+                        GeneratedField::WorkflowTaskTimeout => {
+                            if workflow_task_timeout__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("workflowTaskTimeout"));
+                            }
+                            workflow_task_timeout__ = map_.next_value()?;
+                        }
+                        GeneratedField::ContinuedExecutionRunId => {
+                            if continued_execution_run_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("continuedExecutionRunId"));
+                            }
+                            continued_execution_run_id__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::Initiator => {
+                            if initiator__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("initiator"));
+                            }
+                            initiator__ = Some(map_.next_value::<super::super::enums::v1::ContinueAsNewInitiator>()? as i32);
+                        }
+                        GeneratedField::ContinuedFailure => {
+                            if continued_failure__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("continuedFailure"));
+                            }
+                            continued_failure__ = map_.next_value()?;
+                        }
+                        GeneratedField::LastCompletionResult => {
+                            if last_completion_result__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("lastCompletionResult"));
+                            }
+                            last_completion_result__ = map_.next_value()?;
+                        }
+                        GeneratedField::OriginalExecutionRunId => {
+                            if original_execution_run_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("originalExecutionRunId"));
+                            }
+                            original_execution_run_id__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::Identity => {
+                            if identity__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("identity"));
+                            }
+                            identity__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::FirstExecutionRunId => {
+                            if first_execution_run_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("firstExecutionRunId"));
+                            }
+                            first_execution_run_id__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::RetryPolicy => {
+                            if retry_policy__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("retryPolicy"));
+                            }
+                            retry_policy__ = map_.next_value()?;
+                        }
+                        GeneratedField::Attempt => {
+                            if attempt__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("attempt"));
+                            }
+                            attempt__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::WorkflowExecutionExpirationTime => {
+                            if workflow_execution_expiration_time__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("workflowExecutionExpirationTime"));
+                            }
+                            workflow_execution_expiration_time__ = map_.next_value()?;
+                        }
+                        GeneratedField::CronSchedule => {
+                            if cron_schedule__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("cronSchedule"));
+                            }
+                            cron_schedule__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::FirstWorkflowTaskBackoff => {
+                            if first_workflow_task_backoff__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("firstWorkflowTaskBackoff"));
+                            }
+                            first_workflow_task_backoff__ = map_.next_value()?;
+                        }
+                        GeneratedField::Memo => {
+                            if memo__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("memo"));
+                            }
+                            memo__ = map_.next_value()?;
+                        }
+                        GeneratedField::SearchAttributes => {
+                            if search_attributes__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("searchAttributes"));
+                            }
+                            search_attributes__ = map_.next_value()?;
+                        }
+                        GeneratedField::PrevAutoResetPoints => {
+                            if prev_auto_reset_points__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("prevAutoResetPoints"));
+                            }
+                            prev_auto_reset_points__ = map_.next_value()?;
+                        }
+                        GeneratedField::Header => {
+                            if header__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("header"));
+                            }
+                            header__ = map_.next_value()?;
+                        }
+                        GeneratedField::ParentInitiatedEventVersion => {
+                            if parent_initiated_event_version__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("parentInitiatedEventVersion"));
+                            }
+                            parent_initiated_event_version__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::WorkflowId => {
+                            if workflow_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("workflowId"));
+                            }
+                            workflow_id__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::SourceVersionStamp => {
+                            if source_version_stamp__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("sourceVersionStamp"));
+                            }
+                            source_version_stamp__ = map_.next_value()?;
+                        }
+                        GeneratedField::CompletionCallbacks => {
+                            if completion_callbacks__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("completionCallbacks"));
+                            }
+                            completion_callbacks__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::RootWorkflowExecution => {
+                            if root_workflow_execution__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("rootWorkflowExecution"));
+                            }
+                            root_workflow_execution__ = map_.next_value()?;
+                        }
+                        GeneratedField::InheritedBuildId => {
+                            if inherited_build_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("inheritedBuildId"));
+                            }
+                            inherited_build_id__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::VersioningOverride => {
+                            if versioning_override__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("versioningOverride"));
+                            }
+                            versioning_override__ = map_.next_value()?;
+                        }
+                        GeneratedField::ParentPinnedWorkerDeploymentVersion => {
+                            if parent_pinned_worker_deployment_version__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("parentPinnedWorkerDeploymentVersion"));
+                            }
+                            parent_pinned_worker_deployment_version__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::Priority => {
+                            if priority__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("priority"));
+                            }
+                            priority__ = map_.next_value()?;
+                        }
+                        GeneratedField::InheritedPinnedVersion => {
+                            if inherited_pinned_version__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("inheritedPinnedVersion"));
+                            }
+                            inherited_pinned_version__ = map_.next_value()?;
+                        }
+                        GeneratedField::InheritedAutoUpgradeInfo => {
+                            if inherited_auto_upgrade_info__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("inheritedAutoUpgradeInfo"));
+                            }
+                            inherited_auto_upgrade_info__ = map_.next_value()?;
+                        }
+                        GeneratedField::EagerExecutionAccepted => {
+                            if eager_execution_accepted__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("eagerExecutionAccepted"));
+                            }
+                            eager_execution_accepted__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::DeclinedTargetVersionUpgrade => {
+                            if declined_target_version_upgrade__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("declinedTargetVersionUpgrade"));
+                            }
+                            declined_target_version_upgrade__ = map_.next_value()?;
+                        }
+                        GeneratedField::TimeSkippingConfig => {
+                            if time_skipping_config__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("timeSkippingConfig"));
+                            }
+                            time_skipping_config__ = map_.next_value()?;
+                        }
+                        GeneratedField::InitialSkippedDuration => {
+                            if initial_skipped_duration__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("initialSkippedDuration"));
+                            }
+                            initial_skipped_duration__ = map_.next_value()?;
+                        }
+                    }
+                }
+                Ok(WorkflowExecutionStartedEventAttributes {
+                    workflow_type: workflow_type__,
+                    parent_workflow_namespace: parent_workflow_namespace__.unwrap_or_default(),
+                    parent_workflow_namespace_id: parent_workflow_namespace_id__.unwrap_or_default(),
+                    parent_workflow_execution: parent_workflow_execution__,
+                    parent_initiated_event_id: parent_initiated_event_id__.unwrap_or_default(),
+                    task_queue: task_queue__,
+                    input: input__,
+                    workflow_execution_timeout: workflow_execution_timeout__,
+                    workflow_run_timeout: workflow_run_timeout__,
+                    workflow_task_timeout: workflow_task_timeout__,
+                    continued_execution_run_id: continued_execution_run_id__.unwrap_or_default(),
+                    initiator: initiator__.unwrap_or_default(),
+                    continued_failure: continued_failure__,
+                    last_completion_result: last_completion_result__,
+                    original_execution_run_id: original_execution_run_id__.unwrap_or_default(),
+                    identity: identity__.unwrap_or_default(),
+                    first_execution_run_id: first_execution_run_id__.unwrap_or_default(),
+                    retry_policy: retry_policy__,
+                    attempt: attempt__.unwrap_or_default(),
+                    workflow_execution_expiration_time: workflow_execution_expiration_time__,
+                    cron_schedule: cron_schedule__.unwrap_or_default(),
+                    first_workflow_task_backoff: first_workflow_task_backoff__,
+                    memo: memo__,
+                    search_attributes: search_attributes__,
+                    prev_auto_reset_points: prev_auto_reset_points__,
+                    header: header__,
+                    parent_initiated_event_version: parent_initiated_event_version__.unwrap_or_default(),
+                    workflow_id: workflow_id__.unwrap_or_default(),
+                    source_version_stamp: source_version_stamp__,
+                    completion_callbacks: completion_callbacks__.unwrap_or_default(),
+                    root_workflow_execution: root_workflow_execution__,
+                    inherited_build_id: inherited_build_id__.unwrap_or_default(),
+                    versioning_override: versioning_override__,
+                    parent_pinned_worker_deployment_version: parent_pinned_worker_deployment_version__.unwrap_or_default(),
+                    priority: priority__,
+                    inherited_pinned_version: inherited_pinned_version__,
+                    inherited_auto_upgrade_info: inherited_auto_upgrade_info__,
+                    eager_execution_accepted: eager_execution_accepted__.unwrap_or_default(),
+                    declined_target_version_upgrade: declined_target_version_upgrade__,
+                    time_skipping_config: time_skipping_config__,
+                    initial_skipped_duration: initial_skipped_duration__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("temporal.api.history.v1.WorkflowExecutionStartedEventAttributes", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for WorkflowExecutionTerminatedEventAttributes {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.reason.is_empty() {
+            len += 1;
+        }
+        if self.details.is_some() {
+            len += 1;
+        }
+        if !self.identity.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("temporal.api.history.v1.WorkflowExecutionTerminatedEventAttributes", len)?;
+        if !self.reason.is_empty() {
+            struct_ser.serialize_field("reason", &self.reason)?;
+        }
+        if let Some(v) = self.details.as_ref() {
+            struct_ser.serialize_field("details", v)?;
+        }
+        if !self.identity.is_empty() {
+            struct_ser.serialize_field("identity", &self.identity)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for WorkflowExecutionTerminatedEventAttributes {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "reason",
+            "details",
+            "identity",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Reason,
+            Details,
+            Identity,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "reason" => Ok(GeneratedField::Reason),
+                            "details" => Ok(GeneratedField::Details),
+                            "identity" => Ok(GeneratedField::Identity),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = WorkflowExecutionTerminatedEventAttributes;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct temporal.api.history.v1.WorkflowExecutionTerminatedEventAttributes")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<WorkflowExecutionTerminatedEventAttributes, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut reason__ = None;
+                let mut details__ = None;
+                let mut identity__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Reason => {
+                            if reason__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("reason"));
+                            }
+                            reason__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::Details => {
+                            if details__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("details"));
+                            }
+                            details__ = map_.next_value()?;
+                        }
+                        GeneratedField::Identity => {
+                            if identity__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("identity"));
+                            }
+                            identity__ = Some(map_.next_value()?);
+                        }
+                    }
+                }
+                Ok(WorkflowExecutionTerminatedEventAttributes {
+                    reason: reason__.unwrap_or_default(),
+                    details: details__,
+                    identity: identity__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("temporal.api.history.v1.WorkflowExecutionTerminatedEventAttributes", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for WorkflowExecutionTimeSkippingTransitionedEventAttributes {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.target_time.is_some() {
+            len += 1;
+        }
+        if self.disabled_after_bound {
+            len += 1;
+        }
+        if self.wall_clock_time.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("temporal.api.history.v1.WorkflowExecutionTimeSkippingTransitionedEventAttributes", len)?;
+        if let Some(v) = self.target_time.as_ref() {
+            struct_ser.serialize_field("targetTime", v)?;
+        }
+        if self.disabled_after_bound {
+            struct_ser.serialize_field("disabledAfterBound", &self.disabled_after_bound)?;
+        }
+        if let Some(v) = self.wall_clock_time.as_ref() {
+            struct_ser.serialize_field("wallClockTime", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for WorkflowExecutionTimeSkippingTransitionedEventAttributes {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "target_time",
+            "targetTime",
+            "disabled_after_bound",
+            "disabledAfterBound",
+            "wall_clock_time",
+            "wallClockTime",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            TargetTime,
+            DisabledAfterBound,
+            WallClockTime,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "targetTime" | "target_time" => Ok(GeneratedField::TargetTime),
+                            "disabledAfterBound" | "disabled_after_bound" => Ok(GeneratedField::DisabledAfterBound),
+                            "wallClockTime" | "wall_clock_time" => Ok(GeneratedField::WallClockTime),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = WorkflowExecutionTimeSkippingTransitionedEventAttributes;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct temporal.api.history.v1.WorkflowExecutionTimeSkippingTransitionedEventAttributes")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<WorkflowExecutionTimeSkippingTransitionedEventAttributes, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut target_time__ = None;
+                let mut disabled_after_bound__ = None;
+                let mut wall_clock_time__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::TargetTime => {
+                            if target_time__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("targetTime"));
+                            }
+                            target_time__ = map_.next_value()?;
+                        }
+                        GeneratedField::DisabledAfterBound => {
+                            if disabled_after_bound__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("disabledAfterBound"));
+                            }
+                            disabled_after_bound__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::WallClockTime => {
+                            if wall_clock_time__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("wallClockTime"));
+                            }
+                            wall_clock_time__ = map_.next_value()?;
+                        }
+                    }
+                }
+                Ok(WorkflowExecutionTimeSkippingTransitionedEventAttributes {
+                    target_time: target_time__,
+                    disabled_after_bound: disabled_after_bound__.unwrap_or_default(),
+                    wall_clock_time: wall_clock_time__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("temporal.api.history.v1.WorkflowExecutionTimeSkippingTransitionedEventAttributes", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for WorkflowExecutionTimedOutEventAttributes {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.retry_state != 0 {
+            len += 1;
+        }
+        if !self.new_execution_run_id.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("temporal.api.history.v1.WorkflowExecutionTimedOutEventAttributes", len)?;
+        if self.retry_state != 0 {
+            let v = super::super::enums::v1::RetryState::try_from(self.retry_state)
+                .map_err(|_| serde::ser::Error::custom(format!("Invalid variant {}", self.retry_state)))?;
+            struct_ser.serialize_field("retryState", &v)?;
+        }
+        if !self.new_execution_run_id.is_empty() {
+            struct_ser.serialize_field("newExecutionRunId", &self.new_execution_run_id)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for WorkflowExecutionTimedOutEventAttributes {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "retry_state",
+            "retryState",
+            "new_execution_run_id",
+            "newExecutionRunId",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            RetryState,
+            NewExecutionRunId,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "retryState" | "retry_state" => Ok(GeneratedField::RetryState),
+                            "newExecutionRunId" | "new_execution_run_id" => Ok(GeneratedField::NewExecutionRunId),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = WorkflowExecutionTimedOutEventAttributes;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct temporal.api.history.v1.WorkflowExecutionTimedOutEventAttributes")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<WorkflowExecutionTimedOutEventAttributes, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut retry_state__ = None;
+                let mut new_execution_run_id__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::RetryState => {
+                            if retry_state__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("retryState"));
+                            }
+                            retry_state__ = Some(map_.next_value::<super::super::enums::v1::RetryState>()? as i32);
+                        }
+                        GeneratedField::NewExecutionRunId => {
+                            if new_execution_run_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("newExecutionRunId"));
+                            }
+                            new_execution_run_id__ = Some(map_.next_value()?);
+                        }
+                    }
+                }
+                Ok(WorkflowExecutionTimedOutEventAttributes {
+                    retry_state: retry_state__.unwrap_or_default(),
+                    new_execution_run_id: new_execution_run_id__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("temporal.api.history.v1.WorkflowExecutionTimedOutEventAttributes", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for WorkflowExecutionUnpausedEventAttributes {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.identity.is_empty() {
+            len += 1;
+        }
+        if !self.reason.is_empty() {
+            len += 1;
+        }
+        if !self.request_id.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("temporal.api.history.v1.WorkflowExecutionUnpausedEventAttributes", len)?;
+        if !self.identity.is_empty() {
+            struct_ser.serialize_field("identity", &self.identity)?;
+        }
+        if !self.reason.is_empty() {
+            struct_ser.serialize_field("reason", &self.reason)?;
+        }
+        if !self.request_id.is_empty() {
+            struct_ser.serialize_field("requestId", &self.request_id)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for WorkflowExecutionUnpausedEventAttributes {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "identity",
+            "reason",
+            "request_id",
+            "requestId",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Identity,
+            Reason,
+            RequestId,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "identity" => Ok(GeneratedField::Identity),
+                            "reason" => Ok(GeneratedField::Reason),
+                            "requestId" | "request_id" => Ok(GeneratedField::RequestId),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = WorkflowExecutionUnpausedEventAttributes;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct temporal.api.history.v1.WorkflowExecutionUnpausedEventAttributes")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<WorkflowExecutionUnpausedEventAttributes, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut identity__ = None;
+                let mut reason__ = None;
+                let mut request_id__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Identity => {
+                            if identity__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("identity"));
+                            }
+                            identity__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::Reason => {
+                            if reason__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("reason"));
+                            }
+                            reason__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::RequestId => {
+                            if request_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("requestId"));
+                            }
+                            request_id__ = Some(map_.next_value()?);
+                        }
+                    }
+                }
+                Ok(WorkflowExecutionUnpausedEventAttributes {
+                    identity: identity__.unwrap_or_default(),
+                    reason: reason__.unwrap_or_default(),
+                    request_id: request_id__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("temporal.api.history.v1.WorkflowExecutionUnpausedEventAttributes", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for WorkflowExecutionUpdateAcceptedEventAttributes {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.protocol_instance_id.is_empty() {
+            len += 1;
+        }
+        if !self.accepted_request_message_id.is_empty() {
+            len += 1;
+        }
+        if self.accepted_request_sequencing_event_id != 0 {
+            len += 1;
+        }
+        if self.accepted_request.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("temporal.api.history.v1.WorkflowExecutionUpdateAcceptedEventAttributes", len)?;
+        if !self.protocol_instance_id.is_empty() {
+            struct_ser.serialize_field("protocolInstanceId", &self.protocol_instance_id)?;
+        }
+        if !self.accepted_request_message_id.is_empty() {
+            struct_ser.serialize_field("acceptedRequestMessageId", &self.accepted_request_message_id)?;
+        }
+        if self.accepted_request_sequencing_event_id != 0 {
+            #[allow(clippy::needless_borrow)]
+            #[allow(clippy::needless_borrows_for_generic_args)]
+            struct_ser.serialize_field("acceptedRequestSequencingEventId", ToString::to_string(&self.accepted_request_sequencing_event_id).as_str())?;
+        }
+        if let Some(v) = self.accepted_request.as_ref() {
+            struct_ser.serialize_field("acceptedRequest", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for WorkflowExecutionUpdateAcceptedEventAttributes {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "protocol_instance_id",
+            "protocolInstanceId",
+            "accepted_request_message_id",
+            "acceptedRequestMessageId",
+            "accepted_request_sequencing_event_id",
+            "acceptedRequestSequencingEventId",
+            "accepted_request",
+            "acceptedRequest",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            ProtocolInstanceId,
+            AcceptedRequestMessageId,
+            AcceptedRequestSequencingEventId,
+            AcceptedRequest,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "protocolInstanceId" | "protocol_instance_id" => Ok(GeneratedField::ProtocolInstanceId),
+                            "acceptedRequestMessageId" | "accepted_request_message_id" => Ok(GeneratedField::AcceptedRequestMessageId),
+                            "acceptedRequestSequencingEventId" | "accepted_request_sequencing_event_id" => Ok(GeneratedField::AcceptedRequestSequencingEventId),
+                            "acceptedRequest" | "accepted_request" => Ok(GeneratedField::AcceptedRequest),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = WorkflowExecutionUpdateAcceptedEventAttributes;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct temporal.api.history.v1.WorkflowExecutionUpdateAcceptedEventAttributes")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<WorkflowExecutionUpdateAcceptedEventAttributes, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut protocol_instance_id__ = None;
+                let mut accepted_request_message_id__ = None;
+                let mut accepted_request_sequencing_event_id__ = None;
+                let mut accepted_request__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::ProtocolInstanceId => {
+                            if protocol_instance_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("protocolInstanceId"));
+                            }
+                            protocol_instance_id__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::AcceptedRequestMessageId => {
+                            if accepted_request_message_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("acceptedRequestMessageId"));
+                            }
+                            accepted_request_message_id__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::AcceptedRequestSequencingEventId => {
+                            if accepted_request_sequencing_event_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("acceptedRequestSequencingEventId"));
+                            }
+                            accepted_request_sequencing_event_id__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::AcceptedRequest => {
+                            if accepted_request__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("acceptedRequest"));
+                            }
+                            accepted_request__ = map_.next_value()?;
+                        }
+                    }
+                }
+                Ok(WorkflowExecutionUpdateAcceptedEventAttributes {
+                    protocol_instance_id: protocol_instance_id__.unwrap_or_default(),
+                    accepted_request_message_id: accepted_request_message_id__.unwrap_or_default(),
+                    accepted_request_sequencing_event_id: accepted_request_sequencing_event_id__.unwrap_or_default(),
+                    accepted_request: accepted_request__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("temporal.api.history.v1.WorkflowExecutionUpdateAcceptedEventAttributes", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for WorkflowExecutionUpdateAdmittedEventAttributes {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.request.is_some() {
+            len += 1;
+        }
+        if self.origin != 0 {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("temporal.api.history.v1.WorkflowExecutionUpdateAdmittedEventAttributes", len)?;
+        if let Some(v) = self.request.as_ref() {
+            struct_ser.serialize_field("request", v)?;
+        }
+        if self.origin != 0 {
+            let v = super::super::enums::v1::UpdateAdmittedEventOrigin::try_from(self.origin)
+                .map_err(|_| serde::ser::Error::custom(format!("Invalid variant {}", self.origin)))?;
+            struct_ser.serialize_field("origin", &v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for WorkflowExecutionUpdateAdmittedEventAttributes {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "request",
+            "origin",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Request,
+            Origin,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "request" => Ok(GeneratedField::Request),
+                            "origin" => Ok(GeneratedField::Origin),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = WorkflowExecutionUpdateAdmittedEventAttributes;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct temporal.api.history.v1.WorkflowExecutionUpdateAdmittedEventAttributes")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<WorkflowExecutionUpdateAdmittedEventAttributes, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut request__ = None;
+                let mut origin__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Request => {
+                            if request__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("request"));
+                            }
+                            request__ = map_.next_value()?;
+                        }
+                        GeneratedField::Origin => {
+                            if origin__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("origin"));
+                            }
+                            origin__ = Some(map_.next_value::<super::super::enums::v1::UpdateAdmittedEventOrigin>()? as i32);
+                        }
+                    }
+                }
+                Ok(WorkflowExecutionUpdateAdmittedEventAttributes {
+                    request: request__,
+                    origin: origin__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("temporal.api.history.v1.WorkflowExecutionUpdateAdmittedEventAttributes", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for WorkflowExecutionUpdateCompletedEventAttributes {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.meta.is_some() {
+            len += 1;
+        }
+        if self.accepted_event_id != 0 {
+            len += 1;
+        }
+        if self.outcome.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("temporal.api.history.v1.WorkflowExecutionUpdateCompletedEventAttributes", len)?;
+        if let Some(v) = self.meta.as_ref() {
+            struct_ser.serialize_field("meta", v)?;
+        }
+        if self.accepted_event_id != 0 {
+            #[allow(clippy::needless_borrow)]
+            #[allow(clippy::needless_borrows_for_generic_args)]
+            struct_ser.serialize_field("acceptedEventId", ToString::to_string(&self.accepted_event_id).as_str())?;
+        }
+        if let Some(v) = self.outcome.as_ref() {
+            struct_ser.serialize_field("outcome", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for WorkflowExecutionUpdateCompletedEventAttributes {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "meta",
+            "accepted_event_id",
+            "acceptedEventId",
+            "outcome",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Meta,
+            AcceptedEventId,
+            Outcome,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "meta" => Ok(GeneratedField::Meta),
+                            "acceptedEventId" | "accepted_event_id" => Ok(GeneratedField::AcceptedEventId),
+                            "outcome" => Ok(GeneratedField::Outcome),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = WorkflowExecutionUpdateCompletedEventAttributes;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct temporal.api.history.v1.WorkflowExecutionUpdateCompletedEventAttributes")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<WorkflowExecutionUpdateCompletedEventAttributes, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut meta__ = None;
+                let mut accepted_event_id__ = None;
+                let mut outcome__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Meta => {
+                            if meta__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("meta"));
+                            }
+                            meta__ = map_.next_value()?;
+                        }
+                        GeneratedField::AcceptedEventId => {
+                            if accepted_event_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("acceptedEventId"));
+                            }
+                            accepted_event_id__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::Outcome => {
+                            if outcome__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("outcome"));
+                            }
+                            outcome__ = map_.next_value()?;
+                        }
+                    }
+                }
+                Ok(WorkflowExecutionUpdateCompletedEventAttributes {
+                    meta: meta__,
+                    accepted_event_id: accepted_event_id__.unwrap_or_default(),
+                    outcome: outcome__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("temporal.api.history.v1.WorkflowExecutionUpdateCompletedEventAttributes", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for WorkflowExecutionUpdateRejectedEventAttributes {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.protocol_instance_id.is_empty() {
+            len += 1;
+        }
+        if !self.rejected_request_message_id.is_empty() {
+            len += 1;
+        }
+        if self.rejected_request_sequencing_event_id != 0 {
+            len += 1;
+        }
+        if self.rejected_request.is_some() {
+            len += 1;
+        }
+        if self.failure.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("temporal.api.history.v1.WorkflowExecutionUpdateRejectedEventAttributes", len)?;
+        if !self.protocol_instance_id.is_empty() {
+            struct_ser.serialize_field("protocolInstanceId", &self.protocol_instance_id)?;
+        }
+        if !self.rejected_request_message_id.is_empty() {
+            struct_ser.serialize_field("rejectedRequestMessageId", &self.rejected_request_message_id)?;
+        }
+        if self.rejected_request_sequencing_event_id != 0 {
+            #[allow(clippy::needless_borrow)]
+            #[allow(clippy::needless_borrows_for_generic_args)]
+            struct_ser.serialize_field("rejectedRequestSequencingEventId", ToString::to_string(&self.rejected_request_sequencing_event_id).as_str())?;
+        }
+        if let Some(v) = self.rejected_request.as_ref() {
+            struct_ser.serialize_field("rejectedRequest", v)?;
+        }
+        if let Some(v) = self.failure.as_ref() {
+            struct_ser.serialize_field("failure", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for WorkflowExecutionUpdateRejectedEventAttributes {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "protocol_instance_id",
+            "protocolInstanceId",
+            "rejected_request_message_id",
+            "rejectedRequestMessageId",
+            "rejected_request_sequencing_event_id",
+            "rejectedRequestSequencingEventId",
+            "rejected_request",
+            "rejectedRequest",
+            "failure",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            ProtocolInstanceId,
+            RejectedRequestMessageId,
+            RejectedRequestSequencingEventId,
+            RejectedRequest,
+            Failure,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "protocolInstanceId" | "protocol_instance_id" => Ok(GeneratedField::ProtocolInstanceId),
+                            "rejectedRequestMessageId" | "rejected_request_message_id" => Ok(GeneratedField::RejectedRequestMessageId),
+                            "rejectedRequestSequencingEventId" | "rejected_request_sequencing_event_id" => Ok(GeneratedField::RejectedRequestSequencingEventId),
+                            "rejectedRequest" | "rejected_request" => Ok(GeneratedField::RejectedRequest),
+                            "failure" => Ok(GeneratedField::Failure),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = WorkflowExecutionUpdateRejectedEventAttributes;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct temporal.api.history.v1.WorkflowExecutionUpdateRejectedEventAttributes")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<WorkflowExecutionUpdateRejectedEventAttributes, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut protocol_instance_id__ = None;
+                let mut rejected_request_message_id__ = None;
+                let mut rejected_request_sequencing_event_id__ = None;
+                let mut rejected_request__ = None;
+                let mut failure__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::ProtocolInstanceId => {
+                            if protocol_instance_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("protocolInstanceId"));
+                            }
+                            protocol_instance_id__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::RejectedRequestMessageId => {
+                            if rejected_request_message_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("rejectedRequestMessageId"));
+                            }
+                            rejected_request_message_id__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::RejectedRequestSequencingEventId => {
+                            if rejected_request_sequencing_event_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("rejectedRequestSequencingEventId"));
+                            }
+                            rejected_request_sequencing_event_id__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::RejectedRequest => {
+                            if rejected_request__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("rejectedRequest"));
+                            }
+                            rejected_request__ = map_.next_value()?;
+                        }
+                        GeneratedField::Failure => {
+                            if failure__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("failure"));
+                            }
+                            failure__ = map_.next_value()?;
+                        }
+                    }
+                }
+                Ok(WorkflowExecutionUpdateRejectedEventAttributes {
+                    protocol_instance_id: protocol_instance_id__.unwrap_or_default(),
+                    rejected_request_message_id: rejected_request_message_id__.unwrap_or_default(),
+                    rejected_request_sequencing_event_id: rejected_request_sequencing_event_id__.unwrap_or_default(),
+                    rejected_request: rejected_request__,
+                    failure: failure__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("temporal.api.history.v1.WorkflowExecutionUpdateRejectedEventAttributes", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for WorkflowPropertiesModifiedEventAttributes {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.workflow_task_completed_event_id != 0 {
+            len += 1;
+        }
+        if self.upserted_memo.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("temporal.api.history.v1.WorkflowPropertiesModifiedEventAttributes", len)?;
+        if self.workflow_task_completed_event_id != 0 {
+            #[allow(clippy::needless_borrow)]
+            #[allow(clippy::needless_borrows_for_generic_args)]
+            struct_ser.serialize_field("workflowTaskCompletedEventId", ToString::to_string(&self.workflow_task_completed_event_id).as_str())?;
+        }
+        if let Some(v) = self.upserted_memo.as_ref() {
+            struct_ser.serialize_field("upsertedMemo", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for WorkflowPropertiesModifiedEventAttributes {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "workflow_task_completed_event_id",
+            "workflowTaskCompletedEventId",
+            "upserted_memo",
+            "upsertedMemo",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            WorkflowTaskCompletedEventId,
+            UpsertedMemo,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "workflowTaskCompletedEventId" | "workflow_task_completed_event_id" => Ok(GeneratedField::WorkflowTaskCompletedEventId),
+                            "upsertedMemo" | "upserted_memo" => Ok(GeneratedField::UpsertedMemo),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = WorkflowPropertiesModifiedEventAttributes;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct temporal.api.history.v1.WorkflowPropertiesModifiedEventAttributes")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<WorkflowPropertiesModifiedEventAttributes, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut workflow_task_completed_event_id__ = None;
+                let mut upserted_memo__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::WorkflowTaskCompletedEventId => {
+                            if workflow_task_completed_event_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("workflowTaskCompletedEventId"));
+                            }
+                            workflow_task_completed_event_id__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::UpsertedMemo => {
+                            if upserted_memo__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("upsertedMemo"));
+                            }
+                            upserted_memo__ = map_.next_value()?;
+                        }
+                    }
+                }
+                Ok(WorkflowPropertiesModifiedEventAttributes {
+                    workflow_task_completed_event_id: workflow_task_completed_event_id__.unwrap_or_default(),
+                    upserted_memo: upserted_memo__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("temporal.api.history.v1.WorkflowPropertiesModifiedEventAttributes", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for WorkflowPropertiesModifiedExternallyEventAttributes {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.new_task_queue.is_empty() {
+            len += 1;
+        }
+        if self.new_workflow_task_timeout.is_some() {
+            len += 1;
+        }
+        if self.new_workflow_run_timeout.is_some() {
+            len += 1;
+        }
+        if self.new_workflow_execution_timeout.is_some() {
+            len += 1;
+        }
+        if self.upserted_memo.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("temporal.api.history.v1.WorkflowPropertiesModifiedExternallyEventAttributes", len)?;
+        if !self.new_task_queue.is_empty() {
+            struct_ser.serialize_field("newTaskQueue", &self.new_task_queue)?;
+        }
+        if let Some(v) = self.new_workflow_task_timeout.as_ref() {
+            struct_ser.serialize_field("newWorkflowTaskTimeout", v)?;
+        }
+        if let Some(v) = self.new_workflow_run_timeout.as_ref() {
+            struct_ser.serialize_field("newWorkflowRunTimeout", v)?;
+        }
+        if let Some(v) = self.new_workflow_execution_timeout.as_ref() {
+            struct_ser.serialize_field("newWorkflowExecutionTimeout", v)?;
+        }
+        if let Some(v) = self.upserted_memo.as_ref() {
+            struct_ser.serialize_field("upsertedMemo", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for WorkflowPropertiesModifiedExternallyEventAttributes {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "new_task_queue",
+            "newTaskQueue",
+            "new_workflow_task_timeout",
+            "newWorkflowTaskTimeout",
+            "new_workflow_run_timeout",
+            "newWorkflowRunTimeout",
+            "new_workflow_execution_timeout",
+            "newWorkflowExecutionTimeout",
+            "upserted_memo",
+            "upsertedMemo",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            NewTaskQueue,
+            NewWorkflowTaskTimeout,
+            NewWorkflowRunTimeout,
+            NewWorkflowExecutionTimeout,
+            UpsertedMemo,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "newTaskQueue" | "new_task_queue" => Ok(GeneratedField::NewTaskQueue),
+                            "newWorkflowTaskTimeout" | "new_workflow_task_timeout" => Ok(GeneratedField::NewWorkflowTaskTimeout),
+                            "newWorkflowRunTimeout" | "new_workflow_run_timeout" => Ok(GeneratedField::NewWorkflowRunTimeout),
+                            "newWorkflowExecutionTimeout" | "new_workflow_execution_timeout" => Ok(GeneratedField::NewWorkflowExecutionTimeout),
+                            "upsertedMemo" | "upserted_memo" => Ok(GeneratedField::UpsertedMemo),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = WorkflowPropertiesModifiedExternallyEventAttributes;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct temporal.api.history.v1.WorkflowPropertiesModifiedExternallyEventAttributes")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<WorkflowPropertiesModifiedExternallyEventAttributes, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut new_task_queue__ = None;
+                let mut new_workflow_task_timeout__ = None;
+                let mut new_workflow_run_timeout__ = None;
+                let mut new_workflow_execution_timeout__ = None;
+                let mut upserted_memo__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::NewTaskQueue => {
+                            if new_task_queue__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("newTaskQueue"));
+                            }
+                            new_task_queue__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::NewWorkflowTaskTimeout => {
+                            if new_workflow_task_timeout__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("newWorkflowTaskTimeout"));
+                            }
+                            new_workflow_task_timeout__ = map_.next_value()?;
+                        }
+                        GeneratedField::NewWorkflowRunTimeout => {
+                            if new_workflow_run_timeout__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("newWorkflowRunTimeout"));
+                            }
+                            new_workflow_run_timeout__ = map_.next_value()?;
+                        }
+                        GeneratedField::NewWorkflowExecutionTimeout => {
+                            if new_workflow_execution_timeout__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("newWorkflowExecutionTimeout"));
+                            }
+                            new_workflow_execution_timeout__ = map_.next_value()?;
+                        }
+                        GeneratedField::UpsertedMemo => {
+                            if upserted_memo__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("upsertedMemo"));
+                            }
+                            upserted_memo__ = map_.next_value()?;
+                        }
+                    }
+                }
+                Ok(WorkflowPropertiesModifiedExternallyEventAttributes {
+                    new_task_queue: new_task_queue__.unwrap_or_default(),
+                    new_workflow_task_timeout: new_workflow_task_timeout__,
+                    new_workflow_run_timeout: new_workflow_run_timeout__,
+                    new_workflow_execution_timeout: new_workflow_execution_timeout__,
+                    upserted_memo: upserted_memo__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("temporal.api.history.v1.WorkflowPropertiesModifiedExternallyEventAttributes", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for WorkflowTaskCompletedEventAttributes {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.scheduled_event_id != 0 {
+            len += 1;
+        }
+        if self.started_event_id != 0 {
+            len += 1;
+        }
+        if !self.identity.is_empty() {
+            len += 1;
+        }
+        if !self.binary_checksum.is_empty() {
+            len += 1;
+        }
+        if self.worker_version.is_some() {
+            len += 1;
+        }
+        if self.sdk_metadata.is_some() {
+            len += 1;
+        }
+        if self.metering_metadata.is_some() {
+            len += 1;
+        }
+        if self.deployment.is_some() {
+            len += 1;
+        }
+        if self.versioning_behavior != 0 {
+            len += 1;
+        }
+        if !self.worker_deployment_version.is_empty() {
+            len += 1;
+        }
+        if !self.worker_deployment_name.is_empty() {
+            len += 1;
+        }
+        if self.deployment_version.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("temporal.api.history.v1.WorkflowTaskCompletedEventAttributes", len)?;
+        if self.scheduled_event_id != 0 {
+            #[allow(clippy::needless_borrow)]
+            #[allow(clippy::needless_borrows_for_generic_args)]
+            struct_ser.serialize_field("scheduledEventId", ToString::to_string(&self.scheduled_event_id).as_str())?;
+        }
+        if self.started_event_id != 0 {
+            #[allow(clippy::needless_borrow)]
+            #[allow(clippy::needless_borrows_for_generic_args)]
+            struct_ser.serialize_field("startedEventId", ToString::to_string(&self.started_event_id).as_str())?;
+        }
+        if !self.identity.is_empty() {
+            struct_ser.serialize_field("identity", &self.identity)?;
+        }
+        if !self.binary_checksum.is_empty() {
+            struct_ser.serialize_field("binaryChecksum", &self.binary_checksum)?;
+        }
+        if let Some(v) = self.worker_version.as_ref() {
+            struct_ser.serialize_field("workerVersion", v)?;
+        }
+        if let Some(v) = self.sdk_metadata.as_ref() {
+            struct_ser.serialize_field("sdkMetadata", v)?;
+        }
+        if let Some(v) = self.metering_metadata.as_ref() {
+            struct_ser.serialize_field("meteringMetadata", v)?;
+        }
+        if let Some(v) = self.deployment.as_ref() {
+            struct_ser.serialize_field("deployment", v)?;
+        }
+        if self.versioning_behavior != 0 {
+            let v = super::super::enums::v1::VersioningBehavior::try_from(self.versioning_behavior)
+                .map_err(|_| serde::ser::Error::custom(format!("Invalid variant {}", self.versioning_behavior)))?;
+            struct_ser.serialize_field("versioningBehavior", &v)?;
+        }
+        if !self.worker_deployment_version.is_empty() {
+            struct_ser.serialize_field("workerDeploymentVersion", &self.worker_deployment_version)?;
+        }
+        if !self.worker_deployment_name.is_empty() {
+            struct_ser.serialize_field("workerDeploymentName", &self.worker_deployment_name)?;
+        }
+        if let Some(v) = self.deployment_version.as_ref() {
+            struct_ser.serialize_field("deploymentVersion", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for WorkflowTaskCompletedEventAttributes {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "scheduled_event_id",
+            "scheduledEventId",
+            "started_event_id",
+            "startedEventId",
+            "identity",
+            "binary_checksum",
+            "binaryChecksum",
+            "worker_version",
+            "workerVersion",
+            "sdk_metadata",
+            "sdkMetadata",
+            "metering_metadata",
+            "meteringMetadata",
+            "deployment",
+            "versioning_behavior",
+            "versioningBehavior",
+            "worker_deployment_version",
+            "workerDeploymentVersion",
+            "worker_deployment_name",
+            "workerDeploymentName",
+            "deployment_version",
+            "deploymentVersion",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            ScheduledEventId,
+            StartedEventId,
+            Identity,
+            BinaryChecksum,
+            WorkerVersion,
+            SdkMetadata,
+            MeteringMetadata,
+            Deployment,
+            VersioningBehavior,
+            WorkerDeploymentVersion,
+            WorkerDeploymentName,
+            DeploymentVersion,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "scheduledEventId" | "scheduled_event_id" => Ok(GeneratedField::ScheduledEventId),
+                            "startedEventId" | "started_event_id" => Ok(GeneratedField::StartedEventId),
+                            "identity" => Ok(GeneratedField::Identity),
+                            "binaryChecksum" | "binary_checksum" => Ok(GeneratedField::BinaryChecksum),
+                            "workerVersion" | "worker_version" => Ok(GeneratedField::WorkerVersion),
+                            "sdkMetadata" | "sdk_metadata" => Ok(GeneratedField::SdkMetadata),
+                            "meteringMetadata" | "metering_metadata" => Ok(GeneratedField::MeteringMetadata),
+                            "deployment" => Ok(GeneratedField::Deployment),
+                            "versioningBehavior" | "versioning_behavior" => Ok(GeneratedField::VersioningBehavior),
+                            "workerDeploymentVersion" | "worker_deployment_version" => Ok(GeneratedField::WorkerDeploymentVersion),
+                            "workerDeploymentName" | "worker_deployment_name" => Ok(GeneratedField::WorkerDeploymentName),
+                            "deploymentVersion" | "deployment_version" => Ok(GeneratedField::DeploymentVersion),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = WorkflowTaskCompletedEventAttributes;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct temporal.api.history.v1.WorkflowTaskCompletedEventAttributes")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<WorkflowTaskCompletedEventAttributes, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut scheduled_event_id__ = None;
+                let mut started_event_id__ = None;
+                let mut identity__ = None;
+                let mut binary_checksum__ = None;
+                let mut worker_version__ = None;
+                let mut sdk_metadata__ = None;
+                let mut metering_metadata__ = None;
+                let mut deployment__ = None;
+                let mut versioning_behavior__ = None;
+                let mut worker_deployment_version__ = None;
+                let mut worker_deployment_name__ = None;
+                let mut deployment_version__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::ScheduledEventId => {
+                            if scheduled_event_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("scheduledEventId"));
+                            }
+                            scheduled_event_id__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::StartedEventId => {
+                            if started_event_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("startedEventId"));
+                            }
+                            started_event_id__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::Identity => {
+                            if identity__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("identity"));
+                            }
+                            identity__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::BinaryChecksum => {
+                            if binary_checksum__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("binaryChecksum"));
+                            }
+                            binary_checksum__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::WorkerVersion => {
+                            if worker_version__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("workerVersion"));
+                            }
+                            worker_version__ = map_.next_value()?;
+                        }
+                        GeneratedField::SdkMetadata => {
+                            if sdk_metadata__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("sdkMetadata"));
+                            }
+                            sdk_metadata__ = map_.next_value()?;
+                        }
+                        GeneratedField::MeteringMetadata => {
+                            if metering_metadata__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("meteringMetadata"));
+                            }
+                            metering_metadata__ = map_.next_value()?;
+                        }
+                        GeneratedField::Deployment => {
+                            if deployment__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("deployment"));
+                            }
+                            deployment__ = map_.next_value()?;
+                        }
+                        GeneratedField::VersioningBehavior => {
+                            if versioning_behavior__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("versioningBehavior"));
+                            }
+                            versioning_behavior__ = Some(map_.next_value::<super::super::enums::v1::VersioningBehavior>()? as i32);
+                        }
+                        GeneratedField::WorkerDeploymentVersion => {
+                            if worker_deployment_version__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("workerDeploymentVersion"));
+                            }
+                            worker_deployment_version__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::WorkerDeploymentName => {
+                            if worker_deployment_name__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("workerDeploymentName"));
+                            }
+                            worker_deployment_name__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::DeploymentVersion => {
+                            if deployment_version__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("deploymentVersion"));
+                            }
+                            deployment_version__ = map_.next_value()?;
+                        }
+                    }
+                }
+                Ok(WorkflowTaskCompletedEventAttributes {
+                    scheduled_event_id: scheduled_event_id__.unwrap_or_default(),
+                    started_event_id: started_event_id__.unwrap_or_default(),
+                    identity: identity__.unwrap_or_default(),
+                    binary_checksum: binary_checksum__.unwrap_or_default(),
+                    worker_version: worker_version__,
+                    sdk_metadata: sdk_metadata__,
+                    metering_metadata: metering_metadata__,
+                    deployment: deployment__,
+                    versioning_behavior: versioning_behavior__.unwrap_or_default(),
+                    worker_deployment_version: worker_deployment_version__.unwrap_or_default(),
+                    worker_deployment_name: worker_deployment_name__.unwrap_or_default(),
+                    deployment_version: deployment_version__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("temporal.api.history.v1.WorkflowTaskCompletedEventAttributes", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for WorkflowTaskFailedEventAttributes {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.scheduled_event_id != 0 {
+            len += 1;
+        }
+        if self.started_event_id != 0 {
+            len += 1;
+        }
+        if self.cause != 0 {
+            len += 1;
+        }
+        if self.failure.is_some() {
+            len += 1;
+        }
+        if !self.identity.is_empty() {
+            len += 1;
+        }
+        if !self.base_run_id.is_empty() {
+            len += 1;
+        }
+        if !self.new_run_id.is_empty() {
+            len += 1;
+        }
+        if self.fork_event_version != 0 {
+            len += 1;
+        }
+        if !self.binary_checksum.is_empty() {
+            len += 1;
+        }
+        if self.worker_version.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("temporal.api.history.v1.WorkflowTaskFailedEventAttributes", len)?;
+        if self.scheduled_event_id != 0 {
+            #[allow(clippy::needless_borrow)]
+            #[allow(clippy::needless_borrows_for_generic_args)]
+            struct_ser.serialize_field("scheduledEventId", ToString::to_string(&self.scheduled_event_id).as_str())?;
+        }
+        if self.started_event_id != 0 {
+            #[allow(clippy::needless_borrow)]
+            #[allow(clippy::needless_borrows_for_generic_args)]
+            struct_ser.serialize_field("startedEventId", ToString::to_string(&self.started_event_id).as_str())?;
+        }
+        if self.cause != 0 {
+            let v = super::super::enums::v1::WorkflowTaskFailedCause::try_from(self.cause)
+                .map_err(|_| serde::ser::Error::custom(format!("Invalid variant {}", self.cause)))?;
+            struct_ser.serialize_field("cause", &v)?;
+        }
+        if let Some(v) = self.failure.as_ref() {
+            struct_ser.serialize_field("failure", v)?;
+        }
+        if !self.identity.is_empty() {
+            struct_ser.serialize_field("identity", &self.identity)?;
+        }
+        if !self.base_run_id.is_empty() {
+            struct_ser.serialize_field("baseRunId", &self.base_run_id)?;
+        }
+        if !self.new_run_id.is_empty() {
+            struct_ser.serialize_field("newRunId", &self.new_run_id)?;
+        }
+        if self.fork_event_version != 0 {
+            #[allow(clippy::needless_borrow)]
+            #[allow(clippy::needless_borrows_for_generic_args)]
+            struct_ser.serialize_field("forkEventVersion", ToString::to_string(&self.fork_event_version).as_str())?;
+        }
+        if !self.binary_checksum.is_empty() {
+            struct_ser.serialize_field("binaryChecksum", &self.binary_checksum)?;
+        }
+        if let Some(v) = self.worker_version.as_ref() {
+            struct_ser.serialize_field("workerVersion", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for WorkflowTaskFailedEventAttributes {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "scheduled_event_id",
+            "scheduledEventId",
+            "started_event_id",
+            "startedEventId",
+            "cause",
+            "failure",
+            "identity",
+            "base_run_id",
+            "baseRunId",
+            "new_run_id",
+            "newRunId",
+            "fork_event_version",
+            "forkEventVersion",
+            "binary_checksum",
+            "binaryChecksum",
+            "worker_version",
+            "workerVersion",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            ScheduledEventId,
+            StartedEventId,
+            Cause,
+            Failure,
+            Identity,
+            BaseRunId,
+            NewRunId,
+            ForkEventVersion,
+            BinaryChecksum,
+            WorkerVersion,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "scheduledEventId" | "scheduled_event_id" => Ok(GeneratedField::ScheduledEventId),
+                            "startedEventId" | "started_event_id" => Ok(GeneratedField::StartedEventId),
+                            "cause" => Ok(GeneratedField::Cause),
+                            "failure" => Ok(GeneratedField::Failure),
+                            "identity" => Ok(GeneratedField::Identity),
+                            "baseRunId" | "base_run_id" => Ok(GeneratedField::BaseRunId),
+                            "newRunId" | "new_run_id" => Ok(GeneratedField::NewRunId),
+                            "forkEventVersion" | "fork_event_version" => Ok(GeneratedField::ForkEventVersion),
+                            "binaryChecksum" | "binary_checksum" => Ok(GeneratedField::BinaryChecksum),
+                            "workerVersion" | "worker_version" => Ok(GeneratedField::WorkerVersion),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = WorkflowTaskFailedEventAttributes;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct temporal.api.history.v1.WorkflowTaskFailedEventAttributes")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<WorkflowTaskFailedEventAttributes, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut scheduled_event_id__ = None;
+                let mut started_event_id__ = None;
+                let mut cause__ = None;
+                let mut failure__ = None;
+                let mut identity__ = None;
+                let mut base_run_id__ = None;
+                let mut new_run_id__ = None;
+                let mut fork_event_version__ = None;
+                let mut binary_checksum__ = None;
+                let mut worker_version__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::ScheduledEventId => {
+                            if scheduled_event_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("scheduledEventId"));
+                            }
+                            scheduled_event_id__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::StartedEventId => {
+                            if started_event_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("startedEventId"));
+                            }
+                            started_event_id__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::Cause => {
+                            if cause__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("cause"));
+                            }
+                            cause__ = Some(map_.next_value::<super::super::enums::v1::WorkflowTaskFailedCause>()? as i32);
+                        }
+                        GeneratedField::Failure => {
+                            if failure__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("failure"));
+                            }
+                            failure__ = map_.next_value()?;
+                        }
+                        GeneratedField::Identity => {
+                            if identity__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("identity"));
+                            }
+                            identity__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::BaseRunId => {
+                            if base_run_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("baseRunId"));
+                            }
+                            base_run_id__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::NewRunId => {
+                            if new_run_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("newRunId"));
+                            }
+                            new_run_id__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::ForkEventVersion => {
+                            if fork_event_version__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("forkEventVersion"));
+                            }
+                            fork_event_version__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::BinaryChecksum => {
+                            if binary_checksum__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("binaryChecksum"));
+                            }
+                            binary_checksum__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::WorkerVersion => {
+                            if worker_version__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("workerVersion"));
+                            }
+                            worker_version__ = map_.next_value()?;
+                        }
+                    }
+                }
+                Ok(WorkflowTaskFailedEventAttributes {
+                    scheduled_event_id: scheduled_event_id__.unwrap_or_default(),
+                    started_event_id: started_event_id__.unwrap_or_default(),
+                    cause: cause__.unwrap_or_default(),
+                    failure: failure__,
+                    identity: identity__.unwrap_or_default(),
+                    base_run_id: base_run_id__.unwrap_or_default(),
+                    new_run_id: new_run_id__.unwrap_or_default(),
+                    fork_event_version: fork_event_version__.unwrap_or_default(),
+                    binary_checksum: binary_checksum__.unwrap_or_default(),
+                    worker_version: worker_version__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("temporal.api.history.v1.WorkflowTaskFailedEventAttributes", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for WorkflowTaskScheduledEventAttributes {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.task_queue.is_some() {
+            len += 1;
+        }
+        if self.start_to_close_timeout.is_some() {
+            len += 1;
+        }
+        if self.attempt != 0 {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("temporal.api.history.v1.WorkflowTaskScheduledEventAttributes", len)?;
+        if let Some(v) = self.task_queue.as_ref() {
+            struct_ser.serialize_field("taskQueue", v)?;
+        }
+        if let Some(v) = self.start_to_close_timeout.as_ref() {
+            struct_ser.serialize_field("startToCloseTimeout", v)?;
+        }
+        if self.attempt != 0 {
+            struct_ser.serialize_field("attempt", &self.attempt)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for WorkflowTaskScheduledEventAttributes {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "task_queue",
+            "taskQueue",
+            "start_to_close_timeout",
+            "startToCloseTimeout",
+            "attempt",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            TaskQueue,
+            StartToCloseTimeout,
+            Attempt,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "taskQueue" | "task_queue" => Ok(GeneratedField::TaskQueue),
+                            "startToCloseTimeout" | "start_to_close_timeout" => Ok(GeneratedField::StartToCloseTimeout),
+                            "attempt" => Ok(GeneratedField::Attempt),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = WorkflowTaskScheduledEventAttributes;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct temporal.api.history.v1.WorkflowTaskScheduledEventAttributes")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<WorkflowTaskScheduledEventAttributes, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut task_queue__ = None;
+                let mut start_to_close_timeout__ = None;
+                let mut attempt__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::TaskQueue => {
+                            if task_queue__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("taskQueue"));
+                            }
+                            task_queue__ = map_.next_value()?;
+                        }
+                        GeneratedField::StartToCloseTimeout => {
+                            if start_to_close_timeout__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("startToCloseTimeout"));
+                            }
+                            start_to_close_timeout__ = map_.next_value()?;
+                        }
+                        GeneratedField::Attempt => {
+                            if attempt__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("attempt"));
+                            }
+                            attempt__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                    }
+                }
+                Ok(WorkflowTaskScheduledEventAttributes {
+                    task_queue: task_queue__,
+                    start_to_close_timeout: start_to_close_timeout__,
+                    attempt: attempt__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("temporal.api.history.v1.WorkflowTaskScheduledEventAttributes", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for WorkflowTaskStartedEventAttributes {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.scheduled_event_id != 0 {
+            len += 1;
+        }
+        if !self.identity.is_empty() {
+            len += 1;
+        }
+        if !self.request_id.is_empty() {
+            len += 1;
+        }
+        if self.suggest_continue_as_new {
+            len += 1;
+        }
+        if !self.suggest_continue_as_new_reasons.is_empty() {
+            len += 1;
+        }
+        if self.target_worker_deployment_version_changed {
+            len += 1;
+        }
+        if self.history_size_bytes != 0 {
+            len += 1;
+        }
+        if self.worker_version.is_some() {
+            len += 1;
+        }
+        if self.build_id_redirect_counter != 0 {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("temporal.api.history.v1.WorkflowTaskStartedEventAttributes", len)?;
+        if self.scheduled_event_id != 0 {
+            #[allow(clippy::needless_borrow)]
+            #[allow(clippy::needless_borrows_for_generic_args)]
+            struct_ser.serialize_field("scheduledEventId", ToString::to_string(&self.scheduled_event_id).as_str())?;
+        }
+        if !self.identity.is_empty() {
+            struct_ser.serialize_field("identity", &self.identity)?;
+        }
+        if !self.request_id.is_empty() {
+            struct_ser.serialize_field("requestId", &self.request_id)?;
+        }
+        if self.suggest_continue_as_new {
+            struct_ser.serialize_field("suggestContinueAsNew", &self.suggest_continue_as_new)?;
+        }
+        if !self.suggest_continue_as_new_reasons.is_empty() {
+            let v = self.suggest_continue_as_new_reasons.iter().cloned().map(|v| {
+                super::super::enums::v1::SuggestContinueAsNewReason::try_from(v)
+                    .map_err(|_| serde::ser::Error::custom(format!("Invalid variant {}", v)))
+                }).collect::<std::result::Result<Vec<_>, _>>()?;
+            struct_ser.serialize_field("suggestContinueAsNewReasons", &v)?;
+        }
+        if self.target_worker_deployment_version_changed {
+            struct_ser.serialize_field("targetWorkerDeploymentVersionChanged", &self.target_worker_deployment_version_changed)?;
+        }
+        if self.history_size_bytes != 0 {
+            #[allow(clippy::needless_borrow)]
+            #[allow(clippy::needless_borrows_for_generic_args)]
+            struct_ser.serialize_field("historySizeBytes", ToString::to_string(&self.history_size_bytes).as_str())?;
+        }
+        if let Some(v) = self.worker_version.as_ref() {
+            struct_ser.serialize_field("workerVersion", v)?;
+        }
+        if self.build_id_redirect_counter != 0 {
+            #[allow(clippy::needless_borrow)]
+            #[allow(clippy::needless_borrows_for_generic_args)]
+            struct_ser.serialize_field("buildIdRedirectCounter", ToString::to_string(&self.build_id_redirect_counter).as_str())?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for WorkflowTaskStartedEventAttributes {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "scheduled_event_id",
+            "scheduledEventId",
+            "identity",
+            "request_id",
+            "requestId",
+            "suggest_continue_as_new",
+            "suggestContinueAsNew",
+            "suggest_continue_as_new_reasons",
+            "suggestContinueAsNewReasons",
+            "target_worker_deployment_version_changed",
+            "targetWorkerDeploymentVersionChanged",
+            "history_size_bytes",
+            "historySizeBytes",
+            "worker_version",
+            "workerVersion",
+            "build_id_redirect_counter",
+            "buildIdRedirectCounter",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            ScheduledEventId,
+            Identity,
+            RequestId,
+            SuggestContinueAsNew,
+            SuggestContinueAsNewReasons,
+            TargetWorkerDeploymentVersionChanged,
+            HistorySizeBytes,
+            WorkerVersion,
+            BuildIdRedirectCounter,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "scheduledEventId" | "scheduled_event_id" => Ok(GeneratedField::ScheduledEventId),
+                            "identity" => Ok(GeneratedField::Identity),
+                            "requestId" | "request_id" => Ok(GeneratedField::RequestId),
+                            "suggestContinueAsNew" | "suggest_continue_as_new" => Ok(GeneratedField::SuggestContinueAsNew),
+                            "suggestContinueAsNewReasons" | "suggest_continue_as_new_reasons" => Ok(GeneratedField::SuggestContinueAsNewReasons),
+                            "targetWorkerDeploymentVersionChanged" | "target_worker_deployment_version_changed" => Ok(GeneratedField::TargetWorkerDeploymentVersionChanged),
+                            "historySizeBytes" | "history_size_bytes" => Ok(GeneratedField::HistorySizeBytes),
+                            "workerVersion" | "worker_version" => Ok(GeneratedField::WorkerVersion),
+                            "buildIdRedirectCounter" | "build_id_redirect_counter" => Ok(GeneratedField::BuildIdRedirectCounter),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = WorkflowTaskStartedEventAttributes;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct temporal.api.history.v1.WorkflowTaskStartedEventAttributes")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<WorkflowTaskStartedEventAttributes, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut scheduled_event_id__ = None;
+                let mut identity__ = None;
+                let mut request_id__ = None;
+                let mut suggest_continue_as_new__ = None;
+                let mut suggest_continue_as_new_reasons__ = None;
+                let mut target_worker_deployment_version_changed__ = None;
+                let mut history_size_bytes__ = None;
+                let mut worker_version__ = None;
+                let mut build_id_redirect_counter__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::ScheduledEventId => {
+                            if scheduled_event_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("scheduledEventId"));
+                            }
+                            scheduled_event_id__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::Identity => {
+                            if identity__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("identity"));
+                            }
+                            identity__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::RequestId => {
+                            if request_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("requestId"));
+                            }
+                            request_id__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::SuggestContinueAsNew => {
+                            if suggest_continue_as_new__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("suggestContinueAsNew"));
+                            }
+                            suggest_continue_as_new__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::SuggestContinueAsNewReasons => {
+                            if suggest_continue_as_new_reasons__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("suggestContinueAsNewReasons"));
+                            }
+                            suggest_continue_as_new_reasons__ = Some(map_.next_value::<Vec<super::super::enums::v1::SuggestContinueAsNewReason>>()?.into_iter().map(|x| x as i32).collect());
+                        }
+                        GeneratedField::TargetWorkerDeploymentVersionChanged => {
+                            if target_worker_deployment_version_changed__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("targetWorkerDeploymentVersionChanged"));
+                            }
+                            target_worker_deployment_version_changed__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::HistorySizeBytes => {
+                            if history_size_bytes__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("historySizeBytes"));
+                            }
+                            history_size_bytes__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::WorkerVersion => {
+                            if worker_version__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("workerVersion"));
+                            }
+                            worker_version__ = map_.next_value()?;
+                        }
+                        GeneratedField::BuildIdRedirectCounter => {
+                            if build_id_redirect_counter__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("buildIdRedirectCounter"));
+                            }
+                            build_id_redirect_counter__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                    }
+                }
+                Ok(WorkflowTaskStartedEventAttributes {
+                    scheduled_event_id: scheduled_event_id__.unwrap_or_default(),
+                    identity: identity__.unwrap_or_default(),
+                    request_id: request_id__.unwrap_or_default(),
+                    suggest_continue_as_new: suggest_continue_as_new__.unwrap_or_default(),
+                    suggest_continue_as_new_reasons: suggest_continue_as_new_reasons__.unwrap_or_default(),
+                    target_worker_deployment_version_changed: target_worker_deployment_version_changed__.unwrap_or_default(),
+                    history_size_bytes: history_size_bytes__.unwrap_or_default(),
+                    worker_version: worker_version__,
+                    build_id_redirect_counter: build_id_redirect_counter__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("temporal.api.history.v1.WorkflowTaskStartedEventAttributes", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for WorkflowTaskTimedOutEventAttributes {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.scheduled_event_id != 0 {
+            len += 1;
+        }
+        if self.started_event_id != 0 {
+            len += 1;
+        }
+        if self.timeout_type != 0 {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("temporal.api.history.v1.WorkflowTaskTimedOutEventAttributes", len)?;
+        if self.scheduled_event_id != 0 {
+            #[allow(clippy::needless_borrow)]
+            #[allow(clippy::needless_borrows_for_generic_args)]
+            struct_ser.serialize_field("scheduledEventId", ToString::to_string(&self.scheduled_event_id).as_str())?;
+        }
+        if self.started_event_id != 0 {
+            #[allow(clippy::needless_borrow)]
+            #[allow(clippy::needless_borrows_for_generic_args)]
+            struct_ser.serialize_field("startedEventId", ToString::to_string(&self.started_event_id).as_str())?;
+        }
+        if self.timeout_type != 0 {
+            let v = super::super::enums::v1::TimeoutType::try_from(self.timeout_type)
+                .map_err(|_| serde::ser::Error::custom(format!("Invalid variant {}", self.timeout_type)))?;
+            struct_ser.serialize_field("timeoutType", &v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for WorkflowTaskTimedOutEventAttributes {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "scheduled_event_id",
+            "scheduledEventId",
+            "started_event_id",
+            "startedEventId",
+            "timeout_type",
+            "timeoutType",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            ScheduledEventId,
+            StartedEventId,
+            TimeoutType,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "scheduledEventId" | "scheduled_event_id" => Ok(GeneratedField::ScheduledEventId),
+                            "startedEventId" | "started_event_id" => Ok(GeneratedField::StartedEventId),
+                            "timeoutType" | "timeout_type" => Ok(GeneratedField::TimeoutType),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = WorkflowTaskTimedOutEventAttributes;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct temporal.api.history.v1.WorkflowTaskTimedOutEventAttributes")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<WorkflowTaskTimedOutEventAttributes, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut scheduled_event_id__ = None;
+                let mut started_event_id__ = None;
+                let mut timeout_type__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::ScheduledEventId => {
+                            if scheduled_event_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("scheduledEventId"));
+                            }
+                            scheduled_event_id__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::StartedEventId => {
+                            if started_event_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("startedEventId"));
+                            }
+                            started_event_id__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::TimeoutType => {
+                            if timeout_type__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("timeoutType"));
+                            }
+                            timeout_type__ = Some(map_.next_value::<super::super::enums::v1::TimeoutType>()? as i32);
+                        }
+                    }
+                }
+                Ok(WorkflowTaskTimedOutEventAttributes {
+                    scheduled_event_id: scheduled_event_id__.unwrap_or_default(),
+                    started_event_id: started_event_id__.unwrap_or_default(),
+                    timeout_type: timeout_type__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("temporal.api.history.v1.WorkflowTaskTimedOutEventAttributes", FIELDS, GeneratedVisitor)
+    }
+}
@@ -54,6 +54,25 @@ pub mod namespace_info {
         /// True if the namespace supports standalone activities
         #[prost(bool, tag = "7")]
         pub standalone_activities: bool,
+        /// True if the namespace supports server-side completion of outstanding worker polls on shutdown.
+        /// When enabled, the server will complete polls for workers that send WorkerInstanceKey in their
+        /// poll requests and call ShutdownWorker with the same WorkerInstanceKey. The poll will return
+        /// an empty response. When this flag is true, workers should allow polls to return gracefully
+        /// rather than terminating any open polls on shutdown.
+        #[prost(bool, tag = "8")]
+        pub worker_poll_complete_on_shutdown: bool,
+        /// True if the namespace supports poller autoscaling
+        #[prost(bool, tag = "9")]
+        pub poller_autoscaling: bool,
+        /// True if the namespace supports worker commands (server-to-worker communication via control queues).
+        #[prost(bool, tag = "10")]
+        pub worker_commands: bool,
+        /// True if the namespace supports standalone Nexus operations.
+        #[prost(bool, tag = "11")]
+        pub standalone_nexus_operation: bool,
+        /// True if the namespace supports attaching callbacks on workflow updates
+        #[prost(bool, tag = "12")]
+        pub workflow_update_callbacks: bool,
     }
     #[derive(Clone, Copy, PartialEq, ::prost::Message)]
     pub struct Limits {
@@ -71,7 +90,7 @@ pub mod namespace_info {
 pub struct NamespaceConfig {
     #[prost(message, optional, tag = "1")]
     pub workflow_execution_retention_ttl: ::core::option::Option<
-        ::prost_types::Duration,
+        ::pbjson_types::Duration,
     >,
     #[prost(message, optional, tag = "2")]
     pub bad_binaries: ::core::option::Option<BadBinaries>,
@@ -107,7 +126,7 @@ pub struct BadBinaryInfo {
     #[prost(string, tag = "2")]
     pub operator: ::prost::alloc::string::String,
     #[prost(message, optional, tag = "3")]
-    pub create_time: ::core::option::Option<::prost_types::Timestamp>,
+    pub create_time: ::core::option::Option<::pbjson_types::Timestamp>,
 }
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct UpdateNamespaceInfo {
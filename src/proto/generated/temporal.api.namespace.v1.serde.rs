@@ -0,0 +1,1305 @@
+impl serde::Serialize for BadBinaries {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.binaries.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("temporal.api.namespace.v1.BadBinaries", len)?;
+        if !self.binaries.is_empty() {
+            struct_ser.serialize_field("binaries", &self.binaries)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for BadBinaries {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "binaries",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Binaries,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "binaries" => Ok(GeneratedField::Binaries),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = BadBinaries;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct temporal.api.namespace.v1.BadBinaries")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<BadBinaries, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut binaries__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Binaries => {
+                            if binaries__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("binaries"));
+                            }
+                            binaries__ = Some(
+                                map_.next_value::<std::collections::HashMap<_, _>>()?
+                            );
+                        }
+                    }
+                }
+                Ok(BadBinaries {
+                    binaries: binaries__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("temporal.api.namespace.v1.BadBinaries", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for BadBinaryInfo {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.reason.is_empty() {
+            len += 1;
+        }
+        if !self.operator.is_empty() {
+            len += 1;
+        }
+        if self.create_time.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("temporal.api.namespace.v1.BadBinaryInfo", len)?;
+        if !self.reason.is_empty() {
+            struct_ser.serialize_field("reason", &self.reason)?;
+        }
+        if !self.operator.is_empty() {
+            struct_ser.serialize_field("operator", &self.operator)?;
+        }
+        if let Some(v) = self.create_time.as_ref() {
+            struct_ser.serialize_field("createTime", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for BadBinaryInfo {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "reason",
+            "operator",
+            "create_time",
+            "createTime",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Reason,
+            Operator,
+            CreateTime,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "reason" => Ok(GeneratedField::Reason),
+                            "operator" => Ok(GeneratedField::Operator),
+                            "createTime" | "create_time" => Ok(GeneratedField::CreateTime),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = BadBinaryInfo;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct temporal.api.namespace.v1.BadBinaryInfo")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<BadBinaryInfo, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut reason__ = None;
+                let mut operator__ = None;
+                let mut create_time__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Reason => {
+                            if reason__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("reason"));
+                            }
+                            reason__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::Operator => {
+                            if operator__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("operator"));
+                            }
+                            operator__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::CreateTime => {
+                            if create_time__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("createTime"));
+                            }
+                            create_time__ = map_.next_value()?;
+                        }
+                    }
+                }
+                Ok(BadBinaryInfo {
+                    reason: reason__.unwrap_or_default(),
+                    operator: operator__.unwrap_or_default(),
+                    create_time: create_time__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("temporal.api.namespace.v1.BadBinaryInfo", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for NamespaceConfig {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.workflow_execution_retention_ttl.is_some() {
+            len += 1;
+        }
+        if self.bad_binaries.is_some() {
+            len += 1;
+        }
+        if self.history_archival_state != 0 {
+            len += 1;
+        }
+        if !self.history_archival_uri.is_empty() {
+            len += 1;
+        }
+        if self.visibility_archival_state != 0 {
+            len += 1;
+        }
+        if !self.visibility_archival_uri.is_empty() {
+            len += 1;
+        }
+        if !self.custom_search_attribute_aliases.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("temporal.api.namespace.v1.NamespaceConfig", len)?;
+        if let Some(v) = self.workflow_execution_retention_ttl.as_ref() {
+            struct_ser.serialize_field("workflowExecutionRetentionTtl", v)?;
+        }
+        if let Some(v) = self.bad_binaries.as_ref() {
+            struct_ser.serialize_field("badBinaries", v)?;
+        }
+        if self.history_archival_state != 0 {
+            let v = super::super::enums::v1::ArchivalState::try_from(self.history_archival_state)
+                .map_err(|_| serde::ser::Error::custom(format!("Invalid variant {}", self.history_archival_state)))?;
+            struct_ser.serialize_field("historyArchivalState", &v)?;
+        }
+        if !self.history_archival_uri.is_empty() {
+            struct_ser.serialize_field("historyArchivalUri", &self.history_archival_uri)?;
+        }
+        if self.visibility_archival_state != 0 {
+            let v = super::super::enums::v1::ArchivalState::try_from(self.visibility_archival_state)
+                .map_err(|_| serde::ser::Error::custom(format!("Invalid variant {}", self.visibility_archival_state)))?;
+            struct_ser.serialize_field("visibilityArchivalState", &v)?;
+        }
+        if !self.visibility_archival_uri.is_empty() {
+            struct_ser.serialize_field("visibilityArchivalUri", &self.visibility_archival_uri)?;
+        }
+        if !self.custom_search_attribute_aliases.is_empty() {
+            struct_ser.serialize_field("customSearchAttributeAliases", &self.custom_search_attribute_aliases)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for NamespaceConfig {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "workflow_execution_retention_ttl",
+            "workflowExecutionRetentionTtl",
+            "bad_binaries",
+            "badBinaries",
+            "history_archival_state",
+            "historyArchivalState",
+            "history_archival_uri",
+            "historyArchivalUri",
+            "visibility_archival_state",
+            "visibilityArchivalState",
+            "visibility_archival_uri",
+            "visibilityArchivalUri",
+            "custom_search_attribute_aliases",
+            "customSearchAttributeAliases",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            WorkflowExecutionRetentionTtl,
+            BadBinaries,
+            HistoryArchivalState,
+            HistoryArchivalUri,
+            VisibilityArchivalState,
+            VisibilityArchivalUri,
+            CustomSearchAttributeAliases,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "workflowExecutionRetentionTtl" | "workflow_execution_retention_ttl" => Ok(GeneratedField::WorkflowExecutionRetentionTtl),
+                            "badBinaries" | "bad_binaries" => Ok(GeneratedField::BadBinaries),
+                            "historyArchivalState" | "history_archival_state" => Ok(GeneratedField::HistoryArchivalState),
+                            "historyArchivalUri" | "history_archival_uri" => Ok(GeneratedField::HistoryArchivalUri),
+                            "visibilityArchivalState" | "visibility_archival_state" => Ok(GeneratedField::VisibilityArchivalState),
+                            "visibilityArchivalUri" | "visibility_archival_uri" => Ok(GeneratedField::VisibilityArchivalUri),
+                            "customSearchAttributeAliases" | "custom_search_attribute_aliases" => Ok(GeneratedField::CustomSearchAttributeAliases),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = NamespaceConfig;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct temporal.api.namespace.v1.NamespaceConfig")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<NamespaceConfig, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut workflow_execution_retention_ttl__ = None;
+                let mut bad_binaries__ = None;
+                let mut history_archival_state__ = None;
+                let mut history_archival_uri__ = None;
+                let mut visibility_archival_state__ = None;
+                let mut visibility_archival_uri__ = None;
+                let mut custom_search_attribute_aliases__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::WorkflowExecutionRetentionTtl => {
+                            if workflow_execution_retention_ttl__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("workflowExecutionRetentionTtl"));
+                            }
+                            workflow_execution_retention_ttl__ = map_.next_value()?;
+                        }
+                        GeneratedField::BadBinaries => {
+                            if bad_binaries__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("badBinaries"));
+                            }
+                            bad_binaries__ = map_.next_value()?;
+                        }
+                        GeneratedField::HistoryArchivalState => {
+                            if history_archival_state__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("historyArchivalState"));
+                            }
+                            history_archival_state__ = Some(map_.next_value::<super::super::enums::v1::ArchivalState>()? as i32);
+                        }
+                        GeneratedField::HistoryArchivalUri => {
+                            if history_archival_uri__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("historyArchivalUri"));
+                            }
+                            history_archival_uri__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::VisibilityArchivalState => {
+                            if visibility_archival_state__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("visibilityArchivalState"));
+                            }
+                            visibility_archival_state__ = Some(map_.next_value::<super::super::enums::v1::ArchivalState>()? as i32);
+                        }
+                        GeneratedField::VisibilityArchivalUri => {
+                            if visibility_archival_uri__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("visibilityArchivalUri"));
+                            }
+                            visibility_archival_uri__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::CustomSearchAttributeAliases => {
+                            if custom_search_attribute_aliases__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("customSearchAttributeAliases"));
+                            }
+                            custom_search_attribute_aliases__ = Some(
+                                map_.next_value::<std::collections::HashMap<_, _>>()?
+                            );
+                        }
+                    }
+                }
+                Ok(NamespaceConfig {
+                    workflow_execution_retention_ttl: workflow_execution_retention_ttl__,
+                    bad_binaries: bad_binaries__,
+                    history_archival_state: history_archival_state__.unwrap_or_default(),
+                    history_archival_uri: history_archival_uri__.unwrap_or_default(),
+                    visibility_archival_state: visibility_archival_state__.unwrap_or_default(),
+                    visibility_archival_uri: visibility_archival_uri__.unwrap_or_default(),
+                    custom_search_attribute_aliases: custom_search_attribute_aliases__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("temporal.api.namespace.v1.NamespaceConfig", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for NamespaceFilter {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.include_deleted {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("temporal.api.namespace.v1.NamespaceFilter", len)?;
+        if self.include_deleted {
+            struct_ser.serialize_field("includeDeleted", &self.include_deleted)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for NamespaceFilter {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "include_deleted",
+            "includeDeleted",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            IncludeDeleted,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "includeDeleted" | "include_deleted" => Ok(GeneratedField::IncludeDeleted),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = NamespaceFilter;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct temporal.api.namespace.v1.NamespaceFilter")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<NamespaceFilter, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut include_deleted__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::IncludeDeleted => {
+                            if include_deleted__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("includeDeleted"));
+                            }
+                            include_deleted__ = Some(map_.next_value()?);
+                        }
+                    }
+                }
+                Ok(NamespaceFilter {
+                    include_deleted: include_deleted__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("temporal.api.namespace.v1.NamespaceFilter", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for NamespaceInfo {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.name.is_empty() {
+            len += 1;
+        }
+        if self.state != 0 {
+            len += 1;
+        }
+        if !self.description.is_empty() {
+            len += 1;
+        }
+        if !self.owner_email.is_empty() {
+            len += 1;
+        }
+        if !self.data.is_empty() {
+            len += 1;
+        }
+        if !self.id.is_empty() {
+            len += 1;
+        }
+        if self.capabilities.is_some() {
+            len += 1;
+        }
+        if self.limits.is_some() {
+            len += 1;
+        }
+        if self.supports_schedules {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("temporal.api.namespace.v1.NamespaceInfo", len)?;
+        if !self.name.is_empty() {
+            struct_ser.serialize_field("name", &self.name)?;
+        }
+        if self.state != 0 {
+            let v = super::super::enums::v1::NamespaceState::try_from(self.state)
+                .map_err(|_| serde::ser::Error::custom(format!("Invalid variant {}", self.state)))?;
+            struct_ser.serialize_field("state", &v)?;
+        }
+        if !self.description.is_empty() {
+            struct_ser.serialize_field("description", &self.description)?;
+        }
+        if !self.owner_email.is_empty() {
+            struct_ser.serialize_field("ownerEmail", &self.owner_email)?;
+        }
+        if !self.data.is_empty() {
+            struct_ser.serialize_field("data", &self.data)?;
+        }
+        if !self.id.is_empty() {
+            struct_ser.serialize_field("id", &self.id)?;
+        }
+        if let Some(v) = self.capabilities.as_ref() {
+            struct_ser.serialize_field("capabilities", v)?;
+        }
+        if let Some(v) = self.limits.as_ref() {
+            struct_ser.serialize_field("limits", v)?;
+        }
+        if self.supports_schedules {
+            struct_ser.serialize_field("supportsSchedules", &self.supports_schedules)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for NamespaceInfo {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "name",
+            "state",
+            "description",
+            "owner_email",
+            "ownerEmail",
+            "data",
+            "id",
+            "capabilities",
+            "limits",
+            "supports_schedules",
+            "supportsSchedules",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Name,
+            State,
+            Description,
+            OwnerEmail,
+            Data,
+            Id,
+            Capabilities,
+            Limits,
+            SupportsSchedules,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "name" => Ok(GeneratedField::Name),
+                            "state" => Ok(GeneratedField::State),
+                            "description" => Ok(GeneratedField::Description),
+                            "ownerEmail" | "owner_email" => Ok(GeneratedField::OwnerEmail),
+                            "data" => Ok(GeneratedField::Data),
+                            "id" => Ok(GeneratedField::Id),
+                            "capabilities" => Ok(GeneratedField::Capabilities),
+                            "limits" => Ok(GeneratedField::Limits),
+                            "supportsSchedules" | "supports_schedules" => Ok(GeneratedField::SupportsSchedules),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = NamespaceInfo;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct temporal.api.namespace.v1.NamespaceInfo")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<NamespaceInfo, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut name__ = None;
+                let mut state__ = None;
+                let mut description__ = None;
+                let mut owner_email__ = None;
+                let mut data__ = None;
+                let mut id__ = None;
+                let mut capabilities__ = None;
+                let mut limits__ = None;
+                let mut supports_schedules__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Name => {
+                            if name__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("name"));
+                            }
+                            name__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::State => {
+                            if state__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("state"));
+                            }
+                            state__ = Some(map_.next_value::<super::super::enums::v1::NamespaceState>()? as i32);
+                        }
+                        GeneratedField::Description => {
+                            if description__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("description"));
+                            }
+                            description__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::OwnerEmail => {
+                            if owner_email__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("ownerEmail"));
+                            }
+                            owner_email__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::Data => {
+                            if data__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("data"));
+                            }
+                            data__ = Some(
+                                map_.next_value::<std::collections::HashMap<_, _>>()?
+                            );
+                        }
+                        GeneratedField::Id => {
+                            if id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("id"));
+                            }
+                            id__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::Capabilities => {
+                            if capabilities__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("capabilities"));
+                            }
+                            capabilities__ = map_.next_value()?;
+                        }
+                        GeneratedField::Limits => {
+                            if limits__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("limits"));
+                            }
+                            limits__ = map_.next_value()?;
+                        }
+                        GeneratedField::SupportsSchedules => {
+                            if supports_schedules__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("supportsSchedules"));
+                            }
+                            supports_schedules__ = Some(map_.next_value()?);
+                        }
+                    }
+                }
+                Ok(NamespaceInfo {
+                    name: name__.unwrap_or_default(),
+                    state: state__.unwrap_or_default(),
+                    description: description__.unwrap_or_default(),
+                    owner_email: owner_email__.unwrap_or_default(),
+                    data: data__.unwrap_or_default(),
+                    id: id__.unwrap_or_default(),
+                    capabilities: capabilities__,
+                    limits: limits__,
+                    supports_schedules: supports_schedules__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("temporal.api.namespace.v1.NamespaceInfo", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for namespace_info::Capabilities {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.eager_workflow_start {
+            len += 1;
+        }
+        if self.sync_update {
+            len += 1;
+        }
+        if self.async_update {
+            len += 1;
+        }
+        if self.worker_heartbeats {
+            len += 1;
+        }
+        if self.reported_problems_search_attribute {
+            len += 1;
+        }
+        if self.workflow_pause {
+            len += 1;
+        }
+        if self.standalone_activities {
+            len += 1;
+        }
+        if self.worker_poll_complete_on_shutdown {
+            len += 1;
+        }
+        if self.poller_autoscaling {
+            len += 1;
+        }
+        if self.worker_commands {
+            len += 1;
+        }
+        if self.standalone_nexus_operation {
+            len += 1;
+        }
+        if self.workflow_update_callbacks {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("temporal.api.namespace.v1.NamespaceInfo.Capabilities", len)?;
+        if self.eager_workflow_start {
+            struct_ser.serialize_field("eagerWorkflowStart", &self.eager_workflow_start)?;
+        }
+        if self.sync_update {
+            struct_ser.serialize_field("syncUpdate", &self.sync_update)?;
+        }
+        if self.async_update {
+            struct_ser.serialize_field("asyncUpdate", &self.async_update)?;
+        }
+        if self.worker_heartbeats {
+            struct_ser.serialize_field("workerHeartbeats", &self.worker_heartbeats)?;
+        }
+        if self.reported_problems_search_attribute {
+            struct_ser.serialize_field("reportedProblemsSearchAttribute", &self.reported_problems_search_attribute)?;
+        }
+        if self.workflow_pause {
+            struct_ser.serialize_field("workflowPause", &self.workflow_pause)?;
+        }
+        if self.standalone_activities {
+            struct_ser.serialize_field("standaloneActivities", &self.standalone_activities)?;
+        }
+        if self.worker_poll_complete_on_shutdown {
+            struct_ser.serialize_field("workerPollCompleteOnShutdown", &self.worker_poll_complete_on_shutdown)?;
+        }
+        if self.poller_autoscaling {
+            struct_ser.serialize_field("pollerAutoscaling", &self.poller_autoscaling)?;
+        }
+        if self.worker_commands {
+            struct_ser.serialize_field("workerCommands", &self.worker_commands)?;
+        }
+        if self.standalone_nexus_operation {
+            struct_ser.serialize_field("standaloneNexusOperation", &self.standalone_nexus_operation)?;
+        }
+        if self.workflow_update_callbacks {
+            struct_ser.serialize_field("workflowUpdateCallbacks", &self.workflow_update_callbacks)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for namespace_info::Capabilities {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "eager_workflow_start",
+            "eagerWorkflowStart",
+            "sync_update",
+            "syncUpdate",
+            "async_update",
+            "asyncUpdate",
+            "worker_heartbeats",
+            "workerHeartbeats",
+            "reported_problems_search_attribute",
+            "reportedProblemsSearchAttribute",
+            "workflow_pause",
+            "workflowPause",
+            "standalone_activities",
+            "standaloneActivities",
+            "worker_poll_complete_on_shutdown",
+            "workerPollCompleteOnShutdown",
+            "poller_autoscaling",
+            "pollerAutoscaling",
+            "worker_commands",
+            "workerCommands",
+            "standalone_nexus_operation",
+            "standaloneNexusOperation",
+            "workflow_update_callbacks",
+            "workflowUpdateCallbacks",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            EagerWorkflowStart,
+            SyncUpdate,
+            AsyncUpdate,
+            WorkerHeartbeats,
+            ReportedProblemsSearchAttribute,
+            WorkflowPause,
+            StandaloneActivities,
+            WorkerPollCompleteOnShutdown,
+            PollerAutoscaling,
+            WorkerCommands,
+            StandaloneNexusOperation,
+            WorkflowUpdateCallbacks,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "eagerWorkflowStart" | "eager_workflow_start" => Ok(GeneratedField::EagerWorkflowStart),
+                            "syncUpdate" | "sync_update" => Ok(GeneratedField::SyncUpdate),
+                            "asyncUpdate" | "async_update" => Ok(GeneratedField::AsyncUpdate),
+                            "workerHeartbeats" | "worker_heartbeats" => Ok(GeneratedField::WorkerHeartbeats),
+                            "reportedProblemsSearchAttribute" | "reported_problems_search_attribute" => Ok(GeneratedField::ReportedProblemsSearchAttribute),
+                            "workflowPause" | "workflow_pause" => Ok(GeneratedField::WorkflowPause),
+                            "standaloneActivities" | "standalone_activities" => Ok(GeneratedField::StandaloneActivities),
+                            "workerPollCompleteOnShutdown" | "worker_poll_complete_on_shutdown" => Ok(GeneratedField::WorkerPollCompleteOnShutdown),
+                            "pollerAutoscaling" | "poller_autoscaling" => Ok(GeneratedField::PollerAutoscaling),
+                            "workerCommands" | "worker_commands" => Ok(GeneratedField::WorkerCommands),
+                            "standaloneNexusOperation" | "standalone_nexus_operation" => Ok(GeneratedField::StandaloneNexusOperation),
+                            "workflowUpdateCallbacks" | "workflow_update_callbacks" => Ok(GeneratedField::WorkflowUpdateCallbacks),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = namespace_info::Capabilities;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct temporal.api.namespace.v1.NamespaceInfo.Capabilities")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<namespace_info::Capabilities, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut eager_workflow_start__ = None;
+                let mut sync_update__ = None;
+                let mut async_update__ = None;
+                let mut worker_heartbeats__ = None;
+                let mut reported_problems_search_attribute__ = None;
+                let mut workflow_pause__ = None;
+                let mut standalone_activities__ = None;
+                let mut worker_poll_complete_on_shutdown__ = None;
+                let mut poller_autoscaling__ = None;
+                let mut worker_commands__ = None;
+                let mut standalone_nexus_operation__ = None;
+                let mut workflow_update_callbacks__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::EagerWorkflowStart => {
+                            if eager_workflow_start__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("eagerWorkflowStart"));
+                            }
+                            eager_workflow_start__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::SyncUpdate => {
+                            if sync_update__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("syncUpdate"));
+                            }
+                            sync_update__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::AsyncUpdate => {
+                            if async_update__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("asyncUpdate"));
+                            }
+                            async_update__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::WorkerHeartbeats => {
+                            if worker_heartbeats__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("workerHeartbeats"));
+                            }
+                            worker_heartbeats__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::ReportedProblemsSearchAttribute => {
+                            if reported_problems_search_attribute__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("reportedProblemsSearchAttribute"));
+                            }
+                            reported_problems_search_attribute__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::WorkflowPause => {
+                            if workflow_pause__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("workflowPause"));
+                            }
+                            workflow_pause__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::StandaloneActivities => {
+                            if standalone_activities__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("standaloneActivities"));
+                            }
+                            standalone_activities__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::WorkerPollCompleteOnShutdown => {
+                            if worker_poll_complete_on_shutdown__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("workerPollCompleteOnShutdown"));
+                            }
+                            worker_poll_complete_on_shutdown__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::PollerAutoscaling => {
+                            if poller_autoscaling__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("pollerAutoscaling"));
+                            }
+                            poller_autoscaling__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::WorkerCommands => {
+                            if worker_commands__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("workerCommands"));
+                            }
+                            worker_commands__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::StandaloneNexusOperation => {
+                            if standalone_nexus_operation__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("standaloneNexusOperation"));
+                            }
+                            standalone_nexus_operation__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::WorkflowUpdateCallbacks => {
+                            if workflow_update_callbacks__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("workflowUpdateCallbacks"));
+                            }
+                            workflow_update_callbacks__ = Some(map_.next_value()?);
+                        }
+                    }
+                }
+                Ok(namespace_info::Capabilities {
+                    eager_workflow_start: eager_workflow_start__.unwrap_or_default(),
+                    sync_update: sync_update__.unwrap_or_default(),
+                    async_update: async_update__.unwrap_or_default(),
+                    worker_heartbeats: worker_heartbeats__.unwrap_or_default(),
+                    reported_problems_search_attribute: reported_problems_search_attribute__.unwrap_or_default(),
+                    workflow_pause: workflow_pause__.unwrap_or_default(),
+                    standalone_activities: standalone_activities__.unwrap_or_default(),
+                    worker_poll_complete_on_shutdown: worker_poll_complete_on_shutdown__.unwrap_or_default(),
+                    poller_autoscaling: poller_autoscaling__.unwrap_or_default(),
+                    worker_commands: worker_commands__.unwrap_or_default(),
+                    standalone_nexus_operation: standalone_nexus_operation__.unwrap_or_default(),
+                    workflow_update_callbacks: workflow_update_callbacks__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("temporal.api.namespace.v1.NamespaceInfo.Capabilities", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for namespace_info::Limits {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.blob_size_limit_error != 0 {
+            len += 1;
+        }
+        if self.memo_size_limit_error != 0 {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("temporal.api.namespace.v1.NamespaceInfo.Limits", len)?;
+        if self.blob_size_limit_error != 0 {
+            #[allow(clippy::needless_borrow)]
+            #[allow(clippy::needless_borrows_for_generic_args)]
+            struct_ser.serialize_field("blobSizeLimitError", ToString::to_string(&self.blob_size_limit_error).as_str())?;
+        }
+        if self.memo_size_limit_error != 0 {
+            #[allow(clippy::needless_borrow)]
+            #[allow(clippy::needless_borrows_for_generic_args)]
+            struct_ser.serialize_field("memoSizeLimitError", ToString::to_string(&self.memo_size_limit_error).as_str())?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for namespace_info::Limits {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "blob_size_limit_error",
+            "blobSizeLimitError",
+            "memo_size_limit_error",
+            "memoSizeLimitError",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            BlobSizeLimitError,
+            MemoSizeLimitError,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "blobSizeLimitError" | "blob_size_limit_error" => Ok(GeneratedField::BlobSizeLimitError),
+                            "memoSizeLimitError" | "memo_size_limit_error" => Ok(GeneratedField::MemoSizeLimitError),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = namespace_info::Limits;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct temporal.api.namespace.v1.NamespaceInfo.Limits")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<namespace_info::Limits, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut blob_size_limit_error__ = None;
+                let mut memo_size_limit_error__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::BlobSizeLimitError => {
+                            if blob_size_limit_error__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("blobSizeLimitError"));
+                            }
+                            blob_size_limit_error__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::MemoSizeLimitError => {
+                            if memo_size_limit_error__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("memoSizeLimitError"));
+                            }
+                            memo_size_limit_error__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                    }
+                }
+                Ok(namespace_info::Limits {
+                    blob_size_limit_error: blob_size_limit_error__.unwrap_or_default(),
+                    memo_size_limit_error: memo_size_limit_error__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("temporal.api.namespace.v1.NamespaceInfo.Limits", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for UpdateNamespaceInfo {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.description.is_empty() {
+            len += 1;
+        }
+        if !self.owner_email.is_empty() {
+            len += 1;
+        }
+        if !self.data.is_empty() {
+            len += 1;
+        }
+        if self.state != 0 {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("temporal.api.namespace.v1.UpdateNamespaceInfo", len)?;
+        if !self.description.is_empty() {
+            struct_ser.serialize_field("description", &self.description)?;
+        }
+        if !self.owner_email.is_empty() {
+            struct_ser.serialize_field("ownerEmail", &self.owner_email)?;
+        }
+        if !self.data.is_empty() {
+            struct_ser.serialize_field("data", &self.data)?;
+        }
+        if self.state != 0 {
+            let v = super::super::enums::v1::NamespaceState::try_from(self.state)
+                .map_err(|_| serde::ser::Error::custom(format!("Invalid variant {}", self.state)))?;
+            struct_ser.serialize_field("state", &v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for UpdateNamespaceInfo {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "description",
+            "owner_email",
+            "ownerEmail",
+            "data",
+            "state",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Description,
+            OwnerEmail,
+            Data,
+            State,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "description" => Ok(GeneratedField::Description),
+                            "ownerEmail" | "owner_email" => Ok(GeneratedField::OwnerEmail),
+                            "data" => Ok(GeneratedField::Data),
+                            "state" => Ok(GeneratedField::State),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = UpdateNamespaceInfo;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct temporal.api.namespace.v1.UpdateNamespaceInfo")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<UpdateNamespaceInfo, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut description__ = None;
+                let mut owner_email__ = None;
+                let mut data__ = None;
+                let mut state__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Description => {
+                            if description__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("description"));
+                            }
+                            description__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::OwnerEmail => {
+                            if owner_email__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("ownerEmail"));
+                            }
+                            owner_email__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::Data => {
+                            if data__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("data"));
+                            }
+                            data__ = Some(
+                                map_.next_value::<std::collections::HashMap<_, _>>()?
+                            );
+                        }
+                        GeneratedField::State => {
+                            if state__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("state"));
+                            }
+                            state__ = Some(map_.next_value::<super::super::enums::v1::NamespaceState>()? as i32);
+                        }
+                    }
+                }
+                Ok(UpdateNamespaceInfo {
+                    description: description__.unwrap_or_default(),
+                    owner_email: owner_email__.unwrap_or_default(),
+                    data: data__.unwrap_or_default(),
+                    state: state__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("temporal.api.namespace.v1.UpdateNamespaceInfo", FIELDS, GeneratedVisitor)
+    }
+}
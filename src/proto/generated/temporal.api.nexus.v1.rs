@@ -5,6 +5,8 @@
 pub struct Failure {
     #[prost(string, tag = "1")]
     pub message: ::prost::alloc::string::String,
+    #[prost(string, tag = "4")]
+    pub stack_trace: ::prost::alloc::string::String,
     #[prost(map = "string, string", tag = "2")]
     pub metadata: ::std::collections::HashMap<
         ::prost::alloc::string::String,
@@ -13,6 +15,8 @@ pub struct Failure {
     /// UTF-8 encoded JSON serializable details.
     #[prost(bytes = "vec", tag = "3")]
     pub details: ::prost::alloc::vec::Vec<u8>,
+    #[prost(message, optional, boxed, tag = "5")]
+    pub cause: ::core::option::Option<::prost::alloc::boxed::Box<Failure>>,
 }
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct HandlerError {
@@ -105,7 +109,9 @@ pub struct Request {
     /// (-- api-linter: core::0142::time-field-names=disabled
     ///      aip.dev/not-precedent: Not following linter rules. --)
     #[prost(message, optional, tag = "2")]
-    pub scheduled_time: ::core::option::Option<::prost_types::Timestamp>,
+    pub scheduled_time: ::core::option::Option<::pbjson_types::Timestamp>,
+    #[prost(message, optional, tag = "100")]
+    pub capabilities: ::core::option::Option<request::Capabilities>,
     /// The endpoint this request was addressed to before forwarding to the worker.
     /// Supported from server version 1.30.0.
     #[prost(string, tag = "10")]
@@ -115,6 +121,13 @@ pub struct Request {
 }
 /// Nested message and enum types in `Request`.
 pub mod request {
+    #[derive(Clone, Copy, PartialEq, ::prost::Message)]
+    pub struct Capabilities {
+        /// If set, handlers may use temporal.api.failure.v1.Failure instances to return failures to the server.
+        /// This also allows handler and operation errors to have their own messages and stack traces.
+        #[prost(bool, tag = "1")]
+        pub temporal_failure_responses: bool,
+    }
     #[derive(Clone, PartialEq, ::prost::Oneof)]
     pub enum Variant {
         #[prost(message, tag = "3")]
@@ -126,7 +139,7 @@ pub mod request {
 /// Response variant for StartOperationRequest.
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct StartOperationResponse {
-    #[prost(oneof = "start_operation_response::Variant", tags = "1, 2, 3")]
+    #[prost(oneof = "start_operation_response::Variant", tags = "1, 2, 3, 4")]
     pub variant: ::core::option::Option<start_operation_response::Variant>,
 }
 /// Nested message and enum types in `StartOperationResponse`.
@@ -159,8 +172,13 @@ pub mod start_operation_response {
         #[prost(message, tag = "2")]
         AsyncSuccess(Async),
         /// The operation completed unsuccessfully (failed or canceled).
+        /// Deprecated. Use the failure variant instead.
         #[prost(message, tag = "3")]
         OperationError(super::UnsuccessfulOperationError),
+        /// The operation completed unsuccessfully (failed or canceled).
+        /// Failure object must contain an ApplicationFailureInfo or CanceledFailureInfo object.
+        #[prost(message, tag = "4")]
+        Failure(super::super::super::failure::v1::Failure),
     }
 }
 /// Response variant for CancelOperationRequest.
@@ -200,13 +218,13 @@ pub struct Endpoint {
     /// (-- api-linter: core::0142::time-field-names=disabled
     ///      aip.dev/not-precedent: Not following linter rules. --)
     #[prost(message, optional, tag = "4")]
-    pub created_time: ::core::option::Option<::prost_types::Timestamp>,
+    pub created_time: ::core::option::Option<::pbjson_types::Timestamp>,
     /// The date and time when the endpoint was last modified.
     /// Will not be set if the endpoint has never been modified.
     /// (-- api-linter: core::0142::time-field-names=disabled
     ///      aip.dev/not-precedent: Not following linter rules. --)
     #[prost(message, optional, tag = "5")]
-    pub last_modified_time: ::core::option::Option<::prost_types::Timestamp>,
+    pub last_modified_time: ::core::option::Option<::pbjson_types::Timestamp>,
     /// Server exposed URL prefix for invocation of operations on this endpoint.
     /// This doesn't include the protocol, hostname or port as the server does not know how it should be accessed
     /// publicly. The URL is stable in the face of endpoint renames.
@@ -264,3 +282,196 @@ pub mod endpoint_target {
         External(External),
     }
 }
+/// NexusOperationExecutionCancellationInfo contains the state of a Nexus operation cancellation.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct NexusOperationExecutionCancellationInfo {
+    /// The time when cancellation was requested.
+    #[prost(message, optional, tag = "1")]
+    pub requested_time: ::core::option::Option<::pbjson_types::Timestamp>,
+    #[prost(
+        enumeration = "super::super::enums::v1::NexusOperationCancellationState",
+        tag = "2"
+    )]
+    pub state: i32,
+    /// The number of attempts made to deliver the cancel operation request.
+    /// This number represents a minimum bound since the attempt is incremented after the request completes.
+    #[prost(int32, tag = "3")]
+    pub attempt: i32,
+    /// The time when the last attempt completed.
+    #[prost(message, optional, tag = "4")]
+    pub last_attempt_complete_time: ::core::option::Option<::pbjson_types::Timestamp>,
+    /// The last attempt's failure, if any.
+    #[prost(message, optional, tag = "5")]
+    pub last_attempt_failure: ::core::option::Option<super::super::failure::v1::Failure>,
+    /// The time when the next attempt is scheduled.
+    #[prost(message, optional, tag = "6")]
+    pub next_attempt_schedule_time: ::core::option::Option<::pbjson_types::Timestamp>,
+    /// If the state is BLOCKED, blocked reason provides additional information.
+    #[prost(string, tag = "7")]
+    pub blocked_reason: ::prost::alloc::string::String,
+    /// A reason that may be specified in the CancelNexusOperationRequest.
+    #[prost(string, tag = "8")]
+    pub reason: ::prost::alloc::string::String,
+}
+/// Full current state of a standalone Nexus operation, as of the time of the request.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct NexusOperationExecutionInfo {
+    /// Unique identifier of this Nexus operation within its namespace along with run ID (below).
+    #[prost(string, tag = "1")]
+    pub operation_id: ::prost::alloc::string::String,
+    #[prost(string, tag = "2")]
+    pub run_id: ::prost::alloc::string::String,
+    /// Endpoint name, resolved to a URL via the cluster's endpoint registry.
+    #[prost(string, tag = "3")]
+    pub endpoint: ::prost::alloc::string::String,
+    /// Service name.
+    #[prost(string, tag = "4")]
+    pub service: ::prost::alloc::string::String,
+    /// Operation name.
+    #[prost(string, tag = "5")]
+    pub operation: ::prost::alloc::string::String,
+    /// A general status for this operation, indicates whether it is currently running or in one of the terminal statuses.
+    /// Updated once when the operation is originally scheduled, and again when it reaches a terminal status.
+    #[prost(
+        enumeration = "super::super::enums::v1::NexusOperationExecutionStatus",
+        tag = "6"
+    )]
+    pub status: i32,
+    /// More detailed breakdown of NEXUS_OPERATION_EXECUTION_STATUS_RUNNING.
+    #[prost(
+        enumeration = "super::super::enums::v1::PendingNexusOperationState",
+        tag = "7"
+    )]
+    pub state: i32,
+    /// Schedule-to-close timeout for this operation.
+    /// (-- api-linter: core::0140::prepositions=disabled
+    ///      aip.dev/not-precedent: "to" is used to indicate interval. --)
+    #[prost(message, optional, tag = "8")]
+    pub schedule_to_close_timeout: ::core::option::Option<::pbjson_types::Duration>,
+    /// Schedule-to-start timeout for this operation.
+    /// (-- api-linter: core::0140::prepositions=disabled
+    ///      aip.dev/not-precedent: "to" is used to indicate interval. --)
+    #[prost(message, optional, tag = "9")]
+    pub schedule_to_start_timeout: ::core::option::Option<::pbjson_types::Duration>,
+    /// Start-to-close timeout for this operation.
+    /// (-- api-linter: core::0140::prepositions=disabled
+    ///      aip.dev/not-precedent: "to" is used to indicate interval. --)
+    #[prost(message, optional, tag = "10")]
+    pub start_to_close_timeout: ::core::option::Option<::pbjson_types::Duration>,
+    /// The number of attempts made to deliver the start operation request.
+    /// This number is approximate, it is incremented when a task is added to the history queue.
+    /// In practice, there could be more attempts if a task is executed but fails to commit, or less attempts if a task
+    /// was never executed.
+    #[prost(int32, tag = "11")]
+    pub attempt: i32,
+    /// Time the operation was originally scheduled via a StartNexusOperation request.
+    #[prost(message, optional, tag = "12")]
+    pub schedule_time: ::core::option::Option<::pbjson_types::Timestamp>,
+    /// Scheduled time + schedule to close timeout.
+    #[prost(message, optional, tag = "13")]
+    pub expiration_time: ::core::option::Option<::pbjson_types::Timestamp>,
+    /// Time when the operation transitioned to a closed state.
+    #[prost(message, optional, tag = "14")]
+    pub close_time: ::core::option::Option<::pbjson_types::Timestamp>,
+    /// The time when the last attempt completed.
+    #[prost(message, optional, tag = "15")]
+    pub last_attempt_complete_time: ::core::option::Option<::pbjson_types::Timestamp>,
+    /// The last attempt's failure, if any.
+    #[prost(message, optional, tag = "16")]
+    pub last_attempt_failure: ::core::option::Option<super::super::failure::v1::Failure>,
+    /// The time when the next attempt is scheduled.
+    #[prost(message, optional, tag = "17")]
+    pub next_attempt_schedule_time: ::core::option::Option<::pbjson_types::Timestamp>,
+    /// Elapsed time from schedule_time to now for running operations or to close_time for closed
+    /// operations, including all attempts and backoff between attempts.
+    #[prost(message, optional, tag = "18")]
+    pub execution_duration: ::core::option::Option<::pbjson_types::Duration>,
+    #[prost(message, optional, tag = "19")]
+    pub cancellation_info: ::core::option::Option<
+        NexusOperationExecutionCancellationInfo,
+    >,
+    /// If the state is BLOCKED, blocked reason provides additional information.
+    #[prost(string, tag = "20")]
+    pub blocked_reason: ::prost::alloc::string::String,
+    /// Server-generated request ID used as an idempotency token when submitting start requests to
+    /// the handler. Distinct from the request_id in StartNexusOperationRequest, which is the
+    /// caller-side idempotency key for the StartNexusOperation RPC itself.
+    #[prost(string, tag = "21")]
+    pub request_id: ::prost::alloc::string::String,
+    /// Operation token. Only set for asynchronous operations after a successful StartOperation call.
+    #[prost(string, tag = "22")]
+    pub operation_token: ::prost::alloc::string::String,
+    /// Incremented each time the operation's state is mutated in persistence.
+    #[prost(int64, tag = "23")]
+    pub state_transition_count: i64,
+    #[prost(message, optional, tag = "24")]
+    pub search_attributes: ::core::option::Option<
+        super::super::common::v1::SearchAttributes,
+    >,
+    /// Header for context propagation and tracing purposes.
+    #[prost(map = "string, string", tag = "25")]
+    pub nexus_header: ::std::collections::HashMap<
+        ::prost::alloc::string::String,
+        ::prost::alloc::string::String,
+    >,
+    /// Metadata for use by user interfaces to display the fixed as-of-start summary and details of the operation.
+    #[prost(message, optional, tag = "26")]
+    pub user_metadata: ::core::option::Option<super::super::sdk::v1::UserMetadata>,
+    /// Links attached by the handler of this operation on start or completion.
+    #[prost(message, repeated, tag = "27")]
+    pub links: ::prost::alloc::vec::Vec<super::super::common::v1::Link>,
+    /// The identity of the client who started this operation.
+    #[prost(string, tag = "28")]
+    pub identity: ::prost::alloc::string::String,
+    /// Updated once on scheduled and once on terminal status.
+    #[prost(int64, tag = "29")]
+    pub state_size_bytes: i64,
+}
+/// Limited Nexus operation information returned in the list response.
+/// When adding fields here, ensure that it is also present in NexusOperationExecutionInfo (note that it may already be present in
+/// NexusOperationExecutionInfo but not at the top-level).
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct NexusOperationExecutionListInfo {
+    /// A unique identifier of this operation within its namespace along with run ID (below).
+    #[prost(string, tag = "1")]
+    pub operation_id: ::prost::alloc::string::String,
+    /// The run ID of the standalone Nexus operation.
+    #[prost(string, tag = "2")]
+    pub run_id: ::prost::alloc::string::String,
+    /// Endpoint name.
+    #[prost(string, tag = "3")]
+    pub endpoint: ::prost::alloc::string::String,
+    /// Service name.
+    #[prost(string, tag = "4")]
+    pub service: ::prost::alloc::string::String,
+    /// Operation name.
+    #[prost(string, tag = "5")]
+    pub operation: ::prost::alloc::string::String,
+    /// Time the operation was originally scheduled via a StartNexusOperation request.
+    #[prost(message, optional, tag = "6")]
+    pub schedule_time: ::core::option::Option<::pbjson_types::Timestamp>,
+    /// If the operation is in a terminal status, this field represents the time the operation transitioned to that status.
+    #[prost(message, optional, tag = "7")]
+    pub close_time: ::core::option::Option<::pbjson_types::Timestamp>,
+    /// The status is updated once, when the operation is originally scheduled, and again when the operation reaches a terminal status.
+    #[prost(
+        enumeration = "super::super::enums::v1::NexusOperationExecutionStatus",
+        tag = "8"
+    )]
+    pub status: i32,
+    /// Search attributes from the start request.
+    #[prost(message, optional, tag = "9")]
+    pub search_attributes: ::core::option::Option<
+        super::super::common::v1::SearchAttributes,
+    >,
+    /// Updated on terminal status.
+    #[prost(int64, tag = "10")]
+    pub state_transition_count: i64,
+    /// The difference between close time and scheduled time.
+    /// This field is only populated if the operation is closed.
+    #[prost(message, optional, tag = "11")]
+    pub execution_duration: ::core::option::Option<::pbjson_types::Duration>,
+    /// Updated once on scheduled and once on terminal status.
+    #[prost(int64, tag = "12")]
+    pub state_size_bytes: i64,
+}
@@ -0,0 +1,3418 @@
+impl serde::Serialize for CancelOperationRequest {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.service.is_empty() {
+            len += 1;
+        }
+        if !self.operation.is_empty() {
+            len += 1;
+        }
+        if !self.operation_id.is_empty() {
+            len += 1;
+        }
+        if !self.operation_token.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("temporal.api.nexus.v1.CancelOperationRequest", len)?;
+        if !self.service.is_empty() {
+            struct_ser.serialize_field("service", &self.service)?;
+        }
+        if !self.operation.is_empty() {
+            struct_ser.serialize_field("operation", &self.operation)?;
+        }
+        if !self.operation_id.is_empty() {
+            struct_ser.serialize_field("operationId", &self.operation_id)?;
+        }
+        if !self.operation_token.is_empty() {
+            struct_ser.serialize_field("operationToken", &self.operation_token)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for CancelOperationRequest {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "service",
+            "operation",
+            "operation_id",
+            "operationId",
+            "operation_token",
+            "operationToken",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Service,
+            Operation,
+            OperationId,
+            OperationToken,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "service" => Ok(GeneratedField::Service),
+                            "operation" => Ok(GeneratedField::Operation),
+                            "operationId" | "operation_id" => Ok(GeneratedField::OperationId),
+                            "operationToken" | "operation_token" => Ok(GeneratedField::OperationToken),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = CancelOperationRequest;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct temporal.api.nexus.v1.CancelOperationRequest")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<CancelOperationRequest, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut service__ = None;
+                let mut operation__ = None;
+                let mut operation_id__ = None;
+                let mut operation_token__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Service => {
+                            if service__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("service"));
+                            }
+                            service__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::Operation => {
+                            if operation__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("operation"));
+                            }
+                            operation__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::OperationId => {
+                            if operation_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("operationId"));
+                            }
+                            operation_id__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::OperationToken => {
+                            if operation_token__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("operationToken"));
+                            }
+                            operation_token__ = Some(map_.next_value()?);
+                        }
+                    }
+                }
+                Ok(CancelOperationRequest {
+                    service: service__.unwrap_or_default(),
+                    operation: operation__.unwrap_or_default(),
+                    operation_id: operation_id__.unwrap_or_default(),
+                    operation_token: operation_token__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("temporal.api.nexus.v1.CancelOperationRequest", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for CancelOperationResponse {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let len = 0;
+        let struct_ser = serializer.serialize_struct("temporal.api.nexus.v1.CancelOperationResponse", len)?;
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for CancelOperationResponse {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                            Err(serde::de::Error::unknown_field(value, FIELDS))
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = CancelOperationResponse;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct temporal.api.nexus.v1.CancelOperationResponse")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<CancelOperationResponse, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                while map_.next_key::<GeneratedField>()?.is_some() {
+                    let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                }
+                Ok(CancelOperationResponse {
+                })
+            }
+        }
+        deserializer.deserialize_struct("temporal.api.nexus.v1.CancelOperationResponse", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for Endpoint {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.version != 0 {
+            len += 1;
+        }
+        if !self.id.is_empty() {
+            len += 1;
+        }
+        if self.spec.is_some() {
+            len += 1;
+        }
+        if self.created_time.is_some() {
+            len += 1;
+        }
+        if self.last_modified_time.is_some() {
+            len += 1;
+        }
+        if !self.url_prefix.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("temporal.api.nexus.v1.Endpoint", len)?;
+        if self.version != 0 {
+            #[allow(clippy::needless_borrow)]
+            #[allow(clippy::needless_borrows_for_generic_args)]
+            struct_ser.serialize_field("version", ToString::to_string(&self.version).as_str())?;
+        }
+        if !self.id.is_empty() {
+            struct_ser.serialize_field("id", &self.id)?;
+        }
+        if let Some(v) = self.spec.as_ref() {
+            struct_ser.serialize_field("spec", v)?;
+        }
+        if let Some(v) = self.created_time.as_ref() {
+            struct_ser.serialize_field("createdTime", v)?;
+        }
+        if let Some(v) = self.last_modified_time.as_ref() {
+            struct_ser.serialize_field("lastModifiedTime", v)?;
+        }
+        if !self.url_prefix.is_empty() {
+            struct_ser.serialize_field("urlPrefix", &self.url_prefix)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for Endpoint {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "version",
+            "id",
+            "spec",
+            "created_time",
+            "createdTime",
+            "last_modified_time",
+            "lastModifiedTime",
+            "url_prefix",
+            "urlPrefix",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Version,
+            Id,
+            Spec,
+            CreatedTime,
+            LastModifiedTime,
+            UrlPrefix,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "version" => Ok(GeneratedField::Version),
+                            "id" => Ok(GeneratedField::Id),
+                            "spec" => Ok(GeneratedField::Spec),
+                            "createdTime" | "created_time" => Ok(GeneratedField::CreatedTime),
+                            "lastModifiedTime" | "last_modified_time" => Ok(GeneratedField::LastModifiedTime),
+                            "urlPrefix" | "url_prefix" => Ok(GeneratedField::UrlPrefix),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = Endpoint;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct temporal.api.nexus.v1.Endpoint")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<Endpoint, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut version__ = None;
+                let mut id__ = None;
+                let mut spec__ = None;
+                let mut created_time__ = None;
+                let mut last_modified_time__ = None;
+                let mut url_prefix__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Version => {
+                            if version__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("version"));
+                            }
+                            version__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::Id => {
+                            if id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("id"));
+                            }
+                            id__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::Spec => {
+                            if spec__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("spec"));
+                            }
+                            spec__ = map_.next_value()?;
+                        }
+                        GeneratedField::CreatedTime => {
+                            if created_time__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("createdTime"));
+                            }
+                            created_time__ = map_.next_value()?;
+                        }
+                        GeneratedField::LastModifiedTime => {
+                            if last_modified_time__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("lastModifiedTime"));
+                            }
+                            last_modified_time__ = map_.next_value()?;
+                        }
+                        GeneratedField::UrlPrefix => {
+                            if url_prefix__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("urlPrefix"));
+                            }
+                            url_prefix__ = Some(map_.next_value()?);
+                        }
+                    }
+                }
+                Ok(Endpoint {
+                    version: version__.unwrap_or_default(),
+                    id: id__.unwrap_or_default(),
+                    spec: spec__,
+                    created_time: created_time__,
+                    last_modified_time: last_modified_time__,
+                    url_prefix: url_prefix__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("temporal.api.nexus.v1.Endpoint", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for EndpointSpec {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.name.is_empty() {
+            len += 1;
+        }
+        if self.description.is_some() {
+            len += 1;
+        }
+        if self.target.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("temporal.api.nexus.v1.EndpointSpec", len)?;
+        if !self.name.is_empty() {
+            struct_ser.serialize_field("name", &self.name)?;
+        }
+        if let Some(v) = self.description.as_ref() {
+            struct_ser.serialize_field("description", v)?;
+        }
+        if let Some(v) = self.target.as_ref() {
+            struct_ser.serialize_field("target", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for EndpointSpec {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "name",
+            "description",
+            "target",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Name,
+            Description,
+            Target,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "name" => Ok(GeneratedField::Name),
+                            "description" => Ok(GeneratedField::Description),
+                            "target" => Ok(GeneratedField::Target),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = EndpointSpec;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct temporal.api.nexus.v1.EndpointSpec")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<EndpointSpec, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut name__ = None;
+                let mut description__ = None;
+                let mut target__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Name => {
+                            if name__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("name"));
+                            }
+                            name__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::Description => {
+                            if description__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("description"));
+                            }
+                            description__ = map_.next_value()?;
+                        }
+                        GeneratedField::Target => {
+                            if target__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("target"));
+                            }
+                            target__ = map_.next_value()?;
+                        }
+                    }
+                }
+                Ok(EndpointSpec {
+                    name: name__.unwrap_or_default(),
+                    description: description__,
+                    target: target__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("temporal.api.nexus.v1.EndpointSpec", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for EndpointTarget {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.variant.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("temporal.api.nexus.v1.EndpointTarget", len)?;
+        if let Some(v) = self.variant.as_ref() {
+            match v {
+                endpoint_target::Variant::Worker(v) => {
+                    struct_ser.serialize_field("worker", v)?;
+                }
+                endpoint_target::Variant::External(v) => {
+                    struct_ser.serialize_field("external", v)?;
+                }
+            }
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for EndpointTarget {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "worker",
+            "external",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Worker,
+            External,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "worker" => Ok(GeneratedField::Worker),
+                            "external" => Ok(GeneratedField::External),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = EndpointTarget;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct temporal.api.nexus.v1.EndpointTarget")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<EndpointTarget, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut variant__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Worker => {
+                            if variant__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("worker"));
+                            }
+                            variant__ = map_.next_value::<::std::option::Option<_>>()?.map(endpoint_target::Variant::Worker)
+;
+                        }
+                        GeneratedField::External => {
+                            if variant__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("external"));
+                            }
+                            variant__ = map_.next_value::<::std::option::Option<_>>()?.map(endpoint_target::Variant::External)
+;
+                        }
+                    }
+                }
+                Ok(EndpointTarget {
+                    variant: variant__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("temporal.api.nexus.v1.EndpointTarget", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for endpoint_target::External {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.url.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("temporal.api.nexus.v1.EndpointTarget.External", len)?;
+        if !self.url.is_empty() {
+            struct_ser.serialize_field("url", &self.url)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for endpoint_target::External {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "url",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Url,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "url" => Ok(GeneratedField::Url),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = endpoint_target::External;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct temporal.api.nexus.v1.EndpointTarget.External")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<endpoint_target::External, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut url__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Url => {
+                            if url__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("url"));
+                            }
+                            url__ = Some(map_.next_value()?);
+                        }
+                    }
+                }
+                Ok(endpoint_target::External {
+                    url: url__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("temporal.api.nexus.v1.EndpointTarget.External", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for endpoint_target::Worker {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.namespace.is_empty() {
+            len += 1;
+        }
+        if !self.task_queue.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("temporal.api.nexus.v1.EndpointTarget.Worker", len)?;
+        if !self.namespace.is_empty() {
+            struct_ser.serialize_field("namespace", &self.namespace)?;
+        }
+        if !self.task_queue.is_empty() {
+            struct_ser.serialize_field("taskQueue", &self.task_queue)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for endpoint_target::Worker {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "namespace",
+            "task_queue",
+            "taskQueue",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Namespace,
+            TaskQueue,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "namespace" => Ok(GeneratedField::Namespace),
+                            "taskQueue" | "task_queue" => Ok(GeneratedField::TaskQueue),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = endpoint_target::Worker;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct temporal.api.nexus.v1.EndpointTarget.Worker")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<endpoint_target::Worker, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut namespace__ = None;
+                let mut task_queue__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Namespace => {
+                            if namespace__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("namespace"));
+                            }
+                            namespace__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::TaskQueue => {
+                            if task_queue__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("taskQueue"));
+                            }
+                            task_queue__ = Some(map_.next_value()?);
+                        }
+                    }
+                }
+                Ok(endpoint_target::Worker {
+                    namespace: namespace__.unwrap_or_default(),
+                    task_queue: task_queue__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("temporal.api.nexus.v1.EndpointTarget.Worker", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for Failure {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.message.is_empty() {
+            len += 1;
+        }
+        if !self.stack_trace.is_empty() {
+            len += 1;
+        }
+        if !self.metadata.is_empty() {
+            len += 1;
+        }
+        if !self.details.is_empty() {
+            len += 1;
+        }
+        if self.cause.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("temporal.api.nexus.v1.Failure", len)?;
+        if !self.message.is_empty() {
+            struct_ser.serialize_field("message", &self.message)?;
+        }
+        if !self.stack_trace.is_empty() {
+            struct_ser.serialize_field("stackTrace", &self.stack_trace)?;
+        }
+        if !self.metadata.is_empty() {
+            struct_ser.serialize_field("metadata", &self.metadata)?;
+        }
+        if !self.details.is_empty() {
+            #[allow(clippy::needless_borrow)]
+            #[allow(clippy::needless_borrows_for_generic_args)]
+            struct_ser.serialize_field("details", pbjson::private::base64::encode(&self.details).as_str())?;
+        }
+        if let Some(v) = self.cause.as_ref() {
+            struct_ser.serialize_field("cause", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for Failure {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "message",
+            "stack_trace",
+            "stackTrace",
+            "metadata",
+            "details",
+            "cause",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Message,
+            StackTrace,
+            Metadata,
+            Details,
+            Cause,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "message" => Ok(GeneratedField::Message),
+                            "stackTrace" | "stack_trace" => Ok(GeneratedField::StackTrace),
+                            "metadata" => Ok(GeneratedField::Metadata),
+                            "details" => Ok(GeneratedField::Details),
+                            "cause" => Ok(GeneratedField::Cause),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = Failure;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct temporal.api.nexus.v1.Failure")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<Failure, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut message__ = None;
+                let mut stack_trace__ = None;
+                let mut metadata__ = None;
+                let mut details__ = None;
+                let mut cause__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Message => {
+                            if message__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("message"));
+                            }
+                            message__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::StackTrace => {
+                            if stack_trace__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("stackTrace"));
+                            }
+                            stack_trace__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::Metadata => {
+                            if metadata__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("metadata"));
+                            }
+                            metadata__ = Some(
+                                map_.next_value::<std::collections::HashMap<_, _>>()?
+                            );
+                        }
+                        GeneratedField::Details => {
+                            if details__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("details"));
+                            }
+                            details__ = 
+                                Some(map_.next_value::<::pbjson::private::BytesDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::Cause => {
+                            if cause__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("cause"));
+                            }
+                            cause__ = map_.next_value()?;
+                        }
+                    }
+                }
+                Ok(Failure {
+                    message: message__.unwrap_or_default(),
+                    stack_trace: stack_trace__.unwrap_or_default(),
+                    metadata: metadata__.unwrap_or_default(),
+                    details: details__.unwrap_or_default(),
+                    cause: cause__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("temporal.api.nexus.v1.Failure", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for HandlerError {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.error_type.is_empty() {
+            len += 1;
+        }
+        if self.failure.is_some() {
+            len += 1;
+        }
+        if self.retry_behavior != 0 {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("temporal.api.nexus.v1.HandlerError", len)?;
+        if !self.error_type.is_empty() {
+            struct_ser.serialize_field("errorType", &self.error_type)?;
+        }
+        if let Some(v) = self.failure.as_ref() {
+            struct_ser.serialize_field("failure", v)?;
+        }
+        if self.retry_behavior != 0 {
+            let v = super::super::enums::v1::NexusHandlerErrorRetryBehavior::try_from(self.retry_behavior)
+                .map_err(|_| serde::ser::Error::custom(format!("Invalid variant {}", self.retry_behavior)))?;
+            struct_ser.serialize_field("retryBehavior", &v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for HandlerError {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "error_type",
+            "errorType",
+            "failure",
+            "retry_behavior",
+            "retryBehavior",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            ErrorType,
+            Failure,
+            RetryBehavior,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "errorType" | "error_type" => Ok(GeneratedField::ErrorType),
+                            "failure" => Ok(GeneratedField::Failure),
+                            "retryBehavior" | "retry_behavior" => Ok(GeneratedField::RetryBehavior),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = HandlerError;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct temporal.api.nexus.v1.HandlerError")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<HandlerError, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut error_type__ = None;
+                let mut failure__ = None;
+                let mut retry_behavior__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::ErrorType => {
+                            if error_type__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("errorType"));
+                            }
+                            error_type__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::Failure => {
+                            if failure__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("failure"));
+                            }
+                            failure__ = map_.next_value()?;
+                        }
+                        GeneratedField::RetryBehavior => {
+                            if retry_behavior__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("retryBehavior"));
+                            }
+                            retry_behavior__ = Some(map_.next_value::<super::super::enums::v1::NexusHandlerErrorRetryBehavior>()? as i32);
+                        }
+                    }
+                }
+                Ok(HandlerError {
+                    error_type: error_type__.unwrap_or_default(),
+                    failure: failure__,
+                    retry_behavior: retry_behavior__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("temporal.api.nexus.v1.HandlerError", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for Link {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.url.is_empty() {
+            len += 1;
+        }
+        if !self.r#type.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("temporal.api.nexus.v1.Link", len)?;
+        if !self.url.is_empty() {
+            struct_ser.serialize_field("url", &self.url)?;
+        }
+        if !self.r#type.is_empty() {
+            struct_ser.serialize_field("type", &self.r#type)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for Link {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "url",
+            "type",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Url,
+            Type,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "url" => Ok(GeneratedField::Url),
+                            "type" => Ok(GeneratedField::Type),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = Link;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct temporal.api.nexus.v1.Link")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<Link, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut url__ = None;
+                let mut r#type__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Url => {
+                            if url__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("url"));
+                            }
+                            url__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::Type => {
+                            if r#type__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("type"));
+                            }
+                            r#type__ = Some(map_.next_value()?);
+                        }
+                    }
+                }
+                Ok(Link {
+                    url: url__.unwrap_or_default(),
+                    r#type: r#type__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("temporal.api.nexus.v1.Link", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for NexusOperationExecutionCancellationInfo {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.requested_time.is_some() {
+            len += 1;
+        }
+        if self.state != 0 {
+            len += 1;
+        }
+        if self.attempt != 0 {
+            len += 1;
+        }
+        if self.last_attempt_complete_time.is_some() {
+            len += 1;
+        }
+        if self.last_attempt_failure.is_some() {
+            len += 1;
+        }
+        if self.next_attempt_schedule_time.is_some() {
+            len += 1;
+        }
+        if !self.blocked_reason.is_empty() {
+            len += 1;
+        }
+        if !self.reason.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("temporal.api.nexus.v1.NexusOperationExecutionCancellationInfo", len)?;
+        if let Some(v) = self.requested_time.as_ref() {
+            struct_ser.serialize_field("requestedTime", v)?;
+        }
+        if self.state != 0 {
+            let v = super::super::enums::v1::NexusOperationCancellationState::try_from(self.state)
+                .map_err(|_| serde::ser::Error::custom(format!("Invalid variant {}", self.state)))?;
+            struct_ser.serialize_field("state", &v)?;
+        }
+        if self.attempt != 0 {
+            struct_ser.serialize_field("attempt", &self.attempt)?;
+        }
+        if let Some(v) = self.last_attempt_complete_time.as_ref() {
+            struct_ser.serialize_field("lastAttemptCompleteTime", v)?;
+        }
+        if let Some(v) = self.last_attempt_failure.as_ref() {
+            struct_ser.serialize_field("lastAttemptFailure", v)?;
+        }
+        if let Some(v) = self.next_attempt_schedule_time.as_ref() {
+            struct_ser.serialize_field("nextAttemptScheduleTime", v)?;
+        }
+        if !self.blocked_reason.is_empty() {
+            struct_ser.serialize_field("blockedReason", &self.blocked_reason)?;
+        }
+        if !self.reason.is_empty() {
+            struct_ser.serialize_field("reason", &self.reason)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for NexusOperationExecutionCancellationInfo {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "requested_time",
+            "requestedTime",
+            "state",
+            "attempt",
+            "last_attempt_complete_time",
+            "lastAttemptCompleteTime",
+            "last_attempt_failure",
+            "lastAttemptFailure",
+            "next_attempt_schedule_time",
+            "nextAttemptScheduleTime",
+            "blocked_reason",
+            "blockedReason",
+            "reason",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            RequestedTime,
+            State,
+            Attempt,
+            LastAttemptCompleteTime,
+            LastAttemptFailure,
+            NextAttemptScheduleTime,
+            BlockedReason,
+            Reason,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "requestedTime" | "requested_time" => Ok(GeneratedField::RequestedTime),
+                            "state" => Ok(GeneratedField::State),
+                            "attempt" => Ok(GeneratedField::Attempt),
+                            "lastAttemptCompleteTime" | "last_attempt_complete_time" => Ok(GeneratedField::LastAttemptCompleteTime),
+                            "lastAttemptFailure" | "last_attempt_failure" => Ok(GeneratedField::LastAttemptFailure),
+                            "nextAttemptScheduleTime" | "next_attempt_schedule_time" => Ok(GeneratedField::NextAttemptScheduleTime),
+                            "blockedReason" | "blocked_reason" => Ok(GeneratedField::BlockedReason),
+                            "reason" => Ok(GeneratedField::Reason),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = NexusOperationExecutionCancellationInfo;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct temporal.api.nexus.v1.NexusOperationExecutionCancellationInfo")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<NexusOperationExecutionCancellationInfo, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut requested_time__ = None;
+                let mut state__ = None;
+                let mut attempt__ = None;
+                let mut last_attempt_complete_time__ = None;
+                let mut last_attempt_failure__ = None;
+                let mut next_attempt_schedule_time__ = None;
+                let mut blocked_reason__ = None;
+                let mut reason__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::RequestedTime => {
+                            if requested_time__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("requestedTime"));
+                            }
+                            requested_time__ = map_.next_value()?;
+                        }
+                        GeneratedField::State => {
+                            if state__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("state"));
+                            }
+                            state__ = Some(map_.next_value::<super::super::enums::v1::NexusOperationCancellationState>()? as i32);
+                        }
+                        GeneratedField::Attempt => {
+                            if attempt__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("attempt"));
+                            }
+                            attempt__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::LastAttemptCompleteTime => {
+                            if last_attempt_complete_time__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("lastAttemptCompleteTime"));
+                            }
+                            last_attempt_complete_time__ = map_.next_value()?;
+                        }
+                        GeneratedField::LastAttemptFailure => {
+                            if last_attempt_failure__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("lastAttemptFailure"));
+                            }
+                            last_attempt_failure__ = map_.next_value()?;
+                        }
+                        GeneratedField::NextAttemptScheduleTime => {
+                            if next_attempt_schedule_time__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("nextAttemptScheduleTime"));
+                            }
+                            next_attempt_schedule_time__ = map_.next_value()?;
+                        }
+                        GeneratedField::BlockedReason => {
+                            if blocked_reason__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("blockedReason"));
+                            }
+                            blocked_reason__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::Reason => {
+                            if reason__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("reason"));
+                            }
+                            reason__ = Some(map_.next_value()?);
+                        }
+                    }
+                }
+                Ok(NexusOperationExecutionCancellationInfo {
+                    requested_time: requested_time__,
+                    state: state__.unwrap_or_default(),
+                    attempt: attempt__.unwrap_or_default(),
+                    last_attempt_complete_time: last_attempt_complete_time__,
+                    last_attempt_failure: last_attempt_failure__,
+                    next_attempt_schedule_time: next_attempt_schedule_time__,
+                    blocked_reason: blocked_reason__.unwrap_or_default(),
+                    reason: reason__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("temporal.api.nexus.v1.NexusOperationExecutionCancellationInfo", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for NexusOperationExecutionInfo {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.operation_id.is_empty() {
+            len += 1;
+        }
+        if !self.run_id.is_empty() {
+            len += 1;
+        }
+        if !self.endpoint.is_empty() {
+            len += 1;
+        }
+        if !self.service.is_empty() {
+            len += 1;
+        }
+        if !self.operation.is_empty() {
+            len += 1;
+        }
+        if self.status != 0 {
+            len += 1;
+        }
+        if self.state != 0 {
+            len += 1;
+        }
+        if self.schedule_to_close_timeout.is_some() {
+            len += 1;
+        }
+        if self.schedule_to_start_timeout.is_some() {
+            len += 1;
+        }
+        if self.start_to_close_timeout.is_some() {
+            len += 1;
+        }
+        if self.attempt != 0 {
+            len += 1;
+        }
+        if self.schedule_time.is_some() {
+            len += 1;
+        }
+        if self.expiration_time.is_some() {
+            len += 1;
+        }
+        if self.close_time.is_some() {
+            len += 1;
+        }
+        if self.last_attempt_complete_time.is_some() {
+            len += 1;
+        }
+        if self.last_attempt_failure.is_some() {
+            len += 1;
+        }
+        if self.next_attempt_schedule_time.is_some() {
+            len += 1;
+        }
+        if self.execution_duration.is_some() {
+            len += 1;
+        }
+        if self.cancellation_info.is_some() {
+            len += 1;
+        }
+        if !self.blocked_reason.is_empty() {
+            len += 1;
+        }
+        if !self.request_id.is_empty() {
+            len += 1;
+        }
+        if !self.operation_token.is_empty() {
+            len += 1;
+        }
+        if self.state_transition_count != 0 {
+            len += 1;
+        }
+        if self.search_attributes.is_some() {
+            len += 1;
+        }
+        if !self.nexus_header.is_empty() {
+            len += 1;
+        }
+        if self.user_metadata.is_some() {
+            len += 1;
+        }
+        if !self.links.is_empty() {
+            len += 1;
+        }
+        if !self.identity.is_empty() {
+            len += 1;
+        }
+        if self.state_size_bytes != 0 {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("temporal.api.nexus.v1.NexusOperationExecutionInfo", len)?;
+        if !self.operation_id.is_empty() {
+            struct_ser.serialize_field("operationId", &self.operation_id)?;
+        }
+        if !self.run_id.is_empty() {
+            struct_ser.serialize_field("runId", &self.run_id)?;
+        }
+        if !self.endpoint.is_empty() {
+            struct_ser.serialize_field("endpoint", &self.endpoint)?;
+        }
+        if !self.service.is_empty() {
+            struct_ser.serialize_field("service", &self.service)?;
+        }
+        if !self.operation.is_empty() {
+            struct_ser.serialize_field("operation", &self.operation)?;
+        }
+        if self.status != 0 {
+            let v = super::super::enums::v1::NexusOperationExecutionStatus::try_from(self.status)
+                .map_err(|_| serde::ser::Error::custom(format!("Invalid variant {}", self.status)))?;
+            struct_ser.serialize_field("status", &v)?;
+        }
+        if self.state != 0 {
+            let v = super::super::enums::v1::PendingNexusOperationState::try_from(self.state)
+                .map_err(|_| serde::ser::Error::custom(format!("Invalid variant {}", self.state)))?;
+            struct_ser.serialize_field("state", &v)?;
+        }
+        if let Some(v) = self.schedule_to_close_timeout.as_ref() {
+            struct_ser.serialize_field("scheduleToCloseTimeout", v)?;
+        }
+        if let Some(v) = self.schedule_to_start_timeout.as_ref() {
+            struct_ser.serialize_field("scheduleToStartTimeout", v)?;
+        }
+        if let Some(v) = self.start_to_close_timeout.as_ref() {
+            struct_ser.serialize_field("startToCloseTimeout", v)?;
+        }
+        if self.attempt != 0 {
+            struct_ser.serialize_field("attempt", &self.attempt)?;
+        }
+        if let Some(v) = self.schedule_time.as_ref() {
+            struct_ser.serialize_field("scheduleTime", v)?;
+        }
+        if let Some(v) = self.expiration_time.as_ref() {
+            struct_ser.serialize_field("expirationTime", v)?;
+        }
+        if let Some(v) = self.close_time.as_ref() {
+            struct_ser.serialize_field("closeTime", v)?;
+        }
+        if let Some(v) = self.last_attempt_complete_time.as_ref() {
+            struct_ser.serialize_field("lastAttemptCompleteTime", v)?;
+        }
+        if let Some(v) = self.last_attempt_failure.as_ref() {
+            struct_ser.serialize_field("lastAttemptFailure", v)?;
+        }
+        if let Some(v) = self.next_attempt_schedule_time.as_ref() {
+            struct_ser.serialize_field("nextAttemptScheduleTime", v)?;
+        }
+        if let Some(v) = self.execution_duration.as_ref() {
+            struct_ser.serialize_field("executionDuration", v)?;
+        }
+        if let Some(v) = self.cancellation_info.as_ref() {
+            struct_ser.serialize_field("cancellationInfo", v)?;
+        }
+        if !self.blocked_reason.is_empty() {
+            struct_ser.serialize_field("blockedReason", &self.blocked_reason)?;
+        }
+        if !self.request_id.is_empty() {
+            struct_ser.serialize_field("requestId", &self.request_id)?;
+        }
+        if !self.operation_token.is_empty() {
+            struct_ser.serialize_field("operationToken", &self.operation_token)?;
+        }
+        if self.state_transition_count != 0 {
+            #[allow(clippy::needless_borrow)]
+            #[allow(clippy::needless_borrows_for_generic_args)]
+            struct_ser.serialize_field("stateTransitionCount", ToString::to_string(&self.state_transition_count).as_str())?;
+        }
+        if let Some(v) = self.search_attributes.as_ref() {
+            struct_ser.serialize_field("searchAttributes", v)?;
+        }
+        if !self.nexus_header.is_empty() {
+            struct_ser.serialize_field("nexusHeader", &self.nexus_header)?;
+        }
+        if let Some(v) = self.user_metadata.as_ref() {
+            struct_ser.serialize_field("userMetadata", v)?;
+        }
+        if !self.links.is_empty() {
+            struct_ser.serialize_field("links", &self.links)?;
+        }
+        if !self.identity.is_empty() {
+            struct_ser.serialize_field("identity", &self.identity)?;
+        }
+        if self.state_size_bytes != 0 {
+            #[allow(clippy::needless_borrow)]
+            #[allow(clippy::needless_borrows_for_generic_args)]
+            struct_ser.serialize_field("stateSizeBytes", ToString::to_string(&self.state_size_bytes).as_str())?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for NexusOperationExecutionInfo {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "operation_id",
+            "operationId",
+            "run_id",
+            "runId",
+            "endpoint",
+            "service",
+            "operation",
+            "status",
+            "state",
+            "schedule_to_close_timeout",
+            "scheduleToCloseTimeout",
+            "schedule_to_start_timeout",
+            "scheduleToStartTimeout",
+            "start_to_close_timeout",
+            "startToCloseTimeout",
+            "attempt",
+            "schedule_time",
+            "scheduleTime",
+            "expiration_time",
+            "expirationTime",
+            "close_time",
+            "closeTime",
+            "last_attempt_complete_time",
+            "lastAttemptCompleteTime",
+            "last_attempt_failure",
+            "lastAttemptFailure",
+            "next_attempt_schedule_time",
+            "nextAttemptScheduleTime",
+            "execution_duration",
+            "executionDuration",
+            "cancellation_info",
+            "cancellationInfo",
+            "blocked_reason",
+            "blockedReason",
+            "request_id",
+            "requestId",
+            "operation_token",
+            "operationToken",
+            "state_transition_count",
+            "stateTransitionCount",
+            "search_attributes",
+            "searchAttributes",
+            "nexus_header",
+            "nexusHeader",
+            "user_metadata",
+            "userMetadata",
+            "links",
+            "identity",
+            "state_size_bytes",
+            "stateSizeBytes",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            OperationId,
+            RunId,
+            Endpoint,
+            Service,
+            Operation,
+            Status,
+            State,
+            ScheduleToCloseTimeout,
+            ScheduleToStartTimeout,
+            StartToCloseTimeout,
+            Attempt,
+            ScheduleTime,
+            ExpirationTime,
+            CloseTime,
+            LastAttemptCompleteTime,
+            LastAttemptFailure,
+            NextAttemptScheduleTime,
+            ExecutionDuration,
+            CancellationInfo,
+            BlockedReason,
+            RequestId,
+            OperationToken,
+            StateTransitionCount,
+            SearchAttributes,
+            NexusHeader,
+            UserMetadata,
+            Links,
+            Identity,
+            StateSizeBytes,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "operationId" | "operation_id" => Ok(GeneratedField::OperationId),
+                            "runId" | "run_id" => Ok(GeneratedField::RunId),
+                            "endpoint" => Ok(GeneratedField::Endpoint),
+                            "service" => Ok(GeneratedField::Service),
+                            "operation" => Ok(GeneratedField::Operation),
+                            "status" => Ok(GeneratedField::Status),
+                            "state" => Ok(GeneratedField::State),
+                            "scheduleToCloseTimeout" | "schedule_to_close_timeout" => Ok(GeneratedField::ScheduleToCloseTimeout),
+                            "scheduleToStartTimeout" | "schedule_to_start_timeout" => Ok(GeneratedField::ScheduleToStartTimeout),
+                            "startToCloseTimeout" | "start_to_close_timeout" => Ok(GeneratedField::StartToCloseTimeout),
+                            "attempt" => Ok(GeneratedField::Attempt),
+                            "scheduleTime" | "schedule_time" => Ok(GeneratedField::ScheduleTime),
+                            "expirationTime" | "expiration_time" => Ok(GeneratedField::ExpirationTime),
+                            "closeTime" | "close_time" => Ok(GeneratedField::CloseTime),
+                            "lastAttemptCompleteTime" | "last_attempt_complete_time" => Ok(GeneratedField::LastAttemptCompleteTime),
+                            "lastAttemptFailure" | "last_attempt_failure" => Ok(GeneratedField::LastAttemptFailure),
+                            "nextAttemptScheduleTime" | "next_attempt_schedule_time" => Ok(GeneratedField::NextAttemptScheduleTime),
+                            "executionDuration" | "execution_duration" => Ok(GeneratedField::ExecutionDuration),
+                            "cancellationInfo" | "cancellation_info" => Ok(GeneratedField::CancellationInfo),
+                            "blockedReason" | "blocked_reason" => Ok(GeneratedField::BlockedReason),
+                            "requestId" | "request_id" => Ok(GeneratedField::RequestId),
+                            "operationToken" | "operation_token" => Ok(GeneratedField::OperationToken),
+                            "stateTransitionCount" | "state_transition_count" => Ok(GeneratedField::StateTransitionCount),
+                            "searchAttributes" | "search_attributes" => Ok(GeneratedField::SearchAttributes),
+                            "nexusHeader" | "nexus_header" => Ok(GeneratedField::NexusHeader),
+                            "userMetadata" | "user_metadata" => Ok(GeneratedField::UserMetadata),
+                            "links" => Ok(GeneratedField::Links),
+                            "identity" => Ok(GeneratedField::Identity),
+                            "stateSizeBytes" | "state_size_bytes" => Ok(GeneratedField::StateSizeBytes),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = NexusOperationExecutionInfo;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct temporal.api.nexus.v1.NexusOperationExecutionInfo")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<NexusOperationExecutionInfo, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut operation_id__ = None;
+                let mut run_id__ = None;
+                let mut endpoint__ = None;
+                let mut service__ = None;
+                let mut operation__ = None;
+                let mut status__ = None;
+                let mut state__ = None;
+                let mut schedule_to_close_timeout__ = None;
+                let mut schedule_to_start_timeout__ = None;
+                let mut start_to_close_timeout__ = None;
+                let mut attempt__ = None;
+                let mut schedule_time__ = None;
+                let mut expiration_time__ = None;
+                let mut close_time__ = None;
+                let mut last_attempt_complete_time__ = None;
+                let mut last_attempt_failure__ = None;
+                let mut next_attempt_schedule_time__ = None;
+                let mut execution_duration__ = None;
+                let mut cancellation_info__ = None;
+                let mut blocked_reason__ = None;
+                let mut request_id__ = None;
+                let mut operation_token__ = None;
+                let mut state_transition_count__ = None;
+                let mut search_attributes__ = None;
+                let mut nexus_header__ = None;
+                let mut user_metadata__ = None;
+                let mut links__ = None;
+                let mut identity__ = None;
+                let mut state_size_bytes__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::OperationId => {
+                            if operation_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("operationId"));
+                            }
+                            operation_id__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::RunId => {
+                            if run_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("runId"));
+                            }
+                            run_id__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::Endpoint => {
+                            if endpoint__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("endpoint"));
+                            }
+                            endpoint__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::Service => {
+                            if service__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("service"));
+                            }
+                            service__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::Operation => {
+                            if operation__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("operation"));
+                            }
+                            operation__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::Status => {
+                            if status__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("status"));
+                            }
+                            status__ = Some(map_.next_value::<super::super::enums::v1::NexusOperationExecutionStatus>()? as i32);
+                        }
+                        GeneratedField::State => {
+                            if state__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("state"));
+                            }
+                            state__ = Some(map_.next_value::<super::super::enums::v1::PendingNexusOperationState>()? as i32);
+                        }
+                        GeneratedField::ScheduleToCloseTimeout => {
+                            if schedule_to_close_timeout__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("scheduleToCloseTimeout"));
+                            }
+                            schedule_to_close_timeout__ = map_.next_value()?;
+                        }
+                        GeneratedField::ScheduleToStartTimeout => {
+                            if schedule_to_start_timeout__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("scheduleToStartTimeout"));
+                            }
+                            schedule_to_start_timeout__ = map_.next_value()?;
+                        }
+                        GeneratedField::StartToCloseTimeout => {
+                            if start_to_close_timeout__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("startToCloseTimeout"));
+                            }
+                            start_to_close_timeout__ = map_.next_value()?;
+                        }
+                        GeneratedField::Attempt => {
+                            if attempt__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("attempt"));
+                            }
+                            attempt__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::ScheduleTime => {
+                            if schedule_time__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("scheduleTime"));
+                            }
+                            schedule_time__ = map_.next_value()?;
+                        }
+                        GeneratedField::ExpirationTime => {
+                            if expiration_time__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("expirationTime"));
+                            }
+                            expiration_time__ = map_.next_value()?;
+                        }
+                        GeneratedField::CloseTime => {
+                            if close_time__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("closeTime"));
+                            }
+                            close_time__ = map_.next_value()?;
+                        }
+                        GeneratedField::LastAttemptCompleteTime => {
+                            if last_attempt_complete_time__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("lastAttemptCompleteTime"));
+                            }
+                            last_attempt_complete_time__ = map_.next_value()?;
+                        }
+                        GeneratedField::LastAttemptFailure => {
+                            if last_attempt_failure__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("lastAttemptFailure"));
+                            }
+                            last_attempt_failure__ = map_.next_value()?;
+                        }
+                        GeneratedField::NextAttemptScheduleTime => {
+                            if next_attempt_schedule_time__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("nextAttemptScheduleTime"));
+                            }
+                            next_attempt_schedule_time__ = map_.next_value()?;
+                        }
+                        GeneratedField::ExecutionDuration => {
+                            if execution_duration__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("executionDuration"));
+                            }
+                            execution_duration__ = map_.next_value()?;
+                        }
+                        GeneratedField::CancellationInfo => {
+                            if cancellation_info__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("cancellationInfo"));
+                            }
+                            cancellation_info__ = map_.next_value()?;
+                        }
+                        GeneratedField::BlockedReason => {
+                            if blocked_reason__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("blockedReason"));
+                            }
+                            blocked_reason__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::RequestId => {
+                            if request_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("requestId"));
+                            }
+                            request_id__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::OperationToken => {
+                            if operation_token__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("operationToken"));
+                            }
+                            operation_token__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::StateTransitionCount => {
+                            if state_transition_count__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("stateTransitionCount"));
+                            }
+                            state_transition_count__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::SearchAttributes => {
+                            if search_attributes__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("searchAttributes"));
+                            }
+                            search_attributes__ = map_.next_value()?;
+                        }
+                        GeneratedField::NexusHeader => {
+                            if nexus_header__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("nexusHeader"));
+                            }
+                            nexus_header__ = Some(
+                                map_.next_value::<std::collections::HashMap<_, _>>()?
+                            );
+                        }
+                        GeneratedField::UserMetadata => {
+                            if user_metadata__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("userMetadata"));
+                            }
+                            user_metadata__ = map_.next_value()?;
+                        }
+                        GeneratedField::Links => {
+                            if links__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("links"));
+                            }
+                            links__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::Identity => {
+                            if identity__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("identity"));
+                            }
+                            identity__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::StateSizeBytes => {
+                            if state_size_bytes__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("stateSizeBytes"));
+                            }
+                            state_size_bytes__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                    }
+                }
+                Ok(NexusOperationExecutionInfo {
+                    operation_id: operation_id__.unwrap_or_default(),
+                    run_id: run_id__.unwrap_or_default(),
+                    endpoint: endpoint__.unwrap_or_default(),
+                    service: service__.unwrap_or_default(),
+                    operation: operation__.unwrap_or_default(),
+                    status: status__.unwrap_or_default(),
+                    state: state__.unwrap_or_default(),
+                    schedule_to_close_timeout: schedule_to_close_timeout__,
+                    schedule_to_start_timeout: schedule_to_start_timeout__,
+                    start_to_close_timeout: start_to_close_timeout__,
+                    attempt: attempt__.unwrap_or_default(),
+                    schedule_time: schedule_time__,
+                    expiration_time: expiration_time__,
+                    close_time: close_time__,
+                    last_attempt_complete_time: last_attempt_complete_time__,
+                    last_attempt_failure: last_attempt_failure__,
+                    next_attempt_schedule_time: next_attempt_schedule_time__,
+                    execution_duration: execution_duration__,
+                    cancellation_info: cancellation_info__,
+                    blocked_reason: blocked_reason__.unwrap_or_default(),
+                    request_id: request_id__.unwrap_or_default(),
+                    operation_token: operation_token__.unwrap_or_default(),
+                    state_transition_count: state_transition_count__.unwrap_or_default(),
+                    search_attributes: search_attributes__,
+                    nexus_header: nexus_header__.unwrap_or_default(),
+                    user_metadata: user_metadata__,
+                    links: links__.unwrap_or_default(),
+                    identity: identity__.unwrap_or_default(),
+                    state_size_bytes: state_size_bytes__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("temporal.api.nexus.v1.NexusOperationExecutionInfo", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for NexusOperationExecutionListInfo {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.operation_id.is_empty() {
+            len += 1;
+        }
+        if !self.run_id.is_empty() {
+            len += 1;
+        }
+        if !self.endpoint.is_empty() {
+            len += 1;
+        }
+        if !self.service.is_empty() {
+            len += 1;
+        }
+        if !self.operation.is_empty() {
+            len += 1;
+        }
+        if self.schedule_time.is_some() {
+            len += 1;
+        }
+        if self.close_time.is_some() {
+            len += 1;
+        }
+        if self.status != 0 {
+            len += 1;
+        }
+        if self.search_attributes.is_some() {
+            len += 1;
+        }
+        if self.state_transition_count != 0 {
+            len += 1;
+        }
+        if self.execution_duration.is_some() {
+            len += 1;
+        }
+        if self.state_size_bytes != 0 {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("temporal.api.nexus.v1.NexusOperationExecutionListInfo", len)?;
+        if !self.operation_id.is_empty() {
+            struct_ser.serialize_field("operationId", &self.operation_id)?;
+        }
+        if !self.run_id.is_empty() {
+            struct_ser.serialize_field("runId", &self.run_id)?;
+        }
+        if !self.endpoint.is_empty() {
+            struct_ser.serialize_field("endpoint", &self.endpoint)?;
+        }
+        if !self.service.is_empty() {
+            struct_ser.serialize_field("service", &self.service)?;
+        }
+        if !self.operation.is_empty() {
+            struct_ser.serialize_field("operation", &self.operation)?;
+        }
+        if let Some(v) = self.schedule_time.as_ref() {
+            struct_ser.serialize_field("scheduleTime", v)?;
+        }
+        if let Some(v) = self.close_time.as_ref() {
+            struct_ser.serialize_field("closeTime", v)?;
+        }
+        if self.status != 0 {
+            let v = super::super::enums::v1::NexusOperationExecutionStatus::try_from(self.status)
+                .map_err(|_| serde::ser::Error::custom(format!("Invalid variant {}", self.status)))?;
+            struct_ser.serialize_field("status", &v)?;
+        }
+        if let Some(v) = self.search_attributes.as_ref() {
+            struct_ser.serialize_field("searchAttributes", v)?;
+        }
+        if self.state_transition_count != 0 {
+            #[allow(clippy::needless_borrow)]
+            #[allow(clippy::needless_borrows_for_generic_args)]
+            struct_ser.serialize_field("stateTransitionCount", ToString::to_string(&self.state_transition_count).as_str())?;
+        }
+        if let Some(v) = self.execution_duration.as_ref() {
+            struct_ser.serialize_field("executionDuration", v)?;
+        }
+        if self.state_size_bytes != 0 {
+            #[allow(clippy::needless_borrow)]
+            #[allow(clippy::needless_borrows_for_generic_args)]
+            struct_ser.serialize_field("stateSizeBytes", ToString::to_string(&self.state_size_bytes).as_str())?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for NexusOperationExecutionListInfo {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "operation_id",
+            "operationId",
+            "run_id",
+            "runId",
+            "endpoint",
+            "service",
+            "operation",
+            "schedule_time",
+            "scheduleTime",
+            "close_time",
+            "closeTime",
+            "status",
+            "search_attributes",
+            "searchAttributes",
+            "state_transition_count",
+            "stateTransitionCount",
+            "execution_duration",
+            "executionDuration",
+            "state_size_bytes",
+            "stateSizeBytes",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            OperationId,
+            RunId,
+            Endpoint,
+            Service,
+            Operation,
+            ScheduleTime,
+            CloseTime,
+            Status,
+            SearchAttributes,
+            StateTransitionCount,
+            ExecutionDuration,
+            StateSizeBytes,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "operationId" | "operation_id" => Ok(GeneratedField::OperationId),
+                            "runId" | "run_id" => Ok(GeneratedField::RunId),
+                            "endpoint" => Ok(GeneratedField::Endpoint),
+                            "service" => Ok(GeneratedField::Service),
+                            "operation" => Ok(GeneratedField::Operation),
+                            "scheduleTime" | "schedule_time" => Ok(GeneratedField::ScheduleTime),
+                            "closeTime" | "close_time" => Ok(GeneratedField::CloseTime),
+                            "status" => Ok(GeneratedField::Status),
+                            "searchAttributes" | "search_attributes" => Ok(GeneratedField::SearchAttributes),
+                            "stateTransitionCount" | "state_transition_count" => Ok(GeneratedField::StateTransitionCount),
+                            "executionDuration" | "execution_duration" => Ok(GeneratedField::ExecutionDuration),
+                            "stateSizeBytes" | "state_size_bytes" => Ok(GeneratedField::StateSizeBytes),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = NexusOperationExecutionListInfo;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct temporal.api.nexus.v1.NexusOperationExecutionListInfo")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<NexusOperationExecutionListInfo, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut operation_id__ = None;
+                let mut run_id__ = None;
+                let mut endpoint__ = None;
+                let mut service__ = None;
+                let mut operation__ = None;
+                let mut schedule_time__ = None;
+                let mut close_time__ = None;
+                let mut status__ = None;
+                let mut search_attributes__ = None;
+                let mut state_transition_count__ = None;
+                let mut execution_duration__ = None;
+                let mut state_size_bytes__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::OperationId => {
+                            if operation_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("operationId"));
+                            }
+                            operation_id__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::RunId => {
+                            if run_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("runId"));
+                            }
+                            run_id__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::Endpoint => {
+                            if endpoint__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("endpoint"));
+                            }
+                            endpoint__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::Service => {
+                            if service__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("service"));
+                            }
+                            service__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::Operation => {
+                            if operation__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("operation"));
+                            }
+                            operation__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::ScheduleTime => {
+                            if schedule_time__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("scheduleTime"));
+                            }
+                            schedule_time__ = map_.next_value()?;
+                        }
+                        GeneratedField::CloseTime => {
+                            if close_time__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("closeTime"));
+                            }
+                            close_time__ = map_.next_value()?;
+                        }
+                        GeneratedField::Status => {
+                            if status__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("status"));
+                            }
+                            status__ = Some(map_.next_value::<super::super::enums::v1::NexusOperationExecutionStatus>()? as i32);
+                        }
+                        GeneratedField::SearchAttributes => {
+                            if search_attributes__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("searchAttributes"));
+                            }
+                            search_attributes__ = map_.next_value()?;
+                        }
+                        GeneratedField::StateTransitionCount => {
+                            if state_transition_count__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("stateTransitionCount"));
+                            }
+                            state_transition_count__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::ExecutionDuration => {
+                            if execution_duration__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("executionDuration"));
+                            }
+                            execution_duration__ = map_.next_value()?;
+                        }
+                        GeneratedField::StateSizeBytes => {
+                            if state_size_bytes__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("stateSizeBytes"));
+                            }
+                            state_size_bytes__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                    }
+                }
+                Ok(NexusOperationExecutionListInfo {
+                    operation_id: operation_id__.unwrap_or_default(),
+                    run_id: run_id__.unwrap_or_default(),
+                    endpoint: endpoint__.unwrap_or_default(),
+                    service: service__.unwrap_or_default(),
+                    operation: operation__.unwrap_or_default(),
+                    schedule_time: schedule_time__,
+                    close_time: close_time__,
+                    status: status__.unwrap_or_default(),
+                    search_attributes: search_attributes__,
+                    state_transition_count: state_transition_count__.unwrap_or_default(),
+                    execution_duration: execution_duration__,
+                    state_size_bytes: state_size_bytes__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("temporal.api.nexus.v1.NexusOperationExecutionListInfo", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for Request {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.header.is_empty() {
+            len += 1;
+        }
+        if self.scheduled_time.is_some() {
+            len += 1;
+        }
+        if self.capabilities.is_some() {
+            len += 1;
+        }
+        if !self.endpoint.is_empty() {
+            len += 1;
+        }
+        if self.variant.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("temporal.api.nexus.v1.Request", len)?;
+        if !self.header.is_empty() {
+            struct_ser.serialize_field("header", &self.header)?;
+        }
+        if let Some(v) = self.scheduled_time.as_ref() {
+            struct_ser.serialize_field("scheduledTime", v)?;
+        }
+        if let Some(v) = self.capabilities.as_ref() {
+            struct_ser.serialize_field("capabilities", v)?;
+        }
+        if !self.endpoint.is_empty() {
+            struct_ser.serialize_field("endpoint", &self.endpoint)?;
+        }
+        if let Some(v) = self.variant.as_ref() {
+            match v {
+                request::Variant::StartOperation(v) => {
+                    struct_ser.serialize_field("startOperation", v)?;
+                }
+                request::Variant::CancelOperation(v) => {
+                    struct_ser.serialize_field("cancelOperation", v)?;
+                }
+            }
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for Request {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "header",
+            "scheduled_time",
+            "scheduledTime",
+            "capabilities",
+            "endpoint",
+            "start_operation",
+            "startOperation",
+            "cancel_operation",
+            "cancelOperation",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Header,
+            ScheduledTime,
+            Capabilities,
+            Endpoint,
+            StartOperation,
+            CancelOperation,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "header" => Ok(GeneratedField::Header),
+                            "scheduledTime" | "scheduled_time" => Ok(GeneratedField::ScheduledTime),
+                            "capabilities" => Ok(GeneratedField::Capabilities),
+                            "endpoint" => Ok(GeneratedField::Endpoint),
+                            "startOperation" | "start_operation" => Ok(GeneratedField::StartOperation),
+                            "cancelOperation" | "cancel_operation" => Ok(GeneratedField::CancelOperation),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = Request;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct temporal.api.nexus.v1.Request")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<Request, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut header__ = None;
+                let mut scheduled_time__ = None;
+                let mut capabilities__ = None;
+                let mut endpoint__ = None;
+                let mut variant__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Header => {
+                            if header__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("header"));
+                            }
+                            header__ = Some(
+                                map_.next_value::<std::collections::HashMap<_, _>>()?
+                            );
+                        }
+                        GeneratedField::ScheduledTime => {
+                            if scheduled_time__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("scheduledTime"));
+                            }
+                            scheduled_time__ = map_.next_value()?;
+                        }
+                        GeneratedField::Capabilities => {
+                            if capabilities__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("capabilities"));
+                            }
+                            capabilities__ = map_.next_value()?;
+                        }
+                        GeneratedField::Endpoint => {
+                            if endpoint__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("endpoint"));
+                            }
+                            endpoint__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::StartOperation => {
+                            if variant__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("startOperation"));
+                            }
+                            variant__ = map_.next_value::<::std::option::Option<_>>()?.map(request::Variant::StartOperation)
+;
+                        }
+                        GeneratedField::CancelOperation => {
+                            if variant__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("cancelOperation"));
+                            }
+                            variant__ = map_.next_value::<::std::option::Option<_>>()?.map(request::Variant::CancelOperation)
+;
+                        }
+                    }
+                }
+                Ok(Request {
+                    header: header__.unwrap_or_default(),
+                    scheduled_time: scheduled_time__,
+                    capabilities: capabilities__,
+                    endpoint: endpoint__.unwrap_or_default(),
+                    variant: variant__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("temporal.api.nexus.v1.Request", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for request::Capabilities {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.temporal_failure_responses {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("temporal.api.nexus.v1.Request.Capabilities", len)?;
+        if self.temporal_failure_responses {
+            struct_ser.serialize_field("temporalFailureResponses", &self.temporal_failure_responses)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for request::Capabilities {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "temporal_failure_responses",
+            "temporalFailureResponses",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            TemporalFailureResponses,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "temporalFailureResponses" | "temporal_failure_responses" => Ok(GeneratedField::TemporalFailureResponses),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = request::Capabilities;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct temporal.api.nexus.v1.Request.Capabilities")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<request::Capabilities, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut temporal_failure_responses__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::TemporalFailureResponses => {
+                            if temporal_failure_responses__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("temporalFailureResponses"));
+                            }
+                            temporal_failure_responses__ = Some(map_.next_value()?);
+                        }
+                    }
+                }
+                Ok(request::Capabilities {
+                    temporal_failure_responses: temporal_failure_responses__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("temporal.api.nexus.v1.Request.Capabilities", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for Response {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.variant.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("temporal.api.nexus.v1.Response", len)?;
+        if let Some(v) = self.variant.as_ref() {
+            match v {
+                response::Variant::StartOperation(v) => {
+                    struct_ser.serialize_field("startOperation", v)?;
+                }
+                response::Variant::CancelOperation(v) => {
+                    struct_ser.serialize_field("cancelOperation", v)?;
+                }
+            }
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for Response {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "start_operation",
+            "startOperation",
+            "cancel_operation",
+            "cancelOperation",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            StartOperation,
+            CancelOperation,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "startOperation" | "start_operation" => Ok(GeneratedField::StartOperation),
+                            "cancelOperation" | "cancel_operation" => Ok(GeneratedField::CancelOperation),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = Response;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct temporal.api.nexus.v1.Response")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<Response, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut variant__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::StartOperation => {
+                            if variant__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("startOperation"));
+                            }
+                            variant__ = map_.next_value::<::std::option::Option<_>>()?.map(response::Variant::StartOperation)
+;
+                        }
+                        GeneratedField::CancelOperation => {
+                            if variant__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("cancelOperation"));
+                            }
+                            variant__ = map_.next_value::<::std::option::Option<_>>()?.map(response::Variant::CancelOperation)
+;
+                        }
+                    }
+                }
+                Ok(Response {
+                    variant: variant__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("temporal.api.nexus.v1.Response", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for StartOperationRequest {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.service.is_empty() {
+            len += 1;
+        }
+        if !self.operation.is_empty() {
+            len += 1;
+        }
+        if !self.request_id.is_empty() {
+            len += 1;
+        }
+        if !self.callback.is_empty() {
+            len += 1;
+        }
+        if self.payload.is_some() {
+            len += 1;
+        }
+        if !self.callback_header.is_empty() {
+            len += 1;
+        }
+        if !self.links.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("temporal.api.nexus.v1.StartOperationRequest", len)?;
+        if !self.service.is_empty() {
+            struct_ser.serialize_field("service", &self.service)?;
+        }
+        if !self.operation.is_empty() {
+            struct_ser.serialize_field("operation", &self.operation)?;
+        }
+        if !self.request_id.is_empty() {
+            struct_ser.serialize_field("requestId", &self.request_id)?;
+        }
+        if !self.callback.is_empty() {
+            struct_ser.serialize_field("callback", &self.callback)?;
+        }
+        if let Some(v) = self.payload.as_ref() {
+            struct_ser.serialize_field("payload", v)?;
+        }
+        if !self.callback_header.is_empty() {
+            struct_ser.serialize_field("callbackHeader", &self.callback_header)?;
+        }
+        if !self.links.is_empty() {
+            struct_ser.serialize_field("links", &self.links)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for StartOperationRequest {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "service",
+            "operation",
+            "request_id",
+            "requestId",
+            "callback",
+            "payload",
+            "callback_header",
+            "callbackHeader",
+            "links",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Service,
+            Operation,
+            RequestId,
+            Callback,
+            Payload,
+            CallbackHeader,
+            Links,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "service" => Ok(GeneratedField::Service),
+                            "operation" => Ok(GeneratedField::Operation),
+                            "requestId" | "request_id" => Ok(GeneratedField::RequestId),
+                            "callback" => Ok(GeneratedField::Callback),
+                            "payload" => Ok(GeneratedField::Payload),
+                            "callbackHeader" | "callback_header" => Ok(GeneratedField::CallbackHeader),
+                            "links" => Ok(GeneratedField::Links),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = StartOperationRequest;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct temporal.api.nexus.v1.StartOperationRequest")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<StartOperationRequest, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut service__ = None;
+                let mut operation__ = None;
+                let mut request_id__ = None;
+                let mut callback__ = None;
+                let mut payload__ = None;
+                let mut callback_header__ = None;
+                let mut links__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Service => {
+                            if service__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("service"));
+                            }
+                            service__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::Operation => {
+                            if operation__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("operation"));
+                            }
+                            operation__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::RequestId => {
+                            if request_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("requestId"));
+                            }
+                            request_id__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::Callback => {
+                            if callback__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("callback"));
+                            }
+                            callback__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::Payload => {
+                            if payload__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("payload"));
+                            }
+                            payload__ = map_.next_value()?;
+                        }
+                        GeneratedField::CallbackHeader => {
+                            if callback_header__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("callbackHeader"));
+                            }
+                            callback_header__ = Some(
+                                map_.next_value::<std::collections::HashMap<_, _>>()?
+                            );
+                        }
+                        GeneratedField::Links => {
+                            if links__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("links"));
+                            }
+                            links__ = Some(map_.next_value()?);
+                        }
+                    }
+                }
+                Ok(StartOperationRequest {
+                    service: service__.unwrap_or_default(),
+                    operation: operation__.unwrap_or_default(),
+                    request_id: request_id__.unwrap_or_default(),
+                    callback: callback__.unwrap_or_default(),
+                    payload: payload__,
+                    callback_header: callback_header__.unwrap_or_default(),
+                    links: links__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("temporal.api.nexus.v1.StartOperationRequest", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for StartOperationResponse {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.variant.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("temporal.api.nexus.v1.StartOperationResponse", len)?;
+        if let Some(v) = self.variant.as_ref() {
+            match v {
+                start_operation_response::Variant::SyncSuccess(v) => {
+                    struct_ser.serialize_field("syncSuccess", v)?;
+                }
+                start_operation_response::Variant::AsyncSuccess(v) => {
+                    struct_ser.serialize_field("asyncSuccess", v)?;
+                }
+                start_operation_response::Variant::OperationError(v) => {
+                    struct_ser.serialize_field("operationError", v)?;
+                }
+                start_operation_response::Variant::Failure(v) => {
+                    struct_ser.serialize_field("failure", v)?;
+                }
+            }
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for StartOperationResponse {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "sync_success",
+            "syncSuccess",
+            "async_success",
+            "asyncSuccess",
+            "operation_error",
+            "operationError",
+            "failure",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            SyncSuccess,
+            AsyncSuccess,
+            OperationError,
+            Failure,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "syncSuccess" | "sync_success" => Ok(GeneratedField::SyncSuccess),
+                            "asyncSuccess" | "async_success" => Ok(GeneratedField::AsyncSuccess),
+                            "operationError" | "operation_error" => Ok(GeneratedField::OperationError),
+                            "failure" => Ok(GeneratedField::Failure),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = StartOperationResponse;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct temporal.api.nexus.v1.StartOperationResponse")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<StartOperationResponse, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut variant__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::SyncSuccess => {
+                            if variant__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("syncSuccess"));
+                            }
+                            variant__ = map_.next_value::<::std::option::Option<_>>()?.map(start_operation_response::Variant::SyncSuccess)
+;
+                        }
+                        GeneratedField::AsyncSuccess => {
+                            if variant__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("asyncSuccess"));
+                            }
+                            variant__ = map_.next_value::<::std::option::Option<_>>()?.map(start_operation_response::Variant::AsyncSuccess)
+;
+                        }
+                        GeneratedField::OperationError => {
+                            if variant__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("operationError"));
+                            }
+                            variant__ = map_.next_value::<::std::option::Option<_>>()?.map(start_operation_response::Variant::OperationError)
+;
+                        }
+                        GeneratedField::Failure => {
+                            if variant__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("failure"));
+                            }
+                            variant__ = map_.next_value::<::std::option::Option<_>>()?.map(start_operation_response::Variant::Failure)
+;
+                        }
+                    }
+                }
+                Ok(StartOperationResponse {
+                    variant: variant__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("temporal.api.nexus.v1.StartOperationResponse", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for start_operation_response::Async {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.operation_id.is_empty() {
+            len += 1;
+        }
+        if !self.links.is_empty() {
+            len += 1;
+        }
+        if !self.operation_token.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("temporal.api.nexus.v1.StartOperationResponse.Async", len)?;
+        if !self.operation_id.is_empty() {
+            struct_ser.serialize_field("operationId", &self.operation_id)?;
+        }
+        if !self.links.is_empty() {
+            struct_ser.serialize_field("links", &self.links)?;
+        }
+        if !self.operation_token.is_empty() {
+            struct_ser.serialize_field("operationToken", &self.operation_token)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for start_operation_response::Async {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "operation_id",
+            "operationId",
+            "links",
+            "operation_token",
+            "operationToken",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            OperationId,
+            Links,
+            OperationToken,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "operationId" | "operation_id" => Ok(GeneratedField::OperationId),
+                            "links" => Ok(GeneratedField::Links),
+                            "operationToken" | "operation_token" => Ok(GeneratedField::OperationToken),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = start_operation_response::Async;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct temporal.api.nexus.v1.StartOperationResponse.Async")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<start_operation_response::Async, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut operation_id__ = None;
+                let mut links__ = None;
+                let mut operation_token__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::OperationId => {
+                            if operation_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("operationId"));
+                            }
+                            operation_id__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::Links => {
+                            if links__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("links"));
+                            }
+                            links__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::OperationToken => {
+                            if operation_token__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("operationToken"));
+                            }
+                            operation_token__ = Some(map_.next_value()?);
+                        }
+                    }
+                }
+                Ok(start_operation_response::Async {
+                    operation_id: operation_id__.unwrap_or_default(),
+                    links: links__.unwrap_or_default(),
+                    operation_token: operation_token__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("temporal.api.nexus.v1.StartOperationResponse.Async", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for start_operation_response::Sync {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.payload.is_some() {
+            len += 1;
+        }
+        if !self.links.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("temporal.api.nexus.v1.StartOperationResponse.Sync", len)?;
+        if let Some(v) = self.payload.as_ref() {
+            struct_ser.serialize_field("payload", v)?;
+        }
+        if !self.links.is_empty() {
+            struct_ser.serialize_field("links", &self.links)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for start_operation_response::Sync {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "payload",
+            "links",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Payload,
+            Links,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "payload" => Ok(GeneratedField::Payload),
+                            "links" => Ok(GeneratedField::Links),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = start_operation_response::Sync;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct temporal.api.nexus.v1.StartOperationResponse.Sync")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<start_operation_response::Sync, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut payload__ = None;
+                let mut links__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Payload => {
+                            if payload__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("payload"));
+                            }
+                            payload__ = map_.next_value()?;
+                        }
+                        GeneratedField::Links => {
+                            if links__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("links"));
+                            }
+                            links__ = Some(map_.next_value()?);
+                        }
+                    }
+                }
+                Ok(start_operation_response::Sync {
+                    payload: payload__,
+                    links: links__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("temporal.api.nexus.v1.StartOperationResponse.Sync", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for UnsuccessfulOperationError {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.operation_state.is_empty() {
+            len += 1;
+        }
+        if self.failure.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("temporal.api.nexus.v1.UnsuccessfulOperationError", len)?;
+        if !self.operation_state.is_empty() {
+            struct_ser.serialize_field("operationState", &self.operation_state)?;
+        }
+        if let Some(v) = self.failure.as_ref() {
+            struct_ser.serialize_field("failure", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for UnsuccessfulOperationError {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "operation_state",
+            "operationState",
+            "failure",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            OperationState,
+            Failure,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "operationState" | "operation_state" => Ok(GeneratedField::OperationState),
+                            "failure" => Ok(GeneratedField::Failure),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = UnsuccessfulOperationError;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct temporal.api.nexus.v1.UnsuccessfulOperationError")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<UnsuccessfulOperationError, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut operation_state__ = None;
+                let mut failure__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::OperationState => {
+                            if operation_state__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("operationState"));
+                            }
+                            operation_state__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::Failure => {
+                            if failure__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("failure"));
+                            }
+                            failure__ = map_.next_value()?;
+                        }
+                    }
+                }
+                Ok(UnsuccessfulOperationError {
+                    operation_state: operation_state__.unwrap_or_default(),
+                    failure: failure__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("temporal.api.nexus.v1.UnsuccessfulOperationError", FIELDS, GeneratedVisitor)
+    }
+}
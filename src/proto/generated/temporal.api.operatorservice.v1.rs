@@ -0,0 +1,690 @@
+// This file is @generated by prost-build.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct AddSearchAttributesRequest {
+    /// Mapping between search attribute name and its IndexedValueType.
+    #[prost(
+        map = "string, enumeration(super::super::enums::v1::IndexedValueType)",
+        tag = "1"
+    )]
+    pub search_attributes: ::std::collections::HashMap<
+        ::prost::alloc::string::String,
+        i32,
+    >,
+    #[prost(string, tag = "2")]
+    pub namespace: ::prost::alloc::string::String,
+}
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct AddSearchAttributesResponse {}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct RemoveSearchAttributesRequest {
+    /// Search attribute names to delete.
+    #[prost(string, repeated, tag = "1")]
+    pub search_attributes: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+    #[prost(string, tag = "2")]
+    pub namespace: ::prost::alloc::string::String,
+}
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct RemoveSearchAttributesResponse {}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ListSearchAttributesRequest {
+    #[prost(string, tag = "1")]
+    pub namespace: ::prost::alloc::string::String,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ListSearchAttributesResponse {
+    /// Mapping between custom (user-registered) search attribute name to its IndexedValueType.
+    #[prost(
+        map = "string, enumeration(super::super::enums::v1::IndexedValueType)",
+        tag = "1"
+    )]
+    pub custom_attributes: ::std::collections::HashMap<
+        ::prost::alloc::string::String,
+        i32,
+    >,
+    /// Mapping between system (predefined) search attribute name to its IndexedValueType.
+    #[prost(
+        map = "string, enumeration(super::super::enums::v1::IndexedValueType)",
+        tag = "2"
+    )]
+    pub system_attributes: ::std::collections::HashMap<
+        ::prost::alloc::string::String,
+        i32,
+    >,
+    /// Mapping from the attribute name to the visibility storage native type.
+    #[prost(map = "string, string", tag = "3")]
+    pub storage_schema: ::std::collections::HashMap<
+        ::prost::alloc::string::String,
+        ::prost::alloc::string::String,
+    >,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct DeleteNamespaceRequest {
+    /// Only one of namespace or namespace_id must be specified to identify namespace.
+    #[prost(string, tag = "1")]
+    pub namespace: ::prost::alloc::string::String,
+    #[prost(string, tag = "2")]
+    pub namespace_id: ::prost::alloc::string::String,
+    /// If provided, the deletion of namespace info will be delayed for the given duration (0 means no delay).
+    /// If not provided, the default delay configured in the cluster will be used.
+    #[prost(message, optional, tag = "3")]
+    pub namespace_delete_delay: ::core::option::Option<::pbjson_types::Duration>,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct DeleteNamespaceResponse {
+    /// Temporary namespace name that is used during reclaim resources step.
+    #[prost(string, tag = "1")]
+    pub deleted_namespace: ::prost::alloc::string::String,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct AddOrUpdateRemoteClusterRequest {
+    /// Frontend Address is a cross cluster accessible address for gRPC traffic. This field is required.
+    #[prost(string, tag = "1")]
+    pub frontend_address: ::prost::alloc::string::String,
+    /// Flag to enable / disable the cross cluster connection.
+    #[prost(bool, tag = "2")]
+    pub enable_remote_cluster_connection: bool,
+    /// Frontend HTTP Address is a cross cluster accessible address for HTTP traffic. This field is optional. If not provided
+    ///   on update, the existing HTTP address will be removed.
+    #[prost(string, tag = "3")]
+    pub frontend_http_address: ::prost::alloc::string::String,
+    /// Controls whether replication streams are active.
+    #[prost(bool, tag = "4")]
+    pub enable_replication: bool,
+}
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct AddOrUpdateRemoteClusterResponse {}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct RemoveRemoteClusterRequest {
+    /// Remote cluster name to be removed.
+    #[prost(string, tag = "1")]
+    pub cluster_name: ::prost::alloc::string::String,
+}
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct RemoveRemoteClusterResponse {}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ListClustersRequest {
+    #[prost(int32, tag = "1")]
+    pub page_size: i32,
+    #[prost(bytes = "vec", tag = "2")]
+    pub next_page_token: ::prost::alloc::vec::Vec<u8>,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ListClustersResponse {
+    /// List of all cluster information
+    #[prost(message, repeated, tag = "1")]
+    pub clusters: ::prost::alloc::vec::Vec<ClusterMetadata>,
+    #[prost(bytes = "vec", tag = "4")]
+    pub next_page_token: ::prost::alloc::vec::Vec<u8>,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ClusterMetadata {
+    /// Name of the cluster name.
+    #[prost(string, tag = "1")]
+    pub cluster_name: ::prost::alloc::string::String,
+    /// Id of the cluster.
+    #[prost(string, tag = "2")]
+    pub cluster_id: ::prost::alloc::string::String,
+    /// gRPC address.
+    #[prost(string, tag = "3")]
+    pub address: ::prost::alloc::string::String,
+    /// HTTP address, if one exists.
+    #[prost(string, tag = "7")]
+    pub http_address: ::prost::alloc::string::String,
+    /// A unique failover version across all connected clusters.
+    #[prost(int64, tag = "4")]
+    pub initial_failover_version: i64,
+    /// History service shard number.
+    #[prost(int32, tag = "5")]
+    pub history_shard_count: i32,
+    /// A flag to indicate if a connection is active.
+    #[prost(bool, tag = "6")]
+    pub is_connection_enabled: bool,
+    /// A flag to indicate if replication is enabled.
+    #[prost(bool, tag = "8")]
+    pub is_replication_enabled: bool,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GetNexusEndpointRequest {
+    /// Server-generated unique endpoint ID.
+    #[prost(string, tag = "1")]
+    pub id: ::prost::alloc::string::String,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GetNexusEndpointResponse {
+    #[prost(message, optional, tag = "1")]
+    pub endpoint: ::core::option::Option<super::super::nexus::v1::Endpoint>,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct CreateNexusEndpointRequest {
+    /// Endpoint definition to create.
+    #[prost(message, optional, tag = "1")]
+    pub spec: ::core::option::Option<super::super::nexus::v1::EndpointSpec>,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct CreateNexusEndpointResponse {
+    /// Data post acceptance. Can be used to issue additional updates to this record.
+    #[prost(message, optional, tag = "1")]
+    pub endpoint: ::core::option::Option<super::super::nexus::v1::Endpoint>,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct UpdateNexusEndpointRequest {
+    /// Server-generated unique endpoint ID.
+    #[prost(string, tag = "1")]
+    pub id: ::prost::alloc::string::String,
+    /// Data version for this endpoint. Must match current version.
+    #[prost(int64, tag = "2")]
+    pub version: i64,
+    #[prost(message, optional, tag = "3")]
+    pub spec: ::core::option::Option<super::super::nexus::v1::EndpointSpec>,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct UpdateNexusEndpointResponse {
+    /// Data post acceptance. Can be used to issue additional updates to this record.
+    #[prost(message, optional, tag = "1")]
+    pub endpoint: ::core::option::Option<super::super::nexus::v1::Endpoint>,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct DeleteNexusEndpointRequest {
+    /// Server-generated unique endpoint ID.
+    #[prost(string, tag = "1")]
+    pub id: ::prost::alloc::string::String,
+    /// Data version for this endpoint. Must match current version.
+    #[prost(int64, tag = "2")]
+    pub version: i64,
+}
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct DeleteNexusEndpointResponse {}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ListNexusEndpointsRequest {
+    #[prost(int32, tag = "1")]
+    pub page_size: i32,
+    /// To get the next page, pass in `ListNexusEndpointsResponse.next_page_token` from the previous page's
+    /// response, the token will be empty if there's no other page.
+    /// Note: the last page may be empty if the total number of endpoints registered is a multiple of the page size.
+    #[prost(bytes = "vec", tag = "2")]
+    pub next_page_token: ::prost::alloc::vec::Vec<u8>,
+    /// Name of the incoming endpoint to filter on - optional. Specifying this will result in zero or one results.
+    /// (-- api-linter: core::203::field-behavior-required=disabled
+    ///      aip.dev/not-precedent: Not following linter rules. --)
+    #[prost(string, tag = "3")]
+    pub name: ::prost::alloc::string::String,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ListNexusEndpointsResponse {
+    /// Token for getting the next page.
+    #[prost(bytes = "vec", tag = "1")]
+    pub next_page_token: ::prost::alloc::vec::Vec<u8>,
+    #[prost(message, repeated, tag = "2")]
+    pub endpoints: ::prost::alloc::vec::Vec<super::super::nexus::v1::Endpoint>,
+}
+/// Generated client implementations.
+pub mod operator_service_client {
+    #![allow(
+        unused_variables,
+        dead_code,
+        missing_docs,
+        clippy::wildcard_imports,
+        clippy::let_unit_value,
+    )]
+    use tonic::codegen::*;
+    use tonic::codegen::http::Uri;
+    /// OperatorService API defines how Temporal SDKs and other clients interact with the Temporal server
+    /// to perform administrative functions like registering a search attribute or a namespace.
+    /// APIs in this file could be not compatible with Temporal Cloud, hence it's usage in SDKs should be limited by
+    /// designated APIs that clearly state that they shouldn't be used by the main Application (Workflows & Activities) framework.
+    #[derive(Debug, Clone)]
+    pub struct OperatorServiceClient<T> {
+        inner: tonic::client::Grpc<T>,
+    }
+    impl OperatorServiceClient<tonic::transport::Channel> {
+        /// Attempt to create a new client by connecting to a given endpoint.
+        pub async fn connect<D>(dst: D) -> Result<Self, tonic::transport::Error>
+        where
+            D: TryInto<tonic::transport::Endpoint>,
+            D::Error: Into<StdError>,
+        {
+            let conn = tonic::transport::Endpoint::new(dst)?.connect().await?;
+            Ok(Self::new(conn))
+        }
+    }
+    impl<T> OperatorServiceClient<T>
+    where
+        T: tonic::client::GrpcService<tonic::body::BoxBody>,
+        T::Error: Into<StdError>,
+        T::ResponseBody: Body<Data = Bytes> + std::marker::Send + 'static,
+        <T::ResponseBody as Body>::Error: Into<StdError> + std::marker::Send,
+    {
+        pub fn new(inner: T) -> Self {
+            let inner = tonic::client::Grpc::new(inner);
+            Self { inner }
+        }
+        pub fn with_origin(inner: T, origin: Uri) -> Self {
+            let inner = tonic::client::Grpc::with_origin(inner, origin);
+            Self { inner }
+        }
+        pub fn with_interceptor<F>(
+            inner: T,
+            interceptor: F,
+        ) -> OperatorServiceClient<InterceptedService<T, F>>
+        where
+            F: tonic::service::Interceptor,
+            T::ResponseBody: Default,
+            T: tonic::codegen::Service<
+                http::Request<tonic::body::BoxBody>,
+                Response = http::Response<
+                    <T as tonic::client::GrpcService<tonic::body::BoxBody>>::ResponseBody,
+                >,
+            >,
+            <T as tonic::codegen::Service<
+                http::Request<tonic::body::BoxBody>,
+            >>::Error: Into<StdError> + std::marker::Send + std::marker::Sync,
+        {
+            OperatorServiceClient::new(InterceptedService::new(inner, interceptor))
+        }
+        /// Compress requests with the given encoding.
+        ///
+        /// This requires the server to support it otherwise it might respond with an
+        /// error.
+        #[must_use]
+        pub fn send_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.inner = self.inner.send_compressed(encoding);
+            self
+        }
+        /// Enable decompressing responses.
+        #[must_use]
+        pub fn accept_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.inner = self.inner.accept_compressed(encoding);
+            self
+        }
+        /// Limits the maximum size of a decoded message.
+        ///
+        /// Default: `4MB`
+        #[must_use]
+        pub fn max_decoding_message_size(mut self, limit: usize) -> Self {
+            self.inner = self.inner.max_decoding_message_size(limit);
+            self
+        }
+        /// Limits the maximum size of an encoded message.
+        ///
+        /// Default: `usize::MAX`
+        #[must_use]
+        pub fn max_encoding_message_size(mut self, limit: usize) -> Self {
+            self.inner = self.inner.max_encoding_message_size(limit);
+            self
+        }
+        /// AddSearchAttributes add custom search attributes.
+        ///
+        /// Returns ALREADY_EXISTS status code if a Search Attribute with any of the specified names already exists
+        /// Returns INTERNAL status code with temporal.api.errordetails.v1.SystemWorkflowFailure in Error Details if registration process fails,
+        pub async fn add_search_attributes(
+            &mut self,
+            request: impl tonic::IntoRequest<super::AddSearchAttributesRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::AddSearchAttributesResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/temporal.api.operatorservice.v1.OperatorService/AddSearchAttributes",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "temporal.api.operatorservice.v1.OperatorService",
+                        "AddSearchAttributes",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+        /// RemoveSearchAttributes removes custom search attributes.
+        ///
+        /// Returns NOT_FOUND status code if a Search Attribute with any of the specified names is not registered
+        pub async fn remove_search_attributes(
+            &mut self,
+            request: impl tonic::IntoRequest<super::RemoveSearchAttributesRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::RemoveSearchAttributesResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/temporal.api.operatorservice.v1.OperatorService/RemoveSearchAttributes",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "temporal.api.operatorservice.v1.OperatorService",
+                        "RemoveSearchAttributes",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+        /// ListSearchAttributes returns comprehensive information about search attributes.
+        pub async fn list_search_attributes(
+            &mut self,
+            request: impl tonic::IntoRequest<super::ListSearchAttributesRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ListSearchAttributesResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/temporal.api.operatorservice.v1.OperatorService/ListSearchAttributes",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "temporal.api.operatorservice.v1.OperatorService",
+                        "ListSearchAttributes",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+        /// DeleteNamespace synchronously deletes a namespace and asynchronously reclaims all namespace resources.
+        pub async fn delete_namespace(
+            &mut self,
+            request: impl tonic::IntoRequest<super::DeleteNamespaceRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::DeleteNamespaceResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/temporal.api.operatorservice.v1.OperatorService/DeleteNamespace",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "temporal.api.operatorservice.v1.OperatorService",
+                        "DeleteNamespace",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+        /// AddOrUpdateRemoteCluster adds or updates remote cluster.
+        pub async fn add_or_update_remote_cluster(
+            &mut self,
+            request: impl tonic::IntoRequest<super::AddOrUpdateRemoteClusterRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::AddOrUpdateRemoteClusterResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/temporal.api.operatorservice.v1.OperatorService/AddOrUpdateRemoteCluster",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "temporal.api.operatorservice.v1.OperatorService",
+                        "AddOrUpdateRemoteCluster",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+        /// RemoveRemoteCluster removes remote cluster.
+        pub async fn remove_remote_cluster(
+            &mut self,
+            request: impl tonic::IntoRequest<super::RemoveRemoteClusterRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::RemoveRemoteClusterResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/temporal.api.operatorservice.v1.OperatorService/RemoveRemoteCluster",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "temporal.api.operatorservice.v1.OperatorService",
+                        "RemoveRemoteCluster",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+        /// ListClusters returns information about Temporal clusters.
+        pub async fn list_clusters(
+            &mut self,
+            request: impl tonic::IntoRequest<super::ListClustersRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ListClustersResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/temporal.api.operatorservice.v1.OperatorService/ListClusters",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "temporal.api.operatorservice.v1.OperatorService",
+                        "ListClusters",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+        /// Get a registered Nexus endpoint by ID. The returned version can be used for optimistic updates.
+        pub async fn get_nexus_endpoint(
+            &mut self,
+            request: impl tonic::IntoRequest<super::GetNexusEndpointRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::GetNexusEndpointResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/temporal.api.operatorservice.v1.OperatorService/GetNexusEndpoint",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "temporal.api.operatorservice.v1.OperatorService",
+                        "GetNexusEndpoint",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+        /// Create a Nexus endpoint. This will fail if an endpoint with the same name is already registered with a status of
+        /// ALREADY_EXISTS.
+        /// Returns the created endpoint with its initial version. You may use this version for subsequent updates.
+        pub async fn create_nexus_endpoint(
+            &mut self,
+            request: impl tonic::IntoRequest<super::CreateNexusEndpointRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::CreateNexusEndpointResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/temporal.api.operatorservice.v1.OperatorService/CreateNexusEndpoint",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "temporal.api.operatorservice.v1.OperatorService",
+                        "CreateNexusEndpoint",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+        /// Optimistically update a Nexus endpoint based on provided version as obtained via the `GetNexusEndpoint` or
+        /// `ListNexusEndpointResponse` APIs. This will fail with a status of FAILED_PRECONDITION if the version does not
+        /// match.
+        /// Returns the updated endpoint with its updated version. You may use this version for subsequent updates. You don't
+        /// need to increment the version yourself. The server will increment the version for you after each update.
+        pub async fn update_nexus_endpoint(
+            &mut self,
+            request: impl tonic::IntoRequest<super::UpdateNexusEndpointRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::UpdateNexusEndpointResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/temporal.api.operatorservice.v1.OperatorService/UpdateNexusEndpoint",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "temporal.api.operatorservice.v1.OperatorService",
+                        "UpdateNexusEndpoint",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+        /// Delete an incoming Nexus service by ID.
+        pub async fn delete_nexus_endpoint(
+            &mut self,
+            request: impl tonic::IntoRequest<super::DeleteNexusEndpointRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::DeleteNexusEndpointResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/temporal.api.operatorservice.v1.OperatorService/DeleteNexusEndpoint",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "temporal.api.operatorservice.v1.OperatorService",
+                        "DeleteNexusEndpoint",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+        /// List all Nexus endpoints for the cluster, sorted by ID in ascending order. Set page_token in the request to the
+        /// next_page_token field of the previous response to get the next page of results. An empty next_page_token
+        /// indicates that there are no more results. During pagination, a newly added service with an ID lexicographically
+        /// earlier than the previous page's last endpoint's ID may be missed.
+        pub async fn list_nexus_endpoints(
+            &mut self,
+            request: impl tonic::IntoRequest<super::ListNexusEndpointsRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ListNexusEndpointsResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/temporal.api.operatorservice.v1.OperatorService/ListNexusEndpoints",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "temporal.api.operatorservice.v1.OperatorService",
+                        "ListNexusEndpoints",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+    }
+}
@@ -13,7 +13,7 @@ pub struct Message {
     /// The opaque data carried by this message. The protocol type can be
     /// extracted from the package name of the message carried inside the Any.
     #[prost(message, optional, tag = "5")]
-    pub body: ::core::option::Option<::prost_types::Any>,
+    pub body: ::core::option::Option<::pbjson_types::Any>,
     /// The event ID or command ID after which this message can be delivered. The
     /// effects of history up to and including this event ID should be visible to
     /// the code that handles this message. Omit to opt out of sequencing.
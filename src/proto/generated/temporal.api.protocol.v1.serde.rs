@@ -0,0 +1,165 @@
+impl serde::Serialize for Message {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.id.is_empty() {
+            len += 1;
+        }
+        if !self.protocol_instance_id.is_empty() {
+            len += 1;
+        }
+        if self.body.is_some() {
+            len += 1;
+        }
+        if self.sequencing_id.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("temporal.api.protocol.v1.Message", len)?;
+        if !self.id.is_empty() {
+            struct_ser.serialize_field("id", &self.id)?;
+        }
+        if !self.protocol_instance_id.is_empty() {
+            struct_ser.serialize_field("protocolInstanceId", &self.protocol_instance_id)?;
+        }
+        if let Some(v) = self.body.as_ref() {
+            struct_ser.serialize_field("body", v)?;
+        }
+        if let Some(v) = self.sequencing_id.as_ref() {
+            match v {
+                message::SequencingId::EventId(v) => {
+                    #[allow(clippy::needless_borrow)]
+                    #[allow(clippy::needless_borrows_for_generic_args)]
+                    struct_ser.serialize_field("eventId", ToString::to_string(&v).as_str())?;
+                }
+                message::SequencingId::CommandIndex(v) => {
+                    #[allow(clippy::needless_borrow)]
+                    #[allow(clippy::needless_borrows_for_generic_args)]
+                    struct_ser.serialize_field("commandIndex", ToString::to_string(&v).as_str())?;
+                }
+            }
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for Message {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "id",
+            "protocol_instance_id",
+            "protocolInstanceId",
+            "body",
+            "event_id",
+            "eventId",
+            "command_index",
+            "commandIndex",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Id,
+            ProtocolInstanceId,
+            Body,
+            EventId,
+            CommandIndex,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "id" => Ok(GeneratedField::Id),
+                            "protocolInstanceId" | "protocol_instance_id" => Ok(GeneratedField::ProtocolInstanceId),
+                            "body" => Ok(GeneratedField::Body),
+                            "eventId" | "event_id" => Ok(GeneratedField::EventId),
+                            "commandIndex" | "command_index" => Ok(GeneratedField::CommandIndex),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = Message;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct temporal.api.protocol.v1.Message")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<Message, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut id__ = None;
+                let mut protocol_instance_id__ = None;
+                let mut body__ = None;
+                let mut sequencing_id__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Id => {
+                            if id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("id"));
+                            }
+                            id__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::ProtocolInstanceId => {
+                            if protocol_instance_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("protocolInstanceId"));
+                            }
+                            protocol_instance_id__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::Body => {
+                            if body__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("body"));
+                            }
+                            body__ = map_.next_value()?;
+                        }
+                        GeneratedField::EventId => {
+                            if sequencing_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("eventId"));
+                            }
+                            sequencing_id__ = map_.next_value::<::std::option::Option<::pbjson::private::NumberDeserialize<_>>>()?.map(|x| message::SequencingId::EventId(x.0));
+                        }
+                        GeneratedField::CommandIndex => {
+                            if sequencing_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("commandIndex"));
+                            }
+                            sequencing_id__ = map_.next_value::<::std::option::Option<::pbjson::private::NumberDeserialize<_>>>()?.map(|x| message::SequencingId::CommandIndex(x.0));
+                        }
+                    }
+                }
+                Ok(Message {
+                    id: id__.unwrap_or_default(),
+                    protocol_instance_id: protocol_instance_id__.unwrap_or_default(),
+                    body: body__,
+                    sequencing_id: sequencing_id__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("temporal.api.protocol.v1.Message", FIELDS, GeneratedVisitor)
+    }
+}
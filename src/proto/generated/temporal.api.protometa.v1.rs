@@ -0,0 +1,21 @@
+// This file is @generated by prost-build.
+/// RequestHeaderAnnotation allows specifying that field values from a request
+/// should be propagated as outbound headers.
+///
+/// The value field supports template interpolation where field paths enclosed
+/// in braces will be replaced with the actual field values from the request.
+/// For example:
+///    value: "{workflow_execution.workflow_id}"
+///    value: "workflow-{workflow_execution.workflow_id}"
+///    value: "{namespace}/{workflow_execution.workflow_id}"
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct RequestHeaderAnnotation {
+    /// The name of the header to set (e.g., "temporal-resource-id")
+    #[prost(string, tag = "1")]
+    pub header: ::prost::alloc::string::String,
+    /// A template string that may contain field paths in braces.
+    /// Field paths use dot notation to traverse nested messages.
+    /// Example: "{workflow_execution.workflow_id}"
+    #[prost(string, tag = "2")]
+    pub value: ::prost::alloc::string::String,
+}
@@ -18,7 +18,7 @@ pub struct NamespaceReplicationConfig {
 pub struct FailoverStatus {
     /// Timestamp when the Cluster switched to the following failover_version
     #[prost(message, optional, tag = "1")]
-    pub failover_time: ::core::option::Option<::prost_types::Timestamp>,
+    pub failover_time: ::core::option::Option<::pbjson_types::Timestamp>,
     #[prost(int64, tag = "2")]
     pub failover_version: i64,
 }
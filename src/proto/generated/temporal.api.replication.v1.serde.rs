@@ -0,0 +1,334 @@
+impl serde::Serialize for ClusterReplicationConfig {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.cluster_name.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("temporal.api.replication.v1.ClusterReplicationConfig", len)?;
+        if !self.cluster_name.is_empty() {
+            struct_ser.serialize_field("clusterName", &self.cluster_name)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for ClusterReplicationConfig {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "cluster_name",
+            "clusterName",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            ClusterName,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "clusterName" | "cluster_name" => Ok(GeneratedField::ClusterName),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = ClusterReplicationConfig;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct temporal.api.replication.v1.ClusterReplicationConfig")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<ClusterReplicationConfig, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut cluster_name__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::ClusterName => {
+                            if cluster_name__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("clusterName"));
+                            }
+                            cluster_name__ = Some(map_.next_value()?);
+                        }
+                    }
+                }
+                Ok(ClusterReplicationConfig {
+                    cluster_name: cluster_name__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("temporal.api.replication.v1.ClusterReplicationConfig", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for FailoverStatus {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.failover_time.is_some() {
+            len += 1;
+        }
+        if self.failover_version != 0 {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("temporal.api.replication.v1.FailoverStatus", len)?;
+        if let Some(v) = self.failover_time.as_ref() {
+            struct_ser.serialize_field("failoverTime", v)?;
+        }
+        if self.failover_version != 0 {
+            #[allow(clippy::needless_borrow)]
+            #[allow(clippy::needless_borrows_for_generic_args)]
+            struct_ser.serialize_field("failoverVersion", ToString::to_string(&self.failover_version).as_str())?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for FailoverStatus {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "failover_time",
+            "failoverTime",
+            "failover_version",
+            "failoverVersion",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            FailoverTime,
+            FailoverVersion,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "failoverTime" | "failover_time" => Ok(GeneratedField::FailoverTime),
+                            "failoverVersion" | "failover_version" => Ok(GeneratedField::FailoverVersion),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = FailoverStatus;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct temporal.api.replication.v1.FailoverStatus")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<FailoverStatus, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut failover_time__ = None;
+                let mut failover_version__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::FailoverTime => {
+                            if failover_time__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("failoverTime"));
+                            }
+                            failover_time__ = map_.next_value()?;
+                        }
+                        GeneratedField::FailoverVersion => {
+                            if failover_version__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("failoverVersion"));
+                            }
+                            failover_version__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                    }
+                }
+                Ok(FailoverStatus {
+                    failover_time: failover_time__,
+                    failover_version: failover_version__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("temporal.api.replication.v1.FailoverStatus", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for NamespaceReplicationConfig {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.active_cluster_name.is_empty() {
+            len += 1;
+        }
+        if !self.clusters.is_empty() {
+            len += 1;
+        }
+        if self.state != 0 {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("temporal.api.replication.v1.NamespaceReplicationConfig", len)?;
+        if !self.active_cluster_name.is_empty() {
+            struct_ser.serialize_field("activeClusterName", &self.active_cluster_name)?;
+        }
+        if !self.clusters.is_empty() {
+            struct_ser.serialize_field("clusters", &self.clusters)?;
+        }
+        if self.state != 0 {
+            let v = super::super::enums::v1::ReplicationState::try_from(self.state)
+                .map_err(|_| serde::ser::Error::custom(format!("Invalid variant {}", self.state)))?;
+            struct_ser.serialize_field("state", &v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for NamespaceReplicationConfig {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "active_cluster_name",
+            "activeClusterName",
+            "clusters",
+            "state",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            ActiveClusterName,
+            Clusters,
+            State,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "activeClusterName" | "active_cluster_name" => Ok(GeneratedField::ActiveClusterName),
+                            "clusters" => Ok(GeneratedField::Clusters),
+                            "state" => Ok(GeneratedField::State),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = NamespaceReplicationConfig;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct temporal.api.replication.v1.NamespaceReplicationConfig")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<NamespaceReplicationConfig, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut active_cluster_name__ = None;
+                let mut clusters__ = None;
+                let mut state__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::ActiveClusterName => {
+                            if active_cluster_name__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("activeClusterName"));
+                            }
+                            active_cluster_name__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::Clusters => {
+                            if clusters__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("clusters"));
+                            }
+                            clusters__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::State => {
+                            if state__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("state"));
+                            }
+                            state__ = Some(map_.next_value::<super::super::enums::v1::ReplicationState>()? as i32);
+                        }
+                    }
+                }
+                Ok(NamespaceReplicationConfig {
+                    active_cluster_name: active_cluster_name__.unwrap_or_default(),
+                    clusters: clusters__.unwrap_or_default(),
+                    state: state__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("temporal.api.replication.v1.NamespaceReplicationConfig", FIELDS, GeneratedVisitor)
+    }
+}
@@ -38,7 +38,7 @@ pub struct WorkflowRuleSpec {
     /// Expiration time of the rule. After this time, the rule will be deleted.
     /// Can be empty if the rule should never expire.
     #[prost(message, optional, tag = "5")]
-    pub expiration_time: ::core::option::Option<::prost_types::Timestamp>,
+    pub expiration_time: ::core::option::Option<::pbjson_types::Timestamp>,
     /// Specifies how the rule should be triggered and evaluated.
     /// Currently, only "activity start" type is supported.
     #[prost(oneof = "workflow_rule_spec::Trigger", tags = "2")]
@@ -79,7 +79,7 @@ pub mod workflow_rule_spec {
 pub struct WorkflowRule {
     /// Rule creation time.
     #[prost(message, optional, tag = "1")]
-    pub create_time: ::core::option::Option<::prost_types::Timestamp>,
+    pub create_time: ::core::option::Option<::pbjson_types::Timestamp>,
     /// Rule specification
     #[prost(message, optional, tag = "2")]
     pub spec: ::core::option::Option<WorkflowRuleSpec>,
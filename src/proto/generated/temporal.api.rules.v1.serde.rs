@@ -0,0 +1,570 @@
+impl serde::Serialize for WorkflowRule {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.create_time.is_some() {
+            len += 1;
+        }
+        if self.spec.is_some() {
+            len += 1;
+        }
+        if !self.created_by_identity.is_empty() {
+            len += 1;
+        }
+        if !self.description.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("temporal.api.rules.v1.WorkflowRule", len)?;
+        if let Some(v) = self.create_time.as_ref() {
+            struct_ser.serialize_field("createTime", v)?;
+        }
+        if let Some(v) = self.spec.as_ref() {
+            struct_ser.serialize_field("spec", v)?;
+        }
+        if !self.created_by_identity.is_empty() {
+            struct_ser.serialize_field("createdByIdentity", &self.created_by_identity)?;
+        }
+        if !self.description.is_empty() {
+            struct_ser.serialize_field("description", &self.description)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for WorkflowRule {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "create_time",
+            "createTime",
+            "spec",
+            "created_by_identity",
+            "createdByIdentity",
+            "description",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            CreateTime,
+            Spec,
+            CreatedByIdentity,
+            Description,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "createTime" | "create_time" => Ok(GeneratedField::CreateTime),
+                            "spec" => Ok(GeneratedField::Spec),
+                            "createdByIdentity" | "created_by_identity" => Ok(GeneratedField::CreatedByIdentity),
+                            "description" => Ok(GeneratedField::Description),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = WorkflowRule;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct temporal.api.rules.v1.WorkflowRule")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<WorkflowRule, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut create_time__ = None;
+                let mut spec__ = None;
+                let mut created_by_identity__ = None;
+                let mut description__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::CreateTime => {
+                            if create_time__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("createTime"));
+                            }
+                            create_time__ = map_.next_value()?;
+                        }
+                        GeneratedField::Spec => {
+                            if spec__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("spec"));
+                            }
+                            spec__ = map_.next_value()?;
+                        }
+                        GeneratedField::CreatedByIdentity => {
+                            if created_by_identity__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("createdByIdentity"));
+                            }
+                            created_by_identity__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::Description => {
+                            if description__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("description"));
+                            }
+                            description__ = Some(map_.next_value()?);
+                        }
+                    }
+                }
+                Ok(WorkflowRule {
+                    create_time: create_time__,
+                    spec: spec__,
+                    created_by_identity: created_by_identity__.unwrap_or_default(),
+                    description: description__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("temporal.api.rules.v1.WorkflowRule", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for WorkflowRuleAction {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.variant.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("temporal.api.rules.v1.WorkflowRuleAction", len)?;
+        if let Some(v) = self.variant.as_ref() {
+            match v {
+                workflow_rule_action::Variant::ActivityPause(v) => {
+                    struct_ser.serialize_field("activityPause", v)?;
+                }
+            }
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for WorkflowRuleAction {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "activity_pause",
+            "activityPause",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            ActivityPause,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "activityPause" | "activity_pause" => Ok(GeneratedField::ActivityPause),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = WorkflowRuleAction;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct temporal.api.rules.v1.WorkflowRuleAction")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<WorkflowRuleAction, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut variant__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::ActivityPause => {
+                            if variant__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("activityPause"));
+                            }
+                            variant__ = map_.next_value::<::std::option::Option<_>>()?.map(workflow_rule_action::Variant::ActivityPause)
+;
+                        }
+                    }
+                }
+                Ok(WorkflowRuleAction {
+                    variant: variant__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("temporal.api.rules.v1.WorkflowRuleAction", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for workflow_rule_action::ActionActivityPause {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let len = 0;
+        let struct_ser = serializer.serialize_struct("temporal.api.rules.v1.WorkflowRuleAction.ActionActivityPause", len)?;
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for workflow_rule_action::ActionActivityPause {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                            Err(serde::de::Error::unknown_field(value, FIELDS))
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = workflow_rule_action::ActionActivityPause;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct temporal.api.rules.v1.WorkflowRuleAction.ActionActivityPause")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<workflow_rule_action::ActionActivityPause, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                while map_.next_key::<GeneratedField>()?.is_some() {
+                    let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                }
+                Ok(workflow_rule_action::ActionActivityPause {
+                })
+            }
+        }
+        deserializer.deserialize_struct("temporal.api.rules.v1.WorkflowRuleAction.ActionActivityPause", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for WorkflowRuleSpec {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.id.is_empty() {
+            len += 1;
+        }
+        if !self.visibility_query.is_empty() {
+            len += 1;
+        }
+        if !self.actions.is_empty() {
+            len += 1;
+        }
+        if self.expiration_time.is_some() {
+            len += 1;
+        }
+        if self.trigger.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("temporal.api.rules.v1.WorkflowRuleSpec", len)?;
+        if !self.id.is_empty() {
+            struct_ser.serialize_field("id", &self.id)?;
+        }
+        if !self.visibility_query.is_empty() {
+            struct_ser.serialize_field("visibilityQuery", &self.visibility_query)?;
+        }
+        if !self.actions.is_empty() {
+            struct_ser.serialize_field("actions", &self.actions)?;
+        }
+        if let Some(v) = self.expiration_time.as_ref() {
+            struct_ser.serialize_field("expirationTime", v)?;
+        }
+        if let Some(v) = self.trigger.as_ref() {
+            match v {
+                workflow_rule_spec::Trigger::ActivityStart(v) => {
+                    struct_ser.serialize_field("activityStart", v)?;
+                }
+            }
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for WorkflowRuleSpec {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "id",
+            "visibility_query",
+            "visibilityQuery",
+            "actions",
+            "expiration_time",
+            "expirationTime",
+            "activity_start",
+            "activityStart",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Id,
+            VisibilityQuery,
+            Actions,
+            ExpirationTime,
+            ActivityStart,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "id" => Ok(GeneratedField::Id),
+                            "visibilityQuery" | "visibility_query" => Ok(GeneratedField::VisibilityQuery),
+                            "actions" => Ok(GeneratedField::Actions),
+                            "expirationTime" | "expiration_time" => Ok(GeneratedField::ExpirationTime),
+                            "activityStart" | "activity_start" => Ok(GeneratedField::ActivityStart),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = WorkflowRuleSpec;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct temporal.api.rules.v1.WorkflowRuleSpec")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<WorkflowRuleSpec, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut id__ = None;
+                let mut visibility_query__ = None;
+                let mut actions__ = None;
+                let mut expiration_time__ = None;
+                let mut trigger__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Id => {
+                            if id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("id"));
+                            }
+                            id__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::VisibilityQuery => {
+                            if visibility_query__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("visibilityQuery"));
+                            }
+                            visibility_query__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::Actions => {
+                            if actions__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("actions"));
+                            }
+                            actions__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::ExpirationTime => {
+                            if expiration_time__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("expirationTime"));
+                            }
+                            expiration_time__ = map_.next_value()?;
+                        }
+                        GeneratedField::ActivityStart => {
+                            if trigger__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("activityStart"));
+                            }
+                            trigger__ = map_.next_value::<::std::option::Option<_>>()?.map(workflow_rule_spec::Trigger::ActivityStart)
+;
+                        }
+                    }
+                }
+                Ok(WorkflowRuleSpec {
+                    id: id__.unwrap_or_default(),
+                    visibility_query: visibility_query__.unwrap_or_default(),
+                    actions: actions__.unwrap_or_default(),
+                    expiration_time: expiration_time__,
+                    trigger: trigger__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("temporal.api.rules.v1.WorkflowRuleSpec", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for workflow_rule_spec::ActivityStartingTrigger {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.predicate.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("temporal.api.rules.v1.WorkflowRuleSpec.ActivityStartingTrigger", len)?;
+        if !self.predicate.is_empty() {
+            struct_ser.serialize_field("predicate", &self.predicate)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for workflow_rule_spec::ActivityStartingTrigger {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "predicate",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Predicate,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "predicate" => Ok(GeneratedField::Predicate),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = workflow_rule_spec::ActivityStartingTrigger;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct temporal.api.rules.v1.WorkflowRuleSpec.ActivityStartingTrigger")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<workflow_rule_spec::ActivityStartingTrigger, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut predicate__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Predicate => {
+                            if predicate__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("predicate"));
+                            }
+                            predicate__ = Some(map_.next_value()?);
+                        }
+                    }
+                }
+                Ok(workflow_rule_spec::ActivityStartingTrigger {
+                    predicate: predicate__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("temporal.api.rules.v1.WorkflowRuleSpec.ActivityStartingTrigger", FIELDS, GeneratedVisitor)
+    }
+}
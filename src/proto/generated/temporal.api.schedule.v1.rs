@@ -114,9 +114,9 @@ pub struct StructuredCalendarSpec {
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct IntervalSpec {
     #[prost(message, optional, tag = "1")]
-    pub interval: ::core::option::Option<::prost_types::Duration>,
+    pub interval: ::core::option::Option<::pbjson_types::Duration>,
     #[prost(message, optional, tag = "2")]
-    pub phase: ::core::option::Option<::prost_types::Duration>,
+    pub phase: ::core::option::Option<::pbjson_types::Duration>,
 }
 /// ScheduleSpec is a complete description of a set of absolute timestamps
 /// (possibly infinite) that an action should occur at. The meaning of a
@@ -179,14 +179,14 @@ pub struct ScheduleSpec {
     /// If start_time is set, any timestamps before start_time will be skipped.
     /// (Together, start_time and end_time make an inclusive interval.)
     #[prost(message, optional, tag = "4")]
-    pub start_time: ::core::option::Option<::prost_types::Timestamp>,
+    pub start_time: ::core::option::Option<::pbjson_types::Timestamp>,
     /// If end_time is set, any timestamps after end_time will be skipped.
     #[prost(message, optional, tag = "5")]
-    pub end_time: ::core::option::Option<::prost_types::Timestamp>,
+    pub end_time: ::core::option::Option<::pbjson_types::Timestamp>,
     /// All timestamps will be incremented by a random value from 0 to this
     /// amount of jitter. Default: 0
     #[prost(message, optional, tag = "6")]
-    pub jitter: ::core::option::Option<::prost_types::Duration>,
+    pub jitter: ::core::option::Option<::pbjson_types::Duration>,
     /// Time zone to interpret all calendar-based specs in.
     ///
     /// If unset, defaults to UTC. We recommend using UTC for your application if
@@ -228,7 +228,7 @@ pub struct SchedulePolicies {
     /// time is within this window from the current time.
     /// This value defaults to one year, and can't be less than 10 seconds.
     #[prost(message, optional, tag = "2")]
-    pub catchup_window: ::core::option::Option<::prost_types::Duration>,
+    pub catchup_window: ::core::option::Option<::pbjson_types::Duration>,
     /// If true, and a workflow run fails or times out, turn on "paused".
     /// This applies after retry policies: the full chain of retries must fail to
     /// trigger a pause here.
@@ -261,10 +261,10 @@ pub mod schedule_action {
 pub struct ScheduleActionResult {
     /// Time that the action was taken (according to the schedule, including jitter).
     #[prost(message, optional, tag = "1")]
-    pub schedule_time: ::core::option::Option<::prost_types::Timestamp>,
+    pub schedule_time: ::core::option::Option<::pbjson_types::Timestamp>,
     /// Time that the action was taken (real time).
     #[prost(message, optional, tag = "2")]
-    pub actual_time: ::core::option::Option<::prost_types::Timestamp>,
+    pub actual_time: ::core::option::Option<::pbjson_types::Timestamp>,
     /// If action was start_workflow:
     #[prost(message, optional, tag = "11")]
     pub start_workflow_result: ::core::option::Option<
@@ -308,7 +308,7 @@ pub struct TriggerImmediatelyRequest {
     /// Timestamp used for the identity of the target workflow.
     /// If not set the default value is the current time.
     #[prost(message, optional, tag = "2")]
-    pub scheduled_time: ::core::option::Option<::prost_types::Timestamp>,
+    pub scheduled_time: ::core::option::Option<::pbjson_types::Timestamp>,
 }
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct BackfillRequest {
@@ -319,9 +319,9 @@ pub struct BackfillRequest {
     /// nominally scheduled in the interval but with jitter that pushes it after
     /// end_time will not be included.
     #[prost(message, optional, tag = "1")]
-    pub start_time: ::core::option::Option<::prost_types::Timestamp>,
+    pub start_time: ::core::option::Option<::pbjson_types::Timestamp>,
     #[prost(message, optional, tag = "2")]
-    pub end_time: ::core::option::Option<::prost_types::Timestamp>,
+    pub end_time: ::core::option::Option<::pbjson_types::Timestamp>,
     /// If set, override overlap policy for this request.
     #[prost(enumeration = "super::super::enums::v1::ScheduleOverlapPolicy", tag = "3")]
     pub overlap_policy: i32,
@@ -376,16 +376,19 @@ pub struct ScheduleInfo {
     pub recent_actions: ::prost::alloc::vec::Vec<ScheduleActionResult>,
     /// Next ten scheduled action times.
     #[prost(message, repeated, tag = "5")]
-    pub future_action_times: ::prost::alloc::vec::Vec<::prost_types::Timestamp>,
+    pub future_action_times: ::prost::alloc::vec::Vec<::pbjson_types::Timestamp>,
     /// Timestamps of schedule creation and last update.
     #[prost(message, optional, tag = "6")]
-    pub create_time: ::core::option::Option<::prost_types::Timestamp>,
+    pub create_time: ::core::option::Option<::pbjson_types::Timestamp>,
     #[prost(message, optional, tag = "7")]
-    pub update_time: ::core::option::Option<::prost_types::Timestamp>,
+    pub update_time: ::core::option::Option<::pbjson_types::Timestamp>,
     /// Deprecated.
     #[deprecated]
     #[prost(string, tag = "8")]
     pub invalid_schedule_error: ::prost::alloc::string::String,
+    /// Size of the schedule's internal state (including payloads) in bytes.
+    #[prost(int64, tag = "12")]
+    pub state_size_bytes: i64,
 }
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct Schedule {
@@ -420,7 +423,10 @@ pub struct ScheduleListInfo {
     #[prost(message, repeated, tag = "5")]
     pub recent_actions: ::prost::alloc::vec::Vec<ScheduleActionResult>,
     #[prost(message, repeated, tag = "6")]
-    pub future_action_times: ::prost::alloc::vec::Vec<::prost_types::Timestamp>,
+    pub future_action_times: ::prost::alloc::vec::Vec<::pbjson_types::Timestamp>,
+    /// Size of the schedule's internal state (including payloads) in bytes.
+    #[prost(int64, tag = "7")]
+    pub state_size_bytes: i64,
 }
 /// ScheduleListEntry is returned by ListSchedules.
 #[derive(Clone, PartialEq, ::prost::Message)]
@@ -0,0 +1,2664 @@
+impl serde::Serialize for BackfillRequest {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.start_time.is_some() {
+            len += 1;
+        }
+        if self.end_time.is_some() {
+            len += 1;
+        }
+        if self.overlap_policy != 0 {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("temporal.api.schedule.v1.BackfillRequest", len)?;
+        if let Some(v) = self.start_time.as_ref() {
+            struct_ser.serialize_field("startTime", v)?;
+        }
+        if let Some(v) = self.end_time.as_ref() {
+            struct_ser.serialize_field("endTime", v)?;
+        }
+        if self.overlap_policy != 0 {
+            let v = super::super::enums::v1::ScheduleOverlapPolicy::try_from(self.overlap_policy)
+                .map_err(|_| serde::ser::Error::custom(format!("Invalid variant {}", self.overlap_policy)))?;
+            struct_ser.serialize_field("overlapPolicy", &v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for BackfillRequest {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "start_time",
+            "startTime",
+            "end_time",
+            "endTime",
+            "overlap_policy",
+            "overlapPolicy",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            StartTime,
+            EndTime,
+            OverlapPolicy,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "startTime" | "start_time" => Ok(GeneratedField::StartTime),
+                            "endTime" | "end_time" => Ok(GeneratedField::EndTime),
+                            "overlapPolicy" | "overlap_policy" => Ok(GeneratedField::OverlapPolicy),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = BackfillRequest;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct temporal.api.schedule.v1.BackfillRequest")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<BackfillRequest, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut start_time__ = None;
+                let mut end_time__ = None;
+                let mut overlap_policy__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::StartTime => {
+                            if start_time__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("startTime"));
+                            }
+                            start_time__ = map_.next_value()?;
+                        }
+                        GeneratedField::EndTime => {
+                            if end_time__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("endTime"));
+                            }
+                            end_time__ = map_.next_value()?;
+                        }
+                        GeneratedField::OverlapPolicy => {
+                            if overlap_policy__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("overlapPolicy"));
+                            }
+                            overlap_policy__ = Some(map_.next_value::<super::super::enums::v1::ScheduleOverlapPolicy>()? as i32);
+                        }
+                    }
+                }
+                Ok(BackfillRequest {
+                    start_time: start_time__,
+                    end_time: end_time__,
+                    overlap_policy: overlap_policy__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("temporal.api.schedule.v1.BackfillRequest", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for CalendarSpec {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.second.is_empty() {
+            len += 1;
+        }
+        if !self.minute.is_empty() {
+            len += 1;
+        }
+        if !self.hour.is_empty() {
+            len += 1;
+        }
+        if !self.day_of_month.is_empty() {
+            len += 1;
+        }
+        if !self.month.is_empty() {
+            len += 1;
+        }
+        if !self.year.is_empty() {
+            len += 1;
+        }
+        if !self.day_of_week.is_empty() {
+            len += 1;
+        }
+        if !self.comment.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("temporal.api.schedule.v1.CalendarSpec", len)?;
+        if !self.second.is_empty() {
+            struct_ser.serialize_field("second", &self.second)?;
+        }
+        if !self.minute.is_empty() {
+            struct_ser.serialize_field("minute", &self.minute)?;
+        }
+        if !self.hour.is_empty() {
+            struct_ser.serialize_field("hour", &self.hour)?;
+        }
+        if !self.day_of_month.is_empty() {
+            struct_ser.serialize_field("dayOfMonth", &self.day_of_month)?;
+        }
+        if !self.month.is_empty() {
+            struct_ser.serialize_field("month", &self.month)?;
+        }
+        if !self.year.is_empty() {
+            struct_ser.serialize_field("year", &self.year)?;
+        }
+        if !self.day_of_week.is_empty() {
+            struct_ser.serialize_field("dayOfWeek", &self.day_of_week)?;
+        }
+        if !self.comment.is_empty() {
+            struct_ser.serialize_field("comment", &self.comment)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for CalendarSpec {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "second",
+            "minute",
+            "hour",
+            "day_of_month",
+            "dayOfMonth",
+            "month",
+            "year",
+            "day_of_week",
+            "dayOfWeek",
+            "comment",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Second,
+            Minute,
+            Hour,
+            DayOfMonth,
+            Month,
+            Year,
+            DayOfWeek,
+            Comment,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "second" => Ok(GeneratedField::Second),
+                            "minute" => Ok(GeneratedField::Minute),
+                            "hour" => Ok(GeneratedField::Hour),
+                            "dayOfMonth" | "day_of_month" => Ok(GeneratedField::DayOfMonth),
+                            "month" => Ok(GeneratedField::Month),
+                            "year" => Ok(GeneratedField::Year),
+                            "dayOfWeek" | "day_of_week" => Ok(GeneratedField::DayOfWeek),
+                            "comment" => Ok(GeneratedField::Comment),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = CalendarSpec;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct temporal.api.schedule.v1.CalendarSpec")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<CalendarSpec, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut second__ = None;
+                let mut minute__ = None;
+                let mut hour__ = None;
+                let mut day_of_month__ = None;
+                let mut month__ = None;
+                let mut year__ = None;
+                let mut day_of_week__ = None;
+                let mut comment__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Second => {
+                            if second__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("second"));
+                            }
+                            second__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::Minute => {
+                            if minute__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("minute"));
+                            }
+                            minute__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::Hour => {
+                            if hour__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("hour"));
+                            }
+                            hour__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::DayOfMonth => {
+                            if day_of_month__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("dayOfMonth"));
+                            }
+                            day_of_month__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::Month => {
+                            if month__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("month"));
+                            }
+                            month__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::Year => {
+                            if year__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("year"));
+                            }
+                            year__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::DayOfWeek => {
+                            if day_of_week__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("dayOfWeek"));
+                            }
+                            day_of_week__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::Comment => {
+                            if comment__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("comment"));
+                            }
+                            comment__ = Some(map_.next_value()?);
+                        }
+                    }
+                }
+                Ok(CalendarSpec {
+                    second: second__.unwrap_or_default(),
+                    minute: minute__.unwrap_or_default(),
+                    hour: hour__.unwrap_or_default(),
+                    day_of_month: day_of_month__.unwrap_or_default(),
+                    month: month__.unwrap_or_default(),
+                    year: year__.unwrap_or_default(),
+                    day_of_week: day_of_week__.unwrap_or_default(),
+                    comment: comment__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("temporal.api.schedule.v1.CalendarSpec", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for IntervalSpec {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.interval.is_some() {
+            len += 1;
+        }
+        if self.phase.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("temporal.api.schedule.v1.IntervalSpec", len)?;
+        if let Some(v) = self.interval.as_ref() {
+            struct_ser.serialize_field("interval", v)?;
+        }
+        if let Some(v) = self.phase.as_ref() {
+            struct_ser.serialize_field("phase", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for IntervalSpec {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "interval",
+            "phase",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Interval,
+            Phase,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "interval" => Ok(GeneratedField::Interval),
+                            "phase" => Ok(GeneratedField::Phase),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = IntervalSpec;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct temporal.api.schedule.v1.IntervalSpec")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<IntervalSpec, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut interval__ = None;
+                let mut phase__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Interval => {
+                            if interval__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("interval"));
+                            }
+                            interval__ = map_.next_value()?;
+                        }
+                        GeneratedField::Phase => {
+                            if phase__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("phase"));
+                            }
+                            phase__ = map_.next_value()?;
+                        }
+                    }
+                }
+                Ok(IntervalSpec {
+                    interval: interval__,
+                    phase: phase__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("temporal.api.schedule.v1.IntervalSpec", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for Range {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.start != 0 {
+            len += 1;
+        }
+        if self.end != 0 {
+            len += 1;
+        }
+        if self.step != 0 {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("temporal.api.schedule.v1.Range", len)?;
+        if self.start != 0 {
+            struct_ser.serialize_field("start", &self.start)?;
+        }
+        if self.end != 0 {
+            struct_ser.serialize_field("end", &self.end)?;
+        }
+        if self.step != 0 {
+            struct_ser.serialize_field("step", &self.step)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for Range {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "start",
+            "end",
+            "step",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Start,
+            End,
+            Step,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "start" => Ok(GeneratedField::Start),
+                            "end" => Ok(GeneratedField::End),
+                            "step" => Ok(GeneratedField::Step),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = Range;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct temporal.api.schedule.v1.Range")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<Range, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut start__ = None;
+                let mut end__ = None;
+                let mut step__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Start => {
+                            if start__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("start"));
+                            }
+                            start__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::End => {
+                            if end__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("end"));
+                            }
+                            end__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::Step => {
+                            if step__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("step"));
+                            }
+                            step__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                    }
+                }
+                Ok(Range {
+                    start: start__.unwrap_or_default(),
+                    end: end__.unwrap_or_default(),
+                    step: step__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("temporal.api.schedule.v1.Range", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for Schedule {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.spec.is_some() {
+            len += 1;
+        }
+        if self.action.is_some() {
+            len += 1;
+        }
+        if self.policies.is_some() {
+            len += 1;
+        }
+        if self.state.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("temporal.api.schedule.v1.Schedule", len)?;
+        if let Some(v) = self.spec.as_ref() {
+            struct_ser.serialize_field("spec", v)?;
+        }
+        if let Some(v) = self.action.as_ref() {
+            struct_ser.serialize_field("action", v)?;
+        }
+        if let Some(v) = self.policies.as_ref() {
+            struct_ser.serialize_field("policies", v)?;
+        }
+        if let Some(v) = self.state.as_ref() {
+            struct_ser.serialize_field("state", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for Schedule {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "spec",
+            "action",
+            "policies",
+            "state",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Spec,
+            Action,
+            Policies,
+            State,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "spec" => Ok(GeneratedField::Spec),
+                            "action" => Ok(GeneratedField::Action),
+                            "policies" => Ok(GeneratedField::Policies),
+                            "state" => Ok(GeneratedField::State),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = Schedule;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct temporal.api.schedule.v1.Schedule")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<Schedule, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut spec__ = None;
+                let mut action__ = None;
+                let mut policies__ = None;
+                let mut state__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Spec => {
+                            if spec__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("spec"));
+                            }
+                            spec__ = map_.next_value()?;
+                        }
+                        GeneratedField::Action => {
+                            if action__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("action"));
+                            }
+                            action__ = map_.next_value()?;
+                        }
+                        GeneratedField::Policies => {
+                            if policies__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("policies"));
+                            }
+                            policies__ = map_.next_value()?;
+                        }
+                        GeneratedField::State => {
+                            if state__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("state"));
+                            }
+                            state__ = map_.next_value()?;
+                        }
+                    }
+                }
+                Ok(Schedule {
+                    spec: spec__,
+                    action: action__,
+                    policies: policies__,
+                    state: state__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("temporal.api.schedule.v1.Schedule", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for ScheduleAction {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.action.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("temporal.api.schedule.v1.ScheduleAction", len)?;
+        if let Some(v) = self.action.as_ref() {
+            match v {
+                schedule_action::Action::StartWorkflow(v) => {
+                    struct_ser.serialize_field("startWorkflow", v)?;
+                }
+            }
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for ScheduleAction {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "start_workflow",
+            "startWorkflow",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            StartWorkflow,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "startWorkflow" | "start_workflow" => Ok(GeneratedField::StartWorkflow),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = ScheduleAction;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct temporal.api.schedule.v1.ScheduleAction")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<ScheduleAction, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut action__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::StartWorkflow => {
+                            if action__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("startWorkflow"));
+                            }
+                            action__ = map_.next_value::<::std::option::Option<_>>()?.map(schedule_action::Action::StartWorkflow)
+;
+                        }
+                    }
+                }
+                Ok(ScheduleAction {
+                    action: action__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("temporal.api.schedule.v1.ScheduleAction", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for ScheduleActionResult {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.schedule_time.is_some() {
+            len += 1;
+        }
+        if self.actual_time.is_some() {
+            len += 1;
+        }
+        if self.start_workflow_result.is_some() {
+            len += 1;
+        }
+        if self.start_workflow_status != 0 {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("temporal.api.schedule.v1.ScheduleActionResult", len)?;
+        if let Some(v) = self.schedule_time.as_ref() {
+            struct_ser.serialize_field("scheduleTime", v)?;
+        }
+        if let Some(v) = self.actual_time.as_ref() {
+            struct_ser.serialize_field("actualTime", v)?;
+        }
+        if let Some(v) = self.start_workflow_result.as_ref() {
+            struct_ser.serialize_field("startWorkflowResult", v)?;
+        }
+        if self.start_workflow_status != 0 {
+            let v = super::super::enums::v1::WorkflowExecutionStatus::try_from(self.start_workflow_status)
+                .map_err(|_| serde::ser::Error::custom(format!("Invalid variant {}", self.start_workflow_status)))?;
+            struct_ser.serialize_field("startWorkflowStatus", &v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for ScheduleActionResult {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "schedule_time",
+            "scheduleTime",
+            "actual_time",
+            "actualTime",
+            "start_workflow_result",
+            "startWorkflowResult",
+            "start_workflow_status",
+            "startWorkflowStatus",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            ScheduleTime,
+            ActualTime,
+            StartWorkflowResult,
+            StartWorkflowStatus,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "scheduleTime" | "schedule_time" => Ok(GeneratedField::ScheduleTime),
+                            "actualTime" | "actual_time" => Ok(GeneratedField::ActualTime),
+                            "startWorkflowResult" | "start_workflow_result" => Ok(GeneratedField::StartWorkflowResult),
+                            "startWorkflowStatus" | "start_workflow_status" => Ok(GeneratedField::StartWorkflowStatus),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = ScheduleActionResult;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct temporal.api.schedule.v1.ScheduleActionResult")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<ScheduleActionResult, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut schedule_time__ = None;
+                let mut actual_time__ = None;
+                let mut start_workflow_result__ = None;
+                let mut start_workflow_status__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::ScheduleTime => {
+                            if schedule_time__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("scheduleTime"));
+                            }
+                            schedule_time__ = map_.next_value()?;
+                        }
+                        GeneratedField::ActualTime => {
+                            if actual_time__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("actualTime"));
+                            }
+                            actual_time__ = map_.next_value()?;
+                        }
+                        GeneratedField::StartWorkflowResult => {
+                            if start_workflow_result__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("startWorkflowResult"));
+                            }
+                            start_workflow_result__ = map_.next_value()?;
+                        }
+                        GeneratedField::StartWorkflowStatus => {
+                            if start_workflow_status__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("startWorkflowStatus"));
+                            }
+                            start_workflow_status__ = Some(map_.next_value::<super::super::enums::v1::WorkflowExecutionStatus>()? as i32);
+                        }
+                    }
+                }
+                Ok(ScheduleActionResult {
+                    schedule_time: schedule_time__,
+                    actual_time: actual_time__,
+                    start_workflow_result: start_workflow_result__,
+                    start_workflow_status: start_workflow_status__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("temporal.api.schedule.v1.ScheduleActionResult", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for ScheduleInfo {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.action_count != 0 {
+            len += 1;
+        }
+        if self.missed_catchup_window != 0 {
+            len += 1;
+        }
+        if self.overlap_skipped != 0 {
+            len += 1;
+        }
+        if self.buffer_dropped != 0 {
+            len += 1;
+        }
+        if self.buffer_size != 0 {
+            len += 1;
+        }
+        if !self.running_workflows.is_empty() {
+            len += 1;
+        }
+        if !self.recent_actions.is_empty() {
+            len += 1;
+        }
+        if !self.future_action_times.is_empty() {
+            len += 1;
+        }
+        if self.create_time.is_some() {
+            len += 1;
+        }
+        if self.update_time.is_some() {
+            len += 1;
+        }
+        if !self.invalid_schedule_error.is_empty() {
+            len += 1;
+        }
+        if self.state_size_bytes != 0 {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("temporal.api.schedule.v1.ScheduleInfo", len)?;
+        if self.action_count != 0 {
+            #[allow(clippy::needless_borrow)]
+            #[allow(clippy::needless_borrows_for_generic_args)]
+            struct_ser.serialize_field("actionCount", ToString::to_string(&self.action_count).as_str())?;
+        }
+        if self.missed_catchup_window != 0 {
+            #[allow(clippy::needless_borrow)]
+            #[allow(clippy::needless_borrows_for_generic_args)]
+            struct_ser.serialize_field("missedCatchupWindow", ToString::to_string(&self.missed_catchup_window).as_str())?;
+        }
+        if self.overlap_skipped != 0 {
+            #[allow(clippy::needless_borrow)]
+            #[allow(clippy::needless_borrows_for_generic_args)]
+            struct_ser.serialize_field("overlapSkipped", ToString::to_string(&self.overlap_skipped).as_str())?;
+        }
+        if self.buffer_dropped != 0 {
+            #[allow(clippy::needless_borrow)]
+            #[allow(clippy::needless_borrows_for_generic_args)]
+            struct_ser.serialize_field("bufferDropped", ToString::to_string(&self.buffer_dropped).as_str())?;
+        }
+        if self.buffer_size != 0 {
+            #[allow(clippy::needless_borrow)]
+            #[allow(clippy::needless_borrows_for_generic_args)]
+            struct_ser.serialize_field("bufferSize", ToString::to_string(&self.buffer_size).as_str())?;
+        }
+        if !self.running_workflows.is_empty() {
+            struct_ser.serialize_field("runningWorkflows", &self.running_workflows)?;
+        }
+        if !self.recent_actions.is_empty() {
+            struct_ser.serialize_field("recentActions", &self.recent_actions)?;
+        }
+        if !self.future_action_times.is_empty() {
+            struct_ser.serialize_field("futureActionTimes", &self.future_action_times)?;
+        }
+        if let Some(v) = self.create_time.as_ref() {
+            struct_ser.serialize_field("createTime", v)?;
+        }
+        if let Some(v) = self.update_time.as_ref() {
+            struct_ser.serialize_field("updateTime", v)?;
+        }
+        if !self.invalid_schedule_error.is_empty() {
+            struct_ser.serialize_field("invalidScheduleError", &self.invalid_schedule_error)?;
+        }
+        if self.state_size_bytes != 0 {
+            #[allow(clippy::needless_borrow)]
+            #[allow(clippy::needless_borrows_for_generic_args)]
+            struct_ser.serialize_field("stateSizeBytes", ToString::to_string(&self.state_size_bytes).as_str())?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for ScheduleInfo {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "action_count",
+            "actionCount",
+            "missed_catchup_window",
+            "missedCatchupWindow",
+            "overlap_skipped",
+            "overlapSkipped",
+            "buffer_dropped",
+            "bufferDropped",
+            "buffer_size",
+            "bufferSize",
+            "running_workflows",
+            "runningWorkflows",
+            "recent_actions",
+            "recentActions",
+            "future_action_times",
+            "futureActionTimes",
+            "create_time",
+            "createTime",
+            "update_time",
+            "updateTime",
+            "invalid_schedule_error",
+            "invalidScheduleError",
+            "state_size_bytes",
+            "stateSizeBytes",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            ActionCount,
+            MissedCatchupWindow,
+            OverlapSkipped,
+            BufferDropped,
+            BufferSize,
+            RunningWorkflows,
+            RecentActions,
+            FutureActionTimes,
+            CreateTime,
+            UpdateTime,
+            InvalidScheduleError,
+            StateSizeBytes,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "actionCount" | "action_count" => Ok(GeneratedField::ActionCount),
+                            "missedCatchupWindow" | "missed_catchup_window" => Ok(GeneratedField::MissedCatchupWindow),
+                            "overlapSkipped" | "overlap_skipped" => Ok(GeneratedField::OverlapSkipped),
+                            "bufferDropped" | "buffer_dropped" => Ok(GeneratedField::BufferDropped),
+                            "bufferSize" | "buffer_size" => Ok(GeneratedField::BufferSize),
+                            "runningWorkflows" | "running_workflows" => Ok(GeneratedField::RunningWorkflows),
+                            "recentActions" | "recent_actions" => Ok(GeneratedField::RecentActions),
+                            "futureActionTimes" | "future_action_times" => Ok(GeneratedField::FutureActionTimes),
+                            "createTime" | "create_time" => Ok(GeneratedField::CreateTime),
+                            "updateTime" | "update_time" => Ok(GeneratedField::UpdateTime),
+                            "invalidScheduleError" | "invalid_schedule_error" => Ok(GeneratedField::InvalidScheduleError),
+                            "stateSizeBytes" | "state_size_bytes" => Ok(GeneratedField::StateSizeBytes),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = ScheduleInfo;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct temporal.api.schedule.v1.ScheduleInfo")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<ScheduleInfo, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut action_count__ = None;
+                let mut missed_catchup_window__ = None;
+                let mut overlap_skipped__ = None;
+                let mut buffer_dropped__ = None;
+                let mut buffer_size__ = None;
+                let mut running_workflows__ = None;
+                let mut recent_actions__ = None;
+                let mut future_action_times__ = None;
+                let mut create_time__ = None;
+                let mut update_time__ = None;
+                let mut invalid_schedule_error__ = None;
+                let mut state_size_bytes__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::ActionCount => {
+                            if action_count__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("actionCount"));
+                            }
+                            action_count__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::MissedCatchupWindow => {
+                            if missed_catchup_window__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("missedCatchupWindow"));
+                            }
+                            missed_catchup_window__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::OverlapSkipped => {
+                            if overlap_skipped__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("overlapSkipped"));
+                            }
+                            overlap_skipped__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::BufferDropped => {
+                            if buffer_dropped__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("bufferDropped"));
+                            }
+                            buffer_dropped__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::BufferSize => {
+                            if buffer_size__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("bufferSize"));
+                            }
+                            buffer_size__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::RunningWorkflows => {
+                            if running_workflows__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("runningWorkflows"));
+                            }
+                            running_workflows__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::RecentActions => {
+                            if recent_actions__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("recentActions"));
+                            }
+                            recent_actions__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::FutureActionTimes => {
+                            if future_action_times__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("futureActionTimes"));
+                            }
+                            future_action_times__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::CreateTime => {
+                            if create_time__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("createTime"));
+                            }
+                            create_time__ = map_.next_value()?;
+                        }
+                        GeneratedField::UpdateTime => {
+                            if update_time__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("updateTime"));
+                            }
+                            update_time__ = map_.next_value()?;
+                        }
+                        GeneratedField::InvalidScheduleError => {
+                            if invalid_schedule_error__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("invalidScheduleError"));
+                            }
+                            invalid_schedule_error__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::StateSizeBytes => {
+                            if state_size_bytes__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("stateSizeBytes"));
+                            }
+                            state_size_bytes__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                    }
+                }
+                Ok(ScheduleInfo {
+                    action_count: action_count__.unwrap_or_default(),
+                    missed_catchup_window: missed_catchup_window__.unwrap_or_default(),
+                    overlap_skipped: overlap_skipped__.unwrap_or_default(),
+                    buffer_dropped: buffer_dropped__.unwrap_or_default(),
+                    buffer_size: buffer_size__.unwrap_or_default(),
+                    running_workflows: running_workflows__.unwrap_or_default(),
+                    recent_actions: recent_actions__.unwrap_or_default(),
+                    future_action_times: future_action_times__.unwrap_or_default(),
+                    create_time: create_time__,
+                    update_time: update_time__,
+                    invalid_schedule_error: invalid_schedule_error__.unwrap_or_default(),
+                    state_size_bytes: state_size_bytes__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("temporal.api.schedule.v1.ScheduleInfo", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for ScheduleListEntry {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.schedule_id.is_empty() {
+            len += 1;
+        }
+        if self.memo.is_some() {
+            len += 1;
+        }
+        if self.search_attributes.is_some() {
+            len += 1;
+        }
+        if self.info.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("temporal.api.schedule.v1.ScheduleListEntry", len)?;
+        if !self.schedule_id.is_empty() {
+            struct_ser.serialize_field("scheduleId", &self.schedule_id)?;
+        }
+        if let Some(v) = self.memo.as_ref() {
+            struct_ser.serialize_field("memo", v)?;
+        }
+        if let Some(v) = self.search_attributes.as_ref() {
+            struct_ser.serialize_field("searchAttributes", v)?;
+        }
+        if let Some(v) = self.info.as_ref() {
+            struct_ser.serialize_field("info", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for ScheduleListEntry {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "schedule_id",
+            "scheduleId",
+            "memo",
+            "search_attributes",
+            "searchAttributes",
+            "info",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            ScheduleId,
+            Memo,
+            SearchAttributes,
+            Info,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "scheduleId" | "schedule_id" => Ok(GeneratedField::ScheduleId),
+                            "memo" => Ok(GeneratedField::Memo),
+                            "searchAttributes" | "search_attributes" => Ok(GeneratedField::SearchAttributes),
+                            "info" => Ok(GeneratedField::Info),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = ScheduleListEntry;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct temporal.api.schedule.v1.ScheduleListEntry")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<ScheduleListEntry, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut schedule_id__ = None;
+                let mut memo__ = None;
+                let mut search_attributes__ = None;
+                let mut info__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::ScheduleId => {
+                            if schedule_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("scheduleId"));
+                            }
+                            schedule_id__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::Memo => {
+                            if memo__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("memo"));
+                            }
+                            memo__ = map_.next_value()?;
+                        }
+                        GeneratedField::SearchAttributes => {
+                            if search_attributes__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("searchAttributes"));
+                            }
+                            search_attributes__ = map_.next_value()?;
+                        }
+                        GeneratedField::Info => {
+                            if info__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("info"));
+                            }
+                            info__ = map_.next_value()?;
+                        }
+                    }
+                }
+                Ok(ScheduleListEntry {
+                    schedule_id: schedule_id__.unwrap_or_default(),
+                    memo: memo__,
+                    search_attributes: search_attributes__,
+                    info: info__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("temporal.api.schedule.v1.ScheduleListEntry", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for ScheduleListInfo {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.spec.is_some() {
+            len += 1;
+        }
+        if self.workflow_type.is_some() {
+            len += 1;
+        }
+        if !self.notes.is_empty() {
+            len += 1;
+        }
+        if self.paused {
+            len += 1;
+        }
+        if !self.recent_actions.is_empty() {
+            len += 1;
+        }
+        if !self.future_action_times.is_empty() {
+            len += 1;
+        }
+        if self.state_size_bytes != 0 {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("temporal.api.schedule.v1.ScheduleListInfo", len)?;
+        if let Some(v) = self.spec.as_ref() {
+            struct_ser.serialize_field("spec", v)?;
+        }
+        if let Some(v) = self.workflow_type.as_ref() {
+            struct_ser.serialize_field("workflowType", v)?;
+        }
+        if !self.notes.is_empty() {
+            struct_ser.serialize_field("notes", &self.notes)?;
+        }
+        if self.paused {
+            struct_ser.serialize_field("paused", &self.paused)?;
+        }
+        if !self.recent_actions.is_empty() {
+            struct_ser.serialize_field("recentActions", &self.recent_actions)?;
+        }
+        if !self.future_action_times.is_empty() {
+            struct_ser.serialize_field("futureActionTimes", &self.future_action_times)?;
+        }
+        if self.state_size_bytes != 0 {
+            #[allow(clippy::needless_borrow)]
+            #[allow(clippy::needless_borrows_for_generic_args)]
+            struct_ser.serialize_field("stateSizeBytes", ToString::to_string(&self.state_size_bytes).as_str())?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for ScheduleListInfo {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "spec",
+            "workflow_type",
+            "workflowType",
+            "notes",
+            "paused",
+            "recent_actions",
+            "recentActions",
+            "future_action_times",
+            "futureActionTimes",
+            "state_size_bytes",
+            "stateSizeBytes",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Spec,
+            WorkflowType,
+            Notes,
+            Paused,
+            RecentActions,
+            FutureActionTimes,
+            StateSizeBytes,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "spec" => Ok(GeneratedField::Spec),
+                            "workflowType" | "workflow_type" => Ok(GeneratedField::WorkflowType),
+                            "notes" => Ok(GeneratedField::Notes),
+                            "paused" => Ok(GeneratedField::Paused),
+                            "recentActions" | "recent_actions" => Ok(GeneratedField::RecentActions),
+                            "futureActionTimes" | "future_action_times" => Ok(GeneratedField::FutureActionTimes),
+                            "stateSizeBytes" | "state_size_bytes" => Ok(GeneratedField::StateSizeBytes),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = ScheduleListInfo;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct temporal.api.schedule.v1.ScheduleListInfo")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<ScheduleListInfo, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut spec__ = None;
+                let mut workflow_type__ = None;
+                let mut notes__ = None;
+                let mut paused__ = None;
+                let mut recent_actions__ = None;
+                let mut future_action_times__ = None;
+                let mut state_size_bytes__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Spec => {
+                            if spec__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("spec"));
+                            }
+                            spec__ = map_.next_value()?;
+                        }
+                        GeneratedField::WorkflowType => {
+                            if workflow_type__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("workflowType"));
+                            }
+                            workflow_type__ = map_.next_value()?;
+                        }
+                        GeneratedField::Notes => {
+                            if notes__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("notes"));
+                            }
+                            notes__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::Paused => {
+                            if paused__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("paused"));
+                            }
+                            paused__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::RecentActions => {
+                            if recent_actions__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("recentActions"));
+                            }
+                            recent_actions__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::FutureActionTimes => {
+                            if future_action_times__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("futureActionTimes"));
+                            }
+                            future_action_times__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::StateSizeBytes => {
+                            if state_size_bytes__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("stateSizeBytes"));
+                            }
+                            state_size_bytes__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                    }
+                }
+                Ok(ScheduleListInfo {
+                    spec: spec__,
+                    workflow_type: workflow_type__,
+                    notes: notes__.unwrap_or_default(),
+                    paused: paused__.unwrap_or_default(),
+                    recent_actions: recent_actions__.unwrap_or_default(),
+                    future_action_times: future_action_times__.unwrap_or_default(),
+                    state_size_bytes: state_size_bytes__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("temporal.api.schedule.v1.ScheduleListInfo", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for SchedulePatch {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.trigger_immediately.is_some() {
+            len += 1;
+        }
+        if !self.backfill_request.is_empty() {
+            len += 1;
+        }
+        if !self.pause.is_empty() {
+            len += 1;
+        }
+        if !self.unpause.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("temporal.api.schedule.v1.SchedulePatch", len)?;
+        if let Some(v) = self.trigger_immediately.as_ref() {
+            struct_ser.serialize_field("triggerImmediately", v)?;
+        }
+        if !self.backfill_request.is_empty() {
+            struct_ser.serialize_field("backfillRequest", &self.backfill_request)?;
+        }
+        if !self.pause.is_empty() {
+            struct_ser.serialize_field("pause", &self.pause)?;
+        }
+        if !self.unpause.is_empty() {
+            struct_ser.serialize_field("unpause", &self.unpause)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for SchedulePatch {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "trigger_immediately",
+            "triggerImmediately",
+            "backfill_request",
+            "backfillRequest",
+            "pause",
+            "unpause",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            TriggerImmediately,
+            BackfillRequest,
+            Pause,
+            Unpause,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "triggerImmediately" | "trigger_immediately" => Ok(GeneratedField::TriggerImmediately),
+                            "backfillRequest" | "backfill_request" => Ok(GeneratedField::BackfillRequest),
+                            "pause" => Ok(GeneratedField::Pause),
+                            "unpause" => Ok(GeneratedField::Unpause),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = SchedulePatch;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct temporal.api.schedule.v1.SchedulePatch")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<SchedulePatch, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut trigger_immediately__ = None;
+                let mut backfill_request__ = None;
+                let mut pause__ = None;
+                let mut unpause__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::TriggerImmediately => {
+                            if trigger_immediately__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("triggerImmediately"));
+                            }
+                            trigger_immediately__ = map_.next_value()?;
+                        }
+                        GeneratedField::BackfillRequest => {
+                            if backfill_request__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("backfillRequest"));
+                            }
+                            backfill_request__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::Pause => {
+                            if pause__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("pause"));
+                            }
+                            pause__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::Unpause => {
+                            if unpause__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("unpause"));
+                            }
+                            unpause__ = Some(map_.next_value()?);
+                        }
+                    }
+                }
+                Ok(SchedulePatch {
+                    trigger_immediately: trigger_immediately__,
+                    backfill_request: backfill_request__.unwrap_or_default(),
+                    pause: pause__.unwrap_or_default(),
+                    unpause: unpause__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("temporal.api.schedule.v1.SchedulePatch", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for SchedulePolicies {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.overlap_policy != 0 {
+            len += 1;
+        }
+        if self.catchup_window.is_some() {
+            len += 1;
+        }
+        if self.pause_on_failure {
+            len += 1;
+        }
+        if self.keep_original_workflow_id {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("temporal.api.schedule.v1.SchedulePolicies", len)?;
+        if self.overlap_policy != 0 {
+            let v = super::super::enums::v1::ScheduleOverlapPolicy::try_from(self.overlap_policy)
+                .map_err(|_| serde::ser::Error::custom(format!("Invalid variant {}", self.overlap_policy)))?;
+            struct_ser.serialize_field("overlapPolicy", &v)?;
+        }
+        if let Some(v) = self.catchup_window.as_ref() {
+            struct_ser.serialize_field("catchupWindow", v)?;
+        }
+        if self.pause_on_failure {
+            struct_ser.serialize_field("pauseOnFailure", &self.pause_on_failure)?;
+        }
+        if self.keep_original_workflow_id {
+            struct_ser.serialize_field("keepOriginalWorkflowId", &self.keep_original_workflow_id)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for SchedulePolicies {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "overlap_policy",
+            "overlapPolicy",
+            "catchup_window",
+            "catchupWindow",
+            "pause_on_failure",
+            "pauseOnFailure",
+            "keep_original_workflow_id",
+            "keepOriginalWorkflowId",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            OverlapPolicy,
+            CatchupWindow,
+            PauseOnFailure,
+            KeepOriginalWorkflowId,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "overlapPolicy" | "overlap_policy" => Ok(GeneratedField::OverlapPolicy),
+                            "catchupWindow" | "catchup_window" => Ok(GeneratedField::CatchupWindow),
+                            "pauseOnFailure" | "pause_on_failure" => Ok(GeneratedField::PauseOnFailure),
+                            "keepOriginalWorkflowId" | "keep_original_workflow_id" => Ok(GeneratedField::KeepOriginalWorkflowId),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = SchedulePolicies;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct temporal.api.schedule.v1.SchedulePolicies")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<SchedulePolicies, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut overlap_policy__ = None;
+                let mut catchup_window__ = None;
+                let mut pause_on_failure__ = None;
+                let mut keep_original_workflow_id__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::OverlapPolicy => {
+                            if overlap_policy__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("overlapPolicy"));
+                            }
+                            overlap_policy__ = Some(map_.next_value::<super::super::enums::v1::ScheduleOverlapPolicy>()? as i32);
+                        }
+                        GeneratedField::CatchupWindow => {
+                            if catchup_window__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("catchupWindow"));
+                            }
+                            catchup_window__ = map_.next_value()?;
+                        }
+                        GeneratedField::PauseOnFailure => {
+                            if pause_on_failure__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("pauseOnFailure"));
+                            }
+                            pause_on_failure__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::KeepOriginalWorkflowId => {
+                            if keep_original_workflow_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("keepOriginalWorkflowId"));
+                            }
+                            keep_original_workflow_id__ = Some(map_.next_value()?);
+                        }
+                    }
+                }
+                Ok(SchedulePolicies {
+                    overlap_policy: overlap_policy__.unwrap_or_default(),
+                    catchup_window: catchup_window__,
+                    pause_on_failure: pause_on_failure__.unwrap_or_default(),
+                    keep_original_workflow_id: keep_original_workflow_id__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("temporal.api.schedule.v1.SchedulePolicies", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for ScheduleSpec {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.structured_calendar.is_empty() {
+            len += 1;
+        }
+        if !self.cron_string.is_empty() {
+            len += 1;
+        }
+        if !self.calendar.is_empty() {
+            len += 1;
+        }
+        if !self.interval.is_empty() {
+            len += 1;
+        }
+        if !self.exclude_calendar.is_empty() {
+            len += 1;
+        }
+        if !self.exclude_structured_calendar.is_empty() {
+            len += 1;
+        }
+        if self.start_time.is_some() {
+            len += 1;
+        }
+        if self.end_time.is_some() {
+            len += 1;
+        }
+        if self.jitter.is_some() {
+            len += 1;
+        }
+        if !self.timezone_name.is_empty() {
+            len += 1;
+        }
+        if !self.timezone_data.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("temporal.api.schedule.v1.ScheduleSpec", len)?;
+        if !self.structured_calendar.is_empty() {
+            struct_ser.serialize_field("structuredCalendar", &self.structured_calendar)?;
+        }
+        if !self.cron_string.is_empty() {
+            struct_ser.serialize_field("cronString", &self.cron_string)?;
+        }
+        if !self.calendar.is_empty() {
+            struct_ser.serialize_field("calendar", &self.calendar)?;
+        }
+        if !self.interval.is_empty() {
+            struct_ser.serialize_field("interval", &self.interval)?;
+        }
+        if !self.exclude_calendar.is_empty() {
+            struct_ser.serialize_field("excludeCalendar", &self.exclude_calendar)?;
+        }
+        if !self.exclude_structured_calendar.is_empty() {
+            struct_ser.serialize_field("excludeStructuredCalendar", &self.exclude_structured_calendar)?;
+        }
+        if let Some(v) = self.start_time.as_ref() {
+            struct_ser.serialize_field("startTime", v)?;
+        }
+        if let Some(v) = self.end_time.as_ref() {
+            struct_ser.serialize_field("endTime", v)?;
+        }
+        if let Some(v) = self.jitter.as_ref() {
+            struct_ser.serialize_field("jitter", v)?;
+        }
+        if !self.timezone_name.is_empty() {
+            struct_ser.serialize_field("timezoneName", &self.timezone_name)?;
+        }
+        if !self.timezone_data.is_empty() {
+            #[allow(clippy::needless_borrow)]
+            #[allow(clippy::needless_borrows_for_generic_args)]
+            struct_ser.serialize_field("timezoneData", pbjson::private::base64::encode(&self.timezone_data).as_str())?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for ScheduleSpec {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "structured_calendar",
+            "structuredCalendar",
+            "cron_string",
+            "cronString",
+            "calendar",
+            "interval",
+            "exclude_calendar",
+            "excludeCalendar",
+            "exclude_structured_calendar",
+            "excludeStructuredCalendar",
+            "start_time",
+            "startTime",
+            "end_time",
+            "endTime",
+            "jitter",
+            "timezone_name",
+            "timezoneName",
+            "timezone_data",
+            "timezoneData",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            StructuredCalendar,
+            CronString,
+            Calendar,
+            Interval,
+            ExcludeCalendar,
+            ExcludeStructuredCalendar,
+            StartTime,
+            EndTime,
+            Jitter,
+            TimezoneName,
+            TimezoneData,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "structuredCalendar" | "structured_calendar" => Ok(GeneratedField::StructuredCalendar),
+                            "cronString" | "cron_string" => Ok(GeneratedField::CronString),
+                            "calendar" => Ok(GeneratedField::Calendar),
+                            "interval" => Ok(GeneratedField::Interval),
+                            "excludeCalendar" | "exclude_calendar" => Ok(GeneratedField::ExcludeCalendar),
+                            "excludeStructuredCalendar" | "exclude_structured_calendar" => Ok(GeneratedField::ExcludeStructuredCalendar),
+                            "startTime" | "start_time" => Ok(GeneratedField::StartTime),
+                            "endTime" | "end_time" => Ok(GeneratedField::EndTime),
+                            "jitter" => Ok(GeneratedField::Jitter),
+                            "timezoneName" | "timezone_name" => Ok(GeneratedField::TimezoneName),
+                            "timezoneData" | "timezone_data" => Ok(GeneratedField::TimezoneData),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = ScheduleSpec;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct temporal.api.schedule.v1.ScheduleSpec")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<ScheduleSpec, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut structured_calendar__ = None;
+                let mut cron_string__ = None;
+                let mut calendar__ = None;
+                let mut interval__ = None;
+                let mut exclude_calendar__ = None;
+                let mut exclude_structured_calendar__ = None;
+                let mut start_time__ = None;
+                let mut end_time__ = None;
+                let mut jitter__ = None;
+                let mut timezone_name__ = None;
+                let mut timezone_data__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::StructuredCalendar => {
+                            if structured_calendar__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("structuredCalendar"));
+                            }
+                            structured_calendar__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::CronString => {
+                            if cron_string__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("cronString"));
+                            }
+                            cron_string__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::Calendar => {
+                            if calendar__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("calendar"));
+                            }
+                            calendar__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::Interval => {
+                            if interval__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("interval"));
+                            }
+                            interval__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::ExcludeCalendar => {
+                            if exclude_calendar__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("excludeCalendar"));
+                            }
+                            exclude_calendar__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::ExcludeStructuredCalendar => {
+                            if exclude_structured_calendar__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("excludeStructuredCalendar"));
+                            }
+                            exclude_structured_calendar__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::StartTime => {
+                            if start_time__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("startTime"));
+                            }
+                            start_time__ = map_.next_value()?;
+                        }
+                        GeneratedField::EndTime => {
+                            if end_time__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("endTime"));
+                            }
+                            end_time__ = map_.next_value()?;
+                        }
+                        GeneratedField::Jitter => {
+                            if jitter__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("jitter"));
+                            }
+                            jitter__ = map_.next_value()?;
+                        }
+                        GeneratedField::TimezoneName => {
+                            if timezone_name__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("timezoneName"));
+                            }
+                            timezone_name__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::TimezoneData => {
+                            if timezone_data__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("timezoneData"));
+                            }
+                            timezone_data__ = 
+                                Some(map_.next_value::<::pbjson::private::BytesDeserialize<_>>()?.0)
+                            ;
+                        }
+                    }
+                }
+                Ok(ScheduleSpec {
+                    structured_calendar: structured_calendar__.unwrap_or_default(),
+                    cron_string: cron_string__.unwrap_or_default(),
+                    calendar: calendar__.unwrap_or_default(),
+                    interval: interval__.unwrap_or_default(),
+                    exclude_calendar: exclude_calendar__.unwrap_or_default(),
+                    exclude_structured_calendar: exclude_structured_calendar__.unwrap_or_default(),
+                    start_time: start_time__,
+                    end_time: end_time__,
+                    jitter: jitter__,
+                    timezone_name: timezone_name__.unwrap_or_default(),
+                    timezone_data: timezone_data__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("temporal.api.schedule.v1.ScheduleSpec", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for ScheduleState {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.notes.is_empty() {
+            len += 1;
+        }
+        if self.paused {
+            len += 1;
+        }
+        if self.limited_actions {
+            len += 1;
+        }
+        if self.remaining_actions != 0 {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("temporal.api.schedule.v1.ScheduleState", len)?;
+        if !self.notes.is_empty() {
+            struct_ser.serialize_field("notes", &self.notes)?;
+        }
+        if self.paused {
+            struct_ser.serialize_field("paused", &self.paused)?;
+        }
+        if self.limited_actions {
+            struct_ser.serialize_field("limitedActions", &self.limited_actions)?;
+        }
+        if self.remaining_actions != 0 {
+            #[allow(clippy::needless_borrow)]
+            #[allow(clippy::needless_borrows_for_generic_args)]
+            struct_ser.serialize_field("remainingActions", ToString::to_string(&self.remaining_actions).as_str())?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for ScheduleState {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "notes",
+            "paused",
+            "limited_actions",
+            "limitedActions",
+            "remaining_actions",
+            "remainingActions",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Notes,
+            Paused,
+            LimitedActions,
+            RemainingActions,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "notes" => Ok(GeneratedField::Notes),
+                            "paused" => Ok(GeneratedField::Paused),
+                            "limitedActions" | "limited_actions" => Ok(GeneratedField::LimitedActions),
+                            "remainingActions" | "remaining_actions" => Ok(GeneratedField::RemainingActions),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = ScheduleState;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct temporal.api.schedule.v1.ScheduleState")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<ScheduleState, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut notes__ = None;
+                let mut paused__ = None;
+                let mut limited_actions__ = None;
+                let mut remaining_actions__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Notes => {
+                            if notes__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("notes"));
+                            }
+                            notes__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::Paused => {
+                            if paused__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("paused"));
+                            }
+                            paused__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::LimitedActions => {
+                            if limited_actions__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("limitedActions"));
+                            }
+                            limited_actions__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::RemainingActions => {
+                            if remaining_actions__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("remainingActions"));
+                            }
+                            remaining_actions__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                    }
+                }
+                Ok(ScheduleState {
+                    notes: notes__.unwrap_or_default(),
+                    paused: paused__.unwrap_or_default(),
+                    limited_actions: limited_actions__.unwrap_or_default(),
+                    remaining_actions: remaining_actions__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("temporal.api.schedule.v1.ScheduleState", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for StructuredCalendarSpec {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.second.is_empty() {
+            len += 1;
+        }
+        if !self.minute.is_empty() {
+            len += 1;
+        }
+        if !self.hour.is_empty() {
+            len += 1;
+        }
+        if !self.day_of_month.is_empty() {
+            len += 1;
+        }
+        if !self.month.is_empty() {
+            len += 1;
+        }
+        if !self.year.is_empty() {
+            len += 1;
+        }
+        if !self.day_of_week.is_empty() {
+            len += 1;
+        }
+        if !self.comment.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("temporal.api.schedule.v1.StructuredCalendarSpec", len)?;
+        if !self.second.is_empty() {
+            struct_ser.serialize_field("second", &self.second)?;
+        }
+        if !self.minute.is_empty() {
+            struct_ser.serialize_field("minute", &self.minute)?;
+        }
+        if !self.hour.is_empty() {
+            struct_ser.serialize_field("hour", &self.hour)?;
+        }
+        if !self.day_of_month.is_empty() {
+            struct_ser.serialize_field("dayOfMonth", &self.day_of_month)?;
+        }
+        if !self.month.is_empty() {
+            struct_ser.serialize_field("month", &self.month)?;
+        }
+        if !self.year.is_empty() {
+            struct_ser.serialize_field("year", &self.year)?;
+        }
+        if !self.day_of_week.is_empty() {
+            struct_ser.serialize_field("dayOfWeek", &self.day_of_week)?;
+        }
+        if !self.comment.is_empty() {
+            struct_ser.serialize_field("comment", &self.comment)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for StructuredCalendarSpec {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "second",
+            "minute",
+            "hour",
+            "day_of_month",
+            "dayOfMonth",
+            "month",
+            "year",
+            "day_of_week",
+            "dayOfWeek",
+            "comment",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Second,
+            Minute,
+            Hour,
+            DayOfMonth,
+            Month,
+            Year,
+            DayOfWeek,
+            Comment,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "second" => Ok(GeneratedField::Second),
+                            "minute" => Ok(GeneratedField::Minute),
+                            "hour" => Ok(GeneratedField::Hour),
+                            "dayOfMonth" | "day_of_month" => Ok(GeneratedField::DayOfMonth),
+                            "month" => Ok(GeneratedField::Month),
+                            "year" => Ok(GeneratedField::Year),
+                            "dayOfWeek" | "day_of_week" => Ok(GeneratedField::DayOfWeek),
+                            "comment" => Ok(GeneratedField::Comment),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = StructuredCalendarSpec;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct temporal.api.schedule.v1.StructuredCalendarSpec")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<StructuredCalendarSpec, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut second__ = None;
+                let mut minute__ = None;
+                let mut hour__ = None;
+                let mut day_of_month__ = None;
+                let mut month__ = None;
+                let mut year__ = None;
+                let mut day_of_week__ = None;
+                let mut comment__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Second => {
+                            if second__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("second"));
+                            }
+                            second__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::Minute => {
+                            if minute__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("minute"));
+                            }
+                            minute__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::Hour => {
+                            if hour__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("hour"));
+                            }
+                            hour__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::DayOfMonth => {
+                            if day_of_month__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("dayOfMonth"));
+                            }
+                            day_of_month__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::Month => {
+                            if month__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("month"));
+                            }
+                            month__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::Year => {
+                            if year__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("year"));
+                            }
+                            year__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::DayOfWeek => {
+                            if day_of_week__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("dayOfWeek"));
+                            }
+                            day_of_week__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::Comment => {
+                            if comment__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("comment"));
+                            }
+                            comment__ = Some(map_.next_value()?);
+                        }
+                    }
+                }
+                Ok(StructuredCalendarSpec {
+                    second: second__.unwrap_or_default(),
+                    minute: minute__.unwrap_or_default(),
+                    hour: hour__.unwrap_or_default(),
+                    day_of_month: day_of_month__.unwrap_or_default(),
+                    month: month__.unwrap_or_default(),
+                    year: year__.unwrap_or_default(),
+                    day_of_week: day_of_week__.unwrap_or_default(),
+                    comment: comment__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("temporal.api.schedule.v1.StructuredCalendarSpec", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for TriggerImmediatelyRequest {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.overlap_policy != 0 {
+            len += 1;
+        }
+        if self.scheduled_time.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("temporal.api.schedule.v1.TriggerImmediatelyRequest", len)?;
+        if self.overlap_policy != 0 {
+            let v = super::super::enums::v1::ScheduleOverlapPolicy::try_from(self.overlap_policy)
+                .map_err(|_| serde::ser::Error::custom(format!("Invalid variant {}", self.overlap_policy)))?;
+            struct_ser.serialize_field("overlapPolicy", &v)?;
+        }
+        if let Some(v) = self.scheduled_time.as_ref() {
+            struct_ser.serialize_field("scheduledTime", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for TriggerImmediatelyRequest {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "overlap_policy",
+            "overlapPolicy",
+            "scheduled_time",
+            "scheduledTime",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            OverlapPolicy,
+            ScheduledTime,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "overlapPolicy" | "overlap_policy" => Ok(GeneratedField::OverlapPolicy),
+                            "scheduledTime" | "scheduled_time" => Ok(GeneratedField::ScheduledTime),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = TriggerImmediatelyRequest;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct temporal.api.schedule.v1.TriggerImmediatelyRequest")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<TriggerImmediatelyRequest, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut overlap_policy__ = None;
+                let mut scheduled_time__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::OverlapPolicy => {
+                            if overlap_policy__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("overlapPolicy"));
+                            }
+                            overlap_policy__ = Some(map_.next_value::<super::super::enums::v1::ScheduleOverlapPolicy>()? as i32);
+                        }
+                        GeneratedField::ScheduledTime => {
+                            if scheduled_time__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("scheduledTime"));
+                            }
+                            scheduled_time__ = map_.next_value()?;
+                        }
+                    }
+                }
+                Ok(TriggerImmediatelyRequest {
+                    overlap_policy: overlap_policy__.unwrap_or_default(),
+                    scheduled_time: scheduled_time__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("temporal.api.schedule.v1.TriggerImmediatelyRequest", FIELDS, GeneratedVisitor)
+    }
+}
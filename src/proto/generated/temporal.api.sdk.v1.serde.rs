@@ -0,0 +1,619 @@
+impl serde::Serialize for UserMetadata {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.summary.is_some() {
+            len += 1;
+        }
+        if self.details.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("temporal.api.sdk.v1.UserMetadata", len)?;
+        if let Some(v) = self.summary.as_ref() {
+            struct_ser.serialize_field("summary", v)?;
+        }
+        if let Some(v) = self.details.as_ref() {
+            struct_ser.serialize_field("details", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for UserMetadata {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "summary",
+            "details",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Summary,
+            Details,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "summary" => Ok(GeneratedField::Summary),
+                            "details" => Ok(GeneratedField::Details),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = UserMetadata;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct temporal.api.sdk.v1.UserMetadata")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<UserMetadata, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut summary__ = None;
+                let mut details__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Summary => {
+                            if summary__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("summary"));
+                            }
+                            summary__ = map_.next_value()?;
+                        }
+                        GeneratedField::Details => {
+                            if details__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("details"));
+                            }
+                            details__ = map_.next_value()?;
+                        }
+                    }
+                }
+                Ok(UserMetadata {
+                    summary: summary__,
+                    details: details__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("temporal.api.sdk.v1.UserMetadata", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for WorkerConfig {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.workflow_cache_size != 0 {
+            len += 1;
+        }
+        if self.poller_behavior.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("temporal.api.sdk.v1.WorkerConfig", len)?;
+        if self.workflow_cache_size != 0 {
+            struct_ser.serialize_field("workflowCacheSize", &self.workflow_cache_size)?;
+        }
+        if let Some(v) = self.poller_behavior.as_ref() {
+            match v {
+                worker_config::PollerBehavior::SimplePollerBehavior(v) => {
+                    struct_ser.serialize_field("simplePollerBehavior", v)?;
+                }
+                worker_config::PollerBehavior::AutoscalingPollerBehavior(v) => {
+                    struct_ser.serialize_field("autoscalingPollerBehavior", v)?;
+                }
+            }
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for WorkerConfig {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "workflow_cache_size",
+            "workflowCacheSize",
+            "simple_poller_behavior",
+            "simplePollerBehavior",
+            "autoscaling_poller_behavior",
+            "autoscalingPollerBehavior",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            WorkflowCacheSize,
+            SimplePollerBehavior,
+            AutoscalingPollerBehavior,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "workflowCacheSize" | "workflow_cache_size" => Ok(GeneratedField::WorkflowCacheSize),
+                            "simplePollerBehavior" | "simple_poller_behavior" => Ok(GeneratedField::SimplePollerBehavior),
+                            "autoscalingPollerBehavior" | "autoscaling_poller_behavior" => Ok(GeneratedField::AutoscalingPollerBehavior),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = WorkerConfig;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct temporal.api.sdk.v1.WorkerConfig")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<WorkerConfig, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut workflow_cache_size__ = None;
+                let mut poller_behavior__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::WorkflowCacheSize => {
+                            if workflow_cache_size__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("workflowCacheSize"));
+                            }
+                            workflow_cache_size__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::SimplePollerBehavior => {
+                            if poller_behavior__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("simplePollerBehavior"));
+                            }
+                            poller_behavior__ = map_.next_value::<::std::option::Option<_>>()?.map(worker_config::PollerBehavior::SimplePollerBehavior)
+;
+                        }
+                        GeneratedField::AutoscalingPollerBehavior => {
+                            if poller_behavior__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("autoscalingPollerBehavior"));
+                            }
+                            poller_behavior__ = map_.next_value::<::std::option::Option<_>>()?.map(worker_config::PollerBehavior::AutoscalingPollerBehavior)
+;
+                        }
+                    }
+                }
+                Ok(WorkerConfig {
+                    workflow_cache_size: workflow_cache_size__.unwrap_or_default(),
+                    poller_behavior: poller_behavior__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("temporal.api.sdk.v1.WorkerConfig", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for worker_config::AutoscalingPollerBehavior {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.min_pollers != 0 {
+            len += 1;
+        }
+        if self.max_pollers != 0 {
+            len += 1;
+        }
+        if self.initial_pollers != 0 {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("temporal.api.sdk.v1.WorkerConfig.AutoscalingPollerBehavior", len)?;
+        if self.min_pollers != 0 {
+            struct_ser.serialize_field("minPollers", &self.min_pollers)?;
+        }
+        if self.max_pollers != 0 {
+            struct_ser.serialize_field("maxPollers", &self.max_pollers)?;
+        }
+        if self.initial_pollers != 0 {
+            struct_ser.serialize_field("initialPollers", &self.initial_pollers)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for worker_config::AutoscalingPollerBehavior {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "min_pollers",
+            "minPollers",
+            "max_pollers",
+            "maxPollers",
+            "initial_pollers",
+            "initialPollers",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            MinPollers,
+            MaxPollers,
+            InitialPollers,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "minPollers" | "min_pollers" => Ok(GeneratedField::MinPollers),
+                            "maxPollers" | "max_pollers" => Ok(GeneratedField::MaxPollers),
+                            "initialPollers" | "initial_pollers" => Ok(GeneratedField::InitialPollers),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = worker_config::AutoscalingPollerBehavior;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct temporal.api.sdk.v1.WorkerConfig.AutoscalingPollerBehavior")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<worker_config::AutoscalingPollerBehavior, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut min_pollers__ = None;
+                let mut max_pollers__ = None;
+                let mut initial_pollers__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::MinPollers => {
+                            if min_pollers__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("minPollers"));
+                            }
+                            min_pollers__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::MaxPollers => {
+                            if max_pollers__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("maxPollers"));
+                            }
+                            max_pollers__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::InitialPollers => {
+                            if initial_pollers__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("initialPollers"));
+                            }
+                            initial_pollers__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                    }
+                }
+                Ok(worker_config::AutoscalingPollerBehavior {
+                    min_pollers: min_pollers__.unwrap_or_default(),
+                    max_pollers: max_pollers__.unwrap_or_default(),
+                    initial_pollers: initial_pollers__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("temporal.api.sdk.v1.WorkerConfig.AutoscalingPollerBehavior", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for worker_config::SimplePollerBehavior {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.max_pollers != 0 {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("temporal.api.sdk.v1.WorkerConfig.SimplePollerBehavior", len)?;
+        if self.max_pollers != 0 {
+            struct_ser.serialize_field("maxPollers", &self.max_pollers)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for worker_config::SimplePollerBehavior {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "max_pollers",
+            "maxPollers",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            MaxPollers,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "maxPollers" | "max_pollers" => Ok(GeneratedField::MaxPollers),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = worker_config::SimplePollerBehavior;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct temporal.api.sdk.v1.WorkerConfig.SimplePollerBehavior")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<worker_config::SimplePollerBehavior, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut max_pollers__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::MaxPollers => {
+                            if max_pollers__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("maxPollers"));
+                            }
+                            max_pollers__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                    }
+                }
+                Ok(worker_config::SimplePollerBehavior {
+                    max_pollers: max_pollers__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("temporal.api.sdk.v1.WorkerConfig.SimplePollerBehavior", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for WorkflowTaskCompletedMetadata {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.core_used_flags.is_empty() {
+            len += 1;
+        }
+        if !self.lang_used_flags.is_empty() {
+            len += 1;
+        }
+        if !self.sdk_name.is_empty() {
+            len += 1;
+        }
+        if !self.sdk_version.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("temporal.api.sdk.v1.WorkflowTaskCompletedMetadata", len)?;
+        if !self.core_used_flags.is_empty() {
+            struct_ser.serialize_field("coreUsedFlags", &self.core_used_flags)?;
+        }
+        if !self.lang_used_flags.is_empty() {
+            struct_ser.serialize_field("langUsedFlags", &self.lang_used_flags)?;
+        }
+        if !self.sdk_name.is_empty() {
+            struct_ser.serialize_field("sdkName", &self.sdk_name)?;
+        }
+        if !self.sdk_version.is_empty() {
+            struct_ser.serialize_field("sdkVersion", &self.sdk_version)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for WorkflowTaskCompletedMetadata {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "core_used_flags",
+            "coreUsedFlags",
+            "lang_used_flags",
+            "langUsedFlags",
+            "sdk_name",
+            "sdkName",
+            "sdk_version",
+            "sdkVersion",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            CoreUsedFlags,
+            LangUsedFlags,
+            SdkName,
+            SdkVersion,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "coreUsedFlags" | "core_used_flags" => Ok(GeneratedField::CoreUsedFlags),
+                            "langUsedFlags" | "lang_used_flags" => Ok(GeneratedField::LangUsedFlags),
+                            "sdkName" | "sdk_name" => Ok(GeneratedField::SdkName),
+                            "sdkVersion" | "sdk_version" => Ok(GeneratedField::SdkVersion),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = WorkflowTaskCompletedMetadata;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct temporal.api.sdk.v1.WorkflowTaskCompletedMetadata")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<WorkflowTaskCompletedMetadata, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut core_used_flags__ = None;
+                let mut lang_used_flags__ = None;
+                let mut sdk_name__ = None;
+                let mut sdk_version__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::CoreUsedFlags => {
+                            if core_used_flags__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("coreUsedFlags"));
+                            }
+                            core_used_flags__ = 
+                                Some(map_.next_value::<Vec<::pbjson::private::NumberDeserialize<_>>>()?
+                                    .into_iter().map(|x| x.0).collect())
+                            ;
+                        }
+                        GeneratedField::LangUsedFlags => {
+                            if lang_used_flags__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("langUsedFlags"));
+                            }
+                            lang_used_flags__ = 
+                                Some(map_.next_value::<Vec<::pbjson::private::NumberDeserialize<_>>>()?
+                                    .into_iter().map(|x| x.0).collect())
+                            ;
+                        }
+                        GeneratedField::SdkName => {
+                            if sdk_name__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("sdkName"));
+                            }
+                            sdk_name__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::SdkVersion => {
+                            if sdk_version__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("sdkVersion"));
+                            }
+                            sdk_version__ = Some(map_.next_value()?);
+                        }
+                    }
+                }
+                Ok(WorkflowTaskCompletedMetadata {
+                    core_used_flags: core_used_flags__.unwrap_or_default(),
+                    lang_used_flags: lang_used_flags__.unwrap_or_default(),
+                    sdk_name: sdk_name__.unwrap_or_default(),
+                    sdk_version: sdk_version__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("temporal.api.sdk.v1.WorkflowTaskCompletedMetadata", FIELDS, GeneratedVisitor)
+    }
+}
@@ -17,9 +17,8 @@ pub struct TaskQueue {
 pub struct TaskQueueMetadata {
     /// Allows throttling dispatch of tasks from this queue
     #[prost(message, optional, tag = "1")]
-    pub max_tasks_per_second: ::core::option::Option<f64>,
+    pub max_tasks_per_second: ::core::option::Option<::pbjson_types::DoubleValue>,
 }
-/// Experimental. Worker Deployments are experimental and might significantly change in the future.
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct TaskQueueVersioningInfo {
     /// Specifies which Deployment Version should receive new workflow executions and tasks of
@@ -56,7 +55,7 @@ pub struct TaskQueueVersioningInfo {
     pub ramping_version_percentage: f32,
     /// Last time versioning information of this Task Queue changed.
     #[prost(message, optional, tag = "4")]
-    pub update_time: ::core::option::Option<::prost_types::Timestamp>,
+    pub update_time: ::core::option::Option<::pbjson_types::Timestamp>,
 }
 /// Used for specifying versions the caller is interested in.
 #[derive(Clone, PartialEq, ::prost::Message)]
@@ -118,7 +117,7 @@ pub struct TaskQueueStats {
     /// those tasks only remain valid for a few seconds, they should not affect the result when backlog is older than
     /// few seconds.
     #[prost(message, optional, tag = "2")]
-    pub approximate_backlog_age: ::core::option::Option<::prost_types::Duration>,
+    pub approximate_backlog_age: ::core::option::Option<::pbjson_types::Duration>,
     /// The approximate tasks per second added to the task queue, averaging the last 30 seconds. These includes tasks
     /// whether or not they were added to/dispatched from the backlog or they were dispatched immediately without going
     /// to the backlog (sync-matched).
@@ -183,7 +182,7 @@ pub struct TaskQueuePartitionMetadata {
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct PollerInfo {
     #[prost(message, optional, tag = "1")]
-    pub last_access_time: ::core::option::Option<::prost_types::Timestamp>,
+    pub last_access_time: ::core::option::Option<::pbjson_types::Timestamp>,
     #[prost(string, tag = "2")]
     pub identity: ::prost::alloc::string::String,
     #[prost(double, tag = "3")]
@@ -209,7 +208,7 @@ pub struct StickyExecutionAttributes {
     /// (-- api-linter: core::0140::prepositions=disabled
     ///      aip.dev/not-precedent: "to" is used to indicate interval. --)
     #[prost(message, optional, tag = "2")]
-    pub schedule_to_start_timeout: ::core::option::Option<::prost_types::Duration>,
+    pub schedule_to_start_timeout: ::core::option::Option<::pbjson_types::Duration>,
 }
 /// Used by the worker versioning APIs, represents an unordered set of one or more versions which are
 /// considered to be compatible with each other. Currently the versions are always worker build IDs.
@@ -349,14 +348,21 @@ pub struct TimestampedBuildIdAssignmentRule {
     #[prost(message, optional, tag = "1")]
     pub rule: ::core::option::Option<BuildIdAssignmentRule>,
     #[prost(message, optional, tag = "2")]
-    pub create_time: ::core::option::Option<::prost_types::Timestamp>,
+    pub create_time: ::core::option::Option<::pbjson_types::Timestamp>,
 }
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct TimestampedCompatibleBuildIdRedirectRule {
     #[prost(message, optional, tag = "1")]
     pub rule: ::core::option::Option<CompatibleBuildIdRedirectRule>,
     #[prost(message, optional, tag = "2")]
-    pub create_time: ::core::option::Option<::prost_types::Timestamp>,
+    pub create_time: ::core::option::Option<::pbjson_types::Timestamp>,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PollerGroupInfo {
+    #[prost(string, tag = "1")]
+    pub id: ::prost::alloc::string::String,
+    #[prost(float, tag = "2")]
+    pub weight: f32,
 }
 /// Attached to task responses to give hints to the SDK about how it may adjust its number of
 /// pollers.
@@ -388,7 +394,7 @@ pub struct ConfigMetadata {
     pub update_identity: ::prost::alloc::string::String,
     /// Time of the last update.
     #[prost(message, optional, tag = "3")]
-    pub update_time: ::core::option::Option<::prost_types::Timestamp>,
+    pub update_time: ::core::option::Option<::pbjson_types::Timestamp>,
 }
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct RateLimitConfig {
@@ -0,0 +1,3105 @@
+impl serde::Serialize for BuildIdAssignmentRule {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.target_build_id.is_empty() {
+            len += 1;
+        }
+        if self.ramp.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("temporal.api.taskqueue.v1.BuildIdAssignmentRule", len)?;
+        if !self.target_build_id.is_empty() {
+            struct_ser.serialize_field("targetBuildId", &self.target_build_id)?;
+        }
+        if let Some(v) = self.ramp.as_ref() {
+            match v {
+                build_id_assignment_rule::Ramp::PercentageRamp(v) => {
+                    struct_ser.serialize_field("percentageRamp", v)?;
+                }
+            }
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for BuildIdAssignmentRule {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "target_build_id",
+            "targetBuildId",
+            "percentage_ramp",
+            "percentageRamp",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            TargetBuildId,
+            PercentageRamp,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "targetBuildId" | "target_build_id" => Ok(GeneratedField::TargetBuildId),
+                            "percentageRamp" | "percentage_ramp" => Ok(GeneratedField::PercentageRamp),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = BuildIdAssignmentRule;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct temporal.api.taskqueue.v1.BuildIdAssignmentRule")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<BuildIdAssignmentRule, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut target_build_id__ = None;
+                let mut ramp__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::TargetBuildId => {
+                            if target_build_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("targetBuildId"));
+                            }
+                            target_build_id__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::PercentageRamp => {
+                            if ramp__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("percentageRamp"));
+                            }
+                            ramp__ = map_.next_value::<::std::option::Option<_>>()?.map(build_id_assignment_rule::Ramp::PercentageRamp)
+;
+                        }
+                    }
+                }
+                Ok(BuildIdAssignmentRule {
+                    target_build_id: target_build_id__.unwrap_or_default(),
+                    ramp: ramp__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("temporal.api.taskqueue.v1.BuildIdAssignmentRule", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for BuildIdReachability {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.build_id.is_empty() {
+            len += 1;
+        }
+        if !self.task_queue_reachability.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("temporal.api.taskqueue.v1.BuildIdReachability", len)?;
+        if !self.build_id.is_empty() {
+            struct_ser.serialize_field("buildId", &self.build_id)?;
+        }
+        if !self.task_queue_reachability.is_empty() {
+            struct_ser.serialize_field("taskQueueReachability", &self.task_queue_reachability)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for BuildIdReachability {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "build_id",
+            "buildId",
+            "task_queue_reachability",
+            "taskQueueReachability",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            BuildId,
+            TaskQueueReachability,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "buildId" | "build_id" => Ok(GeneratedField::BuildId),
+                            "taskQueueReachability" | "task_queue_reachability" => Ok(GeneratedField::TaskQueueReachability),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = BuildIdReachability;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct temporal.api.taskqueue.v1.BuildIdReachability")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<BuildIdReachability, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut build_id__ = None;
+                let mut task_queue_reachability__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::BuildId => {
+                            if build_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("buildId"));
+                            }
+                            build_id__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::TaskQueueReachability => {
+                            if task_queue_reachability__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("taskQueueReachability"));
+                            }
+                            task_queue_reachability__ = Some(map_.next_value()?);
+                        }
+                    }
+                }
+                Ok(BuildIdReachability {
+                    build_id: build_id__.unwrap_or_default(),
+                    task_queue_reachability: task_queue_reachability__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("temporal.api.taskqueue.v1.BuildIdReachability", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for CompatibleBuildIdRedirectRule {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.source_build_id.is_empty() {
+            len += 1;
+        }
+        if !self.target_build_id.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("temporal.api.taskqueue.v1.CompatibleBuildIdRedirectRule", len)?;
+        if !self.source_build_id.is_empty() {
+            struct_ser.serialize_field("sourceBuildId", &self.source_build_id)?;
+        }
+        if !self.target_build_id.is_empty() {
+            struct_ser.serialize_field("targetBuildId", &self.target_build_id)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for CompatibleBuildIdRedirectRule {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "source_build_id",
+            "sourceBuildId",
+            "target_build_id",
+            "targetBuildId",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            SourceBuildId,
+            TargetBuildId,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "sourceBuildId" | "source_build_id" => Ok(GeneratedField::SourceBuildId),
+                            "targetBuildId" | "target_build_id" => Ok(GeneratedField::TargetBuildId),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = CompatibleBuildIdRedirectRule;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct temporal.api.taskqueue.v1.CompatibleBuildIdRedirectRule")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<CompatibleBuildIdRedirectRule, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut source_build_id__ = None;
+                let mut target_build_id__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::SourceBuildId => {
+                            if source_build_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("sourceBuildId"));
+                            }
+                            source_build_id__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::TargetBuildId => {
+                            if target_build_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("targetBuildId"));
+                            }
+                            target_build_id__ = Some(map_.next_value()?);
+                        }
+                    }
+                }
+                Ok(CompatibleBuildIdRedirectRule {
+                    source_build_id: source_build_id__.unwrap_or_default(),
+                    target_build_id: target_build_id__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("temporal.api.taskqueue.v1.CompatibleBuildIdRedirectRule", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for CompatibleVersionSet {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.build_ids.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("temporal.api.taskqueue.v1.CompatibleVersionSet", len)?;
+        if !self.build_ids.is_empty() {
+            struct_ser.serialize_field("buildIds", &self.build_ids)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for CompatibleVersionSet {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "build_ids",
+            "buildIds",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            BuildIds,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "buildIds" | "build_ids" => Ok(GeneratedField::BuildIds),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = CompatibleVersionSet;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct temporal.api.taskqueue.v1.CompatibleVersionSet")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<CompatibleVersionSet, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut build_ids__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::BuildIds => {
+                            if build_ids__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("buildIds"));
+                            }
+                            build_ids__ = Some(map_.next_value()?);
+                        }
+                    }
+                }
+                Ok(CompatibleVersionSet {
+                    build_ids: build_ids__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("temporal.api.taskqueue.v1.CompatibleVersionSet", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for ConfigMetadata {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.reason.is_empty() {
+            len += 1;
+        }
+        if !self.update_identity.is_empty() {
+            len += 1;
+        }
+        if self.update_time.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("temporal.api.taskqueue.v1.ConfigMetadata", len)?;
+        if !self.reason.is_empty() {
+            struct_ser.serialize_field("reason", &self.reason)?;
+        }
+        if !self.update_identity.is_empty() {
+            struct_ser.serialize_field("updateIdentity", &self.update_identity)?;
+        }
+        if let Some(v) = self.update_time.as_ref() {
+            struct_ser.serialize_field("updateTime", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for ConfigMetadata {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "reason",
+            "update_identity",
+            "updateIdentity",
+            "update_time",
+            "updateTime",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Reason,
+            UpdateIdentity,
+            UpdateTime,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "reason" => Ok(GeneratedField::Reason),
+                            "updateIdentity" | "update_identity" => Ok(GeneratedField::UpdateIdentity),
+                            "updateTime" | "update_time" => Ok(GeneratedField::UpdateTime),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = ConfigMetadata;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct temporal.api.taskqueue.v1.ConfigMetadata")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<ConfigMetadata, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut reason__ = None;
+                let mut update_identity__ = None;
+                let mut update_time__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Reason => {
+                            if reason__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("reason"));
+                            }
+                            reason__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::UpdateIdentity => {
+                            if update_identity__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("updateIdentity"));
+                            }
+                            update_identity__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::UpdateTime => {
+                            if update_time__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("updateTime"));
+                            }
+                            update_time__ = map_.next_value()?;
+                        }
+                    }
+                }
+                Ok(ConfigMetadata {
+                    reason: reason__.unwrap_or_default(),
+                    update_identity: update_identity__.unwrap_or_default(),
+                    update_time: update_time__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("temporal.api.taskqueue.v1.ConfigMetadata", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for PollerGroupInfo {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.id.is_empty() {
+            len += 1;
+        }
+        if self.weight != 0. {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("temporal.api.taskqueue.v1.PollerGroupInfo", len)?;
+        if !self.id.is_empty() {
+            struct_ser.serialize_field("id", &self.id)?;
+        }
+        if self.weight != 0. {
+            struct_ser.serialize_field("weight", &self.weight)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for PollerGroupInfo {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "id",
+            "weight",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Id,
+            Weight,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "id" => Ok(GeneratedField::Id),
+                            "weight" => Ok(GeneratedField::Weight),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = PollerGroupInfo;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct temporal.api.taskqueue.v1.PollerGroupInfo")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<PollerGroupInfo, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut id__ = None;
+                let mut weight__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Id => {
+                            if id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("id"));
+                            }
+                            id__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::Weight => {
+                            if weight__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("weight"));
+                            }
+                            weight__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                    }
+                }
+                Ok(PollerGroupInfo {
+                    id: id__.unwrap_or_default(),
+                    weight: weight__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("temporal.api.taskqueue.v1.PollerGroupInfo", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for PollerInfo {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.last_access_time.is_some() {
+            len += 1;
+        }
+        if !self.identity.is_empty() {
+            len += 1;
+        }
+        if self.rate_per_second != 0. {
+            len += 1;
+        }
+        if self.worker_version_capabilities.is_some() {
+            len += 1;
+        }
+        if self.deployment_options.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("temporal.api.taskqueue.v1.PollerInfo", len)?;
+        if let Some(v) = self.last_access_time.as_ref() {
+            struct_ser.serialize_field("lastAccessTime", v)?;
+        }
+        if !self.identity.is_empty() {
+            struct_ser.serialize_field("identity", &self.identity)?;
+        }
+        if self.rate_per_second != 0. {
+            struct_ser.serialize_field("ratePerSecond", &self.rate_per_second)?;
+        }
+        if let Some(v) = self.worker_version_capabilities.as_ref() {
+            struct_ser.serialize_field("workerVersionCapabilities", v)?;
+        }
+        if let Some(v) = self.deployment_options.as_ref() {
+            struct_ser.serialize_field("deploymentOptions", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for PollerInfo {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "last_access_time",
+            "lastAccessTime",
+            "identity",
+            "rate_per_second",
+            "ratePerSecond",
+            "worker_version_capabilities",
+            "workerVersionCapabilities",
+            "deployment_options",
+            "deploymentOptions",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            LastAccessTime,
+            Identity,
+            RatePerSecond,
+            WorkerVersionCapabilities,
+            DeploymentOptions,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "lastAccessTime" | "last_access_time" => Ok(GeneratedField::LastAccessTime),
+                            "identity" => Ok(GeneratedField::Identity),
+                            "ratePerSecond" | "rate_per_second" => Ok(GeneratedField::RatePerSecond),
+                            "workerVersionCapabilities" | "worker_version_capabilities" => Ok(GeneratedField::WorkerVersionCapabilities),
+                            "deploymentOptions" | "deployment_options" => Ok(GeneratedField::DeploymentOptions),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = PollerInfo;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct temporal.api.taskqueue.v1.PollerInfo")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<PollerInfo, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut last_access_time__ = None;
+                let mut identity__ = None;
+                let mut rate_per_second__ = None;
+                let mut worker_version_capabilities__ = None;
+                let mut deployment_options__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::LastAccessTime => {
+                            if last_access_time__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("lastAccessTime"));
+                            }
+                            last_access_time__ = map_.next_value()?;
+                        }
+                        GeneratedField::Identity => {
+                            if identity__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("identity"));
+                            }
+                            identity__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::RatePerSecond => {
+                            if rate_per_second__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("ratePerSecond"));
+                            }
+                            rate_per_second__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::WorkerVersionCapabilities => {
+                            if worker_version_capabilities__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("workerVersionCapabilities"));
+                            }
+                            worker_version_capabilities__ = map_.next_value()?;
+                        }
+                        GeneratedField::DeploymentOptions => {
+                            if deployment_options__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("deploymentOptions"));
+                            }
+                            deployment_options__ = map_.next_value()?;
+                        }
+                    }
+                }
+                Ok(PollerInfo {
+                    last_access_time: last_access_time__,
+                    identity: identity__.unwrap_or_default(),
+                    rate_per_second: rate_per_second__.unwrap_or_default(),
+                    worker_version_capabilities: worker_version_capabilities__,
+                    deployment_options: deployment_options__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("temporal.api.taskqueue.v1.PollerInfo", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for PollerScalingDecision {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.poll_request_delta_suggestion != 0 {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("temporal.api.taskqueue.v1.PollerScalingDecision", len)?;
+        if self.poll_request_delta_suggestion != 0 {
+            struct_ser.serialize_field("pollRequestDeltaSuggestion", &self.poll_request_delta_suggestion)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for PollerScalingDecision {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "poll_request_delta_suggestion",
+            "pollRequestDeltaSuggestion",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            PollRequestDeltaSuggestion,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "pollRequestDeltaSuggestion" | "poll_request_delta_suggestion" => Ok(GeneratedField::PollRequestDeltaSuggestion),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = PollerScalingDecision;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct temporal.api.taskqueue.v1.PollerScalingDecision")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<PollerScalingDecision, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut poll_request_delta_suggestion__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::PollRequestDeltaSuggestion => {
+                            if poll_request_delta_suggestion__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("pollRequestDeltaSuggestion"));
+                            }
+                            poll_request_delta_suggestion__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                    }
+                }
+                Ok(PollerScalingDecision {
+                    poll_request_delta_suggestion: poll_request_delta_suggestion__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("temporal.api.taskqueue.v1.PollerScalingDecision", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for RampByPercentage {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.ramp_percentage != 0. {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("temporal.api.taskqueue.v1.RampByPercentage", len)?;
+        if self.ramp_percentage != 0. {
+            struct_ser.serialize_field("rampPercentage", &self.ramp_percentage)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for RampByPercentage {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "ramp_percentage",
+            "rampPercentage",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            RampPercentage,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "rampPercentage" | "ramp_percentage" => Ok(GeneratedField::RampPercentage),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = RampByPercentage;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct temporal.api.taskqueue.v1.RampByPercentage")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<RampByPercentage, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut ramp_percentage__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::RampPercentage => {
+                            if ramp_percentage__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("rampPercentage"));
+                            }
+                            ramp_percentage__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                    }
+                }
+                Ok(RampByPercentage {
+                    ramp_percentage: ramp_percentage__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("temporal.api.taskqueue.v1.RampByPercentage", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for RateLimit {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.requests_per_second != 0. {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("temporal.api.taskqueue.v1.RateLimit", len)?;
+        if self.requests_per_second != 0. {
+            struct_ser.serialize_field("requestsPerSecond", &self.requests_per_second)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for RateLimit {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "requests_per_second",
+            "requestsPerSecond",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            RequestsPerSecond,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "requestsPerSecond" | "requests_per_second" => Ok(GeneratedField::RequestsPerSecond),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = RateLimit;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct temporal.api.taskqueue.v1.RateLimit")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<RateLimit, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut requests_per_second__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::RequestsPerSecond => {
+                            if requests_per_second__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("requestsPerSecond"));
+                            }
+                            requests_per_second__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                    }
+                }
+                Ok(RateLimit {
+                    requests_per_second: requests_per_second__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("temporal.api.taskqueue.v1.RateLimit", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for RateLimitConfig {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.rate_limit.is_some() {
+            len += 1;
+        }
+        if self.metadata.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("temporal.api.taskqueue.v1.RateLimitConfig", len)?;
+        if let Some(v) = self.rate_limit.as_ref() {
+            struct_ser.serialize_field("rateLimit", v)?;
+        }
+        if let Some(v) = self.metadata.as_ref() {
+            struct_ser.serialize_field("metadata", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for RateLimitConfig {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "rate_limit",
+            "rateLimit",
+            "metadata",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            RateLimit,
+            Metadata,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "rateLimit" | "rate_limit" => Ok(GeneratedField::RateLimit),
+                            "metadata" => Ok(GeneratedField::Metadata),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = RateLimitConfig;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct temporal.api.taskqueue.v1.RateLimitConfig")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<RateLimitConfig, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut rate_limit__ = None;
+                let mut metadata__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::RateLimit => {
+                            if rate_limit__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("rateLimit"));
+                            }
+                            rate_limit__ = map_.next_value()?;
+                        }
+                        GeneratedField::Metadata => {
+                            if metadata__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("metadata"));
+                            }
+                            metadata__ = map_.next_value()?;
+                        }
+                    }
+                }
+                Ok(RateLimitConfig {
+                    rate_limit: rate_limit__,
+                    metadata: metadata__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("temporal.api.taskqueue.v1.RateLimitConfig", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for StickyExecutionAttributes {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.worker_task_queue.is_some() {
+            len += 1;
+        }
+        if self.schedule_to_start_timeout.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("temporal.api.taskqueue.v1.StickyExecutionAttributes", len)?;
+        if let Some(v) = self.worker_task_queue.as_ref() {
+            struct_ser.serialize_field("workerTaskQueue", v)?;
+        }
+        if let Some(v) = self.schedule_to_start_timeout.as_ref() {
+            struct_ser.serialize_field("scheduleToStartTimeout", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for StickyExecutionAttributes {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "worker_task_queue",
+            "workerTaskQueue",
+            "schedule_to_start_timeout",
+            "scheduleToStartTimeout",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            WorkerTaskQueue,
+            ScheduleToStartTimeout,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "workerTaskQueue" | "worker_task_queue" => Ok(GeneratedField::WorkerTaskQueue),
+                            "scheduleToStartTimeout" | "schedule_to_start_timeout" => Ok(GeneratedField::ScheduleToStartTimeout),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = StickyExecutionAttributes;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct temporal.api.taskqueue.v1.StickyExecutionAttributes")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<StickyExecutionAttributes, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut worker_task_queue__ = None;
+                let mut schedule_to_start_timeout__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::WorkerTaskQueue => {
+                            if worker_task_queue__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("workerTaskQueue"));
+                            }
+                            worker_task_queue__ = map_.next_value()?;
+                        }
+                        GeneratedField::ScheduleToStartTimeout => {
+                            if schedule_to_start_timeout__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("scheduleToStartTimeout"));
+                            }
+                            schedule_to_start_timeout__ = map_.next_value()?;
+                        }
+                    }
+                }
+                Ok(StickyExecutionAttributes {
+                    worker_task_queue: worker_task_queue__,
+                    schedule_to_start_timeout: schedule_to_start_timeout__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("temporal.api.taskqueue.v1.StickyExecutionAttributes", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for TaskIdBlock {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.start_id != 0 {
+            len += 1;
+        }
+        if self.end_id != 0 {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("temporal.api.taskqueue.v1.TaskIdBlock", len)?;
+        if self.start_id != 0 {
+            #[allow(clippy::needless_borrow)]
+            #[allow(clippy::needless_borrows_for_generic_args)]
+            struct_ser.serialize_field("startId", ToString::to_string(&self.start_id).as_str())?;
+        }
+        if self.end_id != 0 {
+            #[allow(clippy::needless_borrow)]
+            #[allow(clippy::needless_borrows_for_generic_args)]
+            struct_ser.serialize_field("endId", ToString::to_string(&self.end_id).as_str())?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for TaskIdBlock {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "start_id",
+            "startId",
+            "end_id",
+            "endId",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            StartId,
+            EndId,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "startId" | "start_id" => Ok(GeneratedField::StartId),
+                            "endId" | "end_id" => Ok(GeneratedField::EndId),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = TaskIdBlock;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct temporal.api.taskqueue.v1.TaskIdBlock")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<TaskIdBlock, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut start_id__ = None;
+                let mut end_id__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::StartId => {
+                            if start_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("startId"));
+                            }
+                            start_id__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::EndId => {
+                            if end_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("endId"));
+                            }
+                            end_id__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                    }
+                }
+                Ok(TaskIdBlock {
+                    start_id: start_id__.unwrap_or_default(),
+                    end_id: end_id__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("temporal.api.taskqueue.v1.TaskIdBlock", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for TaskQueue {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.name.is_empty() {
+            len += 1;
+        }
+        if self.kind != 0 {
+            len += 1;
+        }
+        if !self.normal_name.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("temporal.api.taskqueue.v1.TaskQueue", len)?;
+        if !self.name.is_empty() {
+            struct_ser.serialize_field("name", &self.name)?;
+        }
+        if self.kind != 0 {
+            let v = super::super::enums::v1::TaskQueueKind::try_from(self.kind)
+                .map_err(|_| serde::ser::Error::custom(format!("Invalid variant {}", self.kind)))?;
+            struct_ser.serialize_field("kind", &v)?;
+        }
+        if !self.normal_name.is_empty() {
+            struct_ser.serialize_field("normalName", &self.normal_name)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for TaskQueue {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "name",
+            "kind",
+            "normal_name",
+            "normalName",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Name,
+            Kind,
+            NormalName,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "name" => Ok(GeneratedField::Name),
+                            "kind" => Ok(GeneratedField::Kind),
+                            "normalName" | "normal_name" => Ok(GeneratedField::NormalName),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = TaskQueue;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct temporal.api.taskqueue.v1.TaskQueue")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<TaskQueue, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut name__ = None;
+                let mut kind__ = None;
+                let mut normal_name__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Name => {
+                            if name__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("name"));
+                            }
+                            name__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::Kind => {
+                            if kind__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("kind"));
+                            }
+                            kind__ = Some(map_.next_value::<super::super::enums::v1::TaskQueueKind>()? as i32);
+                        }
+                        GeneratedField::NormalName => {
+                            if normal_name__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("normalName"));
+                            }
+                            normal_name__ = Some(map_.next_value()?);
+                        }
+                    }
+                }
+                Ok(TaskQueue {
+                    name: name__.unwrap_or_default(),
+                    kind: kind__.unwrap_or_default(),
+                    normal_name: normal_name__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("temporal.api.taskqueue.v1.TaskQueue", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for TaskQueueConfig {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.queue_rate_limit.is_some() {
+            len += 1;
+        }
+        if self.fairness_keys_rate_limit_default.is_some() {
+            len += 1;
+        }
+        if !self.fairness_weight_overrides.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("temporal.api.taskqueue.v1.TaskQueueConfig", len)?;
+        if let Some(v) = self.queue_rate_limit.as_ref() {
+            struct_ser.serialize_field("queueRateLimit", v)?;
+        }
+        if let Some(v) = self.fairness_keys_rate_limit_default.as_ref() {
+            struct_ser.serialize_field("fairnessKeysRateLimitDefault", v)?;
+        }
+        if !self.fairness_weight_overrides.is_empty() {
+            struct_ser.serialize_field("fairnessWeightOverrides", &self.fairness_weight_overrides)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for TaskQueueConfig {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "queue_rate_limit",
+            "queueRateLimit",
+            "fairness_keys_rate_limit_default",
+            "fairnessKeysRateLimitDefault",
+            "fairness_weight_overrides",
+            "fairnessWeightOverrides",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            QueueRateLimit,
+            FairnessKeysRateLimitDefault,
+            FairnessWeightOverrides,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "queueRateLimit" | "queue_rate_limit" => Ok(GeneratedField::QueueRateLimit),
+                            "fairnessKeysRateLimitDefault" | "fairness_keys_rate_limit_default" => Ok(GeneratedField::FairnessKeysRateLimitDefault),
+                            "fairnessWeightOverrides" | "fairness_weight_overrides" => Ok(GeneratedField::FairnessWeightOverrides),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = TaskQueueConfig;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct temporal.api.taskqueue.v1.TaskQueueConfig")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<TaskQueueConfig, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut queue_rate_limit__ = None;
+                let mut fairness_keys_rate_limit_default__ = None;
+                let mut fairness_weight_overrides__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::QueueRateLimit => {
+                            if queue_rate_limit__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("queueRateLimit"));
+                            }
+                            queue_rate_limit__ = map_.next_value()?;
+                        }
+                        GeneratedField::FairnessKeysRateLimitDefault => {
+                            if fairness_keys_rate_limit_default__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("fairnessKeysRateLimitDefault"));
+                            }
+                            fairness_keys_rate_limit_default__ = map_.next_value()?;
+                        }
+                        GeneratedField::FairnessWeightOverrides => {
+                            if fairness_weight_overrides__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("fairnessWeightOverrides"));
+                            }
+                            fairness_weight_overrides__ = Some(
+                                map_.next_value::<std::collections::HashMap<_, ::pbjson::private::NumberDeserialize<f32>>>()?
+                                    .into_iter().map(|(k,v)| (k, v.0)).collect()
+                            );
+                        }
+                    }
+                }
+                Ok(TaskQueueConfig {
+                    queue_rate_limit: queue_rate_limit__,
+                    fairness_keys_rate_limit_default: fairness_keys_rate_limit_default__,
+                    fairness_weight_overrides: fairness_weight_overrides__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("temporal.api.taskqueue.v1.TaskQueueConfig", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for TaskQueueMetadata {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.max_tasks_per_second.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("temporal.api.taskqueue.v1.TaskQueueMetadata", len)?;
+        if let Some(v) = self.max_tasks_per_second.as_ref() {
+            struct_ser.serialize_field("maxTasksPerSecond", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for TaskQueueMetadata {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "max_tasks_per_second",
+            "maxTasksPerSecond",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            MaxTasksPerSecond,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "maxTasksPerSecond" | "max_tasks_per_second" => Ok(GeneratedField::MaxTasksPerSecond),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = TaskQueueMetadata;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct temporal.api.taskqueue.v1.TaskQueueMetadata")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<TaskQueueMetadata, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut max_tasks_per_second__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::MaxTasksPerSecond => {
+                            if max_tasks_per_second__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("maxTasksPerSecond"));
+                            }
+                            max_tasks_per_second__ = map_.next_value()?;
+                        }
+                    }
+                }
+                Ok(TaskQueueMetadata {
+                    max_tasks_per_second: max_tasks_per_second__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("temporal.api.taskqueue.v1.TaskQueueMetadata", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for TaskQueuePartitionMetadata {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.key.is_empty() {
+            len += 1;
+        }
+        if !self.owner_host_name.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("temporal.api.taskqueue.v1.TaskQueuePartitionMetadata", len)?;
+        if !self.key.is_empty() {
+            struct_ser.serialize_field("key", &self.key)?;
+        }
+        if !self.owner_host_name.is_empty() {
+            struct_ser.serialize_field("ownerHostName", &self.owner_host_name)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for TaskQueuePartitionMetadata {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "key",
+            "owner_host_name",
+            "ownerHostName",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Key,
+            OwnerHostName,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "key" => Ok(GeneratedField::Key),
+                            "ownerHostName" | "owner_host_name" => Ok(GeneratedField::OwnerHostName),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = TaskQueuePartitionMetadata;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct temporal.api.taskqueue.v1.TaskQueuePartitionMetadata")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<TaskQueuePartitionMetadata, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut key__ = None;
+                let mut owner_host_name__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Key => {
+                            if key__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("key"));
+                            }
+                            key__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::OwnerHostName => {
+                            if owner_host_name__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("ownerHostName"));
+                            }
+                            owner_host_name__ = Some(map_.next_value()?);
+                        }
+                    }
+                }
+                Ok(TaskQueuePartitionMetadata {
+                    key: key__.unwrap_or_default(),
+                    owner_host_name: owner_host_name__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("temporal.api.taskqueue.v1.TaskQueuePartitionMetadata", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for TaskQueueReachability {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.task_queue.is_empty() {
+            len += 1;
+        }
+        if !self.reachability.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("temporal.api.taskqueue.v1.TaskQueueReachability", len)?;
+        if !self.task_queue.is_empty() {
+            struct_ser.serialize_field("taskQueue", &self.task_queue)?;
+        }
+        if !self.reachability.is_empty() {
+            let v = self.reachability.iter().cloned().map(|v| {
+                super::super::enums::v1::TaskReachability::try_from(v)
+                    .map_err(|_| serde::ser::Error::custom(format!("Invalid variant {}", v)))
+                }).collect::<std::result::Result<Vec<_>, _>>()?;
+            struct_ser.serialize_field("reachability", &v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for TaskQueueReachability {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "task_queue",
+            "taskQueue",
+            "reachability",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            TaskQueue,
+            Reachability,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "taskQueue" | "task_queue" => Ok(GeneratedField::TaskQueue),
+                            "reachability" => Ok(GeneratedField::Reachability),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = TaskQueueReachability;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct temporal.api.taskqueue.v1.TaskQueueReachability")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<TaskQueueReachability, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut task_queue__ = None;
+                let mut reachability__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::TaskQueue => {
+                            if task_queue__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("taskQueue"));
+                            }
+                            task_queue__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::Reachability => {
+                            if reachability__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("reachability"));
+                            }
+                            reachability__ = Some(map_.next_value::<Vec<super::super::enums::v1::TaskReachability>>()?.into_iter().map(|x| x as i32).collect());
+                        }
+                    }
+                }
+                Ok(TaskQueueReachability {
+                    task_queue: task_queue__.unwrap_or_default(),
+                    reachability: reachability__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("temporal.api.taskqueue.v1.TaskQueueReachability", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for TaskQueueStats {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.approximate_backlog_count != 0 {
+            len += 1;
+        }
+        if self.approximate_backlog_age.is_some() {
+            len += 1;
+        }
+        if self.tasks_add_rate != 0. {
+            len += 1;
+        }
+        if self.tasks_dispatch_rate != 0. {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("temporal.api.taskqueue.v1.TaskQueueStats", len)?;
+        if self.approximate_backlog_count != 0 {
+            #[allow(clippy::needless_borrow)]
+            #[allow(clippy::needless_borrows_for_generic_args)]
+            struct_ser.serialize_field("approximateBacklogCount", ToString::to_string(&self.approximate_backlog_count).as_str())?;
+        }
+        if let Some(v) = self.approximate_backlog_age.as_ref() {
+            struct_ser.serialize_field("approximateBacklogAge", v)?;
+        }
+        if self.tasks_add_rate != 0. {
+            struct_ser.serialize_field("tasksAddRate", &self.tasks_add_rate)?;
+        }
+        if self.tasks_dispatch_rate != 0. {
+            struct_ser.serialize_field("tasksDispatchRate", &self.tasks_dispatch_rate)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for TaskQueueStats {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "approximate_backlog_count",
+            "approximateBacklogCount",
+            "approximate_backlog_age",
+            "approximateBacklogAge",
+            "tasks_add_rate",
+            "tasksAddRate",
+            "tasks_dispatch_rate",
+            "tasksDispatchRate",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            ApproximateBacklogCount,
+            ApproximateBacklogAge,
+            TasksAddRate,
+            TasksDispatchRate,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "approximateBacklogCount" | "approximate_backlog_count" => Ok(GeneratedField::ApproximateBacklogCount),
+                            "approximateBacklogAge" | "approximate_backlog_age" => Ok(GeneratedField::ApproximateBacklogAge),
+                            "tasksAddRate" | "tasks_add_rate" => Ok(GeneratedField::TasksAddRate),
+                            "tasksDispatchRate" | "tasks_dispatch_rate" => Ok(GeneratedField::TasksDispatchRate),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = TaskQueueStats;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct temporal.api.taskqueue.v1.TaskQueueStats")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<TaskQueueStats, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut approximate_backlog_count__ = None;
+                let mut approximate_backlog_age__ = None;
+                let mut tasks_add_rate__ = None;
+                let mut tasks_dispatch_rate__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::ApproximateBacklogCount => {
+                            if approximate_backlog_count__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("approximateBacklogCount"));
+                            }
+                            approximate_backlog_count__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::ApproximateBacklogAge => {
+                            if approximate_backlog_age__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("approximateBacklogAge"));
+                            }
+                            approximate_backlog_age__ = map_.next_value()?;
+                        }
+                        GeneratedField::TasksAddRate => {
+                            if tasks_add_rate__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("tasksAddRate"));
+                            }
+                            tasks_add_rate__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::TasksDispatchRate => {
+                            if tasks_dispatch_rate__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("tasksDispatchRate"));
+                            }
+                            tasks_dispatch_rate__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                    }
+                }
+                Ok(TaskQueueStats {
+                    approximate_backlog_count: approximate_backlog_count__.unwrap_or_default(),
+                    approximate_backlog_age: approximate_backlog_age__,
+                    tasks_add_rate: tasks_add_rate__.unwrap_or_default(),
+                    tasks_dispatch_rate: tasks_dispatch_rate__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("temporal.api.taskqueue.v1.TaskQueueStats", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for TaskQueueStatus {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.backlog_count_hint != 0 {
+            len += 1;
+        }
+        if self.read_level != 0 {
+            len += 1;
+        }
+        if self.ack_level != 0 {
+            len += 1;
+        }
+        if self.rate_per_second != 0. {
+            len += 1;
+        }
+        if self.task_id_block.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("temporal.api.taskqueue.v1.TaskQueueStatus", len)?;
+        if self.backlog_count_hint != 0 {
+            #[allow(clippy::needless_borrow)]
+            #[allow(clippy::needless_borrows_for_generic_args)]
+            struct_ser.serialize_field("backlogCountHint", ToString::to_string(&self.backlog_count_hint).as_str())?;
+        }
+        if self.read_level != 0 {
+            #[allow(clippy::needless_borrow)]
+            #[allow(clippy::needless_borrows_for_generic_args)]
+            struct_ser.serialize_field("readLevel", ToString::to_string(&self.read_level).as_str())?;
+        }
+        if self.ack_level != 0 {
+            #[allow(clippy::needless_borrow)]
+            #[allow(clippy::needless_borrows_for_generic_args)]
+            struct_ser.serialize_field("ackLevel", ToString::to_string(&self.ack_level).as_str())?;
+        }
+        if self.rate_per_second != 0. {
+            struct_ser.serialize_field("ratePerSecond", &self.rate_per_second)?;
+        }
+        if let Some(v) = self.task_id_block.as_ref() {
+            struct_ser.serialize_field("taskIdBlock", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for TaskQueueStatus {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "backlog_count_hint",
+            "backlogCountHint",
+            "read_level",
+            "readLevel",
+            "ack_level",
+            "ackLevel",
+            "rate_per_second",
+            "ratePerSecond",
+            "task_id_block",
+            "taskIdBlock",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            BacklogCountHint,
+            ReadLevel,
+            AckLevel,
+            RatePerSecond,
+            TaskIdBlock,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "backlogCountHint" | "backlog_count_hint" => Ok(GeneratedField::BacklogCountHint),
+                            "readLevel" | "read_level" => Ok(GeneratedField::ReadLevel),
+                            "ackLevel" | "ack_level" => Ok(GeneratedField::AckLevel),
+                            "ratePerSecond" | "rate_per_second" => Ok(GeneratedField::RatePerSecond),
+                            "taskIdBlock" | "task_id_block" => Ok(GeneratedField::TaskIdBlock),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = TaskQueueStatus;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct temporal.api.taskqueue.v1.TaskQueueStatus")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<TaskQueueStatus, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut backlog_count_hint__ = None;
+                let mut read_level__ = None;
+                let mut ack_level__ = None;
+                let mut rate_per_second__ = None;
+                let mut task_id_block__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::BacklogCountHint => {
+                            if backlog_count_hint__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("backlogCountHint"));
+                            }
+                            backlog_count_hint__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::ReadLevel => {
+                            if read_level__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("readLevel"));
+                            }
+                            read_level__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::AckLevel => {
+                            if ack_level__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("ackLevel"));
+                            }
+                            ack_level__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::RatePerSecond => {
+                            if rate_per_second__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("ratePerSecond"));
+                            }
+                            rate_per_second__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::TaskIdBlock => {
+                            if task_id_block__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("taskIdBlock"));
+                            }
+                            task_id_block__ = map_.next_value()?;
+                        }
+                    }
+                }
+                Ok(TaskQueueStatus {
+                    backlog_count_hint: backlog_count_hint__.unwrap_or_default(),
+                    read_level: read_level__.unwrap_or_default(),
+                    ack_level: ack_level__.unwrap_or_default(),
+                    rate_per_second: rate_per_second__.unwrap_or_default(),
+                    task_id_block: task_id_block__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("temporal.api.taskqueue.v1.TaskQueueStatus", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for TaskQueueTypeInfo {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.pollers.is_empty() {
+            len += 1;
+        }
+        if self.stats.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("temporal.api.taskqueue.v1.TaskQueueTypeInfo", len)?;
+        if !self.pollers.is_empty() {
+            struct_ser.serialize_field("pollers", &self.pollers)?;
+        }
+        if let Some(v) = self.stats.as_ref() {
+            struct_ser.serialize_field("stats", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for TaskQueueTypeInfo {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "pollers",
+            "stats",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Pollers,
+            Stats,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "pollers" => Ok(GeneratedField::Pollers),
+                            "stats" => Ok(GeneratedField::Stats),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = TaskQueueTypeInfo;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct temporal.api.taskqueue.v1.TaskQueueTypeInfo")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<TaskQueueTypeInfo, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut pollers__ = None;
+                let mut stats__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Pollers => {
+                            if pollers__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("pollers"));
+                            }
+                            pollers__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::Stats => {
+                            if stats__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("stats"));
+                            }
+                            stats__ = map_.next_value()?;
+                        }
+                    }
+                }
+                Ok(TaskQueueTypeInfo {
+                    pollers: pollers__.unwrap_or_default(),
+                    stats: stats__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("temporal.api.taskqueue.v1.TaskQueueTypeInfo", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for TaskQueueVersionInfo {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.types_info.is_empty() {
+            len += 1;
+        }
+        if self.task_reachability != 0 {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("temporal.api.taskqueue.v1.TaskQueueVersionInfo", len)?;
+        if !self.types_info.is_empty() {
+            struct_ser.serialize_field("typesInfo", &self.types_info)?;
+        }
+        if self.task_reachability != 0 {
+            let v = super::super::enums::v1::BuildIdTaskReachability::try_from(self.task_reachability)
+                .map_err(|_| serde::ser::Error::custom(format!("Invalid variant {}", self.task_reachability)))?;
+            struct_ser.serialize_field("taskReachability", &v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for TaskQueueVersionInfo {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "types_info",
+            "typesInfo",
+            "task_reachability",
+            "taskReachability",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            TypesInfo,
+            TaskReachability,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "typesInfo" | "types_info" => Ok(GeneratedField::TypesInfo),
+                            "taskReachability" | "task_reachability" => Ok(GeneratedField::TaskReachability),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = TaskQueueVersionInfo;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct temporal.api.taskqueue.v1.TaskQueueVersionInfo")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<TaskQueueVersionInfo, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut types_info__ = None;
+                let mut task_reachability__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::TypesInfo => {
+                            if types_info__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("typesInfo"));
+                            }
+                            types_info__ = Some(
+                                map_.next_value::<std::collections::HashMap<::pbjson::private::NumberDeserialize<i32>, _>>()?
+                                    .into_iter().map(|(k,v)| (k.0, v)).collect()
+                            );
+                        }
+                        GeneratedField::TaskReachability => {
+                            if task_reachability__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("taskReachability"));
+                            }
+                            task_reachability__ = Some(map_.next_value::<super::super::enums::v1::BuildIdTaskReachability>()? as i32);
+                        }
+                    }
+                }
+                Ok(TaskQueueVersionInfo {
+                    types_info: types_info__.unwrap_or_default(),
+                    task_reachability: task_reachability__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("temporal.api.taskqueue.v1.TaskQueueVersionInfo", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for TaskQueueVersionSelection {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.build_ids.is_empty() {
+            len += 1;
+        }
+        if self.unversioned {
+            len += 1;
+        }
+        if self.all_active {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("temporal.api.taskqueue.v1.TaskQueueVersionSelection", len)?;
+        if !self.build_ids.is_empty() {
+            struct_ser.serialize_field("buildIds", &self.build_ids)?;
+        }
+        if self.unversioned {
+            struct_ser.serialize_field("unversioned", &self.unversioned)?;
+        }
+        if self.all_active {
+            struct_ser.serialize_field("allActive", &self.all_active)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for TaskQueueVersionSelection {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "build_ids",
+            "buildIds",
+            "unversioned",
+            "all_active",
+            "allActive",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            BuildIds,
+            Unversioned,
+            AllActive,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "buildIds" | "build_ids" => Ok(GeneratedField::BuildIds),
+                            "unversioned" => Ok(GeneratedField::Unversioned),
+                            "allActive" | "all_active" => Ok(GeneratedField::AllActive),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = TaskQueueVersionSelection;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct temporal.api.taskqueue.v1.TaskQueueVersionSelection")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<TaskQueueVersionSelection, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut build_ids__ = None;
+                let mut unversioned__ = None;
+                let mut all_active__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::BuildIds => {
+                            if build_ids__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("buildIds"));
+                            }
+                            build_ids__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::Unversioned => {
+                            if unversioned__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("unversioned"));
+                            }
+                            unversioned__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::AllActive => {
+                            if all_active__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("allActive"));
+                            }
+                            all_active__ = Some(map_.next_value()?);
+                        }
+                    }
+                }
+                Ok(TaskQueueVersionSelection {
+                    build_ids: build_ids__.unwrap_or_default(),
+                    unversioned: unversioned__.unwrap_or_default(),
+                    all_active: all_active__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("temporal.api.taskqueue.v1.TaskQueueVersionSelection", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for TaskQueueVersioningInfo {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.current_deployment_version.is_some() {
+            len += 1;
+        }
+        if !self.current_version.is_empty() {
+            len += 1;
+        }
+        if self.ramping_deployment_version.is_some() {
+            len += 1;
+        }
+        if !self.ramping_version.is_empty() {
+            len += 1;
+        }
+        if self.ramping_version_percentage != 0. {
+            len += 1;
+        }
+        if self.update_time.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("temporal.api.taskqueue.v1.TaskQueueVersioningInfo", len)?;
+        if let Some(v) = self.current_deployment_version.as_ref() {
+            struct_ser.serialize_field("currentDeploymentVersion", v)?;
+        }
+        if !self.current_version.is_empty() {
+            struct_ser.serialize_field("currentVersion", &self.current_version)?;
+        }
+        if let Some(v) = self.ramping_deployment_version.as_ref() {
+            struct_ser.serialize_field("rampingDeploymentVersion", v)?;
+        }
+        if !self.ramping_version.is_empty() {
+            struct_ser.serialize_field("rampingVersion", &self.ramping_version)?;
+        }
+        if self.ramping_version_percentage != 0. {
+            struct_ser.serialize_field("rampingVersionPercentage", &self.ramping_version_percentage)?;
+        }
+        if let Some(v) = self.update_time.as_ref() {
+            struct_ser.serialize_field("updateTime", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for TaskQueueVersioningInfo {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "current_deployment_version",
+            "currentDeploymentVersion",
+            "current_version",
+            "currentVersion",
+            "ramping_deployment_version",
+            "rampingDeploymentVersion",
+            "ramping_version",
+            "rampingVersion",
+            "ramping_version_percentage",
+            "rampingVersionPercentage",
+            "update_time",
+            "updateTime",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            CurrentDeploymentVersion,
+            CurrentVersion,
+            RampingDeploymentVersion,
+            RampingVersion,
+            RampingVersionPercentage,
+            UpdateTime,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "currentDeploymentVersion" | "current_deployment_version" => Ok(GeneratedField::CurrentDeploymentVersion),
+                            "currentVersion" | "current_version" => Ok(GeneratedField::CurrentVersion),
+                            "rampingDeploymentVersion" | "ramping_deployment_version" => Ok(GeneratedField::RampingDeploymentVersion),
+                            "rampingVersion" | "ramping_version" => Ok(GeneratedField::RampingVersion),
+                            "rampingVersionPercentage" | "ramping_version_percentage" => Ok(GeneratedField::RampingVersionPercentage),
+                            "updateTime" | "update_time" => Ok(GeneratedField::UpdateTime),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = TaskQueueVersioningInfo;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct temporal.api.taskqueue.v1.TaskQueueVersioningInfo")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<TaskQueueVersioningInfo, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut current_deployment_version__ = None;
+                let mut current_version__ = None;
+                let mut ramping_deployment_version__ = None;
+                let mut ramping_version__ = None;
+                let mut ramping_version_percentage__ = None;
+                let mut update_time__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::CurrentDeploymentVersion => {
+                            if current_deployment_version__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("currentDeploymentVersion"));
+                            }
+                            current_deployment_version__ = map_.next_value()?;
+                        }
+                        GeneratedField::CurrentVersion => {
+                            if current_version__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("currentVersion"));
+                            }
+                            current_version__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::RampingDeploymentVersion => {
+                            if ramping_deployment_version__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("rampingDeploymentVersion"));
+                            }
+                            ramping_deployment_version__ = map_.next_value()?;
+                        }
+                        GeneratedField::RampingVersion => {
+                            if ramping_version__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("rampingVersion"));
+                            }
+                            ramping_version__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::RampingVersionPercentage => {
+                            if ramping_version_percentage__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("rampingVersionPercentage"));
+                            }
+                            ramping_version_percentage__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::UpdateTime => {
+                            if update_time__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("updateTime"));
+                            }
+                            update_time__ = map_.next_value()?;
+                        }
+                    }
+                }
+                Ok(TaskQueueVersioningInfo {
+                    current_deployment_version: current_deployment_version__,
+                    current_version: current_version__.unwrap_or_default(),
+                    ramping_deployment_version: ramping_deployment_version__,
+                    ramping_version: ramping_version__.unwrap_or_default(),
+                    ramping_version_percentage: ramping_version_percentage__.unwrap_or_default(),
+                    update_time: update_time__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("temporal.api.taskqueue.v1.TaskQueueVersioningInfo", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for TimestampedBuildIdAssignmentRule {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.rule.is_some() {
+            len += 1;
+        }
+        if self.create_time.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("temporal.api.taskqueue.v1.TimestampedBuildIdAssignmentRule", len)?;
+        if let Some(v) = self.rule.as_ref() {
+            struct_ser.serialize_field("rule", v)?;
+        }
+        if let Some(v) = self.create_time.as_ref() {
+            struct_ser.serialize_field("createTime", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for TimestampedBuildIdAssignmentRule {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "rule",
+            "create_time",
+            "createTime",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Rule,
+            CreateTime,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "rule" => Ok(GeneratedField::Rule),
+                            "createTime" | "create_time" => Ok(GeneratedField::CreateTime),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = TimestampedBuildIdAssignmentRule;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct temporal.api.taskqueue.v1.TimestampedBuildIdAssignmentRule")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<TimestampedBuildIdAssignmentRule, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut rule__ = None;
+                let mut create_time__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Rule => {
+                            if rule__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("rule"));
+                            }
+                            rule__ = map_.next_value()?;
+                        }
+                        GeneratedField::CreateTime => {
+                            if create_time__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("createTime"));
+                            }
+                            create_time__ = map_.next_value()?;
+                        }
+                    }
+                }
+                Ok(TimestampedBuildIdAssignmentRule {
+                    rule: rule__,
+                    create_time: create_time__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("temporal.api.taskqueue.v1.TimestampedBuildIdAssignmentRule", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for TimestampedCompatibleBuildIdRedirectRule {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.rule.is_some() {
+            len += 1;
+        }
+        if self.create_time.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("temporal.api.taskqueue.v1.TimestampedCompatibleBuildIdRedirectRule", len)?;
+        if let Some(v) = self.rule.as_ref() {
+            struct_ser.serialize_field("rule", v)?;
+        }
+        if let Some(v) = self.create_time.as_ref() {
+            struct_ser.serialize_field("createTime", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for TimestampedCompatibleBuildIdRedirectRule {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "rule",
+            "create_time",
+            "createTime",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Rule,
+            CreateTime,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "rule" => Ok(GeneratedField::Rule),
+                            "createTime" | "create_time" => Ok(GeneratedField::CreateTime),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = TimestampedCompatibleBuildIdRedirectRule;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct temporal.api.taskqueue.v1.TimestampedCompatibleBuildIdRedirectRule")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<TimestampedCompatibleBuildIdRedirectRule, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut rule__ = None;
+                let mut create_time__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Rule => {
+                            if rule__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("rule"));
+                            }
+                            rule__ = map_.next_value()?;
+                        }
+                        GeneratedField::CreateTime => {
+                            if create_time__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("createTime"));
+                            }
+                            create_time__ = map_.next_value()?;
+                        }
+                    }
+                }
+                Ok(TimestampedCompatibleBuildIdRedirectRule {
+                    rule: rule__,
+                    create_time: create_time__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("temporal.api.taskqueue.v1.TimestampedCompatibleBuildIdRedirectRule", FIELDS, GeneratedVisitor)
+    }
+}
@@ -0,0 +1,1097 @@
+impl serde::Serialize for Acceptance {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.accepted_request_message_id.is_empty() {
+            len += 1;
+        }
+        if self.accepted_request_sequencing_event_id != 0 {
+            len += 1;
+        }
+        if self.accepted_request.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("temporal.api.update.v1.Acceptance", len)?;
+        if !self.accepted_request_message_id.is_empty() {
+            struct_ser.serialize_field("acceptedRequestMessageId", &self.accepted_request_message_id)?;
+        }
+        if self.accepted_request_sequencing_event_id != 0 {
+            #[allow(clippy::needless_borrow)]
+            #[allow(clippy::needless_borrows_for_generic_args)]
+            struct_ser.serialize_field("acceptedRequestSequencingEventId", ToString::to_string(&self.accepted_request_sequencing_event_id).as_str())?;
+        }
+        if let Some(v) = self.accepted_request.as_ref() {
+            struct_ser.serialize_field("acceptedRequest", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for Acceptance {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "accepted_request_message_id",
+            "acceptedRequestMessageId",
+            "accepted_request_sequencing_event_id",
+            "acceptedRequestSequencingEventId",
+            "accepted_request",
+            "acceptedRequest",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            AcceptedRequestMessageId,
+            AcceptedRequestSequencingEventId,
+            AcceptedRequest,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "acceptedRequestMessageId" | "accepted_request_message_id" => Ok(GeneratedField::AcceptedRequestMessageId),
+                            "acceptedRequestSequencingEventId" | "accepted_request_sequencing_event_id" => Ok(GeneratedField::AcceptedRequestSequencingEventId),
+                            "acceptedRequest" | "accepted_request" => Ok(GeneratedField::AcceptedRequest),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = Acceptance;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct temporal.api.update.v1.Acceptance")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<Acceptance, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut accepted_request_message_id__ = None;
+                let mut accepted_request_sequencing_event_id__ = None;
+                let mut accepted_request__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::AcceptedRequestMessageId => {
+                            if accepted_request_message_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("acceptedRequestMessageId"));
+                            }
+                            accepted_request_message_id__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::AcceptedRequestSequencingEventId => {
+                            if accepted_request_sequencing_event_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("acceptedRequestSequencingEventId"));
+                            }
+                            accepted_request_sequencing_event_id__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::AcceptedRequest => {
+                            if accepted_request__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("acceptedRequest"));
+                            }
+                            accepted_request__ = map_.next_value()?;
+                        }
+                    }
+                }
+                Ok(Acceptance {
+                    accepted_request_message_id: accepted_request_message_id__.unwrap_or_default(),
+                    accepted_request_sequencing_event_id: accepted_request_sequencing_event_id__.unwrap_or_default(),
+                    accepted_request: accepted_request__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("temporal.api.update.v1.Acceptance", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for Input {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.header.is_some() {
+            len += 1;
+        }
+        if !self.name.is_empty() {
+            len += 1;
+        }
+        if self.args.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("temporal.api.update.v1.Input", len)?;
+        if let Some(v) = self.header.as_ref() {
+            struct_ser.serialize_field("header", v)?;
+        }
+        if !self.name.is_empty() {
+            struct_ser.serialize_field("name", &self.name)?;
+        }
+        if let Some(v) = self.args.as_ref() {
+            struct_ser.serialize_field("args", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for Input {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "header",
+            "name",
+            "args",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Header,
+            Name,
+            Args,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "header" => Ok(GeneratedField::Header),
+                            "name" => Ok(GeneratedField::Name),
+                            "args" => Ok(GeneratedField::Args),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = Input;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct temporal.api.update.v1.Input")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<Input, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut header__ = None;
+                let mut name__ = None;
+                let mut args__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Header => {
+                            if header__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("header"));
+                            }
+                            header__ = map_.next_value()?;
+                        }
+                        GeneratedField::Name => {
+                            if name__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("name"));
+                            }
+                            name__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::Args => {
+                            if args__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("args"));
+                            }
+                            args__ = map_.next_value()?;
+                        }
+                    }
+                }
+                Ok(Input {
+                    header: header__,
+                    name: name__.unwrap_or_default(),
+                    args: args__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("temporal.api.update.v1.Input", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for Meta {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.update_id.is_empty() {
+            len += 1;
+        }
+        if !self.identity.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("temporal.api.update.v1.Meta", len)?;
+        if !self.update_id.is_empty() {
+            struct_ser.serialize_field("updateId", &self.update_id)?;
+        }
+        if !self.identity.is_empty() {
+            struct_ser.serialize_field("identity", &self.identity)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for Meta {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "update_id",
+            "updateId",
+            "identity",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            UpdateId,
+            Identity,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "updateId" | "update_id" => Ok(GeneratedField::UpdateId),
+                            "identity" => Ok(GeneratedField::Identity),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = Meta;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct temporal.api.update.v1.Meta")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<Meta, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut update_id__ = None;
+                let mut identity__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::UpdateId => {
+                            if update_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("updateId"));
+                            }
+                            update_id__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::Identity => {
+                            if identity__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("identity"));
+                            }
+                            identity__ = Some(map_.next_value()?);
+                        }
+                    }
+                }
+                Ok(Meta {
+                    update_id: update_id__.unwrap_or_default(),
+                    identity: identity__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("temporal.api.update.v1.Meta", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for Outcome {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.value.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("temporal.api.update.v1.Outcome", len)?;
+        if let Some(v) = self.value.as_ref() {
+            match v {
+                outcome::Value::Success(v) => {
+                    struct_ser.serialize_field("success", v)?;
+                }
+                outcome::Value::Failure(v) => {
+                    struct_ser.serialize_field("failure", v)?;
+                }
+            }
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for Outcome {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "success",
+            "failure",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Success,
+            Failure,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "success" => Ok(GeneratedField::Success),
+                            "failure" => Ok(GeneratedField::Failure),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = Outcome;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct temporal.api.update.v1.Outcome")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<Outcome, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut value__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Success => {
+                            if value__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("success"));
+                            }
+                            value__ = map_.next_value::<::std::option::Option<_>>()?.map(outcome::Value::Success)
+;
+                        }
+                        GeneratedField::Failure => {
+                            if value__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("failure"));
+                            }
+                            value__ = map_.next_value::<::std::option::Option<_>>()?.map(outcome::Value::Failure)
+;
+                        }
+                    }
+                }
+                Ok(Outcome {
+                    value: value__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("temporal.api.update.v1.Outcome", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for Rejection {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.rejected_request_message_id.is_empty() {
+            len += 1;
+        }
+        if self.rejected_request_sequencing_event_id != 0 {
+            len += 1;
+        }
+        if self.rejected_request.is_some() {
+            len += 1;
+        }
+        if self.failure.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("temporal.api.update.v1.Rejection", len)?;
+        if !self.rejected_request_message_id.is_empty() {
+            struct_ser.serialize_field("rejectedRequestMessageId", &self.rejected_request_message_id)?;
+        }
+        if self.rejected_request_sequencing_event_id != 0 {
+            #[allow(clippy::needless_borrow)]
+            #[allow(clippy::needless_borrows_for_generic_args)]
+            struct_ser.serialize_field("rejectedRequestSequencingEventId", ToString::to_string(&self.rejected_request_sequencing_event_id).as_str())?;
+        }
+        if let Some(v) = self.rejected_request.as_ref() {
+            struct_ser.serialize_field("rejectedRequest", v)?;
+        }
+        if let Some(v) = self.failure.as_ref() {
+            struct_ser.serialize_field("failure", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for Rejection {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "rejected_request_message_id",
+            "rejectedRequestMessageId",
+            "rejected_request_sequencing_event_id",
+            "rejectedRequestSequencingEventId",
+            "rejected_request",
+            "rejectedRequest",
+            "failure",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            RejectedRequestMessageId,
+            RejectedRequestSequencingEventId,
+            RejectedRequest,
+            Failure,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "rejectedRequestMessageId" | "rejected_request_message_id" => Ok(GeneratedField::RejectedRequestMessageId),
+                            "rejectedRequestSequencingEventId" | "rejected_request_sequencing_event_id" => Ok(GeneratedField::RejectedRequestSequencingEventId),
+                            "rejectedRequest" | "rejected_request" => Ok(GeneratedField::RejectedRequest),
+                            "failure" => Ok(GeneratedField::Failure),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = Rejection;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct temporal.api.update.v1.Rejection")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<Rejection, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut rejected_request_message_id__ = None;
+                let mut rejected_request_sequencing_event_id__ = None;
+                let mut rejected_request__ = None;
+                let mut failure__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::RejectedRequestMessageId => {
+                            if rejected_request_message_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("rejectedRequestMessageId"));
+                            }
+                            rejected_request_message_id__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::RejectedRequestSequencingEventId => {
+                            if rejected_request_sequencing_event_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("rejectedRequestSequencingEventId"));
+                            }
+                            rejected_request_sequencing_event_id__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::RejectedRequest => {
+                            if rejected_request__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("rejectedRequest"));
+                            }
+                            rejected_request__ = map_.next_value()?;
+                        }
+                        GeneratedField::Failure => {
+                            if failure__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("failure"));
+                            }
+                            failure__ = map_.next_value()?;
+                        }
+                    }
+                }
+                Ok(Rejection {
+                    rejected_request_message_id: rejected_request_message_id__.unwrap_or_default(),
+                    rejected_request_sequencing_event_id: rejected_request_sequencing_event_id__.unwrap_or_default(),
+                    rejected_request: rejected_request__,
+                    failure: failure__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("temporal.api.update.v1.Rejection", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for Request {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.meta.is_some() {
+            len += 1;
+        }
+        if self.input.is_some() {
+            len += 1;
+        }
+        if !self.request_id.is_empty() {
+            len += 1;
+        }
+        if !self.completion_callbacks.is_empty() {
+            len += 1;
+        }
+        if !self.links.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("temporal.api.update.v1.Request", len)?;
+        if let Some(v) = self.meta.as_ref() {
+            struct_ser.serialize_field("meta", v)?;
+        }
+        if let Some(v) = self.input.as_ref() {
+            struct_ser.serialize_field("input", v)?;
+        }
+        if !self.request_id.is_empty() {
+            struct_ser.serialize_field("requestId", &self.request_id)?;
+        }
+        if !self.completion_callbacks.is_empty() {
+            struct_ser.serialize_field("completionCallbacks", &self.completion_callbacks)?;
+        }
+        if !self.links.is_empty() {
+            struct_ser.serialize_field("links", &self.links)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for Request {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "meta",
+            "input",
+            "request_id",
+            "requestId",
+            "completion_callbacks",
+            "completionCallbacks",
+            "links",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Meta,
+            Input,
+            RequestId,
+            CompletionCallbacks,
+            Links,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "meta" => Ok(GeneratedField::Meta),
+                            "input" => Ok(GeneratedField::Input),
+                            "requestId" | "request_id" => Ok(GeneratedField::RequestId),
+                            "completionCallbacks" | "completion_callbacks" => Ok(GeneratedField::CompletionCallbacks),
+                            "links" => Ok(GeneratedField::Links),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = Request;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct temporal.api.update.v1.Request")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<Request, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut meta__ = None;
+                let mut input__ = None;
+                let mut request_id__ = None;
+                let mut completion_callbacks__ = None;
+                let mut links__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Meta => {
+                            if meta__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("meta"));
+                            }
+                            meta__ = map_.next_value()?;
+                        }
+                        GeneratedField::Input => {
+                            if input__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("input"));
+                            }
+                            input__ = map_.next_value()?;
+                        }
+                        GeneratedField::RequestId => {
+                            if request_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("requestId"));
+                            }
+                            request_id__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::CompletionCallbacks => {
+                            if completion_callbacks__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("completionCallbacks"));
+                            }
+                            completion_callbacks__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::Links => {
+                            if links__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("links"));
+                            }
+                            links__ = Some(map_.next_value()?);
+                        }
+                    }
+                }
+                Ok(Request {
+                    meta: meta__,
+                    input: input__,
+                    request_id: request_id__.unwrap_or_default(),
+                    completion_callbacks: completion_callbacks__.unwrap_or_default(),
+                    links: links__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("temporal.api.update.v1.Request", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for Response {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.meta.is_some() {
+            len += 1;
+        }
+        if self.outcome.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("temporal.api.update.v1.Response", len)?;
+        if let Some(v) = self.meta.as_ref() {
+            struct_ser.serialize_field("meta", v)?;
+        }
+        if let Some(v) = self.outcome.as_ref() {
+            struct_ser.serialize_field("outcome", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for Response {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "meta",
+            "outcome",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Meta,
+            Outcome,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "meta" => Ok(GeneratedField::Meta),
+                            "outcome" => Ok(GeneratedField::Outcome),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = Response;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct temporal.api.update.v1.Response")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<Response, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut meta__ = None;
+                let mut outcome__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Meta => {
+                            if meta__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("meta"));
+                            }
+                            meta__ = map_.next_value()?;
+                        }
+                        GeneratedField::Outcome => {
+                            if outcome__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("outcome"));
+                            }
+                            outcome__ = map_.next_value()?;
+                        }
+                    }
+                }
+                Ok(Response {
+                    meta: meta__,
+                    outcome: outcome__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("temporal.api.update.v1.Response", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for UpdateRef {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.workflow_execution.is_some() {
+            len += 1;
+        }
+        if !self.update_id.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("temporal.api.update.v1.UpdateRef", len)?;
+        if let Some(v) = self.workflow_execution.as_ref() {
+            struct_ser.serialize_field("workflowExecution", v)?;
+        }
+        if !self.update_id.is_empty() {
+            struct_ser.serialize_field("updateId", &self.update_id)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for UpdateRef {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "workflow_execution",
+            "workflowExecution",
+            "update_id",
+            "updateId",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            WorkflowExecution,
+            UpdateId,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "workflowExecution" | "workflow_execution" => Ok(GeneratedField::WorkflowExecution),
+                            "updateId" | "update_id" => Ok(GeneratedField::UpdateId),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = UpdateRef;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct temporal.api.update.v1.UpdateRef")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<UpdateRef, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut workflow_execution__ = None;
+                let mut update_id__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::WorkflowExecution => {
+                            if workflow_execution__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("workflowExecution"));
+                            }
+                            workflow_execution__ = map_.next_value()?;
+                        }
+                        GeneratedField::UpdateId => {
+                            if update_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("updateId"));
+                            }
+                            update_id__ = Some(map_.next_value()?);
+                        }
+                    }
+                }
+                Ok(UpdateRef {
+                    workflow_execution: workflow_execution__,
+                    update_id: update_id__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("temporal.api.update.v1.UpdateRef", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for WaitPolicy {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.lifecycle_stage != 0 {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("temporal.api.update.v1.WaitPolicy", len)?;
+        if self.lifecycle_stage != 0 {
+            let v = super::super::enums::v1::UpdateWorkflowExecutionLifecycleStage::try_from(self.lifecycle_stage)
+                .map_err(|_| serde::ser::Error::custom(format!("Invalid variant {}", self.lifecycle_stage)))?;
+            struct_ser.serialize_field("lifecycleStage", &v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for WaitPolicy {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "lifecycle_stage",
+            "lifecycleStage",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            LifecycleStage,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "lifecycleStage" | "lifecycle_stage" => Ok(GeneratedField::LifecycleStage),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = WaitPolicy;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct temporal.api.update.v1.WaitPolicy")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<WaitPolicy, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut lifecycle_stage__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::LifecycleStage => {
+                            if lifecycle_stage__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("lifecycleStage"));
+                            }
+                            lifecycle_stage__ = Some(map_.next_value::<super::super::enums::v1::UpdateWorkflowExecutionLifecycleStage>()? as i32);
+                        }
+                    }
+                }
+                Ok(WaitPolicy {
+                    lifecycle_stage: lifecycle_stage__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("temporal.api.update.v1.WaitPolicy", FIELDS, GeneratedVisitor)
+    }
+}
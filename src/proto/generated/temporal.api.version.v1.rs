@@ -5,7 +5,7 @@ pub struct ReleaseInfo {
     #[prost(string, tag = "1")]
     pub version: ::prost::alloc::string::String,
     #[prost(message, optional, tag = "2")]
-    pub release_time: ::core::option::Option<::prost_types::Timestamp>,
+    pub release_time: ::core::option::Option<::pbjson_types::Timestamp>,
     #[prost(string, tag = "3")]
     pub notes: ::prost::alloc::string::String,
 }
@@ -29,5 +29,5 @@ pub struct VersionInfo {
     #[prost(message, repeated, tag = "4")]
     pub alerts: ::prost::alloc::vec::Vec<Alert>,
     #[prost(message, optional, tag = "5")]
-    pub last_update_time: ::core::option::Option<::prost_types::Timestamp>,
+    pub last_update_time: ::core::option::Option<::pbjson_types::Timestamp>,
 }
@@ -0,0 +1,396 @@
+impl serde::Serialize for Alert {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.message.is_empty() {
+            len += 1;
+        }
+        if self.severity != 0 {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("temporal.api.version.v1.Alert", len)?;
+        if !self.message.is_empty() {
+            struct_ser.serialize_field("message", &self.message)?;
+        }
+        if self.severity != 0 {
+            let v = super::super::enums::v1::Severity::try_from(self.severity)
+                .map_err(|_| serde::ser::Error::custom(format!("Invalid variant {}", self.severity)))?;
+            struct_ser.serialize_field("severity", &v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for Alert {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "message",
+            "severity",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Message,
+            Severity,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "message" => Ok(GeneratedField::Message),
+                            "severity" => Ok(GeneratedField::Severity),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = Alert;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct temporal.api.version.v1.Alert")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<Alert, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut message__ = None;
+                let mut severity__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Message => {
+                            if message__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("message"));
+                            }
+                            message__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::Severity => {
+                            if severity__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("severity"));
+                            }
+                            severity__ = Some(map_.next_value::<super::super::enums::v1::Severity>()? as i32);
+                        }
+                    }
+                }
+                Ok(Alert {
+                    message: message__.unwrap_or_default(),
+                    severity: severity__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("temporal.api.version.v1.Alert", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for ReleaseInfo {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.version.is_empty() {
+            len += 1;
+        }
+        if self.release_time.is_some() {
+            len += 1;
+        }
+        if !self.notes.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("temporal.api.version.v1.ReleaseInfo", len)?;
+        if !self.version.is_empty() {
+            struct_ser.serialize_field("version", &self.version)?;
+        }
+        if let Some(v) = self.release_time.as_ref() {
+            struct_ser.serialize_field("releaseTime", v)?;
+        }
+        if !self.notes.is_empty() {
+            struct_ser.serialize_field("notes", &self.notes)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for ReleaseInfo {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "version",
+            "release_time",
+            "releaseTime",
+            "notes",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Version,
+            ReleaseTime,
+            Notes,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "version" => Ok(GeneratedField::Version),
+                            "releaseTime" | "release_time" => Ok(GeneratedField::ReleaseTime),
+                            "notes" => Ok(GeneratedField::Notes),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = ReleaseInfo;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct temporal.api.version.v1.ReleaseInfo")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<ReleaseInfo, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut version__ = None;
+                let mut release_time__ = None;
+                let mut notes__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Version => {
+                            if version__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("version"));
+                            }
+                            version__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::ReleaseTime => {
+                            if release_time__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("releaseTime"));
+                            }
+                            release_time__ = map_.next_value()?;
+                        }
+                        GeneratedField::Notes => {
+                            if notes__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("notes"));
+                            }
+                            notes__ = Some(map_.next_value()?);
+                        }
+                    }
+                }
+                Ok(ReleaseInfo {
+                    version: version__.unwrap_or_default(),
+                    release_time: release_time__,
+                    notes: notes__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("temporal.api.version.v1.ReleaseInfo", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for VersionInfo {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.current.is_some() {
+            len += 1;
+        }
+        if self.recommended.is_some() {
+            len += 1;
+        }
+        if !self.instructions.is_empty() {
+            len += 1;
+        }
+        if !self.alerts.is_empty() {
+            len += 1;
+        }
+        if self.last_update_time.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("temporal.api.version.v1.VersionInfo", len)?;
+        if let Some(v) = self.current.as_ref() {
+            struct_ser.serialize_field("current", v)?;
+        }
+        if let Some(v) = self.recommended.as_ref() {
+            struct_ser.serialize_field("recommended", v)?;
+        }
+        if !self.instructions.is_empty() {
+            struct_ser.serialize_field("instructions", &self.instructions)?;
+        }
+        if !self.alerts.is_empty() {
+            struct_ser.serialize_field("alerts", &self.alerts)?;
+        }
+        if let Some(v) = self.last_update_time.as_ref() {
+            struct_ser.serialize_field("lastUpdateTime", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for VersionInfo {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "current",
+            "recommended",
+            "instructions",
+            "alerts",
+            "last_update_time",
+            "lastUpdateTime",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Current,
+            Recommended,
+            Instructions,
+            Alerts,
+            LastUpdateTime,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "current" => Ok(GeneratedField::Current),
+                            "recommended" => Ok(GeneratedField::Recommended),
+                            "instructions" => Ok(GeneratedField::Instructions),
+                            "alerts" => Ok(GeneratedField::Alerts),
+                            "lastUpdateTime" | "last_update_time" => Ok(GeneratedField::LastUpdateTime),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = VersionInfo;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct temporal.api.version.v1.VersionInfo")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<VersionInfo, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut current__ = None;
+                let mut recommended__ = None;
+                let mut instructions__ = None;
+                let mut alerts__ = None;
+                let mut last_update_time__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Current => {
+                            if current__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("current"));
+                            }
+                            current__ = map_.next_value()?;
+                        }
+                        GeneratedField::Recommended => {
+                            if recommended__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("recommended"));
+                            }
+                            recommended__ = map_.next_value()?;
+                        }
+                        GeneratedField::Instructions => {
+                            if instructions__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("instructions"));
+                            }
+                            instructions__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::Alerts => {
+                            if alerts__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("alerts"));
+                            }
+                            alerts__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::LastUpdateTime => {
+                            if last_update_time__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("lastUpdateTime"));
+                            }
+                            last_update_time__ = map_.next_value()?;
+                        }
+                    }
+                }
+                Ok(VersionInfo {
+                    current: current__,
+                    recommended: recommended__,
+                    instructions: instructions__.unwrap_or_default(),
+                    alerts: alerts__.unwrap_or_default(),
+                    last_update_time: last_update_time__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("temporal.api.version.v1.VersionInfo", FIELDS, GeneratedVisitor)
+    }
+}
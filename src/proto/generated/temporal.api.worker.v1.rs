@@ -5,7 +5,7 @@ pub struct WorkerPollerInfo {
     #[prost(int32, tag = "1")]
     pub current_pollers: i32,
     #[prost(message, optional, tag = "2")]
-    pub last_successful_poll_time: ::core::option::Option<::prost_types::Timestamp>,
+    pub last_successful_poll_time: ::core::option::Option<::pbjson_types::Timestamp>,
     /// Set true if the number of concurrent pollers is auto-scaled
     #[prost(bool, tag = "3")]
     pub is_autoscaling: bool,
@@ -97,14 +97,14 @@ pub struct WorkerHeartbeat {
     /// Worker start time.
     /// It can be used to determine worker uptime. (current time - start time)
     #[prost(message, optional, tag = "9")]
-    pub start_time: ::core::option::Option<::prost_types::Timestamp>,
+    pub start_time: ::core::option::Option<::pbjson_types::Timestamp>,
     /// Timestamp of this heartbeat, coming from the worker. Worker should set it to "now".
     /// Note that this timestamp comes directly from the worker and is subject to workers' clock skew.
     #[prost(message, optional, tag = "10")]
-    pub heartbeat_time: ::core::option::Option<::prost_types::Timestamp>,
+    pub heartbeat_time: ::core::option::Option<::pbjson_types::Timestamp>,
     /// Elapsed time since the last heartbeat from the worker.
     #[prost(message, optional, tag = "11")]
-    pub elapsed_since_last_heartbeat: ::core::option::Option<::prost_types::Duration>,
+    pub elapsed_since_last_heartbeat: ::core::option::Option<::pbjson_types::Duration>,
     #[prost(message, optional, tag = "12")]
     pub workflow_task_slots_info: ::core::option::Option<WorkerSlotsInfo>,
     #[prost(message, optional, tag = "13")]
@@ -133,12 +133,65 @@ pub struct WorkerHeartbeat {
     /// Plugins currently in use by this SDK.
     #[prost(message, repeated, tag = "23")]
     pub plugins: ::prost::alloc::vec::Vec<PluginInfo>,
+    /// Storage drivers in use by this SDK.
+    #[prost(message, repeated, tag = "24")]
+    pub drivers: ::prost::alloc::vec::Vec<StorageDriverInfo>,
 }
+/// Detailed worker information.
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct WorkerInfo {
     #[prost(message, optional, tag = "1")]
     pub worker_heartbeat: ::core::option::Option<WorkerHeartbeat>,
 }
+/// Limited worker information returned in the list response.
+/// When adding fields here, ensure that it is also added to WorkerInfo (as it carries the full worker information).
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct WorkerListInfo {
+    /// Worker identifier, should be unique for the namespace.
+    /// It is distinct from worker identity, which is not necessarily namespace-unique.
+    #[prost(string, tag = "1")]
+    pub worker_instance_key: ::prost::alloc::string::String,
+    /// Worker identity, set by the client, may not be unique.
+    /// Usually host_name+(user group name)+process_id, but can be overwritten by the user.
+    #[prost(string, tag = "2")]
+    pub worker_identity: ::prost::alloc::string::String,
+    /// Task queue this worker is polling for tasks.
+    #[prost(string, tag = "3")]
+    pub task_queue: ::prost::alloc::string::String,
+    #[prost(message, optional, tag = "4")]
+    pub deployment_version: ::core::option::Option<
+        super::super::deployment::v1::WorkerDeploymentVersion,
+    >,
+    #[prost(string, tag = "5")]
+    pub sdk_name: ::prost::alloc::string::String,
+    #[prost(string, tag = "6")]
+    pub sdk_version: ::prost::alloc::string::String,
+    /// Worker status. Defined by SDK.
+    #[prost(enumeration = "super::super::enums::v1::WorkerStatus", tag = "7")]
+    pub status: i32,
+    /// Worker start time.
+    /// It can be used to determine worker uptime. (current time - start time)
+    #[prost(message, optional, tag = "8")]
+    pub start_time: ::core::option::Option<::pbjson_types::Timestamp>,
+    /// Worker host identifier.
+    #[prost(string, tag = "9")]
+    pub host_name: ::prost::alloc::string::String,
+    /// Worker grouping identifier. A key to group workers that share the same client+namespace+process.
+    /// This will be used to build the worker command nexus task queue name:
+    /// "temporal-sys/worker-commands/{worker_grouping_key}"
+    #[prost(string, tag = "10")]
+    pub worker_grouping_key: ::prost::alloc::string::String,
+    /// Worker process identifier. This id only needs to be unique
+    /// within one host (so using e.g. a unix pid would be appropriate).
+    #[prost(string, tag = "11")]
+    pub process_id: ::prost::alloc::string::String,
+    /// Plugins currently in use by this SDK.
+    #[prost(message, repeated, tag = "12")]
+    pub plugins: ::prost::alloc::vec::Vec<PluginInfo>,
+    /// Storage drivers in use by this SDK.
+    #[prost(message, repeated, tag = "13")]
+    pub drivers: ::prost::alloc::vec::Vec<StorageDriverInfo>,
+}
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct PluginInfo {
     /// The name of the plugin, required.
@@ -148,3 +201,47 @@ pub struct PluginInfo {
     #[prost(string, tag = "2")]
     pub version: ::prost::alloc::string::String,
 }
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct StorageDriverInfo {
+    /// The type of the driver, required.
+    #[prost(string, tag = "1")]
+    pub r#type: ::prost::alloc::string::String,
+}
+/// A command sent from the server to a worker.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct WorkerCommand {
+    #[prost(oneof = "worker_command::Type", tags = "1")]
+    pub r#type: ::core::option::Option<worker_command::Type>,
+}
+/// Nested message and enum types in `WorkerCommand`.
+pub mod worker_command {
+    #[derive(Clone, PartialEq, ::prost::Oneof)]
+    pub enum Type {
+        #[prost(message, tag = "1")]
+        CancelActivity(super::CancelActivityCommand),
+    }
+}
+/// Cancel an activity if it is still running. Otherwise, do nothing.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct CancelActivityCommand {
+    #[prost(bytes = "vec", tag = "1")]
+    pub task_token: ::prost::alloc::vec::Vec<u8>,
+}
+/// The result of executing a WorkerCommand.
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct WorkerCommandResult {
+    #[prost(oneof = "worker_command_result::Type", tags = "1")]
+    pub r#type: ::core::option::Option<worker_command_result::Type>,
+}
+/// Nested message and enum types in `WorkerCommandResult`.
+pub mod worker_command_result {
+    #[derive(Clone, Copy, PartialEq, ::prost::Oneof)]
+    pub enum Type {
+        #[prost(message, tag = "1")]
+        CancelActivity(super::CancelActivityResult),
+    }
+}
+/// Result of a CancelActivityCommand.
+/// Treat both successful cancellation and no-op (activity is no longer running) as success.
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct CancelActivityResult {}
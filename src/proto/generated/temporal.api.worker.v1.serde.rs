@@ -0,0 +1,1980 @@
+impl serde::Serialize for CancelActivityCommand {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.task_token.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("temporal.api.worker.v1.CancelActivityCommand", len)?;
+        if !self.task_token.is_empty() {
+            #[allow(clippy::needless_borrow)]
+            #[allow(clippy::needless_borrows_for_generic_args)]
+            struct_ser.serialize_field("taskToken", pbjson::private::base64::encode(&self.task_token).as_str())?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for CancelActivityCommand {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "task_token",
+            "taskToken",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            TaskToken,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "taskToken" | "task_token" => Ok(GeneratedField::TaskToken),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = CancelActivityCommand;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct temporal.api.worker.v1.CancelActivityCommand")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<CancelActivityCommand, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut task_token__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::TaskToken => {
+                            if task_token__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("taskToken"));
+                            }
+                            task_token__ = 
+                                Some(map_.next_value::<::pbjson::private::BytesDeserialize<_>>()?.0)
+                            ;
+                        }
+                    }
+                }
+                Ok(CancelActivityCommand {
+                    task_token: task_token__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("temporal.api.worker.v1.CancelActivityCommand", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for CancelActivityResult {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let len = 0;
+        let struct_ser = serializer.serialize_struct("temporal.api.worker.v1.CancelActivityResult", len)?;
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for CancelActivityResult {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                            Err(serde::de::Error::unknown_field(value, FIELDS))
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = CancelActivityResult;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct temporal.api.worker.v1.CancelActivityResult")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<CancelActivityResult, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                while map_.next_key::<GeneratedField>()?.is_some() {
+                    let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                }
+                Ok(CancelActivityResult {
+                })
+            }
+        }
+        deserializer.deserialize_struct("temporal.api.worker.v1.CancelActivityResult", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for PluginInfo {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.name.is_empty() {
+            len += 1;
+        }
+        if !self.version.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("temporal.api.worker.v1.PluginInfo", len)?;
+        if !self.name.is_empty() {
+            struct_ser.serialize_field("name", &self.name)?;
+        }
+        if !self.version.is_empty() {
+            struct_ser.serialize_field("version", &self.version)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for PluginInfo {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "name",
+            "version",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Name,
+            Version,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "name" => Ok(GeneratedField::Name),
+                            "version" => Ok(GeneratedField::Version),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = PluginInfo;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct temporal.api.worker.v1.PluginInfo")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<PluginInfo, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut name__ = None;
+                let mut version__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Name => {
+                            if name__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("name"));
+                            }
+                            name__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::Version => {
+                            if version__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("version"));
+                            }
+                            version__ = Some(map_.next_value()?);
+                        }
+                    }
+                }
+                Ok(PluginInfo {
+                    name: name__.unwrap_or_default(),
+                    version: version__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("temporal.api.worker.v1.PluginInfo", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for StorageDriverInfo {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.r#type.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("temporal.api.worker.v1.StorageDriverInfo", len)?;
+        if !self.r#type.is_empty() {
+            struct_ser.serialize_field("type", &self.r#type)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for StorageDriverInfo {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "type",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Type,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "type" => Ok(GeneratedField::Type),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = StorageDriverInfo;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct temporal.api.worker.v1.StorageDriverInfo")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<StorageDriverInfo, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut r#type__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Type => {
+                            if r#type__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("type"));
+                            }
+                            r#type__ = Some(map_.next_value()?);
+                        }
+                    }
+                }
+                Ok(StorageDriverInfo {
+                    r#type: r#type__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("temporal.api.worker.v1.StorageDriverInfo", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for WorkerCommand {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.r#type.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("temporal.api.worker.v1.WorkerCommand", len)?;
+        if let Some(v) = self.r#type.as_ref() {
+            match v {
+                worker_command::Type::CancelActivity(v) => {
+                    struct_ser.serialize_field("cancelActivity", v)?;
+                }
+            }
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for WorkerCommand {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "cancel_activity",
+            "cancelActivity",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            CancelActivity,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "cancelActivity" | "cancel_activity" => Ok(GeneratedField::CancelActivity),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = WorkerCommand;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct temporal.api.worker.v1.WorkerCommand")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<WorkerCommand, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut r#type__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::CancelActivity => {
+                            if r#type__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("cancelActivity"));
+                            }
+                            r#type__ = map_.next_value::<::std::option::Option<_>>()?.map(worker_command::Type::CancelActivity)
+;
+                        }
+                    }
+                }
+                Ok(WorkerCommand {
+                    r#type: r#type__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("temporal.api.worker.v1.WorkerCommand", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for WorkerCommandResult {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.r#type.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("temporal.api.worker.v1.WorkerCommandResult", len)?;
+        if let Some(v) = self.r#type.as_ref() {
+            match v {
+                worker_command_result::Type::CancelActivity(v) => {
+                    struct_ser.serialize_field("cancelActivity", v)?;
+                }
+            }
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for WorkerCommandResult {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "cancel_activity",
+            "cancelActivity",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            CancelActivity,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "cancelActivity" | "cancel_activity" => Ok(GeneratedField::CancelActivity),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = WorkerCommandResult;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct temporal.api.worker.v1.WorkerCommandResult")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<WorkerCommandResult, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut r#type__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::CancelActivity => {
+                            if r#type__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("cancelActivity"));
+                            }
+                            r#type__ = map_.next_value::<::std::option::Option<_>>()?.map(worker_command_result::Type::CancelActivity)
+;
+                        }
+                    }
+                }
+                Ok(WorkerCommandResult {
+                    r#type: r#type__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("temporal.api.worker.v1.WorkerCommandResult", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for WorkerHeartbeat {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.worker_instance_key.is_empty() {
+            len += 1;
+        }
+        if !self.worker_identity.is_empty() {
+            len += 1;
+        }
+        if self.host_info.is_some() {
+            len += 1;
+        }
+        if !self.task_queue.is_empty() {
+            len += 1;
+        }
+        if self.deployment_version.is_some() {
+            len += 1;
+        }
+        if !self.sdk_name.is_empty() {
+            len += 1;
+        }
+        if !self.sdk_version.is_empty() {
+            len += 1;
+        }
+        if self.status != 0 {
+            len += 1;
+        }
+        if self.start_time.is_some() {
+            len += 1;
+        }
+        if self.heartbeat_time.is_some() {
+            len += 1;
+        }
+        if self.elapsed_since_last_heartbeat.is_some() {
+            len += 1;
+        }
+        if self.workflow_task_slots_info.is_some() {
+            len += 1;
+        }
+        if self.activity_task_slots_info.is_some() {
+            len += 1;
+        }
+        if self.nexus_task_slots_info.is_some() {
+            len += 1;
+        }
+        if self.local_activity_slots_info.is_some() {
+            len += 1;
+        }
+        if self.workflow_poller_info.is_some() {
+            len += 1;
+        }
+        if self.workflow_sticky_poller_info.is_some() {
+            len += 1;
+        }
+        if self.activity_poller_info.is_some() {
+            len += 1;
+        }
+        if self.nexus_poller_info.is_some() {
+            len += 1;
+        }
+        if self.total_sticky_cache_hit != 0 {
+            len += 1;
+        }
+        if self.total_sticky_cache_miss != 0 {
+            len += 1;
+        }
+        if self.current_sticky_cache_size != 0 {
+            len += 1;
+        }
+        if !self.plugins.is_empty() {
+            len += 1;
+        }
+        if !self.drivers.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("temporal.api.worker.v1.WorkerHeartbeat", len)?;
+        if !self.worker_instance_key.is_empty() {
+            struct_ser.serialize_field("workerInstanceKey", &self.worker_instance_key)?;
+        }
+        if !self.worker_identity.is_empty() {
+            struct_ser.serialize_field("workerIdentity", &self.worker_identity)?;
+        }
+        if let Some(v) = self.host_info.as_ref() {
+            struct_ser.serialize_field("hostInfo", v)?;
+        }
+        if !self.task_queue.is_empty() {
+            struct_ser.serialize_field("taskQueue", &self.task_queue)?;
+        }
+        if let Some(v) = self.deployment_version.as_ref() {
+            struct_ser.serialize_field("deploymentVersion", v)?;
+        }
+        if !self.sdk_name.is_empty() {
+            struct_ser.serialize_field("sdkName", &self.sdk_name)?;
+        }
+        if !self.sdk_version.is_empty() {
+            struct_ser.serialize_field("sdkVersion", &self.sdk_version)?;
+        }
+        if self.status != 0 {
+            let v = super::super::enums::v1::WorkerStatus::try_from(self.status)
+                .map_err(|_| serde::ser::Error::custom(format!("Invalid variant {}", self.status)))?;
+            struct_ser.serialize_field("status", &v)?;
+        }
+        if let Some(v) = self.start_time.as_ref() {
+            struct_ser.serialize_field("startTime", v)?;
+        }
+        if let Some(v) = self.heartbeat_time.as_ref() {
+            struct_ser.serialize_field("heartbeatTime", v)?;
+        }
+        if let Some(v) = self.elapsed_since_last_heartbeat.as_ref() {
+            struct_ser.serialize_field("elapsedSinceLastHeartbeat", v)?;
+        }
+        if let Some(v) = self.workflow_task_slots_info.as_ref() {
+            struct_ser.serialize_field("workflowTaskSlotsInfo", v)?;
+        }
+        if let Some(v) = self.activity_task_slots_info.as_ref() {
+            struct_ser.serialize_field("activityTaskSlotsInfo", v)?;
+        }
+        if let Some(v) = self.nexus_task_slots_info.as_ref() {
+            struct_ser.serialize_field("nexusTaskSlotsInfo", v)?;
+        }
+        if let Some(v) = self.local_activity_slots_info.as_ref() {
+            struct_ser.serialize_field("localActivitySlotsInfo", v)?;
+        }
+        if let Some(v) = self.workflow_poller_info.as_ref() {
+            struct_ser.serialize_field("workflowPollerInfo", v)?;
+        }
+        if let Some(v) = self.workflow_sticky_poller_info.as_ref() {
+            struct_ser.serialize_field("workflowStickyPollerInfo", v)?;
+        }
+        if let Some(v) = self.activity_poller_info.as_ref() {
+            struct_ser.serialize_field("activityPollerInfo", v)?;
+        }
+        if let Some(v) = self.nexus_poller_info.as_ref() {
+            struct_ser.serialize_field("nexusPollerInfo", v)?;
+        }
+        if self.total_sticky_cache_hit != 0 {
+            struct_ser.serialize_field("totalStickyCacheHit", &self.total_sticky_cache_hit)?;
+        }
+        if self.total_sticky_cache_miss != 0 {
+            struct_ser.serialize_field("totalStickyCacheMiss", &self.total_sticky_cache_miss)?;
+        }
+        if self.current_sticky_cache_size != 0 {
+            struct_ser.serialize_field("currentStickyCacheSize", &self.current_sticky_cache_size)?;
+        }
+        if !self.plugins.is_empty() {
+            struct_ser.serialize_field("plugins", &self.plugins)?;
+        }
+        if !self.drivers.is_empty() {
+            struct_ser.serialize_field("drivers", &self.drivers)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for WorkerHeartbeat {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "worker_instance_key",
+            "workerInstanceKey",
+            "worker_identity",
+            "workerIdentity",
+            "host_info",
+            "hostInfo",
+            "task_queue",
+            "taskQueue",
+            "deployment_version",
+            "deploymentVersion",
+            "sdk_name",
+            "sdkName",
+            "sdk_version",
+            "sdkVersion",
+            "status",
+            "start_time",
+            "startTime",
+            "heartbeat_time",
+            "heartbeatTime",
+            "elapsed_since_last_heartbeat",
+            "elapsedSinceLastHeartbeat",
+            "workflow_task_slots_info",
+            "workflowTaskSlotsInfo",
+            "activity_task_slots_info",
+            "activityTaskSlotsInfo",
+            "nexus_task_slots_info",
+            "nexusTaskSlotsInfo",
+            "local_activity_slots_info",
+            "localActivitySlotsInfo",
+            "workflow_poller_info",
+            "workflowPollerInfo",
+            "workflow_sticky_poller_info",
+            "workflowStickyPollerInfo",
+            "activity_poller_info",
+            "activityPollerInfo",
+            "nexus_poller_info",
+            "nexusPollerInfo",
+            "total_sticky_cache_hit",
+            "totalStickyCacheHit",
+            "total_sticky_cache_miss",
+            "totalStickyCacheMiss",
+            "current_sticky_cache_size",
+            "currentStickyCacheSize",
+            "plugins",
+            "drivers",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            WorkerInstanceKey,
+            WorkerIdentity,
+            HostInfo,
+            TaskQueue,
+            DeploymentVersion,
+            SdkName,
+            SdkVersion,
+            Status,
+            StartTime,
+            HeartbeatTime,
+            ElapsedSinceLastHeartbeat,
+            WorkflowTaskSlotsInfo,
+            ActivityTaskSlotsInfo,
+            NexusTaskSlotsInfo,
+            LocalActivitySlotsInfo,
+            WorkflowPollerInfo,
+            WorkflowStickyPollerInfo,
+            ActivityPollerInfo,
+            NexusPollerInfo,
+            TotalStickyCacheHit,
+            TotalStickyCacheMiss,
+            CurrentStickyCacheSize,
+            Plugins,
+            Drivers,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "workerInstanceKey" | "worker_instance_key" => Ok(GeneratedField::WorkerInstanceKey),
+                            "workerIdentity" | "worker_identity" => Ok(GeneratedField::WorkerIdentity),
+                            "hostInfo" | "host_info" => Ok(GeneratedField::HostInfo),
+                            "taskQueue" | "task_queue" => Ok(GeneratedField::TaskQueue),
+                            "deploymentVersion" | "deployment_version" => Ok(GeneratedField::DeploymentVersion),
+                            "sdkName" | "sdk_name" => Ok(GeneratedField::SdkName),
+                            "sdkVersion" | "sdk_version" => Ok(GeneratedField::SdkVersion),
+                            "status" => Ok(GeneratedField::Status),
+                            "startTime" | "start_time" => Ok(GeneratedField::StartTime),
+                            "heartbeatTime" | "heartbeat_time" => Ok(GeneratedField::HeartbeatTime),
+                            "elapsedSinceLastHeartbeat" | "elapsed_since_last_heartbeat" => Ok(GeneratedField::ElapsedSinceLastHeartbeat),
+                            "workflowTaskSlotsInfo" | "workflow_task_slots_info" => Ok(GeneratedField::WorkflowTaskSlotsInfo),
+                            "activityTaskSlotsInfo" | "activity_task_slots_info" => Ok(GeneratedField::ActivityTaskSlotsInfo),
+                            "nexusTaskSlotsInfo" | "nexus_task_slots_info" => Ok(GeneratedField::NexusTaskSlotsInfo),
+                            "localActivitySlotsInfo" | "local_activity_slots_info" => Ok(GeneratedField::LocalActivitySlotsInfo),
+                            "workflowPollerInfo" | "workflow_poller_info" => Ok(GeneratedField::WorkflowPollerInfo),
+                            "workflowStickyPollerInfo" | "workflow_sticky_poller_info" => Ok(GeneratedField::WorkflowStickyPollerInfo),
+                            "activityPollerInfo" | "activity_poller_info" => Ok(GeneratedField::ActivityPollerInfo),
+                            "nexusPollerInfo" | "nexus_poller_info" => Ok(GeneratedField::NexusPollerInfo),
+                            "totalStickyCacheHit" | "total_sticky_cache_hit" => Ok(GeneratedField::TotalStickyCacheHit),
+                            "totalStickyCacheMiss" | "total_sticky_cache_miss" => Ok(GeneratedField::TotalStickyCacheMiss),
+                            "currentStickyCacheSize" | "current_sticky_cache_size" => Ok(GeneratedField::CurrentStickyCacheSize),
+                            "plugins" => Ok(GeneratedField::Plugins),
+                            "drivers" => Ok(GeneratedField::Drivers),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = WorkerHeartbeat;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct temporal.api.worker.v1.WorkerHeartbeat")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<WorkerHeartbeat, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut worker_instance_key__ = None;
+                let mut worker_identity__ = None;
+                let mut host_info__ = None;
+                let mut task_queue__ = None;
+                let mut deployment_version__ = None;
+                let mut sdk_name__ = None;
+                let mut sdk_version__ = None;
+                let mut status__ = None;
+                let mut start_time__ = None;
+                let mut heartbeat_time__ = None;
+                let mut elapsed_since_last_heartbeat__ = None;
+                let mut workflow_task_slots_info__ = None;
+                let mut activity_task_slots_info__ = None;
+                let mut nexus_task_slots_info__ = None;
+                let mut local_activity_slots_info__ = None;
+                let mut workflow_poller_info__ = None;
+                let mut workflow_sticky_poller_info__ = None;
+                let mut activity_poller_info__ = None;
+                let mut nexus_poller_info__ = None;
+                let mut total_sticky_cache_hit__ = None;
+                let mut total_sticky_cache_miss__ = None;
+                let mut current_sticky_cache_size__ = None;
+                let mut plugins__ = None;
+                let mut drivers__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::WorkerInstanceKey => {
+                            if worker_instance_key__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("workerInstanceKey"));
+                            }
+                            worker_instance_key__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::WorkerIdentity => {
+                            if worker_identity__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("workerIdentity"));
+                            }
+                            worker_identity__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::HostInfo => {
+                            if host_info__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("hostInfo"));
+                            }
+                            host_info__ = map_.next_value()?;
+                        }
+                        GeneratedField::TaskQueue => {
+                            if task_queue__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("taskQueue"));
+                            }
+                            task_queue__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::DeploymentVersion => {
+                            if deployment_version__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("deploymentVersion"));
+                            }
+                            deployment_version__ = map_.next_value()?;
+                        }
+                        GeneratedField::SdkName => {
+                            if sdk_name__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("sdkName"));
+                            }
+                            sdk_name__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::SdkVersion => {
+                            if sdk_version__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("sdkVersion"));
+                            }
+                            sdk_version__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::Status => {
+                            if status__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("status"));
+                            }
+                            status__ = Some(map_.next_value::<super::super::enums::v1::WorkerStatus>()? as i32);
+                        }
+                        GeneratedField::StartTime => {
+                            if start_time__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("startTime"));
+                            }
+                            start_time__ = map_.next_value()?;
+                        }
+                        GeneratedField::HeartbeatTime => {
+                            if heartbeat_time__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("heartbeatTime"));
+                            }
+                            heartbeat_time__ = map_.next_value()?;
+                        }
+                        GeneratedField::ElapsedSinceLastHeartbeat => {
+                            if elapsed_since_last_heartbeat__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("elapsedSinceLastHeartbeat"));
+                            }
+                            elapsed_since_last_heartbeat__ = map_.next_value()?;
+                        }
+                        GeneratedField::WorkflowTaskSlotsInfo => {
+                            if workflow_task_slots_info__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("workflowTaskSlotsInfo"));
+                            }
+                            workflow_task_slots_info__ = map_.next_value()?;
+                        }
+                        GeneratedField::ActivityTaskSlotsInfo => {
+                            if activity_task_slots_info__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("activityTaskSlotsInfo"));
+                            }
+                            activity_task_slots_info__ = map_.next_value()?;
+                        }
+                        GeneratedField::NexusTaskSlotsInfo => {
+                            if nexus_task_slots_info__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("nexusTaskSlotsInfo"));
+                            }
+                            nexus_task_slots_info__ = map_.next_value()?;
+                        }
+                        GeneratedField::LocalActivitySlotsInfo => {
+                            if local_activity_slots_info__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("localActivitySlotsInfo"));
+                            }
+                            local_activity_slots_info__ = map_.next_value()?;
+                        }
+                        GeneratedField::WorkflowPollerInfo => {
+                            if workflow_poller_info__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("workflowPollerInfo"));
+                            }
+                            workflow_poller_info__ = map_.next_value()?;
+                        }
+                        GeneratedField::WorkflowStickyPollerInfo => {
+                            if workflow_sticky_poller_info__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("workflowStickyPollerInfo"));
+                            }
+                            workflow_sticky_poller_info__ = map_.next_value()?;
+                        }
+                        GeneratedField::ActivityPollerInfo => {
+                            if activity_poller_info__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("activityPollerInfo"));
+                            }
+                            activity_poller_info__ = map_.next_value()?;
+                        }
+                        GeneratedField::NexusPollerInfo => {
+                            if nexus_poller_info__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("nexusPollerInfo"));
+                            }
+                            nexus_poller_info__ = map_.next_value()?;
+                        }
+                        GeneratedField::TotalStickyCacheHit => {
+                            if total_sticky_cache_hit__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("totalStickyCacheHit"));
+                            }
+                            total_sticky_cache_hit__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::TotalStickyCacheMiss => {
+                            if total_sticky_cache_miss__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("totalStickyCacheMiss"));
+                            }
+                            total_sticky_cache_miss__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::CurrentStickyCacheSize => {
+                            if current_sticky_cache_size__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("currentStickyCacheSize"));
+                            }
+                            current_sticky_cache_size__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::Plugins => {
+                            if plugins__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("plugins"));
+                            }
+                            plugins__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::Drivers => {
+                            if drivers__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("drivers"));
+                            }
+                            drivers__ = Some(map_.next_value()?);
+                        }
+                    }
+                }
+                Ok(WorkerHeartbeat {
+                    worker_instance_key: worker_instance_key__.unwrap_or_default(),
+                    worker_identity: worker_identity__.unwrap_or_default(),
+                    host_info: host_info__,
+                    task_queue: task_queue__.unwrap_or_default(),
+                    deployment_version: deployment_version__,
+                    sdk_name: sdk_name__.unwrap_or_default(),
+                    sdk_version: sdk_version__.unwrap_or_default(),
+                    status: status__.unwrap_or_default(),
+                    start_time: start_time__,
+                    heartbeat_time: heartbeat_time__,
+                    elapsed_since_last_heartbeat: elapsed_since_last_heartbeat__,
+                    workflow_task_slots_info: workflow_task_slots_info__,
+                    activity_task_slots_info: activity_task_slots_info__,
+                    nexus_task_slots_info: nexus_task_slots_info__,
+                    local_activity_slots_info: local_activity_slots_info__,
+                    workflow_poller_info: workflow_poller_info__,
+                    workflow_sticky_poller_info: workflow_sticky_poller_info__,
+                    activity_poller_info: activity_poller_info__,
+                    nexus_poller_info: nexus_poller_info__,
+                    total_sticky_cache_hit: total_sticky_cache_hit__.unwrap_or_default(),
+                    total_sticky_cache_miss: total_sticky_cache_miss__.unwrap_or_default(),
+                    current_sticky_cache_size: current_sticky_cache_size__.unwrap_or_default(),
+                    plugins: plugins__.unwrap_or_default(),
+                    drivers: drivers__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("temporal.api.worker.v1.WorkerHeartbeat", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for WorkerHostInfo {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.host_name.is_empty() {
+            len += 1;
+        }
+        if !self.worker_grouping_key.is_empty() {
+            len += 1;
+        }
+        if !self.process_id.is_empty() {
+            len += 1;
+        }
+        if self.current_host_cpu_usage != 0. {
+            len += 1;
+        }
+        if self.current_host_mem_usage != 0. {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("temporal.api.worker.v1.WorkerHostInfo", len)?;
+        if !self.host_name.is_empty() {
+            struct_ser.serialize_field("hostName", &self.host_name)?;
+        }
+        if !self.worker_grouping_key.is_empty() {
+            struct_ser.serialize_field("workerGroupingKey", &self.worker_grouping_key)?;
+        }
+        if !self.process_id.is_empty() {
+            struct_ser.serialize_field("processId", &self.process_id)?;
+        }
+        if self.current_host_cpu_usage != 0. {
+            struct_ser.serialize_field("currentHostCpuUsage", &self.current_host_cpu_usage)?;
+        }
+        if self.current_host_mem_usage != 0. {
+            struct_ser.serialize_field("currentHostMemUsage", &self.current_host_mem_usage)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for WorkerHostInfo {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "host_name",
+            "hostName",
+            "worker_grouping_key",
+            "workerGroupingKey",
+            "process_id",
+            "processId",
+            "current_host_cpu_usage",
+            "currentHostCpuUsage",
+            "current_host_mem_usage",
+            "currentHostMemUsage",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            HostName,
+            WorkerGroupingKey,
+            ProcessId,
+            CurrentHostCpuUsage,
+            CurrentHostMemUsage,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "hostName" | "host_name" => Ok(GeneratedField::HostName),
+                            "workerGroupingKey" | "worker_grouping_key" => Ok(GeneratedField::WorkerGroupingKey),
+                            "processId" | "process_id" => Ok(GeneratedField::ProcessId),
+                            "currentHostCpuUsage" | "current_host_cpu_usage" => Ok(GeneratedField::CurrentHostCpuUsage),
+                            "currentHostMemUsage" | "current_host_mem_usage" => Ok(GeneratedField::CurrentHostMemUsage),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = WorkerHostInfo;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct temporal.api.worker.v1.WorkerHostInfo")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<WorkerHostInfo, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut host_name__ = None;
+                let mut worker_grouping_key__ = None;
+                let mut process_id__ = None;
+                let mut current_host_cpu_usage__ = None;
+                let mut current_host_mem_usage__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::HostName => {
+                            if host_name__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("hostName"));
+                            }
+                            host_name__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::WorkerGroupingKey => {
+                            if worker_grouping_key__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("workerGroupingKey"));
+                            }
+                            worker_grouping_key__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::ProcessId => {
+                            if process_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("processId"));
+                            }
+                            process_id__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::CurrentHostCpuUsage => {
+                            if current_host_cpu_usage__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("currentHostCpuUsage"));
+                            }
+                            current_host_cpu_usage__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::CurrentHostMemUsage => {
+                            if current_host_mem_usage__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("currentHostMemUsage"));
+                            }
+                            current_host_mem_usage__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                    }
+                }
+                Ok(WorkerHostInfo {
+                    host_name: host_name__.unwrap_or_default(),
+                    worker_grouping_key: worker_grouping_key__.unwrap_or_default(),
+                    process_id: process_id__.unwrap_or_default(),
+                    current_host_cpu_usage: current_host_cpu_usage__.unwrap_or_default(),
+                    current_host_mem_usage: current_host_mem_usage__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("temporal.api.worker.v1.WorkerHostInfo", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for WorkerInfo {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.worker_heartbeat.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("temporal.api.worker.v1.WorkerInfo", len)?;
+        if let Some(v) = self.worker_heartbeat.as_ref() {
+            struct_ser.serialize_field("workerHeartbeat", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for WorkerInfo {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "worker_heartbeat",
+            "workerHeartbeat",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            WorkerHeartbeat,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "workerHeartbeat" | "worker_heartbeat" => Ok(GeneratedField::WorkerHeartbeat),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = WorkerInfo;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct temporal.api.worker.v1.WorkerInfo")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<WorkerInfo, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut worker_heartbeat__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::WorkerHeartbeat => {
+                            if worker_heartbeat__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("workerHeartbeat"));
+                            }
+                            worker_heartbeat__ = map_.next_value()?;
+                        }
+                    }
+                }
+                Ok(WorkerInfo {
+                    worker_heartbeat: worker_heartbeat__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("temporal.api.worker.v1.WorkerInfo", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for WorkerListInfo {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.worker_instance_key.is_empty() {
+            len += 1;
+        }
+        if !self.worker_identity.is_empty() {
+            len += 1;
+        }
+        if !self.task_queue.is_empty() {
+            len += 1;
+        }
+        if self.deployment_version.is_some() {
+            len += 1;
+        }
+        if !self.sdk_name.is_empty() {
+            len += 1;
+        }
+        if !self.sdk_version.is_empty() {
+            len += 1;
+        }
+        if self.status != 0 {
+            len += 1;
+        }
+        if self.start_time.is_some() {
+            len += 1;
+        }
+        if !self.host_name.is_empty() {
+            len += 1;
+        }
+        if !self.worker_grouping_key.is_empty() {
+            len += 1;
+        }
+        if !self.process_id.is_empty() {
+            len += 1;
+        }
+        if !self.plugins.is_empty() {
+            len += 1;
+        }
+        if !self.drivers.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("temporal.api.worker.v1.WorkerListInfo", len)?;
+        if !self.worker_instance_key.is_empty() {
+            struct_ser.serialize_field("workerInstanceKey", &self.worker_instance_key)?;
+        }
+        if !self.worker_identity.is_empty() {
+            struct_ser.serialize_field("workerIdentity", &self.worker_identity)?;
+        }
+        if !self.task_queue.is_empty() {
+            struct_ser.serialize_field("taskQueue", &self.task_queue)?;
+        }
+        if let Some(v) = self.deployment_version.as_ref() {
+            struct_ser.serialize_field("deploymentVersion", v)?;
+        }
+        if !self.sdk_name.is_empty() {
+            struct_ser.serialize_field("sdkName", &self.sdk_name)?;
+        }
+        if !self.sdk_version.is_empty() {
+            struct_ser.serialize_field("sdkVersion", &self.sdk_version)?;
+        }
+        if self.status != 0 {
+            let v = super::super::enums::v1::WorkerStatus::try_from(self.status)
+                .map_err(|_| serde::ser::Error::custom(format!("Invalid variant {}", self.status)))?;
+            struct_ser.serialize_field("status", &v)?;
+        }
+        if let Some(v) = self.start_time.as_ref() {
+            struct_ser.serialize_field("startTime", v)?;
+        }
+        if !self.host_name.is_empty() {
+            struct_ser.serialize_field("hostName", &self.host_name)?;
+        }
+        if !self.worker_grouping_key.is_empty() {
+            struct_ser.serialize_field("workerGroupingKey", &self.worker_grouping_key)?;
+        }
+        if !self.process_id.is_empty() {
+            struct_ser.serialize_field("processId", &self.process_id)?;
+        }
+        if !self.plugins.is_empty() {
+            struct_ser.serialize_field("plugins", &self.plugins)?;
+        }
+        if !self.drivers.is_empty() {
+            struct_ser.serialize_field("drivers", &self.drivers)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for WorkerListInfo {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "worker_instance_key",
+            "workerInstanceKey",
+            "worker_identity",
+            "workerIdentity",
+            "task_queue",
+            "taskQueue",
+            "deployment_version",
+            "deploymentVersion",
+            "sdk_name",
+            "sdkName",
+            "sdk_version",
+            "sdkVersion",
+            "status",
+            "start_time",
+            "startTime",
+            "host_name",
+            "hostName",
+            "worker_grouping_key",
+            "workerGroupingKey",
+            "process_id",
+            "processId",
+            "plugins",
+            "drivers",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            WorkerInstanceKey,
+            WorkerIdentity,
+            TaskQueue,
+            DeploymentVersion,
+            SdkName,
+            SdkVersion,
+            Status,
+            StartTime,
+            HostName,
+            WorkerGroupingKey,
+            ProcessId,
+            Plugins,
+            Drivers,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "workerInstanceKey" | "worker_instance_key" => Ok(GeneratedField::WorkerInstanceKey),
+                            "workerIdentity" | "worker_identity" => Ok(GeneratedField::WorkerIdentity),
+                            "taskQueue" | "task_queue" => Ok(GeneratedField::TaskQueue),
+                            "deploymentVersion" | "deployment_version" => Ok(GeneratedField::DeploymentVersion),
+                            "sdkName" | "sdk_name" => Ok(GeneratedField::SdkName),
+                            "sdkVersion" | "sdk_version" => Ok(GeneratedField::SdkVersion),
+                            "status" => Ok(GeneratedField::Status),
+                            "startTime" | "start_time" => Ok(GeneratedField::StartTime),
+                            "hostName" | "host_name" => Ok(GeneratedField::HostName),
+                            "workerGroupingKey" | "worker_grouping_key" => Ok(GeneratedField::WorkerGroupingKey),
+                            "processId" | "process_id" => Ok(GeneratedField::ProcessId),
+                            "plugins" => Ok(GeneratedField::Plugins),
+                            "drivers" => Ok(GeneratedField::Drivers),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = WorkerListInfo;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct temporal.api.worker.v1.WorkerListInfo")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<WorkerListInfo, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut worker_instance_key__ = None;
+                let mut worker_identity__ = None;
+                let mut task_queue__ = None;
+                let mut deployment_version__ = None;
+                let mut sdk_name__ = None;
+                let mut sdk_version__ = None;
+                let mut status__ = None;
+                let mut start_time__ = None;
+                let mut host_name__ = None;
+                let mut worker_grouping_key__ = None;
+                let mut process_id__ = None;
+                let mut plugins__ = None;
+                let mut drivers__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::WorkerInstanceKey => {
+                            if worker_instance_key__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("workerInstanceKey"));
+                            }
+                            worker_instance_key__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::WorkerIdentity => {
+                            if worker_identity__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("workerIdentity"));
+                            }
+                            worker_identity__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::TaskQueue => {
+                            if task_queue__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("taskQueue"));
+                            }
+                            task_queue__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::DeploymentVersion => {
+                            if deployment_version__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("deploymentVersion"));
+                            }
+                            deployment_version__ = map_.next_value()?;
+                        }
+                        GeneratedField::SdkName => {
+                            if sdk_name__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("sdkName"));
+                            }
+                            sdk_name__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::SdkVersion => {
+                            if sdk_version__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("sdkVersion"));
+                            }
+                            sdk_version__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::Status => {
+                            if status__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("status"));
+                            }
+                            status__ = Some(map_.next_value::<super::super::enums::v1::WorkerStatus>()? as i32);
+                        }
+                        GeneratedField::StartTime => {
+                            if start_time__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("startTime"));
+                            }
+                            start_time__ = map_.next_value()?;
+                        }
+                        GeneratedField::HostName => {
+                            if host_name__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("hostName"));
+                            }
+                            host_name__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::WorkerGroupingKey => {
+                            if worker_grouping_key__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("workerGroupingKey"));
+                            }
+                            worker_grouping_key__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::ProcessId => {
+                            if process_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("processId"));
+                            }
+                            process_id__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::Plugins => {
+                            if plugins__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("plugins"));
+                            }
+                            plugins__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::Drivers => {
+                            if drivers__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("drivers"));
+                            }
+                            drivers__ = Some(map_.next_value()?);
+                        }
+                    }
+                }
+                Ok(WorkerListInfo {
+                    worker_instance_key: worker_instance_key__.unwrap_or_default(),
+                    worker_identity: worker_identity__.unwrap_or_default(),
+                    task_queue: task_queue__.unwrap_or_default(),
+                    deployment_version: deployment_version__,
+                    sdk_name: sdk_name__.unwrap_or_default(),
+                    sdk_version: sdk_version__.unwrap_or_default(),
+                    status: status__.unwrap_or_default(),
+                    start_time: start_time__,
+                    host_name: host_name__.unwrap_or_default(),
+                    worker_grouping_key: worker_grouping_key__.unwrap_or_default(),
+                    process_id: process_id__.unwrap_or_default(),
+                    plugins: plugins__.unwrap_or_default(),
+                    drivers: drivers__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("temporal.api.worker.v1.WorkerListInfo", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for WorkerPollerInfo {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.current_pollers != 0 {
+            len += 1;
+        }
+        if self.last_successful_poll_time.is_some() {
+            len += 1;
+        }
+        if self.is_autoscaling {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("temporal.api.worker.v1.WorkerPollerInfo", len)?;
+        if self.current_pollers != 0 {
+            struct_ser.serialize_field("currentPollers", &self.current_pollers)?;
+        }
+        if let Some(v) = self.last_successful_poll_time.as_ref() {
+            struct_ser.serialize_field("lastSuccessfulPollTime", v)?;
+        }
+        if self.is_autoscaling {
+            struct_ser.serialize_field("isAutoscaling", &self.is_autoscaling)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for WorkerPollerInfo {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "current_pollers",
+            "currentPollers",
+            "last_successful_poll_time",
+            "lastSuccessfulPollTime",
+            "is_autoscaling",
+            "isAutoscaling",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            CurrentPollers,
+            LastSuccessfulPollTime,
+            IsAutoscaling,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "currentPollers" | "current_pollers" => Ok(GeneratedField::CurrentPollers),
+                            "lastSuccessfulPollTime" | "last_successful_poll_time" => Ok(GeneratedField::LastSuccessfulPollTime),
+                            "isAutoscaling" | "is_autoscaling" => Ok(GeneratedField::IsAutoscaling),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = WorkerPollerInfo;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct temporal.api.worker.v1.WorkerPollerInfo")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<WorkerPollerInfo, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut current_pollers__ = None;
+                let mut last_successful_poll_time__ = None;
+                let mut is_autoscaling__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::CurrentPollers => {
+                            if current_pollers__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("currentPollers"));
+                            }
+                            current_pollers__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::LastSuccessfulPollTime => {
+                            if last_successful_poll_time__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("lastSuccessfulPollTime"));
+                            }
+                            last_successful_poll_time__ = map_.next_value()?;
+                        }
+                        GeneratedField::IsAutoscaling => {
+                            if is_autoscaling__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("isAutoscaling"));
+                            }
+                            is_autoscaling__ = Some(map_.next_value()?);
+                        }
+                    }
+                }
+                Ok(WorkerPollerInfo {
+                    current_pollers: current_pollers__.unwrap_or_default(),
+                    last_successful_poll_time: last_successful_poll_time__,
+                    is_autoscaling: is_autoscaling__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("temporal.api.worker.v1.WorkerPollerInfo", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for WorkerSlotsInfo {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.current_available_slots != 0 {
+            len += 1;
+        }
+        if self.current_used_slots != 0 {
+            len += 1;
+        }
+        if !self.slot_supplier_kind.is_empty() {
+            len += 1;
+        }
+        if self.total_processed_tasks != 0 {
+            len += 1;
+        }
+        if self.total_failed_tasks != 0 {
+            len += 1;
+        }
+        if self.last_interval_processed_tasks != 0 {
+            len += 1;
+        }
+        if self.last_interval_failure_tasks != 0 {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("temporal.api.worker.v1.WorkerSlotsInfo", len)?;
+        if self.current_available_slots != 0 {
+            struct_ser.serialize_field("currentAvailableSlots", &self.current_available_slots)?;
+        }
+        if self.current_used_slots != 0 {
+            struct_ser.serialize_field("currentUsedSlots", &self.current_used_slots)?;
+        }
+        if !self.slot_supplier_kind.is_empty() {
+            struct_ser.serialize_field("slotSupplierKind", &self.slot_supplier_kind)?;
+        }
+        if self.total_processed_tasks != 0 {
+            struct_ser.serialize_field("totalProcessedTasks", &self.total_processed_tasks)?;
+        }
+        if self.total_failed_tasks != 0 {
+            struct_ser.serialize_field("totalFailedTasks", &self.total_failed_tasks)?;
+        }
+        if self.last_interval_processed_tasks != 0 {
+            struct_ser.serialize_field("lastIntervalProcessedTasks", &self.last_interval_processed_tasks)?;
+        }
+        if self.last_interval_failure_tasks != 0 {
+            struct_ser.serialize_field("lastIntervalFailureTasks", &self.last_interval_failure_tasks)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for WorkerSlotsInfo {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "current_available_slots",
+            "currentAvailableSlots",
+            "current_used_slots",
+            "currentUsedSlots",
+            "slot_supplier_kind",
+            "slotSupplierKind",
+            "total_processed_tasks",
+            "totalProcessedTasks",
+            "total_failed_tasks",
+            "totalFailedTasks",
+            "last_interval_processed_tasks",
+            "lastIntervalProcessedTasks",
+            "last_interval_failure_tasks",
+            "lastIntervalFailureTasks",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            CurrentAvailableSlots,
+            CurrentUsedSlots,
+            SlotSupplierKind,
+            TotalProcessedTasks,
+            TotalFailedTasks,
+            LastIntervalProcessedTasks,
+            LastIntervalFailureTasks,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "currentAvailableSlots" | "current_available_slots" => Ok(GeneratedField::CurrentAvailableSlots),
+                            "currentUsedSlots" | "current_used_slots" => Ok(GeneratedField::CurrentUsedSlots),
+                            "slotSupplierKind" | "slot_supplier_kind" => Ok(GeneratedField::SlotSupplierKind),
+                            "totalProcessedTasks" | "total_processed_tasks" => Ok(GeneratedField::TotalProcessedTasks),
+                            "totalFailedTasks" | "total_failed_tasks" => Ok(GeneratedField::TotalFailedTasks),
+                            "lastIntervalProcessedTasks" | "last_interval_processed_tasks" => Ok(GeneratedField::LastIntervalProcessedTasks),
+                            "lastIntervalFailureTasks" | "last_interval_failure_tasks" => Ok(GeneratedField::LastIntervalFailureTasks),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = WorkerSlotsInfo;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct temporal.api.worker.v1.WorkerSlotsInfo")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<WorkerSlotsInfo, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut current_available_slots__ = None;
+                let mut current_used_slots__ = None;
+                let mut slot_supplier_kind__ = None;
+                let mut total_processed_tasks__ = None;
+                let mut total_failed_tasks__ = None;
+                let mut last_interval_processed_tasks__ = None;
+                let mut last_interval_failure_tasks__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::CurrentAvailableSlots => {
+                            if current_available_slots__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("currentAvailableSlots"));
+                            }
+                            current_available_slots__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::CurrentUsedSlots => {
+                            if current_used_slots__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("currentUsedSlots"));
+                            }
+                            current_used_slots__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::SlotSupplierKind => {
+                            if slot_supplier_kind__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("slotSupplierKind"));
+                            }
+                            slot_supplier_kind__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::TotalProcessedTasks => {
+                            if total_processed_tasks__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("totalProcessedTasks"));
+                            }
+                            total_processed_tasks__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::TotalFailedTasks => {
+                            if total_failed_tasks__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("totalFailedTasks"));
+                            }
+                            total_failed_tasks__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::LastIntervalProcessedTasks => {
+                            if last_interval_processed_tasks__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("lastIntervalProcessedTasks"));
+                            }
+                            last_interval_processed_tasks__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::LastIntervalFailureTasks => {
+                            if last_interval_failure_tasks__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("lastIntervalFailureTasks"));
+                            }
+                            last_interval_failure_tasks__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                    }
+                }
+                Ok(WorkerSlotsInfo {
+                    current_available_slots: current_available_slots__.unwrap_or_default(),
+                    current_used_slots: current_used_slots__.unwrap_or_default(),
+                    slot_supplier_kind: slot_supplier_kind__.unwrap_or_default(),
+                    total_processed_tasks: total_processed_tasks__.unwrap_or_default(),
+                    total_failed_tasks: total_failed_tasks__.unwrap_or_default(),
+                    last_interval_processed_tasks: last_interval_processed_tasks__.unwrap_or_default(),
+                    last_interval_failure_tasks: last_interval_failure_tasks__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("temporal.api.worker.v1.WorkerSlotsInfo", FIELDS, GeneratedVisitor)
+    }
+}
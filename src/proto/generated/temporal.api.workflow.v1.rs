@@ -8,9 +8,9 @@ pub struct WorkflowExecutionInfo {
     #[prost(message, optional, tag = "2")]
     pub r#type: ::core::option::Option<super::super::common::v1::WorkflowType>,
     #[prost(message, optional, tag = "3")]
-    pub start_time: ::core::option::Option<::prost_types::Timestamp>,
+    pub start_time: ::core::option::Option<::pbjson_types::Timestamp>,
     #[prost(message, optional, tag = "4")]
-    pub close_time: ::core::option::Option<::prost_types::Timestamp>,
+    pub close_time: ::core::option::Option<::pbjson_types::Timestamp>,
     #[prost(enumeration = "super::super::enums::v1::WorkflowExecutionStatus", tag = "5")]
     pub status: i32,
     #[prost(int64, tag = "6")]
@@ -22,7 +22,7 @@ pub struct WorkflowExecutionInfo {
         super::super::common::v1::WorkflowExecution,
     >,
     #[prost(message, optional, tag = "9")]
-    pub execution_time: ::core::option::Option<::prost_types::Timestamp>,
+    pub execution_time: ::core::option::Option<::pbjson_types::Timestamp>,
     #[prost(message, optional, tag = "10")]
     pub memo: ::core::option::Option<super::super::common::v1::Memo>,
     #[prost(message, optional, tag = "11")]
@@ -47,7 +47,7 @@ pub struct WorkflowExecutionInfo {
     /// Workflow execution duration is defined as difference between close time and execution time.
     /// This field is only populated if the workflow is closed.
     #[prost(message, optional, tag = "17")]
-    pub execution_duration: ::core::option::Option<::prost_types::Duration>,
+    pub execution_duration: ::core::option::Option<::pbjson_types::Duration>,
     /// Contains information about the root workflow execution.
     /// The root workflow execution is defined as follows:
     /// 1. A workflow without parent workflow is its own root workflow.
@@ -99,7 +99,6 @@ pub struct WorkflowExecutionInfo {
     #[prost(message, optional, tag = "22")]
     pub versioning_info: ::core::option::Option<WorkflowExecutionVersioningInfo>,
     /// The name of Worker Deployment that completed the most recent workflow task.
-    /// Experimental. Worker Deployments are experimental and might change in the future.
     #[prost(string, tag = "23")]
     pub worker_deployment_name: ::prost::alloc::string::String,
     /// Priority metadata
@@ -118,19 +117,19 @@ pub struct WorkflowExecutionExtendedInfo {
     /// Workflow execution expiration time is defined as workflow start time plus expiration timeout.
     /// Workflow start time may change after workflow reset.
     #[prost(message, optional, tag = "1")]
-    pub execution_expiration_time: ::core::option::Option<::prost_types::Timestamp>,
+    pub execution_expiration_time: ::core::option::Option<::pbjson_types::Timestamp>,
     /// Workflow run expiration time is defined as current workflow run start time plus workflow run timeout.
     #[prost(message, optional, tag = "2")]
-    pub run_expiration_time: ::core::option::Option<::prost_types::Timestamp>,
+    pub run_expiration_time: ::core::option::Option<::pbjson_types::Timestamp>,
     /// indicates if the workflow received a cancel request
     #[prost(bool, tag = "3")]
     pub cancel_requested: bool,
     /// Last workflow reset time. Nil if the workflow was never reset.
     #[prost(message, optional, tag = "4")]
-    pub last_reset_time: ::core::option::Option<::prost_types::Timestamp>,
+    pub last_reset_time: ::core::option::Option<::pbjson_types::Timestamp>,
     /// Original workflow start time.
     #[prost(message, optional, tag = "5")]
-    pub original_start_time: ::core::option::Option<::prost_types::Timestamp>,
+    pub original_start_time: ::core::option::Option<::pbjson_types::Timestamp>,
     /// Reset Run ID points to the new run when this execution is reset. If the execution is reset multiple times, it points to the latest run.
     #[prost(string, tag = "6")]
     pub reset_run_id: ::prost::alloc::string::String,
@@ -256,6 +255,21 @@ pub struct WorkflowExecutionVersioningInfo {
     /// task queues and their partitions.
     #[prost(int64, tag = "8")]
     pub revision_number: i64,
+    /// Experimental.
+    /// If this workflow is the result of a continue-as-new, this field is set to the initial_versioning_behavior
+    /// specified in that command.
+    /// Only used for the initial task of this run and the initial task of any retries of this run.
+    /// Not passed to children or to future continue-as-new.
+    ///
+    /// Note: In the first release of Upgrade-on-CaN, when the only ContinueAsNewVersioningBehavior was AutoUpgrade,
+    /// a non-empty InheritedAutoUpgradeInfo meant that the workflow should start as AutoUpgrade. So for compatibility
+    /// with ContinueAsNew history commands generated during that time, know that an UNSPECIFIED value here is equivalent
+    /// to ContinueAsNewVersioningBehaviorAutoUpgrade if the behavior of the workflow is AutoUpgrade.
+    #[prost(
+        enumeration = "super::super::enums::v1::ContinueAsNewVersioningBehavior",
+        tag = "9"
+    )]
+    pub continue_as_new_initial_versioning_behavior: i32,
 }
 /// Holds information about ongoing transition of a workflow execution from one deployment to another.
 /// Deprecated. Use DeploymentVersionTransition.
@@ -287,11 +301,11 @@ pub struct WorkflowExecutionConfig {
     #[prost(message, optional, tag = "1")]
     pub task_queue: ::core::option::Option<super::super::taskqueue::v1::TaskQueue>,
     #[prost(message, optional, tag = "2")]
-    pub workflow_execution_timeout: ::core::option::Option<::prost_types::Duration>,
+    pub workflow_execution_timeout: ::core::option::Option<::pbjson_types::Duration>,
     #[prost(message, optional, tag = "3")]
-    pub workflow_run_timeout: ::core::option::Option<::prost_types::Duration>,
+    pub workflow_run_timeout: ::core::option::Option<::pbjson_types::Duration>,
     #[prost(message, optional, tag = "4")]
-    pub default_workflow_task_timeout: ::core::option::Option<::prost_types::Duration>,
+    pub default_workflow_task_timeout: ::core::option::Option<::pbjson_types::Duration>,
     /// User metadata provided on start workflow.
     #[prost(message, optional, tag = "5")]
     pub user_metadata: ::core::option::Option<super::super::sdk::v1::UserMetadata>,
@@ -307,17 +321,17 @@ pub struct PendingActivityInfo {
     #[prost(message, optional, tag = "4")]
     pub heartbeat_details: ::core::option::Option<super::super::common::v1::Payloads>,
     #[prost(message, optional, tag = "5")]
-    pub last_heartbeat_time: ::core::option::Option<::prost_types::Timestamp>,
+    pub last_heartbeat_time: ::core::option::Option<::pbjson_types::Timestamp>,
     #[prost(message, optional, tag = "6")]
-    pub last_started_time: ::core::option::Option<::prost_types::Timestamp>,
+    pub last_started_time: ::core::option::Option<::pbjson_types::Timestamp>,
     #[prost(int32, tag = "7")]
     pub attempt: i32,
     #[prost(int32, tag = "8")]
     pub maximum_attempts: i32,
     #[prost(message, optional, tag = "9")]
-    pub scheduled_time: ::core::option::Option<::prost_types::Timestamp>,
+    pub scheduled_time: ::core::option::Option<::pbjson_types::Timestamp>,
     #[prost(message, optional, tag = "10")]
-    pub expiration_time: ::core::option::Option<::prost_types::Timestamp>,
+    pub expiration_time: ::core::option::Option<::pbjson_types::Timestamp>,
     #[prost(message, optional, tag = "11")]
     pub last_failure: ::core::option::Option<super::super::failure::v1::Failure>,
     #[prost(string, tag = "12")]
@@ -334,14 +348,14 @@ pub struct PendingActivityInfo {
     /// If activity is currently waiting it will be current retry interval.
     /// If there will be no retry it will be null.
     #[prost(message, optional, tag = "16")]
-    pub current_retry_interval: ::core::option::Option<::prost_types::Duration>,
+    pub current_retry_interval: ::core::option::Option<::pbjson_types::Duration>,
     /// The time when the last activity attempt was completed. If activity has not been completed yet then it will be null.
     #[prost(message, optional, tag = "17")]
-    pub last_attempt_complete_time: ::core::option::Option<::prost_types::Timestamp>,
+    pub last_attempt_complete_time: ::core::option::Option<::pbjson_types::Timestamp>,
     /// Next time when activity will be scheduled.
     /// If activity is currently scheduled or started it will be null.
     #[prost(message, optional, tag = "18")]
-    pub next_attempt_schedule_time: ::core::option::Option<::prost_types::Timestamp>,
+    pub next_attempt_schedule_time: ::core::option::Option<::pbjson_types::Timestamp>,
     /// Indicates if activity is paused.
     #[prost(bool, tag = "19")]
     pub paused: bool,
@@ -390,7 +404,7 @@ pub mod pending_activity_info {
     pub struct PauseInfo {
         /// The time when the activity was paused.
         #[prost(message, optional, tag = "1")]
-        pub pause_time: ::core::option::Option<::prost_types::Timestamp>,
+        pub pause_time: ::core::option::Option<::pbjson_types::Timestamp>,
         #[prost(oneof = "pause_info::PausedBy", tags = "2, 4")]
         pub paused_by: ::core::option::Option<pause_info::PausedBy>,
     }
@@ -435,7 +449,7 @@ pub mod pending_activity_info {
     pub enum AssignedBuildId {
         /// Deprecated. When present, it means this activity is assigned to the build ID of its workflow.
         #[prost(message, tag = "13")]
-        UseWorkflowBuildId(()),
+        UseWorkflowBuildId(::pbjson_types::Empty),
         /// Deprecated. This means the activity is independently versioned and not bound to the build ID of its workflow.
         /// The activity will use the build id in this field instead.
         /// If the task fails and is scheduled again, the assigned build ID may change according to the latest versioning
@@ -466,15 +480,15 @@ pub struct PendingWorkflowTaskInfo {
     )]
     pub state: i32,
     #[prost(message, optional, tag = "2")]
-    pub scheduled_time: ::core::option::Option<::prost_types::Timestamp>,
+    pub scheduled_time: ::core::option::Option<::pbjson_types::Timestamp>,
     /// original_scheduled_time is the scheduled time of the first workflow task during workflow task heartbeat.
     /// Heartbeat workflow task is done by RespondWorkflowTaskComplete with ForceCreateNewWorkflowTask == true and no command
     /// In this case, OriginalScheduledTime won't change. Then when current time - original_scheduled_time exceeds
     /// some threshold, the workflow task will be forced timeout.
     #[prost(message, optional, tag = "3")]
-    pub original_scheduled_time: ::core::option::Option<::prost_types::Timestamp>,
+    pub original_scheduled_time: ::core::option::Option<::pbjson_types::Timestamp>,
     #[prost(message, optional, tag = "4")]
-    pub started_time: ::core::option::Option<::prost_types::Timestamp>,
+    pub started_time: ::core::option::Option<::pbjson_types::Timestamp>,
     #[prost(int32, tag = "5")]
     pub attempt: i32,
 }
@@ -502,12 +516,12 @@ pub struct ResetPointInfo {
     #[prost(int64, tag = "3")]
     pub first_workflow_task_completed_id: i64,
     #[prost(message, optional, tag = "4")]
-    pub create_time: ::core::option::Option<::prost_types::Timestamp>,
+    pub create_time: ::core::option::Option<::pbjson_types::Timestamp>,
     /// (-- api-linter: core::0214::resource-expiry=disabled
     ///      aip.dev/not-precedent: TTL is not defined for ResetPointInfo. --)
     /// The time that the run is deleted due to retention.
     #[prost(message, optional, tag = "5")]
-    pub expire_time: ::core::option::Option<::prost_types::Timestamp>,
+    pub expire_time: ::core::option::Option<::pbjson_types::Timestamp>,
     /// false if the reset point has pending childWFs/reqCancels/signalExternals.
     #[prost(bool, tag = "6")]
     pub resettable: bool,
@@ -527,13 +541,13 @@ pub struct NewWorkflowExecutionInfo {
     pub input: ::core::option::Option<super::super::common::v1::Payloads>,
     /// Total workflow execution timeout including retries and continue as new.
     #[prost(message, optional, tag = "5")]
-    pub workflow_execution_timeout: ::core::option::Option<::prost_types::Duration>,
+    pub workflow_execution_timeout: ::core::option::Option<::pbjson_types::Duration>,
     /// Timeout of a single workflow run.
     #[prost(message, optional, tag = "6")]
-    pub workflow_run_timeout: ::core::option::Option<::prost_types::Duration>,
+    pub workflow_run_timeout: ::core::option::Option<::pbjson_types::Duration>,
     /// Timeout of a single workflow task.
     #[prost(message, optional, tag = "7")]
-    pub workflow_task_timeout: ::core::option::Option<::prost_types::Duration>,
+    pub workflow_task_timeout: ::core::option::Option<::pbjson_types::Duration>,
     /// Default: WORKFLOW_ID_REUSE_POLICY_ALLOW_DUPLICATE.
     #[prost(enumeration = "super::super::enums::v1::WorkflowIdReusePolicy", tag = "8")]
     pub workflow_id_reuse_policy: i32,
@@ -575,7 +589,7 @@ pub struct CallbackInfo {
     pub trigger: ::core::option::Option<callback_info::Trigger>,
     /// The time when the callback was registered.
     #[prost(message, optional, tag = "3")]
-    pub registration_time: ::core::option::Option<::prost_types::Timestamp>,
+    pub registration_time: ::core::option::Option<::pbjson_types::Timestamp>,
     #[prost(enumeration = "super::super::enums::v1::CallbackState", tag = "4")]
     pub state: i32,
     /// The number of attempts made to deliver the callback.
@@ -584,13 +598,13 @@ pub struct CallbackInfo {
     pub attempt: i32,
     /// The time when the last attempt completed.
     #[prost(message, optional, tag = "6")]
-    pub last_attempt_complete_time: ::core::option::Option<::prost_types::Timestamp>,
+    pub last_attempt_complete_time: ::core::option::Option<::pbjson_types::Timestamp>,
     /// The last attempt's failure, if any.
     #[prost(message, optional, tag = "7")]
     pub last_attempt_failure: ::core::option::Option<super::super::failure::v1::Failure>,
     /// The time when the next attempt is scheduled.
     #[prost(message, optional, tag = "8")]
-    pub next_attempt_schedule_time: ::core::option::Option<::prost_types::Timestamp>,
+    pub next_attempt_schedule_time: ::core::option::Option<::pbjson_types::Timestamp>,
     /// If the state is BLOCKED, blocked reason provides additional information.
     #[prost(string, tag = "9")]
     pub blocked_reason: ::prost::alloc::string::String,
@@ -600,17 +614,25 @@ pub mod callback_info {
     /// Trigger for when the workflow is closed.
     #[derive(Clone, Copy, PartialEq, ::prost::Message)]
     pub struct WorkflowClosed {}
-    #[derive(Clone, Copy, PartialEq, ::prost::Message)]
+    /// Trigger for when a workflow update is completed.
+    #[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct UpdateWorkflowExecutionCompleted {
+        #[prost(string, tag = "1")]
+        pub update_id: ::prost::alloc::string::String,
+    }
+    #[derive(Clone, PartialEq, ::prost::Message)]
     pub struct Trigger {
-        #[prost(oneof = "trigger::Variant", tags = "1")]
+        #[prost(oneof = "trigger::Variant", tags = "1, 2")]
         pub variant: ::core::option::Option<trigger::Variant>,
     }
     /// Nested message and enum types in `Trigger`.
     pub mod trigger {
-        #[derive(Clone, Copy, PartialEq, ::prost::Oneof)]
+        #[derive(Clone, PartialEq, ::prost::Oneof)]
         pub enum Variant {
             #[prost(message, tag = "1")]
             WorkflowClosed(super::WorkflowClosed),
+            #[prost(message, tag = "2")]
+            UpdateWorkflowExecutionCompleted(super::UpdateWorkflowExecutionCompleted),
         }
     }
 }
@@ -638,28 +660,30 @@ pub struct PendingNexusOperationInfo {
     /// (-- api-linter: core::0140::prepositions=disabled
     ///      aip.dev/not-precedent: "to" is used to indicate interval. --)
     #[prost(message, optional, tag = "5")]
-    pub schedule_to_close_timeout: ::core::option::Option<::prost_types::Duration>,
+    pub schedule_to_close_timeout: ::core::option::Option<::pbjson_types::Duration>,
     /// The time when the operation was scheduled.
     #[prost(message, optional, tag = "6")]
-    pub scheduled_time: ::core::option::Option<::prost_types::Timestamp>,
+    pub scheduled_time: ::core::option::Option<::pbjson_types::Timestamp>,
     #[prost(
         enumeration = "super::super::enums::v1::PendingNexusOperationState",
         tag = "7"
     )]
     pub state: i32,
     /// The number of attempts made to deliver the start operation request.
-    /// This number represents a minimum bound since the attempt is incremented after the request completes.
+    /// This number is approximate, it is incremented when a task is added to the history queue.
+    /// In practice, there could be more attempts if a task is executed but fails to commit, or less attempts if a task
+    /// was never executed.
     #[prost(int32, tag = "8")]
     pub attempt: i32,
     /// The time when the last attempt completed.
     #[prost(message, optional, tag = "9")]
-    pub last_attempt_complete_time: ::core::option::Option<::prost_types::Timestamp>,
+    pub last_attempt_complete_time: ::core::option::Option<::pbjson_types::Timestamp>,
     /// The last attempt's failure, if any.
     #[prost(message, optional, tag = "10")]
     pub last_attempt_failure: ::core::option::Option<super::super::failure::v1::Failure>,
     /// The time when the next attempt is scheduled.
     #[prost(message, optional, tag = "11")]
-    pub next_attempt_schedule_time: ::core::option::Option<::prost_types::Timestamp>,
+    pub next_attempt_schedule_time: ::core::option::Option<::pbjson_types::Timestamp>,
     #[prost(message, optional, tag = "12")]
     pub cancellation_info: ::core::option::Option<NexusOperationCancellationInfo>,
     /// The event ID of the NexusOperationScheduled event. Can be used to correlate an operation in the
@@ -676,19 +700,19 @@ pub struct PendingNexusOperationInfo {
     /// (-- api-linter: core::0140::prepositions=disabled
     ///      aip.dev/not-precedent: "to" is used to indicate interval. --)
     #[prost(message, optional, tag = "16")]
-    pub schedule_to_start_timeout: ::core::option::Option<::prost_types::Duration>,
+    pub schedule_to_start_timeout: ::core::option::Option<::pbjson_types::Duration>,
     /// Start-to-close timeout for this operation.
     /// (-- api-linter: core::0140::prepositions=disabled
     ///      aip.dev/not-precedent: "to" is used to indicate interval. --)
     #[prost(message, optional, tag = "17")]
-    pub start_to_close_timeout: ::core::option::Option<::prost_types::Duration>,
+    pub start_to_close_timeout: ::core::option::Option<::pbjson_types::Duration>,
 }
 /// NexusOperationCancellationInfo contains the state of a nexus operation cancellation.
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct NexusOperationCancellationInfo {
     /// The time when cancellation was requested.
     #[prost(message, optional, tag = "1")]
-    pub requested_time: ::core::option::Option<::prost_types::Timestamp>,
+    pub requested_time: ::core::option::Option<::pbjson_types::Timestamp>,
     #[prost(
         enumeration = "super::super::enums::v1::NexusOperationCancellationState",
         tag = "2"
@@ -700,13 +724,13 @@ pub struct NexusOperationCancellationInfo {
     pub attempt: i32,
     /// The time when the last attempt completed.
     #[prost(message, optional, tag = "4")]
-    pub last_attempt_complete_time: ::core::option::Option<::prost_types::Timestamp>,
+    pub last_attempt_complete_time: ::core::option::Option<::pbjson_types::Timestamp>,
     /// The last attempt's failure, if any.
     #[prost(message, optional, tag = "5")]
     pub last_attempt_failure: ::core::option::Option<super::super::failure::v1::Failure>,
     /// The time when the next attempt is scheduled.
     #[prost(message, optional, tag = "6")]
-    pub next_attempt_schedule_time: ::core::option::Option<::prost_types::Timestamp>,
+    pub next_attempt_schedule_time: ::core::option::Option<::pbjson_types::Timestamp>,
     /// If the state is BLOCKED, blocked reason provides additional information.
     #[prost(string, tag = "7")]
     pub blocked_reason: ::prost::alloc::string::String,
@@ -719,6 +743,64 @@ pub struct WorkflowExecutionOptions {
     /// If set, overrides the workflow's priority sent by the SDK.
     #[prost(message, optional, tag = "2")]
     pub priority: ::core::option::Option<super::super::common::v1::Priority>,
+    /// Time-skipping configuration for this workflow execution.
+    /// If not set, the time-skipping configuration is not updated by this request;
+    /// the existing configuration is preserved.
+    #[prost(message, optional, tag = "3")]
+    pub time_skipping_config: ::core::option::Option<TimeSkippingConfig>,
+}
+/// Configuration for time skipping during a workflow execution.
+/// When enabled, virtual time advances automatically whenever there is no in-flight work.
+/// In-flight work includes activities, child workflows, Nexus operations, signal/cancel external workflow operations,
+/// and possibly other features added in the future.
+/// User timers are not classified as in-flight work and will be skipped over.
+/// When time advances, it skips to the earlier of the next user timer or the configured bound, if either exists.
+///
+/// Propagation behavior of time skipping:
+/// The enabled flag, bound fields, and accumulated skipped duration are propagated to related executions as follows:
+/// (1) Child workflows and continue-as-new: both the configuration and the accumulated skipped duration are
+///      inherited from the current execution. The configured bound is shared between the inherited skipped
+///      duration and any additional duration skipped by the new run.
+/// (2) Retry and cron: the configuration and accumulated skipped duration are inherited as recorded when the
+///      current workflow started; the accumulated skipped duration of the current run is not propagated.
+/// (3) Reset: the new run retains the time-skipping configuration of the current execution. Because reset replays
+///      all events up to the reset point and re-applies any UpdateWorkflowExecutionOptions changes made after that
+///      point, the resulting run ends up with the same final time-skipping configuration as the previous run.
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct TimeSkippingConfig {
+    /// Enables or disables time skipping for this workflow execution.
+    #[prost(bool, tag = "1")]
+    pub enabled: bool,
+    /// Optional bound that limits the gap between the virtual time of this execution and wall-clock time.
+    /// Once the bound is reached, time skipping is automatically disabled,
+    /// but can be re-enabled by setting `enabled` to true via UpdateWorkflowExecutionOptions.
+    /// This bound cannot be set to a value smaller than the execution's currently skipped duration.
+    ///
+    /// This is useful in testing scenarios where a workflow is expected to receive
+    /// signals, updates, or other external events while timers are in progress.
+    #[prost(oneof = "time_skipping_config::Bound", tags = "4, 5")]
+    pub bound: ::core::option::Option<time_skipping_config::Bound>,
+}
+/// Nested message and enum types in `TimeSkippingConfig`.
+pub mod time_skipping_config {
+    /// Optional bound that limits the gap between the virtual time of this execution and wall-clock time.
+    /// Once the bound is reached, time skipping is automatically disabled,
+    /// but can be re-enabled by setting `enabled` to true via UpdateWorkflowExecutionOptions.
+    /// This bound cannot be set to a value smaller than the execution's currently skipped duration.
+    ///
+    /// This is useful in testing scenarios where a workflow is expected to receive
+    /// signals, updates, or other external events while timers are in progress.
+    #[derive(Clone, Copy, PartialEq, ::prost::Oneof)]
+    pub enum Bound {
+        /// Maximum total virtual time that can be skipped.
+        #[prost(message, tag = "4")]
+        MaxSkippedDuration(::pbjson_types::Duration),
+        /// Maximum elapsed time since time skipping was enabled.
+        /// This includes both skipped time and real time elapsing.
+        /// (-- api-linter: core::0142::time-field-names=disabled --)
+        #[prost(message, tag = "5")]
+        MaxElapsedDuration(::pbjson_types::Duration),
+    }
 }
 /// Used to override the versioning behavior (and pinned deployment version, if applicable) of a
 /// specific workflow execution. If set, this override takes precedence over worker-sent values.
@@ -892,7 +974,7 @@ pub mod post_reset_operation {
         /// Controls which fields from `workflow_execution_options` will be applied.
         /// To unset a field, set it to null and use the update mask to indicate that it should be mutated.
         #[prost(message, optional, tag = "2")]
-        pub update_mask: ::core::option::Option<::prost_types::FieldMask>,
+        pub update_mask: ::core::option::Option<::pbjson_types::FieldMask>,
     }
     #[derive(Clone, PartialEq, ::prost::Oneof)]
     pub enum Variant {
@@ -910,7 +992,7 @@ pub struct WorkflowExecutionPauseInfo {
     pub identity: ::prost::alloc::string::String,
     /// The time when the workflow execution was paused.
     #[prost(message, optional, tag = "2")]
-    pub paused_time: ::core::option::Option<::prost_types::Timestamp>,
+    pub paused_time: ::core::option::Option<::pbjson_types::Timestamp>,
     /// The reason for pausing the workflow execution.
     #[prost(string, tag = "3")]
     pub reason: ::prost::alloc::string::String,
@@ -0,0 +1,5706 @@
+impl serde::Serialize for CallbackInfo {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.callback.is_some() {
+            len += 1;
+        }
+        if self.trigger.is_some() {
+            len += 1;
+        }
+        if self.registration_time.is_some() {
+            len += 1;
+        }
+        if self.state != 0 {
+            len += 1;
+        }
+        if self.attempt != 0 {
+            len += 1;
+        }
+        if self.last_attempt_complete_time.is_some() {
+            len += 1;
+        }
+        if self.last_attempt_failure.is_some() {
+            len += 1;
+        }
+        if self.next_attempt_schedule_time.is_some() {
+            len += 1;
+        }
+        if !self.blocked_reason.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("temporal.api.workflow.v1.CallbackInfo", len)?;
+        if let Some(v) = self.callback.as_ref() {
+            struct_ser.serialize_field("callback", v)?;
+        }
+        if let Some(v) = self.trigger.as_ref() {
+            struct_ser.serialize_field("trigger", v)?;
+        }
+        if let Some(v) = self.registration_time.as_ref() {
+            struct_ser.serialize_field("registrationTime", v)?;
+        }
+        if self.state != 0 {
+            let v = super::super::enums::v1::CallbackState::try_from(self.state)
+                .map_err(|_| serde::ser::Error::custom(format!("Invalid variant {}", self.state)))?;
+            struct_ser.serialize_field("state", &v)?;
+        }
+        if self.attempt != 0 {
+            struct_ser.serialize_field("attempt", &self.attempt)?;
+        }
+        if let Some(v) = self.last_attempt_complete_time.as_ref() {
+            struct_ser.serialize_field("lastAttemptCompleteTime", v)?;
+        }
+        if let Some(v) = self.last_attempt_failure.as_ref() {
+            struct_ser.serialize_field("lastAttemptFailure", v)?;
+        }
+        if let Some(v) = self.next_attempt_schedule_time.as_ref() {
+            struct_ser.serialize_field("nextAttemptScheduleTime", v)?;
+        }
+        if !self.blocked_reason.is_empty() {
+            struct_ser.serialize_field("blockedReason", &self.blocked_reason)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for CallbackInfo {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "callback",
+            "trigger",
+            "registration_time",
+            "registrationTime",
+            "state",
+            "attempt",
+            "last_attempt_complete_time",
+            "lastAttemptCompleteTime",
+            "last_attempt_failure",
+            "lastAttemptFailure",
+            "next_attempt_schedule_time",
+            "nextAttemptScheduleTime",
+            "blocked_reason",
+            "blockedReason",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Callback,
+            Trigger,
+            RegistrationTime,
+            State,
+            Attempt,
+            LastAttemptCompleteTime,
+            LastAttemptFailure,
+            NextAttemptScheduleTime,
+            BlockedReason,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "callback" => Ok(GeneratedField::Callback),
+                            "trigger" => Ok(GeneratedField::Trigger),
+                            "registrationTime" | "registration_time" => Ok(GeneratedField::RegistrationTime),
+                            "state" => Ok(GeneratedField::State),
+                            "attempt" => Ok(GeneratedField::Attempt),
+                            "lastAttemptCompleteTime" | "last_attempt_complete_time" => Ok(GeneratedField::LastAttemptCompleteTime),
+                            "lastAttemptFailure" | "last_attempt_failure" => Ok(GeneratedField::LastAttemptFailure),
+                            "nextAttemptScheduleTime" | "next_attempt_schedule_time" => Ok(GeneratedField::NextAttemptScheduleTime),
+                            "blockedReason" | "blocked_reason" => Ok(GeneratedField::BlockedReason),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = CallbackInfo;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct temporal.api.workflow.v1.CallbackInfo")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<CallbackInfo, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut callback__ = None;
+                let mut trigger__ = None;
+                let mut registration_time__ = None;
+                let mut state__ = None;
+                let mut attempt__ = None;
+                let mut last_attempt_complete_time__ = None;
+                let mut last_attempt_failure__ = None;
+                let mut next_attempt_schedule_time__ = None;
+                let mut blocked_reason__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Callback => {
+                            if callback__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("callback"));
+                            }
+                            callback__ = map_.next_value()?;
+                        }
+                        GeneratedField::Trigger => {
+                            if trigger__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("trigger"));
+                            }
+                            trigger__ = map_.next_value()?;
+                        }
+                        GeneratedField::RegistrationTime => {
+                            if registration_time__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("registrationTime"));
+                            }
+                            registration_time__ = map_.next_value()?;
+                        }
+                        GeneratedField::State => {
+                            if state__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("state"));
+                            }
+                            state__ = Some(map_.next_value::<super::super::enums::v1::CallbackState>()? as i32);
+                        }
+                        GeneratedField::Attempt => {
+                            if attempt__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("attempt"));
+                            }
+                            attempt__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::LastAttemptCompleteTime => {
+                            if last_attempt_complete_time__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("lastAttemptCompleteTime"));
+                            }
+                            last_attempt_complete_time__ = map_.next_value()?;
+                        }
+                        GeneratedField::LastAttemptFailure => {
+                            if last_attempt_failure__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("lastAttemptFailure"));
+                            }
+                            last_attempt_failure__ = map_.next_value()?;
+                        }
+                        GeneratedField::NextAttemptScheduleTime => {
+                            if next_attempt_schedule_time__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("nextAttemptScheduleTime"));
+                            }
+                            next_attempt_schedule_time__ = map_.next_value()?;
+                        }
+                        GeneratedField::BlockedReason => {
+                            if blocked_reason__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("blockedReason"));
+                            }
+                            blocked_reason__ = Some(map_.next_value()?);
+                        }
+                    }
+                }
+                Ok(CallbackInfo {
+                    callback: callback__,
+                    trigger: trigger__,
+                    registration_time: registration_time__,
+                    state: state__.unwrap_or_default(),
+                    attempt: attempt__.unwrap_or_default(),
+                    last_attempt_complete_time: last_attempt_complete_time__,
+                    last_attempt_failure: last_attempt_failure__,
+                    next_attempt_schedule_time: next_attempt_schedule_time__,
+                    blocked_reason: blocked_reason__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("temporal.api.workflow.v1.CallbackInfo", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for callback_info::Trigger {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.variant.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("temporal.api.workflow.v1.CallbackInfo.Trigger", len)?;
+        if let Some(v) = self.variant.as_ref() {
+            match v {
+                callback_info::trigger::Variant::WorkflowClosed(v) => {
+                    struct_ser.serialize_field("workflowClosed", v)?;
+                }
+                callback_info::trigger::Variant::UpdateWorkflowExecutionCompleted(v) => {
+                    struct_ser.serialize_field("updateWorkflowExecutionCompleted", v)?;
+                }
+            }
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for callback_info::Trigger {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "workflow_closed",
+            "workflowClosed",
+            "update_workflow_execution_completed",
+            "updateWorkflowExecutionCompleted",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            WorkflowClosed,
+            UpdateWorkflowExecutionCompleted,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "workflowClosed" | "workflow_closed" => Ok(GeneratedField::WorkflowClosed),
+                            "updateWorkflowExecutionCompleted" | "update_workflow_execution_completed" => Ok(GeneratedField::UpdateWorkflowExecutionCompleted),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = callback_info::Trigger;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct temporal.api.workflow.v1.CallbackInfo.Trigger")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<callback_info::Trigger, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut variant__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::WorkflowClosed => {
+                            if variant__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("workflowClosed"));
+                            }
+                            variant__ = map_.next_value::<::std::option::Option<_>>()?.map(callback_info::trigger::Variant::WorkflowClosed)
+;
+                        }
+                        GeneratedField::UpdateWorkflowExecutionCompleted => {
+                            if variant__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("updateWorkflowExecutionCompleted"));
+                            }
+                            variant__ = map_.next_value::<::std::option::Option<_>>()?.map(callback_info::trigger::Variant::UpdateWorkflowExecutionCompleted)
+;
+                        }
+                    }
+                }
+                Ok(callback_info::Trigger {
+                    variant: variant__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("temporal.api.workflow.v1.CallbackInfo.Trigger", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for callback_info::UpdateWorkflowExecutionCompleted {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.update_id.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("temporal.api.workflow.v1.CallbackInfo.UpdateWorkflowExecutionCompleted", len)?;
+        if !self.update_id.is_empty() {
+            struct_ser.serialize_field("updateId", &self.update_id)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for callback_info::UpdateWorkflowExecutionCompleted {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "update_id",
+            "updateId",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            UpdateId,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "updateId" | "update_id" => Ok(GeneratedField::UpdateId),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = callback_info::UpdateWorkflowExecutionCompleted;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct temporal.api.workflow.v1.CallbackInfo.UpdateWorkflowExecutionCompleted")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<callback_info::UpdateWorkflowExecutionCompleted, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut update_id__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::UpdateId => {
+                            if update_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("updateId"));
+                            }
+                            update_id__ = Some(map_.next_value()?);
+                        }
+                    }
+                }
+                Ok(callback_info::UpdateWorkflowExecutionCompleted {
+                    update_id: update_id__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("temporal.api.workflow.v1.CallbackInfo.UpdateWorkflowExecutionCompleted", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for callback_info::WorkflowClosed {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let len = 0;
+        let struct_ser = serializer.serialize_struct("temporal.api.workflow.v1.CallbackInfo.WorkflowClosed", len)?;
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for callback_info::WorkflowClosed {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                            Err(serde::de::Error::unknown_field(value, FIELDS))
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = callback_info::WorkflowClosed;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct temporal.api.workflow.v1.CallbackInfo.WorkflowClosed")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<callback_info::WorkflowClosed, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                while map_.next_key::<GeneratedField>()?.is_some() {
+                    let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                }
+                Ok(callback_info::WorkflowClosed {
+                })
+            }
+        }
+        deserializer.deserialize_struct("temporal.api.workflow.v1.CallbackInfo.WorkflowClosed", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for DeploymentTransition {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.deployment.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("temporal.api.workflow.v1.DeploymentTransition", len)?;
+        if let Some(v) = self.deployment.as_ref() {
+            struct_ser.serialize_field("deployment", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for DeploymentTransition {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "deployment",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Deployment,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "deployment" => Ok(GeneratedField::Deployment),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = DeploymentTransition;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct temporal.api.workflow.v1.DeploymentTransition")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<DeploymentTransition, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut deployment__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Deployment => {
+                            if deployment__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("deployment"));
+                            }
+                            deployment__ = map_.next_value()?;
+                        }
+                    }
+                }
+                Ok(DeploymentTransition {
+                    deployment: deployment__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("temporal.api.workflow.v1.DeploymentTransition", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for DeploymentVersionTransition {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.version.is_empty() {
+            len += 1;
+        }
+        if self.deployment_version.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("temporal.api.workflow.v1.DeploymentVersionTransition", len)?;
+        if !self.version.is_empty() {
+            struct_ser.serialize_field("version", &self.version)?;
+        }
+        if let Some(v) = self.deployment_version.as_ref() {
+            struct_ser.serialize_field("deploymentVersion", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for DeploymentVersionTransition {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "version",
+            "deployment_version",
+            "deploymentVersion",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Version,
+            DeploymentVersion,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "version" => Ok(GeneratedField::Version),
+                            "deploymentVersion" | "deployment_version" => Ok(GeneratedField::DeploymentVersion),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = DeploymentVersionTransition;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct temporal.api.workflow.v1.DeploymentVersionTransition")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<DeploymentVersionTransition, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut version__ = None;
+                let mut deployment_version__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Version => {
+                            if version__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("version"));
+                            }
+                            version__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::DeploymentVersion => {
+                            if deployment_version__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("deploymentVersion"));
+                            }
+                            deployment_version__ = map_.next_value()?;
+                        }
+                    }
+                }
+                Ok(DeploymentVersionTransition {
+                    version: version__.unwrap_or_default(),
+                    deployment_version: deployment_version__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("temporal.api.workflow.v1.DeploymentVersionTransition", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for NewWorkflowExecutionInfo {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.workflow_id.is_empty() {
+            len += 1;
+        }
+        if self.workflow_type.is_some() {
+            len += 1;
+        }
+        if self.task_queue.is_some() {
+            len += 1;
+        }
+        if self.input.is_some() {
+            len += 1;
+        }
+        if self.workflow_execution_timeout.is_some() {
+            len += 1;
+        }
+        if self.workflow_run_timeout.is_some() {
+            len += 1;
+        }
+        if self.workflow_task_timeout.is_some() {
+            len += 1;
+        }
+        if self.workflow_id_reuse_policy != 0 {
+            len += 1;
+        }
+        if self.retry_policy.is_some() {
+            len += 1;
+        }
+        if !self.cron_schedule.is_empty() {
+            len += 1;
+        }
+        if self.memo.is_some() {
+            len += 1;
+        }
+        if self.search_attributes.is_some() {
+            len += 1;
+        }
+        if self.header.is_some() {
+            len += 1;
+        }
+        if self.user_metadata.is_some() {
+            len += 1;
+        }
+        if self.versioning_override.is_some() {
+            len += 1;
+        }
+        if self.priority.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("temporal.api.workflow.v1.NewWorkflowExecutionInfo", len)?;
+        if !self.workflow_id.is_empty() {
+            struct_ser.serialize_field("workflowId", &self.workflow_id)?;
+        }
+        if let Some(v) = self.workflow_type.as_ref() {
+            struct_ser.serialize_field("workflowType", v)?;
+        }
+        if let Some(v) = self.task_queue.as_ref() {
+            struct_ser.serialize_field("taskQueue", v)?;
+        }
+        if let Some(v) = self.input.as_ref() {
+            struct_ser.serialize_field("input", v)?;
+        }
+        if let Some(v) = self.workflow_execution_timeout.as_ref() {
+            struct_ser.serialize_field("workflowExecutionTimeout", v)?;
+        }
+        if let Some(v) = self.workflow_run_timeout.as_ref() {
+            struct_ser.serialize_field("workflowRunTimeout", v)?;
+        }
+        if let Some(v) = self.workflow_task_timeout.as_ref() {
+            struct_ser.serialize_field("workflowTaskTimeout", v)?;
+        }
+        if self.workflow_id_reuse_policy != 0 {
+            let v = super::super::enums::v1::WorkflowIdReusePolicy::try_from(self.workflow_id_reuse_policy)
+                .map_err(|_| serde::ser::Error::custom(format!("Invalid variant {}", self.workflow_id_reuse_policy)))?;
+            struct_ser.serialize_field("workflowIdReusePolicy", &v)?;
+        }
+        if let Some(v) = self.retry_policy.as_ref() {
+            struct_ser.serialize_field("retryPolicy", v)?;
+        }
+        if !self.cron_schedule.is_empty() {
+            struct_ser.serialize_field("cronSchedule", &self.cron_schedule)?;
+        }
+        if let Some(v) = self.memo.as_ref() {
+            struct_ser.serialize_field("memo", v)?;
+        }
+        if let Some(v) = self.search_attributes.as_ref() {
+            struct_ser.serialize_field("searchAttributes", v)?;
+        }
+        if let Some(v) = self.header.as_ref() {
+            struct_ser.serialize_field("header", v)?;
+        }
+        if let Some(v) = self.user_metadata.as_ref() {
+            struct_ser.serialize_field("userMetadata", v)?;
+        }
+        if let Some(v) = self.versioning_override.as_ref() {
+            struct_ser.serialize_field("versioningOverride", v)?;
+        }
+        if let Some(v) = self.priority.as_ref() {
+            struct_ser.serialize_field("priority", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for NewWorkflowExecutionInfo {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "workflow_id",
+            "workflowId",
+            "workflow_type",
+            "workflowType",
+            "task_queue",
+            "taskQueue",
+            "input",
+            "workflow_execution_timeout",
+            "workflowExecutionTimeout",
+            "workflow_run_timeout",
+            "workflowRunTimeout",
+            "workflow_task_timeout",
+            "workflowTaskTimeout",
+            "workflow_id_reuse_policy",
+            "workflowIdReusePolicy",
+            "retry_policy",
+            "retryPolicy",
+            "cron_schedule",
+            "cronSchedule",
+            "memo",
+            "search_attributes",
+            "searchAttributes",
+            "header",
+            "user_metadata",
+            "userMetadata",
+            "versioning_override",
+            "versioningOverride",
+            "priority",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            WorkflowId,
+            WorkflowType,
+            TaskQueue,
+            Input,
+            WorkflowExecutionTimeout,
+            WorkflowRunTimeout,
+            WorkflowTaskTimeout,
+            WorkflowIdReusePolicy,
+            RetryPolicy,
+            CronSchedule,
+            Memo,
+            SearchAttributes,
+            Header,
+            UserMetadata,
+            VersioningOverride,
+            Priority,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "workflowId" | "workflow_id" => Ok(GeneratedField::WorkflowId),
+                            "workflowType" | "workflow_type" => Ok(GeneratedField::WorkflowType),
+                            "taskQueue" | "task_queue" => Ok(GeneratedField::TaskQueue),
+                            "input" => Ok(GeneratedField::Input),
+                            "workflowExecutionTimeout" | "workflow_execution_timeout" => Ok(GeneratedField::WorkflowExecutionTimeout),
+                            "workflowRunTimeout" | "workflow_run_timeout" => Ok(GeneratedField::WorkflowRunTimeout),
+                            "workflowTaskTimeout" | "workflow_task_timeout" => Ok(GeneratedField::WorkflowTaskTimeout),
+                            "workflowIdReusePolicy" | "workflow_id_reuse_policy" => Ok(GeneratedField::WorkflowIdReusePolicy),
+                            "retryPolicy" | "retry_policy" => Ok(GeneratedField::RetryPolicy),
+                            "cronSchedule" | "cron_schedule" => Ok(GeneratedField::CronSchedule),
+                            "memo" => Ok(GeneratedField::Memo),
+                            "searchAttributes" | "search_attributes" => Ok(GeneratedField::SearchAttributes),
+                            "header" => Ok(GeneratedField::Header),
+                            "userMetadata" | "user_metadata" => Ok(GeneratedField::UserMetadata),
+                            "versioningOverride" | "versioning_override" => Ok(GeneratedField::VersioningOverride),
+                            "priority" => Ok(GeneratedField::Priority),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = NewWorkflowExecutionInfo;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct temporal.api.workflow.v1.NewWorkflowExecutionInfo")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<NewWorkflowExecutionInfo, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut workflow_id__ = None;
+                let mut workflow_type__ = None;
+                let mut task_queue__ = None;
+                let mut input__ = None;
+                let mut workflow_execution_timeout__ = None;
+                let mut workflow_run_timeout__ = None;
+                let mut workflow_task_timeout__ = None;
+                let mut workflow_id_reuse_policy__ = None;
+                let mut retry_policy__ = None;
+                let mut cron_schedule__ = None;
+                let mut memo__ = None;
+                let mut search_attributes__ = None;
+                let mut header__ = None;
+                let mut user_metadata__ = None;
+                let mut versioning_override__ = None;
+                let mut priority__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::WorkflowId => {
+                            if workflow_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("workflowId"));
+                            }
+                            workflow_id__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::WorkflowType => {
+                            if workflow_type__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("workflowType"));
+                            }
+                            workflow_type__ = map_.next_value()?;
+                        }
+                        GeneratedField::TaskQueue => {
+                            if task_queue__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("taskQueue"));
+                            }
+                            task_queue__ = map_.next_value()?;
+                        }
+                        GeneratedField::Input => {
+                            if input__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("input"));
+                            }
+                            input__ = map_.next_value()?;
+                        }
+                        GeneratedField::WorkflowExecutionTimeout => {
+                            if workflow_execution_timeout__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("workflowExecutionTimeout"));
+                            }
+                            workflow_execution_timeout__ = map_.next_value()?;
+                        }
+                        GeneratedField::WorkflowRunTimeout => {
+                            if workflow_run_timeout__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("workflowRunTimeout"));
+                            }
+                            workflow_run_timeout__ = map_.next_value()?;
+                        }
+                        GeneratedField::WorkflowTaskTimeout => {
+                            if workflow_task_timeout__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("workflowTaskTimeout"));
+                            }
+                            workflow_task_timeout__ = map_.next_value()?;
+                        }
+                        GeneratedField::WorkflowIdReusePolicy => {
+                            if workflow_id_reuse_policy__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("workflowIdReusePolicy"));
+                            }
+                            workflow_id_reuse_policy__ = Some(map_.next_value::<super::super::enums::v1::WorkflowIdReusePolicy>()? as i32);
+                        }
+                        GeneratedField::RetryPolicy => {
+                            if retry_policy__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("retryPolicy"));
+                            }
+                            retry_policy__ = map_.next_value()?;
+                        }
+                        GeneratedField::CronSchedule => {
+                            if cron_schedule__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("cronSchedule"));
+                            }
+                            cron_schedule__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::Memo => {
+                            if memo__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("memo"));
+                            }
+                            memo__ = map_.next_value()?;
+                        }
+                        GeneratedField::SearchAttributes => {
+                            if search_attributes__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("searchAttributes"));
+                            }
+                            search_attributes__ = map_.next_value()?;
+                        }
+                        GeneratedField::Header => {
+                            if header__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("header"));
+                            }
+                            header__ = map_.next_value()?;
+                        }
+                        GeneratedField::UserMetadata => {
+                            if user_metadata__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("userMetadata"));
+                            }
+                            user_metadata__ = map_.next_value()?;
+                        }
+                        GeneratedField::VersioningOverride => {
+                            if versioning_override__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("versioningOverride"));
+                            }
+                            versioning_override__ = map_.next_value()?;
+                        }
+                        GeneratedField::Priority => {
+                            if priority__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("priority"));
+                            }
+                            priority__ = map_.next_value()?;
+                        }
+                    }
+                }
+                Ok(NewWorkflowExecutionInfo {
+                    workflow_id: workflow_id__.unwrap_or_default(),
+                    workflow_type: workflow_type__,
+                    task_queue: task_queue__,
+                    input: input__,
+                    workflow_execution_timeout: workflow_execution_timeout__,
+                    workflow_run_timeout: workflow_run_timeout__,
+                    workflow_task_timeout: workflow_task_timeout__,
+                    workflow_id_reuse_policy: workflow_id_reuse_policy__.unwrap_or_default(),
+                    retry_policy: retry_policy__,
+                    cron_schedule: cron_schedule__.unwrap_or_default(),
+                    memo: memo__,
+                    search_attributes: search_attributes__,
+                    header: header__,
+                    user_metadata: user_metadata__,
+                    versioning_override: versioning_override__,
+                    priority: priority__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("temporal.api.workflow.v1.NewWorkflowExecutionInfo", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for NexusOperationCancellationInfo {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.requested_time.is_some() {
+            len += 1;
+        }
+        if self.state != 0 {
+            len += 1;
+        }
+        if self.attempt != 0 {
+            len += 1;
+        }
+        if self.last_attempt_complete_time.is_some() {
+            len += 1;
+        }
+        if self.last_attempt_failure.is_some() {
+            len += 1;
+        }
+        if self.next_attempt_schedule_time.is_some() {
+            len += 1;
+        }
+        if !self.blocked_reason.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("temporal.api.workflow.v1.NexusOperationCancellationInfo", len)?;
+        if let Some(v) = self.requested_time.as_ref() {
+            struct_ser.serialize_field("requestedTime", v)?;
+        }
+        if self.state != 0 {
+            let v = super::super::enums::v1::NexusOperationCancellationState::try_from(self.state)
+                .map_err(|_| serde::ser::Error::custom(format!("Invalid variant {}", self.state)))?;
+            struct_ser.serialize_field("state", &v)?;
+        }
+        if self.attempt != 0 {
+            struct_ser.serialize_field("attempt", &self.attempt)?;
+        }
+        if let Some(v) = self.last_attempt_complete_time.as_ref() {
+            struct_ser.serialize_field("lastAttemptCompleteTime", v)?;
+        }
+        if let Some(v) = self.last_attempt_failure.as_ref() {
+            struct_ser.serialize_field("lastAttemptFailure", v)?;
+        }
+        if let Some(v) = self.next_attempt_schedule_time.as_ref() {
+            struct_ser.serialize_field("nextAttemptScheduleTime", v)?;
+        }
+        if !self.blocked_reason.is_empty() {
+            struct_ser.serialize_field("blockedReason", &self.blocked_reason)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for NexusOperationCancellationInfo {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "requested_time",
+            "requestedTime",
+            "state",
+            "attempt",
+            "last_attempt_complete_time",
+            "lastAttemptCompleteTime",
+            "last_attempt_failure",
+            "lastAttemptFailure",
+            "next_attempt_schedule_time",
+            "nextAttemptScheduleTime",
+            "blocked_reason",
+            "blockedReason",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            RequestedTime,
+            State,
+            Attempt,
+            LastAttemptCompleteTime,
+            LastAttemptFailure,
+            NextAttemptScheduleTime,
+            BlockedReason,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "requestedTime" | "requested_time" => Ok(GeneratedField::RequestedTime),
+                            "state" => Ok(GeneratedField::State),
+                            "attempt" => Ok(GeneratedField::Attempt),
+                            "lastAttemptCompleteTime" | "last_attempt_complete_time" => Ok(GeneratedField::LastAttemptCompleteTime),
+                            "lastAttemptFailure" | "last_attempt_failure" => Ok(GeneratedField::LastAttemptFailure),
+                            "nextAttemptScheduleTime" | "next_attempt_schedule_time" => Ok(GeneratedField::NextAttemptScheduleTime),
+                            "blockedReason" | "blocked_reason" => Ok(GeneratedField::BlockedReason),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = NexusOperationCancellationInfo;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct temporal.api.workflow.v1.NexusOperationCancellationInfo")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<NexusOperationCancellationInfo, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut requested_time__ = None;
+                let mut state__ = None;
+                let mut attempt__ = None;
+                let mut last_attempt_complete_time__ = None;
+                let mut last_attempt_failure__ = None;
+                let mut next_attempt_schedule_time__ = None;
+                let mut blocked_reason__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::RequestedTime => {
+                            if requested_time__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("requestedTime"));
+                            }
+                            requested_time__ = map_.next_value()?;
+                        }
+                        GeneratedField::State => {
+                            if state__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("state"));
+                            }
+                            state__ = Some(map_.next_value::<super::super::enums::v1::NexusOperationCancellationState>()? as i32);
+                        }
+                        GeneratedField::Attempt => {
+                            if attempt__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("attempt"));
+                            }
+                            attempt__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::LastAttemptCompleteTime => {
+                            if last_attempt_complete_time__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("lastAttemptCompleteTime"));
+                            }
+                            last_attempt_complete_time__ = map_.next_value()?;
+                        }
+                        GeneratedField::LastAttemptFailure => {
+                            if last_attempt_failure__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("lastAttemptFailure"));
+                            }
+                            last_attempt_failure__ = map_.next_value()?;
+                        }
+                        GeneratedField::NextAttemptScheduleTime => {
+                            if next_attempt_schedule_time__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("nextAttemptScheduleTime"));
+                            }
+                            next_attempt_schedule_time__ = map_.next_value()?;
+                        }
+                        GeneratedField::BlockedReason => {
+                            if blocked_reason__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("blockedReason"));
+                            }
+                            blocked_reason__ = Some(map_.next_value()?);
+                        }
+                    }
+                }
+                Ok(NexusOperationCancellationInfo {
+                    requested_time: requested_time__,
+                    state: state__.unwrap_or_default(),
+                    attempt: attempt__.unwrap_or_default(),
+                    last_attempt_complete_time: last_attempt_complete_time__,
+                    last_attempt_failure: last_attempt_failure__,
+                    next_attempt_schedule_time: next_attempt_schedule_time__,
+                    blocked_reason: blocked_reason__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("temporal.api.workflow.v1.NexusOperationCancellationInfo", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for OnConflictOptions {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.attach_request_id {
+            len += 1;
+        }
+        if self.attach_completion_callbacks {
+            len += 1;
+        }
+        if self.attach_links {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("temporal.api.workflow.v1.OnConflictOptions", len)?;
+        if self.attach_request_id {
+            struct_ser.serialize_field("attachRequestId", &self.attach_request_id)?;
+        }
+        if self.attach_completion_callbacks {
+            struct_ser.serialize_field("attachCompletionCallbacks", &self.attach_completion_callbacks)?;
+        }
+        if self.attach_links {
+            struct_ser.serialize_field("attachLinks", &self.attach_links)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for OnConflictOptions {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "attach_request_id",
+            "attachRequestId",
+            "attach_completion_callbacks",
+            "attachCompletionCallbacks",
+            "attach_links",
+            "attachLinks",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            AttachRequestId,
+            AttachCompletionCallbacks,
+            AttachLinks,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "attachRequestId" | "attach_request_id" => Ok(GeneratedField::AttachRequestId),
+                            "attachCompletionCallbacks" | "attach_completion_callbacks" => Ok(GeneratedField::AttachCompletionCallbacks),
+                            "attachLinks" | "attach_links" => Ok(GeneratedField::AttachLinks),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = OnConflictOptions;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct temporal.api.workflow.v1.OnConflictOptions")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<OnConflictOptions, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut attach_request_id__ = None;
+                let mut attach_completion_callbacks__ = None;
+                let mut attach_links__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::AttachRequestId => {
+                            if attach_request_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("attachRequestId"));
+                            }
+                            attach_request_id__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::AttachCompletionCallbacks => {
+                            if attach_completion_callbacks__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("attachCompletionCallbacks"));
+                            }
+                            attach_completion_callbacks__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::AttachLinks => {
+                            if attach_links__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("attachLinks"));
+                            }
+                            attach_links__ = Some(map_.next_value()?);
+                        }
+                    }
+                }
+                Ok(OnConflictOptions {
+                    attach_request_id: attach_request_id__.unwrap_or_default(),
+                    attach_completion_callbacks: attach_completion_callbacks__.unwrap_or_default(),
+                    attach_links: attach_links__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("temporal.api.workflow.v1.OnConflictOptions", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for PendingActivityInfo {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.activity_id.is_empty() {
+            len += 1;
+        }
+        if self.activity_type.is_some() {
+            len += 1;
+        }
+        if self.state != 0 {
+            len += 1;
+        }
+        if self.heartbeat_details.is_some() {
+            len += 1;
+        }
+        if self.last_heartbeat_time.is_some() {
+            len += 1;
+        }
+        if self.last_started_time.is_some() {
+            len += 1;
+        }
+        if self.attempt != 0 {
+            len += 1;
+        }
+        if self.maximum_attempts != 0 {
+            len += 1;
+        }
+        if self.scheduled_time.is_some() {
+            len += 1;
+        }
+        if self.expiration_time.is_some() {
+            len += 1;
+        }
+        if self.last_failure.is_some() {
+            len += 1;
+        }
+        if !self.last_worker_identity.is_empty() {
+            len += 1;
+        }
+        if self.last_worker_version_stamp.is_some() {
+            len += 1;
+        }
+        if self.current_retry_interval.is_some() {
+            len += 1;
+        }
+        if self.last_attempt_complete_time.is_some() {
+            len += 1;
+        }
+        if self.next_attempt_schedule_time.is_some() {
+            len += 1;
+        }
+        if self.paused {
+            len += 1;
+        }
+        if self.last_deployment.is_some() {
+            len += 1;
+        }
+        if !self.last_worker_deployment_version.is_empty() {
+            len += 1;
+        }
+        if self.last_deployment_version.is_some() {
+            len += 1;
+        }
+        if self.priority.is_some() {
+            len += 1;
+        }
+        if self.pause_info.is_some() {
+            len += 1;
+        }
+        if self.activity_options.is_some() {
+            len += 1;
+        }
+        if self.assigned_build_id.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("temporal.api.workflow.v1.PendingActivityInfo", len)?;
+        if !self.activity_id.is_empty() {
+            struct_ser.serialize_field("activityId", &self.activity_id)?;
+        }
+        if let Some(v) = self.activity_type.as_ref() {
+            struct_ser.serialize_field("activityType", v)?;
+        }
+        if self.state != 0 {
+            let v = super::super::enums::v1::PendingActivityState::try_from(self.state)
+                .map_err(|_| serde::ser::Error::custom(format!("Invalid variant {}", self.state)))?;
+            struct_ser.serialize_field("state", &v)?;
+        }
+        if let Some(v) = self.heartbeat_details.as_ref() {
+            struct_ser.serialize_field("heartbeatDetails", v)?;
+        }
+        if let Some(v) = self.last_heartbeat_time.as_ref() {
+            struct_ser.serialize_field("lastHeartbeatTime", v)?;
+        }
+        if let Some(v) = self.last_started_time.as_ref() {
+            struct_ser.serialize_field("lastStartedTime", v)?;
+        }
+        if self.attempt != 0 {
+            struct_ser.serialize_field("attempt", &self.attempt)?;
+        }
+        if self.maximum_attempts != 0 {
+            struct_ser.serialize_field("maximumAttempts", &self.maximum_attempts)?;
+        }
+        if let Some(v) = self.scheduled_time.as_ref() {
+            struct_ser.serialize_field("scheduledTime", v)?;
+        }
+        if let Some(v) = self.expiration_time.as_ref() {
+            struct_ser.serialize_field("expirationTime", v)?;
+        }
+        if let Some(v) = self.last_failure.as_ref() {
+            struct_ser.serialize_field("lastFailure", v)?;
+        }
+        if !self.last_worker_identity.is_empty() {
+            struct_ser.serialize_field("lastWorkerIdentity", &self.last_worker_identity)?;
+        }
+        if let Some(v) = self.last_worker_version_stamp.as_ref() {
+            struct_ser.serialize_field("lastWorkerVersionStamp", v)?;
+        }
+        if let Some(v) = self.current_retry_interval.as_ref() {
+            struct_ser.serialize_field("currentRetryInterval", v)?;
+        }
+        if let Some(v) = self.last_attempt_complete_time.as_ref() {
+            struct_ser.serialize_field("lastAttemptCompleteTime", v)?;
+        }
+        if let Some(v) = self.next_attempt_schedule_time.as_ref() {
+            struct_ser.serialize_field("nextAttemptScheduleTime", v)?;
+        }
+        if self.paused {
+            struct_ser.serialize_field("paused", &self.paused)?;
+        }
+        if let Some(v) = self.last_deployment.as_ref() {
+            struct_ser.serialize_field("lastDeployment", v)?;
+        }
+        if !self.last_worker_deployment_version.is_empty() {
+            struct_ser.serialize_field("lastWorkerDeploymentVersion", &self.last_worker_deployment_version)?;
+        }
+        if let Some(v) = self.last_deployment_version.as_ref() {
+            struct_ser.serialize_field("lastDeploymentVersion", v)?;
+        }
+        if let Some(v) = self.priority.as_ref() {
+            struct_ser.serialize_field("priority", v)?;
+        }
+        if let Some(v) = self.pause_info.as_ref() {
+            struct_ser.serialize_field("pauseInfo", v)?;
+        }
+        if let Some(v) = self.activity_options.as_ref() {
+            struct_ser.serialize_field("activityOptions", v)?;
+        }
+        if let Some(v) = self.assigned_build_id.as_ref() {
+            match v {
+                pending_activity_info::AssignedBuildId::UseWorkflowBuildId(v) => {
+                    struct_ser.serialize_field("useWorkflowBuildId", v)?;
+                }
+                pending_activity_info::AssignedBuildId::LastIndependentlyAssignedBuildId(v) => {
+                    struct_ser.serialize_field("lastIndependentlyAssignedBuildId", v)?;
+                }
+            }
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for PendingActivityInfo {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "activity_id",
+            "activityId",
+            "activity_type",
+            "activityType",
+            "state",
+            "heartbeat_details",
+            "heartbeatDetails",
+            "last_heartbeat_time",
+            "lastHeartbeatTime",
+            "last_started_time",
+            "lastStartedTime",
+            "attempt",
+            "maximum_attempts",
+            "maximumAttempts",
+            "scheduled_time",
+            "scheduledTime",
+            "expiration_time",
+            "expirationTime",
+            "last_failure",
+            "lastFailure",
+            "last_worker_identity",
+            "lastWorkerIdentity",
+            "last_worker_version_stamp",
+            "lastWorkerVersionStamp",
+            "current_retry_interval",
+            "currentRetryInterval",
+            "last_attempt_complete_time",
+            "lastAttemptCompleteTime",
+            "next_attempt_schedule_time",
+            "nextAttemptScheduleTime",
+            "paused",
+            "last_deployment",
+            "lastDeployment",
+            "last_worker_deployment_version",
+            "lastWorkerDeploymentVersion",
+            "last_deployment_version",
+            "lastDeploymentVersion",
+            "priority",
+            "pause_info",
+            "pauseInfo",
+            "activity_options",
+            "activityOptions",
+            "use_workflow_build_id",
+            "useWorkflowBuildId",
+            "last_independently_assigned_build_id",
+            "lastIndependentlyAssignedBuildId",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            ActivityId,
+            ActivityType,
+            State,
+            HeartbeatDetails,
+            LastHeartbeatTime,
+            LastStartedTime,
+            Attempt,
+            MaximumAttempts,
+            ScheduledTime,
+            ExpirationTime,
+            LastFailure,
+            LastWorkerIdentity,
+            LastWorkerVersionStamp,
+            CurrentRetryInterval,
+            LastAttemptCompleteTime,
+            NextAttemptScheduleTime,
+            Paused,
+            LastDeployment,
+            LastWorkerDeploymentVersion,
+            LastDeploymentVersion,
+            Priority,
+            PauseInfo,
+            ActivityOptions,
+            UseWorkflowBuildId,
+            LastIndependentlyAssignedBuildId,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "activityId" | "activity_id" => Ok(GeneratedField::ActivityId),
+                            "activityType" | "activity_type" => Ok(GeneratedField::ActivityType),
+                            "state" => Ok(GeneratedField::State),
+                            "heartbeatDetails" | "heartbeat_details" => Ok(GeneratedField::HeartbeatDetails),
+                            "lastHeartbeatTime" | "last_heartbeat_time" => Ok(GeneratedField::LastHeartbeatTime),
+                            "lastStartedTime" | "last_started_time" => Ok(GeneratedField::LastStartedTime),
+                            "attempt" => Ok(GeneratedField::Attempt),
+                            "maximumAttempts" | "maximum_attempts" => Ok(GeneratedField::MaximumAttempts),
+                            "scheduledTime" | "scheduled_time" => Ok(GeneratedField::ScheduledTime),
+                            "expirationTime" | "expiration_time" => Ok(GeneratedField::ExpirationTime),
+                            "lastFailure" | "last_failure" => Ok(GeneratedField::LastFailure),
+                            "lastWorkerIdentity" | "last_worker_identity" => Ok(GeneratedField::LastWorkerIdentity),
+                            "lastWorkerVersionStamp" | "last_worker_version_stamp" => Ok(GeneratedField::LastWorkerVersionStamp),
+                            "currentRetryInterval" | "current_retry_interval" => Ok(GeneratedField::CurrentRetryInterval),
+                            "lastAttemptCompleteTime" | "last_attempt_complete_time" => Ok(GeneratedField::LastAttemptCompleteTime),
+                            "nextAttemptScheduleTime" | "next_attempt_schedule_time" => Ok(GeneratedField::NextAttemptScheduleTime),
+                            "paused" => Ok(GeneratedField::Paused),
+                            "lastDeployment" | "last_deployment" => Ok(GeneratedField::LastDeployment),
+                            "lastWorkerDeploymentVersion" | "last_worker_deployment_version" => Ok(GeneratedField::LastWorkerDeploymentVersion),
+                            "lastDeploymentVersion" | "last_deployment_version" => Ok(GeneratedField::LastDeploymentVersion),
+                            "priority" => Ok(GeneratedField::Priority),
+                            "pauseInfo" | "pause_info" => Ok(GeneratedField::PauseInfo),
+                            "activityOptions" | "activity_options" => Ok(GeneratedField::ActivityOptions),
+                            "useWorkflowBuildId" | "use_workflow_build_id" => Ok(GeneratedField::UseWorkflowBuildId),
+                            "lastIndependentlyAssignedBuildId" | "last_independently_assigned_build_id" => Ok(GeneratedField::LastIndependentlyAssignedBuildId),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = PendingActivityInfo;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct temporal.api.workflow.v1.PendingActivityInfo")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<PendingActivityInfo, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut activity_id__ = None;
+                let mut activity_type__ = None;
+                let mut state__ = None;
+                let mut heartbeat_details__ = None;
+                let mut last_heartbeat_time__ = None;
+                let mut last_started_time__ = None;
+                let mut attempt__ = None;
+                let mut maximum_attempts__ = None;
+                let mut scheduled_time__ = None;
+                let mut expiration_time__ = None;
+                let mut last_failure__ = None;
+                let mut last_worker_identity__ = None;
+                let mut last_worker_version_stamp__ = None;
+                let mut current_retry_interval__ = None;
+                let mut last_attempt_complete_time__ = None;
+                let mut next_attempt_schedule_time__ = None;
+                let mut paused__ = None;
+                let mut last_deployment__ = None;
+                let mut last_worker_deployment_version__ = None;
+                let mut last_deployment_version__ = None;
+                let mut priority__ = None;
+                let mut pause_info__ = None;
+                let mut activity_options__ = None;
+                let mut assigned_build_id__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::ActivityId => {
+                            if activity_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("activityId"));
+                            }
+                            activity_id__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::ActivityType => {
+                            if activity_type__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("activityType"));
+                            }
+                            activity_type__ = map_.next_value()?;
+                        }
+                        GeneratedField::State => {
+                            if state__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("state"));
+                            }
+                            state__ = Some(map_.next_value::<super::super::enums::v1::PendingActivityState>()? as i32);
+                        }
+                        GeneratedField::HeartbeatDetails => {
+                            if heartbeat_details__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("heartbeatDetails"));
+                            }
+                            heartbeat_details__ = map_.next_value()?;
+                        }
+                        GeneratedField::LastHeartbeatTime => {
+                            if last_heartbeat_time__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("lastHeartbeatTime"));
+                            }
+                            last_heartbeat_time__ = map_.next_value()?;
+                        }
+                        GeneratedField::LastStartedTime => {
+                            if last_started_time__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("lastStartedTime"));
+                            }
+                            last_started_time__ = map_.next_value()?;
+                        }
+                        GeneratedField::Attempt => {
+                            if attempt__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("attempt"));
+                            }
+                            attempt__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::MaximumAttempts => {
+                            if maximum_attempts__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("maximumAttempts"));
+                            }
+                            maximum_attempts__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::ScheduledTime => {
+                            if scheduled_time__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("scheduledTime"));
+                            }
+                            scheduled_time__ = map_.next_value()?;
+                        }
+                        GeneratedField::ExpirationTime => {
+                            if expiration_time__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("expirationTime"));
+                            }
+                            expiration_time__ = map_.next_value()?;
+                        }
+                        GeneratedField::LastFailure => {
+                            if last_failure__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("lastFailure"));
+                            }
+                            last_failure__ = map_.next_value()?;
+                        }
+                        GeneratedField::LastWorkerIdentity => {
+                            if last_worker_identity__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("lastWorkerIdentity"));
+                            }
+                            last_worker_identity__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::LastWorkerVersionStamp => {
+                            if last_worker_version_stamp__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("lastWorkerVersionStamp"));
+                            }
+                            last_worker_version_stamp__ = map_.next_value()?;
+                        }
+                        GeneratedField::CurrentRetryInterval => {
+                            if current_retry_interval__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("currentRetryInterval"));
+                            }
+                            current_retry_interval__ = map_.next_value()?;
+                        }
+                        GeneratedField::LastAttemptCompleteTime => {
+                            if last_attempt_complete_time__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("lastAttemptCompleteTime"));
+                            }
+                            last_attempt_complete_time__ = map_.next_value()?;
+                        }
+                        GeneratedField::NextAttemptScheduleTime => {
+                            if next_attempt_schedule_time__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("nextAttemptScheduleTime"));
+                            }
+                            next_attempt_schedule_time__ = map_.next_value()?;
+                        }
+                        GeneratedField::Paused => {
+                            if paused__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("paused"));
+                            }
+                            paused__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::LastDeployment => {
+                            if last_deployment__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("lastDeployment"));
+                            }
+                            last_deployment__ = map_.next_value()?;
+                        }
+                        GeneratedField::LastWorkerDeploymentVersion => {
+                            if last_worker_deployment_version__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("lastWorkerDeploymentVersion"));
+                            }
+                            last_worker_deployment_version__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::LastDeploymentVersion => {
+                            if last_deployment_version__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("lastDeploymentVersion"));
+                            }
+                            last_deployment_version__ = map_.next_value()?;
+                        }
+                        GeneratedField::Priority => {
+                            if priority__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("priority"));
+                            }
+                            priority__ = map_.next_value()?;
+                        }
+                        GeneratedField::PauseInfo => {
+                            if pause_info__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("pauseInfo"));
+                            }
+                            pause_info__ = map_.next_value()?;
+                        }
+                        GeneratedField::ActivityOptions => {
+                            if activity_options__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("activityOptions"));
+                            }
+                            activity_options__ = map_.next_value()?;
+                        }
+                        GeneratedField::UseWorkflowBuildId => {
+                            if assigned_build_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("useWorkflowBuildId"));
+                            }
+                            assigned_build_id__ = map_.next_value::<::std::option::Option<_>>()?.map(pending_activity_info::AssignedBuildId::UseWorkflowBuildId)
+;
+                        }
+                        GeneratedField::LastIndependentlyAssignedBuildId => {
+                            if assigned_build_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("lastIndependentlyAssignedBuildId"));
+                            }
+                            assigned_build_id__ = map_.next_value::<::std::option::Option<_>>()?.map(pending_activity_info::AssignedBuildId::LastIndependentlyAssignedBuildId);
+                        }
+                    }
+                }
+                Ok(PendingActivityInfo {
+                    activity_id: activity_id__.unwrap_or_default(),
+                    activity_type: activity_type__,
+                    state: state__.unwrap_or_default(),
+                    heartbeat_details: heartbeat_details__,
+                    last_heartbeat_time: last_heartbeat_time__,
+                    last_started_time: last_started_time__,
+                    attempt: attempt__.unwrap_or_default(),
+                    maximum_attempts: maximum_attempts__.unwrap_or_default(),
+                    scheduled_time: scheduled_time__,
+                    expiration_time: expiration_time__,
+                    last_failure: last_failure__,
+                    last_worker_identity: last_worker_identity__.unwrap_or_default(),
+                    last_worker_version_stamp: last_worker_version_stamp__,
+                    current_retry_interval: current_retry_interval__,
+                    last_attempt_complete_time: last_attempt_complete_time__,
+                    next_attempt_schedule_time: next_attempt_schedule_time__,
+                    paused: paused__.unwrap_or_default(),
+                    last_deployment: last_deployment__,
+                    last_worker_deployment_version: last_worker_deployment_version__.unwrap_or_default(),
+                    last_deployment_version: last_deployment_version__,
+                    priority: priority__,
+                    pause_info: pause_info__,
+                    activity_options: activity_options__,
+                    assigned_build_id: assigned_build_id__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("temporal.api.workflow.v1.PendingActivityInfo", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for pending_activity_info::PauseInfo {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.pause_time.is_some() {
+            len += 1;
+        }
+        if self.paused_by.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("temporal.api.workflow.v1.PendingActivityInfo.PauseInfo", len)?;
+        if let Some(v) = self.pause_time.as_ref() {
+            struct_ser.serialize_field("pauseTime", v)?;
+        }
+        if let Some(v) = self.paused_by.as_ref() {
+            match v {
+                pending_activity_info::pause_info::PausedBy::Manual(v) => {
+                    struct_ser.serialize_field("manual", v)?;
+                }
+                pending_activity_info::pause_info::PausedBy::Rule(v) => {
+                    struct_ser.serialize_field("rule", v)?;
+                }
+            }
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for pending_activity_info::PauseInfo {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "pause_time",
+            "pauseTime",
+            "manual",
+            "rule",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            PauseTime,
+            Manual,
+            Rule,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "pauseTime" | "pause_time" => Ok(GeneratedField::PauseTime),
+                            "manual" => Ok(GeneratedField::Manual),
+                            "rule" => Ok(GeneratedField::Rule),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = pending_activity_info::PauseInfo;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct temporal.api.workflow.v1.PendingActivityInfo.PauseInfo")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<pending_activity_info::PauseInfo, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut pause_time__ = None;
+                let mut paused_by__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::PauseTime => {
+                            if pause_time__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("pauseTime"));
+                            }
+                            pause_time__ = map_.next_value()?;
+                        }
+                        GeneratedField::Manual => {
+                            if paused_by__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("manual"));
+                            }
+                            paused_by__ = map_.next_value::<::std::option::Option<_>>()?.map(pending_activity_info::pause_info::PausedBy::Manual)
+;
+                        }
+                        GeneratedField::Rule => {
+                            if paused_by__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("rule"));
+                            }
+                            paused_by__ = map_.next_value::<::std::option::Option<_>>()?.map(pending_activity_info::pause_info::PausedBy::Rule)
+;
+                        }
+                    }
+                }
+                Ok(pending_activity_info::PauseInfo {
+                    pause_time: pause_time__,
+                    paused_by: paused_by__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("temporal.api.workflow.v1.PendingActivityInfo.PauseInfo", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for pending_activity_info::pause_info::Manual {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.identity.is_empty() {
+            len += 1;
+        }
+        if !self.reason.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("temporal.api.workflow.v1.PendingActivityInfo.PauseInfo.Manual", len)?;
+        if !self.identity.is_empty() {
+            struct_ser.serialize_field("identity", &self.identity)?;
+        }
+        if !self.reason.is_empty() {
+            struct_ser.serialize_field("reason", &self.reason)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for pending_activity_info::pause_info::Manual {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "identity",
+            "reason",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Identity,
+            Reason,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "identity" => Ok(GeneratedField::Identity),
+                            "reason" => Ok(GeneratedField::Reason),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = pending_activity_info::pause_info::Manual;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct temporal.api.workflow.v1.PendingActivityInfo.PauseInfo.Manual")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<pending_activity_info::pause_info::Manual, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut identity__ = None;
+                let mut reason__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Identity => {
+                            if identity__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("identity"));
+                            }
+                            identity__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::Reason => {
+                            if reason__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("reason"));
+                            }
+                            reason__ = Some(map_.next_value()?);
+                        }
+                    }
+                }
+                Ok(pending_activity_info::pause_info::Manual {
+                    identity: identity__.unwrap_or_default(),
+                    reason: reason__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("temporal.api.workflow.v1.PendingActivityInfo.PauseInfo.Manual", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for pending_activity_info::pause_info::Rule {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.rule_id.is_empty() {
+            len += 1;
+        }
+        if !self.identity.is_empty() {
+            len += 1;
+        }
+        if !self.reason.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("temporal.api.workflow.v1.PendingActivityInfo.PauseInfo.Rule", len)?;
+        if !self.rule_id.is_empty() {
+            struct_ser.serialize_field("ruleId", &self.rule_id)?;
+        }
+        if !self.identity.is_empty() {
+            struct_ser.serialize_field("identity", &self.identity)?;
+        }
+        if !self.reason.is_empty() {
+            struct_ser.serialize_field("reason", &self.reason)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for pending_activity_info::pause_info::Rule {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "rule_id",
+            "ruleId",
+            "identity",
+            "reason",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            RuleId,
+            Identity,
+            Reason,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "ruleId" | "rule_id" => Ok(GeneratedField::RuleId),
+                            "identity" => Ok(GeneratedField::Identity),
+                            "reason" => Ok(GeneratedField::Reason),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = pending_activity_info::pause_info::Rule;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct temporal.api.workflow.v1.PendingActivityInfo.PauseInfo.Rule")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<pending_activity_info::pause_info::Rule, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut rule_id__ = None;
+                let mut identity__ = None;
+                let mut reason__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::RuleId => {
+                            if rule_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("ruleId"));
+                            }
+                            rule_id__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::Identity => {
+                            if identity__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("identity"));
+                            }
+                            identity__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::Reason => {
+                            if reason__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("reason"));
+                            }
+                            reason__ = Some(map_.next_value()?);
+                        }
+                    }
+                }
+                Ok(pending_activity_info::pause_info::Rule {
+                    rule_id: rule_id__.unwrap_or_default(),
+                    identity: identity__.unwrap_or_default(),
+                    reason: reason__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("temporal.api.workflow.v1.PendingActivityInfo.PauseInfo.Rule", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for PendingChildExecutionInfo {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.workflow_id.is_empty() {
+            len += 1;
+        }
+        if !self.run_id.is_empty() {
+            len += 1;
+        }
+        if !self.workflow_type_name.is_empty() {
+            len += 1;
+        }
+        if self.initiated_id != 0 {
+            len += 1;
+        }
+        if self.parent_close_policy != 0 {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("temporal.api.workflow.v1.PendingChildExecutionInfo", len)?;
+        if !self.workflow_id.is_empty() {
+            struct_ser.serialize_field("workflowId", &self.workflow_id)?;
+        }
+        if !self.run_id.is_empty() {
+            struct_ser.serialize_field("runId", &self.run_id)?;
+        }
+        if !self.workflow_type_name.is_empty() {
+            struct_ser.serialize_field("workflowTypeName", &self.workflow_type_name)?;
+        }
+        if self.initiated_id != 0 {
+            #[allow(clippy::needless_borrow)]
+            #[allow(clippy::needless_borrows_for_generic_args)]
+            struct_ser.serialize_field("initiatedId", ToString::to_string(&self.initiated_id).as_str())?;
+        }
+        if self.parent_close_policy != 0 {
+            let v = super::super::enums::v1::ParentClosePolicy::try_from(self.parent_close_policy)
+                .map_err(|_| serde::ser::Error::custom(format!("Invalid variant {}", self.parent_close_policy)))?;
+            struct_ser.serialize_field("parentClosePolicy", &v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for PendingChildExecutionInfo {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "workflow_id",
+            "workflowId",
+            "run_id",
+            "runId",
+            "workflow_type_name",
+            "workflowTypeName",
+            "initiated_id",
+            "initiatedId",
+            "parent_close_policy",
+            "parentClosePolicy",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            WorkflowId,
+            RunId,
+            WorkflowTypeName,
+            InitiatedId,
+            ParentClosePolicy,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "workflowId" | "workflow_id" => Ok(GeneratedField::WorkflowId),
+                            "runId" | "run_id" => Ok(GeneratedField::RunId),
+                            "workflowTypeName" | "workflow_type_name" => Ok(GeneratedField::WorkflowTypeName),
+                            "initiatedId" | "initiated_id" => Ok(GeneratedField::InitiatedId),
+                            "parentClosePolicy" | "parent_close_policy" => Ok(GeneratedField::ParentClosePolicy),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = PendingChildExecutionInfo;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct temporal.api.workflow.v1.PendingChildExecutionInfo")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<PendingChildExecutionInfo, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut workflow_id__ = None;
+                let mut run_id__ = None;
+                let mut workflow_type_name__ = None;
+                let mut initiated_id__ = None;
+                let mut parent_close_policy__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::WorkflowId => {
+                            if workflow_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("workflowId"));
+                            }
+                            workflow_id__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::RunId => {
+                            if run_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("runId"));
+                            }
+                            run_id__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::WorkflowTypeName => {
+                            if workflow_type_name__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("workflowTypeName"));
+                            }
+                            workflow_type_name__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::InitiatedId => {
+                            if initiated_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("initiatedId"));
+                            }
+                            initiated_id__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::ParentClosePolicy => {
+                            if parent_close_policy__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("parentClosePolicy"));
+                            }
+                            parent_close_policy__ = Some(map_.next_value::<super::super::enums::v1::ParentClosePolicy>()? as i32);
+                        }
+                    }
+                }
+                Ok(PendingChildExecutionInfo {
+                    workflow_id: workflow_id__.unwrap_or_default(),
+                    run_id: run_id__.unwrap_or_default(),
+                    workflow_type_name: workflow_type_name__.unwrap_or_default(),
+                    initiated_id: initiated_id__.unwrap_or_default(),
+                    parent_close_policy: parent_close_policy__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("temporal.api.workflow.v1.PendingChildExecutionInfo", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for PendingNexusOperationInfo {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.endpoint.is_empty() {
+            len += 1;
+        }
+        if !self.service.is_empty() {
+            len += 1;
+        }
+        if !self.operation.is_empty() {
+            len += 1;
+        }
+        if !self.operation_id.is_empty() {
+            len += 1;
+        }
+        if self.schedule_to_close_timeout.is_some() {
+            len += 1;
+        }
+        if self.scheduled_time.is_some() {
+            len += 1;
+        }
+        if self.state != 0 {
+            len += 1;
+        }
+        if self.attempt != 0 {
+            len += 1;
+        }
+        if self.last_attempt_complete_time.is_some() {
+            len += 1;
+        }
+        if self.last_attempt_failure.is_some() {
+            len += 1;
+        }
+        if self.next_attempt_schedule_time.is_some() {
+            len += 1;
+        }
+        if self.cancellation_info.is_some() {
+            len += 1;
+        }
+        if self.scheduled_event_id != 0 {
+            len += 1;
+        }
+        if !self.blocked_reason.is_empty() {
+            len += 1;
+        }
+        if !self.operation_token.is_empty() {
+            len += 1;
+        }
+        if self.schedule_to_start_timeout.is_some() {
+            len += 1;
+        }
+        if self.start_to_close_timeout.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("temporal.api.workflow.v1.PendingNexusOperationInfo", len)?;
+        if !self.endpoint.is_empty() {
+            struct_ser.serialize_field("endpoint", &self.endpoint)?;
+        }
+        if !self.service.is_empty() {
+            struct_ser.serialize_field("service", &self.service)?;
+        }
+        if !self.operation.is_empty() {
+            struct_ser.serialize_field("operation", &self.operation)?;
+        }
+        if !self.operation_id.is_empty() {
+            struct_ser.serialize_field("operationId", &self.operation_id)?;
+        }
+        if let Some(v) = self.schedule_to_close_timeout.as_ref() {
+            struct_ser.serialize_field("scheduleToCloseTimeout", v)?;
+        }
+        if let Some(v) = self.scheduled_time.as_ref() {
+            struct_ser.serialize_field("scheduledTime", v)?;
+        }
+        if self.state != 0 {
+            let v = super::super::enums::v1::PendingNexusOperationState::try_from(self.state)
+                .map_err(|_| serde::ser::Error::custom(format!("Invalid variant {}", self.state)))?;
+            struct_ser.serialize_field("state", &v)?;
+        }
+        if self.attempt != 0 {
+            struct_ser.serialize_field("attempt", &self.attempt)?;
+        }
+        if let Some(v) = self.last_attempt_complete_time.as_ref() {
+            struct_ser.serialize_field("lastAttemptCompleteTime", v)?;
+        }
+        if let Some(v) = self.last_attempt_failure.as_ref() {
+            struct_ser.serialize_field("lastAttemptFailure", v)?;
+        }
+        if let Some(v) = self.next_attempt_schedule_time.as_ref() {
+            struct_ser.serialize_field("nextAttemptScheduleTime", v)?;
+        }
+        if let Some(v) = self.cancellation_info.as_ref() {
+            struct_ser.serialize_field("cancellationInfo", v)?;
+        }
+        if self.scheduled_event_id != 0 {
+            #[allow(clippy::needless_borrow)]
+            #[allow(clippy::needless_borrows_for_generic_args)]
+            struct_ser.serialize_field("scheduledEventId", ToString::to_string(&self.scheduled_event_id).as_str())?;
+        }
+        if !self.blocked_reason.is_empty() {
+            struct_ser.serialize_field("blockedReason", &self.blocked_reason)?;
+        }
+        if !self.operation_token.is_empty() {
+            struct_ser.serialize_field("operationToken", &self.operation_token)?;
+        }
+        if let Some(v) = self.schedule_to_start_timeout.as_ref() {
+            struct_ser.serialize_field("scheduleToStartTimeout", v)?;
+        }
+        if let Some(v) = self.start_to_close_timeout.as_ref() {
+            struct_ser.serialize_field("startToCloseTimeout", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for PendingNexusOperationInfo {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "endpoint",
+            "service",
+            "operation",
+            "operation_id",
+            "operationId",
+            "schedule_to_close_timeout",
+            "scheduleToCloseTimeout",
+            "scheduled_time",
+            "scheduledTime",
+            "state",
+            "attempt",
+            "last_attempt_complete_time",
+            "lastAttemptCompleteTime",
+            "last_attempt_failure",
+            "lastAttemptFailure",
+            "next_attempt_schedule_time",
+            "nextAttemptScheduleTime",
+            "cancellation_info",
+            "cancellationInfo",
+            "scheduled_event_id",
+            "scheduledEventId",
+            "blocked_reason",
+            "blockedReason",
+            "operation_token",
+            "operationToken",
+            "schedule_to_start_timeout",
+            "scheduleToStartTimeout",
+            "start_to_close_timeout",
+            "startToCloseTimeout",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Endpoint,
+            Service,
+            Operation,
+            OperationId,
+            ScheduleToCloseTimeout,
+            ScheduledTime,
+            State,
+            Attempt,
+            LastAttemptCompleteTime,
+            LastAttemptFailure,
+            NextAttemptScheduleTime,
+            CancellationInfo,
+            ScheduledEventId,
+            BlockedReason,
+            OperationToken,
+            ScheduleToStartTimeout,
+            StartToCloseTimeout,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "endpoint" => Ok(GeneratedField::Endpoint),
+                            "service" => Ok(GeneratedField::Service),
+                            "operation" => Ok(GeneratedField::Operation),
+                            "operationId" | "operation_id" => Ok(GeneratedField::OperationId),
+                            "scheduleToCloseTimeout" | "schedule_to_close_timeout" => Ok(GeneratedField::ScheduleToCloseTimeout),
+                            "scheduledTime" | "scheduled_time" => Ok(GeneratedField::ScheduledTime),
+                            "state" => Ok(GeneratedField::State),
+                            "attempt" => Ok(GeneratedField::Attempt),
+                            "lastAttemptCompleteTime" | "last_attempt_complete_time" => Ok(GeneratedField::LastAttemptCompleteTime),
+                            "lastAttemptFailure" | "last_attempt_failure" => Ok(GeneratedField::LastAttemptFailure),
+                            "nextAttemptScheduleTime" | "next_attempt_schedule_time" => Ok(GeneratedField::NextAttemptScheduleTime),
+                            "cancellationInfo" | "cancellation_info" => Ok(GeneratedField::CancellationInfo),
+                            "scheduledEventId" | "scheduled_event_id" => Ok(GeneratedField::ScheduledEventId),
+                            "blockedReason" | "blocked_reason" => Ok(GeneratedField::BlockedReason),
+                            "operationToken" | "operation_token" => Ok(GeneratedField::OperationToken),
+                            "scheduleToStartTimeout" | "schedule_to_start_timeout" => Ok(GeneratedField::ScheduleToStartTimeout),
+                            "startToCloseTimeout" | "start_to_close_timeout" => Ok(GeneratedField::StartToCloseTimeout),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = PendingNexusOperationInfo;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct temporal.api.workflow.v1.PendingNexusOperationInfo")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<PendingNexusOperationInfo, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut endpoint__ = None;
+                let mut service__ = None;
+                let mut operation__ = None;
+                let mut operation_id__ = None;
+                let mut schedule_to_close_timeout__ = None;
+                let mut scheduled_time__ = None;
+                let mut state__ = None;
+                let mut attempt__ = None;
+                let mut last_attempt_complete_time__ = None;
+                let mut last_attempt_failure__ = None;
+                let mut next_attempt_schedule_time__ = None;
+                let mut cancellation_info__ = None;
+                let mut scheduled_event_id__ = None;
+                let mut blocked_reason__ = None;
+                let mut operation_token__ = None;
+                let mut schedule_to_start_timeout__ = None;
+                let mut start_to_close_timeout__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Endpoint => {
+                            if endpoint__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("endpoint"));
+                            }
+                            endpoint__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::Service => {
+                            if service__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("service"));
+                            }
+                            service__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::Operation => {
+                            if operation__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("operation"));
+                            }
+                            operation__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::OperationId => {
+                            if operation_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("operationId"));
+                            }
+                            operation_id__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::ScheduleToCloseTimeout => {
+                            if schedule_to_close_timeout__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("scheduleToCloseTimeout"));
+                            }
+                            schedule_to_close_timeout__ = map_.next_value()?;
+                        }
+                        GeneratedField::ScheduledTime => {
+                            if scheduled_time__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("scheduledTime"));
+                            }
+                            scheduled_time__ = map_.next_value()?;
+                        }
+                        GeneratedField::State => {
+                            if state__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("state"));
+                            }
+                            state__ = Some(map_.next_value::<super::super::enums::v1::PendingNexusOperationState>()? as i32);
+                        }
+                        GeneratedField::Attempt => {
+                            if attempt__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("attempt"));
+                            }
+                            attempt__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::LastAttemptCompleteTime => {
+                            if last_attempt_complete_time__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("lastAttemptCompleteTime"));
+                            }
+                            last_attempt_complete_time__ = map_.next_value()?;
+                        }
+                        GeneratedField::LastAttemptFailure => {
+                            if last_attempt_failure__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("lastAttemptFailure"));
+                            }
+                            last_attempt_failure__ = map_.next_value()?;
+                        }
+                        GeneratedField::NextAttemptScheduleTime => {
+                            if next_attempt_schedule_time__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("nextAttemptScheduleTime"));
+                            }
+                            next_attempt_schedule_time__ = map_.next_value()?;
+                        }
+                        GeneratedField::CancellationInfo => {
+                            if cancellation_info__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("cancellationInfo"));
+                            }
+                            cancellation_info__ = map_.next_value()?;
+                        }
+                        GeneratedField::ScheduledEventId => {
+                            if scheduled_event_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("scheduledEventId"));
+                            }
+                            scheduled_event_id__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::BlockedReason => {
+                            if blocked_reason__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("blockedReason"));
+                            }
+                            blocked_reason__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::OperationToken => {
+                            if operation_token__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("operationToken"));
+                            }
+                            operation_token__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::ScheduleToStartTimeout => {
+                            if schedule_to_start_timeout__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("scheduleToStartTimeout"));
+                            }
+                            schedule_to_start_timeout__ = map_.next_value()?;
+                        }
+                        GeneratedField::StartToCloseTimeout => {
+                            if start_to_close_timeout__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("startToCloseTimeout"));
+                            }
+                            start_to_close_timeout__ = map_.next_value()?;
+                        }
+                    }
+                }
+                Ok(PendingNexusOperationInfo {
+                    endpoint: endpoint__.unwrap_or_default(),
+                    service: service__.unwrap_or_default(),
+                    operation: operation__.unwrap_or_default(),
+                    operation_id: operation_id__.unwrap_or_default(),
+                    schedule_to_close_timeout: schedule_to_close_timeout__,
+                    scheduled_time: scheduled_time__,
+                    state: state__.unwrap_or_default(),
+                    attempt: attempt__.unwrap_or_default(),
+                    last_attempt_complete_time: last_attempt_complete_time__,
+                    last_attempt_failure: last_attempt_failure__,
+                    next_attempt_schedule_time: next_attempt_schedule_time__,
+                    cancellation_info: cancellation_info__,
+                    scheduled_event_id: scheduled_event_id__.unwrap_or_default(),
+                    blocked_reason: blocked_reason__.unwrap_or_default(),
+                    operation_token: operation_token__.unwrap_or_default(),
+                    schedule_to_start_timeout: schedule_to_start_timeout__,
+                    start_to_close_timeout: start_to_close_timeout__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("temporal.api.workflow.v1.PendingNexusOperationInfo", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for PendingWorkflowTaskInfo {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.state != 0 {
+            len += 1;
+        }
+        if self.scheduled_time.is_some() {
+            len += 1;
+        }
+        if self.original_scheduled_time.is_some() {
+            len += 1;
+        }
+        if self.started_time.is_some() {
+            len += 1;
+        }
+        if self.attempt != 0 {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("temporal.api.workflow.v1.PendingWorkflowTaskInfo", len)?;
+        if self.state != 0 {
+            let v = super::super::enums::v1::PendingWorkflowTaskState::try_from(self.state)
+                .map_err(|_| serde::ser::Error::custom(format!("Invalid variant {}", self.state)))?;
+            struct_ser.serialize_field("state", &v)?;
+        }
+        if let Some(v) = self.scheduled_time.as_ref() {
+            struct_ser.serialize_field("scheduledTime", v)?;
+        }
+        if let Some(v) = self.original_scheduled_time.as_ref() {
+            struct_ser.serialize_field("originalScheduledTime", v)?;
+        }
+        if let Some(v) = self.started_time.as_ref() {
+            struct_ser.serialize_field("startedTime", v)?;
+        }
+        if self.attempt != 0 {
+            struct_ser.serialize_field("attempt", &self.attempt)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for PendingWorkflowTaskInfo {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "state",
+            "scheduled_time",
+            "scheduledTime",
+            "original_scheduled_time",
+            "originalScheduledTime",
+            "started_time",
+            "startedTime",
+            "attempt",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            State,
+            ScheduledTime,
+            OriginalScheduledTime,
+            StartedTime,
+            Attempt,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "state" => Ok(GeneratedField::State),
+                            "scheduledTime" | "scheduled_time" => Ok(GeneratedField::ScheduledTime),
+                            "originalScheduledTime" | "original_scheduled_time" => Ok(GeneratedField::OriginalScheduledTime),
+                            "startedTime" | "started_time" => Ok(GeneratedField::StartedTime),
+                            "attempt" => Ok(GeneratedField::Attempt),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = PendingWorkflowTaskInfo;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct temporal.api.workflow.v1.PendingWorkflowTaskInfo")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<PendingWorkflowTaskInfo, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut state__ = None;
+                let mut scheduled_time__ = None;
+                let mut original_scheduled_time__ = None;
+                let mut started_time__ = None;
+                let mut attempt__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::State => {
+                            if state__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("state"));
+                            }
+                            state__ = Some(map_.next_value::<super::super::enums::v1::PendingWorkflowTaskState>()? as i32);
+                        }
+                        GeneratedField::ScheduledTime => {
+                            if scheduled_time__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("scheduledTime"));
+                            }
+                            scheduled_time__ = map_.next_value()?;
+                        }
+                        GeneratedField::OriginalScheduledTime => {
+                            if original_scheduled_time__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("originalScheduledTime"));
+                            }
+                            original_scheduled_time__ = map_.next_value()?;
+                        }
+                        GeneratedField::StartedTime => {
+                            if started_time__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("startedTime"));
+                            }
+                            started_time__ = map_.next_value()?;
+                        }
+                        GeneratedField::Attempt => {
+                            if attempt__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("attempt"));
+                            }
+                            attempt__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                    }
+                }
+                Ok(PendingWorkflowTaskInfo {
+                    state: state__.unwrap_or_default(),
+                    scheduled_time: scheduled_time__,
+                    original_scheduled_time: original_scheduled_time__,
+                    started_time: started_time__,
+                    attempt: attempt__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("temporal.api.workflow.v1.PendingWorkflowTaskInfo", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for PostResetOperation {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.variant.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("temporal.api.workflow.v1.PostResetOperation", len)?;
+        if let Some(v) = self.variant.as_ref() {
+            match v {
+                post_reset_operation::Variant::SignalWorkflow(v) => {
+                    struct_ser.serialize_field("signalWorkflow", v)?;
+                }
+                post_reset_operation::Variant::UpdateWorkflowOptions(v) => {
+                    struct_ser.serialize_field("updateWorkflowOptions", v)?;
+                }
+            }
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for PostResetOperation {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "signal_workflow",
+            "signalWorkflow",
+            "update_workflow_options",
+            "updateWorkflowOptions",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            SignalWorkflow,
+            UpdateWorkflowOptions,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "signalWorkflow" | "signal_workflow" => Ok(GeneratedField::SignalWorkflow),
+                            "updateWorkflowOptions" | "update_workflow_options" => Ok(GeneratedField::UpdateWorkflowOptions),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = PostResetOperation;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct temporal.api.workflow.v1.PostResetOperation")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<PostResetOperation, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut variant__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::SignalWorkflow => {
+                            if variant__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("signalWorkflow"));
+                            }
+                            variant__ = map_.next_value::<::std::option::Option<_>>()?.map(post_reset_operation::Variant::SignalWorkflow)
+;
+                        }
+                        GeneratedField::UpdateWorkflowOptions => {
+                            if variant__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("updateWorkflowOptions"));
+                            }
+                            variant__ = map_.next_value::<::std::option::Option<_>>()?.map(post_reset_operation::Variant::UpdateWorkflowOptions)
+;
+                        }
+                    }
+                }
+                Ok(PostResetOperation {
+                    variant: variant__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("temporal.api.workflow.v1.PostResetOperation", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for post_reset_operation::SignalWorkflow {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.signal_name.is_empty() {
+            len += 1;
+        }
+        if self.input.is_some() {
+            len += 1;
+        }
+        if self.header.is_some() {
+            len += 1;
+        }
+        if !self.links.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("temporal.api.workflow.v1.PostResetOperation.SignalWorkflow", len)?;
+        if !self.signal_name.is_empty() {
+            struct_ser.serialize_field("signalName", &self.signal_name)?;
+        }
+        if let Some(v) = self.input.as_ref() {
+            struct_ser.serialize_field("input", v)?;
+        }
+        if let Some(v) = self.header.as_ref() {
+            struct_ser.serialize_field("header", v)?;
+        }
+        if !self.links.is_empty() {
+            struct_ser.serialize_field("links", &self.links)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for post_reset_operation::SignalWorkflow {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "signal_name",
+            "signalName",
+            "input",
+            "header",
+            "links",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            SignalName,
+            Input,
+            Header,
+            Links,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "signalName" | "signal_name" => Ok(GeneratedField::SignalName),
+                            "input" => Ok(GeneratedField::Input),
+                            "header" => Ok(GeneratedField::Header),
+                            "links" => Ok(GeneratedField::Links),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = post_reset_operation::SignalWorkflow;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct temporal.api.workflow.v1.PostResetOperation.SignalWorkflow")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<post_reset_operation::SignalWorkflow, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut signal_name__ = None;
+                let mut input__ = None;
+                let mut header__ = None;
+                let mut links__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::SignalName => {
+                            if signal_name__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("signalName"));
+                            }
+                            signal_name__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::Input => {
+                            if input__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("input"));
+                            }
+                            input__ = map_.next_value()?;
+                        }
+                        GeneratedField::Header => {
+                            if header__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("header"));
+                            }
+                            header__ = map_.next_value()?;
+                        }
+                        GeneratedField::Links => {
+                            if links__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("links"));
+                            }
+                            links__ = Some(map_.next_value()?);
+                        }
+                    }
+                }
+                Ok(post_reset_operation::SignalWorkflow {
+                    signal_name: signal_name__.unwrap_or_default(),
+                    input: input__,
+                    header: header__,
+                    links: links__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("temporal.api.workflow.v1.PostResetOperation.SignalWorkflow", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for post_reset_operation::UpdateWorkflowOptions {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.workflow_execution_options.is_some() {
+            len += 1;
+        }
+        if self.update_mask.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("temporal.api.workflow.v1.PostResetOperation.UpdateWorkflowOptions", len)?;
+        if let Some(v) = self.workflow_execution_options.as_ref() {
+            struct_ser.serialize_field("workflowExecutionOptions", v)?;
+        }
+        if let Some(v) = self.update_mask.as_ref() {
+            struct_ser.serialize_field("updateMask", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for post_reset_operation::UpdateWorkflowOptions {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "workflow_execution_options",
+            "workflowExecutionOptions",
+            "update_mask",
+            "updateMask",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            WorkflowExecutionOptions,
+            UpdateMask,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "workflowExecutionOptions" | "workflow_execution_options" => Ok(GeneratedField::WorkflowExecutionOptions),
+                            "updateMask" | "update_mask" => Ok(GeneratedField::UpdateMask),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = post_reset_operation::UpdateWorkflowOptions;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct temporal.api.workflow.v1.PostResetOperation.UpdateWorkflowOptions")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<post_reset_operation::UpdateWorkflowOptions, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut workflow_execution_options__ = None;
+                let mut update_mask__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::WorkflowExecutionOptions => {
+                            if workflow_execution_options__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("workflowExecutionOptions"));
+                            }
+                            workflow_execution_options__ = map_.next_value()?;
+                        }
+                        GeneratedField::UpdateMask => {
+                            if update_mask__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("updateMask"));
+                            }
+                            update_mask__ = map_.next_value()?;
+                        }
+                    }
+                }
+                Ok(post_reset_operation::UpdateWorkflowOptions {
+                    workflow_execution_options: workflow_execution_options__,
+                    update_mask: update_mask__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("temporal.api.workflow.v1.PostResetOperation.UpdateWorkflowOptions", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for RequestIdInfo {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.event_type != 0 {
+            len += 1;
+        }
+        if self.event_id != 0 {
+            len += 1;
+        }
+        if self.buffered {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("temporal.api.workflow.v1.RequestIdInfo", len)?;
+        if self.event_type != 0 {
+            let v = super::super::enums::v1::EventType::try_from(self.event_type)
+                .map_err(|_| serde::ser::Error::custom(format!("Invalid variant {}", self.event_type)))?;
+            struct_ser.serialize_field("eventType", &v)?;
+        }
+        if self.event_id != 0 {
+            #[allow(clippy::needless_borrow)]
+            #[allow(clippy::needless_borrows_for_generic_args)]
+            struct_ser.serialize_field("eventId", ToString::to_string(&self.event_id).as_str())?;
+        }
+        if self.buffered {
+            struct_ser.serialize_field("buffered", &self.buffered)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for RequestIdInfo {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "event_type",
+            "eventType",
+            "event_id",
+            "eventId",
+            "buffered",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            EventType,
+            EventId,
+            Buffered,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "eventType" | "event_type" => Ok(GeneratedField::EventType),
+                            "eventId" | "event_id" => Ok(GeneratedField::EventId),
+                            "buffered" => Ok(GeneratedField::Buffered),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = RequestIdInfo;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct temporal.api.workflow.v1.RequestIdInfo")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<RequestIdInfo, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut event_type__ = None;
+                let mut event_id__ = None;
+                let mut buffered__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::EventType => {
+                            if event_type__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("eventType"));
+                            }
+                            event_type__ = Some(map_.next_value::<super::super::enums::v1::EventType>()? as i32);
+                        }
+                        GeneratedField::EventId => {
+                            if event_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("eventId"));
+                            }
+                            event_id__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::Buffered => {
+                            if buffered__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("buffered"));
+                            }
+                            buffered__ = Some(map_.next_value()?);
+                        }
+                    }
+                }
+                Ok(RequestIdInfo {
+                    event_type: event_type__.unwrap_or_default(),
+                    event_id: event_id__.unwrap_or_default(),
+                    buffered: buffered__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("temporal.api.workflow.v1.RequestIdInfo", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for ResetPointInfo {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.build_id.is_empty() {
+            len += 1;
+        }
+        if !self.binary_checksum.is_empty() {
+            len += 1;
+        }
+        if !self.run_id.is_empty() {
+            len += 1;
+        }
+        if self.first_workflow_task_completed_id != 0 {
+            len += 1;
+        }
+        if self.create_time.is_some() {
+            len += 1;
+        }
+        if self.expire_time.is_some() {
+            len += 1;
+        }
+        if self.resettable {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("temporal.api.workflow.v1.ResetPointInfo", len)?;
+        if !self.build_id.is_empty() {
+            struct_ser.serialize_field("buildId", &self.build_id)?;
+        }
+        if !self.binary_checksum.is_empty() {
+            struct_ser.serialize_field("binaryChecksum", &self.binary_checksum)?;
+        }
+        if !self.run_id.is_empty() {
+            struct_ser.serialize_field("runId", &self.run_id)?;
+        }
+        if self.first_workflow_task_completed_id != 0 {
+            #[allow(clippy::needless_borrow)]
+            #[allow(clippy::needless_borrows_for_generic_args)]
+            struct_ser.serialize_field("firstWorkflowTaskCompletedId", ToString::to_string(&self.first_workflow_task_completed_id).as_str())?;
+        }
+        if let Some(v) = self.create_time.as_ref() {
+            struct_ser.serialize_field("createTime", v)?;
+        }
+        if let Some(v) = self.expire_time.as_ref() {
+            struct_ser.serialize_field("expireTime", v)?;
+        }
+        if self.resettable {
+            struct_ser.serialize_field("resettable", &self.resettable)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for ResetPointInfo {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "build_id",
+            "buildId",
+            "binary_checksum",
+            "binaryChecksum",
+            "run_id",
+            "runId",
+            "first_workflow_task_completed_id",
+            "firstWorkflowTaskCompletedId",
+            "create_time",
+            "createTime",
+            "expire_time",
+            "expireTime",
+            "resettable",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            BuildId,
+            BinaryChecksum,
+            RunId,
+            FirstWorkflowTaskCompletedId,
+            CreateTime,
+            ExpireTime,
+            Resettable,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "buildId" | "build_id" => Ok(GeneratedField::BuildId),
+                            "binaryChecksum" | "binary_checksum" => Ok(GeneratedField::BinaryChecksum),
+                            "runId" | "run_id" => Ok(GeneratedField::RunId),
+                            "firstWorkflowTaskCompletedId" | "first_workflow_task_completed_id" => Ok(GeneratedField::FirstWorkflowTaskCompletedId),
+                            "createTime" | "create_time" => Ok(GeneratedField::CreateTime),
+                            "expireTime" | "expire_time" => Ok(GeneratedField::ExpireTime),
+                            "resettable" => Ok(GeneratedField::Resettable),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = ResetPointInfo;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct temporal.api.workflow.v1.ResetPointInfo")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<ResetPointInfo, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut build_id__ = None;
+                let mut binary_checksum__ = None;
+                let mut run_id__ = None;
+                let mut first_workflow_task_completed_id__ = None;
+                let mut create_time__ = None;
+                let mut expire_time__ = None;
+                let mut resettable__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::BuildId => {
+                            if build_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("buildId"));
+                            }
+                            build_id__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::BinaryChecksum => {
+                            if binary_checksum__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("binaryChecksum"));
+                            }
+                            binary_checksum__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::RunId => {
+                            if run_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("runId"));
+                            }
+                            run_id__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::FirstWorkflowTaskCompletedId => {
+                            if first_workflow_task_completed_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("firstWorkflowTaskCompletedId"));
+                            }
+                            first_workflow_task_completed_id__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::CreateTime => {
+                            if create_time__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("createTime"));
+                            }
+                            create_time__ = map_.next_value()?;
+                        }
+                        GeneratedField::ExpireTime => {
+                            if expire_time__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("expireTime"));
+                            }
+                            expire_time__ = map_.next_value()?;
+                        }
+                        GeneratedField::Resettable => {
+                            if resettable__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("resettable"));
+                            }
+                            resettable__ = Some(map_.next_value()?);
+                        }
+                    }
+                }
+                Ok(ResetPointInfo {
+                    build_id: build_id__.unwrap_or_default(),
+                    binary_checksum: binary_checksum__.unwrap_or_default(),
+                    run_id: run_id__.unwrap_or_default(),
+                    first_workflow_task_completed_id: first_workflow_task_completed_id__.unwrap_or_default(),
+                    create_time: create_time__,
+                    expire_time: expire_time__,
+                    resettable: resettable__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("temporal.api.workflow.v1.ResetPointInfo", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for ResetPoints {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.points.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("temporal.api.workflow.v1.ResetPoints", len)?;
+        if !self.points.is_empty() {
+            struct_ser.serialize_field("points", &self.points)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for ResetPoints {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "points",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Points,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "points" => Ok(GeneratedField::Points),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = ResetPoints;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct temporal.api.workflow.v1.ResetPoints")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<ResetPoints, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut points__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Points => {
+                            if points__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("points"));
+                            }
+                            points__ = Some(map_.next_value()?);
+                        }
+                    }
+                }
+                Ok(ResetPoints {
+                    points: points__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("temporal.api.workflow.v1.ResetPoints", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for TimeSkippingConfig {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.enabled {
+            len += 1;
+        }
+        if self.bound.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("temporal.api.workflow.v1.TimeSkippingConfig", len)?;
+        if self.enabled {
+            struct_ser.serialize_field("enabled", &self.enabled)?;
+        }
+        if let Some(v) = self.bound.as_ref() {
+            match v {
+                time_skipping_config::Bound::MaxSkippedDuration(v) => {
+                    struct_ser.serialize_field("maxSkippedDuration", v)?;
+                }
+                time_skipping_config::Bound::MaxElapsedDuration(v) => {
+                    struct_ser.serialize_field("maxElapsedDuration", v)?;
+                }
+            }
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for TimeSkippingConfig {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "enabled",
+            "max_skipped_duration",
+            "maxSkippedDuration",
+            "max_elapsed_duration",
+            "maxElapsedDuration",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Enabled,
+            MaxSkippedDuration,
+            MaxElapsedDuration,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "enabled" => Ok(GeneratedField::Enabled),
+                            "maxSkippedDuration" | "max_skipped_duration" => Ok(GeneratedField::MaxSkippedDuration),
+                            "maxElapsedDuration" | "max_elapsed_duration" => Ok(GeneratedField::MaxElapsedDuration),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = TimeSkippingConfig;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct temporal.api.workflow.v1.TimeSkippingConfig")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<TimeSkippingConfig, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut enabled__ = None;
+                let mut bound__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Enabled => {
+                            if enabled__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("enabled"));
+                            }
+                            enabled__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::MaxSkippedDuration => {
+                            if bound__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("maxSkippedDuration"));
+                            }
+                            bound__ = map_.next_value::<::std::option::Option<_>>()?.map(time_skipping_config::Bound::MaxSkippedDuration)
+;
+                        }
+                        GeneratedField::MaxElapsedDuration => {
+                            if bound__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("maxElapsedDuration"));
+                            }
+                            bound__ = map_.next_value::<::std::option::Option<_>>()?.map(time_skipping_config::Bound::MaxElapsedDuration)
+;
+                        }
+                    }
+                }
+                Ok(TimeSkippingConfig {
+                    enabled: enabled__.unwrap_or_default(),
+                    bound: bound__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("temporal.api.workflow.v1.TimeSkippingConfig", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for VersioningOverride {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.behavior != 0 {
+            len += 1;
+        }
+        if self.deployment.is_some() {
+            len += 1;
+        }
+        if !self.pinned_version.is_empty() {
+            len += 1;
+        }
+        if self.r#override.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("temporal.api.workflow.v1.VersioningOverride", len)?;
+        if self.behavior != 0 {
+            let v = super::super::enums::v1::VersioningBehavior::try_from(self.behavior)
+                .map_err(|_| serde::ser::Error::custom(format!("Invalid variant {}", self.behavior)))?;
+            struct_ser.serialize_field("behavior", &v)?;
+        }
+        if let Some(v) = self.deployment.as_ref() {
+            struct_ser.serialize_field("deployment", v)?;
+        }
+        if !self.pinned_version.is_empty() {
+            struct_ser.serialize_field("pinnedVersion", &self.pinned_version)?;
+        }
+        if let Some(v) = self.r#override.as_ref() {
+            match v {
+                versioning_override::Override::Pinned(v) => {
+                    struct_ser.serialize_field("pinned", v)?;
+                }
+                versioning_override::Override::AutoUpgrade(v) => {
+                    struct_ser.serialize_field("autoUpgrade", v)?;
+                }
+            }
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for VersioningOverride {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "behavior",
+            "deployment",
+            "pinned_version",
+            "pinnedVersion",
+            "pinned",
+            "auto_upgrade",
+            "autoUpgrade",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Behavior,
+            Deployment,
+            PinnedVersion,
+            Pinned,
+            AutoUpgrade,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "behavior" => Ok(GeneratedField::Behavior),
+                            "deployment" => Ok(GeneratedField::Deployment),
+                            "pinnedVersion" | "pinned_version" => Ok(GeneratedField::PinnedVersion),
+                            "pinned" => Ok(GeneratedField::Pinned),
+                            "autoUpgrade" | "auto_upgrade" => Ok(GeneratedField::AutoUpgrade),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = VersioningOverride;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct temporal.api.workflow.v1.VersioningOverride")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<VersioningOverride, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut behavior__ = None;
+                let mut deployment__ = None;
+                let mut pinned_version__ = None;
+                let mut r#override__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Behavior => {
+                            if behavior__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("behavior"));
+                            }
+                            behavior__ = Some(map_.next_value::<super::super::enums::v1::VersioningBehavior>()? as i32);
+                        }
+                        GeneratedField::Deployment => {
+                            if deployment__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("deployment"));
+                            }
+                            deployment__ = map_.next_value()?;
+                        }
+                        GeneratedField::PinnedVersion => {
+                            if pinned_version__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("pinnedVersion"));
+                            }
+                            pinned_version__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::Pinned => {
+                            if r#override__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("pinned"));
+                            }
+                            r#override__ = map_.next_value::<::std::option::Option<_>>()?.map(versioning_override::Override::Pinned)
+;
+                        }
+                        GeneratedField::AutoUpgrade => {
+                            if r#override__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("autoUpgrade"));
+                            }
+                            r#override__ = map_.next_value::<::std::option::Option<_>>()?.map(versioning_override::Override::AutoUpgrade);
+                        }
+                    }
+                }
+                Ok(VersioningOverride {
+                    behavior: behavior__.unwrap_or_default(),
+                    deployment: deployment__,
+                    pinned_version: pinned_version__.unwrap_or_default(),
+                    r#override: r#override__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("temporal.api.workflow.v1.VersioningOverride", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for versioning_override::PinnedOverride {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.behavior != 0 {
+            len += 1;
+        }
+        if self.version.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("temporal.api.workflow.v1.VersioningOverride.PinnedOverride", len)?;
+        if self.behavior != 0 {
+            let v = versioning_override::PinnedOverrideBehavior::try_from(self.behavior)
+                .map_err(|_| serde::ser::Error::custom(format!("Invalid variant {}", self.behavior)))?;
+            struct_ser.serialize_field("behavior", &v)?;
+        }
+        if let Some(v) = self.version.as_ref() {
+            struct_ser.serialize_field("version", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for versioning_override::PinnedOverride {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "behavior",
+            "version",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Behavior,
+            Version,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "behavior" => Ok(GeneratedField::Behavior),
+                            "version" => Ok(GeneratedField::Version),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = versioning_override::PinnedOverride;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct temporal.api.workflow.v1.VersioningOverride.PinnedOverride")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<versioning_override::PinnedOverride, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut behavior__ = None;
+                let mut version__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Behavior => {
+                            if behavior__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("behavior"));
+                            }
+                            behavior__ = Some(map_.next_value::<versioning_override::PinnedOverrideBehavior>()? as i32);
+                        }
+                        GeneratedField::Version => {
+                            if version__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("version"));
+                            }
+                            version__ = map_.next_value()?;
+                        }
+                    }
+                }
+                Ok(versioning_override::PinnedOverride {
+                    behavior: behavior__.unwrap_or_default(),
+                    version: version__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("temporal.api.workflow.v1.VersioningOverride.PinnedOverride", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for versioning_override::PinnedOverrideBehavior {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        let variant = match self {
+            Self::Unspecified => "PINNED_OVERRIDE_BEHAVIOR_UNSPECIFIED",
+            Self::Pinned => "PINNED_OVERRIDE_BEHAVIOR_PINNED",
+        };
+        serializer.serialize_str(variant)
+    }
+}
+impl<'de> serde::Deserialize<'de> for versioning_override::PinnedOverrideBehavior {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "PINNED_OVERRIDE_BEHAVIOR_UNSPECIFIED",
+            "PINNED_OVERRIDE_BEHAVIOR_PINNED",
+        ];
+
+        struct GeneratedVisitor;
+
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = versioning_override::PinnedOverrideBehavior;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                write!(formatter, "expected one of: {:?}", &FIELDS)
+            }
+
+            fn visit_i64<E>(self, v: i64) -> std::result::Result<Self::Value, E>
+            where
+                E: serde::de::Error,
+            {
+                i32::try_from(v)
+                    .ok()
+                    .and_then(|x| x.try_into().ok())
+                    .ok_or_else(|| {
+                        serde::de::Error::invalid_value(serde::de::Unexpected::Signed(v), &self)
+                    })
+            }
+
+            fn visit_u64<E>(self, v: u64) -> std::result::Result<Self::Value, E>
+            where
+                E: serde::de::Error,
+            {
+                i32::try_from(v)
+                    .ok()
+                    .and_then(|x| x.try_into().ok())
+                    .ok_or_else(|| {
+                        serde::de::Error::invalid_value(serde::de::Unexpected::Unsigned(v), &self)
+                    })
+            }
+
+            fn visit_str<E>(self, value: &str) -> std::result::Result<Self::Value, E>
+            where
+                E: serde::de::Error,
+            {
+                match value {
+                    "PINNED_OVERRIDE_BEHAVIOR_UNSPECIFIED" => Ok(versioning_override::PinnedOverrideBehavior::Unspecified),
+                    "PINNED_OVERRIDE_BEHAVIOR_PINNED" => Ok(versioning_override::PinnedOverrideBehavior::Pinned),
+                    _ => Err(serde::de::Error::unknown_variant(value, FIELDS)),
+                }
+            }
+        }
+        deserializer.deserialize_any(GeneratedVisitor)
+    }
+}
+impl serde::Serialize for WorkflowExecutionConfig {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.task_queue.is_some() {
+            len += 1;
+        }
+        if self.workflow_execution_timeout.is_some() {
+            len += 1;
+        }
+        if self.workflow_run_timeout.is_some() {
+            len += 1;
+        }
+        if self.default_workflow_task_timeout.is_some() {
+            len += 1;
+        }
+        if self.user_metadata.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("temporal.api.workflow.v1.WorkflowExecutionConfig", len)?;
+        if let Some(v) = self.task_queue.as_ref() {
+            struct_ser.serialize_field("taskQueue", v)?;
+        }
+        if let Some(v) = self.workflow_execution_timeout.as_ref() {
+            struct_ser.serialize_field("workflowExecutionTimeout", v)?;
+        }
+        if let Some(v) = self.workflow_run_timeout.as_ref() {
+            struct_ser.serialize_field("workflowRunTimeout", v)?;
+        }
+        if let Some(v) = self.default_workflow_task_timeout.as_ref() {
+            struct_ser.serialize_field("defaultWorkflowTaskTimeout", v)?;
+        }
+        if let Some(v) = self.user_metadata.as_ref() {
+            struct_ser.serialize_field("userMetadata", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for WorkflowExecutionConfig {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "task_queue",
+            "taskQueue",
+            "workflow_execution_timeout",
+            "workflowExecutionTimeout",
+            "workflow_run_timeout",
+            "workflowRunTimeout",
+            "default_workflow_task_timeout",
+            "defaultWorkflowTaskTimeout",
+            "user_metadata",
+            "userMetadata",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            TaskQueue,
+            WorkflowExecutionTimeout,
+            WorkflowRunTimeout,
+            DefaultWorkflowTaskTimeout,
+            UserMetadata,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "taskQueue" | "task_queue" => Ok(GeneratedField::TaskQueue),
+                            "workflowExecutionTimeout" | "workflow_execution_timeout" => Ok(GeneratedField::WorkflowExecutionTimeout),
+                            "workflowRunTimeout" | "workflow_run_timeout" => Ok(GeneratedField::WorkflowRunTimeout),
+                            "defaultWorkflowTaskTimeout" | "default_workflow_task_timeout" => Ok(GeneratedField::DefaultWorkflowTaskTimeout),
+                            "userMetadata" | "user_metadata" => Ok(GeneratedField::UserMetadata),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = WorkflowExecutionConfig;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct temporal.api.workflow.v1.WorkflowExecutionConfig")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<WorkflowExecutionConfig, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut task_queue__ = None;
+                let mut workflow_execution_timeout__ = None;
+                let mut workflow_run_timeout__ = None;
+                let mut default_workflow_task_timeout__ = None;
+                let mut user_metadata__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::TaskQueue => {
+                            if task_queue__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("taskQueue"));
+                            }
+                            task_queue__ = map_.next_value()?;
+                        }
+                        GeneratedField::WorkflowExecutionTimeout => {
+                            if workflow_execution_timeout__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("workflowExecutionTimeout"));
+                            }
+                            workflow_execution_timeout__ = map_.next_value()?;
+                        }
+                        GeneratedField::WorkflowRunTimeout => {
+                            if workflow_run_timeout__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("workflowRunTimeout"));
+                            }
+                            workflow_run_timeout__ = map_.next_value()?;
+                        }
+                        GeneratedField::DefaultWorkflowTaskTimeout => {
+                            if default_workflow_task_timeout__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("defaultWorkflowTaskTimeout"));
+                            }
+                            default_workflow_task_timeout__ = map_.next_value()?;
+                        }
+                        GeneratedField::UserMetadata => {
+                            if user_metadata__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("userMetadata"));
+                            }
+                            user_metadata__ = map_.next_value()?;
+                        }
+                    }
+                }
+                Ok(WorkflowExecutionConfig {
+                    task_queue: task_queue__,
+                    workflow_execution_timeout: workflow_execution_timeout__,
+                    workflow_run_timeout: workflow_run_timeout__,
+                    default_workflow_task_timeout: default_workflow_task_timeout__,
+                    user_metadata: user_metadata__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("temporal.api.workflow.v1.WorkflowExecutionConfig", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for WorkflowExecutionExtendedInfo {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.execution_expiration_time.is_some() {
+            len += 1;
+        }
+        if self.run_expiration_time.is_some() {
+            len += 1;
+        }
+        if self.cancel_requested {
+            len += 1;
+        }
+        if self.last_reset_time.is_some() {
+            len += 1;
+        }
+        if self.original_start_time.is_some() {
+            len += 1;
+        }
+        if !self.reset_run_id.is_empty() {
+            len += 1;
+        }
+        if !self.request_id_infos.is_empty() {
+            len += 1;
+        }
+        if self.pause_info.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("temporal.api.workflow.v1.WorkflowExecutionExtendedInfo", len)?;
+        if let Some(v) = self.execution_expiration_time.as_ref() {
+            struct_ser.serialize_field("executionExpirationTime", v)?;
+        }
+        if let Some(v) = self.run_expiration_time.as_ref() {
+            struct_ser.serialize_field("runExpirationTime", v)?;
+        }
+        if self.cancel_requested {
+            struct_ser.serialize_field("cancelRequested", &self.cancel_requested)?;
+        }
+        if let Some(v) = self.last_reset_time.as_ref() {
+            struct_ser.serialize_field("lastResetTime", v)?;
+        }
+        if let Some(v) = self.original_start_time.as_ref() {
+            struct_ser.serialize_field("originalStartTime", v)?;
+        }
+        if !self.reset_run_id.is_empty() {
+            struct_ser.serialize_field("resetRunId", &self.reset_run_id)?;
+        }
+        if !self.request_id_infos.is_empty() {
+            struct_ser.serialize_field("requestIdInfos", &self.request_id_infos)?;
+        }
+        if let Some(v) = self.pause_info.as_ref() {
+            struct_ser.serialize_field("pauseInfo", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for WorkflowExecutionExtendedInfo {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "execution_expiration_time",
+            "executionExpirationTime",
+            "run_expiration_time",
+            "runExpirationTime",
+            "cancel_requested",
+            "cancelRequested",
+            "last_reset_time",
+            "lastResetTime",
+            "original_start_time",
+            "originalStartTime",
+            "reset_run_id",
+            "resetRunId",
+            "request_id_infos",
+            "requestIdInfos",
+            "pause_info",
+            "pauseInfo",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            ExecutionExpirationTime,
+            RunExpirationTime,
+            CancelRequested,
+            LastResetTime,
+            OriginalStartTime,
+            ResetRunId,
+            RequestIdInfos,
+            PauseInfo,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "executionExpirationTime" | "execution_expiration_time" => Ok(GeneratedField::ExecutionExpirationTime),
+                            "runExpirationTime" | "run_expiration_time" => Ok(GeneratedField::RunExpirationTime),
+                            "cancelRequested" | "cancel_requested" => Ok(GeneratedField::CancelRequested),
+                            "lastResetTime" | "last_reset_time" => Ok(GeneratedField::LastResetTime),
+                            "originalStartTime" | "original_start_time" => Ok(GeneratedField::OriginalStartTime),
+                            "resetRunId" | "reset_run_id" => Ok(GeneratedField::ResetRunId),
+                            "requestIdInfos" | "request_id_infos" => Ok(GeneratedField::RequestIdInfos),
+                            "pauseInfo" | "pause_info" => Ok(GeneratedField::PauseInfo),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = WorkflowExecutionExtendedInfo;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct temporal.api.workflow.v1.WorkflowExecutionExtendedInfo")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<WorkflowExecutionExtendedInfo, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut execution_expiration_time__ = None;
+                let mut run_expiration_time__ = None;
+                let mut cancel_requested__ = None;
+                let mut last_reset_time__ = None;
+                let mut original_start_time__ = None;
+                let mut reset_run_id__ = None;
+                let mut request_id_infos__ = None;
+                let mut pause_info__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::ExecutionExpirationTime => {
+                            if execution_expiration_time__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("executionExpirationTime"));
+                            }
+                            execution_expiration_time__ = map_.next_value()?;
+                        }
+                        GeneratedField::RunExpirationTime => {
+                            if run_expiration_time__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("runExpirationTime"));
+                            }
+                            run_expiration_time__ = map_.next_value()?;
+                        }
+                        GeneratedField::CancelRequested => {
+                            if cancel_requested__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("cancelRequested"));
+                            }
+                            cancel_requested__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::LastResetTime => {
+                            if last_reset_time__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("lastResetTime"));
+                            }
+                            last_reset_time__ = map_.next_value()?;
+                        }
+                        GeneratedField::OriginalStartTime => {
+                            if original_start_time__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("originalStartTime"));
+                            }
+                            original_start_time__ = map_.next_value()?;
+                        }
+                        GeneratedField::ResetRunId => {
+                            if reset_run_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("resetRunId"));
+                            }
+                            reset_run_id__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::RequestIdInfos => {
+                            if request_id_infos__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("requestIdInfos"));
+                            }
+                            request_id_infos__ = Some(
+                                map_.next_value::<std::collections::HashMap<_, _>>()?
+                            );
+                        }
+                        GeneratedField::PauseInfo => {
+                            if pause_info__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("pauseInfo"));
+                            }
+                            pause_info__ = map_.next_value()?;
+                        }
+                    }
+                }
+                Ok(WorkflowExecutionExtendedInfo {
+                    execution_expiration_time: execution_expiration_time__,
+                    run_expiration_time: run_expiration_time__,
+                    cancel_requested: cancel_requested__.unwrap_or_default(),
+                    last_reset_time: last_reset_time__,
+                    original_start_time: original_start_time__,
+                    reset_run_id: reset_run_id__.unwrap_or_default(),
+                    request_id_infos: request_id_infos__.unwrap_or_default(),
+                    pause_info: pause_info__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("temporal.api.workflow.v1.WorkflowExecutionExtendedInfo", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for WorkflowExecutionInfo {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.execution.is_some() {
+            len += 1;
+        }
+        if self.r#type.is_some() {
+            len += 1;
+        }
+        if self.start_time.is_some() {
+            len += 1;
+        }
+        if self.close_time.is_some() {
+            len += 1;
+        }
+        if self.status != 0 {
+            len += 1;
+        }
+        if self.history_length != 0 {
+            len += 1;
+        }
+        if !self.parent_namespace_id.is_empty() {
+            len += 1;
+        }
+        if self.parent_execution.is_some() {
+            len += 1;
+        }
+        if self.execution_time.is_some() {
+            len += 1;
+        }
+        if self.memo.is_some() {
+            len += 1;
+        }
+        if self.search_attributes.is_some() {
+            len += 1;
+        }
+        if self.auto_reset_points.is_some() {
+            len += 1;
+        }
+        if !self.task_queue.is_empty() {
+            len += 1;
+        }
+        if self.state_transition_count != 0 {
+            len += 1;
+        }
+        if self.history_size_bytes != 0 {
+            len += 1;
+        }
+        if self.most_recent_worker_version_stamp.is_some() {
+            len += 1;
+        }
+        if self.execution_duration.is_some() {
+            len += 1;
+        }
+        if self.root_execution.is_some() {
+            len += 1;
+        }
+        if !self.assigned_build_id.is_empty() {
+            len += 1;
+        }
+        if !self.inherited_build_id.is_empty() {
+            len += 1;
+        }
+        if !self.first_run_id.is_empty() {
+            len += 1;
+        }
+        if self.versioning_info.is_some() {
+            len += 1;
+        }
+        if !self.worker_deployment_name.is_empty() {
+            len += 1;
+        }
+        if self.priority.is_some() {
+            len += 1;
+        }
+        if self.external_payload_size_bytes != 0 {
+            len += 1;
+        }
+        if self.external_payload_count != 0 {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("temporal.api.workflow.v1.WorkflowExecutionInfo", len)?;
+        if let Some(v) = self.execution.as_ref() {
+            struct_ser.serialize_field("execution", v)?;
+        }
+        if let Some(v) = self.r#type.as_ref() {
+            struct_ser.serialize_field("type", v)?;
+        }
+        if let Some(v) = self.start_time.as_ref() {
+            struct_ser.serialize_field("startTime", v)?;
+        }
+        if let Some(v) = self.close_time.as_ref() {
+            struct_ser.serialize_field("closeTime", v)?;
+        }
+        if self.status != 0 {
+            let v = super::super::enums::v1::WorkflowExecutionStatus::try_from(self.status)
+                .map_err(|_| serde::ser::Error::custom(format!("Invalid variant {}", self.status)))?;
+            struct_ser.serialize_field("status", &v)?;
+        }
+        if self.history_length != 0 {
+            #[allow(clippy::needless_borrow)]
+            #[allow(clippy::needless_borrows_for_generic_args)]
+            struct_ser.serialize_field("historyLength", ToString::to_string(&self.history_length).as_str())?;
+        }
+        if !self.parent_namespace_id.is_empty() {
+            struct_ser.serialize_field("parentNamespaceId", &self.parent_namespace_id)?;
+        }
+        if let Some(v) = self.parent_execution.as_ref() {
+            struct_ser.serialize_field("parentExecution", v)?;
+        }
+        if let Some(v) = self.execution_time.as_ref() {
+            struct_ser.serialize_field("executionTime", v)?;
+        }
+        if let Some(v) = self.memo.as_ref() {
+            struct_ser.serialize_field("memo", v)?;
+        }
+        if let Some(v) = self.search_attributes.as_ref() {
+            struct_ser.serialize_field("searchAttributes", v)?;
+        }
+        if let Some(v) = self.auto_reset_points.as_ref() {
+            struct_ser.serialize_field("autoResetPoints", v)?;
+        }
+        if !self.task_queue.is_empty() {
+            struct_ser.serialize_field("taskQueue", &self.task_queue)?;
+        }
+        if self.state_transition_count != 0 {
+            #[allow(clippy::needless_borrow)]
+            #[allow(clippy::needless_borrows_for_generic_args)]
+            struct_ser.serialize_field("stateTransitionCount", ToString::to_string(&self.state_transition_count).as_str())?;
+        }
+        if self.history_size_bytes != 0 {
+            #[allow(clippy::needless_borrow)]
+            #[allow(clippy::needless_borrows_for_generic_args)]
+            struct_ser.serialize_field("historySizeBytes", ToString::to_string(&self.history_size_bytes).as_str())?;
+        }
+        if let Some(v) = self.most_recent_worker_version_stamp.as_ref() {
+            struct_ser.serialize_field("mostRecentWorkerVersionStamp", v)?;
+        }
+        if let Some(v) = self.execution_duration.as_ref() {
+            struct_ser.serialize_field("executionDuration", v)?;
+        }
+        if let Some(v) = self.root_execution.as_ref() {
+            struct_ser.serialize_field("rootExecution", v)?;
+        }
+        if !self.assigned_build_id.is_empty() {
+            struct_ser.serialize_field("assignedBuildId", &self.assigned_build_id)?;
+        }
+        if !self.inherited_build_id.is_empty() {
+            struct_ser.serialize_field("inheritedBuildId", &self.inherited_build_id)?;
+        }
+        if !self.first_run_id.is_empty() {
+            struct_ser.serialize_field("firstRunId", &self.first_run_id)?;
+        }
+        if let Some(v) = self.versioning_info.as_ref() {
+            struct_ser.serialize_field("versioningInfo", v)?;
+        }
+        if !self.worker_deployment_name.is_empty() {
+            struct_ser.serialize_field("workerDeploymentName", &self.worker_deployment_name)?;
+        }
+        if let Some(v) = self.priority.as_ref() {
+            struct_ser.serialize_field("priority", v)?;
+        }
+        if self.external_payload_size_bytes != 0 {
+            #[allow(clippy::needless_borrow)]
+            #[allow(clippy::needless_borrows_for_generic_args)]
+            struct_ser.serialize_field("externalPayloadSizeBytes", ToString::to_string(&self.external_payload_size_bytes).as_str())?;
+        }
+        if self.external_payload_count != 0 {
+            #[allow(clippy::needless_borrow)]
+            #[allow(clippy::needless_borrows_for_generic_args)]
+            struct_ser.serialize_field("externalPayloadCount", ToString::to_string(&self.external_payload_count).as_str())?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for WorkflowExecutionInfo {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "execution",
+            "type",
+            "start_time",
+            "startTime",
+            "close_time",
+            "closeTime",
+            "status",
+            "history_length",
+            "historyLength",
+            "parent_namespace_id",
+            "parentNamespaceId",
+            "parent_execution",
+            "parentExecution",
+            "execution_time",
+            "executionTime",
+            "memo",
+            "search_attributes",
+            "searchAttributes",
+            "auto_reset_points",
+            "autoResetPoints",
+            "task_queue",
+            "taskQueue",
+            "state_transition_count",
+            "stateTransitionCount",
+            "history_size_bytes",
+            "historySizeBytes",
+            "most_recent_worker_version_stamp",
+            "mostRecentWorkerVersionStamp",
+            "execution_duration",
+            "executionDuration",
+            "root_execution",
+            "rootExecution",
+            "assigned_build_id",
+            "assignedBuildId",
+            "inherited_build_id",
+            "inheritedBuildId",
+            "first_run_id",
+            "firstRunId",
+            "versioning_info",
+            "versioningInfo",
+            "worker_deployment_name",
+            "workerDeploymentName",
+            "priority",
+            "external_payload_size_bytes",
+            "externalPayloadSizeBytes",
+            "external_payload_count",
+            "externalPayloadCount",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Execution,
+            Type,
+            StartTime,
+            CloseTime,
+            Status,
+            HistoryLength,
+            ParentNamespaceId,
+            ParentExecution,
+            ExecutionTime,
+            Memo,
+            SearchAttributes,
+            AutoResetPoints,
+            TaskQueue,
+            StateTransitionCount,
+            HistorySizeBytes,
+            MostRecentWorkerVersionStamp,
+            ExecutionDuration,
+            RootExecution,
+            AssignedBuildId,
+            InheritedBuildId,
+            FirstRunId,
+            VersioningInfo,
+            WorkerDeploymentName,
+            Priority,
+            ExternalPayloadSizeBytes,
+            ExternalPayloadCount,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "execution" => Ok(GeneratedField::Execution),
+                            "type" => Ok(GeneratedField::Type),
+                            "startTime" | "start_time" => Ok(GeneratedField::StartTime),
+                            "closeTime" | "close_time" => Ok(GeneratedField::CloseTime),
+                            "status" => Ok(GeneratedField::Status),
+                            "historyLength" | "history_length" => Ok(GeneratedField::HistoryLength),
+                            "parentNamespaceId" | "parent_namespace_id" => Ok(GeneratedField::ParentNamespaceId),
+                            "parentExecution" | "parent_execution" => Ok(GeneratedField::ParentExecution),
+                            "executionTime" | "execution_time" => Ok(GeneratedField::ExecutionTime),
+                            "memo" => Ok(GeneratedField::Memo),
+                            "searchAttributes" | "search_attributes" => Ok(GeneratedField::SearchAttributes),
+                            "autoResetPoints" | "auto_reset_points" => Ok(GeneratedField::AutoResetPoints),
+                            "taskQueue" | "task_queue" => Ok(GeneratedField::TaskQueue),
+                            "stateTransitionCount" | "state_transition_count" => Ok(GeneratedField::StateTransitionCount),
+                            "historySizeBytes" | "history_size_bytes" => Ok(GeneratedField::HistorySizeBytes),
+                            "mostRecentWorkerVersionStamp" | "most_recent_worker_version_stamp" => Ok(GeneratedField::MostRecentWorkerVersionStamp),
+                            "executionDuration" | "execution_duration" => Ok(GeneratedField::ExecutionDuration),
+                            "rootExecution" | "root_execution" => Ok(GeneratedField::RootExecution),
+                            "assignedBuildId" | "assigned_build_id" => Ok(GeneratedField::AssignedBuildId),
+                            "inheritedBuildId" | "inherited_build_id" => Ok(GeneratedField::InheritedBuildId),
+                            "firstRunId" | "first_run_id" => Ok(GeneratedField::FirstRunId),
+                            "versioningInfo" | "versioning_info" => Ok(GeneratedField::VersioningInfo),
+                            "workerDeploymentName" | "worker_deployment_name" => Ok(GeneratedField::WorkerDeploymentName),
+                            "priority" => Ok(GeneratedField::Priority),
+                            "externalPayloadSizeBytes" | "external_payload_size_bytes" => Ok(GeneratedField::ExternalPayloadSizeBytes),
+                            "externalPayloadCount" | "external_payload_count" => Ok(GeneratedField::ExternalPayloadCount),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = WorkflowExecutionInfo;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct temporal.api.workflow.v1.WorkflowExecutionInfo")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<WorkflowExecutionInfo, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut execution__ = None;
+                let mut r#type__ = None;
+                let mut start_time__ = None;
+                let mut close_time__ = None;
+                let mut status__ = None;
+                let mut history_length__ = None;
+                let mut parent_namespace_id__ = None;
+                let mut parent_execution__ = None;
+                let mut execution_time__ = None;
+                let mut memo__ = None;
+                let mut search_attributes__ = None;
+                let mut auto_reset_points__ = None;
+                let mut task_queue__ = None;
+                let mut state_transition_count__ = None;
+                let mut history_size_bytes__ = None;
+                let mut most_recent_worker_version_stamp__ = None;
+                let mut execution_duration__ = None;
+                let mut root_execution__ = None;
+                let mut assigned_build_id__ = None;
+                let mut inherited_build_id__ = None;
+                let mut first_run_id__ = None;
+                let mut versioning_info__ = None;
+                let mut worker_deployment_name__ = None;
+                let mut priority__ = None;
+                let mut external_payload_size_bytes__ = None;
+                let mut external_payload_count__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Execution => {
+                            if execution__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("execution"));
+                            }
+                            execution__ = map_.next_value()?;
+                        }
+                        GeneratedField::Type => {
+                            if r#type__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("type"));
+                            }
+                            r#type__ = map_.next_value()?;
+                        }
+                        GeneratedField::StartTime => {
+                            if start_time__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("startTime"));
+                            }
+                            start_time__ = map_.next_value()?;
+                        }
+                        GeneratedField::CloseTime => {
+                            if close_time__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("closeTime"));
+                            }
+                            close_time__ = map_.next_value()?;
+                        }
+                        GeneratedField::Status => {
+                            if status__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("status"));
+                            }
+                            status__ = Some(map_.next_value::<super::super::enums::v1::WorkflowExecutionStatus>()? as i32);
+                        }
+                        GeneratedField::HistoryLength => {
+                            if history_length__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("historyLength"));
+                            }
+                            history_length__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::ParentNamespaceId => {
+                            if parent_namespace_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("parentNamespaceId"));
+                            }
+                            parent_namespace_id__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::ParentExecution => {
+                            if parent_execution__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("parentExecution"));
+                            }
+                            parent_execution__ = map_.next_value()?;
+                        }
+                        GeneratedField::ExecutionTime => {
+                            if execution_time__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("executionTime"));
+                            }
+                            execution_time__ = map_.next_value()?;
+                        }
+                        GeneratedField::Memo => {
+                            if memo__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("memo"));
+                            }
+                            memo__ = map_.next_value()?;
+                        }
+                        GeneratedField::SearchAttributes => {
+                            if search_attributes__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("searchAttributes"));
+                            }
+                            search_attributes__ = map_.next_value()?;
+                        }
+                        GeneratedField::AutoResetPoints => {
+                            if auto_reset_points__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("autoResetPoints"));
+                            }
+                            auto_reset_points__ = map_.next_value()?;
+                        }
+                        GeneratedField::TaskQueue => {
+                            if task_queue__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("taskQueue"));
+                            }
+                            task_queue__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::StateTransitionCount => {
+                            if state_transition_count__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("stateTransitionCount"));
+                            }
+                            state_transition_count__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::HistorySizeBytes => {
+                            if history_size_bytes__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("historySizeBytes"));
+                            }
+                            history_size_bytes__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::MostRecentWorkerVersionStamp => {
+                            if most_recent_worker_version_stamp__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("mostRecentWorkerVersionStamp"));
+                            }
+                            most_recent_worker_version_stamp__ = map_.next_value()?;
+                        }
+                        GeneratedField::ExecutionDuration => {
+                            if execution_duration__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("executionDuration"));
+                            }
+                            execution_duration__ = map_.next_value()?;
+                        }
+                        GeneratedField::RootExecution => {
+                            if root_execution__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("rootExecution"));
+                            }
+                            root_execution__ = map_.next_value()?;
+                        }
+                        GeneratedField::AssignedBuildId => {
+                            if assigned_build_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("assignedBuildId"));
+                            }
+                            assigned_build_id__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::InheritedBuildId => {
+                            if inherited_build_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("inheritedBuildId"));
+                            }
+                            inherited_build_id__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::FirstRunId => {
+                            if first_run_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("firstRunId"));
+                            }
+                            first_run_id__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::VersioningInfo => {
+                            if versioning_info__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("versioningInfo"));
+                            }
+                            versioning_info__ = map_.next_value()?;
+                        }
+                        GeneratedField::WorkerDeploymentName => {
+                            if worker_deployment_name__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("workerDeploymentName"));
+                            }
+                            worker_deployment_name__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::Priority => {
+                            if priority__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("priority"));
+                            }
+                            priority__ = map_.next_value()?;
+                        }
+                        GeneratedField::ExternalPayloadSizeBytes => {
+                            if external_payload_size_bytes__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("externalPayloadSizeBytes"));
+                            }
+                            external_payload_size_bytes__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::ExternalPayloadCount => {
+                            if external_payload_count__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("externalPayloadCount"));
+                            }
+                            external_payload_count__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                    }
+                }
+                Ok(WorkflowExecutionInfo {
+                    execution: execution__,
+                    r#type: r#type__,
+                    start_time: start_time__,
+                    close_time: close_time__,
+                    status: status__.unwrap_or_default(),
+                    history_length: history_length__.unwrap_or_default(),
+                    parent_namespace_id: parent_namespace_id__.unwrap_or_default(),
+                    parent_execution: parent_execution__,
+                    execution_time: execution_time__,
+                    memo: memo__,
+                    search_attributes: search_attributes__,
+                    auto_reset_points: auto_reset_points__,
+                    task_queue: task_queue__.unwrap_or_default(),
+                    state_transition_count: state_transition_count__.unwrap_or_default(),
+                    history_size_bytes: history_size_bytes__.unwrap_or_default(),
+                    most_recent_worker_version_stamp: most_recent_worker_version_stamp__,
+                    execution_duration: execution_duration__,
+                    root_execution: root_execution__,
+                    assigned_build_id: assigned_build_id__.unwrap_or_default(),
+                    inherited_build_id: inherited_build_id__.unwrap_or_default(),
+                    first_run_id: first_run_id__.unwrap_or_default(),
+                    versioning_info: versioning_info__,
+                    worker_deployment_name: worker_deployment_name__.unwrap_or_default(),
+                    priority: priority__,
+                    external_payload_size_bytes: external_payload_size_bytes__.unwrap_or_default(),
+                    external_payload_count: external_payload_count__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("temporal.api.workflow.v1.WorkflowExecutionInfo", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for WorkflowExecutionOptions {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.versioning_override.is_some() {
+            len += 1;
+        }
+        if self.priority.is_some() {
+            len += 1;
+        }
+        if self.time_skipping_config.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("temporal.api.workflow.v1.WorkflowExecutionOptions", len)?;
+        if let Some(v) = self.versioning_override.as_ref() {
+            struct_ser.serialize_field("versioningOverride", v)?;
+        }
+        if let Some(v) = self.priority.as_ref() {
+            struct_ser.serialize_field("priority", v)?;
+        }
+        if let Some(v) = self.time_skipping_config.as_ref() {
+            struct_ser.serialize_field("timeSkippingConfig", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for WorkflowExecutionOptions {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "versioning_override",
+            "versioningOverride",
+            "priority",
+            "time_skipping_config",
+            "timeSkippingConfig",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            VersioningOverride,
+            Priority,
+            TimeSkippingConfig,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "versioningOverride" | "versioning_override" => Ok(GeneratedField::VersioningOverride),
+                            "priority" => Ok(GeneratedField::Priority),
+                            "timeSkippingConfig" | "time_skipping_config" => Ok(GeneratedField::TimeSkippingConfig),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = WorkflowExecutionOptions;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct temporal.api.workflow.v1.WorkflowExecutionOptions")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<WorkflowExecutionOptions, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut versioning_override__ = None;
+                let mut priority__ = None;
+                let mut time_skipping_config__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::VersioningOverride => {
+                            if versioning_override__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("versioningOverride"));
+                            }
+                            versioning_override__ = map_.next_value()?;
+                        }
+                        GeneratedField::Priority => {
+                            if priority__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("priority"));
+                            }
+                            priority__ = map_.next_value()?;
+                        }
+                        GeneratedField::TimeSkippingConfig => {
+                            if time_skipping_config__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("timeSkippingConfig"));
+                            }
+                            time_skipping_config__ = map_.next_value()?;
+                        }
+                    }
+                }
+                Ok(WorkflowExecutionOptions {
+                    versioning_override: versioning_override__,
+                    priority: priority__,
+                    time_skipping_config: time_skipping_config__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("temporal.api.workflow.v1.WorkflowExecutionOptions", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for WorkflowExecutionPauseInfo {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.identity.is_empty() {
+            len += 1;
+        }
+        if self.paused_time.is_some() {
+            len += 1;
+        }
+        if !self.reason.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("temporal.api.workflow.v1.WorkflowExecutionPauseInfo", len)?;
+        if !self.identity.is_empty() {
+            struct_ser.serialize_field("identity", &self.identity)?;
+        }
+        if let Some(v) = self.paused_time.as_ref() {
+            struct_ser.serialize_field("pausedTime", v)?;
+        }
+        if !self.reason.is_empty() {
+            struct_ser.serialize_field("reason", &self.reason)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for WorkflowExecutionPauseInfo {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "identity",
+            "paused_time",
+            "pausedTime",
+            "reason",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Identity,
+            PausedTime,
+            Reason,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "identity" => Ok(GeneratedField::Identity),
+                            "pausedTime" | "paused_time" => Ok(GeneratedField::PausedTime),
+                            "reason" => Ok(GeneratedField::Reason),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = WorkflowExecutionPauseInfo;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct temporal.api.workflow.v1.WorkflowExecutionPauseInfo")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<WorkflowExecutionPauseInfo, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut identity__ = None;
+                let mut paused_time__ = None;
+                let mut reason__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Identity => {
+                            if identity__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("identity"));
+                            }
+                            identity__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::PausedTime => {
+                            if paused_time__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("pausedTime"));
+                            }
+                            paused_time__ = map_.next_value()?;
+                        }
+                        GeneratedField::Reason => {
+                            if reason__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("reason"));
+                            }
+                            reason__ = Some(map_.next_value()?);
+                        }
+                    }
+                }
+                Ok(WorkflowExecutionPauseInfo {
+                    identity: identity__.unwrap_or_default(),
+                    paused_time: paused_time__,
+                    reason: reason__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("temporal.api.workflow.v1.WorkflowExecutionPauseInfo", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for WorkflowExecutionVersioningInfo {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.behavior != 0 {
+            len += 1;
+        }
+        if self.deployment.is_some() {
+            len += 1;
+        }
+        if !self.version.is_empty() {
+            len += 1;
+        }
+        if self.deployment_version.is_some() {
+            len += 1;
+        }
+        if self.versioning_override.is_some() {
+            len += 1;
+        }
+        if self.deployment_transition.is_some() {
+            len += 1;
+        }
+        if self.version_transition.is_some() {
+            len += 1;
+        }
+        if self.revision_number != 0 {
+            len += 1;
+        }
+        if self.continue_as_new_initial_versioning_behavior != 0 {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("temporal.api.workflow.v1.WorkflowExecutionVersioningInfo", len)?;
+        if self.behavior != 0 {
+            let v = super::super::enums::v1::VersioningBehavior::try_from(self.behavior)
+                .map_err(|_| serde::ser::Error::custom(format!("Invalid variant {}", self.behavior)))?;
+            struct_ser.serialize_field("behavior", &v)?;
+        }
+        if let Some(v) = self.deployment.as_ref() {
+            struct_ser.serialize_field("deployment", v)?;
+        }
+        if !self.version.is_empty() {
+            struct_ser.serialize_field("version", &self.version)?;
+        }
+        if let Some(v) = self.deployment_version.as_ref() {
+            struct_ser.serialize_field("deploymentVersion", v)?;
+        }
+        if let Some(v) = self.versioning_override.as_ref() {
+            struct_ser.serialize_field("versioningOverride", v)?;
+        }
+        if let Some(v) = self.deployment_transition.as_ref() {
+            struct_ser.serialize_field("deploymentTransition", v)?;
+        }
+        if let Some(v) = self.version_transition.as_ref() {
+            struct_ser.serialize_field("versionTransition", v)?;
+        }
+        if self.revision_number != 0 {
+            #[allow(clippy::needless_borrow)]
+            #[allow(clippy::needless_borrows_for_generic_args)]
+            struct_ser.serialize_field("revisionNumber", ToString::to_string(&self.revision_number).as_str())?;
+        }
+        if self.continue_as_new_initial_versioning_behavior != 0 {
+            let v = super::super::enums::v1::ContinueAsNewVersioningBehavior::try_from(self.continue_as_new_initial_versioning_behavior)
+                .map_err(|_| serde::ser::Error::custom(format!("Invalid variant {}", self.continue_as_new_initial_versioning_behavior)))?;
+            struct_ser.serialize_field("continueAsNewInitialVersioningBehavior", &v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for WorkflowExecutionVersioningInfo {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "behavior",
+            "deployment",
+            "version",
+            "deployment_version",
+            "deploymentVersion",
+            "versioning_override",
+            "versioningOverride",
+            "deployment_transition",
+            "deploymentTransition",
+            "version_transition",
+            "versionTransition",
+            "revision_number",
+            "revisionNumber",
+            "continue_as_new_initial_versioning_behavior",
+            "continueAsNewInitialVersioningBehavior",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Behavior,
+            Deployment,
+            Version,
+            DeploymentVersion,
+            VersioningOverride,
+            DeploymentTransition,
+            VersionTransition,
+            RevisionNumber,
+            ContinueAsNewInitialVersioningBehavior,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "behavior" => Ok(GeneratedField::Behavior),
+                            "deployment" => Ok(GeneratedField::Deployment),
+                            "version" => Ok(GeneratedField::Version),
+                            "deploymentVersion" | "deployment_version" => Ok(GeneratedField::DeploymentVersion),
+                            "versioningOverride" | "versioning_override" => Ok(GeneratedField::VersioningOverride),
+                            "deploymentTransition" | "deployment_transition" => Ok(GeneratedField::DeploymentTransition),
+                            "versionTransition" | "version_transition" => Ok(GeneratedField::VersionTransition),
+                            "revisionNumber" | "revision_number" => Ok(GeneratedField::RevisionNumber),
+                            "continueAsNewInitialVersioningBehavior" | "continue_as_new_initial_versioning_behavior" => Ok(GeneratedField::ContinueAsNewInitialVersioningBehavior),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = WorkflowExecutionVersioningInfo;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct temporal.api.workflow.v1.WorkflowExecutionVersioningInfo")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<WorkflowExecutionVersioningInfo, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut behavior__ = None;
+                let mut deployment__ = None;
+                let mut version__ = None;
+                let mut deployment_version__ = None;
+                let mut versioning_override__ = None;
+                let mut deployment_transition__ = None;
+                let mut version_transition__ = None;
+                let mut revision_number__ = None;
+                let mut continue_as_new_initial_versioning_behavior__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Behavior => {
+                            if behavior__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("behavior"));
+                            }
+                            behavior__ = Some(map_.next_value::<super::super::enums::v1::VersioningBehavior>()? as i32);
+                        }
+                        GeneratedField::Deployment => {
+                            if deployment__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("deployment"));
+                            }
+                            deployment__ = map_.next_value()?;
+                        }
+                        GeneratedField::Version => {
+                            if version__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("version"));
+                            }
+                            version__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::DeploymentVersion => {
+                            if deployment_version__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("deploymentVersion"));
+                            }
+                            deployment_version__ = map_.next_value()?;
+                        }
+                        GeneratedField::VersioningOverride => {
+                            if versioning_override__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("versioningOverride"));
+                            }
+                            versioning_override__ = map_.next_value()?;
+                        }
+                        GeneratedField::DeploymentTransition => {
+                            if deployment_transition__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("deploymentTransition"));
+                            }
+                            deployment_transition__ = map_.next_value()?;
+                        }
+                        GeneratedField::VersionTransition => {
+                            if version_transition__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("versionTransition"));
+                            }
+                            version_transition__ = map_.next_value()?;
+                        }
+                        GeneratedField::RevisionNumber => {
+                            if revision_number__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("revisionNumber"));
+                            }
+                            revision_number__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::ContinueAsNewInitialVersioningBehavior => {
+                            if continue_as_new_initial_versioning_behavior__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("continueAsNewInitialVersioningBehavior"));
+                            }
+                            continue_as_new_initial_versioning_behavior__ = Some(map_.next_value::<super::super::enums::v1::ContinueAsNewVersioningBehavior>()? as i32);
+                        }
+                    }
+                }
+                Ok(WorkflowExecutionVersioningInfo {
+                    behavior: behavior__.unwrap_or_default(),
+                    deployment: deployment__,
+                    version: version__.unwrap_or_default(),
+                    deployment_version: deployment_version__,
+                    versioning_override: versioning_override__,
+                    deployment_transition: deployment_transition__,
+                    version_transition: version_transition__,
+                    revision_number: revision_number__.unwrap_or_default(),
+                    continue_as_new_initial_versioning_behavior: continue_as_new_initial_versioning_behavior__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("temporal.api.workflow.v1.WorkflowExecutionVersioningInfo", FIELDS, GeneratedVisitor)
+    }
+}
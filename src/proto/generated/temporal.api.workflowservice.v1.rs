@@ -9,7 +9,7 @@ pub struct RegisterNamespaceRequest {
     pub owner_email: ::prost::alloc::string::String,
     #[prost(message, optional, tag = "4")]
     pub workflow_execution_retention_period: ::core::option::Option<
-        ::prost_types::Duration,
+        ::pbjson_types::Duration,
     >,
     #[prost(message, repeated, tag = "5")]
     pub clusters: ::prost::alloc::vec::Vec<
@@ -64,6 +64,12 @@ pub struct DescribeNamespaceRequest {
     pub namespace: ::prost::alloc::string::String,
     #[prost(string, tag = "2")]
     pub id: ::prost::alloc::string::String,
+    /// If true, the server may serve the response from an eventually-consistent
+    /// source instead of reading through to persistence. Defaults to false,
+    /// which preserves read-after-write consistency. SDKs should set this when
+    /// fetching namespace capabilities on worker/client startup.
+    #[prost(bool, tag = "3")]
+    pub weak_consistency: bool,
 }
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct DescribeNamespaceResponse {
@@ -87,6 +93,13 @@ pub struct DescribeNamespaceResponse {
     pub failover_history: ::prost::alloc::vec::Vec<
         super::super::replication::v1::FailoverStatus,
     >,
+    /// The initial info that client should use for poller group assignment. This information is
+    /// updated through poll response. Client is supposed to use the info received in the latest
+    /// poll response.
+    #[prost(message, repeated, tag = "7")]
+    pub poller_group_infos: ::prost::alloc::vec::Vec<
+        super::super::taskqueue::v1::PollerGroupInfo,
+    >,
 }
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct UpdateNamespaceRequest {
@@ -153,13 +166,13 @@ pub struct StartWorkflowExecutionRequest {
     pub input: ::core::option::Option<super::super::common::v1::Payloads>,
     /// Total workflow execution timeout including retries and continue as new.
     #[prost(message, optional, tag = "6")]
-    pub workflow_execution_timeout: ::core::option::Option<::prost_types::Duration>,
+    pub workflow_execution_timeout: ::core::option::Option<::pbjson_types::Duration>,
     /// Timeout of a single workflow run.
     #[prost(message, optional, tag = "7")]
-    pub workflow_run_timeout: ::core::option::Option<::prost_types::Duration>,
+    pub workflow_run_timeout: ::core::option::Option<::pbjson_types::Duration>,
     /// Timeout of a single workflow task.
     #[prost(message, optional, tag = "8")]
-    pub workflow_task_timeout: ::core::option::Option<::prost_types::Duration>,
+    pub workflow_task_timeout: ::core::option::Option<::pbjson_types::Duration>,
     /// The identity of the client who initiated this request
     #[prost(string, tag = "9")]
     pub identity: ::prost::alloc::string::String,
@@ -215,7 +228,7 @@ pub struct StartWorkflowExecutionRequest {
     /// If the workflow gets a signal before the delay, a workflow task will be dispatched and the rest
     /// of the delay will be ignored.
     #[prost(message, optional, tag = "20")]
-    pub workflow_start_delay: ::core::option::Option<::prost_types::Duration>,
+    pub workflow_start_delay: ::core::option::Option<::pbjson_types::Duration>,
     /// Callbacks to be called by the server when this workflow reaches a terminal state.
     /// If the workflow continues-as-new, these callbacks will be carried over to the new execution.
     /// Callback addresses must be whitelisted in the server's dynamic configuration.
@@ -253,6 +266,11 @@ pub struct StartWorkflowExecutionRequest {
     pub eager_worker_deployment_options: ::core::option::Option<
         super::super::deployment::v1::WorkerDeploymentOptions,
     >,
+    /// Time-skipping configuration. If not set, time skipping is disabled.
+    #[prost(message, optional, tag = "29")]
+    pub time_skipping_config: ::core::option::Option<
+        super::super::workflow::v1::TimeSkippingConfig,
+    >,
 }
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct StartWorkflowExecutionResponse {
@@ -337,13 +355,23 @@ pub struct PollWorkflowTaskQueueRequest {
     pub namespace: ::prost::alloc::string::String,
     #[prost(message, optional, tag = "2")]
     pub task_queue: ::core::option::Option<super::super::taskqueue::v1::TaskQueue>,
+    /// Unless this is the first poll, the client must pass one of the poller group IDs received in
+    /// `poller_group_infos` of the last the PollWorkflowTaskQueueResponse according to the
+    /// instructions. If not set, the poll is routed randomly which can cause it to be blocked
+    /// without receiving a task while the queue actually has tasks in another server location.
+    #[prost(string, tag = "10")]
+    pub poller_group_id: ::prost::alloc::string::String,
     /// The identity of the worker/client who is polling this task queue
     #[prost(string, tag = "3")]
     pub identity: ::prost::alloc::string::String,
     /// A unique key for this worker instance, used for tracking worker lifecycle.
     /// This is guaranteed to be unique, whereas identity is not guaranteed to be unique.
-    #[prost(string, tag = "7")]
+    #[prost(string, tag = "8")]
     pub worker_instance_key: ::prost::alloc::string::String,
+    /// A dedicated per-worker Nexus task queue on which the server sends control
+    /// tasks (e.g. activity cancellation) to this specific worker instance.
+    #[prost(string, tag = "9")]
+    pub worker_control_task_queue: ::prost::alloc::string::String,
     /// Deprecated. Use deployment_options instead.
     /// Each worker process should provide an ID unique to the specific set of code it is running
     /// "checksum" in this field name isn't very accurate, it should be though of as an id.
@@ -359,7 +387,6 @@ pub struct PollWorkflowTaskQueueRequest {
         super::super::common::v1::WorkerVersionCapabilities,
     >,
     /// Worker deployment options that user has set in the worker.
-    /// Experimental. Worker Deployments are experimental and might significantly change in the future.
     #[prost(message, optional, tag = "6")]
     pub deployment_options: ::core::option::Option<
         super::super::deployment::v1::WorkerDeploymentOptions,
@@ -424,10 +451,10 @@ pub struct PollWorkflowTaskQueueResponse {
     >,
     /// When this task was scheduled by the server
     #[prost(message, optional, tag = "12")]
-    pub scheduled_time: ::core::option::Option<::prost_types::Timestamp>,
+    pub scheduled_time: ::core::option::Option<::pbjson_types::Timestamp>,
     /// When the current workflow task started event was generated, meaning the current attempt.
     #[prost(message, optional, tag = "13")]
-    pub started_time: ::core::option::Option<::prost_types::Timestamp>,
+    pub started_time: ::core::option::Option<::pbjson_types::Timestamp>,
     /// Queries that should be executed after applying the history in this task. Responses should be
     /// attached to `RespondWorkflowTaskCompletedRequest::query_results`
     #[prost(map = "string, message", tag = "14")]
@@ -443,6 +470,20 @@ pub struct PollWorkflowTaskQueueResponse {
     pub poller_scaling_decision: ::core::option::Option<
         super::super::taskqueue::v1::PollerScalingDecision,
     >,
+    /// This poller group ID identifies the owner of the workflow task awaiting for query response.
+    /// Corresponding RespondQueryTaskCompleted should pass this value for proper routing.
+    #[prost(string, tag = "17")]
+    pub poller_group_id: ::prost::alloc::string::String,
+    /// The weighted list of poller groups IDs that client should use for future polls to this task
+    /// queue. Client is expected to:
+    ///    1. Maintain minimum number of pollers no less than the number of groups.
+    ///    2. Try to assign the next poll to a group without any pending polls,
+    ///    3. If every group has some pending polls, assign the next poll to a group randomly
+    ///      according to the weights.
+    #[prost(message, repeated, tag = "18")]
+    pub poller_group_infos: ::prost::alloc::vec::Vec<
+        super::super::taskqueue::v1::PollerGroupInfo,
+    >,
 }
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct RespondWorkflowTaskCompletedRequest {
@@ -484,6 +525,9 @@ pub struct RespondWorkflowTaskCompletedRequest {
     >,
     #[prost(string, tag = "9")]
     pub namespace: ::prost::alloc::string::String,
+    /// Resource ID for routing. Contains the workflow ID from the original task.
+    #[prost(string, tag = "18")]
+    pub resource_id: ::prost::alloc::string::String,
     /// Version info of the worker who processed this task. This message's `build_id` field should
     /// always be set by SDKs. Workers opting into versioning will also set the `use_versioning`
     /// field to true. See message docstrings for more.
@@ -527,6 +571,14 @@ pub struct RespondWorkflowTaskCompletedRequest {
     pub deployment_options: ::core::option::Option<
         super::super::deployment::v1::WorkerDeploymentOptions,
     >,
+    /// A unique key for this worker instance, used for tracking worker lifecycle.
+    /// This is guaranteed to be unique, whereas identity is not guaranteed to be unique.
+    #[prost(string, tag = "19")]
+    pub worker_instance_key: ::prost::alloc::string::String,
+    /// A dedicated per-worker Nexus task queue on which the server sends control
+    /// tasks (e.g. activity cancellation) to this specific worker instance.
+    #[prost(string, tag = "20")]
+    pub worker_control_task_queue: ::prost::alloc::string::String,
 }
 /// Nested message and enum types in `RespondWorkflowTaskCompletedRequest`.
 pub mod respond_workflow_task_completed_request {
@@ -579,6 +631,9 @@ pub struct RespondWorkflowTaskFailedRequest {
     pub binary_checksum: ::prost::alloc::string::String,
     #[prost(string, tag = "6")]
     pub namespace: ::prost::alloc::string::String,
+    /// Resource ID for routing. Contains the workflow ID from the original task.
+    #[prost(string, tag = "11")]
+    pub resource_id: ::prost::alloc::string::String,
     /// Protocol messages piggybacking on a WFT as a transport
     #[prost(message, repeated, tag = "7")]
     pub messages: ::prost::alloc::vec::Vec<super::super::protocol::v1::Message>,
@@ -611,13 +666,23 @@ pub struct PollActivityTaskQueueRequest {
     pub namespace: ::prost::alloc::string::String,
     #[prost(message, optional, tag = "2")]
     pub task_queue: ::core::option::Option<super::super::taskqueue::v1::TaskQueue>,
+    /// Unless this is the first poll, the client must pass one of the poller group IDs received in
+    /// `poller_group_infos` of the last the PollActivityTaskQueueResponse according to the
+    /// instructions. If not set, the poll is routed randomly which can cause it to be blocked
+    /// without receiving a task while the queue actually has tasks in another server location.
+    #[prost(string, tag = "10")]
+    pub poller_group_id: ::prost::alloc::string::String,
     /// The identity of the worker/client
     #[prost(string, tag = "3")]
     pub identity: ::prost::alloc::string::String,
     /// A unique key for this worker instance, used for tracking worker lifecycle.
     /// This is guaranteed to be unique, whereas identity is not guaranteed to be unique.
-    #[prost(string, tag = "7")]
+    #[prost(string, tag = "8")]
     pub worker_instance_key: ::prost::alloc::string::String,
+    /// A dedicated per-worker Nexus task queue on which the server sends control
+    /// tasks (e.g. activity cancellation) to this specific worker instance.
+    #[prost(string, tag = "9")]
+    pub worker_control_task_queue: ::prost::alloc::string::String,
     #[prost(message, optional, tag = "4")]
     pub task_queue_metadata: ::core::option::Option<
         super::super::taskqueue::v1::TaskQueueMetadata,
@@ -675,13 +740,15 @@ pub struct PollActivityTaskQueueResponse {
     pub heartbeat_details: ::core::option::Option<super::super::common::v1::Payloads>,
     /// When was this task first scheduled
     #[prost(message, optional, tag = "10")]
-    pub scheduled_time: ::core::option::Option<::prost_types::Timestamp>,
+    pub scheduled_time: ::core::option::Option<::pbjson_types::Timestamp>,
     /// When was this task attempt scheduled
     #[prost(message, optional, tag = "11")]
-    pub current_attempt_scheduled_time: ::core::option::Option<::prost_types::Timestamp>,
+    pub current_attempt_scheduled_time: ::core::option::Option<
+        ::pbjson_types::Timestamp,
+    >,
     /// When was this task started (this attempt)
     #[prost(message, optional, tag = "12")]
-    pub started_time: ::core::option::Option<::prost_types::Timestamp>,
+    pub started_time: ::core::option::Option<::pbjson_types::Timestamp>,
     /// Starting at 1, the number of attempts to perform this activity
     #[prost(int32, tag = "13")]
     pub attempt: i32,
@@ -690,16 +757,16 @@ pub struct PollActivityTaskQueueResponse {
     /// (-- api-linter: core::0140::prepositions=disabled
     ///      aip.dev/not-precedent: "to" is used to indicate interval. --)
     #[prost(message, optional, tag = "14")]
-    pub schedule_to_close_timeout: ::core::option::Option<::prost_types::Duration>,
+    pub schedule_to_close_timeout: ::core::option::Option<::pbjson_types::Duration>,
     /// Current attempt start -> final result reported timeout
     ///
     /// (-- api-linter: core::0140::prepositions=disabled
     ///      aip.dev/not-precedent: "to" is used to indicate interval. --)
     #[prost(message, optional, tag = "15")]
-    pub start_to_close_timeout: ::core::option::Option<::prost_types::Duration>,
+    pub start_to_close_timeout: ::core::option::Option<::pbjson_types::Duration>,
     /// Window within which the activity must report a heartbeat, or be timed out.
     #[prost(message, optional, tag = "16")]
-    pub heartbeat_timeout: ::core::option::Option<::prost_types::Duration>,
+    pub heartbeat_timeout: ::core::option::Option<::pbjson_types::Duration>,
     /// This is the retry policy the service uses which may be different from the one provided
     /// (or not) during activity scheduling. The service can override the provided one if some
     /// values are not specified or exceed configured system limits.
@@ -716,6 +783,16 @@ pub struct PollActivityTaskQueueResponse {
     /// The run ID of the activity execution, only set for standalone activities.
     #[prost(string, tag = "20")]
     pub activity_run_id: ::prost::alloc::string::String,
+    /// The weighted list of poller groups IDs that client should use for future polls to this task
+    /// queue. Client is expected to:
+    ///    1. Maintain minimum number of pollers no less than the number of groups.
+    ///    2. Try to assign the next poll to a group without any pending polls,
+    ///    3. If every group has some pending polls, assign the next poll to a group randomly
+    ///      according to the weights.
+    #[prost(message, repeated, tag = "21")]
+    pub poller_group_infos: ::prost::alloc::vec::Vec<
+        super::super::taskqueue::v1::PollerGroupInfo,
+    >,
 }
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct RecordActivityTaskHeartbeatRequest {
@@ -730,6 +807,9 @@ pub struct RecordActivityTaskHeartbeatRequest {
     pub identity: ::prost::alloc::string::String,
     #[prost(string, tag = "4")]
     pub namespace: ::prost::alloc::string::String,
+    /// Resource ID for routing. Contains the workflow ID or activity ID for standalone activities.
+    #[prost(string, tag = "5")]
+    pub resource_id: ::prost::alloc::string::String,
 }
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct RecordActivityTaskHeartbeatResponse {
@@ -766,6 +846,9 @@ pub struct RecordActivityTaskHeartbeatByIdRequest {
     /// The identity of the worker/client
     #[prost(string, tag = "6")]
     pub identity: ::prost::alloc::string::String,
+    /// Resource ID for routing. Contains "workflow:workflow_id" or "activity:activity_id" for standalone activities.
+    #[prost(string, tag = "7")]
+    pub resource_id: ::prost::alloc::string::String,
 }
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct RecordActivityTaskHeartbeatByIdResponse {
@@ -794,6 +877,9 @@ pub struct RespondActivityTaskCompletedRequest {
     pub identity: ::prost::alloc::string::String,
     #[prost(string, tag = "4")]
     pub namespace: ::prost::alloc::string::String,
+    /// Resource ID for routing. Contains the workflow ID or activity ID for standalone activities.
+    #[prost(string, tag = "8")]
+    pub resource_id: ::prost::alloc::string::String,
     /// Version info of the worker who processed this task. This message's `build_id` field should
     /// always be set by SDKs. Workers opting into versioning will also set the `use_versioning`
     /// field to true. See message docstrings for more.
@@ -838,6 +924,9 @@ pub struct RespondActivityTaskCompletedByIdRequest {
     /// The identity of the worker/client
     #[prost(string, tag = "6")]
     pub identity: ::prost::alloc::string::String,
+    /// Resource ID for routing. Contains "workflow:workflow_id" or "activity:activity_id" for standalone activities.
+    #[prost(string, tag = "7")]
+    pub resource_id: ::prost::alloc::string::String,
 }
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct RespondActivityTaskCompletedByIdResponse {}
@@ -854,6 +943,9 @@ pub struct RespondActivityTaskFailedRequest {
     pub identity: ::prost::alloc::string::String,
     #[prost(string, tag = "4")]
     pub namespace: ::prost::alloc::string::String,
+    /// Resource ID for routing. Contains the workflow ID or activity ID for standalone activities.
+    #[prost(string, tag = "9")]
+    pub resource_id: ::prost::alloc::string::String,
     /// Additional details to be stored as last activity heartbeat
     #[prost(message, optional, tag = "5")]
     pub last_heartbeat_details: ::core::option::Option<
@@ -913,6 +1005,9 @@ pub struct RespondActivityTaskFailedByIdRequest {
     pub last_heartbeat_details: ::core::option::Option<
         super::super::common::v1::Payloads,
     >,
+    /// Resource ID for routing. Contains "workflow:workflow_id" or "activity:activity_id" for standalone activities.
+    #[prost(string, tag = "8")]
+    pub resource_id: ::prost::alloc::string::String,
 }
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct RespondActivityTaskFailedByIdResponse {
@@ -934,6 +1029,9 @@ pub struct RespondActivityTaskCanceledRequest {
     pub identity: ::prost::alloc::string::String,
     #[prost(string, tag = "4")]
     pub namespace: ::prost::alloc::string::String,
+    /// Resource ID for routing. Contains the workflow ID or activity ID for standalone activities.
+    #[prost(string, tag = "8")]
+    pub resource_id: ::prost::alloc::string::String,
     /// Version info of the worker who processed this task. This message's `build_id` field should
     /// always be set by SDKs. Workers opting into versioning will also set the `use_versioning`
     /// field to true. See message docstrings for more.
@@ -983,6 +1081,9 @@ pub struct RespondActivityTaskCanceledByIdRequest {
     pub deployment_options: ::core::option::Option<
         super::super::deployment::v1::WorkerDeploymentOptions,
     >,
+    /// Resource ID for routing. Contains "workflow:workflow_id" or "activity:activity_id" for standalone activities.
+    #[prost(string, tag = "8")]
+    pub resource_id: ::prost::alloc::string::String,
 }
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct RespondActivityTaskCanceledByIdResponse {}
@@ -1049,8 +1150,14 @@ pub struct SignalWorkflowExecutionRequest {
     #[prost(message, repeated, tag = "10")]
     pub links: ::prost::alloc::vec::Vec<super::super::common::v1::Link>,
 }
-#[derive(Clone, Copy, PartialEq, ::prost::Message)]
-pub struct SignalWorkflowExecutionResponse {}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SignalWorkflowExecutionResponse {
+    /// Link to be associated with the WorkflowExecutionSignaled event.
+    /// Added on the response to propagate the backlink.
+    /// Available from Temporal server 1.31 and up.
+    #[prost(message, optional, tag = "1")]
+    pub link: ::core::option::Option<super::super::common::v1::Link>,
+}
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct SignalWithStartWorkflowExecutionRequest {
     #[prost(string, tag = "1")]
@@ -1067,13 +1174,13 @@ pub struct SignalWithStartWorkflowExecutionRequest {
     pub input: ::core::option::Option<super::super::common::v1::Payloads>,
     /// Total workflow execution timeout including retries and continue as new
     #[prost(message, optional, tag = "6")]
-    pub workflow_execution_timeout: ::core::option::Option<::prost_types::Duration>,
+    pub workflow_execution_timeout: ::core::option::Option<::pbjson_types::Duration>,
     /// Timeout of a single workflow run
     #[prost(message, optional, tag = "7")]
-    pub workflow_run_timeout: ::core::option::Option<::prost_types::Duration>,
+    pub workflow_run_timeout: ::core::option::Option<::pbjson_types::Duration>,
     /// Timeout of a single workflow task
     #[prost(message, optional, tag = "8")]
-    pub workflow_task_timeout: ::core::option::Option<::prost_types::Duration>,
+    pub workflow_task_timeout: ::core::option::Option<::pbjson_types::Duration>,
     /// The identity of the worker/client
     #[prost(string, tag = "9")]
     pub identity: ::prost::alloc::string::String,
@@ -1126,7 +1233,7 @@ pub struct SignalWithStartWorkflowExecutionRequest {
     /// and the rest of the delay period will be ignored, even if that request also had a delay.
     /// Signal via SignalWorkflowExecution will not unblock the workflow.
     #[prost(message, optional, tag = "20")]
-    pub workflow_start_delay: ::core::option::Option<::prost_types::Duration>,
+    pub workflow_start_delay: ::core::option::Option<::pbjson_types::Duration>,
     /// Metadata on the workflow if it is started. This is carried over to the WorkflowExecutionInfo
     /// for use by user interfaces to display the fixed as-of-start summary and details of the
     /// workflow.
@@ -1144,6 +1251,11 @@ pub struct SignalWithStartWorkflowExecutionRequest {
     /// Priority metadata
     #[prost(message, optional, tag = "26")]
     pub priority: ::core::option::Option<super::super::common::v1::Priority>,
+    /// Time-skipping configuration. If not set, time skipping is disabled.
+    #[prost(message, optional, tag = "27")]
+    pub time_skipping_config: ::core::option::Option<
+        super::super::workflow::v1::TimeSkippingConfig,
+    >,
 }
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct SignalWithStartWorkflowExecutionResponse {
@@ -1153,6 +1265,11 @@ pub struct SignalWithStartWorkflowExecutionResponse {
     /// If true, a new workflow was started.
     #[prost(bool, tag = "2")]
     pub started: bool,
+    /// Link to be associated with the WorkflowExecutionSignaled event.
+    /// Added on the response to propagate the backlink.
+    /// Available from Temporal server 1.31 and up.
+    #[prost(message, optional, tag = "3")]
+    pub signal_link: ::core::option::Option<super::super::common::v1::Link>,
 }
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct ResetWorkflowExecutionRequest {
@@ -1454,6 +1571,10 @@ pub struct RespondQueryTaskCompletedRequest {
     /// apply to worker responses. See the type's doc for more.
     #[prost(enumeration = "super::super::enums::v1::WorkflowTaskFailedCause", tag = "8")]
     pub cause: i32,
+    /// Client must forward the poller_group_id received in PollWorkflowTaskQueueResponse for proper
+    /// routing of the response.
+    #[prost(string, tag = "9")]
+    pub poller_group_id: ::prost::alloc::string::String,
 }
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct RespondQueryTaskCompletedResponse {}
@@ -1782,6 +1903,11 @@ pub mod get_system_info_response {
         /// This flag is dependent both on server version and for Nexus to be enabled via server configuration.
         #[prost(bool, tag = "11")]
         pub nexus: bool,
+        /// True if the server supports server-scaled deployments.
+        /// This flag is dependent both on server version and for server-scaled deployments
+        /// to be enabled via server configuration.
+        #[prost(bool, tag = "12")]
+        pub server_scaled_deployments: bool,
     }
 }
 #[derive(Clone, PartialEq, ::prost::Message)]
@@ -1906,6 +2032,11 @@ pub struct UpdateScheduleRequest {
     pub search_attributes: ::core::option::Option<
         super::super::common::v1::SearchAttributes,
     >,
+    /// Schedule memo to replace. If set, replaces the entire memo.
+    /// Do not set this field if you do not want to update the memo.
+    /// A non-null empty object will clear the memo.
+    #[prost(message, optional, tag = "8")]
+    pub memo: ::core::option::Option<super::super::common::v1::Memo>,
 }
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct UpdateScheduleResponse {}
@@ -1938,14 +2069,14 @@ pub struct ListScheduleMatchingTimesRequest {
     pub schedule_id: ::prost::alloc::string::String,
     /// Time range to query.
     #[prost(message, optional, tag = "3")]
-    pub start_time: ::core::option::Option<::prost_types::Timestamp>,
+    pub start_time: ::core::option::Option<::pbjson_types::Timestamp>,
     #[prost(message, optional, tag = "4")]
-    pub end_time: ::core::option::Option<::prost_types::Timestamp>,
+    pub end_time: ::core::option::Option<::pbjson_types::Timestamp>,
 }
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct ListScheduleMatchingTimesResponse {
     #[prost(message, repeated, tag = "1")]
-    pub start_time: ::prost::alloc::vec::Vec<::prost_types::Timestamp>,
+    pub start_time: ::prost::alloc::vec::Vec<::pbjson_types::Timestamp>,
 }
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct DeleteScheduleRequest {
@@ -2404,6 +2535,9 @@ pub struct UpdateWorkflowExecutionResponse {
         tag = "3"
     )]
     pub stage: i32,
+    /// Link to the update event. May be null if the update has not yet been accepted.
+    #[prost(message, optional, tag = "4")]
+    pub link: ::core::option::Option<super::super::common::v1::Link>,
 }
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct StartBatchOperationRequest {
@@ -2517,10 +2651,10 @@ pub struct DescribeBatchOperationResponse {
     pub state: i32,
     /// Batch operation start time
     #[prost(message, optional, tag = "4")]
-    pub start_time: ::core::option::Option<::prost_types::Timestamp>,
+    pub start_time: ::core::option::Option<::pbjson_types::Timestamp>,
     /// Batch operation close time
     #[prost(message, optional, tag = "5")]
-    pub close_time: ::core::option::Option<::prost_types::Timestamp>,
+    pub close_time: ::core::option::Option<::pbjson_types::Timestamp>,
     /// Total operation count
     #[prost(int64, tag = "6")]
     pub total_operation_count: i64,
@@ -2608,6 +2742,14 @@ pub struct PollWorkflowExecutionUpdateResponse {
 pub struct PollNexusTaskQueueRequest {
     #[prost(string, tag = "1")]
     pub namespace: ::prost::alloc::string::String,
+    #[prost(message, optional, tag = "3")]
+    pub task_queue: ::core::option::Option<super::super::taskqueue::v1::TaskQueue>,
+    /// Unless this is the first poll, the client must pass one of the poller group IDs received in
+    /// `poller_group_infos` of the last the PollNexusTaskQueueResponse according to the
+    /// instructions. If not set, the poll is routed randomly which can cause it to be blocked
+    /// without receiving a task while the queue actually has tasks in another server location.
+    #[prost(string, tag = "9")]
+    pub poller_group_id: ::prost::alloc::string::String,
     /// The identity of the client who initiated this request.
     #[prost(string, tag = "2")]
     pub identity: ::prost::alloc::string::String,
@@ -2615,8 +2757,6 @@ pub struct PollNexusTaskQueueRequest {
     /// This is guaranteed to be unique, whereas identity is not guaranteed to be unique.
     #[prost(string, tag = "8")]
     pub worker_instance_key: ::prost::alloc::string::String,
-    #[prost(message, optional, tag = "3")]
-    pub task_queue: ::core::option::Option<super::super::taskqueue::v1::TaskQueue>,
     /// Information about this worker's build identifier and if it is choosing to use the versioning
     /// feature. See the `WorkerVersionCapabilities` docstring for more.
     /// Deprecated. Replaced by deployment_options.
@@ -2649,6 +2789,22 @@ pub struct PollNexusTaskQueueResponse {
     pub poller_scaling_decision: ::core::option::Option<
         super::super::taskqueue::v1::PollerScalingDecision,
     >,
+    /// This poller group ID identifies the owner of the nexus task awaiting for synchronous
+    /// response.
+    /// Corresponding `RespondNexusTaskCompleted` and `RespondNexusTaskFailed` calls should pass this
+    /// value for proper response routing.
+    #[prost(string, tag = "4")]
+    pub poller_group_id: ::prost::alloc::string::String,
+    /// The weighted list of poller groups IDs that client should use for future polls to this task
+    /// queue. Client is expected to:
+    ///    1. Maintain minimum number of pollers no less than the number of groups.
+    ///    2. Try to assign the next poll to a group without any pending polls,
+    ///    3. If every group has some pending polls, assign the next poll to a group randomly
+    ///      according to the weights.
+    #[prost(message, repeated, tag = "5")]
+    pub poller_group_infos: ::prost::alloc::vec::Vec<
+        super::super::taskqueue::v1::PollerGroupInfo,
+    >,
 }
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct RespondNexusTaskCompletedRequest {
@@ -2663,6 +2819,10 @@ pub struct RespondNexusTaskCompletedRequest {
     /// Embedded response to be translated into a frontend response.
     #[prost(message, optional, tag = "4")]
     pub response: ::core::option::Option<super::super::nexus::v1::Response>,
+    /// Client must forward the poller_group_id received in PollNexusTaskQueueResponse for proper
+    /// routing of the response.
+    #[prost(string, tag = "5")]
+    pub poller_group_id: ::prost::alloc::string::String,
 }
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct RespondNexusTaskCompletedResponse {}
@@ -2676,9 +2836,17 @@ pub struct RespondNexusTaskFailedRequest {
     /// A unique identifier for this task.
     #[prost(bytes = "vec", tag = "3")]
     pub task_token: ::prost::alloc::vec::Vec<u8>,
-    /// The error the handler failed with.
+    /// Deprecated. Use the failure field instead.
+    #[deprecated]
     #[prost(message, optional, tag = "4")]
     pub error: ::core::option::Option<super::super::nexus::v1::HandlerError>,
+    /// The error the handler failed with. Must contain a NexusHandlerFailureInfo object.
+    #[prost(message, optional, tag = "5")]
+    pub failure: ::core::option::Option<super::super::failure::v1::Failure>,
+    /// Client must forward the poller_group_id received in PollNexusTaskQueueResponse for proper
+    /// routing of the response.
+    #[prost(string, tag = "6")]
+    pub poller_group_id: ::prost::alloc::string::String,
 }
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct RespondNexusTaskFailedResponse {}
@@ -2696,6 +2864,9 @@ pub struct ExecuteMultiOperationRequest {
     /// Note that additional operation-specific restrictions have to be considered.
     #[prost(message, repeated, tag = "2")]
     pub operations: ::prost::alloc::vec::Vec<execute_multi_operation_request::Operation>,
+    /// Resource ID for routing. Should match operations\[0\].start_workflow.workflow_id
+    #[prost(string, tag = "3")]
+    pub resource_id: ::prost::alloc::string::String,
 }
 /// Nested message and enum types in `ExecuteMultiOperationRequest`.
 pub mod execute_multi_operation_request {
@@ -2752,6 +2923,7 @@ pub mod execute_multi_operation_response {
     }
 }
 /// NOTE: keep in sync with temporal.api.batch.v1.BatchOperationUpdateActivityOptions
+/// Deprecated. Use `UpdateActivityExecutionOptionsRequest`.
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct UpdateActivityOptionsRequest {
     /// Namespace of the workflow which scheduled this activity
@@ -2770,10 +2942,10 @@ pub struct UpdateActivityOptionsRequest {
     >,
     /// Controls which fields from `activity_options` will be applied
     #[prost(message, optional, tag = "5")]
-    pub update_mask: ::core::option::Option<::prost_types::FieldMask>,
+    pub update_mask: ::core::option::Option<::pbjson_types::FieldMask>,
     /// If set, the activity options will be restored to the default.
     /// Default options are then options activity was created with.
-    /// They are part of the first SCHEDULE event.
+    /// They are part of the first schedule event.
     /// This flag cannot be combined with any other option; if you supply
     /// restore_original together with other options, the request will be rejected.
     #[prost(bool, tag = "8")]
@@ -2799,6 +2971,44 @@ pub mod update_activity_options_request {
     }
 }
 #[derive(Clone, PartialEq, ::prost::Message)]
+pub struct UpdateActivityExecutionOptionsRequest {
+    /// Namespace of the workflow which scheduled this activity
+    #[prost(string, tag = "1")]
+    pub namespace: ::prost::alloc::string::String,
+    /// If provided, targets a workflow activity for the given workflow ID.
+    /// If empty, targets a standalone activity.
+    #[prost(string, tag = "2")]
+    pub workflow_id: ::prost::alloc::string::String,
+    /// The ID of the activity to target.
+    #[prost(string, tag = "3")]
+    pub activity_id: ::prost::alloc::string::String,
+    /// Run ID of the workflow or standalone activity.
+    #[prost(string, tag = "4")]
+    pub run_id: ::prost::alloc::string::String,
+    /// The identity of the client who initiated this request
+    #[prost(string, tag = "5")]
+    pub identity: ::prost::alloc::string::String,
+    /// Activity options. Partial updates are accepted and controlled by update_mask
+    #[prost(message, optional, tag = "6")]
+    pub activity_options: ::core::option::Option<
+        super::super::activity::v1::ActivityOptions,
+    >,
+    /// Controls which fields from `activity_options` will be applied
+    #[prost(message, optional, tag = "7")]
+    pub update_mask: ::core::option::Option<::pbjson_types::FieldMask>,
+    /// If set, the activity options will be restored to the default.
+    /// Default options are then options activity was created with.
+    /// They are part of the first schedule event.
+    /// This flag cannot be combined with any other option; if you supply
+    /// restore_original together with other options, the request will be rejected.
+    #[prost(bool, tag = "8")]
+    pub restore_original: bool,
+    /// Resource ID for routing. Contains "workflow:{workflow_id}" for workflow activities or "activity:{activity_id}" for standalone activities.
+    #[prost(string, tag = "9")]
+    pub resource_id: ::prost::alloc::string::String,
+}
+/// Deprecated. Use `UpdateActivityExecutionOptionsResponse`.
+#[derive(Clone, PartialEq, ::prost::Message)]
 pub struct UpdateActivityOptionsResponse {
     /// Activity options after an update
     #[prost(message, optional, tag = "1")]
@@ -2807,6 +3017,15 @@ pub struct UpdateActivityOptionsResponse {
     >,
 }
 #[derive(Clone, PartialEq, ::prost::Message)]
+pub struct UpdateActivityExecutionOptionsResponse {
+    /// Activity options after an update
+    #[prost(message, optional, tag = "1")]
+    pub activity_options: ::core::option::Option<
+        super::super::activity::v1::ActivityOptions,
+    >,
+}
+/// Deprecated. Use `PauseActivityExecutionRequest`.
+#[derive(Clone, PartialEq, ::prost::Message)]
 pub struct PauseActivityRequest {
     /// Namespace of the workflow which scheduled this activity.
     #[prost(string, tag = "1")]
@@ -2820,6 +3039,9 @@ pub struct PauseActivityRequest {
     /// Reason to pause the activity.
     #[prost(string, tag = "6")]
     pub reason: ::prost::alloc::string::String,
+    /// Used to de-dupe pause requests.
+    #[prost(string, tag = "7")]
+    pub request_id: ::prost::alloc::string::String,
     /// either activity id or activity type must be provided
     #[prost(oneof = "pause_activity_request::Activity", tags = "4, 5")]
     pub activity: ::core::option::Option<pause_activity_request::Activity>,
@@ -2838,8 +3060,40 @@ pub mod pause_activity_request {
         Type(::prost::alloc::string::String),
     }
 }
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PauseActivityExecutionRequest {
+    /// Namespace of the workflow which scheduled this activity.
+    #[prost(string, tag = "1")]
+    pub namespace: ::prost::alloc::string::String,
+    /// If provided, pause a workflow activity (or activities) for the given workflow ID.
+    /// If empty, targets a standalone activity.
+    #[prost(string, tag = "2")]
+    pub workflow_id: ::prost::alloc::string::String,
+    /// The ID of the activity to target.
+    #[prost(string, tag = "3")]
+    pub activity_id: ::prost::alloc::string::String,
+    /// Run ID of the workflow or standalone activity.
+    #[prost(string, tag = "4")]
+    pub run_id: ::prost::alloc::string::String,
+    /// The identity of the client who initiated this request.
+    #[prost(string, tag = "5")]
+    pub identity: ::prost::alloc::string::String,
+    /// Reason to pause the activity.
+    #[prost(string, tag = "6")]
+    pub reason: ::prost::alloc::string::String,
+    /// Resource ID for routing. Contains "workflow:{workflow_id}" for workflow activities or "activity:{activity_id}" for standalone activities.
+    #[prost(string, tag = "7")]
+    pub resource_id: ::prost::alloc::string::String,
+    /// Used to de-dupe pause requests.
+    #[prost(string, tag = "8")]
+    pub request_id: ::prost::alloc::string::String,
+}
+/// Deprecated. Use `PauseActivityExecutionResponse`.
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct PauseActivityResponse {}
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct PauseActivityExecutionResponse {}
+/// Deprecated. Use `UnpauseActivityExecutionRequest`.
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct UnpauseActivityRequest {
     /// Namespace of the workflow which scheduled this activity.
@@ -2859,7 +3113,7 @@ pub struct UnpauseActivityRequest {
     pub reset_heartbeat: bool,
     /// If set, the activity will start at a random time within the specified jitter duration.
     #[prost(message, optional, tag = "9")]
-    pub jitter: ::core::option::Option<::prost_types::Duration>,
+    pub jitter: ::core::option::Option<::pbjson_types::Duration>,
     /// either activity id or activity type must be provided
     #[prost(oneof = "unpause_activity_request::Activity", tags = "4, 5, 6")]
     pub activity: ::core::option::Option<unpause_activity_request::Activity>,
@@ -2880,9 +3134,47 @@ pub mod unpause_activity_request {
         UnpauseAll(bool),
     }
 }
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct UnpauseActivityExecutionRequest {
+    /// Namespace of the workflow which scheduled this activity.
+    #[prost(string, tag = "1")]
+    pub namespace: ::prost::alloc::string::String,
+    /// If provided, targets a workflow activity for the given workflow ID.
+    /// If empty, targets a standalone activity.
+    #[prost(string, tag = "2")]
+    pub workflow_id: ::prost::alloc::string::String,
+    /// The ID of the activity to target.
+    #[prost(string, tag = "3")]
+    pub activity_id: ::prost::alloc::string::String,
+    /// Run ID of the workflow or standalone activity.
+    #[prost(string, tag = "4")]
+    pub run_id: ::prost::alloc::string::String,
+    /// The identity of the client who initiated this request.
+    #[prost(string, tag = "5")]
+    pub identity: ::prost::alloc::string::String,
+    /// Providing this flag will also reset the number of attempts.
+    #[prost(bool, tag = "6")]
+    pub reset_attempts: bool,
+    /// Providing this flag will also reset the heartbeat details.
+    #[prost(bool, tag = "7")]
+    pub reset_heartbeat: bool,
+    /// Reason to unpause the activity.
+    #[prost(string, tag = "8")]
+    pub reason: ::prost::alloc::string::String,
+    /// If set, the activity will start at a random time within the specified jitter duration.
+    #[prost(message, optional, tag = "9")]
+    pub jitter: ::core::option::Option<::pbjson_types::Duration>,
+    /// Resource ID for routing. Contains "workflow:{workflow_id}" for workflow activities or "activity:{activity_id}" for standalone activities.
+    #[prost(string, tag = "10")]
+    pub resource_id: ::prost::alloc::string::String,
+}
+/// Deprecated. Use `UnpauseActivityExecutionResponse`.
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct UnpauseActivityResponse {}
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct UnpauseActivityExecutionResponse {}
 /// NOTE: keep in sync with temporal.api.batch.v1.BatchOperationResetActivities
+/// Deprecated. Use `ResetActivityExecutionRequest`.
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct ResetActivityRequest {
     /// Namespace of the workflow which scheduled this activity.
@@ -2904,10 +3196,10 @@ pub struct ResetActivityRequest {
     /// If set, and activity is in backoff, the activity will start at a random time within the specified jitter duration.
     /// (unless it is paused and keep_paused is set)
     #[prost(message, optional, tag = "8")]
-    pub jitter: ::core::option::Option<::prost_types::Duration>,
+    pub jitter: ::core::option::Option<::pbjson_types::Duration>,
     /// If set, the activity options will be restored to the defaults.
     /// Default options are then options activity was created with.
-    /// They are part of the first SCHEDULE event.
+    /// They are part of the first schedule event.
     #[prost(bool, tag = "9")]
     pub restore_original_options: bool,
     /// either activity id, activity type or update_all must be provided
@@ -2930,8 +3222,49 @@ pub mod reset_activity_request {
         MatchAll(bool),
     }
 }
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ResetActivityExecutionRequest {
+    /// Namespace of the workflow which scheduled this activity.
+    #[prost(string, tag = "1")]
+    pub namespace: ::prost::alloc::string::String,
+    /// If provided, targets a workflow activity for the given workflow ID.
+    /// If empty, targets a standalone activity.
+    #[prost(string, tag = "2")]
+    pub workflow_id: ::prost::alloc::string::String,
+    /// The ID of the activity to target.
+    #[prost(string, tag = "3")]
+    pub activity_id: ::prost::alloc::string::String,
+    /// Run ID of the workflow or standalone activity.
+    #[prost(string, tag = "4")]
+    pub run_id: ::prost::alloc::string::String,
+    /// The identity of the client who initiated this request.
+    #[prost(string, tag = "5")]
+    pub identity: ::prost::alloc::string::String,
+    /// Indicates that activity should reset heartbeat details.
+    /// This flag will be applied only to the new instance of the activity.
+    #[prost(bool, tag = "6")]
+    pub reset_heartbeat: bool,
+    /// If activity is paused, it will remain paused after reset
+    #[prost(bool, tag = "7")]
+    pub keep_paused: bool,
+    /// If set, and activity is in backoff, the activity will start at a random time within the specified jitter duration.
+    /// (unless it is paused and keep_paused is set)
+    #[prost(message, optional, tag = "8")]
+    pub jitter: ::core::option::Option<::pbjson_types::Duration>,
+    /// If set, the activity options will be restored to the defaults.
+    /// Default options are then options activity was created with.
+    /// They are part of the first schedule event.
+    #[prost(bool, tag = "9")]
+    pub restore_original_options: bool,
+    /// Resource ID for routing. Contains "workflow:{workflow_id}" for workflow activities or "activity:{activity_id}" for standalone activities.
+    #[prost(string, tag = "10")]
+    pub resource_id: ::prost::alloc::string::String,
+}
+/// Deprecated. Use `ResetActivityExecutionRequest`.
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct ResetActivityResponse {}
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct ResetActivityExecutionResponse {}
 /// Keep the parameters in sync with:
 ///    - temporal.api.batch.v1.BatchOperationUpdateWorkflowExecutionOptions.
 ///    - temporal.api.workflow.v1.PostResetOperation.UpdateWorkflowOptions.
@@ -2955,7 +3288,7 @@ pub struct UpdateWorkflowExecutionOptionsRequest {
     /// Controls which fields from `workflow_execution_options` will be applied.
     /// To unset a field, set it to null and use the update mask to indicate that it should be mutated.
     #[prost(message, optional, tag = "4")]
-    pub update_mask: ::core::option::Option<::prost_types::FieldMask>,
+    pub update_mask: ::core::option::Option<::pbjson_types::FieldMask>,
     /// Optional. The identity of the client who initiated this request.
     #[prost(string, tag = "5")]
     pub identity: ::prost::alloc::string::String,
@@ -3264,6 +3597,30 @@ pub struct SetWorkerDeploymentRampingVersionResponse {
     #[prost(float, tag = "3")]
     pub previous_percentage: f32,
 }
+/// Creates a new WorkerDeployment.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct CreateWorkerDeploymentRequest {
+    #[prost(string, tag = "1")]
+    pub namespace: ::prost::alloc::string::String,
+    /// The name of the Worker Deployment to create. If a Worker Deployment with
+    /// this name already exists, an error will be returned.
+    #[prost(string, tag = "2")]
+    pub deployment_name: ::prost::alloc::string::String,
+    /// Optional. The identity of the client who initiated this request.
+    #[prost(string, tag = "4")]
+    pub identity: ::prost::alloc::string::String,
+    /// A unique identifier for this create request for idempotence. Typically UUIDv4.
+    #[prost(string, tag = "5")]
+    pub request_id: ::prost::alloc::string::String,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct CreateWorkerDeploymentResponse {
+    /// This value is returned so that it can be optionally passed to APIs that
+    /// write to the WorkerDeployment state to ensure that the state did not
+    /// change between this API call and a future write.
+    #[prost(bytes = "vec", tag = "1")]
+    pub conflict_token: ::prost::alloc::vec::Vec<u8>,
+}
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct ListWorkerDeploymentsRequest {
     #[prost(string, tag = "1")]
@@ -3292,7 +3649,7 @@ pub mod list_worker_deployments_response {
         #[prost(string, tag = "1")]
         pub name: ::prost::alloc::string::String,
         #[prost(message, optional, tag = "2")]
-        pub create_time: ::core::option::Option<::prost_types::Timestamp>,
+        pub create_time: ::core::option::Option<::pbjson_types::Timestamp>,
         #[prost(message, optional, tag = "3")]
         pub routing_config: ::core::option::Option<
             super::super::super::deployment::v1::RoutingConfig,
@@ -3314,6 +3671,31 @@ pub mod list_worker_deployments_response {
         >,
     }
 }
+/// Creates a new WorkerDeploymentVersion.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct CreateWorkerDeploymentVersionRequest {
+    #[prost(string, tag = "1")]
+    pub namespace: ::prost::alloc::string::String,
+    /// Required.
+    #[prost(message, optional, tag = "2")]
+    pub deployment_version: ::core::option::Option<
+        super::super::deployment::v1::WorkerDeploymentVersion,
+    >,
+    /// Optional. Contains the new worker compute configuration for the Worker
+    /// Deployment. Used for worker scale management.
+    #[prost(message, optional, tag = "4")]
+    pub compute_config: ::core::option::Option<super::super::compute::v1::ComputeConfig>,
+    /// Optional. The identity of the client who initiated this request.
+    #[prost(string, tag = "3")]
+    pub identity: ::prost::alloc::string::String,
+    /// A unique identifier for this create request for idempotence. Typically UUIDv4.
+    /// If a second request with the same ID is recieved, it is considered a successful no-op.
+    /// Retrying with a different request ID for the same deployment name + build ID is an error.
+    #[prost(string, tag = "5")]
+    pub request_id: ::prost::alloc::string::String,
+}
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct CreateWorkerDeploymentVersionResponse {}
 /// Used for manual deletion of Versions. User can delete a Version only when all the
 /// following conditions are met:
 ///   - It is not the Current or Ramping Version of its Deployment.
@@ -3357,6 +3739,67 @@ pub struct DeleteWorkerDeploymentRequest {
 }
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct DeleteWorkerDeploymentResponse {}
+/// Used to update the compute config of a Worker Deployment Version.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct UpdateWorkerDeploymentVersionComputeConfigRequest {
+    #[prost(string, tag = "1")]
+    pub namespace: ::prost::alloc::string::String,
+    /// Required.
+    #[prost(message, optional, tag = "2")]
+    pub deployment_version: ::core::option::Option<
+        super::super::deployment::v1::WorkerDeploymentVersion,
+    >,
+    /// Optional. Contains the compute config scaling groups to add or update for the Worker
+    /// Deployment.
+    #[prost(map = "string, message", tag = "6")]
+    pub compute_config_scaling_groups: ::std::collections::HashMap<
+        ::prost::alloc::string::String,
+        super::super::compute::v1::ComputeConfigScalingGroupUpdate,
+    >,
+    /// Optional. Contains the compute config scaling groups to remove from the Worker Deployment.
+    #[prost(string, repeated, tag = "7")]
+    pub remove_compute_config_scaling_groups: ::prost::alloc::vec::Vec<
+        ::prost::alloc::string::String,
+    >,
+    /// Optional. The identity of the client who initiated this request.
+    #[prost(string, tag = "3")]
+    pub identity: ::prost::alloc::string::String,
+    /// A unique identifier for this create request for idempotence. Typically UUIDv4.
+    /// If a second request with the same ID is recieved, it is considered a successful no-op.
+    /// Retrying with a different request ID for the same deployment name + build ID is an error.
+    #[prost(string, tag = "4")]
+    pub request_id: ::prost::alloc::string::String,
+}
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct UpdateWorkerDeploymentVersionComputeConfigResponse {}
+/// Used to validate the compute config without attaching it to a Worker Deployment Version.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ValidateWorkerDeploymentVersionComputeConfigRequest {
+    #[prost(string, tag = "1")]
+    pub namespace: ::prost::alloc::string::String,
+    /// Required.
+    #[prost(message, optional, tag = "2")]
+    pub deployment_version: ::core::option::Option<
+        super::super::deployment::v1::WorkerDeploymentVersion,
+    >,
+    /// Optional. Contains the compute config scaling groups to add or update for the Worker
+    /// Deployment.
+    #[prost(map = "string, message", tag = "6")]
+    pub compute_config_scaling_groups: ::std::collections::HashMap<
+        ::prost::alloc::string::String,
+        super::super::compute::v1::ComputeConfigScalingGroupUpdate,
+    >,
+    /// Optional. Contains the compute config scaling groups to remove from the Worker Deployment.
+    #[prost(string, repeated, tag = "7")]
+    pub remove_compute_config_scaling_groups: ::prost::alloc::vec::Vec<
+        ::prost::alloc::string::String,
+    >,
+    /// Optional. The identity of the client who initiated this request.
+    #[prost(string, tag = "3")]
+    pub identity: ::prost::alloc::string::String,
+}
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct ValidateWorkerDeploymentVersionComputeConfigResponse {}
 /// Used to update the user-defined metadata of a Worker Deployment Version.
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct UpdateWorkerDeploymentVersionMetadataRequest {
@@ -3479,7 +3922,7 @@ pub struct GetDeploymentReachabilityResponse {
     /// Reachability level might come from server cache. This timestamp specifies when the value
     /// was actually calculated.
     #[prost(message, optional, tag = "3")]
-    pub last_update_time: ::core::option::Option<::prost_types::Timestamp>,
+    pub last_update_time: ::core::option::Option<::pbjson_types::Timestamp>,
 }
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CreateWorkflowRuleRequest {
@@ -3594,6 +4037,9 @@ pub struct RecordWorkerHeartbeatRequest {
     pub worker_heartbeat: ::prost::alloc::vec::Vec<
         super::super::worker::v1::WorkerHeartbeat,
     >,
+    /// Resource ID for routing. Contains the worker grouping key.
+    #[prost(string, tag = "4")]
+    pub resource_id: ::prost::alloc::string::String,
 }
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct RecordWorkerHeartbeatResponse {}
@@ -3605,8 +4051,8 @@ pub struct ListWorkersRequest {
     pub page_size: i32,
     #[prost(bytes = "vec", tag = "3")]
     pub next_page_token: ::prost::alloc::vec::Vec<u8>,
-    /// `query` in ListWorkers is used to filter workers based on worker status info.
-    /// The following worker status attributes are expected are supported as part of the query:
+    /// `query` in ListWorkers is used to filter workers based on worker attributes.
+    /// Supported attributes:
     /// * WorkerInstanceKey
     /// * WorkerIdentity
     /// * HostName
@@ -3616,16 +4062,24 @@ pub struct ListWorkersRequest {
     /// * SdkName
     /// * SdkVersion
     /// * StartTime
-    /// * LastHeartbeatTime
     /// * Status
-    /// Currently metrics are not supported as a part of ListWorkers query.
     #[prost(string, tag = "4")]
     pub query: ::prost::alloc::string::String,
+    /// When true, the response will include system workers that are created implicitly
+    /// by the server and not by the user. By default, system workers are excluded.
+    #[prost(bool, tag = "5")]
+    pub include_system_workers: bool,
 }
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct ListWorkersResponse {
+    /// Deprecated: Use workers instead. This field returns full WorkerInfo which
+    /// includes expensive runtime metrics. We will stop populating this field in the future.
+    #[deprecated]
     #[prost(message, repeated, tag = "1")]
     pub workers_info: ::prost::alloc::vec::Vec<super::super::worker::v1::WorkerInfo>,
+    /// Limited worker information.
+    #[prost(message, repeated, tag = "3")]
+    pub workers: ::prost::alloc::vec::Vec<super::super::worker::v1::WorkerListInfo>,
     /// Next page token
     #[prost(bytes = "vec", tag = "2")]
     pub next_page_token: ::prost::alloc::vec::Vec<u8>,
@@ -3705,6 +4159,9 @@ pub struct FetchWorkerConfigRequest {
     /// only single worker is supported at this time.
     #[prost(message, optional, tag = "6")]
     pub selector: ::core::option::Option<super::super::common::v1::WorkerSelector>,
+    /// Resource ID for routing. Contains the worker grouping key.
+    #[prost(string, tag = "7")]
+    pub resource_id: ::prost::alloc::string::String,
 }
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct FetchWorkerConfigResponse {
@@ -3729,10 +4186,13 @@ pub struct UpdateWorkerConfigRequest {
     pub worker_config: ::core::option::Option<super::super::sdk::v1::WorkerConfig>,
     /// Controls which fields from `worker_config` will be applied
     #[prost(message, optional, tag = "5")]
-    pub update_mask: ::core::option::Option<::prost_types::FieldMask>,
+    pub update_mask: ::core::option::Option<::pbjson_types::FieldMask>,
     /// Defines which workers should receive this command.
     #[prost(message, optional, tag = "6")]
     pub selector: ::core::option::Option<super::super::common::v1::WorkerSelector>,
+    /// Resource ID for routing. Contains the worker grouping key.
+    #[prost(string, tag = "7")]
+    pub resource_id: ::prost::alloc::string::String,
 }
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct UpdateWorkerConfigResponse {
@@ -3838,7 +4298,7 @@ pub struct StartActivityExecutionRequest {
     /// (-- api-linter: core::0140::prepositions=disabled
     ///      aip.dev/not-precedent: "to" is used to indicate interval. --)
     #[prost(message, optional, tag = "7")]
-    pub schedule_to_close_timeout: ::core::option::Option<::prost_types::Duration>,
+    pub schedule_to_close_timeout: ::core::option::Option<::pbjson_types::Duration>,
     /// Limits time an activity task can stay in a task queue before a worker picks it up. This
     /// timeout is always non retryable, as all a retry would achieve is to put it back into the same
     /// queue. Defaults to `schedule_to_close_timeout` if not specified.
@@ -3846,7 +4306,7 @@ pub struct StartActivityExecutionRequest {
     /// (-- api-linter: core::0140::prepositions=disabled
     ///      aip.dev/not-precedent: "to" is used to indicate interval. --)
     #[prost(message, optional, tag = "8")]
-    pub schedule_to_start_timeout: ::core::option::Option<::prost_types::Duration>,
+    pub schedule_to_start_timeout: ::core::option::Option<::pbjson_types::Duration>,
     /// Maximum time an activity is allowed to execute after being picked up by a worker. This
     /// timeout is always retryable. Either this or `schedule_to_close_timeout` must be
     /// specified.
@@ -3854,10 +4314,10 @@ pub struct StartActivityExecutionRequest {
     /// (-- api-linter: core::0140::prepositions=disabled
     ///      aip.dev/not-precedent: "to" is used to indicate interval. --)
     #[prost(message, optional, tag = "9")]
-    pub start_to_close_timeout: ::core::option::Option<::prost_types::Duration>,
+    pub start_to_close_timeout: ::core::option::Option<::pbjson_types::Duration>,
     /// Maximum permitted time between successful worker heartbeats.
     #[prost(message, optional, tag = "10")]
-    pub heartbeat_timeout: ::core::option::Option<::prost_types::Duration>,
+    pub heartbeat_timeout: ::core::option::Option<::pbjson_types::Duration>,
     /// The retry policy for the activity. Will never exceed `schedule_to_close_timeout`.
     #[prost(message, optional, tag = "11")]
     pub retry_policy: ::core::option::Option<super::super::common::v1::RetryPolicy>,
@@ -3889,6 +4349,24 @@ pub struct StartActivityExecutionRequest {
     /// Priority metadata.
     #[prost(message, optional, tag = "18")]
     pub priority: ::core::option::Option<super::super::common::v1::Priority>,
+    /// Callbacks to be called by the server when this activity reaches a terminal state.
+    /// Callback addresses must be whitelisted in the server's dynamic configuration.
+    #[prost(message, repeated, tag = "19")]
+    pub completion_callbacks: ::prost::alloc::vec::Vec<
+        super::super::common::v1::Callback,
+    >,
+    /// Links to be associated with the activity. Callbacks may also have associated links;
+    /// links already included with a callback should not be duplicated here.
+    #[prost(message, repeated, tag = "20")]
+    pub links: ::prost::alloc::vec::Vec<super::super::common::v1::Link>,
+    /// Options for handling conflicts when using ACTIVITY_ID_CONFLICT_POLICY_USE_EXISTING.
+    #[prost(message, optional, tag = "21")]
+    pub on_conflict_options: ::core::option::Option<
+        super::super::common::v1::OnConflictOptions,
+    >,
+    /// Time to wait before dispatching the first activity task. This delay is not applied to retry attempts.
+    #[prost(message, optional, tag = "22")]
+    pub start_delay: ::core::option::Option<::pbjson_types::Duration>,
 }
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct StartActivityExecutionResponse {
@@ -3898,6 +4376,9 @@ pub struct StartActivityExecutionResponse {
     /// If true, a new activity was started.
     #[prost(bool, tag = "2")]
     pub started: bool,
+    /// Link to the started activity.
+    #[prost(message, optional, tag = "3")]
+    pub link: ::core::option::Option<super::super::common::v1::Link>,
 }
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct DescribeActivityExecutionRequest {
@@ -3922,13 +4403,20 @@ pub struct DescribeActivityExecutionRequest {
     /// sequence of state changes.
     #[prost(bytes = "vec", tag = "6")]
     pub long_poll_token: ::prost::alloc::vec::Vec<u8>,
-}
+    /// Include the heartbeat_details field inside info in the response if available.
+    #[prost(bool, tag = "7")]
+    pub include_heartbeat_details: bool,
+    /// Include the last_failure field inside info in the response if available.
+    #[prost(bool, tag = "8")]
+    pub include_last_failure: bool,
+}
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct DescribeActivityExecutionResponse {
     /// The run ID of the activity, useful when run_id was not specified in the request.
     #[prost(string, tag = "1")]
     pub run_id: ::prost::alloc::string::String,
-    /// Information about the activity execution.
+    /// Information about the activity execution. Fields heartbeat_details and last_failure are omitted unless
+    /// the request has include_heartbeat_details or include_last_failure set to true, respectively.
     #[prost(message, optional, tag = "2")]
     pub info: ::core::option::Option<super::super::activity::v1::ActivityExecutionInfo>,
     /// Serialized activity input, passed as arguments to the activity function.
@@ -3943,6 +4431,9 @@ pub struct DescribeActivityExecutionResponse {
     /// Token for follow-on long-poll requests. Absent only if the activity is complete.
     #[prost(bytes = "vec", tag = "5")]
     pub long_poll_token: ::prost::alloc::vec::Vec<u8>,
+    /// Callbacks attached to this activity execution and their current state.
+    #[prost(message, repeated, tag = "6")]
+    pub callbacks: ::prost::alloc::vec::Vec<super::super::activity::v1::CallbackInfo>,
 }
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct PollActivityExecutionRequest {
@@ -3989,6 +4480,243 @@ pub struct ListActivityExecutionsResponse {
     pub next_page_token: ::prost::alloc::vec::Vec<u8>,
 }
 #[derive(Clone, PartialEq, ::prost::Message)]
+pub struct StartNexusOperationExecutionRequest {
+    #[prost(string, tag = "1")]
+    pub namespace: ::prost::alloc::string::String,
+    /// The identity of the client who initiated this request.
+    #[prost(string, tag = "2")]
+    pub identity: ::prost::alloc::string::String,
+    /// A unique identifier for this caller-side start request. Typically UUIDv4.
+    /// StartOperation requests sent to the handler will use a server-generated request ID.
+    #[prost(string, tag = "3")]
+    pub request_id: ::prost::alloc::string::String,
+    /// Identifier for this operation. This is a caller-side ID, distinct from any internal
+    /// operation identifiers generated by the handler. Must be unique among operations in the
+    /// same namespace, subject to the rules imposed by id_reuse_policy and id_conflict_policy.
+    #[prost(string, tag = "4")]
+    pub operation_id: ::prost::alloc::string::String,
+    /// Endpoint name, resolved to a URL via the cluster's endpoint registry.
+    #[prost(string, tag = "5")]
+    pub endpoint: ::prost::alloc::string::String,
+    /// Service name.
+    #[prost(string, tag = "6")]
+    pub service: ::prost::alloc::string::String,
+    /// Operation name.
+    #[prost(string, tag = "7")]
+    pub operation: ::prost::alloc::string::String,
+    /// Schedule-to-close timeout for this operation.
+    /// Indicates how long the caller is willing to wait for operation completion.
+    /// Calls are retried internally by the server.
+    /// (-- api-linter: core::0140::prepositions=disabled
+    ///      aip.dev/not-precedent: "to" is used to indicate interval. --)
+    #[prost(message, optional, tag = "8")]
+    pub schedule_to_close_timeout: ::core::option::Option<::pbjson_types::Duration>,
+    /// Schedule-to-start timeout for this operation.
+    /// Indicates how long the caller is willing to wait for the operation to be started (or completed if synchronous)
+    /// by the handler.
+    /// If not set or zero, no schedule-to-start timeout is enforced.
+    /// (-- api-linter: core::0140::prepositions=disabled
+    ///      aip.dev/not-precedent: "to" is used to indicate interval. --)
+    #[prost(message, optional, tag = "9")]
+    pub schedule_to_start_timeout: ::core::option::Option<::pbjson_types::Duration>,
+    /// Start-to-close timeout for this operation.
+    /// Indicates how long the caller is willing to wait for an asynchronous operation to complete after it has been
+    /// started. Synchronous operations ignore this timeout.
+    /// If not set or zero, no start-to-close timeout is enforced.
+    /// (-- api-linter: core::0140::prepositions=disabled
+    ///      aip.dev/not-precedent: "to" is used to indicate interval. --)
+    #[prost(message, optional, tag = "10")]
+    pub start_to_close_timeout: ::core::option::Option<::pbjson_types::Duration>,
+    /// Serialized input to the operation. Passed as the request payload.
+    #[prost(message, optional, tag = "11")]
+    pub input: ::core::option::Option<super::super::common::v1::Payload>,
+    /// Defines whether to allow re-using the operation id from a previously *closed* operation.
+    /// The default policy is NEXUS_OPERATION_ID_REUSE_POLICY_ALLOW_DUPLICATE.
+    #[prost(
+        enumeration = "super::super::enums::v1::NexusOperationIdReusePolicy",
+        tag = "12"
+    )]
+    pub id_reuse_policy: i32,
+    /// Defines how to resolve an operation id conflict with a *running* operation.
+    /// The default policy is NEXUS_OPERATION_ID_CONFLICT_POLICY_FAIL.
+    #[prost(
+        enumeration = "super::super::enums::v1::NexusOperationIdConflictPolicy",
+        tag = "13"
+    )]
+    pub id_conflict_policy: i32,
+    /// Search attributes for indexing.
+    #[prost(message, optional, tag = "14")]
+    pub search_attributes: ::core::option::Option<
+        super::super::common::v1::SearchAttributes,
+    >,
+    /// Header to attach to the Nexus request.
+    /// Users are responsible for encrypting sensitive data in this header as it is stored in workflow history and
+    /// transmitted to external services as-is.
+    /// This is useful for propagating tracing information.
+    /// Note these headers are not the same as Temporal headers on internal activities and child workflows, these are
+    /// transmitted to Nexus operations that may be external and are not traditional payloads.
+    #[prost(map = "string, string", tag = "15")]
+    pub nexus_header: ::std::collections::HashMap<
+        ::prost::alloc::string::String,
+        ::prost::alloc::string::String,
+    >,
+    /// Metadata for use by user interfaces to display the fixed as-of-start summary and details of the operation.
+    #[prost(message, optional, tag = "16")]
+    pub user_metadata: ::core::option::Option<super::super::sdk::v1::UserMetadata>,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct StartNexusOperationExecutionResponse {
+    /// The run ID of the operation that was started - or used (via NEXUS_OPERATION_ID_CONFLICT_POLICY_USE_EXISTING).
+    #[prost(string, tag = "1")]
+    pub run_id: ::prost::alloc::string::String,
+    /// If true, a new operation was started.
+    #[prost(bool, tag = "2")]
+    pub started: bool,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct DescribeNexusOperationExecutionRequest {
+    #[prost(string, tag = "1")]
+    pub namespace: ::prost::alloc::string::String,
+    #[prost(string, tag = "2")]
+    pub operation_id: ::prost::alloc::string::String,
+    /// Operation run ID. If empty the request targets the latest run.
+    #[prost(string, tag = "3")]
+    pub run_id: ::prost::alloc::string::String,
+    /// Include the input field in the response.
+    #[prost(bool, tag = "4")]
+    pub include_input: bool,
+    /// Include the outcome (result/failure) in the response if the operation has completed.
+    #[prost(bool, tag = "5")]
+    pub include_outcome: bool,
+    /// Token from a previous DescribeNexusOperationExecutionResponse. If present, this RPC will long-poll until operation
+    /// state changes from the state encoded in this token. If absent, return current state immediately.
+    /// If present, run_id must also be present.
+    /// Note that operation state may change multiple times between requests, therefore it is not
+    /// guaranteed that a client making a sequence of long-poll requests will see a complete
+    /// sequence of state changes.
+    #[prost(bytes = "vec", tag = "6")]
+    pub long_poll_token: ::prost::alloc::vec::Vec<u8>,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct DescribeNexusOperationExecutionResponse {
+    /// The run ID of the operation, useful when run_id was not specified in the request.
+    #[prost(string, tag = "1")]
+    pub run_id: ::prost::alloc::string::String,
+    /// Information about the operation.
+    #[prost(message, optional, tag = "2")]
+    pub info: ::core::option::Option<
+        super::super::nexus::v1::NexusOperationExecutionInfo,
+    >,
+    /// Serialized operation input, passed as the request payload.
+    /// Only set if include_input was true in the request.
+    #[prost(message, optional, tag = "3")]
+    pub input: ::core::option::Option<super::super::common::v1::Payload>,
+    /// Token for follow-on long-poll requests. Absent only if the operation is complete.
+    #[prost(bytes = "vec", tag = "6")]
+    pub long_poll_token: ::prost::alloc::vec::Vec<u8>,
+    /// Only set if the operation is completed and include_outcome was true in the request.
+    #[prost(
+        oneof = "describe_nexus_operation_execution_response::Outcome",
+        tags = "4, 5"
+    )]
+    pub outcome: ::core::option::Option<
+        describe_nexus_operation_execution_response::Outcome,
+    >,
+}
+/// Nested message and enum types in `DescribeNexusOperationExecutionResponse`.
+pub mod describe_nexus_operation_execution_response {
+    /// Only set if the operation is completed and include_outcome was true in the request.
+    #[derive(Clone, PartialEq, ::prost::Oneof)]
+    pub enum Outcome {
+        /// The result if the operation completed successfully.
+        #[prost(message, tag = "4")]
+        Result(super::super::super::common::v1::Payload),
+        /// The failure if the operation completed unsuccessfully.
+        #[prost(message, tag = "5")]
+        Failure(super::super::super::failure::v1::Failure),
+    }
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PollNexusOperationExecutionRequest {
+    #[prost(string, tag = "1")]
+    pub namespace: ::prost::alloc::string::String,
+    #[prost(string, tag = "2")]
+    pub operation_id: ::prost::alloc::string::String,
+    /// Operation run ID. If empty the request targets the latest run.
+    #[prost(string, tag = "3")]
+    pub run_id: ::prost::alloc::string::String,
+    /// Stage to wait for. The operation may be in a more advanced stage when the poll is unblocked.
+    #[prost(enumeration = "super::super::enums::v1::NexusOperationWaitStage", tag = "4")]
+    pub wait_stage: i32,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PollNexusOperationExecutionResponse {
+    /// The run ID of the operation, useful when run_id was not specified in the request.
+    #[prost(string, tag = "1")]
+    pub run_id: ::prost::alloc::string::String,
+    /// The current stage of the operation. May be more advanced than the stage requested in the poll.
+    #[prost(enumeration = "super::super::enums::v1::NexusOperationWaitStage", tag = "2")]
+    pub wait_stage: i32,
+    /// Operation token. Only populated for asynchronous operations after a successful StartOperation call.
+    #[prost(string, tag = "3")]
+    pub operation_token: ::prost::alloc::string::String,
+    /// The operation outcome, available if the operation is in a closed state.
+    #[prost(oneof = "poll_nexus_operation_execution_response::Outcome", tags = "4, 5")]
+    pub outcome: ::core::option::Option<
+        poll_nexus_operation_execution_response::Outcome,
+    >,
+}
+/// Nested message and enum types in `PollNexusOperationExecutionResponse`.
+pub mod poll_nexus_operation_execution_response {
+    /// The operation outcome, available if the operation is in a closed state.
+    #[derive(Clone, PartialEq, ::prost::Oneof)]
+    pub enum Outcome {
+        /// The result if the operation completed successfully.
+        #[prost(message, tag = "4")]
+        Result(super::super::super::common::v1::Payload),
+        /// The failure if the operation completed unsuccessfully.
+        #[prost(message, tag = "5")]
+        Failure(super::super::super::failure::v1::Failure),
+    }
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ListNexusOperationExecutionsRequest {
+    #[prost(string, tag = "1")]
+    pub namespace: ::prost::alloc::string::String,
+    /// Max number of operations to return per page.
+    #[prost(int32, tag = "2")]
+    pub page_size: i32,
+    /// Token returned in ListNexusOperationExecutionsResponse.
+    #[prost(bytes = "vec", tag = "3")]
+    pub next_page_token: ::prost::alloc::vec::Vec<u8>,
+    /// Visibility query, see <https://docs.temporal.io/list-filter> for the syntax.
+    /// Search attributes that are avaialble for Nexus operations include:
+    /// - OperationId
+    /// - RunId
+    /// - Endpoint
+    /// - Service
+    /// - Operation
+    /// - RequestId
+    /// - StartTime
+    /// - ExecutionTime
+    /// - CloseTime
+    /// - ExecutionStatus
+    /// - ExecutionDuration
+    /// - StateTransitionCount
+    #[prost(string, tag = "4")]
+    pub query: ::prost::alloc::string::String,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ListNexusOperationExecutionsResponse {
+    #[prost(message, repeated, tag = "1")]
+    pub operations: ::prost::alloc::vec::Vec<
+        super::super::nexus::v1::NexusOperationExecutionListInfo,
+    >,
+    /// Token to use to fetch the next page. If empty, there is no next page.
+    #[prost(bytes = "vec", tag = "2")]
+    pub next_page_token: ::prost::alloc::vec::Vec<u8>,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CountActivityExecutionsRequest {
     #[prost(string, tag = "1")]
     pub namespace: ::prost::alloc::string::String,
@@ -4025,6 +4753,43 @@ pub mod count_activity_executions_response {
     }
 }
 #[derive(Clone, PartialEq, ::prost::Message)]
+pub struct CountNexusOperationExecutionsRequest {
+    #[prost(string, tag = "1")]
+    pub namespace: ::prost::alloc::string::String,
+    /// Visibility query, see <https://docs.temporal.io/list-filter> for the syntax.
+    /// See also ListNexusOperationExecutionsRequest for search attributes available for Nexus operations.
+    #[prost(string, tag = "2")]
+    pub query: ::prost::alloc::string::String,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct CountNexusOperationExecutionsResponse {
+    /// If `query` is not grouping by any field, the count is an approximate number
+    /// of operations that match the query.
+    /// If `query` is grouping by a field, the count is simply the sum of the counts
+    /// of the groups returned in the response. This number can be smaller than the
+    /// total number of operations matching the query.
+    #[prost(int64, tag = "1")]
+    pub count: i64,
+    /// Contains the groups if the request is grouping by a field.
+    /// The list might not be complete, and the counts of each group is approximate.
+    #[prost(message, repeated, tag = "2")]
+    pub groups: ::prost::alloc::vec::Vec<
+        count_nexus_operation_executions_response::AggregationGroup,
+    >,
+}
+/// Nested message and enum types in `CountNexusOperationExecutionsResponse`.
+pub mod count_nexus_operation_executions_response {
+    #[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct AggregationGroup {
+        #[prost(message, repeated, tag = "1")]
+        pub group_values: ::prost::alloc::vec::Vec<
+            super::super::super::common::v1::Payload,
+        >,
+        #[prost(int64, tag = "2")]
+        pub count: i64,
+    }
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
 pub struct RequestCancelActivityExecutionRequest {
     #[prost(string, tag = "1")]
     pub namespace: ::prost::alloc::string::String,
@@ -4079,6 +4844,60 @@ pub struct DeleteActivityExecutionRequest {
 }
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct DeleteActivityExecutionResponse {}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct RequestCancelNexusOperationExecutionRequest {
+    #[prost(string, tag = "1")]
+    pub namespace: ::prost::alloc::string::String,
+    #[prost(string, tag = "2")]
+    pub operation_id: ::prost::alloc::string::String,
+    /// Operation run ID, targets the latest run if empty.
+    #[prost(string, tag = "3")]
+    pub run_id: ::prost::alloc::string::String,
+    /// The identity of the client who initiated this request.
+    #[prost(string, tag = "4")]
+    pub identity: ::prost::alloc::string::String,
+    /// Used to de-dupe cancellation requests.
+    #[prost(string, tag = "5")]
+    pub request_id: ::prost::alloc::string::String,
+    /// Reason for requesting the cancellation, recorded and available via the DescribeNexusOperationExecution API.
+    #[prost(string, tag = "6")]
+    pub reason: ::prost::alloc::string::String,
+}
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct RequestCancelNexusOperationExecutionResponse {}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct TerminateNexusOperationExecutionRequest {
+    #[prost(string, tag = "1")]
+    pub namespace: ::prost::alloc::string::String,
+    #[prost(string, tag = "2")]
+    pub operation_id: ::prost::alloc::string::String,
+    /// Operation run ID, targets the latest run if empty.
+    #[prost(string, tag = "3")]
+    pub run_id: ::prost::alloc::string::String,
+    /// The identity of the client who initiated this request.
+    #[prost(string, tag = "4")]
+    pub identity: ::prost::alloc::string::String,
+    /// Used to de-dupe termination requests.
+    #[prost(string, tag = "5")]
+    pub request_id: ::prost::alloc::string::String,
+    /// Reason for requesting the termination, recorded in the operation's result failure outcome.
+    #[prost(string, tag = "6")]
+    pub reason: ::prost::alloc::string::String,
+}
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct TerminateNexusOperationExecutionResponse {}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct DeleteNexusOperationExecutionRequest {
+    #[prost(string, tag = "1")]
+    pub namespace: ::prost::alloc::string::String,
+    #[prost(string, tag = "2")]
+    pub operation_id: ::prost::alloc::string::String,
+    /// Operation run ID, targets the latest run if empty.
+    #[prost(string, tag = "3")]
+    pub run_id: ::prost::alloc::string::String,
+}
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct DeleteNexusOperationExecutionResponse {}
 /// Generated client implementations.
 pub mod workflow_service_client {
     #![allow(
@@ -4386,7 +5205,8 @@ pub mod workflow_service_client {
         /// Upon failure, it returns `MultiOperationExecutionFailure` where the status code
         /// equals the status code of the *first* operation that failed to be started.
         ///
-        /// NOTE: Experimental API.
+        /// (-- api-linter: core::0127::http-annotation=disabled
+        ///     aip.dev/not-precedent: To be exposed over HTTP in the future. --)
         pub async fn execute_multi_operation(
             &mut self,
             request: impl tonic::IntoRequest<super::ExecuteMultiOperationRequest>,
@@ -5917,6 +6737,7 @@ pub mod workflow_service_client {
             self.inner.unary(req, path, codec).await
         }
         /// Deprecated. Use `UpdateWorkerVersioningRules`.
+        /// Will be removed in server version v1.32.0.
         ///
         /// Allows users to specify sets of worker build id versions on a per task queue basis. Versions
         /// are ordered, and may be either compatible with some extant version, or a new incompatible
@@ -5965,6 +6786,7 @@ pub mod workflow_service_client {
             self.inner.unary(req, path, codec).await
         }
         /// Deprecated. Use `GetWorkerVersioningRules`.
+        /// Will be removed in server version v1.32.0.
         /// Fetches the worker build id versioning sets for a task queue.
         pub async fn get_worker_build_id_compatibility(
             &mut self,
@@ -6016,7 +6838,7 @@ pub mod workflow_service_client {
         /// the target Build ID of a redirect rule is able to process event histories made by the source
         /// Build ID by using [Patching](https://docs.temporal.io/workflows#patching) or other means.
         ///
-        /// WARNING: Worker Versioning is not yet stable and the API and behavior may change incompatibly.
+        /// Will be removed in server version v1.32.0.
         /// (-- api-linter: core::0127::http-annotation=disabled
         ///     aip.dev/not-precedent: We do yet expose versioning API to HTTP. --)
         pub async fn update_worker_versioning_rules(
@@ -6049,7 +6871,7 @@ pub mod workflow_service_client {
             self.inner.unary(req, path, codec).await
         }
         /// Fetches the Build ID assignment and redirect rules for a Task Queue.
-        /// WARNING: Worker Versioning is not yet stable and the API and behavior may change incompatibly.
+        /// Will be removed in server version v1.32.0.
         pub async fn get_worker_versioning_rules(
             &mut self,
             request: impl tonic::IntoRequest<super::GetWorkerVersioningRulesRequest>,
@@ -6080,6 +6902,7 @@ pub mod workflow_service_client {
             self.inner.unary(req, path, codec).await
         }
         /// Deprecated. Use `DescribeTaskQueue`.
+        /// Will be removed in server version v1.32.0.
         ///
         /// Fetches task reachability to determine whether a worker may be retired.
         /// The request may specify task queues to query for or let the server fetch all task queues mapped to the given
@@ -6520,15 +7343,15 @@ pub mod workflow_service_client {
                 );
             self.inner.unary(req, path, codec).await
         }
-        /// Updates the user-given metadata attached to a Worker Deployment Version.
-        /// Experimental. This API might significantly change or be removed in a future release.
-        pub async fn update_worker_deployment_version_metadata(
+        /// Creates a new Worker Deployment.
+        ///
+        /// Experimental. This API might significantly change or be removed in a
+        /// future release.
+        pub async fn create_worker_deployment(
             &mut self,
-            request: impl tonic::IntoRequest<
-                super::UpdateWorkerDeploymentVersionMetadataRequest,
-            >,
+            request: impl tonic::IntoRequest<super::CreateWorkerDeploymentRequest>,
         ) -> std::result::Result<
-            tonic::Response<super::UpdateWorkerDeploymentVersionMetadataResponse>,
+            tonic::Response<super::CreateWorkerDeploymentResponse>,
             tonic::Status,
         > {
             self.inner
@@ -6541,25 +7364,27 @@ pub mod workflow_service_client {
                 })?;
             let codec = tonic::codec::ProstCodec::default();
             let path = http::uri::PathAndQuery::from_static(
-                "/temporal.api.workflowservice.v1.WorkflowService/UpdateWorkerDeploymentVersionMetadata",
+                "/temporal.api.workflowservice.v1.WorkflowService/CreateWorkerDeployment",
             );
             let mut req = request.into_request();
             req.extensions_mut()
                 .insert(
                     GrpcMethod::new(
                         "temporal.api.workflowservice.v1.WorkflowService",
-                        "UpdateWorkerDeploymentVersionMetadata",
+                        "CreateWorkerDeployment",
                     ),
                 );
             self.inner.unary(req, path, codec).await
         }
-        /// Set/unset the ManagerIdentity of a Worker Deployment.
-        /// Experimental. This API might significantly change or be removed in a future release.
-        pub async fn set_worker_deployment_manager(
+        /// Creates a new Worker Deployment Version.
+        ///
+        /// Experimental. This API might significantly change or be removed in a
+        /// future release.
+        pub async fn create_worker_deployment_version(
             &mut self,
-            request: impl tonic::IntoRequest<super::SetWorkerDeploymentManagerRequest>,
+            request: impl tonic::IntoRequest<super::CreateWorkerDeploymentVersionRequest>,
         ) -> std::result::Result<
-            tonic::Response<super::SetWorkerDeploymentManagerResponse>,
+            tonic::Response<super::CreateWorkerDeploymentVersionResponse>,
             tonic::Status,
         > {
             self.inner
@@ -6572,24 +7397,27 @@ pub mod workflow_service_client {
                 })?;
             let codec = tonic::codec::ProstCodec::default();
             let path = http::uri::PathAndQuery::from_static(
-                "/temporal.api.workflowservice.v1.WorkflowService/SetWorkerDeploymentManager",
+                "/temporal.api.workflowservice.v1.WorkflowService/CreateWorkerDeploymentVersion",
             );
             let mut req = request.into_request();
             req.extensions_mut()
                 .insert(
                     GrpcMethod::new(
                         "temporal.api.workflowservice.v1.WorkflowService",
-                        "SetWorkerDeploymentManager",
+                        "CreateWorkerDeploymentVersion",
                     ),
                 );
             self.inner.unary(req, path, codec).await
         }
-        /// Invokes the specified Update function on user Workflow code.
-        pub async fn update_workflow_execution(
+        /// Updates the compute config attached to a Worker Deployment Version.
+        /// Experimental. This API might significantly change or be removed in a future release.
+        pub async fn update_worker_deployment_version_compute_config(
             &mut self,
-            request: impl tonic::IntoRequest<super::UpdateWorkflowExecutionRequest>,
+            request: impl tonic::IntoRequest<
+                super::UpdateWorkerDeploymentVersionComputeConfigRequest,
+            >,
         ) -> std::result::Result<
-            tonic::Response<super::UpdateWorkflowExecutionResponse>,
+            tonic::Response<super::UpdateWorkerDeploymentVersionComputeConfigResponse>,
             tonic::Status,
         > {
             self.inner
@@ -6602,30 +7430,27 @@ pub mod workflow_service_client {
                 })?;
             let codec = tonic::codec::ProstCodec::default();
             let path = http::uri::PathAndQuery::from_static(
-                "/temporal.api.workflowservice.v1.WorkflowService/UpdateWorkflowExecution",
+                "/temporal.api.workflowservice.v1.WorkflowService/UpdateWorkerDeploymentVersionComputeConfig",
             );
             let mut req = request.into_request();
             req.extensions_mut()
                 .insert(
                     GrpcMethod::new(
                         "temporal.api.workflowservice.v1.WorkflowService",
-                        "UpdateWorkflowExecution",
+                        "UpdateWorkerDeploymentVersionComputeConfig",
                     ),
                 );
             self.inner.unary(req, path, codec).await
         }
-        /// Polls a Workflow Execution for the outcome of a Workflow Update
-        /// previously issued through the UpdateWorkflowExecution RPC. The effective
-        /// timeout on this call will be shorter of the the caller-supplied gRPC
-        /// timeout and the server's configured long-poll timeout.
-        ///
-        /// (-- api-linter: core::0127::http-annotation=disabled
-        ///     aip.dev/not-precedent: We don't expose update polling API to HTTP in favor of a potential future non-blocking form. --)
-        pub async fn poll_workflow_execution_update(
+        /// Validates the compute config without attaching it to a Worker Deployment Version.
+        /// Experimental. This API might significantly change or be removed in a future release.
+        pub async fn validate_worker_deployment_version_compute_config(
             &mut self,
-            request: impl tonic::IntoRequest<super::PollWorkflowExecutionUpdateRequest>,
+            request: impl tonic::IntoRequest<
+                super::ValidateWorkerDeploymentVersionComputeConfigRequest,
+            >,
         ) -> std::result::Result<
-            tonic::Response<super::PollWorkflowExecutionUpdateResponse>,
+            tonic::Response<super::ValidateWorkerDeploymentVersionComputeConfigResponse>,
             tonic::Status,
         > {
             self.inner
@@ -6638,24 +7463,27 @@ pub mod workflow_service_client {
                 })?;
             let codec = tonic::codec::ProstCodec::default();
             let path = http::uri::PathAndQuery::from_static(
-                "/temporal.api.workflowservice.v1.WorkflowService/PollWorkflowExecutionUpdate",
+                "/temporal.api.workflowservice.v1.WorkflowService/ValidateWorkerDeploymentVersionComputeConfig",
             );
             let mut req = request.into_request();
             req.extensions_mut()
                 .insert(
                     GrpcMethod::new(
                         "temporal.api.workflowservice.v1.WorkflowService",
-                        "PollWorkflowExecutionUpdate",
+                        "ValidateWorkerDeploymentVersionComputeConfig",
                     ),
                 );
             self.inner.unary(req, path, codec).await
         }
-        /// StartBatchOperation starts a new batch operation
-        pub async fn start_batch_operation(
+        /// Updates the user-given metadata attached to a Worker Deployment Version.
+        /// Experimental. This API might significantly change or be removed in a future release.
+        pub async fn update_worker_deployment_version_metadata(
             &mut self,
-            request: impl tonic::IntoRequest<super::StartBatchOperationRequest>,
+            request: impl tonic::IntoRequest<
+                super::UpdateWorkerDeploymentVersionMetadataRequest,
+            >,
         ) -> std::result::Result<
-            tonic::Response<super::StartBatchOperationResponse>,
+            tonic::Response<super::UpdateWorkerDeploymentVersionMetadataResponse>,
             tonic::Status,
         > {
             self.inner
@@ -6668,22 +7496,149 @@ pub mod workflow_service_client {
                 })?;
             let codec = tonic::codec::ProstCodec::default();
             let path = http::uri::PathAndQuery::from_static(
-                "/temporal.api.workflowservice.v1.WorkflowService/StartBatchOperation",
+                "/temporal.api.workflowservice.v1.WorkflowService/UpdateWorkerDeploymentVersionMetadata",
             );
             let mut req = request.into_request();
             req.extensions_mut()
                 .insert(
                     GrpcMethod::new(
                         "temporal.api.workflowservice.v1.WorkflowService",
-                        "StartBatchOperation",
+                        "UpdateWorkerDeploymentVersionMetadata",
                     ),
                 );
             self.inner.unary(req, path, codec).await
         }
-        /// StopBatchOperation stops a batch operation
-        pub async fn stop_batch_operation(
+        /// Set/unset the ManagerIdentity of a Worker Deployment.
+        /// Experimental. This API might significantly change or be removed in a future release.
+        pub async fn set_worker_deployment_manager(
             &mut self,
-            request: impl tonic::IntoRequest<super::StopBatchOperationRequest>,
+            request: impl tonic::IntoRequest<super::SetWorkerDeploymentManagerRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::SetWorkerDeploymentManagerResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/temporal.api.workflowservice.v1.WorkflowService/SetWorkerDeploymentManager",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "temporal.api.workflowservice.v1.WorkflowService",
+                        "SetWorkerDeploymentManager",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+        /// Invokes the specified Update function on user Workflow code.
+        pub async fn update_workflow_execution(
+            &mut self,
+            request: impl tonic::IntoRequest<super::UpdateWorkflowExecutionRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::UpdateWorkflowExecutionResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/temporal.api.workflowservice.v1.WorkflowService/UpdateWorkflowExecution",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "temporal.api.workflowservice.v1.WorkflowService",
+                        "UpdateWorkflowExecution",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+        /// Polls a Workflow Execution for the outcome of a Workflow Update
+        /// previously issued through the UpdateWorkflowExecution RPC. The effective
+        /// timeout on this call will be shorter of the the caller-supplied gRPC
+        /// timeout and the server's configured long-poll timeout.
+        ///
+        /// (-- api-linter: core::0127::http-annotation=disabled
+        ///     aip.dev/not-precedent: We don't expose update polling API to HTTP in favor of a potential future non-blocking form. --)
+        pub async fn poll_workflow_execution_update(
+            &mut self,
+            request: impl tonic::IntoRequest<super::PollWorkflowExecutionUpdateRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::PollWorkflowExecutionUpdateResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/temporal.api.workflowservice.v1.WorkflowService/PollWorkflowExecutionUpdate",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "temporal.api.workflowservice.v1.WorkflowService",
+                        "PollWorkflowExecutionUpdate",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+        /// StartBatchOperation starts a new batch operation
+        pub async fn start_batch_operation(
+            &mut self,
+            request: impl tonic::IntoRequest<super::StartBatchOperationRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::StartBatchOperationResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/temporal.api.workflowservice.v1.WorkflowService/StartBatchOperation",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "temporal.api.workflowservice.v1.WorkflowService",
+                        "StartBatchOperation",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+        /// StopBatchOperation stops a batch operation
+        pub async fn stop_batch_operation(
+            &mut self,
+            request: impl tonic::IntoRequest<super::StopBatchOperationRequest>,
         ) -> std::result::Result<
             tonic::Response<super::StopBatchOperationResponse>,
             tonic::Status,
@@ -7517,6 +8472,39 @@ pub mod workflow_service_client {
                 );
             self.inner.unary(req, path, codec).await
         }
+        /// StartNexusOperationExecution starts a new Nexus operation.
+        ///
+        /// Returns a `NexusOperationExecutionAlreadyStarted` error if an instance already exists with same operation ID in this
+        /// namespace unless permitted by the specified ID conflict policy.
+        pub async fn start_nexus_operation_execution(
+            &mut self,
+            request: impl tonic::IntoRequest<super::StartNexusOperationExecutionRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::StartNexusOperationExecutionResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/temporal.api.workflowservice.v1.WorkflowService/StartNexusOperationExecution",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "temporal.api.workflowservice.v1.WorkflowService",
+                        "StartNexusOperationExecution",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
+        }
         /// DescribeActivityExecution returns information about an activity execution.
         /// It can be used to:
         /// - Get current activity info without waiting
@@ -7551,6 +8539,42 @@ pub mod workflow_service_client {
                 );
             self.inner.unary(req, path, codec).await
         }
+        /// DescribeNexusOperationExecution returns information about a Nexus operation.
+        /// Supported use cases include:
+        /// - Get current operation info without waiting
+        /// - Long-poll for next state change and return new operation info
+        /// Response can optionally include operation input or outcome (if the operation has completed).
+        pub async fn describe_nexus_operation_execution(
+            &mut self,
+            request: impl tonic::IntoRequest<
+                super::DescribeNexusOperationExecutionRequest,
+            >,
+        ) -> std::result::Result<
+            tonic::Response<super::DescribeNexusOperationExecutionResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/temporal.api.workflowservice.v1.WorkflowService/DescribeNexusOperationExecution",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "temporal.api.workflowservice.v1.WorkflowService",
+                        "DescribeNexusOperationExecution",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
+        }
         /// PollActivityExecution long-polls for an activity execution to complete and returns the
         /// outcome (result or failure).
         pub async fn poll_activity_execution(
@@ -7582,6 +8606,37 @@ pub mod workflow_service_client {
                 );
             self.inner.unary(req, path, codec).await
         }
+        /// PollNexusOperationExecution long-polls for a Nexus operation for a given wait stage to complete and returns
+        /// the outcome (result or failure).
+        pub async fn poll_nexus_operation_execution(
+            &mut self,
+            request: impl tonic::IntoRequest<super::PollNexusOperationExecutionRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::PollNexusOperationExecutionResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/temporal.api.workflowservice.v1.WorkflowService/PollNexusOperationExecution",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "temporal.api.workflowservice.v1.WorkflowService",
+                        "PollNexusOperationExecution",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
+        }
         /// ListActivityExecutions is a visibility API to list activity executions in a specific namespace.
         pub async fn list_activity_executions(
             &mut self,
@@ -7612,6 +8667,36 @@ pub mod workflow_service_client {
                 );
             self.inner.unary(req, path, codec).await
         }
+        /// ListNexusOperationExecutions is a visibility API to list Nexus operations in a specific namespace.
+        pub async fn list_nexus_operation_executions(
+            &mut self,
+            request: impl tonic::IntoRequest<super::ListNexusOperationExecutionsRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ListNexusOperationExecutionsResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/temporal.api.workflowservice.v1.WorkflowService/ListNexusOperationExecutions",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "temporal.api.workflowservice.v1.WorkflowService",
+                        "ListNexusOperationExecutions",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
+        }
         /// CountActivityExecutions is a visibility API to count activity executions in a specific namespace.
         pub async fn count_activity_executions(
             &mut self,
@@ -7642,6 +8727,36 @@ pub mod workflow_service_client {
                 );
             self.inner.unary(req, path, codec).await
         }
+        /// CountNexusOperationExecutions is a visibility API to count Nexus operations in a specific namespace.
+        pub async fn count_nexus_operation_executions(
+            &mut self,
+            request: impl tonic::IntoRequest<super::CountNexusOperationExecutionsRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::CountNexusOperationExecutionsResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/temporal.api.workflowservice.v1.WorkflowService/CountNexusOperationExecutions",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "temporal.api.workflowservice.v1.WorkflowService",
+                        "CountNexusOperationExecutions",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
+        }
         /// RequestCancelActivityExecution requests cancellation of an activity execution.
         ///
         /// Cancellation is cooperative: this call records the request, but the activity must detect and
@@ -7679,6 +8794,42 @@ pub mod workflow_service_client {
                 );
             self.inner.unary(req, path, codec).await
         }
+        /// RequestCancelNexusOperationExecution requests cancellation of a Nexus operation.
+        ///
+        /// Requesting to cancel an operation does not automatically transition the operation to canceled status.
+        /// The operation will only transition to canceled status if it supports cancellation and the handler
+        /// processes the cancellation request.
+        pub async fn request_cancel_nexus_operation_execution(
+            &mut self,
+            request: impl tonic::IntoRequest<
+                super::RequestCancelNexusOperationExecutionRequest,
+            >,
+        ) -> std::result::Result<
+            tonic::Response<super::RequestCancelNexusOperationExecutionResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/temporal.api.workflowservice.v1.WorkflowService/RequestCancelNexusOperationExecution",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "temporal.api.workflowservice.v1.WorkflowService",
+                        "RequestCancelNexusOperationExecution",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
+        }
         /// TerminateActivityExecution terminates an existing activity execution immediately.
         ///
         /// Termination does not reach the worker and the activity code cannot react to it. A terminated activity may have a
@@ -7748,5 +8899,228 @@ pub mod workflow_service_client {
                 );
             self.inner.unary(req, path, codec).await
         }
+        /// PauseActivityExecution pauses the execution of an activity specified by its ID.
+        /// This API can be used to target a workflow activity or a standalone activity
+        ///
+        /// Pausing an activity means:
+        /// - If the activity is currently waiting for a retry or is running and subsequently fails,
+        ///   it will not be rescheduled until it is unpaused.
+        /// - If the activity is already paused, calling this method will have no effect.
+        /// - If the activity is running and finishes successfully, the activity will be completed.
+        /// - If the activity is running and finishes with failure:
+        ///   * if there is no retry left - the activity will be completed.
+        ///   * if there are more retries left - the activity will be paused.
+        /// For long-running activities:
+        /// - activities in paused state will send a cancellation with "activity_paused" set to 'true' in response to 'RecordActivityTaskHeartbeat'.
+        ///
+        /// Returns a `NotFound` error if there is no pending activity with the provided ID
+        pub async fn pause_activity_execution(
+            &mut self,
+            request: impl tonic::IntoRequest<super::PauseActivityExecutionRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::PauseActivityExecutionResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/temporal.api.workflowservice.v1.WorkflowService/PauseActivityExecution",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "temporal.api.workflowservice.v1.WorkflowService",
+                        "PauseActivityExecution",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+        /// ResetActivityExecution resets the execution of an activity specified by its ID.
+        /// This API can be used to target a workflow activity or a standalone activity.
+        ///
+        /// Resetting an activity means:
+        /// * number of attempts will be reset to 0.
+        /// * activity timeouts will be reset.
+        /// * if the activity is waiting for retry, and it is not paused or 'keep_paused' is not provided:
+        ///    it will be scheduled immediately (* see 'jitter' flag)
+        ///
+        /// Returns a `NotFound` error if there is no pending activity with the provided ID or type.
+        pub async fn reset_activity_execution(
+            &mut self,
+            request: impl tonic::IntoRequest<super::ResetActivityExecutionRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ResetActivityExecutionResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/temporal.api.workflowservice.v1.WorkflowService/ResetActivityExecution",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "temporal.api.workflowservice.v1.WorkflowService",
+                        "ResetActivityExecution",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+        /// UnpauseActivityExecution unpauses the execution of an activity specified by its ID.
+        /// This API can be used to target a workflow activity or a standalone activity.
+        ///
+        /// If activity is not paused, this call will have no effect.
+        /// If the activity was paused while waiting for retry, it will be scheduled immediately (* see 'jitter' flag).
+        /// Once the activity is unpaused, all timeout timers will be regenerated.
+        ///
+        /// Returns a `NotFound` error if there is no pending activity with the provided ID
+        pub async fn unpause_activity_execution(
+            &mut self,
+            request: impl tonic::IntoRequest<super::UnpauseActivityExecutionRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::UnpauseActivityExecutionResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/temporal.api.workflowservice.v1.WorkflowService/UnpauseActivityExecution",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "temporal.api.workflowservice.v1.WorkflowService",
+                        "UnpauseActivityExecution",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+        /// UpdateActivityExecutionOptions is called by the client to update the options of an activity by its ID.
+        /// This API can be used to target a workflow activity or a standalone activity.
+        pub async fn update_activity_execution_options(
+            &mut self,
+            request: impl tonic::IntoRequest<
+                super::UpdateActivityExecutionOptionsRequest,
+            >,
+        ) -> std::result::Result<
+            tonic::Response<super::UpdateActivityExecutionOptionsResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/temporal.api.workflowservice.v1.WorkflowService/UpdateActivityExecutionOptions",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "temporal.api.workflowservice.v1.WorkflowService",
+                        "UpdateActivityExecutionOptions",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+        /// TerminateNexusOperationExecution terminates an existing Nexus operation immediately.
+        ///
+        /// Termination happens immediately and the operation handler cannot react to it. A terminated operation will have
+        /// its outcome set to a failure with a termination reason.
+        pub async fn terminate_nexus_operation_execution(
+            &mut self,
+            request: impl tonic::IntoRequest<
+                super::TerminateNexusOperationExecutionRequest,
+            >,
+        ) -> std::result::Result<
+            tonic::Response<super::TerminateNexusOperationExecutionResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/temporal.api.workflowservice.v1.WorkflowService/TerminateNexusOperationExecution",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "temporal.api.workflowservice.v1.WorkflowService",
+                        "TerminateNexusOperationExecution",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+        /// DeleteNexusOperationExecution asynchronously deletes a specific Nexus operation run (when
+        /// run_id is provided) or the latest run (when run_id is not provided). If the operation
+        /// is running, it will be terminated before deletion.
+        ///
+        /// (-- api-linter: core::0127::http-annotation=disabled
+        ///     aip.dev/not-precedent: Nexus operation deletion not exposed to HTTP, users should use cancel or terminate. --)
+        pub async fn delete_nexus_operation_execution(
+            &mut self,
+            request: impl tonic::IntoRequest<super::DeleteNexusOperationExecutionRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::DeleteNexusOperationExecutionResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/temporal.api.workflowservice.v1.WorkflowService/DeleteNexusOperationExecution",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "temporal.api.workflowservice.v1.WorkflowService",
+                        "DeleteNexusOperationExecution",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
+        }
     }
 }
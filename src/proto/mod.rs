@@ -5,6 +5,13 @@
 pub mod google {
     pub mod api {
         include!("generated/google.api.rs");
+        include!("generated/google.api.serde.rs");
+    }
+}
+
+pub mod nexusannotations {
+    pub mod v1 {
+        include!("generated/nexusannotations.v1.rs");
     }
 }
 
@@ -13,120 +20,168 @@ pub mod temporal {
         pub mod activity {
             pub mod v1 {
                 include!("generated/temporal.api.activity.v1.rs");
+                include!("generated/temporal.api.activity.v1.serde.rs");
             }
         }
         pub mod batch {
             pub mod v1 {
                 include!("generated/temporal.api.batch.v1.rs");
+                include!("generated/temporal.api.batch.v1.serde.rs");
+            }
+        }
+        pub mod callback {
+            pub mod v1 {
+                include!("generated/temporal.api.callback.v1.rs");
+                include!("generated/temporal.api.callback.v1.serde.rs");
             }
         }
         pub mod command {
             pub mod v1 {
                 include!("generated/temporal.api.command.v1.rs");
+                include!("generated/temporal.api.command.v1.serde.rs");
             }
         }
         pub mod common {
             pub mod v1 {
                 include!("generated/temporal.api.common.v1.rs");
+                include!("generated/temporal.api.common.v1.serde.rs");
+            }
+        }
+        pub mod compute {
+            pub mod v1 {
+                include!("generated/temporal.api.compute.v1.rs");
+                include!("generated/temporal.api.compute.v1.serde.rs");
             }
         }
         pub mod deployment {
             pub mod v1 {
                 include!("generated/temporal.api.deployment.v1.rs");
+                include!("generated/temporal.api.deployment.v1.serde.rs");
             }
         }
         pub mod enums {
             pub mod v1 {
                 include!("generated/temporal.api.enums.v1.rs");
+                include!("generated/temporal.api.enums.v1.serde.rs");
             }
         }
         pub mod failure {
             pub mod v1 {
                 include!("generated/temporal.api.failure.v1.rs");
+                include!("generated/temporal.api.failure.v1.serde.rs");
             }
         }
         pub mod filter {
             pub mod v1 {
                 include!("generated/temporal.api.filter.v1.rs");
+                include!("generated/temporal.api.filter.v1.serde.rs");
             }
         }
         pub mod history {
             pub mod v1 {
                 include!("generated/temporal.api.history.v1.rs");
+                include!("generated/temporal.api.history.v1.serde.rs");
             }
         }
         pub mod namespace {
             pub mod v1 {
                 include!("generated/temporal.api.namespace.v1.rs");
+                include!("generated/temporal.api.namespace.v1.serde.rs");
             }
         }
         pub mod nexus {
             pub mod v1 {
                 include!("generated/temporal.api.nexus.v1.rs");
+                include!("generated/temporal.api.nexus.v1.serde.rs");
+            }
+        }
+        pub mod operatorservice {
+            pub mod v1 {
+                include!("generated/temporal.api.operatorservice.v1.rs");
+                include!("generated/temporal.api.operatorservice.v1.serde.rs");
             }
         }
         pub mod protocol {
             pub mod v1 {
                 include!("generated/temporal.api.protocol.v1.rs");
+                include!("generated/temporal.api.protocol.v1.serde.rs");
+            }
+        }
+        pub mod protometa {
+            pub mod v1 {
+                include!("generated/temporal.api.protometa.v1.rs");
+                include!("generated/temporal.api.protometa.v1.serde.rs");
             }
         }
         pub mod query {
             pub mod v1 {
                 include!("generated/temporal.api.query.v1.rs");
+                include!("generated/temporal.api.query.v1.serde.rs");
             }
         }
         pub mod replication {
             pub mod v1 {
                 include!("generated/temporal.api.replication.v1.rs");
+                include!("generated/temporal.api.replication.v1.serde.rs");
             }
         }
         pub mod rules {
             pub mod v1 {
                 include!("generated/temporal.api.rules.v1.rs");
+                include!("generated/temporal.api.rules.v1.serde.rs");
             }
         }
         pub mod schedule {
             pub mod v1 {
                 include!("generated/temporal.api.schedule.v1.rs");
+                include!("generated/temporal.api.schedule.v1.serde.rs");
             }
         }
         pub mod sdk {
             pub mod v1 {
                 include!("generated/temporal.api.sdk.v1.rs");
+                include!("generated/temporal.api.sdk.v1.serde.rs");
             }
         }
         pub mod taskqueue {
             pub mod v1 {
                 include!("generated/temporal.api.taskqueue.v1.rs");
+                include!("generated/temporal.api.taskqueue.v1.serde.rs");
             }
         }
         pub mod update {
             pub mod v1 {
                 include!("generated/temporal.api.update.v1.rs");
+                include!("generated/temporal.api.update.v1.serde.rs");
             }
         }
         pub mod version {
             pub mod v1 {
                 include!("generated/temporal.api.version.v1.rs");
+                include!("generated/temporal.api.version.v1.serde.rs");
             }
         }
         pub mod worker {
             pub mod v1 {
                 include!("generated/temporal.api.worker.v1.rs");
+                include!("generated/temporal.api.worker.v1.serde.rs");
             }
         }
         pub mod workflow {
             pub mod v1 {
                 include!("generated/temporal.api.workflow.v1.rs");
+                include!("generated/temporal.api.workflow.v1.serde.rs");
             }
         }
         pub mod workflowservice {
             pub mod v1 {
                 include!("generated/temporal.api.workflowservice.v1.rs");
+                include!("generated/temporal.api.workflowservice.v1.serde.rs");
             }
         }
     }
 }
 
+pub use temporal::api::operatorservice::v1::operator_service_client::OperatorServiceClient;
 pub use temporal::api::workflowservice::v1::workflow_service_client::WorkflowServiceClient;
 pub use temporal::api::workflowservice::v1::*;
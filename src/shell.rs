@@ -0,0 +1,48 @@
+//! `:!<command>` — runs a shell command on the suspended terminal with the
+//! current selection in `T9S_*` environment variables. Also opens links in
+//! the browser.
+
+use std::io::Write;
+use std::process::Stdio;
+
+/// Runs `command` with `sh -c` on the real terminal, then waits for Enter so
+/// its output can be read before the TUI comes back.
+pub async fn run(command: &str, env: &[(String, String)]) -> Result<(), String> {
+    let status = tokio::process::Command::new("sh")
+        .arg("-c")
+        .arg(command)
+        .envs(env.iter().map(|(k, v)| (k, v)))
+        .status()
+        .await
+        .map_err(|e| format!("failed to run {}: {}", command, e))?;
+    let mut stdout = std::io::stdout();
+    if !status.success() {
+        let _ = writeln!(stdout, "\n[{}]", status);
+    }
+    let _ = write!(stdout, "\nPress Enter to return to t9s");
+    let _ = stdout.flush();
+    let _ = tokio::task::spawn_blocking(|| {
+        let mut line = String::new();
+        std::io::stdin().read_line(&mut line)
+    })
+    .await;
+    Ok(())
+}
+
+/// Opens `url` in the default browser with `open` on macOS and `xdg-open`
+/// elsewhere, without waiting for the browser.
+pub fn open_url(url: &str) -> Result<(), String> {
+    let opener = if cfg!(target_os = "macos") {
+        "open"
+    } else {
+        "xdg-open"
+    };
+    tokio::process::Command::new(opener)
+        .arg(url)
+        .stdin(Stdio::null())
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        .spawn()
+        .map(drop)
+        .map_err(|e| format!("failed to run {} {}: {}", opener, url, e))
+}
@@ -1,24 +1,391 @@
-use ratatui::style::Color;
-
-// Backgrounds
-pub const BG_DARK: Color = Color::Rgb(13, 13, 23);
-pub const BG_BAR: Color = Color::Rgb(17, 17, 30);
-pub const BG_SURFACE: Color = Color::Rgb(22, 22, 38);
-pub const BG_HIGHLIGHT: Color = Color::Rgb(30, 30, 58);
-
-// Primary accent (Temporal purple/indigo)
-pub const PURPLE: Color = Color::Rgb(121, 93, 244);
-pub const PURPLE_DIM: Color = Color::Rgb(80, 60, 180);
-
-// Text
-pub const TEXT: Color = Color::Rgb(220, 220, 230);
-pub const TEXT_DIM: Color = Color::Rgb(130, 130, 155);
-pub const TEXT_MUTED: Color = Color::Rgb(75, 75, 100);
-
-// Semantic
-pub const GREEN: Color = Color::Rgb(52, 211, 153);
-pub const BLUE: Color = Color::Rgb(96, 165, 250);
-pub const RED: Color = Color::Rgb(248, 113, 113);
-pub const YELLOW: Color = Color::Rgb(251, 191, 36);
-pub const CYAN: Color = Color::Rgb(103, 232, 249);
-pub const MAGENTA: Color = Color::Rgb(232, 121, 249);
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::sync::{PoisonError, RwLock};
+
+use ratatui::buffer::Buffer;
+use ratatui::style::{Color, Modifier};
+
+/// The UI palette. Widgets read it through [`current`]; it is chosen at
+/// startup from `--theme` or the config file, and again when either is edited.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Theme {
+    // Backgrounds
+    pub bg: Color,
+    pub bg_bar: Color,
+    pub bg_surface: Color,
+    pub bg_highlight: Color,
+
+    // Primary accent (Temporal purple/indigo in the default theme)
+    pub accent: Color,
+    pub accent_dim: Color,
+
+    // Text
+    pub text: Color,
+    pub text_dim: Color,
+    pub text_muted: Color,
+
+    // Semantic
+    pub green: Color,
+    pub blue: Color,
+    pub red: Color,
+    pub yellow: Color,
+    pub cyan: Color,
+    pub magenta: Color,
+}
+
+impl Default for Theme {
+    fn default() -> Self {
+        Self::dark()
+    }
+}
+
+impl Theme {
+    pub const BUILTIN: [&'static str; 3] = ["dark", "light", "high-contrast"];
+
+    pub const fn dark() -> Self {
+        Self {
+            bg: Color::Rgb(13, 13, 23),
+            bg_bar: Color::Rgb(17, 17, 30),
+            bg_surface: Color::Rgb(22, 22, 38),
+            bg_highlight: Color::Rgb(30, 30, 58),
+            accent: Color::Rgb(121, 93, 244),
+            accent_dim: Color::Rgb(80, 60, 180),
+            text: Color::Rgb(220, 220, 230),
+            text_dim: Color::Rgb(130, 130, 155),
+            text_muted: Color::Rgb(75, 75, 100),
+            green: Color::Rgb(52, 211, 153),
+            blue: Color::Rgb(96, 165, 250),
+            red: Color::Rgb(248, 113, 113),
+            yellow: Color::Rgb(251, 191, 36),
+            cyan: Color::Rgb(103, 232, 249),
+            magenta: Color::Rgb(232, 121, 249),
+        }
+    }
+
+    pub const fn light() -> Self {
+        Self {
+            bg: Color::Rgb(250, 250, 252),
+            bg_bar: Color::Rgb(236, 236, 244),
+            bg_surface: Color::Rgb(228, 228, 238),
+            bg_highlight: Color::Rgb(214, 208, 250),
+            accent: Color::Rgb(91, 60, 214),
+            accent_dim: Color::Rgb(140, 120, 220),
+            text: Color::Rgb(28, 28, 40),
+            text_dim: Color::Rgb(85, 85, 110),
+            text_muted: Color::Rgb(135, 135, 155),
+            green: Color::Rgb(4, 120, 87),
+            blue: Color::Rgb(29, 78, 216),
+            red: Color::Rgb(185, 28, 28),
+            yellow: Color::Rgb(161, 98, 7),
+            cyan: Color::Rgb(14, 116, 144),
+            magenta: Color::Rgb(162, 28, 175),
+        }
+    }
+
+    /// Black background, pure text colors and no low-contrast grays.
+    pub const fn high_contrast() -> Self {
+        Self {
+            bg: Color::Rgb(0, 0, 0),
+            bg_bar: Color::Rgb(0, 0, 0),
+            bg_surface: Color::Rgb(0, 0, 0),
+            bg_highlight: Color::Rgb(0, 70, 140),
+            accent: Color::Rgb(190, 170, 255),
+            accent_dim: Color::Rgb(160, 140, 255),
+            text: Color::Rgb(255, 255, 255),
+            text_dim: Color::Rgb(230, 230, 230),
+            text_muted: Color::Rgb(200, 200, 200),
+            green: Color::Rgb(0, 255, 140),
+            blue: Color::Rgb(110, 190, 255),
+            red: Color::Rgb(255, 90, 90),
+            yellow: Color::Rgb(255, 230, 0),
+            cyan: Color::Rgb(0, 255, 255),
+            magenta: Color::Rgb(255, 110, 255),
+        }
+    }
+
+    pub fn builtin(name: &str) -> Option<Self> {
+        match name {
+            "dark" => Some(Self::dark()),
+            "light" => Some(Self::light()),
+            "high-contrast" => Some(Self::high_contrast()),
+            _ => None,
+        }
+    }
+
+    /// Resolves `--theme`: a built-in name, a skin file path, or the name of a
+    /// skin in `<config dir>/t9s/skins/<name>.toml`.
+    pub fn load(spec: &str) -> Result<Self, String> {
+        if let Some(theme) = Self::builtin(spec) {
+            return Ok(theme);
+        }
+        let skin = Self::skin_path(spec).ok_or_else(|| {
+            format!(
+                "unknown theme {:?} (built-in: {}; or a skin file path)",
+                spec,
+                Self::BUILTIN.join(", ")
+            )
+        })?;
+        Self::from_skin_file(&skin)
+    }
+
+    /// The skin file `spec` names, if it isn't a built-in theme and exists.
+    pub fn skin_path(spec: &str) -> Option<PathBuf> {
+        Self::skin_paths(spec).into_iter().find(|p| p.exists())
+    }
+
+    /// Where a skin named by `spec` is looked for, whether or not it exists
+    /// yet: the path itself, then `<config dir>/t9s/skins/<spec>.toml`.
+    pub fn skin_paths(spec: &str) -> Vec<PathBuf> {
+        if Self::builtin(spec).is_some() {
+            return vec![];
+        }
+        std::iter::once(PathBuf::from(spec))
+            .chain(
+                dirs::config_dir()
+                    .map(|dir| dir.join("t9s").join("skins").join(format!("{}.toml", spec))),
+            )
+            .collect()
+    }
+
+    pub fn from_skin_file(path: &Path) -> Result<Self, String> {
+        let content = std::fs::read_to_string(path)
+            .map_err(|e| format!("reading skin {}: {}", path.display(), e))?;
+        Self::from_skin(&content).map_err(|e| format!("skin {}: {}", path.display(), e))
+    }
+
+    /// Parses a skin: an optional `base` built-in theme plus `[colors]`
+    /// overrides as `#rrggbb` or color names, e.g. `accent = "#ff8800"`.
+    pub fn from_skin(content: &str) -> Result<Self, String> {
+        #[derive(serde::Deserialize)]
+        #[serde(deny_unknown_fields)]
+        struct Skin {
+            base: Option<String>,
+            #[serde(default)]
+            colors: HashMap<String, String>,
+        }
+
+        let skin: Skin = toml::from_str(content).map_err(|e| e.to_string())?;
+        let base = skin.base.as_deref().unwrap_or("dark");
+        let mut theme =
+            Self::builtin(base).ok_or_else(|| format!("unknown base theme {:?}", base))?;
+        for (key, value) in &skin.colors {
+            let color: Color = value
+                .parse()
+                .map_err(|_| format!("invalid color {:?} for {}", value, key))?;
+            let slot = match key.as_str() {
+                "bg" => &mut theme.bg,
+                "bg_bar" => &mut theme.bg_bar,
+                "bg_surface" => &mut theme.bg_surface,
+                "bg_highlight" => &mut theme.bg_highlight,
+                "accent" => &mut theme.accent,
+                "accent_dim" => &mut theme.accent_dim,
+                "text" => &mut theme.text,
+                "text_dim" => &mut theme.text_dim,
+                "text_muted" => &mut theme.text_muted,
+                "green" => &mut theme.green,
+                "blue" => &mut theme.blue,
+                "red" => &mut theme.red,
+                "yellow" => &mut theme.yellow,
+                "cyan" => &mut theme.cyan,
+                "magenta" => &mut theme.magenta,
+                _ => return Err(format!("unknown color key {:?}", key)),
+            };
+            *slot = color;
+        }
+        Ok(theme)
+    }
+}
+
+static THEME: RwLock<Theme> = RwLock::new(Theme::dark());
+
+/// Installs the palette, replacing the one in use.
+pub fn set(theme: Theme) {
+    *THEME.write().unwrap_or_else(PoisonError::into_inner) = theme;
+}
+
+/// The active palette; the dark theme until [`set`] is called.
+pub fn current() -> Theme {
+    *THEME.read().unwrap_or_else(PoisonError::into_inner)
+}
+
+/// How many colors the terminal can show. Palettes are truecolor; other
+/// modes remap it when the frame is flushed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, clap::ValueEnum)]
+pub enum ColorMode {
+    #[default]
+    Auto,
+    Truecolor,
+    #[value(name = "256")]
+    Ansi256,
+    #[value(name = "16")]
+    Ansi16,
+    Never,
+}
+
+impl ColorMode {
+    /// Resolves `Auto` from `NO_COLOR`, `COLORTERM` and `TERM`.
+    pub fn resolve(self) -> Self {
+        if self != Self::Auto {
+            return self;
+        }
+        Self::from_env(
+            std::env::var("NO_COLOR").ok().as_deref(),
+            std::env::var("COLORTERM").ok().as_deref(),
+            std::env::var("TERM").ok().as_deref(),
+        )
+    }
+
+    fn from_env(no_color: Option<&str>, colorterm: Option<&str>, term: Option<&str>) -> Self {
+        // https://no-color.org: any non-empty value disables color.
+        if no_color.is_some_and(|v| !v.is_empty()) {
+            return Self::Never;
+        }
+        if matches!(colorterm, Some("truecolor") | Some("24bit")) {
+            return Self::Truecolor;
+        }
+        match term.unwrap_or("") {
+            "dumb" => Self::Never,
+            t if t.contains("truecolor") || t.contains("direct") => Self::Truecolor,
+            t if t.contains("256color") => Self::Ansi256,
+            _ => Self::Ansi16,
+        }
+    }
+}
+
+const ANSI16: [(Color, (u8, u8, u8)); 16] = [
+    (Color::Black, (0, 0, 0)),
+    (Color::Red, (205, 49, 49)),
+    (Color::Green, (13, 188, 121)),
+    (Color::Yellow, (229, 229, 16)),
+    (Color::Blue, (36, 114, 200)),
+    (Color::Magenta, (188, 63, 188)),
+    (Color::Cyan, (17, 168, 205)),
+    (Color::Gray, (204, 204, 204)),
+    (Color::DarkGray, (118, 118, 118)),
+    (Color::LightRed, (241, 76, 76)),
+    (Color::LightGreen, (35, 209, 139)),
+    (Color::LightYellow, (245, 245, 67)),
+    (Color::LightBlue, (59, 142, 234)),
+    (Color::LightMagenta, (214, 112, 214)),
+    (Color::LightCyan, (41, 184, 219)),
+    (Color::White, (229, 229, 229)),
+];
+
+fn to_ansi256(r: u8, g: u8, b: u8) -> Color {
+    // Near-grays use the 24-step ramp, everything else the 6x6x6 cube.
+    if r.abs_diff(g) < 12 && g.abs_diff(b) < 12 && r.abs_diff(b) < 12 {
+        let avg = (r as u16 + g as u16 + b as u16) / 3;
+        if avg < 8 {
+            return Color::Indexed(16);
+        }
+        if avg > 238 {
+            return Color::Indexed(231);
+        }
+        return Color::Indexed(232 + ((avg - 8) * 24 / 231) as u8);
+    }
+    let level = |v: u8| ((v as u16 * 5 + 127) / 255) as u8;
+    Color::Indexed(16 + 36 * level(r) + 6 * level(g) + level(b))
+}
+
+fn to_ansi16(r: u8, g: u8, b: u8) -> Color {
+    let dist = |(pr, pg, pb): (u8, u8, u8)| {
+        let d = |a: u8, b: u8| (a as i32 - b as i32).pow(2);
+        d(r, pr) + d(g, pg) + d(b, pb)
+    };
+    ANSI16
+        .iter()
+        .min_by_key(|(_, rgb)| dist(*rgb))
+        .map(|(c, _)| *c)
+        .unwrap_or(Color::Reset)
+}
+
+/// Maps one color into `mode`'s palette.
+pub fn adapt(color: Color, mode: ColorMode) -> Color {
+    match (color, mode) {
+        (_, ColorMode::Never) => Color::Reset,
+        (Color::Rgb(r, g, b), ColorMode::Ansi256) => to_ansi256(r, g, b),
+        (Color::Rgb(r, g, b), ColorMode::Ansi16) => to_ansi16(r, g, b),
+        (c, _) => c,
+    }
+}
+
+/// Rewrites every cell of a rendered frame for terminals without truecolor.
+/// Dark backgrounds fall back to the terminal default, and the selection
+/// background becomes reverse video so highlighted rows stay visible.
+pub fn adapt_buffer(buf: &mut Buffer, mode: ColorMode) {
+    if matches!(mode, ColorMode::Truecolor | ColorMode::Auto) {
+        return;
+    }
+    for cell in buf.content.iter_mut() {
+        if cell.bg == current().bg_highlight && mode != ColorMode::Ansi256 {
+            cell.modifier.insert(Modifier::REVERSED);
+            cell.bg = Color::Reset;
+        } else if mode == ColorMode::Ansi16 && matches!(cell.bg, Color::Rgb(..)) {
+            cell.bg = match adapt(cell.bg, mode) {
+                Color::Black => Color::Reset,
+                c => c,
+            };
+        } else {
+            cell.bg = adapt(cell.bg, mode);
+        }
+        cell.fg = adapt(cell.fg, mode);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn missing_skins_are_still_looked_for() {
+        assert!(Theme::skin_paths("dark").is_empty());
+        let paths = Theme::skin_paths("/nonexistent/skin.toml");
+        assert_eq!(paths[0], PathBuf::from("/nonexistent/skin.toml"));
+        assert!(Theme::skin_path("/nonexistent/skin.toml").is_none());
+    }
+
+    #[test]
+    fn color_mode_detection_and_downgrade() {
+        assert_eq!(
+            ColorMode::from_env(Some("1"), Some("truecolor"), Some("xterm-256color")),
+            ColorMode::Never
+        );
+        assert_eq!(
+            ColorMode::from_env(Some(""), Some("truecolor"), None),
+            ColorMode::Truecolor
+        );
+        assert_eq!(
+            ColorMode::from_env(None, None, Some("xterm-256color")),
+            ColorMode::Ansi256
+        );
+        assert_eq!(
+            ColorMode::from_env(None, None, Some("linux")),
+            ColorMode::Ansi16
+        );
+
+        // Muted text must not collapse to black in 16-color terminals.
+        let dark = Theme::dark();
+        assert_eq!(adapt(dark.text_muted, ColorMode::Ansi16), Color::DarkGray);
+        assert_eq!(adapt(dark.red, ColorMode::Ansi16), Color::LightRed);
+        assert_eq!(adapt(dark.bg, ColorMode::Ansi256), Color::Indexed(232));
+    }
+
+    #[test]
+    fn skin_overrides_base_theme() {
+        let theme = Theme::from_skin(
+            r##"
+            base = "light"
+            [colors]
+            accent = "#ff8800"
+            red = "lightred"
+            "##,
+        )
+        .unwrap();
+        assert_eq!(theme.accent, Color::Rgb(255, 136, 0));
+        assert_eq!(theme.red, Color::LightRed);
+        assert_eq!(theme.bg, Theme::light().bg);
+
+        assert!(Theme::from_skin("[colors]\nbackground = \"#000000\"").is_err());
+        assert!(Theme::from_skin("base = \"solarized\"").is_err());
+    }
+}
@@ -10,8 +10,9 @@ use ratatui::Terminal;
 
 pub type Tui = Terminal<CrosstermBackend<io::Stdout>>;
 
-pub fn init() -> io::Result<Tui> {
-    execute!(io::stdout(), EnterAlternateScreen, EnableMouseCapture)?;
+pub fn init(mouse_capture: bool) -> io::Result<Tui> {
+    execute!(io::stdout(), EnterAlternateScreen)?;
+    set_mouse_capture(mouse_capture)?;
     enable_raw_mode()?;
     let backend = CrosstermBackend::new(io::stdout());
     let terminal = Terminal::new(backend)?;
@@ -31,3 +32,46 @@ pub fn restore() -> io::Result<()> {
     execute!(io::stdout(), LeaveAlternateScreen, DisableMouseCapture)?;
     Ok(())
 }
+
+/// Restores the terminal when dropped, so every way out of the UI,
+/// including an early `?`, leaves the shell usable.
+pub struct RestoreGuard;
+
+impl Drop for RestoreGuard {
+    fn drop(&mut self) {
+        let _ = restore();
+    }
+}
+
+/// Hands the terminal over to another program, such as `$EDITOR`.
+pub fn suspend() -> io::Result<()> {
+    restore()
+}
+
+/// Takes the terminal back after [`suspend`].
+pub fn resume(terminal: &mut Tui, mouse_capture: bool) -> io::Result<()> {
+    execute!(io::stdout(), EnterAlternateScreen)?;
+    set_mouse_capture(mouse_capture)?;
+    enable_raw_mode()?;
+    terminal.clear()
+}
+
+/// Puts `text` on the clipboard with an OSC 52 escape, which most terminals
+/// (and tmux with `set-clipboard on`) honor, including over SSH.
+pub fn copy_to_clipboard(text: &str) -> io::Result<()> {
+    use base64::Engine;
+    use std::io::Write;
+
+    let encoded = base64::engine::general_purpose::STANDARD.encode(text);
+    let mut stdout = io::stdout();
+    write!(stdout, "\x1b]52;c;{}\x07", encoded)?;
+    stdout.flush()
+}
+
+pub fn set_mouse_capture(enabled: bool) -> io::Result<()> {
+    if enabled {
+        execute!(io::stdout(), EnableMouseCapture)
+    } else {
+        execute!(io::stdout(), DisableMouseCapture)
+    }
+}
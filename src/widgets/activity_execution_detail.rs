@@ -5,16 +5,18 @@ use ratatui::widgets::{Block, Borders, Paragraph, Wrap};
 use ratatui::Frame;
 
 use crate::app::App;
+use crate::i18n::{t, Msg};
 use crate::kinds::detail_tabs_for_kind;
 use crate::theme;
+use crate::widgets::task_queue;
 
 pub fn render(app: &App, frame: &mut Frame, area: Rect) {
     let detail = match &app.activity_execution_detail {
         crate::app::LoadState::Loaded(d) => d,
         crate::app::LoadState::Loading | crate::app::LoadState::NotLoaded => {
             frame.render_widget(
-                Paragraph::new(" Loading activity detail...")
-                    .style(Style::default().fg(theme::TEXT_MUTED)),
+                Paragraph::new(t(Msg::LoadingActivityDetail))
+                    .style(Style::default().fg(theme::current().text_muted)),
                 area,
             );
             return;
@@ -22,7 +24,7 @@ pub fn render(app: &App, frame: &mut Frame, area: Rect) {
         crate::app::LoadState::Error(err) => {
             frame.render_widget(
                 Paragraph::new(format!(" Failed to load activity detail: {}", err))
-                    .style(Style::default().fg(theme::RED)),
+                    .style(Style::default().fg(theme::current().red)),
                 area,
             );
             return;
@@ -36,10 +38,10 @@ pub fn render(app: &App, frame: &mut Frame, area: Rect) {
     for (i, tab) in tabs.iter().enumerate() {
         let style = if i == app.activity_detail_tab {
             Style::default()
-                .fg(theme::PURPLE)
+                .fg(theme::current().accent)
                 .add_modifier(Modifier::BOLD | Modifier::UNDERLINED)
         } else {
-            Style::default().fg(theme::TEXT_MUTED)
+            Style::default().fg(theme::current().text_muted)
         };
         tab_spans.push(Span::styled(format!(" {} ", tab), style));
         tab_spans.push(Span::raw(" "));
@@ -48,14 +50,15 @@ pub fn render(app: &App, frame: &mut Frame, area: Rect) {
 
     let scroll = app.detail_scroll;
     match app.activity_detail_tab {
-        0 => render_summary(detail, frame, layout[1], scroll),
-        1 => render_io(detail, frame, layout[1], scroll),
+        0 => render_summary(app, detail, frame, layout[1], scroll),
+        1 => render_io(app, detail, frame, layout[1], scroll),
         2 => render_task_queue(app, detail, frame, layout[1], scroll),
         _ => {}
     }
 }
 
 fn render_summary(
+    app: &App,
     detail: &crate::domain::ActivityExecutionDetail,
     frame: &mut Frame,
     area: Rect,
@@ -64,19 +67,19 @@ fn render_summary(
     let summary = &detail.summary;
     let schedule_time = summary
         .schedule_time
-        .map(|t| format_time(&t))
+        .map(|t| app.time_display.format(&t))
         .unwrap_or_else(|| "-".to_string());
     let close_time = summary
         .close_time
-        .map(|t| format_time(&t))
+        .map(|t| app.time_display.format(&t))
         .unwrap_or_else(|| "-".to_string());
     let last_started = detail
         .last_started_time
-        .map(|t| format_time(&t))
+        .map(|t| app.time_display.format(&t))
         .unwrap_or_else(|| "-".to_string());
     let last_heartbeat = detail
         .last_heartbeat_time
-        .map(|t| format_time(&t))
+        .map(|t| app.time_display.format(&t))
         .unwrap_or_else(|| "-".to_string());
     let attempt = detail.attempt.to_string();
     let schedule_to_close = format_duration(detail.schedule_to_close_timeout);
@@ -119,6 +122,7 @@ fn render_summary(
 }
 
 fn render_io(
+    app: &App,
     detail: &crate::domain::ActivityExecutionDetail,
     frame: &mut Frame,
     area: Rect,
@@ -129,27 +133,29 @@ fn render_io(
     lines.push(Line::from(Span::styled(
         " Input:",
         Style::default()
-            .fg(theme::PURPLE)
+            .fg(theme::current().accent)
             .add_modifier(Modifier::BOLD),
     )));
-    render_json_value(&mut lines, detail.input.as_ref());
+    render_json_value(&mut lines, detail.input.as_ref().map(|v| app.masked(v)));
     lines.push(Line::from(""));
 
     lines.push(Line::from(Span::styled(
         " Output:",
         Style::default()
-            .fg(theme::GREEN)
+            .fg(theme::current().green)
             .add_modifier(Modifier::BOLD),
     )));
-    render_json_value(&mut lines, detail.output.as_ref());
+    render_json_value(&mut lines, detail.output.as_ref().map(|v| app.masked(v)));
 
     if detail.failure.is_some() {
         lines.push(Line::from(""));
         lines.push(Line::from(Span::styled(
             " Failure:",
-            Style::default().fg(theme::RED).add_modifier(Modifier::BOLD),
+            Style::default()
+                .fg(theme::current().red)
+                .add_modifier(Modifier::BOLD),
         )));
-        render_json_value(&mut lines, detail.failure.as_ref());
+        render_json_value(&mut lines, detail.failure.as_ref().map(|v| app.masked(v)));
     }
 
     frame.render_widget(
@@ -170,47 +176,7 @@ fn render_task_queue(
 ) {
     match &app.activity_execution_task_queue {
         crate::app::LoadState::Loaded(tq) => {
-            let pollers_count = tq.pollers.len().to_string();
-            let mut lines = vec![
-                field_line("Task Queue", &tq.name),
-                field_line("Pollers", &pollers_count),
-                Line::from(""),
-            ];
-
-            if tq.pollers.is_empty() {
-                lines.push(Line::from(Span::styled(
-                    " No pollers",
-                    Style::default().fg(theme::TEXT_MUTED),
-                )));
-            } else {
-                lines.push(Line::from(Span::styled(
-                    " Pollers:",
-                    Style::default()
-                        .fg(theme::PURPLE)
-                        .add_modifier(Modifier::BOLD),
-                )));
-                for p in &tq.pollers {
-                    let last_access = p
-                        .last_access_time
-                        .map(|t| format_time(&t))
-                        .unwrap_or_else(|| "-".to_string());
-                    lines.push(Line::from(vec![
-                        Span::styled("   ", Style::default()),
-                        Span::styled(
-                            format!("{:<40} ", p.identity),
-                            Style::default().fg(theme::TEXT),
-                        ),
-                        Span::styled(
-                            format!("last:{:<20} ", last_access),
-                            Style::default().fg(theme::TEXT_MUTED),
-                        ),
-                        Span::styled(
-                            format!("rate:{:.1}/s", p.rate_per_second),
-                            Style::default().fg(theme::TEXT_MUTED),
-                        ),
-                    ]));
-                }
-            }
+            let lines = task_queue::lines(app, tq, None);
 
             frame.render_widget(
                 Paragraph::new(lines)
@@ -222,8 +188,8 @@ fn render_task_queue(
         }
         crate::app::LoadState::Loading => {
             frame.render_widget(
-                Paragraph::new(" Loading task queue info...")
-                    .style(Style::default().fg(theme::TEXT_MUTED)),
+                Paragraph::new(t(Msg::LoadingTaskQueue))
+                    .style(Style::default().fg(theme::current().text_muted)),
                 area,
             );
         }
@@ -233,26 +199,26 @@ fn render_task_queue(
                     " Task queue: {} (press Tab or 'l' to load)",
                     detail.summary.task_queue
                 ))
-                .style(Style::default().fg(theme::TEXT_MUTED)),
+                .style(Style::default().fg(theme::current().text_muted)),
                 area,
             );
         }
     }
 }
 
-fn render_json_value(lines: &mut Vec<Line<'_>>, value: Option<&serde_json::Value>) {
+fn render_json_value(lines: &mut Vec<Line<'_>>, value: Option<serde_json::Value>) {
     if let Some(value) = value {
-        let formatted = serde_json::to_string_pretty(value).unwrap_or_else(|_| value.to_string());
+        let formatted = serde_json::to_string_pretty(&value).unwrap_or_else(|_| value.to_string());
         for line in formatted.lines() {
             lines.push(Line::from(Span::styled(
                 format!("   {}", line),
-                Style::default().fg(theme::TEXT),
+                Style::default().fg(theme::current().text),
             )));
         }
     } else {
         lines.push(Line::from(Span::styled(
             "   (none)",
-            Style::default().fg(theme::TEXT_MUTED),
+            Style::default().fg(theme::current().text_muted),
         )));
     }
 }
@@ -262,18 +228,16 @@ fn field_line<'a>(label: &'a str, value: &'a str) -> Line<'a> {
         Span::styled(
             format!(" {:<20} ", label),
             Style::default()
-                .fg(theme::PURPLE)
+                .fg(theme::current().accent)
                 .add_modifier(Modifier::BOLD),
         ),
-        Span::styled(value.to_string(), Style::default().fg(theme::TEXT)),
+        Span::styled(
+            value.to_string(),
+            Style::default().fg(theme::current().text),
+        ),
     ])
 }
 
-fn format_time(dt: &chrono::DateTime<chrono::Utc>) -> String {
-    let local = dt.with_timezone(&chrono::Local);
-    local.format("%Y-%m-%d %H:%M:%S").to_string()
-}
-
 fn format_duration(d: Option<std::time::Duration>) -> String {
     d.map(|v| {
         if v.subsec_nanos() == 0 {
@@ -0,0 +1,82 @@
+use ratatui::layout::Rect;
+use ratatui::style::Style;
+use ratatui::text::{Line, Span};
+use ratatui::widgets::{Block, Borders, Clear, Paragraph, Wrap};
+use ratatui::Frame;
+
+use crate::app::App;
+use crate::theme;
+use crate::widgets::centered_rect;
+
+pub fn render(app: &App, frame: &mut Frame, area: Rect) {
+    let lines: Vec<Line> = if app.audit_log.is_empty() {
+        vec![Line::from(Span::styled(
+            " No operations this session",
+            Style::default().fg(theme::current().text_muted),
+        ))]
+    } else {
+        app.audit_log
+            .iter()
+            .rev()
+            .map(|entry| {
+                let (mark, color) = match entry.error {
+                    None => ("✓", theme::current().green),
+                    Some(_) => ("✗", theme::current().red),
+                };
+                let mut spans = vec![
+                    Span::styled(
+                        format!(
+                            " {} ",
+                            entry.time.with_timezone(&chrono::Local).format("%H:%M:%S")
+                        ),
+                        Style::default().fg(theme::current().text_muted),
+                    ),
+                    Span::styled(format!("{} ", mark), Style::default().fg(color)),
+                    Span::styled(
+                        format!("{} ", entry.operation),
+                        Style::default().fg(theme::current().text),
+                    ),
+                    Span::styled(
+                        entry.target.clone(),
+                        Style::default().fg(theme::current().cyan),
+                    ),
+                    Span::styled(
+                        format!("  ns:{}", entry.namespace),
+                        Style::default().fg(theme::current().text_muted),
+                    ),
+                ];
+                if let Some(ref error) = entry.error {
+                    spans.push(Span::styled(
+                        format!("  {}", error),
+                        Style::default().fg(theme::current().red),
+                    ));
+                }
+                Line::from(spans)
+            })
+            .collect()
+    };
+
+    let height = area.height.saturating_sub(4).max(3);
+    let modal_area = centered_rect(80, height, area);
+    frame.render_widget(Clear, modal_area);
+
+    let file = app
+        .audit_file
+        .as_ref()
+        .map(|path| format!(", also in {}", path.display()))
+        .unwrap_or_default();
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(theme::current().accent))
+        .title(format!(
+            " Audit: {} operations, newest first{} (Esc to close) ",
+            app.audit_log.len(),
+            file
+        ));
+
+    let paragraph = Paragraph::new(lines)
+        .block(block)
+        .wrap(Wrap { trim: false })
+        .scroll((app.logs_scroll, 0));
+    frame.render_widget(paragraph, modal_area);
+}
@@ -0,0 +1,71 @@
+use ratatui::layout::Rect;
+use ratatui::style::{Modifier, Style};
+use ratatui::text::{Line, Span};
+use ratatui::widgets::{Block, Borders, Clear, Paragraph};
+use ratatui::Frame;
+
+use crate::app::App;
+use crate::theme;
+use crate::widgets::centered_rect;
+
+pub fn render(app: &App, frame: &mut Frame, area: Rect) {
+    let width = app
+        .bookmarks
+        .iter()
+        .map(|b| b.name.chars().count())
+        .max()
+        .unwrap_or(0);
+    let mut lines: Vec<Line> = app
+        .bookmarks
+        .iter()
+        .enumerate()
+        .map(|(i, bookmark)| {
+            let line = Line::from(vec![
+                Span::styled(
+                    format!(" {:<width$}  ", bookmark.name),
+                    Style::default()
+                        .fg(theme::current().accent)
+                        .add_modifier(Modifier::BOLD),
+                ),
+                Span::styled(
+                    bookmark
+                        .uri
+                        .strip_prefix("temporal://tui/namespaces/")
+                        .unwrap_or(&bookmark.uri)
+                        .to_string(),
+                    Style::default().fg(theme::current().text_dim),
+                ),
+            ]);
+            if i == app.bookmark_cursor {
+                line.style(Style::default().bg(theme::current().bg_highlight))
+            } else {
+                line
+            }
+        })
+        .collect();
+    lines.push(Line::from(""));
+    lines.push(Line::from(Span::styled(
+        " Enter to open | d to delete | Esc to cancel",
+        Style::default().fg(theme::current().text_dim),
+    )));
+
+    let height = (lines.len() as u16 + 2).min(area.height);
+    let modal_area = centered_rect(70, height, area);
+    frame.render_widget(Clear, modal_area);
+
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(theme::current().accent))
+        .title(" Bookmarks ");
+
+    let visible = modal_area.height.saturating_sub(4) as usize;
+    let scroll = app
+        .bookmark_cursor
+        .saturating_sub(visible.saturating_sub(1));
+    frame.render_widget(
+        Paragraph::new(lines)
+            .block(block)
+            .scroll((scroll as u16, 0)),
+        modal_area,
+    );
+}
@@ -3,6 +3,7 @@ use ratatui::style::{Modifier, Style};
 use ratatui::widgets::{Block, Borders, Cell, Paragraph, Row, Table, TableState};
 use ratatui::Frame;
 
+use crate::i18n::{t, Msg};
 use crate::kinds::{collection_spec, KindId};
 use crate::theme;
 
@@ -10,8 +11,8 @@ pub struct CollectionTable {
     pub header: Row<'static>,
     pub rows: Option<Vec<Row<'static>>>,
     pub widths: Vec<Constraint>,
-    pub loading_label: &'static str,
-    pub empty_label: &'static str,
+    pub loading_label: Msg,
+    pub empty_label: Msg,
     pub is_loading: bool,
 }
 
@@ -24,12 +25,13 @@ pub fn render_collection(
     let rows = match table.rows {
         Some(rows) => rows,
         None => {
-            let label = if table.is_loading {
+            let label = t(if table.is_loading {
                 table.loading_label
             } else {
                 table.empty_label
-            };
-            let loading = Paragraph::new(label).style(Style::default().fg(theme::TEXT_MUTED));
+            });
+            let loading =
+                Paragraph::new(label).style(Style::default().fg(theme::current().text_muted));
             frame.render_widget(loading, area);
             return;
         }
@@ -40,7 +42,7 @@ pub fn render_collection(
         .block(Block::default().borders(Borders::NONE))
         .row_highlight_style(
             Style::default()
-                .bg(theme::BG_HIGHLIGHT)
+                .bg(theme::current().bg_highlight)
                 .add_modifier(Modifier::BOLD),
         )
         .highlight_symbol("▸ ");
@@ -57,7 +59,7 @@ pub fn header_row(labels: &[&'static str]) -> Row<'static> {
     )
     .style(
         Style::default()
-            .fg(theme::TEXT_DIM)
+            .fg(theme::current().text_dim)
             .add_modifier(Modifier::BOLD),
     )
     .height(1)
@@ -70,10 +72,12 @@ pub fn render_kind_collection(
     kind: KindId,
 ) {
     let spec = collection_spec(kind);
+    let columns = app.columns_for_width(kind, area.width);
+    let headers: Vec<&'static str> = columns.iter().map(|c| c.header).collect();
     let table = CollectionTable {
-        header: header_row(spec.header),
-        rows: (spec.rows)(app),
-        widths: (spec.widths)(),
+        header: header_row(&headers),
+        rows: (spec.rows)(app, &columns),
+        widths: columns.iter().map(|c| c.width).collect(),
         loading_label: spec.loading_label,
         empty_label: spec.empty_label,
         is_loading: (spec.is_loading)(app),
@@ -1,13 +1,14 @@
-use ratatui::layout::{Constraint, Flex, Layout, Rect};
+use ratatui::layout::Rect;
 use ratatui::style::Style;
 use ratatui::text::{Line, Span};
 use ratatui::widgets::{Block, Borders, Clear, Paragraph};
 use ratatui::Frame;
 
-use crate::app::{App, View};
+use crate::app::{App, Overlay, View};
 use crate::input::commands::{matching_commands, COMMANDS};
 use crate::kinds::KindId;
 use crate::theme;
+use crate::widgets::centered_rect;
 
 pub fn render_command_modal(app: &App, frame: &mut Frame, area: Rect) {
     let input_cmd = app.input_buffer.split_whitespace().next().unwrap_or("");
@@ -19,7 +20,19 @@ pub fn render_command_modal(app: &App, frame: &mut Frame, area: Rect) {
         matching_commands(input_cmd)
     };
 
-    let height = (matches.len() as u16 + 4).min(area.height.saturating_sub(4));
+    // Lists from `[[kinds]]`
+    let custom_kinds: Vec<_> = if app.input_buffer.contains(' ') {
+        vec![]
+    } else {
+        app.kinds
+            .custom()
+            .iter()
+            .filter(|k| k.command.starts_with(input_cmd))
+            .collect()
+    };
+
+    let height =
+        ((matches.len() + custom_kinds.len()) as u16 + 4).min(area.height.saturating_sub(4));
     let modal_area = centered_rect(60, height, area);
     frame.render_widget(Clear, modal_area);
 
@@ -27,18 +40,27 @@ pub fn render_command_modal(app: &App, frame: &mut Frame, area: Rect) {
 
     // Input line: `:` prefix + input text + ghost completion + cursor
     let mut input_spans = vec![
-        Span::styled(":", Style::default().fg(theme::YELLOW)),
-        Span::styled(&app.input_buffer, Style::default().fg(theme::TEXT)),
+        Span::styled(":", Style::default().fg(theme::current().yellow)),
+        Span::styled(
+            &app.input_buffer,
+            Style::default().fg(theme::current().text),
+        ),
     ];
     if !app.input_buffer.is_empty() && !app.input_buffer.contains(' ') {
         if let Some(cmd) = matches.first() {
             if cmd.name.starts_with(input_cmd) && cmd.name.len() > input_cmd.len() {
                 let ghost = &cmd.name[input_cmd.len()..];
-                input_spans.push(Span::styled(ghost, Style::default().fg(theme::TEXT_MUTED)));
+                input_spans.push(Span::styled(
+                    ghost,
+                    Style::default().fg(theme::current().text_muted),
+                ));
             }
         }
     }
-    input_spans.push(Span::styled("_", Style::default().fg(theme::TEXT_MUTED)));
+    input_spans.push(Span::styled(
+        "_",
+        Style::default().fg(theme::current().text_muted),
+    ));
     lines.push(Line::from(input_spans));
 
     // Separator
@@ -47,27 +69,40 @@ pub fn render_command_modal(app: &App, frame: &mut Frame, area: Rect) {
     // Command suggestions
     for (i, cmd) in matches.iter().enumerate() {
         let style = if i == 0 {
-            Style::default().fg(theme::PURPLE)
+            Style::default().fg(theme::current().accent)
         } else {
-            Style::default().fg(theme::TEXT_MUTED)
+            Style::default().fg(theme::current().text_muted)
         };
         let mut spans = vec![Span::styled(format!(":{}", cmd.name), style)];
         for alias in cmd.aliases {
             spans.push(Span::styled(
                 format!("  :{}", alias),
-                Style::default().fg(theme::TEXT_MUTED),
+                Style::default().fg(theme::current().text_muted),
             ));
         }
         spans.push(Span::styled(
             format!("  {}", cmd.description),
-            Style::default().fg(theme::TEXT_DIM),
+            Style::default().fg(theme::current().text_dim),
         ));
         lines.push(Line::from(spans));
     }
 
+    for kind in custom_kinds {
+        lines.push(Line::from(vec![
+            Span::styled(
+                format!(":{}", kind.command),
+                Style::default().fg(theme::current().text_muted),
+            ),
+            Span::styled(
+                format!("  {} ({})", kind.label, kind.base.label().to_lowercase()),
+                Style::default().fg(theme::current().text_dim),
+            ),
+        ]));
+    }
+
     let block = Block::default()
         .borders(Borders::ALL)
-        .border_style(Style::default().fg(theme::YELLOW))
+        .border_style(Style::default().fg(theme::current().yellow))
         .title(" Command ");
 
     let paragraph = Paragraph::new(lines).block(block);
@@ -75,28 +110,64 @@ pub fn render_command_modal(app: &App, frame: &mut Frame, area: Rect) {
 }
 
 pub fn render_search_modal(app: &App, frame: &mut Frame, area: Rect) {
-    let modal_area = centered_rect(60, 10, area);
-    frame.render_widget(Clear, modal_area);
+    let completion = app.search_completion();
 
-    let mut lines = vec![
-        // Input line: `/` prefix + input text + cursor
-        Line::from(vec![
-            Span::styled("/", Style::default().fg(theme::GREEN)),
-            Span::styled(&app.input_buffer, Style::default().fg(theme::TEXT)),
-            Span::styled("_", Style::default().fg(theme::TEXT_MUTED)),
-        ]),
+    // Input line: `/` prefix + input text + ghost completion + cursor
+    let mut input_spans = vec![
+        Span::styled("/", Style::default().fg(theme::current().green)),
+        Span::styled(
+            &app.input_buffer,
+            Style::default().fg(theme::current().text),
+        ),
+    ];
+    if let Some(ghost) = completion.ghost(&app.input_buffer) {
+        input_spans.push(Span::styled(
+            ghost,
+            Style::default().fg(theme::current().text_muted),
+        ));
+    }
+    input_spans.push(Span::styled(
+        "_",
+        Style::default().fg(theme::current().text_muted),
+    ));
+    let mut lines = vec![Line::from(input_spans)];
+    if !completion.candidates.is_empty() {
+        let mut spans = vec![Span::styled(
+            "  Tab ",
+            Style::default().fg(theme::current().text_dim),
+        )];
+        for (i, candidate) in completion.candidates.iter().take(8).enumerate() {
+            let color = if i == 0 {
+                theme::current().accent
+            } else {
+                theme::current().text_muted
+            };
+            spans.push(Span::styled(
+                format!(" {}", candidate),
+                Style::default().fg(color),
+            ));
+        }
+        lines.push(Line::from(spans));
+    }
+    if let Some(error) = &app.search_error {
+        lines.push(Line::from(Span::styled(
+            format!("  {}", error),
+            Style::default().fg(theme::current().red),
+        )));
+    }
+    lines.extend([
         // Separator
         Line::from(""),
         Line::from(Span::styled(
             "Examples:",
-            Style::default().fg(theme::TEXT_DIM),
+            Style::default().fg(theme::current().text_dim),
         )),
-    ];
+    ]);
 
     for example in search_examples(app) {
         lines.push(Line::from(Span::styled(
             format!("  {}", example),
-            Style::default().fg(theme::TEXT_MUTED),
+            Style::default().fg(theme::current().text_muted),
         )));
     }
 
@@ -104,21 +175,75 @@ pub fn render_search_modal(app: &App, frame: &mut Frame, area: Rect) {
         Line::from(""),
         Line::from(Span::styled(
             "Enter to search | Esc to cancel",
-            Style::default().fg(theme::TEXT_DIM),
+            Style::default().fg(theme::current().text_dim),
         )),
     ]);
 
     let block = Block::default()
         .borders(Borders::ALL)
-        .border_style(Style::default().fg(theme::GREEN))
+        .border_style(Style::default().fg(theme::current().green))
         .title(" Search ");
 
+    let modal_area = centered_rect(60, lines.len() as u16 + 2, area);
+    frame.render_widget(Clear, modal_area);
     let paragraph = Paragraph::new(lines).block(block);
     frame.render_widget(paragraph, modal_area);
 }
 
+/// Single-line prompt over the footer, so the table stays visible while the
+/// filter narrows it.
+pub fn render_filter_bar(app: &App, frame: &mut Frame, area: Rect) {
+    let kind = match app.view {
+        View::Collection(kind) | View::Detail(kind) => kind,
+    };
+    let (shown, total, noun, enter) = match kind {
+        _ if app.overlay == Overlay::NamespaceSelector => (
+            app.visible_namespaces().len(),
+            app.namespaces.len(),
+            "namespaces",
+            "switch",
+        ),
+        KindId::Schedule => (
+            app.schedule_list_rows().len(),
+            app.schedules.data().map_or(0, |s| s.len()),
+            "loaded rows",
+            "keep",
+        ),
+        _ => (
+            app.visible_workflow_indices().len(),
+            app.workflows.data().map_or(0, |w| w.len()),
+            "loaded rows",
+            "keep",
+        ),
+    };
+    let line = Line::from(vec![
+        Span::styled(" filter> ", Style::default().fg(theme::current().cyan)),
+        Span::styled(
+            &app.input_buffer,
+            Style::default().fg(theme::current().text),
+        ),
+        Span::styled("_", Style::default().fg(theme::current().text_muted)),
+        Span::styled(
+            format!(
+                "  {}/{} {}  Enter {} | Esc clear",
+                shown, total, noun, enter
+            ),
+            Style::default().fg(theme::current().text_dim),
+        ),
+    ]);
+    frame.render_widget(Clear, area);
+    frame.render_widget(
+        Paragraph::new(line).style(Style::default().bg(theme::current().bg_surface)),
+        area,
+    );
+}
+
 fn search_examples(app: &App) -> Vec<&'static str> {
     match app.view {
+        // Detail views search within the Input/Output or History JSON tree.
+        View::Detail(KindId::WorkflowExecution) => {
+            vec!["customerId", "timeout", "ActivityTaskFailed"]
+        }
         View::Collection(KindId::Schedule) | View::Detail(KindId::Schedule) => vec![
             "TemporalSchedulePaused = true",
             "ScheduleId = 'nightly-reconcile'",
@@ -131,13 +256,3 @@ fn search_examples(app: &App) -> Vec<&'static str> {
         ],
     }
 }
-
-fn centered_rect(percent_x: u16, height: u16, area: Rect) -> Rect {
-    let vertical = Layout::vertical([Constraint::Length(height)])
-        .flex(Flex::Center)
-        .split(area);
-    let horizontal = Layout::horizontal([Constraint::Percentage(percent_x)])
-        .flex(Flex::Center)
-        .split(vertical[0]);
-    horizontal[0]
-}
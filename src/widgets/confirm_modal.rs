@@ -1,80 +1,151 @@
-use ratatui::layout::{Constraint, Flex, Layout, Rect};
+use ratatui::layout::Rect;
 use ratatui::style::{Modifier, Style};
 use ratatui::text::{Line, Span};
 use ratatui::widgets::{Block, Borders, Clear, Paragraph, Wrap};
 use ratatui::Frame;
 
-use crate::app::{ConfirmAction, OperationConfirm, OperationTarget};
+use crate::app::{App, BatchConfirm, ConfirmAction, OperationConfirm, OperationTarget};
+use crate::domain::{format_duration_text, BatchOperation, BatchTarget, NamespaceChange};
+use crate::i18n::{t, tf, Msg};
 use crate::kinds::OperationId;
 use crate::theme;
+use crate::widgets::centered_rect;
 
-pub fn render(action: &ConfirmAction, frame: &mut Frame, area: Rect) {
+pub fn render(app: &App, action: &ConfirmAction, frame: &mut Frame, area: Rect) {
     let message = match action {
         ConfirmAction::Operation(confirm) => confirm_message(confirm),
+        ConfirmAction::Restart(confirm) => restart_message(confirm),
+        ConfirmAction::Namespace(change) => namespace_message(change),
+        ConfirmAction::Batch(confirm) => batch_message(confirm),
+        ConfirmAction::ActivityOptions(draft, edit) => tf(
+            Msg::ConfirmActivityOptions,
+            &[
+                &edit.changed_fields(&draft.edit).join(", "),
+                &draft.activity_id,
+            ],
+        ),
     };
 
-    let modal_area = centered_rect(50, 7, area);
+    let typed = app.confirm_phrase.as_deref();
+    let modal_area = centered_rect(50, if typed.is_some() { 10 } else { 7 }, area);
 
     frame.render_widget(Clear, modal_area);
 
-    let lines = vec![
+    let mut lines = vec![
         Line::from(""),
         Line::from(Span::styled(
             format!("  {}", message),
             Style::default()
-                .fg(theme::YELLOW)
+                .fg(theme::current().yellow)
                 .add_modifier(Modifier::BOLD),
         )),
         Line::from(""),
-        Line::from(vec![
-            Span::styled("  y/Enter", Style::default().fg(theme::GREEN)),
-            Span::raw(" confirm  "),
-            Span::styled("n/Esc", Style::default().fg(theme::RED)),
-            Span::raw(" cancel"),
-        ]),
     ];
+    match typed {
+        Some(phrase) => {
+            lines.push(Line::from(format!(
+                "  {}",
+                tf(Msg::ConfirmTypePhrase, &[phrase])
+            )));
+            let color = if app.confirm_ready() {
+                theme::current().green
+            } else {
+                theme::current().text
+            };
+            lines.push(Line::from(vec![
+                Span::styled(
+                    format!("  > {}", app.confirm_input),
+                    Style::default().fg(color),
+                ),
+                Span::styled("_", Style::default().fg(theme::current().text_muted)),
+            ]));
+            lines.push(Line::from(""));
+            lines.push(Line::from(vec![
+                Span::styled("  Enter", Style::default().fg(theme::current().green)),
+                Span::raw(t(Msg::ConfirmYes)),
+                Span::styled("Esc", Style::default().fg(theme::current().red)),
+                Span::raw(t(Msg::ConfirmNo)),
+            ]));
+        }
+        None => lines.push(Line::from(vec![
+            Span::styled("  y/Enter", Style::default().fg(theme::current().green)),
+            Span::raw(t(Msg::ConfirmYes)),
+            Span::styled("n/Esc", Style::default().fg(theme::current().red)),
+            Span::raw(t(Msg::ConfirmNo)),
+        ])),
+    }
 
     let block = Block::default()
         .borders(Borders::ALL)
-        .border_style(Style::default().fg(theme::YELLOW))
-        .title(" Confirm ");
+        .border_style(Style::default().fg(theme::current().yellow))
+        .title(t(Msg::ConfirmTitle));
 
     let paragraph = Paragraph::new(lines).block(block).wrap(Wrap { trim: true });
     frame.render_widget(paragraph, modal_area);
 }
 
-fn centered_rect(percent_x: u16, height: u16, area: Rect) -> Rect {
-    let vertical = Layout::vertical([Constraint::Length(height)])
-        .flex(Flex::Center)
-        .split(area);
-    let horizontal = Layout::horizontal([Constraint::Percentage(percent_x)])
-        .flex(Flex::Center)
-        .split(vertical[0]);
-    horizontal[0]
-}
-
 fn confirm_message(confirm: &OperationConfirm) -> String {
-    let label = match confirm.op {
-        OperationId::CancelWorkflow => "Cancel workflow",
-        OperationId::TerminateWorkflow => "Terminate workflow",
-        OperationId::TriggerSchedule => "Trigger schedule",
-        OperationId::DeleteSchedule => "Delete schedule",
-        OperationId::PauseSchedule => "Pause schedule",
-        OperationId::CancelActivityExecution => "Cancel activity",
-        OperationId::TerminateActivityExecution => "Terminate activity",
-        OperationId::DeleteActivityExecution => "Delete activity",
+    let msg = match confirm.op {
+        OperationId::CancelWorkflow => Msg::ConfirmCancelWorkflow,
+        OperationId::TerminateWorkflow => Msg::ConfirmTerminateWorkflow,
+        OperationId::TerminateAndRestart => Msg::ConfirmRestartStep1,
+        OperationId::TriggerSchedule => Msg::ConfirmTriggerSchedule,
+        OperationId::DeleteSchedule => Msg::ConfirmDeleteSchedule,
+        OperationId::PauseSchedule => Msg::ConfirmPauseSchedule,
+        OperationId::EditSchedule => Msg::ConfirmEditSchedule,
+        OperationId::CancelActivityExecution => Msg::ConfirmCancelActivity,
+        OperationId::TerminateActivityExecution => Msg::ConfirmTerminateActivity,
+        OperationId::DeleteActivityExecution => Msg::ConfirmDeleteActivity,
     };
 
     match &confirm.target {
-        OperationTarget::Workflow { workflow_id, .. } => {
-            format!("{} {}?", label, workflow_id)
-        }
-        OperationTarget::Schedule { schedule_id } => format!("{} {}?", label, schedule_id),
+        OperationTarget::Workflow { workflow_id, .. } => tf(msg, &[workflow_id]),
+        OperationTarget::Schedule { schedule_id } => tf(msg, &[schedule_id]),
         OperationTarget::ActivityExecution {
             activity_id,
             run_id,
-        } => {
-            format!("{} {} ({})?", label, activity_id, run_id)
+        } => tf(msg, &[&format!("{} ({})", activity_id, run_id)]),
+    }
+}
+
+fn restart_message(confirm: &OperationConfirm) -> String {
+    match &confirm.target {
+        OperationTarget::Workflow { workflow_id, .. } => {
+            tf(Msg::ConfirmRestartStep2, &[workflow_id])
         }
+        _ => confirm_message(confirm),
+    }
+}
+
+fn namespace_message(change: &NamespaceChange) -> String {
+    let span = |d: &std::time::Duration| format_duration_text(d.as_secs() as i64);
+    match change {
+        NamespaceChange::Register {
+            name, retention, ..
+        } => tf(Msg::ConfirmRegisterNamespace, &[name, &span(retention)]),
+        NamespaceChange::Update {
+            name,
+            retention: Some(retention),
+            ..
+        } => tf(Msg::ConfirmRetention, &[name, &span(retention)]),
+        NamespaceChange::Update {
+            name, description, ..
+        } => tf(
+            Msg::ConfirmDescribeNamespace,
+            &[name, description.as_deref().unwrap_or("")],
+        ),
+        NamespaceChange::Deprecate { name } => tf(Msg::ConfirmDeprecateNamespace, &[name]),
+    }
+}
+
+fn batch_message(confirm: &BatchConfirm) -> String {
+    let verb = match confirm.operation {
+        BatchOperation::Cancel => "Cancel",
+        BatchOperation::Terminate => "Terminate",
+    };
+    let count = confirm.count.to_string();
+    match &confirm.target {
+        BatchTarget::Executions(_) => tf(Msg::ConfirmBatchMarked, &[verb, &count]),
+        BatchTarget::Query(query) => tf(Msg::ConfirmBatchQuery, &[verb, &count, query]),
     }
 }
@@ -0,0 +1,200 @@
+use ratatui::layout::Rect;
+use ratatui::style::{Modifier, Style};
+use ratatui::text::{Line, Span};
+use ratatui::widgets::{Block, Borders, Paragraph, Wrap};
+use ratatui::Frame;
+
+use crate::i18n::{t, Msg};
+use crate::theme;
+use crate::widgets::centered_rect;
+
+/// Which connection field is being edited on the error screen.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ConnectionField {
+    Address,
+    Namespace,
+}
+
+/// State of the startup screen shown while connecting and after a failed
+/// connect, before the main app exists.
+#[derive(Debug, Default)]
+pub struct ConnectionScreen {
+    pub address: String,
+    pub namespace: String,
+    /// Active profile name from the config file, if one was picked.
+    pub profile: Option<String>,
+    /// Profile names offered by `p`, in config order.
+    pub profiles: Vec<String>,
+    /// `None` while a connect is in flight.
+    pub error: Option<String>,
+    pub editing: Option<(ConnectionField, String)>,
+    /// Verification URL and user code while an OIDC device login waits.
+    pub device_code: Option<(String, String)>,
+}
+
+impl ConnectionScreen {
+    /// Next profile after the active one, wrapping around.
+    pub fn next_profile(&self) -> Option<&str> {
+        if self.profiles.is_empty() {
+            return None;
+        }
+        let next = match &self.profile {
+            Some(current) => self
+                .profiles
+                .iter()
+                .position(|p| p == current)
+                .map_or(0, |i| (i + 1) % self.profiles.len()),
+            None => 0,
+        };
+        Some(&self.profiles[next])
+    }
+}
+
+pub fn render(screen: &ConnectionScreen, frame: &mut Frame, area: Rect) {
+    frame.render_widget(
+        Block::default().style(Style::default().bg(theme::current().bg)),
+        area,
+    );
+
+    let label = |msg: Msg| {
+        Span::styled(
+            format!("  {:<11}", t(msg)),
+            Style::default().fg(theme::current().text_dim),
+        )
+    };
+    let key = |s: &'static str| Span::styled(s, Style::default().fg(theme::current().accent));
+
+    let mut lines = vec![
+        Line::from(""),
+        Line::from(vec![
+            label(Msg::ConnectionAddress),
+            Span::styled(&screen.address, Style::default().fg(theme::current().text)),
+        ]),
+        Line::from(vec![
+            label(Msg::ConnectionNamespace),
+            Span::styled(
+                &screen.namespace,
+                Style::default().fg(theme::current().text),
+            ),
+        ]),
+    ];
+    if let Some(profile) = &screen.profile {
+        lines.push(Line::from(vec![
+            label(Msg::ConnectionProfile),
+            Span::styled(profile, Style::default().fg(theme::current().text)),
+        ]));
+    }
+    lines.push(Line::from(""));
+
+    let (title, border) = match &screen.error {
+        None if screen.device_code.is_some() => {
+            let (url, code) = screen.device_code.as_ref().unwrap();
+            lines.push(Line::from(vec![
+                label(Msg::ConnectionOpen),
+                Span::styled(url, Style::default().fg(theme::current().blue)),
+            ]));
+            lines.push(Line::from(vec![
+                label(Msg::ConnectionCode),
+                Span::styled(
+                    code,
+                    Style::default()
+                        .fg(theme::current().yellow)
+                        .add_modifier(Modifier::BOLD),
+                ),
+            ]));
+            lines.push(Line::from(""));
+            lines.push(Line::from(vec![
+                Span::styled(
+                    format!("  {}  ", t(Msg::ConnectionWaiting)),
+                    Style::default().fg(theme::current().text_muted),
+                ),
+                key("q"),
+                Span::raw(format!(" {}", t(Msg::HintQuit))),
+            ]));
+            (t(Msg::ConnectionSignInTitle), theme::current().accent_dim)
+        }
+        None => {
+            lines.push(Line::from(Span::styled(
+                format!("  {}", t(Msg::ConnectionConnecting)),
+                Style::default().fg(theme::current().yellow),
+            )));
+            (
+                t(Msg::ConnectionConnectingTitle),
+                theme::current().accent_dim,
+            )
+        }
+        Some(error) => {
+            lines.push(Line::from(Span::styled(
+                format!("  {}", error),
+                Style::default()
+                    .fg(theme::current().red)
+                    .add_modifier(Modifier::BOLD),
+            )));
+            lines.push(Line::from(""));
+            lines.push(Line::from(Span::styled(
+                format!("  {}", t(Msg::ConnectionDoctor)),
+                Style::default().fg(theme::current().text_muted),
+            )));
+            lines.push(Line::from(""));
+            match &screen.editing {
+                Some((field, buffer)) => {
+                    let name = match field {
+                        ConnectionField::Address => Msg::ConnectionNewAddress,
+                        ConnectionField::Namespace => Msg::ConnectionNewNamespace,
+                    };
+                    lines.push(Line::from(vec![
+                        Span::styled(
+                            format!("  {} ", t(name)),
+                            Style::default().fg(theme::current().yellow),
+                        ),
+                        Span::styled(
+                            format!("{}_", buffer),
+                            Style::default().fg(theme::current().text),
+                        ),
+                    ]));
+                    lines.push(Line::from(vec![
+                        key("  Enter"),
+                        Span::raw(format!(" {}  ", t(Msg::HintConnect))),
+                        key("Esc"),
+                        Span::raw(format!(" {}", t(Msg::HintCancel))),
+                    ]));
+                }
+                None => {
+                    let mut hints = vec![
+                        key("  r"),
+                        Span::raw(format!(" {}  ", t(Msg::HintRetry))),
+                        key("a"),
+                        Span::raw(format!(" {}  ", t(Msg::HintEditAddress))),
+                        key("n"),
+                        Span::raw(format!(" {}  ", t(Msg::HintEditNamespace))),
+                    ];
+                    if !screen.profiles.is_empty() {
+                        hints.push(key("p"));
+                        hints.push(Span::raw(format!(" {}  ", t(Msg::HintNextProfile))));
+                    }
+                    hints.push(key("q"));
+                    hints.push(Span::raw(format!(" {}", t(Msg::HintQuit))));
+                    lines.push(Line::from(hints));
+                }
+            }
+            (t(Msg::ConnectionFailedTitle), theme::current().red)
+        }
+    };
+
+    // Long gRPC errors wrap; leave room for them.
+    let width = (area.width as usize * 7 / 10).saturating_sub(2).max(1);
+    let wrapped: usize = lines.iter().map(|l| l.width().max(1).div_ceil(width)).sum();
+    let height = wrapped as u16 + 2;
+    let modal_area = centered_rect(70, height, area);
+
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(border))
+        .title(title);
+    frame.render_widget(
+        Paragraph::new(lines)
+            .block(block)
+            .wrap(Wrap { trim: false }),
+        modal_area,
+    );
+}
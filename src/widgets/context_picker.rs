@@ -0,0 +1,52 @@
+use ratatui::layout::Rect;
+use ratatui::style::{Modifier, Style};
+use ratatui::text::{Line, Span};
+use ratatui::widgets::{Block, Borders, Clear, Paragraph};
+use ratatui::Frame;
+
+use crate::app::App;
+use crate::theme;
+use crate::widgets::centered_rect;
+
+pub fn render(app: &App, frame: &mut Frame, area: Rect) {
+    let mut lines: Vec<Line> = app
+        .profiles
+        .iter()
+        .enumerate()
+        .map(|(i, name)| {
+            let active = app.active_profile.as_ref() == Some(name);
+            let line = Line::from(vec![
+                Span::styled(
+                    if active { " * " } else { "   " },
+                    Style::default().fg(theme::current().green),
+                ),
+                Span::styled(
+                    name.clone(),
+                    Style::default()
+                        .fg(theme::current().accent)
+                        .add_modifier(Modifier::BOLD),
+                ),
+            ]);
+            if i == app.context_picker_cursor {
+                line.style(Style::default().bg(theme::current().bg_highlight))
+            } else {
+                line
+            }
+        })
+        .collect();
+    lines.push(Line::from(""));
+    lines.push(Line::from(Span::styled(
+        " Enter to switch | Esc to cancel",
+        Style::default().fg(theme::current().text_dim),
+    )));
+
+    let modal_area = centered_rect(40, lines.len() as u16 + 2, area);
+    frame.render_widget(Clear, modal_area);
+
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(theme::current().accent))
+        .title(" Contexts ");
+
+    frame.render_widget(Paragraph::new(lines).block(block), modal_area);
+}
@@ -8,7 +8,7 @@ use crate::app::App;
 use crate::theme;
 
 pub fn render(app: &App, frame: &mut Frame, area: Rect) {
-    if let Some((ref msg, _)) = app.last_error {
+    if let Some((ref msg, _)) = app.error_toasts.front() {
         let toast_area = Rect {
             x: area.x,
             y: area.y + area.height.saturating_sub(2),
@@ -16,11 +16,34 @@ pub fn render(app: &App, frame: &mut Frame, area: Rect) {
             height: 1,
         };
 
-        let line = Line::from(vec![
-            Span::styled(" ERROR ", Style::default().fg(theme::TEXT).bg(theme::RED)),
-            Span::styled(format!(" {}", msg), Style::default().fg(theme::RED)),
-        ]);
+        let label_style = if app.toast_focused {
+            Style::default()
+                .fg(theme::current().bg)
+                .bg(theme::current().yellow)
+        } else {
+            Style::default()
+                .fg(theme::current().text)
+                .bg(theme::current().red)
+        };
+        let mut spans = vec![
+            Span::styled(" ERROR ", label_style),
+            Span::styled(
+                format!(" {}", msg),
+                Style::default().fg(theme::current().red),
+            ),
+        ];
+        let queued = app.error_toasts.len() - 1;
+        if queued > 0 {
+            spans.push(Span::styled(
+                format!("  (+{} more)", queued),
+                Style::default().fg(theme::current().text_muted),
+            ));
+        }
+        spans.push(Span::styled(
+            "  Esc dismiss · :logs",
+            Style::default().fg(theme::current().text_muted),
+        ));
 
-        frame.render_widget(Paragraph::new(line), toast_area);
+        frame.render_widget(Paragraph::new(Line::from(spans)), toast_area);
     }
 }
@@ -4,94 +4,203 @@ use ratatui::text::{Line, Span};
 use ratatui::widgets::Paragraph;
 use ratatui::Frame;
 
-use crate::app::{App, InputMode, View};
-use crate::kinds::{kind_spec, KindId};
+use crate::app::{App, InputMode, ListPosition, View};
+use crate::i18n::{t, tf, Msg};
+use crate::kinds::{kind_spec, KindId, SPLIT_MIN_WIDTH, WIDE_MIN_WIDTH};
 use crate::theme;
 
 pub fn render(app: &App, frame: &mut Frame, area: Rect) {
-    let hints = match app.input_mode {
-        InputMode::Command => vec![hint("Esc", "cancel"), hint("Enter", "execute")],
-        InputMode::Search => vec![hint("Esc", "cancel"), hint("Enter", "apply")],
-        InputMode::PendingG => vec![hint("g", "top")],
+    let mut hints = match app.input_mode {
+        InputMode::Command => vec![
+            hint("Esc", t(Msg::HintCancel)),
+            hint("Enter", t(Msg::HintExecute)),
+        ],
+        InputMode::Search => vec![
+            hint("Esc", t(Msg::HintCancel)),
+            hint("Enter", t(Msg::HintApply)),
+        ],
+        InputMode::Filter => vec![
+            hint("Esc", t(Msg::HintClear)),
+            hint("Enter", t(Msg::HintKeep)),
+        ],
+        InputMode::PendingG => vec![hint("g", t(Msg::HintTop))],
         InputMode::Normal => match app.view {
-            View::Collection(kind) => build_collection_hints(kind),
+            View::Collection(kind) => {
+                let mut hints = build_collection_hints(app, kind);
+                let wide = match (app.wide_mode, area.width >= WIDE_MIN_WIDTH) {
+                    (false, _) => t(Msg::HintWide),
+                    (true, true) => t(Msg::HintNarrow),
+                    (true, false) => t(Msg::HintWideNeedsWidth),
+                };
+                hints.insert(hints.len() - 2, hint("W", wide));
+                let split = match (app.split_view, area.width >= SPLIT_MIN_WIDTH) {
+                    (false, _) => t(Msg::HintSplit),
+                    (true, true) => t(Msg::HintUnsplit),
+                    (true, false) => t(Msg::HintSplitNeedsWidth),
+                };
+                hints.insert(hints.len() - 2, hint("V", split));
+                hints
+            }
             View::Detail(KindId::WorkflowExecution) => {
-                build_detail_hints(KindId::WorkflowExecution)
+                build_detail_hints(app, KindId::WorkflowExecution)
             }
-            View::Detail(kind) => build_detail_hints(kind),
+            View::Detail(kind) => build_detail_hints(app, kind),
         },
     };
 
+    if app.input_mode == InputMode::Normal {
+        let polling = if app.polling_enabled {
+            t(Msg::HintPausePoll)
+        } else {
+            t(Msg::HintResumePoll)
+        };
+        hints.push(hint("P", polling));
+        let times = if app.time_display.relative {
+            t(Msg::HintAbsolute)
+        } else {
+            t(Msg::HintRelative)
+        };
+        hints.push(hint("A", times));
+        if !app.error_toasts.is_empty() {
+            hints.push(hint("Bksp", t(Msg::HintClearErrors)));
+        }
+        if !app.mouse_capture {
+            hints.push(hint(":copy-mode", t(Msg::HintMouseOff)));
+        }
+    }
+
     let mut spans: Vec<Span> = vec![Span::raw(" ")];
+    if let Some((notice, _)) = &app.notice {
+        spans.push(Span::styled(
+            notice.as_str(),
+            Style::default().fg(theme::current().green),
+        ));
+        spans.push(Span::raw("  "));
+    }
     for (i, (key, desc)) in hints.iter().enumerate() {
         if i > 0 {
             spans.push(Span::styled("  ", Style::default()));
         }
         spans.push(Span::styled(
             key.as_str(),
-            Style::default().fg(theme::PURPLE),
+            Style::default().fg(theme::current().accent),
         ));
         spans.push(Span::styled(
             format!(":{}", desc),
-            Style::default().fg(theme::TEXT_MUTED),
+            Style::default().fg(theme::current().text_muted),
         ));
     }
 
-    if matches!(app.view, View::Collection(KindId::ActivityExecution)) {
-        if let Some(count) = app.activity_count {
-            spans.push(Span::raw("  "));
-            spans.push(Span::styled(
-                format!("[{} activities]", count),
-                Style::default().fg(theme::TEXT_MUTED),
-            ));
-        }
+    if let View::Collection(kind) = app.view {
+        spans.push(Span::raw("  "));
+        spans.push(Span::styled(
+            position_text(&app.list_position(kind)),
+            Style::default().fg(theme::current().text_muted),
+        ));
     }
 
     let line = Line::from(spans);
-    let widget = Paragraph::new(line).style(Style::default().bg(theme::BG_SURFACE));
+    let widget = Paragraph::new(line).style(Style::default().bg(theme::current().bg_surface));
     frame.render_widget(widget, area);
 }
 
+/// `42/317 (1,204 total) · 18 more pages`.
+fn position_text(position: &ListPosition) -> String {
+    let mut text = tf(
+        Msg::ListPosition,
+        &[
+            &format_count(position.selected as u64),
+            &format_count(position.shown as u64),
+        ],
+    );
+    if let Some(total) = position.total {
+        text.push(' ');
+        text.push_str(&tf(Msg::ListTotal, &[&format_count(total)]));
+    }
+    match position.pages_left {
+        Some(0) => {}
+        Some(pages) => {
+            text.push_str(" · ");
+            text.push_str(&tf(Msg::PagesLeft, &[&format_count(pages)]));
+        }
+        None if position.more_pages => {
+            text.push_str(" · ");
+            text.push_str(t(Msg::MorePages));
+        }
+        None => {}
+    }
+    text
+}
+
+/// `1204` as `1,204`.
+fn format_count(n: u64) -> String {
+    let digits = n.to_string();
+    let mut out = String::new();
+    for (i, c) in digits.chars().enumerate() {
+        if i > 0 && (digits.len() - i).is_multiple_of(3) {
+            out.push(',');
+        }
+        out.push(c);
+    }
+    out
+}
+
 fn hint(key: &str, desc: &str) -> (String, String) {
     (key.to_string(), desc.to_string())
 }
 
-fn build_collection_hints(kind: KindId) -> Vec<(String, String)> {
+fn build_collection_hints(app: &App, kind: KindId) -> Vec<(String, String)> {
     let mut hints = vec![
-        hint("j/k", "nav"),
-        hint("Enter", "select"),
-        hint("/", "search"),
-        hint(":", "cmd"),
+        hint("j/k", t(Msg::HintNav)),
+        hint("Enter", t(Msg::HintSelect)),
+        hint("/", t(Msg::HintSearch)),
+        hint("f", t(Msg::HintFilter)),
+        hint(":", t(Msg::HintCmd)),
     ];
-    hints.extend(operation_hints(kind));
+    hints.extend(operation_hints(app, kind));
     if kind == KindId::Schedule {
-        hints.push(hint("w", "workflows"));
+        hints.push(hint("w", t(Msg::HintWorkflows)));
+        hints.push(hint("z", t(Msg::HintGroup)));
     }
-    hints.push(hint("?", "help"));
-    hints.push(hint("q", "quit"));
+    hints.push(hint("?", t(Msg::HintHelp)));
+    hints.push(hint("q", t(Msg::HintQuit)));
     hints
 }
 
-fn build_detail_hints(kind: KindId) -> Vec<(String, String)> {
-    let mut hints = vec![hint("j/k", "scroll"), hint("Esc", "back")];
+fn build_detail_hints(app: &App, kind: KindId) -> Vec<(String, String)> {
+    let mut hints = vec![
+        hint("j/k", t(Msg::HintScroll)),
+        hint("Esc", t(Msg::HintBack)),
+    ];
     if kind == KindId::WorkflowExecution {
-        hints.insert(0, hint("h/l", "tabs"));
-        hints.insert(1, hint("a", "activities"));
+        hints.insert(0, hint("h/l", t(Msg::HintTabs)));
+        hints.insert(1, hint("a", t(Msg::HintActivities)));
+        hints.insert(2, hint("u/U", t(Msg::HintParentRoot)));
+        hints.insert(3, hint("/", t(Msg::HintFind)));
     } else if kind == KindId::ActivityExecution {
-        hints.insert(0, hint("h/l", "tabs"));
+        hints.insert(0, hint("h/l", t(Msg::HintTabs)));
     }
-    hints.extend(operation_hints(kind));
+    hints.extend(operation_hints(app, kind));
     if kind == KindId::Schedule {
-        hints.push(hint("w", "workflows"));
+        hints.push(hint("w", t(Msg::HintWorkflows)));
+        let has_actions = app
+            .selected_schedule
+            .as_ref()
+            .is_some_and(|s| !s.recent_actions.is_empty());
+        if has_actions {
+            hints[0] = hint("j/k", t(Msg::HintNav));
+            hints.insert(1, hint("Enter", t(Msg::HintOpenRun)));
+        }
     }
-    hints.push(hint("?", "help"));
+    hints.push(hint("?", t(Msg::HintHelp)));
     hints
 }
 
-fn operation_hints(kind: KindId) -> Vec<(String, String)> {
+fn operation_hints(app: &App, kind: KindId) -> Vec<(String, String)> {
     kind_spec(kind)
         .operations
         .iter()
+        .filter(|op| op.id.is_supported(&app.capabilities))
         .map(|op| (op.key.to_string(), op.label.to_string()))
         .collect()
 }
@@ -1,12 +1,14 @@
-use ratatui::layout::{Constraint, Flex, Layout, Rect};
+use ratatui::layout::Rect;
 use ratatui::style::{Modifier, Style};
 use ratatui::text::{Line, Span};
 use ratatui::widgets::{Block, Borders, Clear, Paragraph};
 use ratatui::Frame;
 
 use crate::app::View;
+use crate::i18n::{t, Msg};
 use crate::kinds::{kind_spec, KindId};
 use crate::theme;
+use crate::widgets::centered_rect;
 
 pub fn render(view: &View, frame: &mut Frame, area: Rect) {
     let mut lines = vec![];
@@ -27,51 +29,97 @@ pub fn render(view: &View, frame: &mut Frame, area: Rect) {
     );
 
     lines.push(Line::from(""));
-    lines.push(section("Navigation"));
-    lines.push(binding("j / k / Up / Down", "Navigate up/down"));
-    lines.push(binding("gg / G", "Go to top / bottom"));
-    lines.push(binding("Ctrl+D / Ctrl+U", "Page down / up"));
+    lines.push(section(t(Msg::HelpNavigation)));
+    lines.push(binding("j / k / Up / Down", t(Msg::HelpNavigate)));
+    lines.push(binding("gg / G", t(Msg::HelpTopBottom)));
+    lines.push(binding("Ctrl+D / Ctrl+U", t(Msg::HelpPage)));
     if is_list {
-        lines.push(binding("Enter", "Select / drill in"));
+        lines.push(binding("Enter", t(Msg::HelpSelect)));
     }
+    lines.push(binding(
+        if is_list { ". / Space" } else { "." },
+        t(Msg::HelpQuickActions),
+    ));
     if is_detail {
-        lines.push(binding("Esc", "Back to list"));
+        lines.push(binding("Esc", t(Msg::HelpBackToList)));
     }
 
     lines.push(Line::from(""));
-    lines.push(section("Views"));
-    lines.push(binding(": (colon)", "Command mode"));
-    lines.push(binding(":wf", "Switch to workflows"));
-    lines.push(binding(":sch", "Switch to schedules"));
-    lines.push(binding(":act", "Switch to activities"));
+    lines.push(section(t(Msg::HelpViews)));
+    lines.push(binding(": (colon)", t(Msg::HelpCommandMode)));
+    lines.push(binding(":wf", t(Msg::HelpSwitchWorkflows)));
+    lines.push(binding(":sch", t(Msg::HelpSwitchSchedules)));
+    lines.push(binding(":act", t(Msg::HelpSwitchActivities)));
     if is_list {
-        lines.push(binding("/ (slash)", "Search"));
+        lines.push(binding("/ (slash)", t(Msg::HelpSearch)));
+        lines.push(binding("f / Ctrl+F", t(Msg::HelpFilter)));
+        lines.push(binding("Q", t(Msg::HelpSavedQueries)));
+        lines.push(binding("b", t(Msg::HelpQueryBuilder)));
+        if is_workflow {
+            lines.push(binding("L / :last", t(Msg::HelpTimeRange)));
+            lines.push(binding(":archived", t(Msg::HelpArchived)));
+            lines.push(binding(":allns [ns...]", t(Msg::HelpAllNamespaces)));
+            lines.push(binding(":preview", t(Msg::HelpPreviewPane)));
+        }
+        lines.push(binding("W", t(Msg::HelpWide)));
+        lines.push(binding("V", t(Msg::HelpSplit)));
+        lines.push(binding(":export [path]", t(Msg::HelpExport)));
+        lines.push(binding(":pagesize <n>", t(Msg::HelpPageSize)));
+        lines.push(binding(":!<command>", t(Msg::HelpShell)));
     }
 
     lines.push(Line::from(""));
-    lines.push(section("Commands"));
-    lines.push(binding(":ns <name>", "Switch namespace"));
-    lines.push(binding(":open <uri>", "Open a deep link URI"));
+    lines.push(section(t(Msg::HelpCommands)));
+    lines.push(binding(":ns <name>", t(Msg::HelpSwitchNamespace)));
+    lines.push(binding(":ctx <profile>", t(Msg::HelpSwitchProfile)));
+    lines.push(binding(":open <uri>", t(Msg::HelpOpenUri)));
+    lines.push(binding(":bm [name]", t(Msg::HelpBookmarks)));
+    lines.push(binding(":logs", t(Msg::HelpLogs)));
+    lines.push(binding(":audit", t(Msg::HelpAudit)));
+    lines.push(binding(":attr [add]", t(Msg::HelpSearchAttributes)));
+    lines.push(binding(":overview", t(Msg::HelpOverview)));
+    lines.push(binding(":copy-mode", t(Msg::HelpCopyMode)));
     if is_workflow {
-        lines.push(binding(":signal <name>", "Signal selected workflow"));
+        lines.push(binding(":signal <name>", t(Msg::HelpSignal)));
+        lines.push(binding("S", t(Msg::HelpResendSignal)));
     }
-    lines.push(binding(":q", "Quit"));
+    lines.push(binding(":q <name>", t(Msg::HelpApplyQuery)));
+    lines.push(binding(":q", t(Msg::HelpQuit)));
 
     if is_workflow {
         lines.push(Line::from(""));
-        lines.push(section("Workflow Actions"));
+        lines.push(section(t(Msg::HelpWorkflowActions)));
         for op in kind_spec(KindId::WorkflowExecution).operations {
             lines.push(binding(op.key.to_string(), op.label));
         }
         if is_detail {
-            lines.push(binding("h / l", "Switch detail tabs"));
-            lines.push(binding("a", "Pending activities"));
+            lines.push(binding("h / l", t(Msg::HelpDetailTabs)));
+            lines.push(binding("a", t(Msg::HelpPendingActivities)));
+            lines.push(binding("Enter", t(Msg::HelpOpenChild)));
+            lines.push(binding("Enter", t(Msg::HelpPendingActivityDetail)));
+            lines.push(binding("e", t(Msg::HelpEditActivityOptions)));
+            lines.push(binding("Esc", t(Msg::HelpBackToParent)));
+            lines.push(binding("u / U", t(Msg::HelpParentRoot)));
+            lines.push(binding("r", t(Msg::HelpRefreshStack)));
+            lines.push(binding("Enter / Space", t(Msg::HelpFoldNode)));
+            lines.push(binding("Space", t(Msg::HelpExpandPayloads)));
+            lines.push(binding("/ , n / N", t(Msg::HelpFindPayloads)));
+            lines.push(binding("v", t(Msg::HelpOpenPager)));
+            lines.push(binding("| / :pipe <name>", t(Msg::HelpPipePayload)));
+            lines.push(binding("i / o", t(Msg::HelpCopyPayload)));
+            lines.push(binding(
+                ":save-input / :save-output <path>",
+                t(Msg::HelpSavePayload),
+            ));
+        } else {
+            lines.push(binding("m", t(Msg::HelpMarkWorkflow)));
+            lines.push(binding(":bulk <cancel|terminate>", t(Msg::HelpBulk)));
         }
     }
 
     if is_schedule {
         lines.push(Line::from(""));
-        lines.push(section("Schedule Actions"));
+        lines.push(section(t(Msg::HelpScheduleActions)));
         for op in kind_spec(KindId::Schedule).operations {
             let key = if op.key == 'T' {
                 "T (shift+t)".to_string()
@@ -80,24 +128,39 @@ pub fn render(view: &View, frame: &mut Frame, area: Rect) {
             };
             lines.push(binding(key, op.label));
         }
-        lines.push(binding("w", "Schedule workflows"));
+        lines.push(binding("w", t(Msg::HelpScheduleWorkflows)));
+        if is_detail {
+            lines.push(binding("Enter", t(Msg::HelpOpenRecentAction)));
+            lines.push(binding("h / l", t(Msg::HelpDetailTabs)));
+            lines.push(binding("v", t(Msg::HelpOpenPager)));
+        } else {
+            lines.push(binding("z", t(Msg::HelpGroupSchedules)));
+        }
     }
 
     if is_activity {
         lines.push(Line::from(""));
-        lines.push(section("Activity Actions"));
+        lines.push(section(t(Msg::HelpActivityActions)));
         for op in kind_spec(KindId::ActivityExecution).operations {
             lines.push(binding(op.key.to_string(), op.label));
         }
         if is_detail {
-            lines.push(binding("h / l", "Switch detail tabs"));
+            lines.push(binding("h / l", t(Msg::HelpDetailTabs)));
         }
     }
 
     lines.push(Line::from(""));
-    lines.push(section("General"));
-    lines.push(binding("Ctrl+R", "Refresh"));
-    lines.push(binding("?", "Toggle this help"));
+    lines.push(section(t(Msg::HelpGeneral)));
+    lines.push(binding("Ctrl+R", t(Msg::HelpRefresh)));
+    lines.push(binding("Esc", t(Msg::HelpDismissError)));
+    lines.push(binding("E", t(Msg::HelpHoldError)));
+    lines.push(binding("Backspace", t(Msg::HelpClearErrors)));
+    lines.push(binding("P", t(Msg::HelpPolling)));
+    lines.push(binding("A", t(Msg::HelpRelativeTimes)));
+    lines.push(binding(":tz <zone>", t(Msg::HelpTimezone)));
+    lines.push(binding("Y / :link", t(Msg::HelpCopyLink)));
+    lines.push(binding("1-9", t(Msg::HelpFavoriteNamespace)));
+    lines.push(binding("?", t(Msg::HelpToggleHelp)));
 
     let height = (lines.len() as u16 + 2).min(area.height.saturating_sub(4));
     let modal_area = centered_rect(60, height, area);
@@ -105,8 +168,8 @@ pub fn render(view: &View, frame: &mut Frame, area: Rect) {
 
     let block = Block::default()
         .borders(Borders::ALL)
-        .border_style(Style::default().fg(theme::PURPLE))
-        .title(" Help (? to close) ");
+        .border_style(Style::default().fg(theme::current().accent))
+        .title(t(Msg::HelpTitle));
 
     let paragraph = Paragraph::new(lines).block(block);
     frame.render_widget(paragraph, modal_area);
@@ -116,7 +179,7 @@ fn section(title: &str) -> Line<'_> {
     Line::from(Span::styled(
         format!("  {}", title),
         Style::default()
-            .fg(theme::PURPLE)
+            .fg(theme::current().accent)
             .add_modifier(Modifier::BOLD),
     ))
 }
@@ -127,18 +190,8 @@ fn binding(key: impl Into<String>, desc: impl Into<String>) -> Line<'static> {
     Line::from(vec![
         Span::styled(
             format!("    {:<22}", key),
-            Style::default().fg(theme::YELLOW),
+            Style::default().fg(theme::current().yellow),
         ),
-        Span::styled(desc, Style::default().fg(theme::TEXT)),
+        Span::styled(desc, Style::default().fg(theme::current().text)),
     ])
 }
-
-fn centered_rect(percent_x: u16, height: u16, area: Rect) -> Rect {
-    let vertical = Layout::vertical([Constraint::Length(height)])
-        .flex(Flex::Center)
-        .split(area);
-    let horizontal = Layout::horizontal([Constraint::Percentage(percent_x)])
-        .flex(Flex::Center)
-        .split(vertical[0]);
-    horizontal[0]
-}
@@ -0,0 +1,339 @@
+use std::collections::HashSet;
+
+use ratatui::style::{Modifier, Style};
+use ratatui::text::{Line, Span};
+use serde_json::Value;
+
+use crate::domain::{binary_label, Payload};
+use crate::theme;
+
+/// Containers nested shallower than this are expanded until the user folds them.
+pub const DEFAULT_EXPAND_DEPTH: usize = 2;
+
+/// Fold and search state for one JSON tree view.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct JsonTreeState {
+    pub cursor: usize,
+    pub search: Option<String>,
+    /// Paths whose default expansion has been flipped.
+    toggled: HashSet<String>,
+    /// Roots whose whole subtree is expanded.
+    expanded_roots: HashSet<String>,
+}
+
+impl JsonTreeState {
+    pub fn toggle(&mut self, path: &str) {
+        if !self.toggled.remove(path) {
+            self.toggled.insert(path.to_string());
+        }
+    }
+
+    /// Expands every node under `root`, or restores the default folding if it is
+    /// already fully expanded.
+    pub fn toggle_root(&mut self, root: &str) {
+        let prefix = format!("{}/", root);
+        self.toggled
+            .retain(|p| p != root && !p.starts_with(&prefix));
+        if !self.expanded_roots.remove(root) {
+            self.expanded_roots.insert(root.to_string());
+        }
+    }
+
+    fn is_expanded(&self, root: &str, path: &str, depth: usize) -> bool {
+        let default = self.expanded_roots.contains(root) || depth < DEFAULT_EXPAND_DEPTH;
+        default != self.toggled.contains(path)
+    }
+
+    fn needle(&self) -> Option<String> {
+        self.search
+            .as_deref()
+            .filter(|s| !s.is_empty())
+            .map(str::to_lowercase)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum RowValue {
+    Scalar(Value),
+    /// A binary payload of this many bytes.
+    Binary(usize),
+    Container {
+        is_array: bool,
+        len: usize,
+        expanded: bool,
+    },
+}
+
+/// One visible line of the tree, in display order.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TreeRow {
+    pub path: String,
+    pub depth: usize,
+    pub key: String,
+    pub value: RowValue,
+    pub matched: bool,
+}
+
+impl TreeRow {
+    pub fn is_container(&self) -> bool {
+        matches!(self.value, RowValue::Container { .. })
+    }
+}
+
+/// Flattens `roots` into visible rows. While a search is active, containers
+/// holding a match are expanded regardless of their fold state.
+pub fn rows(roots: &[(&str, &Payload)], state: &JsonTreeState) -> Vec<TreeRow> {
+    let needle = state.needle();
+    let mut out = Vec::new();
+    for (name, payload) in roots {
+        match payload {
+            Payload::Json(value) => push_rows(
+                &mut out,
+                state,
+                needle.as_deref(),
+                name,
+                name,
+                name.to_string(),
+                value,
+                0,
+            ),
+            Payload::Binary(bytes) => out.push(TreeRow {
+                path: name.to_string(),
+                depth: 0,
+                key: name.to_string(),
+                value: RowValue::Binary(bytes.len()),
+                matched: needle
+                    .as_deref()
+                    .is_some_and(|n| name.to_lowercase().contains(n)),
+            }),
+        }
+    }
+    out
+}
+
+/// Like [`rows`] for the children of a single container, without a row for the
+/// container itself. Fold state is keyed under `root`.
+pub fn child_rows(root: &str, value: &Value, state: &JsonTreeState) -> Vec<TreeRow> {
+    let needle = state.needle();
+    let mut out = Vec::new();
+    let children: Vec<(String, &Value)> = match value {
+        Value::Object(map) => map.iter().map(|(k, v)| (k.clone(), v)).collect(),
+        Value::Array(items) => items
+            .iter()
+            .enumerate()
+            .map(|(i, v)| (i.to_string(), v))
+            .collect(),
+        _ => vec![],
+    };
+    for (key, child) in children {
+        let path = format!("{}/{}", root, key);
+        push_rows(
+            &mut out,
+            state,
+            needle.as_deref(),
+            root,
+            &key,
+            path,
+            child,
+            0,
+        );
+    }
+    out
+}
+
+#[allow(clippy::too_many_arguments)]
+fn push_rows(
+    out: &mut Vec<TreeRow>,
+    state: &JsonTreeState,
+    needle: Option<&str>,
+    root: &str,
+    key: &str,
+    path: String,
+    value: &Value,
+    depth: usize,
+) {
+    let matched =
+        needle.is_some_and(|n| key.to_lowercase().contains(n) || scalar_matches(value, n));
+    let children: Vec<(String, &Value)> = match value {
+        Value::Object(map) => map.iter().map(|(k, v)| (k.clone(), v)).collect(),
+        Value::Array(items) => items
+            .iter()
+            .enumerate()
+            .map(|(i, v)| (i.to_string(), v))
+            .collect(),
+        scalar => {
+            out.push(TreeRow {
+                path,
+                depth,
+                key: key.to_string(),
+                value: RowValue::Scalar(scalar.clone()),
+                matched,
+            });
+            return;
+        }
+    };
+
+    let expanded = state.is_expanded(root, &path, depth)
+        || needle.is_some_and(|n| children.iter().any(|(k, v)| subtree_matches(k, v, n)));
+    out.push(TreeRow {
+        path: path.clone(),
+        depth,
+        key: key.to_string(),
+        value: RowValue::Container {
+            is_array: value.is_array(),
+            len: children.len(),
+            expanded,
+        },
+        matched,
+    });
+    if expanded {
+        for (child_key, child) in children {
+            let child_path = format!("{}/{}", path, child_key);
+            push_rows(
+                out,
+                state,
+                needle,
+                root,
+                &child_key,
+                child_path,
+                child,
+                depth + 1,
+            );
+        }
+    }
+}
+
+fn scalar_matches(value: &Value, needle: &str) -> bool {
+    match value {
+        Value::Object(_) | Value::Array(_) => false,
+        Value::String(s) => s.to_lowercase().contains(needle),
+        other => other.to_string().to_lowercase().contains(needle),
+    }
+}
+
+/// Whether `key`, `value` or anything beneath it contains `needle` (lowercase).
+pub fn subtree_matches(key: &str, value: &Value, needle: &str) -> bool {
+    if key.to_lowercase().contains(needle) || scalar_matches(value, needle) {
+        return true;
+    }
+    match value {
+        Value::Object(map) => map.iter().any(|(k, v)| subtree_matches(k, v, needle)),
+        Value::Array(items) => items
+            .iter()
+            .enumerate()
+            .any(|(i, v)| subtree_matches(&i.to_string(), v, needle)),
+        _ => false,
+    }
+}
+
+/// Index of the next (or previous) matching row after `from`, wrapping around.
+pub fn find_match(rows: &[TreeRow], from: usize, forward: bool) -> Option<usize> {
+    let len = rows.len();
+    (1..=len)
+        .map(|step| {
+            if forward {
+                (from + step) % len
+            } else {
+                (from + len - step % len) % len
+            }
+        })
+        .find(|&i| rows[i].matched)
+}
+
+/// Renders rows as lines indented by `indent` columns. Returns the lines and the
+/// line index of the cursor row, if any.
+pub fn lines(
+    rows: &[TreeRow],
+    cursor: Option<usize>,
+    indent: usize,
+) -> (Vec<Line<'static>>, usize) {
+    let mut out = Vec::new();
+    let mut cursor_line = 0;
+    for (idx, row) in rows.iter().enumerate() {
+        let pad = " ".repeat(indent + row.depth * 2);
+        let key_style = if row.matched {
+            Style::default()
+                .fg(theme::current().yellow)
+                .add_modifier(Modifier::BOLD)
+        } else if row.depth == 0 {
+            Style::default()
+                .fg(theme::current().accent)
+                .add_modifier(Modifier::BOLD)
+        } else {
+            Style::default().fg(theme::current().accent)
+        };
+
+        let mut spans = vec![Span::raw(pad.clone())];
+        let mut continuation = Vec::new();
+        match &row.value {
+            RowValue::Container {
+                is_array,
+                len,
+                expanded,
+            } => {
+                spans.push(Span::styled(
+                    if *expanded { "▾ " } else { "▸ " },
+                    Style::default().fg(theme::current().text_muted),
+                ));
+                spans.push(Span::styled(row.key.clone(), key_style));
+                let summary = match (is_array, expanded) {
+                    (true, true) => format!("  [{}]", len),
+                    (false, true) => format!("  {{{}}}", len),
+                    (true, false) => format!("  […] {} items", len),
+                    (false, false) => format!("  {{…}} {} keys", len),
+                };
+                spans.push(Span::styled(
+                    summary,
+                    Style::default().fg(theme::current().text_muted),
+                ));
+            }
+            RowValue::Binary(len) => {
+                spans.push(Span::raw("  "));
+                spans.push(Span::styled(format!("{}: ", row.key), key_style));
+                spans.push(Span::styled(
+                    binary_label(*len),
+                    Style::default().fg(theme::current().text_muted),
+                ));
+            }
+            RowValue::Scalar(value) => {
+                spans.push(Span::raw("  "));
+                spans.push(Span::styled(format!("{}: ", row.key), key_style));
+                let text = match value {
+                    Value::String(s) => s.clone(),
+                    other => other.to_string(),
+                };
+                let mut text_lines = text.lines();
+                spans.push(Span::styled(
+                    text_lines.next().unwrap_or("").to_string(),
+                    scalar_style(value, row.matched),
+                ));
+                for cont in text_lines {
+                    continuation.push(Line::from(Span::styled(
+                        format!("{}    {}", pad, cont),
+                        scalar_style(value, false),
+                    )));
+                }
+            }
+        }
+
+        let mut line = Line::from(spans);
+        if cursor == Some(idx) {
+            cursor_line = out.len();
+            line = line.style(Style::default().bg(theme::current().bg_highlight));
+        }
+        out.push(line);
+        out.extend(continuation);
+    }
+    (out, cursor_line)
+}
+
+fn scalar_style(value: &Value, matched: bool) -> Style {
+    if matched {
+        return Style::default().fg(theme::current().yellow);
+    }
+    match value {
+        Value::String(_) => Style::default().fg(theme::current().text),
+        Value::Null => Style::default().fg(theme::current().text_muted),
+        _ => Style::default().fg(theme::current().cyan),
+    }
+}
@@ -0,0 +1,50 @@
+use ratatui::layout::Rect;
+use ratatui::style::Style;
+use ratatui::text::{Line, Span};
+use ratatui::widgets::{Block, Borders, Clear, Paragraph, Wrap};
+use ratatui::Frame;
+
+use crate::app::App;
+use crate::theme;
+use crate::widgets::centered_rect;
+
+pub fn render(app: &App, frame: &mut Frame, area: Rect) {
+    let lines: Vec<Line> = if app.error_log.is_empty() {
+        vec![Line::from(Span::styled(
+            " No errors this session",
+            Style::default().fg(theme::current().text_muted),
+        ))]
+    } else {
+        app.error_log
+            .iter()
+            .rev()
+            .map(|(at, msg)| {
+                Line::from(vec![
+                    Span::styled(
+                        format!(" {} ", at.format("%H:%M:%S")),
+                        Style::default().fg(theme::current().text_muted),
+                    ),
+                    Span::styled(msg.clone(), Style::default().fg(theme::current().red)),
+                ])
+            })
+            .collect()
+    };
+
+    let height = area.height.saturating_sub(4).max(3);
+    let modal_area = centered_rect(80, height, area);
+    frame.render_widget(Clear, modal_area);
+
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(theme::current().accent))
+        .title(format!(
+            " Logs: {} errors, newest first (Esc to close) ",
+            app.error_log.len()
+        ));
+
+    let paragraph = Paragraph::new(lines)
+        .block(block)
+        .wrap(Wrap { trim: false })
+        .scroll((app.logs_scroll, 0));
+    frame.render_widget(paragraph, modal_area);
+}
@@ -1,11 +1,42 @@
 pub mod activity_execution_detail;
+pub mod audit_log;
+pub mod bookmarks;
 pub mod collection;
 pub mod command_input;
 pub mod confirm_modal;
+pub mod connection_error;
+pub mod context_picker;
 pub mod error_toast;
 pub mod footer;
 pub mod help_overlay;
+pub mod json_tree;
+pub mod logs_panel;
 pub mod namespace_selector;
+pub mod overview;
+pub mod pager;
+pub mod pending_activity;
+pub mod query_builder;
+pub mod query_picker;
+pub mod quick_actions;
 pub mod schedule_detail;
+pub mod search_attributes;
+pub mod signal_picker;
 pub mod tab_bar;
+pub mod task_queue;
+pub mod time_range;
 pub mod workflow_detail;
+pub mod workflow_preview;
+
+use ratatui::layout::{Constraint, Flex, Layout, Rect};
+
+/// A rect `percent_x` of `area`'s width and up to `height` rows tall in the
+/// middle of `area`, for modals.
+pub fn centered_rect(percent_x: u16, height: u16, area: Rect) -> Rect {
+    let vertical = Layout::vertical([Constraint::Length(height.min(area.height))])
+        .flex(Flex::Center)
+        .split(area);
+    let horizontal = Layout::horizontal([Constraint::Percentage(percent_x)])
+        .flex(Flex::Center)
+        .split(vertical[0]);
+    horizontal[0]
+}
@@ -1,50 +1,88 @@
-use ratatui::layout::{Constraint, Flex, Layout, Rect};
+use ratatui::layout::{Constraint, Rect};
 use ratatui::style::{Modifier, Style};
 use ratatui::widgets::{Block, Borders, Cell, Clear, Row, Table};
 use ratatui::Frame;
 
 use crate::app::App;
+use crate::domain::format_duration_text;
 use crate::theme;
+use crate::widgets::centered_rect;
 
 pub fn render(app: &mut App, frame: &mut Frame, area: Rect) {
-    let height = (app.namespaces.len() as u16 + 3).min(area.height.saturating_sub(4));
-    let modal_area = centered_rect(40, height, area);
+    let namespaces = app.visible_namespaces();
+    // Keep the height while filtering so the modal doesn't jump around.
+    let height = (app.namespaces.len() as u16 + 4).min(area.height.saturating_sub(4));
+    let modal_area = centered_rect(60, height, area);
     frame.render_widget(Clear, modal_area);
 
-    let rows: Vec<Row> = app
-        .namespaces
+    let rows: Vec<Row> = namespaces
         .iter()
         .map(|ns| {
-            let indicator = if ns.name == app.namespace { "* " } else { "  " };
-            Row::new(vec![Cell::from(format!("{}{}", indicator, ns.name))])
+            let favorite = app
+                .favorite_namespaces
+                .iter()
+                .take(9)
+                .position(|name| *name == ns.name);
+            let indicator = match favorite {
+                _ if ns.name == app.namespace => "* ".to_string(),
+                Some(i) => format!("{} ", i + 1),
+                None => "  ".to_string(),
+            };
+            let retention = ns
+                .retention
+                .map(|d| format_duration_text(d.as_secs() as i64))
+                .unwrap_or_default();
+            // Registered is the normal state; call out the others.
+            let state_color = if ns.state.eq_ignore_ascii_case("registered") {
+                theme::current().text_dim
+            } else {
+                theme::current().yellow
+            };
+            Row::new(vec![
+                Cell::from(format!("{}{}", indicator, ns.name)),
+                Cell::from(retention).style(Style::default().fg(theme::current().text_dim)),
+                Cell::from(ns.state.to_lowercase()).style(Style::default().fg(state_color)),
+            ])
         })
         .collect();
 
-    let widths = [Constraint::Fill(1)];
+    let widths = [
+        Constraint::Fill(1),
+        Constraint::Length(9),
+        Constraint::Length(11),
+    ];
+    let header = Row::new(vec!["  NAME", "RETENTION", "STATE"]).style(
+        Style::default()
+            .fg(theme::current().text_muted)
+            .add_modifier(Modifier::BOLD),
+    );
 
+    let title = if app.capabilities.namespace_admin {
+        " Select Namespace (Enter select, / filter, n new, e describe, t retention, D deprecate) "
+    } else {
+        " Select Namespace (Enter to select, / to filter, Esc to cancel) "
+    };
+    let mut block = Block::default()
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(theme::current().accent))
+        .title(title);
+    if !app.namespace_filter.is_empty() {
+        block = block.title_bottom(format!(
+            " /{} ({} of {}) ",
+            app.namespace_filter,
+            namespaces.len(),
+            app.namespaces.len()
+        ));
+    }
     let table = Table::new(rows, widths)
-        .block(
-            Block::default()
-                .borders(Borders::ALL)
-                .border_style(Style::default().fg(theme::PURPLE))
-                .title(" Select Namespace (Enter to select, Esc to cancel) "),
-        )
+        .header(header)
+        .block(block)
         .row_highlight_style(
             Style::default()
-                .bg(theme::BG_HIGHLIGHT)
+                .bg(theme::current().bg_highlight)
                 .add_modifier(Modifier::BOLD),
         )
         .highlight_symbol("▸ ");
 
     frame.render_stateful_widget(table, modal_area, &mut app.namespace_selector_state);
 }
-
-fn centered_rect(percent_x: u16, height: u16, area: Rect) -> Rect {
-    let vertical = Layout::vertical([Constraint::Length(height)])
-        .flex(Flex::Center)
-        .split(area);
-    let horizontal = Layout::horizontal([Constraint::Percentage(percent_x)])
-        .flex(Flex::Center)
-        .split(vertical[0]);
-    horizontal[0]
-}
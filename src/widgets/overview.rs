@@ -0,0 +1,97 @@
+use ratatui::layout::Rect;
+use ratatui::style::{Modifier, Style};
+use ratatui::text::{Line, Span};
+use ratatui::widgets::{Block, Borders, Clear, Paragraph};
+use ratatui::Frame;
+
+use crate::app::{App, LoadState};
+use crate::domain::{WorkflowStatus, STATUS_SECTION};
+use crate::kinds::workflow_status_color;
+use crate::theme;
+use crate::widgets::centered_rect;
+
+pub fn render(app: &App, frame: &mut Frame, area: Rect) {
+    let mut cursor_line = 0;
+    let mut lines: Vec<Line> = match &app.overview {
+        LoadState::NotLoaded | LoadState::Loading => vec![Line::from(Span::styled(
+            " Loading overview...",
+            Style::default().fg(theme::current().text_dim),
+        ))],
+        LoadState::Error(msg) => vec![Line::from(Span::styled(
+            format!(" {}", msg),
+            Style::default().fg(theme::current().red),
+        ))],
+        LoadState::Loaded(overview) => {
+            let rows = overview.rows();
+            let width = rows
+                .iter()
+                .map(|r| r.label.chars().count())
+                .max()
+                .unwrap_or(0);
+            let mut lines = vec![];
+            let mut section = "";
+            for (i, row) in rows.iter().enumerate() {
+                if row.section != section {
+                    if !section.is_empty() {
+                        lines.push(Line::from(""));
+                    }
+                    section = row.section;
+                    lines.push(Line::from(Span::styled(
+                        format!(" {}", section),
+                        Style::default()
+                            .fg(theme::current().text_dim)
+                            .add_modifier(Modifier::BOLD),
+                    )));
+                }
+                let label_style = match WorkflowStatus::parse(&row.label) {
+                    _ if row.error => Style::default().fg(theme::current().red),
+                    Some(status) if row.section == STATUS_SECTION => workflow_status_color(&status),
+                    _ if row.query.is_some() => Style::default().fg(theme::current().accent),
+                    _ => Style::default().fg(theme::current().text),
+                };
+                let mut spans = vec![Span::styled(
+                    format!("   {:<width$}", row.label),
+                    label_style,
+                )];
+                if let Some(count) = row.count {
+                    spans.push(Span::styled(
+                        format!("  {:>8}", count),
+                        Style::default().fg(theme::current().text),
+                    ));
+                }
+                let line = Line::from(spans);
+                if i == app.overview_cursor {
+                    cursor_line = lines.len();
+                    lines.push(line.style(Style::default().bg(theme::current().bg_highlight)));
+                } else {
+                    lines.push(line);
+                }
+            }
+            lines
+        }
+    };
+    lines.push(Line::from(""));
+    lines.push(Line::from(Span::styled(
+        " Enter to list matching workflows | r to reload | Esc to close",
+        Style::default().fg(theme::current().text_dim),
+    )));
+
+    let height = (lines.len() as u16 + 2).min(area.height);
+    let modal_area = centered_rect(60, height, area);
+    frame.render_widget(Clear, modal_area);
+
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(theme::current().accent))
+        .title(format!(" Overview ({}) ", app.namespace));
+
+    // Keep the cursor row on screen when the overview is taller than the modal.
+    let visible = modal_area.height.saturating_sub(4) as usize;
+    let scroll = cursor_line.saturating_sub(visible.saturating_sub(1));
+    frame.render_widget(
+        Paragraph::new(lines)
+            .block(block)
+            .scroll((scroll as u16, 0)),
+        modal_area,
+    );
+}
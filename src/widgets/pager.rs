@@ -0,0 +1,71 @@
+use ratatui::layout::Rect;
+use ratatui::style::{Modifier, Style};
+use ratatui::text::{Line, Span};
+use ratatui::widgets::{Block, Borders, Clear, Paragraph};
+use ratatui::Frame;
+
+use crate::app::App;
+use crate::theme;
+use crate::widgets::centered_rect;
+
+pub fn render(app: &App, frame: &mut Frame, area: Rect) {
+    let pager = &app.pager;
+    let modal_area = centered_rect(90, area.height.saturating_sub(4), area);
+    frame.render_widget(Clear, modal_area);
+
+    let height = modal_area.height.saturating_sub(2) as usize;
+    let lines: Vec<Line> = pager
+        .lines
+        .iter()
+        .skip(pager.scroll)
+        .take(height)
+        .map(|line| highlight(line, pager.search.as_deref()))
+        .collect();
+
+    let mut status = format!(" {}% ", pager.percent(height));
+    if pager.search.is_some() {
+        let matches = pager.matches();
+        status = match matches.iter().position(|&i| i == pager.scroll) {
+            Some(i) => format!(" match {}/{} |{}", i + 1, matches.len(), status),
+            None => format!(" {} matches |{}", matches.len(), status),
+        };
+    }
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(theme::current().accent))
+        .title(format!(" {} ", pager.title))
+        .title_bottom(Line::from(Span::styled(
+            " j/k scroll | / search | n/N next/prev | Esc to close ",
+            Style::default().fg(theme::current().text_dim),
+        )))
+        .title_bottom(
+            Line::from(Span::styled(
+                status,
+                Style::default().fg(theme::current().text_dim),
+            ))
+            .right_aligned(),
+        );
+    frame.render_widget(Paragraph::new(lines).block(block), modal_area);
+}
+
+/// A pager line with each occurrence of `needle` picked out.
+fn highlight<'a>(line: &'a str, needle: Option<&str>) -> Line<'a> {
+    let text = Style::default().fg(theme::current().text);
+    let Some(needle) = needle else {
+        return Line::from(Span::styled(line, text));
+    };
+    let hit = Style::default()
+        .fg(theme::current().yellow)
+        .add_modifier(Modifier::BOLD | Modifier::REVERSED);
+    // ASCII lowercasing keeps byte offsets lined up with `line`.
+    let lower = line.to_ascii_lowercase();
+    let mut spans = Vec::new();
+    let mut start = 0;
+    for (at, _) in lower.match_indices(needle) {
+        spans.push(Span::styled(&line[start..at], text));
+        spans.push(Span::styled(&line[at..at + needle.len()], hit));
+        start = at + needle.len();
+    }
+    spans.push(Span::styled(&line[start..], text));
+    Line::from(spans)
+}
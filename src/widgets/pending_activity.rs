@@ -0,0 +1,183 @@
+use ratatui::layout::Rect;
+use ratatui::style::{Modifier, Style};
+use ratatui::text::{Line, Span};
+use ratatui::widgets::{Block, Borders, Clear, Paragraph, Wrap};
+use ratatui::Frame;
+
+use crate::app::App;
+use crate::i18n::{t, tf, Msg};
+use crate::theme;
+use crate::widgets::centered_rect;
+
+pub fn render(app: &App, frame: &mut Frame, area: Rect) {
+    let Some(activity) = app.selected_pending_activity() else {
+        return;
+    };
+
+    let time = |t: &Option<chrono::DateTime<chrono::Utc>>| {
+        t.as_ref()
+            .map(|t| app.time_display.format(t))
+            .unwrap_or_else(|| "-".to_string())
+    };
+    let attempts = if activity.maximum_attempts > 0 {
+        tf(
+            Msg::ActivityAttemptOf,
+            &[
+                &activity.attempt.to_string(),
+                &activity.maximum_attempts.to_string(),
+            ],
+        )
+    } else {
+        tf(
+            Msg::ActivityAttemptUnlimited,
+            &[&activity.attempt.to_string()],
+        )
+    };
+    let worker = if activity.last_worker_identity.is_empty() {
+        "-".to_string()
+    } else {
+        activity.last_worker_identity.clone()
+    };
+    let mut lines = vec![
+        field(Msg::ActivityType, activity.activity_type.clone()),
+        field(Msg::ActivityState, activity.state.to_string()),
+        field(Msg::ActivityAttempt, attempts),
+        field(Msg::ActivityScheduled, time(&activity.scheduled_time)),
+        field(Msg::ActivityLastStarted, time(&activity.last_started_time)),
+        field(
+            Msg::ActivityLastHeartbeat,
+            time(&activity.last_heartbeat_time),
+        ),
+        field(Msg::ActivityNextRetry, time(&activity.next_attempt_time)),
+        field(Msg::ActivityLastWorker, worker),
+    ];
+
+    if let Some(ref options) = activity.options {
+        let text = |value: &Option<String>| value.clone().unwrap_or_else(|| "-".to_string());
+        let attempts = match options.retry_maximum_attempts {
+            0 => t(Msg::ActivityUnlimited).to_string(),
+            n => n.to_string(),
+        };
+        lines.push(Line::from(""));
+        lines.push(section(Msg::ActivityOptions));
+        lines.push(field(
+            Msg::ActivityScheduleToClose,
+            text(&options.schedule_to_close_timeout),
+        ));
+        lines.push(field(
+            Msg::ActivityScheduleToStart,
+            text(&options.schedule_to_start_timeout),
+        ));
+        lines.push(field(
+            Msg::ActivityStartToClose,
+            text(&options.start_to_close_timeout),
+        ));
+        lines.push(field(
+            Msg::ActivityHeartbeat,
+            text(&options.heartbeat_timeout),
+        ));
+        lines.push(field(
+            Msg::ActivityRetryInterval,
+            tf(
+                Msg::ActivityRetryIntervalValue,
+                &[
+                    &text(&options.retry_initial_interval),
+                    &options.retry_backoff_coefficient.to_string(),
+                    &text(&options.retry_maximum_interval),
+                ],
+            ),
+        ));
+        lines.push(field(Msg::ActivityRetryAttempts, attempts));
+        if !options.non_retryable_error_types.is_empty() {
+            lines.push(field(
+                Msg::ActivityNonRetryable,
+                options.non_retryable_error_types.join(", "),
+            ));
+        }
+    }
+
+    lines.push(Line::from(""));
+    lines.push(section(Msg::ActivityHeartbeatDetails));
+    match &activity.heartbeat_details {
+        Some(details) => {
+            let text = serde_json::to_string_pretty(&app.masked(details)).unwrap_or_default();
+            lines.extend(text.lines().map(|line| {
+                Line::from(Span::styled(
+                    format!("   {}", line),
+                    Style::default().fg(theme::current().text),
+                ))
+            }));
+        }
+        None => lines.push(muted(Msg::ActivityNone)),
+    }
+
+    lines.push(Line::from(""));
+    lines.push(section(Msg::ActivityLastFailure));
+    if activity.last_failure.is_none() {
+        lines.push(muted(Msg::ActivityNone));
+    }
+    let mut failure = activity.last_failure.as_ref();
+    let mut first = true;
+    while let Some(f) = failure {
+        if !first {
+            lines.push(muted(Msg::ActivityCausedBy));
+        }
+        first = false;
+        lines.push(Line::from(Span::styled(
+            format!("   {}: {}", f.failure_type, f.message),
+            Style::default().fg(theme::current().red),
+        )));
+        if let Some(ref trace) = f.stack_trace {
+            lines.extend(trace.lines().map(|line| {
+                Line::from(Span::styled(
+                    format!("     {}", line),
+                    Style::default().fg(theme::current().text_muted),
+                ))
+            }));
+        }
+        failure = f.cause.as_deref();
+    }
+
+    let height = area.height.saturating_sub(4).max(3);
+    let modal_area = centered_rect(80, height, area);
+    frame.render_widget(Clear, modal_area);
+
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(theme::current().accent))
+        .title(tf(Msg::ActivityTitle, &[&activity.activity_id]));
+
+    let paragraph = Paragraph::new(lines)
+        .block(block)
+        .wrap(Wrap { trim: false })
+        .scroll((app.logs_scroll, 0));
+    frame.render_widget(paragraph, modal_area);
+}
+
+fn field(label: Msg, value: String) -> Line<'static> {
+    Line::from(vec![
+        Span::styled(
+            format!(" {:<18} ", t(label)),
+            Style::default()
+                .fg(theme::current().accent)
+                .add_modifier(Modifier::BOLD),
+        ),
+        Span::styled(value, Style::default().fg(theme::current().text)),
+    ])
+}
+
+fn section(title: Msg) -> Line<'static> {
+    Line::from(Span::styled(
+        format!(" {}:", t(title)),
+        Style::default()
+            .fg(theme::current().accent)
+            .add_modifier(Modifier::BOLD),
+    ))
+}
+
+fn muted(text: Msg) -> Line<'static> {
+    Line::from(Span::styled(
+        format!("   {}", t(text)),
+        Style::default().fg(theme::current().text_muted),
+    ))
+}
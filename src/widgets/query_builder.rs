@@ -0,0 +1,96 @@
+use ratatui::layout::Rect;
+use ratatui::style::{Modifier, Style};
+use ratatui::text::{Line, Span};
+use ratatui::widgets::{Block, Borders, Clear, Paragraph, Wrap};
+use ratatui::Frame;
+
+use crate::app::App;
+use crate::input::query_builder::BuilderStep;
+use crate::theme;
+use crate::widgets::centered_rect;
+
+pub fn render(app: &App, frame: &mut Frame, area: Rect) {
+    let builder = &app.query_builder;
+    let query = [builder.query(), builder.pending()]
+        .into_iter()
+        .filter(|part| !part.is_empty())
+        .collect::<Vec<_>>()
+        .join(" ");
+
+    let mut lines = vec![
+        Line::from(vec![
+            Span::styled(
+                " Query: ",
+                Style::default()
+                    .fg(theme::current().accent)
+                    .add_modifier(Modifier::BOLD),
+            ),
+            Span::styled(query, Style::default().fg(theme::current().text)),
+        ]),
+        Line::from(""),
+    ];
+    let prompt = match builder.step {
+        BuilderStep::Attribute => " Attribute",
+        BuilderStep::Operator => " Operator",
+        BuilderStep::Value => " Value (comma-separated for IN)",
+        BuilderStep::Next => " Then",
+    };
+    lines.push(Line::from(Span::styled(
+        prompt,
+        Style::default().fg(theme::current().text_dim),
+    )));
+
+    let options = builder.options(&app.query_builder_attributes());
+    // Keep the cursor row on screen in long attribute lists.
+    let visible = (area.height as usize).saturating_sub(12).max(3);
+    let first = builder.cursor.saturating_sub(visible.saturating_sub(1));
+    if builder.step == BuilderStep::Value {
+        lines.push(Line::from(vec![
+            Span::styled("   > ", Style::default().fg(theme::current().accent)),
+            Span::styled(
+                builder.value.clone(),
+                Style::default().fg(theme::current().text),
+            ),
+            Span::styled("█", Style::default().fg(theme::current().accent)),
+        ]));
+    } else {
+        lines.extend(
+            options
+                .iter()
+                .enumerate()
+                .skip(first)
+                .take(visible)
+                .map(|(i, option)| {
+                    let line = Line::from(Span::styled(
+                        format!("   {}", option),
+                        Style::default().fg(theme::current().text),
+                    ));
+                    if i == builder.cursor {
+                        line.style(Style::default().bg(theme::current().bg_highlight))
+                    } else {
+                        line
+                    }
+                }),
+        );
+    }
+    lines.push(Line::from(""));
+    lines.push(Line::from(Span::styled(
+        " Enter to choose | Backspace to go back | Esc to cancel",
+        Style::default().fg(theme::current().text_dim),
+    )));
+
+    let modal_area = centered_rect(60, lines.len() as u16 + 2, area);
+    frame.render_widget(Clear, modal_area);
+
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(theme::current().accent))
+        .title(" Query Builder ");
+
+    frame.render_widget(
+        Paragraph::new(lines)
+            .block(block)
+            .wrap(Wrap { trim: false }),
+        modal_area,
+    );
+}
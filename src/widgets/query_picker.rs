@@ -0,0 +1,58 @@
+use ratatui::layout::Rect;
+use ratatui::style::{Modifier, Style};
+use ratatui::text::{Line, Span};
+use ratatui::widgets::{Block, Borders, Clear, Paragraph};
+use ratatui::Frame;
+
+use crate::app::App;
+use crate::theme;
+use crate::widgets::centered_rect;
+
+pub fn render(app: &App, frame: &mut Frame, area: Rect) {
+    let queries = app.picker_saved_queries();
+    let mut lines: Vec<Line> = queries
+        .iter()
+        .enumerate()
+        .map(|(i, query)| {
+            let line = Line::from(vec![
+                Span::styled(
+                    format!(" {} ", query.name),
+                    Style::default()
+                        .fg(theme::current().accent)
+                        .add_modifier(Modifier::BOLD),
+                ),
+                Span::styled(
+                    query.query.clone(),
+                    Style::default().fg(theme::current().text_dim),
+                ),
+            ]);
+            if i == app.query_picker_cursor {
+                line.style(Style::default().bg(theme::current().bg_highlight))
+            } else {
+                line
+            }
+        })
+        .collect();
+    lines.push(Line::from(""));
+    lines.push(Line::from(Span::styled(
+        " Enter to apply | Esc to cancel",
+        Style::default().fg(theme::current().text_dim),
+    )));
+
+    let modal_area = centered_rect(60, lines.len() as u16 + 2, area);
+    frame.render_widget(Clear, modal_area);
+
+    let kind = match app.view {
+        crate::app::View::Collection(kind) | crate::app::View::Detail(kind) => kind,
+    };
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(theme::current().accent))
+        .title(format!(
+            " Saved {} queries in {} ",
+            kind.label().to_lowercase(),
+            app.namespace
+        ));
+
+    frame.render_widget(Paragraph::new(lines).block(block), modal_area);
+}
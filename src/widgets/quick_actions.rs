@@ -0,0 +1,54 @@
+use ratatui::layout::Rect;
+use ratatui::style::{Modifier, Style};
+use ratatui::text::{Line, Span};
+use ratatui::widgets::{Block, Borders, Clear, Paragraph};
+use ratatui::Frame;
+
+use crate::app::App;
+use crate::i18n::{t, Msg};
+use crate::theme;
+use crate::widgets::centered_rect;
+
+pub fn render(app: &App, frame: &mut Frame, area: Rect) {
+    let kind = app.current_kind_id();
+    let mut lines: Vec<Line> = app
+        .quick_actions()
+        .into_iter()
+        .enumerate()
+        .map(|(i, action)| {
+            let key = action.key(kind).map(String::from).unwrap_or_default();
+            let line = Line::from(vec![
+                Span::styled(
+                    format!(" {:<3}", key),
+                    Style::default()
+                        .fg(theme::current().accent)
+                        .add_modifier(Modifier::BOLD),
+                ),
+                Span::styled(
+                    action.label(kind),
+                    Style::default().fg(theme::current().text),
+                ),
+            ]);
+            if i == app.quick_action_cursor {
+                line.style(Style::default().bg(theme::current().bg_highlight))
+            } else {
+                line
+            }
+        })
+        .collect();
+    lines.push(Line::from(""));
+    lines.push(Line::from(Span::styled(
+        t(Msg::QuickActionsHint),
+        Style::default().fg(theme::current().text_dim),
+    )));
+
+    let modal_area = centered_rect(40, lines.len() as u16 + 2, area);
+    frame.render_widget(Clear, modal_area);
+
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(theme::current().accent))
+        .title(t(Msg::QuickActionsTitle));
+
+    frame.render_widget(Paragraph::new(lines).block(block), modal_area);
+}
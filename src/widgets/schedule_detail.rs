@@ -1,35 +1,82 @@
-use ratatui::layout::Rect;
+use ratatui::layout::{Constraint, Layout, Rect};
 use ratatui::style::{Modifier, Style};
 use ratatui::text::{Line, Span};
 use ratatui::widgets::{Block, Borders, Paragraph, Wrap};
 use ratatui::Frame;
 
 use crate::app::App;
+use crate::domain::{format_relative, format_span, DstKind, DST_LOOKAHEAD_DAYS, UPCOMING_RUNS};
+use crate::i18n::{t, Msg};
+use crate::kinds::{detail_tabs_for_kind, KindId};
 use crate::theme;
+use crate::widgets::workflow_detail::render_raw;
 
 pub fn render(app: &App, frame: &mut Frame, area: Rect) {
     let schedule = match &app.selected_schedule {
         Some(s) => s,
         None => {
-            let loading = Paragraph::new(" Loading schedule detail...")
-                .style(Style::default().fg(theme::TEXT_MUTED));
+            let loading = Paragraph::new(t(Msg::LoadingScheduleDetail))
+                .style(Style::default().fg(theme::current().text_muted));
             frame.render_widget(loading, area);
             return;
         }
     };
 
+    let layout = Layout::vertical([
+        Constraint::Length(1), // tab bar
+        Constraint::Fill(1),   // content
+    ])
+    .split(area);
+
+    let tabs = detail_tabs_for_kind(KindId::Schedule).unwrap_or(&[]);
+    let mut tab_spans: Vec<Span> = vec![Span::raw(" ")];
+    for (i, tab) in tabs.iter().enumerate() {
+        let style = if i == app.schedule_detail_tab {
+            Style::default()
+                .fg(theme::current().accent)
+                .add_modifier(Modifier::BOLD | Modifier::UNDERLINED)
+        } else {
+            Style::default().fg(theme::current().text_muted)
+        };
+        tab_spans.push(Span::styled(format!(" {} ", tab), style));
+        tab_spans.push(Span::raw(" "));
+    }
+    frame.render_widget(Paragraph::new(Line::from(tab_spans)), layout[0]);
+
+    if app.schedule_detail_tab == 1 {
+        render_raw(app, frame, layout[1], app.detail_scroll);
+        return;
+    }
+    let area = layout[1];
+
+    let tz = schedule.tz();
     let next_run = schedule
         .next_run
         .map(|t| {
-            let local = t.with_timezone(&chrono::Local);
-            local.format("%Y-%m-%d %H:%M:%S").to_string()
+            let zoned = schedule.format_in_zone(&t);
+            if tz.is_some() {
+                let local = t.with_timezone(&chrono::Local);
+                format!("{} (local {})", zoned, local.format("%Y-%m-%d %H:%M:%S"))
+            } else {
+                zoned
+            }
         })
         .unwrap_or_else(|| "-".to_string());
+    let timezone = match (&schedule.timezone, tz) {
+        (None, _) => "UTC (default)".to_string(),
+        (Some(name), Some(_)) => name.clone(),
+        (Some(name), None) => format!("{} (unknown zone, times shown in local time)", name),
+    };
+    let spec = if schedule.spec_description.is_empty() {
+        "-".to_string()
+    } else {
+        schedule.spec_description.clone()
+    };
     let action_count = schedule.recent_action_count.to_string();
 
     let state_style = match schedule.state {
-        crate::domain::ScheduleState::Active => Style::default().fg(theme::GREEN),
-        crate::domain::ScheduleState::Paused => Style::default().fg(theme::YELLOW),
+        crate::domain::ScheduleState::Active => Style::default().fg(theme::current().green),
+        crate::domain::ScheduleState::Paused => Style::default().fg(theme::current().yellow),
     };
 
     let mut lines = vec![
@@ -39,25 +86,134 @@ pub fn render(app: &App, frame: &mut Frame, area: Rect) {
             Span::styled(
                 format!(" {:<20} ", "State"),
                 Style::default()
-                    .fg(theme::PURPLE)
+                    .fg(theme::current().accent)
                     .add_modifier(Modifier::BOLD),
             ),
             Span::styled(schedule.state.as_str(), state_style),
         ]),
+    ];
+    // The pause note explains the state, so it sits right under it.
+    for (idx, note) in schedule.notes.lines().enumerate() {
+        let label = if idx == 0 { "Note" } else { "" };
+        lines.push(Line::from(vec![
+            Span::styled(
+                format!(" {:<20} ", label),
+                Style::default()
+                    .fg(theme::current().accent)
+                    .add_modifier(Modifier::BOLD),
+            ),
+            Span::styled(note.to_string(), state_style),
+        ]));
+    }
+    lines.extend([
+        field_line("Spec", &spec),
+        field_line("Time Zone", &timezone),
         field_line("Next Run", &next_run),
         field_line("Recent Actions", &action_count),
-    ];
+    ]);
 
-    if !schedule.notes.is_empty() {
+    let warnings = schedule.dst_warnings(chrono::Utc::now(), DST_LOOKAHEAD_DAYS);
+    if !warnings.is_empty() {
+        let zone = schedule.timezone.as_deref().unwrap_or_default();
         lines.push(Line::from(""));
         lines.push(Line::from(Span::styled(
-            " Notes:",
+            " DST warnings:",
             Style::default()
-                .fg(theme::PURPLE)
+                .fg(theme::current().yellow)
                 .add_modifier(Modifier::BOLD),
         )));
-        for line in schedule.notes.lines() {
-            lines.push(Line::from(format!("   {}", line)));
+        for warning in &warnings {
+            let text = match warning.kind {
+                DstKind::Skipped => format!(
+                    "   ⚠ {} does not exist in {} (clocks jump forward); that run will be skipped",
+                    warning.at.format("%Y-%m-%d %H:%M"),
+                    zone
+                ),
+                DstKind::Repeated => format!(
+                    "   ⚠ {} happens twice in {} (clocks fall back); that run may fire twice",
+                    warning.at.format("%Y-%m-%d %H:%M"),
+                    zone
+                ),
+            };
+            lines.push(Line::from(Span::styled(
+                text,
+                Style::default().fg(theme::current().yellow),
+            )));
+        }
+    }
+
+    if !schedule.upcoming_runs.is_empty() {
+        let now = chrono::Utc::now();
+        lines.push(Line::from(""));
+        lines.push(Line::from(Span::styled(
+            " Upcoming runs:",
+            Style::default()
+                .fg(theme::current().accent)
+                .add_modifier(Modifier::BOLD),
+        )));
+        let mut previous = None;
+        for run in schedule.upcoming_runs.iter().take(UPCOMING_RUNS) {
+            // The gap to the previous run shows the firing pattern.
+            let gap = previous
+                .map(|p| format!("+{}", format_span(*run - p)))
+                .unwrap_or_default();
+            lines.push(Line::from(vec![
+                Span::styled(
+                    format!("   {:<28} ", schedule.format_in_zone(run)),
+                    Style::default().fg(theme::current().text),
+                ),
+                Span::styled(
+                    format!("{:<12} ", format_relative(run, now)),
+                    Style::default().fg(theme::current().cyan),
+                ),
+                Span::styled(gap, Style::default().fg(theme::current().text_muted)),
+            ]));
+            previous = Some(*run);
+        }
+    }
+
+    if !schedule.recent_actions.is_empty() {
+        lines.push(Line::from(""));
+        lines.push(Line::from(Span::styled(
+            format!(
+                " {:<24} {:<24} {:<36} {:<36} {}",
+                "SCHEDULED", "ACTUAL", "WORKFLOW ID", "RUN ID", "STATUS"
+            ),
+            Style::default()
+                .fg(theme::current().accent)
+                .add_modifier(Modifier::BOLD),
+        )));
+        let time = |t: &Option<chrono::DateTime<chrono::Utc>>| {
+            t.map(|t| schedule.format_in_zone(&t))
+                .unwrap_or_else(|| "-".to_string())
+        };
+        for (idx, action) in schedule.recent_actions.iter().enumerate() {
+            let mut line = Line::from(vec![
+                Span::styled(
+                    format!(" {:<24} ", time(&action.scheduled_time)),
+                    Style::default().fg(theme::current().text_dim),
+                ),
+                Span::styled(
+                    format!("{:<24} ", time(&action.actual_time)),
+                    Style::default().fg(theme::current().text),
+                ),
+                Span::styled(
+                    format!("{:<36} ", action.workflow_id),
+                    Style::default().fg(theme::current().cyan),
+                ),
+                Span::styled(
+                    format!("{:<36} ", action.run_id),
+                    Style::default().fg(theme::current().text_muted),
+                ),
+                Span::styled(
+                    action.status.as_ref().map_or("-", |s| s.as_str()),
+                    Style::default().fg(theme::current().text),
+                ),
+            ]);
+            if idx == app.schedule_action_cursor {
+                line = line.style(Style::default().bg(theme::current().bg_highlight));
+            }
+            lines.push(line);
         }
     }
 
@@ -73,9 +229,12 @@ fn field_line<'a>(label: &'a str, value: &'a str) -> Line<'a> {
         Span::styled(
             format!(" {:<20} ", label),
             Style::default()
-                .fg(theme::PURPLE)
+                .fg(theme::current().accent)
                 .add_modifier(Modifier::BOLD),
         ),
-        Span::styled(value.to_string(), Style::default().fg(theme::TEXT)),
+        Span::styled(
+            value.to_string(),
+            Style::default().fg(theme::current().text),
+        ),
     ])
 }
@@ -0,0 +1,86 @@
+use ratatui::layout::Rect;
+use ratatui::style::{Modifier, Style};
+use ratatui::text::{Line, Span};
+use ratatui::widgets::{Block, Borders, Clear, Paragraph};
+use ratatui::Frame;
+
+use crate::app::{App, LoadState};
+use crate::theme;
+use crate::widgets::centered_rect;
+
+pub fn render(app: &App, frame: &mut Frame, area: Rect) {
+    let mut lines: Vec<Line> = match &app.search_attributes {
+        LoadState::NotLoaded | LoadState::Loading => vec![Line::from(Span::styled(
+            " Loading search attributes...",
+            Style::default().fg(theme::current().text_dim),
+        ))],
+        LoadState::Error(msg) => vec![Line::from(Span::styled(
+            format!(" {}", msg),
+            Style::default().fg(theme::current().red),
+        ))],
+        LoadState::Loaded(attributes) => {
+            let width = attributes
+                .iter()
+                .map(|a| a.name.chars().count())
+                .max()
+                .unwrap_or(0);
+            attributes
+                .iter()
+                .enumerate()
+                .map(|(i, attribute)| {
+                    let line = Line::from(vec![
+                        Span::styled(
+                            format!(" {:<width$}  ", attribute.name),
+                            Style::default()
+                                .fg(theme::current().accent)
+                                .add_modifier(Modifier::BOLD),
+                        ),
+                        Span::styled(
+                            format!(
+                                "{:<12}",
+                                attribute.value_type.map_or("Unknown", |t| t.label())
+                            ),
+                            Style::default().fg(theme::current().text),
+                        ),
+                        if attribute.custom {
+                            Span::styled("custom", Style::default().fg(theme::current().green))
+                        } else {
+                            Span::styled("system", Style::default().fg(theme::current().text_dim))
+                        },
+                    ]);
+                    if i == app.search_attribute_cursor {
+                        line.style(Style::default().bg(theme::current().bg_highlight))
+                    } else {
+                        line
+                    }
+                })
+                .collect()
+        }
+    };
+    lines.push(Line::from(""));
+    lines.push(Line::from(Span::styled(
+        " a to add | Esc to close",
+        Style::default().fg(theme::current().text_dim),
+    )));
+
+    let height = (lines.len() as u16 + 2).min(area.height);
+    let modal_area = centered_rect(60, height, area);
+    frame.render_widget(Clear, modal_area);
+
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(theme::current().accent))
+        .title(format!(" Search Attributes ({}) ", app.namespace));
+
+    // Keep the cursor row on screen when the list is taller than the modal.
+    let visible = modal_area.height.saturating_sub(4) as usize;
+    let scroll = app
+        .search_attribute_cursor
+        .saturating_sub(visible.saturating_sub(1));
+    frame.render_widget(
+        Paragraph::new(lines)
+            .block(block)
+            .scroll((scroll as u16, 0)),
+        modal_area,
+    );
+}
@@ -0,0 +1,52 @@
+use ratatui::layout::Rect;
+use ratatui::style::{Modifier, Style};
+use ratatui::text::{Line, Span};
+use ratatui::widgets::{Block, Borders, Clear, Paragraph};
+use ratatui::Frame;
+
+use crate::app::App;
+use crate::theme;
+use crate::widgets::centered_rect;
+
+pub fn render(app: &App, frame: &mut Frame, area: Rect) {
+    let signals = app.namespace_recent_signals();
+    let mut lines: Vec<Line> = signals
+        .iter()
+        .enumerate()
+        .map(|(i, signal)| {
+            let mut spans = vec![Span::styled(
+                format!(" {} ", signal.name),
+                Style::default()
+                    .fg(theme::current().accent)
+                    .add_modifier(Modifier::BOLD),
+            )];
+            if let Some(ref input) = signal.input {
+                spans.push(Span::styled(
+                    input.clone(),
+                    Style::default().fg(theme::current().text_dim),
+                ));
+            }
+            let line = Line::from(spans);
+            if i == app.signal_picker_cursor {
+                line.style(Style::default().bg(theme::current().bg_highlight))
+            } else {
+                line
+            }
+        })
+        .collect();
+    lines.push(Line::from(""));
+    lines.push(Line::from(Span::styled(
+        " Enter to edit and send | Esc to cancel",
+        Style::default().fg(theme::current().text_dim),
+    )));
+
+    let modal_area = centered_rect(60, lines.len() as u16 + 2, area);
+    frame.render_widget(Clear, modal_area);
+
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(theme::current().accent))
+        .title(format!(" Recent signals in {} ", app.namespace));
+
+    frame.render_widget(Paragraph::new(lines).block(block), modal_area);
+}
@@ -5,7 +5,9 @@ use ratatui::widgets::Paragraph;
 use ratatui::Frame;
 
 use crate::app::{App, ConnectionStatus};
-use crate::kinds::KindId;
+use crate::domain::{format_duration_text, HealthLevel};
+use crate::i18n::{t, tf, Msg};
+use crate::kinds::{workflow_status_color, KindId};
 use crate::nav::{ActivitiesRoute, RouteSegment, SchedulesRoute, WorkflowsRoute};
 use crate::theme;
 
@@ -14,65 +16,119 @@ pub fn render(app: &App, frame: &mut Frame, area: Rect) {
         Span::styled(
             " t9s ",
             Style::default()
-                .fg(theme::PURPLE)
+                .fg(theme::current().accent)
                 .add_modifier(Modifier::BOLD),
         ),
-        Span::styled("| ", Style::default().fg(theme::TEXT_MUTED)),
+        Span::styled("| ", Style::default().fg(theme::current().text_muted)),
     ];
 
     let location = app.location();
     let mut active_query = None;
+    let mut time_range = None;
     if let Some(segment) = location.leaf() {
         match segment {
             RouteSegment::Workflows(route) => {
-                active_query = app.search_query_for_kind(KindId::WorkflowExecution);
+                // The raw query; the time range shows separately.
+                active_query = app.search_queries.get(&KindId::WorkflowExecution).cloned();
+                time_range = app.time_range;
+                let label = if app.archived_workflows {
+                    t(Msg::TabArchivedWorkflows)
+                } else {
+                    t(Msg::TabWorkflows)
+                };
                 left_spans.push(Span::styled(
-                    "Workflows",
+                    app.active_custom_kind().map_or(label, |k| k.label.as_str()),
                     Style::default()
-                        .fg(theme::TEXT)
+                        .fg(theme::current().text)
                         .add_modifier(Modifier::BOLD),
                 ));
+                if !app.namespace_fanout.is_empty() {
+                    // Each namespace contributes its first page only.
+                    left_spans.push(Span::styled(
+                        format!(
+                            " {}",
+                            tf(
+                                Msg::TabFanout,
+                                &[
+                                    &app.namespace_fanout.len().to_string(),
+                                    &app.page_size.to_string(),
+                                ],
+                            )
+                        ),
+                        Style::default().fg(theme::current().magenta),
+                    ));
+                    if !app.fanout_failed.is_empty() {
+                        left_spans.push(Span::styled(
+                            format!(
+                                " {}",
+                                tf(Msg::TabFanoutFailed, &[&app.fanout_failed.join(", ")])
+                            ),
+                            Style::default().fg(theme::current().red),
+                        ));
+                    }
+                }
                 if let WorkflowsRoute::Detail { workflow_id, .. }
                 | WorkflowsRoute::Activities { workflow_id, .. } = route
                 {
-                    left_spans.push(Span::styled(" > ", Style::default().fg(theme::TEXT_MUTED)));
+                    for parent in &app.workflow_parents {
+                        left_spans.push(Span::styled(
+                            " > ",
+                            Style::default().fg(theme::current().text_muted),
+                        ));
+                        left_spans.push(Span::styled(
+                            parent.workflow_id.as_str(),
+                            Style::default().fg(theme::current().text_muted),
+                        ));
+                    }
+                    left_spans.push(Span::styled(
+                        " > ",
+                        Style::default().fg(theme::current().text_muted),
+                    ));
                     left_spans.push(Span::styled(
                         workflow_id,
-                        Style::default().fg(theme::TEXT_DIM),
+                        Style::default().fg(theme::current().text_dim),
                     ));
                 }
             }
             RouteSegment::Schedules(route) => {
-                active_query = app.search_query_for_kind(KindId::Schedule);
+                active_query = app.search_queries.get(&KindId::Schedule).cloned();
                 left_spans.push(Span::styled(
-                    "Schedules",
+                    app.active_custom_kind()
+                        .map_or(t(Msg::TabSchedules), |k| k.label.as_str()),
                     Style::default()
-                        .fg(theme::TEXT)
+                        .fg(theme::current().text)
                         .add_modifier(Modifier::BOLD),
                 ));
                 if let SchedulesRoute::Detail { schedule_id }
                 | SchedulesRoute::Workflows { schedule_id, .. } = route
                 {
-                    left_spans.push(Span::styled(" > ", Style::default().fg(theme::TEXT_MUTED)));
+                    left_spans.push(Span::styled(
+                        " > ",
+                        Style::default().fg(theme::current().text_muted),
+                    ));
                     left_spans.push(Span::styled(
                         schedule_id,
-                        Style::default().fg(theme::TEXT_DIM),
+                        Style::default().fg(theme::current().text_dim),
                     ));
                 }
             }
             RouteSegment::Activities(route) => {
-                active_query = app.search_query_for_kind(KindId::ActivityExecution);
+                active_query = app.search_queries.get(&KindId::ActivityExecution).cloned();
                 left_spans.push(Span::styled(
-                    "Activities",
+                    app.active_custom_kind()
+                        .map_or(t(Msg::TabActivities), |k| k.label.as_str()),
                     Style::default()
-                        .fg(theme::TEXT)
+                        .fg(theme::current().text)
                         .add_modifier(Modifier::BOLD),
                 ));
                 if let ActivitiesRoute::Detail { activity_id, .. } = route {
-                    left_spans.push(Span::styled(" > ", Style::default().fg(theme::TEXT_MUTED)));
+                    left_spans.push(Span::styled(
+                        " > ",
+                        Style::default().fg(theme::current().text_muted),
+                    ));
                     left_spans.push(Span::styled(
                         activity_id,
-                        Style::default().fg(theme::TEXT_DIM),
+                        Style::default().fg(theme::current().text_dim),
                     ));
                 }
             }
@@ -81,10 +137,49 @@ pub fn render(app: &App, frame: &mut Frame, area: Rect) {
 
     // Active search indicator
     if let Some(ref query) = active_query {
-        left_spans.push(Span::styled("  /", Style::default().fg(theme::GREEN)));
+        left_spans.push(Span::styled(
+            "  /",
+            Style::default().fg(theme::current().green),
+        ));
         left_spans.push(Span::styled(
             query.as_str(),
-            Style::default().fg(theme::TEXT),
+            Style::default().fg(theme::current().text),
+        ));
+    }
+
+    if let Some(range) = time_range {
+        left_spans.push(Span::styled(
+            format!("  {} ", t(Msg::TabLast)),
+            Style::default().fg(theme::current().cyan),
+        ));
+        left_spans.push(Span::styled(
+            range.label(),
+            Style::default().fg(theme::current().text),
+        ));
+    }
+
+    let filter_kind = match app.view {
+        crate::app::View::Collection(kind) => Some(kind),
+        crate::app::View::Detail(_) => None,
+    };
+    if let Some(filter) = filter_kind.and_then(|kind| app.local_filter(kind)) {
+        left_spans.push(Span::styled(
+            "  f:",
+            Style::default().fg(theme::current().cyan),
+        ));
+        left_spans.push(Span::styled(
+            filter,
+            Style::default().fg(theme::current().text),
+        ));
+    }
+
+    if !app.marked_workflows.is_empty() {
+        left_spans.push(Span::styled(
+            format!(
+                "  {}",
+                tf(Msg::TabMarked, &[&app.marked_workflows.len().to_string()])
+            ),
+            Style::default().fg(theme::current().magenta),
         ));
     }
 
@@ -93,46 +188,120 @@ pub fn render(app: &App, frame: &mut Frame, area: Rect) {
 
     let connection_indicator = match &app.connection_status {
         ConnectionStatus::Connected => {
-            Span::styled("● Connected", Style::default().fg(theme::GREEN))
-        }
-        ConnectionStatus::Connecting => {
-            Span::styled("◌ Connecting...", Style::default().fg(theme::YELLOW))
-        }
-        ConnectionStatus::Disconnected => {
-            Span::styled("○ Disconnected", Style::default().fg(theme::TEXT_MUTED))
-        }
-        ConnectionStatus::Error(msg) => {
-            Span::styled(format!("✗ {}", msg), Style::default().fg(theme::RED))
+            let color = match app.health.level() {
+                HealthLevel::Unknown | HealthLevel::Good => theme::current().green,
+                HealthLevel::Slow => theme::current().yellow,
+                HealthLevel::Bad => theme::current().red,
+            };
+            let label = match (app.health.failures, app.health.latency) {
+                (0, Some(latency)) => tf(
+                    Msg::StatusConnectedLatency,
+                    &[&latency.as_millis().to_string()],
+                ),
+                (0, None) => t(Msg::StatusConnected).to_string(),
+                (n, _) => tf(Msg::StatusFailedChecks, &[&n.to_string()]),
+            };
+            Span::styled(format!("● {}", label), Style::default().fg(color))
         }
+        ConnectionStatus::Connecting => Span::styled(
+            format!("◌ {}", t(Msg::StatusConnecting)),
+            Style::default().fg(theme::current().yellow),
+        ),
+        ConnectionStatus::Disconnected => Span::styled(
+            format!("○ {}", t(Msg::StatusDisconnected)),
+            Style::default().fg(theme::current().text_muted),
+        ),
+        ConnectionStatus::Error(msg) => Span::styled(
+            format!("✗ {}", msg),
+            Style::default().fg(theme::current().red),
+        ),
     };
     right_spans.push(connection_indicator);
 
+    if let Some(ref info) = app.cluster_info {
+        let (label, color) = if info.is_cloud {
+            (
+                format!("  ☁ {}", t(Msg::StatusCloud)),
+                theme::current().blue,
+            )
+        } else if info.is_outdated() {
+            (
+                format!("  ⚠ v{}", info.server_version),
+                theme::current().yellow,
+            )
+        } else {
+            (
+                format!("  v{}", info.server_version),
+                theme::current().text_muted,
+            )
+        };
+        right_spans.push(Span::styled(label, Style::default().fg(color)));
+    }
+
     right_spans.push(Span::styled(
         format!("  ns:{}", app.namespace),
-        Style::default().fg(theme::PURPLE),
+        Style::default().fg(theme::current().accent),
     ));
 
-    if !app.polling_enabled {
+    let next_poll = app
+        .next_poll_in()
+        .map(|d| {
+            format!(
+                " {}",
+                tf(
+                    Msg::StatusNextPoll,
+                    &[&format_duration_text(d.as_secs() as i64)]
+                )
+            )
+        })
+        .unwrap_or_default();
+    if !app.polling_active() {
+        let refreshed = app
+            .last_refresh
+            .map(|t| {
+                format!(
+                    ", {}",
+                    tf(
+                        Msg::StatusRefreshedAgo,
+                        &[&format_duration_text(t.elapsed().as_secs() as i64)]
+                    )
+                )
+            })
+            .unwrap_or_default();
         right_spans.push(Span::styled(
-            "  ⏸ paused",
-            Style::default().fg(theme::YELLOW),
+            format!("  ⏸ {}{}", t(Msg::StatusPaused), refreshed),
+            Style::default().fg(theme::current().yellow),
         ));
     } else if app.error_count > 0 {
         right_spans.push(Span::styled(
-            format!("  ↻ backoff {}s", app.polling_interval.as_secs()),
-            Style::default().fg(theme::YELLOW),
+            format!("  ↻ {}{}", t(Msg::StatusBackoff), next_poll),
+            Style::default().fg(theme::current().yellow),
         ));
     } else {
         right_spans.push(Span::styled(
-            "  ↻ polling",
-            Style::default().fg(theme::TEXT_MUTED),
+            format!("  ↻{}", next_poll),
+            Style::default().fg(theme::current().text_muted),
         ));
     }
 
-    if let Some(count) = app.workflow_count {
+    if !app.workflow_status_counts.is_empty() {
+        right_spans.push(Span::raw("  "));
+        for (i, (status, count)) in app.workflow_status_counts.iter().enumerate() {
+            if i > 0 {
+                right_spans.push(Span::styled(
+                    " · ",
+                    Style::default().fg(theme::current().text_dim),
+                ));
+            }
+            right_spans.push(Span::styled(
+                format!("{} {}", count, status.as_str().to_lowercase()),
+                workflow_status_color(status),
+            ));
+        }
+    } else if let Some(count) = app.workflow_count {
         right_spans.push(Span::styled(
-            format!("  [{} workflows]", count),
-            Style::default().fg(theme::TEXT_MUTED),
+            format!("  {}", tf(Msg::StatusWorkflowCount, &[&count.to_string()])),
+            Style::default().fg(theme::current().text_muted),
         ));
     }
 
@@ -149,6 +318,6 @@ pub fn render(app: &App, frame: &mut Frame, area: Rect) {
     spans.extend(right_spans);
 
     let line = Line::from(spans);
-    let widget = Paragraph::new(line).style(Style::default().bg(theme::BG_BAR));
+    let widget = Paragraph::new(line).style(Style::default().bg(theme::current().bg_bar));
     frame.render_widget(widget, area);
 }
@@ -0,0 +1,128 @@
+use ratatui::style::{Modifier, Style};
+use ratatui::text::{Line, Span};
+
+use crate::app::App;
+use crate::domain::{Poller, TaskQueueInfo, TaskQueueVersioning, WorkflowVersioning};
+use crate::i18n::{t, Msg};
+use crate::theme;
+
+/// Task queue name and its pollers, grouped by task type, then worker
+/// versioning for the queue and, from workflow detail, the execution. A queue
+/// with no activity pollers is flagged, since that's the usual reason
+/// activities sit scheduled and never start.
+pub fn lines(
+    app: &App,
+    tq: &TaskQueueInfo,
+    workflow: Option<&WorkflowVersioning>,
+) -> Vec<Line<'static>> {
+    let label = label_style();
+    let mut lines = vec![field_line(t(Msg::TaskQueueName), &tq.name), Line::from("")];
+    for (title, pollers) in [
+        (t(Msg::TaskQueueWorkflowPollers), &tq.workflow_pollers),
+        (t(Msg::TaskQueueActivityPollers), &tq.activity_pollers),
+    ] {
+        lines.push(Line::from(Span::styled(
+            format!(" {} ({}):", title, pollers.len()),
+            label,
+        )));
+        if pollers.is_empty() {
+            lines.push(Line::from(Span::styled(
+                format!("   {}", t(Msg::TaskQueueNoPollers)),
+                Style::default().fg(theme::current().yellow),
+            )));
+        }
+        lines.extend(pollers.iter().map(|p| poller_line(app, p)));
+        lines.push(Line::from(""));
+    }
+    lines.push(Line::from(Span::styled(
+        format!(" {}:", t(Msg::TaskQueueVersioning)),
+        label,
+    )));
+    if let Some(workflow) = workflow {
+        lines.push(field_line(
+            t(Msg::TaskQueueWorkflowBehavior),
+            &workflow.behavior,
+        ));
+        if let Some(version) = &workflow.version {
+            lines.push(field_line(t(Msg::TaskQueueWorkflowVersion), version));
+        }
+        if let Some(versioning_override) = &workflow.versioning_override {
+            lines.push(field_line(t(Msg::TaskQueueOverride), versioning_override));
+        }
+    }
+    lines.extend(versioning_lines(&tq.versioning));
+    lines
+}
+
+fn versioning_lines(versioning: &TaskQueueVersioning) -> Vec<Line<'static>> {
+    let mut lines = vec![field_line(
+        t(Msg::TaskQueueCurrentVersion),
+        versioning
+            .current_version
+            .as_deref()
+            .unwrap_or(t(Msg::TaskQueueUnversioned)),
+    )];
+    if let Some(ramping) = &versioning.ramping_version {
+        lines.push(field_line(
+            t(Msg::TaskQueueRampingVersion),
+            &format!("{} ({}%)", ramping, versioning.ramping_percentage),
+        ));
+    }
+    for (title, rules) in [
+        (
+            t(Msg::TaskQueueAssignmentRules),
+            &versioning.assignment_rules,
+        ),
+        (t(Msg::TaskQueueRedirectRules), &versioning.redirect_rules),
+    ] {
+        for (idx, rule) in rules.iter().enumerate() {
+            lines.push(field_line(if idx == 0 { title } else { "" }, rule));
+        }
+    }
+    lines
+}
+
+fn label_style() -> Style {
+    Style::default()
+        .fg(theme::current().accent)
+        .add_modifier(Modifier::BOLD)
+}
+
+fn field_line(label: &str, value: &str) -> Line<'static> {
+    Line::from(vec![
+        Span::styled(format!(" {:<20} ", label), label_style()),
+        Span::styled(
+            value.to_string(),
+            Style::default().fg(theme::current().text),
+        ),
+    ])
+}
+
+fn poller_line(app: &App, p: &Poller) -> Line<'static> {
+    let last_access = p
+        .last_access_time
+        .map(|t| app.time_display.format(&t))
+        .unwrap_or_else(|| "-".to_string());
+    Line::from(vec![
+        Span::styled("   ", Style::default()),
+        Span::styled(
+            format!("{:<40} ", p.identity),
+            Style::default().fg(theme::current().text),
+        ),
+        Span::styled(
+            format!("{}:{:<20} ", t(Msg::TaskQueuePollerLast), last_access),
+            Style::default().fg(theme::current().text_muted),
+        ),
+        Span::styled(
+            format!("{}:{:.1}/s", t(Msg::TaskQueuePollerRate), p.rate_per_second),
+            Style::default().fg(theme::current().text_muted),
+        ),
+        Span::styled(
+            p.version
+                .as_ref()
+                .map(|v| format!(" {}:{}", t(Msg::TaskQueuePollerVersion), v))
+                .unwrap_or_default(),
+            Style::default().fg(theme::current().text_muted),
+        ),
+    ])
+}
@@ -0,0 +1,55 @@
+use ratatui::layout::Rect;
+use ratatui::style::{Modifier, Style};
+use ratatui::text::{Line, Span};
+use ratatui::widgets::{Block, Borders, Clear, Paragraph};
+use ratatui::Frame;
+
+use crate::app::{time_range_options, App};
+use crate::theme;
+use crate::widgets::centered_rect;
+
+pub fn render(app: &App, frame: &mut Frame, area: Rect) {
+    let mut lines: Vec<Line> = time_range_options()
+        .into_iter()
+        .enumerate()
+        .map(|(i, range)| {
+            let active = app.time_range == range;
+            let line = Line::from(vec![
+                Span::styled(
+                    if active { " * " } else { "   " },
+                    Style::default().fg(theme::current().green),
+                ),
+                Span::styled(
+                    format!("{:<4}", range.map_or("all", |r| r.label())),
+                    Style::default()
+                        .fg(theme::current().accent)
+                        .add_modifier(Modifier::BOLD),
+                ),
+                Span::styled(
+                    range.map_or("All time", |r| r.description()),
+                    Style::default().fg(theme::current().text),
+                ),
+            ]);
+            if i == app.time_range_cursor {
+                line.style(Style::default().bg(theme::current().bg_highlight))
+            } else {
+                line
+            }
+        })
+        .collect();
+    lines.push(Line::from(""));
+    lines.push(Line::from(Span::styled(
+        " Enter to apply | Esc to cancel",
+        Style::default().fg(theme::current().text_dim),
+    )));
+
+    let modal_area = centered_rect(40, lines.len() as u16 + 2, area);
+    frame.render_widget(Clear, modal_area);
+
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(theme::current().accent))
+        .title(" Started within ");
+
+    frame.render_widget(Paragraph::new(lines).block(block), modal_area);
+}
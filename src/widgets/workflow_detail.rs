@@ -1,3 +1,4 @@
+use chrono::Utc;
 use ratatui::layout::{Constraint, Layout, Rect};
 use ratatui::style::{Modifier, Style};
 use ratatui::text::{Line, Span};
@@ -5,15 +6,21 @@ use ratatui::widgets::{Block, Borders, Paragraph, Wrap};
 use ratatui::Frame;
 
 use crate::app::App;
+use crate::domain::{
+    activity_hotspots, format_duration_text, format_span, timeline_spans,
+    workflow_task_queue_times, ActivityHotspot, TimelineSpan,
+};
+use crate::i18n::{t, Msg};
 use crate::kinds::detail_tabs_for_kind;
 use crate::theme;
+use crate::widgets::{json_tree, task_queue};
 
 pub fn render(app: &App, frame: &mut Frame, area: Rect) {
     let detail = match &app.selected_workflow {
         Some(d) => d,
         None => {
-            let loading = Paragraph::new(" Loading workflow detail...")
-                .style(Style::default().fg(theme::TEXT_MUTED));
+            let loading = Paragraph::new(t(Msg::LoadingWorkflowDetail))
+                .style(Style::default().fg(theme::current().text_muted));
             frame.render_widget(loading, area);
             return;
         }
@@ -31,10 +38,10 @@ pub fn render(app: &App, frame: &mut Frame, area: Rect) {
     for (i, tab) in tabs.iter().enumerate() {
         let style = if i == app.workflow_detail_tab {
             Style::default()
-                .fg(theme::PURPLE)
+                .fg(theme::current().accent)
                 .add_modifier(Modifier::BOLD | Modifier::UNDERLINED)
         } else {
-            Style::default().fg(theme::TEXT_MUTED)
+            Style::default().fg(theme::current().text_muted)
         };
         tab_spans.push(Span::styled(format!(" {} ", tab), style));
         tab_spans.push(Span::raw(" "));
@@ -44,31 +51,67 @@ pub fn render(app: &App, frame: &mut Frame, area: Rect) {
     // Content
     let scroll = app.detail_scroll;
     match app.workflow_detail_tab {
-        0 => render_summary(detail, frame, layout[1], scroll),
-        1 => render_io(detail, frame, layout[1], scroll),
-        2 => render_history(app, frame, layout[1], scroll),
-        3 => render_pending(detail, frame, layout[1], scroll),
+        0 => render_summary(app, detail, frame, layout[1], scroll),
+        1 => render_io(app, frame, layout[1]),
+        2 => render_history(app, frame, layout[1]),
+        3 => render_pending(app, detail, frame, layout[1], scroll),
         4 => render_task_queue(app, detail, frame, layout[1], scroll),
+        5 => render_stack_trace(app, detail, frame, layout[1], scroll),
+        6 => render_children(app, detail, frame, layout[1]),
+        7 => render_raw(app, frame, layout[1], scroll),
+        8 => render_metadata(app, detail, frame, layout[1], scroll),
+        9 => render_timeline(app, detail, frame, layout[1], scroll),
         _ => {}
     }
 }
 
 fn render_summary(
+    app: &App,
     detail: &crate::domain::WorkflowDetail,
     frame: &mut Frame,
     area: Rect,
     scroll: u16,
 ) {
     let wf = &detail.summary;
-    let started = format_time(&wf.start_time);
+    let started = app.time_display.format(&wf.start_time);
     let closed = wf
         .close_time
-        .map(|t| format_time(&t))
+        .map(|t| app.time_display.format(&t))
         .unwrap_or_else(|| "-".to_string());
     let history_len = detail.history_length.to_string();
+    let duration = {
+        let span = format_span(wf.close_time.unwrap_or_else(Utc::now) - wf.start_time);
+        if wf.close_time.is_some() {
+            span
+        } else {
+            format!("{} (running)", span)
+        }
+    };
+    let queue_times = app
+        .workflow_history
+        .data()
+        .map(|events| workflow_task_queue_times(events))
+        .unwrap_or_default();
+    let queue_latency = match (queue_times.first(), queue_times.iter().max()) {
+        (Some(first), Some(max)) if queue_times.len() > 1 => format!(
+            "{} first, {} max over {} tasks",
+            format_span(*first),
+            format_span(*max),
+            queue_times.len()
+        ),
+        (Some(first), _) => format_span(*first),
+        _ => "-".to_string(),
+    };
     let pending_count = detail.pending_activities.len().to_string();
 
-    let lines = vec![
+    let metadata = &detail.user_metadata;
+    let summary = metadata.summary.as_deref().map(|s| app.masked_text(s));
+    let details = metadata.details.as_deref().map(|s| app.masked_text(s));
+    let mut lines = user_metadata_lines(summary.as_deref(), details.as_deref(), 1);
+    if !lines.is_empty() {
+        lines.push(Line::from(""));
+    }
+    lines.extend([
         field_line("Workflow ID", &wf.workflow_id),
         field_line("Run ID", &wf.run_id),
         field_line("Type", &wf.workflow_type),
@@ -76,9 +119,101 @@ fn render_summary(
         field_line("Task Queue", &wf.task_queue),
         field_line("Started", &started),
         field_line("Closed", &closed),
+        field_line("Duration", &duration),
+        field_line("Task Queue Time", &queue_latency),
         field_line("History Length", &history_len),
         field_line("Pending Activities", &pending_count),
+    ]);
+    let parent = detail
+        .parent
+        .as_ref()
+        .map(|p| format!("{} / {}  (u)", p.workflow_id, p.run_id));
+    if let Some(ref parent) = parent {
+        lines.push(field_line("Parent", parent));
+    }
+    let root = detail
+        .root
+        .as_ref()
+        .filter(|r| r.workflow_id != wf.workflow_id)
+        .map(|r| format!("{} / {}  (U)", r.workflow_id, r.run_id));
+    if let Some(ref root) = root {
+        lines.push(field_line("Root", root));
+    }
+
+    let config = &detail.execution_config;
+    let timeout = |d: Option<std::time::Duration>| {
+        d.map(|d| format_duration_text(d.as_secs() as i64))
+            .unwrap_or_else(|| "unlimited".to_string())
+    };
+    let mut execution = vec![
+        (
+            "Execution Timeout".to_string(),
+            timeout(config.execution_timeout),
+        ),
+        ("Run Timeout".to_string(), timeout(config.run_timeout)),
+        ("Task Timeout".to_string(), timeout(config.task_timeout)),
     ];
+    // Only differs from the list's task queue in odd cases, so shown when it does.
+    if !config.task_queue.is_empty() && config.task_queue != wf.task_queue {
+        execution.insert(0, ("Task Queue".to_string(), config.task_queue.clone()));
+    }
+    if let Some(ref cron) = detail.cron_schedule {
+        execution.push(("Cron Schedule".to_string(), cron.clone()));
+    }
+    let retry = detail
+        .retry_policy
+        .as_ref()
+        .map(|policy| {
+            let interval = |d: Option<std::time::Duration>| {
+                d.map(|d| format_span(chrono::Duration::from_std(d).unwrap_or_default()))
+                    .unwrap_or_else(|| "-".to_string())
+            };
+            let mut fields = vec![
+                (
+                    "Initial Interval".to_string(),
+                    interval(policy.initial_interval),
+                ),
+                (
+                    "Backoff".to_string(),
+                    format!("x{}", policy.backoff_coefficient),
+                ),
+                (
+                    "Maximum Interval".to_string(),
+                    interval(policy.maximum_interval),
+                ),
+                (
+                    "Maximum Attempts".to_string(),
+                    match policy.maximum_attempts {
+                        0 => "unlimited".to_string(),
+                        n => n.to_string(),
+                    },
+                ),
+            ];
+            if !policy.non_retryable_error_types.is_empty() {
+                fields.push((
+                    "Non-retryable".to_string(),
+                    policy.non_retryable_error_types.join(", "),
+                ));
+            }
+            fields
+        })
+        .unwrap_or_default();
+
+    let search_attributes = sorted_fields(&app.masked_fields(&detail.search_attributes));
+    let memo = sorted_fields(&app.masked_fields(&detail.memo));
+    for (title, fields) in [
+        ("Execution Config", &execution),
+        ("Retry Policy", &retry),
+        ("Search Attributes", &search_attributes),
+        ("Memo", &memo),
+    ] {
+        if fields.is_empty() {
+            continue;
+        }
+        lines.push(Line::from(""));
+        lines.push(section_line(title));
+        lines.extend(fields.iter().map(|(k, v)| field_line(k, v)));
+    }
 
     let paragraph = Paragraph::new(lines)
         .block(Block::default().borders(Borders::NONE))
@@ -87,129 +222,78 @@ fn render_summary(
     frame.render_widget(paragraph, area);
 }
 
-fn render_io(detail: &crate::domain::WorkflowDetail, frame: &mut Frame, area: Rect, scroll: u16) {
-    let mut lines = vec![];
-
-    lines.push(Line::from(Span::styled(
-        " Input:",
-        Style::default()
-            .fg(theme::PURPLE)
-            .add_modifier(Modifier::BOLD),
-    )));
-    if let Some(ref input) = detail.input {
-        let formatted = serde_json::to_string_pretty(input).unwrap_or_else(|_| input.to_string());
-        for line in formatted.lines() {
-            lines.push(Line::from(Span::styled(
-                format!("   {}", line),
-                Style::default().fg(theme::TEXT),
-            )));
-        }
-    } else {
-        lines.push(Line::from(Span::styled(
-            "   (none)",
-            Style::default().fg(theme::TEXT_MUTED),
-        )));
-    }
-
-    lines.push(Line::from(""));
-
-    lines.push(Line::from(Span::styled(
-        " Output:",
-        Style::default()
-            .fg(theme::GREEN)
-            .add_modifier(Modifier::BOLD),
-    )));
-    if let Some(ref output) = detail.output {
-        let formatted = serde_json::to_string_pretty(output).unwrap_or_else(|_| output.to_string());
-        for line in formatted.lines() {
-            lines.push(Line::from(Span::styled(
-                format!("   {}", line),
-                Style::default().fg(theme::TEXT),
-            )));
-        }
-    } else {
-        lines.push(Line::from(Span::styled(
-            "   (none)",
-            Style::default().fg(theme::TEXT_MUTED),
-        )));
-    }
-
-    if let Some(ref failure) = detail.failure {
-        lines.push(Line::from(Span::styled(
-            " Failure:",
-            Style::default().fg(theme::RED).add_modifier(Modifier::BOLD),
-        )));
-        lines.push(Line::from(format!("   Type: {}", failure.failure_type)));
-        lines.push(Line::from(format!("   Message: {}", failure.message)));
-        if let Some(ref trace) = failure.stack_trace {
-            lines.push(Line::from("   Stack Trace:"));
-            for line in trace.lines() {
-                lines.push(Line::from(format!("     {}", line)));
-            }
-        }
-    }
+fn render_io(app: &App, frame: &mut Frame, area: Rect) {
+    let rows = app.io_tree_rows();
+    let cursor = app.io_tree.cursor.min(rows.len().saturating_sub(1));
+    let (lines, cursor_line) = json_tree::lines(&rows, Some(cursor), 1);
 
+    let scroll = cursor_line.saturating_sub(area.height as usize / 3) as u16;
     let paragraph = Paragraph::new(lines)
         .block(Block::default().borders(Borders::NONE))
-        .wrap(Wrap { trim: false })
         .scroll((scroll, 0));
     frame.render_widget(paragraph, area);
 }
 
-fn render_history(app: &App, frame: &mut Frame, area: Rect, scroll: u16) {
+fn render_history(app: &App, frame: &mut Frame, area: Rect) {
     match &app.workflow_history {
         crate::app::LoadState::Loaded(events) => {
             let mut lines: Vec<Line> = Vec::new();
-            for e in events {
+            let mut cursor_line = 0;
+            for (idx, e) in events.iter().enumerate() {
                 // Event header line
-                lines.push(Line::from(vec![
+                let mut header = vec![
                     Span::styled(
                         format!(" {:>4} ", e.event_id),
-                        Style::default().fg(theme::TEXT_MUTED),
+                        Style::default().fg(theme::current().text_muted),
                     ),
                     Span::styled(
                         format!("{:<45} ", e.event_type),
                         event_type_style(&e.event_type),
                     ),
                     Span::styled(
-                        format_time(&e.timestamp),
-                        Style::default().fg(theme::TEXT_MUTED),
+                        app.time_display.format(&e.timestamp),
+                        Style::default().fg(theme::current().text_muted),
                     ),
-                ]));
-
-                // Event details (if any non-empty details exist)
-                if let Some(obj) = e.details.as_object() {
-                    if !obj.is_empty() {
-                        for (key, value) in obj {
-                            let val_str = match value {
-                                serde_json::Value::String(s) => s.clone(),
-                                other => serde_json::to_string_pretty(other)
-                                    .unwrap_or_else(|_| other.to_string()),
-                            };
-                            // For multi-line values, indent continuation lines
-                            let first_line = val_str.lines().next().unwrap_or("");
-                            lines.push(Line::from(vec![
-                                Span::raw("        "),
-                                Span::styled(
-                                    format!("{}: ", key),
-                                    Style::default().fg(theme::PURPLE),
-                                ),
-                                Span::styled(
-                                    first_line.to_string(),
-                                    Style::default().fg(theme::TEXT_DIM),
-                                ),
-                            ]));
-                            for cont_line in val_str.lines().skip(1) {
-                                lines.push(Line::from(Span::styled(
-                                    format!("          {}", cont_line),
-                                    Style::default().fg(theme::TEXT_DIM),
-                                )));
-                            }
-                        }
-                    }
+                ];
+                if let Some(ref summary) = e.user_metadata.summary {
+                    header.push(Span::styled(
+                        format!("  {}", app.masked_text(summary)),
+                        Style::default().fg(theme::current().text),
+                    ));
+                }
+                if matches!(
+                    e.event_type.as_str(),
+                    "StartChildWorkflowExecutionInitiated" | "ChildWorkflowExecutionStarted"
+                ) {
+                    header.push(Span::styled(
+                        "  ⏎ open child",
+                        Style::default().fg(theme::current().accent_dim),
+                    ));
                 }
+                let mut header = Line::from(header);
+                if idx == app.history_cursor {
+                    cursor_line = lines.len();
+                    header = header.style(Style::default().bg(theme::current().bg_highlight));
+                }
+                lines.push(header);
+                // The summary is already on the header.
+                let details = e
+                    .user_metadata
+                    .details
+                    .as_deref()
+                    .map(|d| app.masked_text(d));
+                lines.extend(user_metadata_lines(None, details.as_deref(), 6));
+
+                let event_rows = json_tree::child_rows(
+                    &e.event_id.to_string(),
+                    &app.masked(&e.details),
+                    &app.history_tree,
+                );
+                lines.extend(json_tree::lines(&event_rows, None, 6).0);
             }
 
+            // Keep the cursor event in the upper third of the viewport.
+            let scroll = cursor_line.saturating_sub(area.height as usize / 3) as u16;
             let paragraph = Paragraph::new(lines)
                 .block(Block::default().borders(Borders::NONE))
                 .scroll((scroll, 0));
@@ -217,58 +301,469 @@ fn render_history(app: &App, frame: &mut Frame, area: Rect, scroll: u16) {
         }
         crate::app::LoadState::Loading => {
             frame.render_widget(
-                Paragraph::new(" Loading history...").style(Style::default().fg(theme::TEXT_MUTED)),
+                Paragraph::new(t(Msg::LoadingHistory))
+                    .style(Style::default().fg(theme::current().text_muted)),
                 area,
             );
         }
         _ => {
             frame.render_widget(
                 Paragraph::new(" Press Tab or 'l' to load history")
-                    .style(Style::default().fg(theme::TEXT_MUTED)),
+                    .style(Style::default().fg(theme::current().text_muted)),
                 area,
             );
         }
     }
 }
 
-fn render_pending(
+fn render_stack_trace(
+    app: &App,
     detail: &crate::domain::WorkflowDetail,
     frame: &mut Frame,
     area: Rect,
     scroll: u16,
 ) {
-    if detail.pending_activities.is_empty() {
+    if detail.summary.status != crate::domain::WorkflowStatus::Running {
         frame.render_widget(
-            Paragraph::new(" No pending activities").style(Style::default().fg(theme::TEXT_MUTED)),
+            Paragraph::new(" Stack traces are only available for running workflows")
+                .style(Style::default().fg(theme::current().text_muted)),
             area,
         );
         return;
     }
 
-    let lines: Vec<Line> = detail
-        .pending_activities
-        .iter()
-        .map(|a| {
-            Line::from(vec![
-                Span::styled(
-                    format!(" {:>6} ", a.activity_id),
-                    Style::default().fg(theme::TEXT_MUTED),
-                ),
-                Span::styled(
-                    format!("{:<30} ", a.activity_type),
-                    Style::default().fg(theme::TEXT),
-                ),
+    let lines: Vec<Line> = match &app.stack_trace {
+        crate::app::LoadState::Loaded(trace) => trace
+            .lines()
+            .map(|line| {
+                Line::from(Span::styled(
+                    format!(" {}", line),
+                    Style::default().fg(theme::current().text),
+                ))
+            })
+            .collect(),
+        crate::app::LoadState::Error(msg) => vec![Line::from(Span::styled(
+            format!(" Stack trace query failed: {}", msg),
+            Style::default().fg(theme::current().red),
+        ))],
+        crate::app::LoadState::Loading => vec![Line::from(Span::styled(
+            " Querying __stack_trace...",
+            Style::default().fg(theme::current().text_muted),
+        ))],
+        crate::app::LoadState::NotLoaded => vec![Line::from(Span::styled(
+            " Press r to query the stack trace",
+            Style::default().fg(theme::current().text_muted),
+        ))],
+    };
+
+    let paragraph = Paragraph::new(lines)
+        .block(Block::default().borders(Borders::NONE))
+        .scroll((scroll, 0));
+    frame.render_widget(paragraph, area);
+}
+
+fn render_metadata(
+    app: &App,
+    detail: &crate::domain::WorkflowDetail,
+    frame: &mut Frame,
+    area: Rect,
+    scroll: u16,
+) {
+    if detail.summary.status != crate::domain::WorkflowStatus::Running {
+        frame.render_widget(
+            Paragraph::new(" Handlers can only be queried for running workflows")
+                .style(Style::default().fg(theme::current().text_muted)),
+            area,
+        );
+        return;
+    }
+
+    let metadata = match &app.workflow_metadata {
+        crate::app::LoadState::Loaded(metadata) => metadata,
+        crate::app::LoadState::Error(msg) => {
+            frame.render_widget(
+                Paragraph::new(format!(" Metadata query failed: {}", msg))
+                    .style(Style::default().fg(theme::current().red)),
+                area,
+            );
+            return;
+        }
+        crate::app::LoadState::Loading => {
+            frame.render_widget(
+                Paragraph::new(" Querying __temporal_workflow_metadata...")
+                    .style(Style::default().fg(theme::current().text_muted)),
+                area,
+            );
+            return;
+        }
+        crate::app::LoadState::NotLoaded => {
+            frame.render_widget(
+                Paragraph::new(" Press r to query the workflow's handlers")
+                    .style(Style::default().fg(theme::current().text_muted)),
+                area,
+            );
+            return;
+        }
+    };
+
+    let mut lines = Vec::new();
+    for (title, handlers) in [
+        ("Signals", &metadata.signals),
+        ("Queries", &metadata.queries),
+        ("Updates", &metadata.updates),
+    ] {
+        lines.push(section_line(title));
+        if handlers.is_empty() {
+            lines.push(muted_line("   (none)"));
+        }
+        for handler in handlers {
+            let name = if handler.name.is_empty() {
+                "(dynamic)"
+            } else {
+                handler.name.as_str()
+            };
+            lines.push(Line::from(vec![
                 Span::styled(
-                    format!("{:<15} ", a.state.as_str()),
-                    Style::default().fg(theme::YELLOW),
+                    format!("   {:<32} ", name),
+                    Style::default().fg(theme::current().cyan),
                 ),
                 Span::styled(
-                    format!("attempt:{}", a.attempt),
-                    Style::default().fg(theme::TEXT_MUTED),
+                    handler.description.clone(),
+                    Style::default().fg(theme::current().text_muted),
                 ),
-            ])
-        })
-        .collect();
+            ]));
+        }
+        lines.push(Line::from(""));
+    }
+    lines.push(section_line("Current Details"));
+    if metadata.current_details.is_empty() {
+        lines.push(muted_line("   (none)"));
+    }
+    lines.extend(metadata.current_details.lines().map(|line| {
+        Line::from(Span::styled(
+            format!("   {}", line),
+            Style::default().fg(theme::current().text),
+        ))
+    }));
+
+    let paragraph = Paragraph::new(lines)
+        .block(Block::default().borders(Borders::NONE))
+        .scroll((scroll, 0));
+    frame.render_widget(paragraph, area);
+}
+
+/// The describe response as JSON; schedules share this tab.
+pub fn render_raw(app: &App, frame: &mut Frame, area: Rect, scroll: u16) {
+    let lines: Vec<Line> = match &app.raw_detail {
+        crate::app::LoadState::Loaded(_) => app
+            .raw_text()
+            .unwrap_or_default()
+            .lines()
+            .map(|line| {
+                Line::from(Span::styled(
+                    format!(" {}", line),
+                    Style::default().fg(theme::current().text),
+                ))
+            })
+            .collect(),
+        crate::app::LoadState::Error(msg) => vec![Line::from(Span::styled(
+            format!(" Describe failed: {}", msg),
+            Style::default().fg(theme::current().red),
+        ))],
+        crate::app::LoadState::Loading => vec![Line::from(Span::styled(
+            " Loading the describe response...",
+            Style::default().fg(theme::current().text_muted),
+        ))],
+        crate::app::LoadState::NotLoaded => vec![Line::from(Span::styled(
+            " Press Ctrl+R to load the describe response",
+            Style::default().fg(theme::current().text_muted),
+        ))],
+    };
+
+    let paragraph = Paragraph::new(lines)
+        .block(Block::default().borders(Borders::NONE))
+        .scroll((scroll, 0));
+    frame.render_widget(paragraph, area);
+}
+
+fn render_pending(
+    app: &App,
+    detail: &crate::domain::WorkflowDetail,
+    frame: &mut Frame,
+    area: Rect,
+    scroll: u16,
+) {
+    if detail.pending_activities.is_empty()
+        && detail.pending_children.is_empty()
+        && detail.pending_nexus_operations.is_empty()
+    {
+        frame.render_widget(
+            Paragraph::new(" Nothing pending")
+                .style(Style::default().fg(theme::current().text_muted)),
+            area,
+        );
+        return;
+    }
+
+    let mut lines: Vec<Line> = vec![section_line("Activities")];
+    if detail.pending_activities.is_empty() {
+        lines.push(muted_line("   (none)"));
+    }
+    lines.extend(
+        detail
+            .pending_activities
+            .iter()
+            .enumerate()
+            .map(|(idx, a)| {
+                let line = Line::from(vec![
+                    Span::styled(
+                        format!(" {:>6} ", a.activity_id),
+                        Style::default().fg(theme::current().text_muted),
+                    ),
+                    Span::styled(
+                        format!("{:<30} ", a.activity_type),
+                        Style::default().fg(theme::current().text),
+                    ),
+                    Span::styled(
+                        format!("{:<15} ", a.state.as_str()),
+                        Style::default().fg(theme::current().yellow),
+                    ),
+                    Span::styled(
+                        format!("attempt:{}", a.attempt),
+                        Style::default().fg(theme::current().text_muted),
+                    ),
+                ]);
+                if idx == app.pending_cursor {
+                    line.style(Style::default().bg(theme::current().bg_highlight))
+                } else {
+                    line
+                }
+            }),
+    );
+
+    if !detail.pending_children.is_empty() {
+        lines.push(Line::from(""));
+        lines.push(muted_line(
+            " Pending child workflows are listed on the Children tab",
+        ));
+    }
+
+    lines.push(Line::from(""));
+    lines.push(section_line("Nexus Operations"));
+    if detail.pending_nexus_operations.is_empty() {
+        lines.push(muted_line("   (none)"));
+    }
+    for op in &detail.pending_nexus_operations {
+        lines.push(Line::from(vec![
+            Span::styled(
+                format!(" {:<24} ", op.endpoint),
+                Style::default().fg(theme::current().text_muted),
+            ),
+            Span::styled(
+                format!("{:<30} ", format!("{}/{}", op.service, op.operation)),
+                Style::default().fg(theme::current().text),
+            ),
+            Span::styled(
+                format!("{:<15} ", op.state),
+                Style::default().fg(theme::current().yellow),
+            ),
+            Span::styled(
+                format!("attempt:{}", op.attempt),
+                Style::default().fg(theme::current().text_muted),
+            ),
+        ]));
+        if let Some(ref msg) = op.last_failure_message {
+            lines.push(Line::from(Span::styled(
+                format!("        {}", msg),
+                Style::default().fg(theme::current().red),
+            )));
+        }
+    }
+
+    // With activities the cursor drives the scroll, as on the Children tab.
+    let scroll = if detail.pending_activities.is_empty() {
+        scroll
+    } else {
+        (app.pending_cursor + 1).saturating_sub(area.height as usize / 3) as u16
+    };
+    let paragraph = Paragraph::new(lines)
+        .block(Block::default().borders(Borders::NONE))
+        .scroll((scroll, 0));
+    frame.render_widget(paragraph, area);
+}
+
+fn render_children(
+    app: &App,
+    detail: &crate::domain::WorkflowDetail,
+    frame: &mut Frame,
+    area: Rect,
+) {
+    if detail.pending_children.is_empty() {
+        frame.render_widget(
+            Paragraph::new(" No pending child workflows")
+                .style(Style::default().fg(theme::current().text_muted)),
+            area,
+        );
+        return;
+    }
+
+    let mut lines = vec![Line::from(Span::styled(
+        format!(
+            " {:<36} {:<36} {:<24} {}",
+            "WORKFLOW ID", "RUN ID", "TYPE", "PARENT CLOSE"
+        ),
+        Style::default()
+            .fg(theme::current().accent)
+            .add_modifier(Modifier::BOLD),
+    ))];
+    for (idx, child) in detail.pending_children.iter().enumerate() {
+        let mut line = Line::from(vec![
+            Span::styled(
+                format!(" {:<36} ", child.workflow_id),
+                Style::default().fg(theme::current().cyan),
+            ),
+            Span::styled(
+                format!("{:<36} ", child.run_id),
+                Style::default().fg(theme::current().text_muted),
+            ),
+            Span::styled(
+                format!("{:<24} ", child.workflow_type),
+                Style::default().fg(theme::current().text),
+            ),
+            Span::styled(
+                child.parent_close_policy.clone(),
+                Style::default().fg(theme::current().yellow),
+            ),
+        ]);
+        if idx == app.child_cursor {
+            line = line.style(Style::default().bg(theme::current().bg_highlight));
+        }
+        lines.push(line);
+    }
+
+    // Header plus cursor row, kept in the upper third of the viewport.
+    let scroll = (app.child_cursor + 1).saturating_sub(area.height as usize / 3) as u16;
+    let paragraph = Paragraph::new(lines)
+        .block(Block::default().borders(Borders::NONE))
+        .scroll((scroll, 0));
+    frame.render_widget(paragraph, area);
+}
+
+const TIMELINE_LABEL_WIDTH: usize = 32;
+
+fn render_timeline(
+    app: &App,
+    detail: &crate::domain::WorkflowDetail,
+    frame: &mut Frame,
+    area: Rect,
+    scroll: u16,
+) {
+    let events = match &app.workflow_history {
+        crate::app::LoadState::Loaded(events) => events,
+        crate::app::LoadState::Loading => {
+            frame.render_widget(
+                Paragraph::new(t(Msg::LoadingHistory))
+                    .style(Style::default().fg(theme::current().text_muted)),
+                area,
+            );
+            return;
+        }
+        _ => {
+            frame.render_widget(
+                Paragraph::new(" The timeline is built from the history, which isn't loaded")
+                    .style(Style::default().fg(theme::current().text_muted)),
+                area,
+            );
+            return;
+        }
+    };
+    let spans = timeline_spans(events);
+    if spans.is_empty() {
+        frame.render_widget(
+            Paragraph::new(" No activities, timers or child workflows in this history")
+                .style(Style::default().fg(theme::current().text_muted)),
+            area,
+        );
+        return;
+    }
+
+    let now = Utc::now();
+    let start = detail.summary.start_time;
+    let end = spans
+        .iter()
+        .map(|s| s.end.unwrap_or(now))
+        .chain([detail.summary.close_time.unwrap_or(now)])
+        .max()
+        .unwrap_or(now);
+    let total = (end - start).num_milliseconds().max(1);
+    // Label, bar, then duration and attempts.
+    let bar_width = (area.width as usize)
+        .saturating_sub(TIMELINE_LABEL_WIDTH + 24)
+        .max(10);
+    let column = |at: chrono::DateTime<Utc>| {
+        let offset = (at - start).num_milliseconds().clamp(0, total);
+        (offset as usize * bar_width / total as usize).min(bar_width - 1)
+    };
+
+    let mut lines = vec![Line::from(Span::styled(
+        format!(
+            " {:<w$} {:<bar_width$} {}",
+            "SPAN",
+            format!("0 → {}", format_span(end - start)),
+            "DURATION",
+            w = TIMELINE_LABEL_WIDTH - 1,
+        ),
+        Style::default()
+            .fg(theme::current().accent)
+            .add_modifier(Modifier::BOLD),
+    ))];
+    for span in &spans {
+        let from = column(span.start);
+        let to = column(span.end.unwrap_or(now)).max(from);
+        let label: String = span.name.chars().take(TIMELINE_LABEL_WIDTH - 3).collect();
+        let label_style = if span.critical {
+            Style::default()
+                .fg(theme::current().accent)
+                .add_modifier(Modifier::BOLD)
+        } else {
+            Style::default().fg(theme::current().text)
+        };
+        let mut info = format!("{} {}", format_span(span.duration(now)), span.outcome);
+        if span.attempts > 1 {
+            info.push_str(&format!(" ×{}", span.attempts));
+        }
+        lines.push(Line::from(vec![
+            Span::styled(
+                format!(" {} ", if span.critical { "▸" } else { " " }),
+                label_style,
+            ),
+            Span::styled(
+                format!("{:<w$}", label, w = TIMELINE_LABEL_WIDTH - 3),
+                label_style,
+            ),
+            Span::raw(" ".repeat(from + 1)),
+            Span::styled(
+                "█".repeat(to - from + 1),
+                Style::default().fg(timeline_color(span)),
+            ),
+            Span::raw(" ".repeat(bar_width - to)),
+            Span::styled(info, Style::default().fg(theme::current().text_muted)),
+        ]));
+    }
+
+    let mut hotspots = activity_hotspots(&spans, now);
+    if !hotspots.is_empty() {
+        lines.push(Line::from(""));
+        lines.push(section_line("Slowest Activities"));
+        lines.extend(hotspot_lines(&hotspots));
+        hotspots.retain(|h| h.retries > 0);
+        hotspots.sort_by_key(|h| std::cmp::Reverse(h.retries));
+        if !hotspots.is_empty() {
+            lines.push(Line::from(""));
+            lines.push(section_line("Most Retried"));
+            lines.extend(hotspot_lines(&hotspots));
+        }
+    }
 
     let paragraph = Paragraph::new(lines)
         .block(Block::default().borders(Borders::NONE))
@@ -276,6 +771,84 @@ fn render_pending(
     frame.render_widget(paragraph, area);
 }
 
+/// The first five hotspots as a table, in the order given.
+fn hotspot_lines(hotspots: &[ActivityHotspot]) -> Vec<Line<'static>> {
+    let mut lines = vec![Line::from(Span::styled(
+        format!(
+            "   {:<30} {:>5} {:>10} {:>10} {:>10} {:>7} {:>6}",
+            "TYPE", "COUNT", "TOTAL", "AVG", "MAX", "RETRIES", "FAILED"
+        ),
+        Style::default().fg(theme::current().text_muted),
+    ))];
+    lines.extend(hotspots.iter().take(5).map(|h| {
+        let failed_style = if h.failed > 0 {
+            Style::default().fg(theme::current().red)
+        } else {
+            Style::default().fg(theme::current().text)
+        };
+        Line::from(vec![
+            Span::styled(
+                format!(
+                    "   {:<30} {:>5} {:>10} {:>10} {:>10} {:>7}",
+                    h.activity_type.chars().take(30).collect::<String>(),
+                    h.count,
+                    format_span(h.total),
+                    format_span(h.average()),
+                    format_span(h.max),
+                    h.retries
+                ),
+                Style::default().fg(theme::current().text),
+            ),
+            Span::styled(format!(" {:>6}", h.failed), failed_style),
+        ])
+    }));
+    lines
+}
+
+fn timeline_color(span: &TimelineSpan) -> ratatui::style::Color {
+    match span.outcome.as_str() {
+        "Completed" | "Fired" => theme::current().green,
+        "Running" => theme::current().cyan,
+        "Canceled" => theme::current().yellow,
+        _ => theme::current().red,
+    }
+}
+
+/// Entries sorted by key, with string values unquoted.
+fn sorted_fields(object: &serde_json::Value) -> Vec<(String, String)> {
+    let Some(map) = object.as_object() else {
+        return vec![];
+    };
+    let mut fields: Vec<(String, String)> = map
+        .iter()
+        .map(|(k, v)| {
+            let value = match v {
+                serde_json::Value::String(s) => s.clone(),
+                other => other.to_string(),
+            };
+            (k.clone(), value)
+        })
+        .collect();
+    fields.sort();
+    fields
+}
+
+fn section_line(title: &str) -> Line<'_> {
+    Line::from(Span::styled(
+        format!(" {}:", title),
+        Style::default()
+            .fg(theme::current().accent)
+            .add_modifier(Modifier::BOLD),
+    ))
+}
+
+fn muted_line(text: &str) -> Line<'_> {
+    Line::from(Span::styled(
+        text,
+        Style::default().fg(theme::current().text_muted),
+    ))
+}
+
 fn render_task_queue(
     app: &App,
     detail: &crate::domain::WorkflowDetail,
@@ -285,47 +858,7 @@ fn render_task_queue(
 ) {
     match &app.task_queue_detail {
         crate::app::LoadState::Loaded(tq) => {
-            let pollers_count = tq.pollers.len().to_string();
-            let mut lines = vec![
-                field_line("Task Queue", &tq.name),
-                field_line("Pollers", &pollers_count),
-                Line::from(""),
-            ];
-
-            if tq.pollers.is_empty() {
-                lines.push(Line::from(Span::styled(
-                    " No pollers",
-                    Style::default().fg(theme::TEXT_MUTED),
-                )));
-            } else {
-                lines.push(Line::from(Span::styled(
-                    " Pollers:",
-                    Style::default()
-                        .fg(theme::PURPLE)
-                        .add_modifier(Modifier::BOLD),
-                )));
-                for p in &tq.pollers {
-                    let last_access = p
-                        .last_access_time
-                        .map(|t| format_time(&t))
-                        .unwrap_or_else(|| "-".to_string());
-                    lines.push(Line::from(vec![
-                        Span::styled("   ", Style::default()),
-                        Span::styled(
-                            format!("{:<40} ", p.identity),
-                            Style::default().fg(theme::TEXT),
-                        ),
-                        Span::styled(
-                            format!("last:{:<20} ", last_access),
-                            Style::default().fg(theme::TEXT_MUTED),
-                        ),
-                        Span::styled(
-                            format!("rate:{:.1}/s", p.rate_per_second),
-                            Style::default().fg(theme::TEXT_MUTED),
-                        ),
-                    ]));
-                }
-            }
+            let lines = task_queue::lines(app, tq, detail.versioning.as_ref());
 
             let paragraph = Paragraph::new(lines)
                 .block(Block::default().borders(Borders::NONE))
@@ -335,8 +868,8 @@ fn render_task_queue(
         }
         crate::app::LoadState::Loading => {
             frame.render_widget(
-                Paragraph::new(" Loading task queue info...")
-                    .style(Style::default().fg(theme::TEXT_MUTED)),
+                Paragraph::new(t(Msg::LoadingTaskQueue))
+                    .style(Style::default().fg(theme::current().text_muted)),
                 area,
             );
         }
@@ -347,40 +880,65 @@ fn render_task_queue(
                     " Task queue: {} (press Tab or 'l' to load)",
                     tq_name
                 ))
-                .style(Style::default().fg(theme::TEXT_MUTED)),
+                .style(Style::default().fg(theme::current().text_muted)),
                 area,
             );
         }
     }
 }
 
+/// The summary in bold, then the details one line at a time.
+fn user_metadata_lines(
+    summary: Option<&str>,
+    details: Option<&str>,
+    indent: usize,
+) -> Vec<Line<'static>> {
+    let pad = " ".repeat(indent);
+    let mut lines = Vec::new();
+    if let Some(summary) = summary {
+        lines.push(Line::from(Span::styled(
+            format!("{}{}", pad, summary),
+            Style::default()
+                .fg(theme::current().text)
+                .add_modifier(Modifier::BOLD),
+        )));
+    }
+    if let Some(details) = details {
+        lines.extend(details.lines().map(|line| {
+            Line::from(Span::styled(
+                format!("{}{}", pad, line),
+                Style::default().fg(theme::current().text_dim),
+            ))
+        }));
+    }
+    lines
+}
+
 fn field_line<'a>(label: &'a str, value: &'a str) -> Line<'a> {
     Line::from(vec![
         Span::styled(
             format!(" {:<20} ", label),
             Style::default()
-                .fg(theme::PURPLE)
+                .fg(theme::current().accent)
                 .add_modifier(Modifier::BOLD),
         ),
-        Span::styled(value.to_string(), Style::default().fg(theme::TEXT)),
+        Span::styled(
+            value.to_string(),
+            Style::default().fg(theme::current().text),
+        ),
     ])
 }
 
 fn event_type_style(event_type: &str) -> Style {
     if event_type.contains("Failed") || event_type.contains("TimedOut") {
-        Style::default().fg(theme::RED)
+        Style::default().fg(theme::current().red)
     } else if event_type.contains("Completed") {
-        Style::default().fg(theme::GREEN)
+        Style::default().fg(theme::current().green)
     } else if event_type.contains("Started") {
-        Style::default().fg(theme::BLUE)
+        Style::default().fg(theme::current().blue)
     } else if event_type.contains("Scheduled") {
-        Style::default().fg(theme::YELLOW)
+        Style::default().fg(theme::current().yellow)
     } else {
-        Style::default().fg(theme::TEXT)
+        Style::default().fg(theme::current().text)
     }
 }
-
-fn format_time(dt: &chrono::DateTime<chrono::Utc>) -> String {
-    let local = dt.with_timezone(&chrono::Local);
-    local.format("%Y-%m-%d %H:%M:%S").to_string()
-}
@@ -0,0 +1,109 @@
+use chrono::Utc;
+use ratatui::layout::Rect;
+use ratatui::style::{Modifier, Style};
+use ratatui::text::{Line, Span};
+use ratatui::widgets::{Block, Borders, Paragraph, Wrap};
+use ratatui::Frame;
+
+use crate::app::{App, LoadState};
+use crate::domain::{format_span, WorkflowDetail};
+use crate::i18n::{t, tf, Msg};
+use crate::kinds::workflow_status_color;
+use crate::theme;
+
+/// Rows the preview pane takes under the workflow list, borders included.
+pub const PREVIEW_HEIGHT: u16 = 6;
+
+/// The compact preview of the highlighted workflow under the list.
+pub fn render(app: &App, frame: &mut Frame, area: Rect) {
+    let block = Block::default()
+        .borders(Borders::TOP)
+        .border_style(Style::default().fg(theme::current().text_muted))
+        .title(t(Msg::PreviewTitle));
+    let lines = match &app.workflow_preview {
+        LoadState::Loaded(detail) => preview_lines(detail),
+        LoadState::Error(e) => vec![Line::from(Span::styled(
+            format!(" {}", e),
+            Style::default().fg(theme::current().red),
+        ))],
+        LoadState::Loading => vec![muted(t(Msg::LoadingWorkflowDetail))],
+        LoadState::NotLoaded => vec![],
+    };
+    frame.render_widget(
+        Paragraph::new(lines)
+            .block(block)
+            .wrap(Wrap { trim: false }),
+        area,
+    );
+}
+
+fn preview_lines(detail: &WorkflowDetail) -> Vec<Line<'static>> {
+    let wf = &detail.summary;
+    let duration = {
+        let span = format_span(wf.close_time.unwrap_or_else(Utc::now) - wf.start_time);
+        if wf.close_time.is_some() {
+            span
+        } else {
+            tf(Msg::PreviewRunning, &[&span])
+        }
+    };
+    let history = match detail.history_length {
+        0 => "-".to_string(),
+        n => tf(Msg::PreviewEvents, &[&n.to_string()]),
+    };
+    // The run's own failure, else that of the first pending activity with
+    // one, in the order the server lists them.
+    let failure = detail.failure.as_ref().or_else(|| {
+        detail
+            .pending_activities
+            .iter()
+            .find_map(|a| a.last_failure.as_ref())
+    });
+
+    let mut lines = vec![
+        Line::from(vec![
+            label(t(Msg::PreviewStatus)),
+            Span::styled(
+                format!("{} {}", wf.status.symbol(), wf.status.as_str()),
+                workflow_status_color(&wf.status),
+            ),
+        ]),
+        field(t(Msg::PreviewType), wf.workflow_type.clone()),
+        field(t(Msg::PreviewDuration), duration),
+        field(t(Msg::PreviewHistory), history),
+    ];
+    match failure {
+        Some(f) => lines.push(Line::from(vec![
+            label(t(Msg::PreviewLastFailure)),
+            Span::styled(
+                format!("{}: {}", f.failure_type, f.message),
+                Style::default().fg(theme::current().red),
+            ),
+        ])),
+        None => lines.push(field(t(Msg::PreviewLastFailure), "-".to_string())),
+    }
+    lines
+}
+
+fn label(text: &'static str) -> Span<'static> {
+    Span::styled(
+        format!(" {:<14} ", text),
+        Style::default()
+            .fg(theme::current().accent)
+            .add_modifier(Modifier::BOLD),
+    )
+}
+
+fn field(name: &'static str, value: String) -> Line<'static> {
+    Line::from(vec![
+        label(name),
+        Span::styled(value, Style::default().fg(theme::current().text)),
+    ])
+}
+
+fn muted(text: &str) -> Line<'static> {
+    Line::from(Span::styled(
+        text.to_string(),
+        Style::default().fg(theme::current().text_muted),
+    ))
+}
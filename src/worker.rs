@@ -1,9 +1,24 @@
-use std::sync::Arc;
+use std::collections::hash_map::DefaultHasher;
+use std::collections::{HashMap, HashSet};
+use std::hash::{Hash, Hasher};
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
 
 use tokio::sync::mpsc;
+use tokio_util::sync::CancellationToken;
 
 use crate::action::Action;
-use crate::client::TemporalClient;
+use crate::app::CancelScope;
+use crate::audit::AuditEntry;
+use crate::client::{ClientError, ClientResult, ExecutionAdmin, TemporalClient};
+use crate::domain::{
+    ActivityOptionsDraft, ActivityOptionsEdit, BatchOperation, BatchTarget, NamespaceChange,
+    NamespaceOverview, ScheduleEdit, ScheduleState, SearchAttributeType, WorkflowMetadata,
+    WorkflowStatus, WorkflowSummary, SCHEDULE_SECTION, STATUS_SECTION, TASK_QUEUE_SECTION,
+    TYPE_SECTION,
+};
+use crate::input::query_syntax::escape_single_quotes;
+use crate::kinds::KindId;
 
 #[derive(Debug)]
 pub enum CliRequest {
@@ -12,24 +27,56 @@ pub enum CliRequest {
         query: Option<String>,
         page_size: i32,
         next_page_token: Vec<u8>,
+        /// List from archival instead of the live visibility store.
+        archived: bool,
+        /// Merge the first page of each of these instead of `namespace`'s.
+        fanout: Vec<String>,
     },
     LoadMoreWorkflows {
         namespace: String,
         query: Option<String>,
         page_size: i32,
         next_page_token: Vec<u8>,
+        archived: bool,
     },
     LoadWorkflowDetail {
         namespace: String,
         workflow_id: String,
         run_id: Option<String>,
     },
+    /// Describe a workflow for the list's preview pane.
+    LoadWorkflowPreview {
+        namespace: String,
+        workflow_id: String,
+        run_id: String,
+    },
     LoadHistory {
         namespace: String,
         workflow_id: String,
         run_id: Option<String>,
     },
+    LoadWorkflowMetadata {
+        namespace: String,
+        workflow_id: String,
+        run_id: Option<String>,
+    },
+    LoadStackTrace {
+        namespace: String,
+        workflow_id: String,
+        run_id: Option<String>,
+    },
+    LoadRawWorkflow {
+        namespace: String,
+        workflow_id: String,
+        run_id: Option<String>,
+    },
+    LoadRawSchedule {
+        namespace: String,
+        schedule_id: String,
+    },
     LoadNamespaces,
+    LoadClusterInfo,
+    HealthCheck,
     LoadWorkflowCount {
         namespace: String,
         query: Option<String>,
@@ -37,6 +84,14 @@ pub enum CliRequest {
     LoadSchedules {
         namespace: String,
         query: Option<String>,
+        page_size: i32,
+        next_page_token: Vec<u8>,
+    },
+    LoadMoreSchedules {
+        namespace: String,
+        query: Option<String>,
+        page_size: i32,
+        next_page_token: Vec<u8>,
     },
     LoadScheduleDetail {
         namespace: String,
@@ -52,10 +107,16 @@ pub enum CliRequest {
         workflow_id: String,
         run_id: Option<String>,
     },
+    TerminateAndRestartWorkflow {
+        namespace: String,
+        workflow_id: String,
+        run_id: Option<String>,
+    },
     PauseSchedule {
         namespace: String,
         schedule_id: String,
         pause: bool,
+        note: String,
     },
     TriggerSchedule {
         namespace: String,
@@ -65,10 +126,37 @@ pub enum CliRequest {
         namespace: String,
         schedule_id: String,
     },
+    LoadScheduleDraft {
+        namespace: String,
+        schedule_id: String,
+    },
+    UpdateSchedule {
+        namespace: String,
+        schedule_id: String,
+        edit: Box<ScheduleEdit>,
+        conflict_token: Vec<u8>,
+    },
+    UpdateActivityOptions {
+        namespace: String,
+        draft: Box<ActivityOptionsDraft>,
+        edit: Box<ActivityOptionsEdit>,
+    },
     DescribeTaskQueue {
         namespace: String,
         task_queue: String,
     },
+    LoadSearchAttributes {
+        namespace: String,
+    },
+    LoadOverview {
+        namespace: String,
+    },
+    AddSearchAttribute {
+        namespace: String,
+        name: String,
+        value_type: SearchAttributeType,
+    },
+    ChangeNamespace(NamespaceChange),
     SignalWorkflow {
         namespace: String,
         workflow_id: String,
@@ -116,34 +204,414 @@ pub enum CliRequest {
     CheckActivitySupport {
         namespace: String,
     },
+    /// Counts the workflows a `:bulk` over `query` would touch.
+    CountBatch {
+        namespace: String,
+        operation: BatchOperation,
+        query: String,
+    },
+    StartBatchOperation {
+        namespace: String,
+        operation: BatchOperation,
+        target: BatchTarget,
+    },
+}
+
+impl CliRequest {
+    /// Whether the result depends on the namespace or query current when the
+    /// request was sent, so a later switch makes it stale. Mutations always
+    /// report back.
+    pub fn is_scoped(&self) -> bool {
+        !matches!(
+            self,
+            CliRequest::LoadNamespaces
+                | CliRequest::LoadClusterInfo
+                | CliRequest::HealthCheck
+                | CliRequest::CancelWorkflow { .. }
+                | CliRequest::TerminateWorkflow { .. }
+                | CliRequest::TerminateAndRestartWorkflow { .. }
+                | CliRequest::PauseSchedule { .. }
+                | CliRequest::TriggerSchedule { .. }
+                | CliRequest::DeleteSchedule { .. }
+                | CliRequest::UpdateSchedule { .. }
+                | CliRequest::UpdateActivityOptions { .. }
+                | CliRequest::AddSearchAttribute { .. }
+                | CliRequest::ChangeNamespace(_)
+                | CliRequest::SignalWorkflow { .. }
+                | CliRequest::RequestCancelActivityExecution { .. }
+                | CliRequest::TerminateActivityExecution { .. }
+                | CliRequest::DeleteActivityExecution { .. }
+                | CliRequest::StartBatchOperation { .. }
+        )
+    }
+
+    /// The scope whose cancellation abandons this request; `None` for
+    /// requests that must always finish.
+    pub fn cancel_scope(&self) -> Option<CancelScope> {
+        match self {
+            CliRequest::LoadWorkflowDetail { .. }
+            | CliRequest::LoadHistory { .. }
+            | CliRequest::LoadStackTrace { .. }
+            | CliRequest::LoadWorkflowMetadata { .. }
+            | CliRequest::LoadRawWorkflow { .. }
+            | CliRequest::LoadRawSchedule { .. }
+            | CliRequest::LoadScheduleDetail { .. }
+            | CliRequest::DescribeTaskQueue { .. }
+            | CliRequest::DescribeActivityExecution { .. } => Some(CancelScope::Detail),
+            request if request.is_scoped() => Some(CancelScope::Namespace),
+            _ => None,
+        }
+    }
+
+    /// Namespace, operation and target of a mutation, for the audit log;
+    /// `None` for reads.
+    pub fn audit(&self) -> Option<(String, &'static str, String)> {
+        let run = |workflow_id: &str, run_id: &Option<String>| match run_id {
+            Some(run_id) if !run_id.is_empty() => format!("{}/{}", workflow_id, run_id),
+            _ => workflow_id.to_string(),
+        };
+        let (namespace, operation, target) = match self {
+            CliRequest::CancelWorkflow {
+                namespace,
+                workflow_id,
+                run_id,
+            } => (namespace, "cancel workflow", run(workflow_id, run_id)),
+            CliRequest::TerminateWorkflow {
+                namespace,
+                workflow_id,
+                run_id,
+            } => (namespace, "terminate workflow", run(workflow_id, run_id)),
+            CliRequest::TerminateAndRestartWorkflow {
+                namespace,
+                workflow_id,
+                run_id,
+            } => (
+                namespace,
+                "terminate and restart workflow",
+                run(workflow_id, run_id),
+            ),
+            CliRequest::SignalWorkflow {
+                namespace,
+                workflow_id,
+                run_id,
+                signal_name,
+                ..
+            } => (
+                namespace,
+                "signal workflow",
+                format!("{} {}", run(workflow_id, run_id), signal_name),
+            ),
+            CliRequest::PauseSchedule {
+                namespace,
+                schedule_id,
+                pause,
+                ..
+            } => (
+                namespace,
+                if *pause {
+                    "pause schedule"
+                } else {
+                    "unpause schedule"
+                },
+                schedule_id.clone(),
+            ),
+            CliRequest::TriggerSchedule {
+                namespace,
+                schedule_id,
+            } => (namespace, "trigger schedule", schedule_id.clone()),
+            CliRequest::DeleteSchedule {
+                namespace,
+                schedule_id,
+            } => (namespace, "delete schedule", schedule_id.clone()),
+            CliRequest::UpdateSchedule {
+                namespace,
+                schedule_id,
+                ..
+            } => (namespace, "update schedule", schedule_id.clone()),
+            CliRequest::UpdateActivityOptions {
+                namespace, draft, ..
+            } => (
+                namespace,
+                "update activity options",
+                format!(
+                    "{}/{} activity {}",
+                    draft.workflow_id, draft.run_id, draft.activity_id
+                ),
+            ),
+            CliRequest::AddSearchAttribute {
+                namespace,
+                name,
+                value_type,
+            } => (
+                namespace,
+                "add search attribute",
+                format!("{} ({})", name, value_type.label()),
+            ),
+            CliRequest::ChangeNamespace(change) => {
+                let operation = match change {
+                    NamespaceChange::Register { .. } => "register namespace",
+                    NamespaceChange::Update { .. } => "update namespace",
+                    NamespaceChange::Deprecate { .. } => "deprecate namespace",
+                };
+                let name = change.name().to_string();
+                return Some((name.clone(), operation, name));
+            }
+            CliRequest::RequestCancelActivityExecution {
+                namespace,
+                activity_id,
+                run_id,
+            } => (
+                namespace,
+                "cancel activity",
+                format!("{}/{}", activity_id, run_id),
+            ),
+            CliRequest::TerminateActivityExecution {
+                namespace,
+                activity_id,
+                run_id,
+                ..
+            } => (
+                namespace,
+                "terminate activity",
+                format!("{}/{}", activity_id, run_id),
+            ),
+            CliRequest::DeleteActivityExecution {
+                namespace,
+                activity_id,
+                run_id,
+            } => (
+                namespace,
+                "delete activity",
+                format!("{}/{}", activity_id, run_id),
+            ),
+            CliRequest::StartBatchOperation {
+                namespace,
+                operation,
+                target,
+            } => (
+                namespace,
+                match operation {
+                    BatchOperation::Cancel => "batch cancel",
+                    BatchOperation::Terminate => "batch terminate",
+                },
+                match target {
+                    BatchTarget::Executions(executions) => {
+                        format!("{} workflows", executions.len())
+                    }
+                    BatchTarget::Query(query) => query.clone(),
+                },
+            ),
+            _ => return None,
+        };
+        Some((namespace.clone(), operation, target))
+    }
+
+    /// Identifies polled list loads so an identical one isn't queued while
+    /// another is still in flight. Other requests always go through.
+    fn fingerprint(&self) -> Option<u64> {
+        match self {
+            CliRequest::LoadWorkflows { .. } | CliRequest::LoadWorkflowCount { .. } => {
+                let mut hasher = DefaultHasher::new();
+                format!("{:?}", self).hash(&mut hasher);
+                Some(hasher.finish())
+            }
+            _ => None,
+        }
+    }
+}
+
+/// Fingerprints of queued or running requests, shared by handles and worker.
+type InFlight = Arc<Mutex<HashSet<(String, u64, u64)>>>;
+
+/// Removes a request's fingerprint once the worker is done with it, however
+/// that happens.
+struct InFlightGuard {
+    in_flight: InFlight,
+    key: Option<(String, u64, u64)>,
+}
+
+impl Drop for InFlightGuard {
+    fn drop(&mut self) {
+        if let Some(key) = self.key.take() {
+            self.in_flight
+                .lock()
+                .unwrap_or_else(|e| e.into_inner())
+                .remove(&key);
+        }
+    }
+}
+
+/// Current token per scope. Cancelling replaces the token, so requests sent
+/// afterwards are unaffected.
+struct CancelTokens {
+    namespace: CancellationToken,
+    detail: CancellationToken,
+}
+
+impl CancelTokens {
+    fn new() -> Self {
+        let namespace = CancellationToken::new();
+        let detail = namespace.child_token();
+        Self { namespace, detail }
+    }
+
+    fn token(&self, scope: CancelScope) -> CancellationToken {
+        match scope {
+            CancelScope::Namespace => self.namespace.clone(),
+            CancelScope::Detail => self.detail.clone(),
+        }
+    }
+
+    fn cancel(&mut self, scope: CancelScope) {
+        match scope {
+            CancelScope::Namespace => {
+                self.namespace.cancel();
+                *self = Self::new();
+            }
+            CancelScope::Detail => {
+                self.detail.cancel();
+                self.detail = self.namespace.child_token();
+            }
+        }
+    }
+}
+
+enum WorkerMessage {
+    Request {
+        context: String,
+        generation: u64,
+        cancel: Option<CancellationToken>,
+        /// Context, generation and request fingerprint, for deduplicated loads.
+        in_flight: Option<(String, u64, u64)>,
+        request: CliRequest,
+    },
+    Register {
+        context: String,
+        client: Arc<dyn TemporalClient>,
+    },
+    AuditFile(Option<PathBuf>),
+    /// Retry the connection of a context whose client gave up reconnecting.
+    Reconnect(String),
 }
 
+/// Sends requests to the worker for one context (a connected cluster).
 #[derive(Clone)]
 pub struct CliHandle {
-    tx: mpsc::UnboundedSender<CliRequest>,
+    tx: mpsc::UnboundedSender<WorkerMessage>,
+    context: String,
+    generation: u64,
+    cancel: Arc<Mutex<CancelTokens>>,
+    in_flight: InFlight,
 }
 
 impl CliHandle {
+    /// Queues `request`, unless it is a polled load identical to one that is
+    /// already queued or running.
     pub fn send(&self, request: CliRequest) {
-        let _ = self.tx.send(request);
+        let in_flight = request
+            .fingerprint()
+            .map(|fingerprint| (self.context.clone(), self.generation, fingerprint));
+        if let Some(key) = &in_flight {
+            let mut pending = self.in_flight.lock().unwrap_or_else(|e| e.into_inner());
+            if !pending.insert(key.clone()) {
+                tracing::trace!("skipping duplicate {:?}", request);
+                return;
+            }
+        }
+        let cancel = request.cancel_scope().map(|scope| {
+            self.cancel
+                .lock()
+                .unwrap_or_else(|e| e.into_inner())
+                .token(scope)
+        });
+        let _ = self.tx.send(WorkerMessage::Request {
+            context: self.context.clone(),
+            generation: self.generation,
+            cancel,
+            in_flight,
+            request,
+        });
+    }
+
+    /// Abandons requests in `scope` that are running or still queued, in
+    /// every context. Their results are never delivered.
+    pub fn cancel(&self, scope: CancelScope) {
+        self.cancel
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .cancel(scope);
+    }
+
+    /// A handle for the same worker whose requests go to `context`.
+    pub fn for_context(&self, context: &str) -> Self {
+        Self {
+            tx: self.tx.clone(),
+            context: context.to_string(),
+            generation: self.generation,
+            cancel: self.cancel.clone(),
+            in_flight: self.in_flight.clone(),
+        }
+    }
+
+    /// A handle whose requests are tagged with the app's [`App::generation`],
+    /// so their results can be told apart from later ones.
+    ///
+    /// [`App::generation`]: crate::app::App::generation
+    pub fn with_generation(mut self, generation: u64) -> Self {
+        self.generation = generation;
+        self
+    }
+
+    /// Sets the file every mutation is appended to from now on.
+    pub fn set_audit_file(&self, path: Option<PathBuf>) {
+        let _ = self.tx.send(WorkerMessage::AuditFile(path));
+    }
+
+    /// Has this context's client start reconnecting again.
+    pub fn reconnect(&self) {
+        let _ = self.tx.send(WorkerMessage::Reconnect(self.context.clone()));
+    }
+
+    /// Adds or replaces the client used for `context`.
+    pub fn register(&self, context: &str, client: Arc<dyn TemporalClient>) {
+        let _ = self.tx.send(WorkerMessage::Register {
+            context: context.to_string(),
+            client,
+        });
     }
 }
 
+/// Runs client calls for every connected context, each on its own task.
+/// Results come back as [`Action::ForContext`] so the app can drop those for
+/// inactive contexts or older generations.
 pub struct CliWorker {
-    client: Arc<dyn TemporalClient>,
-    rx: mpsc::UnboundedReceiver<CliRequest>,
+    clients: HashMap<String, Arc<dyn TemporalClient>>,
+    /// The config's `audit_file`.
+    audit_file: Option<PathBuf>,
+    in_flight: InFlight,
+    rx: mpsc::UnboundedReceiver<WorkerMessage>,
     action_tx: mpsc::UnboundedSender<Action>,
 }
 
 impl CliWorker {
     pub fn new(
+        context: &str,
         client: Arc<dyn TemporalClient>,
         action_tx: mpsc::UnboundedSender<Action>,
     ) -> (Self, CliHandle) {
         let (tx, rx) = mpsc::unbounded_channel();
-        let handle = CliHandle { tx };
+        let in_flight = InFlight::default();
+        let handle = CliHandle {
+            tx,
+            context: context.to_string(),
+            generation: 0,
+            cancel: Arc::new(Mutex::new(CancelTokens::new())),
+            in_flight: in_flight.clone(),
+        };
         let worker = Self {
-            client,
+            clients: HashMap::from([(context.to_string(), client)]),
+            audit_file: None,
+            in_flight,
             rx,
             action_tx,
         };
@@ -151,322 +619,1169 @@ impl CliWorker {
     }
 
     pub async fn run(mut self) {
-        while let Some(request) = self.rx.recv().await {
-            let action = self.process(request).await;
-            if self.action_tx.send(action).is_err() {
-                break;
+        while let Some(message) = self.rx.recv().await {
+            let (context, generation, cancel, in_flight, request) = match message {
+                WorkerMessage::Register { context, client } => {
+                    self.clients.insert(context, client);
+                    continue;
+                }
+                WorkerMessage::AuditFile(path) => {
+                    self.audit_file = path;
+                    continue;
+                }
+                WorkerMessage::Reconnect(context) => {
+                    if let Some(client) = self.clients.get(&context) {
+                        client.reconnect();
+                    }
+                    continue;
+                }
+                WorkerMessage::Request {
+                    context,
+                    generation,
+                    cancel,
+                    in_flight,
+                    request,
+                } => (context, generation, cancel, in_flight, request),
+            };
+            let in_flight = InFlightGuard {
+                in_flight: self.in_flight.clone(),
+                key: in_flight,
+            };
+            let client = self.clients.get(&context).cloned();
+            let audit_file = self.audit_file.clone();
+            let action_tx = self.action_tx.clone();
+            // Each request runs on its own task, so a slow call (a long
+            // history, an unreachable cluster) doesn't hold up the rest.
+            tokio::spawn(async move {
+                let _in_flight = in_flight;
+                let generation = request.is_scoped().then_some(generation);
+                let audit = request.audit();
+                let action = match client {
+                    Some(client) => {
+                        let cancel = cancel.unwrap_or_default();
+                        // Dropping the call future resets its stream, so the
+                        // server stops sending a history nobody will read.
+                        let result = tokio::select! {
+                            biased;
+                            _ = cancel.cancelled() => None,
+                            action = process(client.as_ref(), request) => Some(action),
+                        };
+                        for error in client.take_decode_errors() {
+                            let error = Action::Error(format!("codec decode failed: {}", error));
+                            let _ = action_tx.send(Action::ForContext(
+                                context.clone(),
+                                None,
+                                Box::new(error),
+                            ));
+                        }
+                        match result {
+                            None => {
+                                tracing::debug!("cancelled request for context {:?}", context);
+                                return;
+                            }
+                            Some(Action::Error(msg)) if client.is_reconnecting() => {
+                                Action::ConnectionLost(msg)
+                            }
+                            Some(Action::Error(msg)) if client.is_disconnected() => {
+                                Action::Disconnected(msg)
+                            }
+                            Some(action) => action,
+                        }
+                    }
+                    None => Action::Error(format!("no connection for context {}", context)),
+                };
+                for audited in record_audit(&context, audit, &action, audit_file.as_deref()).await {
+                    let _ = action_tx.send(audited);
+                }
+                let _ = action_tx.send(Action::ForContext(context, generation, Box::new(action)));
+            });
+        }
+    }
+}
+
+/// Records a mutation that finished as `action`: appended to `audit_file`
+/// when one is set, and returned as [`Action::Audited`] for `:audit`,
+/// followed by an error if the file couldn't be written. Nothing for reads.
+pub async fn record_audit(
+    context: &str,
+    audit: Option<(String, &'static str, String)>,
+    action: &Action,
+    audit_file: Option<&Path>,
+) -> Vec<Action> {
+    let Some((namespace, operation, target)) = audit else {
+        return vec![];
+    };
+    let entry = AuditEntry {
+        time: chrono::Utc::now(),
+        context: context.to_string(),
+        namespace,
+        operation: operation.to_string(),
+        target,
+        error: match action {
+            Action::Error(msg) | Action::ConnectionLost(msg) => Some(msg.clone()),
+            _ => None,
+        },
+    };
+    let mut actions = Vec::new();
+    if let Some(path) = audit_file {
+        let (path, line) = (path.to_path_buf(), entry.clone());
+        let written = tokio::task::spawn_blocking(move || crate::audit::append(&path, &line))
+            .await
+            .unwrap_or_else(|e| Err(e.to_string()));
+        if let Err(e) = written {
+            actions.push(Action::Error(format!("writing audit log: {}", e)));
+        }
+    }
+    actions.insert(0, Action::Audited(Box::new(entry)));
+    actions
+}
+
+async fn list_workflows(
+    client: &dyn TemporalClient,
+    namespace: &str,
+    query: Option<String>,
+    page_size: i32,
+    next_page_token: Vec<u8>,
+    archived: bool,
+) -> ClientResult<(Vec<WorkflowSummary>, Vec<u8>)> {
+    if archived {
+        client
+            .list_archived_workflows(namespace, query.as_deref(), page_size, next_page_token)
+            .await
+    } else {
+        client
+            .list_workflows(namespace, query.as_deref(), page_size, next_page_token)
+            .await
+    }
+}
+
+/// Namespaces `:allns` lists at once.
+const FANOUT_CONCURRENCY: usize = 8;
+
+/// The first page of each namespace's workflows, merged, with the
+/// namespaces that failed and why. An error only when every one failed.
+async fn list_across_namespaces(
+    client: &dyn TemporalClient,
+    namespaces: &[String],
+    query: Option<String>,
+    page_size: i32,
+    archived: bool,
+) -> ClientResult<(Vec<WorkflowSummary>, Vec<(String, String)>)> {
+    use futures::StreamExt;
+
+    let lists: Vec<_> = namespaces
+        .iter()
+        .map(|namespace| {
+            let (namespace, query) = (namespace.clone(), query.clone());
+            async move {
+                let page =
+                    list_workflows(client, &namespace, query, page_size, vec![], archived).await;
+                (namespace, page)
+            }
+        })
+        .collect();
+    let pages: Vec<_> = futures::stream::iter(lists)
+        .buffer_unordered(FANOUT_CONCURRENCY)
+        .collect()
+        .await;
+
+    let mut merged = Vec::new();
+    let mut failed = Vec::new();
+    let mut error = None;
+    for (namespace, page) in pages {
+        match page {
+            Ok((workflows, _)) => {
+                merged.extend(workflows.into_iter().map(|wf| WorkflowSummary {
+                    namespace: Some(namespace.clone()),
+                    ..wf
+                }));
             }
+            Err(e) => {
+                tracing::warn!("failed to list workflows in {}: {}", namespace, e);
+                failed.push((namespace, e.to_string()));
+                error.get_or_insert(e);
+            }
+        }
+    }
+    match error {
+        Some(e) if failed.len() == namespaces.len() => Err(e),
+        _ => {
+            failed.sort();
+            Ok((merged, failed))
         }
     }
+}
 
-    async fn process(&self, request: CliRequest) -> Action {
-        match request {
-            CliRequest::LoadWorkflows {
-                namespace,
+/// Reads what the run was started with, then terminates it and starts the
+/// new run from that, so a run whose start can't be read keeps running.
+async fn terminate_and_restart(
+    admin: &dyn ExecutionAdmin,
+    namespace: &str,
+    workflow_id: String,
+    run_id: Option<&str>,
+) -> Action {
+    let attributes = match admin
+        .start_attributes(namespace, &workflow_id, run_id)
+        .await
+    {
+        Ok(attributes) => attributes,
+        Err(e) => return Action::Error(format!("failed to read workflow start: {}", e)),
+    };
+    if let Err(e) = admin
+        .terminate_workflow(namespace, &workflow_id, run_id, "restarted via t9s")
+        .await
+    {
+        return Action::Error(format!("failed to terminate workflow: {}", e));
+    }
+    match admin
+        .start_workflow_from(namespace, &workflow_id, attributes)
+        .await
+    {
+        Ok(new_run_id) => Action::WorkflowRestarted(workflow_id, new_run_id),
+        Err(e) => Action::Error(format!(
+            "terminated workflow but failed to start new run: {}",
+            e
+        )),
+    }
+}
+
+/// Runs one request to completion, returning the action that reports it.
+pub async fn process(client: &dyn TemporalClient, request: CliRequest) -> Action {
+    match request {
+        CliRequest::LoadWorkflows {
+            fanout,
+            query,
+            page_size,
+            archived,
+            ..
+        } if !fanout.is_empty() => {
+            match list_across_namespaces(client, &fanout, query, page_size, archived).await {
+                Ok((workflows, failed)) => Action::FanoutLoaded(workflows, failed),
+                Err(e) => list_error(KindId::WorkflowExecution, "failed to load workflows", e),
+            }
+        }
+        CliRequest::LoadWorkflows {
+            namespace,
+            query,
+            page_size,
+            next_page_token,
+            archived,
+            ..
+        } => {
+            match list_workflows(
+                client,
+                &namespace,
                 query,
                 page_size,
                 next_page_token,
-            } => {
-                match self
-                    .client
-                    .list_workflows(&namespace, query.as_deref(), page_size, next_page_token)
-                    .await
-                {
-                    Ok((workflows, token)) => Action::WorkflowsLoaded(workflows, token),
-                    Err(e) => Action::Error(format!("failed to load workflows: {}", e)),
-                }
+                archived,
+            )
+            .await
+            {
+                Ok((workflows, token)) => Action::WorkflowsLoaded(workflows, token),
+                Err(e) => list_error(KindId::WorkflowExecution, "failed to load workflows", e),
             }
-            CliRequest::LoadMoreWorkflows {
-                namespace,
+        }
+        CliRequest::LoadMoreWorkflows {
+            namespace,
+            query,
+            page_size,
+            next_page_token,
+            archived,
+        } => {
+            match list_workflows(
+                client,
+                &namespace,
                 query,
                 page_size,
                 next_page_token,
-            } => {
-                match self
-                    .client
-                    .list_workflows(&namespace, query.as_deref(), page_size, next_page_token)
-                    .await
-                {
-                    Ok((workflows, token)) => Action::MoreWorkflowsLoaded(workflows, token),
-                    Err(e) => Action::Error(format!("failed to load workflows: {}", e)),
-                }
+                archived,
+            )
+            .await
+            {
+                Ok((workflows, token)) => Action::MoreWorkflowsLoaded(workflows, token),
+                Err(e) => list_error(KindId::WorkflowExecution, "failed to load workflows", e),
             }
-            CliRequest::LoadWorkflowDetail {
-                namespace,
-                workflow_id,
-                run_id,
-            } => {
-                match self
-                    .client
-                    .describe_workflow(&namespace, &workflow_id, run_id.as_deref())
-                    .await
-                {
-                    Ok(detail) => Action::WorkflowDetailLoaded(Box::new(detail)),
-                    Err(e) => Action::Error(format!("failed to load workflow detail: {}", e)),
-                }
+        }
+        CliRequest::LoadWorkflowDetail {
+            namespace,
+            workflow_id,
+            run_id,
+        } => {
+            match client
+                .describe_workflow(&namespace, &workflow_id, run_id.as_deref())
+                .await
+            {
+                Ok(detail) => Action::WorkflowDetailLoaded(Box::new(detail)),
+                Err(e) => Action::Error(format!("failed to load workflow detail: {}", e)),
             }
-            CliRequest::LoadHistory {
-                namespace,
-                workflow_id,
-                run_id,
-            } => {
-                match self
-                    .client
-                    .get_history(&namespace, &workflow_id, run_id.as_deref())
-                    .await
-                {
-                    Ok(events) => Action::HistoryLoaded(events),
-                    Err(e) => Action::Error(format!("failed to load history: {}", e)),
-                }
+        }
+        CliRequest::LoadWorkflowPreview {
+            namespace,
+            workflow_id,
+            run_id,
+        } => {
+            let result = client
+                .describe_workflow(&namespace, &workflow_id, Some(&run_id))
+                .await
+                .map(Box::new)
+                .map_err(|e| e.to_string());
+            Action::WorkflowPreviewLoaded(workflow_id, run_id, result)
+        }
+        CliRequest::LoadHistory {
+            namespace,
+            workflow_id,
+            run_id,
+        } => {
+            match client
+                .get_history(&namespace, &workflow_id, run_id.as_deref())
+                .await
+            {
+                Ok(events) => Action::HistoryLoaded(events),
+                Err(e) => Action::Error(format!("failed to load history: {}", e)),
             }
-            CliRequest::LoadNamespaces => match self.client.list_namespaces().await {
-                Ok(namespaces) => Action::NamespacesLoaded(namespaces),
-                Err(e) => Action::Error(format!("failed to load namespaces: {}", e)),
-            },
-            CliRequest::LoadWorkflowCount { namespace, query } => {
-                match self
-                    .client
-                    .count_workflows(&namespace, query.as_deref())
-                    .await
-                {
-                    Ok(count) => Action::WorkflowCountLoaded(count),
-                    Err(e) => Action::Error(format!("failed to count workflows: {}", e)),
-                }
+        }
+        CliRequest::LoadStackTrace {
+            namespace,
+            workflow_id,
+            run_id,
+        } => {
+            match client
+                .query_workflow(&namespace, &workflow_id, run_id.as_deref(), "__stack_trace")
+                .await
+            {
+                Ok(serde_json::Value::String(trace)) => Action::StackTraceLoaded(trace),
+                Ok(other) => Action::StackTraceLoaded(
+                    serde_json::to_string_pretty(&other).unwrap_or_else(|_| other.to_string()),
+                ),
+                Err(e) => Action::StackTraceFailed(format!("{}", e)),
             }
-            CliRequest::LoadSchedules { namespace, query } => {
-                match self
-                    .client
-                    .list_schedules(&namespace, query.as_deref())
-                    .await
-                {
-                    Ok(schedules) => Action::SchedulesLoaded(schedules),
-                    Err(e) => Action::Error(format!("failed to load schedules: {}", e)),
+        }
+        CliRequest::LoadWorkflowMetadata {
+            namespace,
+            workflow_id,
+            run_id,
+        } => {
+            match client
+                .query_workflow(
+                    &namespace,
+                    &workflow_id,
+                    run_id.as_deref(),
+                    "__temporal_workflow_metadata",
+                )
+                .await
+            {
+                Ok(value) => {
+                    Action::WorkflowMetadataLoaded(Box::new(WorkflowMetadata::from_json(&value)))
                 }
+                Err(e) => Action::WorkflowMetadataFailed(format!("{}", e)),
             }
-            CliRequest::LoadScheduleDetail {
-                namespace,
-                schedule_id,
-            } => {
-                match self
-                    .client
-                    .describe_schedule(&namespace, &schedule_id)
-                    .await
-                {
-                    Ok(schedule) => Action::ScheduleDetailLoaded(Box::new(schedule)),
-                    Err(e) => Action::Error(format!("failed to load schedule detail: {}", e)),
-                }
+        }
+        CliRequest::LoadRawWorkflow {
+            namespace,
+            workflow_id,
+            run_id,
+        } => raw_detail_action(
+            client
+                .describe_workflow_raw(&namespace, &workflow_id, run_id.as_deref())
+                .await,
+        ),
+        CliRequest::LoadRawSchedule {
+            namespace,
+            schedule_id,
+        } => raw_detail_action(client.describe_schedule_raw(&namespace, &schedule_id).await),
+        CliRequest::LoadNamespaces => match client.list_namespaces().await {
+            Ok(namespaces) => Action::NamespacesLoaded(namespaces),
+            Err(e) => Action::Error(format!("failed to load namespaces: {}", e)),
+        },
+        CliRequest::LoadClusterInfo => match client.get_cluster_info().await {
+            Ok(info) => Action::ClusterInfoLoaded(Box::new(info)),
+            Err(e) => Action::Error(format!("failed to load cluster info: {}", e)),
+        },
+        CliRequest::HealthCheck => {
+            let started = std::time::Instant::now();
+            let result = client.get_cluster_info().await;
+            Action::HealthChecked(
+                result
+                    .map(|info| (started.elapsed(), Box::new(info)))
+                    .map_err(|e| e.to_string()),
+            )
+        }
+        CliRequest::LoadWorkflowCount { namespace, query } => {
+            match client
+                .count_workflows_by_status(&namespace, query.as_deref())
+                .await
+            {
+                Ok(counts) => Action::WorkflowStatusCountsLoaded(counts),
+                Err(ClientError::Unimplemented(_)) => Action::WorkflowCountUnavailable,
+                Err(e) => list_error(KindId::WorkflowExecution, "failed to count workflows", e),
             }
-            CliRequest::CancelWorkflow {
-                namespace,
-                workflow_id,
-                run_id,
-            } => {
-                match self
-                    .client
-                    .cancel_workflow(&namespace, &workflow_id, run_id.as_deref())
-                    .await
-                {
-                    Ok(()) => Action::Refresh,
-                    Err(e) => Action::Error(format!("failed to cancel workflow: {}", e)),
-                }
+        }
+        CliRequest::LoadSchedules {
+            namespace,
+            query,
+            page_size,
+            next_page_token,
+        } => {
+            match client
+                .list_schedules(&namespace, query.as_deref(), page_size, next_page_token)
+                .await
+            {
+                Ok((schedules, token)) => Action::SchedulesLoaded(schedules, token),
+                Err(e) => list_error(KindId::Schedule, "failed to load schedules", e),
             }
-            CliRequest::TerminateWorkflow {
-                namespace,
-                workflow_id,
-                run_id,
-            } => {
-                match self
-                    .client
-                    .terminate_workflow(
-                        &namespace,
-                        &workflow_id,
-                        run_id.as_deref(),
-                        "terminated via t9s",
-                    )
-                    .await
-                {
-                    Ok(()) => Action::Refresh,
-                    Err(e) => Action::Error(format!("failed to terminate workflow: {}", e)),
-                }
+        }
+        CliRequest::LoadMoreSchedules {
+            namespace,
+            query,
+            page_size,
+            next_page_token,
+        } => {
+            match client
+                .list_schedules(&namespace, query.as_deref(), page_size, next_page_token)
+                .await
+            {
+                Ok((schedules, token)) => Action::MoreSchedulesLoaded(schedules, token),
+                Err(e) => list_error(KindId::Schedule, "failed to load more schedules", e),
             }
-            CliRequest::PauseSchedule {
-                namespace,
-                schedule_id,
-                pause,
-            } => {
-                match self
-                    .client
-                    .patch_schedule(&namespace, &schedule_id, pause)
-                    .await
-                {
-                    Ok(()) => Action::Refresh,
-                    Err(e) => Action::Error(format!("failed to update schedule: {}", e)),
-                }
+        }
+        CliRequest::LoadScheduleDetail {
+            namespace,
+            schedule_id,
+        } => match client.describe_schedule(&namespace, &schedule_id).await {
+            Ok(schedule) => Action::ScheduleDetailLoaded(Box::new(schedule)),
+            Err(e) => Action::Error(format!("failed to load schedule detail: {}", e)),
+        },
+        CliRequest::CancelWorkflow {
+            namespace,
+            workflow_id,
+            run_id,
+        } => {
+            let Some(admin) = client.execution_admin() else {
+                return unsupported("workflow and activity operations");
+            };
+            match admin
+                .cancel_workflow(&namespace, &workflow_id, run_id.as_deref())
+                .await
+            {
+                Ok(()) => Action::Refresh,
+                Err(e) => Action::Error(format!("failed to cancel workflow: {}", e)),
             }
-            CliRequest::TriggerSchedule {
-                namespace,
-                schedule_id,
-            } => match self.client.trigger_schedule(&namespace, &schedule_id).await {
+        }
+        CliRequest::TerminateWorkflow {
+            namespace,
+            workflow_id,
+            run_id,
+        } => {
+            let Some(admin) = client.execution_admin() else {
+                return unsupported("workflow and activity operations");
+            };
+            match admin
+                .terminate_workflow(
+                    &namespace,
+                    &workflow_id,
+                    run_id.as_deref(),
+                    "terminated via t9s",
+                )
+                .await
+            {
+                Ok(()) => Action::Refresh,
+                Err(e) => Action::Error(format!("failed to terminate workflow: {}", e)),
+            }
+        }
+        CliRequest::TerminateAndRestartWorkflow {
+            namespace,
+            workflow_id,
+            run_id,
+        } => {
+            let Some(admin) = client.execution_admin() else {
+                return unsupported("workflow and activity operations");
+            };
+            terminate_and_restart(admin, &namespace, workflow_id, run_id.as_deref()).await
+        }
+        CliRequest::PauseSchedule {
+            namespace,
+            schedule_id,
+            pause,
+            note,
+        } => {
+            let Some(admin) = client.schedule_admin() else {
+                return unsupported("schedule operations");
+            };
+            match admin
+                .patch_schedule(&namespace, &schedule_id, pause, &note)
+                .await
+            {
+                Ok(()) => Action::Refresh,
+                Err(e) => Action::Error(format!("failed to update schedule: {}", e)),
+            }
+        }
+        CliRequest::TriggerSchedule {
+            namespace,
+            schedule_id,
+        } => {
+            let Some(admin) = client.schedule_admin() else {
+                return unsupported("schedule operations");
+            };
+            match admin.trigger_schedule(&namespace, &schedule_id).await {
                 Ok(()) => Action::Refresh,
                 Err(e) => Action::Error(format!("failed to trigger schedule: {}", e)),
-            },
-            CliRequest::DeleteSchedule {
-                namespace,
-                schedule_id,
-            } => match self.client.delete_schedule(&namespace, &schedule_id).await {
+            }
+        }
+        CliRequest::DeleteSchedule {
+            namespace,
+            schedule_id,
+        } => {
+            let Some(admin) = client.schedule_admin() else {
+                return unsupported("schedule operations");
+            };
+            match admin.delete_schedule(&namespace, &schedule_id).await {
                 Ok(()) => Action::Refresh,
                 Err(e) => Action::Error(format!("failed to delete schedule: {}", e)),
-            },
-            CliRequest::DescribeTaskQueue {
-                namespace,
-                task_queue,
-            } => {
-                match self
-                    .client
-                    .describe_task_queue(&namespace, &task_queue)
-                    .await
-                {
-                    Ok(tq) => Action::TaskQueueDetailLoaded(Box::new(tq)),
-                    Err(e) => Action::Error(format!("failed to describe task queue: {}", e)),
-                }
             }
-            CliRequest::LoadActivityExecutions {
-                namespace,
-                query,
-                page_size,
-                next_page_token,
-            } => {
-                match self
-                    .client
-                    .list_activity_executions(
-                        &namespace,
-                        query.as_deref(),
-                        page_size,
-                        next_page_token,
-                    )
-                    .await
-                {
-                    Ok((activities, token)) => Action::ActivityExecutionsLoaded(activities, token),
-                    Err(e) => Action::Error(format!("failed to load activities: {}", e)),
-                }
+        }
+        CliRequest::LoadScheduleDraft {
+            namespace,
+            schedule_id,
+        } => {
+            let Some(admin) = client.schedule_admin() else {
+                return unsupported("schedule operations");
+            };
+            match admin.schedule_draft(&namespace, &schedule_id).await {
+                Ok(draft) => Action::ScheduleDraftLoaded(Box::new(draft)),
+                Err(e) => Action::Error(format!("failed to load schedule for editing: {}", e)),
             }
-            CliRequest::LoadMoreActivityExecutions {
-                namespace,
-                query,
-                page_size,
-                next_page_token,
-            } => {
-                match self
-                    .client
-                    .list_activity_executions(
-                        &namespace,
-                        query.as_deref(),
-                        page_size,
-                        next_page_token,
-                    )
-                    .await
-                {
-                    Ok((activities, token)) => {
-                        Action::MoreActivityExecutionsLoaded(activities, token)
-                    }
-                    Err(e) => Action::Error(format!("failed to load more activities: {}", e)),
-                }
+        }
+        CliRequest::UpdateSchedule {
+            namespace,
+            schedule_id,
+            edit,
+            conflict_token,
+        } => {
+            let Some(admin) = client.schedule_admin() else {
+                return unsupported("schedule operations");
+            };
+            match admin
+                .update_schedule(&namespace, &schedule_id, &edit, &conflict_token)
+                .await
+            {
+                Ok(()) => Action::Refresh,
+                Err(e) => Action::Error(format!("failed to update schedule: {}", e)),
             }
-            CliRequest::DescribeActivityExecution {
-                namespace,
-                activity_id,
-                run_id,
-            } => {
-                match self
-                    .client
-                    .describe_activity_execution(&namespace, &activity_id, &run_id)
-                    .await
-                {
-                    Ok(detail) => Action::ActivityExecutionDetailLoaded(Box::new(detail)),
-                    Err(e) => Action::Error(format!("failed to load activity detail: {}", e)),
-                }
+        }
+        CliRequest::UpdateActivityOptions {
+            namespace,
+            draft,
+            edit,
+        } => {
+            let Some(admin) = client.execution_admin() else {
+                return unsupported("execution operations");
+            };
+            match admin
+                .update_activity_options(&namespace, &draft, &edit)
+                .await
+            {
+                Ok(()) => Action::Refresh,
+                Err(e) => Action::Error(format!("failed to update activity options: {}", e)),
             }
-            CliRequest::CountActivityExecutions { namespace, query } => {
-                match self
-                    .client
-                    .count_activity_executions(&namespace, query.as_deref())
-                    .await
-                {
-                    Ok(count) => Action::ActivityExecutionCountLoaded(count),
-                    Err(e) => Action::Error(format!("failed to count activities: {}", e)),
-                }
+        }
+        CliRequest::DescribeTaskQueue {
+            namespace,
+            task_queue,
+        } => {
+            let Some(admin) = client.task_queue_reader() else {
+                return unsupported("task queue details");
+            };
+            match admin.describe_task_queue(&namespace, &task_queue).await {
+                Ok(tq) => Action::TaskQueueDetailLoaded(Box::new(tq)),
+                Err(e) => Action::Error(format!("failed to describe task queue: {}", e)),
             }
-            CliRequest::RequestCancelActivityExecution {
-                namespace,
-                activity_id,
-                run_id,
-            } => {
-                match self
-                    .client
-                    .request_cancel_activity_execution(&namespace, &activity_id, &run_id)
-                    .await
-                {
-                    Ok(()) => Action::Refresh,
-                    Err(e) => Action::Error(format!("failed to cancel activity: {}", e)),
-                }
+        }
+        CliRequest::LoadOverview { namespace } => {
+            Action::OverviewLoaded(Box::new(load_overview(client, &namespace).await))
+        }
+        CliRequest::LoadSearchAttributes { namespace } => Action::SearchAttributesLoaded(
+            client
+                .list_search_attributes(&namespace)
+                .await
+                .map_err(|e| format!("failed to list search attributes: {}", e)),
+        ),
+        CliRequest::AddSearchAttribute {
+            namespace,
+            name,
+            value_type,
+        } => {
+            let Some(admin) = client.namespace_admin() else {
+                return unsupported("namespace operations");
+            };
+            if let Err(e) = admin
+                .add_search_attribute(&namespace, &name, value_type)
+                .await
+            {
+                return Action::Error(format!("failed to add search attribute {}: {}", name, e));
             }
-            CliRequest::TerminateActivityExecution {
-                namespace,
-                activity_id,
-                run_id,
-                reason,
-            } => {
-                match self
-                    .client
-                    .terminate_activity_execution(&namespace, &activity_id, &run_id, &reason)
+            Action::SearchAttributesLoaded(
+                client
+                    .list_search_attributes(&namespace)
                     .await
-                {
-                    Ok(()) => Action::Refresh,
-                    Err(e) => Action::Error(format!("failed to terminate activity: {}", e)),
+                    .map_err(|e| format!("failed to list search attributes: {}", e)),
+            )
+        }
+        CliRequest::ChangeNamespace(change) => {
+            let Some(admin) = client.namespace_admin() else {
+                return unsupported("namespace operations");
+            };
+            let (verb, result) = match &change {
+                NamespaceChange::Register {
+                    name,
+                    retention,
+                    description,
+                } => (
+                    "register",
+                    admin
+                        .register_namespace(name, description, *retention)
+                        .await,
+                ),
+                NamespaceChange::Update {
+                    name,
+                    retention,
+                    description,
+                } => (
+                    "update",
+                    admin
+                        .update_namespace(name, description.as_deref(), *retention)
+                        .await,
+                ),
+                NamespaceChange::Deprecate { name } => {
+                    ("deprecate", admin.deprecate_namespace(name).await)
                 }
+            };
+            if let Err(e) = result {
+                return Action::Error(format!(
+                    "failed to {} namespace {}: {}",
+                    verb,
+                    change.name(),
+                    e
+                ));
             }
-            CliRequest::DeleteActivityExecution {
-                namespace,
-                activity_id,
-                run_id,
-            } => {
-                match self
-                    .client
-                    .delete_activity_execution(&namespace, &activity_id, &run_id)
-                    .await
-                {
-                    Ok(()) => Action::Refresh,
-                    Err(e) => Action::Error(format!("failed to delete activity: {}", e)),
-                }
+            match client.list_namespaces().await {
+                Ok(namespaces) => Action::NamespacesLoaded(namespaces),
+                Err(e) => Action::Error(format!("failed to load namespaces: {}", e)),
             }
-            CliRequest::CheckActivitySupport { namespace } => {
-                match self.client.check_activity_support(&namespace).await {
-                    Ok(supported) => Action::ActivitiesSupported(supported),
-                    Err(e) => Action::Error(format!("failed to check activity support: {}", e)),
-                }
+        }
+        CliRequest::LoadActivityExecutions {
+            namespace,
+            query,
+            page_size,
+            next_page_token,
+        } => {
+            match client
+                .list_activity_executions(&namespace, query.as_deref(), page_size, next_page_token)
+                .await
+            {
+                Ok((activities, token)) => Action::ActivityExecutionsLoaded(activities, token),
+                Err(e) => list_error(KindId::ActivityExecution, "failed to load activities", e),
             }
-            CliRequest::SignalWorkflow {
-                namespace,
-                workflow_id,
-                run_id,
-                signal_name,
-                input,
-            } => {
-                match self
-                    .client
-                    .signal_workflow(
-                        &namespace,
-                        &workflow_id,
-                        run_id.as_deref(),
-                        &signal_name,
-                        input.as_deref(),
-                    )
-                    .await
-                {
-                    Ok(()) => Action::Refresh,
-                    Err(e) => Action::Error(format!("failed to signal workflow: {}", e)),
+        }
+        CliRequest::LoadMoreActivityExecutions {
+            namespace,
+            query,
+            page_size,
+            next_page_token,
+        } => {
+            match client
+                .list_activity_executions(&namespace, query.as_deref(), page_size, next_page_token)
+                .await
+            {
+                Ok((activities, token)) => Action::MoreActivityExecutionsLoaded(activities, token),
+                Err(e) => list_error(
+                    KindId::ActivityExecution,
+                    "failed to load more activities",
+                    e,
+                ),
+            }
+        }
+        CliRequest::DescribeActivityExecution {
+            namespace,
+            activity_id,
+            run_id,
+        } => {
+            match client
+                .describe_activity_execution(&namespace, &activity_id, &run_id)
+                .await
+            {
+                Ok(detail) => Action::ActivityExecutionDetailLoaded(Box::new(detail)),
+                Err(e) => Action::Error(format!("failed to load activity detail: {}", e)),
+            }
+        }
+        CliRequest::CountActivityExecutions { namespace, query } => {
+            match client
+                .count_activity_executions(&namespace, query.as_deref())
+                .await
+            {
+                Ok(count) => Action::ActivityExecutionCountLoaded(count),
+                Err(e) => list_error(KindId::ActivityExecution, "failed to count activities", e),
+            }
+        }
+        CliRequest::RequestCancelActivityExecution {
+            namespace,
+            activity_id,
+            run_id,
+        } => {
+            let Some(admin) = client.execution_admin() else {
+                return unsupported("workflow and activity operations");
+            };
+            match admin
+                .request_cancel_activity_execution(&namespace, &activity_id, &run_id)
+                .await
+            {
+                Ok(()) => Action::Refresh,
+                Err(e) => Action::Error(format!("failed to cancel activity: {}", e)),
+            }
+        }
+        CliRequest::TerminateActivityExecution {
+            namespace,
+            activity_id,
+            run_id,
+            reason,
+        } => {
+            let Some(admin) = client.execution_admin() else {
+                return unsupported("workflow and activity operations");
+            };
+            match admin
+                .terminate_activity_execution(&namespace, &activity_id, &run_id, &reason)
+                .await
+            {
+                Ok(()) => Action::Refresh,
+                Err(e) => Action::Error(format!("failed to terminate activity: {}", e)),
+            }
+        }
+        CliRequest::DeleteActivityExecution {
+            namespace,
+            activity_id,
+            run_id,
+        } => {
+            let Some(admin) = client.execution_admin() else {
+                return unsupported("workflow and activity operations");
+            };
+            match admin
+                .delete_activity_execution(&namespace, &activity_id, &run_id)
+                .await
+            {
+                Ok(()) => Action::Refresh,
+                Err(e) => Action::Error(format!("failed to delete activity: {}", e)),
+            }
+        }
+        CliRequest::CountBatch {
+            namespace,
+            operation,
+            query,
+        } => match client.count_workflows(&namespace, Some(&query)).await {
+            Ok(count) => Action::BatchCounted(namespace, operation, query, count),
+            Err(e) => Action::Error(format!("failed to count workflows: {}", e)),
+        },
+        CliRequest::StartBatchOperation {
+            namespace,
+            operation,
+            target,
+        } => {
+            let Some(admin) = client.execution_admin() else {
+                return unsupported("workflow and activity operations");
+            };
+            let reason = format!("{} via t9s", operation.verb());
+            match admin
+                .start_batch_operation(&namespace, operation, &target, &reason)
+                .await
+            {
+                Ok(job_id) => Action::BatchStarted(operation, job_id),
+                Err(e) => {
+                    Action::Error(format!("failed to start batch {}: {}", operation.verb(), e))
                 }
             }
         }
+        CliRequest::CheckActivitySupport { namespace } => {
+            match client.check_activity_support(&namespace).await {
+                Ok(supported) => Action::ActivitiesSupported(supported),
+                Err(e) => Action::Error(format!("failed to check activity support: {}", e)),
+            }
+        }
+        CliRequest::SignalWorkflow {
+            namespace,
+            workflow_id,
+            run_id,
+            signal_name,
+            input,
+        } => {
+            let Some(admin) = client.execution_admin() else {
+                return unsupported("workflow and activity operations");
+            };
+            match admin
+                .signal_workflow(
+                    &namespace,
+                    &workflow_id,
+                    run_id.as_deref(),
+                    &signal_name,
+                    input.as_deref(),
+                )
+                .await
+            {
+                Ok(()) => Action::Refresh,
+                Err(e) => Action::Error(format!("failed to signal workflow: {}", e)),
+            }
+        }
+    }
+}
+
+/// How many recent runs the overview samples for workflow types and task
+/// queues, and how many of each it shows.
+const OVERVIEW_SAMPLE: i32 = 200;
+const OVERVIEW_TOP: usize = 8;
+
+/// Loads each section of the overview on its own; a section that fails
+/// is reported in [`NamespaceOverview::errors`] and the rest still show.
+async fn load_overview(client: &dyn TemporalClient, namespace: &str) -> NamespaceOverview {
+    let failing_since = chrono::Utc::now() - chrono::Duration::days(1);
+    let failing_query = format!(
+        "TemporalScheduledById IS NOT NULL AND ExecutionStatus IN ('Failed', 'TimedOut') \
+         AND CloseTime > '{}'",
+        failing_since.to_rfc3339_opts(chrono::SecondsFormat::Secs, true)
+    );
+    let (status_counts, recent, schedules, failed) = futures::join!(
+        client.count_workflows_by_status(namespace, None),
+        client.list_workflows(namespace, None, OVERVIEW_SAMPLE, vec![]),
+        client.list_schedules(namespace, None, OVERVIEW_SAMPLE, vec![]),
+        client.list_workflows(namespace, Some(&failing_query), OVERVIEW_SAMPLE, vec![]),
+    );
+    let mut overview = NamespaceOverview {
+        failing_since,
+        ..Default::default()
+    };
+    let mut failed_section =
+        |section, e: ClientError| overview.errors.push((section, e.to_string()));
+
+    match status_counts {
+        Ok(counts) => overview.status_counts = counts,
+        Err(e) => failed_section(STATUS_SECTION, e),
+    }
+    let recent = match recent {
+        Ok((recent, _)) => recent,
+        Err(e) => {
+            failed_section(TYPE_SECTION, e);
+            vec![]
+        }
+    };
+    match schedules {
+        Ok((schedules, token)) => {
+            overview.schedules = schedules.len();
+            overview.paused_schedules = schedules
+                .iter()
+                .filter(|s| s.state == ScheduleState::Paused)
+                .count();
+            overview.more_schedules = !token.is_empty();
+        }
+        Err(e) => failed_section(SCHEDULE_SECTION, e),
+    }
+    match failed {
+        Ok((failed, _)) => {
+            overview.failing_schedules = most_common_counted(failed.iter().filter_map(|w| {
+                w.search_attributes
+                    .get("TemporalScheduledById")
+                    .and_then(|v| v.as_str())
+                    .map(str::to_string)
+            }))
+        }
+        Err(e) => failed_section(SCHEDULE_SECTION, e),
+    }
+
+    let workflow_types = most_common(recent.iter().map(|w| w.workflow_type.clone()));
+    let type_counts = futures::future::try_join_all(workflow_types.into_iter().map(|name| {
+        let query = format!("WorkflowType = '{}'", escape_single_quotes(&name));
+        async move {
+            let count = client.count_workflows(namespace, Some(&query)).await?;
+            Ok::<_, ClientError>((name, count))
+        }
+    }))
+    .await;
+    match type_counts {
+        Ok(mut type_counts) => {
+            type_counts.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+            overview.type_counts = type_counts;
+        }
+        Err(e) => failed_section(TYPE_SECTION, e),
+    }
+
+    if let Some(reader) = client.task_queue_reader() {
+        let queues = most_common(
+            recent
+                .iter()
+                .filter(|w| w.status == WorkflowStatus::Running)
+                .map(|w| w.task_queue.clone()),
+        );
+        let infos = futures::future::try_join_all(
+            queues
+                .iter()
+                .map(|queue| reader.describe_task_queue(namespace, queue)),
+        )
+        .await;
+        overview.idle_task_queues = match infos {
+            Ok(infos) => Some(
+                infos
+                    .into_iter()
+                    .filter(|info| {
+                        info.workflow_pollers.is_empty() && info.activity_pollers.is_empty()
+                    })
+                    .map(|info| info.name)
+                    .collect(),
+            ),
+            Err(e) => {
+                failed_section(TASK_QUEUE_SECTION, e);
+                Some(vec![])
+            }
+        };
+    }
+    overview
+}
+
+/// The most frequent values, most frequent first, at most [`OVERVIEW_TOP`].
+fn most_common(values: impl Iterator<Item = String>) -> Vec<String> {
+    most_common_counted(values)
+        .into_iter()
+        .map(|(value, _)| value)
+        .collect()
+}
+
+fn most_common_counted(values: impl Iterator<Item = String>) -> Vec<(String, u64)> {
+    let mut counts: HashMap<String, u64> = HashMap::new();
+    for value in values.filter(|v| !v.is_empty()) {
+        *counts.entry(value).or_default() += 1;
+    }
+    let mut counts: Vec<(String, u64)> = counts.into_iter().collect();
+    counts.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+    counts.truncate(OVERVIEW_TOP);
+    counts
+}
+
+/// A rejected search query goes back to the search modal; anything else is
+/// an ordinary error.
+fn list_error(kind: KindId, what: &str, e: ClientError) -> Action {
+    match e {
+        ClientError::InvalidArgument(msg) => Action::InvalidQuery(kind, msg),
+        e => Action::Error(format!("{}: {}", what, e)),
+    }
+}
+
+fn raw_detail_action(result: ClientResult<serde_json::Value>) -> Action {
+    match result {
+        Ok(value) => Action::RawDetailLoaded(Box::new(value)),
+        Err(e) => Action::RawDetailFailed(e.to_string()),
+    }
+}
+
+fn unsupported(what: &str) -> Action {
+    Action::Error(format!("{} are not supported by this backend", what))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::client::StartAttributes;
+
+    #[test]
+    fn only_polled_loads_are_fingerprinted() {
+        let count = |query: &str| CliRequest::LoadWorkflowCount {
+            namespace: "default".to_string(),
+            query: Some(query.to_string()),
+        };
+        assert_eq!(count("a").fingerprint(), count("a").fingerprint());
+        assert_ne!(count("a").fingerprint(), count("b").fingerprint());
+        assert_eq!(CliRequest::LoadNamespaces.fingerprint(), None);
+    }
+
+    #[test]
+    fn only_mutations_are_audited() {
+        let terminate = CliRequest::TerminateWorkflow {
+            namespace: "prod".to_string(),
+            workflow_id: "order-1".to_string(),
+            run_id: Some("run-1".to_string()),
+        };
+        assert_eq!(
+            terminate.audit(),
+            Some((
+                "prod".to_string(),
+                "terminate workflow",
+                "order-1/run-1".to_string()
+            ))
+        );
+        let pause = CliRequest::PauseSchedule {
+            namespace: "prod".to_string(),
+            schedule_id: "nightly".to_string(),
+            pause: false,
+            note: String::new(),
+        };
+        assert_eq!(pause.audit().map(|(_, op, _)| op), Some("unpause schedule"));
+        assert_eq!(CliRequest::LoadNamespaces.audit(), None);
+    }
+
+    /// Records the restart's calls, failing the ones named in `fail`.
+    struct RestartRecorder {
+        calls: Mutex<Vec<&'static str>>,
+        fail: &'static str,
+    }
+
+    impl RestartRecorder {
+        fn call(&self, name: &'static str) -> ClientResult<()> {
+            self.calls.lock().unwrap().push(name);
+            match name == self.fail {
+                true => Err(ClientError::RequestFailed(name.to_string())),
+                false => Ok(()),
+            }
+        }
+    }
+
+    #[async_trait::async_trait]
+    impl ExecutionAdmin for RestartRecorder {
+        async fn cancel_workflow(&self, _: &str, _: &str, _: Option<&str>) -> ClientResult<()> {
+            unreachable!()
+        }
+
+        async fn terminate_workflow(
+            &self,
+            _: &str,
+            _: &str,
+            _: Option<&str>,
+            _: &str,
+        ) -> ClientResult<()> {
+            self.call("terminate")
+        }
+
+        async fn start_attributes(
+            &self,
+            _: &str,
+            _: &str,
+            _: Option<&str>,
+        ) -> ClientResult<StartAttributes> {
+            self.call("read start")?;
+            Ok(StartAttributes::Http(serde_json::Map::new()))
+        }
+
+        async fn start_workflow_from(
+            &self,
+            _: &str,
+            _: &str,
+            _: StartAttributes,
+        ) -> ClientResult<String> {
+            self.call("start")?;
+            Ok("run-2".to_string())
+        }
+
+        async fn signal_workflow(
+            &self,
+            _: &str,
+            _: &str,
+            _: Option<&str>,
+            _: &str,
+            _: Option<&str>,
+        ) -> ClientResult<()> {
+            unreachable!()
+        }
+
+        async fn request_cancel_activity_execution(
+            &self,
+            _: &str,
+            _: &str,
+            _: &str,
+        ) -> ClientResult<()> {
+            unreachable!()
+        }
+
+        async fn terminate_activity_execution(
+            &self,
+            _: &str,
+            _: &str,
+            _: &str,
+            _: &str,
+        ) -> ClientResult<()> {
+            unreachable!()
+        }
+
+        async fn delete_activity_execution(&self, _: &str, _: &str, _: &str) -> ClientResult<()> {
+            unreachable!()
+        }
+
+        async fn update_activity_options(
+            &self,
+            _: &str,
+            _: &ActivityOptionsDraft,
+            _: &ActivityOptionsEdit,
+        ) -> ClientResult<()> {
+            unreachable!()
+        }
+
+        async fn start_batch_operation(
+            &self,
+            _: &str,
+            _: BatchOperation,
+            _: &BatchTarget,
+            _: &str,
+        ) -> ClientResult<String> {
+            unreachable!()
+        }
+    }
+
+    #[tokio::test]
+    async fn restarts_read_the_start_before_terminating() {
+        let restart = |fail| async move {
+            let admin = RestartRecorder {
+                calls: Mutex::default(),
+                fail,
+            };
+            let action =
+                terminate_and_restart(&admin, "default", "order-1".to_string(), Some("run-1"))
+                    .await;
+            (action, admin.calls.into_inner().unwrap())
+        };
+
+        let (action, calls) = restart("").await;
+        assert!(
+            matches!(action, Action::WorkflowRestarted(id, run) if id == "order-1" && run == "run-2")
+        );
+        assert_eq!(calls, vec!["read start", "terminate", "start"]);
+
+        // A start that can't be read leaves the run alone.
+        let (action, calls) = restart("read start").await;
+        assert!(matches!(action, Action::Error(msg) if msg.starts_with("failed to read")));
+        assert_eq!(calls, vec!["read start"]);
+
+        let (action, calls) = restart("start").await;
+        assert!(matches!(action, Action::Error(msg) if msg.starts_with("terminated workflow")));
+        assert_eq!(calls, vec!["read start", "terminate", "start"]);
+    }
+
+    #[tokio::test]
+    async fn audited_mutations_reach_the_file_and_the_session_log() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("audit.jsonl");
+        let audit = Some(("prod".to_string(), "cancel workflow", "order-1".to_string()));
+
+        let actions = record_audit("staging", audit.clone(), &Action::Refresh, Some(&path)).await;
+        assert!(matches!(
+            actions.as_slice(),
+            [Action::Audited(entry)] if entry.context == "staging" && entry.error.is_none()
+        ));
+        let failed = Action::Error("not found".to_string());
+        record_audit("staging", audit.clone(), &failed, Some(&path)).await;
+        let lines = std::fs::read_to_string(&path).unwrap();
+        assert_eq!(lines.lines().count(), 2);
+        assert!(lines.contains("\"error\":\"not found\""));
+
+        assert!(record_audit("staging", None, &Action::Refresh, Some(&path))
+            .await
+            .is_empty());
+        let unwritable = dir.path().join("missing").join("audit.jsonl");
+        let actions = record_audit("staging", audit, &Action::Refresh, Some(&unwritable)).await;
+        assert!(matches!(
+            actions.as_slice(),
+            [Action::Audited(_), Action::Error(msg)] if msg.starts_with("writing audit log")
+        ));
     }
 }